@@ -20,6 +20,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             .expect("NETBOX_API_TOKEN not set. Run: source ~/.secrets/cim-env.sh"),
         default_site_id: Some(1),
         timeout_secs: 30,
+        soft_delete: false,
     };
 
     println!("🔧 Connecting to NetBox at {}", config.base_url);
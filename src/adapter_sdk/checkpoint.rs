@@ -0,0 +1,167 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Projection Checkpoints
+//!
+//! A checkpoint is the last sequence an adapter applied for one aggregate.
+//! [`ProjectionManager`](crate::projection::manager::ProjectionManager)
+//! replays the stream from the start when a service restarts; with
+//! checkpoints an integration skips what it already wrote to its target
+//! instead of calling the external API again for every historical event.
+//!
+//! Checkpoints are kept per adapter name, so several integrations can share
+//! one store.
+
+use async_nats::jetstream::{self, kv};
+use async_trait::async_trait;
+use futures::StreamExt;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use uuid::Uuid;
+
+use crate::projection::ProjectionError;
+
+/// JetStream KV bucket holding projection checkpoints
+pub const CHECKPOINT_BUCKET: &str = "projection_checkpoints";
+
+/// Persistent per-aggregate positions of projection adapters
+#[async_trait]
+pub trait CheckpointStore: Send + Sync {
+    /// Last sequence `adapter` applied for `aggregate_id`
+    async fn load(&self, adapter: &str, aggregate_id: Uuid)
+        -> Result<Option<u64>, ProjectionError>;
+
+    /// Record positions reached by `adapter`
+    async fn save(&self, adapter: &str, positions: &[(Uuid, u64)]) -> Result<(), ProjectionError>;
+
+    /// Forget every position of `adapter`
+    async fn clear(&self, adapter: &str) -> Result<(), ProjectionError>;
+}
+
+/// In-memory checkpoints, for tests and adapters that rebuild on start
+#[derive(Debug, Default)]
+pub struct MemoryCheckpointStore {
+    positions: Mutex<HashMap<(String, Uuid), u64>>,
+}
+
+impl MemoryCheckpointStore {
+    /// Empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl CheckpointStore for MemoryCheckpointStore {
+    async fn load(
+        &self,
+        adapter: &str,
+        aggregate_id: Uuid,
+    ) -> Result<Option<u64>, ProjectionError> {
+        let positions = self.positions.lock().expect("checkpoint lock poisoned");
+        Ok(positions.get(&(adapter.to_string(), aggregate_id)).copied())
+    }
+
+    async fn save(&self, adapter: &str, positions: &[(Uuid, u64)]) -> Result<(), ProjectionError> {
+        let mut stored = self.positions.lock().expect("checkpoint lock poisoned");
+        for (aggregate_id, sequence) in positions {
+            stored.insert((adapter.to_string(), *aggregate_id), *sequence);
+        }
+        Ok(())
+    }
+
+    async fn clear(&self, adapter: &str) -> Result<(), ProjectionError> {
+        let mut stored = self.positions.lock().expect("checkpoint lock poisoned");
+        stored.retain(|(name, _), _| name != adapter);
+        Ok(())
+    }
+}
+
+/// Checkpoints stored in a JetStream KV bucket, keyed `<adapter>.<aggregate_id>`
+pub struct KvCheckpointStore {
+    store: kv::Store,
+}
+
+impl KvCheckpointStore {
+    /// Open the checkpoint bucket, creating it if needed
+    pub async fn open(jetstream: jetstream::Context) -> Result<Self, ProjectionError> {
+        let store = match jetstream.get_key_value(CHECKPOINT_BUCKET).await {
+            Ok(store) => store,
+            Err(_) => jetstream
+                .create_key_value(kv::Config {
+                    bucket: CHECKPOINT_BUCKET.to_string(),
+                    description: "Per-aggregate positions of projection adapters".to_string(),
+                    history: 1,
+                    ..Default::default()
+                })
+                .await
+                .map_err(|e| ProjectionError::InitializationFailed(e.to_string()))?,
+        };
+
+        Ok(Self { store })
+    }
+
+    fn key(adapter: &str, aggregate_id: Uuid) -> String {
+        format!("{}.{}", adapter, aggregate_id)
+    }
+}
+
+#[async_trait]
+impl CheckpointStore for KvCheckpointStore {
+    async fn load(
+        &self,
+        adapter: &str,
+        aggregate_id: Uuid,
+    ) -> Result<Option<u64>, ProjectionError> {
+        let value = self
+            .store
+            .get(Self::key(adapter, aggregate_id))
+            .await
+            .map_err(|e| ProjectionError::TargetUnavailable(e.to_string()))?;
+
+        value
+            .map(|bytes| {
+                std::str::from_utf8(&bytes)
+                    .ok()
+                    .and_then(|text| text.parse().ok())
+                    .ok_or_else(|| {
+                        ProjectionError::Other(format!(
+                            "Corrupt checkpoint for {} in {}",
+                            aggregate_id, adapter
+                        ))
+                    })
+            })
+            .transpose()
+    }
+
+    async fn save(&self, adapter: &str, positions: &[(Uuid, u64)]) -> Result<(), ProjectionError> {
+        for (aggregate_id, sequence) in positions {
+            self.store
+                .put(
+                    Self::key(adapter, *aggregate_id),
+                    sequence.to_string().into_bytes().into(),
+                )
+                .await
+                .map_err(|e| ProjectionError::TargetUnavailable(e.to_string()))?;
+        }
+        Ok(())
+    }
+
+    async fn clear(&self, adapter: &str) -> Result<(), ProjectionError> {
+        let prefix = format!("{}.", adapter);
+        let mut keys = self
+            .store
+            .keys()
+            .await
+            .map_err(|e| ProjectionError::TargetUnavailable(e.to_string()))?;
+
+        while let Some(key) = keys.next().await {
+            let key = key.map_err(|e| ProjectionError::TargetUnavailable(e.to_string()))?;
+            if key.starts_with(&prefix) {
+                self.store
+                    .purge(&key)
+                    .await
+                    .map_err(|e| ProjectionError::TargetUnavailable(e.to_string()))?;
+            }
+        }
+        Ok(())
+    }
+}
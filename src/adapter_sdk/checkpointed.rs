@@ -0,0 +1,226 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Checkpointed Projection
+//!
+//! [`Checkpointed`] wraps an integration's [`ProjectionAdapter`] and adds
+//! what every integration needs but none should write twice:
+//!
+//! ```text
+//! StoredEvent ──> Checkpointed ── sequence <= checkpoint? ──> skip (metrics.skipped)
+//!                      │
+//!                      └──> inner.project(event) ──ok──> metrics.projected, checkpoint
+//!                                                 └─err─> metrics.failed, error returned
+//! ```
+//!
+//! Checkpoints are flushed to the [`CheckpointStore`] every `flush_every`
+//! projected events and on [`Checkpointed::flush`]. Positions not yet
+//! flushed when the service stops are replayed on the next start, so the
+//! inner adapter must still tolerate seeing an event twice.
+
+use async_trait::async_trait;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::checkpoint::CheckpointStore;
+use super::metrics::AdapterMetrics;
+use crate::jetstream::StoredEvent;
+use crate::projection::{ProjectionAdapter, ProjectionError};
+
+/// Default number of projected events between checkpoint flushes
+pub const DEFAULT_FLUSH_EVERY: usize = 100;
+
+/// Projection adapter with checkpointing, health tracking and metrics
+pub struct Checkpointed<A> {
+    inner: A,
+    store: Arc<dyn CheckpointStore>,
+    metrics: AdapterMetrics,
+    positions: HashMap<Uuid, u64>,
+    dirty: HashSet<Uuid>,
+    flush_every: usize,
+}
+
+impl<A> Checkpointed<A> {
+    /// Wrap `inner`, keeping its checkpoints in `store`
+    pub fn new(inner: A, store: Arc<dyn CheckpointStore>) -> Self {
+        Self {
+            inner,
+            store,
+            metrics: AdapterMetrics::new(),
+            positions: HashMap::new(),
+            dirty: HashSet::new(),
+            flush_every: DEFAULT_FLUSH_EVERY,
+        }
+    }
+
+    /// Flush checkpoints every `events` projected events (at least 1)
+    pub fn with_flush_every(mut self, events: usize) -> Self {
+        self.flush_every = events.max(1);
+        self
+    }
+
+    /// Report into existing metrics instead of fresh ones
+    pub fn with_metrics(mut self, metrics: AdapterMetrics) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// Metrics of this adapter
+    pub fn metrics(&self) -> AdapterMetrics {
+        self.metrics.clone()
+    }
+
+    /// The wrapped adapter
+    pub fn inner(&self) -> &A {
+        &self.inner
+    }
+}
+
+impl<A, E> Checkpointed<A>
+where
+    A: ProjectionAdapter<Event = StoredEvent<E>, Error = ProjectionError>,
+    E: Send + Sync,
+{
+    /// Last sequence applied for `aggregate_id`, loading it on first use
+    async fn checkpoint(&mut self, aggregate_id: Uuid) -> Result<u64, ProjectionError> {
+        if let Some(sequence) = self.positions.get(&aggregate_id) {
+            return Ok(*sequence);
+        }
+
+        let sequence = self
+            .store
+            .load(self.inner.name(), aggregate_id)
+            .await?
+            .unwrap_or(0);
+        self.positions.insert(aggregate_id, sequence);
+        Ok(sequence)
+    }
+
+    /// Write positions reached since the last flush
+    pub async fn flush(&mut self) -> Result<(), ProjectionError> {
+        if self.dirty.is_empty() {
+            return Ok(());
+        }
+
+        let positions: Vec<(Uuid, u64)> = self
+            .dirty
+            .iter()
+            .map(|id| (*id, self.positions[id]))
+            .collect();
+        self.store.save(self.inner.name(), &positions).await?;
+        self.dirty.clear();
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<A, E> ProjectionAdapter for Checkpointed<A>
+where
+    A: ProjectionAdapter<Event = StoredEvent<E>, Error = ProjectionError>,
+    E: Send + Sync + 'static,
+{
+    type Event = StoredEvent<E>;
+    type Error = ProjectionError;
+
+    async fn project(&mut self, event: Self::Event) -> Result<(), Self::Error> {
+        let aggregate_id = event.aggregate_id;
+        let sequence = event.sequence;
+        if sequence <= self.checkpoint(aggregate_id).await? {
+            self.metrics.record_skipped();
+            return Ok(());
+        }
+
+        let event_at = event.timestamp;
+        if let Err(e) = self.inner.project(event).await {
+            self.metrics.record_failed(&e);
+            return Err(e);
+        }
+
+        self.metrics.record_projected(event_at);
+        self.positions.insert(aggregate_id, sequence);
+        self.dirty.insert(aggregate_id);
+        if self.dirty.len() >= self.flush_every {
+            self.flush().await?;
+        }
+        Ok(())
+    }
+
+    async fn initialize(&mut self) -> Result<(), Self::Error> {
+        self.inner.initialize().await
+    }
+
+    async fn health_check(&self) -> Result<(), Self::Error> {
+        let result = self.inner.health_check().await;
+        if let Err(e) = &result {
+            self.metrics.record_health_failure(e);
+        }
+        result
+    }
+
+    async fn reset(&mut self) -> Result<(), Self::Error> {
+        self.inner.reset().await?;
+        self.store.clear(self.inner.name()).await?;
+        self.positions.clear();
+        self.dirty.clear();
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter_sdk::checkpoint::MemoryCheckpointStore;
+    use crate::test_harness::{self, RecordingAdapter};
+
+    #[tokio::test]
+    async fn test_restart_skips_checkpointed_events() {
+        // Arrange
+        let store: Arc<dyn CheckpointStore> = Arc::new(MemoryCheckpointStore::new());
+        let resource_id = Uuid::now_v7();
+        let history = test_harness::resource_history(resource_id, "web-01");
+        let mut first = Checkpointed::new(RecordingAdapter::new("zabbix"), store.clone());
+        for event in history.clone() {
+            first.project(event).await.unwrap();
+        }
+        first.flush().await.unwrap();
+
+        // Act - a restarted service replays the stream from the start
+        let mut restarted = Checkpointed::new(RecordingAdapter::new("zabbix"), store.clone());
+        for event in history.clone() {
+            restarted.project(event).await.unwrap();
+        }
+
+        // Assert
+        assert_eq!(first.inner().events().len(), history.len());
+        assert!(restarted.inner().events().is_empty());
+        let metrics = restarted.metrics().snapshot();
+        assert_eq!(metrics.skipped, history.len() as u64);
+        assert_eq!(metrics.projected, 0);
+        assert_eq!(
+            store.load("zabbix", resource_id).await.unwrap(),
+            Some(history.len() as u64)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failures_are_counted_and_not_checkpointed() {
+        let store: Arc<dyn CheckpointStore> = Arc::new(MemoryCheckpointStore::new());
+        let resource_id = Uuid::now_v7();
+        let history = test_harness::resource_history(resource_id, "web-01");
+        let mut adapter =
+            Checkpointed::new(RecordingAdapter::new("zabbix").failing(), store.clone())
+                .with_flush_every(1);
+
+        assert!(adapter.project(history[0].clone()).await.is_err());
+        assert!(adapter.health_check().await.is_err());
+
+        let metrics = adapter.metrics().snapshot();
+        assert_eq!(metrics.failed, 1);
+        assert_eq!(metrics.health_failures, 1);
+        assert!(metrics.last_error.is_some());
+        assert_eq!(store.load("zabbix", resource_id).await.unwrap(), None);
+    }
+}
@@ -0,0 +1,115 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Adapter Metrics
+//!
+//! Counters every integration reports the same way. [`AdapterMetrics`] is
+//! cheap to clone and shared between the projection and whatever exposes
+//! it (a status endpoint, a periodic log line); [`AdapterMetrics::snapshot`]
+//! reads all counters at once.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, Default)]
+struct Counters {
+    projected: AtomicU64,
+    skipped: AtomicU64,
+    failed: AtomicU64,
+    health_failures: AtomicU64,
+    last_event_at: Mutex<Option<DateTime<Utc>>>,
+    last_error: Mutex<Option<String>>,
+}
+
+/// Shared counters of one adapter
+#[derive(Debug, Clone, Default)]
+pub struct AdapterMetrics {
+    counters: Arc<Counters>,
+}
+
+impl AdapterMetrics {
+    /// Zeroed counters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// An event was written to the target
+    pub fn record_projected(&self, event_at: DateTime<Utc>) {
+        self.counters.projected.fetch_add(1, Ordering::Relaxed);
+        *self
+            .counters
+            .last_event_at
+            .lock()
+            .expect("metrics lock poisoned") = Some(event_at);
+    }
+
+    /// An event was at or behind its checkpoint
+    pub fn record_skipped(&self) {
+        self.counters.skipped.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// The target rejected an event
+    pub fn record_failed(&self, error: impl ToString) {
+        self.counters.failed.fetch_add(1, Ordering::Relaxed);
+        self.set_last_error(error.to_string());
+    }
+
+    /// A health check failed
+    pub fn record_health_failure(&self, error: impl ToString) {
+        self.counters
+            .health_failures
+            .fetch_add(1, Ordering::Relaxed);
+        self.set_last_error(error.to_string());
+    }
+
+    fn set_last_error(&self, error: String) {
+        *self
+            .counters
+            .last_error
+            .lock()
+            .expect("metrics lock poisoned") = Some(error);
+    }
+
+    /// Current values
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        MetricsSnapshot {
+            projected: self.counters.projected.load(Ordering::Relaxed),
+            skipped: self.counters.skipped.load(Ordering::Relaxed),
+            failed: self.counters.failed.load(Ordering::Relaxed),
+            health_failures: self.counters.health_failures.load(Ordering::Relaxed),
+            last_event_at: *self
+                .counters
+                .last_event_at
+                .lock()
+                .expect("metrics lock poisoned"),
+            last_error: self
+                .counters
+                .last_error
+                .lock()
+                .expect("metrics lock poisoned")
+                .clone(),
+        }
+    }
+}
+
+/// Counters of an adapter at one point in time
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MetricsSnapshot {
+    /// Events written to the target
+    pub projected: u64,
+
+    /// Events skipped as already applied
+    pub skipped: u64,
+
+    /// Events the target rejected
+    pub failed: u64,
+
+    /// Failed health checks
+    pub health_failures: u64,
+
+    /// Timestamp of the last event written
+    pub last_event_at: Option<DateTime<Utc>>,
+
+    /// Most recent projection or health error
+    pub last_error: Option<String>,
+}
@@ -0,0 +1,51 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Integration SDK
+//!
+//! Building blocks for third-party integrations (monitoring systems such as
+//! Zabbix or Observium, lab tools such as EVE-NG) so that each one only
+//! writes the calls to its target's API.
+//!
+//! # Writing an integration
+//!
+//! 1. Implement [`ProjectionAdapter`](crate::projection::ProjectionAdapter)
+//!    over `StoredEvent<InfrastructureEvent>` with `ProjectionError` as the
+//!    error type. `name()` identifies the integration in checkpoints and
+//!    logs; keep it stable.
+//! 2. Wrap it in [`Checkpointed`] with a [`CheckpointStore`]
+//!    ([`KvCheckpointStore`] in services, [`MemoryCheckpointStore`] in
+//!    tests). Events at or behind an aggregate's checkpoint are skipped, and
+//!    projected, skipped and failed events and failed health checks are
+//!    counted in [`AdapterMetrics`].
+//! 3. Register the wrapper with the
+//!    [`ProjectionManager`](crate::projection::manager::ProjectionManager).
+//! 4. Test it with the `test-harness` feature's event builders, recording
+//!    adapter and `assert_idempotent` (see `cim_infrastructure::test_harness`).
+//!
+//! Integrations that feed inventory *in* rather than project it out should
+//! submit a [`Manifest`](crate::service::manifest::Manifest) instead: the
+//! manifest applier already journals each item, so an interrupted import
+//! resumes where it stopped.
+//!
+//! # Scaffolding
+//!
+//! `cargo run --bin adapter-scaffold -- <name> [out-dir]` renders a new
+//! crate with all of the above wired in (see [`scaffold`]).
+//!
+//! # Modules
+//!
+//! - [`checkpoint`] - Per-aggregate positions in memory or JetStream KV
+//! - [`checkpointed`] - Adapter wrapper adding checkpoints, health and metrics
+//! - [`metrics`] - Shared counters and snapshots
+//! - [`scaffold`] - New integration crate templates
+
+pub mod checkpoint;
+pub mod checkpointed;
+pub mod metrics;
+pub mod scaffold;
+
+pub use checkpoint::{
+    CheckpointStore, KvCheckpointStore, MemoryCheckpointStore, CHECKPOINT_BUCKET,
+};
+pub use checkpointed::{Checkpointed, DEFAULT_FLUSH_EVERY};
+pub use metrics::{AdapterMetrics, MetricsSnapshot};
+pub use scaffold::{ScaffoldError, ScaffoldFile};
@@ -0,0 +1,356 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Integration Scaffolding
+//!
+//! Renders a new integration crate with the SDK already wired in: a
+//! projection adapter with TODOs where the target's API calls go, a service
+//! binary that checks the target's health on start and runs the projection
+//! through the
+//! [`ProjectionManager`](crate::projection::manager::ProjectionManager) with
+//! KV checkpoints and periodic metrics logging, and tests built on the
+//! `test-harness` feature.
+//!
+//! ```text
+//! cim-adapter-<name>/
+//! ├── Cargo.toml
+//! ├── README.md
+//! ├── src/lib.rs        <Name>Projection
+//! ├── src/main.rs       service: NATS → Checkpointed<<Name>Projection>
+//! └── tests/projection.rs
+//! ```
+//!
+//! Used by the `adapter-scaffold` binary:
+//!
+//! ```text
+//! cargo run --bin adapter-scaffold -- zabbix [out-dir]
+//! ```
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Scaffolding errors
+#[derive(Debug, thiserror::Error)]
+pub enum ScaffoldError {
+    /// Name is not lowercase letters, digits and `-`, starting with a letter
+    #[error("Invalid integration name '{0}': use lowercase letters, digits and '-'")]
+    InvalidName(String),
+
+    /// Refusing to overwrite a file
+    #[error("{0} already exists")]
+    Exists(PathBuf),
+
+    /// Writing a file failed
+    #[error("Failed to write {path}: {source}")]
+    Io {
+        /// File being written
+        path: PathBuf,
+        /// Underlying error
+        source: std::io::Error,
+    },
+}
+
+/// One rendered file, relative to the crate root
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScaffoldFile {
+    /// Path within the new crate
+    pub path: PathBuf,
+
+    /// File contents
+    pub contents: String,
+}
+
+/// Render the crate for integration `name` (e.g. `zabbix`, `eve-ng`)
+pub fn render(name: &str) -> Result<Vec<ScaffoldFile>, ScaffoldError> {
+    let valid = name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        && !name.ends_with('-');
+    if !valid {
+        return Err(ScaffoldError::InvalidName(name.to_string()));
+    }
+
+    let type_name: String = name
+        .split('-')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect();
+    let fill = |template: &str| {
+        template
+            .replace("{{name}}", name)
+            .replace("{{crate_name}}", &name.replace('-', "_"))
+            .replace("{{type_name}}", &type_name)
+    };
+
+    Ok([
+        ("Cargo.toml", CARGO_TOML),
+        ("README.md", README),
+        ("src/lib.rs", LIB_RS),
+        ("src/main.rs", MAIN_RS),
+        ("tests/projection.rs", TESTS_RS),
+    ]
+    .into_iter()
+    .map(|(path, template)| ScaffoldFile {
+        path: PathBuf::from(path),
+        contents: fill(template),
+    })
+    .collect())
+}
+
+/// Write `files` under `dir`, refusing to overwrite anything
+pub fn write(dir: &Path, files: &[ScaffoldFile]) -> Result<(), ScaffoldError> {
+    if let Some(existing) = files
+        .iter()
+        .map(|file| dir.join(&file.path))
+        .find(|path| path.exists())
+    {
+        return Err(ScaffoldError::Exists(existing));
+    }
+
+    for file in files {
+        let path = dir.join(&file.path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|source| ScaffoldError::Io {
+                path: parent.to_path_buf(),
+                source,
+            })?;
+        }
+        fs::write(&path, &file.contents).map_err(|source| ScaffoldError::Io { path, source })?;
+    }
+    Ok(())
+}
+
+const CARGO_TOML: &str = r#"[package]
+name = "cim-adapter-{{name}}"
+version = "0.1.0"
+edition = "2021"
+description = "{{type_name}} integration for CIM infrastructure events"
+
+[dependencies]
+cim-infrastructure = { git = "https://github.com/thecowboyai/cim-infrastructure" }
+async-nats = "0.33"
+async-trait = "0.1"
+anyhow = "1.0"
+tokio = { version = "1.40", features = ["full"] }
+tracing = "0.1"
+tracing-subscriber = { version = "0.3", features = ["env-filter"] }
+uuid = { version = "1.11", features = ["v7"] }
+
+[dev-dependencies]
+cim-infrastructure = { git = "https://github.com/thecowboyai/cim-infrastructure", features = ["test-harness"] }
+"#;
+
+const README: &str = r#"# cim-adapter-{{name}}
+
+Projects CIM infrastructure events into {{type_name}}.
+
+- `src/lib.rs` - `{{type_name}}Projection`; fill in the TODOs with API calls
+- `src/main.rs` - service running the projection with checkpoints and
+  metrics, after checking the target's health
+- `tests/projection.rs` - replay and idempotency tests
+
+Run with:
+
+```text
+NATS_URL=localhost:4222 cargo run
+```
+
+Checkpoints are kept in the `projection_checkpoints` KV bucket under
+`{{name}}.<aggregate_id>`, so a restart skips events already written.
+"#;
+
+const LIB_RS: &str = r#"//! {{type_name}} integration
+//!
+//! Projects infrastructure events into {{type_name}}. Run it wrapped in
+//! `Checkpointed` (see `main.rs`) so restarts skip events already applied.
+
+use async_trait::async_trait;
+use cim_infrastructure::events::compute_resource::ComputeResourceEvent;
+use cim_infrastructure::events::InfrastructureEvent;
+use cim_infrastructure::jetstream::StoredEvent;
+use cim_infrastructure::projection::{ProjectionAdapter, ProjectionError};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Projection of infrastructure events into {{type_name}}
+#[derive(Debug, Default)]
+pub struct {{type_name}}Projection {
+    /// Hostnames of the resources written so far
+    hosts: HashMap<Uuid, String>,
+}
+
+impl {{type_name}}Projection {
+    /// New projection
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Hostnames of the resources written so far
+    pub fn hosts(&self) -> &HashMap<Uuid, String> {
+        &self.hosts
+    }
+}
+
+#[async_trait]
+impl ProjectionAdapter for {{type_name}}Projection {
+    type Event = StoredEvent<InfrastructureEvent>;
+    type Error = ProjectionError;
+
+    async fn project(&mut self, event: Self::Event) -> Result<(), Self::Error> {
+        match &event.data {
+            InfrastructureEvent::ComputeResource(ComputeResourceEvent::ResourceRegistered(e)) => {
+                // TODO: create or update the host in {{type_name}}
+                self.hosts.insert(e.aggregate_id, e.hostname.to_string());
+            }
+            InfrastructureEvent::ComputeResource(ComputeResourceEvent::StatusChanged(_)) => {
+                // TODO: enable or disable monitoring of the host
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    async fn initialize(&mut self) -> Result<(), Self::Error> {
+        // TODO: verify credentials, create host groups or templates
+        Ok(())
+    }
+
+    async fn health_check(&self) -> Result<(), Self::Error> {
+        // TODO: call a cheap {{type_name}} endpoint and map failures to
+        // ProjectionError::TargetUnavailable
+        Ok(())
+    }
+
+    async fn reset(&mut self) -> Result<(), Self::Error> {
+        // TODO: remove the hosts this integration created
+        self.hosts.clear();
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "{{name}}"
+    }
+}
+"#;
+
+const MAIN_RS: &str = r#"//! {{type_name}} integration service
+//!
+//! Environment: `NATS_URL` (default `localhost:4222`), `NATS_STREAM`
+//! (default `INFRASTRUCTURE_EVENTS`).
+
+use anyhow::Result;
+use async_nats::jetstream;
+use cim_infrastructure::adapter_sdk::{Checkpointed, KvCheckpointStore};
+use cim_infrastructure::projection::manager::ProjectionManager;
+use cim_infrastructure::projection::ProjectionAdapter;
+use cim_adapter_{{crate_name}}::{{type_name}}Projection;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{info, warn};
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+        .init();
+
+    let nats_url = std::env::var("NATS_URL").unwrap_or_else(|_| "localhost:4222".to_string());
+    let stream =
+        std::env::var("NATS_STREAM").unwrap_or_else(|_| "INFRASTRUCTURE_EVENTS".to_string());
+
+    let client = async_nats::connect(&nats_url).await?;
+    let jetstream = jetstream::new(client);
+    let checkpoints = Arc::new(KvCheckpointStore::open(jetstream.clone()).await?);
+
+    let mut projection = Checkpointed::new({{type_name}}Projection::new(), checkpoints);
+    projection.initialize().await?;
+    projection.health_check().await?;
+    let metrics = projection.metrics();
+
+    let mut manager = ProjectionManager::new(jetstream, stream);
+    manager.register(projection);
+    manager.start_all().await?;
+    info!("{{name}} integration running");
+
+    let mut interval = tokio::time::interval(Duration::from_secs(60));
+    loop {
+        interval.tick().await;
+        let snapshot = metrics.snapshot();
+        if let Some(error) = &snapshot.last_error {
+            warn!("{{name}} last error: {}", error);
+        }
+        info!("{{name}} metrics: {:?}", snapshot);
+    }
+}
+"#;
+
+const TESTS_RS: &str = r#"use cim_adapter_{{crate_name}}::{{type_name}}Projection;
+use cim_infrastructure::adapter_sdk::{Checkpointed, MemoryCheckpointStore};
+use cim_infrastructure::test_harness;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn test_replay_is_idempotent() {
+    let resource_id = Uuid::now_v7();
+    let mut projection = {{type_name}}Projection::new();
+
+    test_harness::assert_idempotent(
+        &mut projection,
+        test_harness::resource_history(resource_id, "web-01"),
+        |p| p.hosts().clone(),
+    )
+    .await;
+
+    assert_eq!(projection.hosts()[&resource_id], "web-01");
+}
+
+#[tokio::test]
+async fn test_checkpoints_skip_applied_events() {
+    let history = test_harness::resource_history(Uuid::now_v7(), "web-01");
+    let mut projection = Checkpointed::new(
+        {{type_name}}Projection::new(),
+        Arc::new(MemoryCheckpointStore::new()),
+    );
+
+    test_harness::project_all(&mut projection, history.clone()).await.unwrap();
+    test_harness::project_all(&mut projection, history).await.unwrap();
+
+    let metrics = projection.metrics().snapshot();
+    assert_eq!(metrics.projected, 2);
+    assert_eq!(metrics.skipped, 2);
+}
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_fills_names() {
+        // Arrange / Act
+        let files = render("eve-ng").unwrap();
+
+        // Assert
+        let lib = files
+            .iter()
+            .find(|file| file.path == Path::new("src/lib.rs"))
+            .unwrap();
+        assert!(lib.contents.contains("pub struct EveNgProjection"));
+        assert!(lib.contents.contains("\"eve-ng\""));
+        assert!(files.iter().all(|file| !file.contents.contains("{{")));
+        assert!(matches!(
+            render("Zabbix"),
+            Err(ScaffoldError::InvalidName(_))
+        ));
+        assert!(matches!(
+            render("zabbix-"),
+            Err(ScaffoldError::InvalidName(_))
+        ));
+    }
+}
@@ -18,6 +18,7 @@
 //!
 //! ## Relationships
 //! - `(ComputeResource)-[:HAS_INTERFACE]->(Interface)`
+//! - `(ComputeResource)-[:HOSTS]->(ComputeResource)` (hypervisor to VM or container)
 //! - `(Interface)-[:CONNECTED_TO]->(Network)`
 //! - `(Interface)-[:ROUTES_TO]->(Interface)` (for physical connections)
 //! - `(ComputeResource)-[:RUNS]->(Software)`
@@ -33,12 +34,22 @@
 //!
 //! F(ComputeRegistered) = CREATE (r:ComputeResource {...})
 //! F(NetworkDefined) = CREATE (n:Network {...})
-//! F(ConnectionEstablished) = CREATE (i1)-[:ROUTES_TO]->(i2)
+//! F(ConnectionEstablished) = MERGE (i1)-[:ROUTES_TO {connection_id}]->(i2)
+//! F(ConnectionRemoved) = DELETE the ROUTES_TO edge of that connection
+//! F(GuestAttached) = MERGE (host)-[:HOSTS]->(guest)
+//! F(GuestDetached) = DELETE the HOSTS edge of that pair
 //! ```
 //!
+//! Blast radius, single point of failure, policy coverage and topology
+//! diff queries over the graph live in [`queries`].
+//!
+//! Every write is an `UNWIND $rows` statement, so a batch of N events of
+//! one kind costs one round trip instead of N.
+//!
 //! # Example
 //!
 //! ```rust,no_run
+//! use cim_infrastructure::adapters::neo4j::{Neo4jBatchConfig, Neo4jConfig};
 //! use cim_infrastructure::adapters::Neo4jProjectionAdapter;
 //! use cim_infrastructure::projection::ProjectionAdapter;
 //!
@@ -49,25 +60,32 @@
 //!         username: "neo4j".to_string(),
 //!         password: "password".to_string(),
 //!         database: None,
+//!         batch: Neo4jBatchConfig::default(),
 //!     };
 //!
-//!     let mut projection = Neo4jProjectionAdapter::new(config).await?;
+//!     let mut projection = Neo4jProjectionAdapter::new(config).await?
+//!         .with_batching(Neo4jBatchConfig::rebuild());
 //!     projection.initialize().await?;
 //!
 //!     // Project events...
+//!     projection.flush().await?;
 //!
 //!     Ok(())
 //! }
 //! ```
 
+pub mod queries;
+
 use async_trait::async_trait;
-use neo4rs::{Graph, Query};
+use neo4rs::{BoltList, BoltMap, BoltNull, BoltString, BoltType, Graph, Query};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tracing::{debug, info, warn};
 use uuid::Uuid;
 
 use crate::projection::{ProjectionAdapter, ProjectionError};
+pub use queries::InfrastructureQueries;
 
 /// Configuration for Neo4j connection
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -83,6 +101,10 @@ pub struct Neo4jConfig {
 
     /// Optional database name (uses default if None)
     pub database: Option<String>,
+
+    /// Batching of projection writes
+    #[serde(default)]
+    pub batch: Neo4jBatchConfig,
 }
 
 impl Default for Neo4jConfig {
@@ -92,10 +114,67 @@ impl Default for Neo4jConfig {
             username: "neo4j".to_string(),
             password: "password".to_string(),
             database: None,
+            batch: Neo4jBatchConfig::default(),
         }
     }
 }
 
+/// Batching of projection writes into UNWIND statements
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Neo4jBatchConfig {
+    /// Events buffered before a flush (1 = write every event immediately)
+    #[serde(default = "default_batch_size")]
+    pub batch_size: usize,
+
+    /// Longest a buffered event waits before the next event flushes it
+    #[serde(default = "default_flush_interval_ms")]
+    pub flush_interval_ms: u64,
+}
+
+fn default_batch_size() -> usize {
+    1
+}
+
+fn default_flush_interval_ms() -> u64 {
+    1000
+}
+
+impl Default for Neo4jBatchConfig {
+    fn default() -> Self {
+        Self {
+            batch_size: default_batch_size(),
+            flush_interval_ms: default_flush_interval_ms(),
+        }
+    }
+}
+
+impl Neo4jBatchConfig {
+    /// Batching suited to a full rebuild from sequence zero
+    pub fn rebuild() -> Self {
+        Self {
+            batch_size: 1000,
+            flush_interval_ms: 5000,
+        }
+    }
+
+    /// Set the number of events buffered before a flush
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Set the longest a buffered event waits
+    pub fn with_flush_interval(mut self, interval: Duration) -> Self {
+        self.flush_interval_ms = interval.as_millis() as u64;
+        self
+    }
+
+    /// Flush interval as a duration
+    pub fn flush_interval(&self) -> Duration {
+        Duration::from_millis(self.flush_interval_ms)
+    }
+}
+
 /// Infrastructure event type for projection
 ///
 /// This is a simplified event envelope for demonstration.
@@ -108,10 +187,259 @@ pub struct InfrastructureEvent {
     pub data: serde_json::Value,
 }
 
+/// Cypher for each kind of write, applied to a `$rows` list with UNWIND
+const UPSERT_COMPUTE: &str = r#"
+UNWIND $rows AS row
+MERGE (r:ComputeResource {id: row.id})
+SET r.hostname = row.hostname,
+    r.resource_type = row.resource_type,
+    r.updated_at = timestamp()
+"#;
+
+const UPSERT_NETWORK: &str = r#"
+UNWIND $rows AS row
+MERGE (n:Network {id: row.id})
+SET n.name = row.name,
+    n.cidr = coalesce(row.cidr, n.cidr),
+    n.updated_at = timestamp()
+"#;
+
+const UPSERT_CONNECTION: &str = r#"
+UNWIND $rows AS row
+MATCH (i1:Interface {id: row.from_id})
+MATCH (i2:Interface {id: row.to_id})
+MERGE (i1)-[c:ROUTES_TO {connection_id: row.connection_id}]->(i2)
+ON CREATE SET c.established_at = timestamp()
+SET c.label = row.label
+"#;
+
+const LABEL_CONNECTION: &str = r#"
+UNWIND $rows AS row
+MATCH ()-[c:ROUTES_TO {connection_id: row.connection_id}]->()
+SET c.label = row.label
+"#;
+
+const REMOVE_CONNECTION: &str = r#"
+UNWIND $rows AS row
+MATCH ()-[c:ROUTES_TO {connection_id: row.connection_id}]->()
+DELETE c
+"#;
+
+const HOST_GUEST: &str = r#"
+UNWIND $rows AS row
+MATCH (h:ComputeResource {id: row.host_id})
+MATCH (g:ComputeResource {id: row.guest_id})
+MERGE (h)-[:HOSTS]->(g)
+"#;
+
+const UNHOST_GUEST: &str = r#"
+UNWIND $rows AS row
+MATCH (:ComputeResource {id: row.host_id})-[h:HOSTS]->(:ComputeResource {id: row.guest_id})
+DELETE h
+"#;
+
+/// Build one UNWIND row from string fields; `None` becomes null
+fn row(fields: &[(&str, Option<&str>)]) -> BoltType {
+    let mut map = BoltMap::new();
+    for (key, value) in fields {
+        let value = match value {
+            Some(value) => BoltType::from(*value),
+            None => BoltType::Null(BoltNull),
+        };
+        map.put(BoltString::from(*key), value);
+    }
+    BoltType::Map(map)
+}
+
+/// Writes buffered between flushes, grouped by statement
+///
+/// Statements run in dependency order: nodes, then edges, then edge
+/// labels, then edge removals. Within a statement rows keep event order,
+/// so a later upsert of the same node wins. Reordering across kinds is
+/// safe because a removed connection is never re-established. A guest can
+/// be attached again after a detach, so placements keep only the latest
+/// event per (host, guest) pair.
+#[derive(Debug, Default)]
+struct PendingWrites {
+    compute: Vec<BoltType>,
+    networks: Vec<BoltType>,
+    connections: Vec<BoltType>,
+    labels: Vec<BoltType>,
+    removals: Vec<BoltType>,
+    hosted: Vec<(String, String)>,
+    unhosted: Vec<(String, String)>,
+    events: usize,
+    oldest: Option<Instant>,
+}
+
+impl PendingWrites {
+    /// Buffer the write for `event`; unknown event types are skipped
+    fn push(&mut self, event: &InfrastructureEvent) -> Result<(), ProjectionError> {
+        let data = &event.data;
+        let connection_id = event.aggregate_id.to_string();
+
+        match event.event_type.as_str() {
+            "ComputeRegistered" | "compute.registered" => {
+                let id = data["id"].as_str().ok_or_else(|| {
+                    ProjectionError::InvalidEvent(
+                        "Missing 'id' field in ComputeRegistered event".to_string(),
+                    )
+                })?;
+                self.compute.push(row(&[
+                    ("id", Some(id)),
+                    (
+                        "hostname",
+                        Some(data["hostname"].as_str().unwrap_or("unknown")),
+                    ),
+                    (
+                        "resource_type",
+                        Some(data["resource_type"].as_str().unwrap_or("unknown")),
+                    ),
+                ]));
+            }
+            "NetworkDefined" | "network.defined" => {
+                let id = data["id"].as_str().ok_or_else(|| {
+                    ProjectionError::InvalidEvent(
+                        "Missing 'id' field in NetworkDefined event".to_string(),
+                    )
+                })?;
+                self.networks.push(row(&[
+                    ("id", Some(id)),
+                    ("name", Some(data["name"].as_str().unwrap_or("unknown"))),
+                    ("cidr", data["cidr"].as_str()),
+                ]));
+            }
+            // Accepts the `PhysicalConnection` payload (`a_end`/`b_end`
+            // endpoints) as well as the older flat `from_interface`/
+            // `to_interface` form. The relationship is keyed by connection
+            // ID so redelivery and later label/removal events address the
+            // same edge.
+            "ConnectionEstablished" | "connection.established" => {
+                let from_interface = data["a_end"]["interface_id"]
+                    .as_str()
+                    .or_else(|| data["from_interface"].as_str())
+                    .ok_or_else(|| {
+                        ProjectionError::InvalidEvent(
+                            "Missing 'a_end.interface_id' in ConnectionEstablished event"
+                                .to_string(),
+                        )
+                    })?;
+                let to_interface = data["b_end"]["interface_id"]
+                    .as_str()
+                    .or_else(|| data["to_interface"].as_str())
+                    .ok_or_else(|| {
+                        ProjectionError::InvalidEvent(
+                            "Missing 'b_end.interface_id' in ConnectionEstablished event"
+                                .to_string(),
+                        )
+                    })?;
+                self.connections.push(row(&[
+                    ("from_id", Some(from_interface)),
+                    ("to_id", Some(to_interface)),
+                    ("connection_id", Some(connection_id.as_str())),
+                    ("label", Some(data["label"].as_str().unwrap_or_default())),
+                ]));
+            }
+            "ConnectionLabeled" | "connection.labeled" => {
+                let label = data["label"].as_str().ok_or_else(|| {
+                    ProjectionError::InvalidEvent(
+                        "Missing 'label' in ConnectionLabeled event".to_string(),
+                    )
+                })?;
+                self.labels.push(row(&[
+                    ("connection_id", Some(connection_id.as_str())),
+                    ("label", Some(label)),
+                ]));
+            }
+            "ConnectionRemoved" | "connection.removed" => {
+                self.removals
+                    .push(row(&[("connection_id", Some(connection_id.as_str()))]));
+            }
+            // The host is the emitting aggregate unless `host_id` says otherwise
+            "GuestAttached" | "guest.attached" | "GuestDetached" | "guest.detached" => {
+                let guest_id = data["guest_id"].as_str().ok_or_else(|| {
+                    ProjectionError::InvalidEvent(format!(
+                        "Missing 'guest_id' in {} event",
+                        event.event_type
+                    ))
+                })?;
+                let host_id = data["host_id"]
+                    .as_str()
+                    .map(str::to_string)
+                    .unwrap_or_else(|| event.aggregate_id.to_string());
+                let pair = (host_id, guest_id.to_string());
+
+                self.hosted.retain(|pending| *pending != pair);
+                self.unhosted.retain(|pending| *pending != pair);
+                if matches!(event.event_type.as_str(), "GuestAttached" | "guest.attached") {
+                    self.hosted.push(pair);
+                } else {
+                    self.unhosted.push(pair);
+                }
+            }
+            unknown => {
+                warn!("Unknown event type: {}", unknown);
+                // Don't fail on unknown events - allows for graceful evolution
+                return Ok(());
+            }
+        }
+
+        self.events += 1;
+        self.oldest.get_or_insert_with(Instant::now);
+        Ok(())
+    }
+
+    /// Number of buffered events
+    fn len(&self) -> usize {
+        self.events
+    }
+
+    /// True when the batch is full or its oldest write has waited too long
+    fn is_due(&self, batch: &Neo4jBatchConfig) -> bool {
+        self.events >= batch.batch_size.max(1)
+            || self
+                .oldest
+                .is_some_and(|oldest| oldest.elapsed() >= batch.flush_interval())
+    }
+
+    /// Non-empty statements in execution order, with their rows
+    fn statements(&self) -> Vec<(&'static str, Vec<BoltType>)> {
+        let placements = |pairs: &[(String, String)]| -> Vec<BoltType> {
+            pairs
+                .iter()
+                .map(|(host, guest)| {
+                    row(&[("host_id", Some(host.as_str())), ("guest_id", Some(guest.as_str()))])
+                })
+                .collect()
+        };
+
+        [
+            (UPSERT_COMPUTE, self.compute.clone()),
+            (UPSERT_NETWORK, self.networks.clone()),
+            (UPSERT_CONNECTION, self.connections.clone()),
+            (HOST_GUEST, placements(&self.hosted)),
+            (LABEL_CONNECTION, self.labels.clone()),
+            (REMOVE_CONNECTION, self.removals.clone()),
+            (UNHOST_GUEST, placements(&self.unhosted)),
+        ]
+        .into_iter()
+        .filter(|(_, rows)| !rows.is_empty())
+        .collect()
+    }
+}
+
 /// Neo4j projection adapter implementing the Functor F: Events → Neo4jGraph
+///
+/// Writes are buffered and sent as one UNWIND statement per kind of write
+/// once [`Neo4jBatchConfig::batch_size`] events are pending or the oldest
+/// has waited [`Neo4jBatchConfig::flush_interval_ms`]. The interval is
+/// checked when events arrive, so call [`flush`](Self::flush) when a replay
+/// ends or the stream goes quiet. With the default batch size of 1 every
+/// event is written before `project` returns.
 pub struct Neo4jProjectionAdapter {
     graph: Arc<Graph>,
     config: Neo4jConfig,
+    pending: PendingWrites,
 }
 
 impl Neo4jProjectionAdapter {
@@ -131,114 +459,48 @@ impl Neo4jProjectionAdapter {
         Ok(Self {
             graph: Arc::new(graph),
             config,
+            pending: PendingWrites::default(),
         })
     }
 
-    /// Project a compute resource registered event
-    async fn project_compute_registered(
-        &self,
-        data: &serde_json::Value,
-    ) -> Result<(), ProjectionError> {
-        let id = data["id"].as_str().ok_or_else(|| {
-            ProjectionError::InvalidEvent("Missing 'id' field in ComputeRegistered event".to_string())
-        })?;
-
-        let hostname = data["hostname"].as_str().unwrap_or("unknown");
-        let resource_type = data["resource_type"].as_str().unwrap_or("unknown");
-
-        let query = Query::new(
-            r#"
-            MERGE (r:ComputeResource {id: $id})
-            SET r.hostname = $hostname,
-                r.resource_type = $resource_type,
-                r.updated_at = timestamp()
-            RETURN r
-            "#.to_string(),
-        )
-        .param("id", id)
-        .param("hostname", hostname)
-        .param("resource_type", resource_type);
-
-        self.graph
-            .run(query)
-            .await
-            .map_err(|e| ProjectionError::DatabaseError(e.to_string()))?;
-
-        debug!("Projected ComputeRegistered for {}", id);
-        Ok(())
+    /// Set the batching used for writes, e.g. a large batch for a rebuild
+    pub fn with_batching(mut self, batch: Neo4jBatchConfig) -> Self {
+        self.config.batch = batch;
+        self
     }
 
-    /// Project a network defined event
-    async fn project_network_defined(
-        &self,
-        data: &serde_json::Value,
-    ) -> Result<(), ProjectionError> {
-        let id = data["id"].as_str().ok_or_else(|| {
-            ProjectionError::InvalidEvent("Missing 'id' field in NetworkDefined event".to_string())
-        })?;
-
-        let name = data["name"].as_str().unwrap_or("unknown");
-        let cidr = data["cidr"].as_str();
-
-        let mut query = Query::new(
-            r#"
-            MERGE (n:Network {id: $id})
-            SET n.name = $name,
-                n.updated_at = timestamp()
-            "#.to_string(),
-        )
-        .param("id", id)
-        .param("name", name);
-
-        if let Some(cidr_value) = cidr {
-            query = query.param("cidr", cidr_value);
-        }
-
-        self.graph
-            .run(query)
-            .await
-            .map_err(|e| ProjectionError::DatabaseError(e.to_string()))?;
+    /// Typed topology queries over the projected graph
+    ///
+    /// Buffered events are not visible until flushed.
+    pub fn queries(&self) -> InfrastructureQueries {
+        InfrastructureQueries::new(Arc::clone(&self.graph))
+    }
 
-        debug!("Projected NetworkDefined for {}", id);
-        Ok(())
+    /// Number of events buffered but not yet written
+    pub fn pending_events(&self) -> usize {
+        self.pending.len()
     }
 
-    /// Project a connection established event
-    async fn project_connection_established(
-        &self,
-        data: &serde_json::Value,
-    ) -> Result<(), ProjectionError> {
-        let from_interface = data["from_interface"].as_str().ok_or_else(|| {
-            ProjectionError::InvalidEvent(
-                "Missing 'from_interface' in ConnectionEstablished event".to_string(),
-            )
-        })?;
-
-        let to_interface = data["to_interface"].as_str().ok_or_else(|| {
-            ProjectionError::InvalidEvent(
-                "Missing 'to_interface' in ConnectionEstablished event".to_string(),
-            )
-        })?;
-
-        let query = Query::new(
-            r#"
-            MATCH (i1:Interface {id: $from_id})
-            MATCH (i2:Interface {id: $to_id})
-            MERGE (i1)-[:ROUTES_TO {established_at: timestamp()}]->(i2)
-            "#.to_string(),
-        )
-        .param("from_id", from_interface)
-        .param("to_id", to_interface);
+    /// Write every buffered event
+    ///
+    /// On failure the buffer is kept, so the next flush retries it; every
+    /// statement is an idempotent MERGE, SET or DELETE.
+    pub async fn flush(&mut self) -> Result<(), ProjectionError> {
+        if self.pending.len() == 0 {
+            return Ok(());
+        }
 
-        self.graph
-            .run(query)
-            .await
-            .map_err(|e| ProjectionError::DatabaseError(e.to_string()))?;
+        for (cypher, rows) in self.pending.statements() {
+            let query = Query::new(cypher.to_string())
+                .param("rows", BoltType::List(BoltList::from(rows)));
+            self.graph
+                .run(query)
+                .await
+                .map_err(|e| ProjectionError::DatabaseError(e.to_string()))?;
+        }
 
-        debug!(
-            "Projected ConnectionEstablished: {} -> {}",
-            from_interface, to_interface
-        );
+        debug!("Flushed {} events to Neo4j", self.pending.len());
+        self.pending = PendingWrites::default();
         Ok(())
     }
 }
@@ -251,21 +513,9 @@ impl ProjectionAdapter for Neo4jProjectionAdapter {
     async fn project(&mut self, event: Self::Event) -> Result<(), Self::Error> {
         debug!("Projecting event: {} ({})", event.event_type, event.event_id);
 
-        // Route events to specific projection handlers based on event type
-        match event.event_type.as_str() {
-            "ComputeRegistered" | "compute.registered" => {
-                self.project_compute_registered(&event.data).await?
-            }
-            "NetworkDefined" | "network.defined" => {
-                self.project_network_defined(&event.data).await?
-            }
-            "ConnectionEstablished" | "connection.established" => {
-                self.project_connection_established(&event.data).await?
-            }
-            unknown => {
-                warn!("Unknown event type: {}", unknown);
-                // Don't fail on unknown events - allows for graceful evolution
-            }
+        self.pending.push(&event)?;
+        if self.pending.is_due(&self.config.batch) {
+            self.flush().await?;
         }
 
         Ok(())
@@ -295,6 +545,7 @@ impl ProjectionAdapter for Neo4jProjectionAdapter {
             "CREATE INDEX compute_hostname IF NOT EXISTS FOR (r:ComputeResource) ON (r.hostname)",
             "CREATE INDEX network_name IF NOT EXISTS FOR (n:Network) ON (n.name)",
             "CREATE INDEX network_cidr IF NOT EXISTS FOR (n:Network) ON (n.cidr)",
+            "CREATE INDEX routes_to_connection IF NOT EXISTS FOR ()-[c:ROUTES_TO]-() ON (c.connection_id)",
         ];
 
         for index in indexes {
@@ -327,6 +578,8 @@ impl ProjectionAdapter for Neo4jProjectionAdapter {
     async fn reset(&mut self) -> Result<(), Self::Error> {
         warn!("Resetting Neo4j projection - ALL DATA WILL BE DELETED");
 
+        self.pending = PendingWrites::default();
+
         // Delete all nodes and relationships
         self.graph
             .run(Query::new("MATCH (n) DETACH DELETE n".to_string()))
@@ -369,4 +622,89 @@ mod tests {
         assert_eq!(event.event_type, "ComputeRegistered");
         assert!(event.data["hostname"].is_string());
     }
+
+    fn event(event_type: &str, data: serde_json::Value) -> InfrastructureEvent {
+        InfrastructureEvent {
+            event_id: Uuid::now_v7(),
+            aggregate_id: Uuid::now_v7(),
+            event_type: event_type.to_string(),
+            data,
+        }
+    }
+
+    #[test]
+    fn test_pending_writes_group_by_statement_in_dependency_order() {
+        let mut pending = PendingWrites::default();
+        let batch = Neo4jBatchConfig::default().with_batch_size(4);
+
+        pending
+            .push(&event("ConnectionRemoved", serde_json::json!({})))
+            .unwrap();
+        pending
+            .push(&event("ComputeRegistered", serde_json::json!({"id": "a"})))
+            .unwrap();
+        pending
+            .push(&event("SomethingNew", serde_json::json!({})))
+            .unwrap();
+        pending
+            .push(&event("compute.registered", serde_json::json!({"id": "b"})))
+            .unwrap();
+        assert!(!pending.is_due(&batch));
+        pending
+            .push(&event(
+                "ConnectionEstablished",
+                serde_json::json!({
+                    "a_end": {"interface_id": "eth0"},
+                    "b_end": {"interface_id": "eth1"}
+                }),
+            ))
+            .unwrap();
+
+        let statements = pending.statements();
+        assert_eq!(pending.len(), 4);
+        assert!(pending.is_due(&batch));
+        assert_eq!(statements.len(), 3);
+        assert_eq!(statements[0].0, UPSERT_COMPUTE);
+        assert_eq!(statements[0].1.len(), 2);
+        assert_eq!(statements[1].0, UPSERT_CONNECTION);
+        assert_eq!(statements[2].0, REMOVE_CONNECTION);
+    }
+
+    #[test]
+    fn test_invalid_event_is_not_buffered() {
+        let mut pending = PendingWrites::default();
+
+        let result = pending.push(&event("NetworkDefined", serde_json::json!({"name": "lan"})));
+
+        assert!(matches!(result, Err(ProjectionError::InvalidEvent(_))));
+        assert_eq!(pending.len(), 0);
+        assert!(!pending.is_due(&Neo4jBatchConfig::default()));
+    }
+
+    #[test]
+    fn test_latest_placement_of_a_guest_wins() {
+        // Arrange: attach, detach and re-attach the same guest in one batch
+        let mut pending = PendingWrites::default();
+        let attached = event("GuestAttached", serde_json::json!({"guest_id": "vm-1"}));
+        let host_id = attached.aggregate_id;
+        let detached = InfrastructureEvent {
+            event_type: "GuestDetached".to_string(),
+            ..attached.clone()
+        };
+
+        // Act
+        pending.push(&attached).unwrap();
+        pending.push(&detached).unwrap();
+        pending.push(&attached).unwrap();
+
+        // Assert
+        let statements = pending.statements();
+        assert_eq!(pending.len(), 3);
+        assert_eq!(statements.len(), 1);
+        assert_eq!(statements[0].0, HOST_GUEST);
+        assert_eq!(pending.hosted, vec![(host_id.to_string(), "vm-1".to_string())]);
+        assert!(pending
+            .push(&event("guest.detached", serde_json::json!({})))
+            .is_err());
+    }
 }
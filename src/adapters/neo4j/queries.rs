@@ -0,0 +1,421 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+
+//! Topology Queries over the Neo4j Projection
+//!
+//! Higher-level questions answered from the graph written by
+//! [`Neo4jProjectionAdapter`](super::Neo4jProjectionAdapter). Every query
+//! returns typed results rather than Bolt rows.
+//!
+//! Resources are linked through their interfaces:
+//!
+//! ```text
+//! (ComputeResource)-[:HAS_INTERFACE]->(Interface)-[:ROUTES_TO]-(Interface)<-[:HAS_INTERFACE]-(ComputeResource)
+//! ```
+//!
+//! so one device hop is three relationships. `ROUTES_TO` is followed in
+//! either direction, as a cable carries traffic both ways.
+//!
+//! - [`InfrastructureQueries::blast_radius`] - what depends on this switch
+//! - [`InfrastructureQueries::single_points_of_failure`] - resources that
+//!   others are connected to exclusively
+//! - [`InfrastructureQueries::policy_coverage`] - resources without any
+//!   enforced policy
+//! - [`InfrastructureQueries::changes_since`] - topology written since a
+//!   point in time
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! let queries = projection.queries();
+//!
+//! let impact = queries.blast_radius("switch-core-01", 3).await?;
+//! for resource in &impact.impacted {
+//!     println!("{} ({} hops)", resource.hostname, resource.hops);
+//! }
+//! ```
+
+use neo4rs::{Graph, Query};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::projection::ProjectionError;
+
+/// Relationships per device hop
+const RELATIONSHIPS_PER_HOP: u32 = 3;
+
+/// Upper bound on hops a blast radius query may traverse
+pub const MAX_BLAST_RADIUS_HOPS: u32 = 10;
+
+const SINGLE_POINTS_OF_FAILURE: &str = r#"
+MATCH (dependent:ComputeResource)-[:HAS_INTERFACE]->(:Interface)-[:ROUTES_TO]-(:Interface)<-[:HAS_INTERFACE]-(peer:ComputeResource)
+WHERE dependent <> peer
+WITH dependent, collect(DISTINCT peer) AS peers
+WHERE size(peers) = 1
+WITH peers[0] AS spof, collect(dependent.id) AS dependents
+RETURN spof.id AS id, coalesce(spof.hostname, 'unknown') AS hostname, dependents
+ORDER BY size(dependents) DESC, id
+"#;
+
+const POLICY_COVERAGE: &str = r#"
+MATCH (r:ComputeResource)
+OPTIONAL MATCH (r)-[:ENFORCES]->(direct:Policy)
+OPTIONAL MATCH (r)-[:HAS_INTERFACE]->(:Interface)-[:CONNECTED_TO]->(:Network)-[:APPLIES]->(inherited:Policy)
+WITH r, collect(DISTINCT direct.id) + collect(DISTINCT inherited.id) AS policies
+RETURN r.id AS id, coalesce(r.hostname, 'unknown') AS hostname, policies
+ORDER BY id
+"#;
+
+const RESOURCES_CHANGED_SINCE: &str = r#"
+MATCH (r:ComputeResource)
+WHERE r.updated_at >= $since
+RETURN r.id AS id, coalesce(r.hostname, 'unknown') AS hostname, r.updated_at AS updated_at
+ORDER BY updated_at
+"#;
+
+const NETWORKS_CHANGED_SINCE: &str = r#"
+MATCH (n:Network)
+WHERE n.updated_at >= $since
+RETURN n.id AS id, coalesce(n.name, 'unknown') AS name, n.cidr AS cidr, n.updated_at AS updated_at
+ORDER BY updated_at
+"#;
+
+const CONNECTIONS_ESTABLISHED_SINCE: &str = r#"
+MATCH (a:Interface)-[c:ROUTES_TO]->(b:Interface)
+WHERE c.established_at >= $since
+RETURN c.connection_id AS connection_id, a.id AS a_interface, b.id AS b_interface,
+       coalesce(c.label, '') AS label, c.established_at AS established_at
+ORDER BY established_at
+"#;
+
+/// Cypher for a blast radius bounded by `max_hops` device hops
+///
+/// Variable-length bounds cannot be parameters, so the bound is clamped to
+/// [`MAX_BLAST_RADIUS_HOPS`] and written into the statement.
+fn blast_radius_cypher(max_hops: u32) -> String {
+    let relationships = max_hops.clamp(1, MAX_BLAST_RADIUS_HOPS) * RELATIONSHIPS_PER_HOP;
+    format!(
+        r#"
+MATCH (root:ComputeResource {{id: $id}})
+MATCH path = (root)-[:HAS_INTERFACE|ROUTES_TO*1..{relationships}]-(r:ComputeResource)
+WHERE r <> root
+WITH r, min(length(path)) AS distance
+RETURN r.id AS id, coalesce(r.hostname, 'unknown') AS hostname,
+       (distance + {round}) / {per_hop} AS hops
+ORDER BY hops, id
+"#,
+        relationships = relationships,
+        round = RELATIONSHIPS_PER_HOP - 1,
+        per_hop = RELATIONSHIPS_PER_HOP,
+    )
+}
+
+/// A resource affected by the loss of another
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImpactedResource {
+    /// Resource ID
+    pub id: String,
+
+    /// Hostname
+    pub hostname: String,
+
+    /// Device hops from the failed resource (1 = directly cabled)
+    pub hops: u32,
+}
+
+/// Resources reachable through a resource, and so affected when it fails
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlastRadius {
+    /// Resource the analysis starts from
+    pub root: String,
+
+    /// Hop bound the analysis used
+    pub max_hops: u32,
+
+    /// Affected resources, nearest first
+    pub impacted: Vec<ImpactedResource>,
+}
+
+impl BlastRadius {
+    /// Resources cabled directly to the root
+    pub fn direct(&self) -> impl Iterator<Item = &ImpactedResource> {
+        self.impacted.iter().filter(|r| r.hops == 1)
+    }
+}
+
+/// A resource that other resources are connected to exclusively
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SinglePointOfFailure {
+    /// Resource ID
+    pub id: String,
+
+    /// Hostname
+    pub hostname: String,
+
+    /// Resources whose only connection is to this one
+    pub dependents: Vec<String>,
+}
+
+/// Policies applying to one resource
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourcePolicies {
+    /// Resource ID
+    pub id: String,
+
+    /// Hostname
+    pub hostname: String,
+
+    /// Policies enforced on the resource or applied by its networks
+    pub policies: Vec<String>,
+}
+
+/// Which resources have at least one policy applied
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PolicyCoverage {
+    /// Resources with at least one policy
+    pub covered: Vec<ResourcePolicies>,
+
+    /// Resources without any policy
+    pub uncovered: Vec<ResourcePolicies>,
+}
+
+impl PolicyCoverage {
+    /// Split per-resource policies into covered and uncovered
+    pub fn from_resources(resources: impl IntoIterator<Item = ResourcePolicies>) -> Self {
+        let mut coverage = Self::default();
+        for mut resource in resources {
+            resource.policies.sort();
+            resource.policies.dedup();
+            if resource.policies.is_empty() {
+                coverage.uncovered.push(resource);
+            } else {
+                coverage.covered.push(resource);
+            }
+        }
+        coverage
+    }
+
+    /// Total resources considered
+    pub fn total(&self) -> usize {
+        self.covered.len() + self.uncovered.len()
+    }
+
+    /// Fraction of resources covered (1.0 when there are no resources)
+    pub fn ratio(&self) -> f64 {
+        match self.total() {
+            0 => 1.0,
+            total => self.covered.len() as f64 / total as f64,
+        }
+    }
+
+    /// Number of resources each policy applies to, most used first
+    pub fn by_policy(&self) -> Vec<(String, usize)> {
+        let mut counts = std::collections::BTreeMap::<&str, usize>::new();
+        for policy in self.covered.iter().flat_map(|r| &r.policies) {
+            *counts.entry(policy).or_default() += 1;
+        }
+        let mut counts: Vec<_> = counts
+            .into_iter()
+            .map(|(policy, count)| (policy.to_string(), count))
+            .collect();
+        counts.sort_by(|a, b| b.1.cmp(&a.1));
+        counts
+    }
+}
+
+/// A compute resource written since the diff point
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChangedResource {
+    /// Resource ID
+    pub id: String,
+
+    /// Hostname
+    pub hostname: String,
+
+    /// Write time in milliseconds since the epoch
+    pub updated_at: i64,
+}
+
+/// A network written since the diff point
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChangedNetwork {
+    /// Network ID
+    pub id: String,
+
+    /// Network name
+    pub name: String,
+
+    /// CIDR, when known
+    pub cidr: Option<String>,
+
+    /// Write time in milliseconds since the epoch
+    pub updated_at: i64,
+}
+
+/// A connection established since the diff point
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EstablishedConnection {
+    /// Connection aggregate ID
+    pub connection_id: String,
+
+    /// A-end interface
+    pub a_interface: String,
+
+    /// B-end interface
+    pub b_interface: String,
+
+    /// Cable label
+    pub label: String,
+
+    /// Write time in milliseconds since the epoch
+    pub established_at: i64,
+}
+
+/// Topology written to the graph since a point in time
+///
+/// Removed connections are deleted from the graph, so removals are not
+/// part of the diff; read them from the event stream.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TopologyChanges {
+    /// Compute resources registered or updated
+    pub resources: Vec<ChangedResource>,
+
+    /// Networks defined or updated
+    pub networks: Vec<ChangedNetwork>,
+
+    /// Connections established
+    pub connections: Vec<EstablishedConnection>,
+}
+
+impl TopologyChanges {
+    /// True when nothing changed
+    pub fn is_empty(&self) -> bool {
+        self.resources.is_empty() && self.networks.is_empty() && self.connections.is_empty()
+    }
+}
+
+/// Typed queries over the Neo4j infrastructure graph
+#[derive(Clone)]
+pub struct InfrastructureQueries {
+    graph: Arc<Graph>,
+}
+
+impl InfrastructureQueries {
+    /// Query the given graph
+    pub fn new(graph: Arc<Graph>) -> Self {
+        Self { graph }
+    }
+
+    /// Resources reachable through `resource_id` within `max_hops` device
+    /// hops, nearest first
+    ///
+    /// `max_hops` is clamped to 1..=[`MAX_BLAST_RADIUS_HOPS`]. An unknown
+    /// resource has an empty blast radius.
+    pub async fn blast_radius(
+        &self,
+        resource_id: &str,
+        max_hops: u32,
+    ) -> Result<BlastRadius, ProjectionError> {
+        let query = Query::new(blast_radius_cypher(max_hops)).param("id", resource_id);
+        Ok(BlastRadius {
+            root: resource_id.to_string(),
+            max_hops: max_hops.clamp(1, MAX_BLAST_RADIUS_HOPS),
+            impacted: self.fetch(query).await?,
+        })
+    }
+
+    /// Resources that at least one other resource is connected to
+    /// exclusively, those with the most dependents first
+    ///
+    /// This finds single-homed dependents, not every articulation point of
+    /// the graph: a switch whose neighbours are all dual-homed is not
+    /// reported even if losing it would partition the network.
+    pub async fn single_points_of_failure(
+        &self,
+    ) -> Result<Vec<SinglePointOfFailure>, ProjectionError> {
+        self.fetch(Query::new(SINGLE_POINTS_OF_FAILURE.to_string()))
+            .await
+    }
+
+    /// Policies applying to each resource, directly or through the
+    /// networks its interfaces connect to
+    pub async fn policy_coverage(&self) -> Result<PolicyCoverage, ProjectionError> {
+        let resources: Vec<ResourcePolicies> =
+            self.fetch(Query::new(POLICY_COVERAGE.to_string())).await?;
+        Ok(PolicyCoverage::from_resources(resources))
+    }
+
+    /// Nodes and connections written at or after `since_ms` (milliseconds
+    /// since the epoch)
+    pub async fn changes_since(&self, since_ms: i64) -> Result<TopologyChanges, ProjectionError> {
+        let query = |cypher: &str| Query::new(cypher.to_string()).param("since", since_ms);
+        Ok(TopologyChanges {
+            resources: self.fetch(query(RESOURCES_CHANGED_SINCE)).await?,
+            networks: self.fetch(query(NETWORKS_CHANGED_SINCE)).await?,
+            connections: self.fetch(query(CONNECTIONS_ESTABLISHED_SINCE)).await?,
+        })
+    }
+
+    /// Run a query and deserialize every row
+    async fn fetch<T>(&self, query: Query) -> Result<Vec<T>, ProjectionError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let mut stream = self
+            .graph
+            .execute(query)
+            .await
+            .map_err(|e| ProjectionError::DatabaseError(e.to_string()))?;
+
+        let mut rows = Vec::new();
+        while let Some(row) = stream
+            .next()
+            .await
+            .map_err(|e| ProjectionError::DatabaseError(e.to_string()))?
+        {
+            rows.push(
+                row.to::<T>()
+                    .map_err(|e| ProjectionError::DatabaseError(e.to_string()))?,
+            );
+        }
+        Ok(rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resource(id: &str, policies: &[&str]) -> ResourcePolicies {
+        ResourcePolicies {
+            id: id.to_string(),
+            hostname: format!("{}.example.com", id),
+            policies: policies.iter().map(|p| p.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_blast_radius_bound_is_clamped() {
+        assert!(blast_radius_cypher(0).contains("*1..3]"));
+        assert!(blast_radius_cypher(2).contains("*1..6]"));
+        assert!(blast_radius_cypher(1000).contains("*1..30]"));
+    }
+
+    #[test]
+    fn test_policy_coverage_splits_and_counts() {
+        let coverage = PolicyCoverage::from_resources(vec![
+            resource("web01", &["pci", "baseline", "pci"]),
+            resource("web02", &["baseline"]),
+            resource("lab01", &[]),
+        ]);
+
+        assert_eq!(coverage.total(), 3);
+        assert_eq!(coverage.uncovered, vec![resource("lab01", &[])]);
+        assert_eq!(coverage.covered[0].policies, vec!["baseline", "pci"]);
+        assert!((coverage.ratio() - 2.0 / 3.0).abs() < f64::EPSILON);
+        assert_eq!(
+            coverage.by_policy(),
+            vec![("baseline".to_string(), 2), ("pci".to_string(), 1)]
+        );
+        assert_eq!(PolicyCoverage::default().ratio(), 1.0);
+    }
+}
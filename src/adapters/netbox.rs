@@ -18,8 +18,29 @@
 //! F(ComputeRegistered) = POST /api/dcim/devices/
 //! F(NetworkDefined) = POST /api/ipam/prefixes/
 //! F(ConnectionEstablished) = POST /api/dcim/cables/
+//!   (also `ResourcesConnected`; an existing cable between the same two
+//!   interfaces is adopted instead)
+//! F(ConnectionLabeled) = PATCH /api/dcim/cables/{id}/
+//! F(ConnectionRemoved) = DELETE /api/dcim/cables/{id}/
+//! F(BondFormed) = POST /api/dcim/interfaces/ (type "lag")
+//!               + PATCH /api/dcim/interfaces/{member}/ (lag = bond)
+//! F(BondDissolved) = DELETE /api/dcim/interfaces/{id}/
+//! F(ResourceUpdated) = PATCH /api/dcim/devices/{id}/ (fields in the event)
+//! F(StatusChanged) = PATCH /api/dcim/devices/{id}/ (status)
+//! F(ResourceRemoved) = DELETE /api/dcim/devices/{id}/
+//!   (or status "offline" with `soft_delete`)
 //! ```
 //!
+//! Devices of later events are found by the `cim_aggregate_id` custom
+//! field, falling back to the `hostname` in the event. CIM statuses map to
+//! NetBox as provisioning → staged, maintenance → offline and
+//! decommissioned → decommissioning.
+//!
+//! Cables are matched to connections through the `cim_connection_id`
+//! custom field, which must exist on the cable model in NetBox. Bonds are
+//! placed on the device whose `cim_aggregate_id` custom field holds the
+//! bond's resource ID, and their members must already exist there by name.
+//!
 //! # NetBox Data Model
 //!
 //! - **Devices**: Physical servers, VMs, network equipment
@@ -42,6 +63,8 @@
 //!         base_url: "http://10.0.224.131".to_string(),
 //!         api_token: "your-token-here".to_string(),
 //!         default_site_id: Some(1),
+//!         timeout_secs: 30,
+//!         soft_delete: true,
 //!     };
 //!
 //!     let mut projection = NetBoxProjectionAdapter::new(config).await?;
@@ -78,6 +101,10 @@ pub struct NetBoxConfig {
     /// Request timeout in seconds
     #[serde(default = "default_timeout")]
     pub timeout_secs: u64,
+
+    /// Mark removed resources offline instead of deleting their devices
+    #[serde(default)]
+    pub soft_delete: bool,
 }
 
 fn default_timeout() -> u64 {
@@ -91,8 +118,41 @@ impl Default for NetBoxConfig {
             api_token: String::new(),
             default_site_id: Some(1),
             timeout_secs: 30,
+            soft_delete: false,
+        }
+    }
+}
+
+/// NetBox device status for a CIM resource status
+fn device_status(status: &str) -> Option<&'static str> {
+    match status {
+        "provisioning" => Some("staged"),
+        "active" => Some("active"),
+        "maintenance" => Some("offline"),
+        "decommissioned" => Some("decommissioning"),
+        _ => None,
+    }
+}
+
+/// Device fields to PATCH for a `ResourceUpdated` payload
+///
+/// Only fields present in the event are included, so an update never
+/// clears what another event set.
+fn device_update(data: &serde_json::Value) -> serde_json::Map<String, serde_json::Value> {
+    let mut patch = serde_json::Map::new();
+    for (field, netbox_field) in [
+        ("hostname", "name"),
+        ("asset_tag", "asset_tag"),
+        ("serial_number", "serial"),
+    ] {
+        if let Some(value) = data[field].as_str() {
+            patch.insert(netbox_field.to_string(), serde_json::json!(value));
         }
     }
+    if let Some(status) = data["status"].as_str().and_then(device_status) {
+        patch.insert("status".to_string(), serde_json::json!(status));
+    }
+    patch
 }
 
 /// NetBox device representation
@@ -129,6 +189,8 @@ pub struct NetBoxInterface {
     pub mac_address: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lag: Option<i32>,
 }
 
 /// NetBox IP address representation
@@ -161,6 +223,68 @@ pub struct NetBoxPrefix {
     pub description: Option<String>,
 }
 
+/// One end of a NetBox cable
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetBoxCableTermination {
+    /// Terminated object type (e.g. "dcim.interface")
+    pub object_type: String,
+    pub object_id: i32,
+}
+
+/// NetBox cable representation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetBoxCable {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i32>,
+    pub a_terminations: Vec<NetBoxCableTermination>,
+    pub b_terminations: Vec<NetBoxCableTermination>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_fields: Option<serde_json::Value>,
+}
+
+/// Cables already attached to the two interfaces of a new connection
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ExistingCables {
+    a_interface: i32,
+    a_cable: Option<i32>,
+    b_interface: i32,
+    b_cable: Option<i32>,
+}
+
+/// What to do about a new connection given the existing cables
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CablePlan {
+    /// Both interfaces are free
+    Create,
+
+    /// One cable already joins the two interfaces
+    Adopt(i32),
+
+    /// An interface is cabled elsewhere
+    Occupied { interface_id: i32, cable_id: i32 },
+}
+
+impl ExistingCables {
+    fn plan(&self) -> CablePlan {
+        match (self.a_cable, self.b_cable) {
+            (None, None) => CablePlan::Create,
+            (Some(a), Some(b)) if a == b => CablePlan::Adopt(a),
+            (Some(cable_id), _) => CablePlan::Occupied {
+                interface_id: self.a_interface,
+                cable_id,
+            },
+            (None, Some(cable_id)) => CablePlan::Occupied {
+                interface_id: self.b_interface,
+                cable_id,
+            },
+        }
+    }
+}
+
 /// Infrastructure event type for NetBox projection
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InfrastructureEvent {
@@ -548,6 +672,7 @@ impl NetBoxProjectionAdapter {
             mtu,
             mac_address,
             description: data["description"].as_str().map(|s| s.to_string()),
+            lag: None,
         };
 
         let url = format!("{}/api/dcim/interfaces/", self.config.base_url);
@@ -676,6 +801,593 @@ impl NetBoxProjectionAdapter {
             )))
         }
     }
+
+    /// Look up an interface ID by device ID and interface name
+    async fn interface_on_device(
+        &self,
+        device_id: i32,
+        interface_name: &str,
+    ) -> Result<Option<i32>, ProjectionError> {
+        let url = format!(
+            "{}/api/dcim/interfaces/?device_id={}&name={}",
+            self.config.base_url,
+            device_id,
+            urlencoding::encode(interface_name)
+        );
+        let response = self.client.get(&url).send().await
+            .map_err(|e| ProjectionError::DatabaseError(format!("Failed to find interface: {}", e)))?;
+
+        if !response.status().is_success() {
+            return Ok(None);
+        }
+
+        let data: serde_json::Value = response.json().await
+            .map_err(|e| ProjectionError::DatabaseError(format!("Failed to parse response: {}", e)))?;
+        Ok(data["results"][0]["id"].as_i64().map(|id| id as i32))
+    }
+
+    /// Look up the device created for a CIM compute resource
+    ///
+    /// Devices carry the resource aggregate ID in the `cim_aggregate_id`
+    /// custom field.
+    async fn device_for_resource(&self, resource_id: &str) -> Result<Option<i32>, ProjectionError> {
+        let url = format!(
+            "{}/api/dcim/devices/?cf_cim_aggregate_id={}",
+            self.config.base_url,
+            urlencoding::encode(resource_id)
+        );
+        let response = self.client.get(&url).send().await
+            .map_err(|e| ProjectionError::DatabaseError(format!("Failed to find device: {}", e)))?;
+
+        if !response.status().is_success() {
+            return Ok(None);
+        }
+
+        let data: serde_json::Value = response.json().await
+            .map_err(|e| ProjectionError::DatabaseError(format!("Failed to parse response: {}", e)))?;
+        Ok(data["results"][0]["id"].as_i64().map(|id| id as i32))
+    }
+
+    /// Resolve the device of a bond event from its `resource_id`
+    async fn bond_device(&self, data: &serde_json::Value) -> Result<i32, ProjectionError> {
+        let resource_id = data["resource_id"]
+            .as_str()
+            .ok_or_else(|| ProjectionError::InvalidEvent("Missing 'resource_id'".to_string()))?;
+
+        self.device_for_resource(resource_id).await?.ok_or_else(|| {
+            ProjectionError::InvalidEvent(format!(
+                "Device for resource {} not found in NetBox",
+                resource_id
+            ))
+        })
+    }
+
+    /// Look up the cable created for a CIM connection
+    ///
+    /// Cables carry the connection aggregate ID in the `cim_connection_id`
+    /// custom field.
+    async fn cable_for_connection(
+        &self,
+        connection_id: Uuid,
+    ) -> Result<Option<i32>, ProjectionError> {
+        let url = format!(
+            "{}/api/dcim/cables/?cf_cim_connection_id={}",
+            self.config.base_url, connection_id
+        );
+        let response = self.client.get(&url).send().await
+            .map_err(|e| ProjectionError::DatabaseError(format!("Failed to find cable: {}", e)))?;
+
+        if !response.status().is_success() {
+            return Ok(None);
+        }
+
+        let data: serde_json::Value = response.json().await
+            .map_err(|e| ProjectionError::DatabaseError(format!("Failed to parse response: {}", e)))?;
+        Ok(data["results"][0]["id"].as_i64().map(|id| id as i32))
+    }
+
+    /// Resolve one end of a connection (`a_end` / `b_end`) to an interface ID
+    ///
+    /// The device is found by the endpoint's `resource_id` (the
+    /// `cim_aggregate_id` custom field) when present, falling back to its
+    /// `device` hostname for devices created before the custom field.
+    async fn resolve_endpoint(
+        &self,
+        endpoint: &serde_json::Value,
+    ) -> Result<i32, ProjectionError> {
+        let port = endpoint["port"]
+            .as_str()
+            .ok_or_else(|| ProjectionError::InvalidEvent("Missing endpoint 'port'".to_string()))?;
+
+        let by_resource = match endpoint["resource_id"].as_str() {
+            Some(resource_id) => self.device_for_resource(resource_id).await?,
+            None => None,
+        };
+        let device_id = match by_resource {
+            Some(device_id) => device_id,
+            None => {
+                let device = endpoint["device"].as_str().ok_or_else(|| {
+                    ProjectionError::InvalidEvent("Missing endpoint 'device'".to_string())
+                })?;
+                self.device_exists(device).await?.ok_or_else(|| {
+                    ProjectionError::InvalidEvent(format!(
+                        "Device '{}' not found in NetBox",
+                        device
+                    ))
+                })?
+            }
+        };
+
+        self.interface_on_device(device_id, port).await?.ok_or_else(|| {
+            ProjectionError::InvalidEvent(format!(
+                "Interface '{}' on device {} not found in NetBox",
+                port, device_id
+            ))
+        })
+    }
+
+    /// Look up the cable currently attached to an interface
+    async fn cable_on_interface(&self, interface_id: i32) -> Result<Option<i32>, ProjectionError> {
+        let url = format!("{}/api/dcim/interfaces/{}/", self.config.base_url, interface_id);
+        let response = self.client.get(&url).send().await
+            .map_err(|e| ProjectionError::DatabaseError(format!("Failed to find interface: {}", e)))?;
+
+        if !response.status().is_success() {
+            return Ok(None);
+        }
+
+        let data: serde_json::Value = response.json().await
+            .map_err(|e| ProjectionError::DatabaseError(format!("Failed to parse response: {}", e)))?;
+        Ok(data["cable"]["id"].as_i64().map(|id| id as i32))
+    }
+
+    /// Project a connection established event as a cable
+    ///
+    /// Idempotent: a cable already tagged with the connection is left
+    /// alone, and a cable already joining exactly these two interfaces
+    /// (for example one patched by hand in NetBox) is adopted by tagging it.
+    /// A cable on either interface leading elsewhere is reported instead of
+    /// letting NetBox reject the new one.
+    async fn project_connection_established(
+        &self,
+        connection_id: Uuid,
+        data: &serde_json::Value,
+    ) -> Result<(), ProjectionError> {
+        // Check idempotency - cable already created for this connection?
+        if let Some(cable_id) = self.cable_for_connection(connection_id).await? {
+            info!("Cable for connection {} already exists (id: {}), skipping",
+                  connection_id, cable_id);
+            return Ok(());
+        }
+
+        let a_interface = self.resolve_endpoint(&data["a_end"]).await?;
+        let b_interface = self.resolve_endpoint(&data["b_end"]).await?;
+
+        let existing = ExistingCables {
+            a_interface,
+            a_cable: self.cable_on_interface(a_interface).await?,
+            b_interface,
+            b_cable: self.cable_on_interface(b_interface).await?,
+        };
+        match existing.plan() {
+            CablePlan::Create => {}
+            CablePlan::Adopt(cable_id) => {
+                return self.adopt_cable(cable_id, connection_id, data).await;
+            }
+            CablePlan::Occupied { interface_id, cable_id } => {
+                return Err(ProjectionError::InvalidEvent(format!(
+                    "Interface {} already has cable {} to another interface",
+                    interface_id, cable_id
+                )));
+            }
+        }
+
+        let termination = |object_id| vec![NetBoxCableTermination {
+            object_type: "dcim.interface".to_string(),
+            object_id,
+        }];
+        let cable = NetBoxCable {
+            id: None,
+            a_terminations: termination(a_interface),
+            b_terminations: termination(b_interface),
+            status: Some("connected".to_string()),
+            label: data["label"].as_str().map(|s| s.to_string()),
+            custom_fields: Some(serde_json::json!({
+                "cim_connection_id": connection_id,
+            })),
+        };
+
+        let url = format!("{}/api/dcim/cables/", self.config.base_url);
+        let response = self
+            .client
+            .post(&url)
+            .json(&cable)
+            .send()
+            .await
+            .map_err(|e| ProjectionError::DatabaseError(format!("NetBox API error: {}", e)))?;
+
+        if response.status() == StatusCode::CREATED || response.status() == StatusCode::OK {
+            info!("Projected ConnectionEstablished to NetBox: interface {} <-> {}",
+                  a_interface, b_interface);
+            Ok(())
+        } else {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_else(|_| "".to_string());
+            Err(ProjectionError::DatabaseError(format!(
+                "NetBox API returned {}: {}",
+                status, body
+            )))
+        }
+    }
+
+    /// Tag an existing cable with the connection it represents
+    async fn adopt_cable(
+        &self,
+        cable_id: i32,
+        connection_id: Uuid,
+        data: &serde_json::Value,
+    ) -> Result<(), ProjectionError> {
+        let mut patch = serde_json::json!({
+            "status": "connected",
+            "custom_fields": { "cim_connection_id": connection_id },
+        });
+        if let Some(label) = data["label"].as_str() {
+            patch["label"] = serde_json::json!(label);
+        }
+
+        let url = format!("{}/api/dcim/cables/{}/", self.config.base_url, cable_id);
+        let response = self
+            .client
+            .patch(&url)
+            .json(&patch)
+            .send()
+            .await
+            .map_err(|e| ProjectionError::DatabaseError(format!("NetBox API error: {}", e)))?;
+
+        if response.status().is_success() {
+            info!("Adopted NetBox cable {} for connection {}", cable_id, connection_id);
+            Ok(())
+        } else {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_else(|_| "".to_string());
+            Err(ProjectionError::DatabaseError(format!(
+                "NetBox API returned {}: {}",
+                status, body
+            )))
+        }
+    }
+
+    /// Project a connection labeled event onto its cable
+    async fn project_connection_labeled(
+        &self,
+        connection_id: Uuid,
+        data: &serde_json::Value,
+    ) -> Result<(), ProjectionError> {
+        let label = data["label"]
+            .as_str()
+            .ok_or_else(|| ProjectionError::InvalidEvent("Missing 'label'".to_string()))?;
+
+        let Some(cable_id) = self.cable_for_connection(connection_id).await? else {
+            warn!("No cable for connection {}, label not projected", connection_id);
+            return Ok(());
+        };
+
+        let url = format!("{}/api/dcim/cables/{}/", self.config.base_url, cable_id);
+        let response = self
+            .client
+            .patch(&url)
+            .json(&serde_json::json!({ "label": label }))
+            .send()
+            .await
+            .map_err(|e| ProjectionError::DatabaseError(format!("NetBox API error: {}", e)))?;
+
+        if response.status().is_success() {
+            info!("Projected ConnectionLabeled to NetBox: cable {} = {}", cable_id, label);
+            Ok(())
+        } else {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_else(|_| "".to_string());
+            Err(ProjectionError::DatabaseError(format!(
+                "NetBox API returned {}: {}",
+                status, body
+            )))
+        }
+    }
+
+    /// Project a bond formed event as a LAG interface with its members
+    async fn project_bond_formed(&self, data: &serde_json::Value) -> Result<(), ProjectionError> {
+        let name = data["name"]
+            .as_str()
+            .ok_or_else(|| ProjectionError::InvalidEvent("Missing 'name'".to_string()))?;
+        let members = data["members"]
+            .as_array()
+            .ok_or_else(|| ProjectionError::InvalidEvent("Missing 'members'".to_string()))?;
+        let device_id = self.bond_device(data).await?;
+
+        // Resolve every member first so a missing one leaves nothing behind
+        let mut member_ids = Vec::with_capacity(members.len());
+        for member in members {
+            let member_name = member["name"]
+                .as_str()
+                .ok_or_else(|| ProjectionError::InvalidEvent("Missing member 'name'".to_string()))?;
+            let member_id = self
+                .interface_on_device(device_id, member_name)
+                .await?
+                .ok_or_else(|| {
+                    ProjectionError::InvalidEvent(format!(
+                        "Member interface '{}' not found in NetBox",
+                        member_name
+                    ))
+                })?;
+            member_ids.push(member_id);
+        }
+
+        // Idempotent: reuse the LAG if an earlier attempt created it
+        let lag_id = match self.interface_on_device(device_id, name).await? {
+            Some(lag_id) => lag_id,
+            None => {
+                let lag = NetBoxInterface {
+                    id: None,
+                    device: device_id,
+                    name: name.to_string(),
+                    interface_type: "lag".to_string(),
+                    enabled: Some(true),
+                    mtu: None,
+                    mac_address: None,
+                    description: data["mode"].as_str().map(|mode| format!("CIM bond ({})", mode)),
+                    lag: None,
+                };
+                self.create_interface(&lag).await?
+            }
+        };
+
+        for member_id in member_ids {
+            let url = format!("{}/api/dcim/interfaces/{}/", self.config.base_url, member_id);
+            let response = self
+                .client
+                .patch(&url)
+                .json(&serde_json::json!({ "lag": lag_id }))
+                .send()
+                .await
+                .map_err(|e| ProjectionError::DatabaseError(format!("NetBox API error: {}", e)))?;
+
+            if !response.status().is_success() {
+                let status = response.status();
+                let body = response.text().await.unwrap_or_else(|_| "".to_string());
+                return Err(ProjectionError::DatabaseError(format!(
+                    "NetBox API returned {}: {}",
+                    status, body
+                )));
+            }
+        }
+
+        info!("Projected BondFormed to NetBox: LAG {} (id: {}) with {} members",
+              name, lag_id, members.len());
+        Ok(())
+    }
+
+    /// Create an interface and return its ID
+    async fn create_interface(&self, interface: &NetBoxInterface) -> Result<i32, ProjectionError> {
+        let url = format!("{}/api/dcim/interfaces/", self.config.base_url);
+        let response = self
+            .client
+            .post(&url)
+            .json(interface)
+            .send()
+            .await
+            .map_err(|e| ProjectionError::DatabaseError(format!("NetBox API error: {}", e)))?;
+
+        if response.status() == StatusCode::CREATED || response.status() == StatusCode::OK {
+            let created: serde_json::Value = response.json().await
+                .map_err(|e| ProjectionError::DatabaseError(format!("Failed to parse response: {}", e)))?;
+            created["id"]
+                .as_i64()
+                .map(|id| id as i32)
+                .ok_or_else(|| ProjectionError::DatabaseError("Created interface has no id".to_string()))
+        } else {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_else(|_| "".to_string());
+            Err(ProjectionError::DatabaseError(format!(
+                "NetBox API returned {}: {}",
+                status, body
+            )))
+        }
+    }
+
+    /// Project a bond dissolved event by deleting its LAG interface
+    ///
+    /// NetBox clears the `lag` of the members when the LAG is deleted.
+    async fn project_bond_dissolved(&self, data: &serde_json::Value) -> Result<(), ProjectionError> {
+        let name = data["name"]
+            .as_str()
+            .ok_or_else(|| ProjectionError::InvalidEvent("Missing 'name'".to_string()))?;
+        let device_id = self.bond_device(data).await?;
+
+        // Already gone (or never projected): nothing to do
+        let Some(lag_id) = self.interface_on_device(device_id, name).await? else {
+            return Ok(());
+        };
+
+        let url = format!("{}/api/dcim/interfaces/{}/", self.config.base_url, lag_id);
+        let response = self
+            .client
+            .delete(&url)
+            .send()
+            .await
+            .map_err(|e| ProjectionError::DatabaseError(format!("NetBox API error: {}", e)))?;
+
+        if response.status().is_success() || response.status() == StatusCode::NOT_FOUND {
+            info!("Projected BondDissolved to NetBox: LAG {} deleted", lag_id);
+            Ok(())
+        } else {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_else(|_| "".to_string());
+            Err(ProjectionError::DatabaseError(format!(
+                "NetBox API returned {}: {}",
+                status, body
+            )))
+        }
+    }
+
+    /// Find the device of a resource event
+    ///
+    /// Tries the aggregate ID, then the `hostname` in the payload.
+    async fn device_for_event(
+        &self,
+        aggregate_id: Uuid,
+        data: &serde_json::Value,
+    ) -> Result<Option<i32>, ProjectionError> {
+        if let Some(device_id) = self.device_for_resource(&aggregate_id.to_string()).await? {
+            return Ok(Some(device_id));
+        }
+        match data["hostname"].as_str() {
+            Some(hostname) => self.device_exists(hostname).await,
+            None => Ok(None),
+        }
+    }
+
+    /// PATCH a device, treating a missing device as already handled
+    async fn patch_device(
+        &self,
+        device_id: i32,
+        patch: &serde_json::Map<String, serde_json::Value>,
+    ) -> Result<(), ProjectionError> {
+        let url = format!("{}/api/dcim/devices/{}/", self.config.base_url, device_id);
+        let response = self
+            .client
+            .patch(&url)
+            .json(patch)
+            .send()
+            .await
+            .map_err(|e| ProjectionError::DatabaseError(format!("NetBox API error: {}", e)))?;
+
+        if response.status().is_success() || response.status() == StatusCode::NOT_FOUND {
+            Ok(())
+        } else {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_else(|_| "".to_string());
+            Err(ProjectionError::DatabaseError(format!(
+                "NetBox API returned {}: {}",
+                status, body
+            )))
+        }
+    }
+
+    /// Project a resource updated event onto its device
+    async fn project_resource_updated(
+        &self,
+        aggregate_id: Uuid,
+        data: &serde_json::Value,
+    ) -> Result<(), ProjectionError> {
+        let patch = device_update(data);
+        if patch.is_empty() {
+            debug!("ResourceUpdated for {} changes no NetBox fields", aggregate_id);
+            return Ok(());
+        }
+
+        let Some(device_id) = self.device_for_event(aggregate_id, data).await? else {
+            warn!("No device for resource {}, update not projected", aggregate_id);
+            return Ok(());
+        };
+
+        self.patch_device(device_id, &patch).await?;
+        info!("Projected ResourceUpdated to NetBox: device {}", device_id);
+        Ok(())
+    }
+
+    /// Project a status changed event onto its device
+    async fn project_status_changed(
+        &self,
+        aggregate_id: Uuid,
+        data: &serde_json::Value,
+    ) -> Result<(), ProjectionError> {
+        let to_status = data["to_status"]
+            .as_str()
+            .ok_or_else(|| ProjectionError::InvalidEvent("Missing 'to_status'".to_string()))?;
+        let status = device_status(to_status).ok_or_else(|| {
+            ProjectionError::InvalidEvent(format!("Unknown resource status '{}'", to_status))
+        })?;
+
+        let Some(device_id) = self.device_for_event(aggregate_id, data).await? else {
+            warn!("No device for resource {}, status not projected", aggregate_id);
+            return Ok(());
+        };
+
+        let mut patch = serde_json::Map::new();
+        patch.insert("status".to_string(), serde_json::json!(status));
+        self.patch_device(device_id, &patch).await?;
+        info!("Projected StatusChanged to NetBox: device {} = {}", device_id, status);
+        Ok(())
+    }
+
+    /// Project a resource removed event by deleting its device, or marking
+    /// it offline in soft delete mode
+    async fn project_resource_removed(
+        &self,
+        aggregate_id: Uuid,
+        data: &serde_json::Value,
+    ) -> Result<(), ProjectionError> {
+        // Already gone (or never projected): nothing to do
+        let Some(device_id) = self.device_for_event(aggregate_id, data).await? else {
+            return Ok(());
+        };
+
+        if self.config.soft_delete {
+            let mut patch = serde_json::Map::new();
+            patch.insert("status".to_string(), serde_json::json!("offline"));
+            self.patch_device(device_id, &patch).await?;
+            info!("Projected ResourceRemoved to NetBox: device {} offline", device_id);
+            return Ok(());
+        }
+
+        let url = format!("{}/api/dcim/devices/{}/", self.config.base_url, device_id);
+        let response = self
+            .client
+            .delete(&url)
+            .send()
+            .await
+            .map_err(|e| ProjectionError::DatabaseError(format!("NetBox API error: {}", e)))?;
+
+        if response.status().is_success() || response.status() == StatusCode::NOT_FOUND {
+            info!("Projected ResourceRemoved to NetBox: device {} deleted", device_id);
+            Ok(())
+        } else {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_else(|_| "".to_string());
+            Err(ProjectionError::DatabaseError(format!(
+                "NetBox API returned {}: {}",
+                status, body
+            )))
+        }
+    }
+
+    /// Project a connection removed event by deleting its cable
+    async fn project_connection_removed(&self, connection_id: Uuid) -> Result<(), ProjectionError> {
+        // Already gone (or never projected): nothing to do
+        let Some(cable_id) = self.cable_for_connection(connection_id).await? else {
+            return Ok(());
+        };
+
+        let url = format!("{}/api/dcim/cables/{}/", self.config.base_url, cable_id);
+        let response = self
+            .client
+            .delete(&url)
+            .send()
+            .await
+            .map_err(|e| ProjectionError::DatabaseError(format!("NetBox API error: {}", e)))?;
+
+        if response.status().is_success() || response.status() == StatusCode::NOT_FOUND {
+            info!("Projected ConnectionRemoved to NetBox: cable {} deleted", cable_id);
+            Ok(())
+        } else {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_else(|_| "".to_string());
+            Err(ProjectionError::DatabaseError(format!(
+                "NetBox API returned {}: {}",
+                status, body
+            )))
+        }
+    }
 }
 
 #[async_trait]
@@ -703,6 +1415,36 @@ impl ProjectionAdapter for NetBoxProjectionAdapter {
             "IPAssigned" | "ip.assigned" => {
                 self.project_ip_assigned(&event.data).await?
             }
+            "ConnectionEstablished" | "connection.established" | "ResourcesConnected"
+            | "resources.connected" => {
+                self.project_connection_established(event.aggregate_id, &event.data)
+                    .await?
+            }
+            "ConnectionLabeled" | "connection.labeled" => {
+                self.project_connection_labeled(event.aggregate_id, &event.data)
+                    .await?
+            }
+            "ConnectionRemoved" | "connection.removed" => {
+                self.project_connection_removed(event.aggregate_id).await?
+            }
+            "BondFormed" | "bond.formed" => {
+                self.project_bond_formed(&event.data).await?
+            }
+            "BondDissolved" | "bond.dissolved" => {
+                self.project_bond_dissolved(&event.data).await?
+            }
+            "ResourceUpdated" | "resource.updated" => {
+                self.project_resource_updated(event.aggregate_id, &event.data)
+                    .await?
+            }
+            "StatusChanged" | "status.changed" | "status_changed" => {
+                self.project_status_changed(event.aggregate_id, &event.data)
+                    .await?
+            }
+            "ResourceRemoved" | "resource.removed" => {
+                self.project_resource_removed(event.aggregate_id, &event.data)
+                    .await?
+            }
             unknown => {
                 warn!("Unknown event type for NetBox projection: {}", unknown);
                 // Don't fail on unknown events - allows graceful evolution
@@ -796,4 +1538,87 @@ mod tests {
         assert!(json.contains("test-server"));
         assert!(json.contains("active"));
     }
+
+    #[test]
+    fn test_netbox_cable_serialization() {
+        let cable = NetBoxCable {
+            id: None,
+            a_terminations: vec![NetBoxCableTermination {
+                object_type: "dcim.interface".to_string(),
+                object_id: 10,
+            }],
+            b_terminations: vec![NetBoxCableTermination {
+                object_type: "dcim.interface".to_string(),
+                object_id: 20,
+            }],
+            status: Some("connected".to_string()),
+            label: None,
+            custom_fields: None,
+        };
+
+        let json = serde_json::to_value(&cable).unwrap();
+        assert_eq!(json["a_terminations"][0]["object_id"], 10);
+        assert_eq!(json["b_terminations"][0]["object_type"], "dcim.interface");
+        assert!(json.get("label").is_none());
+    }
+
+    #[test]
+    fn test_device_update_includes_only_present_fields() {
+        let patch = device_update(&serde_json::json!({
+            "hostname": "web02.example.com",
+            "serial_number": "SN-1",
+            "status": "maintenance",
+        }));
+
+        assert_eq!(patch["name"], "web02.example.com");
+        assert_eq!(patch["serial"], "SN-1");
+        assert_eq!(patch["status"], "offline");
+        assert!(!patch.contains_key("asset_tag"));
+        assert!(device_update(&serde_json::json!({"unrelated": 1})).is_empty());
+        assert_eq!(device_status("decommissioned"), Some("decommissioning"));
+        assert_eq!(device_status("retired"), None);
+    }
+
+    #[test]
+    fn test_existing_cables_plan() {
+        let free = ExistingCables {
+            a_interface: 10,
+            a_cable: None,
+            b_interface: 20,
+            b_cable: None,
+        };
+
+        assert_eq!(free.plan(), CablePlan::Create);
+        assert_eq!(
+            ExistingCables { a_cable: Some(5), b_cable: Some(5), ..free }.plan(),
+            CablePlan::Adopt(5)
+        );
+        assert_eq!(
+            ExistingCables { a_cable: Some(5), b_cable: Some(6), ..free }.plan(),
+            CablePlan::Occupied { interface_id: 10, cable_id: 5 }
+        );
+        assert_eq!(
+            ExistingCables { b_cable: Some(6), ..free }.plan(),
+            CablePlan::Occupied { interface_id: 20, cable_id: 6 }
+        );
+    }
+
+    #[test]
+    fn test_netbox_lag_interface_serialization() {
+        let lag = NetBoxInterface {
+            id: None,
+            device: 7,
+            name: "bond0".to_string(),
+            interface_type: "lag".to_string(),
+            enabled: Some(true),
+            mtu: None,
+            mac_address: None,
+            description: None,
+            lag: None,
+        };
+
+        let json = serde_json::to_value(&lag).unwrap();
+        assert_eq!(json["type"], "lag");
+        assert!(json.get("lag").is_none());
+    }
 }
@@ -29,15 +29,18 @@ use cim_domain_person::PersonId;
 use cim_domain_policy::PolicyId;
 use cim_domain_spaces::ConceptId;
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::domain::{Hostname, ResourceType};
-use crate::events::ResourceStatus;
+use crate::domain::{ChangeRef, Hostname, ResourceType};
+use crate::events::{ConfigurationBackupRef, ResourceStatus};
 
 /// Command to register a new compute resource
 ///
 /// This is the initial command that creates the aggregate.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct RegisterResourceCommand {
     /// Hostname for the resource
     pub hostname: Hostname,
@@ -50,12 +53,20 @@ pub struct RegisterResourceCommand {
 
     /// Correlation ID for distributed tracing
     pub correlation_id: Uuid,
+
+    /// Change request authorizing the command
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub change_ref: Option<ChangeRef>,
 }
 
 /// Command to assign organization ownership
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct AssignOrganizationCommand {
     /// Organization to assign
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
+    #[cfg_attr(feature = "json-schema", schemars(with = "String"))]
     pub organization_id: EntityId<Organization>,
 
     /// Timestamp when command was issued
@@ -66,12 +77,20 @@ pub struct AssignOrganizationCommand {
 
     /// Optional causation ID (event that caused this command)
     pub causation_id: Option<Uuid>,
+
+    /// Change request authorizing the command
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub change_ref: Option<ChangeRef>,
 }
 
 /// Command to assign physical location
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct AssignLocationCommand {
     /// Location to assign
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
+    #[cfg_attr(feature = "json-schema", schemars(with = "String"))]
     pub location_id: EntityId<LocationMarker>,
 
     /// Timestamp when command was issued
@@ -82,12 +101,20 @@ pub struct AssignLocationCommand {
 
     /// Optional causation ID
     pub causation_id: Option<Uuid>,
+
+    /// Change request authorizing the command
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub change_ref: Option<ChangeRef>,
 }
 
 /// Command to assign owner/primary contact
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct AssignOwnerCommand {
     /// Person to assign as owner
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
+    #[cfg_attr(feature = "json-schema", schemars(with = "String"))]
     pub owner_id: PersonId,
 
     /// Timestamp when command was issued
@@ -98,12 +125,20 @@ pub struct AssignOwnerCommand {
 
     /// Optional causation ID
     pub causation_id: Option<Uuid>,
+
+    /// Change request authorizing the command
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub change_ref: Option<ChangeRef>,
 }
 
 /// Command to add a policy to the resource
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct AddPolicyCommand {
     /// Policy to add
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
+    #[cfg_attr(feature = "json-schema", schemars(with = "String"))]
     pub policy_id: PolicyId,
 
     /// Timestamp when command was issued
@@ -114,12 +149,20 @@ pub struct AddPolicyCommand {
 
     /// Optional causation ID
     pub causation_id: Option<Uuid>,
+
+    /// Change request authorizing the command
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub change_ref: Option<ChangeRef>,
 }
 
 /// Command to remove a policy from the resource
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct RemovePolicyCommand {
     /// Policy to remove
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
+    #[cfg_attr(feature = "json-schema", schemars(with = "String"))]
     pub policy_id: PolicyId,
 
     /// Timestamp when command was issued
@@ -130,12 +173,20 @@ pub struct RemovePolicyCommand {
 
     /// Optional causation ID
     pub causation_id: Option<Uuid>,
+
+    /// Change request authorizing the command
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub change_ref: Option<ChangeRef>,
 }
 
 /// Command to assign account concept for semantic classification
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct AssignAccountConceptCommand {
     /// Concept to assign
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
+    #[cfg_attr(feature = "json-schema", schemars(with = "String"))]
     pub concept_id: ConceptId,
 
     /// Timestamp when command was issued
@@ -146,10 +197,16 @@ pub struct AssignAccountConceptCommand {
 
     /// Optional causation ID
     pub causation_id: Option<Uuid>,
+
+    /// Change request authorizing the command
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub change_ref: Option<ChangeRef>,
 }
 
 /// Command to clear account concept
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct ClearAccountConceptCommand {
     /// Timestamp when command was issued
     pub timestamp: DateTime<Utc>,
@@ -159,10 +216,16 @@ pub struct ClearAccountConceptCommand {
 
     /// Optional causation ID
     pub causation_id: Option<Uuid>,
+
+    /// Change request authorizing the command
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub change_ref: Option<ChangeRef>,
 }
 
 /// Command to set hardware details
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct SetHardwareDetailsCommand {
     /// Hardware manufacturer
     pub manufacturer: Option<String>,
@@ -181,10 +244,16 @@ pub struct SetHardwareDetailsCommand {
 
     /// Optional causation ID
     pub causation_id: Option<Uuid>,
+
+    /// Change request authorizing the command
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub change_ref: Option<ChangeRef>,
 }
 
 /// Command to assign asset tag
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct AssignAssetTagCommand {
     /// Asset tag to assign
     pub asset_tag: String,
@@ -197,10 +266,16 @@ pub struct AssignAssetTagCommand {
 
     /// Optional causation ID
     pub causation_id: Option<Uuid>,
+
+    /// Change request authorizing the command
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub change_ref: Option<ChangeRef>,
 }
 
 /// Command to update custom metadata
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct UpdateMetadataCommand {
     /// Metadata key
     pub key: String,
@@ -216,10 +291,16 @@ pub struct UpdateMetadataCommand {
 
     /// Optional causation ID
     pub causation_id: Option<Uuid>,
+
+    /// Change request authorizing the command
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub change_ref: Option<ChangeRef>,
 }
 
 /// Command to change resource status
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct ChangeStatusCommand {
     /// New status
     pub to_status: ResourceStatus,
@@ -232,6 +313,302 @@ pub struct ChangeStatusCommand {
 
     /// Optional causation ID
     pub causation_id: Option<Uuid>,
+
+    /// Change request authorizing the command
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub change_ref: Option<ChangeRef>,
+}
+
+/// Command to record that a device configuration backup was taken
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct RecordConfigurationBackupCommand {
+    /// Backup location, hash and producing tool
+    pub backup: ConfigurationBackupRef,
+
+    /// Timestamp when command was issued
+    pub timestamp: DateTime<Utc>,
+
+    /// Correlation ID for distributed tracing
+    pub correlation_id: Uuid,
+
+    /// Optional causation ID
+    pub causation_id: Option<Uuid>,
+
+    /// Change request authorizing the command
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub change_ref: Option<ChangeRef>,
+}
+
+/// Command to place a guest on this host
+///
+/// Cycle checks need the hosts above this one, which the host aggregate
+/// does not know; the caller resolves them from the read model.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct AttachGuestCommand {
+    /// Guest resource aggregate
+    pub guest_id: Uuid,
+
+    /// Resource type of the guest
+    pub guest_type: ResourceType,
+
+    /// Hosts this host runs on, nearest first (empty for a physical host)
+    #[serde(default)]
+    pub host_ancestors: Vec<Uuid>,
+
+    /// Timestamp when command was issued
+    pub timestamp: DateTime<Utc>,
+
+    /// Correlation ID for distributed tracing
+    pub correlation_id: Uuid,
+
+    /// Optional causation ID
+    pub causation_id: Option<Uuid>,
+
+    /// Change request authorizing the command
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub change_ref: Option<ChangeRef>,
+}
+
+/// Command to remove a guest from this host
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct DetachGuestCommand {
+    /// Guest resource aggregate
+    pub guest_id: Uuid,
+
+    /// Timestamp when command was issued
+    pub timestamp: DateTime<Utc>,
+
+    /// Correlation ID for distributed tracing
+    pub correlation_id: Uuid,
+
+    /// Optional causation ID
+    pub causation_id: Option<Uuid>,
+
+    /// Change request authorizing the command
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub change_ref: Option<ChangeRef>,
+}
+
+/// Any command accepted by the ComputeResource aggregate
+///
+/// Serialized with a `command` tag so commands can travel over NATS:
+///
+/// ```text
+/// {"command": "assign_asset_tag", "asset_tag": "A-1001", "timestamp": ..., ...}
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum ComputeResourceCommand {
+    /// Register a new resource
+    RegisterResource(RegisterResourceCommand),
+
+    /// Assign organization ownership
+    AssignOrganization(AssignOrganizationCommand),
+
+    /// Assign physical location
+    AssignLocation(AssignLocationCommand),
+
+    /// Assign owner/primary contact
+    AssignOwner(AssignOwnerCommand),
+
+    /// Add a policy
+    AddPolicy(AddPolicyCommand),
+
+    /// Remove a policy
+    RemovePolicy(RemovePolicyCommand),
+
+    /// Assign account concept
+    AssignAccountConcept(AssignAccountConceptCommand),
+
+    /// Clear account concept
+    ClearAccountConcept(ClearAccountConceptCommand),
+
+    /// Set hardware details
+    SetHardwareDetails(SetHardwareDetailsCommand),
+
+    /// Assign asset tag
+    AssignAssetTag(AssignAssetTagCommand),
+
+    /// Update custom metadata
+    UpdateMetadata(UpdateMetadataCommand),
+
+    /// Change lifecycle status
+    ChangeStatus(ChangeStatusCommand),
+
+    /// Record a configuration backup
+    RecordConfigurationBackup(RecordConfigurationBackupCommand),
+
+    /// Place a guest on this host
+    AttachGuest(AttachGuestCommand),
+
+    /// Remove a guest from this host
+    DetachGuest(DetachGuestCommand),
+}
+
+impl ComputeResourceCommand {
+    /// Snake-case command name, as used in the `command` tag and subjects
+    pub fn command_name(&self) -> &'static str {
+        match self {
+            ComputeResourceCommand::RegisterResource(_) => "register_resource",
+            ComputeResourceCommand::AssignOrganization(_) => "assign_organization",
+            ComputeResourceCommand::AssignLocation(_) => "assign_location",
+            ComputeResourceCommand::AssignOwner(_) => "assign_owner",
+            ComputeResourceCommand::AddPolicy(_) => "add_policy",
+            ComputeResourceCommand::RemovePolicy(_) => "remove_policy",
+            ComputeResourceCommand::AssignAccountConcept(_) => "assign_account_concept",
+            ComputeResourceCommand::ClearAccountConcept(_) => "clear_account_concept",
+            ComputeResourceCommand::SetHardwareDetails(_) => "set_hardware_details",
+            ComputeResourceCommand::AssignAssetTag(_) => "assign_asset_tag",
+            ComputeResourceCommand::UpdateMetadata(_) => "update_metadata",
+            ComputeResourceCommand::ChangeStatus(_) => "change_status",
+            ComputeResourceCommand::RecordConfigurationBackup(_) => "record_configuration_backup",
+            ComputeResourceCommand::AttachGuest(_) => "attach_guest",
+            ComputeResourceCommand::DetachGuest(_) => "detach_guest",
+        }
+    }
+
+    /// Correlation ID carried by the command
+    pub fn correlation_id(&self) -> Uuid {
+        match self {
+            ComputeResourceCommand::RegisterResource(c) => c.correlation_id,
+            ComputeResourceCommand::AssignOrganization(c) => c.correlation_id,
+            ComputeResourceCommand::AssignLocation(c) => c.correlation_id,
+            ComputeResourceCommand::AssignOwner(c) => c.correlation_id,
+            ComputeResourceCommand::AddPolicy(c) => c.correlation_id,
+            ComputeResourceCommand::RemovePolicy(c) => c.correlation_id,
+            ComputeResourceCommand::AssignAccountConcept(c) => c.correlation_id,
+            ComputeResourceCommand::ClearAccountConcept(c) => c.correlation_id,
+            ComputeResourceCommand::SetHardwareDetails(c) => c.correlation_id,
+            ComputeResourceCommand::AssignAssetTag(c) => c.correlation_id,
+            ComputeResourceCommand::UpdateMetadata(c) => c.correlation_id,
+            ComputeResourceCommand::ChangeStatus(c) => c.correlation_id,
+            ComputeResourceCommand::RecordConfigurationBackup(c) => c.correlation_id,
+            ComputeResourceCommand::AttachGuest(c) => c.correlation_id,
+            ComputeResourceCommand::DetachGuest(c) => c.correlation_id,
+        }
+    }
+
+    /// Timestamp carried by the command (copied onto its event)
+    pub fn timestamp(&self) -> DateTime<Utc> {
+        match self {
+            ComputeResourceCommand::RegisterResource(c) => c.timestamp,
+            ComputeResourceCommand::AssignOrganization(c) => c.timestamp,
+            ComputeResourceCommand::AssignLocation(c) => c.timestamp,
+            ComputeResourceCommand::AssignOwner(c) => c.timestamp,
+            ComputeResourceCommand::AddPolicy(c) => c.timestamp,
+            ComputeResourceCommand::RemovePolicy(c) => c.timestamp,
+            ComputeResourceCommand::AssignAccountConcept(c) => c.timestamp,
+            ComputeResourceCommand::ClearAccountConcept(c) => c.timestamp,
+            ComputeResourceCommand::SetHardwareDetails(c) => c.timestamp,
+            ComputeResourceCommand::AssignAssetTag(c) => c.timestamp,
+            ComputeResourceCommand::UpdateMetadata(c) => c.timestamp,
+            ComputeResourceCommand::ChangeStatus(c) => c.timestamp,
+            ComputeResourceCommand::RecordConfigurationBackup(c) => c.timestamp,
+            ComputeResourceCommand::AttachGuest(c) => c.timestamp,
+            ComputeResourceCommand::DetachGuest(c) => c.timestamp,
+        }
+    }
+
+    /// Replace the correlation and causation IDs carried by the command
+    ///
+    /// Registration commands carry no causation ID; only their correlation
+    /// ID is replaced.
+    pub fn with_trace(mut self, correlation_id: Uuid, causation_id: Option<Uuid>) -> Self {
+        match &mut self {
+            ComputeResourceCommand::RegisterResource(c) => c.correlation_id = correlation_id,
+            ComputeResourceCommand::AssignOrganization(c) => {
+                c.correlation_id = correlation_id;
+                c.causation_id = causation_id;
+            }
+            ComputeResourceCommand::AssignLocation(c) => {
+                c.correlation_id = correlation_id;
+                c.causation_id = causation_id;
+            }
+            ComputeResourceCommand::AssignOwner(c) => {
+                c.correlation_id = correlation_id;
+                c.causation_id = causation_id;
+            }
+            ComputeResourceCommand::AddPolicy(c) => {
+                c.correlation_id = correlation_id;
+                c.causation_id = causation_id;
+            }
+            ComputeResourceCommand::RemovePolicy(c) => {
+                c.correlation_id = correlation_id;
+                c.causation_id = causation_id;
+            }
+            ComputeResourceCommand::AssignAccountConcept(c) => {
+                c.correlation_id = correlation_id;
+                c.causation_id = causation_id;
+            }
+            ComputeResourceCommand::ClearAccountConcept(c) => {
+                c.correlation_id = correlation_id;
+                c.causation_id = causation_id;
+            }
+            ComputeResourceCommand::SetHardwareDetails(c) => {
+                c.correlation_id = correlation_id;
+                c.causation_id = causation_id;
+            }
+            ComputeResourceCommand::AssignAssetTag(c) => {
+                c.correlation_id = correlation_id;
+                c.causation_id = causation_id;
+            }
+            ComputeResourceCommand::UpdateMetadata(c) => {
+                c.correlation_id = correlation_id;
+                c.causation_id = causation_id;
+            }
+            ComputeResourceCommand::ChangeStatus(c) => {
+                c.correlation_id = correlation_id;
+                c.causation_id = causation_id;
+            }
+            ComputeResourceCommand::RecordConfigurationBackup(c) => {
+                c.correlation_id = correlation_id;
+                c.causation_id = causation_id;
+            }
+            ComputeResourceCommand::AttachGuest(c) => {
+                c.correlation_id = correlation_id;
+                c.causation_id = causation_id;
+            }
+            ComputeResourceCommand::DetachGuest(c) => {
+                c.correlation_id = correlation_id;
+                c.causation_id = causation_id;
+            }
+        }
+        self
+    }
+
+    /// Change request the command was issued under, if any
+    pub fn change_ref(&self) -> Option<&ChangeRef> {
+        match self {
+            ComputeResourceCommand::RegisterResource(c) => c.change_ref.as_ref(),
+            ComputeResourceCommand::AssignOrganization(c) => c.change_ref.as_ref(),
+            ComputeResourceCommand::AssignLocation(c) => c.change_ref.as_ref(),
+            ComputeResourceCommand::AssignOwner(c) => c.change_ref.as_ref(),
+            ComputeResourceCommand::AddPolicy(c) => c.change_ref.as_ref(),
+            ComputeResourceCommand::RemovePolicy(c) => c.change_ref.as_ref(),
+            ComputeResourceCommand::AssignAccountConcept(c) => c.change_ref.as_ref(),
+            ComputeResourceCommand::ClearAccountConcept(c) => c.change_ref.as_ref(),
+            ComputeResourceCommand::SetHardwareDetails(c) => c.change_ref.as_ref(),
+            ComputeResourceCommand::AssignAssetTag(c) => c.change_ref.as_ref(),
+            ComputeResourceCommand::UpdateMetadata(c) => c.change_ref.as_ref(),
+            ComputeResourceCommand::ChangeStatus(c) => c.change_ref.as_ref(),
+            ComputeResourceCommand::RecordConfigurationBackup(c) => c.change_ref.as_ref(),
+            ComputeResourceCommand::AttachGuest(c) => c.change_ref.as_ref(),
+            ComputeResourceCommand::DetachGuest(c) => c.change_ref.as_ref(),
+        }
+    }
+
+    /// Whether the command creates a new aggregate
+    pub fn is_creation(&self) -> bool {
+        matches!(self, ComputeResourceCommand::RegisterResource(_))
+    }
 }
 
 #[cfg(test)]
@@ -251,6 +628,7 @@ mod tests {
             resource_type: ResourceType::PhysicalServer,
             timestamp: test_timestamp(),
             correlation_id: Uuid::now_v7(),
+            change_ref: None,
         };
 
         assert_eq!(cmd.hostname.as_str(), "server01.example.com");
@@ -265,6 +643,7 @@ mod tests {
             timestamp: test_timestamp(),
             correlation_id: Uuid::now_v7(),
             causation_id: None,
+            change_ref: None,
         };
 
         assert_eq!(cmd.organization_id, org_id);
@@ -278,8 +657,28 @@ mod tests {
             timestamp: test_timestamp(),
             correlation_id: Uuid::now_v7(),
             causation_id: None,
+            change_ref: None,
         };
 
         assert_eq!(cmd.to_status, ResourceStatus::Active);
     }
+
+    #[test]
+    fn test_command_envelope_roundtrip() {
+        let cmd = ComputeResourceCommand::AssignAssetTag(AssignAssetTagCommand {
+            asset_tag: "A-1001".to_string(),
+            timestamp: test_timestamp(),
+            correlation_id: Uuid::now_v7(),
+            causation_id: None,
+            change_ref: None,
+        });
+
+        let json = serde_json::to_value(&cmd).unwrap();
+        assert_eq!(json["command"], "assign_asset_tag");
+        assert_eq!(json["asset_tag"], "A-1001");
+
+        let decoded: ComputeResourceCommand = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded, cmd);
+        assert_eq!(decoded.command_name(), "assign_asset_tag");
+    }
 }
@@ -22,6 +22,7 @@ use cim_domain_person::PersonId;
 use cim_domain_policy::PolicyId;
 use cim_domain_spaces::ConceptId;
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::domain::{Hostname, ResourceType};
@@ -38,7 +39,9 @@ use crate::events::infrastructure::InfrastructureEvent;
 /// ```rust,ignore
 /// let state = ComputeResourceState::from_events(&events);
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct ComputeResourceState {
     /// Aggregate ID
     pub id: Uuid,
@@ -50,18 +53,28 @@ pub struct ComputeResourceState {
     pub resource_type: ResourceType,
 
     /// Organization ownership
+    #[cfg_attr(feature = "typescript", ts(type = "string | null"))]
+    #[cfg_attr(feature = "json-schema", schemars(with = "Option<String>"))]
     pub organization_id: Option<EntityId<Organization>>,
 
     /// Physical location
+    #[cfg_attr(feature = "typescript", ts(type = "string | null"))]
+    #[cfg_attr(feature = "json-schema", schemars(with = "Option<String>"))]
     pub location_id: Option<EntityId<LocationMarker>>,
 
     /// Owner/primary contact
+    #[cfg_attr(feature = "typescript", ts(type = "string | null"))]
+    #[cfg_attr(feature = "json-schema", schemars(with = "Option<String>"))]
     pub owner_id: Option<PersonId>,
 
     /// Applicable policies
+    #[cfg_attr(feature = "typescript", ts(type = "Array<string>"))]
+    #[cfg_attr(feature = "json-schema", schemars(with = "Vec<String>"))]
     pub policy_ids: Vec<PolicyId>,
 
     /// Account concept
+    #[cfg_attr(feature = "typescript", ts(type = "string | null"))]
+    #[cfg_attr(feature = "json-schema", schemars(with = "Option<String>"))]
     pub account_concept_id: Option<ConceptId>,
 
     /// Hardware manufacturer
@@ -82,6 +95,13 @@ pub struct ComputeResourceState {
     /// Current status
     pub status: ResourceStatus,
 
+    /// Most recent configuration backup
+    pub last_configuration_backup: Option<ConfigurationBackupRef>,
+
+    /// Virtual machines and containers running on this host
+    #[serde(default)]
+    pub guest_ids: Vec<Uuid>,
+
     /// When this aggregate was created (first event timestamp)
     pub created_at: Option<DateTime<Utc>>,
 
@@ -109,6 +129,8 @@ impl ComputeResourceState {
             asset_tag: None,
             metadata: Vec::new(),
             status: ResourceStatus::Provisioning,
+            last_configuration_backup: None,
+            guest_ids: Vec::new(),
             created_at: None,
             updated_at: None,
         }
@@ -143,6 +165,16 @@ impl ComputeResourceState {
     pub fn version(&self, events: &[ComputeResourceEvent]) -> u64 {
         events.len() as u64
     }
+
+    /// Whether the device lacks a configuration backup newer than `max_age`
+    ///
+    /// `now` is passed explicitly to keep the check pure.
+    pub fn lacks_recent_backup(&self, now: DateTime<Utc>, max_age: chrono::Duration) -> bool {
+        match &self.last_configuration_backup {
+            Some(backup) => now - backup.taken_at > max_age,
+            None => true,
+        }
+    }
 }
 
 /// Apply event to state (pure function)
@@ -285,6 +317,50 @@ pub fn apply_event(state: ComputeResourceState, event: &ComputeResourceEvent) ->
                 ..state
             }
         }
+
+        ConfigurationBackupRecorded(e) => {
+            // Late-arriving records of older backups don't replace newer ones
+            let newer = state
+                .last_configuration_backup
+                .as_ref()
+                .map_or(true, |current| e.backup.taken_at >= current.taken_at);
+
+            ComputeResourceState {
+                last_configuration_backup: if newer {
+                    Some(e.backup.clone())
+                } else {
+                    state.last_configuration_backup.clone()
+                },
+                updated_at: Some(e.timestamp),
+                ..state
+            }
+        }
+
+        GuestAttached(e) => {
+            let mut guest_ids = state.guest_ids.clone();
+            if !guest_ids.contains(&e.guest_id) {
+                guest_ids.push(e.guest_id);
+            }
+            ComputeResourceState {
+                guest_ids,
+                updated_at: Some(e.timestamp),
+                ..state
+            }
+        }
+
+        GuestDetached(e) => {
+            let guest_ids: Vec<_> = state
+                .guest_ids
+                .iter()
+                .filter(|&id| id != &e.guest_id)
+                .copied()
+                .collect();
+            ComputeResourceState {
+                guest_ids,
+                updated_at: Some(e.timestamp),
+                ..state
+            }
+        }
     }
 }
 
@@ -297,6 +373,8 @@ pub fn apply_infrastructure_event(
         InfrastructureEvent::ComputeResource(compute_event) => {
             apply_event(state, compute_event)
         }
+        // Events of other aggregates don't touch compute resource state
+        _ => state,
     }
 }
 
@@ -0,0 +1,526 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Pure Functional PhysicalConnection Aggregate
+//!
+//! A cable between two interfaces. Each connection is its own aggregate;
+//! whether a port is already patched is a question about *all*
+//! connections, so it is answered by a [`PortOccupancy`] view folded from
+//! connection events and passed to the handler explicitly.
+//!
+//! # Invariants
+//!
+//! - An interface cannot be connected to itself
+//! - A port carries at most one live cable
+//! - Removed connections are final (re-patching creates a new connection)
+//!
+//! # Architecture
+//!
+//! ```text
+//! (ConnectionState, PortOccupancy, ConnectionCommand)
+//!     → handle_connection_command() → Result<ConnectionEvent, CommandError>
+//!                                           ↓
+//! ConnectionEvents → apply_connection_event() → ConnectionState
+//!                  → PortOccupancy::apply()   → PortOccupancy
+//! ```
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::aggregate::handlers::CommandError;
+use crate::events::connection::*;
+
+/// Immutable PhysicalConnection State
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ConnectionState {
+    /// Aggregate ID
+    pub id: Uuid,
+
+    /// A side of the cable (None until established)
+    pub a_end: Option<ConnectionEndpoint>,
+
+    /// B side of the cable (None until established)
+    pub b_end: Option<ConnectionEndpoint>,
+
+    /// Cable label
+    pub label: Option<String>,
+
+    /// Whether the cable was removed
+    pub removed: bool,
+
+    /// When this aggregate was created (first event timestamp)
+    pub created_at: Option<DateTime<Utc>>,
+
+    /// When this aggregate was last modified (latest event timestamp)
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl ConnectionState {
+    /// Create default empty state
+    ///
+    /// Used as initial state for event folding.
+    pub fn default_for(id: Uuid) -> Self {
+        Self {
+            id,
+            a_end: None,
+            b_end: None,
+            label: None,
+            removed: false,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    /// Reconstruct state from event stream
+    pub fn from_events(events: &[ConnectionEvent]) -> Self {
+        let aggregate_id = events
+            .first()
+            .map(|e| e.aggregate_id())
+            .unwrap_or_else(Uuid::now_v7);
+
+        events
+            .iter()
+            .fold(Self::default_for(aggregate_id), apply_connection_event)
+    }
+
+    /// Check if aggregate is initialized (has events)
+    pub fn is_initialized(&self) -> bool {
+        self.created_at.is_some()
+    }
+
+    /// Whether the cable is established and not removed
+    pub fn is_live(&self) -> bool {
+        self.is_initialized() && !self.removed
+    }
+}
+
+/// Which live connection occupies each interface
+///
+/// Folded from the connection events of every aggregate (e.g. a replay of
+/// `infrastructure.connection.>`).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PortOccupancy {
+    /// Interface ID → connection ID
+    ports: HashMap<Uuid, Uuid>,
+
+    /// Connection ID → its two interfaces
+    connections: HashMap<Uuid, [Uuid; 2]>,
+}
+
+impl PortOccupancy {
+    /// Create an empty view
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply a connection event
+    pub fn apply(&mut self, event: &ConnectionEvent) {
+        match event {
+            ConnectionEvent::ConnectionEstablished(e) => {
+                let ends = [e.a_end.interface_id, e.b_end.interface_id];
+                for interface_id in ends {
+                    self.ports.insert(interface_id, e.aggregate_id);
+                }
+                self.connections.insert(e.aggregate_id, ends);
+            }
+            ConnectionEvent::ConnectionLabeled(_) => {}
+            ConnectionEvent::ConnectionRemoved(e) => {
+                let Some(ends) = self.connections.remove(&e.aggregate_id) else {
+                    return;
+                };
+                for interface_id in ends {
+                    if self.ports.get(&interface_id) == Some(&e.aggregate_id) {
+                        self.ports.remove(&interface_id);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Connection currently patched into an interface
+    pub fn connection_at(&self, interface_id: Uuid) -> Option<Uuid> {
+        self.ports.get(&interface_id).copied()
+    }
+}
+
+/// Command to patch a cable between two interfaces
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct EstablishConnectionCommand {
+    /// A side of the cable
+    pub a_end: ConnectionEndpoint,
+
+    /// B side of the cable
+    pub b_end: ConnectionEndpoint,
+
+    /// Cable label, if known
+    pub label: Option<String>,
+
+    /// Timestamp when command was issued
+    pub timestamp: DateTime<Utc>,
+
+    /// Correlation ID for distributed tracing
+    pub correlation_id: Uuid,
+
+    /// Optional causation ID (event that caused this command)
+    pub causation_id: Option<Uuid>,
+}
+
+/// Command to set or change a cable label
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct LabelConnectionCommand {
+    /// New label
+    pub label: String,
+
+    /// Timestamp when command was issued
+    pub timestamp: DateTime<Utc>,
+
+    /// Correlation ID for distributed tracing
+    pub correlation_id: Uuid,
+
+    /// Optional causation ID (event that caused this command)
+    pub causation_id: Option<Uuid>,
+}
+
+/// Command to remove a cable
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct RemoveConnectionCommand {
+    /// Why the cable is removed
+    pub reason: Option<String>,
+
+    /// Timestamp when command was issued
+    pub timestamp: DateTime<Utc>,
+
+    /// Correlation ID for distributed tracing
+    pub correlation_id: Uuid,
+
+    /// Optional causation ID (event that caused this command)
+    pub causation_id: Option<Uuid>,
+}
+
+/// Any command accepted by the PhysicalConnection aggregate
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum ConnectionCommand {
+    EstablishConnection(EstablishConnectionCommand),
+    LabelConnection(LabelConnectionCommand),
+    RemoveConnection(RemoveConnectionCommand),
+}
+
+/// Handle EstablishConnection command
+///
+/// # Business Rules
+/// - Connection must not already exist
+/// - The two ends must be different interfaces
+/// - Neither port may carry another live cable
+pub fn handle_establish_connection(
+    state: &ConnectionState,
+    command: EstablishConnectionCommand,
+    aggregate_id: Uuid,
+    occupancy: &PortOccupancy,
+) -> Result<ConnectionEstablished, CommandError> {
+    if state.is_initialized() {
+        return Err(CommandError::AlreadyInitialized);
+    }
+
+    if command.a_end.interface_id == command.b_end.interface_id {
+        return Err(CommandError::BusinessRuleViolation(format!(
+            "Cannot connect {} {} to itself",
+            command.a_end.device, command.a_end.port
+        )));
+    }
+
+    for end in [&command.a_end, &command.b_end] {
+        if let Some(existing) = occupancy.connection_at(end.interface_id) {
+            return Err(CommandError::BusinessRuleViolation(format!(
+                "Port {} {} is already patched (connection {})",
+                end.device, end.port, existing
+            )));
+        }
+    }
+
+    Ok(ConnectionEstablished {
+        event_version: 1,
+        event_id: Uuid::now_v7(),
+        aggregate_id,
+        timestamp: command.timestamp,
+        correlation_id: command.correlation_id,
+        causation_id: command.causation_id,
+        a_end: command.a_end,
+        b_end: command.b_end,
+        label: command.label,
+    })
+}
+
+/// Handle LabelConnection command
+///
+/// # Business Rules
+/// - Connection must be live
+/// - Label must not be empty
+pub fn handle_label_connection(
+    state: &ConnectionState,
+    command: LabelConnectionCommand,
+) -> Result<ConnectionLabeled, CommandError> {
+    if !state.is_live() {
+        return Err(CommandError::NotInitialized);
+    }
+
+    let label = command.label.trim();
+    if label.is_empty() {
+        return Err(CommandError::BusinessRuleViolation(
+            "Cable label must not be empty".to_string(),
+        ));
+    }
+
+    Ok(ConnectionLabeled {
+        event_version: 1,
+        event_id: Uuid::now_v7(),
+        aggregate_id: state.id,
+        timestamp: command.timestamp,
+        correlation_id: command.correlation_id,
+        causation_id: command.causation_id,
+        label: label.to_string(),
+    })
+}
+
+/// Handle RemoveConnection command
+///
+/// # Business Rules
+/// - Connection must be live
+pub fn handle_remove_connection(
+    state: &ConnectionState,
+    command: RemoveConnectionCommand,
+) -> Result<ConnectionRemoved, CommandError> {
+    if !state.is_live() {
+        return Err(CommandError::NotInitialized);
+    }
+
+    Ok(ConnectionRemoved {
+        event_version: 1,
+        event_id: Uuid::now_v7(),
+        aggregate_id: state.id,
+        timestamp: command.timestamp,
+        correlation_id: command.correlation_id,
+        causation_id: command.causation_id,
+        reason: command.reason,
+    })
+}
+
+/// Dispatch any ConnectionCommand to its handler
+///
+/// `aggregate_id` is only used by EstablishConnection.
+pub fn handle_connection_command(
+    state: &ConnectionState,
+    command: ConnectionCommand,
+    aggregate_id: Uuid,
+    occupancy: &PortOccupancy,
+) -> Result<ConnectionEvent, CommandError> {
+    match command {
+        ConnectionCommand::EstablishConnection(cmd) => {
+            handle_establish_connection(state, cmd, aggregate_id, occupancy)
+                .map(ConnectionEvent::ConnectionEstablished)
+        }
+        ConnectionCommand::LabelConnection(cmd) => {
+            handle_label_connection(state, cmd).map(ConnectionEvent::ConnectionLabeled)
+        }
+        ConnectionCommand::RemoveConnection(cmd) => {
+            handle_remove_connection(state, cmd).map(ConnectionEvent::ConnectionRemoved)
+        }
+    }
+}
+
+/// Apply event to state (pure function)
+pub fn apply_connection_event(state: ConnectionState, event: &ConnectionEvent) -> ConnectionState {
+    match event {
+        ConnectionEvent::ConnectionEstablished(e) => ConnectionState {
+            id: e.aggregate_id,
+            a_end: Some(e.a_end.clone()),
+            b_end: Some(e.b_end.clone()),
+            label: e.label.clone(),
+            removed: false,
+            created_at: Some(e.timestamp),
+            updated_at: Some(e.timestamp),
+        },
+
+        ConnectionEvent::ConnectionLabeled(e) => ConnectionState {
+            label: Some(e.label.clone()),
+            updated_at: Some(e.timestamp),
+            ..state
+        },
+
+        ConnectionEvent::ConnectionRemoved(e) => ConnectionState {
+            removed: true,
+            updated_at: Some(e.timestamp),
+            ..state
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::Hostname;
+
+    fn test_timestamp() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-19T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn endpoint(device: &str, port: &str) -> ConnectionEndpoint {
+        ConnectionEndpoint {
+            interface_id: Uuid::now_v7(),
+            resource_id: Uuid::now_v7(),
+            device: Hostname::new(device).unwrap(),
+            port: port.to_string(),
+        }
+    }
+
+    fn establish(
+        a_end: ConnectionEndpoint,
+        b_end: ConnectionEndpoint,
+    ) -> EstablishConnectionCommand {
+        EstablishConnectionCommand {
+            a_end,
+            b_end,
+            label: None,
+            timestamp: test_timestamp(),
+            correlation_id: Uuid::now_v7(),
+            causation_id: None,
+        }
+    }
+
+    fn remove() -> RemoveConnectionCommand {
+        RemoveConnectionCommand {
+            reason: None,
+            timestamp: test_timestamp(),
+            correlation_id: Uuid::now_v7(),
+            causation_id: None,
+        }
+    }
+
+    #[test]
+    fn test_interface_cannot_connect_to_itself() {
+        // Arrange
+        let port = endpoint("sw01", "xe-0/0/1");
+        let state = ConnectionState::default_for(Uuid::now_v7());
+
+        // Act
+        let result = handle_establish_connection(
+            &state,
+            establish(port.clone(), port),
+            state.id,
+            &PortOccupancy::new(),
+        );
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(CommandError::BusinessRuleViolation(_))
+        ));
+    }
+
+    #[test]
+    fn test_port_cannot_be_double_patched() {
+        // Arrange: sw01 xe-0/0/1 is already patched to srv01 eth0
+        let switch_port = endpoint("sw01", "xe-0/0/1");
+        let first_id = Uuid::now_v7();
+        let first = handle_establish_connection(
+            &ConnectionState::default_for(first_id),
+            establish(switch_port.clone(), endpoint("srv01", "eth0")),
+            first_id,
+            &PortOccupancy::new(),
+        )
+        .unwrap();
+        let mut occupancy = PortOccupancy::new();
+        occupancy.apply(&ConnectionEvent::ConnectionEstablished(first));
+
+        // Act
+        let second_id = Uuid::now_v7();
+        let result = handle_establish_connection(
+            &ConnectionState::default_for(second_id),
+            establish(switch_port.clone(), endpoint("srv02", "eth0")),
+            second_id,
+            &occupancy,
+        );
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(CommandError::BusinessRuleViolation(_))
+        ));
+        assert_eq!(
+            occupancy.connection_at(switch_port.interface_id),
+            Some(first_id)
+        );
+    }
+
+    #[test]
+    fn test_removal_frees_ports_and_is_final() {
+        // Arrange
+        let switch_port = endpoint("sw01", "xe-0/0/1");
+        let id = Uuid::now_v7();
+        let established = ConnectionEvent::ConnectionEstablished(
+            handle_establish_connection(
+                &ConnectionState::default_for(id),
+                establish(switch_port.clone(), endpoint("srv01", "eth0")),
+                id,
+                &PortOccupancy::new(),
+            )
+            .unwrap(),
+        );
+        let state = ConnectionState::from_events(&[established.clone()]);
+        let mut occupancy = PortOccupancy::new();
+        occupancy.apply(&established);
+
+        // Act
+        let removed =
+            ConnectionEvent::ConnectionRemoved(handle_remove_connection(&state, remove()).unwrap());
+        occupancy.apply(&removed);
+        let state = apply_connection_event(state, &removed);
+
+        // Assert
+        assert!(!state.is_live());
+        assert_eq!(occupancy.connection_at(switch_port.interface_id), None);
+        assert_eq!(
+            handle_remove_connection(&state, remove()),
+            Err(CommandError::NotInitialized)
+        );
+    }
+
+    #[test]
+    fn test_label_is_trimmed() {
+        let id = Uuid::now_v7();
+        let established = handle_establish_connection(
+            &ConnectionState::default_for(id),
+            establish(endpoint("sw01", "xe-0/0/1"), endpoint("srv01", "eth0")),
+            id,
+            &PortOccupancy::new(),
+        )
+        .unwrap();
+        let state =
+            ConnectionState::from_events(&[ConnectionEvent::ConnectionEstablished(established)]);
+
+        let event = handle_label_connection(
+            &state,
+            LabelConnectionCommand {
+                label: "  CAB-0042 ".to_string(),
+                timestamp: test_timestamp(),
+                correlation_id: Uuid::now_v7(),
+                causation_id: None,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(event.label, "CAB-0042");
+    }
+}
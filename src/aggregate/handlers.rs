@@ -24,13 +24,16 @@
 //! - Resources can't be double-registered
 //! - Policies can't be added twice
 //! - Status changes must follow state machine rules
+//! - Only virtual guests run on a host, and hosting can't form a cycle
 
 use uuid::Uuid;
 
 use crate::aggregate::commands::*;
-use crate::aggregate::compute_resource::ComputeResourceState;
+use crate::aggregate::compute_resource::{apply_event, ComputeResourceState};
+use crate::domain::{NetworkValidationError, ResourceType};
 use crate::events::compute_resource::*;
 use crate::events::ResourceStatus;
+use crate::state_machine::{resource_lifecycle, TransitionError};
 
 /// Command validation error
 #[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
@@ -52,15 +55,45 @@ pub enum CommandError {
     PolicyNotFound(String),
 
     /// Invalid status transition
-    #[error("Invalid status transition from {from:?} to {to:?}")]
+    #[error("Invalid status transition from {from:?} to {to:?} (allowed: {allowed:?}): {reason}")]
     InvalidStatusTransition {
         from: ResourceStatus,
         to: ResourceStatus,
+
+        /// Statuses the resource could move to instead
+        allowed: Vec<ResourceStatus>,
+
+        /// Why the lifecycle FSM rejected the transition
+        #[source]
+        reason: TransitionError,
+    },
+
+    /// Guest already runs on this host
+    #[error("Guest {0} already attached")]
+    GuestAlreadyAttached(Uuid),
+
+    /// Guest does not run on this host
+    #[error("Guest {0} not attached")]
+    GuestNotAttached(Uuid),
+
+    /// Physical hardware can't be placed on a host
+    #[error("Guest {guest_id} is a {resource_type:?}, which can't run on a host")]
+    PhysicalGuest {
+        guest_id: Uuid,
+        resource_type: ResourceType,
     },
 
+    /// Attaching the guest would make a resource host itself
+    #[error("Attaching guest {0} would create a hosting cycle")]
+    GuestCycle(Uuid),
+
     /// Business rule violation
     #[error("Business rule violation: {0}")]
     BusinessRuleViolation(String),
+
+    /// Network conflicts with an existing network
+    #[error("Network conflict: {0}")]
+    NetworkConflict(#[from] NetworkValidationError),
 }
 
 /// Handle RegisterResource command
@@ -350,7 +383,7 @@ pub fn handle_update_metadata(
 ///
 /// # Business Rules
 /// - Resource must be initialized
-/// - Status transition must be valid (per ResourceStatus state machine)
+/// - Status transition must be valid (per the resource lifecycle FSM)
 pub fn handle_change_status(
     state: &ComputeResourceState,
     command: ChangeStatusCommand,
@@ -359,11 +392,13 @@ pub fn handle_change_status(
         return Err(CommandError::NotInitialized);
     }
 
-    // Business rule: Must be valid state transition
-    if !state.status.can_transition_to(&command.to_status) {
+    // Business rule: Must be a transition of the lifecycle FSM
+    if let Err(reason) = resource_lifecycle::transition_to(state.status, command.to_status) {
         return Err(CommandError::InvalidStatusTransition {
             from: state.status,
             to: command.to_status,
+            allowed: resource_lifecycle::allowed_next_states(state.status),
+            reason,
         });
     }
 
@@ -379,6 +414,206 @@ pub fn handle_change_status(
     })
 }
 
+/// Handle RecordConfigurationBackup command
+///
+/// # Business Rules
+/// - Resource must be initialized
+/// - Decommissioned resources don't take new backups
+/// - Object key must be set and the hash must be `<algorithm>:<hex digest>`
+/// - Backup can't be taken after the command was issued
+pub fn handle_record_configuration_backup(
+    state: &ComputeResourceState,
+    command: RecordConfigurationBackupCommand,
+) -> Result<ConfigurationBackupRecorded, CommandError> {
+    if !state.is_initialized() {
+        return Err(CommandError::NotInitialized);
+    }
+
+    if state.status == ResourceStatus::Decommissioned {
+        return Err(CommandError::BusinessRuleViolation(
+            "Cannot record backups for a decommissioned resource".to_string(),
+        ));
+    }
+
+    let backup = &command.backup;
+    if backup.object_key.trim().is_empty() {
+        return Err(CommandError::BusinessRuleViolation(
+            "Backup object key must not be empty".to_string(),
+        ));
+    }
+
+    let hash_is_valid = backup
+        .content_hash
+        .split_once(':')
+        .map_or(false, |(algorithm, digest)| {
+            !algorithm.is_empty()
+                && !digest.is_empty()
+                && digest.chars().all(|c| c.is_ascii_hexdigit())
+        });
+    if !hash_is_valid {
+        return Err(CommandError::BusinessRuleViolation(format!(
+            "Invalid backup content hash '{}', expected <algorithm>:<hex digest>",
+            backup.content_hash
+        )));
+    }
+
+    if backup.taken_at > command.timestamp {
+        return Err(CommandError::BusinessRuleViolation(
+            "Backup cannot be taken after the command timestamp".to_string(),
+        ));
+    }
+
+    Ok(ConfigurationBackupRecorded {
+        event_version: ConfigurationBackupRecorded::CURRENT_VERSION,
+        event_id: Uuid::now_v7(),
+        aggregate_id: state.id,
+        timestamp: command.timestamp,
+        correlation_id: command.correlation_id,
+        causation_id: command.causation_id,
+        backup: command.backup,
+    })
+}
+
+/// Handle AttachGuest command
+///
+/// # Business Rules
+/// - Resource must be initialized and not decommissioned
+/// - Guest must be a virtual machine or container host
+/// - Guest can't be this host or any host above it
+/// - Guest can't be attached twice
+pub fn handle_attach_guest(
+    state: &ComputeResourceState,
+    command: AttachGuestCommand,
+) -> Result<GuestAttached, CommandError> {
+    if !state.is_initialized() {
+        return Err(CommandError::NotInitialized);
+    }
+
+    if state.status == ResourceStatus::Decommissioned {
+        return Err(CommandError::BusinessRuleViolation(
+            "Cannot attach guests to a decommissioned resource".to_string(),
+        ));
+    }
+
+    if !command.guest_type.can_be_guest() {
+        return Err(CommandError::PhysicalGuest {
+            guest_id: command.guest_id,
+            resource_type: command.guest_type,
+        });
+    }
+
+    if command.guest_id == state.id || command.host_ancestors.contains(&command.guest_id) {
+        return Err(CommandError::GuestCycle(command.guest_id));
+    }
+
+    if state.guest_ids.contains(&command.guest_id) {
+        return Err(CommandError::GuestAlreadyAttached(command.guest_id));
+    }
+
+    Ok(GuestAttached {
+        event_version: GuestAttached::CURRENT_VERSION,
+        event_id: Uuid::now_v7(),
+        aggregate_id: state.id,
+        timestamp: command.timestamp,
+        correlation_id: command.correlation_id,
+        causation_id: command.causation_id,
+        guest_id: command.guest_id,
+        guest_type: command.guest_type,
+    })
+}
+
+/// Handle DetachGuest command
+///
+/// # Business Rules
+/// - Resource must be initialized
+/// - Guest must be attached
+pub fn handle_detach_guest(
+    state: &ComputeResourceState,
+    command: DetachGuestCommand,
+) -> Result<GuestDetached, CommandError> {
+    if !state.is_initialized() {
+        return Err(CommandError::NotInitialized);
+    }
+
+    if !state.guest_ids.contains(&command.guest_id) {
+        return Err(CommandError::GuestNotAttached(command.guest_id));
+    }
+
+    Ok(GuestDetached {
+        event_version: GuestDetached::CURRENT_VERSION,
+        event_id: Uuid::now_v7(),
+        aggregate_id: state.id,
+        timestamp: command.timestamp,
+        correlation_id: command.correlation_id,
+        causation_id: command.causation_id,
+        guest_id: command.guest_id,
+    })
+}
+
+/// Handle any compute resource command
+///
+/// Dispatches to the matching handler and wraps the result in
+/// [`ComputeResourceEvent`]. `aggregate_id` is only used for registration.
+pub fn handle_command(
+    state: &ComputeResourceState,
+    command: ComputeResourceCommand,
+    aggregate_id: Uuid,
+) -> Result<ComputeResourceEvent, CommandError> {
+    use ComputeResourceCommand as C;
+    use ComputeResourceEvent as E;
+
+    Ok(match command {
+        C::RegisterResource(c) => {
+            E::ResourceRegistered(handle_register_resource(state, c, aggregate_id)?)
+        }
+        C::AssignOrganization(c) => E::OrganizationAssigned(handle_assign_organization(state, c)?),
+        C::AssignLocation(c) => E::LocationAssigned(handle_assign_location(state, c)?),
+        C::AssignOwner(c) => E::OwnerAssigned(handle_assign_owner(state, c)?),
+        C::AddPolicy(c) => E::PolicyAdded(handle_add_policy(state, c)?),
+        C::RemovePolicy(c) => E::PolicyRemoved(handle_remove_policy(state, c)?),
+        C::AssignAccountConcept(c) => {
+            E::AccountConceptAssigned(handle_assign_account_concept(state, c)?)
+        }
+        C::ClearAccountConcept(c) => {
+            E::AccountConceptCleared(handle_clear_account_concept(state, c)?)
+        }
+        C::SetHardwareDetails(c) => E::HardwareDetailsSet(handle_set_hardware_details(state, c)?),
+        C::AssignAssetTag(c) => E::AssetTagAssigned(handle_assign_asset_tag(state, c)?),
+        C::UpdateMetadata(c) => E::MetadataUpdated(handle_update_metadata(state, c)?),
+        C::ChangeStatus(c) => E::StatusChanged(handle_change_status(state, c)?),
+        C::RecordConfigurationBackup(c) => {
+            E::ConfigurationBackupRecorded(handle_record_configuration_backup(state, c)?)
+        }
+        C::AttachGuest(c) => E::GuestAttached(handle_attach_guest(state, c)?),
+        C::DetachGuest(c) => E::GuestDetached(handle_detach_guest(state, c)?),
+    })
+}
+
+/// Handle a sequence of commands as one transaction
+///
+/// Each command sees the state produced by the events of the commands
+/// before it. The first failing command fails the whole batch, so either
+/// every command yields its event or none do.
+///
+/// # Returns
+/// - Events in command order, and the state after applying all of them
+pub fn handle_commands(
+    state: &ComputeResourceState,
+    commands: Vec<ComputeResourceCommand>,
+    aggregate_id: Uuid,
+) -> Result<(Vec<ComputeResourceEvent>, ComputeResourceState), CommandError> {
+    let mut current = state.clone();
+    let mut events = Vec::with_capacity(commands.len());
+
+    for command in commands {
+        let event = handle_command(&current, command, aggregate_id)?;
+        current = apply_event(current, &event);
+        events.push(event);
+    }
+
+    Ok((events, current))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -404,6 +639,7 @@ mod tests {
             resource_type: ResourceType::PhysicalServer,
             timestamp: test_timestamp(),
             correlation_id: Uuid::now_v7(),
+            change_ref: None,
         };
 
         // Act
@@ -427,6 +663,7 @@ mod tests {
             resource_type: ResourceType::PhysicalServer,
             timestamp: test_timestamp(),
             correlation_id: Uuid::now_v7(),
+            change_ref: None,
         };
 
         // Act
@@ -447,6 +684,7 @@ mod tests {
             timestamp: test_timestamp(),
             correlation_id: Uuid::now_v7(),
             causation_id: None,
+            change_ref: None,
         };
 
         // Act
@@ -470,6 +708,7 @@ mod tests {
             timestamp: test_timestamp(),
             correlation_id: Uuid::now_v7(),
             causation_id: None,
+            change_ref: None,
         };
 
         // Act
@@ -495,6 +734,7 @@ mod tests {
             timestamp: test_timestamp(),
             correlation_id: Uuid::now_v7(),
             causation_id: None,
+            change_ref: None,
         };
 
         // Act
@@ -502,9 +742,175 @@ mod tests {
 
         // Assert
         assert!(result.is_err());
+        match result.unwrap_err() {
+            CommandError::InvalidStatusTransition { allowed, .. } => assert_eq!(
+                allowed,
+                vec![ResourceStatus::Maintenance, ResourceStatus::Decommissioned]
+            ),
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+
+    fn backup_command(content_hash: &str) -> RecordConfigurationBackupCommand {
+        RecordConfigurationBackupCommand {
+            backup: ConfigurationBackupRef {
+                object_store: "device-configs".to_string(),
+                object_key: "core-sw01/2026-01-19.cfg".to_string(),
+                content_hash: content_hash.to_string(),
+                tool: "oxidized".to_string(),
+                taken_at: test_timestamp(),
+                size_bytes: Some(4096),
+            },
+            timestamp: test_timestamp(),
+            correlation_id: Uuid::now_v7(),
+            causation_id: None,
+            change_ref: None,
+        }
+    }
+
+    #[test]
+    fn test_handle_record_configuration_backup_success() {
+        // Arrange
+        let mut state = ComputeResourceState::default_for(test_aggregate_id());
+        state.created_at = Some(test_timestamp());
+
+        // Act
+        let result = handle_record_configuration_backup(&state, backup_command("sha256:9f86d081"));
+
+        // Assert
+        let event = result.unwrap();
+        assert_eq!(event.backup.tool, "oxidized");
+        assert_eq!(event.aggregate_id, test_aggregate_id());
+    }
+
+    #[test]
+    fn test_handle_record_configuration_backup_invalid_hash() {
+        let mut state = ComputeResourceState::default_for(test_aggregate_id());
+        state.created_at = Some(test_timestamp());
+
+        let result = handle_record_configuration_backup(&state, backup_command("not-a-hash"));
+
         assert!(matches!(
             result.unwrap_err(),
-            CommandError::InvalidStatusTransition { .. }
+            CommandError::BusinessRuleViolation(_)
+        ));
+    }
+
+    #[test]
+    fn test_handle_commands_threads_state() {
+        // Arrange - registration followed by a command that needs it
+        let state = ComputeResourceState::default_for(test_aggregate_id());
+        let commands = vec![
+            ComputeResourceCommand::RegisterResource(RegisterResourceCommand {
+                hostname: Hostname::new("server01.example.com").unwrap(),
+                resource_type: ResourceType::PhysicalServer,
+                timestamp: test_timestamp(),
+                correlation_id: Uuid::now_v7(),
+                change_ref: None,
+            }),
+            ComputeResourceCommand::AssignAssetTag(AssignAssetTagCommand {
+                asset_tag: "A-1001".to_string(),
+                timestamp: test_timestamp(),
+                correlation_id: Uuid::now_v7(),
+                causation_id: None,
+                change_ref: None,
+            }),
+        ];
+
+        // Act
+        let (events, after) = handle_commands(&state, commands, test_aggregate_id()).unwrap();
+
+        // Assert
+        assert_eq!(events.len(), 2);
+        assert_eq!(after.asset_tag.as_deref(), Some("A-1001"));
+    }
+
+    #[test]
+    fn test_handle_commands_fails_as_a_unit() {
+        let state = ComputeResourceState::default_for(test_aggregate_id());
+        let commands = vec![ComputeResourceCommand::AssignAssetTag(
+            AssignAssetTagCommand {
+                asset_tag: "A-1001".to_string(),
+                timestamp: test_timestamp(),
+                correlation_id: Uuid::now_v7(),
+                causation_id: None,
+                change_ref: None,
+            },
+        )];
+
+        let result = handle_commands(&state, commands, test_aggregate_id());
+
+        assert_eq!(result.unwrap_err(), CommandError::NotInitialized);
+    }
+
+    fn attach_command(guest_id: Uuid, guest_type: ResourceType) -> AttachGuestCommand {
+        AttachGuestCommand {
+            guest_id,
+            guest_type,
+            host_ancestors: Vec::new(),
+            timestamp: test_timestamp(),
+            correlation_id: Uuid::now_v7(),
+            causation_id: None,
+            change_ref: None,
+        }
+    }
+
+    #[test]
+    fn test_handle_attach_guest_invariants() {
+        // Arrange
+        let mut state = ComputeResourceState::default_for(test_aggregate_id());
+        state.created_at = Some(test_timestamp());
+        let guest_id = Uuid::now_v7();
+
+        // Act
+        let attached = handle_attach_guest(
+            &state,
+            attach_command(guest_id, ResourceType::VirtualMachine),
+        )
+        .unwrap();
+        let state = apply_event(state, &ComputeResourceEvent::GuestAttached(attached));
+
+        // Assert
+        assert_eq!(state.guest_ids, vec![guest_id]);
+        assert_eq!(
+            handle_attach_guest(&state, attach_command(guest_id, ResourceType::VirtualMachine))
+                .unwrap_err(),
+            CommandError::GuestAlreadyAttached(guest_id)
+        );
+        assert!(matches!(
+            handle_attach_guest(
+                &state,
+                attach_command(Uuid::now_v7(), ResourceType::PhysicalServer)
+            )
+            .unwrap_err(),
+            CommandError::PhysicalGuest { .. }
         ));
+        let ancestor = Uuid::now_v7();
+        let mut cyclic = attach_command(ancestor, ResourceType::ContainerHost);
+        cyclic.host_ancestors = vec![ancestor];
+        assert_eq!(
+            handle_attach_guest(&state, cyclic).unwrap_err(),
+            CommandError::GuestCycle(ancestor)
+        );
+    }
+
+    #[test]
+    fn test_handle_detach_guest_not_attached() {
+        let mut state = ComputeResourceState::default_for(test_aggregate_id());
+        state.created_at = Some(test_timestamp());
+        let guest_id = Uuid::now_v7();
+
+        let result = handle_detach_guest(
+            &state,
+            DetachGuestCommand {
+                guest_id,
+                timestamp: test_timestamp(),
+                correlation_id: Uuid::now_v7(),
+                causation_id: None,
+                change_ref: None,
+            },
+        );
+
+        assert_eq!(result.unwrap_err(), CommandError::GuestNotAttached(guest_id));
     }
 }
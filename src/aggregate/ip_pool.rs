@@ -0,0 +1,572 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Pure Functional IpPool Aggregate
+//!
+//! A pool hands out the addresses of one prefix, never the same address
+//! twice. It is the write-side counterpart of the [`ipam`](crate::ipam)
+//! checks: reserved ranges and the IPv4 network/broadcast addresses are
+//! excluded with the same rules as the [`IpAllocator`](crate::ipam::IpAllocator).
+//!
+//! # Architecture
+//!
+//! ```text
+//! IpPoolCommand → handle_ip_pool_command() → Result<IpPoolEvent, CommandError>
+//!                                                  ↓
+//! IpPoolEvents → apply_ip_pool_event() → IpPoolState
+//! ```
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::aggregate::ip_pool::*;
+//!
+//! let state = IpPoolState::from_events(&events);
+//! let event = handle_allocate_address(&state, AllocateAddressCommand {
+//!     address: None, // next free address
+//!     interface_id,
+//!     resource_id,
+//!     timestamp,
+//!     correlation_id,
+//!     causation_id: None,
+//! })?;
+//! ```
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+use uuid::Uuid;
+
+use crate::aggregate::handlers::CommandError;
+use crate::domain::IpAddressWithCidr;
+use crate::events::ip_pool::*;
+use crate::ipam::{check_not_reserved, first_free, free_count, is_assignable};
+use crate::ipam::{IpamNetwork, ReservedRange};
+
+/// Address held by an interface
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct PoolAllocation {
+    /// Address with the pool's prefix length
+    pub address: IpAddressWithCidr,
+
+    /// Interface holding the address
+    pub interface_id: Uuid,
+
+    /// Resource owning the interface
+    pub resource_id: Uuid,
+
+    /// When the address was allocated
+    pub allocated_at: DateTime<Utc>,
+}
+
+/// Immutable IpPool State
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct IpPoolState {
+    /// Aggregate ID
+    pub id: Uuid,
+
+    /// Pool name
+    pub name: String,
+
+    /// Prefix the pool allocates from (None until defined)
+    pub cidr: Option<IpAddressWithCidr>,
+
+    /// Routing scope (VRF); None for the global routing table
+    pub vrf: Option<String>,
+
+    /// Network aggregate the pool serves, if any
+    pub network_id: Option<Uuid>,
+
+    /// Ranges never handed out
+    pub reserved: Vec<ReservedRange>,
+
+    /// Current allocations
+    pub allocations: Vec<PoolAllocation>,
+
+    /// When this aggregate was created (first event timestamp)
+    pub created_at: Option<DateTime<Utc>>,
+
+    /// When this aggregate was last modified (latest event timestamp)
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl IpPoolState {
+    /// Create default empty state
+    ///
+    /// Used as initial state for event folding.
+    pub fn default_for(id: Uuid) -> Self {
+        Self {
+            id,
+            name: String::new(),
+            cidr: None,
+            vrf: None,
+            network_id: None,
+            reserved: Vec::new(),
+            allocations: Vec::new(),
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    /// Reconstruct state from event stream
+    pub fn from_events(events: &[IpPoolEvent]) -> Self {
+        let aggregate_id = events
+            .first()
+            .map(|e| e.aggregate_id())
+            .unwrap_or_else(Uuid::now_v7);
+
+        events
+            .iter()
+            .fold(Self::default_for(aggregate_id), apply_ip_pool_event)
+    }
+
+    /// Check if aggregate is initialized (has events)
+    pub fn is_initialized(&self) -> bool {
+        self.created_at.is_some()
+    }
+
+    /// Allocation holding an address
+    pub fn allocation(&self, address: &IpAddr) -> Option<&PoolAllocation> {
+        self.allocations
+            .iter()
+            .find(|allocation| allocation.address.address() == *address)
+    }
+
+    /// The pool as an IPAM network (None until defined)
+    pub fn ipam_network(&self) -> Option<IpamNetwork> {
+        let cidr = self.cidr.clone()?;
+        let network = self.reserved.iter().cloned().fold(
+            IpamNetwork::new(self.id, self.name.clone(), cidr),
+            |network, range| network.with_reserved(range),
+        );
+
+        Some(match &self.vrf {
+            Some(vrf) => network.in_vrf(vrf.clone()),
+            None => network,
+        })
+    }
+
+    /// Lowest address that can still be allocated
+    pub fn next_free(&self) -> Option<IpAddr> {
+        let network = self.ipam_network()?;
+        first_free(&network, self.allocated_addresses())
+    }
+
+    /// Number of addresses that can still be allocated
+    pub fn free_count(&self) -> u128 {
+        self.ipam_network()
+            .map(|network| free_count(&network, self.allocated_addresses()))
+            .unwrap_or(0)
+    }
+
+    fn allocated_addresses(&self) -> impl Iterator<Item = IpAddr> + '_ {
+        self.allocations
+            .iter()
+            .map(|allocation| allocation.address.address())
+    }
+}
+
+/// Command to define a new pool
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct DefinePoolCommand {
+    /// Pool name
+    pub name: String,
+
+    /// Prefix to allocate from; host bits are cleared
+    pub cidr: IpAddressWithCidr,
+
+    /// Routing scope (VRF); None for the global routing table
+    pub vrf: Option<String>,
+
+    /// Network aggregate the pool serves, if any
+    pub network_id: Option<Uuid>,
+
+    /// Ranges never handed out
+    #[serde(default)]
+    pub reserved: Vec<ReservedRange>,
+
+    /// Timestamp when command was issued
+    pub timestamp: DateTime<Utc>,
+
+    /// Correlation ID for distributed tracing
+    pub correlation_id: Uuid,
+
+    /// Optional causation ID (event that caused this command)
+    pub causation_id: Option<Uuid>,
+}
+
+/// Command to allocate an address to an interface
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct AllocateAddressCommand {
+    /// Specific address to allocate; None for the next free address
+    pub address: Option<IpAddr>,
+
+    /// Interface that will hold the address
+    pub interface_id: Uuid,
+
+    /// Resource owning the interface
+    pub resource_id: Uuid,
+
+    /// Timestamp when command was issued
+    pub timestamp: DateTime<Utc>,
+
+    /// Correlation ID for distributed tracing
+    pub correlation_id: Uuid,
+
+    /// Optional causation ID (event that caused this command)
+    pub causation_id: Option<Uuid>,
+}
+
+/// Command to return an address to the pool
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ReleaseAddressCommand {
+    /// Address to release
+    pub address: IpAddr,
+
+    /// Timestamp when command was issued
+    pub timestamp: DateTime<Utc>,
+
+    /// Correlation ID for distributed tracing
+    pub correlation_id: Uuid,
+
+    /// Optional causation ID (event that caused this command)
+    pub causation_id: Option<Uuid>,
+}
+
+/// Any command accepted by the IpPool aggregate
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum IpPoolCommand {
+    DefinePool(DefinePoolCommand),
+    AllocateAddress(AllocateAddressCommand),
+    ReleaseAddress(ReleaseAddressCommand),
+}
+
+/// Handle DefinePool command
+///
+/// # Business Rules
+/// - Pool must not already be defined
+/// - Name must not be empty
+/// - Reserved ranges must be of the prefix's address family
+pub fn handle_define_pool(
+    state: &IpPoolState,
+    command: DefinePoolCommand,
+    aggregate_id: Uuid,
+) -> Result<PoolDefined, CommandError> {
+    if state.is_initialized() {
+        return Err(CommandError::AlreadyInitialized);
+    }
+
+    let name = command.name.trim();
+    if name.is_empty() {
+        return Err(CommandError::BusinessRuleViolation(
+            "Pool name must not be empty".to_string(),
+        ));
+    }
+
+    let cidr = command.cidr.network();
+    if let Some(range) = command
+        .reserved
+        .iter()
+        .find(|range| range.start.is_ipv4() != cidr.is_ipv4())
+    {
+        return Err(CommandError::BusinessRuleViolation(format!(
+            "Reserved range {}-{} is not in the address family of {}",
+            range.start, range.end, cidr
+        )));
+    }
+
+    Ok(PoolDefined {
+        event_version: 1,
+        event_id: Uuid::now_v7(),
+        aggregate_id,
+        timestamp: command.timestamp,
+        correlation_id: command.correlation_id,
+        causation_id: command.causation_id,
+        name: name.to_string(),
+        cidr,
+        vrf: command.vrf,
+        network_id: command.network_id,
+        reserved: command.reserved,
+    })
+}
+
+/// Handle AllocateAddress command
+///
+/// # Business Rules
+/// - Pool must be defined
+/// - A requested address must lie inside the prefix, outside reserved
+///   ranges, and not be the IPv4 network or broadcast address
+/// - An address is never allocated twice
+/// - Without a requested address the lowest free one is taken
+pub fn handle_allocate_address(
+    state: &IpPoolState,
+    command: AllocateAddressCommand,
+) -> Result<AddressAllocated, CommandError> {
+    let (Some(cidr), Some(network)) = (state.cidr.as_ref(), state.ipam_network()) else {
+        return Err(CommandError::NotInitialized);
+    };
+
+    let address = match command.address {
+        Some(address) => {
+            if !cidr.contains(&address) {
+                return Err(CommandError::BusinessRuleViolation(format!(
+                    "Address {} is outside pool {}",
+                    address, cidr
+                )));
+            }
+            check_not_reserved(&network, &address)
+                .map_err(|e| CommandError::BusinessRuleViolation(e.to_string()))?;
+            if !is_assignable(&network, &address) {
+                return Err(CommandError::BusinessRuleViolation(format!(
+                    "Address {} is the network or broadcast address of {}",
+                    address, cidr
+                )));
+            }
+            if let Some(holder) = state.allocation(&address) {
+                return Err(CommandError::BusinessRuleViolation(format!(
+                    "Address {} is already allocated to interface {}",
+                    address, holder.interface_id
+                )));
+            }
+            address
+        }
+        None => state.next_free().ok_or_else(|| {
+            CommandError::BusinessRuleViolation(format!("Pool {} has no free addresses", cidr))
+        })?,
+    };
+
+    let address = IpAddressWithCidr::from_parts(address, cidr.prefix_length())
+        .map_err(|e| CommandError::BusinessRuleViolation(e.to_string()))?;
+
+    Ok(AddressAllocated {
+        event_version: 1,
+        event_id: Uuid::now_v7(),
+        aggregate_id: state.id,
+        timestamp: command.timestamp,
+        correlation_id: command.correlation_id,
+        causation_id: command.causation_id,
+        address,
+        interface_id: command.interface_id,
+        resource_id: command.resource_id,
+    })
+}
+
+/// Handle ReleaseAddress command
+///
+/// # Business Rules
+/// - Pool must be defined
+/// - Address must currently be allocated
+pub fn handle_release_address(
+    state: &IpPoolState,
+    command: ReleaseAddressCommand,
+) -> Result<AddressReleased, CommandError> {
+    if !state.is_initialized() {
+        return Err(CommandError::NotInitialized);
+    }
+
+    let Some(allocation) = state.allocation(&command.address) else {
+        return Err(CommandError::BusinessRuleViolation(format!(
+            "Address {} is not allocated",
+            command.address
+        )));
+    };
+
+    Ok(AddressReleased {
+        event_version: 1,
+        event_id: Uuid::now_v7(),
+        aggregate_id: state.id,
+        timestamp: command.timestamp,
+        correlation_id: command.correlation_id,
+        causation_id: command.causation_id,
+        address: command.address,
+        interface_id: allocation.interface_id,
+    })
+}
+
+/// Dispatch any IpPoolCommand to its handler
+///
+/// `aggregate_id` is only used by DefinePool.
+pub fn handle_ip_pool_command(
+    state: &IpPoolState,
+    command: IpPoolCommand,
+    aggregate_id: Uuid,
+) -> Result<IpPoolEvent, CommandError> {
+    match command {
+        IpPoolCommand::DefinePool(cmd) => {
+            handle_define_pool(state, cmd, aggregate_id).map(IpPoolEvent::PoolDefined)
+        }
+        IpPoolCommand::AllocateAddress(cmd) => {
+            handle_allocate_address(state, cmd).map(IpPoolEvent::AddressAllocated)
+        }
+        IpPoolCommand::ReleaseAddress(cmd) => {
+            handle_release_address(state, cmd).map(IpPoolEvent::AddressReleased)
+        }
+    }
+}
+
+/// Apply event to state (pure function)
+pub fn apply_ip_pool_event(state: IpPoolState, event: &IpPoolEvent) -> IpPoolState {
+    match event {
+        IpPoolEvent::PoolDefined(e) => IpPoolState {
+            id: e.aggregate_id,
+            name: e.name.clone(),
+            cidr: Some(e.cidr.clone()),
+            vrf: e.vrf.clone(),
+            network_id: e.network_id,
+            reserved: e.reserved.clone(),
+            allocations: Vec::new(),
+            created_at: Some(e.timestamp),
+            updated_at: Some(e.timestamp),
+        },
+
+        IpPoolEvent::AddressAllocated(e) => {
+            let mut allocations = state.allocations;
+            allocations.push(PoolAllocation {
+                address: e.address.clone(),
+                interface_id: e.interface_id,
+                resource_id: e.resource_id,
+                allocated_at: e.timestamp,
+            });
+            IpPoolState {
+                allocations,
+                updated_at: Some(e.timestamp),
+                ..state
+            }
+        }
+
+        IpPoolEvent::AddressReleased(e) => IpPoolState {
+            allocations: state
+                .allocations
+                .into_iter()
+                .filter(|allocation| allocation.address.address() != e.address)
+                .collect(),
+            updated_at: Some(e.timestamp),
+            ..state
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ipam::ReservationPurpose;
+
+    fn test_timestamp() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-19T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn defined(cidr: &str) -> IpPoolState {
+        let id = Uuid::now_v7();
+        let event = handle_define_pool(
+            &IpPoolState::default_for(id),
+            DefinePoolCommand {
+                name: "servers".to_string(),
+                cidr: IpAddressWithCidr::new(cidr).unwrap(),
+                vrf: None,
+                network_id: None,
+                reserved: vec![ReservedRange::single(
+                    "10.0.1.1".parse().unwrap(),
+                    ReservationPurpose::Gateway,
+                )],
+                timestamp: test_timestamp(),
+                correlation_id: Uuid::now_v7(),
+                causation_id: None,
+            },
+            id,
+        )
+        .unwrap();
+        IpPoolState::from_events(&[IpPoolEvent::PoolDefined(event)])
+    }
+
+    fn allocate(address: Option<&str>) -> AllocateAddressCommand {
+        AllocateAddressCommand {
+            address: address.map(|a| a.parse().unwrap()),
+            interface_id: Uuid::now_v7(),
+            resource_id: Uuid::now_v7(),
+            timestamp: test_timestamp(),
+            correlation_id: Uuid::now_v7(),
+            causation_id: None,
+        }
+    }
+
+    fn apply_allocation(state: IpPoolState, command: AllocateAddressCommand) -> IpPoolState {
+        let event = handle_allocate_address(&state, command).unwrap();
+        apply_ip_pool_event(state, &IpPoolEvent::AddressAllocated(event))
+    }
+
+    #[test]
+    fn test_allocate_next_free_skips_reserved_and_allocated() {
+        // Arrange
+        let state = defined("10.0.1.0/24");
+        let state = apply_allocation(state, allocate(Some("10.0.1.2")));
+
+        // Act
+        let event = handle_allocate_address(&state, allocate(None)).unwrap();
+
+        // Assert - .0 is the network address, .1 the gateway, .2 taken
+        assert_eq!(event.address.to_string(), "10.0.1.3/24");
+    }
+
+    #[test]
+    fn test_double_allocation_is_rejected() {
+        // Arrange
+        let state = apply_allocation(defined("10.0.1.0/24"), allocate(Some("10.0.1.10")));
+
+        // Act
+        let result = handle_allocate_address(&state, allocate(Some("10.0.1.10")));
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(CommandError::BusinessRuleViolation(_))
+        ));
+    }
+
+    #[test]
+    fn test_addresses_outside_pool_or_reserved_are_rejected() {
+        let state = defined("10.0.1.0/24");
+
+        for address in ["10.0.2.5", "10.0.1.1", "10.0.1.0", "10.0.1.255"] {
+            let result = handle_allocate_address(&state, allocate(Some(address)));
+            assert!(result.is_err(), "{} was allocated", address);
+        }
+    }
+
+    #[test]
+    fn test_release_returns_address_to_pool() {
+        // Arrange
+        let state = apply_allocation(defined("10.0.1.0/30"), allocate(None));
+        assert_eq!(state.next_free(), None);
+
+        // Act
+        let event = handle_release_address(
+            &state,
+            ReleaseAddressCommand {
+                address: "10.0.1.2".parse().unwrap(),
+                timestamp: test_timestamp(),
+                correlation_id: Uuid::now_v7(),
+                causation_id: None,
+            },
+        )
+        .unwrap();
+        let state = apply_ip_pool_event(state, &IpPoolEvent::AddressReleased(event));
+
+        // Assert
+        assert!(state.allocations.is_empty());
+        assert_eq!(state.next_free(), Some("10.0.1.2".parse().unwrap()));
+        assert_eq!(state.free_count(), 1);
+    }
+}
@@ -96,11 +96,28 @@
 
 pub mod commands;
 pub mod compute_resource;
+pub mod connection;
 pub mod handlers;
+pub mod ip_pool;
+pub mod network;
+pub mod network_interface;
+pub mod queries;
 
 pub use commands::*;
 pub use compute_resource::{
     ComputeResourceState,
     apply_event,
 };
+pub use connection::{
+    apply_connection_event, handle_connection_command, ConnectionCommand, ConnectionState,
+    PortOccupancy,
+};
 pub use handlers::*;
+pub use ip_pool::{
+    apply_ip_pool_event, handle_ip_pool_command, IpPoolCommand, IpPoolState, PoolAllocation,
+};
+pub use network::{apply_network_event, handle_network_command, NetworkCommand, NetworkState};
+pub use network_interface::{
+    apply_network_interface_event, handle_attach_interface, AttachInterfaceCommand,
+    NetworkInterfaceState,
+};
@@ -0,0 +1,617 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Pure Functional Network Aggregate
+//!
+//! A network is an IP prefix, optionally carried on a VLAN. Interfaces of
+//! compute resources attach to it (see
+//! [`network_interface`](crate::aggregate::network_interface)).
+//!
+//! # Architecture
+//!
+//! ```text
+//! NetworkCommand → handle_network_command() → Result<NetworkEvent, CommandError>
+//!                                                   ↓
+//! NetworkEvents → apply_network_event() → NetworkState
+//! ```
+//!
+//! # Conflicts
+//!
+//! Defining, renumbering or re-tagging a network is checked against the
+//! other networks, passed in by the caller as `existing`:
+//!
+//! - Prefixes may not overlap (IPv4 and IPv6 are checked separately).
+//! - A VLAN carries at most one network per layer-2 segment; networks
+//!   without a segment share the default one.
+//!
+//! Violations are returned as [`NetworkValidationError`] inside
+//! [`CommandError::NetworkConflict`].
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::aggregate::network::*;
+//!
+//! let state = NetworkState::from_events(&events);
+//! let event = handle_change_cidr(&state, ChangeCidrCommand {
+//!     cidr: IpAddressWithCidr::new("10.20.0.0/16")?,
+//!     timestamp,
+//!     correlation_id,
+//!     causation_id: None,
+//! }, &other_networks)?;
+//! ```
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::aggregate::handlers::CommandError;
+use crate::domain::{IpAddressWithCidr, NetworkValidationError, VlanId};
+use crate::events::network::*;
+
+/// Immutable Network State
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct NetworkState {
+    /// Aggregate ID
+    pub id: Uuid,
+
+    /// Network name
+    pub name: String,
+
+    /// Network prefix (None until defined)
+    pub cidr: Option<IpAddressWithCidr>,
+
+    /// VLAN carrying the network
+    pub vlan_id: Option<VlanId>,
+
+    /// Layer-2 segment the VLAN lives on; None for the default segment
+    pub segment: Option<String>,
+
+    /// When this aggregate was created (first event timestamp)
+    pub created_at: Option<DateTime<Utc>>,
+
+    /// When this aggregate was last modified (latest event timestamp)
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl NetworkState {
+    /// Create default empty state
+    ///
+    /// Used as initial state for event folding.
+    pub fn default_for(id: Uuid) -> Self {
+        Self {
+            id,
+            name: String::new(),
+            cidr: None,
+            vlan_id: None,
+            segment: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    /// Reconstruct state from event stream
+    pub fn from_events(events: &[NetworkEvent]) -> Self {
+        let aggregate_id = events
+            .first()
+            .map(|e| e.aggregate_id())
+            .unwrap_or_else(Uuid::now_v7);
+
+        events
+            .iter()
+            .fold(Self::default_for(aggregate_id), apply_network_event)
+    }
+
+    /// Check if aggregate is initialized (has events)
+    pub fn is_initialized(&self) -> bool {
+        self.created_at.is_some()
+    }
+}
+
+/// Command to define a new network
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct DefineNetworkCommand {
+    /// Network name
+    pub name: String,
+
+    /// Network prefix; host bits are cleared
+    pub cidr: IpAddressWithCidr,
+
+    /// VLAN carrying the network, if any
+    pub vlan_id: Option<VlanId>,
+
+    /// Layer-2 segment the VLAN lives on; None for the default segment
+    #[serde(default)]
+    pub segment: Option<String>,
+
+    /// Timestamp when command was issued
+    pub timestamp: DateTime<Utc>,
+
+    /// Correlation ID for distributed tracing
+    pub correlation_id: Uuid,
+
+    /// Optional causation ID (event that caused this command)
+    pub causation_id: Option<Uuid>,
+}
+
+/// Command to renumber a network
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ChangeCidrCommand {
+    /// New prefix; host bits are cleared
+    pub cidr: IpAddressWithCidr,
+
+    /// Timestamp when command was issued
+    pub timestamp: DateTime<Utc>,
+
+    /// Correlation ID for distributed tracing
+    pub correlation_id: Uuid,
+
+    /// Optional causation ID (event that caused this command)
+    pub causation_id: Option<Uuid>,
+}
+
+/// Command to bind a network to a VLAN
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct AssignVlanCommand {
+    /// VLAN to carry the network
+    pub vlan_id: VlanId,
+
+    /// Timestamp when command was issued
+    pub timestamp: DateTime<Utc>,
+
+    /// Correlation ID for distributed tracing
+    pub correlation_id: Uuid,
+
+    /// Optional causation ID (event that caused this command)
+    pub causation_id: Option<Uuid>,
+}
+
+/// Any command accepted by the Network aggregate
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum NetworkCommand {
+    DefineNetwork(DefineNetworkCommand),
+    ChangeCidr(ChangeCidrCommand),
+    AssignVlan(AssignVlanCommand),
+}
+
+/// Other defined networks in `existing`
+///
+/// Skips the network's own (possibly stale) entry and undefined networks.
+fn other_networks(
+    network_id: Uuid,
+    existing: &[NetworkState],
+) -> impl Iterator<Item = &NetworkState> {
+    existing
+        .iter()
+        .filter(move |other| other.id != network_id && other.is_initialized())
+}
+
+/// Check that a prefix overlaps none of the other networks
+pub fn check_cidr_overlap(
+    network_id: Uuid,
+    cidr: &IpAddressWithCidr,
+    existing: &[NetworkState],
+) -> Result<(), NetworkValidationError> {
+    for other in other_networks(network_id, existing) {
+        if let Some(other_cidr) = other.cidr.as_ref().filter(|c| cidr.overlaps(c)) {
+            return Err(NetworkValidationError::OverlappingCidr {
+                cidr: cidr.clone(),
+                existing: other.id,
+                existing_cidr: other_cidr.clone(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that a VLAN carries no other network on the same segment
+pub fn check_vlan_free(
+    network_id: Uuid,
+    vlan_id: VlanId,
+    segment: Option<&str>,
+    existing: &[NetworkState],
+) -> Result<(), NetworkValidationError> {
+    let in_use = other_networks(network_id, existing)
+        .find(|other| other.vlan_id == Some(vlan_id) && other.segment.as_deref() == segment);
+
+    match in_use {
+        Some(other) => Err(NetworkValidationError::VlanInUse {
+            vlan_id,
+            segment: segment.map(str::to_string),
+            existing: other.id,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Handle DefineNetwork command
+///
+/// # Business Rules
+/// - Network must not already be defined
+/// - Name must not be empty
+/// - Prefix and VLAN must not conflict with `existing` networks
+pub fn handle_define_network(
+    state: &NetworkState,
+    command: DefineNetworkCommand,
+    aggregate_id: Uuid,
+    existing: &[NetworkState],
+) -> Result<NetworkDefined, CommandError> {
+    if state.is_initialized() {
+        return Err(CommandError::AlreadyInitialized);
+    }
+
+    let name = command.name.trim();
+    if name.is_empty() {
+        return Err(CommandError::BusinessRuleViolation(
+            "Network name must not be empty".to_string(),
+        ));
+    }
+
+    let cidr = command.cidr.network();
+    let segment = command
+        .segment
+        .map(|segment| segment.trim().to_string())
+        .filter(|segment| !segment.is_empty());
+    check_cidr_overlap(aggregate_id, &cidr, existing)?;
+    if let Some(vlan_id) = command.vlan_id {
+        check_vlan_free(aggregate_id, vlan_id, segment.as_deref(), existing)?;
+    }
+
+    Ok(NetworkDefined {
+        event_version: 1,
+        event_id: Uuid::now_v7(),
+        aggregate_id,
+        timestamp: command.timestamp,
+        correlation_id: command.correlation_id,
+        causation_id: command.causation_id,
+        name: name.to_string(),
+        cidr,
+        vlan_id: command.vlan_id,
+        segment,
+    })
+}
+
+/// Handle ChangeCidr command
+///
+/// # Business Rules
+/// - Network must be defined
+/// - New prefix must differ from the current one
+/// - New prefix must not overlap `existing` networks
+pub fn handle_change_cidr(
+    state: &NetworkState,
+    command: ChangeCidrCommand,
+    existing: &[NetworkState],
+) -> Result<CidrChanged, CommandError> {
+    let Some(previous) = state.cidr.clone() else {
+        return Err(CommandError::NotInitialized);
+    };
+
+    let cidr = command.cidr.network();
+    if cidr == previous {
+        return Err(CommandError::BusinessRuleViolation(format!(
+            "Network already uses {}",
+            cidr
+        )));
+    }
+
+    check_cidr_overlap(state.id, &cidr, existing)?;
+
+    Ok(CidrChanged {
+        event_version: 1,
+        event_id: Uuid::now_v7(),
+        aggregate_id: state.id,
+        timestamp: command.timestamp,
+        correlation_id: command.correlation_id,
+        causation_id: command.causation_id,
+        previous,
+        cidr,
+    })
+}
+
+/// Handle AssignVlan command
+///
+/// # Business Rules
+/// - Network must be defined
+/// - VLAN must differ from the current one
+/// - VLAN must not carry another network on the same segment
+pub fn handle_assign_vlan(
+    state: &NetworkState,
+    command: AssignVlanCommand,
+    existing: &[NetworkState],
+) -> Result<VlanAssigned, CommandError> {
+    if !state.is_initialized() {
+        return Err(CommandError::NotInitialized);
+    }
+
+    if state.vlan_id == Some(command.vlan_id) {
+        return Err(CommandError::BusinessRuleViolation(format!(
+            "Network already on VLAN {}",
+            command.vlan_id
+        )));
+    }
+
+    check_vlan_free(
+        state.id,
+        command.vlan_id,
+        state.segment.as_deref(),
+        existing,
+    )?;
+
+    Ok(VlanAssigned {
+        event_version: 1,
+        event_id: Uuid::now_v7(),
+        aggregate_id: state.id,
+        timestamp: command.timestamp,
+        correlation_id: command.correlation_id,
+        causation_id: command.causation_id,
+        vlan_id: command.vlan_id,
+    })
+}
+
+/// Dispatch any NetworkCommand to its handler
+///
+/// `aggregate_id` is only used by DefineNetwork; `existing` are the other
+/// networks conflicts are checked against.
+pub fn handle_network_command(
+    state: &NetworkState,
+    command: NetworkCommand,
+    aggregate_id: Uuid,
+    existing: &[NetworkState],
+) -> Result<NetworkEvent, CommandError> {
+    match command {
+        NetworkCommand::DefineNetwork(cmd) => {
+            handle_define_network(state, cmd, aggregate_id, existing)
+                .map(NetworkEvent::NetworkDefined)
+        }
+        NetworkCommand::ChangeCidr(cmd) => {
+            handle_change_cidr(state, cmd, existing).map(NetworkEvent::CidrChanged)
+        }
+        NetworkCommand::AssignVlan(cmd) => {
+            handle_assign_vlan(state, cmd, existing).map(NetworkEvent::VlanAssigned)
+        }
+    }
+}
+
+/// Apply event to state (pure function)
+pub fn apply_network_event(state: NetworkState, event: &NetworkEvent) -> NetworkState {
+    match event {
+        NetworkEvent::NetworkDefined(e) => NetworkState {
+            id: e.aggregate_id,
+            name: e.name.clone(),
+            cidr: Some(e.cidr.clone()),
+            vlan_id: e.vlan_id,
+            segment: e.segment.clone(),
+            created_at: Some(e.timestamp),
+            updated_at: Some(e.timestamp),
+        },
+
+        NetworkEvent::CidrChanged(e) => NetworkState {
+            cidr: Some(e.cidr.clone()),
+            updated_at: Some(e.timestamp),
+            ..state
+        },
+
+        NetworkEvent::VlanAssigned(e) => NetworkState {
+            vlan_id: Some(e.vlan_id),
+            updated_at: Some(e.timestamp),
+            ..state
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_timestamp() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-19T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn test_aggregate_id() -> Uuid {
+        Uuid::parse_str("01934f4a-1000-7000-8000-000000002000").unwrap()
+    }
+
+    fn define_command(cidr: &str, vlan_id: Option<u16>) -> DefineNetworkCommand {
+        DefineNetworkCommand {
+            name: "servers".to_string(),
+            cidr: IpAddressWithCidr::new(cidr).unwrap(),
+            vlan_id: vlan_id.map(|id| VlanId::new(id).unwrap()),
+            segment: None,
+            timestamp: test_timestamp(),
+            correlation_id: Uuid::now_v7(),
+            causation_id: None,
+        }
+    }
+
+    fn network(aggregate_id: Uuid, command: DefineNetworkCommand) -> NetworkState {
+        let event = handle_define_network(
+            &NetworkState::default_for(aggregate_id),
+            command,
+            aggregate_id,
+            &[],
+        )
+        .unwrap();
+        NetworkState::from_events(&[NetworkEvent::NetworkDefined(event)])
+    }
+
+    fn defined() -> NetworkState {
+        network(test_aggregate_id(), define_command("10.0.1.17/24", None))
+    }
+
+    #[test]
+    fn test_define_network_normalizes_prefix() {
+        // Act
+        let state = defined();
+
+        // Assert
+        assert!(state.is_initialized());
+        assert_eq!(state.name, "servers");
+        assert_eq!(state.cidr.unwrap().to_string(), "10.0.1.0/24");
+    }
+
+    #[test]
+    fn test_define_network_twice_is_rejected() {
+        // Arrange
+        let state = defined();
+        let command = define_command("10.0.2.0/24", None);
+
+        // Act
+        let result = handle_define_network(&state, command, test_aggregate_id(), &[]);
+
+        // Assert
+        assert_eq!(result, Err(CommandError::AlreadyInitialized));
+    }
+
+    #[test]
+    fn test_change_cidr_records_previous_prefix() {
+        // Arrange
+        let state = defined();
+        let command = ChangeCidrCommand {
+            cidr: IpAddressWithCidr::new("10.0.0.0/23").unwrap(),
+            timestamp: test_timestamp(),
+            correlation_id: Uuid::now_v7(),
+            causation_id: None,
+        };
+
+        // Act
+        let event = handle_change_cidr(&state, command, &[]).unwrap();
+        let state = apply_network_event(state, &NetworkEvent::CidrChanged(event.clone()));
+
+        // Assert
+        assert_eq!(event.previous.to_string(), "10.0.1.0/24");
+        assert_eq!(state.cidr, Some(event.cidr));
+    }
+
+    #[test]
+    fn test_assign_same_vlan_is_rejected() {
+        // Arrange
+        let vlan = VlanId::new(100).unwrap();
+        let command = |vlan_id| AssignVlanCommand {
+            vlan_id,
+            timestamp: test_timestamp(),
+            correlation_id: Uuid::now_v7(),
+            causation_id: None,
+        };
+        let event = handle_assign_vlan(&defined(), command(vlan), &[]).unwrap();
+        let state = apply_network_event(defined(), &NetworkEvent::VlanAssigned(event));
+
+        // Act
+        let result = handle_assign_vlan(&state, command(vlan), &[]);
+
+        // Assert
+        assert_eq!(state.vlan_id, Some(vlan));
+        assert!(matches!(
+            result,
+            Err(CommandError::BusinessRuleViolation(_))
+        ));
+    }
+
+    #[test]
+    fn test_commands_require_defined_network() {
+        let state = NetworkState::default_for(test_aggregate_id());
+        let command = ChangeCidrCommand {
+            cidr: IpAddressWithCidr::new("10.0.0.0/16").unwrap(),
+            timestamp: test_timestamp(),
+            correlation_id: Uuid::now_v7(),
+            causation_id: None,
+        };
+
+        assert_eq!(
+            handle_change_cidr(&state, command, &[]),
+            Err(CommandError::NotInitialized)
+        );
+    }
+
+    #[test]
+    fn test_overlapping_prefix_is_rejected() {
+        // Arrange - 10.0.1.0/24 already exists
+        let existing = vec![defined()];
+        let new_id = Uuid::now_v7();
+
+        // Act
+        let overlapping = handle_define_network(
+            &NetworkState::default_for(new_id),
+            define_command("10.0.0.0/16", None),
+            new_id,
+            &existing,
+        );
+        let disjoint = handle_define_network(
+            &NetworkState::default_for(new_id),
+            define_command("10.0.2.0/24", None),
+            new_id,
+            &existing,
+        );
+        let renumbered = handle_change_cidr(
+            &existing[0],
+            ChangeCidrCommand {
+                cidr: IpAddressWithCidr::new("10.0.0.0/23").unwrap(),
+                timestamp: test_timestamp(),
+                correlation_id: Uuid::now_v7(),
+                causation_id: None,
+            },
+            &existing,
+        );
+
+        // Assert - a network never conflicts with its own entry
+        assert!(matches!(
+            overlapping,
+            Err(CommandError::NetworkConflict(
+                NetworkValidationError::OverlappingCidr { existing, .. }
+            )) if existing == test_aggregate_id()
+        ));
+        assert!(disjoint.is_ok());
+        assert!(renumbered.is_ok());
+    }
+
+    #[test]
+    fn test_vlan_reuse_is_scoped_to_segment() {
+        // Arrange - VLAN 100 carries 10.0.1.0/24 on the default segment
+        let existing = vec![network(
+            test_aggregate_id(),
+            define_command("10.0.1.0/24", Some(100)),
+        )];
+        let new_id = Uuid::now_v7();
+        let on_segment = |segment: Option<&str>| DefineNetworkCommand {
+            segment: segment.map(str::to_string),
+            ..define_command("10.0.2.0/24", Some(100))
+        };
+
+        // Act
+        let same_segment = handle_define_network(
+            &NetworkState::default_for(new_id),
+            on_segment(None),
+            new_id,
+            &existing,
+        );
+        let other_segment = handle_define_network(
+            &NetworkState::default_for(new_id),
+            on_segment(Some("dc2-fabric")),
+            new_id,
+            &existing,
+        );
+
+        // Assert
+        assert!(matches!(
+            same_segment,
+            Err(CommandError::NetworkConflict(
+                NetworkValidationError::VlanInUse { .. }
+            ))
+        ));
+        assert_eq!(
+            other_segment.unwrap().segment.as_deref(),
+            Some("dc2-fabric")
+        );
+    }
+}
@@ -0,0 +1,683 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Pure Functional NetworkInterface Aggregate
+//!
+//! An interface of a compute resource, attached to one
+//! [`network`](crate::aggregate::network) at a time. Attaching validates the
+//! interface addresses against the network's current prefix, so the handler
+//! takes the network state as an explicit input.
+//!
+//! A bond (LAG) is an interface aggregate of its own, formed from member
+//! interfaces of the same resource. Forming one validates the members, so
+//! the handler takes their states and the resource's existing bonds.
+//!
+//! # Architecture
+//!
+//! ```text
+//! (NetworkInterfaceState, NetworkState, AttachInterfaceCommand)
+//!     → handle_attach_interface() → Result<InterfaceAttached, CommandError>
+//! (NetworkInterfaceState, members, bonds, FormBondCommand)
+//!     → handle_form_bond() → Result<BondFormed, CommandError>
+//!                                         ↓
+//! NetworkInterfaceEvents → apply_network_interface_event() → NetworkInterfaceState
+//! ```
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::aggregate::handlers::CommandError;
+use crate::aggregate::network::NetworkState;
+use crate::domain::{BondMode, IpAddressWithCidr, MacAddress};
+use crate::events::network_interface::*;
+
+/// Immutable NetworkInterface State
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct NetworkInterfaceState {
+    /// Aggregate ID
+    pub id: Uuid,
+
+    /// Compute resource owning the interface
+    pub resource_id: Option<Uuid>,
+
+    /// Network the interface is attached to
+    pub network_id: Option<Uuid>,
+
+    /// Interface name on the resource
+    pub name: String,
+
+    /// Hardware address
+    pub mac_address: Option<MacAddress>,
+
+    /// Addresses assigned on the network
+    pub addresses: Vec<IpAddressWithCidr>,
+
+    /// Link speed in Mbit/s, if known
+    pub speed_mbps: Option<u32>,
+
+    /// Aggregation of a bonded interface (None for plain and dissolved
+    /// interfaces)
+    pub bond: Option<Bond>,
+
+    /// When this aggregate was created (first event timestamp)
+    pub created_at: Option<DateTime<Utc>>,
+
+    /// When this aggregate was last modified (latest event timestamp)
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl NetworkInterfaceState {
+    /// Create default empty state
+    ///
+    /// Used as initial state for event folding.
+    pub fn default_for(id: Uuid) -> Self {
+        Self {
+            id,
+            resource_id: None,
+            network_id: None,
+            name: String::new(),
+            mac_address: None,
+            addresses: Vec::new(),
+            speed_mbps: None,
+            bond: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    /// Reconstruct state from event stream
+    pub fn from_events(events: &[NetworkInterfaceEvent]) -> Self {
+        let aggregate_id = events
+            .first()
+            .map(|e| e.aggregate_id())
+            .unwrap_or_else(Uuid::now_v7);
+
+        events.iter().fold(
+            Self::default_for(aggregate_id),
+            apply_network_interface_event,
+        )
+    }
+
+    /// Check if aggregate is initialized (has events)
+    pub fn is_initialized(&self) -> bool {
+        self.created_at.is_some()
+    }
+
+    /// Whether this is an active bond
+    pub fn is_bond(&self) -> bool {
+        self.bond.is_some()
+    }
+}
+
+/// Aggregation of a bonded interface
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct Bond {
+    /// Aggregation mode
+    pub mode: BondMode,
+
+    /// Member interfaces
+    pub members: Vec<BondMember>,
+}
+
+impl Bond {
+    /// Whether `interface_id` is a member
+    pub fn contains(&self, interface_id: Uuid) -> bool {
+        self.members.iter().any(|m| m.interface_id == interface_id)
+    }
+}
+
+/// Command to attach an interface to a network
+///
+/// Creates the interface on first use; re-attaching moves it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct AttachInterfaceCommand {
+    /// Compute resource owning the interface
+    pub resource_id: Uuid,
+
+    /// Network to attach to
+    pub network_id: Uuid,
+
+    /// Interface name on the resource (e.g. `eth0`)
+    pub name: String,
+
+    /// Hardware address
+    pub mac_address: Option<MacAddress>,
+
+    /// Addresses to assign on the network
+    pub addresses: Vec<IpAddressWithCidr>,
+
+    /// Link speed in Mbit/s, if known
+    #[serde(default)]
+    pub speed_mbps: Option<u32>,
+
+    /// Timestamp when command was issued
+    pub timestamp: DateTime<Utc>,
+
+    /// Correlation ID for distributed tracing
+    pub correlation_id: Uuid,
+
+    /// Optional causation ID (event that caused this command)
+    pub causation_id: Option<Uuid>,
+}
+
+/// Handle AttachInterface command
+///
+/// # Business Rules
+/// - Target network must be defined and match `command.network_id`
+/// - Name must not be empty
+/// - Every address must fall inside the network prefix
+/// - An existing interface stays with its resource and must move to a
+///   different network
+pub fn handle_attach_interface(
+    state: &NetworkInterfaceState,
+    command: AttachInterfaceCommand,
+    aggregate_id: Uuid,
+    network: &NetworkState,
+) -> Result<InterfaceAttached, CommandError> {
+    let Some(cidr) = network
+        .cidr
+        .as_ref()
+        .filter(|_| network.id == command.network_id)
+    else {
+        return Err(CommandError::BusinessRuleViolation(format!(
+            "Network {} is not defined",
+            command.network_id
+        )));
+    };
+
+    let name = command.name.trim();
+    if name.is_empty() {
+        return Err(CommandError::BusinessRuleViolation(
+            "Interface name must not be empty".to_string(),
+        ));
+    }
+
+    if let Some(outside) = command
+        .addresses
+        .iter()
+        .find(|address| !cidr.contains(&address.address()))
+    {
+        return Err(CommandError::BusinessRuleViolation(format!(
+            "Address {} is outside network {}",
+            outside, cidr
+        )));
+    }
+
+    if state.is_initialized() {
+        if state.resource_id != Some(command.resource_id) {
+            return Err(CommandError::BusinessRuleViolation(
+                "Interface belongs to another resource".to_string(),
+            ));
+        }
+        if state.network_id == Some(command.network_id) {
+            return Err(CommandError::BusinessRuleViolation(format!(
+                "Interface already attached to network {}",
+                command.network_id
+            )));
+        }
+    }
+
+    Ok(InterfaceAttached {
+        event_version: 1,
+        event_id: Uuid::now_v7(),
+        aggregate_id: if state.is_initialized() {
+            state.id
+        } else {
+            aggregate_id
+        },
+        timestamp: command.timestamp,
+        correlation_id: command.correlation_id,
+        causation_id: command.causation_id,
+        resource_id: command.resource_id,
+        network_id: command.network_id,
+        name: name.to_string(),
+        mac_address: command.mac_address,
+        addresses: command.addresses,
+        speed_mbps: command.speed_mbps,
+    })
+}
+
+/// Command to form a bond from member interfaces
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct FormBondCommand {
+    /// Compute resource owning the bond and its members
+    pub resource_id: Uuid,
+
+    /// Bond name on the resource (e.g. `bond0`)
+    pub name: String,
+
+    /// Aggregation mode
+    pub mode: BondMode,
+
+    /// Member interface aggregates
+    pub members: Vec<Uuid>,
+
+    /// Timestamp when command was issued
+    pub timestamp: DateTime<Utc>,
+
+    /// Correlation ID for distributed tracing
+    pub correlation_id: Uuid,
+
+    /// Optional causation ID (event that caused this command)
+    pub causation_id: Option<Uuid>,
+}
+
+/// Handle FormBond command
+///
+/// `members` holds the current state of the member interfaces, `bonds` the
+/// other interfaces of the resource that may be bonds.
+///
+/// # Business Rules
+/// - Bond must not exist yet and its name must not be empty
+/// - At least two distinct members, each an existing interface of
+///   `command.resource_id` that is neither a bond nor a member of one
+/// - Members with a known speed must all run at the same speed
+pub fn handle_form_bond(
+    state: &NetworkInterfaceState,
+    command: FormBondCommand,
+    aggregate_id: Uuid,
+    members: &[NetworkInterfaceState],
+    bonds: &[NetworkInterfaceState],
+) -> Result<BondFormed, CommandError> {
+    if state.is_initialized() {
+        return Err(CommandError::AlreadyInitialized);
+    }
+
+    let name = command.name.trim();
+    if name.is_empty() {
+        return Err(CommandError::BusinessRuleViolation(
+            "Bond name must not be empty".to_string(),
+        ));
+    }
+
+    let mut member_ids = command.members.clone();
+    member_ids.sort();
+    member_ids.dedup();
+    if member_ids.len() != command.members.len() {
+        return Err(CommandError::BusinessRuleViolation(
+            "Bond members must be distinct".to_string(),
+        ));
+    }
+    if command.members.len() < 2 {
+        return Err(CommandError::BusinessRuleViolation(
+            "A bond needs at least two members".to_string(),
+        ));
+    }
+
+    let mut bonded = Vec::with_capacity(command.members.len());
+    let mut speed_mbps = None;
+    for member_id in &command.members {
+        let member = members
+            .iter()
+            .find(|m| m.id == *member_id && m.is_initialized())
+            .ok_or_else(|| {
+                CommandError::BusinessRuleViolation(format!(
+                    "Interface {} is not defined",
+                    member_id
+                ))
+            })?;
+
+        if member.resource_id != Some(command.resource_id) {
+            return Err(CommandError::BusinessRuleViolation(format!(
+                "Interface {} belongs to another resource",
+                member.name
+            )));
+        }
+        if member.is_bond() {
+            return Err(CommandError::BusinessRuleViolation(format!(
+                "Interface {} is itself a bond",
+                member.name
+            )));
+        }
+        if let Some(other) = bonds
+            .iter()
+            .filter(|b| b.id != state.id)
+            .find(|b| b.bond.as_ref().is_some_and(|bond| bond.contains(member.id)))
+        {
+            return Err(CommandError::BusinessRuleViolation(format!(
+                "Interface {} is already a member of {}",
+                member.name, other.name
+            )));
+        }
+
+        if let Some(speed) = member.speed_mbps {
+            match speed_mbps {
+                Some(common) if common != speed => {
+                    return Err(CommandError::BusinessRuleViolation(format!(
+                        "Interface {} runs at {} Mbit/s, other members at {} Mbit/s",
+                        member.name, speed, common
+                    )));
+                }
+                _ => speed_mbps = Some(speed),
+            }
+        }
+
+        bonded.push(BondMember {
+            interface_id: member.id,
+            name: member.name.clone(),
+        });
+    }
+
+    Ok(BondFormed {
+        event_version: 1,
+        event_id: Uuid::now_v7(),
+        aggregate_id,
+        timestamp: command.timestamp,
+        correlation_id: command.correlation_id,
+        causation_id: command.causation_id,
+        resource_id: command.resource_id,
+        name: name.to_string(),
+        mode: command.mode,
+        members: bonded,
+        speed_mbps,
+    })
+}
+
+/// Command to dissolve a bond
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct DissolveBondCommand {
+    /// Timestamp when command was issued
+    pub timestamp: DateTime<Utc>,
+
+    /// Correlation ID for distributed tracing
+    pub correlation_id: Uuid,
+
+    /// Optional causation ID (event that caused this command)
+    pub causation_id: Option<Uuid>,
+}
+
+/// Handle DissolveBond command
+///
+/// # Business Rules
+/// - Interface must be an active bond
+pub fn handle_dissolve_bond(
+    state: &NetworkInterfaceState,
+    command: DissolveBondCommand,
+) -> Result<BondDissolved, CommandError> {
+    let (true, Some(resource_id)) = (state.is_bond(), state.resource_id) else {
+        return Err(CommandError::BusinessRuleViolation(format!(
+            "Interface {} is not a bond",
+            state.id
+        )));
+    };
+
+    Ok(BondDissolved {
+        event_version: 1,
+        event_id: Uuid::now_v7(),
+        aggregate_id: state.id,
+        timestamp: command.timestamp,
+        correlation_id: command.correlation_id,
+        causation_id: command.causation_id,
+        resource_id,
+        name: state.name.clone(),
+    })
+}
+
+/// Apply event to state (pure function)
+pub fn apply_network_interface_event(
+    state: NetworkInterfaceState,
+    event: &NetworkInterfaceEvent,
+) -> NetworkInterfaceState {
+    match event {
+        NetworkInterfaceEvent::InterfaceAttached(e) => NetworkInterfaceState {
+            id: e.aggregate_id,
+            resource_id: Some(e.resource_id),
+            network_id: Some(e.network_id),
+            name: e.name.clone(),
+            mac_address: e.mac_address.clone(),
+            addresses: e.addresses.clone(),
+            speed_mbps: e.speed_mbps.or(state.speed_mbps),
+            bond: state.bond,
+            created_at: state.created_at.or(Some(e.timestamp)),
+            updated_at: Some(e.timestamp),
+        },
+        NetworkInterfaceEvent::BondFormed(e) => NetworkInterfaceState {
+            id: e.aggregate_id,
+            resource_id: Some(e.resource_id),
+            name: e.name.clone(),
+            speed_mbps: e.speed_mbps,
+            bond: Some(Bond {
+                mode: e.mode,
+                members: e.members.clone(),
+            }),
+            created_at: state.created_at.or(Some(e.timestamp)),
+            updated_at: Some(e.timestamp),
+            ..state
+        },
+        NetworkInterfaceEvent::BondDissolved(e) => NetworkInterfaceState {
+            bond: None,
+            updated_at: Some(e.timestamp),
+            ..state
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aggregate::network::{
+        apply_network_event, handle_define_network, DefineNetworkCommand,
+    };
+    use crate::events::network::NetworkEvent;
+
+    fn test_timestamp() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-19T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn network(cidr: &str) -> NetworkState {
+        let id = Uuid::now_v7();
+        let state = NetworkState::default_for(id);
+        let event = handle_define_network(
+            &state,
+            DefineNetworkCommand {
+                name: cidr.to_string(),
+                cidr: IpAddressWithCidr::new(cidr).unwrap(),
+                vlan_id: None,
+                segment: None,
+                timestamp: test_timestamp(),
+                correlation_id: Uuid::now_v7(),
+                causation_id: None,
+            },
+            id,
+            &[],
+        )
+        .unwrap();
+        apply_network_event(state, &NetworkEvent::NetworkDefined(event))
+    }
+
+    fn attach(resource_id: Uuid, network: &NetworkState, address: &str) -> AttachInterfaceCommand {
+        AttachInterfaceCommand {
+            resource_id,
+            network_id: network.id,
+            name: "eth0".to_string(),
+            mac_address: None,
+            addresses: vec![IpAddressWithCidr::new(address).unwrap()],
+            speed_mbps: None,
+            timestamp: test_timestamp(),
+            correlation_id: Uuid::now_v7(),
+            causation_id: None,
+        }
+    }
+
+    #[test]
+    fn test_attach_then_move_to_another_network() {
+        // Arrange
+        let resource_id = Uuid::now_v7();
+        let interface_id = Uuid::now_v7();
+        let servers = network("10.0.1.0/24");
+        let storage = network("10.0.2.0/24");
+        let state = NetworkInterfaceState::default_for(interface_id);
+
+        // Act
+        let first = handle_attach_interface(
+            &state,
+            attach(resource_id, &servers, "10.0.1.10/24"),
+            interface_id,
+            &servers,
+        )
+        .unwrap();
+        let state =
+            apply_network_interface_event(state, &NetworkInterfaceEvent::InterfaceAttached(first));
+        let moved = handle_attach_interface(
+            &state,
+            attach(resource_id, &storage, "10.0.2.10/24"),
+            Uuid::now_v7(),
+            &storage,
+        )
+        .unwrap();
+        let state =
+            apply_network_interface_event(state, &NetworkInterfaceEvent::InterfaceAttached(moved));
+
+        // Assert
+        assert_eq!(state.id, interface_id);
+        assert_eq!(state.network_id, Some(storage.id));
+        assert_eq!(state.addresses[0].to_string(), "10.0.2.10/24");
+    }
+
+    #[test]
+    fn test_address_outside_network_is_rejected() {
+        let servers = network("10.0.1.0/24");
+        let state = NetworkInterfaceState::default_for(Uuid::now_v7());
+
+        let result = handle_attach_interface(
+            &state,
+            attach(Uuid::now_v7(), &servers, "10.0.9.10/24"),
+            state.id,
+            &servers,
+        );
+
+        assert!(matches!(
+            result,
+            Err(CommandError::BusinessRuleViolation(_))
+        ));
+    }
+
+    #[test]
+    fn test_undefined_network_is_rejected() {
+        let undefined = NetworkState::default_for(Uuid::now_v7());
+        let state = NetworkInterfaceState::default_for(Uuid::now_v7());
+
+        let result = handle_attach_interface(
+            &state,
+            attach(Uuid::now_v7(), &undefined, "10.0.1.10/24"),
+            state.id,
+            &undefined,
+        );
+
+        assert!(result.is_err());
+    }
+
+    fn member(resource_id: Uuid, name: &str, speed_mbps: Option<u32>) -> NetworkInterfaceState {
+        let mut state = NetworkInterfaceState::default_for(Uuid::now_v7());
+        state.resource_id = Some(resource_id);
+        state.name = name.to_string();
+        state.speed_mbps = speed_mbps;
+        state.created_at = Some(test_timestamp());
+        state
+    }
+
+    fn form(resource_id: Uuid, members: &[&NetworkInterfaceState]) -> FormBondCommand {
+        FormBondCommand {
+            resource_id,
+            name: "bond0".to_string(),
+            mode: BondMode::Lacp,
+            members: members.iter().map(|m| m.id).collect(),
+            timestamp: test_timestamp(),
+            correlation_id: Uuid::now_v7(),
+            causation_id: None,
+        }
+    }
+
+    #[test]
+    fn test_form_and_dissolve_bond() {
+        // Arrange
+        let resource_id = Uuid::now_v7();
+        let eno1 = member(resource_id, "eno1", Some(25_000));
+        let eno2 = member(resource_id, "eno2", None);
+        let state = NetworkInterfaceState::default_for(Uuid::now_v7());
+        let members = [eno1.clone(), eno2.clone()];
+
+        // Act
+        let formed = handle_form_bond(
+            &state,
+            form(resource_id, &[&eno1, &eno2]),
+            state.id,
+            &members,
+            &[],
+        )
+        .unwrap();
+        let bond = apply_network_interface_event(
+            state,
+            &NetworkInterfaceEvent::BondFormed(formed.clone()),
+        );
+        let dissolved = handle_dissolve_bond(
+            &bond,
+            DissolveBondCommand {
+                timestamp: test_timestamp(),
+                correlation_id: Uuid::now_v7(),
+                causation_id: None,
+            },
+        )
+        .unwrap();
+        let released = apply_network_interface_event(
+            bond.clone(),
+            &NetworkInterfaceEvent::BondDissolved(dissolved),
+        );
+
+        // Assert
+        assert_eq!(formed.speed_mbps, Some(25_000));
+        assert_eq!(formed.members[1].name, "eno2");
+        assert!(bond.bond.as_ref().unwrap().contains(eno1.id));
+        assert!(!released.is_bond());
+        assert_eq!(released.name, "bond0");
+    }
+
+    #[test]
+    fn test_invalid_bond_members_are_rejected() {
+        let resource_id = Uuid::now_v7();
+        let eno1 = member(resource_id, "eno1", Some(25_000));
+        let eno2 = member(resource_id, "eno2", Some(10_000));
+        let foreign = member(Uuid::now_v7(), "eno1", Some(25_000));
+        let eno3 = member(resource_id, "eno3", Some(25_000));
+        let mut existing = member(resource_id, "bond1", None);
+        existing.bond = Some(Bond {
+            mode: BondMode::ActiveBackup,
+            members: vec![BondMember {
+                interface_id: eno3.id,
+                name: "eno3".to_string(),
+            }],
+        });
+        let all = [eno1.clone(), eno2.clone(), foreign.clone(), eno3.clone()];
+        let state = NetworkInterfaceState::default_for(Uuid::now_v7());
+
+        let mismatched = form(resource_id, &[&eno1, &eno2]);
+        let cross_resource = form(resource_id, &[&eno1, &foreign]);
+        let taken = form(resource_id, &[&eno1, &eno3]);
+        let single = form(resource_id, &[&eno1]);
+
+        for command in [mismatched, cross_resource, taken, single] {
+            let result = handle_form_bond(
+                &state,
+                command,
+                state.id,
+                &all,
+                std::slice::from_ref(&existing),
+            );
+            assert!(matches!(
+                result,
+                Err(CommandError::BusinessRuleViolation(_))
+            ));
+        }
+    }
+}
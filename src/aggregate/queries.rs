@@ -0,0 +1,115 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Pure Queries over ComputeResource State
+//!
+//! Read-side helpers that answer questions across many reconstructed
+//! aggregates. Like the handlers, they are pure: the current time and any
+//! thresholds are passed in explicitly.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::aggregate::queries::resources_lacking_recent_backup;
+//!
+//! let overdue = resources_lacking_recent_backup(&states, Utc::now(), Duration::days(7));
+//! ```
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::aggregate::compute_resource::ComputeResourceState;
+use crate::events::ResourceStatus;
+
+/// Whether a resource is expected to have configuration backups
+///
+/// Network and security appliances carry device configuration that must be
+/// restorable; servers are covered by host-level backup tooling instead.
+pub fn requires_configuration_backup(state: &ComputeResourceState) -> bool {
+    state.resource_type.is_network_device() || state.resource_type.is_security_device()
+}
+
+/// Devices whose latest configuration backup is missing or older than `max_age`
+///
+/// Only initialized, non-decommissioned resources that
+/// [require backups](requires_configuration_backup) are considered. Results
+/// are ordered with never-backed-up devices first, then oldest backup first.
+pub fn resources_lacking_recent_backup<'a>(
+    states: &'a [ComputeResourceState],
+    now: DateTime<Utc>,
+    max_age: Duration,
+) -> Vec<&'a ComputeResourceState> {
+    let mut overdue: Vec<&ComputeResourceState> = states
+        .iter()
+        .filter(|state| state.is_initialized())
+        .filter(|state| state.status != ResourceStatus::Decommissioned)
+        .filter(|state| requires_configuration_backup(state))
+        .filter(|state| state.lacks_recent_backup(now, max_age))
+        .collect();
+
+    overdue.sort_by_key(|state| {
+        state
+            .last_configuration_backup
+            .as_ref()
+            .map(|backup| backup.taken_at)
+    });
+
+    overdue
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::ResourceType;
+    use crate::events::ConfigurationBackupRef;
+    use uuid::Uuid;
+
+    fn test_timestamp() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-19T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn device(resource_type: ResourceType, backup_age: Option<Duration>) -> ComputeResourceState {
+        let mut state = ComputeResourceState::default_for(Uuid::now_v7());
+        state.created_at = Some(test_timestamp() - Duration::days(365));
+        state.resource_type = resource_type;
+        state.status = ResourceStatus::Active;
+        state.last_configuration_backup = backup_age.map(|age| ConfigurationBackupRef {
+            object_store: "device-configs".to_string(),
+            object_key: "backup.cfg".to_string(),
+            content_hash: "sha256:00".to_string(),
+            tool: "oxidized".to_string(),
+            taken_at: test_timestamp() - age,
+            size_bytes: None,
+        });
+        state
+    }
+
+    #[test]
+    fn test_finds_missing_and_stale_backups() {
+        // Arrange
+        let states = vec![
+            device(ResourceType::Switch, Some(Duration::days(1))),
+            device(ResourceType::Router, Some(Duration::days(30))),
+            device(ResourceType::Firewall, None),
+            device(ResourceType::PhysicalServer, None),
+        ];
+
+        // Act
+        let overdue = resources_lacking_recent_backup(&states, test_timestamp(), Duration::days(7));
+
+        // Assert - never-backed-up first, servers excluded
+        assert_eq!(overdue.len(), 2);
+        assert_eq!(overdue[0].resource_type, ResourceType::Firewall);
+        assert_eq!(overdue[1].resource_type, ResourceType::Router);
+    }
+
+    #[test]
+    fn test_decommissioned_devices_are_ignored() {
+        let mut retired = device(ResourceType::Switch, None);
+        retired.status = ResourceStatus::Decommissioned;
+
+        let overdue =
+            resources_lacking_recent_backup(&[retired], test_timestamp(), Duration::days(7));
+
+        assert!(overdue.is_empty());
+    }
+}
@@ -0,0 +1,173 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Event-Driven Alerting
+//!
+//! [`AlertEngine`] is a projection: it folds compute resource events into
+//! current state so filters see the resource as of each event, runs every
+//! event through the [`AlertEvaluator`] and publishes an [`AlertRaised`]
+//! for each rule that fires.
+//!
+//! ```text
+//! StoredEvent ──> AlertEngine ──fold──> ComputeResourceState
+//!                      │                       │
+//!                      └── AlertEvaluator::observe(event type, state, timestamp)
+//!                                  │ fired
+//!                                  └──publish──> infrastructure.alert.<rule>
+//! ```
+//!
+//! Windows are measured in event time, so rebuilding the engine replays
+//! history with the same counts and raises the same alerts again.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::alert::{AlertEngine, AlertEvaluator, AlertRuleSet};
+//!
+//! let rules = AlertRuleSet::from_yaml(&std::fs::read_to_string("alerts.yaml")?)?;
+//! let engine = AlertEngine::new(AlertEvaluator::compile(rules.rules)?).with_client(nats_client);
+//! manager.register(engine);
+//! ```
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use tracing::warn;
+use uuid::Uuid;
+
+use super::evaluator::AlertEvaluator;
+use crate::aggregate::{apply_event, ComputeResourceState};
+use crate::events::alert::AlertRaised;
+use crate::events::InfrastructureEvent;
+use crate::jetstream::StoredEvent;
+use crate::nats::NatsClient;
+use crate::projection::{ProjectionAdapter, ProjectionError};
+
+/// Resource state and the last sequence applied to it
+struct Tracked {
+    state: ComputeResourceState,
+    sequence: u64,
+}
+
+/// Projection evaluating alert rules on every event
+pub struct AlertEngine {
+    evaluator: AlertEvaluator,
+    client: Option<NatsClient>,
+    resources: HashMap<Uuid, Tracked>,
+    sequences: HashMap<Uuid, u64>,
+}
+
+impl AlertEngine {
+    /// Evaluate the rules of `evaluator`
+    ///
+    /// Without a client (see [`AlertEngine::with_client`]) alerts are
+    /// logged but not published.
+    pub fn new(evaluator: AlertEvaluator) -> Self {
+        Self {
+            evaluator,
+            client: None,
+            resources: HashMap::new(),
+            sequences: HashMap::new(),
+        }
+    }
+
+    /// Publish alerts through `client`
+    pub fn with_client(mut self, client: NatsClient) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Apply one event and return the alerts it raises
+    ///
+    /// Redelivered events raise none.
+    pub fn observe(&mut self, event: &StoredEvent<InfrastructureEvent>) -> Vec<AlertRaised> {
+        let resource = match &event.data {
+            InfrastructureEvent::ComputeResource(resource_event) => {
+                let tracked = self
+                    .resources
+                    .entry(event.aggregate_id)
+                    .or_insert_with(|| Tracked {
+                        state: ComputeResourceState::default_for(event.aggregate_id),
+                        sequence: 0,
+                    });
+                if event.sequence <= tracked.sequence {
+                    return Vec::new();
+                }
+
+                tracked.state = apply_event(tracked.state.clone(), resource_event);
+                tracked.sequence = event.sequence;
+                Some(&tracked.state)
+            }
+            _ => {
+                let sequence = self.sequences.entry(event.aggregate_id).or_insert(0);
+                if event.sequence <= *sequence {
+                    return Vec::new();
+                }
+                *sequence = event.sequence;
+                None
+            }
+        };
+
+        self.evaluator
+            .observe(
+                event.data.event_type_name(),
+                event.aggregate_id,
+                resource,
+                event.data.timestamp(),
+            )
+            .into_iter()
+            .map(|firing| AlertRaised {
+                event_id: Uuid::now_v7(),
+                raised_at: firing.last_seen,
+                correlation_id: event.correlation_id,
+                causation_id: event.data.event_id(),
+                rule: firing.rule,
+                severity: firing.severity,
+                message: firing.message,
+                count: firing.count,
+                first_seen: firing.first_seen,
+                resource_ids: firing.resource_ids,
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl ProjectionAdapter for AlertEngine {
+    type Event = StoredEvent<InfrastructureEvent>;
+    type Error = ProjectionError;
+
+    async fn project(&mut self, event: Self::Event) -> Result<(), Self::Error> {
+        for alert in self.observe(&event) {
+            warn!(
+                "Alert {} ({}): {}",
+                alert.rule, alert.severity, alert.message
+            );
+
+            if let Some(client) = &self.client {
+                client
+                    .publish(&alert.subject(), &alert)
+                    .await
+                    .map_err(|e| ProjectionError::TargetUnavailable(e.to_string()))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn initialize(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    async fn health_check(&self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    async fn reset(&mut self) -> Result<(), Self::Error> {
+        self.resources.clear();
+        self.sequences.clear();
+        self.evaluator.reset();
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "alert_engine"
+    }
+}
@@ -0,0 +1,379 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Alert Rule Evaluation
+//!
+//! [`AlertEvaluator`] holds compiled rules and, per rule, the matches seen
+//! so far. Each observed event is checked against every rule; a match is
+//! recorded with the event's timestamp, matches older than the rule's
+//! window are dropped, and once `threshold` matches remain the rule fires
+//! and its count starts over. The evaluator is pure apart from this
+//! counting state: time comes from the events, never from the clock.
+
+use chrono::{DateTime, Duration, Utc};
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use uuid::Uuid;
+
+use super::{AlertRule, AlertSeverity};
+use crate::aggregate::ComputeResourceState;
+use crate::policy::{Condition, PolicyError};
+
+/// Alert rule errors
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum AlertError {
+    /// Rule file is not valid YAML for a rule set
+    #[error("Invalid rule file: {0}")]
+    Yaml(String),
+
+    /// Rule name is empty or not usable as a subject token
+    #[error("Invalid rule name '{0}': use letters, digits, '-' and '_'")]
+    InvalidName(String),
+
+    /// Window is not a number followed by `s`, `m`, `h` or `d`
+    #[error("Rule '{rule}': invalid window '{window}'")]
+    InvalidWindow {
+        /// Rule declaring the window
+        rule: String,
+        /// Window as written
+        window: String,
+    },
+
+    /// Threshold is zero
+    #[error("Rule '{0}': threshold must be at least 1")]
+    InvalidThreshold(String),
+
+    /// Filter does not compile
+    #[error(transparent)]
+    Filter(#[from] PolicyError),
+
+    /// Two rules share a name
+    #[error("Duplicate alert rule '{0}'")]
+    DuplicateRule(String),
+}
+
+/// Parse a window such as `30s`, `10m`, `2h` or `1d`
+pub fn parse_window(window: &str) -> Option<Duration> {
+    let window = window.trim();
+    let unit = window.chars().last()?;
+    let amount: i64 = window[..window.len() - unit.len_utf8()].parse().ok()?;
+    if amount <= 0 {
+        return None;
+    }
+
+    match unit {
+        's' => Some(Duration::seconds(amount)),
+        'm' => Some(Duration::minutes(amount)),
+        'h' => Some(Duration::hours(amount)),
+        'd' => Some(Duration::days(amount)),
+        _ => None,
+    }
+}
+
+/// A rule ready for evaluation
+#[derive(Debug, Clone)]
+pub struct CompiledAlertRule {
+    rule: AlertRule,
+    filter: Option<Condition>,
+    window: Option<Duration>,
+}
+
+impl CompiledAlertRule {
+    /// Validate and compile a rule
+    pub fn compile(rule: AlertRule) -> Result<Self, AlertError> {
+        let valid_name = !rule.name.is_empty()
+            && rule
+                .name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !valid_name {
+            return Err(AlertError::InvalidName(rule.name));
+        }
+        if rule.threshold == 0 {
+            return Err(AlertError::InvalidThreshold(rule.name));
+        }
+
+        let window = match &rule.window {
+            Some(window) => {
+                Some(
+                    parse_window(window).ok_or_else(|| AlertError::InvalidWindow {
+                        rule: rule.name.clone(),
+                        window: window.clone(),
+                    })?,
+                )
+            }
+            None => None,
+        };
+        let filter = rule
+            .filter
+            .as_deref()
+            .map(|filter| Condition::compile(&rule.name, filter))
+            .transpose()?;
+
+        Ok(Self {
+            rule,
+            filter,
+            window,
+        })
+    }
+
+    /// Rule name
+    pub fn name(&self) -> &str {
+        &self.rule.name
+    }
+
+    /// The rule as declared
+    pub fn rule(&self) -> &AlertRule {
+        &self.rule
+    }
+
+    /// Window matches must fall in
+    pub fn window(&self) -> Option<Duration> {
+        self.window
+    }
+
+    /// Whether an event matches, ignoring threshold and window
+    pub fn matches(&self, event_type: &str, resource: Option<&ComputeResourceState>) -> bool {
+        if self.rule.event_type != "*" && self.rule.event_type != event_type {
+            return false;
+        }
+
+        match (&self.filter, resource) {
+            (None, _) => true,
+            (Some(filter), Some(resource)) => filter.matches(resource),
+            (Some(_), None) => false,
+        }
+    }
+
+    fn message(&self, count: usize) -> String {
+        if let Some(description) = &self.rule.description {
+            return description.clone();
+        }
+
+        let mut message = format!("{} {} event(s)", count, self.rule.event_type);
+        if let Some(filter) = &self.filter {
+            message.push_str(&format!(" matching '{}'", filter.as_str()));
+        }
+        if let Some(window) = &self.rule.window {
+            message.push_str(&format!(" within {}", window));
+        }
+        message
+    }
+}
+
+/// A rule reached its threshold
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlertFiring {
+    /// Rule that fired
+    pub rule: String,
+
+    /// Severity of the rule
+    pub severity: AlertSeverity,
+
+    /// Rule description, or a summary of what matched
+    pub message: String,
+
+    /// Matches counted
+    pub count: u32,
+
+    /// Resources of the counted matches, sorted
+    pub resource_ids: Vec<Uuid>,
+
+    /// Timestamp of the first counted match
+    pub first_seen: DateTime<Utc>,
+
+    /// Timestamp of the match that fired the rule
+    pub last_seen: DateTime<Utc>,
+}
+
+/// Counted matches of one rule (per resource, or `None` across resources)
+type Matches = HashMap<Option<Uuid>, VecDeque<(DateTime<Utc>, Uuid)>>;
+
+/// Compiled rules and their match counts
+#[derive(Debug, Clone, Default)]
+pub struct AlertEvaluator {
+    rules: Vec<CompiledAlertRule>,
+    matches: Vec<Matches>,
+}
+
+impl AlertEvaluator {
+    /// Evaluator with no rules
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Compile a rule set; fails on the first invalid rule
+    pub fn compile<I>(rules: I) -> Result<Self, AlertError>
+    where
+        I: IntoIterator<Item = AlertRule>,
+    {
+        let mut evaluator = Self::new();
+        for rule in rules {
+            evaluator.register(rule)?;
+        }
+        Ok(evaluator)
+    }
+
+    /// Add a rule
+    pub fn register(&mut self, rule: AlertRule) -> Result<(), AlertError> {
+        if self.rules.iter().any(|r| r.name() == rule.name) {
+            return Err(AlertError::DuplicateRule(rule.name));
+        }
+        self.rules.push(CompiledAlertRule::compile(rule)?);
+        self.matches.push(Matches::new());
+        Ok(())
+    }
+
+    /// Names of registered rules, in registration order
+    pub fn rule_names(&self) -> Vec<&str> {
+        self.rules.iter().map(CompiledAlertRule::name).collect()
+    }
+
+    /// Forget all counted matches
+    pub fn reset(&mut self) {
+        self.matches.iter_mut().for_each(Matches::clear);
+    }
+
+    /// Count an event and return the rules it fires
+    ///
+    /// `resource` is the state of the event's compute resource after the
+    /// event, if it belongs to one.
+    pub fn observe(
+        &mut self,
+        event_type: &str,
+        aggregate_id: Uuid,
+        resource: Option<&ComputeResourceState>,
+        at: DateTime<Utc>,
+    ) -> Vec<AlertFiring> {
+        let mut fired = Vec::new();
+
+        for (rule, matches) in self.rules.iter().zip(self.matches.iter_mut()) {
+            if !rule.matches(event_type, resource) {
+                continue;
+            }
+
+            let key = rule.rule.per_resource.then_some(aggregate_id);
+            let counted = matches.entry(key).or_default();
+            counted.push_back((at, aggregate_id));
+            if let Some(window) = rule.window {
+                while counted
+                    .front()
+                    .is_some_and(|(first, _)| at - *first > window)
+                {
+                    counted.pop_front();
+                }
+            }
+
+            if counted.len() < rule.rule.threshold as usize {
+                continue;
+            }
+
+            let resource_ids: BTreeSet<Uuid> = counted.iter().map(|(_, id)| *id).collect();
+            fired.push(AlertFiring {
+                rule: rule.name().to_string(),
+                severity: rule.rule.severity,
+                message: rule.message(counted.len()),
+                count: counted.len() as u32,
+                resource_ids: resource_ids.into_iter().collect(),
+                first_seen: counted.front().map_or(at, |(first, _)| *first),
+                last_seen: at,
+            });
+            counted.clear();
+        }
+
+        fired
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alert::AlertRuleSet;
+    use crate::domain::Hostname;
+    use crate::events::ResourceStatus;
+
+    fn test_timestamp() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-19T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn decommissioned() -> ComputeResourceState {
+        let mut state = ComputeResourceState::default_for(Uuid::now_v7());
+        state.hostname = Hostname::new("web-01").unwrap();
+        state.status = ResourceStatus::Decommissioned;
+        state
+    }
+
+    #[test]
+    fn test_threshold_within_window() {
+        // Arrange
+        let rules = AlertRuleSet::from_yaml(
+            r#"
+rules:
+  - name: decommission-burst
+    event_type: StatusChanged
+    filter: status == decommissioned
+    threshold: 3
+    window: 10m
+    severity: critical
+"#,
+        )
+        .unwrap();
+        let mut evaluator = AlertEvaluator::compile(rules.rules).unwrap();
+        let resource = decommissioned();
+        let t0 = test_timestamp();
+        let mut observe = |minutes: i64| {
+            evaluator.observe(
+                "StatusChanged",
+                resource.id,
+                Some(&resource),
+                t0 + Duration::minutes(minutes),
+            )
+        };
+
+        // Act - the first match expires before the third arrives
+        let fired: Vec<_> = [0, 8, 12, 14].into_iter().map(&mut observe).collect();
+
+        // Assert
+        assert!(fired[..3].iter().all(Vec::is_empty));
+        assert_eq!(fired[3].len(), 1);
+        assert_eq!(fired[3][0].rule, "decommission-burst");
+        assert_eq!(fired[3][0].severity, AlertSeverity::Critical);
+        assert_eq!(fired[3][0].count, 3);
+        assert_eq!(fired[3][0].first_seen, t0 + Duration::minutes(8));
+        assert!(observe(15).is_empty());
+    }
+
+    #[test]
+    fn test_filters_and_event_types() {
+        let mut evaluator = AlertEvaluator::compile([
+            AlertRule::new("any-event", "*"),
+            AlertRule::new("decommissioned", "StatusChanged")
+                .with_filter("status == decommissioned"),
+        ])
+        .unwrap();
+
+        let on_network =
+            evaluator.observe("NetworkDefined", Uuid::now_v7(), None, test_timestamp());
+
+        assert_eq!(on_network.len(), 1);
+        assert_eq!(on_network[0].rule, "any-event");
+        assert_eq!(on_network[0].message, "1 * event(s)");
+    }
+
+    #[test]
+    fn test_invalid_rules_are_rejected() {
+        assert_eq!(parse_window("90s"), Some(Duration::seconds(90)));
+        assert_eq!(parse_window("10x"), None);
+        assert!(matches!(
+            CompiledAlertRule::compile(AlertRule::new("has.dot", "*")),
+            Err(AlertError::InvalidName(_))
+        ));
+        assert!(matches!(
+            CompiledAlertRule::compile(AlertRule::new("burst", "*").with_threshold(2, "soon")),
+            Err(AlertError::InvalidWindow { .. })
+        ));
+        assert!(matches!(
+            AlertRuleSet::from_yaml("rules: [{name: x}]"),
+            Err(AlertError::Yaml(_))
+        ));
+    }
+}
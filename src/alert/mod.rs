@@ -0,0 +1,176 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Declarative Alert Rules
+//!
+//! Alerts are declared in YAML instead of written as handler code. A rule
+//! names the event type to watch, an optional filter over the resource the
+//! event belongs to, and how many matches within which window raise an
+//! alert:
+//!
+//! ```yaml
+//! rules:
+//!   - name: decommission-burst
+//!     description: Many resources decommissioned in a short time
+//!     event_type: StatusChanged
+//!     filter: status == decommissioned
+//!     threshold: 5
+//!     window: 10m
+//!     severity: critical
+//!
+//!   - name: untagged-server-registered
+//!     event_type: ResourceRegistered
+//!     filter: resource_type == physical_server and asset_tag is not set
+//! ```
+//!
+//! - `event_type` is the event type name (`ResourceRegistered`,
+//!   `StatusChanged`, ...) or `*` for every event.
+//! - `filter` uses the [policy condition language](crate::policy::evaluator)
+//!   and is evaluated against the compute resource *after* the event. Rules
+//!   with a filter only match compute resource events.
+//! - `threshold` (default 1) matches within `window` (`30s`, `10m`, `2h`,
+//!   `1d`) raise the alert; without a window every `threshold` matches do.
+//!   With `per_resource: true`, matches are counted per resource.
+//! - `severity` is `info`, `warning` (default) or `critical`.
+//!
+//! The [`evaluator`] compiles rules and counts matches; with the runtime,
+//! the [`engine`] evaluates them on every stored event and publishes
+//! [`AlertRaised`](crate::events::alert::AlertRaised) on
+//! `infrastructure.alert.<rule>`.
+//!
+//! # Modules
+//!
+//! - [`evaluator`] - Rule compilation, windows and thresholds
+//! - [`engine`] - Event-driven evaluation and publishing (runtime)
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[cfg(feature = "runtime")]
+pub mod engine;
+pub mod evaluator;
+
+#[cfg(feature = "runtime")]
+pub use engine::AlertEngine;
+pub use evaluator::{parse_window, AlertError, AlertEvaluator, AlertFiring, CompiledAlertRule};
+
+/// How urgent an alert is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum AlertSeverity {
+    /// Worth knowing; no action expected
+    Info,
+
+    /// Needs attention
+    #[default]
+    Warning,
+
+    /// Needs immediate attention
+    Critical,
+}
+
+impl fmt::Display for AlertSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AlertSeverity::Info => write!(f, "info"),
+            AlertSeverity::Warning => write!(f, "warning"),
+            AlertSeverity::Critical => write!(f, "critical"),
+        }
+    }
+}
+
+/// A declared alert rule
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct AlertRule {
+    /// Unique rule name; letters, digits, `-` and `_` (used in the subject)
+    pub name: String,
+
+    /// What the alert means, used as its message
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// Event type name to watch, or `*`
+    pub event_type: String,
+
+    /// Condition over the resource after the event
+    #[serde(default)]
+    pub filter: Option<String>,
+
+    /// Matches needed to raise the alert
+    #[serde(default = "default_threshold")]
+    pub threshold: u32,
+
+    /// Window the matches must fall in (`30s`, `10m`, `2h`, `1d`)
+    #[serde(default)]
+    pub window: Option<String>,
+
+    /// Count matches per resource instead of across all resources
+    #[serde(default)]
+    pub per_resource: bool,
+
+    /// Severity of raised alerts
+    #[serde(default)]
+    pub severity: AlertSeverity,
+}
+
+fn default_threshold() -> u32 {
+    1
+}
+
+impl AlertRule {
+    /// Rule raising an alert on every event of `event_type`
+    pub fn new(name: impl Into<String>, event_type: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            event_type: event_type.into(),
+            filter: None,
+            threshold: default_threshold(),
+            window: None,
+            per_resource: false,
+            severity: AlertSeverity::default(),
+        }
+    }
+
+    /// Only match events whose resource satisfies `filter`
+    pub fn with_filter(mut self, filter: impl Into<String>) -> Self {
+        self.filter = Some(filter.into());
+        self
+    }
+
+    /// Raise after `threshold` matches within `window`
+    pub fn with_threshold(mut self, threshold: u32, window: impl Into<String>) -> Self {
+        self.threshold = threshold;
+        self.window = Some(window.into());
+        self
+    }
+
+    /// Set the severity
+    pub fn with_severity(mut self, severity: AlertSeverity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Describe what the alert means
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+/// A rule file
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlertRuleSet {
+    /// Declared rules
+    #[serde(default)]
+    pub rules: Vec<AlertRule>,
+}
+
+impl AlertRuleSet {
+    /// Parse a YAML rule file
+    pub fn from_yaml(yaml: &str) -> Result<Self, AlertError> {
+        serde_yaml::from_str(yaml).map_err(|e| AlertError::Yaml(e.to_string()))
+    }
+}
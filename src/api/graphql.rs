@@ -0,0 +1,935 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! GraphQL Queries over the Read Models
+//!
+//! Serves the [in-memory read model](crate::projection::read_model) as a
+//! GraphQL schema, so UIs can query infrastructure and follow its
+//! relationships without knowing NATS subjects.
+//!
+//! ```text
+//! resource ──interfaces──> interface ──network──> network ──interfaces──> …
+//!    │                        └──connection──> connection ──aEnd/bEnd──> endpoint ──resource──> …
+//!    ├──connections / reachable
+//!    ├──policies ──resources──> …
+//!    └──topology (TopologyView)
+//! ```
+//!
+//! Every top-level field reads one [`ReadModelSnapshot`], and traversal from
+//! it stays on that snapshot, so a response is consistent even while the
+//! projection moves on. Relationships are cyclic, so query depth is capped
+//! at [`MAX_QUERY_DEPTH`].
+//!
+//! # Tenancy
+//!
+//! Queries only see what the organization of the current request may see
+//! (see [`tenancy`](crate::tenancy)): resources of other organizations, and
+//! the interfaces and cables on them, are left out as if they did not
+//! exist. Serve the router behind `rest::authenticated` so the
+//! organization is the authenticated sender's.
+//!
+//! # Endpoints
+//!
+//! ```text
+//! POST /graphql   GraphQL requests
+//! GET  /graphql   GraphiQL explorer
+//! ```
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::api::graphql;
+//!
+//! let handle = read_model.handle();
+//! manager.register(read_model);
+//! graphql::serve("0.0.0.0:8080".parse()?, graphql::schema(handle)).await?;
+//! ```
+//!
+//! ```graphql
+//! {
+//!   resources(status: ACTIVE) {
+//!     hostname
+//!     interfaces { name addresses network { name cidr } }
+//!     policies { id }
+//!   }
+//! }
+//! ```
+
+use async_graphql::http::GraphiQLSource;
+use async_graphql::{Context, EmptyMutation, EmptySubscription, Object, Schema, SimpleObject};
+use async_graphql_axum::GraphQL;
+use axum::response::{Html, IntoResponse};
+use axum::routing::get;
+use axum::Router;
+use chrono::{DateTime, Utc};
+use std::collections::BTreeSet;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tracing::info;
+use uuid::Uuid;
+
+use crate::aggregate::{
+    ComputeResourceState, ConnectionState, NetworkInterfaceState, NetworkState,
+};
+use crate::errors::{InfrastructureError, InfrastructureResult};
+use crate::event_store::provenance::EventEnvelopeMetadata;
+use crate::events::connection::ConnectionEndpoint;
+use crate::events::ResourceStatus;
+use crate::nats::query::TopologyView;
+use crate::projection::read_model::{ReadModelHandle, ReadModelSnapshot};
+use crate::tenancy::TenancyConfig;
+
+/// Deepest query nesting accepted
+pub const MAX_QUERY_DEPTH: usize = 12;
+
+/// Path the schema is served on
+pub const GRAPHQL_PATH: &str = "/graphql";
+
+/// Schema over the read model
+pub type InfrastructureSchema = Schema<QueryRoot, EmptyMutation, EmptySubscription>;
+
+/// Build the schema answering from `read_model`
+pub fn schema(read_model: ReadModelHandle) -> InfrastructureSchema {
+    Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .data(read_model)
+        .limit_depth(MAX_QUERY_DEPTH)
+        .finish()
+}
+
+/// Routes serving `schema` on [`GRAPHQL_PATH`]
+pub fn router(schema: InfrastructureSchema) -> Router {
+    Router::new().route(
+        GRAPHQL_PATH,
+        get(graphiql).post_service(GraphQL::new(schema)),
+    )
+}
+
+/// Serve `schema` over HTTP until the server fails
+pub async fn serve(addr: SocketAddr, schema: InfrastructureSchema) -> InfrastructureResult<()> {
+    let listener = tokio::net::TcpListener::bind(addr).await.map_err(|e| {
+        InfrastructureError::Configuration(format!("cannot listen on {}: {}", addr, e))
+    })?;
+    info!("GraphQL API listening on http://{}{}", addr, GRAPHQL_PATH);
+
+    axum::serve(listener, router(schema))
+        .await
+        .map_err(|e| InfrastructureError::Generic(format!("GraphQL server failed: {}", e)))
+}
+
+async fn graphiql() -> impl IntoResponse {
+    Html(GraphiQLSource::build().endpoint(GRAPHQL_PATH).finish())
+}
+
+fn snapshot(ctx: &Context<'_>) -> async_graphql::Result<Arc<ReadModelSnapshot>> {
+    Ok(ctx.data::<ReadModelHandle>()?.snapshot())
+}
+
+/// Whether the organization of the current request may see `state`
+fn visible(state: &ComputeResourceState) -> bool {
+    let acting = EventEnvelopeMetadata::current().and_then(|envelope| envelope.tenant);
+    TenancyConfig::default()
+        .check_read(acting.as_deref(), state)
+        .is_ok()
+}
+
+/// Whether the resource something belongs to is visible
+///
+/// Resources the snapshot does not know belong to no organization yet.
+fn on_visible_resource(snapshot: &ReadModelSnapshot, resource_id: Option<Uuid>) -> bool {
+    resource_id
+        .and_then(|id| snapshot.get(id))
+        .is_none_or(visible)
+}
+
+/// Visible resources and the live cables between them
+fn connectivity(snapshot: &ReadModelSnapshot) -> TopologyView {
+    TopologyView::connectivity(
+        snapshot.resources().filter(|state| visible(state)),
+        snapshot
+            .connections()
+            .filter(|state| on_visible_ends(snapshot, state)),
+    )
+}
+
+/// Whether both ends of a connection are on visible resources
+fn on_visible_ends(snapshot: &ReadModelSnapshot, state: &ConnectionState) -> bool {
+    [&state.a_end, &state.b_end]
+        .into_iter()
+        .flatten()
+        .all(|end| on_visible_resource(snapshot, Some(end.resource_id)))
+}
+
+/// Root of every query
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    /// Compute resource by ID
+    async fn resource(
+        &self,
+        ctx: &Context<'_>,
+        id: Uuid,
+    ) -> async_graphql::Result<Option<Resource>> {
+        let snapshot = snapshot(ctx)?;
+        Ok(Resource::get(&snapshot, id))
+    }
+
+    /// Compute resources ordered by hostname, optionally filtered
+    async fn resources(
+        &self,
+        ctx: &Context<'_>,
+        status: Option<ResourceStatus>,
+        organization_id: Option<String>,
+    ) -> async_graphql::Result<Vec<Resource>> {
+        let snapshot = snapshot(ctx)?;
+        Ok(Resource::all(&snapshot, |state| {
+            status.is_none_or(|status| state.status == status)
+                && organization_id.as_ref().is_none_or(|org| {
+                    state
+                        .organization_id
+                        .as_ref()
+                        .is_some_and(|id| id.to_string() == *org)
+                })
+        }))
+    }
+
+    /// Network interface by ID
+    async fn interface(
+        &self,
+        ctx: &Context<'_>,
+        id: Uuid,
+    ) -> async_graphql::Result<Option<Interface>> {
+        let snapshot = snapshot(ctx)?;
+        Ok(Interface::get(&snapshot, id))
+    }
+
+    /// Network interfaces ordered by name
+    async fn interfaces(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<Interface>> {
+        let snapshot = snapshot(ctx)?;
+        Ok(Interface::all(&snapshot, |_| true))
+    }
+
+    /// Network by ID
+    async fn network(&self, ctx: &Context<'_>, id: Uuid) -> async_graphql::Result<Option<Network>> {
+        let snapshot = snapshot(ctx)?;
+        Ok(Network::get(&snapshot, id))
+    }
+
+    /// Networks ordered by name
+    async fn networks(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<Network>> {
+        let snapshot = snapshot(ctx)?;
+        Ok(Network::all(&snapshot))
+    }
+
+    /// Live physical connections
+    async fn connections(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<Connection>> {
+        let snapshot = snapshot(ctx)?;
+        Ok(Connection::all(&snapshot, |_| true))
+    }
+
+    /// Policies applied to at least one resource
+    async fn policies(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<Policy>> {
+        let snapshot = snapshot(ctx)?;
+        let ids: BTreeSet<String> = snapshot
+            .resources()
+            .filter(|state| visible(state))
+            .flat_map(|state| state.policy_ids.iter().map(|id| id.to_string()))
+            .collect();
+        Ok(ids
+            .into_iter()
+            .map(|id| Policy {
+                snapshot: snapshot.clone(),
+                id,
+            })
+            .collect())
+    }
+
+    /// Direct relationships of a resource
+    async fn topology(
+        &self,
+        ctx: &Context<'_>,
+        root: Uuid,
+    ) -> async_graphql::Result<Option<TopologyView>> {
+        let snapshot = snapshot(ctx)?;
+        Ok(Resource::get(&snapshot, root)
+            .map(|resource| TopologyView::from_resource(&resource.state)))
+    }
+
+    /// Every resource and the live cables between them
+    async fn connectivity(&self, ctx: &Context<'_>) -> async_graphql::Result<TopologyView> {
+        let snapshot = snapshot(ctx)?;
+        Ok(connectivity(&snapshot))
+    }
+}
+
+/// Custom metadata entry of a resource
+#[derive(SimpleObject)]
+pub struct MetadataEntry {
+    /// Metadata key
+    pub key: String,
+
+    /// Metadata value
+    pub value: String,
+}
+
+/// Compute resource and its relationships
+pub struct Resource {
+    snapshot: Arc<ReadModelSnapshot>,
+    state: ComputeResourceState,
+}
+
+impl Resource {
+    fn get(snapshot: &Arc<ReadModelSnapshot>, id: Uuid) -> Option<Self> {
+        snapshot
+            .get(id)
+            .filter(|state| visible(state))
+            .map(|state| Self {
+                snapshot: snapshot.clone(),
+                state: state.clone(),
+            })
+    }
+
+    fn all(
+        snapshot: &Arc<ReadModelSnapshot>,
+        filter: impl Fn(&ComputeResourceState) -> bool,
+    ) -> Vec<Self> {
+        let mut resources: Vec<Self> = snapshot
+            .resources()
+            .filter(|state| state.is_initialized() && visible(state) && filter(state))
+            .map(|state| Self {
+                snapshot: snapshot.clone(),
+                state: state.clone(),
+            })
+            .collect();
+        resources.sort_by(|a, b| a.state.hostname.as_str().cmp(b.state.hostname.as_str()));
+        resources
+    }
+}
+
+#[Object]
+impl Resource {
+    /// Aggregate ID
+    async fn id(&self) -> Uuid {
+        self.state.id
+    }
+
+    /// Hostname
+    async fn hostname(&self) -> &str {
+        self.state.hostname.as_str()
+    }
+
+    /// Resource type (e.g. `physical_server`)
+    async fn resource_type(&self) -> &'static str {
+        self.state.resource_type.as_str()
+    }
+
+    /// Current status
+    async fn status(&self) -> ResourceStatus {
+        self.state.status
+    }
+
+    /// Owning organization
+    async fn organization_id(&self) -> Option<String> {
+        self.state.organization_id.as_ref().map(|id| id.to_string())
+    }
+
+    /// Physical location
+    async fn location_id(&self) -> Option<String> {
+        self.state.location_id.as_ref().map(|id| id.to_string())
+    }
+
+    /// Owner / primary contact
+    async fn owner_id(&self) -> Option<String> {
+        self.state.owner_id.as_ref().map(|id| id.to_string())
+    }
+
+    /// Hardware manufacturer
+    async fn manufacturer(&self) -> Option<&str> {
+        self.state.manufacturer.as_deref()
+    }
+
+    /// Hardware model
+    async fn model(&self) -> Option<&str> {
+        self.state.model.as_deref()
+    }
+
+    /// Serial number
+    async fn serial_number(&self) -> Option<&str> {
+        self.state.serial_number.as_deref()
+    }
+
+    /// Asset tag
+    async fn asset_tag(&self) -> Option<&str> {
+        self.state.asset_tag.as_deref()
+    }
+
+    /// Custom metadata
+    async fn metadata(&self) -> Vec<MetadataEntry> {
+        self.state
+            .metadata
+            .iter()
+            .map(|(key, value)| MetadataEntry {
+                key: key.clone(),
+                value: value.clone(),
+            })
+            .collect()
+    }
+
+    /// First event timestamp
+    async fn created_at(&self) -> Option<DateTime<Utc>> {
+        self.state.created_at
+    }
+
+    /// Latest event timestamp
+    async fn updated_at(&self) -> Option<DateTime<Utc>> {
+        self.state.updated_at
+    }
+
+    /// Network interfaces of this resource
+    async fn interfaces(&self) -> Vec<Interface> {
+        Interface::all(&self.snapshot, |state| {
+            state.resource_id == Some(self.state.id)
+        })
+    }
+
+    /// Networks this resource's interfaces attach to
+    async fn networks(&self) -> Vec<Network> {
+        let ids: BTreeSet<Uuid> = self
+            .snapshot
+            .interfaces()
+            .filter(|state| state.resource_id == Some(self.state.id))
+            .filter_map(|state| state.network_id)
+            .collect();
+        ids.into_iter()
+            .filter_map(|id| Network::get(&self.snapshot, id))
+            .collect()
+    }
+
+    /// Live connections with an end on this resource
+    async fn connections(&self) -> Vec<Connection> {
+        Connection::all(&self.snapshot, |state| {
+            [&state.a_end, &state.b_end]
+                .into_iter()
+                .flatten()
+                .any(|end| end.resource_id == self.state.id)
+        })
+    }
+
+    /// Resources reachable over cables, nearest first
+    async fn reachable(&self) -> Vec<Resource> {
+        let view = connectivity(&self.snapshot);
+        view.reachable_from(self.state.id)
+            .into_iter()
+            .filter_map(|node| node.id.parse().ok())
+            .filter_map(|id| Resource::get(&self.snapshot, id))
+            .collect()
+    }
+
+    /// Policies applied to this resource
+    async fn policies(&self) -> Vec<Policy> {
+        self.state
+            .policy_ids
+            .iter()
+            .map(|id| Policy {
+                snapshot: self.snapshot.clone(),
+                id: id.to_string(),
+            })
+            .collect()
+    }
+
+    /// Direct relationships of this resource
+    async fn topology(&self) -> TopologyView {
+        TopologyView::from_resource(&self.state)
+    }
+}
+
+/// Network interface and its relationships
+pub struct Interface {
+    snapshot: Arc<ReadModelSnapshot>,
+    state: NetworkInterfaceState,
+}
+
+impl Interface {
+    fn get(snapshot: &Arc<ReadModelSnapshot>, id: Uuid) -> Option<Self> {
+        snapshot
+            .interface(id)
+            .filter(|state| on_visible_resource(snapshot, state.resource_id))
+            .map(|state| Self {
+                snapshot: snapshot.clone(),
+                state: state.clone(),
+            })
+    }
+
+    fn all(
+        snapshot: &Arc<ReadModelSnapshot>,
+        filter: impl Fn(&NetworkInterfaceState) -> bool,
+    ) -> Vec<Self> {
+        let mut interfaces: Vec<Self> = snapshot
+            .interfaces()
+            .filter(|state| {
+                state.is_initialized()
+                    && on_visible_resource(snapshot, state.resource_id)
+                    && filter(state)
+            })
+            .map(|state| Self {
+                snapshot: snapshot.clone(),
+                state: state.clone(),
+            })
+            .collect();
+        interfaces.sort_by(|a, b| {
+            a.state
+                .name
+                .cmp(&b.state.name)
+                .then(a.state.id.cmp(&b.state.id))
+        });
+        interfaces
+    }
+}
+
+#[Object]
+impl Interface {
+    /// Aggregate ID
+    async fn id(&self) -> Uuid {
+        self.state.id
+    }
+
+    /// Interface name (e.g. `eth0`)
+    async fn name(&self) -> &str {
+        &self.state.name
+    }
+
+    /// MAC address
+    async fn mac_address(&self) -> Option<String> {
+        self.state.mac_address.as_ref().map(|mac| mac.to_string())
+    }
+
+    /// Addresses in CIDR notation
+    async fn addresses(&self) -> Vec<String> {
+        self.state.addresses.iter().map(|a| a.to_string()).collect()
+    }
+
+    /// Link speed in Mbit/s
+    async fn speed_mbps(&self) -> Option<u32> {
+        self.state.speed_mbps
+    }
+
+    /// Whether this is a bonded interface
+    async fn is_bond(&self) -> bool {
+        self.state.is_bond()
+    }
+
+    /// Resource the interface belongs to
+    async fn resource(&self) -> Option<Resource> {
+        Resource::get(&self.snapshot, self.state.resource_id?)
+    }
+
+    /// Network the interface attaches to
+    async fn network(&self) -> Option<Network> {
+        Network::get(&self.snapshot, self.state.network_id?)
+    }
+
+    /// Live connection plugged into this interface
+    async fn connection(&self) -> Option<Connection> {
+        Connection::all(&self.snapshot, |state| {
+            [&state.a_end, &state.b_end]
+                .into_iter()
+                .flatten()
+                .any(|end| end.interface_id == self.state.id)
+        })
+        .into_iter()
+        .next()
+    }
+}
+
+/// Network and its relationships
+pub struct Network {
+    snapshot: Arc<ReadModelSnapshot>,
+    state: NetworkState,
+}
+
+impl Network {
+    fn get(snapshot: &Arc<ReadModelSnapshot>, id: Uuid) -> Option<Self> {
+        snapshot.network(id).map(|state| Self {
+            snapshot: snapshot.clone(),
+            state: state.clone(),
+        })
+    }
+
+    fn all(snapshot: &Arc<ReadModelSnapshot>) -> Vec<Self> {
+        let mut networks: Vec<Self> = snapshot
+            .networks()
+            .filter(|state| state.is_initialized())
+            .map(|state| Self {
+                snapshot: snapshot.clone(),
+                state: state.clone(),
+            })
+            .collect();
+        networks.sort_by(|a, b| a.state.name.cmp(&b.state.name));
+        networks
+    }
+}
+
+#[Object]
+impl Network {
+    /// Aggregate ID
+    async fn id(&self) -> Uuid {
+        self.state.id
+    }
+
+    /// Network name
+    async fn name(&self) -> &str {
+        &self.state.name
+    }
+
+    /// Prefix in CIDR notation
+    async fn cidr(&self) -> Option<String> {
+        self.state.cidr.as_ref().map(|cidr| cidr.to_string())
+    }
+
+    /// VLAN the network is bound to
+    async fn vlan_id(&self) -> Option<u16> {
+        self.state.vlan_id.map(|vlan| vlan.value())
+    }
+
+    /// Network segment
+    async fn segment(&self) -> Option<&str> {
+        self.state.segment.as_deref()
+    }
+
+    /// Interfaces attached to this network
+    async fn interfaces(&self) -> Vec<Interface> {
+        Interface::all(&self.snapshot, |state| {
+            state.network_id == Some(self.state.id)
+        })
+    }
+}
+
+/// Live physical connection between two interfaces
+pub struct Connection {
+    snapshot: Arc<ReadModelSnapshot>,
+    state: ConnectionState,
+}
+
+impl Connection {
+    fn all(
+        snapshot: &Arc<ReadModelSnapshot>,
+        filter: impl Fn(&ConnectionState) -> bool,
+    ) -> Vec<Self> {
+        let mut connections: Vec<Self> = snapshot
+            .connections()
+            .filter(|state| on_visible_ends(snapshot, state) && filter(state))
+            .map(|state| Self {
+                snapshot: snapshot.clone(),
+                state: state.clone(),
+            })
+            .collect();
+        connections.sort_by_key(|connection| connection.state.id);
+        connections
+    }
+
+    fn endpoint(&self, end: &Option<ConnectionEndpoint>) -> Option<Endpoint> {
+        end.as_ref().map(|end| Endpoint {
+            snapshot: self.snapshot.clone(),
+            end: end.clone(),
+        })
+    }
+}
+
+#[Object]
+impl Connection {
+    /// Aggregate ID
+    async fn id(&self) -> Uuid {
+        self.state.id
+    }
+
+    /// Cable label
+    async fn label(&self) -> Option<&str> {
+        self.state.label.as_deref()
+    }
+
+    /// A end of the cable
+    async fn a_end(&self) -> Option<Endpoint> {
+        self.endpoint(&self.state.a_end)
+    }
+
+    /// B end of the cable
+    async fn b_end(&self) -> Option<Endpoint> {
+        self.endpoint(&self.state.b_end)
+    }
+}
+
+/// One end of a connection
+pub struct Endpoint {
+    snapshot: Arc<ReadModelSnapshot>,
+    end: ConnectionEndpoint,
+}
+
+#[Object]
+impl Endpoint {
+    /// Device name recorded on the cable
+    async fn device(&self) -> &str {
+        self.end.device.as_str()
+    }
+
+    /// Port name recorded on the cable
+    async fn port(&self) -> &str {
+        &self.end.port
+    }
+
+    /// Resource at this end
+    async fn resource(&self) -> Option<Resource> {
+        Resource::get(&self.snapshot, self.end.resource_id)
+    }
+
+    /// Interface at this end
+    async fn interface(&self) -> Option<Interface> {
+        Interface::get(&self.snapshot, self.end.interface_id)
+    }
+}
+
+/// Policy and the resources it applies to
+pub struct Policy {
+    snapshot: Arc<ReadModelSnapshot>,
+    id: String,
+}
+
+#[Object]
+impl Policy {
+    /// Policy ID
+    async fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Resources the policy applies to
+    async fn resources(&self) -> Vec<Resource> {
+        Resource::all(&self.snapshot, |state| {
+            state.policy_ids.iter().any(|id| id.to_string() == self.id)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Hostname, IpAddressWithCidr, ResourceType};
+    use crate::events::compute_resource::{
+        ComputeResourceEvent, OrganizationAssigned, PolicyAdded, ResourceRegistered,
+    };
+    use crate::events::network::{NetworkDefined, NetworkEvent};
+    use crate::events::network_interface::{InterfaceAttached, NetworkInterfaceEvent};
+    use crate::events::InfrastructureEvent;
+    use crate::jetstream::StoredEvent;
+    use crate::projection::read_model::{InMemoryReadModel, ReadModelConfig};
+    use crate::projection::ProjectionAdapter;
+    use cim_domain::EntityId;
+    use cim_domain_policy::PolicyId;
+
+    fn stored(
+        aggregate_id: Uuid,
+        sequence: u64,
+        data: InfrastructureEvent,
+    ) -> StoredEvent<InfrastructureEvent> {
+        StoredEvent::new(
+            Uuid::now_v7(),
+            aggregate_id,
+            sequence,
+            Uuid::now_v7(),
+            Uuid::now_v7(),
+            "test",
+            data,
+        )
+    }
+
+    /// web-01 with one interface on the `servers` network and one policy
+    async fn read_model(resource_id: Uuid, policy_id: PolicyId) -> ReadModelHandle {
+        let network_id = Uuid::now_v7();
+        let interface_id = Uuid::now_v7();
+        let mut model = InMemoryReadModel::new(ReadModelConfig::default());
+        let events = [
+            stored(
+                resource_id,
+                1,
+                InfrastructureEvent::ComputeResource(ComputeResourceEvent::ResourceRegistered(
+                    ResourceRegistered {
+                        event_version: 1,
+                        event_id: Uuid::now_v7(),
+                        aggregate_id: resource_id,
+                        timestamp: Utc::now(),
+                        correlation_id: Uuid::now_v7(),
+                        causation_id: None,
+                        hostname: Hostname::new("web-01").unwrap(),
+                        resource_type: ResourceType::PhysicalServer,
+                    },
+                )),
+            ),
+            stored(
+                resource_id,
+                2,
+                InfrastructureEvent::ComputeResource(ComputeResourceEvent::PolicyAdded(
+                    PolicyAdded {
+                        event_version: 1,
+                        event_id: Uuid::now_v7(),
+                        aggregate_id: resource_id,
+                        timestamp: Utc::now(),
+                        correlation_id: Uuid::now_v7(),
+                        causation_id: None,
+                        policy_id,
+                    },
+                )),
+            ),
+            stored(
+                network_id,
+                1,
+                InfrastructureEvent::Network(NetworkEvent::NetworkDefined(NetworkDefined {
+                    event_version: 1,
+                    event_id: Uuid::now_v7(),
+                    aggregate_id: network_id,
+                    timestamp: Utc::now(),
+                    correlation_id: Uuid::now_v7(),
+                    causation_id: None,
+                    name: "servers".to_string(),
+                    cidr: IpAddressWithCidr::new("10.20.0.0/24").unwrap(),
+                    vlan_id: None,
+                    segment: None,
+                })),
+            ),
+            stored(
+                interface_id,
+                1,
+                InfrastructureEvent::NetworkInterface(NetworkInterfaceEvent::InterfaceAttached(
+                    InterfaceAttached {
+                        event_version: 1,
+                        event_id: Uuid::now_v7(),
+                        aggregate_id: interface_id,
+                        timestamp: Utc::now(),
+                        correlation_id: Uuid::now_v7(),
+                        causation_id: None,
+                        resource_id,
+                        network_id,
+                        name: "eth0".to_string(),
+                        mac_address: None,
+                        addresses: vec![IpAddressWithCidr::new("10.20.0.5/24").unwrap()],
+                        speed_mbps: Some(10_000),
+                    },
+                )),
+            ),
+        ];
+        for event in events {
+            model.project(event).await.unwrap();
+        }
+        model.handle()
+    }
+
+    #[tokio::test]
+    async fn test_traverses_resource_relationships() {
+        // Arrange
+        let resource_id = Uuid::now_v7();
+        let policy_id = PolicyId::new();
+        let schema = schema(read_model(resource_id, policy_id.clone()).await);
+        let query = format!(
+            r#"{{
+                resource(id: "{}") {{
+                    hostname
+                    status
+                    interfaces {{ name addresses network {{ name cidr interfaces {{ name }} }} }}
+                    policies {{ id resources {{ hostname }} }}
+                }}
+            }}"#,
+            resource_id
+        );
+
+        // Act
+        let response = schema.execute(query).await;
+
+        // Assert
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let data = response.data.into_json().unwrap();
+        let resource = &data["resource"];
+        assert_eq!(resource["hostname"], "web-01");
+        assert_eq!(resource["status"], "PROVISIONING");
+        let interface = &resource["interfaces"][0];
+        assert_eq!(interface["addresses"][0], "10.20.0.5/24");
+        assert_eq!(interface["network"]["name"], "servers");
+        assert_eq!(interface["network"]["interfaces"][0]["name"], "eth0");
+        assert_eq!(resource["policies"][0]["id"], policy_id.to_string());
+        assert_eq!(
+            resource["policies"][0]["resources"][0]["hostname"],
+            "web-01"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_hides_resources_of_other_organizations() {
+        // Arrange: web-01 of acme and db-01 of globex
+        let (web, db) = (Uuid::now_v7(), Uuid::now_v7());
+        let (acme, globex) = (EntityId::new(), EntityId::new());
+        let mut model = InMemoryReadModel::new(ReadModelConfig::default());
+        for (resource_id, hostname, organization_id) in
+            [(web, "web-01", acme.clone()), (db, "db-01", globex)]
+        {
+            let registered = ComputeResourceEvent::ResourceRegistered(ResourceRegistered {
+                event_version: 1,
+                event_id: Uuid::now_v7(),
+                aggregate_id: resource_id,
+                timestamp: Utc::now(),
+                correlation_id: Uuid::now_v7(),
+                causation_id: None,
+                hostname: Hostname::new(hostname).unwrap(),
+                resource_type: ResourceType::PhysicalServer,
+            });
+            let assigned = ComputeResourceEvent::OrganizationAssigned(OrganizationAssigned {
+                event_version: 1,
+                event_id: Uuid::now_v7(),
+                aggregate_id: resource_id,
+                timestamp: Utc::now(),
+                correlation_id: Uuid::now_v7(),
+                causation_id: None,
+                organization_id,
+            });
+            for (sequence, event) in [(1, registered), (2, assigned)] {
+                let event = InfrastructureEvent::ComputeResource(event);
+                model
+                    .project(stored(resource_id, sequence, event))
+                    .await
+                    .unwrap();
+            }
+        }
+        let schema = schema(model.handle());
+        let query = format!(
+            r#"{{ resources {{ hostname }} resource(id: "{db}") {{ hostname }} topology(root: "{db}") {{ root }} }}"#
+        );
+
+        // Act
+        let scoped = EventEnvelopeMetadata::new()
+            .with_tenant(acme.to_string())
+            .scope(schema.execute(query.as_str()))
+            .await;
+        let unscoped = schema.execute(query.as_str()).await;
+
+        // Assert
+        assert!(scoped.errors.is_empty(), "{:?}", scoped.errors);
+        let scoped = scoped.data.into_json().unwrap();
+        assert_eq!(
+            scoped["resources"],
+            serde_json::json!([{"hostname": "web-01"}])
+        );
+        assert!(scoped["resource"].is_null());
+        assert!(scoped["topology"].is_null());
+        let unscoped = unscoped.data.into_json().unwrap();
+        assert_eq!(unscoped["resources"].as_array().unwrap().len(), 2);
+        assert_eq!(unscoped["resource"]["hostname"], "db-01");
+    }
+
+    #[tokio::test]
+    async fn test_rejects_queries_deeper_than_limit() {
+        let schema = schema(read_model(Uuid::now_v7(), PolicyId::new()).await);
+        let cycle = "interfaces { network { ".repeat(MAX_QUERY_DEPTH);
+        let query = format!(
+            "{{ resources {{ {} name {} }} }}",
+            cycle,
+            "} }".repeat(MAX_QUERY_DEPTH)
+        );
+
+        let response = schema.execute(query).await;
+
+        assert!(!response.errors.is_empty());
+    }
+}
@@ -0,0 +1,16 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! HTTP APIs
+//!
+//! Read and write access for clients that cannot speak NATS. Each API is
+//! behind its own feature:
+//!
+//! - [`graphql`] (`graphql`) - GraphQL queries over the in-memory read model
+//! - [`rest`] (`rest`) - REST commands and queries over the service layer
+//! - [`websocket`] (`websocket`) - live event stream with backfill
+
+#[cfg(feature = "graphql")]
+pub mod graphql;
+#[cfg(feature = "rest")]
+pub mod rest;
+#[cfg(feature = "websocket")]
+pub mod websocket;
@@ -0,0 +1,612 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! REST API for Commands and Queries
+//!
+//! Maps HTTP onto the service layer and read models for integrations that
+//! cannot speak NATS. Command replies are the same [`CommandReply`]
+//! envelopes the [command bus](crate::service::command_bus) sends; query
+//! failures are [`QueryReply::Error`]s.
+//!
+//! # Routes
+//!
+//! ```text
+//! POST /resources                  {"hostname": "web-01", "resource_type": "physical_server"}
+//! POST /resources/{id}/policies    {"policy_id": "0193…"}
+//! GET  /resources/{id}             ComputeResourceState
+//! GET  /topology?root=0193…&depth=1 TopologyView
+//! GET  /healthz                    HealthReport   (see health_router)
+//! ```
+//!
+//! # Correlation
+//!
+//! Commands take their correlation ID from the `X-Correlation-ID` header
+//! and their causation ID from `X-Causation-ID`; without the header a new
+//! correlation ID is generated. Every response carries the correlation ID
+//! it was handled under in `X-Correlation-ID`.
+//!
+//! # Authentication
+//!
+//! [`authenticated`] identifies the sender of every request by its
+//! `Authorization: Bearer` token and handles the request under the actor
+//! and tenant the [`Authenticator`] returns, so events record who sent them
+//! and tenancy checks act for the sender's organization. Requests it cannot
+//! identify get a 401.
+//!
+//! Queries are answered through a [`ScopedReadModel`]: resources of other
+//! organizations get a 404, and topology views leave out their nodes.
+//!
+//! # Rate Limits
+//!
+//! [`rate_limited`] puts commands (`POST` requests) through a
+//! [`RateLimiter`], keyed by the organization the sender is authenticated
+//! for, so the router must be [`authenticated`] around it. Refused commands
+//! get a 429 with a `Retry-After` header, or a 503 while the limiter cannot
+//! reach its buckets.
+//!
+//! # Status Codes
+//!
+//! | Outcome                      | Status |
+//! |------------------------------|--------|
+//! | Resource registered          | 201    |
+//! | Other command accepted       | 200    |
+//! | Malformed body or header     | 400    |
+//! | Missing or invalid token     | 401    |
+//! | Actor not authorized         | 403    |
+//! | Query for another org        | 403    |
+//! | Not found                    | 404    |
+//! | Concurrency conflict         | 409    |
+//! | Rejected by business rules   | 422    |
+//! | Organization over its rate   | 429    |
+//! | Query not supported          | 501    |
+//! | Event store / NATS failure   | 503    |
+//! | Health check: not ready      | 503    |
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::api::rest;
+//!
+//! let app = rest::authenticated(
+//!     rest::rate_limited(
+//!         rest::router(Arc::new(service), Arc::new(read_model.handle())),
+//!         limiter,
+//!     ),
+//!     Arc::new(authenticator),
+//! )
+//! .merge(rest::health_router(Arc::new(monitor)));
+//! rest::serve("0.0.0.0:8081".parse()?, app).await?;
+//! ```
+
+use axum::extract::{Path, Query, Request, State};
+use axum::http::{header, HeaderMap, HeaderValue, Method, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use chrono::Utc;
+use cim_domain_policy::PolicyId;
+use serde::{Deserialize, Serialize};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::aggregate::commands::{AddPolicyCommand, RegisterResourceCommand};
+use crate::change_control::ChangeRef;
+use crate::domain::{Hostname, ResourceType};
+use crate::errors::{InfrastructureError, InfrastructureResult};
+use crate::event_store::provenance::EventEnvelopeMetadata;
+use crate::health::HealthMonitor;
+use crate::nats::query::{QueryError, QueryReply, ReadModel, ScopedReadModel, TopologyQuery};
+use crate::service::authentication::{bearer_token, AuthenticationError, Authenticator};
+use crate::service::rate_limit::{RateLimitError, RateLimiter};
+use crate::service::{CommandReply, ComputeResourceService, NackReason};
+
+/// Header carrying the correlation ID
+pub const CORRELATION_ID_HEADER: &str = "x-correlation-id";
+
+/// Header carrying the causation ID
+pub const CAUSATION_ID_HEADER: &str = "x-causation-id";
+
+/// Body of `POST /resources`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RegisterResourceRequest {
+    /// ID of the new resource (generated when omitted); retrying with the
+    /// same ID never registers a second resource
+    #[serde(default)]
+    pub id: Option<Uuid>,
+
+    /// Hostname
+    pub hostname: Hostname,
+
+    /// Resource type
+    pub resource_type: ResourceType,
+
+    /// Change request authorizing the registration
+    #[serde(default)]
+    pub change_ref: Option<ChangeRef>,
+}
+
+/// Body of `POST /resources/{id}/policies`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddPolicyRequest {
+    /// Policy to apply
+    pub policy_id: PolicyId,
+
+    /// Change request authorizing the change
+    #[serde(default)]
+    pub change_ref: Option<ChangeRef>,
+}
+
+/// Shared state of the handlers
+struct ApiState<S, R> {
+    service: Arc<S>,
+    read_model: Arc<R>,
+}
+
+impl<S, R> Clone for ApiState<S, R> {
+    fn clone(&self) -> Self {
+        Self {
+            service: self.service.clone(),
+            read_model: self.read_model.clone(),
+        }
+    }
+}
+
+impl<S, R> ApiState<S, ScopedReadModel<R>> {
+    /// State answering queries for the organization of each request
+    fn scoped(service: Arc<S>, read_model: Arc<R>) -> Self {
+        Self {
+            service,
+            read_model: Arc::new(ScopedReadModel::new(read_model)),
+        }
+    }
+}
+
+/// Routes over `service` (commands) and `read_model` (queries)
+///
+/// Queries are scoped to the organization of each request; see
+/// [`ScopedReadModel`].
+pub fn router<S, R>(service: Arc<S>, read_model: Arc<R>) -> Router
+where
+    S: ComputeResourceService + 'static,
+    R: ReadModel + 'static,
+{
+    Router::new()
+        .route(
+            "/resources",
+            post(register_resource::<S, ScopedReadModel<R>>),
+        )
+        .route("/resources/:id", get(get_resource::<S, ScopedReadModel<R>>))
+        .route(
+            "/resources/:id/policies",
+            post(add_policy::<S, ScopedReadModel<R>>),
+        )
+        .route("/topology", get(topology::<S, ScopedReadModel<R>>))
+        .with_state(ApiState::scoped(service, read_model))
+}
+
+/// `GET /healthz`: the monitor's current report, 200 when ready and 503
+/// otherwise
+pub fn health_router(monitor: Arc<HealthMonitor>) -> Router {
+    Router::new()
+        .route("/healthz", get(healthz))
+        .with_state(monitor)
+}
+
+/// Handle every request to `app` as the sender `authenticator` identifies,
+/// refusing requests it cannot identify
+///
+/// Apply it last, so the layers added before run as the sender too.
+pub fn authenticated(app: Router, authenticator: Arc<dyn Authenticator>) -> Router {
+    app.layer(middleware::from_fn_with_state(authenticator, authenticate))
+}
+
+/// Refuse commands sent to `app` by organizations over their rate
+///
+/// The organization is the authenticated sender's; see [`authenticated`].
+pub fn rate_limited(app: Router, limiter: RateLimiter) -> Router {
+    app.layer(middleware::from_fn_with_state(limiter, limit_commands))
+}
+
+/// Serve `app` over HTTP until the server fails
+pub async fn serve(addr: SocketAddr, app: Router) -> InfrastructureResult<()> {
+    let listener = tokio::net::TcpListener::bind(addr).await.map_err(|e| {
+        InfrastructureError::Configuration(format!("cannot listen on {}: {}", addr, e))
+    })?;
+    info!("REST API listening on http://{}", addr);
+
+    axum::serve(listener, app)
+        .await
+        .map_err(|e| InfrastructureError::Generic(format!("REST server failed: {}", e)))
+}
+
+/// Correlation and causation IDs of a request
+///
+/// A missing correlation ID is generated; malformed IDs are rejected.
+pub fn correlation_ids(headers: &HeaderMap) -> Result<(Uuid, Option<Uuid>), CommandReply> {
+    let parse = |name: &str| -> Result<Option<Uuid>, CommandReply> {
+        headers
+            .get(name)
+            .map(|value| {
+                value
+                    .to_str()
+                    .ok()
+                    .and_then(|value| Uuid::parse_str(value.trim()).ok())
+                    .ok_or_else(|| {
+                        CommandReply::nack(
+                            NackReason::Malformed,
+                            format!("{} header is not a UUID", name),
+                            None,
+                        )
+                    })
+            })
+            .transpose()
+    };
+
+    let correlation_id = parse(CORRELATION_ID_HEADER)?.unwrap_or_else(Uuid::now_v7);
+    Ok((correlation_id, parse(CAUSATION_ID_HEADER)?))
+}
+
+/// HTTP status of a command reply
+pub fn command_status(reply: &CommandReply) -> StatusCode {
+    match reply {
+        CommandReply::Ack { .. } => StatusCode::OK,
+        CommandReply::Nack { reason, .. } => match reason {
+            NackReason::InvalidSubject | NackReason::Malformed => StatusCode::BAD_REQUEST,
+            NackReason::Rejected => StatusCode::UNPROCESSABLE_ENTITY,
+            NackReason::Unauthenticated => StatusCode::UNAUTHORIZED,
+            NackReason::Forbidden => StatusCode::FORBIDDEN,
+            NackReason::NotFound => StatusCode::NOT_FOUND,
+            NackReason::Conflict => StatusCode::CONFLICT,
+            NackReason::RateLimited => StatusCode::TOO_MANY_REQUESTS,
+            NackReason::Unavailable => StatusCode::SERVICE_UNAVAILABLE,
+        },
+    }
+}
+
+/// HTTP status of a failed query
+pub fn query_status(error: &QueryError) -> StatusCode {
+    match error {
+        QueryError::NotFound(_) => StatusCode::NOT_FOUND,
+        QueryError::Unsupported(_) => StatusCode::NOT_IMPLEMENTED,
+        QueryError::Malformed(_) => StatusCode::BAD_REQUEST,
+        QueryError::Unavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
+        QueryError::Unauthenticated(_) => StatusCode::UNAUTHORIZED,
+        QueryError::Forbidden(_) => StatusCode::FORBIDDEN,
+    }
+}
+
+async fn register_resource<S: ComputeResourceService, R>(
+    State(state): State<ApiState<S, R>>,
+    headers: HeaderMap,
+    body: Result<Json<RegisterResourceRequest>, axum::extract::rejection::JsonRejection>,
+) -> Response {
+    let (correlation_id, _) = match correlation_ids(&headers) {
+        Ok(ids) => ids,
+        Err(reply) => return command_response(reply, None),
+    };
+    let Json(request) = match body {
+        Ok(body) => body,
+        Err(e) => return malformed(e.body_text(), correlation_id),
+    };
+
+    let command = RegisterResourceCommand {
+        hostname: request.hostname,
+        resource_type: request.resource_type,
+        timestamp: Utc::now(),
+        correlation_id,
+        change_ref: request.change_ref,
+    };
+    let result = match request.id {
+        Some(id) => state
+            .service
+            .register_resource_as(id, command)
+            .await
+            .map(|_| id),
+        None => state.service.register_resource(command).await,
+    };
+
+    let reply = match result {
+        Ok(aggregate_id) => CommandReply::Ack {
+            aggregate_id,
+            correlation_id,
+        },
+        Err(e) => {
+            warn!("Registration {} rejected: {}", correlation_id, e);
+            CommandReply::from_service_error(&e, correlation_id)
+        }
+    };
+    let location = match &reply {
+        CommandReply::Ack { aggregate_id, .. } => Some(format!("/resources/{}", aggregate_id)),
+        CommandReply::Nack { .. } => None,
+    };
+
+    let mut response = command_response(reply, Some(correlation_id));
+    if let Some(location) = location.and_then(|l| HeaderValue::from_str(&l).ok()) {
+        *response.status_mut() = StatusCode::CREATED;
+        response.headers_mut().insert(header::LOCATION, location);
+    }
+    response
+}
+
+async fn add_policy<S: ComputeResourceService, R>(
+    State(state): State<ApiState<S, R>>,
+    Path(aggregate_id): Path<Uuid>,
+    headers: HeaderMap,
+    body: Result<Json<AddPolicyRequest>, axum::extract::rejection::JsonRejection>,
+) -> Response {
+    let (correlation_id, causation_id) = match correlation_ids(&headers) {
+        Ok(ids) => ids,
+        Err(reply) => return command_response(reply, None),
+    };
+    let Json(request) = match body {
+        Ok(body) => body,
+        Err(e) => return malformed(e.body_text(), correlation_id),
+    };
+
+    let command = AddPolicyCommand {
+        policy_id: request.policy_id,
+        timestamp: Utc::now(),
+        correlation_id,
+        causation_id,
+        change_ref: request.change_ref,
+    };
+    let reply = match state.service.add_policy(aggregate_id, command).await {
+        Ok(()) => CommandReply::Ack {
+            aggregate_id,
+            correlation_id,
+        },
+        Err(e) => {
+            warn!("Policy change {} rejected: {}", correlation_id, e);
+            CommandReply::from_service_error(&e, correlation_id)
+        }
+    };
+    command_response(reply, Some(correlation_id))
+}
+
+async fn get_resource<S, R: ReadModel>(
+    State(state): State<ApiState<S, R>>,
+    Path(aggregate_id): Path<Uuid>,
+) -> Response {
+    query_response(state.read_model.compute_resource(aggregate_id).await)
+}
+
+async fn topology<S, R: ReadModel>(
+    State(state): State<ApiState<S, R>>,
+    query: Result<Query<TopologyQuery>, axum::extract::rejection::QueryRejection>,
+) -> Response {
+    match query {
+        Ok(Query(query)) => query_response(state.read_model.topology(&query).await),
+        Err(e) => query_response::<()>(Err(QueryError::Malformed(e.body_text()))),
+    }
+}
+
+async fn authenticate(
+    State(authenticator): State<Arc<dyn Authenticator>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let token = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(bearer_token);
+
+    match authenticator.authenticate(token).await {
+        Ok(envelope) => envelope.scope(next.run(request)).await,
+        Err(e) => {
+            warn!(
+                "Request to {} not authenticated: {}",
+                request.uri().path(),
+                e
+            );
+            let correlation_id = correlation_ids(request.headers()).ok().map(|(id, _)| id);
+            let mut response = command_response(
+                CommandReply::from_authentication_error(&e, correlation_id),
+                correlation_id,
+            );
+            if !matches!(e, AuthenticationError::Unavailable(_)) {
+                response
+                    .headers_mut()
+                    .insert(header::WWW_AUTHENTICATE, HeaderValue::from_static("Bearer"));
+            }
+            response
+        }
+    }
+}
+
+async fn limit_commands(
+    State(limiter): State<RateLimiter>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if request.method() != Method::POST {
+        return next.run(request).await;
+    }
+    // Malformed IDs are left for the handler to reject
+    let Ok((correlation_id, _)) = correlation_ids(request.headers()) else {
+        return next.run(request).await;
+    };
+    let tenant = EventEnvelopeMetadata::current().and_then(|envelope| envelope.tenant);
+
+    match limiter.check(tenant.as_deref(), correlation_id).await {
+        Ok(()) => next.run(request).await,
+        Err(e) => {
+            warn!("Command {} throttled: {}", correlation_id, e);
+            let mut response = command_response(
+                CommandReply::from_rate_limit_error(&e, correlation_id),
+                Some(correlation_id),
+            );
+            if let RateLimitError::Exhausted(exhausted) = &e {
+                let retry_after = exhausted.retry_after.as_secs_f64().ceil() as u64;
+                response
+                    .headers_mut()
+                    .insert(header::RETRY_AFTER, HeaderValue::from(retry_after.max(1)));
+            }
+            response
+        }
+    }
+}
+
+async fn healthz(State(monitor): State<Arc<HealthMonitor>>) -> Response {
+    let report = monitor.check().await;
+    let status = if report.is_ready() {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (status, Json(report)).into_response()
+}
+
+fn malformed(message: String, correlation_id: Uuid) -> Response {
+    command_response(
+        CommandReply::nack(NackReason::Malformed, message, Some(correlation_id)),
+        Some(correlation_id),
+    )
+}
+
+fn command_response(reply: CommandReply, correlation_id: Option<Uuid>) -> Response {
+    let mut response = (command_status(&reply), Json(reply)).into_response();
+    if let Some(value) = correlation_id.and_then(|id| HeaderValue::from_str(&id.to_string()).ok()) {
+        response.headers_mut().insert(CORRELATION_ID_HEADER, value);
+    }
+    response
+}
+
+fn query_response<T: Serialize>(result: Result<T, QueryError>) -> Response {
+    match result {
+        Ok(value) => Json(value).into_response(),
+        Err(e) => (
+            query_status(&e),
+            Json(QueryReply::Error {
+                code: e.code().to_string(),
+                message: e.to_string(),
+            }),
+        )
+            .into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aggregate::ComputeResourceState;
+    use crate::nats::query::TopologyView;
+    use cim_domain::EntityId;
+
+    #[test]
+    fn test_correlation_ids_from_headers() {
+        // Arrange
+        let correlation_id = Uuid::now_v7();
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            CORRELATION_ID_HEADER,
+            HeaderValue::from_str(&correlation_id.to_string()).unwrap(),
+        );
+        let mut malformed = HeaderMap::new();
+        malformed.insert(CAUSATION_ID_HEADER, HeaderValue::from_static("not-a-uuid"));
+
+        // Act
+        let given = correlation_ids(&headers).unwrap();
+        let generated = correlation_ids(&HeaderMap::new()).unwrap();
+        let rejected = correlation_ids(&malformed).unwrap_err();
+
+        // Assert
+        assert_eq!(given, (correlation_id, None));
+        assert_ne!(generated.0, correlation_id);
+        assert_eq!(command_status(&rejected), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn test_failures_map_to_status_codes() {
+        let conflict = CommandReply::nack(NackReason::Conflict, "stale", Some(Uuid::now_v7()));
+        let rejected = CommandReply::nack(NackReason::Rejected, "invalid", None);
+        let throttled = CommandReply::nack(NackReason::RateLimited, "slow down", None);
+        let anonymous = CommandReply::nack(NackReason::Unauthenticated, "no token", None);
+        let limiter_down = CommandReply::from_rate_limit_error(
+            &RateLimitError::Unavailable("kv down".to_string()),
+            Uuid::now_v7(),
+        );
+
+        assert_eq!(command_status(&conflict), StatusCode::CONFLICT);
+        assert_eq!(command_status(&rejected), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(command_status(&throttled), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(command_status(&anonymous), StatusCode::UNAUTHORIZED);
+        assert_eq!(
+            command_status(&limiter_down),
+            StatusCode::SERVICE_UNAVAILABLE
+        );
+        assert_eq!(
+            query_status(&QueryError::NotFound("x".to_string())),
+            StatusCode::NOT_FOUND
+        );
+        assert_eq!(
+            query_status(&QueryError::Unsupported("topology".to_string())),
+            StatusCode::NOT_IMPLEMENTED
+        );
+        assert_eq!(
+            query_status(&QueryError::Forbidden("listing".to_string())),
+            StatusCode::FORBIDDEN
+        );
+    }
+
+    /// Read model holding one resource of each of two organizations
+    struct TwoTenants([ComputeResourceState; 2]);
+
+    #[async_trait::async_trait]
+    impl ReadModel for TwoTenants {
+        async fn compute_resource(
+            &self,
+            aggregate_id: Uuid,
+        ) -> Result<ComputeResourceState, QueryError> {
+            self.0
+                .iter()
+                .find(|state| state.id == aggregate_id)
+                .cloned()
+                .ok_or_else(|| QueryError::NotFound(aggregate_id.to_string()))
+        }
+
+        async fn topology(&self, query: &TopologyQuery) -> Result<TopologyView, QueryError> {
+            Ok(TopologyView::from_resource(
+                &self.compute_resource(query.root).await?,
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resources_of_other_organizations_are_not_found() {
+        // Arrange
+        let [acme, globex] = [(); 2].map(|_| ComputeResourceState {
+            organization_id: Some(EntityId::new()),
+            ..ComputeResourceState::default_for(Uuid::now_v7())
+        });
+        let state = ApiState::scoped(
+            Arc::new(()),
+            Arc::new(TwoTenants([acme.clone(), globex.clone()])),
+        );
+        let acting = EventEnvelopeMetadata::new()
+            .with_tenant(acme.organization_id.as_ref().unwrap().to_string());
+        let globex_topology = TopologyQuery {
+            root: globex.id,
+            depth: 1,
+        };
+
+        // Act
+        let (own, foreign, foreign_topology) = acting
+            .scope(async {
+                (
+                    get_resource(State(state.clone()), Path(acme.id)).await,
+                    get_resource(State(state.clone()), Path(globex.id)).await,
+                    topology(State(state.clone()), Ok(Query(globex_topology))).await,
+                )
+            })
+            .await;
+        let unscoped = get_resource(State(state.clone()), Path(globex.id)).await;
+
+        // Assert
+        assert_eq!(own.status(), StatusCode::OK);
+        assert_eq!(foreign.status(), StatusCode::NOT_FOUND);
+        assert_eq!(foreign_topology.status(), StatusCode::NOT_FOUND);
+        assert_eq!(unscoped.status(), StatusCode::OK);
+    }
+}
@@ -0,0 +1,314 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! WebSocket Event Stream
+//!
+//! Streams events from the event stream to browser clients, so topology
+//! UIs can update live without embedding a NATS client. Each connection
+//! reads through its own ephemeral JetStream consumer, optionally starting
+//! from an earlier stream sequence to backfill what the UI missed:
+//!
+//! ```text
+//! GET /events/ws?subject=infrastructure.compute.>&from_sequence=1200   (upgrade)
+//!
+//! server ──> {"type": "event", "sequence": 1200, "subject": "infrastructure.compute.0193….registered",
+//!             "event": {"aggregate_type": "compute_resource", "event": {…}}}
+//!        ──> {"type": "event", "sequence": 1201, …}
+//!        ──> {"type": "error", "message": "…"}   (then closes)
+//! ```
+//!
+//! | Parameter       | Default                | Meaning                                          |
+//! |-----------------|------------------------|--------------------------------------------------|
+//! | `subject`       | `infrastructure.*.*.*` | aggregate event subjects to stream, wildcards ok |
+//! | `from_sequence` | none                   | first stream sequence to send; live only if none |
+//!
+//! Subjects must select aggregate events (`infrastructure.<aggregate>.>`
+//! or four tokens), not the derived subjects sharing the stream. Clients
+//! only listen; anything they send other than a close is ignored. The
+//! stream is not scoped to an organization, so serve it to trusted UIs
+//! only.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::api::websocket::EventStreamServer;
+//!
+//! let app = EventStreamServer::new(jetstream, "INFRASTRUCTURE_EVENTS").router();
+//! let listener = tokio::net::TcpListener::bind("0.0.0.0:8082").await?;
+//! axum::serve(listener, app).await?;
+//! ```
+//!
+//! ```javascript
+//! const socket = new WebSocket("ws://localhost:8082/events/ws?subject=infrastructure.compute.>");
+//! socket.onmessage = (message) => updateTopology(JSON.parse(message.data));
+//! ```
+
+use async_nats::jetstream;
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{Query, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::{debug, warn};
+
+use crate::errors::{InfrastructureError, InfrastructureResult};
+use crate::event_store::offload::PayloadOffload;
+use crate::event_store::subscriber::{EventSubscriber, EventSubscriberBuilder};
+use crate::events::InfrastructureEvent;
+use crate::jetstream::{ConsumerConfig, DeliverPolicy};
+use crate::nats::query::{QueryError, QueryReply};
+use crate::subjects::{AggregateType, INFRASTRUCTURE_ROOT};
+
+/// Subject streamed when the client names none: every aggregate event
+pub const DEFAULT_SUBJECT: &str = "infrastructure.*.*.*";
+
+/// Query parameters of `GET /events/ws`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StreamRequest {
+    /// Subject filter
+    #[serde(default = "default_subject")]
+    pub subject: String,
+
+    /// First stream sequence to send
+    #[serde(default)]
+    pub from_sequence: Option<u64>,
+}
+
+fn default_subject() -> String {
+    DEFAULT_SUBJECT.to_string()
+}
+
+/// Message sent to the client, as a JSON text frame
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StreamFrame {
+    /// An event from the stream
+    Event {
+        /// Stream sequence, usable as `from_sequence` when reconnecting
+        sequence: u64,
+
+        /// Subject the event was published on
+        subject: String,
+
+        /// The event
+        event: InfrastructureEvent,
+    },
+
+    /// The stream failed; the server closes the connection
+    Error {
+        /// Human-readable detail
+        message: String,
+    },
+}
+
+/// Check that `subject` selects aggregate events only
+///
+/// Accepts `infrastructure.<aggregate>.>` and four-token subjects whose
+/// aggregate token is an aggregate type or `*`.
+pub fn check_subject(subject: &str) -> Result<(), String> {
+    let tokens: Vec<&str> = subject.split('.').collect();
+    let wildcard_last = tokens
+        .iter()
+        .enumerate()
+        .all(|(index, token)| !token.is_empty() && (*token != ">" || index == tokens.len() - 1));
+    let selects_events = match tokens.as_slice() {
+        [root, aggregate, ">"] => {
+            *root == INFRASTRUCTURE_ROOT && AggregateType::parse(aggregate).is_some()
+        }
+        [root, aggregate, _, _] => {
+            *root == INFRASTRUCTURE_ROOT
+                && (*aggregate == "*" || AggregateType::parse(aggregate).is_some())
+        }
+        _ => false,
+    };
+
+    if wildcard_last && selects_events {
+        Ok(())
+    } else {
+        Err(format!(
+            "{} does not select aggregate events; use {}.<aggregate>.> or four tokens",
+            subject, INFRASTRUCTURE_ROOT
+        ))
+    }
+}
+
+/// Serves the event stream over WebSocket
+#[derive(Clone)]
+pub struct EventStreamServer {
+    jetstream: jetstream::Context,
+    stream_name: Arc<str>,
+    payloads: Option<PayloadOffload>,
+}
+
+impl EventStreamServer {
+    /// Stream events of `stream_name`
+    pub fn new(jetstream: jetstream::Context, stream_name: impl Into<String>) -> Self {
+        Self {
+            jetstream,
+            stream_name: stream_name.into().into(),
+            payloads: None,
+        }
+    }
+
+    /// Resolve payloads the event store offloaded to `offload`'s store
+    pub fn with_payload_offload(mut self, offload: PayloadOffload) -> Self {
+        self.payloads = Some(offload);
+        self
+    }
+
+    /// `GET /events/ws`
+    pub fn router(self) -> Router {
+        Router::new()
+            .route("/events/ws", get(upgrade))
+            .with_state(self)
+    }
+
+    async fn subscribe(&self, request: &StreamRequest) -> InfrastructureResult<EventSubscriber> {
+        let consumer = ConsumerConfig {
+            filter_subject: Some(request.subject.clone()),
+            deliver_policy: match request.from_sequence {
+                Some(sequence) => DeliverPolicy::ByStartSequence(sequence),
+                None => DeliverPolicy::New,
+            },
+            ..ConsumerConfig::default()
+        };
+        let mut builder = EventSubscriberBuilder::new(self.jetstream.clone(), &*self.stream_name)
+            .with_consumer(consumer)
+            .ephemeral();
+        if let Some(offload) = &self.payloads {
+            builder = builder.with_payload_offload(offload.clone());
+        }
+        builder.build().await
+    }
+
+    async fn stream(self, mut socket: WebSocket, request: StreamRequest) {
+        let mut subscriber = match self.subscribe(&request).await {
+            Ok(subscriber) => subscriber,
+            Err(e) => {
+                let _ = send(&mut socket, &failure(&e)).await;
+                return;
+            }
+        };
+        debug!("Streaming {} over WebSocket", request.subject);
+
+        loop {
+            tokio::select! {
+                received = subscriber.next() => {
+                    let frame = match received {
+                        Some(Ok(received)) => StreamFrame::Event {
+                            sequence: received.sequence,
+                            subject: received.subject,
+                            event: received.event,
+                        },
+                        // One undecodable event should not end the stream
+                        Some(Err(e @ InfrastructureError::Deserialization(_))) => {
+                            warn!("Skipped event on WebSocket stream: {}", e);
+                            continue;
+                        }
+                        Some(Err(e)) => {
+                            let _ = send(&mut socket, &failure(&e)).await;
+                            break;
+                        }
+                        None => break,
+                    };
+                    if send(&mut socket, &frame).await.is_err() {
+                        break;
+                    }
+                }
+                incoming = socket.recv() => match incoming {
+                    Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                    Some(Ok(_)) => {}
+                },
+            }
+        }
+        debug!("WebSocket stream of {} closed", request.subject);
+    }
+}
+
+async fn upgrade(
+    State(server): State<EventStreamServer>,
+    ws: WebSocketUpgrade,
+    query: Result<Query<StreamRequest>, axum::extract::rejection::QueryRejection>,
+) -> Response {
+    let request = match query {
+        Ok(Query(request)) => request,
+        Err(e) => return malformed(e.body_text()),
+    };
+    if let Err(message) = check_subject(&request.subject) {
+        return malformed(message);
+    }
+    ws.on_upgrade(move |socket| server.stream(socket, request))
+}
+
+fn malformed(message: String) -> Response {
+    let error = QueryError::Malformed(message);
+    (
+        StatusCode::BAD_REQUEST,
+        Json(QueryReply::Error {
+            code: error.code().to_string(),
+            message: error.to_string(),
+        }),
+    )
+        .into_response()
+}
+
+fn failure(error: &InfrastructureError) -> StreamFrame {
+    StreamFrame::Error {
+        message: error.to_string(),
+    }
+}
+
+async fn send(socket: &mut WebSocket, frame: &StreamFrame) -> Result<(), axum::Error> {
+    let text = serde_json::to_string(frame).map_err(axum::Error::new)?;
+    socket.send(Message::Text(text)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_aggregate_event_subjects_are_streamed() {
+        // Arrange
+        let accepted = [
+            DEFAULT_SUBJECT,
+            "infrastructure.compute.>",
+            "infrastructure.network.*.defined",
+            "infrastructure.compute.0193abcd.*",
+        ];
+        let refused = [
+            "infrastructure.>",
+            "infrastructure.correlation.>",
+            "infrastructure.advisory.subnet_nearly_full",
+            "infrastructure.*.>",
+            "infrastructure.compute.>.registered",
+            "infrastructure..*.*",
+            "other.compute.>",
+        ];
+
+        // Act / Assert
+        for subject in accepted {
+            assert!(check_subject(subject).is_ok(), "{}", subject);
+        }
+        for subject in refused {
+            assert!(check_subject(subject).is_err(), "{}", subject);
+        }
+    }
+
+    #[test]
+    fn test_request_defaults_to_live_aggregate_events() {
+        // Arrange
+        let empty: StreamRequest = serde_json::from_str("{}").unwrap();
+        let backfill: StreamRequest = serde_json::from_value(serde_json::json!({
+            "subject": "infrastructure.compute.>",
+            "from_sequence": 1200,
+        }))
+        .unwrap();
+
+        // Assert
+        assert_eq!(empty.subject, DEFAULT_SUBJECT);
+        assert_eq!(empty.from_sequence, None);
+        assert_eq!(backfill.from_sequence, Some(1200));
+    }
+}
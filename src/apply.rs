@@ -0,0 +1,846 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Declarative Apply
+//!
+//! Converges the event-sourced state towards a [`TopologyDocument`], the
+//! way `kubectl apply` does for manifests:
+//!
+//! ```text
+//! TopologyDocument ─┐
+//!                   ├──ApplyPlan::diff()──> ApplyPlan ──preview (Display)
+//! current state  ───┘                          │
+//! (read model)                                 └──execute()──> commands
+//! ```
+//!
+//! Only the commands needed are planned:
+//!
+//! | Document vs. current state            | Change                             |
+//! |---------------------------------------|------------------------------------|
+//! | resource missing                      | `+` RegisterResource, then details |
+//! | metadata, hardware or status differ   | `~` the matching command           |
+//! | resource absent, `prune` set          | `-` ChangeStatus → decommissioned  |
+//! | network missing                       | `+` DefineNetwork                  |
+//! | network CIDR or VLAN differ           | `~` ChangeCidr / AssignVlan        |
+//!
+//! Resources are matched by hostname (case-insensitively), networks by
+//! name. Fields the document leaves out are left alone, and metadata keys
+//! it doesn't list are kept. Every resource the document manages is tagged
+//! with [`APPLIED_BY_KEY`] set to the document name; pruning only
+//! decommissions resources carrying that tag, so applying one document
+//! never removes what another document or an operator created. Networks
+//! are never pruned.
+//!
+//! Changes the commands cannot express (a different resource type, a
+//! status the lifecycle can't reach) make the diff fail with every such
+//! problem listed, before anything is sent.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::apply::{ApplyOptions, ApplyPlan, TopologyDocument};
+//!
+//! let document = TopologyDocument::from_yaml(&std::fs::read_to_string("site.yaml")?)?;
+//! let snapshot = read_model.snapshot();
+//! let plan = ApplyPlan::diff(
+//!     &document,
+//!     snapshot.resources(),
+//!     snapshot.networks(),
+//!     ApplyOptions::default().with_prune(true),
+//!     Uuid::now_v7(),
+//!     Utc::now(),
+//! )?;
+//!
+//! print!("{}", plan); // preview
+//! plan.execute(&compute_service, &network_service).await?;
+//! ```
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use uuid::Uuid;
+
+use crate::aggregate::commands::{
+    AssignAssetTagCommand, ChangeStatusCommand, ComputeResourceCommand, RegisterResourceCommand,
+    SetHardwareDetailsCommand, UpdateMetadataCommand,
+};
+use crate::aggregate::network::{
+    AssignVlanCommand, ChangeCidrCommand, DefineNetworkCommand, NetworkState,
+};
+use crate::aggregate::ComputeResourceState;
+use crate::domain::{Hostname, IpAddressWithCidr, ResourceType, VlanId};
+use crate::events::ResourceStatus;
+use crate::state_machine::resource_lifecycle::allowed_next_states;
+
+#[cfg(feature = "runtime")]
+use crate::service::network::NetworkService;
+#[cfg(feature = "runtime")]
+use crate::service::{ComputeResourceService, ServiceResult};
+
+/// Metadata key naming the document that manages a resource
+pub const APPLIED_BY_KEY: &str = "applied_by";
+
+/// Apply errors
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ApplyError {
+    /// Document is not valid YAML or JSON of the expected shape
+    #[error("Invalid topology document: {0}")]
+    Parse(String),
+
+    /// The document can't be reached from the current state
+    #[error("Cannot apply topology ({} problems): {}", .0.len(), .0.join("; "))]
+    Invalid(Vec<String>),
+}
+
+/// Desired state of one compute resource
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DesiredResource {
+    /// Hostname, the resource's identity in the document
+    pub hostname: String,
+
+    /// Resource type
+    pub resource_type: ResourceType,
+
+    /// Lifecycle status; left alone when absent
+    #[serde(default)]
+    pub status: Option<ResourceStatus>,
+
+    /// Metadata entries to set
+    #[serde(default)]
+    pub metadata: BTreeMap<String, String>,
+
+    /// Hardware manufacturer
+    #[serde(default)]
+    pub manufacturer: Option<String>,
+
+    /// Hardware model
+    #[serde(default)]
+    pub model: Option<String>,
+
+    /// Serial number
+    #[serde(default)]
+    pub serial_number: Option<String>,
+
+    /// Asset tag
+    #[serde(default)]
+    pub asset_tag: Option<String>,
+}
+
+/// Desired state of one network
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DesiredNetwork {
+    /// Network name, the network's identity in the document
+    pub name: String,
+
+    /// Network prefix (`10.0.1.0/24`)
+    pub cidr: String,
+
+    /// VLAN carrying the network; left alone when absent
+    #[serde(default)]
+    pub vlan_id: Option<u16>,
+
+    /// Layer-2 segment, used when the network is defined
+    #[serde(default)]
+    pub segment: Option<String>,
+}
+
+/// Declarative description of (part of) the infrastructure
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TopologyDocument {
+    /// Document name, recorded on every resource it manages
+    pub name: String,
+
+    /// Networks
+    #[serde(default)]
+    pub networks: Vec<DesiredNetwork>,
+
+    /// Compute resources
+    #[serde(default)]
+    pub resources: Vec<DesiredResource>,
+}
+
+impl TopologyDocument {
+    /// Parse a YAML document
+    pub fn from_yaml(yaml: &str) -> Result<Self, ApplyError> {
+        serde_yaml::from_str(yaml).map_err(|e| ApplyError::Parse(e.to_string()))
+    }
+
+    /// Parse a JSON document
+    pub fn from_json(json: &str) -> Result<Self, ApplyError> {
+        serde_json::from_str(json).map_err(|e| ApplyError::Parse(e.to_string()))
+    }
+}
+
+/// How a diff treats what the document doesn't mention
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApplyOptions {
+    /// Decommission resources this document applied earlier but no longer lists
+    pub prune: bool,
+}
+
+impl ApplyOptions {
+    /// Enable or disable pruning
+    pub fn with_prune(mut self, prune: bool) -> Self {
+        self.prune = prune;
+        self
+    }
+}
+
+/// Kind of a planned change
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeAction {
+    /// Something new is created
+    Create,
+
+    /// Something existing is changed
+    Update,
+
+    /// Something existing is removed
+    Remove,
+}
+
+impl ChangeAction {
+    /// Diff marker (`+`, `~`, `-`)
+    pub fn marker(&self) -> char {
+        match self {
+            ChangeAction::Create => '+',
+            ChangeAction::Update => '~',
+            ChangeAction::Remove => '-',
+        }
+    }
+}
+
+/// Command of a planned change, with the aggregate it targets
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ApplyCommand {
+    /// Compute resource command
+    Compute {
+        /// Resource the command targets; the new ID for registrations
+        aggregate_id: Uuid,
+
+        /// The command
+        command: ComputeResourceCommand,
+    },
+
+    /// Define a new network
+    DefineNetwork {
+        /// The command
+        command: DefineNetworkCommand,
+    },
+
+    /// Renumber a network
+    ChangeCidr {
+        /// Network the command targets
+        network_id: Uuid,
+
+        /// The command
+        command: ChangeCidrCommand,
+    },
+
+    /// Move a network to another VLAN
+    AssignVlan {
+        /// Network the command targets
+        network_id: Uuid,
+
+        /// The command
+        command: AssignVlanCommand,
+    },
+}
+
+/// One step towards the desired state
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PlannedChange {
+    /// Create, update or remove
+    pub action: ChangeAction,
+
+    /// What changes (`resource web-01`, `network lan`)
+    pub subject: String,
+
+    /// Human-readable description of the change
+    pub summary: String,
+
+    /// Command that makes the change
+    pub command: ApplyCommand,
+}
+
+impl fmt::Display for PlannedChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}: {}",
+            self.action.marker(),
+            self.subject,
+            self.summary
+        )
+    }
+}
+
+/// Commands converging the current state to a document
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ApplyPlan {
+    /// Document name
+    pub name: String,
+
+    /// Correlation ID carried by every command
+    pub correlation_id: Uuid,
+
+    /// Timestamp carried by every command
+    pub timestamp: DateTime<Utc>,
+
+    /// Changes in execution order
+    pub changes: Vec<PlannedChange>,
+}
+
+impl fmt::Display for ApplyPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.changes.is_empty() {
+            return writeln!(f, "{}: no changes", self.name);
+        }
+        for change in &self.changes {
+            writeln!(f, "{}", change)?;
+        }
+        writeln!(
+            f,
+            "{}: {} to create, {} to update, {} to remove",
+            self.name,
+            self.count(ChangeAction::Create),
+            self.count(ChangeAction::Update),
+            self.count(ChangeAction::Remove)
+        )
+    }
+}
+
+/// Builds the changes of one diff
+struct Planner<'a> {
+    correlation_id: Uuid,
+    timestamp: DateTime<Utc>,
+    changes: Vec<PlannedChange>,
+    problems: Vec<String>,
+    document: &'a str,
+}
+
+impl Planner<'_> {
+    fn push(
+        &mut self,
+        action: ChangeAction,
+        subject: &str,
+        summary: String,
+        command: ApplyCommand,
+    ) {
+        self.changes.push(PlannedChange {
+            action,
+            subject: subject.to_string(),
+            summary,
+            command,
+        });
+    }
+
+    fn compute(
+        &mut self,
+        action: ChangeAction,
+        subject: &str,
+        summary: String,
+        aggregate_id: Uuid,
+        command: ComputeResourceCommand,
+    ) {
+        self.push(
+            action,
+            subject,
+            summary,
+            ApplyCommand::Compute {
+                aggregate_id,
+                command,
+            },
+        );
+    }
+
+    /// Changes bringing `current` (None for a new resource) to `desired`
+    fn resource(
+        &mut self,
+        desired: &DesiredResource,
+        hostname: Hostname,
+        current: Option<&ComputeResourceState>,
+    ) {
+        let subject = format!("resource {}", hostname);
+        let (aggregate_id, mut status) = match current {
+            Some(current) => {
+                if current.resource_type != desired.resource_type {
+                    self.problems.push(format!(
+                        "{}: resource type is {:?}, document wants {:?}",
+                        subject, current.resource_type, desired.resource_type
+                    ));
+                    return;
+                }
+                (current.id, current.status)
+            }
+            None => {
+                let aggregate_id = Uuid::now_v7();
+                self.compute(
+                    ChangeAction::Create,
+                    &subject,
+                    format!("register ({:?})", desired.resource_type),
+                    aggregate_id,
+                    ComputeResourceCommand::RegisterResource(RegisterResourceCommand {
+                        hostname,
+                        resource_type: desired.resource_type,
+                        timestamp: self.timestamp,
+                        correlation_id: self.correlation_id,
+                        change_ref: None,
+                    }),
+                );
+                (aggregate_id, ResourceStatus::Provisioning)
+            }
+        };
+        let action = if current.is_some() {
+            ChangeAction::Update
+        } else {
+            ChangeAction::Create
+        };
+        let current_metadata: HashMap<&str, &str> = current
+            .map(|current| {
+                current
+                    .metadata
+                    .iter()
+                    .map(|(key, value)| (key.as_str(), value.as_str()))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let owner = (APPLIED_BY_KEY.to_string(), self.document.to_string());
+        for (key, value) in desired.metadata.iter().chain([(&owner.0, &owner.1)]) {
+            let before = current_metadata.get(key.as_str()).copied();
+            if before == Some(value.as_str()) {
+                continue;
+            }
+            self.compute(
+                action,
+                &subject,
+                format!(
+                    "metadata {}: {} -> {}",
+                    key,
+                    before.unwrap_or("(unset)"),
+                    value
+                ),
+                aggregate_id,
+                ComputeResourceCommand::UpdateMetadata(UpdateMetadataCommand {
+                    key: key.clone(),
+                    value: value.clone(),
+                    timestamp: self.timestamp,
+                    correlation_id: self.correlation_id,
+                    causation_id: None,
+                    change_ref: None,
+                }),
+            );
+        }
+
+        let current_hardware = current
+            .map(|c| {
+                (
+                    c.manufacturer.clone(),
+                    c.model.clone(),
+                    c.serial_number.clone(),
+                )
+            })
+            .unwrap_or_default();
+        let hardware = (
+            desired.manufacturer.clone().or(current_hardware.0.clone()),
+            desired.model.clone().or(current_hardware.1.clone()),
+            desired.serial_number.clone().or(current_hardware.2.clone()),
+        );
+        if hardware != current_hardware {
+            let (manufacturer, model, serial_number) = hardware;
+            self.compute(
+                action,
+                &subject,
+                format!(
+                    "hardware {} {} (serial {})",
+                    manufacturer.as_deref().unwrap_or("-"),
+                    model.as_deref().unwrap_or("-"),
+                    serial_number.as_deref().unwrap_or("-")
+                ),
+                aggregate_id,
+                ComputeResourceCommand::SetHardwareDetails(SetHardwareDetailsCommand {
+                    manufacturer,
+                    model,
+                    serial_number,
+                    timestamp: self.timestamp,
+                    correlation_id: self.correlation_id,
+                    causation_id: None,
+                    change_ref: None,
+                }),
+            );
+        }
+
+        if let Some(asset_tag) = &desired.asset_tag {
+            if current.and_then(|c| c.asset_tag.as_ref()) != Some(asset_tag) {
+                self.compute(
+                    action,
+                    &subject,
+                    format!("asset tag {}", asset_tag),
+                    aggregate_id,
+                    ComputeResourceCommand::AssignAssetTag(AssignAssetTagCommand {
+                        asset_tag: asset_tag.clone(),
+                        timestamp: self.timestamp,
+                        correlation_id: self.correlation_id,
+                        causation_id: None,
+                        change_ref: None,
+                    }),
+                );
+            }
+        }
+
+        if let Some(to_status) = desired.status {
+            if to_status != status {
+                self.status(action, &subject, aggregate_id, &mut status, to_status);
+            }
+        }
+    }
+
+    /// Change the status, or record why the lifecycle can't
+    fn status(
+        &mut self,
+        action: ChangeAction,
+        subject: &str,
+        aggregate_id: Uuid,
+        status: &mut ResourceStatus,
+        to_status: ResourceStatus,
+    ) {
+        if !allowed_next_states(*status).contains(&to_status) {
+            self.problems.push(format!(
+                "{}: status {:?} can't move to {:?}",
+                subject, status, to_status
+            ));
+            return;
+        }
+        self.compute(
+            action,
+            subject,
+            format!("status {:?} -> {:?}", status, to_status),
+            aggregate_id,
+            ComputeResourceCommand::ChangeStatus(ChangeStatusCommand {
+                to_status,
+                timestamp: self.timestamp,
+                correlation_id: self.correlation_id,
+                causation_id: None,
+                change_ref: None,
+            }),
+        );
+        *status = to_status;
+    }
+
+    /// Changes bringing `current` (None for a new network) to `desired`
+    fn network(&mut self, desired: &DesiredNetwork, current: Option<&NetworkState>) {
+        let subject = format!("network {}", desired.name);
+        let cidr = IpAddressWithCidr::new(&desired.cidr)
+            .map(|cidr| cidr.network())
+            .map_err(|e| format!("{}: cidr {}: {}", subject, desired.cidr, e));
+        let vlan_id = desired
+            .vlan_id
+            .map(VlanId::new)
+            .transpose()
+            .map_err(|e| format!("{}: vlan_id: {}", subject, e));
+        let (cidr, vlan_id) = match (cidr, vlan_id) {
+            (Ok(cidr), Ok(vlan_id)) => (cidr, vlan_id),
+            (cidr, vlan_id) => {
+                self.problems.extend(cidr.err());
+                self.problems.extend(vlan_id.err());
+                return;
+            }
+        };
+
+        let Some(current) = current else {
+            self.push(
+                ChangeAction::Create,
+                &subject,
+                format!("define {}", cidr),
+                ApplyCommand::DefineNetwork {
+                    command: DefineNetworkCommand {
+                        name: desired.name.clone(),
+                        cidr,
+                        vlan_id,
+                        segment: desired.segment.clone(),
+                        timestamp: self.timestamp,
+                        correlation_id: self.correlation_id,
+                        causation_id: None,
+                    },
+                },
+            );
+            return;
+        };
+
+        if current.cidr.as_ref() != Some(&cidr) {
+            let before = current
+                .cidr
+                .as_ref()
+                .map_or_else(|| "(unset)".to_string(), |cidr| cidr.to_string());
+            self.push(
+                ChangeAction::Update,
+                &subject,
+                format!("cidr {} -> {}", before, cidr),
+                ApplyCommand::ChangeCidr {
+                    network_id: current.id,
+                    command: ChangeCidrCommand {
+                        cidr,
+                        timestamp: self.timestamp,
+                        correlation_id: self.correlation_id,
+                        causation_id: None,
+                    },
+                },
+            );
+        }
+        if let Some(vlan_id) = vlan_id.filter(|vlan_id| current.vlan_id != Some(*vlan_id)) {
+            self.push(
+                ChangeAction::Update,
+                &subject,
+                format!("vlan {}", vlan_id),
+                ApplyCommand::AssignVlan {
+                    network_id: current.id,
+                    command: AssignVlanCommand {
+                        vlan_id,
+                        timestamp: self.timestamp,
+                        correlation_id: self.correlation_id,
+                        causation_id: None,
+                    },
+                },
+            );
+        }
+    }
+}
+
+impl ApplyPlan {
+    /// Diff `document` against the current resources and networks
+    ///
+    /// Decommissioned resources count as absent.
+    pub fn diff<'a>(
+        document: &TopologyDocument,
+        resources: impl IntoIterator<Item = &'a ComputeResourceState>,
+        networks: impl IntoIterator<Item = &'a NetworkState>,
+        options: ApplyOptions,
+        correlation_id: Uuid,
+        timestamp: DateTime<Utc>,
+    ) -> Result<Self, ApplyError> {
+        let mut planner = Planner {
+            correlation_id,
+            timestamp,
+            changes: Vec::new(),
+            problems: Vec::new(),
+            document: &document.name,
+        };
+
+        let networks: HashMap<&str, &NetworkState> = networks
+            .into_iter()
+            .map(|network| (network.name.as_str(), network))
+            .collect();
+        for desired in &document.networks {
+            planner.network(desired, networks.get(desired.name.as_str()).copied());
+        }
+
+        let mut resources: HashMap<String, &ComputeResourceState> = resources
+            .into_iter()
+            .filter(|resource| resource.status != ResourceStatus::Decommissioned)
+            .map(|resource| (resource.hostname.as_str().to_ascii_lowercase(), resource))
+            .collect();
+        for desired in &document.resources {
+            let hostname = match Hostname::new(desired.hostname.as_str()) {
+                Ok(hostname) => hostname,
+                Err(e) => {
+                    planner
+                        .problems
+                        .push(format!("resource {}: {}", desired.hostname, e));
+                    continue;
+                }
+            };
+            let current = resources.remove(&hostname.as_str().to_ascii_lowercase());
+            planner.resource(desired, hostname, current);
+        }
+
+        if options.prune {
+            // What is left was not listed; remove only what this document applied
+            let mut absent: Vec<&ComputeResourceState> = resources
+                .into_values()
+                .filter(|resource| {
+                    resource
+                        .metadata
+                        .iter()
+                        .any(|(key, value)| key == APPLIED_BY_KEY && *value == document.name)
+                })
+                .collect();
+            absent.sort_by(|a, b| a.hostname.as_str().cmp(b.hostname.as_str()));
+            for resource in absent {
+                let mut status = resource.status;
+                planner.status(
+                    ChangeAction::Remove,
+                    &format!("resource {}", resource.hostname),
+                    resource.id,
+                    &mut status,
+                    ResourceStatus::Decommissioned,
+                );
+            }
+        }
+
+        if !planner.problems.is_empty() {
+            return Err(ApplyError::Invalid(planner.problems));
+        }
+        Ok(Self {
+            name: document.name.clone(),
+            correlation_id,
+            timestamp,
+            changes: planner.changes,
+        })
+    }
+
+    /// Whether the current state already matches the document
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Number of changes of one kind
+    pub fn count(&self, action: ChangeAction) -> usize {
+        self.changes
+            .iter()
+            .filter(|change| change.action == action)
+            .count()
+    }
+}
+
+/// Outcome of executing an apply plan
+#[cfg(feature = "runtime")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ApplyReport {
+    /// Changes executed
+    pub applied: usize,
+
+    /// IDs the network service assigned to defined networks, by name
+    pub networks: HashMap<String, Uuid>,
+}
+
+#[cfg(feature = "runtime")]
+impl ApplyPlan {
+    /// Execute the changes in order, stopping at the first rejected command
+    ///
+    /// Changes executed before a rejection stay stored; diffing again
+    /// plans only what is still missing.
+    pub async fn execute(
+        &self,
+        compute: &dyn ComputeResourceService,
+        network: &dyn NetworkService,
+    ) -> ServiceResult<ApplyReport> {
+        let mut report = ApplyReport::default();
+
+        for change in &self.changes {
+            match &change.command {
+                ApplyCommand::Compute {
+                    aggregate_id,
+                    command,
+                } => {
+                    compute
+                        .execute(Some(*aggregate_id), command.clone())
+                        .await?;
+                }
+                ApplyCommand::DefineNetwork { command } => {
+                    let network_id = network.define_network(command.clone()).await?;
+                    report.networks.insert(command.name.clone(), network_id);
+                }
+                ApplyCommand::ChangeCidr {
+                    network_id,
+                    command,
+                } => network.change_cidr(*network_id, command.clone()).await?,
+                ApplyCommand::AssignVlan {
+                    network_id,
+                    command,
+                } => network.assign_vlan(*network_id, command.clone()).await?,
+            }
+            report.applied += 1;
+        }
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_timestamp() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-19T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn resource(hostname: &str, metadata: &[(&str, &str)]) -> ComputeResourceState {
+        let mut state = ComputeResourceState::default_for(Uuid::now_v7());
+        state.hostname = Hostname::new(hostname).unwrap();
+        state.status = ResourceStatus::Active;
+        state.created_at = Some(test_timestamp());
+        state.metadata = metadata
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        state
+    }
+
+    const DOCUMENT: &str = r#"
+name: site-a
+resources:
+  - hostname: web-01
+    resource_type: physical_server
+    metadata:
+      rack: a2
+  - hostname: web-02
+    resource_type: physical_server
+    status: active
+"#;
+
+    #[test]
+    fn test_diff_plans_only_what_differs() {
+        // Arrange: web-01 exists in another rack, old-01 was applied earlier
+        let document = TopologyDocument::from_yaml(DOCUMENT).unwrap();
+        let current = vec![
+            resource("web-01", &[("rack", "a1"), (APPLIED_BY_KEY, "site-a")]),
+            resource("old-01", &[(APPLIED_BY_KEY, "site-a")]),
+            resource("db-01", &[]),
+        ];
+
+        // Act
+        let plan = ApplyPlan::diff(
+            &document,
+            &current,
+            [],
+            ApplyOptions::default().with_prune(true),
+            Uuid::now_v7(),
+            test_timestamp(),
+        )
+        .unwrap();
+
+        // Assert: one update, web-02 registered and activated, old-01 removed
+        let preview = plan.to_string();
+        assert!(preview.contains("~ resource web-01: metadata rack: a1 -> a2"));
+        assert!(preview.contains("+ resource web-02: register (PhysicalServer)"));
+        assert!(preview.contains("+ resource web-02: status Provisioning -> Active"));
+        assert!(preview.contains("- resource old-01: status Active -> Decommissioned"));
+        assert!(!preview.contains("db-01"));
+        assert_eq!(plan.count(ChangeAction::Remove), 1);
+    }
+
+    #[test]
+    fn test_diff_rejects_unreachable_state() {
+        let document = TopologyDocument::from_yaml(DOCUMENT).unwrap();
+        let mut web = resource("WEB-01", &[]);
+        web.resource_type = ResourceType::Switch;
+
+        let result = ApplyPlan::diff(
+            &document,
+            [&web],
+            [],
+            ApplyOptions::default(),
+            Uuid::now_v7(),
+            test_timestamp(),
+        );
+
+        assert!(matches!(
+            result,
+            Err(ApplyError::Invalid(problems)) if problems[0].contains("resource type is Switch")
+        ));
+    }
+}
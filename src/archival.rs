@@ -0,0 +1,298 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Archival Candidates
+//!
+//! Finds compute resources that have had no events for a configurable idle
+//! period and are not active, so compaction and archival start from a list
+//! of concrete aggregates instead of someone searching the event store:
+//!
+//! ```text
+//! ReadModelHandle ──resources──> ArchivalAnalyzer ──find_stale_aggregates()──> Vec<StaleAggregate>
+//!                                      │
+//!                                      ├──publish──> infrastructure.advisory.archival_candidate
+//!                                      └──> ArchivalHandle ──> infrastructure.query.archival.candidates
+//! ```
+//!
+//! Detection is pure ([`find_stale_aggregates`]): a resource is stale when
+//! its last event (`updated_at`) is older than the idle period and its
+//! status is anything but `active`. With the runtime, [`ArchivalAnalyzer`]
+//! runs it on an interval, publishes an [`ArchivalCandidate`] advisory the
+//! first time an aggregate is found stale, and keeps the current list on an
+//! [`ArchivalHandle`] for queries.
+//!
+//! Only compute resources carry a lifecycle status, so networks and
+//! connections are never reported.
+//!
+//! With the `cold-storage` feature, [`cold_storage`] moves the history of
+//! decommissioned aggregates out of JetStream into object storage.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::archival::{ArchivalAnalyzer, ArchivalConfig};
+//!
+//! let mut analyzer = ArchivalAnalyzer::new(nats_client, read_model.clone(), ArchivalConfig::default());
+//! let read_model = read_model.with_archival(analyzer.handle());
+//!
+//! tokio::spawn(async move { analyzer.run(Duration::from_secs(24 * 3600)).await });
+//! QueryResponder::new(nats_client, Arc::new(read_model)).run().await?;
+//! ```
+
+#[cfg(feature = "runtime")]
+use arc_swap::ArcSwap;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "runtime")]
+use std::collections::HashSet;
+#[cfg(feature = "runtime")]
+use std::sync::Arc;
+#[cfg(feature = "runtime")]
+use tracing::info;
+use uuid::Uuid;
+
+use crate::aggregate::ComputeResourceState;
+#[cfg(feature = "runtime")]
+use crate::errors::InfrastructureResult;
+#[cfg(feature = "clock")]
+use crate::events::advisory::{AdvisoryEvent, ArchivalCandidate};
+use crate::events::ResourceStatus;
+#[cfg(feature = "runtime")]
+use crate::nats::NatsClient;
+#[cfg(feature = "runtime")]
+use crate::projection::read_model::ReadModelHandle;
+
+#[cfg(feature = "cold-storage")]
+pub mod cold_storage;
+
+/// Archival analysis settings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArchivalConfig {
+    /// Days without events after which an inactive resource is stale
+    #[serde(default = "default_idle_days")]
+    pub idle_days: u32,
+}
+
+fn default_idle_days() -> u32 {
+    180
+}
+
+impl Default for ArchivalConfig {
+    fn default() -> Self {
+        Self {
+            idle_days: default_idle_days(),
+        }
+    }
+}
+
+/// Resource idle long enough to be archived
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct StaleAggregate {
+    /// Idle aggregate
+    pub aggregate_id: Uuid,
+
+    /// Hostname of the resource
+    pub hostname: String,
+
+    /// Current status (never `active`)
+    pub status: ResourceStatus,
+
+    /// Organization of the resource, if assigned
+    pub organization_id: Option<String>,
+
+    /// Timestamp of the resource's last event
+    pub last_event_at: DateTime<Utc>,
+
+    /// Whole days since the last event
+    #[cfg_attr(feature = "typescript", ts(type = "number"))]
+    pub idle_days: i64,
+}
+
+impl StaleAggregate {
+    /// Build the advisory event for this finding
+    #[cfg(feature = "clock")]
+    pub fn to_advisory(&self, correlation_id: Uuid) -> AdvisoryEvent {
+        AdvisoryEvent::ArchivalCandidate(ArchivalCandidate {
+            event_id: Uuid::now_v7(),
+            detected_at: Utc::now(),
+            correlation_id,
+            candidate: self.clone(),
+        })
+    }
+}
+
+/// Resources without events for `config.idle_days` that are not active
+///
+/// Results are ordered by last event, the longest idle first.
+pub fn find_stale_aggregates<'a>(
+    resources: impl IntoIterator<Item = &'a ComputeResourceState>,
+    config: &ArchivalConfig,
+    now: DateTime<Utc>,
+) -> Vec<StaleAggregate> {
+    let cutoff = now - Duration::days(i64::from(config.idle_days));
+
+    let mut stale: Vec<StaleAggregate> = resources
+        .into_iter()
+        .filter(|resource| resource.status != ResourceStatus::Active)
+        .filter_map(|resource| {
+            let last_event_at = resource.updated_at.or(resource.created_at)?;
+            (last_event_at < cutoff).then(|| StaleAggregate {
+                aggregate_id: resource.id,
+                hostname: resource.hostname.to_string(),
+                status: resource.status,
+                organization_id: resource.organization_id.as_ref().map(ToString::to_string),
+                last_event_at,
+                idle_days: (now - last_event_at).num_days(),
+            })
+        })
+        .collect();
+
+    stale.sort_by(|a, b| {
+        a.last_event_at
+            .cmp(&b.last_event_at)
+            .then(a.aggregate_id.cmp(&b.aggregate_id))
+    });
+    stale
+}
+
+/// Current archival candidates, shared between the analyzer and query
+/// handlers
+///
+/// Cloning is cheap; all clones see the same candidates.
+#[cfg(feature = "runtime")]
+#[derive(Clone, Default)]
+pub struct ArchivalHandle {
+    candidates: Arc<ArcSwap<Vec<StaleAggregate>>>,
+}
+
+#[cfg(feature = "runtime")]
+impl ArchivalHandle {
+    /// Candidates found by the latest analysis, longest idle first
+    pub fn current(&self) -> Vec<StaleAggregate> {
+        self.candidates.load().as_ref().clone()
+    }
+
+    /// Candidates of one organization
+    pub fn for_organization(&self, organization_id: &str) -> Vec<StaleAggregate> {
+        self.candidates
+            .load()
+            .iter()
+            .filter(|c| c.organization_id.as_deref() == Some(organization_id))
+            .cloned()
+            .collect()
+    }
+
+    fn replace(&self, candidates: Vec<StaleAggregate>) {
+        self.candidates.store(Arc::new(candidates));
+    }
+}
+
+/// Periodically looks for stale aggregates and publishes advisories
+#[cfg(feature = "runtime")]
+pub struct ArchivalAnalyzer {
+    client: NatsClient,
+    read_model: ReadModelHandle,
+    config: ArchivalConfig,
+    reported: HashSet<Uuid>,
+    candidates: ArchivalHandle,
+}
+
+#[cfg(feature = "runtime")]
+impl ArchivalAnalyzer {
+    /// Analyze the resources of `read_model`
+    pub fn new(client: NatsClient, read_model: ReadModelHandle, config: ArchivalConfig) -> Self {
+        Self {
+            client,
+            read_model,
+            config,
+            reported: HashSet::new(),
+            candidates: ArchivalHandle::default(),
+        }
+    }
+
+    /// Handle onto the current candidates
+    pub fn handle(&self) -> ArchivalHandle {
+        self.candidates.clone()
+    }
+
+    /// Analyze once, publishing advisories for newly stale aggregates
+    ///
+    /// Returns every current candidate, new or not. An aggregate that
+    /// becomes active again (or receives events) drops off the list and is
+    /// reported again if it goes stale later.
+    pub async fn analyze_once(&mut self) -> InfrastructureResult<Vec<StaleAggregate>> {
+        let snapshot = self.read_model.snapshot();
+        let stale = find_stale_aggregates(snapshot.resources(), &self.config, Utc::now());
+        let correlation_id = Uuid::now_v7();
+
+        let current: HashSet<Uuid> = stale.iter().map(|c| c.aggregate_id).collect();
+        self.reported.retain(|id| current.contains(id));
+
+        for candidate in &stale {
+            if self.reported.insert(candidate.aggregate_id) {
+                info!(
+                    "Archival candidate {} ({}): idle {} days",
+                    candidate.hostname, candidate.aggregate_id, candidate.idle_days
+                );
+                let advisory = candidate.to_advisory(correlation_id);
+                self.client.publish(&advisory.subject(), &advisory).await?;
+            }
+        }
+
+        self.candidates.replace(stale.clone());
+        Ok(stale)
+    }
+
+    /// Analyze on a fixed interval until an error occurs
+    pub async fn run(&mut self, interval: std::time::Duration) -> InfrastructureResult<()> {
+        info!("Starting archival analyzer (interval: {:?})", interval);
+        let mut ticker = tokio::time::interval(interval);
+
+        loop {
+            ticker.tick().await;
+            self.analyze_once().await?;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Hostname, ResourceType};
+
+    fn test_timestamp() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-19T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn resource(hostname: &str, status: ResourceStatus, idle_days: i64) -> ComputeResourceState {
+        let mut state = ComputeResourceState::default_for(Uuid::now_v7());
+        state.hostname = Hostname::new(hostname).unwrap();
+        state.resource_type = ResourceType::PhysicalServer;
+        state.status = status;
+        state.created_at = Some(test_timestamp() - Duration::days(idle_days + 30));
+        state.updated_at = Some(test_timestamp() - Duration::days(idle_days));
+        state
+    }
+
+    #[test]
+    fn test_finds_idle_inactive_resources_oldest_first() {
+        // Arrange
+        let resources = [
+            resource("old-active", ResourceStatus::Active, 400),
+            resource("recently-retired", ResourceStatus::Decommissioned, 30),
+            resource("retired", ResourceStatus::Decommissioned, 200),
+            resource("forgotten", ResourceStatus::Maintenance, 365),
+        ];
+
+        // Act
+        let stale = find_stale_aggregates(&resources, &ArchivalConfig::default(), test_timestamp());
+
+        // Assert
+        let hostnames: Vec<&str> = stale.iter().map(|c| c.hostname.as_str()).collect();
+        assert_eq!(hostnames, vec!["forgotten", "retired"]);
+        assert_eq!(stale[0].idle_days, 365);
+        assert_eq!(stale[1].status, ResourceStatus::Decommissioned);
+    }
+}
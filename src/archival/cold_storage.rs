@@ -0,0 +1,513 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Cold Storage Archives
+//!
+//! Moves the event history of decommissioned aggregates out of JetStream
+//! into object storage (S3 or anything S3-compatible), and brings it back
+//! when an audit needs it:
+//!
+//! ```text
+//! archive(id):  read events ──> events.jsonl.gz ──put──> <prefix>/<id>/events.jsonl.gz
+//!                           └─> manifest.json   ──put──> <prefix>/<id>/manifest.json
+//!               read back + verify checksum ──> purge aggregate subjects from JetStream
+//!
+//! restore(id):  get manifest + events ──verify──> append to a target EventStore
+//! ```
+//!
+//! The events file holds one [`StoredEvent`] per line, in sequence order,
+//! gzip-compressed. The [`ArchiveManifest`] records counts, sequence and
+//! time ranges, and SHA-256 checksums of both the compressed file and its
+//! contents. The manifest is written last and the archive is read back and
+//! verified before anything is purged, so an interrupted run leaves the
+//! events in JetStream.
+//!
+//! Only aggregates that reached their end are archived: compute resources
+//! whose status is `decommissioned`, and removed connections. Correlation
+//! and change index copies stay in the stream. Events already compacted
+//! away are not in the stream and so not in the archive; their snapshot
+//! stays in the snapshot bucket.
+//!
+//! Restoring appends the archived domain events to the given store as a
+//! new history (expected version 0), typically a separate audit stream
+//! rather than production; the original envelopes are returned by
+//! [`ColdStorage::load`].
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::archival::cold_storage::{Archiver, ColdStorage};
+//!
+//! // Credentials, region and endpoint (for MinIO and the like) come from
+//! // the usual AWS_* variables
+//! let storage = ColdStorage::s3_from_env("infra-archive", "events")?;
+//! let archiver = Archiver::new(Arc::new(event_store), storage.clone());
+//!
+//! for candidate in archival_handle.current() {
+//!     let report = archiver.archive(candidate.aggregate_id).await?;
+//!     println!("{} events archived, {} purged", report.manifest.event_count, report.purged);
+//! }
+//!
+//! archiver.restore(aggregate_id, &audit_store).await?;
+//! ```
+
+use chrono::{DateTime, Utc};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use object_store::path::Path;
+use object_store::ObjectStore;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::io::{BufRead, BufReader, Write};
+use std::sync::Arc;
+use tracing::info;
+use uuid::Uuid;
+
+use crate::aggregate::ComputeResourceState;
+use crate::audit::hex;
+use crate::event_store::{EventStore, NatsEventStore};
+use crate::events::{ComputeResourceEvent, ConnectionEvent, InfrastructureEvent, ResourceStatus};
+use crate::jetstream::StoredEvent;
+
+/// Version of the archive layout written by this crate
+pub const ARCHIVE_FORMAT_VERSION: u32 = 1;
+
+/// File name of the compressed events within an archive
+pub const EVENTS_FILE: &str = "events.jsonl.gz";
+
+/// File name of the manifest within an archive
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+/// Why an archive could not be written, read or restored
+#[derive(Debug, thiserror::Error)]
+pub enum ArchiveError {
+    /// The aggregate has no events in the stream
+    #[error("Aggregate {0} has no events to archive")]
+    Empty(Uuid),
+
+    /// The aggregate has not reached its end of life
+    #[error("Aggregate {aggregate_id} is not decommissioned ({reason})")]
+    NotDecommissioned { aggregate_id: Uuid, reason: String },
+
+    /// No archive exists for the aggregate
+    #[error("No archive for aggregate {0}")]
+    NotFound(Uuid),
+
+    /// The archive does not match its manifest
+    #[error("Archive of {aggregate_id} is corrupt: {reason}")]
+    Corrupt { aggregate_id: Uuid, reason: String },
+
+    /// Object storage failed
+    #[error("Object storage error: {0}")]
+    Storage(String),
+
+    /// Event store failed
+    #[error("Event store error: {0}")]
+    EventStore(String),
+}
+
+/// Description of one archive, stored next to its events
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArchiveManifest {
+    /// Archive layout version
+    pub format_version: u32,
+
+    /// Archived aggregate
+    pub aggregate_id: Uuid,
+
+    /// Events in the archive
+    pub event_count: u64,
+
+    /// Aggregate version of the first archived event
+    pub first_sequence: u64,
+
+    /// Aggregate version of the last archived event
+    pub last_sequence: u64,
+
+    /// Timestamp of the first archived event
+    pub first_event_at: DateTime<Utc>,
+
+    /// Timestamp of the last archived event
+    pub last_event_at: DateTime<Utc>,
+
+    /// When the archive was written
+    pub archived_at: DateTime<Utc>,
+
+    /// Size of the compressed events file
+    pub compressed_bytes: u64,
+
+    /// SHA-256 of the compressed events file, hex
+    pub compressed_sha256: String,
+
+    /// SHA-256 of the uncompressed JSONL, hex
+    pub content_sha256: String,
+}
+
+/// Encode events as gzip-compressed JSONL with their manifest
+///
+/// `events` must be one aggregate's events in sequence order.
+pub fn encode_archive(
+    aggregate_id: Uuid,
+    events: &[StoredEvent<InfrastructureEvent>],
+    archived_at: DateTime<Utc>,
+) -> Result<(Vec<u8>, ArchiveManifest), ArchiveError> {
+    let (Some(first), Some(last)) = (events.first(), events.last()) else {
+        return Err(ArchiveError::Empty(aggregate_id));
+    };
+
+    let mut jsonl = Vec::new();
+    for event in events {
+        serde_json::to_writer(&mut jsonl, event).map_err(|e| corrupt(aggregate_id, e))?;
+        jsonl.push(b'\n');
+    }
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(&jsonl)
+        .map_err(|e| ArchiveError::Storage(e.to_string()))?;
+    let compressed = encoder
+        .finish()
+        .map_err(|e| ArchiveError::Storage(e.to_string()))?;
+
+    let manifest = ArchiveManifest {
+        format_version: ARCHIVE_FORMAT_VERSION,
+        aggregate_id,
+        event_count: events.len() as u64,
+        first_sequence: first.sequence,
+        last_sequence: last.sequence,
+        first_event_at: first.timestamp,
+        last_event_at: last.timestamp,
+        archived_at,
+        compressed_bytes: compressed.len() as u64,
+        compressed_sha256: sha256(&compressed),
+        content_sha256: sha256(&jsonl),
+    };
+    Ok((compressed, manifest))
+}
+
+/// Decode an events file, checking it against its manifest
+pub fn decode_archive(
+    manifest: &ArchiveManifest,
+    compressed: &[u8],
+) -> Result<Vec<StoredEvent<InfrastructureEvent>>, ArchiveError> {
+    let aggregate_id = manifest.aggregate_id;
+    if sha256(compressed) != manifest.compressed_sha256 {
+        return Err(corrupt(aggregate_id, "checksum of the events file differs"));
+    }
+
+    let mut events = Vec::new();
+    let mut content = Sha256::new();
+    for line in BufReader::new(GzDecoder::new(compressed)).lines() {
+        let line = line.map_err(|e| corrupt(aggregate_id, e))?;
+        content.update(line.as_bytes());
+        content.update(b"\n");
+        events.push(serde_json::from_str(&line).map_err(|e| corrupt(aggregate_id, e))?);
+    }
+
+    if hex(&content.finalize()) != manifest.content_sha256 {
+        return Err(corrupt(aggregate_id, "checksum of the contents differs"));
+    }
+    if events.len() as u64 != manifest.event_count {
+        return Err(corrupt(
+            aggregate_id,
+            format!(
+                "{} events, manifest lists {}",
+                events.len(),
+                manifest.event_count
+            ),
+        ));
+    }
+    Ok(events)
+}
+
+/// Whether an aggregate's history has reached its end
+///
+/// Err carries the reason it has not.
+pub fn check_decommissioned(events: &[StoredEvent<InfrastructureEvent>]) -> Result<(), String> {
+    match events.last().map(|event| &event.data) {
+        Some(InfrastructureEvent::ComputeResource(_)) => {
+            let compute: Vec<ComputeResourceEvent> = events
+                .iter()
+                .filter_map(|event| match &event.data {
+                    InfrastructureEvent::ComputeResource(event) => Some(event.clone()),
+                    _ => None,
+                })
+                .collect();
+            let status = ComputeResourceState::from_events(&compute).status;
+            if status == ResourceStatus::Decommissioned {
+                Ok(())
+            } else {
+                Err(format!("status is {:?}", status))
+            }
+        }
+        Some(InfrastructureEvent::Connection(ConnectionEvent::ConnectionRemoved(_))) => Ok(()),
+        Some(InfrastructureEvent::Connection(_)) => Err("connection not removed".to_string()),
+        Some(other) => Err(format!(
+            "{} aggregates have no end of life",
+            other.aggregate_type()
+        )),
+        None => Err("no events".to_string()),
+    }
+}
+
+/// Archives in an object store, under a key prefix
+#[derive(Clone)]
+pub struct ColdStorage {
+    store: Arc<dyn ObjectStore>,
+    prefix: Path,
+}
+
+impl ColdStorage {
+    /// Archives in `store` under `prefix`
+    pub fn new(store: Arc<dyn ObjectStore>, prefix: impl Into<String>) -> Self {
+        Self {
+            store,
+            prefix: Path::from(prefix.into()),
+        }
+    }
+
+    /// Archives in an S3 bucket configured from the `AWS_*` environment
+    /// (set `AWS_ENDPOINT` for S3-compatible stores)
+    pub fn s3_from_env(bucket: &str, prefix: impl Into<String>) -> Result<Self, ArchiveError> {
+        let store = object_store::aws::AmazonS3Builder::from_env()
+            .with_bucket_name(bucket)
+            .build()
+            .map_err(storage)?;
+        Ok(Self::new(Arc::new(store), prefix))
+    }
+
+    fn path(&self, aggregate_id: Uuid, file: &str) -> Path {
+        self.prefix
+            .child(aggregate_id.to_string())
+            .child(file.to_string())
+    }
+
+    /// Write an archive; the manifest goes last
+    pub async fn put(
+        &self,
+        manifest: &ArchiveManifest,
+        compressed: Vec<u8>,
+    ) -> Result<(), ArchiveError> {
+        let aggregate_id = manifest.aggregate_id;
+        self.store
+            .put(&self.path(aggregate_id, EVENTS_FILE), compressed.into())
+            .await
+            .map_err(storage)?;
+        let manifest_json =
+            serde_json::to_vec_pretty(manifest).map_err(|e| corrupt(aggregate_id, e))?;
+        self.store
+            .put(
+                &self.path(aggregate_id, MANIFEST_FILE),
+                manifest_json.into(),
+            )
+            .await
+            .map_err(storage)?;
+        Ok(())
+    }
+
+    /// Manifest of an aggregate's archive
+    pub async fn manifest(&self, aggregate_id: Uuid) -> Result<ArchiveManifest, ArchiveError> {
+        let bytes = self.get(aggregate_id, MANIFEST_FILE).await?;
+        serde_json::from_slice(&bytes).map_err(|e| corrupt(aggregate_id, e))
+    }
+
+    /// Read and verify an aggregate's archive
+    pub async fn load(
+        &self,
+        aggregate_id: Uuid,
+    ) -> Result<(ArchiveManifest, Vec<StoredEvent<InfrastructureEvent>>), ArchiveError> {
+        let manifest = self.manifest(aggregate_id).await?;
+        let compressed = self.get(aggregate_id, EVENTS_FILE).await?;
+        let events = decode_archive(&manifest, &compressed)?;
+        Ok((manifest, events))
+    }
+
+    async fn get(&self, aggregate_id: Uuid, file: &str) -> Result<Vec<u8>, ArchiveError> {
+        match self.store.get(&self.path(aggregate_id, file)).await {
+            Ok(result) => Ok(result.bytes().await.map_err(storage)?.to_vec()),
+            Err(object_store::Error::NotFound { .. }) => Err(ArchiveError::NotFound(aggregate_id)),
+            Err(e) => Err(storage(e)),
+        }
+    }
+}
+
+/// Result of archiving one aggregate
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchiveReport {
+    /// Manifest of the written archive
+    pub manifest: ArchiveManifest,
+
+    /// Messages purged from JetStream
+    pub purged: u64,
+}
+
+/// Moves aggregates between JetStream and cold storage
+pub struct Archiver {
+    event_store: Arc<NatsEventStore>,
+    storage: ColdStorage,
+}
+
+impl Archiver {
+    /// Archive from `event_store` into `storage`
+    pub fn new(event_store: Arc<NatsEventStore>, storage: ColdStorage) -> Self {
+        Self {
+            event_store,
+            storage,
+        }
+    }
+
+    /// Archive a decommissioned aggregate and purge it from JetStream
+    pub async fn archive(&self, aggregate_id: Uuid) -> Result<ArchiveReport, ArchiveError> {
+        let events = self
+            .event_store
+            .read_events(aggregate_id)
+            .await
+            .map_err(|e| ArchiveError::EventStore(e.to_string()))?;
+        if events.is_empty() {
+            return Err(ArchiveError::Empty(aggregate_id));
+        }
+        check_decommissioned(&events).map_err(|reason| ArchiveError::NotDecommissioned {
+            aggregate_id,
+            reason,
+        })?;
+
+        let (compressed, manifest) = encode_archive(aggregate_id, &events, Utc::now())?;
+        self.storage.put(&manifest, compressed).await?;
+
+        // Never purge what cannot be read back
+        let (stored, _) = self.storage.load(aggregate_id).await?;
+        if stored != manifest {
+            return Err(corrupt(aggregate_id, "manifest read back differs"));
+        }
+
+        let purged = self
+            .event_store
+            .purge_aggregate(aggregate_id)
+            .await
+            .map_err(|e| ArchiveError::EventStore(e.to_string()))?;
+        info!(
+            "Archived {} events of {} and purged {} messages",
+            manifest.event_count, aggregate_id, purged
+        );
+
+        Ok(ArchiveReport { manifest, purged })
+    }
+
+    /// Append an archived aggregate's events to `target`, returning its
+    /// new version
+    pub async fn restore(
+        &self,
+        aggregate_id: Uuid,
+        target: &dyn EventStore,
+    ) -> Result<u64, ArchiveError> {
+        let (_, events) = self.storage.load(aggregate_id).await?;
+        let version = target
+            .append(
+                aggregate_id,
+                events.into_iter().map(|event| event.data).collect(),
+                Some(0),
+            )
+            .await
+            .map_err(|e| ArchiveError::EventStore(e.to_string()))?;
+        info!(
+            "Restored {} from cold storage at version {}",
+            aggregate_id, version
+        );
+        Ok(version)
+    }
+}
+
+fn sha256(bytes: &[u8]) -> String {
+    hex(&Sha256::digest(bytes))
+}
+
+fn corrupt(aggregate_id: Uuid, reason: impl ToString) -> ArchiveError {
+    ArchiveError::Corrupt {
+        aggregate_id,
+        reason: reason.to_string(),
+    }
+}
+
+fn storage(error: impl ToString) -> ArchiveError {
+    ArchiveError::Storage(error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::StatusChanged;
+    use crate::test_harness;
+    use object_store::memory::InMemory;
+
+    fn history(aggregate_id: Uuid, decommissioned: bool) -> Vec<StoredEvent<InfrastructureEvent>> {
+        let mut events = vec![test_harness::stored(
+            1,
+            test_harness::registered(aggregate_id, "web-01"),
+        )];
+        if decommissioned {
+            let retired = ComputeResourceEvent::StatusChanged(StatusChanged {
+                event_version: 1,
+                event_id: Uuid::now_v7(),
+                aggregate_id,
+                timestamp: test_harness::test_timestamp(),
+                correlation_id: Uuid::now_v7(),
+                causation_id: None,
+                from_status: ResourceStatus::Provisioning,
+                to_status: ResourceStatus::Decommissioned,
+            });
+            events.push(test_harness::stored(
+                2,
+                InfrastructureEvent::ComputeResource(retired),
+            ));
+        }
+        events
+    }
+
+    fn event_ids(events: &[StoredEvent<InfrastructureEvent>]) -> Vec<Uuid> {
+        events.iter().map(|event| event.event_id).collect()
+    }
+
+    #[test]
+    fn test_archive_round_trips_and_detects_tampering() {
+        // Arrange
+        let aggregate_id = Uuid::now_v7();
+        let events = history(aggregate_id, true);
+
+        // Act
+        let (compressed, manifest) = encode_archive(aggregate_id, &events, Utc::now()).unwrap();
+        let decoded = decode_archive(&manifest, &compressed).unwrap();
+        let mut tampered = compressed.clone();
+        let last = tampered.len() - 1;
+        tampered[last] ^= 0xff;
+
+        // Assert
+        assert_eq!(event_ids(&decoded), event_ids(&events));
+        assert_eq!(decoded[1].data, events[1].data);
+        assert_eq!(manifest.event_count, 2);
+        assert_eq!((manifest.first_sequence, manifest.last_sequence), (1, 2));
+        assert_eq!(manifest.compressed_bytes, compressed.len() as u64);
+        assert!(matches!(
+            decode_archive(&manifest, &tampered),
+            Err(ArchiveError::Corrupt { .. })
+        ));
+        assert!(check_decommissioned(&events).is_ok());
+        assert!(check_decommissioned(&history(aggregate_id, false)).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_cold_storage_keeps_archives_by_aggregate() {
+        // Arrange
+        let storage = ColdStorage::new(Arc::new(InMemory::new()), "archive");
+        let aggregate_id = Uuid::now_v7();
+        let events = history(aggregate_id, true);
+        let (compressed, manifest) = encode_archive(aggregate_id, &events, Utc::now()).unwrap();
+
+        // Act
+        storage.put(&manifest, compressed).await.unwrap();
+        let (loaded_manifest, loaded) = storage.load(aggregate_id).await.unwrap();
+        let missing = storage.load(Uuid::now_v7()).await;
+
+        // Assert
+        assert_eq!(loaded_manifest, manifest);
+        assert_eq!(event_ids(&loaded), event_ids(&events));
+        assert!(matches!(missing, Err(ArchiveError::NotFound(_))));
+    }
+}
@@ -0,0 +1,669 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! AsyncAPI Specification
+//!
+//! Builds an AsyncAPI 3.0 document describing the NATS contract of this
+//! crate: event (untenanted and per tenant), correlation and change index,
+//! advisory, policy, alert, scorecard, feed, notification, progress,
+//! observation and health subjects, and the request/reply channels of the
+//! command bus, read-model queries and discovery agents.
+//!
+//! Channel addresses come from [`subjects`](crate::subjects) and payload
+//! schemas are derived from the Rust types (including their serde tags and
+//! renames), so the document follows the code rather than a hand-maintained
+//! copy. Every subject of the
+//! [`SubjectRegistry`](crate::subjects::registry::SubjectRegistry) has a
+//! channel of the same name carrying its payload types as messages.
+//!
+//! # Generating
+//!
+//! ```text
+//! cargo run --bin export-asyncapi --features asyncapi -- asyncapi.json
+//! ```
+
+use schemars::gen::{SchemaGenerator, SchemaSettings};
+use schemars::JsonSchema;
+use serde_json::{json, Map, Value};
+
+use crate::aggregate::ComputeResourceState;
+use crate::archival::StaleAggregate;
+use crate::discovery::facts::HostFacts;
+use crate::enrichment::EnrichedResource;
+use crate::events::advisory::{advisory_subject, AdvisoryEvent};
+use crate::events::alert::{alert_subject, AlertRaised};
+use crate::events::policy::{policy_subject, PolicyEvent};
+use crate::events::progress::OperationProgress;
+use crate::events::scorecard::{scorecard_subject, ScorecardComputed};
+use crate::events::{ComputeResourceEvent, InfrastructureEvent};
+use crate::health::HealthReport;
+use crate::jetstream::StoredEvent;
+use crate::nats::query::{
+    GetArchivalCandidates, GetComputeResource, GetEnrichedResources, GetScorecard, ListResources,
+    QueryReply, TopologyQuery, TopologyView,
+};
+use crate::projection::change_feed::ChangeFeedEntry;
+use crate::projection::registry::ResourcePage;
+use crate::reconcile::Observation;
+use crate::scorecard::Scorecard;
+use crate::service::{CommandReply, InfrastructureCommand};
+use crate::subjects::{subjects, AggregateType, INFRASTRUCTURE_ROOT};
+
+/// AsyncAPI version of the generated document
+pub const ASYNCAPI_VERSION: &str = "3.0.0";
+
+/// Generate the AsyncAPI document
+pub fn generate() -> Value {
+    let mut components = Components::new();
+
+    let stored_event = components.message::<StoredEvent<InfrastructureEvent>>(
+        "StoredEvent",
+        "Persisted event envelope",
+        "Event as written to JetStream, with sequence and correlation metadata.",
+    );
+    let infrastructure_event = components.message::<InfrastructureEvent>(
+        "InfrastructureEvent",
+        "Live event notification",
+        "Published on core NATS after append by the network service.",
+    );
+    let compute_event = components.message::<ComputeResourceEvent>(
+        "ComputeResourceEvent",
+        "Live compute event notification",
+        "Published on core NATS after append by the compute resource service.",
+    );
+    let advisory = components.message::<AdvisoryEvent>(
+        "AdvisoryEvent",
+        "Advisory finding",
+        "Finding from a background check; not part of any aggregate stream.",
+    );
+    let policy = components.message::<PolicyEvent>(
+        "PolicyEvent",
+        "Policy compliance change",
+        "Resource started or stopped breaking a policy rule; caused by an aggregate event.",
+    );
+    let alert = components.message::<AlertRaised>(
+        "AlertRaised",
+        "Alert",
+        "A declared alert rule reached its threshold; caused by an aggregate event.",
+    );
+    let scorecard = components.message::<ScorecardComputed>(
+        "ScorecardComputed",
+        "Organization scorecard",
+        "Inventory hygiene of one organization; one per organization and generator run.",
+    );
+    let observation = components.message::<Observation>(
+        "Observation",
+        "Observed infrastructure",
+        "Facts from an agent or scan, reconciled against the declared state.",
+    );
+    let feed_entry = components.message::<ChangeFeedEntry>(
+        "ChangeFeedEntry",
+        "Organization activity feed entry",
+        "Summarized change; deduplicated with Nats-Msg-Id.",
+    );
+    let progress = components.message::<OperationProgress>(
+        "OperationProgress",
+        "Operation progress report",
+        "Throttled report of a long-running operation; the last one is completed or failed.",
+    );
+    let health_report = components.message::<HealthReport>(
+        "HealthReport",
+        "Component health report",
+        "Periodic health of NATS, streams, projections and adapters.",
+    );
+    let host_facts = components.message::<HostFacts>(
+        "HostFacts",
+        "Host facts",
+        "Hardware facts reported by a discovery agent.",
+    );
+    let command = components.message::<InfrastructureCommand>(
+        "InfrastructureCommand",
+        "Command request",
+        "The subject must agree with the payload's aggregate type and command tag.",
+    );
+    let command_reply = components.message::<CommandReply>(
+        "CommandReply",
+        "Command reply",
+        "Ack once the resulting event is persisted, otherwise a nack with a reason.",
+    );
+    let get_compute = components.message::<GetComputeResource>(
+        "GetComputeResource",
+        "Compute resource query",
+        "Replies with a ComputeResourceState result.",
+    );
+    let topology_query = components.message::<TopologyQuery>(
+        "TopologyQuery",
+        "Topology query",
+        "Replies with a TopologyView result.",
+    );
+    let get_scorecard = components.message::<GetScorecard>(
+        "GetScorecard",
+        "Scorecard query",
+        "Replies with the latest Scorecard result of the organization.",
+    );
+    let get_enriched = components.message::<GetEnrichedResources>(
+        "GetEnrichedResources",
+        "Enriched resources query",
+        "Replies with an array of EnrichedResource results; unknown IDs are left out.",
+    );
+    let get_archival = components.message::<GetArchivalCandidates>(
+        "GetArchivalCandidates",
+        "Archival candidates query",
+        "Replies with an array of StaleAggregate results, longest idle first.",
+    );
+    let list_resources = components.message::<ListResources>(
+        "ListResources",
+        "Resource listing query",
+        "Replies with a ResourcePage result, ordered by hostname.",
+    );
+    let query_reply = components.message::<QueryReply>(
+        "QueryReply",
+        "Query reply",
+        "`result` holds the read model named by the query.",
+    );
+
+    // Query results travel as untyped JSON; publish their shapes alongside
+    components.schema::<ComputeResourceState>();
+    components.schema::<TopologyView>();
+    components.schema::<Scorecard>();
+    components.schema::<EnrichedResource>();
+    components.schema::<StaleAggregate>();
+    components.schema::<ResourcePage>();
+
+    let aggregates: Vec<String> = [
+        AggregateType::Compute,
+        AggregateType::Network,
+        AggregateType::Interface,
+        AggregateType::IpPool,
+        AggregateType::Connection,
+    ]
+    .iter()
+    .map(ToString::to_string)
+    .collect();
+
+    let channels = json!({
+        "events": {
+            "address": format!("{}.{{aggregate}}.{{aggregateId}}.{{eventType}}", INFRASTRUCTURE_ROOT),
+            "title": "Aggregate events",
+            "description": "Canonical event subjects. The event store persists the \
+                            StoredEvent envelope; services additionally publish the bare \
+                            event on the same subject once it is appended.",
+            "parameters": {
+                "aggregate": {
+                    "enum": aggregates,
+                    "description": "Aggregate type token",
+                },
+                "aggregateId": { "description": "Aggregate UUID" },
+                "eventType": { "description": "Operation token of the aggregate" },
+            },
+            "messages": {
+                "storedEvent": stored_event.clone(),
+                "infrastructureEvent": infrastructure_event.clone(),
+                "computeResourceEvent": compute_event.clone(),
+            },
+        },
+        "tenantEvents": {
+            "address": format!(
+                "{}.{{orgId}}.{{aggregate}}.{{aggregateId}}.{{eventType}}",
+                INFRASTRUCTURE_ROOT
+            ),
+            "title": "Tenant aggregate events",
+            "description": "Aggregate events of one organization on a shared cluster.",
+            "parameters": {
+                "orgId": { "description": "Organization ID" },
+                "aggregate": {
+                    "enum": aggregates,
+                    "description": "Aggregate type token",
+                },
+                "aggregateId": { "description": "Aggregate UUID" },
+                "eventType": { "description": "Operation token of the aggregate" },
+            },
+            "messages": {
+                "storedEvent": stored_event.clone(),
+                "infrastructureEvent": infrastructure_event,
+                "computeResourceEvent": compute_event,
+            },
+        },
+        "correlation": {
+            "address": format!("{}.correlation.{{correlationId}}", INFRASTRUCTURE_ROOT),
+            "title": "Correlation index",
+            "description": "Copy of every stored event keyed by correlation ID.",
+            "parameters": {
+                "correlationId": { "description": "Correlation UUID" },
+            },
+            "messages": { "storedEvent": stored_event.clone() },
+        },
+        "change": {
+            "address": format!("{}.change.{{changeKey}}", INFRASTRUCTURE_ROOT),
+            "title": "Change request index",
+            "description": "Copy of every event written under a change request, keyed by the UUID v5 of its change_ref.",
+            "parameters": {
+                "changeKey": { "description": "ChangeRef::index_key of the change request" },
+            },
+            "messages": { "storedEvent": stored_event.clone() },
+        },
+        "cid": {
+            "address": format!("{}.cid.{{cid}}", INFRASTRUCTURE_ROOT),
+            "title": "Content address index",
+            "description": "Copy of every content-addressed event, keyed by the CIDv1 of its content.",
+            "parameters": {
+                "cid": { "description": "CIDv1 of the event content" },
+            },
+            "messages": { "storedEvent": stored_event.clone() },
+        },
+        "advisories": {
+            "address": advisory_subject("{advisoryType}"),
+            "title": "Advisories",
+            "parameters": {
+                "advisoryType": {
+                    "enum": [
+                        "ip_conflict_detected",
+                        "subnet_nearly_full",
+                        "dual_stack_incomplete",
+                        "drift_detected",
+                        "archival_candidate",
+                        "host_rollup_changed",
+                        "quota_exceeded",
+                    ],
+                    "description": "Advisory type token",
+                },
+            },
+            "messages": { "advisory": advisory },
+        },
+        "policy": {
+            "address": policy_subject("{policyEventType}"),
+            "title": "Policy compliance",
+            "parameters": {
+                "policyEventType": {
+                    "enum": ["violation_detected", "compliance_restored"],
+                    "description": "Policy event type token",
+                },
+            },
+            "messages": { "policyEvent": policy },
+        },
+        "alerts": {
+            "address": alert_subject("{rule}"),
+            "title": "Alerts",
+            "parameters": {
+                "rule": { "description": "Name of the alert rule" },
+            },
+            "messages": { "alert": alert },
+        },
+        "scorecards": {
+            "address": scorecard_subject(Some("{organizationId}")),
+            "title": "Organization scorecards",
+            "parameters": {
+                "organizationId": {
+                    "description": "Organization ID, or `unassigned` for resources without one",
+                },
+            },
+            "messages": { "scorecard": scorecard },
+        },
+        "organizationFeed": {
+            "address": subjects::organization_feed("{organizationId}"),
+            "title": "Organization change feed",
+            "parameters": {
+                "organizationId": { "description": "Organization ID" },
+            },
+            "messages": { "entry": feed_entry },
+        },
+        "notifications": {
+            "address": subjects::notification("{aggregate}", "{eventType}"),
+            "title": "Event notifications",
+            "description": "Stored events fanned out per type. Core NATS only; never captured by the event stream.",
+            "parameters": {
+                "aggregate": { "description": "Aggregate type token" },
+                "eventType": { "description": "Operation token of the aggregate" },
+            },
+            "messages": { "storedEvent": stored_event },
+        },
+        "progress": {
+            "address": subjects::progress("{operationId}"),
+            "title": "Operation progress",
+            "description": "Core NATS only; never captured by the event stream.",
+            "parameters": {
+                "operationId": { "description": "Operation UUID shared by one run" },
+            },
+            "messages": { "report": progress },
+        },
+        "observations": {
+            "address": subjects::observation("{source}"),
+            "title": "Observations",
+            "description": "Core NATS only; never captured by the event stream.",
+            "parameters": {
+                "source": { "description": "Reporting agent or scan" },
+            },
+            "messages": { "observation": observation },
+        },
+        "healthReports": {
+            "address": subjects::health_report(),
+            "title": "Health reports",
+            "description": "Core NATS only; never captured by the event stream.",
+            "messages": { "report": health_report },
+        },
+        "agentFacts": {
+            "address": subjects::agent_facts("{agent}"),
+            "title": "Discovery agent facts",
+            "description": "Request/reply with an empty request; never captured by the event stream.",
+            "parameters": {
+                "agent": { "description": "Agent name" },
+            },
+        },
+        "agentFactsReplies": {
+            "address": null,
+            "description": "Requester's reply inbox",
+            "messages": { "reply": host_facts },
+        },
+        "computeCommands": {
+            "address": subjects::command(AggregateType::Compute, "{command}"),
+            "title": "Compute resource commands",
+            "description": "Request/reply; never captured by the event stream.",
+            "parameters": {
+                "command": { "description": "Command tag, e.g. `assign_owner`" },
+            },
+            "messages": { "command": command },
+        },
+        "commandReplies": {
+            "address": null,
+            "description": "Requester's reply inbox",
+            "messages": { "reply": command_reply },
+        },
+        "getComputeResource": {
+            "address": subjects::query("compute", "get"),
+            "title": "Get compute resource",
+            "messages": { "query": get_compute },
+        },
+        "viewTopology": {
+            "address": subjects::query("topology", "view"),
+            "title": "View topology",
+            "messages": { "query": topology_query },
+        },
+        "getScorecard": {
+            "address": subjects::query("scorecard", "get"),
+            "title": "Get scorecard",
+            "messages": { "query": get_scorecard },
+        },
+        "getEnrichedResources": {
+            "address": subjects::query("compute", "enriched"),
+            "title": "Get enriched resources",
+            "messages": { "query": get_enriched },
+        },
+        "getArchivalCandidates": {
+            "address": subjects::query("archival", "candidates"),
+            "title": "Get archival candidates",
+            "messages": { "query": get_archival },
+        },
+        "listResources": {
+            "address": subjects::query("compute", "list"),
+            "title": "List resources",
+            "messages": { "query": list_resources },
+        },
+        "queryReplies": {
+            "address": null,
+            "description": "Requester's reply inbox",
+            "messages": { "reply": query_reply },
+        },
+    });
+
+    let operations = json!({
+        "receiveEvents": {
+            "action": "receive",
+            "channel": channel_ref("events"),
+        },
+        "receiveCorrelationIndex": {
+            "action": "receive",
+            "channel": channel_ref("correlation"),
+        },
+        "receiveChangeIndex": {
+            "action": "receive",
+            "channel": channel_ref("change"),
+        },
+        "receiveCidIndex": {
+            "action": "receive",
+            "channel": channel_ref("cid"),
+        },
+        "receiveAdvisories": {
+            "action": "receive",
+            "channel": channel_ref("advisories"),
+        },
+        "receivePolicyEvents": {
+            "action": "receive",
+            "channel": channel_ref("policy"),
+        },
+        "receiveAlerts": {
+            "action": "receive",
+            "channel": channel_ref("alerts"),
+        },
+        "receiveScorecards": {
+            "action": "receive",
+            "channel": channel_ref("scorecards"),
+        },
+        "receiveOrganizationFeed": {
+            "action": "receive",
+            "channel": channel_ref("organizationFeed"),
+        },
+        "receiveNotifications": {
+            "action": "receive",
+            "channel": channel_ref("notifications"),
+        },
+        "receiveProgress": {
+            "action": "receive",
+            "channel": channel_ref("progress"),
+        },
+        "receiveTenantEvents": {
+            "action": "receive",
+            "channel": channel_ref("tenantEvents"),
+        },
+        "receiveHealthReports": {
+            "action": "receive",
+            "channel": channel_ref("healthReports"),
+        },
+        "requestAgentFacts": {
+            "action": "send",
+            "channel": channel_ref("agentFacts"),
+            "reply": { "channel": channel_ref("agentFactsReplies") },
+        },
+        "sendObservation": {
+            "action": "send",
+            "channel": channel_ref("observations"),
+        },
+        "sendComputeCommand": {
+            "action": "send",
+            "channel": channel_ref("computeCommands"),
+            "reply": { "channel": channel_ref("commandReplies") },
+        },
+        "getComputeResource": {
+            "action": "send",
+            "channel": channel_ref("getComputeResource"),
+            "reply": { "channel": channel_ref("queryReplies") },
+        },
+        "viewTopology": {
+            "action": "send",
+            "channel": channel_ref("viewTopology"),
+            "reply": { "channel": channel_ref("queryReplies") },
+        },
+        "getScorecard": {
+            "action": "send",
+            "channel": channel_ref("getScorecard"),
+            "reply": { "channel": channel_ref("queryReplies") },
+        },
+        "getEnrichedResources": {
+            "action": "send",
+            "channel": channel_ref("getEnrichedResources"),
+            "reply": { "channel": channel_ref("queryReplies") },
+        },
+        "getArchivalCandidates": {
+            "action": "send",
+            "channel": channel_ref("getArchivalCandidates"),
+            "reply": { "channel": channel_ref("queryReplies") },
+        },
+        "listResources": {
+            "action": "send",
+            "channel": channel_ref("listResources"),
+            "reply": { "channel": channel_ref("queryReplies") },
+        },
+    });
+
+    json!({
+        "asyncapi": ASYNCAPI_VERSION,
+        "info": {
+            "title": "CIM Infrastructure",
+            "version": env!("CARGO_PKG_VERSION"),
+            "description": "NATS subjects and payloads of the infrastructure domain.",
+        },
+        "defaultContentType": "application/json",
+        "channels": channels,
+        "operations": operations,
+        "components": components.into_value(),
+    })
+}
+
+/// Reference to a channel of the document
+fn channel_ref(name: &str) -> Value {
+    json!({ "$ref": format!("#/channels/{}", name) })
+}
+
+/// Collects message definitions and the schemas they reference
+struct Components {
+    generator: SchemaGenerator,
+    messages: Map<String, Value>,
+}
+
+impl Components {
+    fn new() -> Self {
+        let generator = SchemaSettings::draft07()
+            .with(|settings| settings.definitions_path = "#/components/schemas/".to_string())
+            .into_generator();
+
+        Self {
+            generator,
+            messages: Map::new(),
+        }
+    }
+
+    /// Add a schema for `T` (and everything it references)
+    fn schema<T: JsonSchema>(&mut self) -> Value {
+        let schema = self.generator.subschema_for::<T>();
+        serde_json::to_value(schema).expect("JSON schema serializes")
+    }
+
+    /// Add a message carrying `T` and return a reference to it
+    fn message<T: JsonSchema>(&mut self, name: &str, title: &str, summary: &str) -> Value {
+        let payload = self.schema::<T>();
+        self.messages.insert(
+            name.to_string(),
+            json!({
+                "name": name,
+                "title": title,
+                "summary": summary,
+                "contentType": "application/json",
+                "payload": payload,
+            }),
+        );
+
+        json!({ "$ref": format!("#/components/messages/{}", name) })
+    }
+
+    fn into_value(mut self) -> Value {
+        let schemas = serde_json::to_value(self.generator.take_definitions())
+            .expect("JSON schemas serialize");
+
+        json!({
+            "messages": self.messages,
+            "schemas": schemas,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subjects::registry::SubjectRegistry;
+
+    /// Collect every `$ref` in the document
+    fn refs(value: &Value, found: &mut Vec<String>) {
+        match value {
+            Value::Object(map) => {
+                if let Some(Value::String(target)) = map.get("$ref") {
+                    found.push(target.clone());
+                }
+                map.values().for_each(|v| refs(v, found));
+            }
+            Value::Array(items) => items.iter().for_each(|v| refs(v, found)),
+            _ => {}
+        }
+    }
+
+    #[test]
+    fn test_every_reference_resolves() {
+        let document = generate();
+
+        let mut found = Vec::new();
+        refs(&document, &mut found);
+
+        assert!(!found.is_empty());
+        for target in found {
+            let pointer = target.strip_prefix('#').expect("local reference");
+            assert!(document.pointer(pointer).is_some(), "dangling {}", target);
+        }
+    }
+
+    #[test]
+    fn test_channels_follow_subjects() {
+        let document = generate();
+        let channels = &document["channels"];
+
+        assert_eq!(document["asyncapi"], ASYNCAPI_VERSION);
+        assert_eq!(
+            channels["computeCommands"]["address"],
+            "infrastructure.cmd.compute.{command}"
+        );
+        assert_eq!(
+            channels["viewTopology"]["address"],
+            "infrastructure.query.topology.view"
+        );
+        assert_eq!(
+            channels["advisories"]["address"],
+            "infrastructure.advisory.{advisoryType}"
+        );
+        assert_eq!(
+            channels["policy"]["address"],
+            "infrastructure.policy.{policyEventType}"
+        );
+        assert_eq!(channels["alerts"]["address"], "infrastructure.alert.{rule}");
+        assert_eq!(
+            channels["scorecards"]["address"],
+            "infrastructure.scorecard.{organizationId}"
+        );
+        assert_eq!(
+            channels["observations"]["address"],
+            "infrastructure.observation.{source}"
+        );
+        assert_eq!(
+            channels["progress"]["address"],
+            "infrastructure.progress.{operationId}"
+        );
+        assert!(document["components"]["schemas"]["InfrastructureEvent"].is_object());
+    }
+
+    #[test]
+    fn test_every_registered_subject_is_a_channel() {
+        // Arrange
+        let document = generate();
+        let registry = SubjectRegistry::standard();
+
+        // Act & Assert
+        for entry in registry.entries() {
+            let channel = &document["channels"][&entry.name];
+            assert_eq!(channel["address"], entry.pattern, "{}", entry.name);
+
+            let mut found = Vec::new();
+            refs(&channel["messages"], &mut found);
+            for payload in &entry.payloads {
+                // Empty requests carry no message
+                if payload.name != "Empty" {
+                    let message = format!("#/components/messages/{}", payload.name);
+                    assert!(found.contains(&message), "{} lacks {}", entry.name, message);
+                }
+            }
+        }
+    }
+}
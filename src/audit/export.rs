@@ -0,0 +1,122 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Audit Log Export
+//!
+//! | Format | Lossless | Verifiable | Use                                  |
+//! |--------|----------|------------|--------------------------------------|
+//! | JSONL  | yes      | yes        | archival, [`from_jsonl`] + re-verify |
+//! | CSV    | no       | no         | spreadsheets for compliance reviews  |
+//!
+//! CSV keeps the hashes and signatures as columns so a reviewer can match
+//! rows against a verified JSONL export.
+
+use crate::audit::{AuditEntry, AuditError};
+use crate::export::csv;
+
+/// CSV column headers, in order
+pub const CSV_HEADERS: [&str; 15] = [
+    "index",
+    "recorded_at",
+    "event_id",
+    "event_type",
+    "aggregate_id",
+    "sequence",
+    "occurred_at",
+    "correlation_id",
+    "causation_id",
+    "actor",
+    "source",
+    "change_ref",
+    "previous_hash",
+    "hash",
+    "signature",
+];
+
+/// One entry as a JSON line, newline included
+pub fn to_jsonl_line(entry: &AuditEntry) -> String {
+    let mut line = serde_json::to_string(entry).expect("audit entry serializes");
+    line.push('\n');
+    line
+}
+
+/// Entries as JSON Lines
+pub fn to_jsonl(entries: &[AuditEntry]) -> String {
+    entries.iter().map(to_jsonl_line).collect()
+}
+
+/// Parse a JSON Lines export (blank lines are skipped)
+pub fn from_jsonl(text: &str) -> Result<Vec<AuditEntry>, AuditError> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(number, line)| {
+            serde_json::from_str(line).map_err(|e| AuditError::Malformed {
+                line: number + 1,
+                message: e.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Entries as CSV
+pub fn to_csv(entries: &[AuditEntry]) -> String {
+    let headers: Vec<String> = CSV_HEADERS.iter().map(ToString::to_string).collect();
+    let text = |value: &Option<String>| value.clone().unwrap_or_default();
+    let rows: Vec<Vec<String>> = entries
+        .iter()
+        .map(|entry| {
+            vec![
+                entry.index.to_string(),
+                entry.recorded_at.to_rfc3339(),
+                entry.event_id.to_string(),
+                entry.event_type.clone(),
+                entry.aggregate_id.to_string(),
+                entry.sequence.to_string(),
+                entry.occurred_at.to_rfc3339(),
+                entry.correlation_id.to_string(),
+                entry.causation_id.to_string(),
+                text(&entry.actor),
+                text(&entry.source),
+                text(&entry.change_ref),
+                entry.previous_hash.clone(),
+                entry.hash.clone(),
+                text(&entry.signature),
+            ]
+        })
+        .collect();
+    csv::write_table(&headers, &rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::{verify_chain, AuditLog};
+    use crate::test_harness::{registered, stored};
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_jsonl_round_trip_stays_verifiable() {
+        // Arrange
+        let mut log = AuditLog::new();
+        for event_type in ["ResourceRegistered", "StatusChanged"] {
+            let mut event = stored(1, registered(Uuid::now_v7(), "web-01"));
+            event.event_type = event_type.to_string();
+            log.append(&event, Utc::now());
+        }
+
+        // Act
+        let jsonl = to_jsonl(log.entries());
+        let parsed = from_jsonl(&jsonl).unwrap();
+        let csv = to_csv(log.entries());
+
+        // Assert
+        assert_eq!(parsed, log.entries());
+        assert!(verify_chain(&parsed).is_ok());
+        assert_eq!(csv.lines().count(), 3);
+        assert!(csv.starts_with("index,recorded_at,event_id"));
+        assert!(matches!(
+            from_jsonl("{\"index\": 0}"),
+            Err(AuditError::Malformed { line: 1, .. })
+        ));
+    }
+}
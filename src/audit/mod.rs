@@ -0,0 +1,494 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Audit Log
+//!
+//! Projects every stored event into a tamper-evident audit log. Each entry
+//! records who did what to which aggregate, under which correlation and
+//! causation, and is chained to the entry before it:
+//!
+//! ```text
+//! entry n:  hash = SHA-256( previous_hash ‖ canonical JSON of entry n )
+//!
+//!   GENESIS ──> e1.hash ──> e2.hash ──> e3.hash ──> ...
+//!                 │           │           │
+//!             signature   signature   signature    (ed25519, optional)
+//! ```
+//!
+//! Changing, removing or reordering any entry breaks every hash after it,
+//! which [`verify_chain`] reports with the first broken index. With the
+//! `audit-signing` feature each hash is also signed, so a verifier holding
+//! only the public key can tell the log was written by the audit service.
+//!
+//! Entries are exported as JSON Lines (lossless, verifiable) or CSV (for
+//! spreadsheets) by [`export`]; [`AuditProjection::with_sink`] appends each
+//! entry to a JSONL file as it is recorded. On restart the projection
+//! resumes from the file: its entries are verified and loaded, so new
+//! entries continue the chain and events already audited are not audited
+//! again.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::audit::{export, verify_chain, AuditProjection};
+//!
+//! let projection = AuditProjection::new().with_sink("/var/log/cim/audit.jsonl")?;
+//! let audit = projection.handle();
+//! manager.register(projection);
+//! manager.start_all().await?;
+//!
+//! let entries = audit.entries();
+//! verify_chain(&entries)?;
+//! std::fs::write("audit.csv", export::to_csv(&entries))?;
+//! ```
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+use uuid::Uuid;
+
+use crate::change_control::change_ref_of;
+use crate::event_store::provenance::EventEnvelopeMetadata;
+use crate::events::InfrastructureEvent;
+use crate::jetstream::StoredEvent;
+use crate::projection::{ProjectionAdapter, ProjectionError};
+
+pub mod export;
+#[cfg(feature = "audit-signing")]
+pub mod signing;
+
+#[cfg(feature = "audit-signing")]
+pub use signing::{AuditSigner, AuditVerifier};
+
+/// `previous_hash` of the first entry
+pub const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// Audit log errors
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum AuditError {
+    /// An entry does not continue the chain or its hash does not match
+    #[error("Audit chain broken at entry {index}: {reason}")]
+    ChainBroken {
+        /// Index of the first bad entry
+        index: u64,
+        /// What did not match
+        reason: String,
+    },
+
+    /// A signing or verifying key is not a valid ed25519 key
+    #[error("Invalid audit key: {0}")]
+    InvalidKey(String),
+
+    /// An entry's signature is missing or invalid
+    #[error("Invalid signature on audit entry {0}")]
+    InvalidSignature(u64),
+
+    /// An export line could not be parsed
+    #[error("Malformed audit export line {line}: {message}")]
+    Malformed {
+        /// 1-based line number
+        line: usize,
+        /// Parser error
+        message: String,
+    },
+
+    /// The audit sink could not be written
+    #[error("Audit sink error: {0}")]
+    Sink(String),
+}
+
+/// One audited event
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// Position in the log, from 0
+    pub index: u64,
+
+    /// When the entry was recorded
+    pub recorded_at: DateTime<Utc>,
+
+    /// Audited event
+    pub event_id: Uuid,
+
+    /// Event type name
+    pub event_type: String,
+
+    /// Aggregate the event belongs to
+    pub aggregate_id: Uuid,
+
+    /// Version of the aggregate after the event
+    pub sequence: u64,
+
+    /// When the event occurred
+    pub occurred_at: DateTime<Utc>,
+
+    /// Correlation ID of the event
+    pub correlation_id: Uuid,
+
+    /// Causation ID of the event
+    pub causation_id: Uuid,
+
+    /// Who caused the event (`kind:id`), when known
+    #[serde(default)]
+    pub actor: Option<String>,
+
+    /// System the command came through, when known
+    #[serde(default)]
+    pub source: Option<String>,
+
+    /// Change request the event was written under
+    #[serde(default)]
+    pub change_ref: Option<String>,
+
+    /// Hash of the previous entry ([`GENESIS_HASH`] for the first)
+    pub previous_hash: String,
+
+    /// SHA-256 of `previous_hash` and this entry, hex
+    pub hash: String,
+
+    /// ed25519 signature of `hash`, hex
+    #[serde(default)]
+    pub signature: Option<String>,
+}
+
+impl AuditEntry {
+    /// Recompute this entry's hash from its content
+    pub fn compute_hash(&self) -> String {
+        let unsealed = Self {
+            hash: String::new(),
+            signature: None,
+            ..self.clone()
+        };
+        let content = serde_json::to_vec(&unsealed).expect("audit entry serializes");
+
+        let mut hasher = Sha256::new();
+        hasher.update(self.previous_hash.as_bytes());
+        hasher.update(&content);
+        hex(&hasher.finalize())
+    }
+}
+
+/// Append-only, hash-chained audit log
+#[derive(Default)]
+pub struct AuditLog {
+    entries: Vec<AuditEntry>,
+    /// Domain event IDs of recorded events
+    seen: HashSet<Uuid>,
+    #[cfg(feature = "audit-signing")]
+    signer: Option<AuditSigner>,
+}
+
+impl AuditLog {
+    /// Empty log
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sign every entry appended from now on
+    #[cfg(feature = "audit-signing")]
+    pub fn with_signer(mut self, signer: AuditSigner) -> Self {
+        self.signer = Some(signer);
+        self
+    }
+
+    /// Entries in order
+    pub fn entries(&self) -> &[AuditEntry] {
+        &self.entries
+    }
+
+    /// Hash of the last entry ([`GENESIS_HASH`] when empty)
+    pub fn head(&self) -> &str {
+        self.entries
+            .last()
+            .map(|entry| entry.hash.as_str())
+            .unwrap_or(GENESIS_HASH)
+    }
+
+    /// Record an event; None if it is already in the log
+    pub fn append(
+        &mut self,
+        event: &StoredEvent<InfrastructureEvent>,
+        recorded_at: DateTime<Utc>,
+    ) -> Option<&AuditEntry> {
+        let event_id = event.data.event_id();
+        if !self.seen.insert(event_id) {
+            return None;
+        }
+
+        let envelope = EventEnvelopeMetadata::of(event);
+        let mut entry = AuditEntry {
+            index: self.entries.len() as u64,
+            recorded_at,
+            event_id,
+            event_type: event.event_type.clone(),
+            aggregate_id: event.aggregate_id,
+            sequence: event.sequence,
+            occurred_at: event.timestamp,
+            correlation_id: event.correlation_id,
+            causation_id: event.causation_id,
+            actor: envelope
+                .as_ref()
+                .and_then(|envelope| envelope.actor.as_ref())
+                .map(ToString::to_string),
+            source: envelope.and_then(|envelope| envelope.source),
+            change_ref: change_ref_of(event).map(|change_ref| change_ref.as_str().to_string()),
+            previous_hash: self.head().to_string(),
+            hash: String::new(),
+            signature: None,
+        };
+        entry.hash = entry.compute_hash();
+        #[cfg(feature = "audit-signing")]
+        if let Some(signer) = &self.signer {
+            entry.signature = Some(signer.sign(&entry.hash));
+        }
+
+        self.entries.push(entry);
+        self.entries.last()
+    }
+
+    /// Continue from `entries`, previously written by a log
+    ///
+    /// Replaces the log's entries; fails if they do not form an unbroken
+    /// chain, so a damaged log is never extended.
+    pub fn restore(&mut self, entries: Vec<AuditEntry>) -> Result<(), AuditError> {
+        verify_chain(&entries)?;
+        self.seen = entries.iter().map(|entry| entry.event_id).collect();
+        self.entries = entries;
+        Ok(())
+    }
+
+    /// Forget every entry
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.seen.clear();
+    }
+}
+
+/// Check that entries form an unbroken chain from [`GENESIS_HASH`]
+pub fn verify_chain(entries: &[AuditEntry]) -> Result<(), AuditError> {
+    let mut previous = GENESIS_HASH;
+    for (position, entry) in entries.iter().enumerate() {
+        let broken = |reason: &str| AuditError::ChainBroken {
+            index: entry.index,
+            reason: reason.to_string(),
+        };
+        if entry.index != position as u64 {
+            return Err(broken("index out of sequence"));
+        }
+        if entry.previous_hash != previous {
+            return Err(broken("previous hash does not match"));
+        }
+        if entry.compute_hash() != entry.hash {
+            return Err(broken("content does not match hash"));
+        }
+        previous = &entry.hash;
+    }
+    Ok(())
+}
+
+/// Query-side handle onto an audit log
+#[derive(Clone, Default)]
+pub struct AuditLogHandle {
+    log: Arc<RwLock<AuditLog>>,
+}
+
+impl AuditLogHandle {
+    /// Snapshot of the entries
+    pub fn entries(&self) -> Vec<AuditEntry> {
+        self.log.read().expect("audit log lock").entries().to_vec()
+    }
+
+    /// Hash of the last entry
+    pub fn head(&self) -> String {
+        self.log.read().expect("audit log lock").head().to_string()
+    }
+}
+
+/// Projection recording every stored event in an [`AuditLog`]
+pub struct AuditProjection {
+    handle: AuditLogHandle,
+    sink: Option<(PathBuf, File)>,
+}
+
+impl AuditProjection {
+    /// Projection over an empty log
+    pub fn new() -> Self {
+        Self::with_log(AuditLog::new())
+    }
+
+    /// Projection over a configured log (e.g. one with a signer)
+    pub fn with_log(log: AuditLog) -> Self {
+        Self {
+            handle: AuditLogHandle {
+                log: Arc::new(RwLock::new(log)),
+            },
+            sink: None,
+        }
+    }
+
+    /// Also append each entry to a JSONL file
+    ///
+    /// The file is opened for appending only; existing lines are kept and
+    /// the log resumes from them (see [`AuditLog::restore`]).
+    pub fn with_sink(mut self, path: impl AsRef<Path>) -> Result<Self, AuditError> {
+        let path = path.as_ref().to_path_buf();
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|e| AuditError::Sink(e.to_string()))?;
+        self.restore_from(&path)?;
+        self.sink = Some((path, file));
+        Ok(self)
+    }
+
+    /// Load the entries already written to the sink at `path`
+    fn restore_from(&self, path: &Path) -> Result<(), AuditError> {
+        let text = std::fs::read_to_string(path).map_err(|e| AuditError::Sink(e.to_string()))?;
+        let entries = export::from_jsonl(&text)?;
+        self.handle
+            .log
+            .write()
+            .expect("audit log lock")
+            .restore(entries)
+    }
+
+    /// Query-side handle sharing this projection's log
+    pub fn handle(&self) -> AuditLogHandle {
+        self.handle.clone()
+    }
+}
+
+impl Default for AuditProjection {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ProjectionAdapter for AuditProjection {
+    type Event = StoredEvent<InfrastructureEvent>;
+    type Error = ProjectionError;
+
+    async fn project(&mut self, event: Self::Event) -> Result<(), Self::Error> {
+        let line = {
+            let mut log = self.handle.log.write().expect("audit log lock");
+            match log.append(&event, Utc::now()) {
+                Some(entry) => export::to_jsonl_line(entry),
+                None => return Ok(()),
+            }
+        };
+
+        if let Some((_, sink)) = &mut self.sink {
+            sink.write_all(line.as_bytes())
+                .and_then(|_| sink.flush())
+                .map_err(|e| ProjectionError::TargetUnavailable(e.to_string()))?;
+        }
+        Ok(())
+    }
+
+    async fn initialize(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    async fn health_check(&self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Clears the in-memory log, then reloads it from the sink if there is
+    /// one: the sink is append-only, so replayed events already in it are
+    /// not audited twice
+    async fn reset(&mut self) -> Result<(), Self::Error> {
+        self.handle.log.write().expect("audit log lock").clear();
+        if let Some((path, _)) = &self.sink {
+            self.restore_from(path)
+                .map_err(|e| ProjectionError::TargetUnavailable(e.to_string()))?;
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "audit"
+    }
+}
+
+/// Lowercase hex encoding
+pub(crate) fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_harness::{self, registered};
+
+    fn stored(event_type: &str) -> StoredEvent<InfrastructureEvent> {
+        let mut event = test_harness::stored(1, registered(Uuid::now_v7(), "web-01"));
+        event.event_type = event_type.to_string();
+        event
+    }
+
+    #[test]
+    fn test_chain_detects_tampering() {
+        // Arrange
+        let mut log = AuditLog::new();
+        let first = stored("ResourceRegistered");
+        log.append(&first, Utc::now());
+        log.append(&stored("StatusChanged"), Utc::now());
+        log.append(&stored("PolicyAdded"), Utc::now());
+
+        // Act
+        let mut redelivered = first.clone();
+        redelivered.event_id = Uuid::now_v7();
+        let duplicate = log.append(&redelivered, Utc::now()).is_none();
+        let mut tampered = log.entries().to_vec();
+        tampered[1].event_type = "PolicyRemoved".to_string();
+        let mut truncated = log.entries().to_vec();
+        truncated.remove(0);
+
+        // Assert
+        assert!(duplicate);
+        assert_eq!(log.entries()[0].previous_hash, GENESIS_HASH);
+        assert_eq!(log.head(), log.entries()[2].hash);
+        assert!(verify_chain(log.entries()).is_ok());
+        assert!(matches!(
+            verify_chain(&tampered),
+            Err(AuditError::ChainBroken { index: 1, .. })
+        ));
+        assert!(verify_chain(&truncated).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_restarted_projection_continues_the_sink_chain() {
+        // Arrange
+        let path = std::env::temp_dir().join(format!("cim-audit-{}.jsonl", Uuid::now_v7()));
+        let first = stored("ResourceRegistered");
+        let mut before = AuditProjection::new().with_sink(&path).unwrap();
+        before.project(first.clone()).await.unwrap();
+        before.project(stored("StatusChanged")).await.unwrap();
+        drop(before);
+
+        // Act
+        let mut after = AuditProjection::new().with_sink(&path).unwrap();
+        after.project(first).await.unwrap();
+        after.project(stored("PolicyAdded")).await.unwrap();
+        after.reset().await.unwrap();
+
+        // Assert
+        let written = export::from_jsonl(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(written.len(), 3, "the redelivered event is not audited again");
+        assert_eq!(written[2].index, 2);
+        assert!(verify_chain(&written).is_ok());
+        assert_eq!(after.handle().entries(), written);
+
+        std::fs::write(&path, export::to_jsonl(&written[1..])).unwrap();
+        assert!(matches!(
+            AuditProjection::new().with_sink(&path),
+            Err(AuditError::ChainBroken { index: 1, .. })
+        ));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,119 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Audit Entry Signatures
+//!
+//! The audit service signs each entry's hash with an ed25519 key; anyone
+//! holding the public key can check a whole export with
+//! [`AuditVerifier::verify`]. Since each hash covers the previous one,
+//! signatures also pin the order of entries.
+//!
+//! Keys are raw 32-byte ed25519 keys. How the signing key is stored
+//! (file, KMS, HSM export) is up to the deployment.
+
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+
+use crate::audit::{hex, verify_chain, AuditEntry, AuditError};
+
+/// Signs audit entries
+#[derive(Clone)]
+pub struct AuditSigner {
+    key: SigningKey,
+}
+
+impl AuditSigner {
+    /// Signer from a raw 32-byte secret key
+    pub fn from_bytes(secret: &[u8; 32]) -> Self {
+        Self {
+            key: SigningKey::from_bytes(secret),
+        }
+    }
+
+    /// Verifier for this signer's entries
+    pub fn verifier(&self) -> AuditVerifier {
+        AuditVerifier {
+            key: self.key.verifying_key(),
+        }
+    }
+
+    /// Signature of an entry hash, hex
+    pub fn sign(&self, hash: &str) -> String {
+        hex(&self.key.sign(hash.as_bytes()).to_bytes())
+    }
+}
+
+/// Checks signed audit entries
+#[derive(Debug, Clone)]
+pub struct AuditVerifier {
+    key: VerifyingKey,
+}
+
+impl AuditVerifier {
+    /// Verifier from a raw 32-byte public key
+    pub fn from_bytes(public: &[u8; 32]) -> Result<Self, AuditError> {
+        let key =
+            VerifyingKey::from_bytes(public).map_err(|e| AuditError::InvalidKey(e.to_string()))?;
+        Ok(Self { key })
+    }
+
+    /// Public key, raw bytes
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.key.to_bytes()
+    }
+
+    /// Check the chain and every entry's signature
+    pub fn verify(&self, entries: &[AuditEntry]) -> Result<(), AuditError> {
+        verify_chain(entries)?;
+        for entry in entries {
+            let signature = entry
+                .signature
+                .as_deref()
+                .and_then(unhex)
+                .and_then(|bytes| <[u8; 64]>::try_from(bytes).ok())
+                .ok_or(AuditError::InvalidSignature(entry.index))?;
+            self.key
+                .verify(entry.hash.as_bytes(), &Signature::from_bytes(&signature))
+                .map_err(|_| AuditError::InvalidSignature(entry.index))?;
+        }
+        Ok(())
+    }
+}
+
+/// Decode lowercase or uppercase hex
+fn unhex(text: &str) -> Option<Vec<u8>> {
+    if text.len() % 2 != 0 {
+        return None;
+    }
+    (0..text.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(text.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::AuditLog;
+    use crate::test_harness::{registered, stored};
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_signatures_verify_with_public_key_only() {
+        // Arrange
+        let signer = AuditSigner::from_bytes(&[7; 32]);
+        let verifier = AuditVerifier::from_bytes(&signer.verifier().to_bytes()).unwrap();
+        let mut log = AuditLog::new().with_signer(signer);
+        let event = stored(1, registered(Uuid::now_v7(), "web-01"));
+        log.append(&event, Utc::now());
+
+        // Act
+        let mut forged = log.entries().to_vec();
+        forged[0].signature = Some(AuditSigner::from_bytes(&[9; 32]).sign(&forged[0].hash));
+
+        // Assert
+        assert!(verifier.verify(log.entries()).is_ok());
+        assert_eq!(
+            verifier.verify(&forged),
+            Err(AuditError::InvalidSignature(0))
+        );
+    }
+}
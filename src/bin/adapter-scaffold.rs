@@ -0,0 +1,31 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Integration Scaffolding
+//!
+//! Creates a new integration crate with checkpointing, metrics and tests
+//! wired in.
+//!
+//! Run with: cargo run --bin adapter-scaffold -- <name> [out-dir]
+//!
+//! The crate is written to `<out-dir>/cim-adapter-<name>`; `out-dir`
+//! defaults to the current directory.
+
+#![cfg(feature = "runtime")]
+
+use anyhow::{Context, Result};
+use cim_infrastructure::adapter_sdk::scaffold;
+use std::path::PathBuf;
+
+fn main() -> Result<()> {
+    let mut args = std::env::args().skip(1);
+    let name = args
+        .next()
+        .context("Usage: adapter-scaffold <name> [out-dir]")?;
+    let out_dir = args.next().map(PathBuf::from).unwrap_or_else(|| ".".into());
+
+    let files = scaffold::render(&name)?;
+    let crate_dir = out_dir.join(format!("cim-adapter-{}", name));
+    scaffold::write(&crate_dir, &files)?;
+
+    println!("Integration crate written to {}", crate_dir.display());
+    Ok(())
+}
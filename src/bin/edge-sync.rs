@@ -0,0 +1,89 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Edge Sync
+//!
+//! Pushes events from a local (sled) event store to JetStream when the
+//! edge site has connectivity.
+//!
+//! Run with: cargo run --bin edge-sync --features local-store [-- --once]
+//!
+//! Configuration (environment):
+//! 1. LOCAL_STORE_PATH - local event store directory (default: ./cim-events)
+//! 2. NATS_URL - upstream NATS server (default: nats://localhost:4222)
+//! 3. SYNC_INTERVAL_SECS - seconds between runs (default: 60)
+
+#![cfg(feature = "local-store")]
+
+use anyhow::{Context, Result};
+use cim_infrastructure::event_store::sync::LocalEventSync;
+use cim_infrastructure::event_store::{NatsEventStore, SledEventStore};
+use cim_infrastructure::nats::progress::CancellationToken;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{info, warn};
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::from_default_env()
+                .add_directive(tracing::Level::INFO.into()),
+        )
+        .init();
+
+    let path = std::env::var("LOCAL_STORE_PATH").unwrap_or_else(|_| "./cim-events".to_string());
+    let nats_url =
+        std::env::var("NATS_URL").unwrap_or_else(|_| "nats://localhost:4222".to_string());
+    let interval = Duration::from_secs(
+        std::env::var("SYNC_INTERVAL_SECS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(60),
+    );
+    let once = std::env::args().any(|arg| arg == "--once");
+
+    let local = SledEventStore::open(&path)
+        .with_context(|| format!("Failed to open local event store at {}", path))?;
+    info!("Local event store: {}", path);
+
+    // The upstream may be unreachable for a long time; keep trying
+    let upstream = loop {
+        match NatsEventStore::connect(&nats_url).await {
+            Ok(store) => break store,
+            Err(e) if !once => {
+                warn!(
+                    "Upstream {} unavailable, retrying in {:?}: {}",
+                    nats_url, interval, e
+                );
+                tokio::time::sleep(interval).await;
+            }
+            Err(e) => return Err(e).context("Failed to connect to upstream NATS"),
+        }
+    };
+    info!("Connected to upstream {}", nats_url);
+
+    // Ctrl-C stops between aggregates instead of mid-push
+    let cancel = CancellationToken::new();
+    tokio::spawn({
+        let cancel = cancel.clone();
+        async move {
+            if tokio::signal::ctrl_c().await.is_ok() {
+                cancel.cancel();
+            }
+        }
+    });
+
+    let sync = LocalEventSync::new(local, Arc::new(upstream));
+    if once {
+        let report = sync.sync_once_with(&cancel).await?;
+        info!(
+            "Pushed {} events for {} aggregates ({} conflicts)",
+            report.events_pushed,
+            report.aggregates_synced,
+            report.conflicts.len()
+        );
+        return Ok(());
+    }
+
+    sync.run_until(interval, &cancel).await;
+    Ok(())
+}
@@ -0,0 +1,67 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Event Sampling
+//!
+//! Copies a masked sample of production events into a local dev store.
+//!
+//! Run with: cargo run --bin event-sample -- <sampling.yaml> --nats <dev-url>
+//!       or: cargo run --bin event-sample --features local-store -- <sampling.yaml> --dir <path>
+//!
+//! SOURCE_NATS_URL selects the production server (default
+//! nats://localhost:4222). See `event_store::sampling` for the
+//! configuration format.
+
+#![cfg(feature = "runtime")]
+
+use anyhow::{bail, Context, Result};
+use cim_infrastructure::event_store::sampling::{EventSampler, SamplingConfig};
+use cim_infrastructure::event_store::{EventStore, NatsEventStore};
+use tracing::info;
+
+const USAGE: &str = "Usage: event-sample <sampling.yaml> (--nats <url> | --dir <path>)";
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::from_default_env()
+                .add_directive(tracing::Level::INFO.into()),
+        )
+        .init();
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let (path, target_kind, target) = match args.as_slice() {
+        [path, kind, target] => (path, kind.as_str(), target),
+        _ => bail!(USAGE),
+    };
+
+    let yaml = std::fs::read_to_string(path).with_context(|| format!("Failed to read {}", path))?;
+    let sampler = EventSampler::new(SamplingConfig::from_yaml(&yaml)?);
+
+    let store: Box<dyn EventStore> = match target_kind {
+        "--nats" => Box::new(NatsEventStore::connect(target).await?),
+        #[cfg(feature = "local-store")]
+        "--dir" => Box::new(
+            cim_infrastructure::event_store::SledEventStore::open(target)
+                .with_context(|| format!("Failed to open local event store at {}", target))?,
+        ),
+        #[cfg(not(feature = "local-store"))]
+        "--dir" => bail!("--dir needs the local-store feature"),
+        _ => bail!(USAGE),
+    };
+
+    let source_url =
+        std::env::var("SOURCE_NATS_URL").unwrap_or_else(|_| "nats://localhost:4222".to_string());
+    let source = NatsEventStore::connect(&source_url)
+        .await
+        .with_context(|| format!("Failed to connect to {}", source_url))?;
+
+    let sample = sampler.sample(&source).await?;
+    info!("Sampled {} aggregates from {}", sample.len(), source_url);
+
+    let report = sampler.load(&sample, store.as_ref()).await?;
+    info!(
+        "Loaded {} events for {} aggregates into {} ({} already present)",
+        report.events, report.aggregates, target, report.already_present
+    );
+    Ok(())
+}
@@ -0,0 +1,26 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Export AsyncAPI Document
+//!
+//! Writes the AsyncAPI specification of the infrastructure NATS contract.
+//!
+//! Run with: cargo run --bin export-asyncapi --features asyncapi [-- <out-file>]
+//!
+//! The output file defaults to ./asyncapi.json.
+
+#![cfg(feature = "asyncapi")]
+
+use anyhow::{Context, Result};
+
+fn main() -> Result<()> {
+    let out_file = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "asyncapi.json".to_string());
+
+    let document = cim_infrastructure::asyncapi::generate();
+    let json = serde_json::to_string_pretty(&document)?;
+    std::fs::write(&out_file, json + "\n")
+        .with_context(|| format!("Failed to write AsyncAPI document to {}", out_file))?;
+
+    println!("AsyncAPI document written to {}", out_file);
+    Ok(())
+}
@@ -0,0 +1,24 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Export JSON Schemas
+//!
+//! Writes versioned JSON Schemas for events and commands.
+//!
+//! Run with: cargo run --bin export-schemas --features json-schema [-- <out-dir>]
+//!
+//! The output directory defaults to ./schemas.
+
+#![cfg(feature = "json-schema")]
+
+use anyhow::{Context, Result};
+
+fn main() -> Result<()> {
+    let out_dir = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "schemas".to_string());
+
+    let written = cim_infrastructure::schema::export_all(&out_dir)
+        .with_context(|| format!("Failed to export JSON Schemas to {}", out_dir))?;
+
+    println!("{} JSON Schemas written to {}", written.len(), out_dir);
+    Ok(())
+}
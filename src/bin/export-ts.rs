@@ -0,0 +1,24 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Export TypeScript Definitions
+//!
+//! Writes `.ts` declarations for events, commands and read models.
+//!
+//! Run with: cargo run --bin export-ts --features typescript [-- <out-dir>]
+//!
+//! The output directory defaults to ./bindings.
+
+#![cfg(feature = "typescript")]
+
+use anyhow::{Context, Result};
+
+fn main() -> Result<()> {
+    let out_dir = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "bindings".to_string());
+
+    cim_infrastructure::typescript::export_all(&out_dir)
+        .with_context(|| format!("Failed to export TypeScript definitions to {}", out_dir))?;
+
+    println!("TypeScript definitions written to {}", out_dir);
+    Ok(())
+}
@@ -58,6 +58,9 @@ impl ProjectorConfig {
                 .ok()
                 .and_then(|s| s.parse().ok()),
             timeout_secs: 30,
+            soft_delete: std::env::var("NETBOX_SOFT_DELETE")
+                .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+                .unwrap_or(false),
         };
 
         Ok(Self {
@@ -114,7 +117,7 @@ async fn main() -> Result<()> {
             jetstream
                 .create_stream(jetstream::stream::Config {
                     name: config.stream_name.clone(),
-                    subjects: vec!["infrastructure.>".to_string()],
+                    subjects: cim_infrastructure::subjects::subjects::stream_subjects(),
                     max_age: Duration::from_secs(7 * 24 * 60 * 60), // 7 days
                     ..Default::default()
                 })
@@ -0,0 +1,77 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Terraform State Import
+//!
+//! Registers the networks, instances and interfaces of a Terraform state
+//! file in the event store.
+//!
+//! Run with: cargo run --bin terraform-import -- <terraform.tfstate> [--dry-run]
+//!
+//! With `--dry-run` the planned commands are printed as JSON and nothing is
+//! sent. NATS_URL selects the server (default nats://localhost:4222).
+
+#![cfg(feature = "runtime")]
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use cim_infrastructure::event_store::NatsEventStore;
+use cim_infrastructure::import::{ImportPlan, TerraformState};
+use cim_infrastructure::service::network::EventSourcedNetworkService;
+use cim_infrastructure::service::EventSourcedComputeResourceService;
+use cim_infrastructure::{NatsClient, NatsConfig};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::from_default_env()
+                .add_directive(tracing::Level::INFO.into()),
+        )
+        .init();
+
+    let path = std::env::args()
+        .nth(1)
+        .context("Usage: terraform-import <terraform.tfstate> [--dry-run]")?;
+    let dry_run = std::env::args().any(|arg| arg == "--dry-run");
+
+    let json =
+        std::fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path))?;
+    let state = TerraformState::from_json(&json)?;
+    let plan = ImportPlan::from_state(&state, Uuid::now_v7(), Utc::now());
+
+    for skipped in &plan.skipped {
+        warn!("Skipping {}: {}", skipped.address, skipped.reason);
+    }
+    if dry_run {
+        println!("{}", serde_json::to_string_pretty(&plan)?);
+        return Ok(());
+    }
+
+    let nats_url =
+        std::env::var("NATS_URL").unwrap_or_else(|_| "nats://localhost:4222".to_string());
+    let client = NatsClient::new(NatsConfig {
+        servers: vec![nats_url.clone()],
+        name: "terraform-import".to_string(),
+        ..Default::default()
+    })
+    .await?;
+    let compute = EventSourcedComputeResourceService::new(
+        NatsEventStore::connect(&nats_url).await?,
+        client.clone(),
+    );
+    let network =
+        EventSourcedNetworkService::new(NatsEventStore::connect(&nats_url).await?, client);
+
+    let report = plan.apply(&compute, &network).await?;
+    info!(
+        "Imported {}: {} networks, {} resources ({} already registered), {} interfaces; correlation {}",
+        path,
+        report.networks.len(),
+        report.registered,
+        report.already_registered,
+        report.interfaces,
+        plan.correlation_id
+    );
+    Ok(())
+}
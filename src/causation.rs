@@ -0,0 +1,296 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Causation Graphs
+//!
+//! Reconstructs what happened under one correlation ID: which command
+//! produced which events, and which of those events triggered the next
+//! command (a saga step, a process manager reaction).
+//!
+//! ```text
+//! command ──> ResourceRegistered ──> command ──> InterfaceAttached
+//!  (web-01)                            (eth0)  └─> BondFormed
+//! ```
+//!
+//! Commands are not stored, so they are inferred: events of one aggregate
+//! with the same cause were written by one command. An event whose
+//! `causation_id` names another event of the correlation hangs below that
+//! event; one without a cause, or with a cause outside the correlation,
+//! starts at a root command.
+//!
+//! Graphs are exported as Graphviz DOT ([`CausationGraph::to_dot`]),
+//! Mermaid flowcharts ([`CausationGraph::to_mermaid`]) or, with the
+//! `petgraph` feature, a `petgraph` graph for analysis.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::causation::CausationGraph;
+//!
+//! let graph = CausationGraph::load(&event_store, correlation_id).await?;
+//! std::fs::write("saga.dot", graph.to_dot())?;
+//! println!("{}", graph.to_mermaid());
+//! ```
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use crate::events::InfrastructureEvent;
+
+/// A step of a causation graph
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum CausationNode {
+    /// An inferred command against one aggregate
+    Command {
+        /// Aggregate the command targeted
+        aggregate_id: Uuid,
+
+        /// Event outside the correlation that caused it, if any
+        #[serde(default)]
+        external_cause: Option<Uuid>,
+    },
+
+    /// A stored event
+    Event {
+        /// Event ID
+        event_id: Uuid,
+
+        /// Event type name
+        event_type: String,
+
+        /// Aggregate the event belongs to
+        aggregate_id: Uuid,
+
+        /// When the event occurred
+        timestamp: DateTime<Utc>,
+    },
+}
+
+impl CausationNode {
+    /// Short label for diagrams
+    pub fn label(&self) -> String {
+        match self {
+            CausationNode::Command { aggregate_id, .. } => {
+                format!("command {}", short_id(aggregate_id))
+            }
+            CausationNode::Event {
+                event_type,
+                aggregate_id,
+                ..
+            } => format!("{} {}", event_type, short_id(aggregate_id)),
+        }
+    }
+}
+
+/// Commands and events of one correlation, linked by causation
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CausationGraph {
+    /// Correlation the graph was built for
+    pub correlation_id: Uuid,
+
+    /// Nodes, commands before the events they produced
+    pub nodes: Vec<CausationNode>,
+
+    /// Edges as (from, to) indices into `nodes`
+    pub edges: Vec<(usize, usize)>,
+}
+
+impl CausationGraph {
+    /// Build the graph from a correlation's events
+    ///
+    /// Events of other correlations are ignored; order does not matter.
+    pub fn from_events<'a>(
+        correlation_id: Uuid,
+        events: impl IntoIterator<Item = &'a InfrastructureEvent>,
+    ) -> Self {
+        let mut events: Vec<&InfrastructureEvent> = events
+            .into_iter()
+            .filter(|event| event.correlation_id() == correlation_id)
+            .collect();
+        events.sort_by_key(|event| (event.timestamp(), event.event_id()));
+        let known: HashSet<Uuid> = events.iter().map(|event| event.event_id()).collect();
+
+        let mut graph = Self {
+            correlation_id,
+            ..Self::default()
+        };
+        let mut event_nodes: HashMap<Uuid, usize> = HashMap::new();
+        let mut command_nodes: HashMap<(Option<Uuid>, Uuid), usize> = HashMap::new();
+
+        for event in events {
+            let cause = event.causation_id().filter(|id| *id != event.event_id());
+            let internal_cause = cause.filter(|id| known.contains(id));
+            let aggregate_id = event.aggregate_id();
+
+            // Events of one aggregate with the same cause share a command
+            let key = (internal_cause.or(cause), aggregate_id);
+            let command = match command_nodes.get(&key) {
+                Some(index) => *index,
+                None => {
+                    let index = graph.push(CausationNode::Command {
+                        aggregate_id,
+                        external_cause: cause.filter(|_| internal_cause.is_none()),
+                    });
+                    if let Some(parent) = internal_cause.and_then(|id| event_nodes.get(&id)) {
+                        graph.edges.push((*parent, index));
+                    }
+                    command_nodes.insert(key, index);
+                    index
+                }
+            };
+
+            let index = graph.push(CausationNode::Event {
+                event_id: event.event_id(),
+                event_type: event.event_type_name().to_string(),
+                aggregate_id,
+                timestamp: event.timestamp(),
+            });
+            graph.edges.push((command, index));
+            event_nodes.insert(event.event_id(), index);
+        }
+
+        graph
+    }
+
+    /// Load and build the graph of a correlation from an event store
+    #[cfg(feature = "runtime")]
+    pub async fn load(
+        store: &impl crate::event_store::EventStore,
+        correlation_id: Uuid,
+    ) -> crate::errors::InfrastructureResult<Self> {
+        let stored = store.read_by_correlation(correlation_id).await?;
+        Ok(Self::from_events(
+            correlation_id,
+            stored.iter().map(|stored| &stored.data),
+        ))
+    }
+
+    /// Indices of nodes nothing leads to
+    pub fn roots(&self) -> Vec<usize> {
+        let targets: HashSet<usize> = self.edges.iter().map(|(_, to)| *to).collect();
+        (0..self.nodes.len())
+            .filter(|index| !targets.contains(index))
+            .collect()
+    }
+
+    /// Graphviz DOT
+    pub fn to_dot(&self) -> String {
+        let mut out = format!(
+            "digraph \"causation {}\" {{\n  rankdir=LR;\n",
+            self.correlation_id
+        );
+        for (index, node) in self.nodes.iter().enumerate() {
+            let shape = match node {
+                CausationNode::Command { .. } => "ellipse",
+                CausationNode::Event { .. } => "box",
+            };
+            out.push_str(&format!(
+                "  n{} [label=\"{}\", shape={}];\n",
+                index,
+                node.label().replace('"', "\\\""),
+                shape
+            ));
+        }
+        for (from, to) in &self.edges {
+            out.push_str(&format!("  n{} -> n{};\n", from, to));
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// Mermaid flowchart
+    pub fn to_mermaid(&self) -> String {
+        let mut out = String::from("flowchart LR\n");
+        for (index, node) in self.nodes.iter().enumerate() {
+            let label = node.label().replace('"', "#quot;");
+            match node {
+                CausationNode::Command { .. } => {
+                    out.push_str(&format!("  n{}([\"{}\"])\n", index, label))
+                }
+                CausationNode::Event { .. } => {
+                    out.push_str(&format!("  n{}[\"{}\"]\n", index, label))
+                }
+            }
+        }
+        for (from, to) in &self.edges {
+            out.push_str(&format!("  n{} --> n{}\n", from, to));
+        }
+        out
+    }
+
+    /// The graph as a `petgraph` directed graph
+    #[cfg(feature = "petgraph")]
+    pub fn to_petgraph(&self) -> petgraph::graph::DiGraph<CausationNode, ()> {
+        let mut graph = petgraph::graph::DiGraph::new();
+        let indices: Vec<_> = self
+            .nodes
+            .iter()
+            .map(|node| graph.add_node(node.clone()))
+            .collect();
+        for (from, to) in &self.edges {
+            graph.add_edge(indices[*from], indices[*to], ());
+        }
+        graph
+    }
+
+    fn push(&mut self, node: CausationNode) -> usize {
+        self.nodes.push(node);
+        self.nodes.len() - 1
+    }
+}
+
+/// First 8 hex digits of an ID
+fn short_id(id: &Uuid) -> String {
+    id.simple().to_string()[..8].to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Hostname, ResourceType};
+    use crate::events::{ComputeResourceEvent, ResourceRegistered};
+
+    fn registered(
+        aggregate_id: Uuid,
+        correlation_id: Uuid,
+        causation_id: Option<Uuid>,
+    ) -> InfrastructureEvent {
+        InfrastructureEvent::ComputeResource(ComputeResourceEvent::ResourceRegistered(
+            ResourceRegistered {
+                event_version: 1,
+                event_id: Uuid::now_v7(),
+                aggregate_id,
+                timestamp: Utc::now(),
+                correlation_id,
+                causation_id,
+                hostname: Hostname::new("edge-01").unwrap(),
+                resource_type: ResourceType::PhysicalServer,
+            },
+        ))
+    }
+
+    #[test]
+    fn test_saga_steps_hang_below_their_cause() {
+        // Arrange: the first event triggers a command writing two events
+        let correlation_id = Uuid::now_v7();
+        let first = registered(Uuid::now_v7(), correlation_id, None);
+        let follow_up = Uuid::now_v7();
+        let second = registered(follow_up, correlation_id, Some(first.event_id()));
+        let third = registered(follow_up, correlation_id, Some(first.event_id()));
+        let unrelated = registered(Uuid::now_v7(), Uuid::now_v7(), None);
+
+        // Act
+        let graph =
+            CausationGraph::from_events(correlation_id, [&third, &unrelated, &second, &first]);
+
+        // Assert: command -> first -> command -> second, third
+        assert_eq!(graph.nodes.len(), 5);
+        assert_eq!(graph.roots(), vec![0]);
+        assert_eq!(graph.edges, vec![(0, 1), (1, 2), (2, 3), (2, 4)]);
+        assert!(graph.to_dot().contains("n1 -> n2;"));
+        assert!(graph
+            .to_mermaid()
+            .starts_with("flowchart LR\n  n0([\"command"));
+    }
+}
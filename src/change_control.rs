@@ -0,0 +1,227 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Change Control
+//!
+//! Ties the event log to an external change process (ITIL change tickets,
+//! issue trackers). Every mutating compute resource command may carry a
+//! [`ChangeRef`]; the service copies it into the `metadata` of the events
+//! the command produced, and the event store indexes those events so an
+//! auditor can list everything a change request did:
+//!
+//! ```text
+//! Command{change_ref} ──> service ──check──> ChangeControlConfig
+//!                            │
+//!                            └──append──> StoredEvent.metadata {"change_ref": "CHG0012345"}
+//!                                             └──> infrastructure.change.<index_key>
+//! ```
+//!
+//! With [`ChangeControlConfig::require_for_production`] set, commands
+//! against production resources (tagged `environment=production` by
+//! default) are rejected without a change reference. A command that tags a
+//! resource as production, or removes the tag, needs one as well.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::change_control::ChangeControlConfig;
+//!
+//! let service = EventSourcedComputeResourceService::new(event_store, nats_client)
+//!     .with_change_control(ChangeControlConfig::required_for_production());
+//!
+//! let events = event_store.read_by_change_ref(&ChangeRef::new("CHG0012345")?).await?;
+//! ```
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::aggregate::ComputeResourceState;
+pub use crate::domain::ChangeRef;
+#[cfg(feature = "runtime")]
+use crate::jetstream::StoredEvent;
+
+/// Key of the change reference inside `StoredEvent::metadata`
+#[cfg(feature = "runtime")]
+const CHANGE_REF_KEY: &str = "change_ref";
+
+/// Change control settings
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChangeControlConfig {
+    /// Reject commands against production resources without a change
+    /// reference
+    #[serde(default)]
+    pub require_for_production: bool,
+
+    /// Metadata key marking the environment of a resource
+    #[serde(default = "default_environment_key")]
+    pub environment_key: String,
+
+    /// Value of `environment_key` for production resources
+    #[serde(default = "default_production_value")]
+    pub production_value: String,
+}
+
+fn default_environment_key() -> String {
+    "environment".to_string()
+}
+
+fn default_production_value() -> String {
+    "production".to_string()
+}
+
+impl Default for ChangeControlConfig {
+    fn default() -> Self {
+        Self {
+            require_for_production: false,
+            environment_key: default_environment_key(),
+            production_value: default_production_value(),
+        }
+    }
+}
+
+/// Command rejected by change control
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ChangeControlError {
+    /// A production resource was changed without a change reference
+    #[error("Changing production resource {0} requires a change_ref")]
+    ChangeRefRequired(Uuid),
+
+    /// Commands appended together named different change requests
+    #[error("Commands appended together name different change requests: {0} and {1}")]
+    ConflictingChangeRefs(ChangeRef, ChangeRef),
+}
+
+impl ChangeControlConfig {
+    /// Default tagging, with change references required for production
+    pub fn required_for_production() -> Self {
+        Self {
+            require_for_production: true,
+            ..Self::default()
+        }
+    }
+
+    /// Whether a resource is tagged as production
+    pub fn is_production(&self, state: &ComputeResourceState) -> bool {
+        state
+            .metadata
+            .iter()
+            .any(|(key, value)| key == &self.environment_key && value == &self.production_value)
+    }
+
+    /// Check the transition of a resource from `before` to `after`
+    ///
+    /// A change reference is required when either state is production, so
+    /// promoting a resource to production and demoting it are both
+    /// controlled changes.
+    pub fn check(
+        &self,
+        before: &ComputeResourceState,
+        after: &ComputeResourceState,
+        change_ref: Option<&ChangeRef>,
+    ) -> Result<(), ChangeControlError> {
+        if !self.require_for_production || change_ref.is_some() {
+            return Ok(());
+        }
+
+        if self.is_production(before) || self.is_production(after) {
+            return Err(ChangeControlError::ChangeRefRequired(after.id));
+        }
+
+        Ok(())
+    }
+}
+
+/// The one change request a group of commands is written under
+///
+/// Events of one append share their metadata, so commands appended
+/// together may name at most one change request; commands without one are
+/// covered by it.
+pub fn shared_change_ref<'a>(
+    change_refs: impl IntoIterator<Item = Option<&'a ChangeRef>>,
+) -> Result<Option<ChangeRef>, ChangeControlError> {
+    let mut shared: Option<&ChangeRef> = None;
+    for change_ref in change_refs.into_iter().flatten() {
+        match shared {
+            Some(existing) if existing != change_ref => {
+                return Err(ChangeControlError::ConflictingChangeRefs(
+                    existing.clone(),
+                    change_ref.clone(),
+                ))
+            }
+            _ => shared = Some(change_ref),
+        }
+    }
+    Ok(shared.cloned())
+}
+
+/// Record the change reference in the event metadata, keeping other keys
+#[cfg(feature = "runtime")]
+pub fn attach_change_ref<E>(event: &mut StoredEvent<E>, change_ref: &ChangeRef) {
+    let mut metadata = match event.metadata.take() {
+        Some(serde_json::Value::Object(map)) => map,
+        _ => serde_json::Map::new(),
+    };
+    metadata.insert(
+        CHANGE_REF_KEY.to_string(),
+        serde_json::Value::String(change_ref.as_str().to_string()),
+    );
+    event.metadata = Some(serde_json::Value::Object(metadata));
+}
+
+/// Change reference an event was written under, if any
+#[cfg(feature = "runtime")]
+pub fn change_ref_of<E>(event: &StoredEvent<E>) -> Option<ChangeRef> {
+    let change_ref = event.metadata.as_ref()?.get(CHANGE_REF_KEY)?.as_str()?;
+    ChangeRef::new(change_ref).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Hostname, ResourceType};
+
+    fn resource(environment: &str) -> ComputeResourceState {
+        let mut state = ComputeResourceState::default_for(Uuid::now_v7());
+        state.hostname = Hostname::new("db01").unwrap();
+        state.resource_type = ResourceType::PhysicalServer;
+        state.metadata = vec![("environment".to_string(), environment.to_string())];
+        state
+    }
+
+    #[test]
+    fn test_change_ref_required_for_production_transitions() {
+        // Arrange
+        let config = ChangeControlConfig::required_for_production();
+        let staging = resource("staging");
+        let production = resource("production");
+        let change = ChangeRef::new("CHG0012345").unwrap();
+
+        // Act / Assert
+        assert!(config.check(&staging, &staging, None).is_ok());
+        assert_eq!(
+            config.check(&staging, &production, None),
+            Err(ChangeControlError::ChangeRefRequired(production.id))
+        );
+        assert!(config.check(&production, &production, None).is_err());
+        assert!(config
+            .check(&production, &production, Some(&change))
+            .is_ok());
+        assert!(ChangeControlConfig::default()
+            .check(&production, &production, None)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_shared_change_ref() {
+        let first = ChangeRef::new("CHG1").unwrap();
+        let second = ChangeRef::new("CHG2").unwrap();
+
+        assert_eq!(shared_change_ref([None, None]), Ok(None));
+        assert_eq!(
+            shared_change_ref([None, Some(&first), Some(&first)]),
+            Ok(Some(first.clone()))
+        );
+        assert_eq!(
+            shared_change_ref([Some(&first), Some(&second)]),
+            Err(ChangeControlError::ConflictingChangeRefs(first, second))
+        );
+    }
+}
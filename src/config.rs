@@ -0,0 +1,674 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Layered Configuration
+//!
+//! One [`Config`] covers every subsystem a service wires together. It is
+//! assembled from layers, each overriding the keys it sets in the ones
+//! before it:
+//!
+//! ```text
+//! profile defaults (dev | staging | prod)
+//!   < YAML file
+//!   < environment   CIM_INFRA__EVENT_STORE__REPLICAS=3
+//!   < overrides     "event_store.replicas=3"
+//!   ──validate()──> Config ──> NatsConfig, JetStreamConfig, ConsumerConfig,
+//!                              ReadModelConfig, RetryPolicy, ChangeControlConfig
+//! ```
+//!
+//! The `dev` profile is the `Default` of each subsystem's own config;
+//! `staging` and `prod` change only what differs. Environment values and
+//! overrides are parsed as YAML scalars, so `true`, `3` and `nats://a:4222`
+//! all get the type of the key they set. Unknown keys are rejected, so a
+//! misspelled variable fails startup instead of being ignored.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::config::{ConfigLoader, Profile};
+//!
+//! let config = ConfigLoader::new(Profile::Prod)
+//!     .with_file("/etc/cim/infrastructure.yaml")
+//!     .with_env()
+//!     .load()?;
+//! config.print_effective();
+//!
+//! let client = NatsClient::new(config.nats_config()).await?;
+//! let event_store = NatsEventStore::with_config(client.jetstream(), config.jetstream_config()).await?;
+//! ```
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::change_control::ChangeControlConfig;
+use crate::errors::{InfrastructureError, InfrastructureResult};
+use crate::event_store::codec::CodecKind;
+use crate::jetstream::{ConsumerConfig, DeliverPolicy, JetStreamConfig, StorageType};
+use crate::nats::NatsConfig;
+use crate::projection::read_model::{ReadModelConfig, RetainedDetail};
+use crate::projection::retry::RetryPolicy;
+
+/// Prefix of environment variables read by [`ConfigLoader::with_env`]
+pub const ENV_PREFIX: &str = "CIM_INFRA__";
+
+/// Environment variable selecting the profile in [`ConfigLoader::from_env`]
+pub const PROFILE_ENV: &str = "CIM_INFRA_PROFILE";
+
+/// Deployment profile supplying the base layer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Profile {
+    /// Local development: one server, in-memory stream
+    #[default]
+    Dev,
+
+    /// Shared test environment: file storage, longer retention
+    Staging,
+
+    /// Production: replicated stream, TLS and change references required
+    Prod,
+}
+
+impl std::str::FromStr for Profile {
+    type Err = InfrastructureError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "dev" | "development" => Ok(Profile::Dev),
+            "staging" => Ok(Profile::Staging),
+            "prod" | "production" => Ok(Profile::Prod),
+            other => Err(InfrastructureError::Configuration(format!(
+                "unknown profile {:?} (expected dev, staging or prod)",
+                other
+            ))),
+        }
+    }
+}
+
+/// NATS connection settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NatsSection {
+    /// Server URLs
+    pub servers: Vec<String>,
+
+    /// Client name
+    pub name: String,
+
+    /// Connection timeout in seconds
+    pub connect_timeout_secs: u64,
+
+    /// Request timeout in seconds
+    pub request_timeout_secs: u64,
+
+    /// Messages buffered while NATS is unreachable (0 = fail publishes)
+    pub outage_buffer: usize,
+}
+
+/// Event stream settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EventStoreSection {
+    /// Stream name
+    pub stream_name: String,
+
+    /// Maximum age of events in days
+    pub max_age_days: u64,
+
+    /// Maximum bytes stored (-1 = unlimited)
+    pub max_bytes: i64,
+
+    /// Maximum messages kept per subject (-1 = unlimited)
+    pub max_messages_per_subject: i64,
+
+    /// Stream replicas
+    pub replicas: usize,
+
+    /// Keep the stream in memory instead of on disk
+    pub memory_storage: bool,
+
+    /// Wire format of event payloads (`json`, `cbor` or `protobuf`)
+    pub codec: CodecKind,
+}
+
+/// Event subscriber settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriberSection {
+    /// Durable consumer name
+    pub consumer_name: String,
+
+    /// Subject filter (None = every event in the stream)
+    pub filter_subject: Option<String>,
+
+    /// Deliver only events published after the consumer is created
+    pub deliver_new: bool,
+
+    /// Maximum unacknowledged events
+    pub max_ack_pending: i64,
+}
+
+/// Projection settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProjectionsSection {
+    /// Detail the read model retains per resource
+    pub retained_detail: RetainedDetail,
+
+    /// Events applied between published read model snapshots
+    pub publish_every: usize,
+
+    /// Attempts per event, including the first
+    pub retry_max_attempts: u32,
+
+    /// Delay before the first retry in milliseconds
+    pub retry_initial_backoff_ms: u64,
+
+    /// Upper bound for any retry delay in milliseconds
+    pub retry_max_backoff_ms: u64,
+}
+
+/// Metrics settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MetricsSection {
+    /// Collect and log metrics
+    pub enabled: bool,
+
+    /// Seconds between metric log lines
+    pub log_interval_secs: u64,
+}
+
+/// Security settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SecuritySection {
+    /// Only allow `tls://` NATS servers
+    pub require_tls: bool,
+
+    /// Change control for production resources
+    pub change_control: ChangeControlConfig,
+}
+
+/// Configuration of every subsystem
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Profile the base layer came from
+    pub profile: Profile,
+
+    /// NATS connection
+    pub nats: NatsSection,
+
+    /// Event stream
+    pub event_store: EventStoreSection,
+
+    /// Event subscriber
+    pub subscriber: SubscriberSection,
+
+    /// Projections
+    pub projections: ProjectionsSection,
+
+    /// Metrics
+    pub metrics: MetricsSection,
+
+    /// Security
+    pub security: SecuritySection,
+}
+
+impl Config {
+    /// Defaults of a profile, before any other layer
+    pub fn for_profile(profile: Profile) -> Self {
+        let nats = NatsConfig::default();
+        let stream = JetStreamConfig::default();
+        let consumer = ConsumerConfig::default();
+        let read_model = ReadModelConfig::default();
+        let retry = RetryPolicy::default();
+
+        let mut config = Self {
+            profile,
+            nats: NatsSection {
+                servers: nats.servers,
+                name: nats.name,
+                connect_timeout_secs: nats.connect_timeout.as_secs(),
+                request_timeout_secs: nats.request_timeout.as_secs(),
+                outage_buffer: nats.outage_buffer,
+            },
+            event_store: EventStoreSection {
+                stream_name: stream.stream_name,
+                max_age_days: stream.max_age.as_secs() / (24 * 60 * 60),
+                max_bytes: stream.max_bytes,
+                max_messages_per_subject: stream.max_messages_per_subject,
+                replicas: stream.replicas,
+                memory_storage: stream.storage == StorageType::Memory,
+                codec: stream.codec,
+            },
+            subscriber: SubscriberSection {
+                consumer_name: consumer.name,
+                filter_subject: consumer.filter_subject,
+                deliver_new: consumer.deliver_policy == DeliverPolicy::New,
+                max_ack_pending: consumer.max_ack_pending,
+            },
+            projections: ProjectionsSection {
+                retained_detail: read_model.detail,
+                publish_every: read_model.publish_every,
+                retry_max_attempts: retry.max_attempts,
+                retry_initial_backoff_ms: retry.initial_backoff.as_millis() as u64,
+                retry_max_backoff_ms: retry.max_backoff.as_millis() as u64,
+            },
+            metrics: MetricsSection {
+                enabled: true,
+                log_interval_secs: 60,
+            },
+            security: SecuritySection {
+                require_tls: false,
+                change_control: ChangeControlConfig::default(),
+            },
+        };
+
+        match profile {
+            Profile::Dev => {
+                config.event_store.memory_storage = true;
+                config.event_store.max_age_days = 7;
+            }
+            Profile::Staging => {
+                config.event_store.max_age_days = 90;
+            }
+            Profile::Prod => {
+                config.event_store.max_age_days = 365;
+                config.event_store.replicas = 3;
+                config.projections.retry_max_attempts = 8;
+                config.security.require_tls = true;
+                config.security.change_control.require_for_production = true;
+            }
+        }
+        config
+    }
+
+    /// Check the configuration, reporting every problem at once
+    pub fn validate(&self) -> InfrastructureResult<()> {
+        let mut problems = Vec::new();
+
+        if self.nats.servers.is_empty() {
+            problems.push("nats.servers must not be empty".to_string());
+        }
+        if self.security.require_tls {
+            for server in self
+                .nats
+                .servers
+                .iter()
+                .filter(|s| !s.starts_with("tls://"))
+            {
+                problems.push(format!(
+                    "nats.servers: {} is not tls:// but security.require_tls is set",
+                    server
+                ));
+            }
+        }
+
+        let stream_name = &self.event_store.stream_name;
+        if stream_name.is_empty()
+            || stream_name
+                .chars()
+                .any(|c| c.is_whitespace() || matches!(c, '.' | '*' | '>'))
+        {
+            problems.push(format!(
+                "event_store.stream_name {:?} must be non-empty without spaces, '.', '*' or '>'",
+                stream_name
+            ));
+        }
+        if !(1..=5).contains(&self.event_store.replicas) {
+            problems.push("event_store.replicas must be between 1 and 5".to_string());
+        }
+        if self.event_store.max_bytes == 0 || self.event_store.max_bytes < -1 {
+            problems.push("event_store.max_bytes must be positive or -1".to_string());
+        }
+        if self.event_store.max_messages_per_subject == 0
+            || self.event_store.max_messages_per_subject < -1
+        {
+            problems
+                .push("event_store.max_messages_per_subject must be positive or -1".to_string());
+        }
+        if let Err(e) = self.event_store.codec.codec() {
+            problems.push(format!("event_store.codec: {}", e));
+        }
+        if self.profile == Profile::Prod && self.event_store.memory_storage {
+            problems.push("event_store.memory_storage is not allowed in prod".to_string());
+        }
+
+        if self.subscriber.consumer_name.is_empty() {
+            problems.push("subscriber.consumer_name must not be empty".to_string());
+        }
+        if self.subscriber.max_ack_pending < 1 {
+            problems.push("subscriber.max_ack_pending must be at least 1".to_string());
+        }
+
+        if self.projections.publish_every == 0 {
+            problems.push("projections.publish_every must be at least 1".to_string());
+        }
+        if self.projections.retry_max_attempts == 0 {
+            problems.push("projections.retry_max_attempts must be at least 1".to_string());
+        }
+        if self.projections.retry_initial_backoff_ms > self.projections.retry_max_backoff_ms {
+            problems.push(
+                "projections.retry_initial_backoff_ms must not exceed retry_max_backoff_ms"
+                    .to_string(),
+            );
+        }
+
+        if self.metrics.enabled && self.metrics.log_interval_secs == 0 {
+            problems.push("metrics.log_interval_secs must be at least 1".to_string());
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(InfrastructureError::Configuration(problems.join("; ")))
+        }
+    }
+
+    /// Effective configuration as YAML
+    pub fn effective_yaml(&self) -> String {
+        serde_yaml::to_string(self).unwrap_or_else(|e| format!("# unprintable: {}", e))
+    }
+
+    /// Print the effective configuration to stdout
+    pub fn print_effective(&self) {
+        println!("# effective configuration ({:?})", self.profile);
+        print!("{}", self.effective_yaml());
+    }
+
+    /// NATS client configuration
+    pub fn nats_config(&self) -> NatsConfig {
+        NatsConfig {
+            servers: self.nats.servers.clone(),
+            name: self.nats.name.clone(),
+            connect_timeout: Duration::from_secs(self.nats.connect_timeout_secs),
+            request_timeout: Duration::from_secs(self.nats.request_timeout_secs),
+            outage_buffer: self.nats.outage_buffer,
+        }
+    }
+
+    /// Event stream configuration
+    pub fn jetstream_config(&self) -> JetStreamConfig {
+        let stream = &self.event_store;
+        JetStreamConfig {
+            stream_name: stream.stream_name.clone(),
+            storage: if stream.memory_storage {
+                StorageType::Memory
+            } else {
+                StorageType::File
+            },
+            replicas: stream.replicas,
+            codec: stream.codec,
+            ..JetStreamConfig::default()
+        }
+        .with_max_age(Duration::from_secs(stream.max_age_days * 24 * 60 * 60))
+        .with_max_bytes(stream.max_bytes)
+        .with_max_messages_per_subject(stream.max_messages_per_subject)
+    }
+
+    /// Event subscriber configuration
+    pub fn consumer_config(&self) -> ConsumerConfig {
+        ConsumerConfig {
+            name: self.subscriber.consumer_name.clone(),
+            filter_subject: self.subscriber.filter_subject.clone(),
+            deliver_policy: if self.subscriber.deliver_new {
+                DeliverPolicy::New
+            } else {
+                DeliverPolicy::All
+            },
+            max_ack_pending: self.subscriber.max_ack_pending,
+            ..ConsumerConfig::default()
+        }
+    }
+
+    /// Read model configuration
+    pub fn read_model_config(&self) -> ReadModelConfig {
+        ReadModelConfig::default()
+            .with_detail(self.projections.retained_detail)
+            .with_publish_every(self.projections.publish_every)
+    }
+
+    /// Projection retry policy
+    pub fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy::default()
+            .with_max_attempts(self.projections.retry_max_attempts)
+            .with_initial_backoff(Duration::from_millis(
+                self.projections.retry_initial_backoff_ms,
+            ))
+            .with_max_backoff(Duration::from_millis(self.projections.retry_max_backoff_ms))
+    }
+
+    /// Change control configuration
+    pub fn change_control(&self) -> ChangeControlConfig {
+        self.security.change_control.clone()
+    }
+
+    /// Interval between metric log lines (None = metrics disabled)
+    pub fn metrics_interval(&self) -> Option<Duration> {
+        self.metrics
+            .enabled
+            .then(|| Duration::from_secs(self.metrics.log_interval_secs))
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::for_profile(Profile::default())
+    }
+}
+
+/// Builds a [`Config`] from its layers
+#[derive(Debug, Clone, Default)]
+pub struct ConfigLoader {
+    profile: Profile,
+    files: Vec<PathBuf>,
+    env: Vec<(String, String)>,
+    overrides: Vec<(String, String)>,
+}
+
+impl ConfigLoader {
+    /// Start from the defaults of `profile`
+    pub fn new(profile: Profile) -> Self {
+        Self {
+            profile,
+            ..Self::default()
+        }
+    }
+
+    /// Start from the profile named by `CIM_INFRA_PROFILE` (default: dev)
+    pub fn from_env() -> InfrastructureResult<Self> {
+        let profile = match std::env::var(PROFILE_ENV) {
+            Ok(name) => name.parse()?,
+            Err(_) => Profile::default(),
+        };
+        Ok(Self::new(profile))
+    }
+
+    /// Layer a YAML file over the profile; later files win
+    pub fn with_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.files.push(path.into());
+        self
+    }
+
+    /// Layer the process environment over the files
+    pub fn with_env(self) -> Self {
+        self.with_env_vars(std::env::vars())
+    }
+
+    /// Layer the given `CIM_INFRA__SECTION__KEY` variables over the files
+    ///
+    /// Variables without the prefix are ignored.
+    pub fn with_env_vars(mut self, vars: impl IntoIterator<Item = (String, String)>) -> Self {
+        self.env
+            .extend(vars.into_iter().filter_map(|(name, value)| {
+                let path = name.strip_prefix(ENV_PREFIX)?;
+                Some((path.to_ascii_lowercase().replace("__", "."), value))
+            }));
+        self
+    }
+
+    /// Override one dotted key, such as `event_store.replicas`, over every
+    /// other layer
+    pub fn with_override(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.overrides.push((key.into(), value.into()));
+        self
+    }
+
+    /// Override from a `key=value` argument
+    pub fn with_override_arg(self, arg: &str) -> InfrastructureResult<Self> {
+        let (key, value) = arg.split_once('=').ok_or_else(|| {
+            InfrastructureError::Configuration(format!(
+                "override {:?} is not of the form key=value",
+                arg
+            ))
+        })?;
+        Ok(self.with_override(key.trim(), value.trim()))
+    }
+
+    /// Merge the layers and validate the result
+    pub fn load(self) -> InfrastructureResult<Config> {
+        let mut merged = serde_json::to_value(Config::for_profile(self.profile))
+            .map_err(|e| InfrastructureError::Serialization(e.to_string()))?;
+
+        for path in &self.files {
+            let text = std::fs::read_to_string(path).map_err(|e| {
+                InfrastructureError::Configuration(format!("{}: {}", path.display(), e))
+            })?;
+            let layer: Value = serde_yaml::from_str(&text).map_err(|e| {
+                InfrastructureError::Configuration(format!("{}: {}", path.display(), e))
+            })?;
+            if !layer.is_null() {
+                merge(&mut merged, layer);
+            }
+        }
+
+        for (key, raw) in self.env.iter().chain(&self.overrides) {
+            set_path(&mut merged, key, scalar(raw));
+        }
+
+        let config: Config = serde_json::from_value(merged)
+            .map_err(|e| InfrastructureError::Configuration(e.to_string()))?;
+        if config.profile != self.profile {
+            return Err(InfrastructureError::Configuration(
+                "profile can only be chosen when creating the loader".to_string(),
+            ));
+        }
+
+        config.validate()?;
+        Ok(config)
+    }
+}
+
+/// Deep-merge `layer` into `base`; objects merge key by key, anything else
+/// replaces
+fn merge(base: &mut Value, layer: Value) {
+    match (base, layer) {
+        (Value::Object(base), Value::Object(layer)) => {
+            for (key, value) in layer {
+                match base.get_mut(&key) {
+                    Some(existing) => merge(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, layer) => *base = layer,
+    }
+}
+
+/// Set a dotted key, creating intermediate objects
+fn set_path(root: &mut Value, key: &str, value: Value) {
+    let mut current = root;
+    for part in key.split('.') {
+        if !current.is_object() {
+            *current = Value::Object(Default::default());
+        }
+        current = current
+            .as_object_mut()
+            .expect("just made an object")
+            .entry(part)
+            .or_insert(Value::Null);
+    }
+    *current = value;
+}
+
+/// Parse an environment or override value as a YAML scalar
+fn scalar(raw: &str) -> Value {
+    serde_yaml::from_str(raw).unwrap_or_else(|_| Value::String(raw.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layers_override_in_order() {
+        // Arrange
+        let file = std::env::temp_dir().join(format!("cim-config-{}.yaml", uuid::Uuid::now_v7()));
+        std::fs::write(
+            &file,
+            "event_store:\n  replicas: 2\n  max_age_days: 30\nprojections:\n  retained_detail: key_fields\n",
+        )
+        .unwrap();
+        let env = vec![
+            (
+                "CIM_INFRA__EVENT_STORE__REPLICAS".to_string(),
+                "3".to_string(),
+            ),
+            (
+                "CIM_INFRA__METRICS__ENABLED".to_string(),
+                "false".to_string(),
+            ),
+            ("HOME".to_string(), "/root".to_string()),
+        ];
+
+        // Act
+        let config = ConfigLoader::new(Profile::Staging)
+            .with_file(&file)
+            .with_env_vars(env)
+            .with_override("event_store.replicas", "5")
+            .load()
+            .unwrap();
+        std::fs::remove_file(&file).unwrap();
+
+        // Assert
+        assert_eq!(config.event_store.replicas, 5);
+        assert_eq!(config.event_store.max_age_days, 30);
+        assert!(!config.event_store.memory_storage);
+        assert_eq!(
+            config.projections.retained_detail,
+            RetainedDetail::KeyFields
+        );
+        assert_eq!(config.metrics_interval(), None);
+        assert_eq!(
+            config.jetstream_config().max_age,
+            Duration::from_secs(30 * 24 * 60 * 60)
+        );
+        assert!(config.effective_yaml().contains("replicas: 5"));
+    }
+
+    #[test]
+    fn test_validation_reports_every_problem() {
+        let unknown = ConfigLoader::new(Profile::Dev)
+            .with_override("event_store.replica", "3")
+            .load();
+        let invalid = ConfigLoader::new(Profile::Prod)
+            .with_override("event_store.memory_storage", "true")
+            .with_override("projections.publish_every", "0")
+            .load();
+
+        assert!(matches!(
+            unknown,
+            Err(InfrastructureError::Configuration(_))
+        ));
+        let Err(InfrastructureError::Configuration(message)) = invalid else {
+            panic!("expected a configuration error");
+        };
+        assert!(message.contains("not tls://"));
+        assert!(message.contains("memory_storage"));
+        assert!(message.contains("publish_every"));
+    }
+}
@@ -0,0 +1,477 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Naming and Metadata Conventions
+//!
+//! Deployments register [`ConventionRule`]s with a [`ConventionLinter`]
+//! (hostname patterns per site, required metadata keys per resource type,
+//! or their own rules). The linter runs in two places:
+//!
+//! - **On command handling**: the service checks the state a command would
+//!   produce. Error-severity violations the command introduces reject it;
+//!   warnings are logged. Violations the resource already had do not block
+//!   unrelated commands.
+//! - **As a batch audit**: [`ConventionLinter::audit`] checks existing
+//!   inventory and returns a [`ConventionReport`].
+//!
+//! # Architecture
+//!
+//! ```text
+//! ConventionLinter
+//!   ├── HostnamePattern    (site → regex)
+//!   ├── RequiredMetadata   (resource type → keys)
+//!   └── custom rules       (impl ConventionRule)
+//!         │
+//!         ├── check_transition(before, after)   ← service layer
+//!         └── audit(resources) → ConventionReport
+//! ```
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::conventions::{ConventionLinter, HostnamePattern, RequiredMetadata};
+//!
+//! let linter = Arc::new(ConventionLinter::new()
+//!     .with_rule(
+//!         HostnamePattern::new()
+//!             .for_site(dc1_location_id, r"^dc1-[a-z]+-\d{2}$")?,
+//!     )
+//!     .with_rule(
+//!         RequiredMetadata::new().require(ResourceType::PhysicalServer, ["rack", "cost_center"]),
+//!     ));
+//!
+//! let service = EventSourcedComputeResourceService::new(event_store, nats_client)
+//!     .with_conventions(linter.clone());
+//!
+//! let report = linter.audit(&inventory);
+//! println!("{} violations", report.violations.len());
+//! ```
+
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use uuid::Uuid;
+
+use crate::aggregate::ComputeResourceState;
+use crate::domain::ResourceType;
+
+/// Convention configuration errors
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ConventionError {
+    /// Pattern is not a valid regular expression
+    #[error("Invalid pattern '{pattern}': {message}")]
+    InvalidPattern {
+        /// The rejected pattern
+        pattern: String,
+        /// Regex parser message
+        message: String,
+    },
+}
+
+/// How serious a violation is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    /// Reported, never blocks a command
+    Warning,
+
+    /// Rejects commands that introduce it
+    Error,
+}
+
+/// One convention broken by one resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ConventionViolation {
+    /// Offending resource
+    pub resource_id: Uuid,
+
+    /// Hostname of the resource, for readability
+    pub hostname: String,
+
+    /// Name of the rule that was broken
+    pub rule: String,
+
+    /// Severity of the rule
+    pub severity: Severity,
+
+    /// What is wrong
+    pub message: String,
+}
+
+impl fmt::Display for ConventionViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({}): {}", self.hostname, self.rule, self.message)
+    }
+}
+
+/// A naming or metadata convention
+///
+/// Rules are pure: they inspect state and return one message per problem.
+pub trait ConventionRule: Send + Sync {
+    /// Rule name used in reports
+    fn name(&self) -> &str;
+
+    /// Severity of violations of this rule
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    /// Describe every way the resource breaks this rule
+    fn check(&self, resource: &ComputeResourceState) -> Vec<String>;
+}
+
+/// Hostnames must match a pattern chosen by the resource's site
+///
+/// Sites are identified by location ID. Resources without a location, or
+/// at a site with no pattern, use the default pattern if one is set.
+#[derive(Debug, Clone, Default)]
+pub struct HostnamePattern {
+    default: Option<Regex>,
+    per_site: HashMap<String, Regex>,
+    severity: Option<Severity>,
+}
+
+impl HostnamePattern {
+    /// Rule with no patterns (accepts everything)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pattern for resources at `site`
+    pub fn for_site(mut self, site: impl ToString, pattern: &str) -> Result<Self, ConventionError> {
+        self.per_site.insert(site.to_string(), compile(pattern)?);
+        Ok(self)
+    }
+
+    /// Pattern for resources at sites without their own pattern
+    pub fn with_default(mut self, pattern: &str) -> Result<Self, ConventionError> {
+        self.default = Some(compile(pattern)?);
+        Ok(self)
+    }
+
+    /// Override the severity (default: error)
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = Some(severity);
+        self
+    }
+
+    fn pattern_for(&self, resource: &ComputeResourceState) -> Option<&Regex> {
+        resource
+            .location_id
+            .as_ref()
+            .and_then(|site| self.per_site.get(&site.to_string()))
+            .or(self.default.as_ref())
+    }
+}
+
+impl ConventionRule for HostnamePattern {
+    fn name(&self) -> &str {
+        "hostname_pattern"
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity.unwrap_or(Severity::Error)
+    }
+
+    fn check(&self, resource: &ComputeResourceState) -> Vec<String> {
+        match self.pattern_for(resource) {
+            Some(pattern) if !pattern.is_match(resource.hostname.as_str()) => vec![format!(
+                "hostname '{}' does not match '{}'",
+                resource.hostname,
+                pattern.as_str()
+            )],
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Resources of a type must carry certain metadata keys
+///
+/// Defaults to warning severity: freshly registered resources have no
+/// metadata yet, so the keys usually arrive in later commands.
+#[derive(Debug, Clone, Default)]
+pub struct RequiredMetadata {
+    per_type: HashMap<ResourceType, Vec<String>>,
+    severity: Option<Severity>,
+}
+
+impl RequiredMetadata {
+    /// Rule with no requirements
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Require `keys` on every resource of `resource_type`
+    pub fn require<I, K>(mut self, resource_type: ResourceType, keys: I) -> Self
+    where
+        I: IntoIterator<Item = K>,
+        K: Into<String>,
+    {
+        self.per_type
+            .entry(resource_type)
+            .or_default()
+            .extend(keys.into_iter().map(Into::into));
+        self
+    }
+
+    /// Override the severity (default: warning)
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = Some(severity);
+        self
+    }
+}
+
+impl ConventionRule for RequiredMetadata {
+    fn name(&self) -> &str {
+        "required_metadata"
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity.unwrap_or(Severity::Warning)
+    }
+
+    fn check(&self, resource: &ComputeResourceState) -> Vec<String> {
+        let Some(required) = self.per_type.get(&resource.resource_type) else {
+            return Vec::new();
+        };
+
+        required
+            .iter()
+            .filter(|key| {
+                !resource
+                    .metadata
+                    .iter()
+                    .any(|(k, v)| k == *key && !v.trim().is_empty())
+            })
+            .map(|key| format!("missing metadata key '{}'", key))
+            .collect()
+    }
+}
+
+/// Registered conventions
+#[derive(Default)]
+pub struct ConventionLinter {
+    rules: Vec<Box<dyn ConventionRule>>,
+}
+
+impl ConventionLinter {
+    /// Linter with no rules
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a rule
+    pub fn with_rule(mut self, rule: impl ConventionRule + 'static) -> Self {
+        self.register(rule);
+        self
+    }
+
+    /// Add a rule to an existing linter
+    pub fn register(&mut self, rule: impl ConventionRule + 'static) {
+        self.rules.push(Box::new(rule));
+    }
+
+    /// Names of registered rules, in registration order
+    pub fn rule_names(&self) -> Vec<&str> {
+        self.rules.iter().map(|rule| rule.name()).collect()
+    }
+
+    /// Check one resource against every rule
+    pub fn check(&self, resource: &ComputeResourceState) -> Vec<ConventionViolation> {
+        self.rules
+            .iter()
+            .flat_map(|rule| {
+                rule.check(resource)
+                    .into_iter()
+                    .map(move |message| ConventionViolation {
+                        resource_id: resource.id,
+                        hostname: resource.hostname.to_string(),
+                        rule: rule.name().to_string(),
+                        severity: rule.severity(),
+                        message,
+                    })
+            })
+            .collect()
+    }
+
+    /// Violations present in `after` that `before` did not have
+    ///
+    /// Used on command handling so that pre-existing violations do not
+    /// block unrelated changes. Violations are compared by rule and message.
+    pub fn check_transition(
+        &self,
+        before: &ComputeResourceState,
+        after: &ComputeResourceState,
+    ) -> Vec<ConventionViolation> {
+        let existing: HashSet<(String, String)> = if before.is_initialized() {
+            self.check(before)
+                .into_iter()
+                .map(|v| (v.rule, v.message))
+                .collect()
+        } else {
+            HashSet::new()
+        };
+
+        self.check(after)
+            .into_iter()
+            .filter(|v| !existing.contains(&(v.rule.clone(), v.message.clone())))
+            .collect()
+    }
+
+    /// Check existing inventory and build a report
+    #[cfg(feature = "clock")]
+    pub fn audit<'a, I>(&self, resources: I) -> ConventionReport
+    where
+        I: IntoIterator<Item = &'a ComputeResourceState>,
+    {
+        self.audit_at(resources, Utc::now())
+    }
+
+    /// Check existing inventory, stamping the report with `generated_at`
+    pub fn audit_at<'a, I>(&self, resources: I, generated_at: DateTime<Utc>) -> ConventionReport
+    where
+        I: IntoIterator<Item = &'a ComputeResourceState>,
+    {
+        let mut resources_checked = 0;
+        let mut violations = Vec::new();
+
+        for resource in resources {
+            resources_checked += 1;
+            violations.extend(self.check(resource));
+        }
+
+        violations.sort_by(|a, b| {
+            b.severity
+                .cmp(&a.severity)
+                .then_with(|| a.hostname.cmp(&b.hostname))
+                .then_with(|| a.rule.cmp(&b.rule))
+        });
+
+        ConventionReport {
+            generated_at,
+            resources_checked,
+            violations,
+        }
+    }
+}
+
+/// Result of a batch audit
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConventionReport {
+    /// When the audit ran
+    pub generated_at: DateTime<Utc>,
+
+    /// Number of resources inspected
+    pub resources_checked: usize,
+
+    /// Violations, errors first, then by hostname
+    pub violations: Vec<ConventionViolation>,
+}
+
+impl ConventionReport {
+    /// Whether no resource broke any rule
+    pub fn is_clean(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Violations of the given severity
+    pub fn with_severity(&self, severity: Severity) -> impl Iterator<Item = &ConventionViolation> {
+        self.violations
+            .iter()
+            .filter(move |v| v.severity == severity)
+    }
+
+    /// Number of violations per rule
+    pub fn by_rule(&self) -> BTreeMap<&str, usize> {
+        let mut counts = BTreeMap::new();
+        for violation in &self.violations {
+            *counts.entry(violation.rule.as_str()).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Number of distinct resources with at least one violation
+    pub fn resources_with_violations(&self) -> usize {
+        self.violations
+            .iter()
+            .map(|v| v.resource_id)
+            .collect::<HashSet<_>>()
+            .len()
+    }
+}
+
+fn compile(pattern: &str) -> Result<Regex, ConventionError> {
+    Regex::new(pattern).map_err(|e| ConventionError::InvalidPattern {
+        pattern: pattern.to_string(),
+        message: e.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::Hostname;
+    use chrono::TimeZone;
+
+    fn resource(hostname: &str, resource_type: ResourceType) -> ComputeResourceState {
+        let mut state = ComputeResourceState::default_for(Uuid::now_v7());
+        state.hostname = Hostname::new(hostname).unwrap();
+        state.resource_type = resource_type;
+        state.created_at = Some(Utc.with_ymd_and_hms(2026, 1, 19, 12, 0, 0).unwrap());
+        state
+    }
+
+    fn linter() -> ConventionLinter {
+        ConventionLinter::new()
+            .with_rule(
+                HostnamePattern::new()
+                    .with_default(r"^[a-z]+-\d{2}$")
+                    .unwrap(),
+            )
+            .with_rule(RequiredMetadata::new().require(ResourceType::PhysicalServer, ["rack"]))
+    }
+
+    #[test]
+    fn test_invalid_pattern_is_rejected() {
+        let result = HostnamePattern::new().with_default("(unclosed");
+
+        assert!(matches!(
+            result,
+            Err(ConventionError::InvalidPattern { .. })
+        ));
+    }
+
+    #[test]
+    fn test_audit_reports_each_rule() {
+        // Arrange
+        let good = {
+            let mut state = resource("web-01", ResourceType::PhysicalServer);
+            state.metadata.push(("rack".to_string(), "R12".to_string()));
+            state
+        };
+        let bad_name = resource("WebServer", ResourceType::VirtualMachine);
+        let no_rack = resource("db-02", ResourceType::PhysicalServer);
+
+        // Act
+        let report = linter().audit([&good, &bad_name, &no_rack]);
+
+        // Assert
+        assert_eq!(report.resources_checked, 3);
+        assert_eq!(report.resources_with_violations(), 2);
+        assert_eq!(report.by_rule().get("hostname_pattern"), Some(&1));
+        assert_eq!(report.violations[0].severity, Severity::Error);
+        assert_eq!(report.with_severity(Severity::Warning).count(), 1);
+    }
+
+    #[test]
+    fn test_transition_ignores_existing_violations() {
+        let before = resource("WebServer", ResourceType::VirtualMachine);
+        let mut after = before.clone();
+        after.asset_tag = Some("A-1001".to_string());
+
+        let registering = ComputeResourceState::default_for(before.id);
+
+        assert!(linter().check_transition(&before, &after).is_empty());
+        assert_eq!(linter().check_transition(&registering, &before).len(), 1);
+    }
+}
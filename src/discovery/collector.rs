@@ -0,0 +1,293 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Fact Collectors
+//!
+//! Two ways to collect [`HostFacts`]:
+//!
+//! - [`SshCollector`] runs [`LINUX_FACTS_SCRIPT`] through the system `ssh`
+//!   binary in batch mode, so keys, agents and `~/.ssh/config` work as
+//!   they do for operators. Nothing is installed on the host.
+//! - [`AgentCollector`] asks a small agent running on the host over NATS
+//!   request/reply on `infrastructure.agent.<agent>.facts`; the agent
+//!   answers with `HostFacts` as JSON.
+//!
+//! Both give up after their timeout so one hung host cannot stall a scan.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::Duration;
+use tokio::process::Command;
+use uuid::Uuid;
+
+use crate::discovery::facts::{HostFacts, LINUX_FACTS_SCRIPT};
+use crate::discovery::DiscoveryError;
+use crate::nats::NatsClient;
+use crate::subjects::{is_valid_token, subjects};
+
+/// How facts are collected from a target
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum CollectionMethod {
+    /// Run the facts script over SSH
+    Ssh {
+        /// Login user (None = `ssh` default)
+        #[serde(default)]
+        user: Option<String>,
+
+        /// Port (None = 22 or `~/.ssh/config`)
+        #[serde(default)]
+        port: Option<u16>,
+
+        /// Private key file
+        #[serde(default)]
+        identity_file: Option<PathBuf>,
+    },
+
+    /// Ask the discovery agent on the host
+    Agent {
+        /// Agent name, one subject token (e.g. the short hostname)
+        agent: String,
+    },
+}
+
+/// A host to scan
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiscoveryTarget {
+    /// Address or name to connect to
+    pub address: String,
+
+    /// Resource the host is known to be, if any
+    #[serde(default)]
+    pub resource_id: Option<Uuid>,
+
+    /// Collection method
+    #[serde(flatten)]
+    pub method: CollectionMethod,
+}
+
+impl DiscoveryTarget {
+    /// Target scanned over SSH with the `ssh` defaults
+    pub fn ssh(address: impl Into<String>) -> Self {
+        Self {
+            address: address.into(),
+            resource_id: None,
+            method: CollectionMethod::Ssh {
+                user: None,
+                port: None,
+                identity_file: None,
+            },
+        }
+    }
+
+    /// Target asked through its discovery agent
+    pub fn agent(address: impl Into<String>, agent: impl Into<String>) -> Self {
+        Self {
+            address: address.into(),
+            resource_id: None,
+            method: CollectionMethod::Agent {
+                agent: agent.into(),
+            },
+        }
+    }
+
+    /// Pin the target to a known resource
+    pub fn with_resource_id(mut self, resource_id: Uuid) -> Self {
+        self.resource_id = Some(resource_id);
+        self
+    }
+}
+
+/// Collects the facts of one target
+#[async_trait]
+pub trait FactCollector: Send + Sync {
+    /// Whether this collector handles the target's method
+    fn supports(&self, target: &DiscoveryTarget) -> bool;
+
+    /// Collect the target's facts
+    async fn collect(&self, target: &DiscoveryTarget) -> Result<HostFacts, DiscoveryError>;
+}
+
+/// Collects facts by running [`LINUX_FACTS_SCRIPT`] over SSH
+#[derive(Debug, Clone)]
+pub struct SshCollector {
+    program: PathBuf,
+    timeout: Duration,
+}
+
+impl Default for SshCollector {
+    fn default() -> Self {
+        Self {
+            program: PathBuf::from("ssh"),
+            timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+impl SshCollector {
+    /// Collector using `ssh` from `PATH` and a 30 second timeout
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use another SSH client binary
+    pub fn with_program(mut self, program: impl Into<PathBuf>) -> Self {
+        self.program = program.into();
+        self
+    }
+
+    /// Give up on a host after `timeout`
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Arguments passed to the SSH client
+    fn args(&self, target: &DiscoveryTarget) -> Vec<String> {
+        let mut args = vec![
+            "-o".to_string(),
+            "BatchMode=yes".to_string(),
+            "-o".to_string(),
+            format!("ConnectTimeout={}", self.timeout.as_secs().max(1)),
+        ];
+        if let CollectionMethod::Ssh {
+            user,
+            port,
+            identity_file,
+        } = &target.method
+        {
+            if let Some(port) = port {
+                args.extend(["-p".to_string(), port.to_string()]);
+            }
+            if let Some(identity_file) = identity_file {
+                args.extend(["-i".to_string(), identity_file.display().to_string()]);
+            }
+            args.push(match user {
+                Some(user) => format!("{}@{}", user, target.address),
+                None => target.address.clone(),
+            });
+        }
+        args.push(LINUX_FACTS_SCRIPT.to_string());
+        args
+    }
+}
+
+#[async_trait]
+impl FactCollector for SshCollector {
+    fn supports(&self, target: &DiscoveryTarget) -> bool {
+        matches!(target.method, CollectionMethod::Ssh { .. })
+    }
+
+    async fn collect(&self, target: &DiscoveryTarget) -> Result<HostFacts, DiscoveryError> {
+        let output = Command::new(&self.program)
+            .args(self.args(target))
+            .kill_on_drop(true)
+            .output();
+        let output = tokio::time::timeout(self.timeout, output)
+            .await
+            .map_err(|_| {
+                DiscoveryError::Unreachable(format!(
+                    "{}: no answer within {:?}",
+                    target.address, self.timeout
+                ))
+            })?
+            .map_err(|e| DiscoveryError::Unreachable(format!("{}: {}", target.address, e)))?;
+
+        if !output.status.success() {
+            return Err(DiscoveryError::Unreachable(format!(
+                "{}: ssh exited with {}: {}",
+                target.address,
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        HostFacts::from_linux_report(&String::from_utf8_lossy(&output.stdout))
+    }
+}
+
+/// Collects facts from discovery agents over NATS
+#[derive(Clone)]
+pub struct AgentCollector {
+    client: NatsClient,
+    timeout: Duration,
+}
+
+impl AgentCollector {
+    /// Collector with a 10 second timeout
+    pub fn new(client: NatsClient) -> Self {
+        Self {
+            client,
+            timeout: Duration::from_secs(10),
+        }
+    }
+
+    /// Give up on an agent after `timeout`
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+#[async_trait]
+impl FactCollector for AgentCollector {
+    fn supports(&self, target: &DiscoveryTarget) -> bool {
+        matches!(target.method, CollectionMethod::Agent { .. })
+    }
+
+    async fn collect(&self, target: &DiscoveryTarget) -> Result<HostFacts, DiscoveryError> {
+        let CollectionMethod::Agent { agent } = &target.method else {
+            return Err(DiscoveryError::Unreachable(format!(
+                "{}: not an agent target",
+                target.address
+            )));
+        };
+        if !is_valid_token(agent) {
+            return Err(DiscoveryError::Unreachable(format!(
+                "{}: agent name {:?} is not a subject token",
+                target.address, agent
+            )));
+        }
+
+        let request = self
+            .client
+            .inner()
+            .request(subjects::agent_facts(agent), Default::default());
+        let reply = tokio::time::timeout(self.timeout, request)
+            .await
+            .map_err(|_| {
+                DiscoveryError::Unreachable(format!(
+                    "{}: agent {} did not answer within {:?}",
+                    target.address, agent, self.timeout
+                ))
+            })?
+            .map_err(|e| DiscoveryError::Unreachable(format!("{}: {}", target.address, e)))?;
+
+        serde_json::from_slice(&reply.payload)
+            .map_err(|e| DiscoveryError::Malformed(format!("agent {}: {}", agent, e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ssh_arguments() {
+        let target = DiscoveryTarget {
+            address: "10.20.0.5".to_string(),
+            resource_id: None,
+            method: CollectionMethod::Ssh {
+                user: Some("inventory".to_string()),
+                port: Some(2222),
+                identity_file: None,
+            },
+        };
+
+        let args = SshCollector::new().args(&target);
+
+        assert!(args.contains(&"BatchMode=yes".to_string()));
+        assert!(args.windows(2).any(|w| w == ["-p", "2222"]));
+        assert_eq!(args[args.len() - 2], "inventory@10.20.0.5");
+        assert_eq!(args.last().unwrap(), LINUX_FACTS_SCRIPT);
+        assert!(!SshCollector::new().supports(&DiscoveryTarget::agent("web-01", "web-01")));
+    }
+}
@@ -0,0 +1,385 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Host Facts
+//!
+//! Hardware facts of one host, as reported by an agent or parsed from the
+//! output of [`LINUX_FACTS_SCRIPT`]. The script prints one section per
+//! source, each introduced by a `### <name>` line:
+//!
+//! | Section      | Source                                   |
+//! |--------------|------------------------------------------|
+//! | `hostname`   | `hostname`                               |
+//! | `dmi`        | `/sys/class/dmi/id` (vendor, model, serial) |
+//! | `cpu`        | `lscpu`                                  |
+//! | `memory`     | `/proc/meminfo`                          |
+//! | `disks`      | `lsblk -b -d -n -o NAME,SIZE,TYPE`       |
+//! | `interfaces` | `ip -j addr show`                        |
+//! | `speed`      | `/sys/class/net/*/speed`                 |
+//!
+//! Missing sections or unreadable values (the serial number needs root)
+//! leave the fact unset; only the hostname is required. Firmware
+//! placeholders such as `To Be Filled By O.E.M.` count as unset.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::discovery::DiscoveryError;
+use crate::domain::{Hostname, IpAddressWithCidr, MacAddress, ResourceType};
+use crate::reconcile::{ObservedHost, ObservedInterface};
+
+/// Shell script whose output [`HostFacts::from_linux_report`] parses
+pub const LINUX_FACTS_SCRIPT: &str = r#"echo '### hostname'; hostname
+echo '### dmi'; for f in sys_vendor product_name product_serial; do printf '%s=%s\n' "$f" "$(cat /sys/class/dmi/id/$f 2>/dev/null)"; done
+echo '### cpu'; lscpu 2>/dev/null
+echo '### memory'; grep MemTotal /proc/meminfo
+echo '### disks'; lsblk -b -d -n -o NAME,SIZE,TYPE 2>/dev/null
+echo '### interfaces'; ip -j addr show 2>/dev/null
+echo '### speed'; for n in /sys/class/net/*; do printf '%s %s\n' "${n##*/}" "$(cat "$n/speed" 2>/dev/null)"; done
+"#;
+
+/// Firmware placeholder values that mean "not set"
+const PLACEHOLDERS: &[&str] = &[
+    "to be filled by o.e.m.",
+    "not specified",
+    "default string",
+    "system serial number",
+    "none",
+    "0",
+];
+
+/// Product names and vendors of hypervisors
+const VIRTUAL_PLATFORMS: &[&str] = &[
+    "kvm",
+    "qemu",
+    "vmware",
+    "virtualbox",
+    "virtual machine",
+    "hvm domu",
+    "google compute engine",
+    "amazon ec2",
+];
+
+/// Processor facts
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct CpuFacts {
+    /// Processor model name
+    pub model: String,
+
+    /// Logical CPUs
+    pub cores: u32,
+}
+
+/// Block device facts
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct DiskFacts {
+    /// Device name (e.g. `nvme0n1`)
+    pub name: String,
+
+    /// Capacity in bytes
+    pub size_bytes: u64,
+}
+
+/// Network interface facts
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct NicFacts {
+    /// Interface name (e.g. `eno1`)
+    pub name: String,
+
+    /// Hardware address
+    #[serde(default)]
+    pub mac_address: Option<MacAddress>,
+
+    /// Global addresses configured on the interface
+    #[serde(default)]
+    pub addresses: Vec<IpAddressWithCidr>,
+
+    /// Link speed in Mbit/s, if the link is up
+    #[serde(default)]
+    pub speed_mbps: Option<u32>,
+}
+
+/// Hardware facts of one host
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct HostFacts {
+    /// Hostname the host reports
+    pub hostname: Hostname,
+
+    /// Physical server or virtual machine, from the DMI product
+    pub resource_type: ResourceType,
+
+    /// Hardware manufacturer
+    #[serde(default)]
+    pub manufacturer: Option<String>,
+
+    /// Hardware model
+    #[serde(default)]
+    pub model: Option<String>,
+
+    /// Serial number
+    #[serde(default)]
+    pub serial_number: Option<String>,
+
+    /// Processors
+    #[serde(default)]
+    pub cpu: Option<CpuFacts>,
+
+    /// Installed memory in MiB
+    #[serde(default)]
+    pub memory_mib: Option<u64>,
+
+    /// Physical disks
+    #[serde(default)]
+    pub disks: Vec<DiskFacts>,
+
+    /// Network interfaces, loopback excluded
+    #[serde(default)]
+    pub interfaces: Vec<NicFacts>,
+}
+
+impl HostFacts {
+    /// Parse the output of [`LINUX_FACTS_SCRIPT`]
+    pub fn from_linux_report(report: &str) -> Result<Self, DiscoveryError> {
+        let sections = sections(report);
+        let section = |name: &str| sections.get(name).map(Vec::as_slice).unwrap_or_default();
+
+        let hostname = section("hostname")
+            .iter()
+            .map(|line| line.trim())
+            .find(|line| !line.is_empty())
+            .ok_or_else(|| DiscoveryError::Malformed("report has no hostname".to_string()))?;
+        let hostname = Hostname::new(hostname)
+            .map_err(|e| DiscoveryError::Malformed(format!("hostname {:?}: {}", hostname, e)))?;
+
+        let dmi: HashMap<&str, &str> = section("dmi")
+            .iter()
+            .filter_map(|line| line.split_once('='))
+            .map(|(key, value)| (key.trim(), value.trim()))
+            .collect();
+        let dmi_value = |key: &str| {
+            dmi.get(key)
+                .filter(|value| !value.is_empty())
+                .filter(|value| !PLACEHOLDERS.contains(&value.to_ascii_lowercase().as_str()))
+                .map(|value| value.to_string())
+        };
+        let manufacturer = dmi_value("sys_vendor");
+        let model = dmi_value("product_name");
+        let platform = format!(
+            "{} {}",
+            manufacturer.as_deref().unwrap_or_default(),
+            model.as_deref().unwrap_or_default()
+        )
+        .to_ascii_lowercase();
+        let resource_type = if VIRTUAL_PLATFORMS.iter().any(|p| platform.contains(p)) {
+            ResourceType::VirtualMachine
+        } else {
+            ResourceType::PhysicalServer
+        };
+
+        Ok(Self {
+            hostname,
+            resource_type,
+            manufacturer,
+            model,
+            serial_number: dmi_value("product_serial"),
+            cpu: parse_cpu(section("cpu")),
+            memory_mib: parse_memory(section("memory")),
+            disks: parse_disks(section("disks")),
+            interfaces: parse_interfaces(section("interfaces"), section("speed"))?,
+        })
+    }
+
+    /// Total disk capacity in bytes
+    pub fn disk_bytes(&self) -> u64 {
+        self.disks.iter().map(|disk| disk.size_bytes).sum()
+    }
+
+    /// The host as an observation for drift detection
+    pub fn to_observed_host(&self) -> ObservedHost {
+        self.interfaces
+            .iter()
+            .fold(ObservedHost::new(self.hostname.clone()), |host, nic| {
+                let interface = ObservedInterface::new(nic.name.clone(), nic.addresses.clone());
+                host.with_interface(match &nic.mac_address {
+                    Some(mac) => interface.with_mac_address(mac.clone()),
+                    None => interface,
+                })
+            })
+    }
+}
+
+/// Lines of each `### <name>` section
+fn sections(report: &str) -> HashMap<&str, Vec<&str>> {
+    let mut sections: HashMap<&str, Vec<&str>> = HashMap::new();
+    let mut current = None;
+    for line in report.lines() {
+        if let Some(name) = line.strip_prefix("### ") {
+            current = Some(name.trim());
+            sections.entry(name.trim()).or_default();
+        } else if let Some(name) = current {
+            sections.entry(name).or_default().push(line);
+        }
+    }
+    sections
+}
+
+/// `Key: value` lines as used by `lscpu` and `/proc/meminfo`
+fn field<'a>(lines: &[&'a str], key: &str) -> Option<&'a str> {
+    lines.iter().find_map(|line| {
+        let (k, v) = line.split_once(':')?;
+        (k.trim() == key).then(|| v.trim())
+    })
+}
+
+fn parse_cpu(lines: &[&str]) -> Option<CpuFacts> {
+    Some(CpuFacts {
+        model: field(lines, "Model name")?.to_string(),
+        cores: field(lines, "CPU(s)")?.parse().ok()?,
+    })
+}
+
+fn parse_memory(lines: &[&str]) -> Option<u64> {
+    let kib: u64 = field(lines, "MemTotal")?
+        .split_whitespace()
+        .next()?
+        .parse()
+        .ok()?;
+    Some(kib / 1024)
+}
+
+fn parse_disks(lines: &[&str]) -> Vec<DiskFacts> {
+    lines
+        .iter()
+        .filter_map(
+            |line| match line.split_whitespace().collect::<Vec<_>>()[..] {
+                [name, size, "disk"] => Some(DiskFacts {
+                    name: name.to_string(),
+                    size_bytes: size.parse().ok()?,
+                }),
+                _ => None,
+            },
+        )
+        .collect()
+}
+
+/// One interface of `ip -j addr show`
+#[derive(Deserialize)]
+struct IpLink {
+    ifname: String,
+    #[serde(default)]
+    address: Option<String>,
+    #[serde(default)]
+    link_type: Option<String>,
+    #[serde(default)]
+    addr_info: Vec<IpAddrInfo>,
+}
+
+#[derive(Deserialize)]
+struct IpAddrInfo {
+    local: String,
+    prefixlen: u8,
+    #[serde(default)]
+    scope: Option<String>,
+}
+
+fn parse_interfaces(lines: &[&str], speed: &[&str]) -> Result<Vec<NicFacts>, DiscoveryError> {
+    let json = lines.join("\n");
+    if json.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    let links: Vec<IpLink> = serde_json::from_str(&json)
+        .map_err(|e| DiscoveryError::Malformed(format!("ip addr output: {}", e)))?;
+
+    let speeds: HashMap<&str, u32> = speed
+        .iter()
+        .filter_map(|line| {
+            let (name, mbps) = line.split_once(' ')?;
+            Some((name, mbps.trim().parse().ok()?))
+        })
+        .collect();
+
+    Ok(links
+        .into_iter()
+        .filter(|link| link.link_type.as_deref() != Some("loopback"))
+        .map(|link| NicFacts {
+            mac_address: link
+                .address
+                .as_deref()
+                .and_then(|mac| MacAddress::new(mac).ok()),
+            addresses: link
+                .addr_info
+                .iter()
+                .filter(|info| info.scope.as_deref().unwrap_or("global") == "global")
+                .filter_map(|info| {
+                    IpAddressWithCidr::new(format!("{}/{}", info.local, info.prefixlen)).ok()
+                })
+                .collect(),
+            speed_mbps: speeds.get(link.ifname.as_str()).copied(),
+            name: link.ifname,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REPORT: &str = r#"### hostname
+web-01
+### dmi
+sys_vendor=Dell Inc.
+product_name=PowerEdge R650
+product_serial=To Be Filled By O.E.M.
+### cpu
+Architecture:            x86_64
+CPU(s):                  32
+Model name:              Intel(R) Xeon(R) Silver 4314 CPU @ 2.40GHz
+### memory
+MemTotal:       263856980 kB
+### disks
+sda 480103981056 disk
+sr0 1073741312 rom
+nvme0n1 1920383410176 disk
+### interfaces
+[{"ifname":"lo","link_type":"loopback","address":"00:00:00:00:00:00","addr_info":[{"family":"inet","local":"127.0.0.1","prefixlen":8,"scope":"host"}]},
+ {"ifname":"eno1","link_type":"ether","address":"b8:ca:3a:6e:12:01","addr_info":[{"family":"inet","local":"10.20.0.5","prefixlen":24,"scope":"global"},{"family":"inet6","local":"fe80::baca:3aff:fe6e:1201","prefixlen":64,"scope":"link"}]}]
+### speed
+lo
+eno1 10000
+"#;
+
+    #[test]
+    fn test_parse_linux_report() {
+        let facts = HostFacts::from_linux_report(REPORT).unwrap();
+
+        assert_eq!(facts.hostname.as_str(), "web-01");
+        assert_eq!(facts.resource_type, ResourceType::PhysicalServer);
+        assert_eq!(facts.model.as_deref(), Some("PowerEdge R650"));
+        assert_eq!(facts.serial_number, None);
+        assert_eq!(facts.cpu.as_ref().unwrap().cores, 32);
+        assert_eq!(facts.memory_mib, Some(257672));
+        assert_eq!(facts.disks.len(), 2);
+        assert_eq!(facts.interfaces.len(), 1);
+        let eno1 = &facts.interfaces[0];
+        assert_eq!(eno1.name, "eno1");
+        assert_eq!(eno1.addresses.len(), 1);
+        assert_eq!(eno1.addresses[0].as_cidr(), "10.20.0.5/24");
+        assert_eq!(eno1.speed_mbps, Some(10_000));
+    }
+
+    #[test]
+    fn test_virtual_platform_and_missing_hostname() {
+        let vm = REPORT.replace("PowerEdge R650", "KVM");
+
+        assert_eq!(
+            HostFacts::from_linux_report(&vm).unwrap().resource_type,
+            ResourceType::VirtualMachine
+        );
+        assert!(matches!(
+            HostFacts::from_linux_report("### dmi\nsys_vendor=QEMU\n"),
+            Err(DiscoveryError::Malformed(_))
+        ));
+    }
+}
@@ -0,0 +1,653 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! LLDP Topology Discovery
+//!
+//! Learns port-to-port wiring from the LLDP neighbor tables of switches,
+//! read over SNMP (LLDP-MIB, IEEE 802.1AB), and plans the connection
+//! commands that keep the PhysicalConnection graph in line with it:
+//!
+//! ```text
+//! snmpwalk switch 1.0.8802.1.1.2.1 ──> LldpNeighbor ──TopologyPlan::from_neighbors──> RemoveConnection
+//!                                      (local port,        (against the Inventory)    EstablishConnection
+//!                                       remote sysName/port)
+//! ```
+//!
+//! Both ends of a neighbor are resolved to interfaces by hostname (full or
+//! short name) and interface name, so interfaces must be named as LLDP
+//! advertises them (`lldpRemPortId`, or `lldpRemPortDesc` when the port ID
+//! is a MAC address). A link reported by both switches is one cable.
+//!
+//! # Pruning
+//!
+//! Only ports of switches that were polled successfully are pruned: a live
+//! cable on such a port is removed when the port reports a different
+//! neighbor or none at all. Hosts therefore need an LLDP agent (e.g.
+//! `lldpd`) for their cables to survive a sync. Ports whose neighbor
+//! matches no interface keep their cable, as do cables between two devices
+//! that were not polled.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use uuid::Uuid;
+
+use crate::aggregate::connection::{EstablishConnectionCommand, RemoveConnectionCommand};
+use crate::aggregate::ComputeResourceState;
+use crate::discovery::plan::Inventory;
+use crate::domain::Hostname;
+use crate::events::ConnectionEndpoint;
+
+#[cfg(feature = "runtime")]
+use crate::discovery::scheduler::InventorySource;
+#[cfg(feature = "runtime")]
+use crate::discovery::DiscoveryError;
+#[cfg(feature = "runtime")]
+use crate::errors::{InfrastructureError, InfrastructureResult};
+#[cfg(feature = "runtime")]
+use crate::service::{ConnectionService, ServiceResult};
+
+/// Root of the LLDP-MIB objects walked
+pub const LLDP_MIB_ROOT: &str = "1.0.8802.1.1.2.1";
+
+/// `lldpRemTable` column prefixes, indexed by `timeMark.localPortNum.remIndex`
+const REM_PORT_ID: &str = "1.0.8802.1.1.2.1.4.1.1.7.";
+const REM_PORT_DESC: &str = "1.0.8802.1.1.2.1.4.1.1.8.";
+const REM_SYS_NAME: &str = "1.0.8802.1.1.2.1.4.1.1.9.";
+
+/// `lldpLocPortTable` column prefixes, indexed by `localPortNum`
+const LOC_PORT_ID: &str = "1.0.8802.1.1.2.1.3.7.1.3.";
+const LOC_PORT_DESC: &str = "1.0.8802.1.1.2.1.3.7.1.4.";
+
+/// One entry of a switch's LLDP neighbor table
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LldpNeighbor {
+    /// Polled switch
+    pub local_device: Hostname,
+
+    /// Port on the polled switch
+    pub local_port: String,
+
+    /// System name the neighbor advertises
+    pub remote_device: String,
+
+    /// Port the neighbor advertises
+    pub remote_port: String,
+}
+
+impl LldpNeighbor {
+    /// Parse the neighbor table of `local_device` from `snmpwalk -On -OQ`
+    /// output of [`LLDP_MIB_ROOT`]
+    ///
+    /// Entries without a system name or port are skipped.
+    pub fn from_snmpwalk(local_device: &Hostname, walk: &str) -> Vec<Self> {
+        let values: HashMap<&str, String> = walk
+            .lines()
+            .filter_map(|line| {
+                let (oid, value) = line.split_once('=')?;
+                Some((oid.trim().trim_start_matches('.'), snmp_value(value)))
+            })
+            .collect();
+        let column = |prefix: &str| -> BTreeMap<&str, &String> {
+            values
+                .iter()
+                .filter_map(|(oid, value)| Some((oid.strip_prefix(prefix)?, value)))
+                .collect()
+        };
+
+        let local_ids = column(LOC_PORT_ID);
+        let local_descs = column(LOC_PORT_DESC);
+        let remote_ids = column(REM_PORT_ID);
+        let remote_descs = column(REM_PORT_DESC);
+
+        column(REM_SYS_NAME)
+            .into_iter()
+            .filter_map(|(index, remote_device)| {
+                // index = timeMark.localPortNum.remIndex
+                let local_port_num = index.split('.').nth(1)?;
+                let local_port = port_name(
+                    local_ids.get(local_port_num),
+                    local_descs.get(local_port_num),
+                )?;
+                let remote_port = port_name(remote_ids.get(index), remote_descs.get(index))?;
+                (!remote_device.is_empty()).then(|| Self {
+                    local_device: local_device.clone(),
+                    local_port,
+                    remote_device: remote_device.clone(),
+                    remote_port,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Value of one `snmpwalk` line, without type prefix or quotes
+fn snmp_value(value: &str) -> String {
+    let value = value.trim();
+    let value = ["STRING:", "Hex-STRING:", "INTEGER:"]
+        .iter()
+        .find_map(|prefix| value.strip_prefix(prefix))
+        .unwrap_or(value)
+        .trim();
+    value.trim_matches('"').trim().to_string()
+}
+
+/// Port ID, or the description when the ID is a MAC address
+fn port_name(id: Option<&&String>, desc: Option<&&String>) -> Option<String> {
+    let is_mac = |value: &str| {
+        let octets: Vec<&str> = value.split([' ', ':', '-']).collect();
+        octets.len() == 6
+            && octets
+                .iter()
+                .all(|o| o.len() == 2 && o.chars().all(|c| c.is_ascii_hexdigit()))
+    };
+    id.filter(|id| !id.is_empty() && !is_mac(id))
+        .or(desc.filter(|desc| !desc.is_empty()))
+        .map(|name| name.to_string())
+}
+
+/// Commands bringing the connection graph in line with LLDP
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TopologyPlan {
+    /// Live connections to remove, removed before any cable is patched
+    pub remove: Vec<(Uuid, RemoveConnectionCommand)>,
+
+    /// Cables to patch
+    pub establish: Vec<EstablishConnectionCommand>,
+
+    /// Neighbors whose ends match no known interface
+    pub unresolved: Vec<LldpNeighbor>,
+}
+
+impl TopologyPlan {
+    /// Plan the commands for the neighbors reported by the `polled`
+    /// switches
+    pub fn from_neighbors(
+        polled: &[Hostname],
+        neighbors: &[LldpNeighbor],
+        inventory: &Inventory,
+        correlation_id: Uuid,
+        timestamp: DateTime<Utc>,
+    ) -> Self {
+        let polled: HashSet<Uuid> = polled
+            .iter()
+            .filter_map(|device| find_resource(inventory, device.as_str()))
+            .map(|resource| resource.id)
+            .collect();
+
+        // Links keyed by their interfaces in sorted order, so a link
+        // reported from both ends is one cable
+        let mut observed: BTreeMap<(Uuid, Uuid), (ConnectionEndpoint, ConnectionEndpoint)> =
+            BTreeMap::new();
+        let mut unresolved = Vec::new();
+        let mut unresolved_ports = HashSet::new();
+        for neighbor in neighbors {
+            let local = endpoint(
+                inventory,
+                neighbor.local_device.as_str(),
+                &neighbor.local_port,
+            );
+            let remote = endpoint(inventory, &neighbor.remote_device, &neighbor.remote_port);
+            let (Some(local), Some(remote)) = (local, remote) else {
+                if let Some(resource) = find_resource(inventory, neighbor.local_device.as_str()) {
+                    unresolved_ports.insert((resource.id, neighbor.local_port.clone()));
+                }
+                unresolved.push(neighbor.clone());
+                continue;
+            };
+            let (a_end, b_end) = if local.interface_id <= remote.interface_id {
+                (local, remote)
+            } else {
+                (remote, local)
+            };
+            observed
+                .entry((a_end.interface_id, b_end.interface_id))
+                .or_insert((a_end, b_end));
+        }
+
+        let mut remove = Vec::new();
+        for connection in inventory.connections.iter().filter(|c| c.is_live()) {
+            let (Some(a_end), Some(b_end)) = (&connection.a_end, &connection.b_end) else {
+                continue;
+            };
+            let key = if a_end.interface_id <= b_end.interface_id {
+                (a_end.interface_id, b_end.interface_id)
+            } else {
+                (b_end.interface_id, a_end.interface_id)
+            };
+            if observed.remove(&key).is_some() {
+                continue;
+            }
+            let pruned = [a_end, b_end]
+                .into_iter()
+                .filter(|end| !unresolved_ports.contains(&(end.resource_id, end.port.clone())))
+                .find(|end| polled.contains(&end.resource_id));
+            if let Some(end) = pruned {
+                remove.push((
+                    connection.id,
+                    RemoveConnectionCommand {
+                        reason: Some(format!(
+                            "LLDP on {} no longer reports this cable on {}",
+                            end.device, end.port
+                        )),
+                        timestamp,
+                        correlation_id,
+                        causation_id: None,
+                    },
+                ));
+            }
+        }
+
+        let establish = observed
+            .into_values()
+            .map(|(a_end, b_end)| EstablishConnectionCommand {
+                a_end,
+                b_end,
+                label: None,
+                timestamp,
+                correlation_id,
+                causation_id: None,
+            })
+            .collect();
+
+        Self {
+            remove,
+            establish,
+            unresolved,
+        }
+    }
+
+    /// Whether the connection graph already matches LLDP
+    pub fn is_empty(&self) -> bool {
+        self.remove.is_empty() && self.establish.is_empty()
+    }
+}
+
+/// Resource named `device` (hostname or short name, case-insensitive)
+fn find_resource<'a>(inventory: &'a Inventory, device: &str) -> Option<&'a ComputeResourceState> {
+    let short = device.split('.').next().unwrap_or(device);
+    let resources = || inventory.resources.iter().filter(|r| r.is_initialized());
+    resources()
+        .find(|r| r.hostname.as_str().eq_ignore_ascii_case(device))
+        .or_else(|| resources().find(|r| r.hostname.short_name().eq_ignore_ascii_case(short)))
+}
+
+/// Interface `port` of `device`
+fn endpoint(inventory: &Inventory, device: &str, port: &str) -> Option<ConnectionEndpoint> {
+    let resource = find_resource(inventory, device)?;
+    let interface = inventory
+        .interfaces
+        .iter()
+        .find(|i| i.is_initialized() && i.resource_id == Some(resource.id) && i.name == port)?;
+    Some(ConnectionEndpoint {
+        interface_id: interface.id,
+        resource_id: resource.id,
+        device: resource.hostname.clone(),
+        port: port.to_string(),
+    })
+}
+
+/// Result of applying a [`TopologyPlan`]
+#[cfg(feature = "runtime")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TopologyReport {
+    /// Cables patched
+    pub established: usize,
+
+    /// Cables removed
+    pub removed: usize,
+}
+
+#[cfg(feature = "runtime")]
+impl TopologyPlan {
+    /// Execute the plan, stopping at the first rejected command
+    pub async fn apply(
+        &self,
+        connections: &dyn ConnectionService,
+    ) -> ServiceResult<TopologyReport> {
+        let mut report = TopologyReport::default();
+
+        for (connection_id, command) in &self.remove {
+            connections
+                .remove_connection(*connection_id, command.clone())
+                .await?;
+            report.removed += 1;
+        }
+
+        for command in &self.establish {
+            connections.establish_connection(command.clone()).await?;
+            report.established += 1;
+        }
+
+        Ok(report)
+    }
+}
+
+/// A switch whose LLDP table is polled
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SwitchTarget {
+    /// Switch as named in the inventory
+    pub device: Hostname,
+
+    /// Address to poll
+    pub address: String,
+
+    /// SNMPv2c community
+    pub community: String,
+}
+
+/// Polls LLDP neighbor tables with the net-snmp `snmpwalk` binary
+#[cfg(feature = "runtime")]
+#[derive(Debug, Clone)]
+pub struct SnmpPoller {
+    program: std::path::PathBuf,
+    timeout: std::time::Duration,
+}
+
+#[cfg(feature = "runtime")]
+impl Default for SnmpPoller {
+    fn default() -> Self {
+        Self {
+            program: std::path::PathBuf::from("snmpwalk"),
+            timeout: std::time::Duration::from_secs(30),
+        }
+    }
+}
+
+#[cfg(feature = "runtime")]
+impl SnmpPoller {
+    /// Poller using `snmpwalk` from `PATH` and a 30 second timeout
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use another `snmpwalk` binary
+    pub fn with_program(mut self, program: impl Into<std::path::PathBuf>) -> Self {
+        self.program = program.into();
+        self
+    }
+
+    /// Give up on a switch after `timeout`
+    pub fn with_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Read the switch's LLDP neighbors
+    pub async fn poll(&self, switch: &SwitchTarget) -> Result<Vec<LldpNeighbor>, DiscoveryError> {
+        let output = tokio::process::Command::new(&self.program)
+            .args(["-v2c", "-c", &switch.community, "-On", "-OQ"])
+            .arg(&switch.address)
+            .arg(LLDP_MIB_ROOT)
+            .kill_on_drop(true)
+            .output();
+        let output = tokio::time::timeout(self.timeout, output)
+            .await
+            .map_err(|_| {
+                DiscoveryError::Unreachable(format!(
+                    "{}: no SNMP answer within {:?}",
+                    switch.address, self.timeout
+                ))
+            })?
+            .map_err(|e| DiscoveryError::Unreachable(format!("{}: {}", switch.address, e)))?;
+
+        if !output.status.success() {
+            return Err(DiscoveryError::Unreachable(format!(
+                "{}: snmpwalk exited with {}: {}",
+                switch.address,
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        Ok(LldpNeighbor::from_snmpwalk(
+            &switch.device,
+            &String::from_utf8_lossy(&output.stdout),
+        ))
+    }
+}
+
+/// Result of one topology sync
+#[cfg(feature = "runtime")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TopologySyncOutcome {
+    /// Switches polled successfully
+    pub polled: usize,
+
+    /// Cables patched and removed
+    pub report: TopologyReport,
+
+    /// Neighbors matching no known interface
+    pub unresolved: usize,
+
+    /// Switches that could not be polled, with the reason
+    pub failed: Vec<(String, String)>,
+}
+
+/// Keeps the connection graph in sync with the switches' LLDP tables
+///
+/// ```rust,ignore
+/// let sync = TopologySync::new(read_model.handle(), Arc::new(connection_service))
+///     .with_switch(SwitchTarget { device: Hostname::new("leaf-01")?, address: "10.0.0.11".into(), community: "public".into() });
+/// tokio::spawn(async move { sync.run(Duration::from_secs(900)).await });
+/// ```
+#[cfg(feature = "runtime")]
+pub struct TopologySync<S> {
+    source: S,
+    connections: std::sync::Arc<dyn ConnectionService>,
+    poller: SnmpPoller,
+    switches: Vec<SwitchTarget>,
+}
+
+#[cfg(feature = "runtime")]
+impl<S: InventorySource> TopologySync<S> {
+    /// Sync without switches
+    pub fn new(source: S, connections: std::sync::Arc<dyn ConnectionService>) -> Self {
+        Self {
+            source,
+            connections,
+            poller: SnmpPoller::new(),
+            switches: Vec::new(),
+        }
+    }
+
+    /// Use a differently configured poller
+    pub fn with_poller(mut self, poller: SnmpPoller) -> Self {
+        self.poller = poller;
+        self
+    }
+
+    /// Add a switch
+    pub fn with_switch(mut self, switch: SwitchTarget) -> Self {
+        self.switches.push(switch);
+        self
+    }
+
+    /// Poll every switch once and apply the differences
+    ///
+    /// Switches that cannot be polled are skipped and their cables left
+    /// alone. Fails if the inventory cannot be loaded or a command is
+    /// rejected.
+    pub async fn sync_once(&self) -> InfrastructureResult<TopologySyncOutcome> {
+        let mut outcome = TopologySyncOutcome::default();
+        let mut polled = Vec::new();
+        let mut neighbors = Vec::new();
+
+        for switch in &self.switches {
+            match self.poller.poll(switch).await {
+                Ok(found) => {
+                    polled.push(switch.device.clone());
+                    neighbors.extend(found);
+                }
+                Err(e) => {
+                    tracing::warn!("LLDP poll of {} failed: {}", switch.device, e);
+                    outcome
+                        .failed
+                        .push((switch.device.to_string(), e.to_string()));
+                }
+            }
+        }
+        outcome.polled = polled.len();
+
+        let inventory = self.source.inventory().await?;
+        let plan = TopologyPlan::from_neighbors(
+            &polled,
+            &neighbors,
+            &inventory,
+            Uuid::now_v7(),
+            Utc::now(),
+        );
+        outcome.unresolved = plan.unresolved.len();
+        for neighbor in &plan.unresolved {
+            tracing::debug!(
+                "LLDP neighbor {} {} -> {} {} matches no interface",
+                neighbor.local_device,
+                neighbor.local_port,
+                neighbor.remote_device,
+                neighbor.remote_port
+            );
+        }
+
+        outcome.report = plan
+            .apply(self.connections.as_ref())
+            .await
+            .map_err(|e| InfrastructureError::Generic(e.to_string()))?;
+        Ok(outcome)
+    }
+
+    /// Sync on a fixed interval until a sync fails
+    pub async fn run(&self, interval: std::time::Duration) -> InfrastructureResult<()> {
+        tracing::info!(
+            "Starting LLDP topology sync of {} switch(es) (interval: {:?})",
+            self.switches.len(),
+            interval
+        );
+        let mut ticker = tokio::time::interval(interval);
+
+        loop {
+            ticker.tick().await;
+            let outcome = self.sync_once().await?;
+            tracing::info!(
+                "LLDP sync: {} cable(s) patched, {} removed, {} unresolved",
+                outcome.report.established,
+                outcome.report.removed,
+                outcome.unresolved
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aggregate::connection::ConnectionState;
+    use crate::aggregate::network_interface::NetworkInterfaceState;
+
+    fn test_timestamp() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-19T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    const WALK: &str = r#".1.0.8802.1.1.2.1.3.7.1.3.1 = "ge-0/0/1"
+.1.0.8802.1.1.2.1.3.7.1.3.2 = "ge-0/0/2"
+.1.0.8802.1.1.2.1.4.1.1.7.0.1.3 = "eno1"
+.1.0.8802.1.1.2.1.4.1.1.9.0.1.3 = "web-01.example.com"
+.1.0.8802.1.1.2.1.4.1.1.7.0.2.4 = 00 1c 73 aa bb cc
+.1.0.8802.1.1.2.1.4.1.1.8.0.2.4 = "eno2"
+.1.0.8802.1.1.2.1.4.1.1.9.0.2.4 = "db-01"
+"#;
+
+    fn resource(hostname: &str) -> ComputeResourceState {
+        let mut state = ComputeResourceState::default_for(Uuid::now_v7());
+        state.hostname = Hostname::new(hostname).unwrap();
+        state.created_at = Some(test_timestamp());
+        state
+    }
+
+    fn interface(resource: &ComputeResourceState, name: &str) -> NetworkInterfaceState {
+        let mut state = NetworkInterfaceState::default_for(Uuid::now_v7());
+        state.resource_id = Some(resource.id);
+        state.name = name.to_string();
+        state.created_at = Some(test_timestamp());
+        state
+    }
+
+    fn connection(a: ConnectionEndpoint, b: ConnectionEndpoint) -> ConnectionState {
+        let mut state = ConnectionState::default_for(Uuid::now_v7());
+        state.a_end = Some(a);
+        state.b_end = Some(b);
+        state.created_at = Some(test_timestamp());
+        state
+    }
+
+    #[test]
+    fn test_parse_lldp_walk() {
+        let switch = Hostname::new("leaf-01").unwrap();
+
+        let neighbors = LldpNeighbor::from_snmpwalk(&switch, WALK);
+
+        assert_eq!(neighbors.len(), 2);
+        assert!(neighbors.contains(&LldpNeighbor {
+            local_device: switch.clone(),
+            local_port: "ge-0/0/1".to_string(),
+            remote_device: "web-01.example.com".to_string(),
+            remote_port: "eno1".to_string(),
+        }));
+        // MAC port IDs fall back to the port description
+        assert!(neighbors
+            .iter()
+            .any(|n| n.remote_device == "db-01" && n.remote_port == "eno2"));
+    }
+
+    #[test]
+    fn test_plan_patches_new_and_prunes_stale_cables() {
+        // Arrange
+        let leaf = resource("leaf-01");
+        let web = resource("web-01");
+        let db = resource("db-01");
+        let old = resource("old-01");
+        let ports = [
+            (&leaf, interface(&leaf, "ge-0/0/1")),
+            (&leaf, interface(&leaf, "ge-0/0/2")),
+            (&web, interface(&web, "eno1")),
+            (&db, interface(&db, "eno2")),
+            (&old, interface(&old, "eth0")),
+        ];
+        let end = |i: usize| ConnectionEndpoint {
+            interface_id: ports[i].1.id,
+            resource_id: ports[i].0.id,
+            device: ports[i].0.hostname.clone(),
+            port: ports[i].1.name.clone(),
+        };
+        let kept = connection(end(0), end(2));
+        let stale = connection(end(1), end(4));
+        let inventory = Inventory::new(
+            vec![leaf.clone(), web.clone(), db.clone(), old.clone()],
+            ports.iter().map(|(_, port)| port.clone()).collect(),
+            vec![],
+        )
+        .with_connections(vec![kept, stale.clone()]);
+        let neighbors = LldpNeighbor::from_snmpwalk(&leaf.hostname, WALK);
+
+        // Act
+        let plan = TopologyPlan::from_neighbors(
+            &[leaf.hostname.clone()],
+            &neighbors,
+            &inventory,
+            Uuid::now_v7(),
+            test_timestamp(),
+        );
+        let unpolled =
+            TopologyPlan::from_neighbors(&[], &[], &inventory, Uuid::now_v7(), test_timestamp());
+
+        // Assert
+        assert_eq!(plan.remove.len(), 1);
+        assert_eq!(plan.remove[0].0, stale.id);
+        assert_eq!(plan.establish.len(), 1);
+        let ports_patched = [
+            plan.establish[0].a_end.port.as_str(),
+            plan.establish[0].b_end.port.as_str(),
+        ];
+        assert!(ports_patched.contains(&"ge-0/0/2") && ports_patched.contains(&"eno2"));
+        assert!(plan.unresolved.is_empty());
+        assert!(unpolled.is_empty());
+    }
+}
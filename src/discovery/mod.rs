@@ -0,0 +1,64 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Hardware Discovery
+//!
+//! Connects to hosts, collects their hardware facts and plans the commands
+//! that bring the inventory in line with what was found:
+//!
+//! ```text
+//!  ssh host 'LINUX_FACTS_SCRIPT' ─┐
+//!                                 ├─> HostFacts ──DiscoveryPlan::from_facts──> RegisterResource
+//!  agent (NATS request/reply) ────┘        │         (against the Inventory)   SetHardwareDetails
+//!                                          │                                   UpdateMetadata (cpu, memory, disks)
+//!                                          │                                   AttachInterface
+//!                                          └──> Observation (drift monitor)
+//! ```
+//!
+//! Planning is pure and only emits commands for what differs from the
+//! inventory, so re-scanning an unchanged host plans nothing; a changed
+//! serial number, a new disk or a NIC moved to another network plans
+//! exactly that change. [`DiscoveryScheduler`] re-scans its targets on a
+//! fixed interval; [`TopologySync`] does the same for cabling, patching
+//! and removing connections to match the switches' LLDP tables.
+//!
+//! # Modules
+//!
+//! - [`facts`] - Host facts and the Linux fact report parser
+//! - [`plan`] - Commands planned from facts
+//! - [`lldp`] - Port-to-port wiring from switch LLDP tables over SNMP
+//! - [`collector`] - SSH and NATS agent fact collectors
+//! - [`scheduler`] - Periodic re-scans
+
+pub mod facts;
+pub mod lldp;
+pub mod plan;
+
+#[cfg(feature = "runtime")]
+pub mod collector;
+#[cfg(feature = "runtime")]
+pub mod scheduler;
+
+#[cfg(feature = "runtime")]
+pub use collector::{
+    AgentCollector, CollectionMethod, DiscoveryTarget, FactCollector, SshCollector,
+};
+pub use facts::{CpuFacts, DiskFacts, HostFacts, NicFacts, LINUX_FACTS_SCRIPT};
+pub use lldp::{LldpNeighbor, SwitchTarget, TopologyPlan};
+#[cfg(feature = "runtime")]
+pub use lldp::{SnmpPoller, TopologyReport, TopologySync, TopologySyncOutcome};
+#[cfg(feature = "runtime")]
+pub use plan::DiscoveryReport;
+pub use plan::{DiscoveryPlan, Inventory, PlannedAttachment};
+#[cfg(feature = "runtime")]
+pub use scheduler::{DiscoveryScheduler, InventorySource, ScanOutcome};
+
+/// Discovery failures
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum DiscoveryError {
+    /// Host could not be reached or the collection command failed
+    #[error("Host unreachable: {0}")]
+    Unreachable(String),
+
+    /// Collected facts could not be parsed
+    #[error("Malformed facts: {0}")]
+    Malformed(String),
+}
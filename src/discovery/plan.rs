@@ -0,0 +1,533 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Discovery Plans
+//!
+//! Compares the facts collected from one host with the inventory and plans
+//! the commands that record what changed:
+//!
+//! | Found                                   | Command              |
+//! |-----------------------------------------|----------------------|
+//! | Host matches no resource                | `RegisterResource`   |
+//! | Manufacturer, model or serial differ    | `SetHardwareDetails` |
+//! | CPU, memory or disk facts differ        | `UpdateMetadata`     |
+//! | NIC is new, moved network or readdressed| `AttachInterface`    |
+//!
+//! # Identity
+//!
+//! A host is matched to a resource by the ID its target names, then by
+//! serial number, then by hostname, so a renamed host keeps its history.
+//! Unmatched hosts are registered under a UUIDv5 of their serial number
+//! (or hostname when the firmware reports none), so two scanners finding
+//! the same new host register it once. NICs are matched by name within the
+//! resource and placed on the network whose prefix holds their first
+//! address; NICs with no address in a known network are listed in
+//! [`DiscoveryPlan::unplaced`].
+
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::aggregate::commands::{
+    RegisterResourceCommand, SetHardwareDetailsCommand, UpdateMetadataCommand,
+};
+use crate::aggregate::connection::ConnectionState;
+use crate::aggregate::network::NetworkState;
+use crate::aggregate::network_interface::{AttachInterfaceCommand, NetworkInterfaceState};
+use crate::aggregate::ComputeResourceState;
+use crate::discovery::facts::{HostFacts, NicFacts};
+
+#[cfg(feature = "runtime")]
+use crate::aggregate::CommandError;
+#[cfg(feature = "runtime")]
+use crate::service::network::NetworkService;
+#[cfg(feature = "runtime")]
+use crate::service::{ComputeResourceService, ServiceError, ServiceResult};
+
+/// Namespace for the aggregate IDs of discovered resources
+pub const DISCOVERY_NAMESPACE: Uuid = Uuid::from_u128(0x8c2d_5f17_3e9a_4b60_a7d1_62e0_9f4b_1c38);
+
+/// Metadata key holding the processor model
+pub const CPU_MODEL_KEY: &str = "cpu_model";
+
+/// Metadata key holding the logical CPU count
+pub const CPU_CORES_KEY: &str = "cpu_cores";
+
+/// Metadata key holding the installed memory in MiB
+pub const MEMORY_MIB_KEY: &str = "memory_mib";
+
+/// Metadata key holding the number of disks
+pub const DISK_COUNT_KEY: &str = "disk_count";
+
+/// Metadata key holding the total disk capacity in GiB
+pub const DISK_TOTAL_GIB_KEY: &str = "disk_total_gib";
+
+/// What is already recorded, as the plan compares against it
+#[derive(Debug, Clone, Default)]
+pub struct Inventory {
+    /// Compute resources
+    pub resources: Vec<ComputeResourceState>,
+
+    /// Network interfaces
+    pub interfaces: Vec<NetworkInterfaceState>,
+
+    /// Networks
+    pub networks: Vec<NetworkState>,
+
+    /// Live physical connections
+    pub connections: Vec<ConnectionState>,
+}
+
+impl Inventory {
+    /// Inventory of the given aggregates
+    pub fn new(
+        resources: Vec<ComputeResourceState>,
+        interfaces: Vec<NetworkInterfaceState>,
+        networks: Vec<NetworkState>,
+    ) -> Self {
+        Self {
+            resources,
+            interfaces,
+            networks,
+            connections: Vec::new(),
+        }
+    }
+
+    /// Add the live connections (needed for topology discovery)
+    pub fn with_connections(mut self, connections: Vec<ConnectionState>) -> Self {
+        self.connections = connections;
+        self
+    }
+
+    /// Resource a host's facts belong to
+    fn find_resource(
+        &self,
+        resource_id: Option<Uuid>,
+        facts: &HostFacts,
+    ) -> Option<&ComputeResourceState> {
+        let resources = || self.resources.iter().filter(|r| r.is_initialized());
+        if let Some(id) = resource_id {
+            return resources().find(|r| r.id == id);
+        }
+        facts
+            .serial_number
+            .as_ref()
+            .and_then(|serial| resources().find(|r| r.serial_number.as_ref() == Some(serial)))
+            .or_else(|| {
+                resources().find(|r| r.hostname.to_lowercase() == facts.hostname.to_lowercase())
+            })
+    }
+
+    /// Network whose prefix holds one of the NIC's addresses
+    fn network_for(&self, nic: &NicFacts) -> Option<&NetworkState> {
+        nic.addresses.iter().find_map(|address| {
+            self.networks.iter().find(|network| {
+                network
+                    .cidr
+                    .as_ref()
+                    .is_some_and(|cidr| cidr.contains(&address.address()))
+            })
+        })
+    }
+}
+
+/// An interface to attach
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedAttachment {
+    /// Existing interface to move, or None for a new interface
+    pub interface_id: Option<Uuid>,
+
+    /// Attachment command
+    pub command: AttachInterfaceCommand,
+}
+
+/// Commands recording one host's facts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiscoveryPlan {
+    /// Resource the facts belong to
+    pub resource_id: Uuid,
+
+    /// Hostname the host reported
+    pub hostname: String,
+
+    /// Registration, when the host matches no resource
+    pub register: Option<RegisterResourceCommand>,
+
+    /// Hardware details, when they differ
+    pub hardware: Option<SetHardwareDetailsCommand>,
+
+    /// CPU, memory and disk metadata that differs
+    pub metadata: Vec<UpdateMetadataCommand>,
+
+    /// Interfaces to attach or move
+    pub interfaces: Vec<PlannedAttachment>,
+
+    /// NICs with no address in a known network
+    pub unplaced: Vec<String>,
+}
+
+impl DiscoveryPlan {
+    /// Plan the commands for `facts`
+    ///
+    /// `resource_id` pins the host to a resource (e.g. from the scan
+    /// target); otherwise the resource is matched as described in the
+    /// [module documentation](self).
+    pub fn from_facts(
+        resource_id: Option<Uuid>,
+        facts: &HostFacts,
+        inventory: &Inventory,
+        correlation_id: Uuid,
+        timestamp: DateTime<Utc>,
+    ) -> Self {
+        let existing = inventory.find_resource(resource_id, facts);
+        let resource_id = existing.map(|r| r.id).or(resource_id).unwrap_or_else(|| {
+            let key = facts
+                .serial_number
+                .clone()
+                .unwrap_or_else(|| facts.hostname.as_str().to_lowercase());
+            Uuid::new_v5(&DISCOVERY_NAMESPACE, key.as_bytes())
+        });
+
+        let register = existing.is_none().then(|| RegisterResourceCommand {
+            hostname: facts.hostname.clone(),
+            resource_type: facts.resource_type,
+            timestamp,
+            correlation_id,
+            change_ref: None,
+        });
+
+        let hardware_changed = existing.is_none_or(|r| {
+            r.manufacturer != facts.manufacturer
+                || r.model != facts.model
+                || r.serial_number != facts.serial_number
+        });
+        let has_hardware =
+            facts.manufacturer.is_some() || facts.model.is_some() || facts.serial_number.is_some();
+        let hardware = (hardware_changed && has_hardware).then(|| SetHardwareDetailsCommand {
+            manufacturer: facts.manufacturer.clone(),
+            model: facts.model.clone(),
+            serial_number: facts.serial_number.clone(),
+            timestamp,
+            correlation_id,
+            causation_id: None,
+            change_ref: None,
+        });
+
+        let recorded: HashMap<&str, &str> = existing
+            .map(|r| {
+                r.metadata
+                    .iter()
+                    .map(|(k, v)| (k.as_str(), v.as_str()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let metadata = metadata_facts(facts)
+            .into_iter()
+            .filter(|(key, value)| recorded.get(key) != Some(&value.as_str()))
+            .map(|(key, value)| UpdateMetadataCommand {
+                key: key.to_string(),
+                value,
+                timestamp,
+                correlation_id,
+                causation_id: None,
+                change_ref: None,
+            })
+            .collect();
+
+        let mut interfaces = Vec::new();
+        let mut unplaced = Vec::new();
+        for nic in &facts.interfaces {
+            let Some(network) = inventory.network_for(nic) else {
+                unplaced.push(nic.name.clone());
+                continue;
+            };
+            let current = inventory
+                .interfaces
+                .iter()
+                .filter(|i| i.is_initialized() && i.bond.is_none())
+                .find(|i| i.resource_id == Some(resource_id) && i.name == nic.name);
+            let unchanged = current.is_some_and(|i| {
+                i.network_id == Some(network.id)
+                    && i.addresses == nic.addresses
+                    && i.mac_address == nic.mac_address
+                    && i.speed_mbps == nic.speed_mbps
+            });
+            if unchanged {
+                continue;
+            }
+            interfaces.push(PlannedAttachment {
+                interface_id: current.map(|i| i.id),
+                command: AttachInterfaceCommand {
+                    resource_id,
+                    network_id: network.id,
+                    name: nic.name.clone(),
+                    mac_address: nic.mac_address.clone(),
+                    addresses: nic.addresses.clone(),
+                    speed_mbps: nic.speed_mbps,
+                    timestamp,
+                    correlation_id,
+                    causation_id: None,
+                },
+            });
+        }
+
+        Self {
+            resource_id,
+            hostname: facts.hostname.as_str().to_string(),
+            register,
+            hardware,
+            metadata,
+            interfaces,
+            unplaced,
+        }
+    }
+
+    /// Whether the inventory already matches the facts
+    pub fn is_empty(&self) -> bool {
+        self.register.is_none()
+            && self.hardware.is_none()
+            && self.metadata.is_empty()
+            && self.interfaces.is_empty()
+    }
+
+    /// Number of commands planned
+    pub fn command_count(&self) -> usize {
+        usize::from(self.register.is_some())
+            + usize::from(self.hardware.is_some())
+            + self.metadata.len()
+            + self.interfaces.len()
+    }
+}
+
+/// Metadata recorded for the facts that have no dedicated command
+fn metadata_facts(facts: &HostFacts) -> Vec<(&'static str, String)> {
+    let mut metadata = Vec::new();
+    if let Some(cpu) = &facts.cpu {
+        metadata.push((CPU_MODEL_KEY, cpu.model.clone()));
+        metadata.push((CPU_CORES_KEY, cpu.cores.to_string()));
+    }
+    if let Some(memory_mib) = facts.memory_mib {
+        metadata.push((MEMORY_MIB_KEY, memory_mib.to_string()));
+    }
+    if !facts.disks.is_empty() {
+        metadata.push((DISK_COUNT_KEY, facts.disks.len().to_string()));
+        metadata.push((
+            DISK_TOTAL_GIB_KEY,
+            (facts.disk_bytes() / (1024 * 1024 * 1024)).to_string(),
+        ));
+    }
+    metadata
+}
+
+/// Result of applying a [`DiscoveryPlan`]
+#[cfg(feature = "runtime")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DiscoveryReport {
+    /// Whether the resource was registered by this run
+    pub registered: bool,
+
+    /// Commands accepted
+    pub applied: usize,
+}
+
+#[cfg(feature = "runtime")]
+impl DiscoveryPlan {
+    /// Execute the plan, stopping at the first rejected command
+    ///
+    /// A resource registered concurrently by another scanner is treated as
+    /// registered; the remaining commands still apply.
+    pub async fn apply(
+        &self,
+        compute: &dyn ComputeResourceService,
+        network: &dyn NetworkService,
+    ) -> ServiceResult<DiscoveryReport> {
+        let mut report = DiscoveryReport::default();
+
+        if let Some(command) = &self.register {
+            match compute
+                .register_resource_as(self.resource_id, command.clone())
+                .await
+            {
+                Ok(()) => {
+                    report.registered = true;
+                    report.applied += 1;
+                }
+                Err(ServiceError::CommandError(CommandError::AlreadyInitialized)) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        if let Some(command) = &self.hardware {
+            compute
+                .set_hardware_details(self.resource_id, command.clone())
+                .await?;
+            report.applied += 1;
+        }
+
+        for command in &self.metadata {
+            compute
+                .update_metadata(self.resource_id, command.clone())
+                .await?;
+            report.applied += 1;
+        }
+
+        for planned in &self.interfaces {
+            network
+                .attach_interface(planned.interface_id, planned.command.clone())
+                .await?;
+            report.applied += 1;
+        }
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::discovery::facts::{CpuFacts, DiskFacts};
+    use crate::domain::{Hostname, IpAddressWithCidr, MacAddress, ResourceType};
+
+    fn test_timestamp() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-19T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn facts() -> HostFacts {
+        HostFacts {
+            hostname: Hostname::new("web-01").unwrap(),
+            resource_type: ResourceType::PhysicalServer,
+            manufacturer: Some("Dell Inc.".to_string()),
+            model: Some("PowerEdge R650".to_string()),
+            serial_number: Some("7XK2MQ3".to_string()),
+            cpu: Some(CpuFacts {
+                model: "Intel(R) Xeon(R) Silver 4314".to_string(),
+                cores: 32,
+            }),
+            memory_mib: Some(257_672),
+            disks: vec![DiskFacts {
+                name: "nvme0n1".to_string(),
+                size_bytes: 1_920_383_410_176,
+            }],
+            interfaces: vec![
+                NicFacts {
+                    name: "eno1".to_string(),
+                    mac_address: Some(MacAddress::new("b8:ca:3a:6e:12:01").unwrap()),
+                    addresses: vec![IpAddressWithCidr::new("10.20.0.5/24").unwrap()],
+                    speed_mbps: Some(10_000),
+                },
+                NicFacts {
+                    name: "eno2".to_string(),
+                    mac_address: None,
+                    addresses: vec![IpAddressWithCidr::new("192.0.2.5/24").unwrap()],
+                    speed_mbps: None,
+                },
+            ],
+        }
+    }
+
+    fn network() -> NetworkState {
+        let mut network = NetworkState::default_for(Uuid::now_v7());
+        network.name = "servers".to_string();
+        network.cidr = Some(IpAddressWithCidr::new("10.20.0.0/24").unwrap());
+        network.created_at = Some(test_timestamp());
+        network
+    }
+
+    /// Inventory after the plan for `facts` was applied
+    fn recorded(plan: &DiscoveryPlan, facts: &HostFacts, network: &NetworkState) -> Inventory {
+        let mut resource = ComputeResourceState::default_for(plan.resource_id);
+        resource.hostname = facts.hostname.clone();
+        resource.created_at = Some(test_timestamp());
+        resource.manufacturer = facts.manufacturer.clone();
+        resource.model = facts.model.clone();
+        resource.serial_number = facts.serial_number.clone();
+        resource.metadata = plan
+            .metadata
+            .iter()
+            .map(|m| (m.key.clone(), m.value.clone()))
+            .collect();
+
+        let interfaces = plan
+            .interfaces
+            .iter()
+            .map(|planned| {
+                let mut interface = NetworkInterfaceState::default_for(Uuid::now_v7());
+                interface.resource_id = Some(planned.command.resource_id);
+                interface.network_id = Some(planned.command.network_id);
+                interface.name = planned.command.name.clone();
+                interface.mac_address = planned.command.mac_address.clone();
+                interface.addresses = planned.command.addresses.clone();
+                interface.speed_mbps = planned.command.speed_mbps;
+                interface.created_at = Some(test_timestamp());
+                interface
+            })
+            .collect();
+
+        Inventory::new(vec![resource], interfaces, vec![network.clone()])
+    }
+
+    #[test]
+    fn test_new_host_is_registered_with_everything() {
+        // Arrange
+        let facts = facts();
+        let inventory = Inventory::new(vec![], vec![], vec![network()]);
+
+        // Act
+        let plan =
+            DiscoveryPlan::from_facts(None, &facts, &inventory, Uuid::now_v7(), test_timestamp());
+
+        // Assert
+        assert!(plan.register.is_some());
+        assert!(plan.hardware.is_some());
+        assert_eq!(plan.metadata.len(), 5);
+        assert_eq!(plan.interfaces.len(), 1);
+        assert_eq!(plan.interfaces[0].interface_id, None);
+        assert_eq!(plan.unplaced, vec!["eno2".to_string()]);
+        assert_eq!(
+            plan.resource_id,
+            Uuid::new_v5(&DISCOVERY_NAMESPACE, b"7XK2MQ3")
+        );
+    }
+
+    #[test]
+    fn test_rescan_plans_only_changes() {
+        // Arrange
+        let network = network();
+        let facts = facts();
+        let first = DiscoveryPlan::from_facts(
+            None,
+            &facts,
+            &Inventory::new(vec![], vec![], vec![network.clone()]),
+            Uuid::now_v7(),
+            test_timestamp(),
+        );
+        let inventory = recorded(&first, &facts, &network);
+
+        let mut upgraded = facts.clone();
+        upgraded.hostname = Hostname::new("web-01-renamed").unwrap();
+        upgraded.memory_mib = Some(515_344);
+        upgraded.interfaces[0].speed_mbps = Some(25_000);
+
+        // Act
+        let unchanged =
+            DiscoveryPlan::from_facts(None, &facts, &inventory, Uuid::now_v7(), test_timestamp());
+        let changed = DiscoveryPlan::from_facts(
+            None,
+            &upgraded,
+            &inventory,
+            Uuid::now_v7(),
+            test_timestamp(),
+        );
+
+        // Assert
+        assert!(unchanged.is_empty());
+        assert_eq!(changed.resource_id, first.resource_id);
+        assert!(changed.register.is_none());
+        assert!(changed.hardware.is_none());
+        assert_eq!(changed.metadata.len(), 1);
+        assert_eq!(changed.metadata[0].key, MEMORY_MIB_KEY);
+        assert_eq!(changed.interfaces.len(), 1);
+        assert!(changed.interfaces[0].interface_id.is_some());
+        assert_eq!(changed.command_count(), 2);
+    }
+}
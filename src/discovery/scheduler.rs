@@ -0,0 +1,239 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Discovery Scheduler
+//!
+//! Scans a fixed list of targets: collects each host's facts, plans the
+//! commands against the current inventory and applies them. A scan of an
+//! unchanged estate applies nothing, so the scheduler can re-scan often;
+//! what did change (a replaced disk, a NIC moved to another network)
+//! shows up as ordinary events under one correlation ID per host.
+//!
+//! Hosts that cannot be reached or whose commands are rejected are logged
+//! and reported in the [`ScanOutcome`]; the rest of the scan continues.
+//! With [`DiscoveryScheduler::with_observations`] every scan also publishes
+//! an [`Observation`] for the drift monitor.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::discovery::{DiscoveryScheduler, DiscoveryTarget, SshCollector};
+//!
+//! let scheduler = DiscoveryScheduler::new(read_model.handle(), compute, network)
+//!     .with_collector(SshCollector::new())
+//!     .with_target(DiscoveryTarget::ssh("web-01.example.com"));
+//! tokio::spawn(async move { scheduler.run(Duration::from_secs(6 * 3600)).await });
+//! ```
+
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::discovery::collector::{DiscoveryTarget, FactCollector};
+use crate::discovery::facts::HostFacts;
+use crate::discovery::plan::{DiscoveryPlan, Inventory};
+use crate::discovery::DiscoveryError;
+use crate::errors::InfrastructureResult;
+use crate::nats::NatsClient;
+use crate::projection::read_model::ReadModelHandle;
+use crate::reconcile::Observation;
+use crate::service::network::NetworkService;
+use crate::service::ComputeResourceService;
+use crate::subjects::subjects;
+
+/// Observation source name of discovery scans
+pub const DISCOVERY_SOURCE: &str = "discovery";
+
+/// Supplies the inventory scans are planned against
+#[async_trait]
+pub trait InventorySource: Send + Sync {
+    /// Load the current inventory
+    async fn inventory(&self) -> InfrastructureResult<Inventory>;
+}
+
+/// The read model as inventory
+///
+/// The read model must retain [`Full`](crate::projection::read_model::RetainedDetail::Full)
+/// detail: with key fields only, hardware details and metadata look unset
+/// and every scan would record them again.
+#[async_trait]
+impl InventorySource for ReadModelHandle {
+    async fn inventory(&self) -> InfrastructureResult<Inventory> {
+        let snapshot = self.snapshot();
+        Ok(Inventory::new(
+            snapshot.resources().cloned().collect(),
+            snapshot.interfaces().cloned().collect(),
+            snapshot.networks().cloned().collect(),
+        )
+        .with_connections(snapshot.connections().cloned().collect()))
+    }
+}
+
+/// Result of one scan
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScanOutcome {
+    /// Targets scanned
+    pub scanned: usize,
+
+    /// Resources registered
+    pub registered: usize,
+
+    /// Commands applied across all hosts
+    pub applied: usize,
+
+    /// Hosts that could not be scanned or updated, with the reason
+    pub failed: Vec<(String, String)>,
+}
+
+/// Scans targets and records what changed
+pub struct DiscoveryScheduler<S> {
+    source: S,
+    compute: Arc<dyn ComputeResourceService>,
+    network: Arc<dyn NetworkService>,
+    collectors: Vec<Arc<dyn FactCollector>>,
+    targets: Vec<DiscoveryTarget>,
+    observations: Option<NatsClient>,
+}
+
+impl<S: InventorySource> DiscoveryScheduler<S> {
+    /// Scheduler without collectors or targets
+    pub fn new(
+        source: S,
+        compute: Arc<dyn ComputeResourceService>,
+        network: Arc<dyn NetworkService>,
+    ) -> Self {
+        Self {
+            source,
+            compute,
+            network,
+            collectors: Vec::new(),
+            targets: Vec::new(),
+            observations: None,
+        }
+    }
+
+    /// Add a fact collector
+    pub fn with_collector(mut self, collector: impl FactCollector + 'static) -> Self {
+        self.collectors.push(Arc::new(collector));
+        self
+    }
+
+    /// Add a target
+    pub fn with_target(mut self, target: DiscoveryTarget) -> Self {
+        self.targets.push(target);
+        self
+    }
+
+    /// Publish each scan as an observation for the drift monitor
+    pub fn with_observations(mut self, client: NatsClient) -> Self {
+        self.observations = Some(client);
+        self
+    }
+
+    /// Scan every target once
+    ///
+    /// Fails only if the inventory cannot be loaded.
+    pub async fn scan_once(&self) -> InfrastructureResult<ScanOutcome> {
+        let mut outcome = ScanOutcome::default();
+        let mut observation = Observation::new(DISCOVERY_SOURCE, Utc::now());
+
+        for target in &self.targets {
+            outcome.scanned += 1;
+            let facts = match self.collect(target).await {
+                Ok(facts) => facts,
+                Err(e) => {
+                    warn!("Discovery of {} failed: {}", target.address, e);
+                    outcome.failed.push((target.address.clone(), e.to_string()));
+                    continue;
+                }
+            };
+            observation = observation.with_host(facts.to_observed_host());
+
+            // Reload per host so a host registered earlier in this scan is
+            // matched rather than registered twice.
+            let inventory = self.source.inventory().await?;
+            let plan = DiscoveryPlan::from_facts(
+                target.resource_id,
+                &facts,
+                &inventory,
+                Uuid::now_v7(),
+                Utc::now(),
+            );
+            if !plan.unplaced.is_empty() {
+                warn!(
+                    "{}: interfaces {:?} have no address in a known network",
+                    plan.hostname, plan.unplaced
+                );
+            }
+            if plan.is_empty() {
+                continue;
+            }
+
+            match plan
+                .apply(self.compute.as_ref(), self.network.as_ref())
+                .await
+            {
+                Ok(report) => {
+                    info!(
+                        "{}: recorded {} change(s) from discovery",
+                        plan.hostname, report.applied
+                    );
+                    outcome.registered += usize::from(report.registered);
+                    outcome.applied += report.applied;
+                }
+                Err(e) => {
+                    warn!("Discovery changes for {} rejected: {}", plan.hostname, e);
+                    outcome.failed.push((target.address.clone(), e.to_string()));
+                }
+            }
+        }
+
+        if let Some(client) = &self.observations {
+            if let Err(e) = client
+                .publish(&subjects::observation(DISCOVERY_SOURCE), &observation)
+                .await
+            {
+                warn!("Failed to publish discovery observation: {}", e);
+            }
+        }
+
+        Ok(outcome)
+    }
+
+    /// Scan on a fixed interval until the inventory cannot be loaded
+    pub async fn run(&self, interval: Duration) -> InfrastructureResult<()> {
+        info!(
+            "Starting discovery of {} target(s) (interval: {:?})",
+            self.targets.len(),
+            interval
+        );
+        let mut ticker = tokio::time::interval(interval);
+
+        loop {
+            ticker.tick().await;
+            let outcome = self.scan_once().await?;
+            info!(
+                "Discovery scanned {} target(s): {} registered, {} change(s), {} failed",
+                outcome.scanned,
+                outcome.registered,
+                outcome.applied,
+                outcome.failed.len()
+            );
+        }
+    }
+
+    async fn collect(&self, target: &DiscoveryTarget) -> Result<HostFacts, DiscoveryError> {
+        let collector = self
+            .collectors
+            .iter()
+            .find(|collector| collector.supports(target))
+            .ok_or_else(|| {
+                DiscoveryError::Unreachable(format!(
+                    "{}: no collector for this method",
+                    target.address
+                ))
+            })?;
+        collector.collect(target).await
+    }
+}
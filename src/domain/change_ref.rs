@@ -0,0 +1,133 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Change Request Reference Value Object
+//!
+//! Identifies the external change request (ITIL change ticket, issue URL)
+//! that authorized a command. The reference is opaque to this crate; it is
+//! only validated to be something a human can paste into a ticket system.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Change reference validation error
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum ChangeRefError {
+    #[error("Change reference is empty")]
+    Empty,
+
+    #[error("Change reference exceeds maximum length of 512 characters: {0}")]
+    TooLong(usize),
+
+    #[error("Change reference contains whitespace or control characters")]
+    InvalidCharacter,
+}
+
+/// Namespace of the index keys derived from change references
+const CHANGE_REF_NAMESPACE: Uuid = Uuid::from_u128(0x6f1e_2c8a_4b7d_5e93_a1c0_d24f_8e6b_3a15);
+
+/// Reference to the change request that authorized a command
+///
+/// A ticket ID (`CHG0012345`) or URL
+/// (`https://jira.example.com/browse/OPS-1234`), without whitespace.
+///
+/// # Examples
+///
+/// ```rust
+/// use cim_infrastructure::domain::ChangeRef;
+///
+/// let change = ChangeRef::new("CHG0012345").unwrap();
+/// assert_eq!(change.as_str(), "CHG0012345");
+///
+/// assert!(ChangeRef::new("").is_err());
+/// assert!(ChangeRef::new("CHG 12345").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+pub struct ChangeRef(String);
+
+impl ChangeRef {
+    /// Maximum length of a reference
+    pub const MAX_LENGTH: usize = 512;
+
+    /// Create a change reference with validation
+    ///
+    /// Surrounding whitespace is trimmed.
+    pub fn new(change_ref: impl Into<String>) -> Result<Self, ChangeRefError> {
+        let change_ref = change_ref.into().trim().to_string();
+
+        if change_ref.is_empty() {
+            return Err(ChangeRefError::Empty);
+        }
+
+        if change_ref.len() > Self::MAX_LENGTH {
+            return Err(ChangeRefError::TooLong(change_ref.len()));
+        }
+
+        if change_ref
+            .chars()
+            .any(|c| c.is_whitespace() || c.is_control())
+        {
+            return Err(ChangeRefError::InvalidCharacter);
+        }
+
+        Ok(Self(change_ref))
+    }
+
+    /// Get the reference as a string slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Stable, subject-safe key of the reference
+    ///
+    /// References may contain `.`, `/` or `*`, so indexes are keyed by a
+    /// UUID v5 of the reference instead of the reference itself.
+    pub fn index_key(&self) -> Uuid {
+        Uuid::new_v5(&CHANGE_REF_NAMESPACE, self.0.as_bytes())
+    }
+}
+
+impl fmt::Display for ChangeRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::str::FromStr for ChangeRef {
+    type Err = ChangeRefError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_change_ref_validation_and_index_key() {
+        let ticket = ChangeRef::new(" CHG0012345 ").unwrap();
+        let url = ChangeRef::new("https://jira.example.com/browse/OPS-1234").unwrap();
+
+        assert_eq!(ticket.as_str(), "CHG0012345");
+        assert_eq!(
+            ticket.index_key(),
+            ChangeRef::new("CHG0012345").unwrap().index_key()
+        );
+        assert_ne!(ticket.index_key(), url.index_key());
+
+        assert_eq!(ChangeRef::new("  "), Err(ChangeRefError::Empty));
+        assert_eq!(
+            ChangeRef::new("CHG 1"),
+            Err(ChangeRefError::InvalidCharacter)
+        );
+        assert!(matches!(
+            ChangeRef::new("x".repeat(513)),
+            Err(ChangeRefError::TooLong(513))
+        ));
+    }
+}
@@ -52,6 +52,8 @@ pub enum HostnameError {
 /// assert!(Hostname::new("invalid-.com").is_err());  // Ends with hyphen
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(transparent)]
 pub struct Hostname(String);
 
@@ -8,6 +8,7 @@
 //! # Value Objects with Invariants
 //!
 //! - [`Hostname`] - DNS-validated hostnames (RFC 1123)
+//! - [`ChangeRef`] - External change request reference (ticket ID or URL)
 //! - [`IpAddressWithCidr`] - IPv4/IPv6 with CIDR notation
 //! - [`MacAddress`] - 48-bit MAC address validation
 //! - [`VlanId`] - IEEE 802.1Q VLAN ID (1-4094)
@@ -17,6 +18,7 @@
 //! # Entities with Domain Composition
 //!
 //! - [`ComputeResource`] - Compute infrastructure with Organization/Person/Location references
+//!   (requires the `clock` feature)
 //!
 //! # Domain Relationships
 //!
@@ -26,6 +28,8 @@
 //! - `location_id` → cim-domain-location
 //! - NixOS topology integration via cim-domain-nix
 
+pub mod change_ref;
+#[cfg(feature = "clock")]
 pub mod compute_resource;
 pub mod hostname;
 pub mod invariants;
@@ -33,10 +37,12 @@ pub mod network;
 pub mod resource_type;
 
 // Re-export value objects
+pub use change_ref::{ChangeRef, ChangeRefError};
+#[cfg(feature = "clock")]
 pub use compute_resource::{ComputeResource, ComputeResourceBuilder, ComputeResourceError};
 pub use hostname::{Hostname, HostnameError};
 pub use invariants::{ValidationError, ValidationResult};
 pub use network::{
-    IpAddressWithCidr, MacAddress, Mtu, NetworkError, VlanId,
+    BondMode, IpAddressWithCidr, MacAddress, Mtu, NetworkError, NetworkValidationError, VlanId,
 };
 pub use resource_type::{ResourceCategory, ResourceType};
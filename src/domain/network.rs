@@ -6,6 +6,7 @@ use std::fmt;
 use std::net::IpAddr;
 use std::str::FromStr;
 use thiserror::Error;
+use uuid::Uuid;
 
 /// Network validation error
 #[derive(Debug, Error, Clone, PartialEq, Eq)]
@@ -29,6 +30,29 @@ pub enum NetworkError {
     InvalidMtu(u32),
 }
 
+/// Conflict between a network and the networks already defined
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum NetworkValidationError {
+    /// Prefix shares addresses with another network's prefix
+    #[error("{cidr} overlaps {existing_cidr} of network {existing}")]
+    OverlappingCidr {
+        cidr: IpAddressWithCidr,
+        existing: Uuid,
+        existing_cidr: IpAddressWithCidr,
+    },
+
+    /// VLAN already carries another network on the same layer-2 segment
+    #[error(
+        "VLAN {vlan_id} on segment {} is already used by network {existing}",
+        segment.as_deref().unwrap_or("default")
+    )]
+    VlanInUse {
+        vlan_id: VlanId,
+        segment: Option<String>,
+        existing: Uuid,
+    },
+}
+
 /// IP Address with CIDR notation value object
 ///
 /// Represents an IPv4 or IPv6 address with optional prefix length.
@@ -47,6 +71,8 @@ pub enum NetworkError {
 /// assert_eq!(ip.prefix_length(), Some(24));
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IpAddressWithCidr {
     address: IpAddr,
     prefix_length: Option<u8>,
@@ -143,6 +169,87 @@ impl IpAddressWithCidr {
             self.address.to_string()
         }
     }
+
+    /// Address width in bits (32 for IPv4, 128 for IPv6)
+    pub fn max_prefix_length(&self) -> u8 {
+        match self.address {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        }
+    }
+
+    /// Prefix length, treating a bare address as a host route (/32 or /128)
+    pub fn effective_prefix_length(&self) -> u8 {
+        self.prefix_length.unwrap_or_else(|| self.max_prefix_length())
+    }
+
+    /// Network this address belongs to (host bits cleared)
+    pub fn network(&self) -> Self {
+        let prefix = self.effective_prefix_length();
+        let bits = ip_to_bits(self.address) & prefix_mask(prefix, self.max_prefix_length());
+
+        Self {
+            address: bits_to_ip(bits, self.is_ipv4()),
+            prefix_length: Some(prefix),
+        }
+    }
+
+    /// Number of addresses covered by the prefix
+    ///
+    /// Saturates at `u128::MAX` for a /0 IPv6 prefix.
+    pub fn address_count(&self) -> u128 {
+        let host_bits = (self.max_prefix_length() - self.effective_prefix_length()) as u32;
+        1u128.checked_shl(host_bits).unwrap_or(u128::MAX)
+    }
+
+    /// Check whether an address falls inside this prefix
+    pub fn contains(&self, address: &IpAddr) -> bool {
+        if address.is_ipv4() != self.is_ipv4() {
+            return false;
+        }
+
+        let mask = prefix_mask(self.effective_prefix_length(), self.max_prefix_length());
+        ip_to_bits(*address) & mask == ip_to_bits(self.address) & mask
+    }
+
+    /// Check whether two prefixes share any address
+    pub fn overlaps(&self, other: &Self) -> bool {
+        if self.is_ipv4() != other.is_ipv4() {
+            return false;
+        }
+
+        // Two prefixes overlap iff one contains the other's network address
+        let shorter = self.effective_prefix_length().min(other.effective_prefix_length());
+        let mask = prefix_mask(shorter, self.max_prefix_length());
+        ip_to_bits(self.address) & mask == ip_to_bits(other.address) & mask
+    }
+}
+
+/// Numeric value of an address (IPv4 occupies the low 32 bits)
+pub(crate) fn ip_to_bits(address: IpAddr) -> u128 {
+    match address {
+        IpAddr::V4(v4) => u32::from(v4) as u128,
+        IpAddr::V6(v6) => u128::from(v6),
+    }
+}
+
+/// Address from its numeric value
+pub(crate) fn bits_to_ip(bits: u128, ipv4: bool) -> IpAddr {
+    if ipv4 {
+        IpAddr::V4(std::net::Ipv4Addr::from(bits as u32))
+    } else {
+        IpAddr::V6(std::net::Ipv6Addr::from(bits))
+    }
+}
+
+/// Network mask for a prefix length within an address width
+fn prefix_mask(prefix: u8, width: u8) -> u128 {
+    let all_ones: u128 = if width == 32 { u32::MAX as u128 } else { u128::MAX };
+    let host_bits = (width - prefix) as u32;
+
+    all_ones
+        .checked_shl(host_bits)
+        .map_or(0, |mask| mask & all_ones)
 }
 
 impl fmt::Display for IpAddressWithCidr {
@@ -175,6 +282,8 @@ impl FromStr for IpAddressWithCidr {
 /// assert_eq!(mac.as_str(), "00:11:22:33:44:55");
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(transparent)]
 pub struct MacAddress([u8; 6]);
 
@@ -259,6 +368,8 @@ impl FromStr for MacAddress {
 /// - Valid VLAN ID range (1-4094)
 /// - VLAN 0 and 4095 are reserved
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(transparent)]
 pub struct VlanId(u16);
 
@@ -309,6 +420,8 @@ impl TryFrom<u16> for VlanId {
 /// - 68 = minimum IPv4 MTU
 /// - 9000 = jumbo frames
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(transparent)]
 pub struct Mtu(u32);
 
@@ -373,6 +486,43 @@ impl TryFrom<u32> for Mtu {
     }
 }
 
+/// Link aggregation mode of a bonded interface
+///
+/// Serialized and displayed with the Linux bonding driver names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum BondMode {
+    /// IEEE 802.3ad dynamic link aggregation (LACP)
+    #[serde(rename = "802.3ad")]
+    Lacp,
+
+    /// One active member, the others on standby
+    #[serde(rename = "active-backup")]
+    ActiveBackup,
+
+    /// Static aggregation balanced by transmit hash
+    #[serde(rename = "balance-xor")]
+    BalanceXor,
+}
+
+impl BondMode {
+    /// Bonding driver name of the mode
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BondMode::Lacp => "802.3ad",
+            BondMode::ActiveBackup => "active-backup",
+            BondMode::BalanceXor => "balance-xor",
+        }
+    }
+}
+
+impl fmt::Display for BondMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -400,6 +550,30 @@ mod tests {
         assert_eq!(ip.prefix_length(), Some(64));
     }
 
+    #[test]
+    fn test_network_and_containment() {
+        let ip = IpAddressWithCidr::new("192.168.1.10/24").unwrap();
+        assert_eq!(ip.network().as_cidr(), "192.168.1.0/24");
+        assert_eq!(ip.address_count(), 256);
+        assert!(ip.contains(&"192.168.1.254".parse().unwrap()));
+        assert!(!ip.contains(&"192.168.2.1".parse().unwrap()));
+        assert!(!ip.contains(&"2001:db8::1".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_prefix_overlap() {
+        let wide = IpAddressWithCidr::new("10.0.0.0/16").unwrap();
+        let narrow = IpAddressWithCidr::new("10.0.5.0/24").unwrap();
+        let other = IpAddressWithCidr::new("10.1.0.0/16").unwrap();
+        let v6 = IpAddressWithCidr::new("2001:db8::/32").unwrap();
+
+        assert!(wide.overlaps(&narrow));
+        assert!(narrow.overlaps(&wide));
+        assert!(!wide.overlaps(&other));
+        assert!(!wide.overlaps(&v6));
+        assert!(v6.overlaps(&IpAddressWithCidr::new("2001:db8:1::/48").unwrap()));
+    }
+
     #[test]
     fn test_invalid_ip() {
         assert!(IpAddressWithCidr::new("999.999.999.999").is_err());
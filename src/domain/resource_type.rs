@@ -13,6 +13,8 @@ use std::fmt;
 /// This enum defines the complete set of infrastructure resource types
 /// that CIM can model and project into various systems (NetBox, monitoring, etc.)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum ResourceType {
     // Compute Resources
@@ -307,6 +309,14 @@ impl ResourceType {
                 | Self::Hypervisor
         )
     }
+
+    /// Check if this can run as a guest on another resource
+    ///
+    /// Only virtual machines and container hosts run on a host; everything
+    /// else is physical hardware.
+    pub fn can_be_guest(&self) -> bool {
+        matches!(self, Self::VirtualMachine | Self::ContainerHost)
+    }
 }
 
 impl Default for ResourceType {
@@ -335,6 +345,8 @@ impl From<String> for ResourceType {
 
 /// Resource category (high-level grouping)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum ResourceCategory {
     /// Compute resources (servers, VMs, etc.)
     Compute,
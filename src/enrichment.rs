@@ -0,0 +1,404 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Display Name Enrichment
+//!
+//! Resources reference their organization and owner by ID only; the names
+//! live in the organization and person domains. Instead of every UI looking
+//! each ID up there (one request per resource and reference), this module
+//! keeps a cache of display names and joins them onto read-model results:
+//!
+//! ```text
+//! organization.> ──┐
+//!                  ├──DirectoryEvent──> DisplayNameCache ──┐
+//! person.>       ──┘        (miss) ──> DisplayNameLookup   │
+//!                                                          ▼
+//! ReadModelHandle ──ComputeResourceState──> EnrichedResource ──> infrastructure.query.compute.enriched
+//! ```
+//!
+//! Joining is pure ([`EnrichedResource::join`] over [`DisplayNames`]). With
+//! the runtime, [`DisplayNameCache`] is fed by the other domains' events and
+//! can fall back to a [`DisplayNameLookup`] (e.g. a request to the owning
+//! service) for IDs it has not seen; a name that cannot be resolved is left
+//! out rather than failing the query.
+//!
+//! # Directory Events
+//!
+//! Only the ID and name of an event are read, so creations and renames of
+//! any version are understood: a payload with `person_id` names a person,
+//! otherwise one with `organization_id` names an organization. The name is
+//! taken from `display_name`, falling back to `name`.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::enrichment::{DisplayNameCache, EnrichmentConfig};
+//!
+//! let names = DisplayNameCache::new();
+//! let read_model = read_model.with_display_names(names.clone());
+//!
+//! tokio::spawn({
+//!     let client = nats_client.clone();
+//!     async move { names.run(&client, &EnrichmentConfig::default()).await }
+//! });
+//! QueryResponder::new(nats_client, Arc::new(read_model)).run().await?;
+//! ```
+
+#[cfg(feature = "runtime")]
+use async_trait::async_trait;
+#[cfg(feature = "runtime")]
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+#[cfg(feature = "runtime")]
+use std::sync::{Arc, RwLock};
+#[cfg(feature = "runtime")]
+use tracing::{debug, info, warn};
+
+use crate::aggregate::ComputeResourceState;
+#[cfg(feature = "runtime")]
+use crate::errors::InfrastructureResult;
+#[cfg(feature = "runtime")]
+use crate::nats::NatsClient;
+
+/// Name announced by the organization or person domain
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum DirectoryEvent {
+    /// An organization was created or renamed
+    Organization {
+        /// Organization ID
+        id: String,
+        /// Display name
+        name: String,
+    },
+
+    /// A person was created or renamed
+    Person {
+        /// Person ID
+        id: String,
+        /// Display name
+        name: String,
+    },
+}
+
+impl DirectoryEvent {
+    /// Read the ID and name from an event payload
+    ///
+    /// Returns `None` for payloads that name nobody (e.g. membership
+    /// changes), which callers skip.
+    pub fn from_payload(payload: &[u8]) -> Option<Self> {
+        let value: Value = serde_json::from_slice(payload).ok()?;
+        let name = value
+            .get("display_name")
+            .or_else(|| value.get("name"))
+            .and_then(Value::as_str)?
+            .to_string();
+        let id = |field: &str| value.get(field).and_then(Value::as_str).map(str::to_string);
+
+        // People carry their organization's ID too, so check them first
+        if let Some(id) = id("person_id") {
+            Some(DirectoryEvent::Person { id, name })
+        } else {
+            id("organization_id").map(|id| DirectoryEvent::Organization { id, name })
+        }
+    }
+}
+
+/// Known display names of organizations and people
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DisplayNames {
+    organizations: HashMap<String, String>,
+    people: HashMap<String, String>,
+}
+
+impl DisplayNames {
+    /// Empty set of names
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a name, replacing any earlier one
+    pub fn apply(&mut self, event: &DirectoryEvent) {
+        match event {
+            DirectoryEvent::Organization { id, name } => {
+                self.organizations.insert(id.clone(), name.clone());
+            }
+            DirectoryEvent::Person { id, name } => {
+                self.people.insert(id.clone(), name.clone());
+            }
+        }
+    }
+
+    /// Display name of an organization
+    pub fn organization(&self, organization_id: &str) -> Option<&str> {
+        self.organizations.get(organization_id).map(String::as_str)
+    }
+
+    /// Display name of a person
+    pub fn person(&self, person_id: &str) -> Option<&str> {
+        self.people.get(person_id).map(String::as_str)
+    }
+
+    /// Number of names known
+    pub fn len(&self) -> usize {
+        self.organizations.len() + self.people.len()
+    }
+
+    /// Whether no names are known
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Resource joined with the names of its organization and owner
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct EnrichedResource {
+    /// The resource as held by the read model
+    pub resource: ComputeResourceState,
+
+    /// Display name of the organization, when assigned and known
+    pub organization_name: Option<String>,
+
+    /// Display name of the owner, when assigned and known
+    pub owner_name: Option<String>,
+}
+
+impl EnrichedResource {
+    /// Join `resource` with the names in `names`
+    pub fn join(resource: ComputeResourceState, names: &DisplayNames) -> Self {
+        let organization_name = resource
+            .organization_id
+            .as_ref()
+            .and_then(|id| names.organization(&id.to_string()))
+            .map(str::to_string);
+        let owner_name = resource
+            .owner_id
+            .as_ref()
+            .and_then(|id| names.person(&id.to_string()))
+            .map(str::to_string);
+
+        Self {
+            resource,
+            organization_name,
+            owner_name,
+        }
+    }
+}
+
+/// Subjects carrying organization and person events
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnrichmentConfig {
+    /// Subjects to subscribe to; payloads are read with
+    /// [`DirectoryEvent::from_payload`]
+    #[serde(default = "default_subjects")]
+    pub subjects: Vec<String>,
+}
+
+fn default_subjects() -> Vec<String> {
+    vec!["organization.>".to_string(), "person.>".to_string()]
+}
+
+impl Default for EnrichmentConfig {
+    fn default() -> Self {
+        Self {
+            subjects: default_subjects(),
+        }
+    }
+}
+
+/// Resolves names the cache has not seen, e.g. by asking the owning service
+#[cfg(feature = "runtime")]
+#[async_trait]
+pub trait DisplayNameLookup: Send + Sync {
+    /// Display name of an organization, if it exists
+    async fn organization_name(
+        &self,
+        organization_id: &str,
+    ) -> InfrastructureResult<Option<String>>;
+
+    /// Display name of a person, if they exist
+    async fn person_name(&self, person_id: &str) -> InfrastructureResult<Option<String>>;
+}
+
+/// Display names shared between the subscription task and query handlers
+///
+/// Cloning is cheap; all clones see the same names.
+#[cfg(feature = "runtime")]
+#[derive(Clone, Default)]
+pub struct DisplayNameCache {
+    names: Arc<RwLock<DisplayNames>>,
+    lookup: Option<Arc<dyn DisplayNameLookup>>,
+}
+
+#[cfg(feature = "runtime")]
+impl DisplayNameCache {
+    /// Empty cache without a fallback lookup
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolve names missing from the cache with `lookup`
+    pub fn with_lookup(mut self, lookup: Arc<dyn DisplayNameLookup>) -> Self {
+        self.lookup = Some(lookup);
+        self
+    }
+
+    /// Record a name
+    pub fn apply(&self, event: &DirectoryEvent) {
+        self.names
+            .write()
+            .expect("display name lock poisoned")
+            .apply(event);
+    }
+
+    /// Copy of the names currently known
+    pub fn names(&self) -> DisplayNames {
+        self.names
+            .read()
+            .expect("display name lock poisoned")
+            .clone()
+    }
+
+    /// Join `resource` with its organization and owner names
+    ///
+    /// Names missing from the cache are resolved with the lookup, if any,
+    /// and cached. Lookup failures are logged and the name left out.
+    pub async fn enrich(&self, resource: ComputeResourceState) -> EnrichedResource {
+        if let Some(lookup) = &self.lookup {
+            self.resolve_missing(lookup.as_ref(), &resource).await;
+        }
+        let names = self.names.read().expect("display name lock poisoned");
+        EnrichedResource::join(resource, &names)
+    }
+
+    async fn resolve_missing(
+        &self,
+        lookup: &dyn DisplayNameLookup,
+        resource: &ComputeResourceState,
+    ) {
+        let known = self.names();
+
+        if let Some(id) = resource.organization_id.as_ref().map(ToString::to_string) {
+            if known.organization(&id).is_none() {
+                match lookup.organization_name(&id).await {
+                    Ok(Some(name)) => self.apply(&DirectoryEvent::Organization { id, name }),
+                    Ok(None) => debug!("Organization {} has no display name", id),
+                    Err(e) => warn!("Looking up organization {} failed: {}", id, e),
+                }
+            }
+        }
+
+        if let Some(id) = resource.owner_id.as_ref().map(ToString::to_string) {
+            if known.person(&id).is_none() {
+                match lookup.person_name(&id).await {
+                    Ok(Some(name)) => self.apply(&DirectoryEvent::Person { id, name }),
+                    Ok(None) => debug!("Person {} has no display name", id),
+                    Err(e) => warn!("Looking up person {} failed: {}", id, e),
+                }
+            }
+        }
+    }
+
+    /// Record names from the events on the configured subjects
+    ///
+    /// Payloads that name nobody are skipped. Returns when every
+    /// subscription has closed.
+    pub async fn run(
+        &self,
+        client: &NatsClient,
+        config: &EnrichmentConfig,
+    ) -> InfrastructureResult<()> {
+        let mut subscribers = Vec::with_capacity(config.subjects.len());
+        for subject in &config.subjects {
+            subscribers.push(client.subscribe(subject).await?);
+        }
+        info!("Caching display names from {}", config.subjects.join(", "));
+
+        let mut messages = futures::stream::select_all(subscribers);
+        while let Some(message) = messages.next().await {
+            match DirectoryEvent::from_payload(&message.payload) {
+                Some(event) => self.apply(&event),
+                None => debug!("No display name in event on {}", message.subject),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Hostname, ResourceType};
+    use cim_domain::EntityId;
+    use cim_domain_person::PersonId;
+    use serde_json::json;
+    use uuid::Uuid;
+
+    fn payload(value: Value) -> Vec<u8> {
+        serde_json::to_vec(&value).unwrap()
+    }
+
+    #[test]
+    fn test_reads_names_from_directory_events() {
+        // Arrange
+        let organization_id = Uuid::now_v7().to_string();
+        let person_id = Uuid::now_v7().to_string();
+        let organization_created = payload(json!({
+            "organization_id": organization_id,
+            "name": "CowboyAI",
+            "domain": "cowboyai.com",
+        }));
+        let person_renamed = payload(json!({
+            "person_id": person_id,
+            "organization_id": organization_id,
+            "name": "Alice Smith",
+            "display_name": "Alice",
+        }));
+        let member_added = payload(json!({
+            "organization_id": organization_id,
+            "person_id": person_id,
+        }));
+
+        // Act
+        let mut names = DisplayNames::new();
+        for event in [&organization_created, &person_renamed, &member_added]
+            .into_iter()
+            .filter_map(|p| DirectoryEvent::from_payload(p))
+        {
+            names.apply(&event);
+        }
+
+        // Assert
+        assert_eq!(names.len(), 2);
+        assert_eq!(names.organization(&organization_id), Some("CowboyAI"));
+        assert_eq!(names.person(&person_id), Some("Alice"));
+    }
+
+    #[test]
+    fn test_join_leaves_unknown_names_out() {
+        // Arrange
+        let mut resource = ComputeResourceState::default_for(Uuid::now_v7());
+        resource.hostname = Hostname::new("web-01").unwrap();
+        resource.resource_type = ResourceType::PhysicalServer;
+        let organization = EntityId::new();
+        resource.organization_id = Some(organization.clone());
+        resource.owner_id = Some(PersonId::new());
+
+        let mut names = DisplayNames::new();
+        names.apply(&DirectoryEvent::Organization {
+            id: organization.to_string(),
+            name: "CowboyAI".to_string(),
+        });
+
+        // Act
+        let enriched = EnrichedResource::join(resource.clone(), &names);
+
+        // Assert
+        assert_eq!(enriched.resource, resource);
+        assert_eq!(enriched.organization_name.as_deref(), Some("CowboyAI"));
+        assert_eq!(enriched.owner_name, None);
+    }
+}
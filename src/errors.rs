@@ -33,6 +33,10 @@ pub enum InfrastructureError {
     #[error("Operation timed out: {0}")]
     Timeout(String),
 
+    /// Operation was cancelled before it finished
+    #[error("Operation cancelled: {0}")]
+    Cancelled(String),
+
     /// Concurrency/version mismatch error (optimistic locking)
     #[error("Concurrency error: {0}")]
     ConcurrencyError(String),
@@ -45,6 +49,7 @@ pub enum InfrastructureError {
 /// Result type for infrastructure operations
 pub type InfrastructureResult<T> = Result<T, InfrastructureError>;
 
+#[cfg(feature = "runtime")]
 impl From<async_nats::Error> for InfrastructureError {
     fn from(err: async_nats::Error) -> Self {
         InfrastructureError::NatsConnection(err.to_string())
@@ -0,0 +1,646 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Stream Backup and Restore
+//!
+//! [`EventStoreBackup`] copies a whole JetStream stream to a portable file
+//! and back, for cluster migrations and periodic offline backups. The file
+//! is JSON Lines, one tagged object per line:
+//!
+//! ```text
+//! {"kind":"header","format":"cim-infrastructure-stream-backup","version":1,…}
+//! {"kind":"record","sequence":1,"subject":"…","time":"…","headers":{…},"payload":"<base64>"}
+//! {"kind":"record","sequence":2,…}
+//! …
+//! {"kind":"trailer","messages":2,"sha256":"<hex of every record line>"}
+//! ```
+//!
+//! What a restore preserves:
+//!
+//! | Property        | How                                                        |
+//! |-----------------|------------------------------------------------------------|
+//! | Sequence        | first sequence set by a purge, gaps filled then deleted    |
+//! | Subject/payload | republished unchanged                                      |
+//! | Headers         | republished, except `Nats-Expected-*` publish guards       |
+//! | Timestamp       | servers stamp their own time; the original is kept in the  |
+//! |                 | [`ORIGINAL_TIME_HEADER`] header                            |
+//!
+//! Imports refuse a file whose trailer is missing or does not match, and a
+//! target stream that already holds messages. Create the target stream
+//! (for example with [`create_infrastructure_stream`]) before importing,
+//! and start subscribers only after the import finished.
+//!
+//! [`create_infrastructure_stream`]: crate::jetstream::create_infrastructure_stream
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::event_store::backup::EventStoreBackup;
+//!
+//! let report = EventStoreBackup::new(old_cluster, "INFRASTRUCTURE_EVENTS")
+//!     .export("infrastructure.backup.jsonl")
+//!     .await?;
+//!
+//! create_infrastructure_stream(new_cluster.clone(), JetStreamConfig::default()).await?;
+//! EventStoreBackup::new(new_cluster, "INFRASTRUCTURE_EVENTS")
+//!     .import("infrastructure.backup.jsonl")
+//!     .await?;
+//! ```
+
+use async_nats::jetstream::{self, consumer, context::Publish};
+use async_nats::HeaderMap;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use chrono::{DateTime, TimeZone, Utc};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::path::Path;
+use tokio::fs::File;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, BufWriter};
+use tracing::info;
+
+use crate::audit::hex;
+use crate::errors::{InfrastructureError, InfrastructureResult};
+
+/// Identifies a stream backup file
+pub const BACKUP_FORMAT: &str = "cim-infrastructure-stream-backup";
+
+/// Version of the backup file layout
+pub const BACKUP_VERSION: u32 = 1;
+
+/// Header carrying the server time of the original message
+pub const ORIGINAL_TIME_HEADER: &str = "Cim-Original-Time";
+
+/// Prefix of publish guard headers, which are not restored
+const EXPECTED_HEADER_PREFIX: &str = "Nats-Expected-";
+
+/// First line of a backup file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackupHeader {
+    /// Always [`BACKUP_FORMAT`]
+    pub format: String,
+
+    /// Layout version, [`BACKUP_VERSION`] when written
+    pub version: u32,
+
+    /// Name of the exported stream
+    pub stream: String,
+
+    /// Subjects of the exported stream
+    pub subjects: Vec<String>,
+
+    /// First stream sequence at export time
+    pub first_sequence: u64,
+
+    /// Last stream sequence at export time
+    pub last_sequence: u64,
+
+    /// When the export started
+    pub exported_at: DateTime<Utc>,
+}
+
+/// One stored message
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackupRecord {
+    /// Stream sequence
+    pub sequence: u64,
+
+    /// Subject it was published on
+    pub subject: String,
+
+    /// Server time it was stored at
+    pub time: DateTime<Utc>,
+
+    /// Message headers, every value of each name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, Vec<String>>,
+
+    /// Payload, base64
+    pub payload: String,
+}
+
+impl BackupRecord {
+    /// Record of a payload and its headers
+    pub fn new(
+        sequence: u64,
+        subject: impl Into<String>,
+        time: DateTime<Utc>,
+        headers: BTreeMap<String, Vec<String>>,
+        payload: &[u8],
+    ) -> Self {
+        Self {
+            sequence,
+            subject: subject.into(),
+            time,
+            headers,
+            payload: STANDARD.encode(payload),
+        }
+    }
+
+    /// Decoded payload
+    pub fn payload_bytes(&self) -> InfrastructureResult<Vec<u8>> {
+        STANDARD.decode(&self.payload).map_err(|e| {
+            InfrastructureError::Deserialization(format!(
+                "Backup record #{} has an invalid payload: {}",
+                self.sequence, e
+            ))
+        })
+    }
+
+    /// Publish request restoring this record
+    ///
+    /// Publish guards are dropped, and the original time is recorded
+    /// unless an earlier restore already did.
+    fn publish(&self) -> InfrastructureResult<Publish> {
+        let mut publish = Publish::build().payload(self.payload_bytes()?.into());
+        for (name, values) in &self.headers {
+            if name.starts_with(EXPECTED_HEADER_PREFIX) {
+                continue;
+            }
+            for value in values {
+                publish = publish.header(name.as_str(), value.as_str());
+            }
+        }
+        if !self.headers.contains_key(ORIGINAL_TIME_HEADER) {
+            publish = publish.header(ORIGINAL_TIME_HEADER, self.time.to_rfc3339().as_str());
+        }
+        Ok(publish)
+    }
+}
+
+/// Last line of a backup file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackupTrailer {
+    /// Number of records
+    pub messages: u64,
+
+    /// SHA-256 of every record line, hex
+    pub sha256: String,
+}
+
+/// A line of a backup file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum BackupLine {
+    /// First line
+    Header(BackupHeader),
+
+    /// One per message, in sequence order
+    Record(BackupRecord),
+
+    /// Last line
+    Trailer(BackupTrailer),
+}
+
+impl BackupLine {
+    fn encode(&self) -> InfrastructureResult<String> {
+        serde_json::to_string(self).map_err(|e| InfrastructureError::Serialization(e.to_string()))
+    }
+
+    fn decode(line: &str, number: usize) -> InfrastructureResult<Self> {
+        serde_json::from_str(line).map_err(|e| {
+            InfrastructureError::Deserialization(format!("Backup line {}: {}", number, e))
+        })
+    }
+}
+
+/// Running checksum over the record lines of a backup
+#[derive(Debug, Clone, Default)]
+pub struct BackupDigest {
+    hasher: Sha256,
+    messages: u64,
+}
+
+impl BackupDigest {
+    /// Add one encoded record line
+    pub fn push(&mut self, line: &str) {
+        self.hasher.update(line.as_bytes());
+        self.hasher.update(b"\n");
+        self.messages += 1;
+    }
+
+    /// Trailer for the lines added so far
+    pub fn trailer(&self) -> BackupTrailer {
+        BackupTrailer {
+            messages: self.messages,
+            sha256: hex(&self.hasher.clone().finalize()),
+        }
+    }
+
+    /// Check the lines added so far against a trailer
+    pub fn verify(&self, trailer: &BackupTrailer) -> InfrastructureResult<()> {
+        if self.trailer() == *trailer {
+            Ok(())
+        } else {
+            Err(InfrastructureError::Deserialization(format!(
+                "Backup checksum mismatch: trailer lists {} messages, file holds {}",
+                trailer.messages, self.messages
+            )))
+        }
+    }
+}
+
+/// What an export or import moved
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BackupReport {
+    /// Stream the messages came from
+    pub stream: String,
+
+    /// Messages written or restored
+    pub messages: u64,
+
+    /// First sequence moved (`0` when empty)
+    pub first_sequence: u64,
+
+    /// Last sequence moved (`0` when empty)
+    pub last_sequence: u64,
+
+    /// Sequence gaps recreated on import
+    pub gaps: u64,
+}
+
+/// Exports and imports one JetStream stream
+#[derive(Clone)]
+pub struct EventStoreBackup {
+    jetstream: jetstream::Context,
+    stream_name: String,
+}
+
+impl EventStoreBackup {
+    /// Backup tool for `stream_name`
+    pub fn new(jetstream: jetstream::Context, stream_name: impl Into<String>) -> Self {
+        Self {
+            jetstream,
+            stream_name: stream_name.into(),
+        }
+    }
+
+    async fn stream(&self) -> InfrastructureResult<jetstream::stream::Stream> {
+        self.jetstream
+            .get_stream(&self.stream_name)
+            .await
+            .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))
+    }
+
+    /// Write every message of the stream to `path`
+    ///
+    /// Messages published after the export starts are not included.
+    pub async fn export(&self, path: impl AsRef<Path>) -> InfrastructureResult<BackupReport> {
+        let mut stream = self.stream().await?;
+        let stream_info = stream
+            .info()
+            .await
+            .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?;
+        let header = BackupHeader {
+            format: BACKUP_FORMAT.to_string(),
+            version: BACKUP_VERSION,
+            stream: self.stream_name.clone(),
+            subjects: stream_info.config.subjects.clone(),
+            first_sequence: stream_info.state.first_sequence,
+            last_sequence: stream_info.state.last_sequence,
+            exported_at: Utc::now(),
+        };
+        let total = stream_info.state.messages;
+
+        let mut writer = BufWriter::new(File::create(path.as_ref()).await.map_err(io_error)?);
+        write_line(&mut writer, &BackupLine::Header(header.clone())).await?;
+
+        let mut digest = BackupDigest::default();
+        if total > 0 {
+            let consumer = stream
+                .create_consumer(consumer::pull::Config {
+                    deliver_policy: consumer::DeliverPolicy::All,
+                    ack_policy: consumer::AckPolicy::None,
+                    ..Default::default()
+                })
+                .await
+                .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?;
+            let mut messages = consumer
+                .messages()
+                .await
+                .map_err(|e| InfrastructureError::NatsSubscribe(e.to_string()))?;
+
+            while let Some(message) = messages.next().await {
+                let message =
+                    message.map_err(|e| InfrastructureError::NatsSubscribe(e.to_string()))?;
+                let message_info = message
+                    .info()
+                    .map_err(|e| InfrastructureError::NatsSubscribe(e.to_string()))?;
+                let sequence = message_info.stream_sequence;
+                if sequence > header.last_sequence {
+                    break;
+                }
+                let published = message_info.published;
+                let time = Utc
+                    .timestamp_opt(published.unix_timestamp(), published.nanosecond())
+                    .single()
+                    .unwrap_or(header.exported_at);
+
+                let record = BackupRecord::new(
+                    sequence,
+                    message.subject.to_string(),
+                    time,
+                    header_values(message.headers.as_ref()),
+                    &message.payload,
+                );
+                let line = BackupLine::Record(record).encode()?;
+                digest.push(&line);
+                writer.write_all(line.as_bytes()).await.map_err(io_error)?;
+                writer.write_all(b"\n").await.map_err(io_error)?;
+
+                if sequence == header.last_sequence {
+                    break;
+                }
+            }
+        }
+
+        let trailer = digest.trailer();
+        write_line(&mut writer, &BackupLine::Trailer(trailer.clone())).await?;
+        writer.flush().await.map_err(io_error)?;
+        info!(
+            "Exported {} messages of stream {}",
+            trailer.messages, self.stream_name
+        );
+
+        Ok(BackupReport {
+            stream: self.stream_name.clone(),
+            messages: trailer.messages,
+            first_sequence: header.first_sequence,
+            last_sequence: header.last_sequence,
+            gaps: 0,
+        })
+    }
+
+    /// Restore a backup from `path` into the (empty) stream
+    pub async fn import(&self, path: impl AsRef<Path>) -> InfrastructureResult<BackupReport> {
+        let path = path.as_ref();
+        let header = verify_file(path).await?;
+
+        let mut stream = self.stream().await?;
+        let state = stream
+            .info()
+            .await
+            .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?
+            .state
+            .clone();
+        if state.messages > 0 || state.last_sequence > 0 {
+            return Err(InfrastructureError::Configuration(format!(
+                "Stream {} is not empty (last sequence {}); import needs a new stream",
+                self.stream_name, state.last_sequence
+            )));
+        }
+
+        let mut lines = BufReader::new(File::open(path).await.map_err(io_error)?).lines();
+        let mut next_sequence = 1;
+        let mut report = BackupReport {
+            stream: header.stream.clone(),
+            messages: 0,
+            first_sequence: 0,
+            last_sequence: 0,
+            gaps: 0,
+        };
+        let mut number = 0;
+        while let Some(line) = lines.next_line().await.map_err(io_error)? {
+            number += 1;
+            let BackupLine::Record(record) = BackupLine::decode(&line, number)? else {
+                continue;
+            };
+
+            if report.messages == 0 && record.sequence > 1 {
+                // An empty stream starts at the purge sequence
+                stream
+                    .purge()
+                    .sequence(record.sequence)
+                    .await
+                    .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?;
+                next_sequence = record.sequence;
+            }
+            while next_sequence < record.sequence {
+                self.fill_gap(&stream, &record.subject, next_sequence)
+                    .await?;
+                next_sequence += 1;
+                report.gaps += 1;
+            }
+
+            let sequence = self.publish(&record.subject, record.publish()?).await?;
+            if sequence != record.sequence {
+                return Err(InfrastructureError::ConcurrencyError(format!(
+                    "Record #{} was stored as #{}; was the stream written during import?",
+                    record.sequence, sequence
+                )));
+            }
+            if report.messages == 0 {
+                report.first_sequence = sequence;
+            }
+            report.last_sequence = sequence;
+            report.messages += 1;
+            next_sequence = sequence + 1;
+        }
+
+        info!(
+            "Imported {} messages of stream {} into {}",
+            report.messages, header.stream, self.stream_name
+        );
+        Ok(report)
+    }
+
+    /// Take up sequence `sequence` with a placeholder, then delete it
+    async fn fill_gap(
+        &self,
+        stream: &jetstream::stream::Stream,
+        subject: &str,
+        sequence: u64,
+    ) -> InfrastructureResult<()> {
+        let filler = self
+            .publish(
+                subject,
+                Publish::build().header("Cim-Backup-Filler", "true"),
+            )
+            .await?;
+        if filler != sequence {
+            return Err(InfrastructureError::ConcurrencyError(format!(
+                "Gap #{} was stored as #{}",
+                sequence, filler
+            )));
+        }
+        stream
+            .delete_message(filler)
+            .await
+            .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?;
+        Ok(())
+    }
+
+    /// Publish and wait for the stored sequence
+    async fn publish(&self, subject: &str, publish: Publish) -> InfrastructureResult<u64> {
+        let ack = self
+            .jetstream
+            .send_publish(subject.to_string(), publish)
+            .await
+            .map_err(|e| InfrastructureError::NatsPublish(e.to_string()))?
+            .await
+            .map_err(|e| InfrastructureError::NatsPublish(e.to_string()))?;
+        Ok(ack.sequence)
+    }
+}
+
+/// Read a whole backup file, checking its header, order and trailer
+async fn verify_file(path: &Path) -> InfrastructureResult<BackupHeader> {
+    let mut lines = BufReader::new(File::open(path).await.map_err(io_error)?).lines();
+    let mut header = None;
+    let mut trailer = None;
+    let mut digest = BackupDigest::default();
+    let mut last_sequence = 0;
+    let mut number = 0;
+
+    while let Some(line) = lines.next_line().await.map_err(io_error)? {
+        number += 1;
+        let malformed = |reason: &str| {
+            InfrastructureError::Deserialization(format!("Backup line {}: {}", number, reason))
+        };
+        match BackupLine::decode(&line, number)? {
+            BackupLine::Header(read) if number == 1 => {
+                if read.format != BACKUP_FORMAT || read.version > BACKUP_VERSION {
+                    return Err(malformed(&format!(
+                        "unsupported format {} v{}",
+                        read.format, read.version
+                    )));
+                }
+                header = Some(read);
+            }
+            BackupLine::Record(record) if header.is_some() && trailer.is_none() => {
+                if record.sequence <= last_sequence {
+                    return Err(malformed("records out of sequence order"));
+                }
+                last_sequence = record.sequence;
+                digest.push(&line);
+            }
+            BackupLine::Trailer(read) if header.is_some() && trailer.is_none() => {
+                trailer = Some(read);
+            }
+            _ => return Err(malformed("unexpected line")),
+        }
+    }
+
+    let header = header
+        .ok_or_else(|| InfrastructureError::Deserialization("Backup file is empty".to_string()))?;
+    let trailer = trailer.ok_or_else(|| {
+        InfrastructureError::Deserialization("Backup file is truncated: no trailer".to_string())
+    })?;
+    digest.verify(&trailer)?;
+    Ok(header)
+}
+
+/// Every header of a message, by name
+fn header_values(headers: Option<&HeaderMap>) -> BTreeMap<String, Vec<String>> {
+    headers
+        .map(|headers| {
+            headers
+                .iter()
+                .map(|(name, values)| {
+                    (
+                        name.to_string(),
+                        values
+                            .iter()
+                            .map(|value| value.as_str().to_string())
+                            .collect(),
+                    )
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+async fn write_line(writer: &mut BufWriter<File>, line: &BackupLine) -> InfrastructureResult<()> {
+    writer
+        .write_all(line.encode()?.as_bytes())
+        .await
+        .map_err(io_error)?;
+    writer.write_all(b"\n").await.map_err(io_error)
+}
+
+fn io_error(e: std::io::Error) -> InfrastructureError {
+    InfrastructureError::Generic(format!("Backup file: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(sequence: u64) -> BackupRecord {
+        BackupRecord::new(
+            sequence,
+            "infrastructure.compute.web-01.registered",
+            Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap(),
+            BTreeMap::from([(
+                "Nats-Msg-Id".to_string(),
+                vec![format!("event-{}", sequence)],
+            )]),
+            b"{\"hostname\":\"web-01\"}",
+        )
+    }
+
+    #[test]
+    fn test_record_lines_round_trip() {
+        // Arrange
+        let original = BackupLine::Record(record(42));
+
+        // Act
+        let line = original.encode().unwrap();
+        let decoded = BackupLine::decode(&line, 2).unwrap();
+
+        // Assert
+        assert!(line.starts_with("{\"kind\":\"record\""));
+        assert_eq!(decoded, original);
+        let BackupLine::Record(decoded) = decoded else {
+            unreachable!()
+        };
+        assert_eq!(
+            decoded.payload_bytes().unwrap(),
+            b"{\"hostname\":\"web-01\"}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_truncated_and_tampered_files_are_rejected() {
+        // Arrange
+        let header = BackupLine::Header(BackupHeader {
+            format: BACKUP_FORMAT.to_string(),
+            version: BACKUP_VERSION,
+            stream: "INFRASTRUCTURE_EVENTS".to_string(),
+            subjects: vec!["infrastructure.>".to_string()],
+            first_sequence: 1,
+            last_sequence: 3,
+            exported_at: Utc.with_ymd_and_hms(2025, 1, 2, 0, 0, 0).unwrap(),
+        });
+        let records: Vec<String> = [1, 3]
+            .into_iter()
+            .map(|sequence| BackupLine::Record(record(sequence)).encode().unwrap())
+            .collect();
+        let mut digest = BackupDigest::default();
+        records.iter().for_each(|line| digest.push(line));
+        let trailer = BackupLine::Trailer(digest.trailer()).encode().unwrap();
+        let dir = std::env::temp_dir().join(format!("cim-backup-{}", uuid::Uuid::now_v7()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let write = |name: &str, lines: &[&str]| {
+            let path = dir.join(name);
+            std::fs::write(&path, lines.join("\n") + "\n").unwrap();
+            path
+        };
+        let header = header.encode().unwrap();
+        let tampered = records[1].replace("event-3", "event-4");
+        let complete = write("complete", &[&header, &records[0], &records[1], &trailer]);
+        let truncated = write("truncated", &[&header, &records[0], &records[1]]);
+        let altered = write("altered", &[&header, &records[0], &tampered, &trailer]);
+
+        // Act
+        let complete = verify_file(&complete).await;
+        let truncated = verify_file(&truncated).await;
+        let altered = verify_file(&altered).await;
+
+        // Assert
+        assert_eq!(complete.unwrap().last_sequence, 3);
+        assert!(truncated.unwrap_err().to_string().contains("truncated"));
+        assert!(altered.unwrap_err().to_string().contains("checksum"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,184 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Multi-Event Append Batches
+//!
+//! JetStream acknowledges messages one at a time, so a multi-event append
+//! can fail part way through. Every event of a multi-event append carries a
+//! [`BatchMarker`] in its `metadata`; readers drop batches that are not
+//! complete, which makes the append all-or-nothing from the reader's point
+//! of view. A failed batch leaves its sequence numbers free for the next
+//! append.
+//!
+//! # Metadata
+//!
+//! ```text
+//! {"batch": {"batch_id": "0193…", "index": 0, "size": 3}}
+//! ```
+//!
+//! Single-event appends carry no marker and are always complete.
+//! Consumers that tail the raw stream (projection replay) see events as
+//! they were written and can use [`BatchMarker::of`] to group them.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::jetstream::StoredEvent;
+
+/// Key of the batch marker inside `StoredEvent::metadata`
+const BATCH_KEY: &str = "batch";
+
+/// Position of an event within an append batch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BatchMarker {
+    /// Shared by every event of the batch
+    pub batch_id: Uuid,
+
+    /// Zero-based position in the batch
+    pub index: u32,
+
+    /// Number of events in the batch
+    pub size: u32,
+}
+
+impl BatchMarker {
+    /// Record the marker in the event metadata, keeping other keys
+    pub fn attach<E>(&self, event: &mut StoredEvent<E>) {
+        let mut metadata = match event.metadata.take() {
+            Some(serde_json::Value::Object(map)) => map,
+            _ => serde_json::Map::new(),
+        };
+        metadata.insert(
+            BATCH_KEY.to_string(),
+            serde_json::to_value(self).expect("batch marker serializes"),
+        );
+        event.metadata = Some(serde_json::Value::Object(metadata));
+    }
+
+    /// Marker of an event, if it was written as part of a batch
+    pub fn of<E>(event: &StoredEvent<E>) -> Option<Self> {
+        let marker = event.metadata.as_ref()?.get(BATCH_KEY)?;
+        serde_json::from_value(marker.clone()).ok()
+    }
+}
+
+/// Whether a stream may be cut right after `event` without splitting a batch
+pub fn ends_batch<E>(event: &StoredEvent<E>) -> bool {
+    match BatchMarker::of(event) {
+        Some(marker) => marker.index + 1 >= marker.size,
+        None => true,
+    }
+}
+
+/// Stream sequence of the first message of the batch `from` belongs to
+///
+/// `events` pairs each event with its stream sequence. Cutting a stream
+/// there instead of at `from` keeps batches whole; readers would drop
+/// the rest of a partly purged one.
+pub fn batch_start<E>(events: &[(u64, StoredEvent<E>)], from: u64) -> u64 {
+    let marker = events
+        .iter()
+        .find(|(stream_sequence, _)| *stream_sequence == from)
+        .and_then(|(_, event)| BatchMarker::of(event));
+    let Some(marker) = marker else {
+        return from;
+    };
+    events
+        .iter()
+        .filter(|(_, event)| BatchMarker::of(event).is_some_and(|m| m.batch_id == marker.batch_id))
+        .map(|(stream_sequence, _)| *stream_sequence)
+        .min()
+        .unwrap_or(from)
+}
+
+/// Remove events belonging to batches that were not fully written
+///
+/// Order of the remaining events is preserved.
+pub fn drop_incomplete_batches<E>(events: Vec<StoredEvent<E>>) -> Vec<StoredEvent<E>> {
+    let mut written: HashMap<Uuid, (u32, u32)> = HashMap::new();
+    for marker in events.iter().filter_map(BatchMarker::of) {
+        let entry = written.entry(marker.batch_id).or_insert((0, marker.size));
+        entry.0 += 1;
+    }
+
+    events
+        .into_iter()
+        .filter(|event| match BatchMarker::of(event) {
+            Some(marker) => written
+                .get(&marker.batch_id)
+                .is_some_and(|&(count, size)| count >= size),
+            None => true,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stored(sequence: u64, marker: Option<BatchMarker>) -> StoredEvent<&'static str> {
+        let mut event = StoredEvent::new(
+            Uuid::now_v7(),
+            Uuid::nil(),
+            sequence,
+            Uuid::now_v7(),
+            Uuid::now_v7(),
+            "test",
+            "data",
+        );
+        if let Some(marker) = marker {
+            marker.attach(&mut event);
+        }
+        event
+    }
+
+    #[test]
+    fn test_incomplete_batches_are_dropped() {
+        // Arrange: a single event, a complete batch of 2, a torn batch of 3
+        let complete = Uuid::now_v7();
+        let torn = Uuid::now_v7();
+        let marker = |batch_id, index, size| Some(BatchMarker { batch_id, index, size });
+        let events = vec![
+            stored(1, None),
+            stored(2, marker(complete, 0, 2)),
+            stored(3, marker(complete, 1, 2)),
+            stored(4, marker(torn, 0, 3)),
+            stored(5, marker(torn, 1, 3)),
+        ];
+
+        // Act
+        let visible = drop_incomplete_batches(events);
+
+        // Assert
+        let sequences: Vec<u64> = visible.iter().map(|e| e.sequence).collect();
+        assert_eq!(sequences, vec![1, 2, 3]);
+        assert_eq!(BatchMarker::of(&visible[2]).unwrap().index, 1);
+    }
+
+    #[test]
+    fn test_cuts_are_moved_to_batch_boundaries() {
+        // Arrange: a single event, then a batch of 3 at stream sequences 11..=13
+        let batch_id = Uuid::now_v7();
+        let marker = |index| {
+            Some(BatchMarker {
+                batch_id,
+                index,
+                size: 3,
+            })
+        };
+        let events = vec![
+            (10, stored(1, None)),
+            (11, stored(2, marker(0))),
+            (12, stored(3, marker(1))),
+            (13, stored(4, marker(2))),
+        ];
+
+        // Act
+        let boundaries: Vec<bool> = events.iter().map(|(_, e)| ends_batch(e)).collect();
+        let start = batch_start(&events, 12);
+
+        // Assert
+        assert_eq!(boundaries, vec![true, false, false, true]);
+        assert_eq!(start, 11);
+        assert_eq!(batch_start(&events, 10), 10);
+    }
+}
@@ -0,0 +1,382 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! CloudEvents 1.0 Envelopes
+//!
+//! Lets consumers that speak CloudEvents (Knative eventing, Argo Events,
+//! cloud event routers) read the infrastructure stream without knowing the
+//! [`StoredEvent`] layout. Enable it on the event store with
+//! [`NatsEventStore::with_cloud_events`](crate::event_store::NatsEventStore::with_cloud_events).
+//!
+//! # Content Modes
+//!
+//! ```text
+//! Binary      headers  ce-specversion, ce-id, ce-source, ce-type, ce-subject,
+//!                      ce-time, ce-correlationid, ce-causationid, ce-sequence,
+//!                      Content-Type: <codec content type>
+//!             payload  StoredEvent in the store's codec (unchanged)
+//!
+//! Structured  headers  Content-Type: application/cloudevents+json
+//!             payload  {"specversion": "1.0", "id": ..., "type": ..., "data": <StoredEvent JSON>}
+//! ```
+//!
+//! Binary mode leaves payloads untouched, so readers that ignore headers
+//! keep working. Structured mode changes the payload; this crate's readers
+//! unwrap it with [`unwrap_structured`], other consumers of the raw stream
+//! must understand CloudEvents.
+//!
+//! # Attributes
+//!
+//! | Attribute         | Value                                           |
+//! |-------------------|-------------------------------------------------|
+//! | `id`              | domain event ID                                 |
+//! | `source`          | [`CloudEventsConfig::source`]                   |
+//! | `type`            | `<type_prefix>.<aggregate type>.<event type>`   |
+//! | `subject`         | aggregate ID                                    |
+//! | `time`            | event timestamp                                 |
+//! | `correlationid`   | correlation ID (extension)                      |
+//! | `causationid`     | causation ID (extension)                        |
+//! | `sequence`        | aggregate version (sequence extension)          |
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::errors::{InfrastructureError, InfrastructureResult};
+use crate::event_store::codec::EventCodec;
+use crate::events::InfrastructureEvent;
+use crate::jetstream::StoredEvent;
+use crate::subjects::AggregateType;
+
+/// CloudEvents specification version written on every event
+pub const SPEC_VERSION: &str = "1.0";
+
+/// Content type of a structured-mode payload
+pub const STRUCTURED_CONTENT_TYPE: &str = "application/cloudevents+json";
+
+/// Content type of the event data
+pub const DATA_CONTENT_TYPE: &str = "application/json";
+
+/// Prefix of binary-mode attribute headers
+pub const HEADER_PREFIX: &str = "ce-";
+
+/// How CloudEvents attributes travel with a message
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContentMode {
+    /// Attributes in `ce-` headers, payload unchanged
+    #[default]
+    Binary,
+
+    /// Attributes and data in one JSON envelope
+    Structured,
+}
+
+/// CloudEvents settings of an event store
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CloudEventsConfig {
+    /// Content mode
+    #[serde(default)]
+    pub mode: ContentMode,
+
+    /// `source` attribute (a URI reference identifying this producer)
+    #[serde(default = "default_source")]
+    pub source: String,
+
+    /// Reverse-DNS prefix of the `type` attribute
+    #[serde(default = "default_type_prefix")]
+    pub type_prefix: String,
+}
+
+fn default_source() -> String {
+    "/cim/infrastructure".to_string()
+}
+
+fn default_type_prefix() -> String {
+    "ai.thecowboy.cim.infrastructure".to_string()
+}
+
+impl Default for CloudEventsConfig {
+    fn default() -> Self {
+        Self {
+            mode: ContentMode::default(),
+            source: default_source(),
+            type_prefix: default_type_prefix(),
+        }
+    }
+}
+
+impl CloudEventsConfig {
+    /// Binary content mode with default attributes
+    pub fn binary() -> Self {
+        Self::default()
+    }
+
+    /// Structured content mode with default attributes
+    pub fn structured() -> Self {
+        Self {
+            mode: ContentMode::Structured,
+            ..Self::default()
+        }
+    }
+
+    /// Set the `source` attribute
+    pub fn with_source(mut self, source: impl Into<String>) -> Self {
+        self.source = source.into();
+        self
+    }
+
+    /// Set the prefix of the `type` attribute
+    pub fn with_type_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.type_prefix = prefix.into();
+        self
+    }
+
+    /// Attributes of a stored event, without data
+    pub fn attributes(
+        &self,
+        aggregate_type: AggregateType,
+        stored: &StoredEvent<InfrastructureEvent>,
+    ) -> CloudEvent {
+        CloudEvent {
+            specversion: SPEC_VERSION.to_string(),
+            id: stored.data.event_id().to_string(),
+            source: self.source.clone(),
+            event_type: format!(
+                "{}.{}.{}",
+                self.type_prefix,
+                aggregate_type,
+                stored.event_type.to_lowercase()
+            ),
+            subject: Some(stored.aggregate_id.to_string()),
+            time: Some(stored.timestamp),
+            datacontenttype: Some(DATA_CONTENT_TYPE.to_string()),
+            correlationid: Some(stored.correlation_id.to_string()),
+            causationid: Some(stored.causation_id.to_string()),
+            sequence: Some(stored.sequence.to_string()),
+            data: None,
+        }
+    }
+
+    /// Headers and payload of a message carrying `attributes` and the
+    /// stored event `payload`
+    ///
+    /// Binary mode writes the payload with `codec`, which also sets the
+    /// data content type. Structured mode is the CloudEvents JSON format,
+    /// so its envelope is always JSON.
+    pub fn encode(
+        &self,
+        mut attributes: CloudEvent,
+        payload: Value,
+        codec: &dyn EventCodec,
+    ) -> InfrastructureResult<(Vec<(String, String)>, Vec<u8>)> {
+        match self.mode {
+            ContentMode::Binary => {
+                attributes.datacontenttype = Some(codec.content_type().to_string());
+                Ok((attributes.headers(), codec.encode(&payload)?))
+            }
+            ContentMode::Structured => {
+                let envelope = CloudEvent {
+                    data: Some(payload),
+                    ..attributes
+                };
+                let bytes = serde_json::to_vec(&envelope)
+                    .map_err(|e| InfrastructureError::Serialization(e.to_string()))?;
+                Ok((
+                    vec![(
+                        "Content-Type".to_string(),
+                        STRUCTURED_CONTENT_TYPE.to_string(),
+                    )],
+                    bytes,
+                ))
+            }
+        }
+    }
+}
+
+/// A CloudEvents 1.0 event with this crate's extension attributes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CloudEvent {
+    /// Specification version
+    pub specversion: String,
+
+    /// Event ID
+    pub id: String,
+
+    /// Producer
+    pub source: String,
+
+    /// Event type
+    #[serde(rename = "type")]
+    pub event_type: String,
+
+    /// Aggregate the event belongs to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+
+    /// When the event occurred
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<DateTime<Utc>>,
+
+    /// Content type of `data`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub datacontenttype: Option<String>,
+
+    /// Correlation ID extension
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correlationid: Option<String>,
+
+    /// Causation ID extension
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub causationid: Option<String>,
+
+    /// Sequence extension (aggregate version)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sequence: Option<String>,
+
+    /// Event data (structured mode only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
+}
+
+impl CloudEvent {
+    /// Binary-mode headers: every attribute as `ce-<name>`, and the data
+    /// content type as `Content-Type`
+    pub fn headers(&self) -> Vec<(String, String)> {
+        let mut headers = vec![
+            header("specversion", &self.specversion),
+            header("id", &self.id),
+            header("source", &self.source),
+            header("type", &self.event_type),
+        ];
+        let optional = [
+            ("subject", self.subject.clone()),
+            ("time", self.time.map(|t| t.to_rfc3339())),
+            ("correlationid", self.correlationid.clone()),
+            ("causationid", self.causationid.clone()),
+            ("sequence", self.sequence.clone()),
+        ];
+        headers.extend(
+            optional
+                .into_iter()
+                .filter_map(|(name, value)| value.map(|value| header(name, &value))),
+        );
+        if let Some(content_type) = &self.datacontenttype {
+            headers.push(("Content-Type".to_string(), content_type.clone()));
+        }
+        headers
+    }
+
+    /// Read binary-mode attributes from message headers
+    ///
+    /// Returns `None` unless the required attributes are present.
+    pub fn from_headers<'a>(headers: impl IntoIterator<Item = (&'a str, &'a str)>) -> Option<Self> {
+        let mut attributes = std::collections::HashMap::new();
+        let mut content_type = None;
+        for (name, value) in headers {
+            let lower = name.to_ascii_lowercase();
+            if let Some(attribute) = lower.strip_prefix(HEADER_PREFIX) {
+                attributes.insert(attribute.to_string(), value.to_string());
+            } else if lower == "content-type" {
+                content_type = Some(value.to_string());
+            }
+        }
+
+        Some(Self {
+            specversion: attributes.remove("specversion")?,
+            id: attributes.remove("id")?,
+            source: attributes.remove("source")?,
+            event_type: attributes.remove("type")?,
+            subject: attributes.remove("subject"),
+            time: attributes
+                .remove("time")
+                .and_then(|t| DateTime::parse_from_rfc3339(&t).ok())
+                .map(|t| t.with_timezone(&Utc)),
+            datacontenttype: content_type,
+            correlationid: attributes.remove("correlationid"),
+            causationid: attributes.remove("causationid"),
+            sequence: attributes.remove("sequence"),
+            data: None,
+        })
+    }
+}
+
+fn header(name: &str, value: &str) -> (String, String) {
+    (format!("{}{}", HEADER_PREFIX, name), value.to_string())
+}
+
+/// The stored event inside a structured-mode payload, or the payload
+/// itself when it is not a CloudEvent
+pub fn unwrap_structured(mut payload: Value) -> Value {
+    let is_cloud_event =
+        payload.get("specversion").is_some_and(Value::is_string) && payload.get("data").is_some();
+    if is_cloud_event {
+        payload["data"].take()
+    } else {
+        payload
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event_store::codec::JsonCodec;
+    use crate::test_harness::{self, registered};
+    use uuid::Uuid;
+
+    fn stored() -> StoredEvent<InfrastructureEvent> {
+        test_harness::stored(3, registered(Uuid::now_v7(), "web01"))
+    }
+
+    #[test]
+    fn test_binary_mode_round_trips_through_headers() {
+        let config = CloudEventsConfig::binary().with_source("/dc1/infra");
+        let event = stored();
+        let payload = serde_json::to_value(&event).unwrap();
+
+        let attributes = config.attributes(AggregateType::Compute, &event);
+        let (headers, body) = config
+            .encode(attributes.clone(), payload.clone(), &JsonCodec)
+            .unwrap();
+        let parsed =
+            CloudEvent::from_headers(headers.iter().map(|(n, v)| (n.as_str(), v.as_str())))
+                .unwrap();
+
+        assert_eq!(serde_json::from_slice::<Value>(&body).unwrap(), payload);
+        assert_eq!(parsed, attributes);
+        assert_eq!(parsed.id, event.data.event_id().to_string());
+        assert_eq!(
+            parsed.event_type,
+            "ai.thecowboy.cim.infrastructure.compute.resourceregistered"
+        );
+        assert_eq!(parsed.source, "/dc1/infra");
+        assert_eq!(parsed.sequence.as_deref(), Some("3"));
+    }
+
+    #[test]
+    fn test_structured_mode_wraps_and_unwraps_the_stored_event() {
+        let config = CloudEventsConfig::structured();
+        let event = stored();
+        let payload = serde_json::to_value(&event).unwrap();
+
+        let attributes = config.attributes(AggregateType::Network, &event);
+        let (headers, body) = config.encode(attributes, payload, &JsonCodec).unwrap();
+        let envelope: Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(
+            headers,
+            vec![(
+                "Content-Type".to_string(),
+                STRUCTURED_CONTENT_TYPE.to_string()
+            )]
+        );
+        assert_eq!(envelope["specversion"], SPEC_VERSION);
+        assert_eq!(envelope["subject"], event.aggregate_id.to_string());
+        assert_eq!(
+            unwrap_structured(envelope),
+            serde_json::to_value(&event).unwrap()
+        );
+        assert_eq!(
+            unwrap_structured(serde_json::json!({"data": 1})),
+            serde_json::json!({"data": 1})
+        );
+    }
+}
@@ -0,0 +1,342 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Event Payload Codecs
+//!
+//! The event store builds every payload as JSON values, because
+//! encryption, unknown-field checks and upcasting all work on that form.
+//! An [`EventCodec`] only decides how the finished value is written to the
+//! wire:
+//!
+//! ```text
+//! StoredEvent ─serialize─> Value ─protect()─> Value ─EventCodec::encode─> bytes + Content-Type
+//!                                                                              │
+//! StoredEvent <─decode_payload── Value <─reveal/upcast── Value <─decode────────┘
+//! ```
+//!
+//! | Codec      | Content-Type               | Feature    |
+//! |------------|----------------------------|------------|
+//! | JSON       | `application/json`         | (always)   |
+//! | CBOR       | `application/cbor`         | `cbor`     |
+//! | Protobuf   | `application/x-protobuf`   | `protobuf` |
+//!
+//! Every message carries its codec's `Content-Type` header and readers
+//! pick the codec from it, so a stream can switch codecs without
+//! rewriting history. Messages without the header are JSON.
+//!
+//! The protobuf codec writes `google.protobuf.Value`, whose numbers are
+//! doubles: integers beyond ±2^53 are refused on encode rather than
+//! silently rounded.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::Arc;
+
+use crate::errors::{InfrastructureError, InfrastructureResult};
+
+/// Content-Type of JSON payloads
+pub const JSON_CONTENT_TYPE: &str = "application/json";
+
+/// Content-Type of CBOR payloads
+pub const CBOR_CONTENT_TYPE: &str = "application/cbor";
+
+/// Content-Type of protobuf payloads
+pub const PROTOBUF_CONTENT_TYPE: &str = "application/x-protobuf";
+
+/// Wire format of event payloads
+pub trait EventCodec: Send + Sync {
+    /// Content-Type header value written with each message
+    fn content_type(&self) -> &'static str;
+
+    /// Encode a payload
+    fn encode(&self, value: &Value) -> InfrastructureResult<Vec<u8>>;
+
+    /// Decode a payload
+    fn decode(&self, bytes: &[u8]) -> InfrastructureResult<Value>;
+}
+
+/// Codec selectable in configuration
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CodecKind {
+    /// JSON (readable with any NATS tool)
+    #[default]
+    Json,
+
+    /// CBOR (requires the `cbor` feature)
+    Cbor,
+
+    /// Protobuf `google.protobuf.Value` (requires the `protobuf` feature)
+    Protobuf,
+}
+
+impl CodecKind {
+    /// Content-Type of this codec's payloads
+    pub fn content_type(self) -> &'static str {
+        match self {
+            CodecKind::Json => JSON_CONTENT_TYPE,
+            CodecKind::Cbor => CBOR_CONTENT_TYPE,
+            CodecKind::Protobuf => PROTOBUF_CONTENT_TYPE,
+        }
+    }
+
+    /// Codec of a Content-Type header, ignoring parameters such as
+    /// `charset`
+    ///
+    /// JSON-based media types (`+json`, such as structured CloudEvents)
+    /// use the JSON codec.
+    pub fn from_content_type(content_type: &str) -> Option<Self> {
+        let media_type = content_type.split(';').next()?.trim();
+        if media_type.to_ascii_lowercase().ends_with("+json") {
+            return Some(CodecKind::Json);
+        }
+        [CodecKind::Json, CodecKind::Cbor, CodecKind::Protobuf]
+            .into_iter()
+            .find(|kind| kind.content_type().eq_ignore_ascii_case(media_type))
+    }
+
+    /// Instantiate the codec
+    ///
+    /// Fails if the codec's feature is not enabled in this build.
+    pub fn codec(self) -> InfrastructureResult<Arc<dyn EventCodec>> {
+        match self {
+            CodecKind::Json => Ok(Arc::new(JsonCodec)),
+            #[cfg(feature = "cbor")]
+            CodecKind::Cbor => Ok(Arc::new(CborCodec)),
+            #[cfg(feature = "protobuf")]
+            CodecKind::Protobuf => Ok(Arc::new(ProtobufCodec)),
+            #[allow(unreachable_patterns)]
+            other => Err(InfrastructureError::Configuration(format!(
+                "{:?} codec is not enabled in this build",
+                other
+            ))),
+        }
+    }
+}
+
+/// Codec for a message's Content-Type header (None = JSON)
+pub fn codec_for_content_type(
+    content_type: Option<&str>,
+) -> InfrastructureResult<Arc<dyn EventCodec>> {
+    match content_type {
+        None => Ok(Arc::new(JsonCodec)),
+        Some(content_type) => CodecKind::from_content_type(content_type)
+            .ok_or_else(|| {
+                InfrastructureError::Deserialization(format!(
+                    "Unsupported payload content type: {}",
+                    content_type
+                ))
+            })?
+            .codec(),
+    }
+}
+
+/// Content-Type header of a message, if any
+pub fn message_content_type(headers: Option<&async_nats::HeaderMap>) -> Option<&str> {
+    headers
+        .and_then(|headers| headers.get("Content-Type"))
+        .map(|value| value.as_str())
+}
+
+/// JSON payloads
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonCodec;
+
+impl EventCodec for JsonCodec {
+    fn content_type(&self) -> &'static str {
+        JSON_CONTENT_TYPE
+    }
+
+    fn encode(&self, value: &Value) -> InfrastructureResult<Vec<u8>> {
+        serde_json::to_vec(value).map_err(|e| InfrastructureError::Serialization(e.to_string()))
+    }
+
+    fn decode(&self, bytes: &[u8]) -> InfrastructureResult<Value> {
+        serde_json::from_slice(bytes)
+            .map_err(|e| InfrastructureError::Deserialization(e.to_string()))
+    }
+}
+
+/// CBOR payloads
+#[cfg(feature = "cbor")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CborCodec;
+
+#[cfg(feature = "cbor")]
+impl EventCodec for CborCodec {
+    fn content_type(&self) -> &'static str {
+        CBOR_CONTENT_TYPE
+    }
+
+    fn encode(&self, value: &Value) -> InfrastructureResult<Vec<u8>> {
+        let mut bytes = Vec::new();
+        ciborium::into_writer(value, &mut bytes)
+            .map_err(|e| InfrastructureError::Serialization(e.to_string()))?;
+        Ok(bytes)
+    }
+
+    fn decode(&self, bytes: &[u8]) -> InfrastructureResult<Value> {
+        ciborium::from_reader(bytes)
+            .map_err(|e| InfrastructureError::Deserialization(e.to_string()))
+    }
+}
+
+/// Protobuf `google.protobuf.Value` payloads
+#[cfg(feature = "protobuf")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ProtobufCodec;
+
+#[cfg(feature = "protobuf")]
+mod protobuf {
+    use prost_types::value::Kind;
+    use prost_types::{ListValue, Struct};
+    use serde_json::{Map, Number, Value};
+
+    use crate::errors::{InfrastructureError, InfrastructureResult};
+
+    /// Largest integer a double represents exactly
+    const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_992.0;
+
+    pub(super) fn to_proto(value: &Value) -> InfrastructureResult<prost_types::Value> {
+        let kind = match value {
+            Value::Null => Kind::NullValue(0),
+            Value::Bool(b) => Kind::BoolValue(*b),
+            Value::Number(n) => {
+                let f = n.as_f64().unwrap_or(f64::NAN);
+                let is_integer = n.is_i64() || n.is_u64();
+                if is_integer && f.abs() > MAX_SAFE_INTEGER {
+                    return Err(InfrastructureError::Serialization(format!(
+                        "integer {} does not fit a protobuf double exactly",
+                        n
+                    )));
+                }
+                Kind::NumberValue(f)
+            }
+            Value::String(s) => Kind::StringValue(s.clone()),
+            Value::Array(items) => Kind::ListValue(ListValue {
+                values: items.iter().map(to_proto).collect::<Result<_, _>>()?,
+            }),
+            Value::Object(fields) => Kind::StructValue(Struct {
+                fields: fields
+                    .iter()
+                    .map(|(k, v)| Ok((k.clone(), to_proto(v)?)))
+                    .collect::<InfrastructureResult<_>>()?,
+            }),
+        };
+        Ok(prost_types::Value { kind: Some(kind) })
+    }
+
+    pub(super) fn from_proto(value: prost_types::Value) -> InfrastructureResult<Value> {
+        Ok(match value.kind {
+            None | Some(Kind::NullValue(_)) => Value::Null,
+            Some(Kind::BoolValue(b)) => Value::Bool(b),
+            Some(Kind::NumberValue(f)) => {
+                // Whole numbers were integers when encoded
+                if f.fract() == 0.0 && f.abs() <= MAX_SAFE_INTEGER {
+                    if f < 0.0 {
+                        Value::Number(Number::from(f as i64))
+                    } else {
+                        Value::Number(Number::from(f as u64))
+                    }
+                } else {
+                    Value::Number(Number::from_f64(f).ok_or_else(|| {
+                        InfrastructureError::Deserialization(format!("invalid number {}", f))
+                    })?)
+                }
+            }
+            Some(Kind::StringValue(s)) => Value::String(s),
+            Some(Kind::ListValue(list)) => Value::Array(
+                list.values
+                    .into_iter()
+                    .map(from_proto)
+                    .collect::<Result<_, _>>()?,
+            ),
+            Some(Kind::StructValue(fields)) => Value::Object(
+                fields
+                    .fields
+                    .into_iter()
+                    .map(|(k, v)| Ok((k, from_proto(v)?)))
+                    .collect::<InfrastructureResult<Map<_, _>>>()?,
+            ),
+        })
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl EventCodec for ProtobufCodec {
+    fn content_type(&self) -> &'static str {
+        PROTOBUF_CONTENT_TYPE
+    }
+
+    fn encode(&self, value: &Value) -> InfrastructureResult<Vec<u8>> {
+        Ok(prost::Message::encode_to_vec(&protobuf::to_proto(value)?))
+    }
+
+    fn decode(&self, bytes: &[u8]) -> InfrastructureResult<Value> {
+        let value = <prost_types::Value as prost::Message>::decode(bytes)
+            .map_err(|e| InfrastructureError::Deserialization(e.to_string()))?;
+        protobuf::from_proto(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn payload() -> Value {
+        serde_json::json!({
+            "event_id": "0190c2a4-0000-7000-8000-000000000001",
+            "sequence": 42,
+            "offset": -3,
+            "ratio": 0.5,
+            "data": {"tags": ["a", "b"], "removed": null, "active": true},
+        })
+    }
+
+    #[test]
+    fn test_content_type_selects_codec() {
+        assert_eq!(
+            CodecKind::from_content_type("application/json; charset=utf-8"),
+            Some(CodecKind::Json)
+        );
+        assert_eq!(
+            CodecKind::from_content_type("application/x-protobuf"),
+            Some(CodecKind::Protobuf)
+        );
+        assert_eq!(
+            CodecKind::from_content_type("application/cloudevents+json"),
+            Some(CodecKind::Json)
+        );
+        assert_eq!(CodecKind::from_content_type("text/plain"), None);
+        assert!(codec_for_content_type(None).is_ok());
+        assert!(codec_for_content_type(Some("text/plain")).is_err());
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let codec = CodecKind::Json.codec().unwrap();
+        let bytes = codec.encode(&payload()).unwrap();
+
+        assert_eq!(codec.decode(&bytes).unwrap(), payload());
+    }
+
+    #[cfg(feature = "cbor")]
+    #[test]
+    fn test_cbor_round_trip_is_smaller() {
+        let cbor = CodecKind::Cbor.codec().unwrap();
+        let bytes = cbor.encode(&payload()).unwrap();
+
+        assert_eq!(cbor.decode(&bytes).unwrap(), payload());
+        assert!(bytes.len() < JsonCodec.encode(&payload()).unwrap().len());
+    }
+
+    #[cfg(feature = "protobuf")]
+    #[test]
+    fn test_protobuf_round_trip_refuses_unsafe_integers() {
+        let protobuf = CodecKind::Protobuf.codec().unwrap();
+        let bytes = protobuf.encode(&payload()).unwrap();
+
+        assert_eq!(protobuf.decode(&bytes).unwrap(), payload());
+        assert!(protobuf
+            .encode(&serde_json::json!({"big": u64::MAX}))
+            .is_err());
+    }
+}
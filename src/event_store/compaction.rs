@@ -0,0 +1,355 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Event Store Compaction
+//!
+//! Some aggregate types do not need their full history: once a cable has
+//! been re-labelled a dozen times, nobody replays the first label. For
+//! those types a [`Compactor`] folds events older than a horizon into a
+//! [snapshot](crate::event_store::snapshot) and then purges them:
+//!
+//! ```text
+//! events 1..40 (older than horizon) ──fold──> AggregateSnapshot{version: 40}
+//!                                    ──purge──> gone
+//! events 41..                        ──kept──> rebuilt on top of the snapshot
+//! ```
+//!
+//! The snapshot is written before anything is purged, so an interrupted
+//! run loses nothing. The latest event of an aggregate is always kept,
+//! because the aggregate's version is read from it.
+//!
+//! Types without a horizon in the [`CompactionPolicy`] keep every event.
+//! Only aggregate types implementing [`Snapshottable`] can be compacted.
+//!
+//! # Readers
+//!
+//! Anything that replays a compacted aggregate must start from its
+//! snapshot, such as
+//! [`EventSourcedComputeResourceService::with_snapshots`](crate::service::EventSourcedComputeResourceService::with_snapshots).
+//! Projections rebuilt from an empty state only see the kept events.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::event_store::compaction::{CompactionPolicy, Compactor};
+//! use cim_infrastructure::subjects::AggregateType;
+//!
+//! let policy = CompactionPolicy::new().with_horizon(AggregateType::Connection, Duration::days(90));
+//! let snapshots = SnapshotStore::open(jetstream).await?;
+//! let compactor = Compactor::new(Arc::new(event_store), snapshots, policy);
+//!
+//! tokio::spawn(async move { compactor.run(std::time::Duration::from_secs(24 * 3600)).await });
+//! ```
+
+use chrono::{DateTime, Duration, Utc};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use tracing::info;
+use uuid::Uuid;
+
+use crate::aggregate::{ComputeResourceState, ConnectionState};
+use crate::errors::InfrastructureResult;
+use crate::event_store::batch::{drop_incomplete_batches, ends_batch};
+use crate::event_store::snapshot::{rebuild, AggregateSnapshot, SnapshotStore, Snapshottable};
+use crate::event_store::{EventStore, NatsEventStore};
+use crate::events::InfrastructureEvent;
+use crate::jetstream::StoredEvent;
+use crate::subjects::AggregateType;
+
+/// How long each aggregate type keeps its full history
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CompactionPolicy {
+    horizons: HashMap<AggregateType, Duration>,
+}
+
+impl CompactionPolicy {
+    /// Policy that keeps every event
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Compact events of `aggregate_type` older than `horizon`
+    pub fn with_horizon(mut self, aggregate_type: AggregateType, horizon: Duration) -> Self {
+        self.horizons.insert(aggregate_type, horizon);
+        self
+    }
+
+    /// Horizon of an aggregate type; None keeps its full history
+    pub fn horizon(&self, aggregate_type: AggregateType) -> Option<Duration> {
+        self.horizons.get(&aggregate_type).copied()
+    }
+}
+
+/// Highest version that may be compacted: the last event older than
+/// `cutoff`, never the latest event
+///
+/// The point is rounded down to the end of a batch, so a batch is never
+/// split. `events` must be in sequence order.
+pub fn compaction_point<E>(events: &[StoredEvent<E>], cutoff: DateTime<Utc>) -> Option<u64> {
+    let (_, older) = events.split_last()?;
+    older
+        .iter()
+        .take_while(|event| event.timestamp < cutoff)
+        .filter(|event| ends_batch(event))
+        .last()
+        .map(|event| event.sequence)
+}
+
+/// Result of compacting one aggregate
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompactionReport {
+    /// The aggregate
+    pub aggregate_id: Uuid,
+
+    /// Version the snapshot covers
+    pub snapshot_version: u64,
+
+    /// Messages purged from the stream
+    pub purged: u64,
+}
+
+/// Snapshots and purges old events according to a [`CompactionPolicy`]
+pub struct Compactor {
+    event_store: Arc<NatsEventStore>,
+    snapshots: SnapshotStore,
+    policy: CompactionPolicy,
+}
+
+impl Compactor {
+    /// Compact `event_store`, keeping snapshots in `snapshots`
+    pub fn new(
+        event_store: Arc<NatsEventStore>,
+        snapshots: SnapshotStore,
+        policy: CompactionPolicy,
+    ) -> Self {
+        Self {
+            event_store,
+            snapshots,
+            policy,
+        }
+    }
+
+    /// Compact every aggregate of every type with a horizon
+    pub async fn run_once(
+        &self,
+        now: DateTime<Utc>,
+    ) -> InfrastructureResult<Vec<CompactionReport>> {
+        let mut reports = self.compact_type::<ComputeResourceState>(now).await?;
+        reports.extend(self.compact_type::<ConnectionState>(now).await?);
+        Ok(reports)
+    }
+
+    /// Compact on a fixed interval until an error occurs
+    pub async fn run(&self, interval: std::time::Duration) -> InfrastructureResult<()> {
+        info!("Starting event store compaction (interval: {:?})", interval);
+        let mut ticker = tokio::time::interval(interval);
+
+        loop {
+            ticker.tick().await;
+            let reports = self.run_once(Utc::now()).await?;
+            let purged: u64 = reports.iter().map(|r| r.purged).sum();
+            info!(
+                "Compacted {} aggregates, purged {} messages",
+                reports.len(),
+                purged
+            );
+        }
+    }
+
+    /// Compact one aggregate, if its type has a horizon
+    pub async fn compact<S: Snapshottable>(
+        &self,
+        aggregate_id: Uuid,
+        now: DateTime<Utc>,
+    ) -> InfrastructureResult<Option<CompactionReport>> {
+        if self.policy.horizon(S::AGGREGATE_TYPE).is_none() {
+            return Ok(None);
+        }
+        let events = self.event_store.read_events(aggregate_id).await?;
+        self.compact_events::<S>(aggregate_id, &events, now).await
+    }
+
+    async fn compact_type<S: Snapshottable>(
+        &self,
+        now: DateTime<Utc>,
+    ) -> InfrastructureResult<Vec<CompactionReport>> {
+        if self.policy.horizon(S::AGGREGATE_TYPE).is_none() {
+            return Ok(Vec::new());
+        }
+
+        let mut by_aggregate: BTreeMap<Uuid, Vec<StoredEvent<InfrastructureEvent>>> =
+            BTreeMap::new();
+        for event in self
+            .event_store
+            .read_aggregate_type(S::AGGREGATE_TYPE)
+            .await?
+        {
+            by_aggregate
+                .entry(event.aggregate_id)
+                .or_default()
+                .push(event);
+        }
+
+        let mut reports = Vec::new();
+        for (aggregate_id, events) in by_aggregate {
+            let mut events = drop_incomplete_batches(events);
+            events.sort_by_key(|e| e.sequence);
+            reports.extend(self.compact_events::<S>(aggregate_id, &events, now).await?);
+        }
+        Ok(reports)
+    }
+
+    /// Snapshot through the compaction point, then purge what it covers
+    async fn compact_events<S: Snapshottable>(
+        &self,
+        aggregate_id: Uuid,
+        events: &[StoredEvent<InfrastructureEvent>],
+        now: DateTime<Utc>,
+    ) -> InfrastructureResult<Option<CompactionReport>> {
+        let Some(horizon) = self.policy.horizon(S::AGGREGATE_TYPE) else {
+            return Ok(None);
+        };
+        let Some(version) = compaction_point(events, now - horizon) else {
+            return Ok(None);
+        };
+
+        let snapshot = self.snapshots.load::<S>(aggregate_id).await?;
+        let already_covered = snapshot.as_ref().is_some_and(|s| s.version >= version);
+        if !already_covered {
+            let covered: Vec<_> = events
+                .iter()
+                .filter(|e| e.sequence <= version)
+                .cloned()
+                .collect();
+            let state = rebuild(aggregate_id, snapshot, &covered);
+            self.snapshots
+                .save(&AggregateSnapshot {
+                    aggregate_id,
+                    version,
+                    taken_at: now,
+                    state,
+                })
+                .await?;
+        }
+
+        let purged = self
+            .event_store
+            .purge_through(aggregate_id, version)
+            .await?;
+        if already_covered && purged == 0 {
+            return Ok(None);
+        }
+
+        Ok(Some(CompactionReport {
+            aggregate_id,
+            snapshot_version: version,
+            purged,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event_store::batch::BatchMarker;
+    use crate::events::connection::{ConnectionEvent, ConnectionLabeled};
+
+    fn test_timestamp() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-19T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn labeled(
+        aggregate_id: Uuid,
+        sequence: u64,
+        days_ago: i64,
+    ) -> StoredEvent<InfrastructureEvent> {
+        let mut stored = StoredEvent::new(
+            Uuid::now_v7(),
+            aggregate_id,
+            sequence,
+            Uuid::now_v7(),
+            Uuid::now_v7(),
+            "connection_labeled",
+            InfrastructureEvent::Connection(ConnectionEvent::ConnectionLabeled(
+                ConnectionLabeled {
+                    event_version: 1,
+                    event_id: Uuid::now_v7(),
+                    aggregate_id,
+                    timestamp: test_timestamp() - Duration::days(days_ago),
+                    correlation_id: Uuid::now_v7(),
+                    causation_id: None,
+                    label: format!("C-{}", sequence),
+                },
+            )),
+        );
+        stored.timestamp = test_timestamp() - Duration::days(days_ago);
+        stored
+    }
+
+    #[test]
+    fn test_compaction_point_keeps_recent_and_latest_events() {
+        // Arrange
+        let aggregate_id = Uuid::now_v7();
+        let cutoff = test_timestamp() - Duration::days(90);
+        let history = vec![
+            labeled(aggregate_id, 1, 200),
+            labeled(aggregate_id, 2, 120),
+            labeled(aggregate_id, 3, 10),
+        ];
+        let all_old = vec![labeled(aggregate_id, 1, 200), labeled(aggregate_id, 2, 120)];
+
+        // Act / Assert
+        assert_eq!(compaction_point(&history, cutoff), Some(2));
+        assert_eq!(compaction_point(&all_old, cutoff), Some(1));
+        assert_eq!(compaction_point(&history[..1], cutoff), None);
+        assert_eq!(compaction_point(&history[2..], cutoff), None);
+    }
+
+    #[test]
+    fn test_compaction_point_never_splits_a_batch() {
+        // Arrange: versions 2 and 3 were appended as one batch
+        let aggregate_id = Uuid::now_v7();
+        let cutoff = test_timestamp() - Duration::days(90);
+        let batch_id = Uuid::now_v7();
+        let mut history = vec![
+            labeled(aggregate_id, 1, 200),
+            labeled(aggregate_id, 2, 120),
+            labeled(aggregate_id, 3, 10),
+            labeled(aggregate_id, 4, 5),
+        ];
+        for (index, event) in history[1..3].iter_mut().enumerate() {
+            BatchMarker {
+                batch_id,
+                index: index as u32,
+                size: 2,
+            }
+            .attach(event);
+        }
+
+        // Act
+        let point = compaction_point(&history, cutoff);
+
+        // Assert
+        assert_eq!(point, Some(1));
+    }
+
+    #[test]
+    fn test_rebuild_skips_events_covered_by_snapshot() {
+        let aggregate_id = Uuid::now_v7();
+        let history = vec![labeled(aggregate_id, 1, 3), labeled(aggregate_id, 2, 2)];
+        let snapshot = AggregateSnapshot {
+            aggregate_id,
+            version: 2,
+            taken_at: test_timestamp(),
+            state: rebuild::<ConnectionState>(aggregate_id, None, &history),
+        };
+
+        let state = rebuild(
+            aggregate_id,
+            Some(snapshot),
+            &[history[1].clone(), labeled(aggregate_id, 3, 1)],
+        );
+
+        assert_eq!(state.label.as_deref(), Some("C-3"));
+    }
+}
@@ -0,0 +1,263 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Content-Addressed Events
+//!
+//! With [`NatsEventStore::with_content_addressing`](super::NatsEventStore::with_content_addressing)
+//! every appended event gets a CID, so other domains can reference it by
+//! content rather than by stream position:
+//!
+//! ```text
+//! CIDv1( json codec, sha2-256( canonical JSON of the event without metadata ) )
+//!
+//! metadata  {"cid": "bagaaiera…"}
+//! header    Cim-Event-Cid: bagaaiera…
+//! index     infrastructure.cid.<cid>   copy of the event
+//! ```
+//!
+//! Metadata is left out of the hash: it carries the CID itself and the
+//! [hash link](super::integrity), which covers the CID in turn. Everything
+//! else of the envelope (event ID, aggregate, sequence, timestamp,
+//! correlation, causation, type and data) is addressed.
+//!
+//! [`NatsEventStore::read_event_by_cid`](super::NatsEventStore::read_event_by_cid)
+//! reads the index and returns only an event whose content still hashes to
+//! the requested CID.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! let store = NatsEventStore::connect(url).await?.with_content_addressing(true);
+//! store.append(aggregate_id, events).await?;
+//!
+//! let event = &store.read_events(aggregate_id).await?[0];
+//! let cid = content::cid_of(event).expect("appended with content addressing");
+//! assert_eq!(store.read_event_by_cid(&cid).await?.unwrap().event_id, event.event_id);
+//! ```
+
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::jetstream::StoredEvent;
+
+/// Multicodec of JSON content
+pub const JSON_CODEC: u64 = 0x0200;
+
+/// Header carrying the CID of a published event
+pub const CID_HEADER: &str = "Cim-Event-Cid";
+
+/// Key of the CID inside `StoredEvent::metadata`
+const CID_KEY: &str = "cid";
+
+/// Multihash code of SHA2-256
+const SHA2_256: u64 = 0x12;
+
+/// Multibase prefix of lowercase base32, the default text form of CIDv1
+const BASE32_PREFIX: char = 'b';
+
+const BASE32_ALPHABET: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+/// A CIDv1 whose multihash is SHA2-256
+///
+/// Displayed and parsed in its default text form, lowercase base32 with
+/// the `b` multibase prefix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cid {
+    codec: u64,
+    digest: [u8; 32],
+}
+
+/// Text that is not a SHA2-256 CIDv1 in base32
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Invalid CID {0:?}")]
+pub struct InvalidCid(pub String);
+
+impl Cid {
+    /// CID of content with multicodec `codec` and SHA2-256 `digest`
+    pub fn new(codec: u64, digest: [u8; 32]) -> Self {
+        Self { codec, digest }
+    }
+
+    /// Multicodec of the content
+    pub fn codec(&self) -> u64 {
+        self.codec
+    }
+
+    /// SHA2-256 of the content
+    pub fn digest(&self) -> &[u8; 32] {
+        &self.digest
+    }
+
+    /// Binary form: version, codec, hash code, digest length, digest
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(40);
+        for value in [1, self.codec, SHA2_256, 32] {
+            write_varint(&mut bytes, value);
+        }
+        bytes.extend_from_slice(&self.digest);
+        bytes
+    }
+
+    fn from_bytes(mut bytes: &[u8]) -> Option<Self> {
+        let version = read_varint(&mut bytes)?;
+        let codec = read_varint(&mut bytes)?;
+        let hash = read_varint(&mut bytes)?;
+        let length = read_varint(&mut bytes)?;
+        if version != 1 || hash != SHA2_256 || length != 32 {
+            return None;
+        }
+        Some(Self::new(codec, bytes.try_into().ok()?))
+    }
+}
+
+impl fmt::Display for Cid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = String::with_capacity(64);
+        text.push(BASE32_PREFIX);
+        let (mut buffer, mut bits) = (0u16, 0);
+        for byte in self.to_bytes() {
+            buffer = (buffer << 8) | u16::from(byte);
+            bits += 8;
+            while bits >= 5 {
+                bits -= 5;
+                text.push(BASE32_ALPHABET[usize::from((buffer >> bits) & 31)] as char);
+            }
+        }
+        if bits > 0 {
+            text.push(BASE32_ALPHABET[usize::from((buffer << (5 - bits)) & 31)] as char);
+        }
+        f.write_str(&text)
+    }
+}
+
+impl FromStr for Cid {
+    type Err = InvalidCid;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidCid(text.to_string());
+        let encoded = text.strip_prefix(BASE32_PREFIX).ok_or_else(invalid)?;
+        let mut bytes = Vec::with_capacity(encoded.len() * 5 / 8);
+        let (mut buffer, mut bits) = (0u16, 0);
+        for symbol in encoded.bytes() {
+            let value = BASE32_ALPHABET
+                .iter()
+                .position(|&letter| letter == symbol)
+                .ok_or_else(invalid)?;
+            buffer = (buffer << 5) | value as u16;
+            bits += 5;
+            if bits >= 8 {
+                bits -= 8;
+                bytes.push((buffer >> bits) as u8);
+            }
+        }
+        Self::from_bytes(&bytes).ok_or_else(invalid)
+    }
+}
+
+impl TryFrom<&str> for Cid {
+    type Error = InvalidCid;
+
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        text.parse()
+    }
+}
+
+fn write_varint(bytes: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        bytes.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+fn read_varint(bytes: &mut &[u8]) -> Option<u64> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let (&byte, rest) = bytes.split_first()?;
+        *bytes = rest;
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+    None
+}
+
+/// CIDv1 of `bytes` under `codec`, hashed with SHA2-256
+fn content_cid(codec: u64, bytes: &[u8]) -> Cid {
+    Cid::new(codec, Sha256::digest(bytes).into())
+}
+
+/// CID of an event's content, ignoring its metadata
+pub fn event_cid<E: Serialize>(event: &StoredEvent<E>) -> Cid {
+    let mut value = serde_json::to_value(event).expect("stored event serializes");
+    if let serde_json::Value::Object(fields) = &mut value {
+        fields.remove("metadata");
+    }
+    let content = serde_json::to_vec(&value).expect("stored event serializes");
+    content_cid(JSON_CODEC, &content)
+}
+
+/// Record the event's CID in its metadata, keeping other keys
+pub fn attach<E: Serialize>(event: &mut StoredEvent<E>) -> Cid {
+    let cid = event_cid(event);
+    let mut metadata = match event.metadata.take() {
+        Some(serde_json::Value::Object(map)) => map,
+        _ => serde_json::Map::new(),
+    };
+    metadata.insert(CID_KEY.to_string(), cid.to_string().into());
+    event.metadata = Some(serde_json::Value::Object(metadata));
+    cid
+}
+
+/// CID recorded in an event's metadata, if it was content-addressed
+pub fn cid_of<E>(event: &StoredEvent<E>) -> Option<Cid> {
+    let cid = event.metadata.as_ref()?.get(CID_KEY)?.as_str()?;
+    Cid::try_from(cid).ok()
+}
+
+/// Whether the event's content hashes to `cid`
+pub fn matches<E: Serialize>(event: &StoredEvent<E>, cid: &Cid) -> bool {
+    event_cid(event) == *cid
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_harness;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_cid_of_empty_raw_content_matches_the_reference() {
+        // Arrange
+        const RAW_CODEC: u64 = 0x55;
+
+        // Act
+        let cid = content_cid(RAW_CODEC, b"");
+
+        // Assert
+        assert_eq!(
+            cid.to_string(),
+            "bafkreihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku"
+        );
+    }
+
+    #[test]
+    fn test_attached_cid_ignores_metadata_and_detects_changes() {
+        // Arrange
+        let mut event = test_harness::stored(1, test_harness::registered(Uuid::now_v7(), "web-01"));
+
+        // Act
+        let cid = attach(&mut event);
+        let mut changed = event.clone();
+        changed.event_type = "ResourceDecommissioned".to_string();
+
+        // Assert
+        assert_eq!(cid_of(&event), Some(cid));
+        assert_eq!(cid.codec(), JSON_CODEC);
+        assert!(matches(&event, &cid), "metadata is not addressed");
+        assert!(!matches(&changed, &cid));
+        assert_eq!(cid.to_string().parse::<Cid>(), Ok(cid));
+        assert!("bafy".parse::<Cid>().is_err());
+    }
+}
@@ -0,0 +1,201 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Partitioned Event Dispatch
+//!
+//! [`DispatchPool`] drains an [`EventSubscriber`] into a fixed number of
+//! partition workers. Events are routed by aggregate ID, so one aggregate's
+//! events always reach the same worker and are handled in stream order,
+//! while different aggregates are handled in parallel.
+//!
+//! ```text
+//!                       ┌─ queue ─> worker 0 ─> handle ─> ack
+//! EventSubscriber ──────┼─ queue ─> worker 1 ─> handle ─> ack
+//!   (aggregate_id % N)  └─ queue ─> worker N-1 ─> ...
+//! ```
+//!
+//! # Back-pressure
+//!
+//! Each worker has a bounded queue. When one is full the pool stops pulling
+//! from the consumer, and a message is acknowledged only after its handler
+//! returns, so the server stops delivering once the consumer's
+//! `max_ack_pending` is reached.
+//!
+//! # Failures
+//!
+//! A handler error stops the pool and is returned. The failed event and
+//! everything queued behind it stay unacknowledged and are redelivered to
+//! the next subscriber, so no aggregate's events are handled out of order.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::event_store::dispatch::DispatchPool;
+//!
+//! let subscriber = EventSubscriberBuilder::new(jetstream, "INFRASTRUCTURE_EVENTS")
+//!     .with_aggregate(AggregateType::Compute)
+//!     .build()
+//!     .await?;
+//! DispatchPool::new(8).run(subscriber, Arc::new(handler)).await?;
+//! ```
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tokio::task::JoinSet;
+use tracing::{error, info};
+use uuid::Uuid;
+
+use crate::errors::{InfrastructureError, InfrastructureResult};
+use crate::event_store::subscriber::{EventSubscriber, PendingEvent, ReceivedEvent};
+
+/// Handles events dispatched by a [`DispatchPool`]
+#[async_trait]
+pub trait EventHandler: Send + Sync + 'static {
+    /// Handle one event; an error stops the pool
+    async fn handle(&self, event: &ReceivedEvent) -> InfrastructureResult<()>;
+}
+
+/// Handles events of different aggregates in parallel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DispatchPool {
+    partitions: usize,
+    queue_depth: usize,
+}
+
+impl DispatchPool {
+    /// Pool with `partitions` workers (at least one) and 64 queued events
+    /// per worker
+    pub fn new(partitions: usize) -> Self {
+        Self {
+            partitions: partitions.max(1),
+            queue_depth: 64,
+        }
+    }
+
+    /// Events queued per worker before the pool stops pulling
+    pub fn with_queue_depth(mut self, queue_depth: usize) -> Self {
+        self.queue_depth = queue_depth.max(1);
+        self
+    }
+
+    /// Number of workers
+    pub fn partitions(&self) -> usize {
+        self.partitions
+    }
+
+    /// Worker handling an aggregate's events
+    pub fn partition_of(&self, aggregate_id: Uuid) -> usize {
+        (aggregate_id.as_u128() % self.partitions as u128) as usize
+    }
+
+    /// Dispatch events until the subscriber ends or a handler fails
+    pub async fn run<H: EventHandler>(
+        &self,
+        mut subscriber: EventSubscriber,
+        handler: Arc<H>,
+    ) -> InfrastructureResult<()> {
+        info!("Dispatching events to {} partition(s)", self.partitions);
+
+        let mut workers = JoinSet::new();
+        let mut queues = Vec::with_capacity(self.partitions);
+        for partition in 0..self.partitions {
+            let (sender, receiver) = mpsc::channel(self.queue_depth);
+            queues.push(sender);
+            workers.spawn(run_partition(partition, receiver, handler.clone()));
+        }
+
+        let outcome = loop {
+            let pending = tokio::select! {
+                Some(result) = workers.join_next() => break worker_outcome(result),
+                pending = subscriber.next_pending() => match pending {
+                    Some(pending) => pending,
+                    None => break Ok(()),
+                },
+            };
+            let pending = match pending {
+                Ok(pending) => pending,
+                Err(e) => break Err(e),
+            };
+
+            let queue = &queues[self.partition_of(pending.received.event.aggregate_id())];
+            // Waits while the worker's queue is full
+            if queue.send(pending).await.is_err() {
+                // The worker stopped; its outcome is collected below
+                break Ok(());
+            }
+        };
+
+        // Let the workers finish what is queued, unless one already failed
+        drop(queues);
+        if outcome.is_err() {
+            workers.abort_all();
+        }
+        let mut first_error = outcome.err();
+        while let Some(result) = workers.join_next().await {
+            if let Err(e) = worker_outcome(result) {
+                first_error.get_or_insert(e);
+            }
+        }
+
+        match first_error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Handle one partition's events in order, acknowledging each when done
+async fn run_partition<H: EventHandler>(
+    partition: usize,
+    mut queue: mpsc::Receiver<PendingEvent>,
+    handler: Arc<H>,
+) -> InfrastructureResult<()> {
+    while let Some(pending) = queue.recv().await {
+        if let Err(e) = handler.handle(&pending.received).await {
+            error!(
+                "Partition {} stopped at sequence {}: {}",
+                partition, pending.received.sequence, e
+            );
+            pending.fail(&e);
+            return Err(e);
+        }
+        pending.ack().await?;
+    }
+    Ok(())
+}
+
+/// A finished worker's result; a cancelled worker is not an error
+fn worker_outcome(
+    result: Result<InfrastructureResult<()>, tokio::task::JoinError>,
+) -> InfrastructureResult<()> {
+    match result {
+        Ok(outcome) => outcome,
+        Err(e) if e.is_cancelled() => Ok(()),
+        Err(e) => Err(InfrastructureError::Generic(format!(
+            "Dispatch worker panicked: {}",
+            e
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aggregate_stays_in_one_partition() {
+        // Arrange
+        let pool = DispatchPool::new(4);
+        let aggregates: Vec<Uuid> = (0..64).map(|_| Uuid::now_v7()).collect();
+
+        // Act
+        let partitions: Vec<usize> = aggregates.iter().map(|id| pool.partition_of(*id)).collect();
+
+        // Assert
+        for (id, partition) in aggregates.iter().zip(&partitions) {
+            assert!(*partition < 4);
+            assert_eq!(pool.partition_of(*id), *partition);
+        }
+        assert!(partitions.iter().any(|p| *p != partitions[0]));
+        assert_eq!(DispatchPool::new(0).partitions(), 1);
+    }
+}
@@ -0,0 +1,292 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Per-Aggregate Hash Chains
+//!
+//! With [`NatsEventStore::with_hash_chain`](super::NatsEventStore::with_hash_chain)
+//! every appended event is linked to the event before it in its aggregate.
+//! The link lives in the event `metadata`:
+//!
+//! ```text
+//! {"chain": {"prev_event_hash": "9f2c…", "event_hash": "41ab…"}}
+//!
+//! event_hash = SHA-256( prev_event_hash ‖ canonical JSON of the event without its link )
+//!
+//!   GENESIS ──> e1.event_hash ──> e2.event_hash ──> e3.event_hash ──> ...
+//! ```
+//!
+//! [`verify_chain`] (or
+//! [`NatsEventStore::verify_aggregate`](super::NatsEventStore::verify_aggregate))
+//! walks the events in stream order and reports every break:
+//!
+//! | Break                    | Detected when                                         |
+//! |--------------------------|-------------------------------------------------------|
+//! | [`BreakKind::Missing`]   | the sequence skips a number, or does not start at 1   |
+//! | [`BreakKind::Reordered`] | the sequence is not above the one before              |
+//! | [`BreakKind::Tampered`]  | the recomputed hash differs from the stored one, or   |
+//! |                          | `prev_event_hash` is not the hash of the event before |
+//!
+//! Events appended before chaining was enabled carry no link but are hashed
+//! all the same, so the first linked event covers them.
+//!
+//! Events are hashed as they read back. Upcasters that rewrite chained
+//! events, serialization policies that drop or redact fields, crypto
+//! shredding and [compaction](super::compaction) all change what reads
+//! back, and show up as breaks.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! let store = NatsEventStore::connect(url).await?.with_hash_chain(true);
+//! store.append(aggregate_id, events).await?;
+//!
+//! let verification = store.verify_aggregate(aggregate_id).await?;
+//! assert!(verification.is_intact(), "{:?}", verification.breaks);
+//! ```
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::audit::{hex, GENESIS_HASH};
+use crate::jetstream::StoredEvent;
+
+/// Key of the hash link inside `StoredEvent::metadata`
+const CHAIN_KEY: &str = "chain";
+
+/// Link of an event to the event before it in its aggregate
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HashLink {
+    /// Hash of the previous event ([`GENESIS_HASH`] for the first)
+    pub prev_event_hash: String,
+
+    /// Hash of this event, chained to `prev_event_hash`
+    pub event_hash: String,
+}
+
+impl HashLink {
+    /// Link `event` to `prev_event_hash`, returning the event's hash
+    ///
+    /// Attach every other metadata before linking; later changes break
+    /// the chain.
+    pub fn attach<E: Serialize>(event: &mut StoredEvent<E>, prev_event_hash: &str) -> String {
+        let event_hash = event_hash(prev_event_hash, event);
+        let mut metadata = match event.metadata.take() {
+            Some(serde_json::Value::Object(map)) => map,
+            _ => serde_json::Map::new(),
+        };
+        let link = Self {
+            prev_event_hash: prev_event_hash.to_string(),
+            event_hash: event_hash.clone(),
+        };
+        metadata.insert(
+            CHAIN_KEY.to_string(),
+            serde_json::to_value(link).expect("hash link serializes"),
+        );
+        event.metadata = Some(serde_json::Value::Object(metadata));
+        event_hash
+    }
+
+    /// Link of an event, if it was appended with chaining enabled
+    pub fn of<E>(event: &StoredEvent<E>) -> Option<Self> {
+        let link = event.metadata.as_ref()?.get(CHAIN_KEY)?;
+        serde_json::from_value(link.clone()).ok()
+    }
+}
+
+/// Hash of `event` chained to `prev_event_hash`, ignoring its own link
+pub fn event_hash<E: Serialize>(prev_event_hash: &str, event: &StoredEvent<E>) -> String {
+    let mut value = serde_json::to_value(event).expect("stored event serializes");
+    if let Some(serde_json::Value::Object(metadata)) = value.get_mut("metadata") {
+        metadata.remove(CHAIN_KEY);
+        if metadata.is_empty() {
+            value["metadata"] = serde_json::Value::Null;
+        }
+    }
+    let content = serde_json::to_vec(&value).expect("stored event serializes");
+
+    let mut hasher = Sha256::new();
+    hasher.update(prev_event_hash.as_bytes());
+    hasher.update(&content);
+    hex(&hasher.finalize())
+}
+
+/// Hash the next appended event links to
+///
+/// `events` are the complete events of one aggregate in sequence order.
+pub fn chain_head<E: Serialize>(events: &[StoredEvent<E>]) -> String {
+    events.iter().fold(GENESIS_HASH.to_string(), |head, event| {
+        match HashLink::of(event) {
+            Some(link) => link.event_hash,
+            None => event_hash(&head, event),
+        }
+    })
+}
+
+/// What is wrong at one event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum BreakKind {
+    /// Events before this one are missing; `expected` was the next sequence
+    Missing { expected: u64 },
+
+    /// Stored after an event with sequence `after`
+    Reordered { after: u64 },
+
+    /// Content or link does not match the chain
+    Tampered,
+}
+
+/// A break in an aggregate's chain
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ChainBreak {
+    /// Aggregate sequence of the event
+    pub sequence: u64,
+
+    /// Event ID of the event
+    pub event_id: Uuid,
+
+    /// What is wrong
+    #[serde(flatten)]
+    pub kind: BreakKind,
+}
+
+/// Result of walking an aggregate's chain
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ChainVerification {
+    /// Events walked
+    pub events: usize,
+
+    /// Events carrying a hash link
+    pub linked: usize,
+
+    /// Hash of the last event
+    pub head: String,
+
+    /// Every break found, in stream order
+    pub breaks: Vec<ChainBreak>,
+}
+
+impl ChainVerification {
+    /// Whether no break was found
+    pub fn is_intact(&self) -> bool {
+        self.breaks.is_empty()
+    }
+}
+
+/// Walk the events of one aggregate, in stream order
+///
+/// After a break the walk continues from the event's own hash, so one
+/// altered event is reported once rather than for every event after it.
+pub fn verify_chain<E: Serialize>(events: &[StoredEvent<E>]) -> ChainVerification {
+    let mut verification = ChainVerification {
+        events: events.len(),
+        linked: 0,
+        head: GENESIS_HASH.to_string(),
+        breaks: Vec::new(),
+    };
+    let mut last_sequence = 0;
+
+    for event in events {
+        let sequence_break = if event.sequence <= last_sequence {
+            Some(BreakKind::Reordered {
+                after: last_sequence,
+            })
+        } else if event.sequence != last_sequence + 1 {
+            Some(BreakKind::Missing {
+                expected: last_sequence + 1,
+            })
+        } else {
+            None
+        };
+
+        let (tampered, hash) = match HashLink::of(event) {
+            Some(link) => {
+                verification.linked += 1;
+                let tampered = link.prev_event_hash != verification.head
+                    || event_hash(&link.prev_event_hash, event) != link.event_hash;
+                (tampered, link.event_hash)
+            }
+            None => (false, event_hash(&verification.head, event)),
+        };
+
+        if let Some(kind) = sequence_break.or(tampered.then_some(BreakKind::Tampered)) {
+            verification.breaks.push(ChainBreak {
+                sequence: event.sequence,
+                event_id: event.event_id,
+                kind,
+            });
+        }
+        verification.head = hash;
+        last_sequence = last_sequence.max(event.sequence);
+    }
+
+    verification
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_harness;
+
+    fn chained(count: u64) -> Vec<StoredEvent<crate::events::InfrastructureEvent>> {
+        let aggregate_id = Uuid::now_v7();
+        let mut head = GENESIS_HASH.to_string();
+        (1..=count)
+            .map(|sequence| {
+                let mut event = test_harness::stored(
+                    sequence,
+                    test_harness::registered(aggregate_id, "web-01"),
+                );
+                head = HashLink::attach(&mut event, &head);
+                event
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_intact_chain_verifies_and_continues_from_its_head() {
+        // Arrange
+        let events = chained(3);
+
+        // Act
+        let verification = verify_chain(&events);
+
+        // Assert
+        assert!(verification.is_intact(), "{:?}", verification.breaks);
+        assert_eq!(verification.linked, 3);
+        assert_eq!(verification.head, chain_head(&events));
+        assert_eq!(
+            HashLink::of(&events[1]).unwrap().prev_event_hash,
+            HashLink::of(&events[0]).unwrap().event_hash
+        );
+    }
+
+    #[test]
+    fn test_missing_reordered_and_tampered_events_are_reported() {
+        // Arrange
+        let events = chained(4);
+        let missing = vec![events[0].clone(), events[2].clone(), events[3].clone()];
+        let reordered = vec![
+            events[0].clone(),
+            events[2].clone(),
+            events[1].clone(),
+            events[3].clone(),
+        ];
+        let mut tampered = events.clone();
+        tampered[1].event_type = "ResourceDecommissioned".to_string();
+
+        // Act
+        let missing = verify_chain(&missing).breaks;
+        let reordered = verify_chain(&reordered).breaks;
+        let tampered = verify_chain(&tampered).breaks;
+
+        // Assert
+        assert_eq!(missing[0].kind, BreakKind::Missing { expected: 2 });
+        assert_eq!(missing[0].sequence, 3);
+        assert!(reordered
+            .iter()
+            .any(|b| b.sequence == 2 && b.kind == BreakKind::Reordered { after: 3 }));
+        assert_eq!(tampered.len(), 1, "only the altered event: {:?}", tampered);
+        assert_eq!(tampered[0].sequence, 2);
+        assert_eq!(tampered[0].kind, BreakKind::Tampered);
+    }
+}
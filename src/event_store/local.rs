@@ -0,0 +1,371 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! File-Backed Local Event Store
+//!
+//! [`EventStore`] on an embedded sled database, for edge sites that run
+//! without NATS. Appends, reads, version checks and correlation lookups
+//! behave like [`NatsEventStore`](crate::event_store::NatsEventStore); events
+//! are later pushed upstream with [`LocalEventSync`](crate::event_store::sync::LocalEventSync).
+//!
+//! # Trees
+//!
+//! ```text
+//! events        aggregate_id ‖ sequence (BE)                  → StoredEvent JSON
+//! versions      aggregate_id                                  → current version (BE)
+//! correlation   correlation_id ‖ aggregate_id ‖ sequence (BE) → ()
+//! synced        aggregate_id                                  → last version pushed upstream
+//! ```
+//!
+//! An append writes the events, the version and the correlation index in a
+//! single transaction, so multi-event appends need no batch markers.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::event_store::{EventStore, SledEventStore};
+//!
+//! let store = SledEventStore::open("/var/lib/cim/events")?;
+//! store.append(aggregate_id, vec![event], Some(0)).await?;
+//! ```
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sled::transaction::{ConflictableTransactionError, TransactionError};
+use sled::{Transactional, Tree};
+use std::path::Path;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::errors::{InfrastructureError, InfrastructureResult};
+use crate::event_store::{envelope, EventStore};
+use crate::events::{InfrastructureEvent, UpcasterRegistry};
+use crate::jetstream::StoredEvent;
+
+/// Length of an event key (aggregate ID + sequence)
+const EVENT_KEY_LEN: usize = 24;
+
+/// Embedded, file-backed event store
+#[derive(Clone)]
+pub struct SledEventStore {
+    db: sled::Db,
+    events: Tree,
+    versions: Tree,
+    correlation: Tree,
+    synced: Tree,
+
+    /// Upcasters applied to every event read from the store
+    upcasters: Arc<UpcasterRegistry>,
+}
+
+fn storage_error(e: sled::Error) -> InfrastructureError {
+    InfrastructureError::Generic(format!("Local event store: {}", e))
+}
+
+fn event_key(aggregate_id: Uuid, sequence: u64) -> [u8; EVENT_KEY_LEN] {
+    let mut key = [0u8; EVENT_KEY_LEN];
+    key[..16].copy_from_slice(aggregate_id.as_bytes());
+    key[16..].copy_from_slice(&sequence.to_be_bytes());
+    key
+}
+
+fn decode_u64(bytes: &[u8]) -> u64 {
+    let mut buf = [0u8; 8];
+    buf.copy_from_slice(&bytes[..8]);
+    u64::from_be_bytes(buf)
+}
+
+impl SledEventStore {
+    /// Open (or create) a store at `path`
+    pub fn open(path: impl AsRef<Path>) -> InfrastructureResult<Self> {
+        let db = sled::open(path).map_err(storage_error)?;
+        Self::from_db(db)
+    }
+
+    /// Temporary store deleted on drop (tests, dry runs)
+    pub fn temporary() -> InfrastructureResult<Self> {
+        let db = sled::Config::new()
+            .temporary(true)
+            .open()
+            .map_err(storage_error)?;
+        Self::from_db(db)
+    }
+
+    fn from_db(db: sled::Db) -> InfrastructureResult<Self> {
+        let tree = |name: &str| db.open_tree(name).map_err(storage_error);
+        Ok(Self {
+            events: tree("events")?,
+            versions: tree("versions")?,
+            correlation: tree("correlation")?,
+            synced: tree("synced")?,
+            db,
+            upcasters: Arc::new(UpcasterRegistry::new()),
+        })
+    }
+
+    /// Upcast old payload versions when reading
+    pub fn with_upcasters(mut self, upcasters: UpcasterRegistry) -> Self {
+        self.upcasters = Arc::new(upcasters);
+        self
+    }
+
+    fn decode(&self, payload: &[u8]) -> InfrastructureResult<StoredEvent<InfrastructureEvent>> {
+        if self.upcasters.is_empty() {
+            return serde_json::from_slice(payload)
+                .map_err(|e| InfrastructureError::Deserialization(e.to_string()));
+        }
+
+        let raw: serde_json::Value = serde_json::from_slice(payload)
+            .map_err(|e| InfrastructureError::Deserialization(e.to_string()))?;
+        serde_json::from_value(self.upcasters.upcast_stored(raw)?)
+            .map_err(|e| InfrastructureError::Deserialization(e.to_string()))
+    }
+
+    /// Aggregates with at least one event, with their current version
+    pub fn aggregates(&self) -> InfrastructureResult<Vec<(Uuid, u64)>> {
+        self.versions
+            .iter()
+            .map(|entry| {
+                let (key, value) = entry.map_err(storage_error)?;
+                let aggregate_id = Uuid::from_slice(&key)
+                    .map_err(|e| InfrastructureError::Deserialization(e.to_string()))?;
+                Ok((aggregate_id, decode_u64(&value)))
+            })
+            .collect()
+    }
+
+    /// Last version of an aggregate pushed upstream (0 = none)
+    pub fn synced_version(&self, aggregate_id: Uuid) -> InfrastructureResult<u64> {
+        Ok(self
+            .synced
+            .get(aggregate_id.as_bytes())
+            .map_err(storage_error)?
+            .map(|v| decode_u64(&v))
+            .unwrap_or(0))
+    }
+
+    /// Record that events up to `version` were pushed upstream
+    pub fn mark_synced(&self, aggregate_id: Uuid, version: u64) -> InfrastructureResult<()> {
+        self.synced
+            .insert(aggregate_id.as_bytes(), &version.to_be_bytes()[..])
+            .map_err(storage_error)?;
+        Ok(())
+    }
+
+    /// Flush pending writes to disk
+    pub async fn flush(&self) -> InfrastructureResult<()> {
+        self.db.flush_async().await.map_err(storage_error)?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventStore for SledEventStore {
+    async fn append(
+        &self,
+        aggregate_id: Uuid,
+        events: Vec<InfrastructureEvent>,
+        expected_version: Option<u64>,
+    ) -> InfrastructureResult<u64> {
+        let result = (&self.events, &self.versions, &self.correlation).transaction(
+            |(tx_events, tx_versions, tx_correlation)| {
+                let current = tx_versions
+                    .get(aggregate_id.as_bytes())?
+                    .map(|v| decode_u64(&v))
+                    .unwrap_or(0);
+
+                if let Some(expected) = expected_version {
+                    if expected != current {
+                        return Err(ConflictableTransactionError::Abort(
+                            InfrastructureError::ConcurrencyError(format!(
+                                "Expected version {}, but current version is {}",
+                                expected, current
+                            )),
+                        ));
+                    }
+                }
+
+                let mut sequence = current;
+                for event in &events {
+                    sequence += 1;
+                    let stored = envelope(aggregate_id, sequence, event.clone());
+                    let payload = serde_json::to_vec(&stored).map_err(|e| {
+                        ConflictableTransactionError::Abort(InfrastructureError::Serialization(
+                            e.to_string(),
+                        ))
+                    })?;
+
+                    let key = event_key(aggregate_id, sequence);
+                    tx_events.insert(&key[..], payload)?;
+
+                    let mut index_key = stored.correlation_id.as_bytes().to_vec();
+                    index_key.extend_from_slice(&key);
+                    tx_correlation.insert(index_key, &b""[..])?;
+                }
+
+                tx_versions.insert(&aggregate_id.as_bytes()[..], &sequence.to_be_bytes()[..])?;
+                Ok(sequence)
+            },
+        );
+
+        let version = result.map_err(|e| match e {
+            TransactionError::Abort(e) => e,
+            TransactionError::Storage(e) => storage_error(e),
+        })?;
+
+        self.flush().await?;
+        Ok(version)
+    }
+
+    async fn read_events(
+        &self,
+        aggregate_id: Uuid,
+    ) -> InfrastructureResult<Vec<StoredEvent<InfrastructureEvent>>> {
+        self.read_events_from(aggregate_id, 1).await
+    }
+
+    async fn read_events_from(
+        &self,
+        aggregate_id: Uuid,
+        from_version: u64,
+    ) -> InfrastructureResult<Vec<StoredEvent<InfrastructureEvent>>> {
+        // Keys sort by sequence within an aggregate
+        self.events
+            .range(event_key(aggregate_id, from_version)..=event_key(aggregate_id, u64::MAX))
+            .map(|entry| {
+                let (_, payload) = entry.map_err(storage_error)?;
+                self.decode(&payload)
+            })
+            .collect()
+    }
+
+    async fn read_by_correlation(
+        &self,
+        correlation_id: Uuid,
+    ) -> InfrastructureResult<Vec<StoredEvent<InfrastructureEvent>>> {
+        let mut events = Vec::new();
+        for entry in self.correlation.scan_prefix(correlation_id.as_bytes()) {
+            let (key, _) = entry.map_err(storage_error)?;
+            if let Some(payload) = self.events.get(&key[16..]).map_err(storage_error)? {
+                events.push(self.decode(&payload)?);
+            }
+        }
+
+        // Sort by timestamp for chronological order
+        events.sort_by_key(|e| e.timestamp);
+
+        Ok(events)
+    }
+
+    async fn get_version(&self, aggregate_id: Uuid) -> InfrastructureResult<Option<u64>> {
+        Ok(self
+            .versions
+            .get(aggregate_id.as_bytes())
+            .map_err(storage_error)?
+            .map(|v| decode_u64(&v)))
+    }
+
+    async fn read_events_by_time_range(
+        &self,
+        aggregate_id: Uuid,
+        from_time: DateTime<Utc>,
+        to_time: DateTime<Utc>,
+    ) -> InfrastructureResult<Vec<StoredEvent<InfrastructureEvent>>> {
+        let events = self.read_events(aggregate_id).await?;
+
+        Ok(events
+            .into_iter()
+            .filter(|e| e.timestamp >= from_time && e.timestamp <= to_time)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Hostname, ResourceType};
+    use crate::events::compute_resource::{ComputeResourceEvent, ResourceRegistered};
+
+    fn test_timestamp() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-19T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn registered(aggregate_id: Uuid, correlation_id: Uuid) -> InfrastructureEvent {
+        InfrastructureEvent::ComputeResource(ComputeResourceEvent::ResourceRegistered(
+            ResourceRegistered {
+                event_version: 1,
+                event_id: Uuid::now_v7(),
+                aggregate_id,
+                timestamp: test_timestamp(),
+                correlation_id,
+                causation_id: None,
+                hostname: Hostname::new("edge-01").unwrap(),
+                resource_type: ResourceType::PhysicalServer,
+            },
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_append_and_read_back() {
+        // Arrange
+        let store = SledEventStore::temporary().unwrap();
+        let aggregate_id = Uuid::now_v7();
+        let correlation_id = Uuid::now_v7();
+
+        // Act
+        let version = store
+            .append(
+                aggregate_id,
+                vec![
+                    registered(aggregate_id, correlation_id),
+                    registered(aggregate_id, correlation_id),
+                ],
+                Some(0),
+            )
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(version, 2);
+        assert_eq!(store.get_version(aggregate_id).await.unwrap(), Some(2));
+        let events = store.read_events_from(aggregate_id, 2).await.unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].sequence, 2);
+        assert_eq!(
+            store
+                .read_by_correlation(correlation_id)
+                .await
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stale_expected_version_is_rejected() {
+        let store = SledEventStore::temporary().unwrap();
+        let aggregate_id = Uuid::now_v7();
+        store
+            .append(
+                aggregate_id,
+                vec![registered(aggregate_id, Uuid::now_v7())],
+                None,
+            )
+            .await
+            .unwrap();
+
+        let result = store
+            .append(
+                aggregate_id,
+                vec![registered(aggregate_id, Uuid::now_v7())],
+                Some(0),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(InfrastructureError::ConcurrencyError(_))
+        ));
+        assert_eq!(store.read_events(aggregate_id).await.unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,331 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Subscriber Middleware
+//!
+//! [`EventMiddleware`] hooks into an [`EventSubscriber`](super::EventSubscriber)
+//! around every decoded event, so logging, metrics, payload rewrites or
+//! tenant filtering do not need their own subscriber loop:
+//!
+//! ```text
+//! decode ──> before(1) ──> before(2) ──> predicates ──> caller / DispatchPool
+//!                                                            │
+//!            after(1)  <──  after(2)  <──────────────── ack or failure
+//! ```
+//!
+//! `before` hooks run in the order the middleware was added and may change
+//! the event or skip it; skipped events are acknowledged and never reach
+//! the caller. `after` hooks run in reverse order, once the event is
+//! finished, and only for middleware whose `before` hook ran:
+//!
+//! | Outcome                   | When                                             |
+//! |---------------------------|--------------------------------------------------|
+//! | [`Outcome::Acknowledged`] | the caller (or a dispatch handler) is done       |
+//! | [`Outcome::Skipped`]      | a middleware or predicate dropped the event      |
+//! | [`Outcome::Failed`]       | a dispatch handler or the acknowledgement failed |
+//!
+//! Failed events are redelivered and pass through `before` again.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::event_store::middleware::{
+//!     EventMiddleware, Flow, MetricsMiddleware, TracingMiddleware,
+//! };
+//!
+//! struct OnlyAcme;
+//!
+//! impl EventMiddleware for OnlyAcme {
+//!     fn before(&self, event: &mut ReceivedEvent) -> Flow {
+//!         Flow::continue_if(event.subject.starts_with("infrastructure.acme."))
+//!     }
+//! }
+//!
+//! let metrics = MetricsMiddleware::new();
+//! let subscriber = EventSubscriberBuilder::new(jetstream, "INFRASTRUCTURE_EVENTS")
+//!     .with_middleware(TracingMiddleware)
+//!     .with_middleware(metrics.clone())
+//!     .with_middleware(OnlyAcme)
+//!     .build()
+//!     .await?;
+//! ```
+
+use serde::Serialize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tracing::{debug, warn};
+
+use crate::errors::InfrastructureError;
+use crate::event_store::subscriber::ReceivedEvent;
+
+/// What a `before` hook decided
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Flow {
+    /// Pass the event on
+    Continue,
+
+    /// Acknowledge the event and drop it
+    Skip,
+}
+
+impl Flow {
+    /// [`Flow::Continue`] when `keep` holds, [`Flow::Skip`] otherwise
+    pub fn continue_if(keep: bool) -> Self {
+        if keep {
+            Flow::Continue
+        } else {
+            Flow::Skip
+        }
+    }
+}
+
+/// How a delivered event finished
+#[derive(Debug, Clone, Copy)]
+pub enum Outcome<'a> {
+    /// Handled and acknowledged
+    Acknowledged,
+
+    /// Dropped by a middleware or predicate, and acknowledged
+    Skipped,
+
+    /// Handling or acknowledging failed; the server redelivers the event
+    Failed(&'a InfrastructureError),
+}
+
+/// Hooks around every event a subscriber delivers
+pub trait EventMiddleware: Send + Sync + 'static {
+    /// Called once the event is decoded, before the predicates
+    fn before(&self, _event: &mut ReceivedEvent) -> Flow {
+        Flow::Continue
+    }
+
+    /// Called once the event is finished
+    fn after(&self, _event: &ReceivedEvent, _outcome: Outcome<'_>) {}
+}
+
+/// Middleware of one subscriber, in the order it was added
+#[derive(Clone, Default)]
+pub(crate) struct MiddlewareChain {
+    layers: Vec<Arc<dyn EventMiddleware>>,
+}
+
+impl MiddlewareChain {
+    pub(crate) fn push(&mut self, middleware: Arc<dyn EventMiddleware>) {
+        self.layers.push(middleware);
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Run the `before` hooks; on a skip, the number of hooks that ran
+    pub(crate) fn before(&self, event: &mut ReceivedEvent) -> Result<(), usize> {
+        for (index, layer) in self.layers.iter().enumerate() {
+            if layer.before(event) == Flow::Skip {
+                return Err(index + 1);
+            }
+        }
+        Ok(())
+    }
+
+    /// Run the `after` hooks of the first `ran` middleware, last first
+    pub(crate) fn after(&self, ran: usize, event: &ReceivedEvent, outcome: Outcome<'_>) {
+        for layer in self.layers[..ran.min(self.layers.len())].iter().rev() {
+            layer.after(event, outcome);
+        }
+    }
+}
+
+/// Logs every event at debug level, and failures as warnings
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TracingMiddleware;
+
+impl EventMiddleware for TracingMiddleware {
+    fn before(&self, event: &mut ReceivedEvent) -> Flow {
+        debug!(
+            "Received {} #{} on {}",
+            event.event.event_type_name(),
+            event.sequence,
+            event.subject
+        );
+        Flow::Continue
+    }
+
+    fn after(&self, event: &ReceivedEvent, outcome: Outcome<'_>) {
+        match outcome {
+            Outcome::Acknowledged => debug!("Handled #{} on {}", event.sequence, event.subject),
+            Outcome::Skipped => debug!("Skipped #{} on {}", event.sequence, event.subject),
+            Outcome::Failed(e) => warn!(
+                "Event #{} on {} failed: {}",
+                event.sequence, event.subject, e
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Counters {
+    received: AtomicU64,
+    acknowledged: AtomicU64,
+    skipped: AtomicU64,
+    failed: AtomicU64,
+    last_sequence: AtomicU64,
+}
+
+/// Subscriber counters at one point in time
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct SubscriberMetrics {
+    /// Events seen, including redeliveries
+    pub received: u64,
+
+    /// Events handled and acknowledged
+    pub acknowledged: u64,
+
+    /// Events dropped by later middleware or predicates
+    pub skipped: u64,
+
+    /// Events whose handling or acknowledgement failed
+    pub failed: u64,
+
+    /// Stream sequence of the last acknowledged event
+    pub last_sequence: u64,
+}
+
+/// Counts what the subscriber delivers
+///
+/// Cloning is cheap; all clones share the counters, so keep one to read
+/// them while the subscriber owns another.
+#[derive(Debug, Clone, Default)]
+pub struct MetricsMiddleware {
+    counters: Arc<Counters>,
+}
+
+impl MetricsMiddleware {
+    /// Zeroed counters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Current values
+    pub fn snapshot(&self) -> SubscriberMetrics {
+        SubscriberMetrics {
+            received: self.counters.received.load(Ordering::Relaxed),
+            acknowledged: self.counters.acknowledged.load(Ordering::Relaxed),
+            skipped: self.counters.skipped.load(Ordering::Relaxed),
+            failed: self.counters.failed.load(Ordering::Relaxed),
+            last_sequence: self.counters.last_sequence.load(Ordering::Relaxed),
+        }
+    }
+}
+
+impl EventMiddleware for MetricsMiddleware {
+    fn before(&self, _event: &mut ReceivedEvent) -> Flow {
+        self.counters.received.fetch_add(1, Ordering::Relaxed);
+        Flow::Continue
+    }
+
+    fn after(&self, event: &ReceivedEvent, outcome: Outcome<'_>) {
+        let counter = match outcome {
+            Outcome::Acknowledged => {
+                self.counters
+                    .last_sequence
+                    .fetch_max(event.sequence, Ordering::Relaxed);
+                &self.counters.acknowledged
+            }
+            Outcome::Skipped => &self.counters.skipped,
+            Outcome::Failed(_) => &self.counters.failed,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_harness;
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    fn received(subject: &str) -> ReceivedEvent {
+        ReceivedEvent {
+            sequence: 7,
+            subject: subject.to_string(),
+            event: test_harness::registered(Uuid::now_v7(), "web-01"),
+            envelope: None,
+        }
+    }
+
+    /// Records its hook calls and skips subjects containing `skip`
+    struct Recorder {
+        name: &'static str,
+        calls: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl EventMiddleware for Recorder {
+        fn before(&self, event: &mut ReceivedEvent) -> Flow {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("{}.before", self.name));
+            event.subject.push_str(&format!(".{}", self.name));
+            Flow::continue_if(!event.subject.contains("skip"))
+        }
+
+        fn after(&self, _event: &ReceivedEvent, outcome: Outcome<'_>) {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("{}.after({:?})", self.name, outcome));
+        }
+    }
+
+    #[test]
+    fn test_hooks_run_in_onion_order_and_stop_at_a_skip() {
+        // Arrange
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let metrics = MetricsMiddleware::new();
+        let mut chain = MiddlewareChain::default();
+        chain.push(Arc::new(metrics.clone()));
+        chain.push(Arc::new(Recorder {
+            name: "a",
+            calls: calls.clone(),
+        }));
+        chain.push(Arc::new(Recorder {
+            name: "b",
+            calls: calls.clone(),
+        }));
+        let mut kept = received("infrastructure.compute.x.registered");
+        let mut skipped = received("infrastructure.compute.skip.registered");
+
+        // Act
+        let kept_flow = chain.before(&mut kept);
+        chain.after(chain.len(), &kept, Outcome::Acknowledged);
+        let skipped_flow = chain.before(&mut skipped);
+        let ran = skipped_flow.unwrap_err();
+        chain.after(ran, &skipped, Outcome::Skipped);
+
+        // Assert
+        assert_eq!(kept_flow, Ok(()));
+        assert_eq!(kept.subject, "infrastructure.compute.x.registered.a.b");
+        assert_eq!(ran, 2, "metrics and a ran before a skipped");
+        assert_eq!(
+            *calls.lock().unwrap(),
+            [
+                "a.before",
+                "b.before",
+                "b.after(Acknowledged)",
+                "a.after(Acknowledged)",
+                "a.before",
+                "a.after(Skipped)",
+            ]
+        );
+        assert_eq!(
+            metrics.snapshot(),
+            SubscriberMetrics {
+                received: 2,
+                acknowledged: 1,
+                skipped: 1,
+                failed: 0,
+                last_sequence: 7,
+            }
+        );
+    }
+}
@@ -50,9 +50,36 @@ use crate::errors::InfrastructureResult;
 use crate::events::InfrastructureEvent;
 use crate::jetstream::StoredEvent;
 
+pub mod backup;
+pub mod batch;
+pub mod cloudevents;
+pub mod codec;
+pub mod compaction;
+#[cfg(feature = "content-addressing")]
+pub mod content;
+pub mod dispatch;
+pub mod integrity;
+#[cfg(feature = "local-store")]
+pub mod local;
+pub mod middleware;
 pub mod nats;
+pub mod offload;
+pub mod provenance;
+pub mod sampling;
+pub mod snapshot;
+#[cfg(feature = "field-encryption")]
+pub mod shredding;
+pub mod subscriber;
+#[cfg(feature = "local-store")]
+pub mod sync;
 
+#[cfg(feature = "local-store")]
+pub use local::SledEventStore;
+pub use dispatch::{DispatchPool, EventHandler};
+pub use middleware::EventMiddleware;
 pub use nats::NatsEventStore;
+pub use provenance::{Actor, ActorKind, EventEnvelopeMetadata};
+pub use subscriber::{EventFilter, EventSubscriber, EventSubscriberBuilder, Partition};
 
 /// Event Store trait for persisting and retrieving domain events
 ///
@@ -68,7 +95,9 @@ pub trait EventStore: Send + Sync {
     /// Append events to an aggregate's event stream
     ///
     /// Events are written atomically - either all succeed or all fail.
-    /// The expected_version provides optimistic concurrency control.
+    /// The expected_version is checked once for the whole batch, and the
+    /// events receive consecutive sequence numbers. Readers never observe
+    /// part of a batch.
     ///
     /// # Arguments
     ///
@@ -177,6 +206,25 @@ pub trait EventStore: Send + Sync {
     ) -> InfrastructureResult<Vec<StoredEvent<InfrastructureEvent>>>;
 }
 
+/// Wrap an event in the stored envelope written by every backend
+pub(crate) fn envelope(
+    aggregate_id: Uuid,
+    sequence: u64,
+    event: InfrastructureEvent,
+) -> StoredEvent<InfrastructureEvent> {
+    StoredEvent {
+        event_id: event.event_id(),
+        aggregate_id,
+        sequence,
+        timestamp: event.timestamp(),
+        correlation_id: event.correlation_id(),
+        causation_id: event.causation_id().unwrap_or(event.aggregate_id()),
+        event_type: event.event_type_name().to_string(),
+        data: event,
+        metadata: None,
+    }
+}
+
 /// Event metadata for correlation and causation tracking
 #[derive(Debug, Clone)]
 pub struct EventMetadata {
@@ -210,6 +258,34 @@ impl EventMetadata {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::{Hostname, ResourceType};
+    use crate::events::compute_resource::{ComputeResourceEvent, ResourceRegistered};
+
+    #[test]
+    fn test_envelope_keeps_the_event_id() {
+        // Arrange
+        let aggregate_id = Uuid::now_v7();
+        let event_id = Uuid::now_v7();
+        let event = InfrastructureEvent::ComputeResource(ComputeResourceEvent::ResourceRegistered(
+            ResourceRegistered {
+                event_version: 1,
+                event_id,
+                aggregate_id,
+                timestamp: Utc::now(),
+                correlation_id: Uuid::now_v7(),
+                causation_id: None,
+                hostname: Hostname::new("web-01").unwrap(),
+                resource_type: ResourceType::PhysicalServer,
+            },
+        ));
+
+        // Act
+        let stored = envelope(aggregate_id, 1, event);
+
+        // Assert
+        assert_eq!(stored.event_id, event_id);
+        assert_eq!(stored.aggregate_id, aggregate_id);
+    }
 
     #[test]
     fn test_event_metadata_creation() {
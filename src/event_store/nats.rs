@@ -3,19 +3,156 @@
 //!
 //! This module implements the EventStore trait using NATS JetStream as the
 //! persistent storage backend, providing durable event streaming with replay.
+//!
+//! # Subjects
+//!
+//! ```text
+//! infrastructure.compute.<aggregate_id>.<operation>    canonical event
+//! infrastructure.network.<aggregate_id>.<operation>    canonical event (networks)
+//! infrastructure.interface.<aggregate_id>.<operation>  canonical event (interfaces, bonds)
+//! infrastructure.ip_pool.<aggregate_id>.<operation>    canonical event (IP pools)
+//! infrastructure.connection.<aggregate_id>.<operation> canonical event (cables)
+//! infrastructure.correlation.<correlation_id>          correlation index copy
+//! infrastructure.change.<change key>                   change request index copy
+//! infrastructure.cid.<cid>                             content address index copy
+//! ```
+//!
+//! A store for a single organization
+//! ([`JetStreamConfig::for_tenant`]) uses the same layout under
+//! `infrastructure.<org_id>`.
+//!
+//! The correlation index lets [`EventStore::read_by_correlation`] fetch a
+//! causation chain with a single filtered consumer instead of replaying the
+//! entire stream.
+//!
+//! Events appended with [`NatsEventStore::append_with_change_ref`] are also
+//! copied to the change index, keyed by [`ChangeRef::index_key`], for
+//! [`NatsEventStore::read_by_change_ref`].
+//!
+//! Index copies are written after the events themselves, so an append
+//! whose events are stored succeeds even if a copy cannot be written. The
+//! failure is logged and counted on [`NatsEventStore::index_failures`];
+//! the events stay readable from their aggregate, and
+//! [`NatsEventStore::scan_by_correlation`] finds correlated events the
+//! index missed.
+//!
+//! Events appended with [`NatsEventStore::append_with_context`] carry an
+//! [`EventEnvelopeMetadata`] (actor, source, schema) in their metadata.
+//!
+//! Multi-event appends are all-or-nothing for readers of this store; see
+//! [`batch`](crate::event_store::batch).
+//!
+//! # Concurrency
+//!
+//! Appends with an expected version are enforced by JetStream itself, not
+//! by comparing versions client-side and hoping nobody writes in between.
+//! Each message is published with
+//!
+//! ```text
+//! Nats-Msg-Id                                  <event_id>
+//! Nats-Expected-Last-Subject-Sequence          <stream sequence of the aggregate's last message>
+//! Nats-Expected-Last-Subject-Sequence-Subject  infrastructure.*.<aggregate_id>.>
+//! ```
+//!
+//! so the server rejects the write if any message was stored for the
+//! aggregate since it was read, and drops publishes of an event it already
+//! holds (within the stream's duplicate window); an append whose message
+//! was dropped that way fails instead of reporting success. Messages of a
+//! batch are chained, each expecting the one before it. Checking the whole
+//! aggregate subject tree needs NATS server 2.11 or later.
+//!
+//! # Unknown Fields
+//!
+//! Following the [`SerializationPolicy`]'s unknown-field treatment, appends
+//! refuse payloads that would not decode back completely, while reads
+//! accept fields written by newer versions and count them on
+//! [`NatsEventStore::ignored_fields`].
+//!
+//! # Crypto-Shredding
+//!
+//! With [`NatsEventStore::with_crypto_shredding`] protected fields are
+//! encrypted with a key of their own aggregate, and
+//! [`NatsEventStore::forget_aggregate`] makes them unreadable for good; see
+//! [`shredding`](crate::event_store::shredding).
+//!
+//! # CloudEvents
+//!
+//! With [`NatsEventStore::with_cloud_events`] every published message
+//! carries CloudEvents 1.0 attributes, as `ce-` headers or a structured
+//! envelope; see [`cloudevents`](crate::event_store::cloudevents).
+//!
+//! # Payload Codecs
+//!
+//! Payloads are JSON unless [`JetStreamConfig::codec`] selects CBOR or
+//! protobuf. Each message carries its codec's `Content-Type` header and
+//! reads decode by that header, so events written with an earlier codec
+//! stay readable; see [`codec`](crate::event_store::codec).
+//!
+//! # Hash Chains
+//!
+//! With [`NatsEventStore::with_hash_chain`] every appended event is linked
+//! to the one before it in its aggregate, and
+//! [`NatsEventStore::verify_aggregate`] detects missing, reordered or
+//! tampered events; see [`integrity`](crate::event_store::integrity).
+//!
+//! # Content Addressing
+//!
+//! With the `content-addressing` feature and
+//! [`NatsEventStore::with_content_addressing`] every appended event carries
+//! a CID in its metadata and a `Cim-Event-Cid` header, and is copied to the
+//! CID index read by [`NatsEventStore::read_event_by_cid`]; see
+//! [`content`](crate::event_store::content).
+//!
+//! # Large Payloads
+//!
+//! With [`NatsEventStore::with_payload_offload`] payloads above a
+//! threshold go to an object store and messages carry a reference that
+//! reads resolve; see [`offload`](crate::event_store::offload).
+//!
+//! # Retention
+//!
+//! Stream limits (max age, bytes, messages per subject) are set on
+//! [`JetStreamConfig`]. Old events of aggregate types that do not need
+//! their full history can be snapshotted and purged with
+//! [`compaction`](crate::event_store::compaction).
 
+use async_nats::jetstream::context::{Publish, PublishError, PublishErrorKind};
+use async_nats::jetstream::publish::PublishAck;
 use async_nats::jetstream::{self, stream::Stream};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use futures::StreamExt;
 use serde_json;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tracing::{debug, warn};
 use uuid::Uuid;
 
+use crate::change_control::{attach_change_ref, ChangeRef};
 use crate::errors::{InfrastructureError, InfrastructureResult};
-use crate::event_store::EventStore;
-use crate::events::InfrastructureEvent;
+use crate::event_store::batch::{batch_start, drop_incomplete_batches, BatchMarker};
+use crate::event_store::cloudevents::{unwrap_structured, CloudEventsConfig};
+use crate::event_store::codec::{
+    codec_for_content_type, message_content_type, EventCodec, JsonCodec,
+};
+#[cfg(feature = "content-addressing")]
+use crate::event_store::content::{self, Cid, CID_HEADER};
+use crate::event_store::integrity::{self, ChainVerification, HashLink};
+use crate::event_store::offload::{resolve_payload, PayloadOffload};
+use crate::event_store::provenance::EventEnvelopeMetadata;
+#[cfg(feature = "field-encryption")]
+use crate::event_store::shredding::{self, DataKeyStore};
+use crate::event_store::{envelope, EventStore};
+use crate::events::serialization::{
+    decode_payload, FieldCipher, IgnoredFieldReport, SerializationPolicy, UnknownFields,
+};
+use crate::events::{InfrastructureEvent, UpcasterRegistry};
 use crate::jetstream::{create_infrastructure_stream, JetStreamConfig, StoredEvent};
-use crate::subjects::AggregateType;
+use crate::subjects::{AggregateType, Operation};
+
+/// Header naming the subject filter `Nats-Expected-Last-Subject-Sequence`
+/// applies to
+const EXPECTED_LAST_SUBJECT_SEQUENCE_SUBJECT: &str = "Nats-Expected-Last-Subject-Sequence-Subject";
 
 /// NATS JetStream-backed event store
 ///
@@ -32,7 +169,7 @@ use crate::subjects::AggregateType;
 ///
 /// #[tokio::main]
 /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-///     let store = NatsEventStore::connect("nats://10.0.20.1:4222").await?;
+///     let store = NatsEventStore::connect("nats://localhost:4222").await?;
 ///     // Use store...
 ///     Ok(())
 /// }
@@ -46,6 +183,45 @@ pub struct NatsEventStore {
 
     /// Base subject prefix (e.g., "infrastructure")
     subject_prefix: String,
+
+    /// Whether appends also write to the correlation index subject
+    index_correlation: bool,
+
+    /// Upcasters applied to every event read from the stream
+    upcasters: Arc<UpcasterRegistry>,
+
+    /// Field-level transformations applied when writing events
+    serialization: SerializationPolicy,
+
+    /// Cipher for encrypted fields (None = cannot read or write them)
+    cipher: Option<Arc<dyn FieldCipher>>,
+
+    /// Per-aggregate keys for encrypted fields, replacing `cipher`
+    #[cfg(feature = "field-encryption")]
+    data_keys: Option<Arc<dyn DataKeyStore>>,
+
+    /// Fields dropped while reading events
+    ignored_fields: IgnoredFieldReport,
+
+    /// Index copies that could not be written
+    index_failures: Arc<AtomicU64>,
+
+    /// CloudEvents envelope for published events (None = plain payloads)
+    cloud_events: Option<CloudEventsConfig>,
+
+    /// Wire format of published payloads
+    codec: Arc<dyn EventCodec>,
+
+    /// Whether appended events are hash-chained per aggregate
+    hash_chain: bool,
+
+    /// Object store for payloads too large for a message (None = never
+    /// offload)
+    payloads: Option<PayloadOffload>,
+
+    /// Whether appended events get a CID and a CID index copy
+    #[cfg(feature = "content-addressing")]
+    content_addressing: bool,
 }
 
 impl NatsEventStore {
@@ -75,10 +251,28 @@ impl NatsEventStore {
             jetstream,
             stream,
             subject_prefix: "infrastructure".to_string(),
+            index_correlation: true,
+            upcasters: Arc::new(UpcasterRegistry::new()),
+            serialization: SerializationPolicy::default(),
+            cipher: None,
+            #[cfg(feature = "field-encryption")]
+            data_keys: None,
+            ignored_fields: IgnoredFieldReport::new(),
+            index_failures: Arc::new(AtomicU64::new(0)),
+            cloud_events: None,
+            codec: Arc::new(JsonCodec),
+            hash_chain: false,
+            payloads: None,
+            #[cfg(feature = "content-addressing")]
+            content_addressing: false,
         })
     }
 
     /// Connect with custom configuration
+    ///
+    /// With a [tenant](JetStreamConfig::for_tenant) configuration, events
+    /// are published under `infrastructure.<org_id>` and only that
+    /// organization's stream is read.
     pub async fn connect_with_config(
         nats_url: &str,
         config: JetStreamConfig,
@@ -88,128 +282,309 @@ impl NatsEventStore {
             .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?;
 
         let jetstream = jetstream::new(client);
+        let subject_prefix = config.subject_prefix();
+        let codec = config.codec.codec()?;
         let stream = create_infrastructure_stream(jetstream.clone(), config).await?;
 
         Ok(Self {
             jetstream,
             stream,
-            subject_prefix: "infrastructure".to_string(),
+            subject_prefix,
+            index_correlation: true,
+            upcasters: Arc::new(UpcasterRegistry::new()),
+            serialization: SerializationPolicy::default(),
+            cipher: None,
+            #[cfg(feature = "field-encryption")]
+            data_keys: None,
+            ignored_fields: IgnoredFieldReport::new(),
+            index_failures: Arc::new(AtomicU64::new(0)),
+            cloud_events: None,
+            codec,
+            hash_chain: false,
+            payloads: None,
+            #[cfg(feature = "content-addressing")]
+            content_addressing: false,
         })
     }
 
     /// Build subject for an aggregate event
     ///
-    /// Format: infrastructure.compute.<aggregate_id>.<event_type>
-    fn build_subject(&self, aggregate_id: Uuid, event_type: &str) -> String {
+    /// Format: infrastructure.<aggregate_type>.<aggregate_id>.<operation>
+    fn build_subject(
+        &self,
+        aggregate_type: AggregateType,
+        aggregate_id: Uuid,
+        operation: Operation,
+    ) -> String {
         format!(
             "{}.{}.{}.{}",
-            self.subject_prefix,
-            AggregateType::Compute,
-            aggregate_id,
-            event_type.to_lowercase()
+            self.subject_prefix, aggregate_type, aggregate_id, operation
         )
     }
 
-    /// Get stream subject filter for an aggregate
+    /// Enable or disable per-aggregate hash chaining (disabled by default)
     ///
-    /// Format: infrastructure.compute.<aggregate_id>.>
-    fn aggregate_subject_filter(&self, aggregate_id: Uuid) -> String {
-        format!(
-            "{}.{}.{}.>",
-            self.subject_prefix,
-            AggregateType::Compute,
-            aggregate_id
-        )
+    /// Appended events carry a [`HashLink`] to the event before them, which
+    /// [`NatsEventStore::verify_aggregate`] checks; see
+    /// [`integrity`](crate::event_store::integrity).
+    pub fn with_hash_chain(mut self, enabled: bool) -> Self {
+        self.hash_chain = enabled;
+        self
     }
-}
 
-#[async_trait]
-impl EventStore for NatsEventStore {
-    async fn append(
+    /// Enable or disable content addressing (disabled by default)
+    ///
+    /// Appended events get a CID, recorded in their metadata and a
+    /// [`CID_HEADER`](content::CID_HEADER) header, and are indexed for
+    /// [`NatsEventStore::read_event_by_cid`].
+    #[cfg(feature = "content-addressing")]
+    pub fn with_content_addressing(mut self, enabled: bool) -> Self {
+        self.content_addressing = enabled;
+        self
+    }
+
+    /// Offload large payloads to an object store
+    ///
+    /// Payloads above the offload threshold are stored out of band and
+    /// resolved again on read; see [`offload`](crate::event_store::offload).
+    pub fn with_payload_offload(mut self, offload: PayloadOffload) -> Self {
+        self.payloads = Some(offload);
+        self
+    }
+
+    /// Enable or disable the correlation index (enabled by default)
+    ///
+    /// When disabled, [`EventStore::read_by_correlation`] only finds events
+    /// appended while the index was enabled; use
+    /// [`NatsEventStore::scan_by_correlation`] for older history.
+    pub fn with_correlation_index(mut self, enabled: bool) -> Self {
+        self.index_correlation = enabled;
+        self
+    }
+
+    /// Publish events as CloudEvents in the configured content mode
+    ///
+    /// Reads accept both plain and structured payloads, whatever this
+    /// setting.
+    pub fn with_cloud_events(mut self, config: CloudEventsConfig) -> Self {
+        self.cloud_events = Some(config);
+        self
+    }
+
+    /// Upgrade old event versions on read using the given registry
+    pub fn with_upcasters(mut self, upcasters: UpcasterRegistry) -> Self {
+        self.upcasters = Arc::new(upcasters);
+        self
+    }
+
+    /// Apply a serialization policy (e.g. field encryption)
+    ///
+    /// Writers need a cipher whenever the policy encrypts fields. Readers
+    /// without a cipher cannot decode events containing encrypted fields.
+    pub fn with_serialization_policy(
+        mut self,
+        policy: SerializationPolicy,
+        cipher: Option<Arc<dyn FieldCipher>>,
+    ) -> Self {
+        self.serialization = policy;
+        self.cipher = cipher;
+        self
+    }
+
+    /// Encrypt the fields `policy` protects with a key per aggregate
+    ///
+    /// Keys are created on an aggregate's first protected event. Events of
+    /// aggregates whose key was destroyed with
+    /// [`NatsEventStore::forget_aggregate`] still read, with their protected
+    /// fields replaced by [`shredding::SHREDDED_VALUE`].
+    #[cfg(feature = "field-encryption")]
+    pub fn with_crypto_shredding(
+        mut self,
+        policy: SerializationPolicy,
+        keys: Arc<dyn DataKeyStore>,
+    ) -> Self {
+        self.serialization = policy;
+        self.cipher = None;
+        self.data_keys = Some(keys);
+        self
+    }
+
+    /// Destroy the data key of an aggregate
+    ///
+    /// The aggregate's events stay in the stream, but the fields that were
+    /// encrypted with its key can no longer be read, and new events with
+    /// protected fields are rejected. This cannot be undone.
+    #[cfg(feature = "field-encryption")]
+    pub async fn forget_aggregate(&self, aggregate_id: Uuid) -> InfrastructureResult<()> {
+        let keys = self.data_keys.as_ref().ok_or_else(|| {
+            InfrastructureError::Configuration("Crypto-shredding is not enabled".to_string())
+        })?;
+        keys.forget(aggregate_id).await
+    }
+
+    /// Cipher for the protected fields of events appended to `aggregate_id`
+    #[cfg(feature = "field-encryption")]
+    async fn write_cipher(
         &self,
         aggregate_id: Uuid,
-        events: Vec<InfrastructureEvent>,
-        expected_version: Option<u64>,
-    ) -> InfrastructureResult<u64> {
-        // Get current version for concurrency check
-        let current_version = self.get_version(aggregate_id).await?;
-
-        // Verify expected version matches
-        if let Some(expected) = expected_version {
-            match current_version {
-                Some(current) if current != expected => {
-                    return Err(InfrastructureError::ConcurrencyError(format!(
-                        "Expected version {}, but current version is {}",
-                        expected, current
-                    )));
-                }
-                None if expected != 0 => {
-                    return Err(InfrastructureError::ConcurrencyError(format!(
-                        "Expected version {}, but aggregate has no events",
-                        expected
-                    )));
-                }
-                _ => {}
-            }
+        events: &[InfrastructureEvent],
+    ) -> InfrastructureResult<Option<Arc<dyn FieldCipher>>> {
+        let Some(keys) = &self.data_keys else {
+            return Ok(self.cipher.clone());
+        };
+        if !events
+            .iter()
+            .any(|event| self.serialization.encrypts(event.event_type_name()))
+        {
+            return Ok(None);
         }
 
-        let mut next_sequence = current_version.map(|v| v + 1).unwrap_or(1);
+        let cipher: Arc<dyn FieldCipher> =
+            Arc::new(shredding::write_cipher(keys.as_ref(), aggregate_id).await?);
+        Ok(Some(cipher))
+    }
+
+    /// Cipher for the protected fields of events appended to `aggregate_id`
+    #[cfg(not(feature = "field-encryption"))]
+    async fn write_cipher(
+        &self,
+        _aggregate_id: Uuid,
+        _events: &[InfrastructureEvent],
+    ) -> InfrastructureResult<Option<Arc<dyn FieldCipher>>> {
+        Ok(self.cipher.clone())
+    }
 
-        // Append each event
-        for event in events {
-            let event_type = event.event_type_name();
-            let subject = self.build_subject(aggregate_id, event_type);
+    /// Decrypt the encrypted fields of a serialized stored event
+    async fn reveal(&self, raw: &mut serde_json::Value) -> InfrastructureResult<()> {
+        #[cfg(feature = "field-encryption")]
+        if let Some(keys) = &self.data_keys {
+            return shredding::reveal_or_shred(&self.serialization, keys.as_ref(), raw).await;
+        }
 
-            // Wrap in StoredEvent envelope
-            let stored_event = StoredEvent {
-                event_id: event.aggregate_id(), // Use event's ID
-                aggregate_id,
-                sequence: next_sequence,
-                timestamp: event.timestamp(),
-                correlation_id: event.correlation_id(),
-                causation_id: event.causation_id().unwrap_or(event.aggregate_id()),
-                event_type: event_type.to_string(),
-                data: event,
-                metadata: None,
-            };
+        if let Some(cipher) = &self.cipher {
+            self.serialization.reveal(raw, cipher.as_ref())?;
+        }
+        Ok(())
+    }
 
-            // Serialize to JSON
-            let payload = serde_json::to_vec(&stored_event)
-                .map_err(|e| InfrastructureError::Serialization(e.to_string()))?;
+    /// Fields dropped while reading events, counted per event type
+    ///
+    /// Cloning the report shares it; it keeps counting as the store reads.
+    pub fn ignored_fields(&self) -> IgnoredFieldReport {
+        self.ignored_fields.clone()
+    }
 
-            // Publish to JetStream
-            self.jetstream
-                .publish(subject, payload.into())
-                .await
-                .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?
-                .await
-                .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?;
+    /// Index copies that could not be written since the store was created
+    pub fn index_failures(&self) -> u64 {
+        self.index_failures.load(Ordering::Relaxed)
+    }
 
-            next_sequence += 1;
+    /// Serialize a stored event, encrypting protected fields
+    ///
+    /// With [`UnknownFields::Deny`] for writes, the payload must decode back
+    /// without dropping any field. The codec writes the result to the wire.
+    fn encode_stored_event(
+        &self,
+        stored_event: &StoredEvent<InfrastructureEvent>,
+        cipher: Option<&dyn FieldCipher>,
+    ) -> InfrastructureResult<serde_json::Value> {
+        let mut value = serde_json::to_value(stored_event)
+            .map_err(|e| InfrastructureError::Serialization(e.to_string()))?;
+        if self.serialization.unknown_fields().write == UnknownFields::Deny {
+            decode_payload::<StoredEvent<InfrastructureEvent>>(&value, UnknownFields::Deny)?;
         }
+        self.serialization.protect(&mut value, cipher)?;
 
-        Ok(next_sequence - 1)
+        Ok(value)
     }
 
-    async fn read_events(
+    /// Deserialize a stored event, decrypting protected fields and
+    /// upcasting old payload versions
+    ///
+    /// Fields the upcasted payload carries beyond the typed event are
+    /// recorded on the ignored-field report, or fail the read with
+    /// [`UnknownFields::Deny`].
+    async fn decode_stored_event(
         &self,
-        aggregate_id: Uuid,
-    ) -> InfrastructureResult<Vec<StoredEvent<InfrastructureEvent>>> {
-        self.read_events_from(aggregate_id, 1).await
+        payload: &[u8],
+        content_type: Option<&str>,
+    ) -> InfrastructureResult<StoredEvent<InfrastructureEvent>> {
+        let raw = codec_for_content_type(content_type)?.decode(payload)?;
+        let mut raw = unwrap_structured(raw);
+        self.reveal(&mut raw).await?;
+        let upcasted = if self.upcasters.is_empty() {
+            raw
+        } else {
+            self.upcasters.upcast_stored(raw)?
+        };
+
+        let (stored_event, ignored) =
+            decode_payload(&upcasted, self.serialization.unknown_fields().read)?;
+        if let Some(ignored) = ignored {
+            debug!("Ignored unknown fields in {}", ignored);
+            self.ignored_fields.record(&ignored);
+        }
+        Ok(stored_event)
     }
 
-    async fn read_events_from(
+    /// Build the correlation index subject
+    ///
+    /// Format: infrastructure.correlation.<correlation_id>
+    fn correlation_subject(&self, correlation_id: Uuid) -> String {
+        format!("{}.correlation.{}", self.subject_prefix, correlation_id)
+    }
+
+    /// Build the CID index subject
+    ///
+    /// Format: infrastructure.cid.<cid>
+    #[cfg(feature = "content-addressing")]
+    fn cid_subject(&self, cid: &str) -> String {
+        format!("{}.cid.{}", self.subject_prefix, cid)
+    }
+
+    /// Build the change index subject
+    ///
+    /// Format: infrastructure.change.<change_ref index key>
+    fn change_subject(&self, change_ref: &ChangeRef) -> String {
+        format!("{}.change.{}", self.subject_prefix, change_ref.index_key())
+    }
+
+    /// Get stream subject filter for an aggregate
+    ///
+    /// Format: infrastructure.*.<aggregate_id>.>
+    ///
+    /// Aggregate IDs are unique across aggregate types, so the type token
+    /// is wildcarded.
+    fn aggregate_subject_filter(&self, aggregate_id: Uuid) -> String {
+        format!("{}.*.{}.>", self.subject_prefix, aggregate_id)
+    }
+
+    /// Read every stored event matching a subject filter
+    async fn fetch_stored_events(
         &self,
-        aggregate_id: Uuid,
-        from_version: u64,
+        filter_subject: String,
     ) -> InfrastructureResult<Vec<StoredEvent<InfrastructureEvent>>> {
-        // Create consumer for this aggregate
-        let filter_subject = self.aggregate_subject_filter(aggregate_id);
+        Ok(self
+            .fetch_sequenced_events(filter_subject)
+            .await?
+            .into_iter()
+            .map(|(_, event)| event)
+            .collect())
+    }
 
+    /// Read every stored event matching a subject filter, with its stream
+    /// sequence
+    ///
+    /// Uses an ephemeral pull consumer and fetches in bounded batches until
+    /// the stream reports no more messages.
+    async fn fetch_sequenced_events(
+        &self,
+        filter_subject: String,
+    ) -> InfrastructureResult<Vec<(u64, StoredEvent<InfrastructureEvent>)>> {
         let consumer = self
             .stream
             .create_consumer(jetstream::consumer::pull::Config {
-                filter_subject: filter_subject.clone(),
+                filter_subject,
                 ..Default::default()
             })
             .await
@@ -250,14 +625,19 @@ impl EventStore for NatsEventStore {
             while let Some(message) = messages.next().await {
                 let msg = message.map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?;
 
-                // Deserialize StoredEvent
-                let stored_event: StoredEvent<InfrastructureEvent> = serde_json::from_slice(&msg.payload)
-                    .map_err(|e| InfrastructureError::Serialization(e.to_string()))?;
+                // Deserialize StoredEvent, upgrading old schema versions
+                let payload =
+                    resolve_payload(self.payloads.as_ref(), msg.headers.as_ref(), &msg.payload)
+                        .await?;
+                let stored_event = self
+                    .decode_stored_event(&payload, message_content_type(msg.headers.as_ref()))
+                    .await?;
+                let stream_sequence = msg
+                    .info()
+                    .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?
+                    .stream_sequence;
 
-                // Filter by version
-                if stored_event.sequence >= from_version {
-                    events.push(stored_event);
-                }
+                events.push((stream_sequence, stored_event));
 
                 // Acknowledge message
                 msg.ack()
@@ -273,83 +653,462 @@ impl EventStore for NatsEventStore {
             }
         }
 
-        // Sort by sequence to ensure ordering
-        events.sort_by_key(|e| e.sequence);
-
         Ok(events)
     }
 
-    async fn read_by_correlation(
+    /// Current version of an aggregate, the stream sequence of its last
+    /// message (0 if it has none) and, with hash chaining, the hash the
+    /// next event links to
+    ///
+    /// The stream sequence counts messages of torn batches, which the
+    /// version ignores: a later append must still come after them.
+    async fn aggregate_head(
         &self,
-        correlation_id: Uuid,
+        aggregate_id: Uuid,
+    ) -> InfrastructureResult<(Option<u64>, u64, Option<String>)> {
+        let sequenced = self
+            .fetch_sequenced_events(self.aggregate_subject_filter(aggregate_id))
+            .await?;
+        let last_stream_sequence = sequenced.iter().map(|(seq, _)| *seq).max().unwrap_or(0);
+        let mut complete = drop_incomplete_batches(sequenced.into_iter().map(|(_, e)| e).collect());
+        let version = complete.iter().map(|e| e.sequence).max();
+        let chain_head = self.hash_chain.then(|| {
+            complete.sort_by_key(|e| e.sequence);
+            integrity::chain_head(&complete)
+        });
+
+        Ok((version, last_stream_sequence, chain_head))
+    }
+
+    /// Check the hash chain of an aggregate
+    ///
+    /// Walks the complete events in stream order and reports missing,
+    /// reordered and tampered events; see
+    /// [`integrity`](crate::event_store::integrity).
+    pub async fn verify_aggregate(
+        &self,
+        aggregate_id: Uuid,
+    ) -> InfrastructureResult<ChainVerification> {
+        let events = drop_incomplete_batches(
+            self.fetch_stored_events(self.aggregate_subject_filter(aggregate_id))
+                .await?,
+        );
+
+        Ok(integrity::verify_chain(&events))
+    }
+
+    /// Read every event of one aggregate type, in stream order
+    ///
+    /// Replays the aggregate type's whole subject tree. Meant for checks
+    /// across a modest number of aggregates (such as network overlap), not
+    /// for hot paths.
+    pub async fn read_aggregate_type(
+        &self,
+        aggregate_type: AggregateType,
     ) -> InfrastructureResult<Vec<StoredEvent<InfrastructureEvent>>> {
-        // Create consumer for all infrastructure events
-        let consumer = self
-            .stream
-            .create_consumer(jetstream::consumer::pull::Config {
-                filter_subject: format!("{}.>", self.subject_prefix),
-                ..Default::default()
-            })
+        self.fetch_stored_events(format!("{}.{}.>", self.subject_prefix, aggregate_type))
             .await
-            .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?;
+    }
 
+    /// Find correlated events by scanning every aggregate event
+    ///
+    /// This replays the whole stream and filters client-side. It is only
+    /// needed for events appended before the correlation index existed;
+    /// prefer [`EventStore::read_by_correlation`].
+    pub async fn scan_by_correlation(
+        &self,
+        correlation_id: Uuid,
+    ) -> InfrastructureResult<Vec<StoredEvent<InfrastructureEvent>>> {
+        // Only aggregate events carry StoredEvent payloads in the canonical
+        // form; derived subjects (feeds, indexes) are skipped
         let mut events = Vec::new();
+        for aggregate_type in [
+            AggregateType::Compute,
+            AggregateType::Network,
+            AggregateType::Interface,
+            AggregateType::IpPool,
+            AggregateType::Connection,
+        ] {
+            let filter = format!("{}.{}.>", self.subject_prefix, aggregate_type);
+            events.extend(
+                self.fetch_stored_events(filter)
+                    .await?
+                    .into_iter()
+                    .filter(|e| e.correlation_id == correlation_id),
+            );
+        }
 
-        // Fetch messages in bounded batches to avoid infinite wait
-        const BATCH_SIZE: usize = 10000;
+        // Sort by timestamp for chronological order
+        events.sort_by_key(|e| e.timestamp);
 
-        loop {
-            // Fetch a batch of messages with short timeout
-            // If no messages available, fetch will timeout and we treat that as "no more messages"
-            let messages_result = consumer
-                .fetch()
-                .max_messages(BATCH_SIZE)
-                .expires(std::time::Duration::from_secs(2))
-                .messages()
-                .await;
+        Ok(events)
+    }
 
-            // Handle timeout as "no messages available" rather than error
-            let mut messages = match messages_result {
-                Ok(msgs) => msgs,
-                Err(e) => {
-                    // If timeout or "no messages", we're done
-                    let err_msg = e.to_string().to_lowercase();
-                    if err_msg.contains("timeout") || err_msg.contains("timed out") || err_msg.contains("no messages") {
-                        break;
-                    }
-                    // Other errors are real problems
-                    return Err(InfrastructureError::NatsConnection(e.to_string()));
+    /// Append events written under a change request
+    ///
+    /// Behaves like [`EventStore::append`], additionally recording
+    /// `change_ref` in the metadata of every event and copying the events
+    /// to the change index read by [`NatsEventStore::read_by_change_ref`].
+    pub async fn append_with_change_ref(
+        &self,
+        aggregate_id: Uuid,
+        events: Vec<InfrastructureEvent>,
+        expected_version: Option<u64>,
+        change_ref: Option<&ChangeRef>,
+    ) -> InfrastructureResult<u64> {
+        self.append_with_context(aggregate_id, events, expected_version, change_ref, None)
+            .await
+    }
+
+    /// Append events with their change request and envelope
+    ///
+    /// Behaves like [`NatsEventStore::append_with_change_ref`],
+    /// additionally recording `metadata` in the metadata of every event.
+    pub async fn append_with_context(
+        &self,
+        aggregate_id: Uuid,
+        events: Vec<InfrastructureEvent>,
+        expected_version: Option<u64>,
+        change_ref: Option<&ChangeRef>,
+        metadata: Option<&EventEnvelopeMetadata>,
+    ) -> InfrastructureResult<u64> {
+        // Get current version for concurrency check
+        let (current_version, last_stream_sequence, mut chain_head) =
+            self.aggregate_head(aggregate_id).await?;
+
+        // Fail fast on a stale version; JetStream enforces it on publish
+        if let Some(expected) = expected_version {
+            match current_version {
+                Some(current) if current != expected => {
+                    return Err(InfrastructureError::ConcurrencyError(format!(
+                        "Expected version {}, but current version is {}",
+                        expected, current
+                    )));
                 }
-            };
+                None if expected != 0 => {
+                    return Err(InfrastructureError::ConcurrencyError(format!(
+                        "Expected version {}, but aggregate has no events",
+                        expected
+                    )));
+                }
+                _ => {}
+            }
+        }
 
-            let mut batch_count = 0;
+        let first_sequence = current_version.map(|v| v + 1).unwrap_or(1);
 
-            while let Some(message) = messages.next().await {
-                let msg = message.map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?;
+        // Multi-event appends are marked so readers can drop torn batches
+        let batch = (events.len() > 1).then(|| BatchMarker {
+            batch_id: Uuid::now_v7(),
+            index: 0,
+            size: events.len() as u32,
+        });
 
-                // Deserialize StoredEvent
-                let stored_event: StoredEvent<InfrastructureEvent> = serde_json::from_slice(&msg.payload)
-                    .map_err(|e| InfrastructureError::Serialization(e.to_string()))?;
+        // Encode every event before publishing anything, so encoding
+        // failures cannot leave a partial batch behind
+        let cipher = self.write_cipher(aggregate_id, &events).await?;
+        let mut encoded = Vec::with_capacity(events.len());
+        for (offset, event) in events.into_iter().enumerate() {
+            let aggregate_type = event.aggregate_type();
+            let subject = self.build_subject(aggregate_type, aggregate_id, event.operation());
 
-                // Filter by correlation_id
-                if stored_event.correlation_id == correlation_id {
-                    events.push(stored_event);
+            // Wrap in StoredEvent envelope
+            let mut stored_event = envelope(aggregate_id, first_sequence + offset as u64, event);
+            if let Some(batch) = batch {
+                BatchMarker {
+                    index: offset as u32,
+                    ..batch
                 }
+                .attach(&mut stored_event);
+            }
+            if let Some(change_ref) = change_ref {
+                attach_change_ref(&mut stored_event, change_ref);
+            }
+            if let Some(metadata) = metadata {
+                metadata.attach(&mut stored_event);
+            }
+            #[cfg(feature = "content-addressing")]
+            let cid = self
+                .content_addressing
+                .then(|| content::attach(&mut stored_event).to_string());
+            if let Some(head) = chain_head.as_mut() {
+                *head = HashLink::attach(&mut stored_event, head);
+            }
 
-                // Acknowledge message
-                msg.ack()
-                    .await
-                    .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?;
+            // Serialize, encrypting protected fields, then write with the codec
+            let value = self.encode_stored_event(&stored_event, cipher.as_deref())?;
+            let (mut headers, payload) = match &self.cloud_events {
+                Some(config) => config.encode(
+                    config.attributes(aggregate_type, &stored_event),
+                    value,
+                    self.codec.as_ref(),
+                )?,
+                None => (
+                    vec![(
+                        "Content-Type".to_string(),
+                        self.codec.content_type().to_string(),
+                    )],
+                    self.codec.encode(&value)?,
+                ),
+            };
+            #[cfg(feature = "content-addressing")]
+            if let Some(cid) = &cid {
+                headers.push((CID_HEADER.to_string(), cid.clone()));
+            }
+            let payload = match &self.payloads {
+                Some(offload) => offload.offload(&mut headers, payload).await?,
+                None => payload,
+            };
+            encoded.push(EncodedEvent {
+                subject,
+                event_id: stored_event.event_id,
+                correlation_id: stored_event.correlation_id,
+                #[cfg(feature = "content-addressing")]
+                cid,
+                headers,
+                payload,
+            });
+        }
 
-                batch_count += 1;
+        let appended = encoded.len() as u64;
+
+        // With an expected version every message must follow the previous
+        // one, so each acknowledgement is awaited before the next publish;
+        // otherwise the whole batch is published before waiting
+        let guarded = expected_version.is_some();
+        let aggregate_filter = self.aggregate_subject_filter(aggregate_id);
+        let mut last_sequence = last_stream_sequence;
+        let mut acks = Vec::with_capacity(encoded.len());
+        for event in &encoded {
+            let message_id = event.event_id.to_string();
+            let mut publish = event.publish(message_id.clone());
+            if guarded {
+                publish = publish
+                    .expected_last_subject_sequence(last_sequence)
+                    .header(
+                        EXPECTED_LAST_SUBJECT_SEQUENCE_SUBJECT,
+                        aggregate_filter.as_str(),
+                    );
             }
 
-            // If we got fewer messages than batch size, we've read all available events
-            if batch_count < BATCH_SIZE {
-                break;
+            let ack = self
+                .jetstream
+                .send_publish(event.subject.clone(), publish)
+                .await
+                .map_err(publish_error)?;
+            if guarded {
+                let ack = ack.await.map_err(publish_error)?;
+                last_sequence = not_duplicate(ack, &message_id)?.sequence;
+            } else {
+                acks.push((message_id, ack));
+            }
+        }
+        for (message_id, ack) in acks {
+            not_duplicate(ack.await.map_err(publish_error)?, &message_id)?;
+        }
+
+        // Index the events under their change request
+        if let Some(change_ref) = change_ref {
+            for event in &encoded {
+                self.publish_index_copy(self.change_subject(change_ref), event, "change")
+                    .await;
+            }
+        }
+
+        // Maintain the correlation index so chains can be read directly
+        if self.index_correlation {
+            for event in &encoded {
+                let subject = self.correlation_subject(event.correlation_id);
+                self.publish_index_copy(subject, event, "correlation").await;
             }
         }
 
+        // Index content-addressed events under their CID
+        #[cfg(feature = "content-addressing")]
+        for event in &encoded {
+            if let Some(cid) = &event.cid {
+                self.publish_index_copy(self.cid_subject(cid), event, "cid")
+                    .await;
+            }
+        }
+
+        Ok(first_sequence + appended - 1)
+    }
+
+    /// Copy a stored event to the `index` subject `subject`
+    ///
+    /// The event itself is already stored, so a failure is logged and
+    /// counted rather than failing the append.
+    async fn publish_index_copy(&self, subject: String, event: &EncodedEvent, index: &str) {
+        let message_id = format!("{}.{}", event.event_id, index);
+        let written = async {
+            let ack = self
+                .jetstream
+                .send_publish(subject, event.publish(message_id.clone()))
+                .await
+                .map_err(publish_error)?
+                .await
+                .map_err(publish_error)?;
+            not_duplicate(ack, &message_id)
+        }
+        .await;
+        if let Err(e) = written {
+            self.index_failures.fetch_add(1, Ordering::Relaxed);
+            warn!(
+                "Could not write {} index copy of event {}: {}",
+                index, event.event_id, e
+            );
+        }
+    }
+
+    /// Read every event written under a change request
+    ///
+    /// Events are returned in the order they were written, across all
+    /// aggregates the change touched.
+    pub async fn read_by_change_ref(
+        &self,
+        change_ref: &ChangeRef,
+    ) -> InfrastructureResult<Vec<StoredEvent<InfrastructureEvent>>> {
+        let mut events = self
+            .fetch_stored_events(self.change_subject(change_ref))
+            .await?;
+
+        // Sort by timestamp for chronological order
+        events.sort_by_key(|e| e.timestamp);
+
+        Ok(events)
+    }
+
+    /// Read the event with content identifier `cid`
+    ///
+    /// Only finds events appended with content addressing enabled, and only
+    /// returns one whose content still hashes to `cid`.
+    #[cfg(feature = "content-addressing")]
+    pub async fn read_event_by_cid(
+        &self,
+        cid: &Cid,
+    ) -> InfrastructureResult<Option<StoredEvent<InfrastructureEvent>>> {
+        let events = self
+            .fetch_stored_events(self.cid_subject(&cid.to_string()))
+            .await?;
+
+        Ok(events
+            .into_iter()
+            .find(|event| content::matches(event, cid)))
+    }
+
+    /// Remove the events of an aggregate up to and including `version`
+    ///
+    /// Used by [`compaction`](crate::event_store::compaction) once a
+    /// snapshot covers `version`. The aggregate's version is read from its
+    /// latest event, so nothing is purged unless an event above `version`
+    /// remains. A batch reaching above `version` is kept whole. Index copies
+    /// (correlation, change) are kept. Returns the number of messages
+    /// removed.
+    pub async fn purge_through(
+        &self,
+        aggregate_id: Uuid,
+        version: u64,
+    ) -> InfrastructureResult<u64> {
+        let filter_subject = self.aggregate_subject_filter(aggregate_id);
+        let sequenced = self.fetch_sequenced_events(filter_subject.clone()).await?;
+
+        // Messages before the first one above `version` go, including
+        // those of torn batches
+        let Some(keep_from) = sequenced
+            .iter()
+            .filter(|(_, event)| event.sequence > version)
+            .map(|(stream_sequence, _)| *stream_sequence)
+            .min()
+        else {
+            return Ok(0);
+        };
+        let keep_from = batch_start(&sequenced, keep_from);
+
+        let response = self
+            .stream
+            .purge()
+            .filter(filter_subject)
+            .sequence(keep_from)
+            .await
+            .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?;
+        debug!(
+            "Purged {} messages of aggregate {} through version {}",
+            response.purged, aggregate_id, version
+        );
+
+        Ok(response.purged)
+    }
+
+    /// Remove every event of an aggregate
+    ///
+    /// Used by [cold storage archival](crate::archival) once the events are
+    /// safely archived. Index copies (correlation, change) are kept.
+    /// Returns the number of messages removed.
+    pub async fn purge_aggregate(&self, aggregate_id: Uuid) -> InfrastructureResult<u64> {
+        let response = self
+            .stream
+            .purge()
+            .filter(self.aggregate_subject_filter(aggregate_id))
+            .await
+            .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?;
+        debug!(
+            "Purged all {} messages of aggregate {}",
+            response.purged, aggregate_id
+        );
+
+        Ok(response.purged)
+    }
+}
+
+#[async_trait]
+impl EventStore for NatsEventStore {
+    async fn append(
+        &self,
+        aggregate_id: Uuid,
+        events: Vec<InfrastructureEvent>,
+        expected_version: Option<u64>,
+    ) -> InfrastructureResult<u64> {
+        self.append_with_change_ref(aggregate_id, events, expected_version, None)
+            .await
+    }
+
+    async fn read_events(
+        &self,
+        aggregate_id: Uuid,
+    ) -> InfrastructureResult<Vec<StoredEvent<InfrastructureEvent>>> {
+        self.read_events_from(aggregate_id, 1).await
+    }
+
+    async fn read_events_from(
+        &self,
+        aggregate_id: Uuid,
+        from_version: u64,
+    ) -> InfrastructureResult<Vec<StoredEvent<InfrastructureEvent>>> {
+        let filter_subject = self.aggregate_subject_filter(aggregate_id);
+
+        // Events of torn multi-event appends are never visible
+        let mut events: Vec<_> =
+            drop_incomplete_batches(self.fetch_stored_events(filter_subject).await?)
+                .into_iter()
+                .filter(|e| e.sequence >= from_version)
+                .collect();
+
+        // Sort by sequence to ensure ordering
+        events.sort_by_key(|e| e.sequence);
+
+        Ok(events)
+    }
+
+    async fn read_by_correlation(
+        &self,
+        correlation_id: Uuid,
+    ) -> InfrastructureResult<Vec<StoredEvent<InfrastructureEvent>>> {
+        // The correlation index holds a copy of every event in the chain
+        let mut events = self
+            .fetch_stored_events(self.correlation_subject(correlation_id))
+            .await?;
+
         // Sort by timestamp for chronological order
         events.sort_by_key(|e| e.timestamp);
 
@@ -357,9 +1116,9 @@ impl EventStore for NatsEventStore {
     }
 
     async fn get_version(&self, aggregate_id: Uuid) -> InfrastructureResult<Option<u64>> {
-        let events = self.read_events(aggregate_id).await?;
+        let (version, _, _) = self.aggregate_head(aggregate_id).await?;
 
-        Ok(events.iter().map(|e| e.sequence).max())
+        Ok(version)
     }
 
     async fn read_events_by_time_range(
@@ -379,19 +1138,72 @@ impl EventStore for NatsEventStore {
     }
 }
 
+/// An event serialized for publishing, with the headers of its envelope
+struct EncodedEvent {
+    subject: String,
+    event_id: Uuid,
+    correlation_id: Uuid,
+    /// CID when content addressing is enabled
+    #[cfg(feature = "content-addressing")]
+    cid: Option<String>,
+    headers: Vec<(String, String)>,
+    payload: Vec<u8>,
+}
+
+impl EncodedEvent {
+    /// Publish request for this event under `message_id`
+    fn publish(&self, message_id: String) -> Publish {
+        self.headers.iter().fold(
+            Publish::build()
+                .payload(self.payload.clone().into())
+                .message_id(message_id),
+            |publish, (name, value)| publish.header(name.as_str(), value.as_str()),
+        )
+    }
+}
+
+/// Map a failed publish, reporting a rejected expected sequence as a
+/// concurrency conflict
+fn publish_error(e: PublishError) -> InfrastructureError {
+    match e.kind() {
+        PublishErrorKind::WrongLastSequence => InfrastructureError::ConcurrencyError(format!(
+            "Aggregate was written concurrently: {}",
+            e
+        )),
+        _ => InfrastructureError::NatsConnection(e.to_string()),
+    }
+}
+
+/// Refuse an acknowledgement for a message the server dropped as a
+/// duplicate of one it already holds
+///
+/// Message IDs are event IDs, so this only happens when an event ID is
+/// reused within the stream's duplicate window.
+fn not_duplicate(ack: PublishAck, message_id: &str) -> InfrastructureResult<PublishAck> {
+    if ack.duplicate {
+        return Err(InfrastructureError::NatsPublish(format!(
+            "Message {} was dropped as a duplicate of a stored message",
+            message_id
+        )));
+    }
+    Ok(ack)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::domain::{Hostname, ResourceType};
     use crate::events::compute_resource::{ComputeResourceEvent, ResourceRegistered};
+    use crate::test_harness::TestNats;
 
     // Integration tests with real NATS
-    // These require a running NATS server and are marked with #[ignore]
+    // These need `nats-server` on PATH or NATS_URL and are marked with #[ignore]
 
     #[tokio::test]
     #[ignore] // Requires NATS server
     async fn test_nats_event_store_integration() -> InfrastructureResult<()> {
-        let store = NatsEventStore::connect("nats://10.0.20.1:4222").await?;
+        let nats = TestNats::start().await?;
+        let store = nats.event_store().await?;
 
         let aggregate_id = Uuid::now_v7();
         let correlation_id = Uuid::now_v7();
@@ -419,17 +1231,95 @@ mod tests {
         assert_eq!(events.len(), 1);
         assert_eq!(events[0].sequence, 1);
 
-        // Verify correlation tracking
+        // Verify correlation tracking via the index and the full scan
         let correlated = store.read_by_correlation(correlation_id).await?;
         assert_eq!(correlated.len(), 1);
 
+        let scanned = store.scan_by_correlation(correlation_id).await?;
+        assert_eq!(scanned.len(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore] // Requires NATS server
+    async fn test_missing_index_copies_do_not_fail_the_append() -> InfrastructureResult<()> {
+        // Arrange: a stream that does not capture the correlation index
+        let nats = TestNats::start().await?;
+        let config = JetStreamConfig {
+            stream_name: "INFRASTRUCTURE_EVENTS_UNINDEXED".to_string(),
+            subjects: vec!["infrastructure.compute.>".to_string()],
+            ..Default::default()
+        };
+        let store = NatsEventStore::connect_with_config(nats.url(), config).await?;
+        let aggregate_id = Uuid::now_v7();
+        let event = InfrastructureEvent::ComputeResource(ComputeResourceEvent::ResourceRegistered(
+            ResourceRegistered {
+                event_version: 1,
+                event_id: Uuid::now_v7(),
+                aggregate_id,
+                timestamp: Utc::now(),
+                correlation_id: Uuid::now_v7(),
+                causation_id: None,
+                hostname: Hostname::new("test-server01").unwrap(),
+                resource_type: ResourceType::PhysicalServer,
+            },
+        ));
+
+        // Act
+        let version = store.append(aggregate_id, vec![event], Some(0)).await?;
+
+        // Assert
+        assert_eq!(version, 1);
+        assert_eq!(store.read_events(aggregate_id).await?.len(), 1);
+        assert_eq!(store.index_failures(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore] // Requires NATS server
+    async fn test_events_of_one_aggregate_are_all_stored() -> InfrastructureResult<()> {
+        let nats = TestNats::start().await?;
+        let store = nats.event_store().await?;
+        let aggregate_id = Uuid::now_v7();
+        let registered = |hostname: &str| {
+            InfrastructureEvent::ComputeResource(ComputeResourceEvent::ResourceRegistered(
+                ResourceRegistered {
+                    event_version: 1,
+                    event_id: Uuid::now_v7(),
+                    aggregate_id,
+                    timestamp: Utc::now(),
+                    correlation_id: Uuid::now_v7(),
+                    causation_id: None,
+                    hostname: Hostname::new(hostname).unwrap(),
+                    resource_type: ResourceType::PhysicalServer,
+                },
+            ))
+        };
+        let first = registered("test-server01");
+        let second = registered("test-server02");
+        let event_ids = vec![first.event_id(), second.event_id()];
+
+        // Separate appends publish one message each, deduplicated by event ID
+        store.append(aggregate_id, vec![first], Some(0)).await?;
+        store.append(aggregate_id, vec![second], Some(1)).await?;
+
+        let events = store.read_events(aggregate_id).await?;
+        assert_eq!(
+            events.iter().map(|e| e.event_id).collect::<Vec<_>>(),
+            event_ids
+        );
+        assert_eq!(events[1].sequence, 2);
+
         Ok(())
     }
 
     #[tokio::test]
     #[ignore] // Requires NATS server
     async fn test_concurrency_control() -> InfrastructureResult<()> {
-        let store = NatsEventStore::connect("nats://10.0.20.1:4222").await?;
+        let nats = TestNats::start().await?;
+        let store = nats.event_store().await?;
 
         let aggregate_id = Uuid::now_v7();
 
@@ -473,4 +1363,41 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    #[ignore] // Requires NATS server 2.11+
+    async fn test_concurrent_appends_rejected_by_server() -> InfrastructureResult<()> {
+        let nats = TestNats::start().await?;
+        let store = nats.event_store().await?;
+        let aggregate_id = Uuid::now_v7();
+        let registered = |hostname: &str| {
+            InfrastructureEvent::ComputeResource(ComputeResourceEvent::ResourceRegistered(
+                ResourceRegistered {
+                    event_version: 1,
+                    event_id: Uuid::now_v7(),
+                    aggregate_id,
+                    timestamp: Utc::now(),
+                    correlation_id: Uuid::now_v7(),
+                    causation_id: None,
+                    hostname: Hostname::new(hostname).unwrap(),
+                    resource_type: ResourceType::PhysicalServer,
+                },
+            ))
+        };
+
+        // Both writers pass the client-side check against the empty stream
+        let (first, second) = tokio::join!(
+            store.append(aggregate_id, vec![registered("test-server01")], Some(0)),
+            store.append(aggregate_id, vec![registered("test-server02")], Some(0)),
+        );
+
+        let conflicts = [&first, &second]
+            .iter()
+            .filter(|r| matches!(r, Err(InfrastructureError::ConcurrencyError(_))))
+            .count();
+        assert_eq!(conflicts, 1);
+        assert_eq!(store.read_events(aggregate_id).await?.len(), 1);
+
+        Ok(())
+    }
 }
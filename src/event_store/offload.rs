@@ -0,0 +1,392 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Large Payload Offloading
+//!
+//! NATS refuses messages above the server's `max_payload` (1 MiB by
+//! default), which events carrying configuration dumps or hardware
+//! inventories can exceed. With a [`PayloadOffload`], payloads above a
+//! threshold are written to an object store and the message carries a
+//! reference instead:
+//!
+//! ```text
+//! append:  payload > threshold ──> PayloadStore::put(<sha256>, payload)
+//!          message body: empty
+//!          Cim-Payload-Ref:    <sha256>
+//!          Cim-Payload-Sha256: <hex digest of the payload>
+//!          Content-Type:       unchanged
+//!
+//! read:    Cim-Payload-Ref ──> PayloadStore::get(<sha256>) ──> check digest ──> decode
+//! ```
+//!
+//! Payloads are keyed by their digest, so every event of an aggregate
+//! keeps its own payload and identical payloads are stored once. Reads
+//! follow the reference in the message, whatever key it names.
+//!
+//! The event store, [`EventSubscriber`](super::EventSubscriber) and
+//! [`ProjectionManager`](crate::projection::ProjectionManager) resolve
+//! references when given the same store; readers without one fail on
+//! offloaded events rather than decode an empty body.
+//!
+//! | Store                     | Where payloads live                    |
+//! |---------------------------|----------------------------------------|
+//! | [`JetStreamPayloadStore`] | a JetStream object store bucket        |
+//! | `S3PayloadStore`          | S3-compatible storage (`cold-storage`) |
+//!
+//! Stream [backups](super::backup) copy references, not the offloaded
+//! payloads; back up the bucket alongside.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::event_store::offload::{JetStreamPayloadStore, PayloadOffload};
+//!
+//! let payloads = JetStreamPayloadStore::open(jetstream.clone(), DEFAULT_BUCKET).await?;
+//! let offload = PayloadOffload::new(Arc::new(payloads)).with_threshold(256 * 1024);
+//!
+//! let store = NatsEventStore::connect(url).await?.with_payload_offload(offload.clone());
+//! let manager = ProjectionManager::new(jetstream, "INFRASTRUCTURE_EVENTS")
+//!     .with_payload_offload(offload);
+//! ```
+
+use async_nats::jetstream::{self, object_store};
+use async_nats::HeaderMap;
+use async_trait::async_trait;
+use sha2::{Digest, Sha256};
+use std::borrow::Cow;
+use std::sync::Arc;
+use tokio::io::AsyncReadExt;
+
+use crate::audit::hex;
+use crate::errors::{InfrastructureError, InfrastructureResult};
+
+/// Default object store bucket for offloaded payloads
+pub const DEFAULT_BUCKET: &str = "infrastructure-payloads";
+
+/// Default threshold: half the default NATS `max_payload`, leaving room
+/// for headers and index copies
+pub const DEFAULT_THRESHOLD: usize = 512 * 1024;
+
+/// Header carrying the key of an offloaded payload
+pub const PAYLOAD_REF_HEADER: &str = "Cim-Payload-Ref";
+
+/// Header carrying the SHA-256 of an offloaded payload, hex
+pub const PAYLOAD_SHA256_HEADER: &str = "Cim-Payload-Sha256";
+
+/// Where offloaded payloads are kept
+#[async_trait]
+pub trait PayloadStore: Send + Sync {
+    /// Store `payload` under `key`, replacing any earlier one
+    async fn put(&self, key: &str, payload: Vec<u8>) -> InfrastructureResult<()>;
+
+    /// Payload stored under `key`
+    async fn get(&self, key: &str) -> InfrastructureResult<Vec<u8>>;
+}
+
+/// Payloads in a JetStream object store bucket
+#[derive(Clone)]
+pub struct JetStreamPayloadStore {
+    store: object_store::ObjectStore,
+}
+
+impl JetStreamPayloadStore {
+    /// Open `bucket`, creating it if needed
+    pub async fn open(jetstream: jetstream::Context, bucket: &str) -> InfrastructureResult<Self> {
+        let store = match jetstream.get_object_store(bucket).await {
+            Ok(store) => store,
+            Err(_) => jetstream
+                .create_object_store(object_store::Config {
+                    bucket: bucket.to_string(),
+                    description: Some("Offloaded infrastructure event payloads".to_string()),
+                    ..Default::default()
+                })
+                .await
+                .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?,
+        };
+        Ok(Self { store })
+    }
+}
+
+#[async_trait]
+impl PayloadStore for JetStreamPayloadStore {
+    async fn put(&self, key: &str, payload: Vec<u8>) -> InfrastructureResult<()> {
+        self.store
+            .put(key, &mut payload.as_slice())
+            .await
+            .map_err(|e| InfrastructureError::NatsPublish(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> InfrastructureResult<Vec<u8>> {
+        let mut object = self
+            .store
+            .get(key)
+            .await
+            .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?;
+        let mut payload = Vec::new();
+        object
+            .read_to_end(&mut payload)
+            .await
+            .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?;
+        Ok(payload)
+    }
+}
+
+/// Payloads in S3-compatible storage, under a key prefix
+#[cfg(feature = "cold-storage")]
+#[derive(Clone)]
+pub struct S3PayloadStore {
+    store: Arc<dyn ::object_store::ObjectStore>,
+    prefix: ::object_store::path::Path,
+}
+
+#[cfg(feature = "cold-storage")]
+impl S3PayloadStore {
+    /// Payloads in `store` under `prefix`
+    pub fn new(store: Arc<dyn ::object_store::ObjectStore>, prefix: impl Into<String>) -> Self {
+        Self {
+            store,
+            prefix: ::object_store::path::Path::from(prefix.into()),
+        }
+    }
+}
+
+#[cfg(feature = "cold-storage")]
+#[async_trait]
+impl PayloadStore for S3PayloadStore {
+    async fn put(&self, key: &str, payload: Vec<u8>) -> InfrastructureResult<()> {
+        self.store
+            .put(&self.prefix.child(key), payload.into())
+            .await
+            .map_err(|e| InfrastructureError::Generic(format!("Payload store: {}", e)))?;
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> InfrastructureResult<Vec<u8>> {
+        let storage = |e: ::object_store::Error| {
+            InfrastructureError::Generic(format!("Payload store: {}", e))
+        };
+        let result = self
+            .store
+            .get(&self.prefix.child(key))
+            .await
+            .map_err(storage)?;
+        Ok(result.bytes().await.map_err(storage)?.to_vec())
+    }
+}
+
+/// Moves payloads above a threshold into a [`PayloadStore`]
+#[derive(Clone)]
+pub struct PayloadOffload {
+    store: Arc<dyn PayloadStore>,
+    threshold: usize,
+}
+
+impl PayloadOffload {
+    /// Offload to `store` above [`DEFAULT_THRESHOLD`]
+    pub fn new(store: Arc<dyn PayloadStore>) -> Self {
+        Self {
+            store,
+            threshold: DEFAULT_THRESHOLD,
+        }
+    }
+
+    /// Offload payloads larger than `bytes`
+    pub fn with_threshold(mut self, bytes: usize) -> Self {
+        self.threshold = bytes;
+        self
+    }
+
+    /// Message body for `payload`: unchanged up to the threshold, otherwise
+    /// stored under its digest, with the reference added to `headers`
+    pub async fn offload(
+        &self,
+        headers: &mut Vec<(String, String)>,
+        payload: Vec<u8>,
+    ) -> InfrastructureResult<Vec<u8>> {
+        if payload.len() <= self.threshold {
+            return Ok(payload);
+        }
+
+        let digest = hex(&Sha256::digest(&payload));
+        self.store.put(&digest, payload).await?;
+        headers.push((PAYLOAD_REF_HEADER.to_string(), digest.clone()));
+        headers.push((PAYLOAD_SHA256_HEADER.to_string(), digest));
+        Ok(Vec::new())
+    }
+}
+
+/// Payload of a message, fetching it from the store if it was offloaded
+///
+/// Fails if the message holds a reference but no store is configured, or
+/// the stored payload does not match the digest in the headers.
+pub async fn resolve_payload<'a>(
+    offload: Option<&PayloadOffload>,
+    headers: Option<&HeaderMap>,
+    payload: &'a [u8],
+) -> InfrastructureResult<Cow<'a, [u8]>> {
+    let header = |name: &str| {
+        headers
+            .and_then(|headers| headers.get(name))
+            .map(|value| value.as_str())
+    };
+    let Some(key) = header(PAYLOAD_REF_HEADER) else {
+        return Ok(Cow::Borrowed(payload));
+    };
+    let offload = offload.ok_or_else(|| {
+        InfrastructureError::Configuration(format!(
+            "Payload {} was offloaded, but no payload store is configured",
+            key
+        ))
+    })?;
+
+    let resolved = offload.store.get(key).await?;
+    if header(PAYLOAD_SHA256_HEADER) != Some(hex(&Sha256::digest(&resolved)).as_str()) {
+        return Err(InfrastructureError::Deserialization(format!(
+            "Offloaded payload {} does not match its digest",
+            key
+        )));
+    }
+    Ok(Cow::Owned(resolved))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MemoryPayloads(Mutex<HashMap<String, Vec<u8>>>);
+
+    #[async_trait]
+    impl PayloadStore for MemoryPayloads {
+        async fn put(&self, key: &str, payload: Vec<u8>) -> InfrastructureResult<()> {
+            self.0.lock().unwrap().insert(key.to_string(), payload);
+            Ok(())
+        }
+
+        async fn get(&self, key: &str) -> InfrastructureResult<Vec<u8>> {
+            self.0
+                .lock()
+                .unwrap()
+                .get(key)
+                .cloned()
+                .ok_or_else(|| InfrastructureError::Generic(format!("no payload {}", key)))
+        }
+    }
+
+    fn header_map(headers: &[(String, String)]) -> HeaderMap {
+        let mut map = HeaderMap::new();
+        for (name, value) in headers {
+            map.insert(name.as_str(), value.as_str());
+        }
+        map
+    }
+
+    fn reference(headers: &[(String, String)]) -> &str {
+        headers
+            .iter()
+            .find(|(name, _)| name == PAYLOAD_REF_HEADER)
+            .map(|(_, value)| value.as_str())
+            .expect("payload was offloaded")
+    }
+
+    #[tokio::test]
+    async fn test_large_payloads_round_trip_through_the_store() {
+        // Arrange
+        let payloads = Arc::new(MemoryPayloads::default());
+        let offload = PayloadOffload::new(payloads.clone()).with_threshold(16);
+        let large = vec![b'x'; 64];
+        let mut small_headers = Vec::new();
+        let mut large_headers = Vec::new();
+
+        // Act
+        let small_body = offload
+            .offload(&mut small_headers, b"{}".to_vec())
+            .await
+            .unwrap();
+        let large_body = offload
+            .offload(&mut large_headers, large.clone())
+            .await
+            .unwrap();
+        let headers = header_map(&large_headers);
+        let resolved = resolve_payload(Some(&offload), Some(&headers), &large_body)
+            .await
+            .unwrap();
+        let without_store = resolve_payload(None, Some(&headers), &large_body).await;
+
+        // Assert
+        assert_eq!(small_body, b"{}");
+        assert!(small_headers.is_empty());
+        assert!(large_body.is_empty());
+        assert_eq!(resolved.as_ref(), large.as_slice());
+        assert!(matches!(
+            without_store,
+            Err(InfrastructureError::Configuration(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_altered_offloaded_payload_is_rejected() {
+        // Arrange
+        let payloads = Arc::new(MemoryPayloads::default());
+        let offload = PayloadOffload::new(payloads.clone()).with_threshold(0);
+        let mut headers = Vec::new();
+        let body = offload
+            .offload(&mut headers, b"original".to_vec())
+            .await
+            .unwrap();
+        payloads
+            .put(reference(&headers), b"altered".to_vec())
+            .await
+            .unwrap();
+
+        // Act
+        let resolved = resolve_payload(Some(&offload), Some(&header_map(&headers)), &body).await;
+
+        // Assert
+        assert!(matches!(
+            resolved,
+            Err(InfrastructureError::Deserialization(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_events_of_one_aggregate_keep_their_own_payloads() {
+        // Arrange
+        let payloads = Arc::new(MemoryPayloads::default());
+        let offload = PayloadOffload::new(payloads.clone()).with_threshold(16);
+        let first = vec![b'a'; 64];
+        let second = vec![b'b'; 64];
+        let mut first_headers = Vec::new();
+        let mut second_headers = Vec::new();
+
+        // Act
+        let first_body = offload
+            .offload(&mut first_headers, first.clone())
+            .await
+            .unwrap();
+        let second_body = offload
+            .offload(&mut second_headers, second.clone())
+            .await
+            .unwrap();
+
+        // Assert
+        assert_ne!(reference(&first_headers), reference(&second_headers));
+        let first_headers = header_map(&first_headers);
+        let second_headers = header_map(&second_headers);
+        assert_eq!(
+            resolve_payload(Some(&offload), Some(&first_headers), &first_body)
+                .await
+                .unwrap()
+                .as_ref(),
+            first.as_slice()
+        );
+        assert_eq!(
+            resolve_payload(Some(&offload), Some(&second_headers), &second_body)
+                .await
+                .unwrap()
+                .as_ref(),
+            second.as_slice()
+        );
+    }
+}
@@ -0,0 +1,289 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Event Provenance
+//!
+//! Who wrote an event, from where and against which schema. The envelope
+//! travels in `StoredEvent::metadata` next to the batch marker and change
+//! reference, so it is stored, replayed and delivered to subscribers with
+//! the event itself:
+//!
+//! ```text
+//! {"envelope": {"actor": {"kind": "user", "id": "alice@example.com"},
+//!               "source": "netops-portal", "ip": "10.0.4.17",
+//!               "schema": "https://schemas.example.com/infrastructure/v1",
+//!               "tenant": "acme"}}
+//! ```
+//!
+//! # Populating
+//!
+//! Services stamp the envelope configured with `with_envelope` (source
+//! system, schema, tenant) on every event they write. Who is acting
+//! changes per request, so API edges run the command inside
+//! [`EventEnvelopeMetadata::scope`]; fields set there win over the
+//! service's defaults.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::event_store::provenance::{Actor, EventEnvelopeMetadata};
+//!
+//! let service = EventSourcedComputeResourceService::new(event_store, nats_client)
+//!     .with_envelope(EventEnvelopeMetadata::new().with_source("netops-portal"));
+//!
+//! let request = EventEnvelopeMetadata::new()
+//!     .with_actor(Actor::user("alice@example.com"))
+//!     .with_ip(peer.ip());
+//! request.scope(service.register_resource(command)).await?;
+//!
+//! for stored in event_store.read_events(aggregate_id).await? {
+//!     let actor = EventEnvelopeMetadata::of(&stored).and_then(|envelope| envelope.actor);
+//! }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::future::Future;
+use std::net::IpAddr;
+
+use crate::jetstream::StoredEvent;
+
+/// Key of the envelope inside `StoredEvent::metadata`
+const ENVELOPE_KEY: &str = "envelope";
+
+tokio::task_local! {
+    static REQUEST_ENVELOPE: EventEnvelopeMetadata;
+}
+
+/// Kind of principal behind an event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActorKind {
+    /// A person
+    User,
+    /// Another system acting with its own credentials
+    Service,
+    /// This system on its own (schedulers, discovery, sync)
+    System,
+}
+
+/// Principal behind an event
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Actor {
+    /// Kind of principal
+    pub kind: ActorKind,
+
+    /// Identity as known to the authenticating system
+    pub id: String,
+}
+
+impl Actor {
+    /// A person
+    pub fn user(id: impl Into<String>) -> Self {
+        Self {
+            kind: ActorKind::User,
+            id: id.into(),
+        }
+    }
+
+    /// Another system
+    pub fn service(id: impl Into<String>) -> Self {
+        Self {
+            kind: ActorKind::Service,
+            id: id.into(),
+        }
+    }
+
+    /// A component of this system
+    pub fn system(id: impl Into<String>) -> Self {
+        Self {
+            kind: ActorKind::System,
+            id: id.into(),
+        }
+    }
+}
+
+impl fmt::Display for Actor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self.kind {
+            ActorKind::User => "user",
+            ActorKind::Service => "service",
+            ActorKind::System => "system",
+        };
+        write!(f, "{}:{}", kind, self.id)
+    }
+}
+
+/// Typed provenance of a stored event
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventEnvelopeMetadata {
+    /// Who caused the event
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Actor>,
+
+    /// System the command came through
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+
+    /// Client address of the request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip: Option<IpAddr>,
+
+    /// URI of the schema the payload conforms to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+
+    /// Organization the event was written for
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant: Option<String>,
+}
+
+impl EventEnvelopeMetadata {
+    /// Envelope with no fields set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the actor
+    pub fn with_actor(mut self, actor: Actor) -> Self {
+        self.actor = Some(actor);
+        self
+    }
+
+    /// Set the source system
+    pub fn with_source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    /// Set the client address
+    pub fn with_ip(mut self, ip: IpAddr) -> Self {
+        self.ip = Some(ip);
+        self
+    }
+
+    /// Set the schema URI
+    pub fn with_schema(mut self, schema: impl Into<String>) -> Self {
+        self.schema = Some(schema.into());
+        self
+    }
+
+    /// Set the tenant
+    pub fn with_tenant(mut self, tenant: impl Into<String>) -> Self {
+        self.tenant = Some(tenant.into());
+        self
+    }
+
+    /// Whether no field is set
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Fields of `self`, with unset ones taken from `defaults`
+    pub fn or(self, defaults: &EventEnvelopeMetadata) -> Self {
+        Self {
+            actor: self.actor.or_else(|| defaults.actor.clone()),
+            source: self.source.or_else(|| defaults.source.clone()),
+            ip: self.ip.or(defaults.ip),
+            schema: self.schema.or_else(|| defaults.schema.clone()),
+            tenant: self.tenant.or_else(|| defaults.tenant.clone()),
+        }
+    }
+
+    /// Run `future` with this envelope as the request's envelope
+    pub async fn scope<F: Future>(self, future: F) -> F::Output {
+        REQUEST_ENVELOPE.scope(self, future).await
+    }
+
+    /// Envelope of the current request, if running inside [`Self::scope`]
+    pub fn current() -> Option<Self> {
+        REQUEST_ENVELOPE.try_with(Clone::clone).ok()
+    }
+
+    /// Envelope to write: the request's, completed from `defaults`
+    pub fn resolve(defaults: Option<&EventEnvelopeMetadata>) -> Option<Self> {
+        let envelope = match (Self::current(), defaults) {
+            (Some(current), Some(defaults)) => current.or(defaults),
+            (Some(current), None) => current,
+            (None, Some(defaults)) => defaults.clone(),
+            (None, None) => return None,
+        };
+        (!envelope.is_empty()).then_some(envelope)
+    }
+
+    /// Record the envelope in the event metadata, keeping other keys
+    pub fn attach<E>(&self, event: &mut StoredEvent<E>) {
+        let mut metadata = match event.metadata.take() {
+            Some(serde_json::Value::Object(map)) => map,
+            _ => serde_json::Map::new(),
+        };
+        metadata.insert(
+            ENVELOPE_KEY.to_string(),
+            serde_json::to_value(self).expect("envelope serializes"),
+        );
+        event.metadata = Some(serde_json::Value::Object(metadata));
+    }
+
+    /// Envelope of an event, if it was written with one
+    pub fn of<E>(event: &StoredEvent<E>) -> Option<Self> {
+        let envelope = event.metadata.as_ref()?.get(ENVELOPE_KEY)?;
+        serde_json::from_value(envelope.clone()).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event_store::batch::BatchMarker;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_envelope_round_trips_next_to_other_metadata() {
+        // Arrange
+        let mut event = StoredEvent::new(
+            Uuid::now_v7(),
+            Uuid::now_v7(),
+            1,
+            Uuid::now_v7(),
+            Uuid::now_v7(),
+            "ResourceRegistered",
+            "data",
+        );
+        let marker = BatchMarker {
+            batch_id: Uuid::now_v7(),
+            index: 0,
+            size: 2,
+        };
+        let envelope = EventEnvelopeMetadata::new()
+            .with_actor(Actor::user("alice@example.com"))
+            .with_ip("10.0.4.17".parse().unwrap());
+
+        // Act
+        marker.attach(&mut event);
+        envelope.attach(&mut event);
+
+        // Assert
+        assert_eq!(EventEnvelopeMetadata::of(&event), Some(envelope));
+        assert_eq!(BatchMarker::of(&event), Some(marker));
+    }
+
+    #[tokio::test]
+    async fn test_request_envelope_wins_over_defaults() {
+        // Arrange
+        let defaults = EventEnvelopeMetadata::new()
+            .with_source("netops-portal")
+            .with_actor(Actor::system("portal"));
+        let request = EventEnvelopeMetadata::new().with_actor(Actor::user("alice@example.com"));
+
+        // Act
+        let outside = EventEnvelopeMetadata::resolve(Some(&defaults));
+        let inside = request
+            .scope(async { EventEnvelopeMetadata::resolve(Some(&defaults)) })
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(outside, Some(defaults));
+        assert_eq!(inside.actor, Some(Actor::user("alice@example.com")));
+        assert_eq!(inside.source.as_deref(), Some("netops-portal"));
+        assert_eq!(EventEnvelopeMetadata::resolve(None), None);
+    }
+}
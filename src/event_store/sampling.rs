@@ -0,0 +1,465 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Event Sampling for Development Stores
+//!
+//! Copies a masked subset of a production event store into a local dev
+//! store (NATS or sled), so development runs against realistic data shapes
+//! without a full production copy:
+//!
+//! ```text
+//! production NatsEventStore ──read_aggregate_type()──> select (quota per type)
+//!                                                          │ mask (redact, pseudonym)
+//!                                                          ▼
+//!                                  dev EventStore <──append()── EventSample
+//! ```
+//!
+//! # Selection
+//!
+//! Sampling is per aggregate, never per event: a selected aggregate keeps
+//! its whole history, so it folds to a valid state. Each aggregate type has
+//! a quota; aggregates are ranked by a hash of their ID and the configured
+//! salt, which spreads the sample over old and new aggregates and selects
+//! the same ones on every run with the same salt. References between
+//! aggregates (an interface's resource, a cable's ends) may point outside
+//! the sample.
+//!
+//! # Masking
+//!
+//! Mask rules address fields of the event payload (`data.event.<field>`),
+//! optionally for one event type only:
+//!
+//! - `redact` replaces text with `"redacted"`; meant for free text such as
+//!   metadata values and serial numbers
+//! - `pseudonym` replaces a value with a stable substitute of the same
+//!   shape: UUIDs stay UUIDs and text becomes `<field>-<hash>` (a valid
+//!   hostname label), so equal inputs still join after masking
+//!
+//! Pseudonyms are derived from the salt; keep it secret, since without it
+//! the substitutes of known IDs can be recomputed.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::event_store::sampling::{EventSampler, SamplingConfig};
+//!
+//! let sampler = EventSampler::new(SamplingConfig::from_yaml(&yaml)?);
+//! let sample = sampler.sample(&production).await?;
+//! let report = sampler.load(&sample, &dev_store).await?;
+//! ```
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::errors::{InfrastructureError, InfrastructureResult};
+use crate::event_store::batch::drop_incomplete_batches;
+use crate::event_store::{EventStore, NatsEventStore};
+use crate::events::InfrastructureEvent;
+use crate::jetstream::StoredEvent;
+use crate::subjects::AggregateType;
+
+/// Replacement for redacted text
+pub const REDACTED: &str = "redacted";
+
+/// Sampling configuration could not be read
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum SamplingError {
+    /// YAML could not be parsed
+    #[error("Invalid sampling configuration: {0}")]
+    Yaml(String),
+}
+
+/// How a masked field is replaced
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Mask {
+    /// Replace text with [`REDACTED`]
+    Redact,
+
+    /// Replace with a stable substitute of the same shape
+    Pseudonym,
+}
+
+/// A field to mask
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MaskRule {
+    /// Event type name (e.g. "MetadataUpdated"); omit for every event type
+    #[serde(default)]
+    pub event_type: Option<String>,
+
+    /// Field name within the event payload
+    pub field: String,
+
+    /// Replacement
+    pub mask: Mask,
+}
+
+impl MaskRule {
+    /// Mask `field` of every event type
+    pub fn new(field: impl Into<String>, mask: Mask) -> Self {
+        Self {
+            event_type: None,
+            field: field.into(),
+            mask,
+        }
+    }
+
+    /// Only mask the field in events of `event_type`
+    pub fn for_event_type(mut self, event_type: impl Into<String>) -> Self {
+        self.event_type = Some(event_type.into());
+        self
+    }
+
+    fn applies_to(&self, event_type: &str) -> bool {
+        self.event_type.as_deref().map_or(true, |t| t == event_type)
+    }
+}
+
+/// Aggregates sampled per aggregate type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SampleQuotas {
+    /// Compute resources
+    #[serde(default = "default_compute_quota")]
+    pub compute: usize,
+
+    /// Networks, network interfaces and IP pools (each type sampled up to
+    /// this quota)
+    #[serde(default = "default_network_quota")]
+    pub network: usize,
+
+    /// Connections
+    #[serde(default = "default_connection_quota")]
+    pub connection: usize,
+}
+
+fn default_compute_quota() -> usize {
+    100
+}
+
+fn default_network_quota() -> usize {
+    20
+}
+
+fn default_connection_quota() -> usize {
+    50
+}
+
+impl Default for SampleQuotas {
+    fn default() -> Self {
+        Self {
+            compute: default_compute_quota(),
+            network: default_network_quota(),
+            connection: default_connection_quota(),
+        }
+    }
+}
+
+impl SampleQuotas {
+    /// Quota of an aggregate type (0 for types that are never sampled)
+    pub fn for_type(&self, aggregate_type: AggregateType) -> usize {
+        match aggregate_type {
+            AggregateType::Compute => self.compute,
+            AggregateType::Network | AggregateType::Interface | AggregateType::IpPool => {
+                self.network
+            }
+            AggregateType::Connection => self.connection,
+            AggregateType::Software | AggregateType::Policy => 0,
+        }
+    }
+}
+
+/// What to sample and how to mask it
+///
+/// ```yaml
+/// salt: dev-2026
+/// quotas:
+///   compute: 200
+///   network: 10
+/// masks:
+///   - field: hostname
+///     mask: pseudonym
+///   - event_type: MetadataUpdated
+///     field: value
+///     mask: redact
+/// ```
+///
+/// Without `masks`, the [default masks](SamplingConfig::default_masks)
+/// apply; an empty list masks nothing.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SamplingConfig {
+    /// Seed for aggregate selection and pseudonyms
+    #[serde(default)]
+    pub salt: String,
+
+    /// Aggregates sampled per type
+    #[serde(default)]
+    pub quotas: SampleQuotas,
+
+    /// Fields masked before loading
+    #[serde(default = "SamplingConfig::default_masks")]
+    pub masks: Vec<MaskRule>,
+}
+
+impl Default for SamplingConfig {
+    fn default() -> Self {
+        Self {
+            salt: String::new(),
+            quotas: SampleQuotas::default(),
+            masks: Self::default_masks(),
+        }
+    }
+}
+
+impl SamplingConfig {
+    /// Parse a configuration from YAML
+    pub fn from_yaml(yaml: &str) -> Result<Self, SamplingError> {
+        serde_yaml::from_str(yaml).map_err(|e| SamplingError::Yaml(e.to_string()))
+    }
+
+    /// Pseudonymous hostnames and owners; redacted serial numbers, asset
+    /// tags and metadata values
+    pub fn default_masks() -> Vec<MaskRule> {
+        vec![
+            MaskRule::new("hostname", Mask::Pseudonym),
+            MaskRule::new("owner_id", Mask::Pseudonym),
+            MaskRule::new("serial_number", Mask::Redact),
+            MaskRule::new("asset_tag", Mask::Redact),
+            MaskRule::new("value", Mask::Redact).for_event_type("MetadataUpdated"),
+        ]
+    }
+}
+
+/// Masked histories of the sampled aggregates, grouped per aggregate in
+/// sequence order
+pub type EventSample = Vec<(Uuid, Vec<StoredEvent<InfrastructureEvent>>)>;
+
+/// Result of loading a sample
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct LoadReport {
+    /// Aggregates written
+    pub aggregates: usize,
+
+    /// Events written
+    pub events: usize,
+
+    /// Aggregates skipped because the target already has events for them
+    pub already_present: usize,
+}
+
+/// Samples and masks events
+#[derive(Debug, Clone)]
+pub struct EventSampler {
+    config: SamplingConfig,
+}
+
+impl EventSampler {
+    /// Sampler applying `config`
+    pub fn new(config: SamplingConfig) -> Self {
+        Self { config }
+    }
+
+    /// Select whole aggregate histories from `events` of one aggregate type,
+    /// up to the type's quota
+    pub fn select(
+        &self,
+        aggregate_type: AggregateType,
+        events: Vec<StoredEvent<InfrastructureEvent>>,
+    ) -> EventSample {
+        let mut histories: HashMap<Uuid, Vec<StoredEvent<InfrastructureEvent>>> = HashMap::new();
+        for event in drop_incomplete_batches(events) {
+            histories.entry(event.aggregate_id).or_default().push(event);
+        }
+
+        let mut ranked: Vec<(Uuid, Uuid)> = histories
+            .keys()
+            .map(|id| (self.digest(id.as_bytes()), *id))
+            .collect();
+        ranked.sort();
+
+        ranked
+            .into_iter()
+            .take(self.config.quotas.for_type(aggregate_type))
+            .filter_map(|(_, id)| {
+                let mut history = histories.remove(&id)?;
+                history.sort_by_key(|e| e.sequence);
+                Some((id, history))
+            })
+            .collect()
+    }
+
+    /// Apply the mask rules to one event
+    pub fn mask(
+        &self,
+        event: &StoredEvent<InfrastructureEvent>,
+    ) -> InfrastructureResult<StoredEvent<InfrastructureEvent>> {
+        let mut value = serde_json::to_value(event)
+            .map_err(|e| InfrastructureError::Serialization(e.to_string()))?;
+
+        if let Some(payload) = value
+            .get_mut("data")
+            .and_then(|data| data.get_mut("event"))
+            .and_then(Value::as_object_mut)
+        {
+            for rule in self
+                .config
+                .masks
+                .iter()
+                .filter(|rule| rule.applies_to(&event.event_type))
+            {
+                if let Some(field) = payload.get_mut(&rule.field) {
+                    *field = self.masked(&rule.field, rule.mask, field);
+                }
+            }
+        }
+
+        serde_json::from_value(value).map_err(|e| {
+            InfrastructureError::Deserialization(format!(
+                "Masked {} event is invalid: {}",
+                event.event_type, e
+            ))
+        })
+    }
+
+    /// Sample and mask every sampled aggregate type of `source`
+    pub async fn sample(&self, source: &NatsEventStore) -> InfrastructureResult<EventSample> {
+        let mut sample = Vec::new();
+        for aggregate_type in [
+            AggregateType::Compute,
+            AggregateType::Network,
+            AggregateType::Interface,
+            AggregateType::IpPool,
+            AggregateType::Connection,
+        ] {
+            if self.config.quotas.for_type(aggregate_type) == 0 {
+                continue;
+            }
+
+            let events = source.read_aggregate_type(aggregate_type).await?;
+            for (aggregate_id, history) in self.select(aggregate_type, events) {
+                let masked = history
+                    .iter()
+                    .map(|event| self.mask(event))
+                    .collect::<InfrastructureResult<Vec<_>>>()?;
+                sample.push((aggregate_id, masked));
+            }
+        }
+        Ok(sample)
+    }
+
+    /// Append a sample to `target`
+    ///
+    /// Each aggregate is appended in one batch expecting an empty stream;
+    /// aggregates the target already holds (e.g. from an earlier load) are
+    /// skipped and counted.
+    pub async fn load(
+        &self,
+        sample: &EventSample,
+        target: &dyn EventStore,
+    ) -> InfrastructureResult<LoadReport> {
+        let mut report = LoadReport::default();
+        for (aggregate_id, history) in sample {
+            let events: Vec<InfrastructureEvent> =
+                history.iter().map(|stored| stored.data.clone()).collect();
+            let count = events.len();
+
+            match target.append(*aggregate_id, events, Some(0)).await {
+                Ok(_) => {
+                    report.aggregates += 1;
+                    report.events += count;
+                }
+                Err(InfrastructureError::ConcurrencyError(_)) => report.already_present += 1,
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(report)
+    }
+
+    fn masked(&self, field: &str, mask: Mask, value: &Value) -> Value {
+        match (mask, value) {
+            (_, Value::Null) => Value::Null,
+            (Mask::Redact, Value::String(_)) => Value::String(REDACTED.to_string()),
+            (Mask::Redact, other) => other.clone(),
+            (Mask::Pseudonym, Value::String(text)) => {
+                let digest = self.digest(text.as_bytes());
+                if Uuid::parse_str(text).is_ok() {
+                    Value::String(digest.to_string())
+                } else {
+                    let hash = digest.simple().to_string();
+                    Value::String(format!("{}-{}", field.replace('_', "-"), &hash[..8]))
+                }
+            }
+            (Mask::Pseudonym, other) => {
+                Value::String(self.digest(other.to_string().as_bytes()).to_string())
+            }
+        }
+    }
+
+    /// Stable hash of `input` under the configured salt
+    fn digest(&self, input: &[u8]) -> Uuid {
+        let mut name = self.config.salt.as_bytes().to_vec();
+        name.push(0);
+        name.extend_from_slice(input);
+        Uuid::new_v5(&Uuid::NAMESPACE_OID, &name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_harness::{metadata_updated, registered, stored};
+
+    fn history(hostname: &str) -> Vec<StoredEvent<InfrastructureEvent>> {
+        let aggregate_id = Uuid::now_v7();
+        vec![
+            stored(1, registered(aggregate_id, hostname)),
+            stored(2, metadata_updated(aggregate_id, "contract", "ACME-4411")),
+        ]
+    }
+
+    #[test]
+    fn test_select_keeps_whole_histories_within_quota() {
+        // Arrange
+        let config = SamplingConfig::from_yaml("salt: test\nquotas:\n  compute: 2\n").unwrap();
+        let sampler = EventSampler::new(config);
+        let events: Vec<_> = ["web-01", "web-02", "web-03"]
+            .into_iter()
+            .flat_map(history)
+            .collect();
+
+        // Act
+        let first = sampler.select(AggregateType::Compute, events.clone());
+        let second = sampler.select(AggregateType::Compute, events);
+
+        // Assert
+        assert_eq!(first.len(), 2);
+        assert!(first.iter().all(|(_, history)| history.len() == 2
+            && history[0].sequence == 1
+            && history[1].sequence == 2));
+        let ids = |sample: &EventSample| sample.iter().map(|(id, _)| *id).collect::<Vec<_>>();
+        assert_eq!(ids(&first), ids(&second));
+    }
+
+    #[test]
+    fn test_mask_pseudonymizes_and_redacts() {
+        // Arrange
+        let sampler = EventSampler::new(SamplingConfig::default());
+        let events = history("db-primary-01");
+
+        // Act
+        let masked: Vec<_> = events.iter().map(|e| sampler.mask(e).unwrap()).collect();
+        let again = sampler.mask(&events[0]).unwrap();
+
+        // Assert
+        let hostname = |event: &StoredEvent<InfrastructureEvent>| {
+            serde_json::to_value(event).unwrap()["data"]["event"]["hostname"].clone()
+        };
+        let masked_hostname = hostname(&masked[0]);
+        assert_ne!(masked_hostname, "db-primary-01");
+        assert!(masked_hostname.as_str().unwrap().starts_with("hostname-"));
+        assert_eq!(hostname(&again), masked_hostname);
+
+        let metadata = serde_json::to_value(&masked[1]).unwrap();
+        assert_eq!(metadata["data"]["event"]["key"], "contract");
+        assert_eq!(metadata["data"]["event"]["value"], REDACTED);
+    }
+}
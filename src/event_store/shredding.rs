@@ -0,0 +1,352 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Crypto-Shredding
+//!
+//! Events are never deleted, so personal data written into them (owners,
+//! locations) cannot be erased by editing the stream. Instead each
+//! aggregate gets its own data key, its protected fields are encrypted with
+//! that key, and forgetting the aggregate destroys the key:
+//!
+//! ```text
+//! append(id, [OwnerAssigned]) ──> DataKeyStore: key(id) (created on first use)
+//!                                  └─> owner_id = {"$encrypted": ...} ──> JetStream
+//!
+//! forget_aggregate(id) ──> DataKeyStore: key(id) := tombstone
+//!
+//! read(id) ──> key(id) present  ──> owner_id revealed
+//!          └─> key(id) destroyed ──> owner_id = SHREDDED_VALUE
+//! ```
+//!
+//! The stream itself is untouched: sequences, event IDs, correlation chains
+//! and every unprotected field stay readable, and aggregates still replay.
+//! Which fields are protected comes from the [`SerializationPolicy`] given
+//! to [`NatsEventStore::with_crypto_shredding`], usually
+//! [`SerializationPolicy::confidential_references`].
+//!
+//! Once forgotten, an aggregate keeps its tombstone: further events with
+//! protected fields are rejected with [`FieldEncryptionError::KeyDestroyed`]
+//! rather than starting a fresh key. Keys live in the JetStream KV bucket
+//! [`DATA_KEY_BUCKET`] with a history of one, so the tombstone replaces the
+//! key message; backups of that bucket must be expired as well.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::event_store::shredding::KvDataKeyStore;
+//!
+//! let keys = KvDataKeyStore::open(jetstream).await?;
+//! let store = NatsEventStore::connect(url)
+//!     .await?
+//!     .with_crypto_shredding(SerializationPolicy::confidential_references(), Arc::new(keys));
+//!
+//! // GDPR erasure request for a resource's owner data
+//! store.forget_aggregate(aggregate_id).await?;
+//! ```
+//!
+//! [`NatsEventStore::with_crypto_shredding`]: super::NatsEventStore::with_crypto_shredding
+
+use async_nats::jetstream::{self, kv};
+use async_trait::async_trait;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use uuid::Uuid;
+
+use crate::errors::{InfrastructureError, InfrastructureResult};
+use crate::events::serialization::{
+    event_payload_mut, is_encrypted, ChaChaFieldCipher, FieldEncryptionError, SerializationPolicy,
+};
+use crate::nats::kv::create_entry;
+
+/// JetStream KV bucket holding per-aggregate data keys
+pub const DATA_KEY_BUCKET: &str = "infrastructure_data_keys";
+
+/// Value substituted for protected fields whose key was destroyed
+///
+/// Protected fields are identifier references, so the nil UUID keeps the
+/// payload decodable.
+pub const SHREDDED_VALUE: &str = "00000000-0000-0000-0000-000000000000";
+
+/// KV value marking a destroyed key
+const TOMBSTONE: &[u8] = b"forgotten";
+
+/// Data key of one aggregate
+#[derive(Clone, PartialEq, Eq)]
+pub enum DataKey {
+    /// 256-bit key protecting the aggregate's fields
+    Active([u8; 32]),
+
+    /// The key was destroyed by [`DataKeyStore::forget`]
+    Forgotten,
+}
+
+impl std::fmt::Debug for DataKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DataKey::Active(_) => f.write_str("Active(..)"),
+            DataKey::Forgotten => f.write_str("Forgotten"),
+        }
+    }
+}
+
+impl DataKey {
+    fn decode(aggregate_id: Uuid, bytes: &[u8]) -> InfrastructureResult<Self> {
+        if bytes == TOMBSTONE {
+            return Ok(DataKey::Forgotten);
+        }
+        let key: [u8; 32] = bytes.try_into().map_err(|_| {
+            InfrastructureError::Deserialization(format!("Corrupt data key for {}", aggregate_id))
+        })?;
+        Ok(DataKey::Active(key))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        match self {
+            DataKey::Active(key) => key.to_vec(),
+            DataKey::Forgotten => TOMBSTONE.to_vec(),
+        }
+    }
+}
+
+/// Persistent per-aggregate data keys
+#[async_trait]
+pub trait DataKeyStore: Send + Sync {
+    /// Key of `aggregate_id`, if one was ever created
+    async fn load(&self, aggregate_id: Uuid) -> InfrastructureResult<Option<DataKey>>;
+
+    /// Store `key` for `aggregate_id` unless it already has one, and return
+    /// the key in effect
+    async fn insert(&self, aggregate_id: Uuid, key: [u8; 32]) -> InfrastructureResult<DataKey>;
+
+    /// Destroy the key of `aggregate_id`, leaving a tombstone
+    async fn forget(&self, aggregate_id: Uuid) -> InfrastructureResult<()>;
+}
+
+/// In-memory data keys, for tests
+#[derive(Debug, Default)]
+pub struct MemoryDataKeyStore {
+    keys: Mutex<HashMap<Uuid, DataKey>>,
+}
+
+impl MemoryDataKeyStore {
+    /// Empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl DataKeyStore for MemoryDataKeyStore {
+    async fn load(&self, aggregate_id: Uuid) -> InfrastructureResult<Option<DataKey>> {
+        Ok(self
+            .keys
+            .lock()
+            .expect("key lock poisoned")
+            .get(&aggregate_id)
+            .cloned())
+    }
+
+    async fn insert(&self, aggregate_id: Uuid, key: [u8; 32]) -> InfrastructureResult<DataKey> {
+        let mut keys = self.keys.lock().expect("key lock poisoned");
+        Ok(keys
+            .entry(aggregate_id)
+            .or_insert(DataKey::Active(key))
+            .clone())
+    }
+
+    async fn forget(&self, aggregate_id: Uuid) -> InfrastructureResult<()> {
+        self.keys
+            .lock()
+            .expect("key lock poisoned")
+            .insert(aggregate_id, DataKey::Forgotten);
+        Ok(())
+    }
+}
+
+/// Data keys stored in a JetStream KV bucket, keyed by aggregate ID
+pub struct KvDataKeyStore {
+    store: kv::Store,
+}
+
+impl KvDataKeyStore {
+    /// Open the data key bucket, creating it if needed
+    pub async fn open(jetstream: jetstream::Context) -> InfrastructureResult<Self> {
+        let store = match jetstream.get_key_value(DATA_KEY_BUCKET).await {
+            Ok(store) => store,
+            Err(_) => jetstream
+                .create_key_value(kv::Config {
+                    bucket: DATA_KEY_BUCKET.to_string(),
+                    description: "Per-aggregate data keys for crypto-shredding".to_string(),
+                    history: 1,
+                    ..Default::default()
+                })
+                .await
+                .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?,
+        };
+
+        Ok(Self { store })
+    }
+}
+
+#[async_trait]
+impl DataKeyStore for KvDataKeyStore {
+    async fn load(&self, aggregate_id: Uuid) -> InfrastructureResult<Option<DataKey>> {
+        self.store
+            .get(aggregate_id.to_string())
+            .await
+            .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?
+            .map(|bytes| DataKey::decode(aggregate_id, &bytes))
+            .transpose()
+    }
+
+    async fn insert(&self, aggregate_id: Uuid, key: [u8; 32]) -> InfrastructureResult<DataKey> {
+        let created = DataKey::Active(key);
+        // Nothing is written if the entry exists; another writer won the
+        // race (or the aggregate was forgotten), so use whatever is stored
+        match create_entry(&self.store, &aggregate_id.to_string(), created.encode()).await? {
+            Some(_) => Ok(created),
+            None => self.load(aggregate_id).await?.ok_or_else(|| {
+                InfrastructureError::NatsConnection(format!(
+                    "Cannot create data key for {}",
+                    aggregate_id
+                ))
+            }),
+        }
+    }
+
+    async fn forget(&self, aggregate_id: Uuid) -> InfrastructureResult<()> {
+        self.store
+            .put(aggregate_id.to_string(), DataKey::Forgotten.encode().into())
+            .await
+            .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?;
+        Ok(())
+    }
+}
+
+fn key_id(aggregate_id: Uuid) -> String {
+    format!("aggregate-{}", aggregate_id)
+}
+
+/// Cipher for writing protected fields of `aggregate_id`, creating its key
+/// on first use
+///
+/// Fails with [`FieldEncryptionError::KeyDestroyed`] once the aggregate was
+/// forgotten.
+pub async fn write_cipher(
+    keys: &dyn DataKeyStore,
+    aggregate_id: Uuid,
+) -> InfrastructureResult<ChaChaFieldCipher> {
+    let key = match keys.load(aggregate_id).await? {
+        Some(key) => key,
+        None => keys.insert(aggregate_id, generate_key()).await?,
+    };
+
+    match key {
+        DataKey::Active(key) => Ok(ChaChaFieldCipher::new(key_id(aggregate_id), key)),
+        DataKey::Forgotten => Err(FieldEncryptionError::KeyDestroyed(key_id(aggregate_id)).into()),
+    }
+}
+
+/// Decrypt the protected fields of a serialized stored event with its
+/// aggregate's key, or replace them with [`SHREDDED_VALUE`] if the key is
+/// gone
+///
+/// Events without encrypted fields are left as they are and cost no key
+/// lookup.
+pub async fn reveal_or_shred(
+    policy: &SerializationPolicy,
+    keys: &dyn DataKeyStore,
+    stored: &mut Value,
+) -> InfrastructureResult<()> {
+    let encrypted = event_payload_mut(stored)
+        .and_then(|payload| payload.as_object())
+        .is_some_and(|payload| payload.values().any(is_encrypted));
+    if !encrypted {
+        return Ok(());
+    }
+
+    let aggregate_id = stored
+        .get("aggregate_id")
+        .and_then(Value::as_str)
+        .and_then(|id| Uuid::parse_str(id).ok())
+        .ok_or_else(|| {
+            InfrastructureError::Deserialization("Encrypted event without aggregate_id".to_string())
+        })?;
+
+    match keys.load(aggregate_id).await? {
+        Some(DataKey::Active(key)) => {
+            let cipher = ChaChaFieldCipher::new(key_id(aggregate_id), key);
+            policy.reveal(stored, &cipher)?;
+        }
+        Some(DataKey::Forgotten) | None => shred(stored),
+    }
+    Ok(())
+}
+
+/// Replace every encrypted field of a serialized stored event
+fn shred(stored: &mut Value) {
+    let Some(payload) = event_payload_mut(stored).and_then(Value::as_object_mut) else {
+        return;
+    };
+    for value in payload.values_mut().filter(|value| is_encrypted(value)) {
+        *value = Value::String(SHREDDED_VALUE.to_string());
+    }
+}
+
+fn generate_key() -> [u8; 32] {
+    use chacha20poly1305::aead::{KeyInit, OsRng};
+
+    let generated = chacha20poly1305::ChaCha20Poly1305::generate_key(&mut OsRng);
+    let mut key = [0u8; 32];
+    key.copy_from_slice(&generated);
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::serialization::FieldCipher;
+
+    fn owner_assigned_json(aggregate_id: Uuid) -> Value {
+        serde_json::json!({
+            "event_type": "OwnerAssigned",
+            "aggregate_id": aggregate_id.to_string(),
+            "data": {
+                "aggregate_type": "compute_resource",
+                "event": {
+                    "type": "owner_assigned",
+                    "event_version": 1,
+                    "owner_id": "01934f4a-2000-7000-8000-000000002000"
+                }
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_forgotten_aggregate_is_shredded() {
+        // Arrange
+        let policy = SerializationPolicy::confidential_references();
+        let keys = MemoryDataKeyStore::new();
+        let aggregate_id = Uuid::now_v7();
+        let original = owner_assigned_json(aggregate_id);
+        let mut stored = original.clone();
+        let cipher = write_cipher(&keys, aggregate_id).await.unwrap();
+        policy
+            .protect(&mut stored, Some(&cipher as &dyn FieldCipher))
+            .unwrap();
+        let mut before = stored.clone();
+
+        // Act
+        reveal_or_shred(&policy, &keys, &mut before).await.unwrap();
+        keys.forget(aggregate_id).await.unwrap();
+        reveal_or_shred(&policy, &keys, &mut stored).await.unwrap();
+
+        // Assert
+        assert_eq!(before, original);
+        assert_eq!(stored["data"]["event"]["owner_id"], SHREDDED_VALUE);
+        assert_eq!(stored["data"]["event"]["event_version"], 1);
+        assert!(matches!(
+            write_cipher(&keys, aggregate_id).await,
+            Err(InfrastructureError::Serialization(_))
+        ));
+    }
+}
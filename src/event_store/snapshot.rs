@@ -0,0 +1,168 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Aggregate Snapshots
+//!
+//! A snapshot is the folded state of an aggregate at one version. Readers
+//! start from the snapshot and apply only the events after it, which is
+//! what lets [`compaction`](crate::event_store::compaction) purge the
+//! events a snapshot covers.
+//!
+//! ```text
+//! AggregateSnapshot{version: 40, state} + events 41.. ──rebuild()──> current state
+//! ```
+//!
+//! Snapshots are kept in the `infrastructure_snapshots` JetStream KV
+//! bucket under `<aggregate_type>.<aggregate_id>`.
+
+use async_nats::jetstream::{self, kv};
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::aggregate::compute_resource::apply_infrastructure_event;
+use crate::aggregate::{apply_connection_event, ComputeResourceState, ConnectionState};
+use crate::errors::{InfrastructureError, InfrastructureResult};
+use crate::events::InfrastructureEvent;
+use crate::jetstream::StoredEvent;
+use crate::subjects::AggregateType;
+
+/// KV bucket holding aggregate snapshots
+pub const SNAPSHOT_BUCKET: &str = "infrastructure_snapshots";
+
+/// Aggregate state that can be snapshotted and rebuilt from its events
+pub trait Snapshottable: Serialize + DeserializeOwned + Send + Sync {
+    /// Subject tree the aggregate's events are stored under
+    const AGGREGATE_TYPE: AggregateType;
+
+    /// State before the first event
+    fn initial(aggregate_id: Uuid) -> Self;
+
+    /// Apply one event; events of other aggregates are ignored
+    fn apply(self, event: &InfrastructureEvent) -> Self;
+}
+
+impl Snapshottable for ComputeResourceState {
+    const AGGREGATE_TYPE: AggregateType = AggregateType::Compute;
+
+    fn initial(aggregate_id: Uuid) -> Self {
+        ComputeResourceState::default_for(aggregate_id)
+    }
+
+    fn apply(self, event: &InfrastructureEvent) -> Self {
+        apply_infrastructure_event(self, event)
+    }
+}
+
+impl Snapshottable for ConnectionState {
+    const AGGREGATE_TYPE: AggregateType = AggregateType::Connection;
+
+    fn initial(aggregate_id: Uuid) -> Self {
+        ConnectionState::default_for(aggregate_id)
+    }
+
+    fn apply(self, event: &InfrastructureEvent) -> Self {
+        match event {
+            InfrastructureEvent::Connection(event) => apply_connection_event(self, event),
+            _ => self,
+        }
+    }
+}
+
+/// State of an aggregate as of one version
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AggregateSnapshot<S> {
+    /// The aggregate
+    pub aggregate_id: Uuid,
+
+    /// Last event sequence folded into `state`
+    pub version: u64,
+
+    /// When the snapshot was taken
+    pub taken_at: DateTime<Utc>,
+
+    /// Folded state
+    pub state: S,
+}
+
+/// Rebuild the state of an aggregate from its snapshot and events
+///
+/// Events at or below the snapshot's version are skipped, so it does not
+/// matter whether they were purged yet. `events` must be in sequence order.
+pub fn rebuild<S: Snapshottable>(
+    aggregate_id: Uuid,
+    snapshot: Option<AggregateSnapshot<S>>,
+    events: &[StoredEvent<InfrastructureEvent>],
+) -> S {
+    let (state, version) = match snapshot {
+        Some(snapshot) => (snapshot.state, snapshot.version),
+        None => (S::initial(aggregate_id), 0),
+    };
+
+    events
+        .iter()
+        .filter(|event| event.sequence > version)
+        .fold(state, |state, event| state.apply(&event.data))
+}
+
+/// Snapshots stored in a JetStream KV bucket
+#[derive(Clone)]
+pub struct SnapshotStore {
+    store: kv::Store,
+}
+
+impl SnapshotStore {
+    /// Open the snapshot bucket, creating it if needed
+    pub async fn open(jetstream: jetstream::Context) -> InfrastructureResult<Self> {
+        let store = match jetstream.get_key_value(SNAPSHOT_BUCKET).await {
+            Ok(store) => store,
+            Err(_) => jetstream
+                .create_key_value(kv::Config {
+                    bucket: SNAPSHOT_BUCKET.to_string(),
+                    description: "Aggregate snapshots taken before compaction".to_string(),
+                    history: 1,
+                    ..Default::default()
+                })
+                .await
+                .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?,
+        };
+
+        Ok(Self { store })
+    }
+
+    /// Latest snapshot of an aggregate, if one was taken
+    pub async fn load<S: Snapshottable>(
+        &self,
+        aggregate_id: Uuid,
+    ) -> InfrastructureResult<Option<AggregateSnapshot<S>>> {
+        self.store
+            .get(snapshot_key(S::AGGREGATE_TYPE, aggregate_id))
+            .await
+            .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?
+            .map(|bytes| {
+                serde_json::from_slice(&bytes)
+                    .map_err(|e| InfrastructureError::Deserialization(e.to_string()))
+            })
+            .transpose()
+    }
+
+    /// Store a snapshot, replacing the previous one
+    pub async fn save<S: Snapshottable>(
+        &self,
+        snapshot: &AggregateSnapshot<S>,
+    ) -> InfrastructureResult<()> {
+        let payload = serde_json::to_vec(snapshot)
+            .map_err(|e| InfrastructureError::Serialization(e.to_string()))?;
+        self.store
+            .put(
+                snapshot_key(S::AGGREGATE_TYPE, snapshot.aggregate_id),
+                payload.into(),
+            )
+            .await
+            .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?;
+        Ok(())
+    }
+}
+
+fn snapshot_key(aggregate_type: AggregateType, aggregate_id: Uuid) -> String {
+    format!("{}.{}", aggregate_type, aggregate_id)
+}
@@ -0,0 +1,670 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Filtered Event Subscriptions
+//!
+//! [`EventSubscriberBuilder`] reads the event stream through a JetStream
+//! pull consumer whose filter is compiled from typed selections instead of
+//! hand-written wildcards:
+//!
+//! | Selection                                       | Filter subjects                     |
+//! |-------------------------------------------------|-------------------------------------|
+//! | nothing                                         | whole stream                        |
+//! | `with_aggregate(Network)`                       | `infrastructure.network.>`          |
+//! | `with_aggregate(Network)`, `[Defined, Removed]` | `infrastructure.network.*.defined`, |
+//! |                                                 | `infrastructure.network.*.removed`  |
+//! | `[Established]`                                 | `infrastructure.*.*.established`    |
+//!
+//! Operations select the last token of `<aggregate>.<aggregate_id>.<operation>`
+//! subjects. With a tenant, `infrastructure` becomes `infrastructure.<org_id>`.
+//!
+//! Anything a subject cannot express (a network's VLAN, a resource's
+//! organization) goes into predicates over the decoded event. Predicates
+//! run client-side: events they reject are still delivered by the server,
+//! acknowledged and skipped.
+//!
+//! [`EventSubscriber::next`] hands out one event at a time; to handle
+//! different aggregates in parallel, pass the subscriber to a
+//! [`DispatchPool`](crate::event_store::dispatch::DispatchPool).
+//!
+//! Logging, metrics, payload rewrites and other cross-cutting concerns
+//! plug in as [`EventMiddleware`](crate::event_store::middleware::EventMiddleware)
+//! with [`EventSubscriberBuilder::with_middleware`]; middleware runs before
+//! the predicates.
+//!
+//! # Scaling out
+//!
+//! Instances built with the same [`EventSubscriberBuilder::queue_group`]
+//! share one durable consumer and compete for its messages, so each event
+//! is handled by one instance. Ordering across instances is then lost; for
+//! projections that need each aggregate's events in order, give every
+//! instance a partition as well:
+//!
+//! ```text
+//!                      ┌─> neo4j-0-of-2 (aggregate_id % 2 == 0) ─> instance A, C
+//! INFRASTRUCTURE_EVENTS┤
+//!                      └─> neo4j-1-of-2 (aggregate_id % 2 == 1) ─> instance B
+//! ```
+//!
+//! Each partition is its own durable consumer reading the selected
+//! subjects; events of other partitions are acknowledged and skipped. A
+//! partition's consumer hands out one message at a time, so instances
+//! sharing a partition (for failover) still see its events in order.
+//! Throughput grows with the partition count.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::event_store::subscriber::EventSubscriberBuilder;
+//! use cim_infrastructure::{AggregateType, InfrastructureEvent, Operation};
+//!
+//! let mut subscriber = EventSubscriberBuilder::new(jetstream, "INFRASTRUCTURE_EVENTS")
+//!     .with_aggregate(AggregateType::Network)
+//!     .with_operations([Operation::Defined, Operation::Removed])
+//!     .with_predicate(|event| matches!(event, InfrastructureEvent::Network(_)))
+//!     .build()
+//!     .await?;
+//!
+//! while let Some(received) = subscriber.next().await {
+//!     let received = received?;
+//!     println!("{} at sequence {}", received.subject, received.sequence);
+//! }
+//! ```
+
+use async_nats::jetstream::{self, consumer};
+use futures::StreamExt;
+use std::fmt;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::errors::{InfrastructureError, InfrastructureResult};
+use crate::event_store::cloudevents::unwrap_structured;
+use crate::event_store::codec::{codec_for_content_type, message_content_type};
+use crate::event_store::middleware::{EventMiddleware, MiddlewareChain, Outcome};
+use crate::event_store::offload::{resolve_payload, PayloadOffload};
+use crate::event_store::provenance::EventEnvelopeMetadata;
+use crate::events::serialization::{decode_payload, UnknownFields};
+use crate::events::InfrastructureEvent;
+use crate::jetstream::{AckPolicy, ConsumerConfig, DeliverPolicy, StoredEvent};
+use crate::subjects::{subjects, AggregateType, Operation, INFRASTRUCTURE_ROOT};
+
+/// Client-side filter over decoded events
+pub type EventPredicate = Arc<dyn Fn(&InfrastructureEvent) -> bool + Send + Sync>;
+
+/// Typed selection of events
+#[derive(Clone, Default)]
+pub struct EventFilter {
+    tenant: Option<String>,
+    aggregates: Vec<AggregateType>,
+    operations: Vec<Operation>,
+    predicates: Vec<EventPredicate>,
+}
+
+impl EventFilter {
+    /// Filter selecting every event
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Select events of one organization's subjects
+    pub fn with_tenant(mut self, org_id: impl Into<String>) -> Self {
+        self.tenant = Some(org_id.into());
+        self
+    }
+
+    /// Select events of an aggregate type (repeat for several)
+    pub fn with_aggregate(mut self, aggregate: AggregateType) -> Self {
+        if !self.aggregates.contains(&aggregate) {
+            self.aggregates.push(aggregate);
+        }
+        self
+    }
+
+    /// Select events of these operations
+    pub fn with_operations(mut self, operations: impl IntoIterator<Item = Operation>) -> Self {
+        for operation in operations {
+            if !self.operations.contains(&operation) {
+                self.operations.push(operation);
+            }
+        }
+        self
+    }
+
+    /// Keep only events the predicate accepts (all predicates must accept)
+    pub fn with_predicate(
+        mut self,
+        predicate: impl Fn(&InfrastructureEvent) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.predicates.push(Arc::new(predicate));
+        self
+    }
+
+    /// JetStream filter subjects (empty = whole stream)
+    pub fn filter_subjects(&self) -> Vec<String> {
+        let root = match &self.tenant {
+            Some(org_id) => subjects::tenant_root(org_id),
+            None => INFRASTRUCTURE_ROOT.to_string(),
+        };
+        let aggregates: Vec<String> = if self.aggregates.is_empty() {
+            vec!["*".to_string()]
+        } else {
+            self.aggregates.iter().map(ToString::to_string).collect()
+        };
+
+        if self.operations.is_empty() {
+            if self.aggregates.is_empty() {
+                return Vec::new();
+            }
+            return aggregates
+                .iter()
+                .map(|aggregate| format!("{}.{}.>", root, aggregate))
+                .collect();
+        }
+
+        let root = &root;
+        aggregates
+            .iter()
+            .flat_map(|aggregate| {
+                self.operations
+                    .iter()
+                    .map(move |operation| format!("{}.{}.*.{}", root, aggregate, operation))
+            })
+            .collect()
+    }
+
+    /// Whether every predicate accepts the event
+    pub fn matches(&self, event: &InfrastructureEvent) -> bool {
+        self.predicates.iter().all(|predicate| predicate(event))
+    }
+}
+
+impl fmt::Debug for EventFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventFilter")
+            .field("tenant", &self.tenant)
+            .field("aggregates", &self.aggregates)
+            .field("operations", &self.operations)
+            .field("predicates", &self.predicates.len())
+            .finish()
+    }
+}
+
+/// An event delivered to a subscriber
+#[derive(Debug, Clone)]
+pub struct ReceivedEvent {
+    /// Stream sequence
+    pub sequence: u64,
+
+    /// Subject the event was published on
+    pub subject: String,
+
+    /// The domain event
+    pub event: InfrastructureEvent,
+
+    /// Who wrote the event, if it was stored with an envelope
+    pub envelope: Option<EventEnvelopeMetadata>,
+}
+
+/// Share of the aggregates one partitioned subscriber handles
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Partition {
+    /// This subscriber's partition, below `count`
+    pub index: usize,
+
+    /// Number of partitions
+    pub count: usize,
+}
+
+impl Partition {
+    /// Whether this partition handles the aggregate's events
+    ///
+    /// Routes like [`DispatchPool`](crate::event_store::dispatch::DispatchPool).
+    pub fn owns(&self, aggregate_id: Uuid) -> bool {
+        (aggregate_id.as_u128() % self.count as u128) as usize == self.index
+    }
+}
+
+/// Builds an [`EventSubscriber`]
+pub struct EventSubscriberBuilder {
+    jetstream: jetstream::Context,
+    stream_name: String,
+    consumer: ConsumerConfig,
+    filter: EventFilter,
+    queue_group: Option<String>,
+    partition: Option<Partition>,
+    middleware: MiddlewareChain,
+    payloads: Option<PayloadOffload>,
+    ephemeral: bool,
+}
+
+impl EventSubscriberBuilder {
+    /// Subscriber to a stream with the default consumer settings
+    pub fn new(jetstream: jetstream::Context, stream_name: impl Into<String>) -> Self {
+        Self {
+            jetstream,
+            stream_name: stream_name.into(),
+            consumer: ConsumerConfig::default(),
+            filter: EventFilter::new(),
+            queue_group: None,
+            partition: None,
+            middleware: MiddlewareChain::default(),
+            payloads: None,
+            ephemeral: false,
+        }
+    }
+
+    /// Consumer settings; a typed selection replaces its `filter_subject`
+    pub fn with_consumer(mut self, consumer: ConsumerConfig) -> Self {
+        self.consumer = consumer;
+        self
+    }
+
+    /// Replace the whole selection
+    pub fn with_filter(mut self, filter: EventFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Select events of one organization's subjects
+    pub fn with_tenant(mut self, org_id: impl Into<String>) -> Self {
+        self.filter = self.filter.with_tenant(org_id);
+        self
+    }
+
+    /// Select events of an aggregate type (repeat for several)
+    pub fn with_aggregate(mut self, aggregate: AggregateType) -> Self {
+        self.filter = self.filter.with_aggregate(aggregate);
+        self
+    }
+
+    /// Select events of these operations
+    pub fn with_operations(mut self, operations: impl IntoIterator<Item = Operation>) -> Self {
+        self.filter = self.filter.with_operations(operations);
+        self
+    }
+
+    /// Keep only events the predicate accepts
+    pub fn with_predicate(
+        mut self,
+        predicate: impl Fn(&InfrastructureEvent) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.filter = self.filter.with_predicate(predicate);
+        self
+    }
+
+    /// Share a durable consumer named `name` with other instances
+    ///
+    /// Replaces the consumer name; instances in one group must select the
+    /// same events.
+    pub fn queue_group(mut self, name: impl Into<String>) -> Self {
+        self.queue_group = Some(name.into());
+        self
+    }
+
+    /// Handle only the aggregates of partition `index` out of `count`
+    pub fn with_partition(mut self, index: usize, count: usize) -> Self {
+        self.partition = Some(Partition { index, count });
+        self
+    }
+
+    /// Run middleware around every event (in the order added)
+    pub fn with_middleware(mut self, middleware: impl EventMiddleware) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Resolve payloads the event store offloaded to `offload`'s store
+    pub fn with_payload_offload(mut self, offload: PayloadOffload) -> Self {
+        self.payloads = Some(offload);
+        self
+    }
+
+    /// Use an unnamed consumer the server removes once it goes unused
+    ///
+    /// For short-lived readers such as a UI connection; the consumer name
+    /// and queue group are ignored.
+    pub fn ephemeral(mut self) -> Self {
+        self.ephemeral = true;
+        self
+    }
+
+    /// Create the consumer and start receiving
+    pub async fn build(mut self) -> InfrastructureResult<EventSubscriber> {
+        let consumer = group_consumer(&self.consumer, self.queue_group.as_deref(), self.partition)?;
+        if let Some(partition) = self.partition {
+            self.filter = self
+                .filter
+                .with_predicate(move |event| partition.owns(event.aggregate_id()));
+        }
+        let mut config = pull_config(&consumer, self.filter.filter_subjects())?;
+        if self.ephemeral {
+            config.durable_name = None;
+        }
+        let acknowledge = config.ack_policy != AckPolicy::None;
+        let stream = self
+            .jetstream
+            .get_stream(&self.stream_name)
+            .await
+            .map_err(|e| InfrastructureError::NatsSubscribe(e.to_string()))?;
+        let consumer = stream
+            .create_consumer(config)
+            .await
+            .map_err(|e| InfrastructureError::NatsSubscribe(e.to_string()))?;
+        let messages = consumer
+            .messages()
+            .await
+            .map_err(|e| InfrastructureError::NatsSubscribe(e.to_string()))?;
+
+        Ok(EventSubscriber {
+            messages,
+            filter: self.filter,
+            acknowledge,
+            middleware: Arc::new(self.middleware),
+            payloads: self.payloads,
+        })
+    }
+}
+
+/// Receives the events selected by an [`EventFilter`]
+pub struct EventSubscriber {
+    messages: consumer::pull::Stream,
+    filter: EventFilter,
+    acknowledge: bool,
+    middleware: Arc<MiddlewareChain>,
+    payloads: Option<PayloadOffload>,
+}
+
+impl EventSubscriber {
+    /// Next selected event (None when the consumer is gone)
+    ///
+    /// Each message is acknowledged once decoded, whether or not the
+    /// predicates keep it. Messages that cannot be decoded are not
+    /// acknowledged and come back as errors, so the server redelivers them.
+    pub async fn next(&mut self) -> Option<InfrastructureResult<ReceivedEvent>> {
+        let pending = match self.next_pending().await? {
+            Ok(pending) => pending,
+            Err(e) => return Some(Err(e)),
+        };
+        let received = pending.received.clone();
+        Some(pending.ack().await.map(|_| received))
+    }
+
+    /// Next selected event, acknowledged only when the caller is done
+    ///
+    /// Events the middleware or predicates reject are acknowledged here and
+    /// skipped.
+    pub(crate) async fn next_pending(&mut self) -> Option<InfrastructureResult<PendingEvent>> {
+        loop {
+            let message = match self.messages.next().await? {
+                Ok(message) => message,
+                Err(e) => return Some(Err(InfrastructureError::NatsSubscribe(e.to_string()))),
+            };
+            let sequence = match message.info() {
+                Ok(info) => info.stream_sequence,
+                Err(e) => return Some(Err(InfrastructureError::NatsSubscribe(e.to_string()))),
+            };
+            let payload = match resolve_payload(
+                self.payloads.as_ref(),
+                message.headers.as_ref(),
+                &message.payload,
+            )
+            .await
+            {
+                Ok(payload) => payload,
+                Err(e) => return Some(Err(e)),
+            };
+            let (event, envelope) =
+                match decode_event(&payload, message_content_type(message.headers.as_ref())) {
+                    Ok(decoded) => decoded,
+                    Err(e) => return Some(Err(e)),
+                };
+
+            let mut received = ReceivedEvent {
+                sequence,
+                subject: message.subject.to_string(),
+                event,
+                envelope,
+            };
+            let message = self.acknowledge.then_some(message);
+            let ran = match self.middleware.before(&mut received) {
+                Ok(()) if self.filter.matches(&received.event) => {
+                    return Some(Ok(PendingEvent {
+                        received,
+                        message,
+                        middleware: self.middleware.clone(),
+                    }));
+                }
+                Ok(()) => self.middleware.len(),
+                Err(ran) => ran,
+            };
+
+            let acked = ack_message(message).await;
+            let outcome = match &acked {
+                Ok(()) => Outcome::Skipped,
+                Err(e) => Outcome::Failed(e),
+            };
+            self.middleware.after(ran, &received, outcome);
+            if let Err(e) = acked {
+                return Some(Err(e));
+            }
+        }
+    }
+}
+
+/// A delivered event whose message is not acknowledged yet
+pub(crate) struct PendingEvent {
+    pub(crate) received: ReceivedEvent,
+    message: Option<jetstream::Message>,
+    middleware: Arc<MiddlewareChain>,
+}
+
+impl PendingEvent {
+    /// Acknowledge the message (no-op without acknowledgements)
+    pub(crate) async fn ack(self) -> InfrastructureResult<()> {
+        let acked = ack_message(self.message).await;
+        let outcome = match &acked {
+            Ok(()) => Outcome::Acknowledged,
+            Err(e) => Outcome::Failed(e),
+        };
+        self.middleware
+            .after(self.middleware.len(), &self.received, outcome);
+        acked
+    }
+
+    /// Give up on the event without acknowledging it, so it is redelivered
+    pub(crate) fn fail(self, error: &InfrastructureError) {
+        self.middleware.after(
+            self.middleware.len(),
+            &self.received,
+            Outcome::Failed(error),
+        );
+    }
+}
+
+/// Acknowledge a message (no-op without acknowledgements)
+async fn ack_message(message: Option<jetstream::Message>) -> InfrastructureResult<()> {
+    if let Some(message) = message {
+        message
+            .ack()
+            .await
+            .map_err(|e| InfrastructureError::NatsSubscribe(e.to_string()))?;
+    }
+    Ok(())
+}
+
+/// Decode a stored event with its envelope, or a bare event as services
+/// publish them
+fn decode_event(
+    payload: &[u8],
+    content_type: Option<&str>,
+) -> InfrastructureResult<(InfrastructureEvent, Option<EventEnvelopeMetadata>)> {
+    let raw = unwrap_structured(codec_for_content_type(content_type)?.decode(payload)?);
+    if let Ok((stored, _)) =
+        decode_payload::<StoredEvent<InfrastructureEvent>>(&raw, UnknownFields::Allow)
+    {
+        let envelope = EventEnvelopeMetadata::of(&stored);
+        return Ok((stored.data, envelope));
+    }
+    decode_payload::<InfrastructureEvent>(&raw, UnknownFields::Allow)
+        .map(|(event, _)| (event, None))
+        .map_err(|e| InfrastructureError::Deserialization(e.to_string()))
+}
+
+/// Consumer settings for a queue group and partition
+///
+/// Partitions get their own durable consumer (`<group>-<index>-of-<count>`)
+/// delivering one message at a time.
+fn group_consumer(
+    consumer: &ConsumerConfig,
+    queue_group: Option<&str>,
+    partition: Option<Partition>,
+) -> InfrastructureResult<ConsumerConfig> {
+    let mut consumer = consumer.clone();
+    if let Some(group) = queue_group {
+        if group.is_empty()
+            || group.contains(|c: char| c == '.' || c == '*' || c == '>' || c.is_whitespace())
+        {
+            return Err(InfrastructureError::Configuration(format!(
+                "Queue group '{}' is not a valid consumer name",
+                group
+            )));
+        }
+        consumer.name = group.to_string();
+    }
+    if let Some(partition) = partition {
+        if partition.index >= partition.count {
+            return Err(InfrastructureError::Configuration(format!(
+                "Partition {} is out of range for {} partitions",
+                partition.index, partition.count
+            )));
+        }
+        consumer.name = format!(
+            "{}-{}-of-{}",
+            consumer.name, partition.index, partition.count
+        );
+        consumer.max_ack_pending = 1;
+    }
+    Ok(consumer)
+}
+
+/// Pull consumer settings for a consumer config and compiled filter
+fn pull_config(
+    consumer: &ConsumerConfig,
+    filter_subjects: Vec<String>,
+) -> InfrastructureResult<consumer::pull::Config> {
+    let deliver_policy = match consumer.deliver_policy {
+        DeliverPolicy::All => consumer::DeliverPolicy::All,
+        DeliverPolicy::New => consumer::DeliverPolicy::New,
+        DeliverPolicy::ByStartSequence(start_sequence) => {
+            consumer::DeliverPolicy::ByStartSequence { start_sequence }
+        }
+        DeliverPolicy::ByStartTime(start) => {
+            return Err(InfrastructureError::Configuration(format!(
+                "Subscribing from a start time ({}) is not supported; use a start sequence",
+                start
+            )))
+        }
+    };
+    let ack_policy = match consumer.ack_policy {
+        AckPolicy::Explicit => consumer::AckPolicy::Explicit,
+        AckPolicy::None => consumer::AckPolicy::None,
+        AckPolicy::All => consumer::AckPolicy::All,
+    };
+
+    let mut config = consumer::pull::Config {
+        durable_name: Some(consumer.name.clone()),
+        deliver_policy,
+        ack_policy,
+        max_ack_pending: consumer.max_ack_pending,
+        ..Default::default()
+    };
+    match filter_subjects.len() {
+        0 => config.filter_subject = consumer.filter_subject.clone().unwrap_or_default(),
+        1 => config.filter_subject = filter_subjects[0].clone(),
+        _ => config.filter_subjects = filter_subjects,
+    }
+    Ok(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_subjects_compile_from_selection() {
+        // Arrange
+        let everything = EventFilter::new();
+        let networks = EventFilter::new().with_aggregate(AggregateType::Network);
+        let operations = networks
+            .clone()
+            .with_operations([Operation::Defined, Operation::Removed]);
+        let tenant_connections = EventFilter::new()
+            .with_tenant("acme")
+            .with_operations([Operation::Established]);
+
+        // Act / Assert
+        assert!(everything.filter_subjects().is_empty());
+        assert_eq!(networks.filter_subjects(), vec!["infrastructure.network.>"]);
+        assert_eq!(
+            operations.filter_subjects(),
+            vec![
+                "infrastructure.network.*.defined",
+                "infrastructure.network.*.removed"
+            ]
+        );
+        assert_eq!(
+            tenant_connections.filter_subjects(),
+            vec!["infrastructure.acme.*.*.established"]
+        );
+    }
+
+    #[test]
+    fn test_pull_config_uses_single_filter_subject() {
+        // Arrange
+        let consumer = ConsumerConfig {
+            filter_subject: Some("infrastructure.compute.>".to_string()),
+            ..ConsumerConfig::default()
+        };
+
+        // Act
+        let unfiltered = pull_config(&consumer, Vec::new()).unwrap();
+        let single = pull_config(&consumer, vec!["infrastructure.network.>".to_string()]).unwrap();
+        let several = pull_config(
+            &consumer,
+            vec![
+                "infrastructure.network.>".to_string(),
+                "infrastructure.connection.>".to_string(),
+            ],
+        )
+        .unwrap();
+
+        // Assert
+        assert_eq!(unfiltered.filter_subject, "infrastructure.compute.>");
+        assert_eq!(single.filter_subject, "infrastructure.network.>");
+        assert!(single.filter_subjects.is_empty());
+        assert_eq!(several.filter_subjects.len(), 2);
+        assert_eq!(
+            several.durable_name.as_deref(),
+            Some("infrastructure-consumer")
+        );
+    }
+
+    #[test]
+    fn test_partitions_get_their_own_serial_consumer() {
+        // Arrange
+        let consumer = ConsumerConfig::default();
+        let partition = Partition { index: 1, count: 4 };
+
+        // Act
+        let shared = group_consumer(&consumer, Some("neo4j"), None).unwrap();
+        let partitioned = group_consumer(&consumer, Some("neo4j"), Some(partition)).unwrap();
+
+        // Assert
+        assert_eq!(shared.name, "neo4j");
+        assert_eq!(shared.max_ack_pending, consumer.max_ack_pending);
+        assert_eq!(partitioned.name, "neo4j-1-of-4");
+        assert_eq!(partitioned.max_ack_pending, 1);
+        assert!(partition.owns(Uuid::from_u128(5)));
+        assert!(!partition.owns(Uuid::from_u128(6)));
+        assert!(group_consumer(&consumer, Some("neo4j.projection"), None).is_err());
+        assert!(group_consumer(&consumer, None, Some(Partition { index: 4, count: 4 })).is_err());
+    }
+}
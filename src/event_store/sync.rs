@@ -0,0 +1,292 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Upstream Sync for Local Event Stores
+//!
+//! Pushes events written to a [`SledEventStore`] at an edge site to an
+//! upstream [`EventStore`] (normally JetStream) once connectivity returns.
+//!
+//! # Semantics
+//!
+//! - Each aggregate's unsynced events go up in one append, with the last
+//!   synced version as the expected version. The upstream store rejects the
+//!   append if the aggregate changed there in the meantime; such aggregates
+//!   are reported as conflicts and retried on the next run, never
+//!   overwritten.
+//! - The synced version is recorded only after the upstream append
+//!   succeeded, so an interrupted run resends, never skips, events.
+//! - With [`LocalEventSync::with_progress`], runs with a backlog publish
+//!   progress (operation `edge_sync`, counted in events) on
+//!   `infrastructure.progress.<operation_id>`; conflicts are reported as
+//!   errors.
+//! - Cancellation is checked between aggregates, so a cancelled run never
+//!   leaves an aggregate half pushed.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::event_store::sync::LocalEventSync;
+//!
+//! let sync = LocalEventSync::new(local_store, Arc::new(nats_store));
+//! sync.run(Duration::from_secs(60)).await;
+//! ```
+
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::errors::{InfrastructureError, InfrastructureResult};
+use crate::event_store::{EventStore, SledEventStore};
+use crate::nats::progress::{CancellationToken, ProgressReporter};
+use crate::nats::NatsClient;
+
+/// Outcome of one sync run
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SyncReport {
+    /// Aggregates whose events were pushed
+    pub aggregates_synced: usize,
+
+    /// Events pushed upstream
+    pub events_pushed: u64,
+
+    /// Aggregates that changed upstream since the last sync
+    pub conflicts: Vec<Uuid>,
+}
+
+/// Pushes local events to an upstream event store
+pub struct LocalEventSync {
+    local: SledEventStore,
+    upstream: Arc<dyn EventStore>,
+    progress: Option<NatsClient>,
+}
+
+impl LocalEventSync {
+    /// Sync `local` into `upstream`
+    pub fn new(local: SledEventStore, upstream: Arc<dyn EventStore>) -> Self {
+        Self {
+            local,
+            upstream,
+            progress: None,
+        }
+    }
+
+    /// Publish progress of runs with a backlog through `client`
+    pub fn with_progress(mut self, client: NatsClient) -> Self {
+        self.progress = Some(client);
+        self
+    }
+
+    /// Push every unsynced event once
+    ///
+    /// Conflicts are reported in the result; other upstream errors (such as
+    /// lost connectivity) abort the run.
+    pub async fn sync_once(&self) -> InfrastructureResult<SyncReport> {
+        self.sync_once_with(&CancellationToken::new()).await
+    }
+
+    /// Push every unsynced event once, stopping early if `cancel` fires
+    ///
+    /// A cancelled run returns [`InfrastructureError::Cancelled`];
+    /// aggregates pushed before that stay synced.
+    pub async fn sync_once_with(
+        &self,
+        cancel: &CancellationToken,
+    ) -> InfrastructureResult<SyncReport> {
+        let mut pending = Vec::new();
+        for (aggregate_id, version) in self.local.aggregates()? {
+            let synced = self.local.synced_version(aggregate_id)?;
+            if version > synced {
+                pending.push((aggregate_id, version, synced));
+            }
+        }
+
+        if pending.is_empty() {
+            return Ok(SyncReport::default());
+        }
+
+        let mut progress = ProgressReporter::new("edge_sync");
+        if let Some(client) = &self.progress {
+            progress = progress.with_client(client.clone());
+        }
+        let backlog = pending
+            .iter()
+            .map(|(_, version, synced)| version - synced)
+            .sum();
+        progress.start(Some(backlog)).await;
+
+        match self.push(pending, &mut progress, cancel).await {
+            Ok(report) => {
+                progress.complete().await;
+                Ok(report)
+            }
+            Err(e @ InfrastructureError::Cancelled(_)) => {
+                progress.cancel().await;
+                Err(e)
+            }
+            Err(e) => {
+                progress.fail(e.to_string()).await;
+                Err(e)
+            }
+        }
+    }
+
+    /// Push each pending `(aggregate, local version, synced version)`
+    async fn push(
+        &self,
+        pending: Vec<(Uuid, u64, u64)>,
+        progress: &mut ProgressReporter,
+        cancel: &CancellationToken,
+    ) -> InfrastructureResult<SyncReport> {
+        let mut report = SyncReport::default();
+
+        for (aggregate_id, version, synced) in pending {
+            if cancel.is_cancelled() {
+                return Err(InfrastructureError::Cancelled(format!(
+                    "edge sync after {} aggregates",
+                    report.aggregates_synced
+                )));
+            }
+
+            let events: Vec<_> = self
+                .local
+                .read_events_from(aggregate_id, synced + 1)
+                .await?
+                .into_iter()
+                .map(|stored| stored.data)
+                .collect();
+            let count = events.len() as u64;
+
+            match self
+                .upstream
+                .append(aggregate_id, events, Some(synced))
+                .await
+            {
+                Ok(_) => {
+                    self.local.mark_synced(aggregate_id, version)?;
+                    report.aggregates_synced += 1;
+                    report.events_pushed += count;
+                    progress.advance(count).await;
+                }
+                Err(InfrastructureError::ConcurrencyError(message)) => {
+                    warn!("Aggregate {} changed upstream: {}", aggregate_id, message);
+                    progress
+                        .error(format!("aggregate {} changed upstream", aggregate_id))
+                        .await;
+                    progress.advance(version - synced).await;
+                    report.conflicts.push(aggregate_id);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Sync on a fixed interval, retrying after failures
+    pub async fn run(&self, interval: Duration) {
+        self.run_until(interval, &CancellationToken::new()).await
+    }
+
+    /// Sync on a fixed interval until `cancel` fires
+    pub async fn run_until(&self, interval: Duration, cancel: &CancellationToken) {
+        let mut ticker = tokio::time::interval(interval);
+
+        loop {
+            tokio::select! {
+                _ = cancel.cancelled() => {
+                    info!("Sync stopped");
+                    return;
+                }
+                _ = ticker.tick() => {}
+            }
+
+            match self.sync_once_with(cancel).await {
+                Ok(report) if report.events_pushed > 0 || !report.conflicts.is_empty() => info!(
+                    "Pushed {} events for {} aggregates ({} conflicts)",
+                    report.events_pushed,
+                    report.aggregates_synced,
+                    report.conflicts.len()
+                ),
+                Ok(_) => {}
+                Err(InfrastructureError::Cancelled(_)) => {}
+                Err(e) => warn!("Sync failed, retrying in {:?}: {}", interval, e),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Hostname, ResourceType};
+    use crate::events::compute_resource::{ComputeResourceEvent, ResourceRegistered};
+    use crate::events::InfrastructureEvent;
+    use chrono::{DateTime, Utc};
+
+    fn registered(aggregate_id: Uuid) -> InfrastructureEvent {
+        InfrastructureEvent::ComputeResource(ComputeResourceEvent::ResourceRegistered(
+            ResourceRegistered {
+                event_version: 1,
+                event_id: Uuid::now_v7(),
+                aggregate_id,
+                timestamp: DateTime::parse_from_rfc3339("2026-01-19T12:00:00Z")
+                    .unwrap()
+                    .with_timezone(&Utc),
+                correlation_id: Uuid::now_v7(),
+                causation_id: None,
+                hostname: Hostname::new("edge-01").unwrap(),
+                resource_type: ResourceType::PhysicalServer,
+            },
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_sync_pushes_only_new_events() {
+        // Arrange: a local edge store and a second store standing in for upstream
+        let local = SledEventStore::temporary().unwrap();
+        let upstream = SledEventStore::temporary().unwrap();
+        let aggregate_id = Uuid::now_v7();
+        local
+            .append(aggregate_id, vec![registered(aggregate_id)], Some(0))
+            .await
+            .unwrap();
+        let sync = LocalEventSync::new(local.clone(), Arc::new(upstream.clone()));
+
+        // Act
+        let first = sync.sync_once().await.unwrap();
+        local
+            .append(aggregate_id, vec![registered(aggregate_id)], Some(1))
+            .await
+            .unwrap();
+        let second = sync.sync_once().await.unwrap();
+        let third = sync.sync_once().await.unwrap();
+
+        // Assert
+        assert_eq!(first.events_pushed, 1);
+        assert_eq!(second.events_pushed, 1);
+        assert_eq!(third, SyncReport::default());
+        assert_eq!(upstream.get_version(aggregate_id).await.unwrap(), Some(2));
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_sync_pushes_nothing() {
+        // Arrange
+        let local = SledEventStore::temporary().unwrap();
+        let upstream = SledEventStore::temporary().unwrap();
+        let aggregate_id = Uuid::now_v7();
+        local
+            .append(aggregate_id, vec![registered(aggregate_id)], Some(0))
+            .await
+            .unwrap();
+        let sync = LocalEventSync::new(local.clone(), Arc::new(upstream.clone()));
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+
+        // Act
+        let result = sync.sync_once_with(&cancel).await;
+
+        // Assert - the backlog is still there for the next run
+        assert!(matches!(result, Err(InfrastructureError::Cancelled(_))));
+        assert_eq!(upstream.get_version(aggregate_id).await.unwrap(), None);
+        assert_eq!(sync.sync_once().await.unwrap().events_pushed, 1);
+    }
+}
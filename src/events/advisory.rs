@@ -0,0 +1,318 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Advisory Events
+//!
+//! Advisories are findings produced by background checks and read models
+//! (conflicts, capacity warnings, hygiene issues). Unlike aggregate events
+//! they do not change aggregate state and are not part of any aggregate's
+//! event stream; they are published for operators, alerting and dashboards.
+//!
+//! # Subjects
+//!
+//! ```text
+//! infrastructure.advisory.<advisory_type>
+//! ```
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::events::advisory::AdvisoryEvent;
+//!
+//! let advisory = AdvisoryEvent::IpConflictDetected(conflict_event);
+//! nats_client.publish(&advisory.subject(), &advisory).await?;
+//! ```
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+use uuid::Uuid;
+
+use crate::archival::StaleAggregate;
+use crate::domain::IpAddressWithCidr;
+use crate::reconcile::Drift;
+use crate::rollup::{HostRollup, RollupStatus};
+use crate::subjects::INFRASTRUCTURE_ROOT;
+
+/// Advisory events emitted by background checks
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AdvisoryEvent {
+    /// Same IP address assigned to multiple interfaces in one scope
+    IpConflictDetected(IpConflictDetected),
+
+    /// Network utilization crossed the configured threshold
+    SubnetNearlyFull(SubnetNearlyFull),
+
+    /// Resource has IPv4-only interfaces in a network that mandates dual-stack
+    DualStackIncomplete(DualStackIncomplete),
+
+    /// Observed infrastructure differs from the declared state
+    DriftDetected(DriftDetected),
+
+    /// Aggregate has been idle long enough to be archived
+    ArchivalCandidate(ArchivalCandidate),
+
+    /// Combined status of a host and its guests changed
+    HostRollupChanged(HostRollupChanged),
+
+    /// An organization ran out of its command rate
+    QuotaExceeded(QuotaExceeded),
+}
+
+impl AdvisoryEvent {
+    /// Advisory type token used in the subject
+    pub fn advisory_type(&self) -> &'static str {
+        match self {
+            AdvisoryEvent::IpConflictDetected(_) => "ip_conflict_detected",
+            AdvisoryEvent::SubnetNearlyFull(_) => "subnet_nearly_full",
+            AdvisoryEvent::DualStackIncomplete(_) => "dual_stack_incomplete",
+            AdvisoryEvent::DriftDetected(_) => "drift_detected",
+            AdvisoryEvent::ArchivalCandidate(_) => "archival_candidate",
+            AdvisoryEvent::HostRollupChanged(_) => "host_rollup_changed",
+            AdvisoryEvent::QuotaExceeded(_) => "quota_exceeded",
+        }
+    }
+
+    /// Unique advisory ID
+    pub fn event_id(&self) -> Uuid {
+        match self {
+            AdvisoryEvent::IpConflictDetected(e) => e.event_id,
+            AdvisoryEvent::SubnetNearlyFull(e) => e.event_id,
+            AdvisoryEvent::DualStackIncomplete(e) => e.event_id,
+            AdvisoryEvent::DriftDetected(e) => e.event_id,
+            AdvisoryEvent::ArchivalCandidate(e) => e.event_id,
+            AdvisoryEvent::HostRollupChanged(e) => e.event_id,
+            AdvisoryEvent::QuotaExceeded(e) => e.event_id,
+        }
+    }
+
+    /// When the condition was detected
+    pub fn detected_at(&self) -> DateTime<Utc> {
+        match self {
+            AdvisoryEvent::IpConflictDetected(e) => e.detected_at,
+            AdvisoryEvent::SubnetNearlyFull(e) => e.detected_at,
+            AdvisoryEvent::DualStackIncomplete(e) => e.detected_at,
+            AdvisoryEvent::DriftDetected(e) => e.detected_at,
+            AdvisoryEvent::ArchivalCandidate(e) => e.detected_at,
+            AdvisoryEvent::HostRollupChanged(e) => e.detected_at,
+            AdvisoryEvent::QuotaExceeded(e) => e.detected_at,
+        }
+    }
+
+    /// NATS subject for this advisory
+    pub fn subject(&self) -> String {
+        advisory_subject(self.advisory_type())
+    }
+}
+
+/// Subject for an advisory type
+pub fn advisory_subject(advisory_type: &str) -> String {
+    format!("{}.advisory.{}", INFRASTRUCTURE_ROOT, advisory_type)
+}
+
+/// Same IP address assigned to more than one interface within a scope
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct IpConflictDetected {
+    /// Unique advisory ID
+    pub event_id: Uuid,
+
+    /// When the conflict was detected
+    pub detected_at: DateTime<Utc>,
+
+    /// Correlation ID of the check run that found the conflict
+    pub correlation_id: Uuid,
+
+    /// Conflicting address
+    pub address: IpAddr,
+
+    /// Routing scope (VRF) of the conflict; None for the global table
+    pub vrf: Option<String>,
+
+    /// Interfaces holding the address
+    pub interface_ids: Vec<Uuid>,
+
+    /// Resources owning those interfaces
+    pub resource_ids: Vec<Uuid>,
+}
+
+/// Network utilization at or above the configured threshold
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct SubnetNearlyFull {
+    /// Unique advisory ID
+    pub event_id: Uuid,
+
+    /// When the condition was detected
+    pub detected_at: DateTime<Utc>,
+
+    /// Correlation ID of the check run
+    pub correlation_id: Uuid,
+
+    /// Network that is nearly full
+    pub network_id: Uuid,
+
+    /// Network prefix
+    pub prefix: IpAddressWithCidr,
+
+    /// Routing scope (VRF); None for the global table
+    pub vrf: Option<String>,
+
+    /// Addresses allocated
+    #[cfg_attr(feature = "typescript", ts(type = "number"))]
+    pub allocated: u128,
+
+    /// Addresses available for allocation in total
+    #[cfg_attr(feature = "typescript", ts(type = "number"))]
+    pub usable: u128,
+
+    /// Percentage of usable addresses allocated
+    pub utilization_percent: f64,
+
+    /// Threshold that was crossed, in percent
+    pub threshold_percent: f64,
+}
+
+/// Resource with interfaces addressed only from the IPv4 side of a dual-stack pair
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct DualStackIncomplete {
+    /// Unique advisory ID
+    pub event_id: Uuid,
+
+    /// When the condition was detected
+    pub detected_at: DateTime<Utc>,
+
+    /// Correlation ID of the check run
+    pub correlation_id: Uuid,
+
+    /// Resource with IPv4-only interfaces
+    pub resource_id: Uuid,
+
+    /// Interfaces missing an IPv6 address
+    pub interface_ids: Vec<Uuid>,
+
+    /// IPv4 network of the dual-stack pair
+    pub ipv4_network_id: Uuid,
+
+    /// IPv6 network of the dual-stack pair
+    pub ipv6_network_id: Uuid,
+}
+
+/// Observed infrastructure differs from the declared state
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct DriftDetected {
+    /// Unique advisory ID
+    pub event_id: Uuid,
+
+    /// When the drift was detected
+    pub detected_at: DateTime<Utc>,
+
+    /// Correlation ID of the reconciliation run
+    pub correlation_id: Uuid,
+
+    /// Agent or scan whose observation showed the drift
+    pub source: String,
+
+    /// The difference found
+    pub drift: Drift,
+}
+
+/// Aggregate without events for the configured idle period and not active
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ArchivalCandidate {
+    /// Unique advisory ID
+    pub event_id: Uuid,
+
+    /// When the aggregate was found idle
+    pub detected_at: DateTime<Utc>,
+
+    /// Correlation ID of the analysis run
+    pub correlation_id: Uuid,
+
+    /// The idle aggregate
+    pub candidate: StaleAggregate,
+}
+
+/// Rollup status of a host moved
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct HostRollupChanged {
+    /// Unique advisory ID
+    pub event_id: Uuid,
+
+    /// When the change was detected
+    pub detected_at: DateTime<Utc>,
+
+    /// Correlation ID of the event that caused the change
+    pub correlation_id: Uuid,
+
+    /// Rollup status before the change; None for the host's first rollup
+    pub previous: Option<RollupStatus>,
+
+    /// The host's rollup after the change
+    pub rollup: HostRollup,
+}
+
+/// Commands of an organization were throttled
+///
+/// Emitted once when the organization's token bucket runs dry, not for
+/// every command refused while it stays empty.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct QuotaExceeded {
+    /// Unique advisory ID
+    pub event_id: Uuid,
+
+    /// When the first command was refused
+    pub detected_at: DateTime<Utc>,
+
+    /// Correlation ID of the refused command
+    pub correlation_id: Uuid,
+
+    /// Throttled organization; None for commands naming no organization
+    pub organization_id: Option<String>,
+
+    /// Commands the organization may send in a burst
+    pub burst: u32,
+
+    /// Commands per second the bucket refills with
+    pub per_second: f64,
+
+    /// How long until the next command is admitted, in milliseconds
+    #[cfg_attr(feature = "typescript", ts(type = "number"))]
+    pub retry_after_ms: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_advisory_subject() {
+        let advisory = AdvisoryEvent::IpConflictDetected(IpConflictDetected {
+            event_id: Uuid::now_v7(),
+            detected_at: Utc::now(),
+            correlation_id: Uuid::now_v7(),
+            address: "10.0.0.5".parse().unwrap(),
+            vrf: None,
+            interface_ids: vec![Uuid::now_v7(), Uuid::now_v7()],
+            resource_ids: vec![Uuid::now_v7()],
+        });
+
+        assert_eq!(advisory.subject(), "infrastructure.advisory.ip_conflict_detected");
+
+        let json = serde_json::to_string(&advisory).unwrap();
+        assert!(json.contains("\"type\":\"ip_conflict_detected\""));
+    }
+}
@@ -0,0 +1,93 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Alert Events
+//!
+//! Raised when a declared [alert rule](crate::alert) reaches its threshold.
+//! Like policy events they are derived from aggregate events and name the
+//! event that fired the rule.
+//!
+//! # Subjects
+//!
+//! ```text
+//! infrastructure.alert.<rule>
+//! ```
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::alert::AlertSeverity;
+use crate::subjects::INFRASTRUCTURE_ROOT;
+
+/// An alert rule reached its threshold
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct AlertRaised {
+    /// Unique event ID
+    pub event_id: Uuid,
+
+    /// Timestamp of the event that fired the rule
+    pub raised_at: DateTime<Utc>,
+
+    /// Correlation ID of the firing event
+    pub correlation_id: Uuid,
+
+    /// ID of the firing event
+    pub causation_id: Uuid,
+
+    /// Rule that fired
+    pub rule: String,
+
+    /// Severity configured on the rule
+    pub severity: AlertSeverity,
+
+    /// Rule description, or a summary of what matched
+    pub message: String,
+
+    /// Matching events counted
+    pub count: u32,
+
+    /// Timestamp of the first counted event
+    pub first_seen: DateTime<Utc>,
+
+    /// Aggregates of the counted events
+    pub resource_ids: Vec<Uuid>,
+}
+
+impl AlertRaised {
+    /// NATS subject for this event
+    pub fn subject(&self) -> String {
+        alert_subject(&self.rule)
+    }
+}
+
+/// Subject for alerts of a rule
+pub fn alert_subject(rule: &str) -> String {
+    format!("{}.alert.{}", INFRASTRUCTURE_ROOT, rule)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alert_subject() {
+        let alert = AlertRaised {
+            event_id: Uuid::now_v7(),
+            raised_at: Utc::now(),
+            correlation_id: Uuid::now_v7(),
+            causation_id: Uuid::now_v7(),
+            rule: "decommission-burst".to_string(),
+            severity: AlertSeverity::Critical,
+            message: "Many resources decommissioned".to_string(),
+            count: 5,
+            first_seen: Utc::now(),
+            resource_ids: vec![Uuid::now_v7()],
+        };
+
+        assert_eq!(alert.subject(), "infrastructure.alert.decommission-burst");
+
+        let json = serde_json::to_value(&alert).unwrap();
+        assert_eq!(json["severity"], "critical");
+    }
+}
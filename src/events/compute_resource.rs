@@ -26,6 +26,8 @@ use crate::domain::{Hostname, ResourceType};
 /// All events are immutable and represent facts that have occurred.
 /// Each event type corresponds to a specific state change in the ComputeResource aggregate.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ComputeResourceEvent {
     /// Resource was registered/created
@@ -63,10 +65,21 @@ pub enum ComputeResourceEvent {
 
     /// Resource status changed (provisioning, active, maintenance, decommissioned)
     StatusChanged(StatusChanged),
+
+    /// Device configuration backup was taken
+    ConfigurationBackupRecorded(ConfigurationBackupRecorded),
+
+    /// A virtual machine or container host was placed on this host
+    GuestAttached(GuestAttached),
+
+    /// A guest was removed from this host
+    GuestDetached(GuestDetached),
 }
 
 /// Resource was initially registered in the system
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct ResourceRegistered {
     /// Event version for schema evolution
     pub event_version: u32,
@@ -95,6 +108,8 @@ pub struct ResourceRegistered {
 
 /// Organization ownership was assigned to resource
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct OrganizationAssigned {
     pub event_version: u32,
     pub event_id: Uuid,
@@ -104,11 +119,15 @@ pub struct OrganizationAssigned {
     pub causation_id: Option<Uuid>,
 
     /// Organization that now owns this resource
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
+    #[cfg_attr(feature = "json-schema", schemars(with = "String"))]
     pub organization_id: EntityId<Organization>,
 }
 
 /// Physical location was assigned to resource
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct LocationAssigned {
     pub event_version: u32,
     pub event_id: Uuid,
@@ -118,11 +137,15 @@ pub struct LocationAssigned {
     pub causation_id: Option<Uuid>,
 
     /// Physical location of resource
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
+    #[cfg_attr(feature = "json-schema", schemars(with = "String"))]
     pub location_id: EntityId<LocationMarker>,
 }
 
 /// Owner/primary contact was assigned
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct OwnerAssigned {
     pub event_version: u32,
     pub event_id: Uuid,
@@ -132,11 +155,15 @@ pub struct OwnerAssigned {
     pub causation_id: Option<Uuid>,
 
     /// Person who owns/is responsible for this resource
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
+    #[cfg_attr(feature = "json-schema", schemars(with = "String"))]
     pub owner_id: PersonId,
 }
 
 /// Policy was added to resource
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct PolicyAdded {
     pub event_version: u32,
     pub event_id: Uuid,
@@ -146,11 +173,15 @@ pub struct PolicyAdded {
     pub causation_id: Option<Uuid>,
 
     /// Policy that was applied
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
+    #[cfg_attr(feature = "json-schema", schemars(with = "String"))]
     pub policy_id: PolicyId,
 }
 
 /// Policy was removed from resource
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct PolicyRemoved {
     pub event_version: u32,
     pub event_id: Uuid,
@@ -160,11 +191,15 @@ pub struct PolicyRemoved {
     pub causation_id: Option<Uuid>,
 
     /// Policy that was removed
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
+    #[cfg_attr(feature = "json-schema", schemars(with = "String"))]
     pub policy_id: PolicyId,
 }
 
 /// Account concept was associated with resource
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct AccountConceptAssigned {
     pub event_version: u32,
     pub event_id: Uuid,
@@ -174,11 +209,15 @@ pub struct AccountConceptAssigned {
     pub causation_id: Option<Uuid>,
 
     /// Concept ID in conceptual space
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
+    #[cfg_attr(feature = "json-schema", schemars(with = "String"))]
     pub concept_id: ConceptId,
 }
 
 /// Account concept association was removed
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct AccountConceptCleared {
     pub event_version: u32,
     pub event_id: Uuid,
@@ -190,6 +229,8 @@ pub struct AccountConceptCleared {
 
 /// Hardware details were set
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct HardwareDetailsSet {
     pub event_version: u32,
     pub event_id: Uuid,
@@ -210,6 +251,8 @@ pub struct HardwareDetailsSet {
 
 /// Asset tag was assigned
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct AssetTagAssigned {
     pub event_version: u32,
     pub event_id: Uuid,
@@ -224,6 +267,8 @@ pub struct AssetTagAssigned {
 
 /// Metadata entry was added or updated
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct MetadataUpdated {
     pub event_version: u32,
     pub event_id: Uuid,
@@ -241,6 +286,8 @@ pub struct MetadataUpdated {
 
 /// Resource status changed
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct StatusChanged {
     pub event_version: u32,
     pub event_id: Uuid,
@@ -256,8 +303,89 @@ pub struct StatusChanged {
     pub to_status: ResourceStatus,
 }
 
+/// Reference to a stored device configuration backup
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ConfigurationBackupRef {
+    /// Object store bucket holding the backup
+    pub object_store: String,
+
+    /// Object key/name within the bucket
+    pub object_key: String,
+
+    /// Content hash in `<algorithm>:<hex digest>` form (e.g. "sha256:9f86d0...")
+    pub content_hash: String,
+
+    /// Tool that took the backup (e.g. "oxidized", "rancid", "ansible")
+    pub tool: String,
+
+    /// When the backup was taken on the device
+    pub taken_at: DateTime<Utc>,
+
+    /// Backup size in bytes, if known
+    #[cfg_attr(feature = "typescript", ts(type = "number | null"))]
+    pub size_bytes: Option<u64>,
+}
+
+/// Configuration backup of the device was recorded
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ConfigurationBackupRecorded {
+    pub event_version: u32,
+    pub event_id: Uuid,
+    pub aggregate_id: Uuid,
+    pub timestamp: DateTime<Utc>,
+    pub correlation_id: Uuid,
+    pub causation_id: Option<Uuid>,
+
+    /// Where the backup lives and how it was produced
+    pub backup: ConfigurationBackupRef,
+}
+
+/// A guest was placed on the host
+///
+/// Recorded on the host aggregate, which owns its guest list.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct GuestAttached {
+    pub event_version: u32,
+    pub event_id: Uuid,
+    pub aggregate_id: Uuid,
+    pub timestamp: DateTime<Utc>,
+    pub correlation_id: Uuid,
+    pub causation_id: Option<Uuid>,
+
+    /// Guest resource aggregate
+    pub guest_id: Uuid,
+
+    /// Resource type of the guest
+    pub guest_type: ResourceType,
+}
+
+/// A guest was removed from the host
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct GuestDetached {
+    pub event_version: u32,
+    pub event_id: Uuid,
+    pub aggregate_id: Uuid,
+    pub timestamp: DateTime<Utc>,
+    pub correlation_id: Uuid,
+    pub causation_id: Option<Uuid>,
+
+    /// Guest resource aggregate
+    pub guest_id: Uuid,
+}
+
 /// Resource lifecycle status
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
 #[serde(rename_all = "snake_case")]
 pub enum ResourceStatus {
     /// Resource is being provisioned
@@ -354,6 +482,18 @@ impl StatusChanged {
     pub const CURRENT_VERSION: u32 = 1;
 }
 
+impl ConfigurationBackupRecorded {
+    pub const CURRENT_VERSION: u32 = 1;
+}
+
+impl GuestAttached {
+    pub const CURRENT_VERSION: u32 = 1;
+}
+
+impl GuestDetached {
+    pub const CURRENT_VERSION: u32 = 1;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,100 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Physical Connection Domain Events
+//!
+//! State changes of PhysicalConnection aggregates: a cable patched between
+//! two interfaces.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::Hostname;
+
+/// Physical Connection Domain Events
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ConnectionEvent {
+    /// Cable was patched between two interfaces
+    ConnectionEstablished(ConnectionEstablished),
+
+    /// Cable label was set or changed
+    ConnectionLabeled(ConnectionLabeled),
+
+    /// Cable was removed, freeing both ports
+    ConnectionRemoved(ConnectionRemoved),
+}
+
+/// One end of a physical connection
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ConnectionEndpoint {
+    /// Interface aggregate the cable plugs into
+    pub interface_id: Uuid,
+
+    /// Compute resource owning the interface
+    pub resource_id: Uuid,
+
+    /// Hostname of the resource (carried for projections that key devices
+    /// by name, such as NetBox)
+    pub device: Hostname,
+
+    /// Port name on the device (e.g. `xe-0/0/1`)
+    pub port: String,
+}
+
+/// Cable was patched between two interfaces
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ConnectionEstablished {
+    pub event_version: u32,
+    pub event_id: Uuid,
+    pub aggregate_id: Uuid,
+    pub timestamp: DateTime<Utc>,
+    pub correlation_id: Uuid,
+    pub causation_id: Option<Uuid>,
+
+    /// A side of the cable
+    pub a_end: ConnectionEndpoint,
+
+    /// B side of the cable
+    pub b_end: ConnectionEndpoint,
+
+    /// Cable label, if known when patched
+    pub label: Option<String>,
+}
+
+/// Cable label was set or changed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ConnectionLabeled {
+    pub event_version: u32,
+    pub event_id: Uuid,
+    pub aggregate_id: Uuid,
+    pub timestamp: DateTime<Utc>,
+    pub correlation_id: Uuid,
+    pub causation_id: Option<Uuid>,
+
+    /// New label
+    pub label: String,
+}
+
+/// Cable was removed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ConnectionRemoved {
+    pub event_version: u32,
+    pub event_id: Uuid,
+    pub aggregate_id: Uuid,
+    pub timestamp: DateTime<Utc>,
+    pub correlation_id: Uuid,
+    pub causation_id: Option<Uuid>,
+
+    /// Why the cable was removed, if recorded
+    pub reason: Option<String>,
+}
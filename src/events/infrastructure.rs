@@ -10,6 +10,11 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use super::compute_resource::ComputeResourceEvent;
+use super::connection::ConnectionEvent;
+use super::ip_pool::IpPoolEvent;
+use super::network::NetworkEvent;
+use super::network_interface::NetworkInterfaceEvent;
+use crate::subjects::{AggregateType, Operation, SubjectBuilder};
 
 /// Infrastructure Domain Events
 ///
@@ -22,13 +27,26 @@ use super::compute_resource::ComputeResourceEvent;
 /// - Supports future aggregate types (Network, Storage, etc.)
 /// - Enables polymorphic projections
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(tag = "aggregate_type", content = "event", rename_all = "snake_case")]
 pub enum InfrastructureEvent {
     /// Events from ComputeResource aggregate
     ComputeResource(ComputeResourceEvent),
 
+    /// Events from Network aggregate
+    Network(NetworkEvent),
+
+    /// Events from NetworkInterface aggregate
+    NetworkInterface(NetworkInterfaceEvent),
+
+    /// Events from PhysicalConnection aggregate
+    Connection(ConnectionEvent),
+
+    /// Events from IpPool aggregate
+    IpPool(IpPoolEvent),
+
     // Future aggregate types:
-    // Network(NetworkEvent) - routers, switches, VLANs
     // Storage(StorageEvent) - volumes, arrays, snapshots
     // Container(ContainerEvent) - pods, deployments, services
 }
@@ -38,6 +56,21 @@ impl InfrastructureEvent {
     pub fn aggregate_id(&self) -> Uuid {
         match self {
             InfrastructureEvent::ComputeResource(event) => event.aggregate_id(),
+            InfrastructureEvent::Network(event) => event.aggregate_id(),
+            InfrastructureEvent::NetworkInterface(event) => event.aggregate_id(),
+            InfrastructureEvent::Connection(event) => event.aggregate_id(),
+            InfrastructureEvent::IpPool(event) => event.aggregate_id(),
+        }
+    }
+
+    /// Extract event ID from any event type
+    pub fn event_id(&self) -> Uuid {
+        match self {
+            InfrastructureEvent::ComputeResource(event) => event.event_id(),
+            InfrastructureEvent::Network(event) => event.event_id(),
+            InfrastructureEvent::NetworkInterface(event) => event.event_id(),
+            InfrastructureEvent::Connection(event) => event.event_id(),
+            InfrastructureEvent::IpPool(event) => event.event_id(),
         }
     }
 
@@ -45,6 +78,10 @@ impl InfrastructureEvent {
     pub fn timestamp(&self) -> DateTime<Utc> {
         match self {
             InfrastructureEvent::ComputeResource(event) => event.timestamp(),
+            InfrastructureEvent::Network(event) => event.timestamp(),
+            InfrastructureEvent::NetworkInterface(event) => event.timestamp(),
+            InfrastructureEvent::Connection(event) => event.timestamp(),
+            InfrastructureEvent::IpPool(event) => event.timestamp(),
         }
     }
 
@@ -52,6 +89,10 @@ impl InfrastructureEvent {
     pub fn correlation_id(&self) -> Uuid {
         match self {
             InfrastructureEvent::ComputeResource(event) => event.correlation_id(),
+            InfrastructureEvent::Network(event) => event.correlation_id(),
+            InfrastructureEvent::NetworkInterface(event) => event.correlation_id(),
+            InfrastructureEvent::Connection(event) => event.correlation_id(),
+            InfrastructureEvent::IpPool(event) => event.correlation_id(),
         }
     }
 
@@ -59,6 +100,10 @@ impl InfrastructureEvent {
     pub fn causation_id(&self) -> Option<Uuid> {
         match self {
             InfrastructureEvent::ComputeResource(event) => event.causation_id(),
+            InfrastructureEvent::Network(event) => event.causation_id(),
+            InfrastructureEvent::NetworkInterface(event) => event.causation_id(),
+            InfrastructureEvent::Connection(event) => event.causation_id(),
+            InfrastructureEvent::IpPool(event) => event.causation_id(),
         }
     }
 
@@ -66,13 +111,52 @@ impl InfrastructureEvent {
     pub fn event_version(&self) -> u32 {
         match self {
             InfrastructureEvent::ComputeResource(event) => event.event_version(),
+            InfrastructureEvent::Network(event) => event.event_version(),
+            InfrastructureEvent::NetworkInterface(event) => event.event_version(),
+            InfrastructureEvent::Connection(event) => event.event_version(),
+            InfrastructureEvent::IpPool(event) => event.event_version(),
+        }
+    }
+
+    /// Aggregate type token used in event subjects
+    pub fn aggregate_type(&self) -> AggregateType {
+        match self {
+            InfrastructureEvent::ComputeResource(_) => AggregateType::Compute,
+            InfrastructureEvent::Network(_) => AggregateType::Network,
+            InfrastructureEvent::NetworkInterface(_) => AggregateType::Interface,
+            InfrastructureEvent::IpPool(_) => AggregateType::IpPool,
+            InfrastructureEvent::Connection(_) => AggregateType::Connection,
+        }
+    }
+
+    /// Operation token used in event subjects
+    pub fn operation(&self) -> Operation {
+        match self {
+            InfrastructureEvent::ComputeResource(event) => event.operation(),
+            InfrastructureEvent::Network(event) => event.operation(),
+            InfrastructureEvent::NetworkInterface(event) => event.operation(),
+            InfrastructureEvent::Connection(event) => event.operation(),
+            InfrastructureEvent::IpPool(event) => event.operation(),
         }
     }
 
+    /// Event subject: `infrastructure.{aggregate}.{aggregate_id}.{operation}`
+    pub fn subject(&self) -> String {
+        SubjectBuilder::new()
+            .aggregate(self.aggregate_type())
+            .aggregate_id(self.aggregate_id())
+            .operation(self.operation())
+            .build()
+    }
+
     /// Get human-readable event type name
     pub fn event_type_name(&self) -> &str {
         match self {
             InfrastructureEvent::ComputeResource(event) => event.event_type_name(),
+            InfrastructureEvent::Network(event) => event.event_type_name(),
+            InfrastructureEvent::NetworkInterface(event) => event.event_type_name(),
+            InfrastructureEvent::Connection(event) => event.event_type_name(),
+            InfrastructureEvent::IpPool(event) => event.event_type_name(),
         }
     }
 }
@@ -95,6 +179,32 @@ impl ComputeResourceEvent {
             AssetTagAssigned(e) => e.aggregate_id,
             MetadataUpdated(e) => e.aggregate_id,
             StatusChanged(e) => e.aggregate_id,
+            ConfigurationBackupRecorded(e) => e.aggregate_id,
+            GuestAttached(e) => e.aggregate_id,
+            GuestDetached(e) => e.aggregate_id,
+        }
+    }
+
+    /// Extract event ID from compute resource event
+    pub fn event_id(&self) -> Uuid {
+        use super::compute_resource::ComputeResourceEvent::*;
+
+        match self {
+            ResourceRegistered(e) => e.event_id,
+            OrganizationAssigned(e) => e.event_id,
+            LocationAssigned(e) => e.event_id,
+            OwnerAssigned(e) => e.event_id,
+            PolicyAdded(e) => e.event_id,
+            PolicyRemoved(e) => e.event_id,
+            AccountConceptAssigned(e) => e.event_id,
+            AccountConceptCleared(e) => e.event_id,
+            HardwareDetailsSet(e) => e.event_id,
+            AssetTagAssigned(e) => e.event_id,
+            MetadataUpdated(e) => e.event_id,
+            StatusChanged(e) => e.event_id,
+            ConfigurationBackupRecorded(e) => e.event_id,
+            GuestAttached(e) => e.event_id,
+            GuestDetached(e) => e.event_id,
         }
     }
 
@@ -115,6 +225,9 @@ impl ComputeResourceEvent {
             AssetTagAssigned(e) => e.timestamp,
             MetadataUpdated(e) => e.timestamp,
             StatusChanged(e) => e.timestamp,
+            ConfigurationBackupRecorded(e) => e.timestamp,
+            GuestAttached(e) => e.timestamp,
+            GuestDetached(e) => e.timestamp,
         }
     }
 
@@ -135,6 +248,9 @@ impl ComputeResourceEvent {
             AssetTagAssigned(e) => e.correlation_id,
             MetadataUpdated(e) => e.correlation_id,
             StatusChanged(e) => e.correlation_id,
+            ConfigurationBackupRecorded(e) => e.correlation_id,
+            GuestAttached(e) => e.correlation_id,
+            GuestDetached(e) => e.correlation_id,
         }
     }
 
@@ -155,6 +271,9 @@ impl ComputeResourceEvent {
             AssetTagAssigned(e) => e.causation_id,
             MetadataUpdated(e) => e.causation_id,
             StatusChanged(e) => e.causation_id,
+            ConfigurationBackupRecorded(e) => e.causation_id,
+            GuestAttached(e) => e.causation_id,
+            GuestDetached(e) => e.causation_id,
         }
     }
 
@@ -175,6 +294,9 @@ impl ComputeResourceEvent {
             AssetTagAssigned(e) => e.event_version,
             MetadataUpdated(e) => e.event_version,
             StatusChanged(e) => e.event_version,
+            ConfigurationBackupRecorded(e) => e.event_version,
+            GuestAttached(e) => e.event_version,
+            GuestDetached(e) => e.event_version,
         }
     }
 
@@ -195,6 +317,392 @@ impl ComputeResourceEvent {
             AssetTagAssigned(_) => "AssetTagAssigned",
             MetadataUpdated(_) => "MetadataUpdated",
             StatusChanged(_) => "StatusChanged",
+            ConfigurationBackupRecorded(_) => "ConfigurationBackupRecorded",
+            GuestAttached(_) => "GuestAttached",
+            GuestDetached(_) => "GuestDetached",
+        }
+    }
+
+    /// Operation token used in event subjects
+    pub fn operation(&self) -> Operation {
+        use super::compute_resource::ComputeResourceEvent::*;
+
+        match self {
+            ResourceRegistered(_) => Operation::Registered,
+            OrganizationAssigned(_) => Operation::OrganizationAssigned,
+            LocationAssigned(_) => Operation::LocationAssigned,
+            OwnerAssigned(_) => Operation::OwnerAssigned,
+            PolicyAdded(_) => Operation::PolicyAdded,
+            PolicyRemoved(_) => Operation::PolicyRemoved,
+            AccountConceptAssigned(_) => Operation::AccountConceptAssigned,
+            AccountConceptCleared(_) => Operation::AccountConceptCleared,
+            HardwareDetailsSet(_) => Operation::HardwareDetailsSet,
+            AssetTagAssigned(_) => Operation::AssetTagAssigned,
+            MetadataUpdated(_) => Operation::MetadataUpdated,
+            StatusChanged(_) => Operation::StatusChanged,
+            ConfigurationBackupRecorded(_) => Operation::ConfigurationBackupRecorded,
+            GuestAttached(_) => Operation::GuestAttached,
+            GuestDetached(_) => Operation::GuestDetached,
+        }
+    }
+}
+
+impl NetworkEvent {
+    /// Extract aggregate ID from network event
+    pub fn aggregate_id(&self) -> Uuid {
+        use super::network::NetworkEvent::*;
+
+        match self {
+            NetworkDefined(e) => e.aggregate_id,
+            CidrChanged(e) => e.aggregate_id,
+            VlanAssigned(e) => e.aggregate_id,
+        }
+    }
+
+    /// Extract event ID from network event
+    pub fn event_id(&self) -> Uuid {
+        use super::network::NetworkEvent::*;
+
+        match self {
+            NetworkDefined(e) => e.event_id,
+            CidrChanged(e) => e.event_id,
+            VlanAssigned(e) => e.event_id,
+        }
+    }
+
+    /// Extract timestamp from network event
+    pub fn timestamp(&self) -> DateTime<Utc> {
+        use super::network::NetworkEvent::*;
+
+        match self {
+            NetworkDefined(e) => e.timestamp,
+            CidrChanged(e) => e.timestamp,
+            VlanAssigned(e) => e.timestamp,
+        }
+    }
+
+    /// Extract correlation ID from network event
+    pub fn correlation_id(&self) -> Uuid {
+        use super::network::NetworkEvent::*;
+
+        match self {
+            NetworkDefined(e) => e.correlation_id,
+            CidrChanged(e) => e.correlation_id,
+            VlanAssigned(e) => e.correlation_id,
+        }
+    }
+
+    /// Extract causation ID from network event
+    pub fn causation_id(&self) -> Option<Uuid> {
+        use super::network::NetworkEvent::*;
+
+        match self {
+            NetworkDefined(e) => e.causation_id,
+            CidrChanged(e) => e.causation_id,
+            VlanAssigned(e) => e.causation_id,
+        }
+    }
+
+    /// Extract event version from network event
+    pub fn event_version(&self) -> u32 {
+        use super::network::NetworkEvent::*;
+
+        match self {
+            NetworkDefined(e) => e.event_version,
+            CidrChanged(e) => e.event_version,
+            VlanAssigned(e) => e.event_version,
+        }
+    }
+
+    /// Get human-readable event type name
+    pub fn event_type_name(&self) -> &str {
+        use super::network::NetworkEvent::*;
+
+        match self {
+            NetworkDefined(_) => "NetworkDefined",
+            CidrChanged(_) => "CidrChanged",
+            VlanAssigned(_) => "VlanAssigned",
+        }
+    }
+
+    /// Operation token used in event subjects
+    pub fn operation(&self) -> Operation {
+        use super::network::NetworkEvent::*;
+
+        match self {
+            NetworkDefined(_) => Operation::Defined,
+            CidrChanged(_) => Operation::CidrChanged,
+            VlanAssigned(_) => Operation::VlanAssigned,
+        }
+    }
+}
+
+impl NetworkInterfaceEvent {
+    /// Extract aggregate ID from network interface event
+    pub fn aggregate_id(&self) -> Uuid {
+        use super::network_interface::NetworkInterfaceEvent::*;
+
+        match self {
+            InterfaceAttached(e) => e.aggregate_id,
+            BondFormed(e) => e.aggregate_id,
+            BondDissolved(e) => e.aggregate_id,
+        }
+    }
+
+    /// Extract event ID from network interface event
+    pub fn event_id(&self) -> Uuid {
+        use super::network_interface::NetworkInterfaceEvent::*;
+
+        match self {
+            InterfaceAttached(e) => e.event_id,
+            BondFormed(e) => e.event_id,
+            BondDissolved(e) => e.event_id,
+        }
+    }
+
+    /// Extract timestamp from network interface event
+    pub fn timestamp(&self) -> DateTime<Utc> {
+        use super::network_interface::NetworkInterfaceEvent::*;
+
+        match self {
+            InterfaceAttached(e) => e.timestamp,
+            BondFormed(e) => e.timestamp,
+            BondDissolved(e) => e.timestamp,
+        }
+    }
+
+    /// Extract correlation ID from network interface event
+    pub fn correlation_id(&self) -> Uuid {
+        use super::network_interface::NetworkInterfaceEvent::*;
+
+        match self {
+            InterfaceAttached(e) => e.correlation_id,
+            BondFormed(e) => e.correlation_id,
+            BondDissolved(e) => e.correlation_id,
+        }
+    }
+
+    /// Extract causation ID from network interface event
+    pub fn causation_id(&self) -> Option<Uuid> {
+        use super::network_interface::NetworkInterfaceEvent::*;
+
+        match self {
+            InterfaceAttached(e) => e.causation_id,
+            BondFormed(e) => e.causation_id,
+            BondDissolved(e) => e.causation_id,
+        }
+    }
+
+    /// Extract event version from network interface event
+    pub fn event_version(&self) -> u32 {
+        use super::network_interface::NetworkInterfaceEvent::*;
+
+        match self {
+            InterfaceAttached(e) => e.event_version,
+            BondFormed(e) => e.event_version,
+            BondDissolved(e) => e.event_version,
+        }
+    }
+
+    /// Get human-readable event type name
+    pub fn event_type_name(&self) -> &str {
+        use super::network_interface::NetworkInterfaceEvent::*;
+
+        match self {
+            InterfaceAttached(_) => "InterfaceAttached",
+            BondFormed(_) => "BondFormed",
+            BondDissolved(_) => "BondDissolved",
+        }
+    }
+
+    /// Operation token used in event subjects
+    pub fn operation(&self) -> Operation {
+        use super::network_interface::NetworkInterfaceEvent::*;
+
+        match self {
+            InterfaceAttached(_) => Operation::Attached,
+            BondFormed(_) => Operation::BondFormed,
+            BondDissolved(_) => Operation::BondDissolved,
+        }
+    }
+}
+
+impl ConnectionEvent {
+    /// Extract aggregate ID from connection event
+    pub fn aggregate_id(&self) -> Uuid {
+        use super::connection::ConnectionEvent::*;
+
+        match self {
+            ConnectionEstablished(e) => e.aggregate_id,
+            ConnectionLabeled(e) => e.aggregate_id,
+            ConnectionRemoved(e) => e.aggregate_id,
+        }
+    }
+
+    /// Extract event ID from connection event
+    pub fn event_id(&self) -> Uuid {
+        use super::connection::ConnectionEvent::*;
+
+        match self {
+            ConnectionEstablished(e) => e.event_id,
+            ConnectionLabeled(e) => e.event_id,
+            ConnectionRemoved(e) => e.event_id,
+        }
+    }
+
+    /// Extract timestamp from connection event
+    pub fn timestamp(&self) -> DateTime<Utc> {
+        use super::connection::ConnectionEvent::*;
+
+        match self {
+            ConnectionEstablished(e) => e.timestamp,
+            ConnectionLabeled(e) => e.timestamp,
+            ConnectionRemoved(e) => e.timestamp,
+        }
+    }
+
+    /// Extract correlation ID from connection event
+    pub fn correlation_id(&self) -> Uuid {
+        use super::connection::ConnectionEvent::*;
+
+        match self {
+            ConnectionEstablished(e) => e.correlation_id,
+            ConnectionLabeled(e) => e.correlation_id,
+            ConnectionRemoved(e) => e.correlation_id,
+        }
+    }
+
+    /// Extract causation ID from connection event
+    pub fn causation_id(&self) -> Option<Uuid> {
+        use super::connection::ConnectionEvent::*;
+
+        match self {
+            ConnectionEstablished(e) => e.causation_id,
+            ConnectionLabeled(e) => e.causation_id,
+            ConnectionRemoved(e) => e.causation_id,
+        }
+    }
+
+    /// Extract event version from connection event
+    pub fn event_version(&self) -> u32 {
+        use super::connection::ConnectionEvent::*;
+
+        match self {
+            ConnectionEstablished(e) => e.event_version,
+            ConnectionLabeled(e) => e.event_version,
+            ConnectionRemoved(e) => e.event_version,
+        }
+    }
+
+    /// Get human-readable event type name
+    pub fn event_type_name(&self) -> &str {
+        use super::connection::ConnectionEvent::*;
+
+        match self {
+            ConnectionEstablished(_) => "ConnectionEstablished",
+            ConnectionLabeled(_) => "ConnectionLabeled",
+            ConnectionRemoved(_) => "ConnectionRemoved",
+        }
+    }
+
+    /// Operation token used in event subjects
+    pub fn operation(&self) -> Operation {
+        use super::connection::ConnectionEvent::*;
+
+        match self {
+            ConnectionEstablished(_) => Operation::Established,
+            ConnectionLabeled(_) => Operation::Labeled,
+            ConnectionRemoved(_) => Operation::Removed,
+        }
+    }
+}
+
+impl IpPoolEvent {
+    /// Extract aggregate ID from IP pool event
+    pub fn aggregate_id(&self) -> Uuid {
+        use super::ip_pool::IpPoolEvent::*;
+
+        match self {
+            PoolDefined(e) => e.aggregate_id,
+            AddressAllocated(e) => e.aggregate_id,
+            AddressReleased(e) => e.aggregate_id,
+        }
+    }
+
+    /// Extract event ID from IP pool event
+    pub fn event_id(&self) -> Uuid {
+        use super::ip_pool::IpPoolEvent::*;
+
+        match self {
+            PoolDefined(e) => e.event_id,
+            AddressAllocated(e) => e.event_id,
+            AddressReleased(e) => e.event_id,
+        }
+    }
+
+    /// Extract timestamp from IP pool event
+    pub fn timestamp(&self) -> DateTime<Utc> {
+        use super::ip_pool::IpPoolEvent::*;
+
+        match self {
+            PoolDefined(e) => e.timestamp,
+            AddressAllocated(e) => e.timestamp,
+            AddressReleased(e) => e.timestamp,
+        }
+    }
+
+    /// Extract correlation ID from IP pool event
+    pub fn correlation_id(&self) -> Uuid {
+        use super::ip_pool::IpPoolEvent::*;
+
+        match self {
+            PoolDefined(e) => e.correlation_id,
+            AddressAllocated(e) => e.correlation_id,
+            AddressReleased(e) => e.correlation_id,
+        }
+    }
+
+    /// Extract causation ID from IP pool event
+    pub fn causation_id(&self) -> Option<Uuid> {
+        use super::ip_pool::IpPoolEvent::*;
+
+        match self {
+            PoolDefined(e) => e.causation_id,
+            AddressAllocated(e) => e.causation_id,
+            AddressReleased(e) => e.causation_id,
+        }
+    }
+
+    /// Extract event version from IP pool event
+    pub fn event_version(&self) -> u32 {
+        use super::ip_pool::IpPoolEvent::*;
+
+        match self {
+            PoolDefined(e) => e.event_version,
+            AddressAllocated(e) => e.event_version,
+            AddressReleased(e) => e.event_version,
+        }
+    }
+
+    /// Get human-readable event type name
+    pub fn event_type_name(&self) -> &str {
+        use super::ip_pool::IpPoolEvent::*;
+
+        match self {
+            PoolDefined(_) => "PoolDefined",
+            AddressAllocated(_) => "AddressAllocated",
+            AddressReleased(_) => "AddressReleased",
+        }
+    }
+
+    /// Operation token used in event subjects
+    pub fn operation(&self) -> Operation {
+        use super::ip_pool::IpPoolEvent::*;
+
+        match self {
+            PoolDefined(_) => Operation::Defined,
+            AddressAllocated(_) => Operation::AddressAllocated,
+            AddressReleased(_) => Operation::AddressReleased,
         }
     }
 }
@@ -225,6 +733,13 @@ mod tests {
         assert_eq!(infra_event.correlation_id(), compute_event.correlation_id());
         assert_eq!(infra_event.event_version(), 1);
         assert_eq!(infra_event.event_type_name(), "ResourceRegistered");
+        assert_eq!(
+            infra_event.subject(),
+            format!(
+                "infrastructure.compute.{}.registered",
+                compute_event.aggregate_id()
+            )
+        );
     }
 
     #[test]
@@ -0,0 +1,98 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! IP Pool Domain Events
+//!
+//! State changes of IpPool aggregates: a prefix whose addresses are handed
+//! out to interfaces one at a time.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+use uuid::Uuid;
+
+use crate::domain::IpAddressWithCidr;
+use crate::ipam::ReservedRange;
+
+/// IP Pool Domain Events
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum IpPoolEvent {
+    /// Pool was defined over a prefix
+    PoolDefined(PoolDefined),
+
+    /// Address was allocated to an interface
+    AddressAllocated(AddressAllocated),
+
+    /// Address was returned to the pool
+    AddressReleased(AddressReleased),
+}
+
+/// Pool was defined over a prefix
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct PoolDefined {
+    pub event_version: u32,
+    pub event_id: Uuid,
+    pub aggregate_id: Uuid,
+    pub timestamp: DateTime<Utc>,
+    pub correlation_id: Uuid,
+    pub causation_id: Option<Uuid>,
+
+    /// Pool name
+    pub name: String,
+
+    /// Prefix the pool allocates from (host bits cleared)
+    pub cidr: IpAddressWithCidr,
+
+    /// Routing scope (VRF); None for the global routing table
+    pub vrf: Option<String>,
+
+    /// Network aggregate the pool serves, if any
+    pub network_id: Option<Uuid>,
+
+    /// Ranges never handed out (gateway, DHCP pool, anycast)
+    pub reserved: Vec<ReservedRange>,
+}
+
+/// Address was allocated to an interface
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct AddressAllocated {
+    pub event_version: u32,
+    pub event_id: Uuid,
+    pub aggregate_id: Uuid,
+    pub timestamp: DateTime<Utc>,
+    pub correlation_id: Uuid,
+    pub causation_id: Option<Uuid>,
+
+    /// Allocated address with the pool's prefix length
+    pub address: IpAddressWithCidr,
+
+    /// Interface holding the address
+    pub interface_id: Uuid,
+
+    /// Resource owning the interface
+    pub resource_id: Uuid,
+}
+
+/// Address was returned to the pool
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct AddressReleased {
+    pub event_version: u32,
+    pub event_id: Uuid,
+    pub aggregate_id: Uuid,
+    pub timestamp: DateTime<Utc>,
+    pub correlation_id: Uuid,
+    pub causation_id: Option<Uuid>,
+
+    /// Released address
+    pub address: IpAddr,
+
+    /// Interface that held the address
+    pub interface_id: Uuid,
+}
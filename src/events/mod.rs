@@ -58,19 +58,54 @@
 //! - [`infrastructure`] - Top-level polymorphic event envelope
 //! - [`compute_resource`] - ComputeResource aggregate events
 //! - [`versioning`] - Event version migration infrastructure
+//! - [`serialization`] - Serialization policy (field-level encryption)
+//! - [`advisory`] - Advisory events from background checks
+//! - [`network`] / [`network_interface`] - Network and interface aggregate events
+//! - [`connection`] - PhysicalConnection (cable) aggregate events
+//! - [`ip_pool`] - IpPool (address allocation) aggregate events
+//! - [`policy`] - Policy violation and compliance events
+//! - [`alert`] - Alerts raised by declared alert rules
+//! - [`progress`] - Progress reports of long-running operations
+//! - [`scorecard`] - Per-organization inventory hygiene scorecards
 
+pub mod advisory;
+pub mod alert;
 pub mod compute_resource;
+pub mod connection;
 pub mod infrastructure;
+pub mod ip_pool;
+pub mod network;
+pub mod network_interface;
+pub mod policy;
+pub mod progress;
+pub mod scorecard;
+pub mod serialization;
 pub mod versioning;
 
 // Re-export commonly used types
 pub use compute_resource::{
     AccountConceptAssigned, AccountConceptCleared, AssetTagAssigned, ComputeResourceEvent,
-    HardwareDetailsSet, LocationAssigned, MetadataUpdated, OrganizationAssigned, OwnerAssigned,
+    ConfigurationBackupRecorded, ConfigurationBackupRef, GuestAttached, GuestDetached, HardwareDetailsSet, LocationAssigned, MetadataUpdated, OrganizationAssigned, OwnerAssigned,
     PolicyAdded, PolicyRemoved, ResourceRegistered, ResourceStatus, StatusChanged,
 };
+pub use advisory::{
+    AdvisoryEvent, DriftDetected, DualStackIncomplete, IpConflictDetected, SubnetNearlyFull,
+};
+pub use connection::{
+    ConnectionEndpoint, ConnectionEstablished, ConnectionEvent, ConnectionLabeled,
+    ConnectionRemoved,
+};
+pub use alert::AlertRaised;
 pub use infrastructure::InfrastructureEvent;
+pub use ip_pool::{AddressAllocated, AddressReleased, IpPoolEvent, PoolDefined};
+pub use network::{CidrChanged, NetworkDefined, NetworkEvent, VlanAssigned};
+pub use network_interface::{
+    BondDissolved, BondFormed, BondMember, InterfaceAttached, NetworkInterfaceEvent,
+};
+pub use policy::{PolicyComplianceRestored, PolicyEvent, PolicyViolationDetected};
+pub use progress::{OperationProgress, ProgressStatus, ProgressTracker};
+pub use scorecard::ScorecardComputed;
 pub use versioning::{
-    EventVersionInfo, UpcastError, Upcaster, UpcasterChain,
+    EventVersionInfo, UpcastError, Upcaster, UpcasterChain, UpcasterRegistry,
     get_event_version, set_event_version,
 };
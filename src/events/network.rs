@@ -0,0 +1,89 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Network Domain Events
+//!
+//! State changes of Network aggregates (an IP prefix, optionally bound to a
+//! VLAN). Same conventions as the compute resource events: past tense,
+//! versioned, with correlation and causation IDs.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::{IpAddressWithCidr, VlanId};
+
+/// Network Domain Events
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum NetworkEvent {
+    /// Network was defined
+    NetworkDefined(NetworkDefined),
+
+    /// Network prefix was changed (renumbering)
+    CidrChanged(CidrChanged),
+
+    /// Network was bound to a VLAN
+    VlanAssigned(VlanAssigned),
+}
+
+/// Network was defined
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct NetworkDefined {
+    pub event_version: u32,
+    pub event_id: Uuid,
+    pub aggregate_id: Uuid,
+    pub timestamp: DateTime<Utc>,
+    pub correlation_id: Uuid,
+    pub causation_id: Option<Uuid>,
+
+    /// Network name
+    pub name: String,
+
+    /// Network prefix (network address with prefix length)
+    pub cidr: IpAddressWithCidr,
+
+    /// VLAN carrying the network, if any
+    pub vlan_id: Option<VlanId>,
+
+    /// Layer-2 segment the VLAN lives on; None for the default segment
+    #[serde(default)]
+    pub segment: Option<String>,
+}
+
+/// Network prefix was changed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct CidrChanged {
+    pub event_version: u32,
+    pub event_id: Uuid,
+    pub aggregate_id: Uuid,
+    pub timestamp: DateTime<Utc>,
+    pub correlation_id: Uuid,
+    pub causation_id: Option<Uuid>,
+
+    /// Prefix before the change
+    pub previous: IpAddressWithCidr,
+
+    /// New prefix
+    pub cidr: IpAddressWithCidr,
+}
+
+/// Network was bound to a VLAN
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct VlanAssigned {
+    pub event_version: u32,
+    pub event_id: Uuid,
+    pub aggregate_id: Uuid,
+    pub timestamp: DateTime<Utc>,
+    pub correlation_id: Uuid,
+    pub causation_id: Option<Uuid>,
+
+    /// VLAN now carrying the network
+    pub vlan_id: VlanId,
+}
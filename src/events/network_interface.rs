@@ -0,0 +1,124 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Network Interface Domain Events
+//!
+//! State changes of NetworkInterface aggregates: an interface of a compute
+//! resource attached to a network, or a bond aggregating several of them.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::{BondMode, IpAddressWithCidr, MacAddress};
+
+/// Network Interface Domain Events
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum NetworkInterfaceEvent {
+    /// Interface was attached to a network
+    InterfaceAttached(InterfaceAttached),
+
+    /// Bonded interface was formed from member interfaces
+    BondFormed(BondFormed),
+
+    /// Bonded interface was dissolved, releasing its members
+    BondDissolved(BondDissolved),
+}
+
+/// Interface was attached to a network
+///
+/// The first attachment creates the interface; later ones move it to
+/// another network.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct InterfaceAttached {
+    pub event_version: u32,
+    pub event_id: Uuid,
+    pub aggregate_id: Uuid,
+    pub timestamp: DateTime<Utc>,
+    pub correlation_id: Uuid,
+    pub causation_id: Option<Uuid>,
+
+    /// Compute resource owning the interface
+    pub resource_id: Uuid,
+
+    /// Network the interface is attached to
+    pub network_id: Uuid,
+
+    /// Interface name on the resource (e.g. `eth0`)
+    pub name: String,
+
+    /// Hardware address
+    pub mac_address: Option<MacAddress>,
+
+    /// Addresses assigned on the network
+    pub addresses: Vec<IpAddressWithCidr>,
+
+    /// Link speed in Mbit/s, if known
+    #[serde(default)]
+    pub speed_mbps: Option<u32>,
+}
+
+/// Member of a bonded interface
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct BondMember {
+    /// Member interface aggregate
+    pub interface_id: Uuid,
+
+    /// Member interface name on the resource (e.g. `eno1`)
+    pub name: String,
+}
+
+/// Bonded interface was formed from member interfaces
+///
+/// Creates the bond as an interface aggregate of its own; it can then be
+/// attached to a network like any other interface.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct BondFormed {
+    pub event_version: u32,
+    pub event_id: Uuid,
+    pub aggregate_id: Uuid,
+    pub timestamp: DateTime<Utc>,
+    pub correlation_id: Uuid,
+    pub causation_id: Option<Uuid>,
+
+    /// Compute resource owning the bond and its members
+    pub resource_id: Uuid,
+
+    /// Bond name on the resource (e.g. `bond0`)
+    pub name: String,
+
+    /// Aggregation mode
+    pub mode: BondMode,
+
+    /// Member interfaces
+    pub members: Vec<BondMember>,
+
+    /// Common link speed of the members in Mbit/s, if known
+    pub speed_mbps: Option<u32>,
+}
+
+/// Bonded interface was dissolved, releasing its members
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct BondDissolved {
+    pub event_version: u32,
+    pub event_id: Uuid,
+    pub aggregate_id: Uuid,
+    pub timestamp: DateTime<Utc>,
+    pub correlation_id: Uuid,
+    pub causation_id: Option<Uuid>,
+
+    /// Compute resource owning the bond
+    pub resource_id: Uuid,
+
+    /// Bond name on the resource
+    pub name: String,
+}
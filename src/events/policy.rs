@@ -0,0 +1,175 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Policy Compliance Events
+//!
+//! Emitted when a resource starts or stops breaking a
+//! [`PolicyRule`](crate::policy::PolicyRule). Like advisories, they are
+//! derived from aggregate events rather than part of any aggregate's event
+//! stream; each one names the aggregate event that caused it.
+//!
+//! # Subjects
+//!
+//! ```text
+//! infrastructure.policy.violation_detected
+//! infrastructure.policy.compliance_restored
+//! ```
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::policy::PolicyAction;
+use crate::subjects::{AggregateType, Operation, INFRASTRUCTURE_ROOT};
+
+/// Policy compliance events
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PolicyEvent {
+    /// Resource started breaking a rule
+    PolicyViolationDetected(PolicyViolationDetected),
+
+    /// Resource complies with a rule it used to break
+    PolicyComplianceRestored(PolicyComplianceRestored),
+}
+
+impl PolicyEvent {
+    /// Event type token used in the subject
+    pub fn event_type(&self) -> &'static str {
+        self.operation().as_str()
+    }
+
+    /// Operation of the event
+    pub fn operation(&self) -> Operation {
+        match self {
+            PolicyEvent::PolicyViolationDetected(_) => Operation::ViolationDetected,
+            PolicyEvent::PolicyComplianceRestored(_) => Operation::ComplianceRestored,
+        }
+    }
+
+    /// Unique event ID
+    pub fn event_id(&self) -> Uuid {
+        match self {
+            PolicyEvent::PolicyViolationDetected(e) => e.event_id,
+            PolicyEvent::PolicyComplianceRestored(e) => e.event_id,
+        }
+    }
+
+    /// Resource the event is about
+    pub fn resource_id(&self) -> Uuid {
+        match self {
+            PolicyEvent::PolicyViolationDetected(e) => e.resource_id,
+            PolicyEvent::PolicyComplianceRestored(e) => e.resource_id,
+        }
+    }
+
+    /// Rule the event is about
+    pub fn rule(&self) -> &str {
+        match self {
+            PolicyEvent::PolicyViolationDetected(e) => &e.rule,
+            PolicyEvent::PolicyComplianceRestored(e) => &e.rule,
+        }
+    }
+
+    /// NATS subject for this event
+    pub fn subject(&self) -> String {
+        policy_subject(self.event_type())
+    }
+}
+
+/// Subject for a policy event type
+pub fn policy_subject(event_type: &str) -> String {
+    format!(
+        "{}.{}.{}",
+        INFRASTRUCTURE_ROOT,
+        AggregateType::Policy,
+        event_type
+    )
+}
+
+/// Resource started breaking a rule
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct PolicyViolationDetected {
+    /// Unique event ID
+    pub event_id: Uuid,
+
+    /// Timestamp of the aggregate event that caused the violation
+    pub detected_at: DateTime<Utc>,
+
+    /// Correlation ID of the causing event
+    pub correlation_id: Uuid,
+
+    /// ID of the causing event
+    pub causation_id: Uuid,
+
+    /// Offending resource
+    pub resource_id: Uuid,
+
+    /// Hostname of the resource
+    pub hostname: String,
+
+    /// Name of the broken rule
+    pub rule: String,
+
+    /// Action configured on the rule
+    pub action: PolicyAction,
+
+    /// Rule description, or the condition that held
+    pub message: String,
+}
+
+/// Resource complies with a rule it used to break
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct PolicyComplianceRestored {
+    /// Unique event ID
+    pub event_id: Uuid,
+
+    /// Timestamp of the aggregate event that restored compliance
+    pub detected_at: DateTime<Utc>,
+
+    /// Correlation ID of the causing event
+    pub correlation_id: Uuid,
+
+    /// ID of the causing event
+    pub causation_id: Uuid,
+
+    /// Resource that complies again
+    pub resource_id: Uuid,
+
+    /// Hostname of the resource
+    pub hostname: String,
+
+    /// Name of the rule
+    pub rule: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy_subjects() {
+        let event = PolicyEvent::PolicyComplianceRestored(PolicyComplianceRestored {
+            event_id: Uuid::now_v7(),
+            detected_at: Utc::now(),
+            correlation_id: Uuid::now_v7(),
+            causation_id: Uuid::now_v7(),
+            resource_id: Uuid::now_v7(),
+            hostname: "web-01".to_string(),
+            rule: "asset-tagged".to_string(),
+        });
+
+        assert_eq!(event.subject(), "infrastructure.policy.compliance_restored");
+        assert_eq!(
+            policy_subject("violation_detected"),
+            "infrastructure.policy.violation_detected"
+        );
+
+        let json = serde_json::to_string(&event).unwrap();
+        assert!(json.contains("\"type\":\"policy_compliance_restored\""));
+    }
+}
@@ -0,0 +1,393 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Operation Progress Events
+//!
+//! Slow operations (projection rebuilds, edge sync, bulk imports, discovery
+//! scans) report how far along they are so callers are not blind during
+//! multi-minute jobs. Like advisories, progress events are not part of any
+//! aggregate's event stream; they are published on core NATS and are never
+//! persisted.
+//!
+//! # Subjects
+//!
+//! ```text
+//! infrastructure.progress.<operation_id>
+//! ```
+//!
+//! Every report for one run of an operation shares an operation ID. The
+//! last report has status `completed`, `failed` or `cancelled`.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::events::progress::ProgressTracker;
+//!
+//! let mut tracker = ProgressTracker::new(Uuid::now_v7(), "bulk_import", Utc::now());
+//! tracker.set_total(1_000);
+//! tracker.advance(250);
+//!
+//! let progress = tracker.running(Utc::now());
+//! nats_client.publish(&progress.subject(), &progress).await?;
+//! ```
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use uuid::Uuid;
+
+use crate::subjects::subjects;
+
+/// Most recent error messages carried in a progress report
+pub const MAX_REPORTED_ERRORS: usize = 20;
+
+/// Lifecycle of a reported operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ProgressStatus {
+    /// Operation is still working
+    Running,
+
+    /// Operation finished; errors may still have been recorded along the way
+    Completed,
+
+    /// Operation aborted
+    Failed,
+
+    /// Operation stopped on request before it finished
+    Cancelled,
+}
+
+impl ProgressStatus {
+    /// Whether no further reports follow
+    pub fn is_finished(&self) -> bool {
+        !matches!(self, ProgressStatus::Running)
+    }
+}
+
+/// Progress report of a long-running operation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct OperationProgress {
+    /// ID shared by every report of one run
+    pub operation_id: Uuid,
+
+    /// Kind of operation, e.g. `projection_rebuild`
+    pub operation: String,
+
+    /// What the operation works on (projection name, import file, scan range)
+    pub scope: Option<String>,
+
+    /// Current status
+    pub status: ProgressStatus,
+
+    /// Work items done so far
+    #[cfg_attr(feature = "typescript", ts(type = "number"))]
+    pub completed: u64,
+
+    /// Total work items, when known up front
+    #[cfg_attr(feature = "typescript", ts(type = "number | null"))]
+    pub total: Option<u64>,
+
+    /// Percentage done (0-100), when the total is known
+    pub percent: Option<f64>,
+
+    /// Estimated seconds until done, extrapolated from the rate so far
+    #[cfg_attr(feature = "typescript", ts(type = "number | null"))]
+    pub eta_secs: Option<u64>,
+
+    /// Errors recorded so far
+    #[cfg_attr(feature = "typescript", ts(type = "number"))]
+    pub error_count: u64,
+
+    /// Most recent error messages (at most [`MAX_REPORTED_ERRORS`])
+    pub errors: Vec<String>,
+
+    /// Failure reason for `failed` reports
+    pub message: Option<String>,
+
+    /// When the operation started
+    pub started_at: DateTime<Utc>,
+
+    /// When this report was produced
+    pub timestamp: DateTime<Utc>,
+}
+
+impl OperationProgress {
+    /// NATS subject for this report
+    pub fn subject(&self) -> String {
+        subjects::progress(&self.operation_id.to_string())
+    }
+}
+
+impl fmt::Display for OperationProgress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.operation)?;
+        if let Some(scope) = &self.scope {
+            write!(f, " {}", scope)?;
+        }
+
+        match (self.total, self.percent) {
+            (Some(total), Some(percent)) => {
+                write!(f, ": {}/{} ({:.0}%)", self.completed, total, percent)?
+            }
+            _ => write!(f, ": {} done", self.completed)?,
+        }
+
+        match self.status {
+            ProgressStatus::Running => {
+                if let Some(eta) = self.eta_secs {
+                    write!(f, ", eta {}s", eta)?;
+                }
+            }
+            ProgressStatus::Completed => write!(f, ", completed")?,
+            ProgressStatus::Cancelled => write!(f, ", cancelled")?,
+            ProgressStatus::Failed => write!(
+                f,
+                ", failed: {}",
+                self.message.as_deref().unwrap_or("unknown error")
+            )?,
+        }
+
+        if self.error_count > 0 {
+            write!(f, " ({} errors)", self.error_count)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Counts work done by one run of an operation and produces its reports
+///
+/// Time is passed in rather than read from the clock, so the tracker works
+/// without the `clock` feature and percent/ETA are deterministic in tests.
+#[derive(Debug, Clone)]
+pub struct ProgressTracker {
+    operation_id: Uuid,
+    operation: String,
+    scope: Option<String>,
+    started_at: DateTime<Utc>,
+    total: Option<u64>,
+    completed: u64,
+    error_count: u64,
+    errors: Vec<String>,
+}
+
+impl ProgressTracker {
+    /// Track a run that started at `started_at`
+    pub fn new(
+        operation_id: Uuid,
+        operation: impl Into<String>,
+        started_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            operation_id,
+            operation: operation.into(),
+            scope: None,
+            started_at,
+            total: None,
+            completed: 0,
+            error_count: 0,
+            errors: Vec::new(),
+        }
+    }
+
+    /// Name what the operation works on
+    pub fn with_scope(mut self, scope: impl Into<String>) -> Self {
+        self.scope = Some(scope.into());
+        self
+    }
+
+    /// ID shared by every report of this run
+    pub fn operation_id(&self) -> Uuid {
+        self.operation_id
+    }
+
+    /// Work items done so far
+    pub fn completed(&self) -> u64 {
+        self.completed
+    }
+
+    /// Errors recorded so far
+    pub fn error_count(&self) -> u64 {
+        self.error_count
+    }
+
+    /// Set the total once it is known
+    pub fn set_total(&mut self, total: u64) {
+        self.total = Some(total);
+    }
+
+    /// Count finished work items
+    pub fn advance(&mut self, items: u64) {
+        self.completed = self.completed.saturating_add(items);
+    }
+
+    /// Record a non-fatal error; only the most recent messages are kept
+    pub fn record_error(&mut self, message: impl Into<String>) {
+        self.error_count += 1;
+        if self.errors.len() == MAX_REPORTED_ERRORS {
+            self.errors.remove(0);
+        }
+        self.errors.push(message.into());
+    }
+
+    /// Percentage done, when the total is known
+    pub fn percent(&self) -> Option<f64> {
+        self.total.map(|total| {
+            if total == 0 {
+                100.0
+            } else {
+                (self.completed.min(total) as f64 / total as f64) * 100.0
+            }
+        })
+    }
+
+    /// Estimated seconds until done
+    ///
+    /// Assumes the remaining items go at the average rate so far; None
+    /// until the total is known and at least one item is done.
+    pub fn eta_secs(&self, now: DateTime<Utc>) -> Option<u64> {
+        let total = self.total?;
+        if self.completed == 0 {
+            return None;
+        }
+
+        let remaining = total.saturating_sub(self.completed);
+        let elapsed_ms = (now - self.started_at).num_milliseconds().max(0) as u128;
+        let eta_ms = elapsed_ms * remaining as u128 / self.completed as u128;
+
+        Some((eta_ms / 1000) as u64)
+    }
+
+    /// Report for a run still in progress
+    pub fn running(&self, now: DateTime<Utc>) -> OperationProgress {
+        self.report(ProgressStatus::Running, None, now)
+    }
+
+    /// Final report for a run that finished
+    pub fn finished(&self, now: DateTime<Utc>) -> OperationProgress {
+        let mut report = self.report(ProgressStatus::Completed, None, now);
+        report.eta_secs = Some(0);
+        report
+    }
+
+    /// Final report for a run that aborted
+    pub fn failed(&self, message: impl Into<String>, now: DateTime<Utc>) -> OperationProgress {
+        let mut report = self.report(ProgressStatus::Failed, Some(message.into()), now);
+        report.eta_secs = None;
+        report
+    }
+
+    /// Final report for a run stopped on request
+    pub fn cancelled(&self, now: DateTime<Utc>) -> OperationProgress {
+        let mut report = self.report(ProgressStatus::Cancelled, None, now);
+        report.eta_secs = None;
+        report
+    }
+
+    fn report(
+        &self,
+        status: ProgressStatus,
+        message: Option<String>,
+        now: DateTime<Utc>,
+    ) -> OperationProgress {
+        OperationProgress {
+            operation_id: self.operation_id,
+            operation: self.operation.clone(),
+            scope: self.scope.clone(),
+            status,
+            completed: self.completed,
+            total: self.total,
+            percent: self.percent(),
+            eta_secs: self.eta_secs(now),
+            error_count: self.error_count,
+            errors: self.errors.clone(),
+            message,
+            started_at: self.started_at,
+            timestamp: now,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn test_timestamp() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-19T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_percent_and_eta_follow_rate() {
+        // Arrange
+        let started = test_timestamp();
+        let mut tracker = ProgressTracker::new(Uuid::now_v7(), "bulk_import", started);
+
+        // Act - a quarter done after 30 seconds
+        tracker.set_total(1_000);
+        tracker.advance(250);
+        let report = tracker.running(started + Duration::seconds(30));
+
+        // Assert
+        assert_eq!(report.status, ProgressStatus::Running);
+        assert_eq!(report.percent, Some(25.0));
+        assert_eq!(report.eta_secs, Some(90));
+        assert_eq!(
+            report.subject(),
+            format!("infrastructure.progress.{}", tracker.operation_id())
+        );
+    }
+
+    #[test]
+    fn test_unknown_total_has_no_estimate() {
+        let started = test_timestamp();
+        let mut tracker = ProgressTracker::new(Uuid::now_v7(), "discovery_scan", started);
+        tracker.advance(12);
+
+        let report = tracker.running(started + Duration::seconds(5));
+
+        assert_eq!(report.percent, None);
+        assert_eq!(report.eta_secs, None);
+        assert_eq!(report.to_string(), "discovery_scan: 12 done");
+    }
+
+    #[test]
+    fn test_errors_are_bounded() {
+        let started = test_timestamp();
+        let mut tracker = ProgressTracker::new(Uuid::now_v7(), "edge_sync", started);
+
+        for i in 0..MAX_REPORTED_ERRORS + 5 {
+            tracker.record_error(format!("error {}", i));
+        }
+        let report = tracker.failed("upstream unreachable", started);
+
+        assert!(report.status.is_finished());
+        assert_eq!(report.error_count, (MAX_REPORTED_ERRORS + 5) as u64);
+        assert_eq!(report.errors.len(), MAX_REPORTED_ERRORS);
+        assert_eq!(report.errors[0], "error 5");
+        assert_eq!(report.message.as_deref(), Some("upstream unreachable"));
+    }
+
+    #[test]
+    fn test_cancelled_report_is_terminal() {
+        let started = test_timestamp();
+        let mut tracker =
+            ProgressTracker::new(Uuid::now_v7(), "projection_rebuild", started).with_scope("neo4j");
+        tracker.set_total(10);
+        tracker.advance(4);
+
+        let report = tracker.cancelled(started + Duration::seconds(8));
+
+        assert!(report.status.is_finished());
+        assert_eq!(report.eta_secs, None);
+        assert_eq!(
+            report.to_string(),
+            "projection_rebuild neo4j: 4/10 (40%), cancelled"
+        );
+    }
+}
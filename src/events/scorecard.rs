@@ -0,0 +1,54 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Scorecard Events
+//!
+//! Published by the [scorecard generator](crate::scorecard) after each run,
+//! one per organization. Scorecard subjects are captured by the event
+//! stream, so replaying them shows how an organization's inventory hygiene
+//! developed over time.
+//!
+//! # Subjects
+//!
+//! ```text
+//! infrastructure.scorecard.<organization_id>
+//! infrastructure.scorecard.unassigned
+//! ```
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::scorecard::Scorecard;
+use crate::subjects::INFRASTRUCTURE_ROOT;
+
+/// Subject token for resources without an organization
+pub const UNASSIGNED_ORGANIZATION: &str = "unassigned";
+
+/// A scorecard was computed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ScorecardComputed {
+    /// Unique event ID
+    pub event_id: Uuid,
+
+    /// Correlation ID shared by all scorecards of one run
+    pub correlation_id: Uuid,
+
+    /// The scorecard
+    pub scorecard: Scorecard,
+}
+
+impl ScorecardComputed {
+    /// NATS subject for this event
+    pub fn subject(&self) -> String {
+        scorecard_subject(self.scorecard.organization_id.as_deref())
+    }
+}
+
+/// Subject for an organization's scorecards (None for unassigned resources)
+pub fn scorecard_subject(organization_id: Option<&str>) -> String {
+    format!(
+        "{}.scorecard.{}",
+        INFRASTRUCTURE_ROOT,
+        organization_id.unwrap_or(UNASSIGNED_ORGANIZATION)
+    )
+}
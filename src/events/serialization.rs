@@ -0,0 +1,726 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Event Serialization Policy
+//!
+//! Controls how events are transformed between their typed form and the JSON
+//! written to the event store. The policy covers selective field
+//! encryption: configured fields (e.g. `owner_id`, `location_id`) are
+//! replaced with an encrypted envelope before publishing, and only holders
+//! of the key can restore them. It also decides what happens to payload
+//! fields the typed events do not declare.
+//!
+//! # Architecture
+//!
+//! ```text
+//! StoredEvent ─serialize─> JSON ─protect()─> JSON with {"$encrypted": ...} ─> JetStream
+//!                                                         │
+//!              authorized reader <─reveal()───────────────┘
+//!              other readers see only the encrypted envelope
+//! ```
+//!
+//! Field rules address the versioned payload inside the stored envelope
+//! (`data.event.<field>`) for a given event type name.
+//!
+//! # Unknown Fields
+//!
+//! Serde drops fields a struct does not declare. Rather than leave that
+//! implicit, [`decode_payload`] re-serializes what it decoded and reports
+//! every field that did not survive as [`IgnoredFields`]. The
+//! [`UnknownFieldPolicy`] decides per direction whether that is an error:
+//!
+//! - **write** (default [`UnknownFields::Deny`]): the event store checks
+//!   that every payload it writes decodes back completely, so nothing is
+//!   stored that this version's readers would silently lose
+//! - **read** (default [`UnknownFields::Allow`]): the event store and
+//!   projections accept events written by newer versions and record the
+//!   dropped fields on an [`IgnoredFieldReport`]
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::events::serialization::{ChaChaFieldCipher, SerializationPolicy};
+//!
+//! let policy = SerializationPolicy::confidential_references();
+//! let cipher = ChaChaFieldCipher::new("infra-2026-01", key_bytes);
+//!
+//! let store = NatsEventStore::connect(url)
+//!     .await?
+//!     .with_serialization_policy(policy, Some(Arc::new(cipher)));
+//! ```
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Arc, Mutex};
+
+use crate::errors::InfrastructureError;
+
+/// JSON key marking an encrypted field value
+pub const ENCRYPTED_MARKER: &str = "$encrypted";
+
+/// Errors raised while applying the serialization policy
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum FieldEncryptionError {
+    /// Policy requires encryption but no cipher is configured
+    #[error("Field '{0}' must be encrypted but no cipher is configured")]
+    CipherNotConfigured(String),
+
+    /// Encrypted value was produced with a different key
+    #[error("Unknown encryption key: {0}")]
+    UnknownKey(String),
+
+    /// Encryption failed
+    #[error("Encryption failed: {0}")]
+    EncryptionFailed(String),
+
+    /// Decryption failed (wrong key or tampered ciphertext)
+    #[error("Decryption failed: {0}")]
+    DecryptionFailed(String),
+
+    /// Encrypted envelope is malformed
+    #[error("Malformed encrypted value: {0}")]
+    Malformed(String),
+
+    /// The key was destroyed; its data can no longer be written or read
+    #[error("Encryption key destroyed: {0}")]
+    KeyDestroyed(String),
+}
+
+impl From<FieldEncryptionError> for InfrastructureError {
+    fn from(err: FieldEncryptionError) -> Self {
+        InfrastructureError::Serialization(err.to_string())
+    }
+}
+
+/// Encrypted representation of a single field value
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EncryptedValue {
+    /// Cipher algorithm identifier
+    pub alg: String,
+
+    /// Identifier of the key used for encryption
+    pub key_id: String,
+
+    /// Base64-encoded nonce
+    pub nonce: String,
+
+    /// Base64-encoded ciphertext (including authentication tag)
+    pub ciphertext: String,
+}
+
+/// Symmetric cipher used for field-level encryption
+pub trait FieldCipher: Send + Sync {
+    /// Identifier of the key this cipher encrypts with
+    fn key_id(&self) -> &str;
+
+    /// Encrypt the serialized field value
+    fn encrypt(&self, plaintext: &[u8]) -> Result<EncryptedValue, FieldEncryptionError>;
+
+    /// Decrypt a field value encrypted by this cipher
+    fn decrypt(&self, value: &EncryptedValue) -> Result<Vec<u8>, FieldEncryptionError>;
+}
+
+/// A field that must be encrypted for a given event type
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct EncryptedField {
+    /// Event type name (e.g. "OwnerAssigned")
+    pub event_type: String,
+
+    /// Field name within the event payload (e.g. "owner_id")
+    pub field: String,
+}
+
+impl EncryptedField {
+    /// Create a field rule
+    pub fn new(event_type: impl Into<String>, field: impl Into<String>) -> Self {
+        Self {
+            event_type: event_type.into(),
+            field: field.into(),
+        }
+    }
+}
+
+/// Treatment of payload fields the typed event does not declare
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UnknownFields {
+    /// Decode anyway and report the dropped fields
+    #[default]
+    Allow,
+
+    /// Reject the payload
+    Deny,
+}
+
+/// Unknown-field treatment for each direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnknownFieldPolicy {
+    /// Events appended to the store
+    pub write: UnknownFields,
+
+    /// Events read from the store or delivered to projections
+    pub read: UnknownFields,
+}
+
+impl UnknownFieldPolicy {
+    /// Deny unknown fields in both directions
+    pub fn strict() -> Self {
+        Self {
+            write: UnknownFields::Deny,
+            read: UnknownFields::Deny,
+        }
+    }
+
+    /// Allow unknown fields in both directions
+    pub fn lenient() -> Self {
+        Self {
+            write: UnknownFields::Allow,
+            read: UnknownFields::Allow,
+        }
+    }
+}
+
+impl Default for UnknownFieldPolicy {
+    /// Deny on write, allow on read
+    fn default() -> Self {
+        Self {
+            write: UnknownFields::Deny,
+            read: UnknownFields::Allow,
+        }
+    }
+}
+
+/// Serialization policy applied by the event store
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SerializationPolicy {
+    encrypted_fields: BTreeSet<EncryptedField>,
+    unknown_fields: UnknownFieldPolicy,
+}
+
+impl SerializationPolicy {
+    /// Policy with no transformations (plain JSON)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Policy encrypting owner and location references
+    pub fn confidential_references() -> Self {
+        Self::new()
+            .encrypt_field("OwnerAssigned", "owner_id")
+            .encrypt_field("LocationAssigned", "location_id")
+    }
+
+    /// Encrypt a field of an event type
+    pub fn encrypt_field(mut self, event_type: impl Into<String>, field: impl Into<String>) -> Self {
+        self.encrypted_fields
+            .insert(EncryptedField::new(event_type, field));
+        self
+    }
+
+    /// Set the unknown-field treatment
+    pub fn with_unknown_fields(mut self, policy: UnknownFieldPolicy) -> Self {
+        self.unknown_fields = policy;
+        self
+    }
+
+    /// Unknown-field treatment
+    pub fn unknown_fields(&self) -> UnknownFieldPolicy {
+        self.unknown_fields
+    }
+
+    /// Whether any fields are encrypted
+    pub fn has_encrypted_fields(&self) -> bool {
+        !self.encrypted_fields.is_empty()
+    }
+
+    /// Configured encrypted fields
+    pub fn encrypted_fields(&self) -> impl Iterator<Item = &EncryptedField> {
+        self.encrypted_fields.iter()
+    }
+
+    /// Whether the policy encrypts any field of an event type
+    pub fn encrypts(&self, event_type: &str) -> bool {
+        self.fields_for(event_type).next().is_some()
+    }
+
+    /// Fields to encrypt for an event type
+    fn fields_for<'a>(&'a self, event_type: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.encrypted_fields
+            .iter()
+            .filter(move |rule| rule.event_type == event_type)
+            .map(|rule| rule.field.as_str())
+    }
+
+    /// Encrypt configured fields of a serialized stored event in place
+    pub fn protect(
+        &self,
+        stored: &mut Value,
+        cipher: Option<&dyn FieldCipher>,
+    ) -> Result<(), FieldEncryptionError> {
+        let Some(event_type) = stored_event_type(stored) else {
+            return Ok(());
+        };
+
+        let fields: Vec<String> = self.fields_for(&event_type).map(str::to_string).collect();
+        if fields.is_empty() {
+            return Ok(());
+        }
+
+        let Some(payload) = event_payload_mut(stored) else {
+            return Ok(());
+        };
+
+        for field in fields {
+            let Some(value) = payload.get_mut(&field) else {
+                continue;
+            };
+            if is_encrypted(value) {
+                continue;
+            }
+
+            let cipher = cipher.ok_or_else(|| FieldEncryptionError::CipherNotConfigured(field.clone()))?;
+            let plaintext = serde_json::to_vec(value)
+                .map_err(|e| FieldEncryptionError::EncryptionFailed(e.to_string()))?;
+            let encrypted = cipher.encrypt(&plaintext)?;
+
+            *value = serde_json::json!({ ENCRYPTED_MARKER: encrypted });
+        }
+
+        Ok(())
+    }
+
+    /// Decrypt every encrypted field of a serialized stored event in place
+    ///
+    /// Only readers holding the cipher can call this successfully; values
+    /// encrypted under a different key produce [`FieldEncryptionError::UnknownKey`].
+    pub fn reveal(&self, stored: &mut Value, cipher: &dyn FieldCipher) -> Result<(), FieldEncryptionError> {
+        let Some(payload) = event_payload_mut(stored).and_then(Value::as_object_mut) else {
+            return Ok(());
+        };
+
+        for value in payload.values_mut() {
+            let Some(envelope) = value.get(ENCRYPTED_MARKER) else {
+                continue;
+            };
+
+            let encrypted: EncryptedValue = serde_json::from_value(envelope.clone())
+                .map_err(|e| FieldEncryptionError::Malformed(e.to_string()))?;
+            if encrypted.key_id != cipher.key_id() {
+                return Err(FieldEncryptionError::UnknownKey(encrypted.key_id));
+            }
+
+            let plaintext = cipher.decrypt(&encrypted)?;
+            *value = serde_json::from_slice(&plaintext)
+                .map_err(|e| FieldEncryptionError::Malformed(e.to_string()))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Whether a JSON value is an encrypted field envelope
+pub fn is_encrypted(value: &Value) -> bool {
+    value.get(ENCRYPTED_MARKER).is_some()
+}
+
+/// Payload fields dropped while decoding one event
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IgnoredFields {
+    /// Event type name from the stored envelope (empty if absent)
+    pub event_type: String,
+
+    /// Dotted paths of the dropped fields (e.g. `data.event.rack_unit`)
+    pub paths: Vec<String>,
+}
+
+impl std::fmt::Display for IgnoredFields {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.event_type, self.paths.join(", "))
+    }
+}
+
+/// Errors raised while decoding a payload under an [`UnknownFields`] mode
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PayloadDecodeError {
+    /// Payload does not match the typed event
+    #[error("{0}")]
+    Invalid(String),
+
+    /// Payload carries fields the typed event does not declare
+    #[error("Unknown fields in {0}")]
+    UnknownFields(IgnoredFields),
+}
+
+impl From<PayloadDecodeError> for InfrastructureError {
+    fn from(err: PayloadDecodeError) -> Self {
+        InfrastructureError::Deserialization(err.to_string())
+    }
+}
+
+/// Decode a serialized stored event, detecting dropped fields
+///
+/// The decoded value is serialized again and compared with `raw`; fields
+/// present in `raw` but missing afterwards were dropped by serde. Fields
+/// that were `null` in `raw` are not reported, since omitting an empty
+/// optional field loses nothing. With [`UnknownFields::Deny`] any dropped
+/// field fails the decode, otherwise the fields are returned alongside the
+/// value.
+pub fn decode_payload<T>(
+    raw: &Value,
+    mode: UnknownFields,
+) -> Result<(T, Option<IgnoredFields>), PayloadDecodeError>
+where
+    T: DeserializeOwned + Serialize,
+{
+    let decoded = T::deserialize(raw).map_err(|e| PayloadDecodeError::Invalid(e.to_string()))?;
+    let reencoded =
+        serde_json::to_value(&decoded).map_err(|e| PayloadDecodeError::Invalid(e.to_string()))?;
+
+    let paths = ignored_fields(raw, &reencoded);
+    if paths.is_empty() {
+        return Ok((decoded, None));
+    }
+
+    let ignored = IgnoredFields {
+        event_type: stored_event_type(raw).unwrap_or_default(),
+        paths,
+    };
+    match mode {
+        UnknownFields::Allow => Ok((decoded, Some(ignored))),
+        UnknownFields::Deny => Err(PayloadDecodeError::UnknownFields(ignored)),
+    }
+}
+
+/// Dotted paths of non-null fields in `raw` that are missing from
+/// `decoded`, sorted
+pub fn ignored_fields(raw: &Value, decoded: &Value) -> Vec<String> {
+    let mut paths = Vec::new();
+    collect_ignored(raw, decoded, "", &mut paths);
+    paths.sort();
+    paths
+}
+
+fn collect_ignored(raw: &Value, decoded: &Value, prefix: &str, paths: &mut Vec<String>) {
+    let child = |key: &str| {
+        if prefix.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{}", prefix, key)
+        }
+    };
+
+    match (raw, decoded) {
+        (Value::Object(raw), Value::Object(decoded)) => {
+            for (key, value) in raw {
+                match decoded.get(key) {
+                    Some(kept) => collect_ignored(value, kept, &child(key), paths),
+                    None if value.is_null() => {}
+                    None => paths.push(child(key)),
+                }
+            }
+        }
+        (Value::Array(raw), Value::Array(decoded)) => {
+            for (index, (value, kept)) in raw.iter().zip(decoded).enumerate() {
+                collect_ignored(value, kept, &child(&index.to_string()), paths);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Count of one ignored field path for one event type
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IgnoredFieldCount {
+    /// Event type name
+    pub event_type: String,
+
+    /// Dotted field path
+    pub path: String,
+
+    /// Events decoded without this field
+    pub count: u64,
+}
+
+/// Running tally of ignored fields, shared by the decoders that report to it
+///
+/// Cloning is cheap; all clones count into the same tally.
+#[derive(Debug, Clone, Default)]
+pub struct IgnoredFieldReport {
+    counts: Arc<Mutex<BTreeMap<(String, String), u64>>>,
+}
+
+impl IgnoredFieldReport {
+    /// Empty report
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Count the fields dropped from one event
+    pub fn record(&self, ignored: &IgnoredFields) {
+        let mut counts = self.counts.lock().expect("report lock poisoned");
+        for path in &ignored.paths {
+            *counts
+                .entry((ignored.event_type.clone(), path.clone()))
+                .or_insert(0) += 1;
+        }
+    }
+
+    /// Counts so far, ordered by event type and path
+    pub fn snapshot(&self) -> Vec<IgnoredFieldCount> {
+        self.counts
+            .lock()
+            .expect("report lock poisoned")
+            .iter()
+            .map(|((event_type, path), count)| IgnoredFieldCount {
+                event_type: event_type.clone(),
+                path: path.clone(),
+                count: *count,
+            })
+            .collect()
+    }
+
+    /// Whether no field has been ignored
+    pub fn is_empty(&self) -> bool {
+        self.counts.lock().expect("report lock poisoned").is_empty()
+    }
+}
+
+fn stored_event_type(stored: &Value) -> Option<String> {
+    stored
+        .get("event_type")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+}
+
+pub(crate) fn event_payload_mut(stored: &mut Value) -> Option<&mut Value> {
+    stored.get_mut("data").and_then(|data| data.get_mut("event"))
+}
+
+/// ChaCha20-Poly1305 field cipher
+#[cfg(feature = "field-encryption")]
+pub struct ChaChaFieldCipher {
+    key_id: String,
+    cipher: chacha20poly1305::ChaCha20Poly1305,
+}
+
+#[cfg(feature = "field-encryption")]
+impl ChaChaFieldCipher {
+    /// Algorithm identifier stored in encrypted envelopes
+    pub const ALGORITHM: &'static str = "chacha20poly1305";
+
+    /// Create a cipher from a 256-bit key
+    pub fn new(key_id: impl Into<String>, key: [u8; 32]) -> Self {
+        use chacha20poly1305::KeyInit;
+
+        Self {
+            key_id: key_id.into(),
+            cipher: chacha20poly1305::ChaCha20Poly1305::new(&key.into()),
+        }
+    }
+}
+
+#[cfg(feature = "field-encryption")]
+impl FieldCipher for ChaChaFieldCipher {
+    fn key_id(&self) -> &str {
+        &self.key_id
+    }
+
+    fn encrypt(&self, plaintext: &[u8]) -> Result<EncryptedValue, FieldEncryptionError> {
+        use base64::Engine;
+        use chacha20poly1305::aead::{Aead, AeadCore, OsRng};
+
+        let nonce = chacha20poly1305::ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = self
+            .cipher
+            .encrypt(&nonce, plaintext)
+            .map_err(|e| FieldEncryptionError::EncryptionFailed(e.to_string()))?;
+
+        let engine = base64::engine::general_purpose::STANDARD;
+        Ok(EncryptedValue {
+            alg: Self::ALGORITHM.to_string(),
+            key_id: self.key_id.clone(),
+            nonce: engine.encode(nonce),
+            ciphertext: engine.encode(ciphertext),
+        })
+    }
+
+    fn decrypt(&self, value: &EncryptedValue) -> Result<Vec<u8>, FieldEncryptionError> {
+        use base64::Engine;
+        use chacha20poly1305::aead::Aead;
+
+        if value.alg != Self::ALGORITHM {
+            return Err(FieldEncryptionError::Malformed(format!(
+                "unsupported algorithm {}",
+                value.alg
+            )));
+        }
+
+        let engine = base64::engine::general_purpose::STANDARD;
+        let nonce = engine
+            .decode(&value.nonce)
+            .map_err(|e| FieldEncryptionError::Malformed(e.to_string()))?;
+        let ciphertext = engine
+            .decode(&value.ciphertext)
+            .map_err(|e| FieldEncryptionError::Malformed(e.to_string()))?;
+
+        if nonce.len() != 12 {
+            return Err(FieldEncryptionError::Malformed("nonce must be 12 bytes".to_string()));
+        }
+
+        self.cipher
+            .decrypt(chacha20poly1305::Nonce::from_slice(&nonce), ciphertext.as_ref())
+            .map_err(|e| FieldEncryptionError::DecryptionFailed(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Reversible test cipher (not secure)
+    struct XorCipher(&'static str);
+
+    impl FieldCipher for XorCipher {
+        fn key_id(&self) -> &str {
+            self.0
+        }
+
+        fn encrypt(&self, plaintext: &[u8]) -> Result<EncryptedValue, FieldEncryptionError> {
+            Ok(EncryptedValue {
+                alg: "xor".to_string(),
+                key_id: self.0.to_string(),
+                nonce: String::new(),
+                ciphertext: plaintext.iter().map(|b| format!("{:02x}", b ^ 0x5a)).collect(),
+            })
+        }
+
+        fn decrypt(&self, value: &EncryptedValue) -> Result<Vec<u8>, FieldEncryptionError> {
+            (0..value.ciphertext.len())
+                .step_by(2)
+                .map(|i| {
+                    u8::from_str_radix(&value.ciphertext[i..i + 2], 16)
+                        .map(|b| b ^ 0x5a)
+                        .map_err(|e| FieldEncryptionError::Malformed(e.to_string()))
+                })
+                .collect()
+        }
+    }
+
+    fn owner_assigned_json() -> Value {
+        serde_json::json!({
+            "event_type": "OwnerAssigned",
+            "data": {
+                "aggregate_type": "compute_resource",
+                "event": {
+                    "type": "owner_assigned",
+                    "event_version": 1,
+                    "owner_id": "01934f4a-2000-7000-8000-000000002000"
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_protect_and_reveal_round_trip() {
+        // Arrange
+        let policy = SerializationPolicy::confidential_references();
+        let cipher = XorCipher("k1");
+        let original = owner_assigned_json();
+        let mut stored = original.clone();
+
+        // Act
+        policy.protect(&mut stored, Some(&cipher)).unwrap();
+
+        // Assert - field is hidden, other fields untouched
+        assert!(is_encrypted(&stored["data"]["event"]["owner_id"]));
+        assert_eq!(stored["data"]["event"]["event_version"], 1);
+
+        policy.reveal(&mut stored, &cipher).unwrap();
+        assert_eq!(stored, original);
+    }
+
+    #[test]
+    fn test_protect_requires_cipher() {
+        let policy = SerializationPolicy::confidential_references();
+        let mut stored = owner_assigned_json();
+
+        let result = policy.protect(&mut stored, None);
+
+        assert!(matches!(result, Err(FieldEncryptionError::CipherNotConfigured(_))));
+    }
+
+    #[test]
+    fn test_unconfigured_event_types_are_untouched() {
+        let policy = SerializationPolicy::confidential_references();
+        let mut stored = owner_assigned_json();
+        stored["event_type"] = serde_json::json!("MetadataUpdated");
+        let original = stored.clone();
+
+        policy.protect(&mut stored, None).unwrap();
+
+        assert_eq!(stored, original);
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct OwnerAssignedV1 {
+        event_type: String,
+        data: OwnerAssignedData,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct OwnerAssignedData {
+        aggregate_type: String,
+        event: OwnerAssignedPayload,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct OwnerAssignedPayload {
+        owner_id: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        note: Option<String>,
+    }
+
+    #[test]
+    fn test_unknown_fields_reported_or_rejected() {
+        // Arrange - a newer writer added `team`; `note` is an empty optional
+        let mut raw = owner_assigned_json();
+        raw["data"]["event"]["team"] = serde_json::json!("platform");
+        raw["data"]["event"]["note"] = Value::Null;
+        let report = IgnoredFieldReport::new();
+
+        // Act
+        let (decoded, ignored) =
+            decode_payload::<OwnerAssignedV1>(&raw, UnknownFields::Allow).unwrap();
+        let ignored = ignored.unwrap();
+        report.record(&ignored);
+        let denied = decode_payload::<OwnerAssignedV1>(&raw, UnknownFields::Deny);
+
+        // Assert
+        assert_eq!(
+            decoded.data.event.owner_id,
+            "01934f4a-2000-7000-8000-000000002000"
+        );
+        assert_eq!(ignored.event_type, "OwnerAssigned");
+        assert_eq!(
+            ignored.paths,
+            vec![
+                "data.event.event_version",
+                "data.event.team",
+                "data.event.type"
+            ]
+        );
+        assert_eq!(report.snapshot().len(), 3);
+        assert!(matches!(denied, Err(PayloadDecodeError::UnknownFields(i)) if i == ignored));
+    }
+
+    #[test]
+    fn test_reveal_rejects_foreign_key() {
+        let policy = SerializationPolicy::confidential_references();
+        let mut stored = owner_assigned_json();
+        policy.protect(&mut stored, Some(&XorCipher("k1"))).unwrap();
+
+        let result = policy.reveal(&mut stored, &XorCipher("k2"));
+
+        assert_eq!(result, Err(FieldEncryptionError::UnknownKey("k1".to_string())));
+    }
+}
@@ -40,6 +40,20 @@
 //! }
 //! ```
 //!
+//! # Registry
+//!
+//! [`UpcasterRegistry`] maps event type names to upcaster chains. The NATS
+//! event store applies the registry to every stored event it reads, so
+//! aggregates and projections only ever see the latest schema:
+//!
+//! ```rust,ignore
+//! let registry = UpcasterRegistry::new()
+//!     .with("ResourceRegistered", ResourceRegisteredV1ToV2);
+//!
+//! let store = NatsEventStore::connect(url).await?.with_upcasters(registry);
+//! let events = store.read_events(aggregate_id).await?; // v2 only
+//! ```
+//!
 //! # References
 //!
 //! - [Event Sourcing: What is Upcasting?](https://artium.ai/insights/event-sourcing-what-is-upcasting-a-deep-dive)
@@ -47,9 +61,11 @@
 //! - [Marten Events Versioning](https://martendb.io/events/versioning.html)
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt;
 
 use crate::errors::InfrastructureError;
+use crate::events::InfrastructureEvent;
 
 /// Error type for upcasting operations
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -246,6 +262,96 @@ impl<T> Default for UpcasterChain<T> {
     }
 }
 
+/// Registry of upcaster chains keyed by event type name
+///
+/// Event type names match [`InfrastructureEvent::event_type_name`] and the
+/// `event_type` field of stored events (e.g. `"ResourceRegistered"`).
+#[derive(Default)]
+pub struct UpcasterRegistry {
+    chains: HashMap<String, UpcasterChain<InfrastructureEvent>>,
+}
+
+impl UpcasterRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register an upcaster for an event type
+    ///
+    /// Upcasters for the same event type should be registered in version order.
+    pub fn register<U>(&mut self, event_type: impl Into<String>, upcaster: U)
+    where
+        U: Upcaster<InfrastructureEvent> + 'static,
+    {
+        self.chains.entry(event_type.into()).or_default().add(upcaster);
+    }
+
+    /// Builder-style variant of [`UpcasterRegistry::register`]
+    pub fn with<U>(mut self, event_type: impl Into<String>, upcaster: U) -> Self
+    where
+        U: Upcaster<InfrastructureEvent> + 'static,
+    {
+        self.register(event_type, upcaster);
+        self
+    }
+
+    /// Whether no upcasters are registered
+    pub fn is_empty(&self) -> bool {
+        self.chains.is_empty()
+    }
+
+    /// Latest known version for an event type, if any upcasters exist
+    pub fn latest_version(&self, event_type: &str) -> Option<u32> {
+        self.chains.get(event_type).and_then(|chain| chain.latest_version())
+    }
+
+    /// Upcast a single event payload to the latest registered version
+    ///
+    /// Payloads for event types without upcasters are returned unchanged.
+    pub fn upcast(
+        &self,
+        event_type: &str,
+        value: serde_json::Value,
+    ) -> Result<serde_json::Value, UpcastError> {
+        match self.chains.get(event_type) {
+            Some(chain) => {
+                let version = get_event_version(&value)?;
+                chain.upcast_to_latest(value, version)
+            }
+            None => Ok(value),
+        }
+    }
+
+    /// Upcast the domain payload inside a stored event envelope
+    ///
+    /// Expects the JSON layout produced by
+    /// `StoredEvent<InfrastructureEvent>`, where the versioned payload lives
+    /// at `data.event` and the type name at `event_type`.
+    pub fn upcast_stored(
+        &self,
+        mut stored: serde_json::Value,
+    ) -> Result<serde_json::Value, UpcastError> {
+        let event_type = stored
+            .get("event_type")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| UpcastError::MissingField("event_type".to_string()))?
+            .to_string();
+
+        if !self.chains.contains_key(&event_type) {
+            return Ok(stored);
+        }
+
+        let payload = stored
+            .get_mut("data")
+            .and_then(|data| data.get_mut("event"))
+            .ok_or_else(|| UpcastError::MissingField("data.event".to_string()))?;
+
+        *payload = self.upcast(&event_type, payload.take())?;
+        Ok(stored)
+    }
+}
+
 /// Helper to extract event version from JSON
 pub fn get_event_version(value: &serde_json::Value) -> Result<u32, UpcastError> {
     value
@@ -430,6 +536,54 @@ mod tests {
         assert_eq!(v2_json["new_field"], "default");
     }
 
+    struct TestRegisteredV1ToV2;
+
+    impl Upcaster<InfrastructureEvent> for TestRegisteredV1ToV2 {
+        fn from_version(&self) -> u32 {
+            1
+        }
+
+        fn to_version(&self) -> u32 {
+            2
+        }
+
+        fn upcast(&self, mut value: serde_json::Value) -> Result<serde_json::Value, UpcastError> {
+            value["site"] = serde_json::json!("unknown");
+            set_event_version(&mut value, 2)?;
+            Ok(value)
+        }
+    }
+
+    #[test]
+    fn test_registry_upcasts_stored_envelope() {
+        let registry = UpcasterRegistry::new().with("ResourceRegistered", TestRegisteredV1ToV2);
+
+        let stored = serde_json::json!({
+            "event_type": "ResourceRegistered",
+            "data": {
+                "aggregate_type": "compute_resource",
+                "event": { "type": "resource_registered", "event_version": 1 }
+            }
+        });
+
+        let upcasted = registry.upcast_stored(stored).unwrap();
+        assert_eq!(upcasted["data"]["event"]["event_version"], 2);
+        assert_eq!(upcasted["data"]["event"]["site"], "unknown");
+        assert_eq!(registry.latest_version("ResourceRegistered"), Some(2));
+    }
+
+    #[test]
+    fn test_registry_passes_through_unregistered_types() {
+        let registry = UpcasterRegistry::new().with("ResourceRegistered", TestRegisteredV1ToV2);
+
+        let stored = serde_json::json!({
+            "event_type": "StatusChanged",
+            "data": { "event": { "event_version": 1 } }
+        });
+
+        assert_eq!(registry.upcast_stored(stored.clone()).unwrap(), stored);
+    }
+
     #[test]
     fn test_upcaster_chain_already_latest() {
         let mut chain: UpcasterChain<TestEvent> = UpcasterChain::new();
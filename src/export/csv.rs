@@ -0,0 +1,53 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! CSV Output
+//!
+//! Writes RFC 4180 CSV: CRLF line endings, and fields quoted when they
+//! contain a comma, quote or line break. Fields starting with `=`, `+`,
+//! `-` or `@` are prefixed with `'` so spreadsheets do not evaluate
+//! hostnames or metadata as formulas.
+
+/// Write a header row and data rows as CSV
+pub fn write_table(headers: &[String], rows: &[Vec<String>]) -> String {
+    let mut out = String::new();
+    for row in std::iter::once(headers).chain(rows.iter().map(Vec::as_slice)) {
+        let fields: Vec<String> = row.iter().map(|field| escape(field)).collect();
+        out.push_str(&fields.join(","));
+        out.push_str("\r\n");
+    }
+    out
+}
+
+/// Escape one field
+pub fn escape(field: &str) -> String {
+    let field = if field.starts_with(['=', '+', '-', '@']) {
+        format!("'{}", field)
+    } else {
+        field.to_string()
+    };
+
+    if field.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fields_are_quoted_and_defused() {
+        // Arrange
+        let headers = vec!["hostname".to_string(), "notes".to_string()];
+        let rows = vec![vec!["web-01".to_string(), "rack 4, \"top\"".to_string()]];
+
+        // Act
+        let csv = write_table(&headers, &rows);
+
+        // Assert
+        assert_eq!(csv, "hostname,notes\r\nweb-01,\"rack 4, \"\"top\"\"\"\r\n");
+        assert_eq!(escape("=HYPERLINK(\"x\")"), "\"'=HYPERLINK(\"\"x\"\")\"");
+        assert_eq!(escape("-"), "'-");
+    }
+}
@@ -0,0 +1,294 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Topology Diagrams
+//!
+//! Renders a [`TopologyView`] as a diagram to embed in docs and pull
+//! requests, without a graph database:
+//!
+//! | Format                     | Renders with                      |
+//! |----------------------------|-----------------------------------|
+//! | [`DiagramFormat::Dot`]     | Graphviz (`dot -Tsvg`)            |
+//! | [`DiagramFormat::Mermaid`] | GitHub/GitLab markdown, mkdocs    |
+//! | [`DiagramFormat::GraphMl`] | yEd, Gephi, Cytoscape             |
+//!
+//! Views around one resource ([`TopologyView::from_resource`]) show its
+//! organization, location, owner and policies; the inventory-wide view
+//! ([`TopologyView::connectivity`]) shows every resource and the cables
+//! between them. Nodes are shaped by kind, the root is drawn bold and edges
+//! carry their relationship. Edges whose ends are not nodes of the view
+//! are left out.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::export::diagram::{render, DiagramFormat};
+//!
+//! let view = read_model.connectivity();
+//! std::fs::write("topology.dot", render(&view, DiagramFormat::Dot))?;
+//! println!("```mermaid\n{}```", render(&view, DiagramFormat::Mermaid));
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::nats::query::{TopologyEdge, TopologyNode, TopologyNodeKind, TopologyView};
+
+use super::ExportError;
+
+/// Diagram output format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiagramFormat {
+    /// Graphviz DOT
+    Dot,
+
+    /// Mermaid flowchart
+    Mermaid,
+
+    /// GraphML (XML)
+    GraphMl,
+}
+
+impl DiagramFormat {
+    /// Parse a format name (`dot`, `mermaid`, `graphml`)
+    pub fn parse(name: &str) -> Result<Self, ExportError> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "dot" | "graphviz" => Ok(DiagramFormat::Dot),
+            "mermaid" | "mmd" => Ok(DiagramFormat::Mermaid),
+            "graphml" => Ok(DiagramFormat::GraphMl),
+            _ => Err(ExportError::UnknownFormat(name.to_string())),
+        }
+    }
+
+    /// Conventional file extension
+    pub fn extension(&self) -> &'static str {
+        match self {
+            DiagramFormat::Dot => "dot",
+            DiagramFormat::Mermaid => "mmd",
+            DiagramFormat::GraphMl => "graphml",
+        }
+    }
+}
+
+impl fmt::Display for DiagramFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            DiagramFormat::Dot => "dot",
+            DiagramFormat::Mermaid => "mermaid",
+            DiagramFormat::GraphMl => "graphml",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Render a view in the given format
+pub fn render(view: &TopologyView, format: DiagramFormat) -> String {
+    match format {
+        DiagramFormat::Dot => to_dot(view),
+        DiagramFormat::Mermaid => to_mermaid(view),
+        DiagramFormat::GraphMl => to_graphml(view),
+    }
+}
+
+/// Graphviz DOT digraph
+pub fn to_dot(view: &TopologyView) -> String {
+    let mut out = String::from("digraph topology {\n  rankdir=LR;\n");
+    for (index, node) in view.nodes.iter().enumerate() {
+        let shape = match node.kind {
+            TopologyNodeKind::ComputeResource => "box",
+            TopologyNodeKind::Organization => "house",
+            TopologyNodeKind::Location => "folder",
+            TopologyNodeKind::Person => "ellipse",
+            TopologyNodeKind::Policy => "note",
+        };
+        let style = if node.id == view.root {
+            ", style=bold"
+        } else {
+            ""
+        };
+        out.push_str(&format!(
+            "  n{} [label=\"{}\", shape={}{}];\n",
+            index,
+            node.label.replace('"', "\\\""),
+            shape,
+            style
+        ));
+    }
+    for (from, to, edge) in edges(view) {
+        out.push_str(&format!(
+            "  n{} -> n{} [label=\"{}\"];\n",
+            from, to, edge.relationship
+        ));
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// Mermaid flowchart
+pub fn to_mermaid(view: &TopologyView) -> String {
+    let mut out = String::from("flowchart LR\n");
+    for (index, node) in view.nodes.iter().enumerate() {
+        let label = node.label.replace('"', "#quot;");
+        let (open, close) = match node.kind {
+            TopologyNodeKind::ComputeResource => ("[", "]"),
+            TopologyNodeKind::Organization => ("[[", "]]"),
+            TopologyNodeKind::Location => ("[/", "/]"),
+            TopologyNodeKind::Person => ("([", "])"),
+            TopologyNodeKind::Policy => ("{{", "}}"),
+        };
+        out.push_str(&format!("  n{}{}\"{}\"{}\n", index, open, label, close));
+    }
+    for (from, to, edge) in edges(view) {
+        out.push_str(&format!("  n{} -->|{}| n{}\n", from, edge.relationship, to));
+    }
+    if let Some(root) = view.nodes.iter().position(|node| node.id == view.root) {
+        out.push_str(&format!("  style n{} stroke-width:3px\n", root));
+    }
+    out
+}
+
+/// GraphML document with `label` and `kind` node data and `relationship`
+/// edge data
+pub fn to_graphml(view: &TopologyView) -> String {
+    let mut out = String::from(concat!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+        "<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n",
+        "  <key id=\"label\" for=\"node\" attr.name=\"label\" attr.type=\"string\"/>\n",
+        "  <key id=\"kind\" for=\"node\" attr.name=\"kind\" attr.type=\"string\"/>\n",
+        "  <key id=\"root\" for=\"node\" attr.name=\"root\" attr.type=\"boolean\">",
+        "<default>false</default></key>\n",
+        "  <key id=\"relationship\" for=\"edge\" attr.name=\"relationship\" attr.type=\"string\"/>\n",
+        "  <graph id=\"topology\" edgedefault=\"directed\">\n",
+    ));
+    for node in &view.nodes {
+        out.push_str(&format!(
+            "    <node id=\"{}\">\n      <data key=\"label\">{}</data>\n      <data key=\"kind\">{}</data>\n",
+            xml_escape(&node.id),
+            xml_escape(&node.label),
+            kind_name(node)
+        ));
+        if node.id == view.root {
+            out.push_str("      <data key=\"root\">true</data>\n");
+        }
+        out.push_str("    </node>\n");
+    }
+    for (index, (from, to, edge)) in edges(view).into_iter().enumerate() {
+        out.push_str(&format!(
+            "    <edge id=\"e{}\" source=\"{}\" target=\"{}\">\n      <data key=\"relationship\">{}</data>\n    </edge>\n",
+            index,
+            xml_escape(&view.nodes[from].id),
+            xml_escape(&view.nodes[to].id),
+            xml_escape(&edge.relationship)
+        ));
+    }
+    out.push_str("  </graph>\n</graphml>\n");
+    out
+}
+
+/// Edges with both ends in the view, as node indices
+fn edges(view: &TopologyView) -> Vec<(usize, usize, &TopologyEdge)> {
+    let index: HashMap<&str, usize> = view
+        .nodes
+        .iter()
+        .enumerate()
+        .map(|(index, node)| (node.id.as_str(), index))
+        .collect();
+    view.edges
+        .iter()
+        .filter_map(|edge| {
+            Some((
+                *index.get(edge.from.as_str())?,
+                *index.get(edge.to.as_str())?,
+                edge,
+            ))
+        })
+        .collect()
+}
+
+fn kind_name(node: &TopologyNode) -> &'static str {
+    match node.kind {
+        TopologyNodeKind::ComputeResource => "compute_resource",
+        TopologyNodeKind::Organization => "organization",
+        TopologyNodeKind::Location => "location",
+        TopologyNodeKind::Person => "person",
+        TopologyNodeKind::Policy => "policy",
+    }
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn view() -> TopologyView {
+        TopologyView {
+            root: "r1".to_string(),
+            nodes: vec![
+                TopologyNode {
+                    id: "r1".to_string(),
+                    kind: TopologyNodeKind::ComputeResource,
+                    label: "web-01".to_string(),
+                },
+                TopologyNode {
+                    id: "l1".to_string(),
+                    kind: TopologyNodeKind::Location,
+                    label: "DC \"East\" & Co".to_string(),
+                },
+            ],
+            edges: vec![
+                TopologyEdge {
+                    from: "r1".to_string(),
+                    to: "l1".to_string(),
+                    relationship: "LOCATED_AT".to_string(),
+                },
+                TopologyEdge {
+                    from: "r1".to_string(),
+                    to: "missing".to_string(),
+                    relationship: "OWNED_BY".to_string(),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_formats_render_nodes_and_edges() {
+        // Arrange
+        let view = view();
+
+        // Act
+        let dot = render(&view, DiagramFormat::Dot);
+        let mermaid = render(&view, DiagramFormat::Mermaid);
+        let graphml = render(&view, DiagramFormat::GraphMl);
+
+        // Assert
+        assert!(dot.contains("n0 [label=\"web-01\", shape=box, style=bold];"));
+        assert!(dot.contains("n1 [label=\"DC \\\"East\\\" & Co\", shape=folder];"));
+        assert!(dot.contains("n0 -> n1 [label=\"LOCATED_AT\"];"));
+        assert!(!dot.contains("OWNED_BY"));
+
+        assert!(mermaid.contains("n1[/\"DC #quot;East#quot; & Co\"/]"));
+        assert!(mermaid.contains("n0 -->|LOCATED_AT| n1"));
+        assert!(mermaid.contains("style n0 stroke-width:3px"));
+
+        assert!(graphml.contains("<data key=\"label\">DC &quot;East&quot; &amp; Co</data>"));
+        assert!(graphml.contains("<edge id=\"e0\" source=\"r1\" target=\"l1\">"));
+        assert!(!graphml.contains("OWNED_BY"));
+    }
+
+    #[test]
+    fn test_format_names() {
+        assert_eq!(
+            DiagramFormat::parse("GraphML").unwrap(),
+            DiagramFormat::GraphMl
+        );
+        assert_eq!(DiagramFormat::parse("mermaid").unwrap().extension(), "mmd");
+        assert_eq!(
+            DiagramFormat::parse("svg"),
+            Err(ExportError::UnknownFormat("svg".to_string()))
+        );
+    }
+}
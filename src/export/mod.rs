@@ -0,0 +1,440 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Inventory Export for Audits
+//!
+//! Turns compute resource read models into auditor-friendly tables: one row
+//! per resource, with the columns the audit asks for, optionally narrowed
+//! by a filter. Tables are written as CSV ([`csv`]) or, with the `xlsx`
+//! feature, as an Excel workbook ([`xlsx`]). Topology views render as
+//! DOT, Mermaid or GraphML diagrams ([`diagram`], `runtime` feature).
+//!
+//! # Columns
+//!
+//! Columns are chosen by name (see [`Column::parse`]), e.g.
+//! `hostname,status,organization,metadata.rack`. The default set covers a
+//! typical compliance inventory: hostname, type, status, organization,
+//! owner, location, policies, hardware and warranty.
+//!
+//! Read models carry IDs for organizations, owners, locations and policies.
+//! Register display names with [`InventoryExport::with_label`] to show
+//! names instead; unknown IDs are written as-is.
+//!
+//! # Filtering
+//!
+//! Filters use the policy condition language
+//! ([`policy::evaluator`](crate::policy::evaluator)), e.g.
+//! `status != decommissioned and resource_type in [physical_server, hypervisor]`.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::export::{Column, InventoryExport};
+//!
+//! let export = InventoryExport::new()
+//!     .with_columns(Column::parse_list("hostname,status,owner,warranty")?)
+//!     .with_filter("status == active")?
+//!     .with_label(owner_id, "Jane Doe");
+//!
+//! std::fs::write("inventory.csv", export.to_csv(&resources))?;
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::aggregate::ComputeResourceState;
+use crate::events::ResourceStatus;
+use crate::policy::{Condition, PolicyError};
+
+pub mod csv;
+#[cfg(feature = "runtime")]
+pub mod diagram;
+#[cfg(feature = "xlsx")]
+pub mod xlsx;
+
+/// Metadata key holding a resource's warranty end date
+pub const WARRANTY_METADATA_KEY: &str = "warranty_expires";
+
+/// Export configuration errors
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ExportError {
+    /// Column name is not known
+    #[error("Unknown column '{0}'")]
+    UnknownColumn(String),
+
+    /// Filter condition does not compile
+    #[error("Invalid filter: {0}")]
+    InvalidFilter(#[from] PolicyError),
+
+    /// Workbook could not be written
+    #[error("Workbook error: {0}")]
+    Workbook(String),
+
+    /// Diagram format is not known
+    #[error("Unknown diagram format '{0}'")]
+    UnknownFormat(String),
+}
+
+/// A column of the inventory table
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Column {
+    /// Aggregate ID
+    Id,
+    /// Hostname
+    Hostname,
+    /// Resource type token
+    ResourceType,
+    /// Lifecycle status
+    Status,
+    /// Owning organization
+    Organization,
+    /// Owner / primary contact
+    Owner,
+    /// Physical location
+    Location,
+    /// Applied policies, separated by `; `
+    Policies,
+    /// Account concept
+    AccountConcept,
+    /// Hardware manufacturer
+    Manufacturer,
+    /// Hardware model
+    Model,
+    /// Serial number
+    SerialNumber,
+    /// Asset tag
+    AssetTag,
+    /// Warranty end date (metadata key [`WARRANTY_METADATA_KEY`])
+    Warranty,
+    /// When the most recent configuration backup was taken
+    LastBackup,
+    /// When the resource was registered
+    CreatedAt,
+    /// When the resource last changed
+    UpdatedAt,
+    /// Any metadata entry
+    Metadata(String),
+}
+
+impl Column {
+    /// Default audit columns
+    pub fn defaults() -> Vec<Column> {
+        vec![
+            Column::Hostname,
+            Column::ResourceType,
+            Column::Status,
+            Column::Organization,
+            Column::Owner,
+            Column::Location,
+            Column::Policies,
+            Column::Manufacturer,
+            Column::Model,
+            Column::SerialNumber,
+            Column::AssetTag,
+            Column::Warranty,
+        ]
+    }
+
+    /// Parse a column name (`hostname`, `metadata.rack`, ...)
+    pub fn parse(name: &str) -> Result<Self, ExportError> {
+        let name = name.trim();
+        if let Some(key) = name.strip_prefix("metadata.") {
+            if !key.is_empty() {
+                return Ok(Column::Metadata(key.to_string()));
+            }
+        }
+
+        Ok(match name {
+            "id" => Column::Id,
+            "hostname" => Column::Hostname,
+            "resource_type" => Column::ResourceType,
+            "status" => Column::Status,
+            "organization" => Column::Organization,
+            "owner" => Column::Owner,
+            "location" => Column::Location,
+            "policies" => Column::Policies,
+            "account_concept" => Column::AccountConcept,
+            "manufacturer" => Column::Manufacturer,
+            "model" => Column::Model,
+            "serial_number" => Column::SerialNumber,
+            "asset_tag" => Column::AssetTag,
+            "warranty" => Column::Warranty,
+            "last_backup" => Column::LastBackup,
+            "created_at" => Column::CreatedAt,
+            "updated_at" => Column::UpdatedAt,
+            _ => return Err(ExportError::UnknownColumn(name.to_string())),
+        })
+    }
+
+    /// Parse a comma-separated list of column names
+    pub fn parse_list(names: &str) -> Result<Vec<Self>, ExportError> {
+        names
+            .split(',')
+            .filter(|name| !name.trim().is_empty())
+            .map(Column::parse)
+            .collect()
+    }
+}
+
+impl fmt::Display for Column {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Column::Id => "id",
+            Column::Hostname => "hostname",
+            Column::ResourceType => "resource_type",
+            Column::Status => "status",
+            Column::Organization => "organization",
+            Column::Owner => "owner",
+            Column::Location => "location",
+            Column::Policies => "policies",
+            Column::AccountConcept => "account_concept",
+            Column::Manufacturer => "manufacturer",
+            Column::Model => "model",
+            Column::SerialNumber => "serial_number",
+            Column::AssetTag => "asset_tag",
+            Column::Warranty => "warranty",
+            Column::LastBackup => "last_backup",
+            Column::CreatedAt => "created_at",
+            Column::UpdatedAt => "updated_at",
+            Column::Metadata(key) => return write!(f, "metadata.{}", key),
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Inventory table definition: columns, filter and display names
+#[derive(Debug, Clone)]
+pub struct InventoryExport {
+    columns: Vec<Column>,
+    filter: Option<Condition>,
+    labels: HashMap<String, String>,
+}
+
+impl Default for InventoryExport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InventoryExport {
+    /// Export with the default columns and no filter
+    pub fn new() -> Self {
+        Self {
+            columns: Column::defaults(),
+            filter: None,
+            labels: HashMap::new(),
+        }
+    }
+
+    /// Choose the columns, in output order
+    pub fn with_columns(mut self, columns: Vec<Column>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Only export resources for which `condition` holds
+    pub fn with_filter(mut self, condition: &str) -> Result<Self, ExportError> {
+        self.filter = Some(Condition::compile("export filter", condition)?);
+        Ok(self)
+    }
+
+    /// Show `name` wherever the ID `id` appears
+    pub fn with_label(mut self, id: impl ToString, name: impl Into<String>) -> Self {
+        self.labels.insert(id.to_string(), name.into());
+        self
+    }
+
+    /// Selected columns
+    pub fn columns(&self) -> &[Column] {
+        &self.columns
+    }
+
+    /// Header row
+    pub fn headers(&self) -> Vec<String> {
+        self.columns.iter().map(ToString::to_string).collect()
+    }
+
+    /// Data rows of the resources passing the filter, sorted by hostname
+    pub fn rows<'a, I>(&self, resources: I) -> Vec<Vec<String>>
+    where
+        I: IntoIterator<Item = &'a ComputeResourceState>,
+    {
+        let mut selected: Vec<_> = resources
+            .into_iter()
+            .filter(|resource| resource.is_initialized())
+            .filter(|resource| {
+                self.filter
+                    .as_ref()
+                    .map_or(true, |condition| condition.matches(resource))
+            })
+            .collect();
+        selected.sort_by(|a, b| a.hostname.as_str().cmp(b.hostname.as_str()));
+
+        selected
+            .into_iter()
+            .map(|resource| {
+                self.columns
+                    .iter()
+                    .map(|column| self.cell(resource, column))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Table as CSV (RFC 4180)
+    pub fn to_csv<'a, I>(&self, resources: I) -> String
+    where
+        I: IntoIterator<Item = &'a ComputeResourceState>,
+    {
+        csv::write_table(&self.headers(), &self.rows(resources))
+    }
+
+    /// Table as an Excel workbook
+    #[cfg(feature = "xlsx")]
+    pub fn to_xlsx<'a, I>(&self, resources: I) -> Result<Vec<u8>, ExportError>
+    where
+        I: IntoIterator<Item = &'a ComputeResourceState>,
+    {
+        xlsx::write_table(&self.headers(), &self.rows(resources))
+    }
+
+    fn label(&self, id: impl ToString) -> String {
+        let id = id.to_string();
+        self.labels.get(&id).cloned().unwrap_or(id)
+    }
+
+    fn cell(&self, resource: &ComputeResourceState, column: &Column) -> String {
+        let text = |value: &Option<String>| value.clone().unwrap_or_default();
+        let metadata = |key: &str| {
+            resource
+                .metadata
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.clone())
+                .unwrap_or_default()
+        };
+
+        match column {
+            Column::Id => resource.id.to_string(),
+            Column::Hostname => resource.hostname.to_string(),
+            Column::ResourceType => resource.resource_type.as_str().to_string(),
+            Column::Status => match resource.status {
+                ResourceStatus::Provisioning => "provisioning",
+                ResourceStatus::Active => "active",
+                ResourceStatus::Maintenance => "maintenance",
+                ResourceStatus::Decommissioned => "decommissioned",
+            }
+            .to_string(),
+            Column::Organization => resource
+                .organization_id
+                .as_ref()
+                .map(|id| self.label(id))
+                .unwrap_or_default(),
+            Column::Owner => resource
+                .owner_id
+                .as_ref()
+                .map(|id| self.label(id))
+                .unwrap_or_default(),
+            Column::Location => resource
+                .location_id
+                .as_ref()
+                .map(|id| self.label(id))
+                .unwrap_or_default(),
+            Column::Policies => resource
+                .policy_ids
+                .iter()
+                .map(|id| self.label(id))
+                .collect::<Vec<_>>()
+                .join("; "),
+            Column::AccountConcept => resource
+                .account_concept_id
+                .as_ref()
+                .map(|id| self.label(id))
+                .unwrap_or_default(),
+            Column::Manufacturer => text(&resource.manufacturer),
+            Column::Model => text(&resource.model),
+            Column::SerialNumber => text(&resource.serial_number),
+            Column::AssetTag => text(&resource.asset_tag),
+            Column::Warranty => metadata(WARRANTY_METADATA_KEY),
+            Column::LastBackup => resource
+                .last_configuration_backup
+                .as_ref()
+                .map(|backup| backup.taken_at.to_rfc3339())
+                .unwrap_or_default(),
+            Column::CreatedAt => resource
+                .created_at
+                .map(|at| at.to_rfc3339())
+                .unwrap_or_default(),
+            Column::UpdatedAt => resource
+                .updated_at
+                .map(|at| at.to_rfc3339())
+                .unwrap_or_default(),
+            Column::Metadata(key) => metadata(key),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Hostname, ResourceType};
+    use chrono::{TimeZone, Utc};
+    use uuid::Uuid;
+
+    fn resource(hostname: &str, status: ResourceStatus) -> ComputeResourceState {
+        let mut state = ComputeResourceState::default_for(Uuid::now_v7());
+        state.hostname = Hostname::new(hostname).unwrap();
+        state.resource_type = ResourceType::PhysicalServer;
+        state.status = status;
+        state.created_at = Some(Utc.with_ymd_and_hms(2026, 1, 19, 12, 0, 0).unwrap());
+        state
+    }
+
+    #[test]
+    fn test_rows_follow_columns_filter_and_labels() {
+        // Arrange
+        let policy_id = cim_domain_policy::PolicyId::new();
+        let mut web = resource("web-02", ResourceStatus::Active);
+        web.policy_ids.push(policy_id.clone());
+        web.metadata
+            .push((WARRANTY_METADATA_KEY.to_string(), "2028-03-31".to_string()));
+        let db = resource("db-01", ResourceStatus::Active);
+        let retired = resource("old-01", ResourceStatus::Decommissioned);
+
+        let export = InventoryExport::new()
+            .with_columns(Column::parse_list("hostname, status, policies, warranty").unwrap())
+            .with_filter("status != decommissioned")
+            .unwrap()
+            .with_label(&policy_id, "PCI-DSS");
+
+        // Act
+        let rows = export.rows([&web, &db, &retired]);
+
+        // Assert
+        assert_eq!(
+            export.headers(),
+            ["hostname", "status", "policies", "warranty"]
+        );
+        assert_eq!(
+            rows,
+            [
+                ["db-01", "active", "", ""],
+                ["web-02", "active", "PCI-DSS", "2028-03-31"],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_columns_and_filters_are_rejected() {
+        assert_eq!(
+            Column::parse_list("hostname,colour"),
+            Err(ExportError::UnknownColumn("colour".to_string()))
+        );
+        assert!(matches!(
+            InventoryExport::new().with_filter("status =="),
+            Err(ExportError::InvalidFilter(_))
+        ));
+        assert_eq!(
+            Column::parse("metadata.rack").unwrap().to_string(),
+            "metadata.rack"
+        );
+    }
+}
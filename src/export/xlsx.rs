@@ -0,0 +1,41 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Excel Output
+//!
+//! Writes a single "Inventory" worksheet with a bold, frozen header row and
+//! an autofilter over the data. All cells are written as text, so values are
+//! never interpreted as formulas or numbers.
+
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+
+use super::ExportError;
+
+/// Worksheet name
+pub const SHEET_NAME: &str = "Inventory";
+
+/// Write a header row and data rows as an `.xlsx` workbook
+pub fn write_table(headers: &[String], rows: &[Vec<String>]) -> Result<Vec<u8>, ExportError> {
+    build(headers, rows).map_err(|e| ExportError::Workbook(e.to_string()))
+}
+
+fn build(headers: &[String], rows: &[Vec<String>]) -> Result<Vec<u8>, XlsxError> {
+    let mut workbook = Workbook::new();
+    let sheet = workbook.add_worksheet();
+    sheet.set_name(SHEET_NAME)?;
+
+    let bold = Format::new().set_bold();
+    for (col, header) in headers.iter().enumerate() {
+        sheet.write_string_with_format(0, col as u16, header, &bold)?;
+    }
+    for (row, values) in rows.iter().enumerate() {
+        for (col, value) in values.iter().enumerate() {
+            sheet.write_string(row as u32 + 1, col as u16, value)?;
+        }
+    }
+
+    if !headers.is_empty() {
+        sheet.set_freeze_panes(1, 0)?;
+        sheet.autofilter(0, 0, rows.len() as u32, headers.len() as u16 - 1)?;
+    }
+
+    workbook.save_to_buffer()
+}
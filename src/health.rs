@@ -0,0 +1,516 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Health and Readiness
+//!
+//! Structured health of every component a deployment depends on, gathered
+//! into one [`HealthReport`]:
+//!
+//! | Check             | Healthy                 | Degraded                | Unhealthy              |
+//! |-------------------|-------------------------|-------------------------|------------------------|
+//! | [`NatsHealth`]    | connected               | reconnecting            | disconnected           |
+//! | [`StreamHealth`]  | stream info readable    |                         | stream missing         |
+//! | [`ProjectionLag`] | lag ≤ degraded limit    | lag ≤ unhealthy limit   | further behind         |
+//! | [`AdapterHealth`] | `health_check()` passes |                         | `health_check()` fails |
+//!
+//! [`AdapterHealth`] wraps any [`ProjectionAdapter`](crate::projection::ProjectionAdapter),
+//! so Neo4j and NetBox reachability are checked by their adapters' own
+//! health checks.
+//!
+//! The report's status is that of its worst component. A deployment is
+//! *ready* unless a component is unhealthy; degraded components (a
+//! reconnecting client, a lagging projection) still serve traffic.
+//!
+//! # Publishing
+//!
+//! [`HealthMonitor::run`] publishes a report on `infrastructure.health.report`
+//! at a fixed interval. With the `rest` feature,
+//! [`api::rest::health_router`](crate::api::rest::health_router) serves the
+//! current report on `GET /healthz` (200 when ready, 503 otherwise).
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::health::{HealthMonitor, NatsHealth, ProjectionLag, StreamHealth};
+//!
+//! let monitor = HealthMonitor::new()
+//!     .with_check(NatsHealth::new(client.clone()))
+//!     .with_check(StreamHealth::new(jetstream.clone(), "INFRASTRUCTURE_EVENTS"))
+//!     .with_check(ProjectionLag::new(jetstream, "INFRASTRUCTURE_EVENTS", manager.positions()));
+//! tokio::spawn(async move { monitor.run(client, Duration::from_secs(30)).await });
+//! ```
+
+#[cfg(feature = "runtime")]
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+#[cfg(feature = "runtime")]
+use std::time::Duration;
+#[cfg(feature = "runtime")]
+use tracing::{info, warn};
+
+#[cfg(feature = "runtime")]
+use crate::errors::InfrastructureResult;
+#[cfg(feature = "runtime")]
+use crate::nats::NatsClient;
+#[cfg(feature = "runtime")]
+use crate::projection::manager::ProjectionPositions;
+#[cfg(feature = "runtime")]
+use crate::projection::ProjectionAdapter;
+#[cfg(feature = "runtime")]
+use crate::subjects::subjects;
+
+/// Health of a component, ordered from best to worst
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum HealthStatus {
+    /// Working normally
+    Healthy,
+
+    /// Working, but impaired (reconnecting, lagging)
+    Degraded,
+
+    /// Not working
+    Unhealthy,
+}
+
+impl fmt::Display for HealthStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HealthStatus::Healthy => write!(f, "healthy"),
+            HealthStatus::Degraded => write!(f, "degraded"),
+            HealthStatus::Unhealthy => write!(f, "unhealthy"),
+        }
+    }
+}
+
+/// Health of one component
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ComponentHealth {
+    /// Component name (e.g. `nats`, `stream`, `neo4j`)
+    pub name: String,
+
+    /// Status
+    pub status: HealthStatus,
+
+    /// What was found, for operators
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+impl ComponentHealth {
+    /// Healthy component
+    pub fn healthy(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            status: HealthStatus::Healthy,
+            detail: None,
+        }
+    }
+
+    /// Degraded component
+    pub fn degraded(name: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            status: HealthStatus::Degraded,
+            detail: Some(detail.into()),
+        }
+    }
+
+    /// Unhealthy component
+    pub fn unhealthy(name: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            status: HealthStatus::Unhealthy,
+            detail: Some(detail.into()),
+        }
+    }
+
+    /// Attach a detail
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+}
+
+/// Health of every component at one point in time
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct HealthReport {
+    /// Worst status of any component (healthy when there are none)
+    pub status: HealthStatus,
+
+    /// When the checks ran
+    pub checked_at: DateTime<Utc>,
+
+    /// Component results, in check order
+    pub components: Vec<ComponentHealth>,
+}
+
+impl HealthReport {
+    /// Report over component results
+    pub fn new(components: Vec<ComponentHealth>, checked_at: DateTime<Utc>) -> Self {
+        Self {
+            status: components
+                .iter()
+                .map(|c| c.status)
+                .max()
+                .unwrap_or(HealthStatus::Healthy),
+            checked_at,
+            components,
+        }
+    }
+
+    /// Whether the deployment can serve traffic (nothing unhealthy)
+    pub fn is_ready(&self) -> bool {
+        self.status != HealthStatus::Unhealthy
+    }
+
+    /// Result of one component
+    pub fn component(&self, name: &str) -> Option<&ComponentHealth> {
+        self.components.iter().find(|c| c.name == name)
+    }
+}
+
+/// One component's health check
+#[cfg(feature = "runtime")]
+#[async_trait]
+pub trait HealthCheck: Send + Sync {
+    /// Component name the result is reported under
+    fn name(&self) -> &str;
+
+    /// Check the component
+    ///
+    /// Failures are reported as unhealthy results, never as errors.
+    async fn check(&self) -> ComponentHealth;
+}
+
+/// NATS connection state
+#[cfg(feature = "runtime")]
+pub struct NatsHealth {
+    client: NatsClient,
+}
+
+#[cfg(feature = "runtime")]
+impl NatsHealth {
+    /// Check the connection of `client`
+    pub fn new(client: NatsClient) -> Self {
+        Self { client }
+    }
+}
+
+#[cfg(feature = "runtime")]
+#[async_trait]
+impl HealthCheck for NatsHealth {
+    fn name(&self) -> &str {
+        "nats"
+    }
+
+    async fn check(&self) -> ComponentHealth {
+        use async_nats::connection::State;
+
+        match self.client.inner().connection_state() {
+            State::Connected => ComponentHealth::healthy("nats"),
+            State::Pending => ComponentHealth::degraded("nats", "reconnecting"),
+            State::Disconnected => ComponentHealth::unhealthy("nats", "disconnected"),
+        }
+    }
+}
+
+/// JetStream stream status
+#[cfg(feature = "runtime")]
+pub struct StreamHealth {
+    jetstream: async_nats::jetstream::Context,
+    stream_name: String,
+}
+
+#[cfg(feature = "runtime")]
+impl StreamHealth {
+    /// Check the stream `stream_name`
+    pub fn new(jetstream: async_nats::jetstream::Context, stream_name: impl Into<String>) -> Self {
+        Self {
+            jetstream,
+            stream_name: stream_name.into(),
+        }
+    }
+}
+
+#[cfg(feature = "runtime")]
+#[async_trait]
+impl HealthCheck for StreamHealth {
+    fn name(&self) -> &str {
+        "stream"
+    }
+
+    async fn check(&self) -> ComponentHealth {
+        let info = match self.jetstream.get_stream(&self.stream_name).await {
+            Ok(mut stream) => stream.info().await.map(|info| info.state.clone()),
+            Err(e) => {
+                return ComponentHealth::unhealthy("stream", format!("{}: {}", self.stream_name, e))
+            }
+        };
+        match info {
+            Ok(state) => ComponentHealth::healthy("stream").with_detail(format!(
+                "{}: {} messages, last sequence {}",
+                self.stream_name, state.messages, state.last_sequence
+            )),
+            Err(e) => ComponentHealth::unhealthy("stream", format!("{}: {}", self.stream_name, e)),
+        }
+    }
+}
+
+/// How far projections are behind the stream
+#[cfg(feature = "runtime")]
+pub struct ProjectionLag {
+    jetstream: async_nats::jetstream::Context,
+    stream_name: String,
+    positions: ProjectionPositions,
+    degraded_after: u64,
+    unhealthy_after: u64,
+}
+
+#[cfg(feature = "runtime")]
+impl ProjectionLag {
+    /// Check the projections behind `positions`; degraded beyond 100
+    /// events of lag, unhealthy beyond 10 000
+    pub fn new(
+        jetstream: async_nats::jetstream::Context,
+        stream_name: impl Into<String>,
+        positions: ProjectionPositions,
+    ) -> Self {
+        Self {
+            jetstream,
+            stream_name: stream_name.into(),
+            positions,
+            degraded_after: 100,
+            unhealthy_after: 10_000,
+        }
+    }
+
+    /// Lag (in events) beyond which projections are degraded and
+    /// unhealthy
+    pub fn with_thresholds(mut self, degraded_after: u64, unhealthy_after: u64) -> Self {
+        self.degraded_after = degraded_after;
+        self.unhealthy_after = unhealthy_after;
+        self
+    }
+
+    /// Status of a lag
+    fn status(&self, lag: u64) -> HealthStatus {
+        if lag > self.unhealthy_after {
+            HealthStatus::Unhealthy
+        } else if lag > self.degraded_after {
+            HealthStatus::Degraded
+        } else {
+            HealthStatus::Healthy
+        }
+    }
+}
+
+#[cfg(feature = "runtime")]
+#[async_trait]
+impl HealthCheck for ProjectionLag {
+    fn name(&self) -> &str {
+        "projections"
+    }
+
+    async fn check(&self) -> ComponentHealth {
+        let last_sequence = match self.jetstream.get_stream(&self.stream_name).await {
+            Ok(mut stream) => match stream.info().await {
+                Ok(info) => info.state.last_sequence,
+                Err(e) => return ComponentHealth::unhealthy("projections", e.to_string()),
+            },
+            Err(e) => return ComponentHealth::unhealthy("projections", e.to_string()),
+        };
+
+        let lags: Vec<(String, u64)> = self
+            .positions
+            .current()
+            .into_iter()
+            .map(|(name, position)| (name, last_sequence.saturating_sub(position)))
+            .collect();
+        let status = lags
+            .iter()
+            .map(|(_, lag)| self.status(*lag))
+            .max()
+            .unwrap_or(HealthStatus::Healthy);
+        let detail = lags
+            .iter()
+            .map(|(name, lag)| format!("{} lag {}", name, lag))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        ComponentHealth {
+            name: "projections".to_string(),
+            status,
+            detail: (!detail.is_empty()).then_some(detail),
+        }
+    }
+}
+
+/// A projection target's own health check (Neo4j, NetBox, ...)
+#[cfg(feature = "runtime")]
+pub struct AdapterHealth<P> {
+    name: String,
+    adapter: P,
+}
+
+#[cfg(feature = "runtime")]
+impl<P: ProjectionAdapter> AdapterHealth<P> {
+    /// Report `adapter`'s health under `name`
+    ///
+    /// The adapter is only used for health checks; give it its own
+    /// connection rather than the one a projection manager runs.
+    pub fn new(name: impl Into<String>, adapter: P) -> Self {
+        Self {
+            name: name.into(),
+            adapter,
+        }
+    }
+}
+
+#[cfg(feature = "runtime")]
+#[async_trait]
+impl<P> HealthCheck for AdapterHealth<P>
+where
+    P: ProjectionAdapter + Send + Sync,
+{
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn check(&self) -> ComponentHealth {
+        match self.adapter.health_check().await {
+            Ok(()) => ComponentHealth::healthy(self.name.clone()),
+            Err(e) => ComponentHealth::unhealthy(self.name.clone(), e.to_string()),
+        }
+    }
+}
+
+/// Runs health checks and publishes their reports
+#[cfg(feature = "runtime")]
+pub struct HealthMonitor {
+    checks: Vec<Box<dyn HealthCheck>>,
+    timeout: Duration,
+}
+
+#[cfg(feature = "runtime")]
+impl Default for HealthMonitor {
+    fn default() -> Self {
+        Self {
+            checks: Vec::new(),
+            timeout: Duration::from_secs(5),
+        }
+    }
+}
+
+#[cfg(feature = "runtime")]
+impl HealthMonitor {
+    /// Monitor without checks and a 5 second timeout per check
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a check
+    pub fn with_check(mut self, check: impl HealthCheck + 'static) -> Self {
+        self.checks.push(Box::new(check));
+        self
+    }
+
+    /// Report a check that takes longer than `timeout` as unhealthy
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Run every check concurrently
+    pub async fn check(&self) -> HealthReport {
+        let checked_at = Utc::now();
+        let results = futures::future::join_all(self.checks.iter().map(|check| async move {
+            tokio::time::timeout(self.timeout, check.check())
+                .await
+                .unwrap_or_else(|_| {
+                    ComponentHealth::unhealthy(
+                        check.name(),
+                        format!("no result within {:?}", self.timeout),
+                    )
+                })
+        }))
+        .await;
+        HealthReport::new(results, checked_at)
+    }
+
+    /// Run the checks and publish the report on
+    /// `infrastructure.health.report`
+    pub async fn publish(&self, client: &NatsClient) -> InfrastructureResult<HealthReport> {
+        let report = self.check().await;
+        client.publish(&subjects::health_report(), &report).await?;
+        Ok(report)
+    }
+
+    /// Publish a report on a fixed interval until publishing fails
+    pub async fn run(&self, client: NatsClient, interval: Duration) -> InfrastructureResult<()> {
+        info!("Starting health monitor (interval: {:?})", interval);
+        let mut ticker = tokio::time::interval(interval);
+        let mut last_status = None;
+
+        loop {
+            ticker.tick().await;
+            let report = self.publish(&client).await?;
+            if last_status != Some(report.status) {
+                if report.status == HealthStatus::Healthy {
+                    info!("Health: {}", report.status);
+                } else {
+                    let impaired: Vec<String> = report
+                        .components
+                        .iter()
+                        .filter(|c| c.status != HealthStatus::Healthy)
+                        .map(|c| format!("{} {}", c.name, c.status))
+                        .collect();
+                    warn!("Health: {} ({})", report.status, impaired.join(", "));
+                }
+                last_status = Some(report.status);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_status_is_worst_component() {
+        // Arrange
+        let components = vec![
+            ComponentHealth::healthy("nats"),
+            ComponentHealth::degraded("projections", "neo4j lag 250"),
+        ];
+
+        // Act
+        let degraded = HealthReport::new(components.clone(), Utc::now());
+        let unhealthy = HealthReport::new(
+            [
+                components,
+                vec![ComponentHealth::unhealthy("neo4j", "connection refused")],
+            ]
+            .concat(),
+            Utc::now(),
+        );
+
+        // Assert
+        assert_eq!(degraded.status, HealthStatus::Degraded);
+        assert!(degraded.is_ready());
+        assert_eq!(unhealthy.status, HealthStatus::Unhealthy);
+        assert!(!unhealthy.is_ready());
+        assert_eq!(
+            unhealthy.component("neo4j").unwrap().detail.as_deref(),
+            Some("connection refused")
+        );
+        assert_eq!(
+            HealthReport::new(vec![], Utc::now()).status,
+            HealthStatus::Healthy
+        );
+    }
+}
@@ -0,0 +1,603 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Bulk Import Documents
+//!
+//! A bulk import document lists networks, resources, interfaces and
+//! connections in YAML or JSON. Items refer to each other by name:
+//!
+//! ```text
+//! networks:      name ◄────────────────┐
+//! resources:     hostname ◄──────┐     │
+//! interfaces:    resource ───────┘     │
+//!                network ──────────────┘
+//!                name ◄──────────┐
+//! connections:   a_end / b_end ──┘ (resource + interface)
+//! ```
+//!
+//! [`BulkImportDocument::plan`] validates the whole document before
+//! anything is sent: every value must parse, every reference must resolve,
+//! hostnames (case-insensitively), network names and interface names per
+//! resource must be unique, and no interface may carry two cables. All
+//! problems are reported together in [`BulkImportError::Invalid`].
+//!
+//! The plan's commands share one correlation ID and timestamp, so the
+//! import can be traced as one operation. Resources are registered under a
+//! UUIDv5 of their hostname; importing a document again finds them already
+//! registered. Networks are assigned IDs by the network service, as with
+//! the Terraform import.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::import::BulkImportDocument;
+//!
+//! let document = BulkImportDocument::from_yaml(&std::fs::read_to_string("rack-a.yaml")?)?;
+//! let plan = document.plan(Uuid::now_v7(), Utc::now())?;
+//! let report = bulk_import_service.import(&plan).await?;
+//! ```
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use uuid::Uuid;
+
+use crate::aggregate::commands::{RegisterResourceCommand, UpdateMetadataCommand};
+use crate::aggregate::connection::EstablishConnectionCommand;
+use crate::aggregate::network::DefineNetworkCommand;
+use crate::aggregate::network_interface::AttachInterfaceCommand;
+use crate::domain::{Hostname, IpAddressWithCidr, MacAddress, ResourceType, VlanId};
+use crate::events::ConnectionEndpoint;
+
+/// Namespace for the aggregate IDs of bulk-imported resources
+pub const BULK_IMPORT_NAMESPACE: Uuid = Uuid::from_u128(0x8c41_5e07_d2a9_4f3b_b6e1_07c8_59a2_f41d);
+
+/// Name of documents that don't set one
+pub const DEFAULT_DOCUMENT_NAME: &str = "bulk-import";
+
+/// Bulk import errors
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum BulkImportError {
+    /// Document is not valid YAML or JSON of the expected shape
+    #[error("Invalid bulk import document: {0}")]
+    Parse(String),
+
+    /// Document parsed but its items don't hold together
+    #[error("Bulk import document has {} problems: {}", .0.len(), .0.join("; "))]
+    Invalid(Vec<String>),
+}
+
+/// A network to define
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NetworkSpec {
+    /// Network name, referenced by interfaces
+    pub name: String,
+
+    /// Network prefix (`10.0.1.0/24`)
+    pub cidr: String,
+
+    /// VLAN carrying the network
+    #[serde(default)]
+    pub vlan_id: Option<u16>,
+
+    /// Layer-2 segment the VLAN lives on
+    #[serde(default)]
+    pub segment: Option<String>,
+}
+
+/// A compute resource to register
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceSpec {
+    /// Hostname, referenced by interfaces and connections
+    pub hostname: String,
+
+    /// Resource type (`physical_server`, `switch`, ...)
+    pub resource_type: ResourceType,
+
+    /// Metadata recorded after registration
+    #[serde(default)]
+    pub metadata: BTreeMap<String, String>,
+}
+
+/// An interface to attach to a network
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InterfaceSpec {
+    /// Hostname of the owning resource
+    pub resource: String,
+
+    /// Name of the network
+    pub network: String,
+
+    /// Interface name on the resource (`eth0`)
+    pub name: String,
+
+    /// Hardware address
+    #[serde(default)]
+    pub mac_address: Option<String>,
+
+    /// Addresses on the network (`10.0.1.10/24`)
+    #[serde(default)]
+    pub addresses: Vec<String>,
+
+    /// Link speed in Mbit/s
+    #[serde(default)]
+    pub speed_mbps: Option<u32>,
+}
+
+/// One end of a cable, by hostname and interface name
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PortSpec {
+    /// Hostname of the device
+    pub resource: String,
+
+    /// Interface name on the device
+    pub interface: String,
+}
+
+/// A cable between two interfaces of the document
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConnectionSpec {
+    /// A side of the cable
+    pub a_end: PortSpec,
+
+    /// B side of the cable
+    pub b_end: PortSpec,
+
+    /// Cable label
+    #[serde(default)]
+    pub label: Option<String>,
+}
+
+/// Everything one bulk import brings in
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BulkImportDocument {
+    /// Name shown in progress reports
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// Networks, defined first
+    #[serde(default)]
+    pub networks: Vec<NetworkSpec>,
+
+    /// Compute resources
+    #[serde(default)]
+    pub resources: Vec<ResourceSpec>,
+
+    /// Interfaces, attached once resources and networks exist
+    #[serde(default)]
+    pub interfaces: Vec<InterfaceSpec>,
+
+    /// Connections, established last
+    #[serde(default)]
+    pub connections: Vec<ConnectionSpec>,
+}
+
+/// A resource to register, with its metadata
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BulkResource {
+    /// Aggregate ID derived from the hostname
+    pub aggregate_id: Uuid,
+
+    /// Command registering the resource
+    pub command: RegisterResourceCommand,
+
+    /// Metadata recorded after registration
+    pub metadata: Vec<UpdateMetadataCommand>,
+}
+
+/// An interface to attach once its network is defined
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BulkInterface {
+    /// Aggregate ID of the owning resource
+    pub resource_id: Uuid,
+
+    /// Name of the network
+    pub network: String,
+
+    /// Interface name on the resource
+    pub name: String,
+
+    /// Hardware address
+    pub mac_address: Option<MacAddress>,
+
+    /// Addresses on the network
+    pub addresses: Vec<IpAddressWithCidr>,
+
+    /// Link speed in Mbit/s
+    pub speed_mbps: Option<u32>,
+}
+
+/// One end of a planned cable
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BulkPort {
+    /// Aggregate ID of the device
+    pub resource_id: Uuid,
+
+    /// Hostname of the device
+    pub device: Hostname,
+
+    /// Interface name on the device
+    pub interface: String,
+}
+
+/// A cable to establish once both interfaces are attached
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BulkConnection {
+    /// A side of the cable
+    pub a_end: BulkPort,
+
+    /// B side of the cable
+    pub b_end: BulkPort,
+
+    /// Cable label
+    pub label: Option<String>,
+}
+
+/// Validated commands of a bulk import document
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BulkImportPlan {
+    /// Document name
+    pub name: String,
+
+    /// Correlation ID carried by every command
+    pub correlation_id: Uuid,
+
+    /// Timestamp carried by every command
+    pub timestamp: DateTime<Utc>,
+
+    /// Networks, defined first
+    pub networks: Vec<DefineNetworkCommand>,
+
+    /// Compute resources
+    pub resources: Vec<BulkResource>,
+
+    /// Interfaces
+    pub interfaces: Vec<BulkInterface>,
+
+    /// Connections, established last
+    pub connections: Vec<BulkConnection>,
+}
+
+impl BulkImportDocument {
+    /// Parse a YAML document
+    pub fn from_yaml(yaml: &str) -> Result<Self, BulkImportError> {
+        serde_yaml::from_str(yaml).map_err(|e| BulkImportError::Parse(e.to_string()))
+    }
+
+    /// Parse a JSON document
+    pub fn from_json(json: &str) -> Result<Self, BulkImportError> {
+        serde_json::from_str(json).map_err(|e| BulkImportError::Parse(e.to_string()))
+    }
+
+    /// Validate the document and plan its commands
+    pub fn plan(
+        &self,
+        correlation_id: Uuid,
+        timestamp: DateTime<Utc>,
+    ) -> Result<BulkImportPlan, BulkImportError> {
+        let mut problems = Vec::new();
+        let mut plan = BulkImportPlan {
+            name: self
+                .name
+                .clone()
+                .unwrap_or_else(|| DEFAULT_DOCUMENT_NAME.to_string()),
+            correlation_id,
+            timestamp,
+            networks: Vec::new(),
+            resources: Vec::new(),
+            interfaces: Vec::new(),
+            connections: Vec::new(),
+        };
+
+        let mut network_names = HashSet::new();
+        for (index, spec) in self.networks.iter().enumerate() {
+            let at = format!("networks[{}]", index);
+            if spec.name.trim().is_empty() {
+                problems.push(format!("{}: name must not be empty", at));
+            } else if !network_names.insert(spec.name.as_str()) {
+                problems.push(format!("{}: duplicate network name {}", at, spec.name));
+            }
+            let cidr = IpAddressWithCidr::new(&spec.cidr)
+                .map_err(|e| problems.push(format!("{}: cidr {}: {}", at, spec.cidr, e)));
+            let vlan_id = spec
+                .vlan_id
+                .map(VlanId::new)
+                .transpose()
+                .map_err(|e| problems.push(format!("{}: vlan_id: {}", at, e)));
+
+            if let (Ok(cidr), Ok(vlan_id)) = (cidr, vlan_id) {
+                plan.networks.push(DefineNetworkCommand {
+                    name: spec.name.clone(),
+                    cidr,
+                    vlan_id,
+                    segment: spec.segment.clone(),
+                    timestamp,
+                    correlation_id,
+                    causation_id: None,
+                });
+            }
+        }
+
+        // Keyed by lowercase hostname
+        let mut resources: HashMap<String, (Uuid, Hostname)> = HashMap::new();
+        for (index, spec) in self.resources.iter().enumerate() {
+            let at = format!("resources[{}]", index);
+            let hostname = match Hostname::new(spec.hostname.as_str()) {
+                Ok(hostname) => hostname,
+                Err(e) => {
+                    problems.push(format!("{}: hostname {}: {}", at, spec.hostname, e));
+                    continue;
+                }
+            };
+            let key = hostname.as_str().to_ascii_lowercase();
+            if resources.contains_key(&key) {
+                problems.push(format!("{}: duplicate hostname {}", at, spec.hostname));
+                continue;
+            }
+
+            let aggregate_id = Uuid::new_v5(&BULK_IMPORT_NAMESPACE, key.as_bytes());
+            resources.insert(key, (aggregate_id, hostname.clone()));
+            plan.resources.push(BulkResource {
+                aggregate_id,
+                command: RegisterResourceCommand {
+                    hostname,
+                    resource_type: spec.resource_type,
+                    timestamp,
+                    correlation_id,
+                    change_ref: None,
+                },
+                metadata: spec
+                    .metadata
+                    .iter()
+                    .map(|(key, value)| UpdateMetadataCommand {
+                        key: key.clone(),
+                        value: value.clone(),
+                        timestamp,
+                        correlation_id,
+                        causation_id: None,
+                        change_ref: None,
+                    })
+                    .collect(),
+            });
+        }
+        let resource = |name: &str| resources.get(&name.to_ascii_lowercase());
+
+        let mut interfaces: HashSet<(Uuid, &str)> = HashSet::new();
+        for (index, spec) in self.interfaces.iter().enumerate() {
+            let at = format!("interfaces[{}]", index);
+            let Some((resource_id, _)) = resource(&spec.resource) else {
+                problems.push(format!("{}: unknown resource {}", at, spec.resource));
+                continue;
+            };
+            if !network_names.contains(spec.network.as_str()) {
+                problems.push(format!("{}: unknown network {}", at, spec.network));
+            }
+            if spec.name.trim().is_empty() {
+                problems.push(format!("{}: name must not be empty", at));
+            } else if !interfaces.insert((*resource_id, spec.name.as_str())) {
+                problems.push(format!(
+                    "{}: duplicate interface {} on {}",
+                    at, spec.name, spec.resource
+                ));
+            }
+            let mac_address = spec
+                .mac_address
+                .as_deref()
+                .map(MacAddress::new)
+                .transpose()
+                .map_err(|e| problems.push(format!("{}: mac_address: {}", at, e)));
+            let addresses: Result<Vec<_>, _> = spec
+                .addresses
+                .iter()
+                .map(|address| {
+                    IpAddressWithCidr::new(address)
+                        .map_err(|e| format!("{}: address {}: {}", at, address, e))
+                })
+                .collect();
+            let addresses = addresses.map_err(|problem| problems.push(problem));
+
+            if let (Ok(mac_address), Ok(addresses)) = (mac_address, addresses) {
+                plan.interfaces.push(BulkInterface {
+                    resource_id: *resource_id,
+                    network: spec.network.clone(),
+                    name: spec.name.clone(),
+                    mac_address,
+                    addresses,
+                    speed_mbps: spec.speed_mbps,
+                });
+            }
+        }
+
+        let mut cabled: HashSet<(Uuid, String)> = HashSet::new();
+        for (index, spec) in self.connections.iter().enumerate() {
+            let at = format!("connections[{}]", index);
+            let mut port = |end: &str, spec: &PortSpec| -> Option<BulkPort> {
+                let Some((resource_id, device)) = resource(&spec.resource) else {
+                    problems.push(format!(
+                        "{}.{}: unknown resource {}",
+                        at, end, spec.resource
+                    ));
+                    return None;
+                };
+                if !interfaces.contains(&(*resource_id, spec.interface.as_str())) {
+                    problems.push(format!(
+                        "{}.{}: unknown interface {} on {}",
+                        at, end, spec.interface, spec.resource
+                    ));
+                    return None;
+                }
+                Some(BulkPort {
+                    resource_id: *resource_id,
+                    device: device.clone(),
+                    interface: spec.interface.clone(),
+                })
+            };
+            let a_end = port("a_end", &spec.a_end);
+            let b_end = port("b_end", &spec.b_end);
+            let (Some(a_end), Some(b_end)) = (a_end, b_end) else {
+                continue;
+            };
+
+            if a_end == b_end {
+                problems.push(format!("{}: both ends are the same interface", at));
+                continue;
+            }
+            for end in [&a_end, &b_end] {
+                if !cabled.insert((end.resource_id, end.interface.clone())) {
+                    problems.push(format!(
+                        "{}: interface {} on {} already has a cable",
+                        at, end.interface, end.device
+                    ));
+                }
+            }
+            plan.connections.push(BulkConnection {
+                a_end,
+                b_end,
+                label: spec.label.clone(),
+            });
+        }
+
+        if problems.is_empty() {
+            Ok(plan)
+        } else {
+            Err(BulkImportError::Invalid(problems))
+        }
+    }
+}
+
+impl BulkImportPlan {
+    /// Commands the plan sends, for progress totals
+    pub fn command_count(&self) -> usize {
+        self.networks.len()
+            + self.resources.len()
+            + self
+                .resources
+                .iter()
+                .map(|resource| resource.metadata.len())
+                .sum::<usize>()
+            + self.interfaces.len()
+            + self.connections.len()
+    }
+
+    /// Command attaching `interface` to the network defined as `network_id`
+    pub fn attach_command(
+        &self,
+        interface: &BulkInterface,
+        network_id: Uuid,
+    ) -> AttachInterfaceCommand {
+        AttachInterfaceCommand {
+            resource_id: interface.resource_id,
+            network_id,
+            name: interface.name.clone(),
+            mac_address: interface.mac_address.clone(),
+            addresses: interface.addresses.clone(),
+            speed_mbps: interface.speed_mbps,
+            timestamp: self.timestamp,
+            correlation_id: self.correlation_id,
+            causation_id: None,
+        }
+    }
+
+    /// Command establishing `connection` between the attached interfaces
+    pub fn establish_command(
+        &self,
+        connection: &BulkConnection,
+        a_interface_id: Uuid,
+        b_interface_id: Uuid,
+    ) -> EstablishConnectionCommand {
+        let endpoint = |port: &BulkPort, interface_id: Uuid| ConnectionEndpoint {
+            interface_id,
+            resource_id: port.resource_id,
+            device: port.device.clone(),
+            port: port.interface.clone(),
+        };
+
+        EstablishConnectionCommand {
+            a_end: endpoint(&connection.a_end, a_interface_id),
+            b_end: endpoint(&connection.b_end, b_interface_id),
+            label: connection.label.clone(),
+            timestamp: self.timestamp,
+            correlation_id: self.correlation_id,
+            causation_id: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_timestamp() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-19T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    const DOCUMENT: &str = r#"
+name: rack-a
+networks:
+  - name: lan
+    cidr: 10.0.1.0/24
+    vlan_id: 100
+resources:
+  - hostname: web-01
+    resource_type: physical_server
+    metadata:
+      rack: a1
+  - hostname: sw-01
+    resource_type: switch
+interfaces:
+  - resource: web-01
+    network: lan
+    name: eth0
+    addresses: [10.0.1.10/24]
+  - resource: SW-01
+    network: lan
+    name: xe-0/0/1
+connections:
+  - a_end: {resource: web-01, interface: eth0}
+    b_end: {resource: sw-01, interface: xe-0/0/1}
+    label: C-0001
+"#;
+
+    #[test]
+    fn test_plan_resolves_references() {
+        // Arrange
+        let document = BulkImportDocument::from_yaml(DOCUMENT).unwrap();
+        let correlation_id = Uuid::now_v7();
+
+        // Act
+        let plan = document.plan(correlation_id, test_timestamp()).unwrap();
+
+        // Assert
+        assert_eq!(plan.name, "rack-a");
+        assert_eq!(plan.command_count(), 7);
+        let switch = &plan.resources[1];
+        assert_eq!(plan.interfaces[1].resource_id, switch.aggregate_id);
+        assert_eq!(plan.connections[0].b_end.device.as_str(), "sw-01");
+        let command = plan.establish_command(&plan.connections[0], Uuid::now_v7(), Uuid::now_v7());
+        assert_eq!(command.correlation_id, correlation_id);
+        assert_eq!(command.label.as_deref(), Some("C-0001"));
+    }
+
+    #[test]
+    fn test_plan_reports_every_problem() {
+        let mut document = BulkImportDocument::from_yaml(DOCUMENT).unwrap();
+        document.resources[1].hostname = "WEB-01".to_string();
+        document.interfaces[0].network = "wan".to_string();
+
+        let result = document.plan(Uuid::now_v7(), test_timestamp());
+
+        let Err(BulkImportError::Invalid(problems)) = result else {
+            panic!("expected validation problems, got {:?}", result);
+        };
+        assert!(problems
+            .iter()
+            .any(|p| p.contains("duplicate hostname WEB-01")));
+        assert!(problems.iter().any(|p| p.contains("unknown network wan")));
+        assert!(problems
+            .iter()
+            .any(|p| p.contains("unknown resource SW-01")));
+        assert!(problems
+            .iter()
+            .any(|p| p.contains("b_end: unknown resource sw-01")));
+    }
+}
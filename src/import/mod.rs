@@ -0,0 +1,28 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Inventory Import
+//!
+//! Bootstraps the event store from inventories kept elsewhere. Each source
+//! is read into a plan of ordinary commands (the same ones a client would
+//! send), so imported resources go through the usual business rules and
+//! their events look like any other registration.
+//!
+//! # Modules
+//!
+//! - [`terraform`] - Terraform state files (format version 4)
+//! - [`bulk`] - YAML/JSON documents of networks, resources, interfaces and
+//!   connections, executed by
+//!   [`BulkImportService`](crate::service::bulk_import::BulkImportService)
+
+pub mod bulk;
+pub mod terraform;
+
+pub use bulk::{
+    BulkConnection, BulkImportDocument, BulkImportError, BulkImportPlan, BulkInterface, BulkPort,
+    BulkResource, ConnectionSpec, InterfaceSpec, NetworkSpec, PortSpec, ResourceSpec,
+};
+#[cfg(feature = "runtime")]
+pub use terraform::ImportReport;
+pub use terraform::{
+    ImportPlan, PlannedInterface, PlannedNetwork, PlannedResource, SkippedResource, StateInstance,
+    StateResource, TerraformError, TerraformState,
+};
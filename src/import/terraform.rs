@@ -0,0 +1,686 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Terraform State Import
+//!
+//! Reads a Terraform state file (`terraform.tfstate`, format version 4) and
+//! plans the commands that register the infrastructure it describes:
+//!
+//! ```text
+//! aws_subnet, google_compute_subnetwork,        ──> DefineNetwork
+//! digitalocean_vpc
+//!
+//! aws_instance, google_compute_instance,        ──> RegisterResource (virtual machine)
+//! digitalocean_droplet                              UpdateMetadata   (terraform_address,
+//!                                                                     cloud_provider, cloud_id,
+//!                                                                     instance_type)
+//!
+//! instance subnet + private address             ──> AttachInterface
+//! ```
+//!
+//! Planning is pure: [`ImportPlan::from_state`] takes the correlation ID and
+//! timestamp every command carries, so all events of one import share a
+//! correlation ID and can be traced (or reviewed with `--dry-run`) as one
+//! operation. Managed resources of other types, and instances that cannot
+//! be mapped (no usable hostname, subnet missing from the state), are listed
+//! in [`ImportPlan::skipped`]; data sources are ignored.
+//!
+//! # Identity
+//!
+//! Resources are registered under a UUIDv5 of the state's lineage and the
+//! Terraform address, so importing the same state again finds them already
+//! registered instead of creating duplicates. Networks are assigned IDs by
+//! the network service; an import is meant to bootstrap networks once.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::import::{ImportPlan, TerraformState};
+//!
+//! let state = TerraformState::from_json(&std::fs::read_to_string("terraform.tfstate")?)?;
+//! let plan = ImportPlan::from_state(&state, Uuid::now_v7(), Utc::now());
+//! let report = plan.apply(&compute_service, &network_service).await?;
+//! ```
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::net::IpAddr;
+use uuid::Uuid;
+
+use crate::aggregate::commands::{RegisterResourceCommand, UpdateMetadataCommand};
+use crate::aggregate::network::DefineNetworkCommand;
+use crate::aggregate::network_interface::AttachInterfaceCommand;
+use crate::domain::{Hostname, IpAddressWithCidr, ResourceType};
+
+#[cfg(feature = "runtime")]
+use crate::aggregate::CommandError;
+#[cfg(feature = "runtime")]
+use crate::service::network::NetworkService;
+#[cfg(feature = "runtime")]
+use crate::service::{ComputeResourceService, ServiceError, ServiceResult};
+
+/// Namespace for the aggregate IDs of imported resources
+pub const TERRAFORM_NAMESPACE: Uuid = Uuid::from_u128(0x3b8e_91d4_7a2f_4c6b_8e05_f1a9_2d7c_4e63);
+
+/// Metadata key holding the Terraform address of an imported resource
+pub const TERRAFORM_ADDRESS_KEY: &str = "terraform_address";
+
+/// Terraform state errors
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TerraformError {
+    /// File is not a Terraform state
+    #[error("Invalid Terraform state: {0}")]
+    Json(String),
+
+    /// State format other than version 4
+    #[error("Unsupported Terraform state version {0} (expected 4)")]
+    UnsupportedVersion(u64),
+}
+
+/// A Terraform state file
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TerraformState {
+    /// State format version
+    pub version: u64,
+
+    /// Terraform release that wrote the state
+    #[serde(default)]
+    pub terraform_version: Option<String>,
+
+    /// Identifies the state across serials
+    #[serde(default)]
+    pub lineage: Option<String>,
+
+    /// Managed resources and data sources
+    #[serde(default)]
+    pub resources: Vec<StateResource>,
+}
+
+/// A resource block in the state
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct StateResource {
+    /// Module path (`module.network`), absent for the root module
+    #[serde(default)]
+    pub module: Option<String>,
+
+    /// `managed` or `data`
+    #[serde(default)]
+    pub mode: String,
+
+    /// Provider resource type (`aws_instance`)
+    #[serde(rename = "type")]
+    pub resource_type: String,
+
+    /// Resource name in the configuration
+    pub name: String,
+
+    /// One instance per `count` index or `for_each` key
+    #[serde(default)]
+    pub instances: Vec<StateInstance>,
+}
+
+/// One instance of a resource block
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct StateInstance {
+    /// `count` index or `for_each` key
+    #[serde(default)]
+    pub index_key: Option<Value>,
+
+    /// Provider attributes
+    #[serde(default)]
+    pub attributes: Value,
+}
+
+impl TerraformState {
+    /// Parse a state file
+    pub fn from_json(json: &str) -> Result<Self, TerraformError> {
+        let state: Self =
+            serde_json::from_str(json).map_err(|e| TerraformError::Json(e.to_string()))?;
+        if state.version != 4 {
+            return Err(TerraformError::UnsupportedVersion(state.version));
+        }
+        Ok(state)
+    }
+}
+
+impl StateResource {
+    /// Terraform address of one instance (`module.app.aws_instance.web[0]`)
+    pub fn address(&self, instance: &StateInstance) -> String {
+        let mut address = match &self.module {
+            Some(module) => format!("{}.{}.{}", module, self.resource_type, self.name),
+            None => format!("{}.{}", self.resource_type, self.name),
+        };
+        match &instance.index_key {
+            Some(Value::String(key)) => address.push_str(&format!("[\"{}\"]", key)),
+            Some(Value::Number(index)) => address.push_str(&format!("[{}]", index)),
+            _ => {}
+        }
+        address
+    }
+}
+
+/// A network to define
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PlannedNetwork {
+    /// Terraform address
+    pub address: String,
+
+    /// Command defining the network
+    pub command: DefineNetworkCommand,
+}
+
+/// A compute resource to register
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PlannedResource {
+    /// Terraform address
+    pub address: String,
+
+    /// Aggregate ID derived from the lineage and address
+    pub aggregate_id: Uuid,
+
+    /// Command registering the resource
+    pub command: RegisterResourceCommand,
+
+    /// Metadata recorded after registration
+    pub metadata: Vec<UpdateMetadataCommand>,
+}
+
+/// An interface to attach once its network is defined
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PlannedInterface {
+    /// Terraform address of the instance
+    pub resource_address: String,
+
+    /// Aggregate ID of the instance
+    pub resource_id: Uuid,
+
+    /// Terraform address of the network
+    pub network_address: String,
+
+    /// Interface name on the instance
+    pub name: String,
+
+    /// Private addresses, with the network's prefix length
+    pub addresses: Vec<IpAddressWithCidr>,
+}
+
+/// A managed resource instance, or one of its interfaces, left out of the plan
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SkippedResource {
+    /// Terraform address
+    pub address: String,
+
+    /// Why it was skipped
+    pub reason: String,
+}
+
+/// Commands that bring a Terraform state into the event store
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ImportPlan {
+    /// Correlation ID carried by every command
+    pub correlation_id: Uuid,
+
+    /// Timestamp carried by every command
+    pub timestamp: DateTime<Utc>,
+
+    /// Networks, defined first
+    pub networks: Vec<PlannedNetwork>,
+
+    /// Compute resources
+    pub resources: Vec<PlannedResource>,
+
+    /// Interfaces, attached last
+    pub interfaces: Vec<PlannedInterface>,
+
+    /// Instances left out, with the reason
+    pub skipped: Vec<SkippedResource>,
+}
+
+/// Network attributes of a supported network type
+struct NetworkAttributes<'a> {
+    cidr: Option<&'a str>,
+    name: Option<&'a str>,
+    ids: Vec<&'a str>,
+}
+
+/// Instance attributes of a supported compute type
+struct InstanceAttributes<'a> {
+    provider: &'static str,
+    name: Option<&'a str>,
+    cloud_id: Option<&'a str>,
+    instance_type: Option<&'a str>,
+    /// (interface name, network reference, private address)
+    interfaces: Vec<(String, &'a str, &'a str)>,
+}
+
+fn text<'a>(attributes: &'a Value, key: &str) -> Option<&'a str> {
+    attributes
+        .get(key)
+        .and_then(Value::as_str)
+        .filter(|value| !value.is_empty())
+}
+
+fn network_attributes<'a>(resource_type: &str, attrs: &'a Value) -> Option<NetworkAttributes<'a>> {
+    let (cidr_key, name, id_keys): (&str, Option<&str>, &[&str]) = match resource_type {
+        "aws_subnet" => (
+            "cidr_block",
+            attrs.get("tags").and_then(|tags| text(tags, "Name")),
+            &["id", "arn"],
+        ),
+        "google_compute_subnetwork" => ("ip_cidr_range", text(attrs, "name"), &["id", "self_link"]),
+        "digitalocean_vpc" => ("ip_range", text(attrs, "name"), &["id", "urn"]),
+        _ => return None,
+    };
+
+    Some(NetworkAttributes {
+        cidr: text(attrs, cidr_key),
+        name,
+        ids: id_keys.iter().filter_map(|key| text(attrs, key)).collect(),
+    })
+}
+
+fn instance_attributes<'a>(
+    resource_type: &str,
+    attrs: &'a Value,
+) -> Option<InstanceAttributes<'a>> {
+    let instance = match resource_type {
+        "aws_instance" => InstanceAttributes {
+            provider: "aws",
+            name: attrs.get("tags").and_then(|tags| text(tags, "Name")),
+            cloud_id: text(attrs, "id"),
+            instance_type: text(attrs, "instance_type"),
+            interfaces: text(attrs, "subnet_id")
+                .zip(text(attrs, "private_ip"))
+                .map(|(subnet, ip)| ("eth0".to_string(), subnet, ip))
+                .into_iter()
+                .collect(),
+        },
+        "google_compute_instance" => InstanceAttributes {
+            provider: "gcp",
+            name: text(attrs, "name"),
+            cloud_id: text(attrs, "instance_id").or_else(|| text(attrs, "id")),
+            instance_type: text(attrs, "machine_type"),
+            interfaces: attrs
+                .get("network_interface")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+                .enumerate()
+                .filter_map(|(index, nic)| {
+                    let name = text(nic, "name")
+                        .map(str::to_string)
+                        .unwrap_or_else(|| format!("nic{}", index));
+                    Some((name, text(nic, "subnetwork")?, text(nic, "network_ip")?))
+                })
+                .collect(),
+        },
+        "digitalocean_droplet" => InstanceAttributes {
+            provider: "digitalocean",
+            name: text(attrs, "name"),
+            cloud_id: text(attrs, "id"),
+            instance_type: text(attrs, "size"),
+            interfaces: text(attrs, "vpc_uuid")
+                .zip(text(attrs, "ipv4_address_private"))
+                .map(|(vpc, ip)| ("eth1".to_string(), vpc, ip))
+                .into_iter()
+                .collect(),
+        },
+        _ => return None,
+    };
+    Some(instance)
+}
+
+/// Lowercase `name` and replace characters a hostname cannot hold
+fn hostname_from(name: &str) -> Option<Hostname> {
+    let cleaned: String = name
+        .to_ascii_lowercase()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '.' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    Hostname::new(cleaned.trim_matches(|c| c == '-' || c == '.')).ok()
+}
+
+impl ImportPlan {
+    /// Plan the commands importing `state`
+    pub fn from_state(
+        state: &TerraformState,
+        correlation_id: Uuid,
+        timestamp: DateTime<Utc>,
+    ) -> Self {
+        let mut plan = Self {
+            correlation_id,
+            timestamp,
+            networks: Vec::new(),
+            resources: Vec::new(),
+            interfaces: Vec::new(),
+            skipped: Vec::new(),
+        };
+        let lineage = state.lineage.as_deref().unwrap_or_default();
+        let managed = || {
+            state
+                .resources
+                .iter()
+                .filter(|resource| resource.mode != "data")
+                .flat_map(|resource| {
+                    resource
+                        .instances
+                        .iter()
+                        .map(move |instance| (resource, instance))
+                })
+        };
+
+        // Networks first, so instances can resolve their subnets
+        let mut networks_by_id: HashMap<&str, (String, IpAddressWithCidr)> = HashMap::new();
+        for (resource, instance) in managed() {
+            let Some(attrs) = network_attributes(&resource.resource_type, &instance.attributes)
+            else {
+                continue;
+            };
+            let address = resource.address(instance);
+            let Some(cidr) = attrs
+                .cidr
+                .and_then(|cidr| IpAddressWithCidr::new(cidr).ok())
+            else {
+                plan.skip(address, "no valid CIDR");
+                continue;
+            };
+
+            for id in attrs.ids {
+                networks_by_id.insert(id, (address.clone(), cidr.clone()));
+            }
+            plan.networks.push(PlannedNetwork {
+                command: DefineNetworkCommand {
+                    name: attrs.name.unwrap_or(&address).to_string(),
+                    cidr,
+                    vlan_id: None,
+                    segment: None,
+                    timestamp,
+                    correlation_id,
+                    causation_id: None,
+                },
+                address,
+            });
+        }
+
+        for (resource, instance) in managed() {
+            if network_attributes(&resource.resource_type, &instance.attributes).is_some() {
+                continue;
+            }
+            let address = resource.address(instance);
+            let Some(attrs) = instance_attributes(&resource.resource_type, &instance.attributes)
+            else {
+                plan.skip(
+                    address,
+                    format!("unsupported type {}", resource.resource_type),
+                );
+                continue;
+            };
+            let Some(hostname) = hostname_from(attrs.name.unwrap_or(&resource.name)) else {
+                plan.skip(address, "no usable hostname");
+                continue;
+            };
+
+            let aggregate_id = Uuid::new_v5(
+                &TERRAFORM_NAMESPACE,
+                format!("{}:{}", lineage, address).as_bytes(),
+            );
+            for (name, network_ref, ip) in attrs.interfaces {
+                let Some((network_address, cidr)) = networks_by_id.get(network_ref) else {
+                    plan.skip(
+                        address.clone(),
+                        format!("interface {}: network {} not in state", name, network_ref),
+                    );
+                    continue;
+                };
+                let Some(ip) = ip
+                    .parse::<IpAddr>()
+                    .ok()
+                    .and_then(|ip| IpAddressWithCidr::from_parts(ip, cidr.prefix_length()).ok())
+                else {
+                    plan.skip(
+                        address.clone(),
+                        format!("interface {}: invalid address {}", name, ip),
+                    );
+                    continue;
+                };
+
+                plan.interfaces.push(PlannedInterface {
+                    resource_address: address.clone(),
+                    resource_id: aggregate_id,
+                    network_address: network_address.clone(),
+                    name,
+                    addresses: vec![ip],
+                });
+            }
+
+            let metadata = [
+                (TERRAFORM_ADDRESS_KEY, Some(address.as_str())),
+                ("cloud_provider", Some(attrs.provider)),
+                ("cloud_id", attrs.cloud_id),
+                ("instance_type", attrs.instance_type),
+            ]
+            .into_iter()
+            .filter_map(|(key, value)| {
+                Some(UpdateMetadataCommand {
+                    key: key.to_string(),
+                    value: value?.to_string(),
+                    timestamp,
+                    correlation_id,
+                    causation_id: None,
+                    change_ref: None,
+                })
+            })
+            .collect();
+
+            plan.resources.push(PlannedResource {
+                address,
+                aggregate_id,
+                command: RegisterResourceCommand {
+                    hostname,
+                    resource_type: ResourceType::VirtualMachine,
+                    timestamp,
+                    correlation_id,
+                    change_ref: None,
+                },
+                metadata,
+            });
+        }
+
+        plan
+    }
+
+    fn skip(&mut self, address: String, reason: impl Into<String>) {
+        self.skipped.push(SkippedResource {
+            address,
+            reason: reason.into(),
+        });
+    }
+
+    /// Command attaching `interface` to the network defined as `network_id`
+    pub fn attach_command(
+        &self,
+        interface: &PlannedInterface,
+        network_id: Uuid,
+    ) -> AttachInterfaceCommand {
+        AttachInterfaceCommand {
+            resource_id: interface.resource_id,
+            network_id,
+            name: interface.name.clone(),
+            mac_address: None,
+            addresses: interface.addresses.clone(),
+            speed_mbps: None,
+            timestamp: self.timestamp,
+            correlation_id: self.correlation_id,
+            causation_id: None,
+        }
+    }
+}
+
+/// Outcome of applying an import plan
+#[cfg(feature = "runtime")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImportReport {
+    /// Defined networks by Terraform address
+    pub networks: HashMap<String, Uuid>,
+
+    /// Resources registered by this run
+    pub registered: usize,
+
+    /// Resources registered by an earlier import of the same state
+    pub already_registered: usize,
+
+    /// Interfaces attached
+    pub interfaces: usize,
+}
+
+#[cfg(feature = "runtime")]
+impl ImportPlan {
+    /// Execute the plan, stopping at the first rejected command
+    ///
+    /// Resources found already registered keep their metadata and
+    /// interfaces; only newly registered ones get them.
+    pub async fn apply(
+        &self,
+        compute: &dyn ComputeResourceService,
+        network: &dyn NetworkService,
+    ) -> ServiceResult<ImportReport> {
+        let mut report = ImportReport::default();
+
+        for planned in &self.networks {
+            let network_id = network.define_network(planned.command.clone()).await?;
+            report.networks.insert(planned.address.clone(), network_id);
+        }
+
+        let mut registered = std::collections::HashSet::new();
+        for planned in &self.resources {
+            match compute
+                .register_resource_as(planned.aggregate_id, planned.command.clone())
+                .await
+            {
+                Ok(()) => {}
+                Err(ServiceError::CommandError(CommandError::AlreadyInitialized)) => {
+                    report.already_registered += 1;
+                    continue;
+                }
+                Err(e) => return Err(e),
+            }
+
+            for command in &planned.metadata {
+                compute
+                    .update_metadata(planned.aggregate_id, command.clone())
+                    .await?;
+            }
+            registered.insert(planned.aggregate_id);
+            report.registered += 1;
+        }
+
+        for interface in &self.interfaces {
+            if !registered.contains(&interface.resource_id) {
+                continue;
+            }
+            if let Some(network_id) = report.networks.get(&interface.network_address) {
+                network
+                    .attach_interface(None, self.attach_command(interface, *network_id))
+                    .await?;
+                report.interfaces += 1;
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_timestamp() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-19T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    const STATE: &str = r#"{
+      "version": 4,
+      "terraform_version": "1.9.5",
+      "lineage": "5d7f1c2a-0b3e-4f6d-9a8c-1e2f3a4b5c6d",
+      "resources": [
+        {"mode": "data", "type": "aws_ami", "name": "ubuntu",
+         "instances": [{"attributes": {"id": "ami-123"}}]},
+        {"mode": "managed", "type": "aws_subnet", "name": "private",
+         "instances": [{"attributes": {"id": "subnet-0a1", "cidr_block": "10.20.1.0/24",
+                                       "tags": {"Name": "private-a"}}}]},
+        {"mode": "managed", "type": "aws_instance", "name": "web",
+         "instances": [
+           {"index_key": 0, "attributes": {"id": "i-0001", "instance_type": "t3.small",
+             "subnet_id": "subnet-0a1", "private_ip": "10.20.1.10",
+             "tags": {"Name": "Web_01"}}},
+           {"index_key": 1, "attributes": {"id": "i-0002", "subnet_id": "subnet-zzz",
+             "private_ip": "10.30.0.5", "tags": {"Name": "web-02"}}}
+         ]},
+        {"mode": "managed", "type": "aws_security_group", "name": "web",
+         "instances": [{"attributes": {"id": "sg-1"}}]}
+      ]
+    }"#;
+
+    #[test]
+    fn test_plan_from_state() {
+        // Arrange
+        let state = TerraformState::from_json(STATE).unwrap();
+        let correlation_id = Uuid::now_v7();
+
+        // Act
+        let plan = ImportPlan::from_state(&state, correlation_id, test_timestamp());
+
+        // Assert
+        assert_eq!(plan.networks.len(), 1);
+        assert_eq!(plan.networks[0].command.name, "private-a");
+        assert_eq!(plan.resources.len(), 2);
+        let web = &plan.resources[0];
+        assert_eq!(web.address, "aws_instance.web[0]");
+        assert_eq!(web.command.hostname.as_str(), "web-01");
+        assert_eq!(web.command.correlation_id, correlation_id);
+        assert!(web
+            .metadata
+            .iter()
+            .any(|m| m.key == "instance_type" && m.value == "t3.small"));
+
+        assert_eq!(plan.interfaces.len(), 1);
+        assert_eq!(plan.interfaces[0].resource_id, web.aggregate_id);
+        assert_eq!(plan.interfaces[0].network_address, "aws_subnet.private");
+        assert_eq!(plan.interfaces[0].addresses[0].as_cidr(), "10.20.1.10/24");
+
+        let skipped: Vec<&str> = plan.skipped.iter().map(|s| s.address.as_str()).collect();
+        assert_eq!(
+            skipped,
+            vec!["aws_instance.web[1]", "aws_security_group.web"]
+        );
+    }
+
+    #[test]
+    fn test_resource_ids_are_stable_across_imports() {
+        let state = TerraformState::from_json(STATE).unwrap();
+
+        let first = ImportPlan::from_state(&state, Uuid::now_v7(), test_timestamp());
+        let second = ImportPlan::from_state(&state, Uuid::now_v7(), test_timestamp());
+
+        assert_eq!(
+            first.resources[0].aggregate_id,
+            second.resources[0].aggregate_id
+        );
+        assert_ne!(
+            first.resources[0].aggregate_id,
+            first.resources[1].aggregate_id
+        );
+        assert_eq!(
+            TerraformState::from_json(r#"{"version": 3}"#),
+            Err(TerraformError::UnsupportedVersion(3))
+        );
+    }
+}
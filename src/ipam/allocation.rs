@@ -0,0 +1,499 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Address Allocation
+//!
+//! Each network carries reserved ranges (gateway, DHCP pool, anycast) and an
+//! [`AllocationPolicy`] describing how the site hands out addresses. The
+//! [`IpAllocator`] enforces both: it never returns a reserved, already
+//! assigned, network or broadcast address, and picks among the free ones
+//! according to the policy.
+//!
+//! # Policies
+//!
+//! | Policy | Picks |
+//! |--------|-------|
+//! | `Sequential` | Lowest free address |
+//! | `Random` | Uniformly random free address (seedable for reproducibility) |
+//! | `StickyByMac` | The address the MAC held before, else one derived from the MAC |
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::ipam::{AllocationPolicy, AllocationRequest, IpAllocator, ReservedRange};
+//!
+//! let network = IpamNetwork::new(id, "dc1-servers", "10.0.0.0/24".parse()?)
+//!     .with_reserved(ReservedRange::single(gateway, ReservationPurpose::Gateway))
+//!     .with_allocation_policy(AllocationPolicy::Sequential);
+//!
+//! let mut allocator = IpAllocator::new();
+//! let assignment = allocator.allocate(&network, &registry, &AllocationRequest::new(iface, host))?;
+//! registry.assign(assignment);
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::net::IpAddr;
+use thiserror::Error;
+use uuid::Uuid;
+
+use super::ranges::{covered, gaps, interval_len, merge, reserved_intervals, AddressSpace, Interval};
+use super::{IpAssignment, IpAssignmentRegistry, IpamNetwork};
+use crate::domain::network::{bits_to_ip, ip_to_bits};
+use crate::domain::{IpAddressWithCidr, MacAddress};
+
+/// Why a range of addresses is held back from allocation
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ReservationPurpose {
+    /// Default gateway / first-hop router addresses (incl. VRRP/HSRP VIPs)
+    Gateway,
+
+    /// Range handed out by a DHCP server
+    DhcpPool,
+
+    /// Anycast service addresses
+    Anycast,
+
+    /// Site-specific reservation
+    Other(String),
+}
+
+/// Contiguous range of addresses excluded from allocation
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ReservedRange {
+    /// First reserved address (inclusive)
+    pub start: IpAddr,
+
+    /// Last reserved address (inclusive)
+    pub end: IpAddr,
+
+    /// Why the range is reserved
+    pub purpose: ReservationPurpose,
+}
+
+impl ReservedRange {
+    /// Create a range; both ends must be the same family and in order
+    pub fn new(
+        start: IpAddr,
+        end: IpAddr,
+        purpose: ReservationPurpose,
+    ) -> Result<Self, AllocationError> {
+        if start.is_ipv4() != end.is_ipv4() {
+            return Err(AllocationError::InvalidRange(format!(
+                "{} and {} are different address families",
+                start, end
+            )));
+        }
+        if ip_to_bits(start) > ip_to_bits(end) {
+            return Err(AllocationError::InvalidRange(format!(
+                "{} is after {}",
+                start, end
+            )));
+        }
+
+        Ok(Self {
+            start,
+            end,
+            purpose,
+        })
+    }
+
+    /// Reserve a single address
+    pub fn single(address: IpAddr, purpose: ReservationPurpose) -> Self {
+        Self {
+            start: address,
+            end: address,
+            purpose,
+        }
+    }
+
+    /// Whether an address falls inside the range
+    pub fn contains(&self, address: &IpAddr) -> bool {
+        address.is_ipv4() == self.start.is_ipv4()
+            && ip_to_bits(self.start) <= ip_to_bits(*address)
+            && ip_to_bits(*address) <= ip_to_bits(self.end)
+    }
+}
+
+/// How the allocator chooses among free addresses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AllocationPolicy {
+    /// Lowest free address first
+    #[default]
+    Sequential,
+
+    /// Random free address; a fixed seed makes the sequence reproducible
+    Random {
+        /// PRNG seed (None = seeded from the clock)
+        seed: Option<u64>,
+    },
+
+    /// Same MAC gets the same address; requires a MAC on every request
+    StickyByMac,
+}
+
+/// Request for an address on a network
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AllocationRequest {
+    /// Interface that will hold the address
+    pub interface_id: Uuid,
+
+    /// Resource owning the interface
+    pub resource_id: Uuid,
+
+    /// Interface MAC address (required by [`AllocationPolicy::StickyByMac`])
+    pub mac: Option<MacAddress>,
+}
+
+impl AllocationRequest {
+    /// Create a request without a MAC address
+    pub fn new(interface_id: Uuid, resource_id: Uuid) -> Self {
+        Self {
+            interface_id,
+            resource_id,
+            mac: None,
+        }
+    }
+
+    /// Attach the interface MAC address
+    pub fn with_mac(mut self, mac: MacAddress) -> Self {
+        self.mac = Some(mac);
+        self
+    }
+}
+
+/// Errors raised while allocating addresses
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum AllocationError {
+    /// No free address remains in the network
+    #[error("Network {0} has no free addresses")]
+    Exhausted(String),
+
+    /// The network's policy needs a MAC address and the request had none
+    #[error("Network {0} allocates sticky-by-MAC but the request has no MAC address")]
+    MacRequired(String),
+
+    /// A reserved range is malformed
+    #[error("Invalid reserved range: {0}")]
+    InvalidRange(String),
+
+    /// The address is reserved on this network
+    #[error("{address} is reserved for {purpose:?}")]
+    Reserved {
+        /// Requested address
+        address: IpAddr,
+        /// Reservation it falls into
+        purpose: ReservationPurpose,
+    },
+}
+
+/// Reject manual assignments that land in a reserved range
+pub fn check_not_reserved(network: &IpamNetwork, address: &IpAddr) -> Result<(), AllocationError> {
+    match network.reserved.iter().find(|r| r.contains(address)) {
+        Some(range) => Err(AllocationError::Reserved {
+            address: *address,
+            purpose: range.purpose.clone(),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Hands out free addresses according to each network's policy
+///
+/// The allocator only reads the registry; callers record the returned
+/// assignment (e.g. via an interface event) so that the next allocation
+/// sees it.
+#[derive(Debug, Default)]
+pub struct IpAllocator {
+    /// PRNG state per network for the random policy
+    rng: HashMap<Uuid, u64>,
+
+    /// Last address handed to each MAC, per network
+    sticky: HashMap<(Uuid, MacAddress), IpAddr>,
+}
+
+impl IpAllocator {
+    /// Create an allocator with no history
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remember that a MAC holds an address (e.g. when loading history)
+    pub fn remember(&mut self, network_id: Uuid, mac: MacAddress, address: IpAddr) {
+        self.sticky.insert((network_id, mac), address);
+    }
+
+    /// Allocate a free address on a network
+    pub fn allocate(
+        &mut self,
+        network: &IpamNetwork,
+        registry: &IpAssignmentRegistry,
+        request: &AllocationRequest,
+    ) -> Result<IpAssignment, AllocationError> {
+        let free = free_intervals(network, registry);
+        let free_count: u128 = free.iter().copied().map(interval_len).sum();
+        if free_count == 0 {
+            return Err(AllocationError::Exhausted(network.prefix.as_cidr()));
+        }
+
+        let bits = match network.allocation_policy {
+            AllocationPolicy::Sequential => free[0].0,
+            AllocationPolicy::Random { seed } => {
+                let state = self
+                    .rng
+                    .entry(network.network_id)
+                    .or_insert_with(|| seed.unwrap_or_else(clock_seed));
+                nth_free(&free, wide_random(state) % free_count)
+            }
+            AllocationPolicy::StickyByMac => {
+                let mac = request
+                    .mac
+                    .clone()
+                    .ok_or_else(|| AllocationError::MacRequired(network.prefix.as_cidr()))?;
+
+                let remembered = self
+                    .sticky
+                    .get(&(network.network_id, mac.clone()))
+                    .map(|address| ip_to_bits(*address))
+                    .filter(|bits| covered(&free, *bits));
+
+                let bits = remembered.unwrap_or_else(|| {
+                    nth_free(&free, mac_hash(network.network_id, &mac) % free_count)
+                });
+                self.sticky.insert(
+                    (network.network_id, mac),
+                    bits_to_ip(bits, network.prefix.is_ipv4()),
+                );
+                bits
+            }
+        };
+
+        let address = IpAddressWithCidr::from_parts(
+            bits_to_ip(bits, network.prefix.is_ipv4()),
+            network.prefix.prefix_length(),
+        )
+        .expect("address inside a valid prefix");
+
+        let assignment = IpAssignment::new(address, request.interface_id, request.resource_id);
+        Ok(match &network.vrf {
+            Some(vrf) => assignment.in_vrf(vrf.clone()),
+            None => assignment,
+        })
+    }
+}
+
+/// Addresses of a network that are neither reserved nor assigned
+fn free_intervals(network: &IpamNetwork, registry: &IpAssignmentRegistry) -> Vec<Interval> {
+    free_intervals_excluding(
+        network,
+        registry
+            .assignments()
+            .filter(|a| network.contains(a))
+            .map(IpAssignment::ip),
+    )
+}
+
+/// Addresses of a network that are neither reserved nor in `taken`
+fn free_intervals_excluding(
+    network: &IpamNetwork,
+    taken: impl IntoIterator<Item = IpAddr>,
+) -> Vec<Interval> {
+    let space = AddressSpace::of(&network.prefix);
+
+    let occupied = merge(
+        reserved_intervals(network)
+            .into_iter()
+            .chain(taken.into_iter().map(|address| {
+                let bits = ip_to_bits(address);
+                (bits, bits)
+            }))
+            .collect(),
+    );
+
+    gaps(space.first, space.last, &occupied)
+}
+
+/// Whether an address may be handed out on a network at all
+///
+/// False outside the prefix, for the IPv4 network and broadcast addresses
+/// and inside reserved ranges. Existing assignments are not considered.
+pub fn is_assignable(network: &IpamNetwork, address: &IpAddr) -> bool {
+    network.prefix.contains(address)
+        && !covered(&reserved_intervals(network), ip_to_bits(*address))
+}
+
+/// Lowest assignable address not in `taken`
+///
+/// The sequential policy for callers that track assignments themselves
+/// rather than in an [`IpAssignmentRegistry`].
+pub fn first_free(
+    network: &IpamNetwork,
+    taken: impl IntoIterator<Item = IpAddr>,
+) -> Option<IpAddr> {
+    free_intervals_excluding(network, taken)
+        .first()
+        .map(|&(bits, _)| bits_to_ip(bits, network.prefix.is_ipv4()))
+}
+
+/// Number of assignable addresses not in `taken`
+pub fn free_count(network: &IpamNetwork, taken: impl IntoIterator<Item = IpAddr>) -> u128 {
+    free_intervals_excluding(network, taken)
+        .into_iter()
+        .map(interval_len)
+        .sum()
+}
+
+/// The n-th free address (0-based) across the free intervals
+fn nth_free(free: &[Interval], mut n: u128) -> u128 {
+    for &interval in free {
+        let len = interval_len(interval);
+        if n < len {
+            return interval.0 + n;
+        }
+        n -= len;
+    }
+    free.last().map(|&(_, end)| end).unwrap_or_default()
+}
+
+/// SplitMix64 step
+fn next_u64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// 128 random bits, enough to index any IPv6 prefix
+fn wide_random(state: &mut u64) -> u128 {
+    ((next_u64(state) as u128) << 64) | next_u64(state) as u128
+}
+
+fn clock_seed() -> u64 {
+    Uuid::now_v7().as_u128() as u64
+}
+
+/// Stable FNV-1a hash of a MAC within a network
+///
+/// Deliberately independent of `std`'s hasher so the same MAC maps to the
+/// same starting address across processes and releases.
+fn mac_hash(network_id: Uuid, mac: &MacAddress) -> u128 {
+    const OFFSET: u128 = 0x6c62272e07bb014262b821756295c58d;
+    const PRIME: u128 = 0x0000000001000000000000000000013B;
+
+    network_id
+        .as_bytes()
+        .iter()
+        .chain(mac.octets().iter())
+        .fold(OFFSET, |hash, byte| (hash ^ *byte as u128).wrapping_mul(PRIME))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn network(policy: AllocationPolicy) -> IpamNetwork {
+        IpamNetwork::new(
+            Uuid::now_v7(),
+            "test",
+            IpAddressWithCidr::new("10.0.0.0/24").unwrap(),
+        )
+        .with_reserved(ReservedRange::single(
+            "10.0.0.1".parse().unwrap(),
+            ReservationPurpose::Gateway,
+        ))
+        .with_allocation_policy(policy)
+    }
+
+    fn request() -> AllocationRequest {
+        AllocationRequest::new(Uuid::now_v7(), Uuid::now_v7())
+    }
+
+    #[test]
+    fn test_sequential_skips_reserved_and_assigned() {
+        // Arrange
+        let net = network(AllocationPolicy::Sequential);
+        let mut registry = IpAssignmentRegistry::new();
+        let mut allocator = IpAllocator::new();
+
+        // Act
+        let first = allocator.allocate(&net, &registry, &request()).unwrap();
+        registry.assign(first.clone());
+        let second = allocator.allocate(&net, &registry, &request()).unwrap();
+
+        // Assert - .0 is the network address, .1 the gateway
+        assert_eq!(first.ip().to_string(), "10.0.0.2");
+        assert_eq!(second.ip().to_string(), "10.0.0.3");
+        assert_eq!(first.address.prefix_length(), Some(24));
+    }
+
+    #[test]
+    fn test_first_free_skips_taken_addresses() {
+        let net = network(AllocationPolicy::Sequential);
+        let taken: Vec<IpAddr> = vec!["10.0.0.2".parse().unwrap(), "10.0.0.3".parse().unwrap()];
+
+        assert_eq!(
+            first_free(&net, taken.iter().copied()),
+            Some("10.0.0.4".parse().unwrap())
+        );
+        assert_eq!(free_count(&net, taken), 251);
+        assert!(!is_assignable(&net, &"10.0.0.1".parse().unwrap()));
+        assert!(!is_assignable(&net, &"10.0.0.255".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_random_with_seed_is_reproducible() {
+        let net = network(AllocationPolicy::Random { seed: Some(7) });
+        let registry = IpAssignmentRegistry::new();
+
+        let a = IpAllocator::new().allocate(&net, &registry, &request()).unwrap();
+        let b = IpAllocator::new().allocate(&net, &registry, &request()).unwrap();
+
+        assert_eq!(a.ip(), b.ip());
+        assert!(check_not_reserved(&net, &a.ip()).is_ok());
+    }
+
+    #[test]
+    fn test_sticky_by_mac_returns_same_address() {
+        let net = network(AllocationPolicy::StickyByMac);
+        let mac = MacAddress::new("00:11:22:33:44:55").unwrap();
+        let mut registry = IpAssignmentRegistry::new();
+        let mut allocator = IpAllocator::new();
+
+        let first = allocator
+            .allocate(&net, &registry, &request().with_mac(mac.clone()))
+            .unwrap();
+        let again = allocator
+            .allocate(&net, &registry, &request().with_mac(mac))
+            .unwrap();
+        assert_eq!(first.ip(), again.ip());
+
+        registry.assign(first);
+        assert_eq!(
+            allocator.allocate(&net, &registry, &request()),
+            Err(AllocationError::MacRequired("10.0.0.0/24".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_exhausted_network() {
+        let net = IpamNetwork::new(
+            Uuid::now_v7(),
+            "p2p",
+            IpAddressWithCidr::new("10.0.0.0/32").unwrap(),
+        );
+        let registry = IpAssignmentRegistry::from_assignments([IpAssignment::new(
+            IpAddressWithCidr::new("10.0.0.0/32").unwrap(),
+            Uuid::now_v7(),
+            Uuid::now_v7(),
+        )]);
+
+        let result = IpAllocator::new().allocate(&net, &registry, &request());
+
+        assert!(matches!(result, Err(AllocationError::Exhausted(_))));
+    }
+}
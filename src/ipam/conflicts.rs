@@ -0,0 +1,284 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! IP Conflict Detection
+//!
+//! An address conflicts when two or more distinct interfaces hold it within
+//! the same routing scope. Detection is pure ([`detect_conflicts`],
+//! [`validate_assignment`]); [`IpConflictMonitor`] runs it periodically
+//! against an [`IpAssignmentSource`] and publishes an
+//! [`IpConflictDetected`] advisory for each newly found conflict.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::ipam::{validate_assignment, IpConflictMonitor};
+//!
+//! // Reject a new assignment up front
+//! validate_assignment(registry.assignments(), &candidate)?;
+//!
+//! // Or run the background check
+//! let mut monitor = IpConflictMonitor::new(nats_client, source);
+//! monitor.run(Duration::from_secs(300)).await?;
+//! ```
+
+#[cfg(feature = "runtime")]
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::net::IpAddr;
+#[cfg(feature = "runtime")]
+use std::time::Duration;
+#[cfg(feature = "runtime")]
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use super::IpAssignment;
+#[cfg(feature = "runtime")]
+use crate::errors::InfrastructureResult;
+#[cfg(feature = "clock")]
+use crate::events::advisory::{AdvisoryEvent, IpConflictDetected};
+#[cfg(feature = "runtime")]
+use crate::nats::NatsClient;
+
+/// An address held by more than one interface within a scope
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IpConflict {
+    /// Conflicting address
+    pub address: IpAddr,
+
+    /// Routing scope (VRF); None for the global routing table
+    pub vrf: Option<String>,
+
+    /// Every assignment of the address in this scope
+    pub assignments: Vec<IpAssignment>,
+}
+
+impl IpConflict {
+    /// Interfaces involved, sorted and deduplicated
+    pub fn interface_ids(&self) -> Vec<Uuid> {
+        self.assignments
+            .iter()
+            .map(|a| a.interface_id)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// Resources involved, sorted and deduplicated
+    pub fn resource_ids(&self) -> Vec<Uuid> {
+        self.assignments
+            .iter()
+            .map(|a| a.resource_id)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// Stable key identifying this conflict across check runs
+    #[cfg(feature = "runtime")]
+    fn key(&self) -> (Option<String>, IpAddr, Vec<Uuid>) {
+        (self.vrf.clone(), self.address, self.interface_ids())
+    }
+
+    /// Build the advisory event for this conflict
+    #[cfg(feature = "clock")]
+    pub fn to_advisory(&self, correlation_id: Uuid) -> AdvisoryEvent {
+        AdvisoryEvent::IpConflictDetected(IpConflictDetected {
+            event_id: Uuid::now_v7(),
+            detected_at: chrono::Utc::now(),
+            correlation_id,
+            address: self.address,
+            vrf: self.vrf.clone(),
+            interface_ids: self.interface_ids(),
+            resource_ids: self.resource_ids(),
+        })
+    }
+}
+
+impl fmt::Display for IpConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} is assigned to {} interfaces in {}",
+            self.address,
+            self.interface_ids().len(),
+            self.vrf.as_deref().unwrap_or("the global table")
+        )
+    }
+}
+
+impl std::error::Error for IpConflict {}
+
+/// Find every address held by more than one interface within a scope
+///
+/// The same interface listing an address twice is not a conflict, and the
+/// same address in different VRFs is not a conflict. Results are ordered by
+/// scope, then address.
+pub fn detect_conflicts(assignments: &[IpAssignment]) -> Vec<IpConflict> {
+    let mut by_scope: BTreeMap<(Option<String>, IpAddr), Vec<IpAssignment>> = BTreeMap::new();
+    for assignment in assignments {
+        by_scope
+            .entry(assignment.scope_key())
+            .or_default()
+            .push(assignment.clone());
+    }
+
+    by_scope
+        .into_iter()
+        .filter_map(|((vrf, address), assignments)| {
+            let conflict = IpConflict {
+                address,
+                vrf,
+                assignments,
+            };
+            (conflict.interface_ids().len() > 1).then_some(conflict)
+        })
+        .collect()
+}
+
+/// Check that a new assignment does not collide with existing ones
+///
+/// Returns the conflict the candidate would create, including the
+/// candidate itself among the assignments.
+pub fn validate_assignment<'a>(
+    existing: impl IntoIterator<Item = &'a IpAssignment>,
+    candidate: &IpAssignment,
+) -> Result<(), IpConflict> {
+    let key = candidate.scope_key();
+    let mut holders: Vec<IpAssignment> = existing
+        .into_iter()
+        .filter(|a| a.scope_key() == key && a.interface_id != candidate.interface_id)
+        .cloned()
+        .collect();
+
+    if holders.is_empty() {
+        return Ok(());
+    }
+
+    holders.push(candidate.clone());
+    Err(IpConflict {
+        address: candidate.ip(),
+        vrf: candidate.vrf.clone(),
+        assignments: holders,
+    })
+}
+
+/// Supplies the current set of IP assignments to the monitor
+#[cfg(feature = "runtime")]
+#[async_trait]
+pub trait IpAssignmentSource: Send + Sync {
+    /// Load all current assignments
+    async fn assignments(&self) -> InfrastructureResult<Vec<IpAssignment>>;
+}
+
+/// Background check that publishes advisories for new IP conflicts
+///
+/// Each conflict is announced once. If it is resolved and later reappears,
+/// it is announced again.
+#[cfg(feature = "runtime")]
+pub struct IpConflictMonitor<S> {
+    client: NatsClient,
+    source: S,
+    reported: BTreeSet<(Option<String>, IpAddr, Vec<Uuid>)>,
+}
+
+#[cfg(feature = "runtime")]
+impl<S: IpAssignmentSource> IpConflictMonitor<S> {
+    /// Create a monitor over an assignment source
+    pub fn new(client: NatsClient, source: S) -> Self {
+        Self {
+            client,
+            source,
+            reported: BTreeSet::new(),
+        }
+    }
+
+    /// Run one check, publishing advisories for newly detected conflicts
+    ///
+    /// Returns all conflicts currently present, new or not.
+    pub async fn check_once(&mut self) -> InfrastructureResult<Vec<IpConflict>> {
+        let assignments = self.source.assignments().await?;
+        let conflicts = detect_conflicts(&assignments);
+        let correlation_id = Uuid::now_v7();
+
+        let current: BTreeSet<_> = conflicts.iter().map(IpConflict::key).collect();
+        self.reported.retain(|key| current.contains(key));
+
+        for conflict in &conflicts {
+            if self.reported.contains(&conflict.key()) {
+                continue;
+            }
+
+            warn!("IP conflict detected: {}", conflict);
+            let advisory = conflict.to_advisory(correlation_id);
+            self.client.publish(&advisory.subject(), &advisory).await?;
+            self.reported.insert(conflict.key());
+        }
+
+        Ok(conflicts)
+    }
+
+    /// Run checks on a fixed interval until an error occurs
+    pub async fn run(&mut self, interval: Duration) -> InfrastructureResult<()> {
+        info!("Starting IP conflict monitor (interval: {:?})", interval);
+        let mut ticker = tokio::time::interval(interval);
+
+        loop {
+            ticker.tick().await;
+            self.check_once().await?;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::IpAddressWithCidr;
+
+    fn assignment(cidr: &str) -> IpAssignment {
+        IpAssignment::new(
+            IpAddressWithCidr::new(cidr).unwrap(),
+            Uuid::now_v7(),
+            Uuid::now_v7(),
+        )
+    }
+
+    #[test]
+    fn test_detects_duplicate_address_in_same_scope() {
+        // Arrange
+        let assignments = vec![
+            assignment("10.0.0.5/24"),
+            assignment("10.0.0.5/24"),
+            assignment("10.0.0.6/24"),
+        ];
+
+        // Act
+        let conflicts = detect_conflicts(&assignments);
+
+        // Assert
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].address, "10.0.0.5".parse::<IpAddr>().unwrap());
+        assert_eq!(conflicts[0].interface_ids().len(), 2);
+    }
+
+    #[test]
+    fn test_different_vrfs_do_not_conflict() {
+        let assignments = vec![
+            assignment("10.0.0.5/24").in_vrf("blue"),
+            assignment("10.0.0.5/24").in_vrf("red"),
+        ];
+
+        assert!(detect_conflicts(&assignments).is_empty());
+    }
+
+    #[test]
+    fn test_validate_assignment_rejects_duplicate() {
+        let existing = vec![assignment("192.168.1.10/24")];
+        let candidate = assignment("192.168.1.10/24");
+
+        let conflict = validate_assignment(&existing, &candidate).unwrap_err();
+
+        assert_eq!(conflict.assignments.len(), 2);
+        assert!(validate_assignment(&existing, &assignment("192.168.1.11/24")).is_ok());
+    }
+}
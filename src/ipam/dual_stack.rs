@@ -0,0 +1,394 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Dual-Stack Consistency
+//!
+//! A [`DualStackPolicy`] pairs an IPv4 network with an IPv6 network and
+//! mandates that every interface addressed in one is also addressed in the
+//! other, optionally with an IPv6 interface identifier derived by a site
+//! convention.
+//!
+//! # Interface Identifier Conventions
+//!
+//! ```text
+//! Eui64          MAC 00:11:22:33:44:55  →  2001:db8:1::211:22ff:fe33:4455
+//! Ipv4Embedded   10.0.0.25              →  2001:db8:1::a00:19
+//! Ipv4HostPart   10.0.0.25/24           →  2001:db8:1::19
+//! ```
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::ipam::dual_stack::{check_dual_stack, ipv4_only_advisories};
+//!
+//! let violations = check_dual_stack(&policy, &v4_net, &v6_net, &assignments, &macs);
+//! for advisory in ipv4_only_advisories(&policy, &violations, correlation_id) {
+//!     nats_client.publish(&advisory.subject(), &advisory).await?;
+//! }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::net::IpAddr;
+use uuid::Uuid;
+
+use super::ranges::AddressSpace;
+use super::{IpAssignment, IpamNetwork};
+use crate::domain::network::{bits_to_ip, ip_to_bits};
+use crate::domain::MacAddress;
+#[cfg(feature = "clock")]
+use crate::events::advisory::{AdvisoryEvent, DualStackIncomplete};
+
+/// How the IPv6 interface identifier relates to other interface data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InterfaceIdDerivation {
+    /// Any IPv6 address in the network is acceptable
+    #[default]
+    Any,
+
+    /// Modified EUI-64 from the interface MAC (RFC 4291 appendix A)
+    Eui64,
+
+    /// Low 32 bits carry the full IPv4 address
+    Ipv4Embedded,
+
+    /// Interface identifier equals the IPv4 host offset within its network
+    Ipv4HostPart,
+}
+
+/// Requirement that two networks are addressed together
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DualStackPolicy {
+    /// IPv4 side of the pair
+    pub ipv4_network_id: Uuid,
+
+    /// IPv6 side of the pair
+    pub ipv6_network_id: Uuid,
+
+    /// Interface identifier convention for the IPv6 address
+    #[serde(default)]
+    pub derivation: InterfaceIdDerivation,
+}
+
+/// A dual-stack rule broken by one interface
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DualStackViolation {
+    /// Interface has an IPv4 address but no IPv6 address
+    MissingIpv6 {
+        /// Interface lacking IPv6
+        interface_id: Uuid,
+        /// Resource owning it
+        resource_id: Uuid,
+        /// The IPv4 address it has
+        ipv4: IpAddr,
+    },
+
+    /// Interface has an IPv6 address but no IPv4 address
+    MissingIpv4 {
+        /// Interface lacking IPv4
+        interface_id: Uuid,
+        /// Resource owning it
+        resource_id: Uuid,
+        /// The IPv6 address it has
+        ipv6: IpAddr,
+    },
+
+    /// IPv6 address does not follow the interface identifier convention
+    InterfaceIdMismatch {
+        /// Interface with the wrong address
+        interface_id: Uuid,
+        /// Resource owning it
+        resource_id: Uuid,
+        /// Address the convention produces
+        expected: IpAddr,
+        /// Addresses actually assigned
+        actual: Vec<IpAddr>,
+    },
+
+    /// EUI-64 convention applies but the interface MAC is unknown
+    MissingMac {
+        /// Interface without a known MAC
+        interface_id: Uuid,
+        /// Resource owning it
+        resource_id: Uuid,
+    },
+}
+
+impl DualStackViolation {
+    /// Interface the violation concerns
+    pub fn interface_id(&self) -> Uuid {
+        match self {
+            DualStackViolation::MissingIpv6 { interface_id, .. }
+            | DualStackViolation::MissingIpv4 { interface_id, .. }
+            | DualStackViolation::InterfaceIdMismatch { interface_id, .. }
+            | DualStackViolation::MissingMac { interface_id, .. } => *interface_id,
+        }
+    }
+
+    /// Resource the violation concerns
+    pub fn resource_id(&self) -> Uuid {
+        match self {
+            DualStackViolation::MissingIpv6 { resource_id, .. }
+            | DualStackViolation::MissingIpv4 { resource_id, .. }
+            | DualStackViolation::InterfaceIdMismatch { resource_id, .. }
+            | DualStackViolation::MissingMac { resource_id, .. } => *resource_id,
+        }
+    }
+}
+
+/// Modified EUI-64 interface identifier for a MAC address
+pub fn eui64_interface_id(mac: &MacAddress) -> u64 {
+    let [a, b, c, d, e, f] = mac.octets();
+    u64::from_be_bytes([a ^ 0x02, b, c, 0xff, 0xfe, d, e, f])
+}
+
+/// IPv6 address the policy expects for an interface
+///
+/// Returns None when any IPv6 address is acceptable, or when the
+/// convention cannot be applied (no MAC for EUI-64, IPv6 prefix longer
+/// than /64, or an address outside the IPv4 network).
+pub fn expected_ipv6(
+    derivation: InterfaceIdDerivation,
+    ipv4: IpAddr,
+    ipv4_network: &IpamNetwork,
+    ipv6_network: &IpamNetwork,
+    mac: Option<&MacAddress>,
+) -> Option<IpAddr> {
+    let v6 = AddressSpace::of(&ipv6_network.prefix);
+    if v6.ipv4 {
+        return None;
+    }
+
+    let interface_id: u128 = match derivation {
+        InterfaceIdDerivation::Any => return None,
+        InterfaceIdDerivation::Eui64 => {
+            if v6.host_bits < 64 {
+                return None;
+            }
+            eui64_interface_id(mac?) as u128
+        }
+        InterfaceIdDerivation::Ipv4Embedded => ip_to_bits(ipv4),
+        InterfaceIdDerivation::Ipv4HostPart => {
+            if !ipv4_network.prefix.contains(&ipv4) {
+                return None;
+            }
+            ip_to_bits(ipv4) - AddressSpace::of(&ipv4_network.prefix).first
+        }
+    };
+
+    if interface_id > v6.last - v6.first {
+        return None;
+    }
+    Some(bits_to_ip(v6.first | interface_id, false))
+}
+
+/// Check every interface addressed in either network of a dual-stack pair
+///
+/// `macs` maps interface IDs to MAC addresses and is only consulted for the
+/// EUI-64 convention. Violations are ordered by interface ID.
+pub fn check_dual_stack(
+    policy: &DualStackPolicy,
+    ipv4_network: &IpamNetwork,
+    ipv6_network: &IpamNetwork,
+    assignments: &[IpAssignment],
+    macs: &HashMap<Uuid, MacAddress>,
+) -> Vec<DualStackViolation> {
+    #[derive(Default)]
+    struct Addresses {
+        resource_id: Uuid,
+        v4: BTreeSet<IpAddr>,
+        v6: BTreeSet<IpAddr>,
+    }
+
+    let mut by_interface: BTreeMap<Uuid, Addresses> = BTreeMap::new();
+    for assignment in assignments {
+        let in_v4 = ipv4_network.contains(assignment);
+        let in_v6 = ipv6_network.contains(assignment);
+        if !in_v4 && !in_v6 {
+            continue;
+        }
+
+        let entry = by_interface.entry(assignment.interface_id).or_default();
+        entry.resource_id = assignment.resource_id;
+        if in_v4 {
+            entry.v4.insert(assignment.ip());
+        }
+        if in_v6 {
+            entry.v6.insert(assignment.ip());
+        }
+    }
+
+    let mut violations = Vec::new();
+    for (interface_id, addresses) in by_interface {
+        let resource_id = addresses.resource_id;
+
+        match (addresses.v4.first(), addresses.v6.first()) {
+            (Some(&ipv4), None) => violations.push(DualStackViolation::MissingIpv6 {
+                interface_id,
+                resource_id,
+                ipv4,
+            }),
+            (None, Some(&ipv6)) => violations.push(DualStackViolation::MissingIpv4 {
+                interface_id,
+                resource_id,
+                ipv6,
+            }),
+            (Some(&ipv4), Some(_)) => {
+                if policy.derivation == InterfaceIdDerivation::Any {
+                    continue;
+                }
+
+                let mac = macs.get(&interface_id);
+                if policy.derivation == InterfaceIdDerivation::Eui64 && mac.is_none() {
+                    violations.push(DualStackViolation::MissingMac {
+                        interface_id,
+                        resource_id,
+                    });
+                    continue;
+                }
+
+                let Some(expected) =
+                    expected_ipv6(policy.derivation, ipv4, ipv4_network, ipv6_network, mac)
+                else {
+                    continue;
+                };
+                if !addresses.v6.contains(&expected) {
+                    violations.push(DualStackViolation::InterfaceIdMismatch {
+                        interface_id,
+                        resource_id,
+                        expected,
+                        actual: addresses.v6.iter().copied().collect(),
+                    });
+                }
+            }
+            (None, None) => {}
+        }
+    }
+
+    violations
+}
+
+/// One [`DualStackIncomplete`] advisory per resource with IPv4-only interfaces
+#[cfg(feature = "clock")]
+pub fn ipv4_only_advisories(
+    policy: &DualStackPolicy,
+    violations: &[DualStackViolation],
+    correlation_id: Uuid,
+) -> Vec<AdvisoryEvent> {
+    let mut by_resource: BTreeMap<Uuid, Vec<Uuid>> = BTreeMap::new();
+    for violation in violations {
+        if let DualStackViolation::MissingIpv6 {
+            interface_id,
+            resource_id,
+            ..
+        } = violation
+        {
+            by_resource
+                .entry(*resource_id)
+                .or_default()
+                .push(*interface_id);
+        }
+    }
+
+    by_resource
+        .into_iter()
+        .map(|(resource_id, interface_ids)| {
+            AdvisoryEvent::DualStackIncomplete(DualStackIncomplete {
+                event_id: Uuid::now_v7(),
+                detected_at: chrono::Utc::now(),
+                correlation_id,
+                resource_id,
+                interface_ids,
+                ipv4_network_id: policy.ipv4_network_id,
+                ipv6_network_id: policy.ipv6_network_id,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::IpAddressWithCidr;
+
+    fn networks() -> (IpamNetwork, IpamNetwork) {
+        (
+            IpamNetwork::new(
+                Uuid::now_v7(),
+                "v4",
+                IpAddressWithCidr::new("10.0.0.0/24").unwrap(),
+            ),
+            IpamNetwork::new(
+                Uuid::now_v7(),
+                "v6",
+                IpAddressWithCidr::new("2001:db8:1::/64").unwrap(),
+            ),
+        )
+    }
+
+    fn policy(
+        v4: &IpamNetwork,
+        v6: &IpamNetwork,
+        derivation: InterfaceIdDerivation,
+    ) -> DualStackPolicy {
+        DualStackPolicy {
+            ipv4_network_id: v4.network_id,
+            ipv6_network_id: v6.network_id,
+            derivation,
+        }
+    }
+
+    fn assign(address: &str, interface_id: Uuid, resource_id: Uuid) -> IpAssignment {
+        IpAssignment::new(
+            IpAddressWithCidr::new(address).unwrap(),
+            interface_id,
+            resource_id,
+        )
+    }
+
+    #[test]
+    fn test_expected_ipv6_conventions() {
+        let (v4, v6) = networks();
+        let mac = MacAddress::new("00:11:22:33:44:55").unwrap();
+        let ipv4: IpAddr = "10.0.0.25".parse().unwrap();
+
+        let eui = expected_ipv6(InterfaceIdDerivation::Eui64, ipv4, &v4, &v6, Some(&mac));
+        let embedded = expected_ipv6(InterfaceIdDerivation::Ipv4Embedded, ipv4, &v4, &v6, None);
+        let host = expected_ipv6(InterfaceIdDerivation::Ipv4HostPart, ipv4, &v4, &v6, None);
+
+        assert_eq!(eui.unwrap().to_string(), "2001:db8:1:0:211:22ff:fe33:4455");
+        assert_eq!(embedded.unwrap().to_string(), "2001:db8:1::a00:19");
+        assert_eq!(host.unwrap().to_string(), "2001:db8:1::19");
+    }
+
+    #[test]
+    fn test_ipv4_only_interface_is_reported() {
+        // Arrange
+        let (v4, v6) = networks();
+        let policy = policy(&v4, &v6, InterfaceIdDerivation::Ipv4HostPart);
+        let (good, bad, wrong) = (Uuid::now_v7(), Uuid::now_v7(), Uuid::now_v7());
+        let resource = Uuid::now_v7();
+        let assignments = vec![
+            assign("10.0.0.10/24", good, resource),
+            assign("2001:db8:1::a/64", good, resource),
+            assign("10.0.0.11/24", bad, resource),
+            assign("10.0.0.12/24", wrong, resource),
+            assign("2001:db8:1::99/64", wrong, resource),
+        ];
+
+        // Act
+        let violations = check_dual_stack(&policy, &v4, &v6, &assignments, &HashMap::new());
+        let advisories = ipv4_only_advisories(&policy, &violations, Uuid::now_v7());
+
+        // Assert
+        assert_eq!(violations.len(), 2);
+        assert!(violations
+            .iter()
+            .any(|v| matches!(v, DualStackViolation::MissingIpv6 { interface_id, .. } if *interface_id == bad)));
+        assert!(violations
+            .iter()
+            .any(|v| matches!(v, DualStackViolation::InterfaceIdMismatch { interface_id, .. } if *interface_id == wrong)));
+        assert_eq!(advisories.len(), 1);
+        assert_eq!(advisories[0].advisory_type(), "dual_stack_incomplete");
+    }
+}
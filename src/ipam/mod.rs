@@ -0,0 +1,169 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! IP Address Management (IPAM)
+//!
+//! Read-side tooling for IP address assignments: indexing which interface
+//! holds which address, checks that detect inventory errors such as the
+//! same address being assigned twice within one routing scope, and
+//! capacity reporting per network.
+//!
+//! # Architecture
+//!
+//! ```text
+//! Assignment source ──> IpAssignmentRegistry ──> detect_conflicts()
+//!  (projection, NetBox)        (index)                  │
+//!                                                       ▼
+//!                                 IpConflictMonitor ──> infrastructure.advisory.ip_conflict_detected
+//! ```
+//!
+//! Addresses handed out on the write side are owned by the event-sourced
+//! [`IpPool`](crate::aggregate::ip_pool) aggregate, which applies the same
+//! reservation rules as the [`IpAllocator`].
+//!
+//! # Scopes
+//!
+//! Addresses are unique per routing scope (VRF). Assignments without a VRF
+//! belong to the global routing table. The same address in two different
+//! VRFs is not a conflict.
+//!
+//! # Modules
+//!
+//! - [`registry`] - In-memory index of address assignments
+//! - [`conflicts`] - Conflict detection, validation and background monitor
+//! - [`utilization`] - Per-network utilization and capacity advisories
+//! - [`allocation`] - Reserved ranges, allocation policies and the allocator
+//! - [`dual_stack`] - IPv4/IPv6 pairing checks and interface ID conventions
+
+pub mod allocation;
+pub mod conflicts;
+pub mod dual_stack;
+mod ranges;
+pub mod registry;
+pub mod utilization;
+
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+use uuid::Uuid;
+
+use crate::domain::IpAddressWithCidr;
+
+pub use allocation::{
+    check_not_reserved, first_free, free_count, is_assignable, AllocationError, AllocationPolicy,
+    AllocationRequest, IpAllocator, ReservationPurpose, ReservedRange,
+};
+pub use conflicts::{detect_conflicts, validate_assignment, IpConflict};
+#[cfg(feature = "runtime")]
+pub use conflicts::{IpAssignmentSource, IpConflictMonitor};
+pub use dual_stack::{
+    check_dual_stack, expected_ipv6, DualStackPolicy, DualStackViolation, InterfaceIdDerivation,
+};
+#[cfg(feature = "clock")]
+pub use dual_stack::ipv4_only_advisories;
+pub use registry::IpAssignmentRegistry;
+pub use utilization::{subnet_utilization, utilization_report, SubnetUtilization};
+
+/// A network (prefix within a routing scope) managed by IPAM
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IpamNetwork {
+    /// Network identifier
+    pub network_id: Uuid,
+
+    /// Human-readable name (e.g. "dc1-servers")
+    pub name: String,
+
+    /// Network prefix (host bits cleared)
+    pub prefix: IpAddressWithCidr,
+
+    /// Routing scope (VRF); None for the global routing table
+    pub vrf: Option<String>,
+
+    /// Ranges excluded from allocation (gateway, DHCP pool, anycast)
+    #[serde(default)]
+    pub reserved: Vec<ReservedRange>,
+
+    /// How the allocator picks free addresses
+    #[serde(default)]
+    pub allocation_policy: AllocationPolicy,
+}
+
+impl IpamNetwork {
+    /// Create a network in the global routing table
+    ///
+    /// Host bits in `prefix` are cleared, so `10.0.0.1/24` becomes `10.0.0.0/24`.
+    pub fn new(network_id: Uuid, name: impl Into<String>, prefix: IpAddressWithCidr) -> Self {
+        Self {
+            network_id,
+            name: name.into(),
+            prefix: prefix.network(),
+            vrf: None,
+            reserved: Vec::new(),
+            allocation_policy: AllocationPolicy::default(),
+        }
+    }
+
+    /// Place the network in a VRF
+    pub fn in_vrf(mut self, vrf: impl Into<String>) -> Self {
+        self.vrf = Some(vrf.into());
+        self
+    }
+
+    /// Add a reserved range
+    pub fn with_reserved(mut self, range: ReservedRange) -> Self {
+        self.reserved.push(range);
+        self
+    }
+
+    /// Set the allocation policy
+    pub fn with_allocation_policy(mut self, policy: AllocationPolicy) -> Self {
+        self.allocation_policy = policy;
+        self
+    }
+
+    /// Whether an assignment falls inside this network's scope and prefix
+    pub fn contains(&self, assignment: &IpAssignment) -> bool {
+        assignment.vrf == self.vrf && self.prefix.contains(&assignment.ip())
+    }
+}
+
+/// An IP address assigned to a resource interface
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct IpAssignment {
+    /// Address with the prefix of the network it was assigned from
+    pub address: IpAddressWithCidr,
+
+    /// Interface holding the address
+    pub interface_id: Uuid,
+
+    /// Resource owning the interface
+    pub resource_id: Uuid,
+
+    /// Routing scope (VRF); None for the global routing table
+    pub vrf: Option<String>,
+}
+
+impl IpAssignment {
+    /// Create an assignment in the global routing table
+    pub fn new(address: IpAddressWithCidr, interface_id: Uuid, resource_id: Uuid) -> Self {
+        Self {
+            address,
+            interface_id,
+            resource_id,
+            vrf: None,
+        }
+    }
+
+    /// Place the assignment in a VRF
+    pub fn in_vrf(mut self, vrf: impl Into<String>) -> Self {
+        self.vrf = Some(vrf.into());
+        self
+    }
+
+    /// Bare IP address
+    pub fn ip(&self) -> IpAddr {
+        self.address.address()
+    }
+
+    /// Key identifying the address within its scope
+    pub fn scope_key(&self) -> (Option<String>, IpAddr) {
+        (self.vrf.clone(), self.ip())
+    }
+}
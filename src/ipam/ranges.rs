@@ -0,0 +1,139 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Address Interval Arithmetic
+//!
+//! Addresses are handled as integers (see [`ip_to_bits`]) and sets of
+//! addresses as sorted, merged inclusive intervals. This keeps large IPv6
+//! prefixes cheap: cost depends on the number of allocations and
+//! reservations, never on prefix size.
+
+use crate::domain::network::ip_to_bits;
+use crate::domain::IpAddressWithCidr;
+
+use super::IpamNetwork;
+
+/// Inclusive address interval
+pub(crate) type Interval = (u128, u128);
+
+/// Numeric bounds of a prefix
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AddressSpace {
+    pub first: u128,
+    pub last: u128,
+    pub host_bits: u32,
+    pub ipv4: bool,
+}
+
+impl AddressSpace {
+    /// Bounds of a prefix (host bits ignored)
+    pub fn of(prefix: &IpAddressWithCidr) -> Self {
+        let network = prefix.network();
+        let host_bits = (network.max_prefix_length() - network.effective_prefix_length()) as u32;
+        let first = ip_to_bits(network.address());
+
+        Self {
+            first,
+            last: first | host_mask(host_bits),
+            host_bits,
+            ipv4: network.is_ipv4(),
+        }
+    }
+}
+
+/// Mask of the low `host_bits` bits
+pub(crate) fn host_mask(host_bits: u32) -> u128 {
+    1u128
+        .checked_shl(host_bits)
+        .map_or(u128::MAX, |size| size - 1)
+}
+
+/// Number of addresses in an interval (saturating for the full IPv6 space)
+pub(crate) fn interval_len((start, end): Interval) -> u128 {
+    (end - start).saturating_add(1)
+}
+
+/// Sort and merge overlapping or adjacent intervals
+pub(crate) fn merge(mut intervals: Vec<Interval>) -> Vec<Interval> {
+    intervals.sort_unstable();
+
+    let mut merged: Vec<Interval> = Vec::with_capacity(intervals.len());
+    for (start, end) in intervals {
+        match merged.last_mut() {
+            Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+/// Whether an address falls inside any of the merged intervals
+pub(crate) fn covered(merged: &[Interval], address: u128) -> bool {
+    let index = merged.partition_point(|&(start, _)| start <= address);
+    index > 0 && merged[index - 1].1 >= address
+}
+
+/// Intervals of `first..=last` not covered by the merged intervals
+pub(crate) fn gaps(first: u128, last: u128, merged: &[Interval]) -> Vec<Interval> {
+    let mut gaps = Vec::new();
+    let mut cursor = Some(first);
+
+    for &(start, end) in merged {
+        let Some(from) = cursor else { break };
+        if end < from {
+            continue;
+        }
+        if start > last {
+            break;
+        }
+        if start > from {
+            gaps.push((from, start - 1));
+        }
+        cursor = end.checked_add(1).filter(|next| *next <= last);
+    }
+
+    if let Some(from) = cursor {
+        gaps.push((from, last));
+    }
+    gaps
+}
+
+/// Addresses of a network that may never be assigned to interfaces
+///
+/// Covers the IPv4 network and broadcast addresses (prefixes up to /30) and
+/// the network's reserved ranges, clipped to the prefix.
+pub(crate) fn reserved_intervals(network: &IpamNetwork) -> Vec<Interval> {
+    let space = AddressSpace::of(&network.prefix);
+    let mut intervals = Vec::new();
+
+    if space.ipv4 && space.host_bits >= 2 {
+        intervals.push((space.first, space.first));
+        intervals.push((space.last, space.last));
+    }
+
+    for range in &network.reserved {
+        if range.start.is_ipv4() != space.ipv4 {
+            continue;
+        }
+        let start = ip_to_bits(range.start).max(space.first);
+        let end = ip_to_bits(range.end).min(space.last);
+        if start <= end {
+            intervals.push((start, end));
+        }
+    }
+
+    merge(intervals)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_and_gaps() {
+        let merged = merge(vec![(5, 7), (0, 0), (8, 9), (20, 30)]);
+
+        assert_eq!(merged, vec![(0, 0), (5, 9), (20, 30)]);
+        assert_eq!(gaps(0, 31, &merged), vec![(1, 4), (10, 19), (31, 31)]);
+        assert!(covered(&merged, 6));
+        assert!(!covered(&merged, 10));
+    }
+}
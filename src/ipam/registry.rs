@@ -0,0 +1,158 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! IP Assignment Registry
+//!
+//! In-memory index of which interfaces hold which addresses, keyed by
+//! routing scope. Kept up to date by whatever feeds assignments (interface
+//! projections, NetBox sync) and queried by the IPAM checks.
+
+use std::collections::{BTreeMap, HashMap};
+use std::net::IpAddr;
+use uuid::Uuid;
+
+use super::conflicts::{detect_conflicts, IpConflict};
+use super::utilization::{subnet_utilization, SubnetUtilization};
+use super::{IpAssignment, IpamNetwork};
+
+/// Index of IP assignments by scope and address
+#[derive(Debug, Clone, Default)]
+pub struct IpAssignmentRegistry {
+    by_address: BTreeMap<(Option<String>, IpAddr), Vec<IpAssignment>>,
+    by_interface: HashMap<Uuid, Vec<(Option<String>, IpAddr)>>,
+}
+
+impl IpAssignmentRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build a registry from a list of assignments
+    pub fn from_assignments(assignments: impl IntoIterator<Item = IpAssignment>) -> Self {
+        let mut registry = Self::new();
+        for assignment in assignments {
+            registry.assign(assignment);
+        }
+        registry
+    }
+
+    /// Record an assignment (idempotent per interface and address)
+    pub fn assign(&mut self, assignment: IpAssignment) {
+        let key = assignment.scope_key();
+        let holders = self.by_address.entry(key.clone()).or_default();
+
+        if holders
+            .iter()
+            .any(|existing| existing.interface_id == assignment.interface_id)
+        {
+            return;
+        }
+
+        self.by_interface
+            .entry(assignment.interface_id)
+            .or_default()
+            .push(key);
+        holders.push(assignment);
+    }
+
+    /// Remove an address from an interface
+    pub fn release(&mut self, interface_id: Uuid, vrf: Option<&str>, address: IpAddr) {
+        let key = (vrf.map(str::to_string), address);
+
+        if let Some(holders) = self.by_address.get_mut(&key) {
+            holders.retain(|a| a.interface_id != interface_id);
+            if holders.is_empty() {
+                self.by_address.remove(&key);
+            }
+        }
+
+        if let Some(keys) = self.by_interface.get_mut(&interface_id) {
+            keys.retain(|k| k != &key);
+        }
+    }
+
+    /// Remove every address held by an interface
+    pub fn remove_interface(&mut self, interface_id: Uuid) {
+        for (vrf, address) in self.by_interface.remove(&interface_id).unwrap_or_default() {
+            let key = (vrf, address);
+            if let Some(holders) = self.by_address.get_mut(&key) {
+                holders.retain(|a| a.interface_id != interface_id);
+                if holders.is_empty() {
+                    self.by_address.remove(&key);
+                }
+            }
+        }
+    }
+
+    /// Interfaces holding an address within a scope
+    pub fn holders(&self, vrf: Option<&str>, address: IpAddr) -> &[IpAssignment] {
+        self.by_address
+            .get(&(vrf.map(str::to_string), address))
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    /// All assignments in address order
+    pub fn assignments(&self) -> impl Iterator<Item = &IpAssignment> {
+        self.by_address.values().flatten()
+    }
+
+    /// Number of distinct (scope, address) pairs in use
+    pub fn len(&self) -> usize {
+        self.by_address.len()
+    }
+
+    /// Whether no addresses are assigned
+    pub fn is_empty(&self) -> bool {
+        self.by_address.is_empty()
+    }
+
+    /// Current address conflicts
+    pub fn conflicts(&self) -> Vec<IpConflict> {
+        let assignments: Vec<IpAssignment> = self.assignments().cloned().collect();
+        detect_conflicts(&assignments)
+    }
+
+    /// Current utilization of a network
+    pub fn utilization(&self, network: &IpamNetwork) -> SubnetUtilization {
+        let assignments: Vec<IpAssignment> = self
+            .assignments()
+            .filter(|a| network.contains(a))
+            .cloned()
+            .collect();
+        subnet_utilization(network, &assignments)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::IpAddressWithCidr;
+
+    fn assignment(cidr: &str, interface_id: Uuid) -> IpAssignment {
+        IpAssignment::new(IpAddressWithCidr::new(cidr).unwrap(), interface_id, Uuid::now_v7())
+    }
+
+    #[test]
+    fn test_assign_is_idempotent_per_interface() {
+        let interface = Uuid::now_v7();
+        let mut registry = IpAssignmentRegistry::new();
+
+        registry.assign(assignment("10.0.0.5/24", interface));
+        registry.assign(assignment("10.0.0.5/24", interface));
+
+        assert_eq!(registry.len(), 1);
+        assert_eq!(registry.holders(None, "10.0.0.5".parse().unwrap()).len(), 1);
+    }
+
+    #[test]
+    fn test_remove_interface_releases_addresses() {
+        let interface = Uuid::now_v7();
+        let mut registry = IpAssignmentRegistry::new();
+        registry.assign(assignment("10.0.0.5/24", interface));
+        registry.assign(assignment("10.0.0.6/24", interface));
+
+        registry.remove_interface(interface);
+
+        assert!(registry.is_empty());
+    }
+}
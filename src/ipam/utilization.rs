@@ -0,0 +1,311 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Subnet Utilization
+//!
+//! Capacity figures per network: how many addresses are allocated, how many
+//! remain, and the largest aligned block still free (the biggest subnet that
+//! could be carved out without renumbering anything).
+//!
+//! # Counting Rules
+//!
+//! - IPv4 prefixes up to /30 reserve the network and broadcast addresses;
+//!   /31 and /32 use every address (RFC 3021)
+//! - IPv6 prefixes use every address
+//! - Reserved ranges (gateway, DHCP pool, anycast) are not usable
+//! - An address held by several interfaces counts once
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::ipam::utilization::{nearly_full, utilization_report};
+//!
+//! let report = utilization_report(&networks, &assignments);
+//! for advisory in nearly_full(&report, 90.0, correlation_id) {
+//!     nats_client.publish(&advisory.subject(), &advisory).await?;
+//! }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use uuid::Uuid;
+
+use super::ranges::{covered, gaps, interval_len, merge, reserved_intervals, AddressSpace};
+use super::{IpAssignment, IpamNetwork};
+use crate::domain::network::{bits_to_ip, ip_to_bits};
+use crate::domain::IpAddressWithCidr;
+#[cfg(feature = "clock")]
+use crate::events::advisory::{AdvisoryEvent, SubnetNearlyFull};
+
+/// Utilization of a single network
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubnetUtilization {
+    /// Network identifier
+    pub network_id: Uuid,
+
+    /// Network prefix
+    pub prefix: IpAddressWithCidr,
+
+    /// Routing scope (VRF); None for the global routing table
+    pub vrf: Option<String>,
+
+    /// Addresses covered by the prefix
+    pub total_addresses: u128,
+
+    /// Addresses that can be assigned to interfaces
+    pub usable_addresses: u128,
+
+    /// Distinct usable addresses currently assigned
+    pub allocated: u128,
+
+    /// Usable addresses still free
+    pub available: u128,
+
+    /// Largest aligned prefix containing no assigned or reserved address
+    pub largest_free_block: Option<IpAddressWithCidr>,
+}
+
+impl SubnetUtilization {
+    /// Percentage of usable addresses allocated (0.0 - 100.0)
+    pub fn utilization_percent(&self) -> f64 {
+        if self.usable_addresses == 0 {
+            return 100.0;
+        }
+        self.allocated as f64 / self.usable_addresses as f64 * 100.0
+    }
+
+    /// Whether utilization is at or above a threshold percentage
+    pub fn is_at_least(&self, threshold_percent: f64) -> bool {
+        self.utilization_percent() >= threshold_percent
+    }
+
+    /// Build a [`SubnetNearlyFull`] advisory for this network
+    #[cfg(feature = "clock")]
+    pub fn to_advisory(&self, threshold_percent: f64, correlation_id: Uuid) -> AdvisoryEvent {
+        AdvisoryEvent::SubnetNearlyFull(SubnetNearlyFull {
+            event_id: Uuid::now_v7(),
+            detected_at: chrono::Utc::now(),
+            correlation_id,
+            network_id: self.network_id,
+            prefix: self.prefix.clone(),
+            vrf: self.vrf.clone(),
+            allocated: self.allocated,
+            usable: self.usable_addresses,
+            utilization_percent: self.utilization_percent(),
+            threshold_percent,
+        })
+    }
+}
+
+/// Compute utilization of one network from the current assignments
+///
+/// Assignments outside the network's prefix or VRF are ignored, as are
+/// assignments inside reserved ranges (they do not consume allocatable
+/// space).
+pub fn subnet_utilization(network: &IpamNetwork, assignments: &[IpAssignment]) -> SubnetUtilization {
+    let prefix = network.prefix.network();
+    let space = AddressSpace::of(&prefix);
+    let total_addresses = prefix.address_count();
+
+    let reserved = reserved_intervals(network);
+    let reserved_count: u128 = reserved.iter().copied().map(interval_len).sum();
+
+    let allocated_bits: BTreeSet<u128> = assignments
+        .iter()
+        .filter(|a| network.contains(a))
+        .map(|a| ip_to_bits(a.ip()))
+        .filter(|bits| !covered(&reserved, *bits))
+        .collect();
+
+    let usable_addresses = total_addresses.saturating_sub(reserved_count);
+    let allocated = allocated_bits.len() as u128;
+
+    let occupied = merge(
+        reserved
+            .iter()
+            .copied()
+            .chain(allocated_bits.iter().map(|&bits| (bits, bits)))
+            .collect(),
+    );
+
+    let mut best: Option<(u128, u32)> = None;
+    for (start, end) in gaps(space.first, space.last, &occupied) {
+        if let Some(candidate) = largest_aligned_in(start, end, space.host_bits) {
+            let better = match best {
+                Some((_, bits)) => candidate.1 > bits,
+                None => true,
+            };
+            if better {
+                best = Some(candidate);
+            }
+        }
+    }
+
+    let width = prefix.max_prefix_length();
+    let largest_free_block = best.map(|(start, block_bits)| {
+        IpAddressWithCidr::from_parts(bits_to_ip(start, space.ipv4), Some(width - block_bits as u8))
+            .expect("block prefix is within address width")
+    });
+
+    SubnetUtilization {
+        network_id: network.network_id,
+        prefix,
+        vrf: network.vrf.clone(),
+        total_addresses,
+        usable_addresses,
+        allocated,
+        available: usable_addresses - allocated,
+        largest_free_block,
+    }
+}
+
+/// Utilization of every network, most utilized first
+pub fn utilization_report(
+    networks: &[IpamNetwork],
+    assignments: &[IpAssignment],
+) -> Vec<SubnetUtilization> {
+    let mut report: Vec<SubnetUtilization> = networks
+        .iter()
+        .map(|network| subnet_utilization(network, assignments))
+        .collect();
+
+    report.sort_by(|a, b| b.utilization_percent().total_cmp(&a.utilization_percent()));
+    report
+}
+
+/// Advisories for networks at or above a utilization threshold
+#[cfg(feature = "clock")]
+pub fn nearly_full(
+    report: &[SubnetUtilization],
+    threshold_percent: f64,
+    correlation_id: Uuid,
+) -> Vec<AdvisoryEvent> {
+    report
+        .iter()
+        .filter(|u| u.is_at_least(threshold_percent))
+        .map(|u| u.to_advisory(threshold_percent, correlation_id))
+        .collect()
+}
+
+/// Largest power-of-two aligned block inside `start..=end`
+fn largest_aligned_in(mut start: u128, end: u128, max_bits: u32) -> Option<(u128, u32)> {
+    let mut best: Option<(u128, u32)> = None;
+
+    while start <= end {
+        let alignment = if start == 0 { 128 } else { start.trailing_zeros() };
+        let span = (end - start).checked_add(1);
+        let fits = span.map_or(128, |len| 127 - len.leading_zeros());
+        let bits = alignment.min(fits).min(max_bits);
+
+        let better = match best {
+            Some((_, b)) => bits > b,
+            None => true,
+        };
+        if better {
+            best = Some((start, bits));
+        }
+
+        match 1u128.checked_shl(bits).and_then(|size| start.checked_add(size)) {
+            Some(next) => start = next,
+            None => break,
+        }
+    }
+
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ipam::{ReservationPurpose, ReservedRange};
+
+    fn network(cidr: &str) -> IpamNetwork {
+        IpamNetwork::new(Uuid::now_v7(), "test", IpAddressWithCidr::new(cidr).unwrap())
+    }
+
+    fn assignment(address: &str) -> IpAssignment {
+        IpAssignment::new(
+            IpAddressWithCidr::new(address).unwrap(),
+            Uuid::now_v7(),
+            Uuid::now_v7(),
+        )
+    }
+
+    #[test]
+    fn test_ipv4_utilization_and_free_block() {
+        // Arrange - .1 to .64 allocated in a /24
+        let net = network("10.0.0.0/24");
+        let assignments: Vec<_> = (1..=64)
+            .map(|host| assignment(&format!("10.0.0.{}/24", host)))
+            .collect();
+
+        // Act
+        let utilization = subnet_utilization(&net, &assignments);
+
+        // Assert
+        assert_eq!(utilization.total_addresses, 256);
+        assert_eq!(utilization.usable_addresses, 254);
+        assert_eq!(utilization.allocated, 64);
+        assert_eq!(utilization.available, 190);
+        // .128/25 contains the broadcast address, so the best block is .128/26
+        assert_eq!(
+            utilization.largest_free_block.unwrap().as_cidr(),
+            "10.0.0.128/26"
+        );
+    }
+
+    #[test]
+    fn test_assignments_outside_scope_are_ignored() {
+        let net = network("10.0.0.0/30").in_vrf("blue");
+        let assignments = vec![
+            assignment("10.0.0.1/30").in_vrf("blue"),
+            assignment("10.0.0.2/30").in_vrf("red"),
+            assignment("10.0.1.1/30").in_vrf("blue"),
+        ];
+
+        let utilization = subnet_utilization(&net, &assignments);
+
+        assert_eq!(utilization.usable_addresses, 2);
+        assert_eq!(utilization.allocated, 1);
+        assert_eq!(utilization.utilization_percent(), 50.0);
+    }
+
+    #[test]
+    fn test_reserved_ranges_reduce_usable_space() {
+        let net = network("10.0.0.0/24")
+            .with_reserved(ReservedRange::single(
+                "10.0.0.1".parse().unwrap(),
+                ReservationPurpose::Gateway,
+            ))
+            .with_reserved(
+                ReservedRange::new(
+                    "10.0.0.128".parse().unwrap(),
+                    "10.0.0.254".parse().unwrap(),
+                    ReservationPurpose::DhcpPool,
+                )
+                .unwrap(),
+            );
+
+        let utilization = subnet_utilization(&net, &[assignment("10.0.0.200/24")]);
+
+        assert_eq!(utilization.usable_addresses, 126);
+        assert_eq!(utilization.allocated, 0);
+        assert_eq!(
+            utilization.largest_free_block.unwrap().as_cidr(),
+            "10.0.0.64/26"
+        );
+    }
+
+    #[test]
+    fn test_nearly_full_emits_advisories_above_threshold() {
+        let full = network("192.168.0.0/30");
+        let empty = network("192.168.1.0/24");
+        let assignments = vec![assignment("192.168.0.1/30"), assignment("192.168.0.2/30")];
+
+        let report = utilization_report(&[empty, full], &assignments);
+        let advisories = nearly_full(&report, 90.0, Uuid::now_v7());
+
+        assert_eq!(report[0].utilization_percent(), 100.0);
+        assert_eq!(advisories.len(), 1);
+        assert_eq!(advisories[0].advisory_type(), "subnet_nearly_full");
+        assert!(report[0].largest_free_block.is_none());
+    }
+}
@@ -13,6 +13,26 @@
 //! - **Stream Configuration**: Subject-based stream organization
 //! - **Ordering Guarantees**: Sequence numbers and timestamps
 //!
+//! # Upgrading Streams
+//!
+//! [`create_infrastructure_stream`] migrates an existing stream's subjects
+//! in place. Releases before the split into per-aggregate subjects bound
+//! the stream to `infrastructure.>`, which also captured commands and
+//! queries, so every request got a JetStream publish ack back:
+//!
+//! ```text
+//! before: infrastructure.>
+//! after:  infrastructure.compute.>, infrastructure.network.>, ...,
+//!         infrastructure.correlation.>, infrastructure.change.>, ...
+//! ```
+//!
+//! Subjects overlapping `infrastructure.{cmd,query,notify,progress,
+//! observation,health,agent}.>` are dropped and the configured ones added.
+//! Messages already stored stay in the stream; requests captured before
+//! the upgrade remain there until the stream's limits age them out, and
+//! can be purged by subject (`nats stream purge <stream> --subject
+//! 'infrastructure.cmd.>'`).
+//!
 //! # Example
 //!
 //! ```rust,no_run
@@ -34,9 +54,11 @@ use async_nats::jetstream::{self, stream::Stream};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
+use tracing::info;
 use uuid::Uuid;
 
 use crate::errors::{InfrastructureError, InfrastructureResult};
+use crate::event_store::codec::CodecKind;
 
 /// Configuration for JetStream infrastructure event streams
 #[derive(Debug, Clone)]
@@ -44,7 +66,9 @@ pub struct JetStreamConfig {
     /// Stream name for infrastructure events
     pub stream_name: String,
 
-    /// Subjects this stream will capture (defaults to "infrastructure.>")
+    /// Subjects this stream will capture (defaults to
+    /// [`subjects::stream_subjects`](crate::subjects::subjects::stream_subjects),
+    /// which leaves out request/reply subjects such as commands)
     pub subjects: Vec<String>,
 
     /// Maximum age of messages (default: 30 days)
@@ -53,6 +77,16 @@ pub struct JetStreamConfig {
     /// Maximum bytes stored in stream (default: 10GB)
     pub max_bytes: i64,
 
+    /// Maximum messages stored in stream (default: -1, unlimited)
+    pub max_messages: i64,
+
+    /// Maximum messages kept per subject (default: -1, unlimited)
+    ///
+    /// Event subjects are `<aggregate>.<aggregate_id>.<event_type>`, so a
+    /// limit of 1 keeps only the latest event of each type per aggregate.
+    /// Only set this for streams whose consumers never need older events.
+    pub max_messages_per_subject: i64,
+
     /// Storage type (File or Memory)
     pub storage: StorageType,
 
@@ -61,18 +95,97 @@ pub struct JetStreamConfig {
 
     /// Retention policy
     pub retention: RetentionPolicy,
+
+    /// Organization whose events this stream holds (None = untenanted
+    /// `infrastructure.<aggregate>` subjects)
+    pub tenant: Option<String>,
+
+    /// Wire format of event payloads (default: JSON)
+    pub codec: CodecKind,
 }
 
 impl Default for JetStreamConfig {
     fn default() -> Self {
         Self {
             stream_name: "INFRASTRUCTURE_EVENTS".to_string(),
-            subjects: vec!["infrastructure.>".to_string()],
+            subjects: crate::subjects::subjects::stream_subjects(),
             max_age: Duration::from_secs(30 * 24 * 60 * 60), // 30 days
             max_bytes: 10 * 1024 * 1024 * 1024, // 10 GB
+            max_messages: -1,
+            max_messages_per_subject: -1,
             storage: StorageType::File,
             replicas: 1,
             retention: RetentionPolicy::Limits,
+            tenant: None,
+            codec: CodecKind::Json,
+        }
+    }
+}
+
+impl JetStreamConfig {
+    /// Stream holding only one organization's events
+    ///
+    /// The stream is named `INFRASTRUCTURE_EVENTS_T<HEX>`, where `<HEX>` is
+    /// the hex encoding of the organization ID, so no two organizations
+    /// share a stream. It captures
+    /// [`subjects::tenant_stream_subjects`](crate::subjects::subjects::tenant_stream_subjects);
+    /// event stores using it publish under `infrastructure.<org_id>`.
+    ///
+    /// Fails with [`InfrastructureError::Configuration`] if `org_id` is not
+    /// a valid subject token or is [reserved](crate::subjects::is_reserved_token).
+    pub fn for_tenant(org_id: impl Into<String>) -> InfrastructureResult<Self> {
+        let org_id = org_id.into();
+        if !crate::subjects::is_valid_tenant(&org_id) {
+            return Err(InfrastructureError::Configuration(format!(
+                "tenant must be a single, unreserved subject token: {:?}",
+                org_id
+            )));
+        }
+
+        let stream_suffix: String = org_id.bytes().map(|b| format!("{:02X}", b)).collect();
+        Ok(Self {
+            stream_name: format!("INFRASTRUCTURE_EVENTS_T{}", stream_suffix),
+            subjects: crate::subjects::subjects::tenant_stream_subjects(&org_id),
+            tenant: Some(org_id),
+            ..Self::default()
+        })
+    }
+
+    /// Set the maximum age of messages
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    /// Set the maximum bytes stored
+    pub fn with_max_bytes(mut self, max_bytes: i64) -> Self {
+        self.max_bytes = max_bytes;
+        self
+    }
+
+    /// Set the maximum messages stored
+    pub fn with_max_messages(mut self, max_messages: i64) -> Self {
+        self.max_messages = max_messages;
+        self
+    }
+
+    /// Set the maximum messages kept per subject
+    pub fn with_max_messages_per_subject(mut self, max_messages: i64) -> Self {
+        self.max_messages_per_subject = max_messages;
+        self
+    }
+
+    /// Set the wire format of event payloads
+    pub fn with_codec(mut self, codec: CodecKind) -> Self {
+        self.codec = codec;
+        self
+    }
+
+    /// Root of the subjects events are published under
+    pub fn subject_prefix(&self) -> String {
+        match &self.tenant {
+            Some(org_id) => crate::subjects::subjects::tenant_root(org_id),
+            None => crate::subjects::INFRASTRUCTURE_ROOT.to_string(),
         }
     }
 }
@@ -101,6 +214,7 @@ pub enum RetentionPolicy {
 ///
 /// This wraps domain events with correlation tracking and sequencing.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct StoredEvent<E> {
     /// Unique event ID (UUID v7 for time-ordering)
     pub event_id: Uuid,
@@ -126,7 +240,10 @@ pub struct StoredEvent<E> {
     /// The actual domain event data
     pub data: E,
 
-    /// Optional metadata (e.g., user context, source system)
+    /// Optional metadata object; typed entries are read and written with
+    /// [`EventEnvelopeMetadata`](crate::event_store::provenance::EventEnvelopeMetadata),
+    /// [`BatchMarker`](crate::event_store::batch::BatchMarker) and
+    /// [`change_control`](crate::change_control)
     pub metadata: Option<serde_json::Value>,
 }
 
@@ -165,10 +282,27 @@ impl<E> StoredEvent<E> {
 ///
 /// This function is idempotent - it will create the stream if it doesn't exist,
 /// or update it if the configuration has changed.
+///
+/// An existing stream is migrated to the configured subjects: subjects it
+/// lacks are added, and subjects capturing request/reply traffic (such as
+/// the `infrastructure.>` of older releases) are dropped. Configured
+/// subjects capturing request/reply traffic are rejected.
 pub async fn create_infrastructure_stream(
     jetstream: jetstream::Context,
     config: JetStreamConfig,
 ) -> InfrastructureResult<Stream> {
+    let transient = crate::subjects::subjects::transient_subjects();
+    if let Some(subject) = config
+        .subjects
+        .iter()
+        .find(|subject| captures_any(subject, &transient))
+    {
+        return Err(InfrastructureError::Configuration(format!(
+            "Stream subject {} would capture commands or queries",
+            subject
+        )));
+    }
+
     let storage = match config.storage {
         StorageType::File => jetstream::stream::StorageType::File,
         StorageType::Memory => jetstream::stream::StorageType::Memory,
@@ -185,20 +319,71 @@ pub async fn create_infrastructure_stream(
         subjects: config.subjects,
         max_age: config.max_age,
         max_bytes: config.max_bytes,
+        max_messages: config.max_messages,
+        max_messages_per_subject: config.max_messages_per_subject,
         storage,
         num_replicas: config.replicas,
         retention,
         ..Default::default()
     };
 
-    let stream = jetstream
-        .get_or_create_stream(stream_config)
+    let mut stream = jetstream
+        .get_or_create_stream(stream_config.clone())
         .await
         .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?;
 
+    let existing = stream.cached_info().config.clone();
+    let subjects = migrated_subjects(&existing.subjects, &stream_config.subjects, &transient);
+    if subjects != existing.subjects {
+        info!(
+            stream = %config.stream_name,
+            from = ?existing.subjects,
+            to = ?subjects,
+            "Migrating stream subjects"
+        );
+        let mut updated = existing;
+        updated.subjects = subjects;
+        jetstream
+            .update_stream(&updated)
+            .await
+            .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?;
+        stream = jetstream
+            .get_stream(&config.stream_name)
+            .await
+            .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?;
+    }
+
     Ok(stream)
 }
 
+/// Whether `subject` captures any of the `transient` subjects
+fn captures_any(subject: &str, transient: &[String]) -> bool {
+    transient
+        .iter()
+        .any(|transient| crate::subjects::subjects_overlap(subject, transient))
+}
+
+/// Subjects of an existing stream once migrated to the `wanted` ones
+///
+/// Streams created by older releases lack subjects added since (such as
+/// the interface and IP pool aggregates) and may capture `infrastructure.>`,
+/// which takes in every command and query too. Subjects capturing
+/// `transient` ones are dropped and the missing ones added; every other
+/// subject is kept.
+fn migrated_subjects(existing: &[String], wanted: &[String], transient: &[String]) -> Vec<String> {
+    let mut subjects: Vec<String> = existing
+        .iter()
+        .filter(|subject| !captures_any(subject, transient))
+        .cloned()
+        .collect();
+    for subject in wanted {
+        if !subjects.contains(subject) {
+            subjects.push(subject.clone());
+        }
+    }
+    subjects
+}
+
 /// Consumer configuration for event processing
 #[derive(Debug, Clone)]
 pub struct ConsumerConfig {
@@ -262,9 +447,62 @@ mod tests {
     fn test_default_config() {
         let config = JetStreamConfig::default();
         assert_eq!(config.stream_name, "INFRASTRUCTURE_EVENTS");
-        assert_eq!(config.subjects, vec!["infrastructure.>"]);
+        assert!(config.subjects.contains(&"infrastructure.compute.>".to_string()));
+        assert!(!config.subjects.contains(&"infrastructure.>".to_string()));
         assert_eq!(config.storage, StorageType::File);
         assert_eq!(config.retention, RetentionPolicy::Limits);
+        assert_eq!(config.max_messages_per_subject, -1);
+    }
+
+    #[test]
+    fn test_tenant_config() {
+        let config = JetStreamConfig::for_tenant("0193e4b2-acme").unwrap();
+        assert_eq!(
+            config.stream_name,
+            "INFRASTRUCTURE_EVENTS_T30313933653462322D61636D65"
+        );
+        assert_eq!(config.subject_prefix(), "infrastructure.0193e4b2-acme");
+        assert!(config
+            .subjects
+            .contains(&"infrastructure.0193e4b2-acme.compute.>".to_string()));
+        assert_eq!(
+            JetStreamConfig::default().subject_prefix(),
+            "infrastructure"
+        );
+    }
+
+    #[test]
+    fn test_upgraded_streams_stop_capturing_requests() {
+        // Arrange
+        let transient = crate::subjects::subjects::transient_subjects();
+        let wanted = JetStreamConfig::default().subjects;
+        let legacy = vec!["infrastructure.>".to_string(), "audit.>".to_string()];
+
+        // Act
+        let migrated = migrated_subjects(&legacy, &wanted, &transient);
+
+        // Assert
+        assert!(!migrated.contains(&"infrastructure.>".to_string()));
+        assert!(migrated.contains(&"audit.>".to_string()));
+        assert!(wanted.iter().all(|subject| migrated.contains(subject)));
+        assert_eq!(migrated_subjects(&wanted, &wanted, &transient), wanted);
+        assert!(captures_any("infrastructure.*.compute.>", &transient));
+    }
+
+    #[test]
+    fn test_tenant_streams_never_collide() {
+        let dashed = JetStreamConfig::for_tenant("acme-eu").unwrap();
+        let underscored = JetStreamConfig::for_tenant("acme_eu").unwrap();
+        let cased = JetStreamConfig::for_tenant("ACME_EU").unwrap();
+
+        assert_ne!(dashed.stream_name, underscored.stream_name);
+        assert_ne!(underscored.stream_name, cased.stream_name);
+        for invalid in ["compute", "ip_pool", "feed", "cmd", "a.b", ""] {
+            assert!(matches!(
+                JetStreamConfig::for_tenant(invalid),
+                Err(InfrastructureError::Configuration(_))
+            ));
+        }
     }
 
     #[test]
@@ -28,9 +28,76 @@
 //! - [`subjects`] - NATS subject patterns
 //! - [`projection`] - Projection adapter trait (Functor interface)
 //! - [`adapters`] - Concrete projection implementations
+//! - [`adapter_sdk`] - Checkpointing, metrics, test kit and scaffolding for
+//!   third-party integrations
 //! - [`frp`] - Functional Reactive Programming abstractions
+//! - [`ipam`] - IP address management checks (conflict detection)
+//! - [`conventions`] - Naming and metadata convention linting
+//! - [`policy`] - Policy rules, evaluation and compliance monitoring
+//! - [`alert`] - Declarative alert rules over the event stream
+//! - [`export`] - CSV/XLSX inventory exports for audits
+//! - [`import`] - Bootstrapping inventory from Terraform state
+//! - [`discovery`] - Hardware discovery over SSH or host agents, with periodic re-scans
+//! - [`reconcile`] - Drift detection between declared and observed infrastructure
+//! - [`apply`] - Declarative topology documents converged with previewable plans
+//! - [`rollup`] - Host status rolled up with the status of its guests
+//! - [`scorecard`] - Per-organization inventory hygiene scorecards
+//! - [`enrichment`] - Organization and owner display names joined onto read models
+//! - [`archival`] - Idle, inactive aggregates suggested for archival
+//! - [`change_control`] - Change request references on commands and events
+//! - [`tenancy`] - Commands and reads limited to the acting organization
+//! - [`causation`] - Causation graphs of a correlation, as DOT or Mermaid
+//! - [`config`] - Layered dev/staging/prod configuration for every subsystem
+//! - [`health`] - Component health and readiness reports
+//! - [`audit`] - Hash-chained audit log with JSONL/CSV export
+//! - [`api`] - GraphQL, REST and WebSocket APIs for clients that cannot
+//!   speak NATS
+//! - [`visualization`] - Live topology graph for Bevy front-ends
+//! - [`process_manager`] - Sagas for cross-aggregate workflows
 //! - [`errors`] - Error types
 //!
+//! # Features
+//!
+//! - `runtime` (default) - NATS, JetStream, event store, projections,
+//!   services and process managers
+//! - `clock` - helpers that stamp the current time (enabled by `runtime`)
+//! - `wasm` - UUID generation in browsers and edge functions
+//! - `local-store` - file-backed event store for edge sites, with upstream sync
+//! - `python` - PyO3 extension module ([`python`])
+//! - `typescript` - TypeScript definitions for events, commands and read
+//!   models ([`typescript`])
+//! - `json-schema` - versioned JSON Schemas for events and commands
+//!   ([`schema`])
+//! - `asyncapi` - AsyncAPI document for NATS subjects and payloads
+//!   ([`asyncapi`])
+//! - `xlsx` - Excel workbooks for inventory exports ([`export`])
+//! - `cbor`, `protobuf` - binary event payload codecs
+//!   ([`event_store::codec`])
+//! - `graphql` - GraphQL query server over the read model ([`api::graphql`])
+//! - `rest` - REST API for commands and queries ([`api::rest`])
+//! - `websocket` - WebSocket event stream for browser UIs
+//!   ([`api::websocket`])
+//! - `visualization` - live topology graph as a Bevy ECS resource
+//!   ([`visualization`])
+//! - `cold-storage` - archives of decommissioned aggregates in S3-compatible
+//!   object storage ([`archival`])
+//! - `content-addressing` - CIDs for appended events and lookups by CID
+//!   ([`event_store::content`])
+//! - `audit-signing` - ed25519 signatures on audit log entries ([`audit`])
+//! - `petgraph` - causation graphs as `petgraph` graphs ([`causation`])
+//! - `test-support` - proptest strategies for aggregate commands
+//!   ([`test_harness::strategies`]), without the runtime
+//! - `test-harness` - throwaway NATS servers, fixtures and given/when/then
+//!   scenarios for tests, with the `test-support` strategies
+//!   ([`test_harness`])
+//!
+//! With `--no-default-features` only the pure core is built: [`domain`]
+//! value objects, [`events`], the [`aggregate`] fold and command handlers,
+//! [`state_machine`], [`ipam`], [`conventions`] and [`policy`] checks,
+//! [`subjects`] and [`frp`]. It has no async runtime or NATS dependency and
+//! compiles for `wasm32-unknown-unknown`, so client-side tooling validates
+//! commands and folds state with the same code as the services.
+//!
 //! # Quick Start
 //!
 //! ```rust,no_run
@@ -49,40 +116,111 @@
 
 // Core modules
 pub mod aggregate;
+pub mod alert;
+pub mod apply;
+pub mod archival;
+pub mod causation;
+pub mod change_control;
+pub mod conventions;
+pub mod discovery;
 pub mod domain;
+pub mod enrichment;
 pub mod errors;
-pub mod event_store;
 pub mod events;
+pub mod export;
 pub mod frp;
+pub mod health;
+pub mod import;
+pub mod ipam;
+pub mod policy;
+pub mod reconcile;
+pub mod rollup;
+pub mod scorecard;
+pub mod state_machine;
+pub mod subjects;
+pub mod tenancy;
+
+// Runtime modules (NATS, JetStream, async services)
+#[cfg(feature = "runtime")]
+pub mod audit;
+#[cfg(feature = "runtime")]
+pub mod config;
+#[cfg(feature = "runtime")]
+pub mod event_store;
+#[cfg(feature = "runtime")]
 pub mod jetstream;
+#[cfg(feature = "runtime")]
 pub mod nats;
+#[cfg(feature = "runtime")]
+pub mod process_manager;
+#[cfg(feature = "runtime")]
 pub mod projection;
+#[cfg(feature = "runtime")]
 pub mod service;
-pub mod state_machine;
-pub mod subjects;
+
+// Python bindings (feature-gated)
+#[cfg(feature = "python")]
+pub mod python;
+
+// TypeScript definition export (feature-gated)
+#[cfg(feature = "typescript")]
+pub mod typescript;
+
+// JSON Schema export (feature-gated)
+#[cfg(feature = "json-schema")]
+pub mod schema;
+
+// AsyncAPI specification (feature-gated)
+#[cfg(feature = "asyncapi")]
+pub mod asyncapi;
+
+// HTTP APIs (feature-gated)
+#[cfg(any(feature = "graphql", feature = "rest", feature = "websocket"))]
+pub mod api;
+
+// Bevy topology visualization (feature-gated)
+#[cfg(feature = "visualization")]
+pub mod visualization;
 
 // Projection adapters (feature-gated)
+#[cfg(feature = "runtime")]
 pub mod adapters;
+#[cfg(feature = "runtime")]
+pub mod adapter_sdk;
+
+// Test fixtures (feature-gated, always built for the crate's own tests)
+#[cfg(any(
+    feature = "test-support",
+    feature = "test-harness",
+    all(test, feature = "runtime")
+))]
+pub mod test_harness;
 
 // Re-export commonly used types
 pub use aggregate::{ComputeResourceState, apply_event, CommandError};
 pub use domain::{
-    ComputeResource, ComputeResourceBuilder, ComputeResourceError, Hostname, HostnameError,
-    IpAddressWithCidr, MacAddress, Mtu, NetworkError, ResourceCategory, ResourceType, VlanId,
+    Hostname, HostnameError, IpAddressWithCidr, MacAddress, Mtu, NetworkError, ResourceCategory,
+    ResourceType, VlanId,
 };
+#[cfg(feature = "clock")]
+pub use domain::{ComputeResource, ComputeResourceBuilder, ComputeResourceError};
 pub use errors::{InfrastructureError, InfrastructureResult};
+#[cfg(feature = "runtime")]
 pub use event_store::{EventMetadata, EventStore, NatsEventStore};
 pub use events::{
     AccountConceptAssigned, AccountConceptCleared, AssetTagAssigned, ComputeResourceEvent,
-    HardwareDetailsSet, InfrastructureEvent, LocationAssigned, MetadataUpdated,
-    OrganizationAssigned, OwnerAssigned, PolicyAdded, PolicyRemoved, ResourceRegistered,
-    ResourceStatus, StatusChanged,
+    ConfigurationBackupRecorded, ConnectionEvent, GuestAttached, GuestDetached, HardwareDetailsSet, InfrastructureEvent, IpPoolEvent, LocationAssigned, MetadataUpdated,
+    NetworkEvent, NetworkInterfaceEvent, OrganizationAssigned, OwnerAssigned, PolicyAdded,
+    PolicyRemoved, ResourceRegistered, ResourceStatus, StatusChanged,
 };
+#[cfg(feature = "runtime")]
 pub use jetstream::{
     AckPolicy, ConsumerConfig, DeliverPolicy, JetStreamConfig, RetentionPolicy, StorageType,
     StoredEvent,
 };
+#[cfg(feature = "runtime")]
 pub use nats::{MessageHandler, NatsClient, NatsConfig};
+#[cfg(feature = "runtime")]
 pub use projection::{ProjectionAdapter, ProjectionError};
 pub use subjects::{AggregateType, Operation, SubjectBuilder};
 
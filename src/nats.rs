@@ -1,13 +1,28 @@
 //! NATS client abstraction for messaging infrastructure
+//!
+//! Read models are served over request/reply by [`query::QueryResponder`];
+//! long-running operations report through [`progress::ProgressReporter`].
+//! Publishes during a connection outage are buffered and replayed on
+//! reconnect ([`outage`]). A client given a
+//! [`SubjectRegistry`] refuses publishes outside the registered subjects.
+//! KV entries shared between instances are written conditionally through
+//! [`kv`].
 
-use async_nats::{Client, ConnectOptions, Subscriber};
+pub mod kv;
+pub mod outage;
+pub mod progress;
+pub mod query;
+
+use async_nats::{Client, ConnectOptions, HeaderMap, Subscriber};
 use futures::StreamExt;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use std::time::Duration;
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
 
 use crate::errors::{InfrastructureError, InfrastructureResult};
+use crate::subjects::registry::SubjectRegistry;
+use outage::{ConnectionEvent, ConnectionListener, ConnectionMetrics, Hold, OutageBuffer};
 
 /// Configuration for NATS connection
 #[derive(Debug, Clone)]
@@ -20,6 +35,8 @@ pub struct NatsConfig {
     pub connect_timeout: Duration,
     /// Request timeout
     pub request_timeout: Duration,
+    /// Messages held while disconnected (0 fails publishes instead)
+    pub outage_buffer: usize,
 }
 
 impl Default for NatsConfig {
@@ -29,6 +46,7 @@ impl Default for NatsConfig {
             name: "cim-client".to_string(),
             connect_timeout: Duration::from_secs(10),
             request_timeout: Duration::from_secs(5),
+            outage_buffer: 10_000,
         }
     }
 }
@@ -37,41 +55,120 @@ impl Default for NatsConfig {
 #[derive(Clone)]
 pub struct NatsClient {
     client: Client,
+    outage: Arc<OutageBuffer>,
+    registry: Option<Arc<SubjectRegistry>>,
 }
 
 impl NatsClient {
     /// Create a new NATS client with the given configuration
+    ///
+    /// The client reconnects on its own after the connection drops; see
+    /// [`outage`] for what happens to publishes in the meantime.
     pub async fn new(config: NatsConfig) -> InfrastructureResult<Self> {
+        let outage = Arc::new(OutageBuffer::new(config.outage_buffer));
+        // The callback outlives no client: it only holds a weak reference
+        let watched = Arc::downgrade(&outage);
         let connect_options = ConnectOptions::new()
             .name(&config.name)
             .connection_timeout(config.connect_timeout)
-            .request_timeout(Some(config.request_timeout));
+            .request_timeout(Some(config.request_timeout))
+            .event_callback(move |event| {
+                let watched = watched.clone();
+                async move {
+                    let Some(outage) = watched.upgrade() else {
+                        return;
+                    };
+                    match event {
+                        async_nats::Event::Disconnected => outage.disconnected(),
+                        async_nats::Event::Connected => {
+                            if outage.reconnected() {
+                                tokio::spawn(async move { outage.replay().await });
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            });
 
         let client = async_nats::connect_with_options(config.servers.join(","), connect_options)
             .await
             .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?;
+        outage.set_client(client.clone());
 
         info!("Connected to NATS at {:?}", config.servers);
 
-        Ok(Self { client })
+        Ok(Self {
+            client,
+            outage,
+            registry: None,
+        })
+    }
+
+    /// Check every publish against `registry`
+    ///
+    /// Publishes on unregistered subjects, or with payloads that are none
+    /// of the subject's payload types, fail before reaching NATS.
+    pub fn with_subject_registry(mut self, registry: SubjectRegistry) -> Self {
+        self.registry = Some(Arc::new(registry));
+        self
     }
 
     /// Publish a message to a subject
+    ///
+    /// While disconnected the message is buffered and Ok returned; it fails
+    /// only when buffering is off or the buffer is full, or when the message
+    /// breaks the client's subject registry.
     pub async fn publish<T>(&self, subject: &str, message: &T) -> InfrastructureResult<()>
     where
         T: Serialize,
     {
         let payload = serde_json::to_vec(message)?;
+        if let Some(registry) = &self.registry {
+            registry
+                .validate(subject, &payload)
+                .map_err(|e| InfrastructureError::NatsPublish(e.to_string()))?;
+        }
+
+        let payload = match self.outage.hold(subject, payload)? {
+            Hold::Publish(payload) => payload,
+            Hold::Buffered { replay } => {
+                debug!("Buffered message to subject: {}", subject);
+                if replay {
+                    let outage = self.outage.clone();
+                    tokio::spawn(async move { outage.replay().await });
+                }
+                return Ok(());
+            }
+        };
 
-        self.client
-            .publish(subject.to_string(), payload.into())
+        if let Err(e) = self
+            .client
+            .publish(subject.to_string(), payload.clone().into())
             .await
-            .map_err(|e| InfrastructureError::NatsPublish(e.to_string()))?;
+        {
+            if self.outage.hold_failed(subject, payload)? {
+                warn!("Publish to {} failed, buffered for replay: {}", subject, e);
+                return Ok(());
+            }
+            return Err(InfrastructureError::NatsPublish(e.to_string()));
+        }
 
         debug!("Published message to subject: {}", subject);
         Ok(())
     }
 
+    /// Call `listener` on every disconnect, reconnect, replay and refused
+    /// message
+    pub fn on_connection_event(&self, listener: impl Fn(&ConnectionEvent) + Send + Sync + 'static) {
+        let listener: ConnectionListener = Arc::new(listener);
+        self.outage.add_listener(listener);
+    }
+
+    /// Connection and outage buffer counters
+    pub fn connection_metrics(&self) -> ConnectionMetrics {
+        self.outage.metrics()
+    }
+
     /// Subscribe to a subject
     pub async fn subscribe(&self, subject: &str) -> InfrastructureResult<Subscriber> {
         let subscriber = self
@@ -104,6 +201,31 @@ impl NatsClient {
         Ok(result)
     }
 
+    /// Request-reply pattern, sending `headers` with the request
+    pub async fn request_with_headers<T, R>(
+        &self,
+        subject: &str,
+        headers: HeaderMap,
+        request: &T,
+    ) -> InfrastructureResult<R>
+    where
+        T: Serialize,
+        R: for<'de> Deserialize<'de>,
+    {
+        let payload = serde_json::to_vec(request)?;
+
+        let response = self
+            .client
+            .request_with_headers(subject.to_string(), headers, payload.into())
+            .await
+            .map_err(|e| InfrastructureError::NatsPublish(e.to_string()))?;
+
+        let result: R = serde_json::from_slice(&response.payload)
+            .map_err(|e| InfrastructureError::Deserialization(e.to_string()))?;
+
+        Ok(result)
+    }
+
     /// Get the underlying NATS client for advanced operations
     pub fn inner(&self) -> &Client {
         &self.client
@@ -0,0 +1,132 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Conditional Writes to JetStream KV
+//!
+//! Leases, uniqueness claims, schedules and token buckets are KV entries
+//! that instances must not overwrite blindly: they are written only if
+//! absent, or only if unchanged since they were read. async-nats offers
+//! both as `update` with an expected revision, revision 0 meaning "no
+//! entry yet":
+//!
+//! ```text
+//! create_entry(key) ──update(key, 0)──> ok ──> Some(revision)
+//!                                  └──> key exists ──entry(key)──> vacant ──update(key, rev)
+//!                                                                  live ────> None
+//!
+//! update_entry(key, rev) ──update(key, rev)──> ok ──> Some(revision)
+//!                                         └──> key moved past rev ──> None
+//!
+//! clear_entry(key, rev) ──update(key, "", rev)──> ok ──> true
+//! ```
+//!
+//! An entry is vacant when it is a delete or purge marker or holds an
+//! empty value. [`clear_entry`] gives a key up by writing such an empty
+//! value, and only if nobody wrote the key since `rev`, which a plain KV
+//! delete cannot guarantee.
+//!
+//! The server reports a stale revision as a generic failure, so a failed
+//! write counts as lost to another writer only if the key really moved on;
+//! any other failure is returned as an error.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::nats::kv::{clear_entry, create_entry};
+//!
+//! if let Some(revision) = create_entry(&store, "lease.0193…", holder.into_bytes()).await? {
+//!     // ... the key is ours ...
+//!     clear_entry(&store, "lease.0193…", revision).await?;
+//! }
+//! ```
+
+use async_nats::jetstream::kv;
+
+use crate::errors::{InfrastructureError, InfrastructureResult};
+
+/// Write `value` under `key` unless the key holds a live entry
+///
+/// Returns the new revision, or `None` if the key is taken.
+pub async fn create_entry(
+    store: &kv::Store,
+    key: &str,
+    value: Vec<u8>,
+) -> InfrastructureResult<Option<u64>> {
+    if let Some(revision) = update_entry(store, key, value.clone(), 0).await? {
+        return Ok(Some(revision));
+    }
+
+    // The key has history; take it over if its last entry is vacant
+    match entry(store, key).await? {
+        Some(entry) if is_vacant(&entry.operation, &entry.value) => {
+            update_entry(store, key, value, entry.revision).await
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Write `value` under `key` if the key is still at `revision`
+///
+/// Returns the new revision, or `None` if another writer got there first.
+pub async fn update_entry(
+    store: &kv::Store,
+    key: &str,
+    value: Vec<u8>,
+    revision: u64,
+) -> InfrastructureResult<Option<u64>> {
+    match store.update(key, value.into(), revision).await {
+        Ok(revision) => Ok(Some(revision)),
+        Err(e) if e.kind() == kv::UpdateErrorKind::Other => {
+            let current = entry(store, key).await?.map_or(0, |entry| entry.revision);
+            if current != revision {
+                Ok(None)
+            } else {
+                Err(InfrastructureError::NatsPublish(e.to_string()))
+            }
+        }
+        Err(e) => Err(InfrastructureError::NatsPublish(e.to_string())),
+    }
+}
+
+/// Give up `key` if it is still at `revision`
+///
+/// Returns `false` if another writer changed the key since.
+pub async fn clear_entry(
+    store: &kv::Store,
+    key: &str,
+    revision: u64,
+) -> InfrastructureResult<bool> {
+    Ok(update_entry(store, key, Vec::new(), revision)
+        .await?
+        .is_some())
+}
+
+/// Whether an entry leaves its key free to create
+pub fn is_vacant(operation: &kv::Operation, value: &[u8]) -> bool {
+    match operation {
+        kv::Operation::Put => value.is_empty(),
+        kv::Operation::Delete | kv::Operation::Purge => true,
+    }
+}
+
+async fn entry(store: &kv::Store, key: &str) -> InfrastructureResult<Option<kv::Entry>> {
+    store
+        .entry(key)
+        .await
+        .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markers_and_cleared_values_are_vacant() {
+        assert!(is_vacant(&kv::Operation::Delete, b""));
+        assert!(is_vacant(&kv::Operation::Purge, b""));
+        assert!(is_vacant(&kv::Operation::Put, b""));
+    }
+
+    #[test]
+    fn test_written_values_are_live() {
+        assert!(!is_vacant(&kv::Operation::Put, b"api-1"));
+    }
+}
@@ -0,0 +1,372 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Buffered Publishing During NATS Outages
+//!
+//! While the connection is down, [`NatsClient::publish`](super::NatsClient::publish)
+//! holds messages in a bounded local queue instead of failing the caller,
+//! and replays them in order once the client has reconnected:
+//!
+//! ```text
+//!            connected                disconnected               reconnected
+//! publish ──────────────> NATS   publish ──> [queue] (bounded)   [queue] ──replay──> NATS
+//!                                             full? → NatsPublish error
+//! ```
+//!
+//! Messages published while a replay is pending queue up behind it, so
+//! subscribers see the original order. The queue lives in memory: messages
+//! still queued when the process exits are lost. Events are appended to
+//! the event store before they are published, so projections that catch
+//! up from the stream miss nothing; only live subscribers see a gap.
+//!
+//! The capacity is [`NatsConfig::outage_buffer`](super::NatsConfig::outage_buffer);
+//! 0 turns buffering off, and publishes during an outage fail as before.
+//!
+//! # Observing the connection
+//!
+//! ```rust,ignore
+//! client.on_connection_event(|event| match event {
+//!     ConnectionEvent::Disconnected => warn!("NATS down, buffering"),
+//!     ConnectionEvent::Replayed { published, .. } => info!("replayed {}", published),
+//!     _ => {}
+//! });
+//!
+//! let metrics = client.connection_metrics();
+//! gauge!("nats_buffered_messages", metrics.buffered as f64);
+//! ```
+
+use async_nats::Client;
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+use tracing::{info, warn};
+
+use crate::errors::{InfrastructureError, InfrastructureResult};
+
+/// Change of the connection or of the outage buffer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConnectionEvent {
+    /// Connection lost; publishes are buffered from now on
+    Disconnected,
+
+    /// Connection restored with messages waiting for replay
+    Reconnected {
+        /// Messages waiting
+        buffered: usize,
+    },
+
+    /// Buffered messages were published again
+    Replayed {
+        /// Messages published by this replay
+        published: usize,
+
+        /// Messages still waiting (replay stopped at a failure)
+        remaining: usize,
+    },
+
+    /// A message was refused because the buffer is full
+    BufferFull {
+        /// Subject of the refused message
+        subject: String,
+    },
+}
+
+/// Connection counters since the client was created
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct ConnectionMetrics {
+    /// Whether the client is connected now
+    pub connected: bool,
+
+    /// Connections lost
+    pub disconnects: u64,
+
+    /// Connections restored
+    pub reconnects: u64,
+
+    /// Messages waiting in the buffer now
+    pub buffered: usize,
+
+    /// Messages ever buffered
+    pub buffered_total: u64,
+
+    /// Messages published by replays
+    pub replayed_total: u64,
+
+    /// Messages refused because the buffer was full
+    pub refused_total: u64,
+}
+
+/// Callback receiving connection events
+pub type ConnectionListener = Arc<dyn Fn(&ConnectionEvent) + Send + Sync>;
+
+/// What [`OutageBuffer::hold`] decided for a message
+pub(crate) enum Hold {
+    /// Publish now
+    Publish(Vec<u8>),
+
+    /// Queued; start a replay if set
+    Buffered { replay: bool },
+}
+
+#[derive(Default)]
+struct BufferState {
+    queue: VecDeque<(String, Vec<u8>)>,
+    replaying: bool,
+    metrics: ConnectionMetrics,
+}
+
+/// Shared by a client's clones and its connection event callback
+pub(crate) struct OutageBuffer {
+    capacity: usize,
+    state: Mutex<BufferState>,
+    listeners: RwLock<Vec<ConnectionListener>>,
+    client: OnceLock<Client>,
+}
+
+impl OutageBuffer {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            state: Mutex::new(BufferState {
+                metrics: ConnectionMetrics {
+                    connected: true,
+                    ..ConnectionMetrics::default()
+                },
+                ..BufferState::default()
+            }),
+            listeners: RwLock::new(Vec::new()),
+            client: OnceLock::new(),
+        }
+    }
+
+    /// Client used for replays, set once the first connect succeeded
+    pub(crate) fn set_client(&self, client: Client) {
+        let _ = self.client.set(client);
+    }
+
+    pub(crate) fn add_listener(&self, listener: ConnectionListener) {
+        self.listeners
+            .write()
+            .expect("listener lock poisoned")
+            .push(listener);
+    }
+
+    pub(crate) fn metrics(&self) -> ConnectionMetrics {
+        let state = self.state.lock().expect("buffer lock poisoned");
+        ConnectionMetrics {
+            buffered: state.queue.len(),
+            ..state.metrics
+        }
+    }
+
+    fn emit(&self, event: ConnectionEvent) {
+        for listener in self
+            .listeners
+            .read()
+            .expect("listener lock poisoned")
+            .iter()
+        {
+            listener(&event);
+        }
+    }
+
+    pub(crate) fn disconnected(&self) {
+        {
+            let mut state = self.state.lock().expect("buffer lock poisoned");
+            if !state.metrics.connected {
+                return;
+            }
+            state.metrics.connected = false;
+            state.metrics.disconnects += 1;
+        }
+        warn!(
+            "NATS connection lost; buffering up to {} messages",
+            self.capacity
+        );
+        self.emit(ConnectionEvent::Disconnected);
+    }
+
+    /// Mark the connection restored; true when a replay should start
+    pub(crate) fn reconnected(&self) -> bool {
+        let buffered = {
+            let mut state = self.state.lock().expect("buffer lock poisoned");
+            if state.metrics.connected {
+                return false;
+            }
+            state.metrics.connected = true;
+            state.metrics.reconnects += 1;
+            state.queue.len()
+        };
+        info!("NATS connection restored; {} messages to replay", buffered);
+        self.emit(ConnectionEvent::Reconnected { buffered });
+        buffered > 0
+    }
+
+    /// Decide whether a message goes out now or waits in the buffer
+    pub(crate) fn hold(&self, subject: &str, payload: Vec<u8>) -> InfrastructureResult<Hold> {
+        let held = {
+            let mut state = self.state.lock().expect("buffer lock poisoned");
+            if self.capacity == 0 || (state.metrics.connected && state.queue.is_empty()) {
+                return Ok(Hold::Publish(payload));
+            }
+            self.push(&mut state, subject, payload)
+                .then(|| Hold::Buffered {
+                    replay: state.metrics.connected && !state.replaying,
+                })
+        };
+        held.ok_or_else(|| self.refused(subject))
+    }
+
+    /// Queue a message whose publish failed; false when buffering is off
+    pub(crate) fn hold_failed(
+        &self,
+        subject: &str,
+        payload: Vec<u8>,
+    ) -> InfrastructureResult<bool> {
+        if self.capacity == 0 {
+            return Ok(false);
+        }
+        let pushed = {
+            let mut state = self.state.lock().expect("buffer lock poisoned");
+            self.push(&mut state, subject, payload)
+        };
+        if pushed {
+            Ok(true)
+        } else {
+            Err(self.refused(subject))
+        }
+    }
+
+    /// Queue a message; false when the buffer is full
+    fn push(&self, state: &mut BufferState, subject: &str, payload: Vec<u8>) -> bool {
+        if state.queue.len() >= self.capacity {
+            state.metrics.refused_total += 1;
+            return false;
+        }
+        state.queue.push_back((subject.to_string(), payload));
+        state.metrics.buffered_total += 1;
+        true
+    }
+
+    fn refused(&self, subject: &str) -> InfrastructureError {
+        self.emit(ConnectionEvent::BufferFull {
+            subject: subject.to_string(),
+        });
+        InfrastructureError::NatsPublish(format!(
+            "NATS unavailable and outage buffer full ({} messages); {} not sent",
+            self.capacity, subject
+        ))
+    }
+
+    /// Publish buffered messages in order until the queue is empty or a
+    /// publish fails; only one replay runs at a time
+    pub(crate) async fn replay(&self) {
+        let Some(client) = self.client.get() else {
+            return;
+        };
+        {
+            let mut state = self.state.lock().expect("buffer lock poisoned");
+            if state.replaying {
+                return;
+            }
+            state.replaying = true;
+        }
+
+        let mut published = 0;
+        loop {
+            let next = {
+                let mut state = self.state.lock().expect("buffer lock poisoned");
+                if !state.metrics.connected {
+                    None
+                } else {
+                    state.queue.pop_front()
+                }
+            };
+            let Some((subject, payload)) = next else {
+                break;
+            };
+            if let Err(e) = client
+                .publish(subject.clone(), payload.clone().into())
+                .await
+            {
+                warn!("Replay of {} failed, keeping it buffered: {}", subject, e);
+                let mut state = self.state.lock().expect("buffer lock poisoned");
+                state.queue.push_front((subject, payload));
+                break;
+            }
+            published += 1;
+        }
+
+        let remaining = {
+            let mut state = self.state.lock().expect("buffer lock poisoned");
+            state.replaying = false;
+            state.metrics.replayed_total += published as u64;
+            state.queue.len()
+        };
+        if published > 0 || remaining > 0 {
+            info!(
+                "Replayed {} buffered NATS messages, {} remaining",
+                published, remaining
+            );
+            self.emit(ConnectionEvent::Replayed {
+                published,
+                remaining,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_outage_buffers_in_order_up_to_capacity() {
+        // Arrange
+        let buffer = OutageBuffer::new(2);
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let seen = events.clone();
+        buffer.add_listener(Arc::new(move |event: &ConnectionEvent| {
+            seen.lock().unwrap().push(event.clone())
+        }));
+
+        // Act
+        let before = buffer.hold("a", b"1".to_vec()).unwrap();
+        buffer.disconnected();
+        let first = buffer.hold("b", b"2".to_vec()).unwrap();
+        let second = buffer.hold("c", b"3".to_vec()).unwrap();
+        let refused = buffer.hold("d", b"4".to_vec());
+        let replay = buffer.reconnected();
+        let behind = buffer.hold("e", b"5".to_vec());
+
+        // Assert
+        assert!(matches!(before, Hold::Publish(_)));
+        assert!(matches!(first, Hold::Buffered { replay: false }));
+        assert!(matches!(second, Hold::Buffered { replay: false }));
+        assert!(matches!(refused, Err(InfrastructureError::NatsPublish(_))));
+        assert!(replay);
+        assert!(behind.is_err(), "queued messages keep the buffer full");
+
+        let metrics = buffer.metrics();
+        assert_eq!(metrics.buffered, 2);
+        assert_eq!((metrics.disconnects, metrics.reconnects), (1, 1));
+        assert_eq!(metrics.refused_total, 2);
+        let queued: Vec<_> = buffer
+            .state
+            .lock()
+            .unwrap()
+            .queue
+            .iter()
+            .map(|(subject, _)| subject.clone())
+            .collect();
+        assert_eq!(queued, ["b", "c"]);
+        assert_eq!(
+            events.lock().unwrap()[..3],
+            [
+                ConnectionEvent::Disconnected,
+                ConnectionEvent::BufferFull {
+                    subject: "d".to_string()
+                },
+                ConnectionEvent::Reconnected { buffered: 2 },
+            ]
+        );
+    }
+}
@@ -0,0 +1,205 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Publishing and Watching Operation Progress
+//!
+//! [`ProgressReporter`] wraps a [`ProgressTracker`] for subsystems doing slow
+//! work: it stamps reports with the clock and publishes them, throttled, on
+//! `infrastructure.progress.<operation_id>`. [`watch_progress`] is the other
+//! side, for callers (CLIs, UIs) following one run.
+//!
+//! Progress is best effort. A failed publish is logged and never fails the
+//! operation being reported.
+//!
+//! # Cancellation
+//!
+//! Long-running operations take a [`CancellationToken`] and check it
+//! between work items. A cancelled run stops at the next safe point, ends
+//! with a `cancelled` report ([`ProgressReporter::cancel`]) and returns a
+//! cancellation error; work already done is kept.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::nats::progress::{watch_progress, ProgressReporter};
+//!
+//! // Worker
+//! let mut progress = ProgressReporter::new("bulk_import").with_client(nats_client.clone());
+//! progress.start(Some(rows.len() as u64)).await;
+//! for row in rows {
+//!     if cancel.is_cancelled() {
+//!         progress.cancel().await;
+//!         return Err(InfrastructureError::Cancelled("bulk_import".into()));
+//!     }
+//!     import(row).await?;
+//!     progress.advance(1).await;
+//! }
+//! progress.complete().await;
+//!
+//! // Caller
+//! let mut reports = Box::pin(watch_progress(&nats_client, operation_id).await?);
+//! while let Some(report) = reports.next().await {
+//!     println!("{}", report);
+//! }
+//! ```
+
+use chrono::Utc;
+use futures::{future, Stream, StreamExt};
+use std::time::{Duration, Instant};
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+use crate::errors::InfrastructureResult;
+use crate::events::progress::{OperationProgress, ProgressTracker};
+use crate::nats::NatsClient;
+use crate::subjects::subjects;
+
+pub use tokio_util::sync::CancellationToken;
+
+/// Default minimum time between two running reports
+pub const DEFAULT_REPORT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Tracks one run of an operation and publishes its progress
+pub struct ProgressReporter {
+    client: Option<NatsClient>,
+    tracker: ProgressTracker,
+    interval: Duration,
+    last_report: Option<Instant>,
+}
+
+impl ProgressReporter {
+    /// Track a new run of `operation`
+    ///
+    /// Without a client (see [`ProgressReporter::with_client`]) progress is
+    /// tracked and logged but not published.
+    pub fn new(operation: impl Into<String>) -> Self {
+        Self::with_id(Uuid::now_v7(), operation)
+    }
+
+    /// Track a run under an ID chosen by the caller
+    ///
+    /// Lets the caller subscribe with [`watch_progress`] before the run
+    /// publishes its first report.
+    pub fn with_id(operation_id: Uuid, operation: impl Into<String>) -> Self {
+        Self {
+            client: None,
+            tracker: ProgressTracker::new(operation_id, operation, Utc::now()),
+            interval: DEFAULT_REPORT_INTERVAL,
+            last_report: None,
+        }
+    }
+
+    /// Publish reports through `client`
+    pub fn with_client(mut self, client: NatsClient) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Name what the operation works on
+    pub fn with_scope(mut self, scope: impl Into<String>) -> Self {
+        self.tracker = self.tracker.with_scope(scope);
+        self
+    }
+
+    /// Minimum time between two running reports
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// ID to pass to [`watch_progress`]
+    pub fn operation_id(&self) -> Uuid {
+        self.tracker.operation_id()
+    }
+
+    /// Underlying counters
+    pub fn tracker(&self) -> &ProgressTracker {
+        &self.tracker
+    }
+
+    /// Publish the first report, with the total if it is known
+    pub async fn start(&mut self, total: Option<u64>) {
+        if let Some(total) = total {
+            self.tracker.set_total(total);
+        }
+        self.publish(self.tracker.running(Utc::now())).await;
+    }
+
+    /// Count finished work items; publishes at most once per interval
+    pub async fn advance(&mut self, items: u64) {
+        self.tracker.advance(items);
+        self.publish_if_due().await;
+    }
+
+    /// Record a non-fatal error
+    pub async fn error(&mut self, message: impl Into<String>) {
+        self.tracker.record_error(message);
+        self.publish_if_due().await;
+    }
+
+    /// Publish the final report of a finished run
+    pub async fn complete(mut self) {
+        self.publish(self.tracker.finished(Utc::now())).await;
+    }
+
+    /// Publish the final report of an aborted run
+    pub async fn fail(mut self, message: impl Into<String>) {
+        self.publish(self.tracker.failed(message, Utc::now())).await;
+    }
+
+    /// Publish the final report of a run stopped on request
+    pub async fn cancel(mut self) {
+        self.publish(self.tracker.cancelled(Utc::now())).await;
+    }
+
+    async fn publish_if_due(&mut self) {
+        let due = match self.last_report {
+            Some(last) => last.elapsed() >= self.interval,
+            None => true,
+        };
+
+        if due {
+            self.publish(self.tracker.running(Utc::now())).await;
+        }
+    }
+
+    async fn publish(&mut self, report: OperationProgress) {
+        self.last_report = Some(Instant::now());
+        debug!("{}", report);
+
+        let Some(client) = &self.client else {
+            return;
+        };
+
+        if let Err(e) = client.publish(&report.subject(), &report).await {
+            warn!(
+                "Failed to publish progress of operation {}: {}",
+                report.operation_id, e
+            );
+        }
+    }
+}
+
+/// Follow the progress reports of one run
+///
+/// The stream ends after the `completed`, `failed` or `cancelled` report. Subscribe
+/// before starting the operation, or early reports are missed; reports that
+/// cannot be decoded are skipped.
+pub async fn watch_progress(
+    client: &NatsClient,
+    operation_id: Uuid,
+) -> InfrastructureResult<impl Stream<Item = OperationProgress>> {
+    let subscriber = client
+        .subscribe(&subjects::progress(&operation_id.to_string()))
+        .await?;
+
+    Ok(subscriber
+        .filter_map(|message| {
+            future::ready(serde_json::from_slice::<OperationProgress>(&message.payload).ok())
+        })
+        .scan(false, |finished, report| {
+            if *finished {
+                return future::ready(None);
+            }
+            *finished = report.status.is_finished();
+            future::ready(Some(report))
+        }))
+}
@@ -0,0 +1,1141 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Read-Model Queries over NATS
+//!
+//! Serves read models over NATS request/reply so services that are not
+//! linked against this crate (and have no database connection) can read
+//! current state.
+//!
+//! # Subjects
+//!
+//! ```text
+//! infrastructure.query.compute.get     {"aggregate_id": "0193…"}
+//! infrastructure.query.topology.view   {"root": "0193…", "depth": 1}
+//! infrastructure.query.scorecard.get   {"organization_id": "0193…"}
+//! infrastructure.query.compute.enriched {"aggregate_ids": ["0193…", …]}
+//! infrastructure.query.archival.candidates {"organization_id": "0193…"}
+//! infrastructure.query.compute.list    {"filter": {"status": "active"}, "page": 1, "page_size": 50}
+//! ```
+//!
+//! # Replies
+//!
+//! ```text
+//! {"status": "ok",    "result": { …ComputeResourceState, TopologyView, Scorecard, ResourcePage, [EnrichedResource] or [StaleAggregate]… }}
+//! {"status": "error", "code": "not_found", "message": "…"}
+//! ```
+//!
+//! Query subjects are never captured by the event stream; see
+//! [`subjects::stream_subjects`].
+//!
+//! # Tenancy
+//!
+//! The responder answers through a [`ScopedReadModel`], so every query is
+//! limited to the organization it is sent for (see
+//! [`tenancy`](crate::tenancy)): resources of other organizations are not
+//! found, listings only cover the acting organization, and topology views
+//! leave out other organizations' nodes. With an [`Authenticator`], the
+//! `Authorization: Bearer` header of each request identifies its sender and
+//! organization; requests it cannot identify fail as `unauthenticated`.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::nats::query::{QueryResponder, ServiceReadModel};
+//!
+//! let read_model = ServiceReadModel::new(Arc::new(service));
+//! QueryResponder::new(nats_client, Arc::new(read_model))
+//!     .with_authenticator(Arc::new(authenticator))
+//!     .run()
+//!     .await?;
+//! ```
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Arc;
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+use crate::aggregate::{ComputeResourceState, ConnectionState};
+use crate::archival::StaleAggregate;
+use crate::enrichment::EnrichedResource;
+use crate::errors::{InfrastructureError, InfrastructureResult};
+use crate::event_store::provenance::EventEnvelopeMetadata;
+use crate::nats::NatsClient;
+use crate::projection::registry::{ResourceFilter, ResourcePage};
+use crate::scorecard::Scorecard;
+use crate::service::authentication::{
+    bearer_token, AuthenticationError, Authenticator, AUTHORIZATION_HEADER,
+};
+use crate::service::{ComputeResourceService, ServiceError};
+use crate::subjects::subjects;
+use crate::tenancy::{TenancyConfig, TenancyError};
+
+/// Query a read model failed
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum QueryError {
+    /// Requested entity does not exist
+    #[error("Not found: {0}")]
+    NotFound(String),
+
+    /// Read model does not answer this query
+    #[error("Unsupported query: {0}")]
+    Unsupported(String),
+
+    /// Request payload could not be decoded
+    #[error("Malformed query: {0}")]
+    Malformed(String),
+
+    /// Backing store failed
+    #[error("Read model unavailable: {0}")]
+    Unavailable(String),
+
+    /// Sender could not be identified
+    #[error("Unauthenticated: {0}")]
+    Unauthenticated(String),
+
+    /// Sender may not make this query
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+}
+
+impl QueryError {
+    /// Error code sent in replies
+    pub fn code(&self) -> &'static str {
+        match self {
+            QueryError::NotFound(_) => "not_found",
+            QueryError::Unsupported(_) => "unsupported",
+            QueryError::Malformed(_) => "malformed",
+            QueryError::Unavailable(_) => "unavailable",
+            QueryError::Unauthenticated(_) => "unauthenticated",
+            QueryError::Forbidden(_) => "forbidden",
+        }
+    }
+
+    /// Query error for a sender that could not be authenticated
+    pub fn from_authentication_error(error: &AuthenticationError) -> Self {
+        match error {
+            AuthenticationError::Missing | AuthenticationError::Invalid => {
+                QueryError::Unauthenticated(error.to_string())
+            }
+            AuthenticationError::Unavailable(_) => QueryError::Unavailable(error.to_string()),
+        }
+    }
+}
+
+/// Request for `infrastructure.query.compute.get`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct GetComputeResource {
+    /// Resource to fetch
+    pub aggregate_id: Uuid,
+}
+
+/// Request for `infrastructure.query.topology.view`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct TopologyQuery {
+    /// Resource at the centre of the view
+    pub root: Uuid,
+
+    /// Relationship hops to include from the root
+    #[serde(default = "default_depth")]
+    pub depth: u32,
+}
+
+/// Request for `infrastructure.query.scorecard.get`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct GetScorecard {
+    /// Organization to fetch; omit for resources without an organization
+    #[serde(default)]
+    pub organization_id: Option<String>,
+}
+
+/// Request for `infrastructure.query.compute.enriched`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct GetEnrichedResources {
+    /// Resources to fetch; unknown IDs are left out of the result
+    pub aggregate_ids: Vec<Uuid>,
+}
+
+/// Request for `infrastructure.query.archival.candidates`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct GetArchivalCandidates {
+    /// Organization to list; omit for every organization
+    #[serde(default)]
+    pub organization_id: Option<String>,
+}
+
+/// Request for `infrastructure.query.compute.list`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ListResources {
+    /// Resources to include; omit for every resource not decommissioned
+    #[serde(default)]
+    pub filter: ResourceFilter,
+
+    /// Page number, from 1
+    #[serde(default = "default_page")]
+    pub page: u32,
+
+    /// Resources per page, capped at
+    /// [`MAX_PAGE_SIZE`](crate::projection::registry::MAX_PAGE_SIZE)
+    #[serde(default = "default_page_size")]
+    pub page_size: u32,
+}
+
+fn default_depth() -> u32 {
+    1
+}
+
+fn default_page() -> u32 {
+    1
+}
+
+fn default_page_size() -> u32 {
+    50
+}
+
+/// Kind of node in a topology view
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[serde(rename_all = "snake_case")]
+pub enum TopologyNodeKind {
+    /// Compute resource
+    ComputeResource,
+
+    /// Owning organization
+    Organization,
+
+    /// Physical location
+    Location,
+
+    /// Owner / primary contact
+    Person,
+
+    /// Applied policy
+    Policy,
+}
+
+/// Node in a topology view
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+pub struct TopologyNode {
+    /// Node identifier
+    pub id: String,
+
+    /// What the node represents
+    pub kind: TopologyNodeKind,
+
+    /// Display label
+    pub label: String,
+}
+
+/// Directed relationship in a topology view
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+pub struct TopologyEdge {
+    /// Source node ID
+    pub from: String,
+
+    /// Target node ID
+    pub to: String,
+
+    /// Relationship type (graph naming, e.g. `LOCATED_AT`)
+    pub relationship: String,
+}
+
+/// Relationship of a live cable between two compute resources
+pub const CABLED_TO: &str = "CABLED_TO";
+
+/// Subgraph around a resource
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+pub struct TopologyView {
+    /// Node the view is centred on
+    pub root: String,
+
+    /// Nodes in the view, root first
+    pub nodes: Vec<TopologyNode>,
+
+    /// Relationships between nodes
+    pub edges: Vec<TopologyEdge>,
+}
+
+impl TopologyView {
+    /// Direct relationships of one resource, derived from its state
+    pub fn from_resource(state: &ComputeResourceState) -> Self {
+        let root = state.id.to_string();
+        let mut view = Self {
+            root: root.clone(),
+            nodes: vec![TopologyNode {
+                id: root,
+                kind: TopologyNodeKind::ComputeResource,
+                label: state.hostname.to_string(),
+            }],
+            edges: Vec::new(),
+        };
+
+        if let Some(organization_id) = &state.organization_id {
+            view.link(
+                organization_id.to_string(),
+                TopologyNodeKind::Organization,
+                "OWNED_BY",
+            );
+        }
+        if let Some(location_id) = &state.location_id {
+            view.link(
+                location_id.to_string(),
+                TopologyNodeKind::Location,
+                "LOCATED_AT",
+            );
+        }
+        if let Some(owner_id) = &state.owner_id {
+            view.link(owner_id.to_string(), TopologyNodeKind::Person, "MANAGED_BY");
+        }
+        for policy_id in &state.policy_ids {
+            view.link(policy_id.to_string(), TopologyNodeKind::Policy, "ENFORCES");
+        }
+
+        view
+    }
+
+    /// Every resource and the live cables between them
+    ///
+    /// The view is not centred on a resource, so `root` is empty. Each live
+    /// connection becomes a [`CABLED_TO`] edge from its A-end resource to
+    /// its B-end resource; cables between two ports of the same resource are
+    /// left out. Resources only known from a cable endpoint are labelled
+    /// with the device name the cable carries.
+    pub fn connectivity<'a>(
+        resources: impl IntoIterator<Item = &'a ComputeResourceState>,
+        connections: impl IntoIterator<Item = &'a ConnectionState>,
+    ) -> Self {
+        let mut nodes: HashMap<Uuid, String> = resources
+            .into_iter()
+            .map(|state| (state.id, state.hostname.to_string()))
+            .collect();
+
+        let mut edges = Vec::new();
+        for connection in connections.into_iter().filter(|c| c.is_live()) {
+            let (Some(a_end), Some(b_end)) = (&connection.a_end, &connection.b_end) else {
+                continue;
+            };
+            if a_end.resource_id == b_end.resource_id {
+                continue;
+            }
+            for end in [a_end, b_end] {
+                nodes
+                    .entry(end.resource_id)
+                    .or_insert_with(|| end.device.to_string());
+            }
+            edges.push(TopologyEdge {
+                from: a_end.resource_id.to_string(),
+                to: b_end.resource_id.to_string(),
+                relationship: CABLED_TO.to_string(),
+            });
+        }
+
+        let mut nodes: Vec<TopologyNode> = nodes
+            .into_iter()
+            .map(|(id, label)| TopologyNode {
+                id: id.to_string(),
+                kind: TopologyNodeKind::ComputeResource,
+                label,
+            })
+            .collect();
+        nodes.sort_by(|a, b| a.label.cmp(&b.label).then(a.id.cmp(&b.id)));
+
+        Self {
+            root: String::new(),
+            nodes,
+            edges,
+        }
+    }
+
+    /// Shortest cable path between two resources, both ends included
+    ///
+    /// None when either resource is not in the view or no path exists.
+    pub fn path_between(&self, resource_a: Uuid, resource_b: Uuid) -> Option<Vec<&TopologyNode>> {
+        let nodes = self.node_index();
+        let start = nodes.get(resource_a.to_string().as_str())?.id.as_str();
+        let goal = nodes.get(resource_b.to_string().as_str())?.id.as_str();
+
+        let cabling = self.cabling();
+        let mut previous: HashMap<&str, &str> = HashMap::new();
+        let mut queue = VecDeque::from([start]);
+        let mut seen = HashSet::from([start]);
+        while let Some(id) = queue.pop_front() {
+            if id == goal {
+                let mut path = vec![nodes[id]];
+                let mut current = id;
+                while let Some(&hop) = previous.get(current) {
+                    path.push(nodes[hop]);
+                    current = hop;
+                }
+                path.reverse();
+                return Some(path);
+            }
+            for &next in cabling.get(id).into_iter().flatten() {
+                if nodes.contains_key(next) && seen.insert(next) {
+                    previous.insert(next, id);
+                    queue.push_back(next);
+                }
+            }
+        }
+        None
+    }
+
+    /// Resources reachable from `resource` over cables, nearest first
+    ///
+    /// The resource itself is not included.
+    pub fn reachable_from(&self, resource: Uuid) -> Vec<&TopologyNode> {
+        let nodes = self.node_index();
+        let Some(start) = nodes.get(resource.to_string().as_str()) else {
+            return Vec::new();
+        };
+
+        let cabling = self.cabling();
+        let mut reachable = Vec::new();
+        let mut queue = VecDeque::from([start.id.as_str()]);
+        let mut seen = HashSet::from([start.id.as_str()]);
+        while let Some(id) = queue.pop_front() {
+            for &next in cabling.get(id).into_iter().flatten() {
+                if nodes.contains_key(next) && seen.insert(next) {
+                    reachable.push(nodes[next]);
+                    queue.push_back(next);
+                }
+            }
+        }
+        reachable
+    }
+
+    /// Compute resources without a cable to another resource
+    pub fn isolated_resources(&self) -> Vec<&TopologyNode> {
+        let cabling = self.cabling();
+        self.nodes
+            .iter()
+            .filter(|node| node.kind == TopologyNodeKind::ComputeResource)
+            .filter(|node| !cabling.contains_key(node.id.as_str()))
+            .collect()
+    }
+
+    fn node_index(&self) -> HashMap<&str, &TopologyNode> {
+        self.nodes
+            .iter()
+            .map(|node| (node.id.as_str(), node))
+            .collect()
+    }
+
+    /// Cable adjacency between resources, ignoring direction
+    fn cabling(&self) -> HashMap<&str, Vec<&str>> {
+        let mut cabling: HashMap<&str, Vec<&str>> = HashMap::new();
+        for edge in self.edges.iter().filter(|e| e.relationship == CABLED_TO) {
+            cabling.entry(&edge.from).or_default().push(&edge.to);
+            cabling.entry(&edge.to).or_default().push(&edge.from);
+        }
+        cabling
+    }
+
+    fn link(&mut self, id: String, kind: TopologyNodeKind, relationship: &str) {
+        self.edges.push(TopologyEdge {
+            from: self.root.clone(),
+            to: id.clone(),
+            relationship: relationship.to_string(),
+        });
+        self.nodes.push(TopologyNode {
+            label: id.clone(),
+            id,
+            kind,
+        });
+    }
+}
+
+/// Read side answering queries
+#[async_trait]
+pub trait ReadModel: Send + Sync {
+    /// Current state of a compute resource
+    async fn compute_resource(
+        &self,
+        aggregate_id: Uuid,
+    ) -> Result<ComputeResourceState, QueryError>;
+
+    /// Topology around a resource
+    async fn topology(&self, query: &TopologyQuery) -> Result<TopologyView, QueryError> {
+        Err(QueryError::Unsupported(format!(
+            "topology view (root {})",
+            query.root
+        )))
+    }
+
+    /// Latest scorecard of an organization
+    async fn scorecard(&self, query: &GetScorecard) -> Result<Scorecard, QueryError> {
+        Err(QueryError::Unsupported(format!(
+            "scorecard ({})",
+            query.organization_id.as_deref().unwrap_or("unassigned")
+        )))
+    }
+
+    /// Resources joined with their organization and owner names
+    async fn enriched_resources(
+        &self,
+        query: &GetEnrichedResources,
+    ) -> Result<Vec<EnrichedResource>, QueryError> {
+        Err(QueryError::Unsupported(format!(
+            "enriched resources ({} requested)",
+            query.aggregate_ids.len()
+        )))
+    }
+
+    /// Aggregates idle long enough to be archived
+    async fn archival_candidates(
+        &self,
+        query: &GetArchivalCandidates,
+    ) -> Result<Vec<StaleAggregate>, QueryError> {
+        Err(QueryError::Unsupported(format!(
+            "archival candidates ({})",
+            query.organization_id.as_deref().unwrap_or("all")
+        )))
+    }
+
+    /// One page of the resources matching a filter
+    async fn list_resources(&self, query: &ListResources) -> Result<ResourcePage, QueryError> {
+        Err(QueryError::Unsupported(format!(
+            "resource listing (page {})",
+            query.page
+        )))
+    }
+}
+
+/// Read model backed by the event-sourced service
+///
+/// Reconstructs state from the event store on every query. Topology views
+/// cover direct relationships only (depth 1).
+pub struct ServiceReadModel<S> {
+    service: Arc<S>,
+}
+
+impl<S> ServiceReadModel<S> {
+    /// Serve queries from `service`
+    pub fn new(service: Arc<S>) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait]
+impl<S: ComputeResourceService> ReadModel for ServiceReadModel<S> {
+    async fn compute_resource(
+        &self,
+        aggregate_id: Uuid,
+    ) -> Result<ComputeResourceState, QueryError> {
+        self.service
+            .get_resource(aggregate_id)
+            .await
+            .map_err(|e| match e {
+                ServiceError::NotFound(id) => QueryError::NotFound(id.to_string()),
+                other => QueryError::Unavailable(other.to_string()),
+            })
+    }
+
+    async fn topology(&self, query: &TopologyQuery) -> Result<TopologyView, QueryError> {
+        let state = self.compute_resource(query.root).await?;
+        Ok(TopologyView::from_resource(&state))
+    }
+
+    async fn list_resources(&self, query: &ListResources) -> Result<ResourcePage, QueryError> {
+        self.service
+            .list_resources(&query.filter, query.page, query.page_size)
+            .await
+            .map_err(|e| QueryError::Unavailable(e.to_string()))
+    }
+}
+
+/// Read model limited to the organization of the current request
+///
+/// Answers from `inner` for the tenant of the current
+/// [`EventEnvelopeMetadata`]: resources of other organizations are not
+/// found, listings, scorecards and archival candidates are limited to the
+/// acting organization, and topology views drop the resources and
+/// organizations of others. Without an acting organization every resource
+/// is visible, unless the [`TenancyConfig`] is strict.
+pub struct ScopedReadModel<R> {
+    inner: Arc<R>,
+    tenancy: TenancyConfig,
+}
+
+impl<R> ScopedReadModel<R> {
+    /// Scope queries against `inner`
+    pub fn new(inner: Arc<R>) -> Self {
+        Self {
+            inner,
+            tenancy: TenancyConfig::default(),
+        }
+    }
+
+    /// Scope queries by `tenancy`
+    pub fn with_tenancy(mut self, tenancy: TenancyConfig) -> Self {
+        self.tenancy = tenancy;
+        self
+    }
+
+    fn acting() -> Option<String> {
+        EventEnvelopeMetadata::current().and_then(|envelope| envelope.tenant)
+    }
+
+    /// Organization a listing filtered on `requested` is limited to
+    fn scope_listing(&self, requested: Option<&str>) -> Result<Option<String>, QueryError> {
+        self.tenancy
+            .scope_listing(Self::acting().as_deref(), requested)
+            .map_err(scope_error)
+    }
+
+    fn check_read(&self, state: &ComputeResourceState) -> Result<(), QueryError> {
+        self.tenancy
+            .check_read(Self::acting().as_deref(), state)
+            .map_err(scope_error)
+    }
+}
+
+#[async_trait]
+impl<R: ReadModel> ReadModel for ScopedReadModel<R> {
+    async fn compute_resource(
+        &self,
+        aggregate_id: Uuid,
+    ) -> Result<ComputeResourceState, QueryError> {
+        let state = self.inner.compute_resource(aggregate_id).await?;
+        self.check_read(&state)?;
+        Ok(state)
+    }
+
+    async fn topology(&self, query: &TopologyQuery) -> Result<TopologyView, QueryError> {
+        self.compute_resource(query.root).await?;
+        let mut view = self.inner.topology(query).await?;
+
+        let acting = Self::acting();
+        let mut hidden = HashSet::new();
+        for node in &view.nodes {
+            let visible = match node.kind {
+                TopologyNodeKind::ComputeResource if node.id != view.root => {
+                    match Uuid::parse_str(&node.id) {
+                        Ok(id) => self.compute_resource(id).await.is_ok(),
+                        Err(_) => false,
+                    }
+                }
+                TopologyNodeKind::Organization => {
+                    acting.is_none() || acting.as_deref() == Some(node.id.as_str())
+                }
+                _ => true,
+            };
+            if !visible {
+                hidden.insert(node.id.clone());
+            }
+        }
+
+        view.nodes.retain(|node| !hidden.contains(&node.id));
+        view.edges
+            .retain(|edge| !hidden.contains(&edge.from) && !hidden.contains(&edge.to));
+        Ok(view)
+    }
+
+    async fn scorecard(&self, query: &GetScorecard) -> Result<Scorecard, QueryError> {
+        let query = GetScorecard {
+            organization_id: self.scope_listing(query.organization_id.as_deref())?,
+        };
+        self.inner.scorecard(&query).await
+    }
+
+    async fn enriched_resources(
+        &self,
+        query: &GetEnrichedResources,
+    ) -> Result<Vec<EnrichedResource>, QueryError> {
+        let mut enriched = self.inner.enriched_resources(query).await?;
+        enriched.retain(|resource| self.check_read(&resource.resource).is_ok());
+        Ok(enriched)
+    }
+
+    async fn archival_candidates(
+        &self,
+        query: &GetArchivalCandidates,
+    ) -> Result<Vec<StaleAggregate>, QueryError> {
+        let query = GetArchivalCandidates {
+            organization_id: self.scope_listing(query.organization_id.as_deref())?,
+        };
+        self.inner.archival_candidates(&query).await
+    }
+
+    async fn list_resources(&self, query: &ListResources) -> Result<ResourcePage, QueryError> {
+        let mut query = query.clone();
+        query.filter.organization_id =
+            self.scope_listing(query.filter.organization_id.as_deref())?;
+        self.inner.list_resources(&query).await
+    }
+}
+
+/// Query error for a read tenancy refused
+///
+/// Resources of other organizations are reported as not found, so a
+/// sender cannot learn which organization owns them.
+fn scope_error(error: TenancyError) -> QueryError {
+    match error {
+        TenancyError::ForeignResource { aggregate_id, .. }
+        | TenancyError::ForeignAssignment { aggregate_id, .. } => {
+            QueryError::NotFound(aggregate_id.to_string())
+        }
+        other => QueryError::Forbidden(other.to_string()),
+    }
+}
+
+/// Reply sent on the request's reply subject
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum QueryReply {
+    /// Query answered
+    Ok {
+        /// The requested read model, as JSON
+        result: serde_json::Value,
+    },
+
+    /// Query failed
+    Error {
+        /// Error code (see [`QueryError::code`])
+        code: String,
+
+        /// Human-readable detail
+        message: String,
+    },
+}
+
+impl QueryReply {
+    fn from_result<T: Serialize>(result: Result<T, QueryError>) -> Self {
+        match result.and_then(|value| {
+            serde_json::to_value(value).map_err(|e| QueryError::Unavailable(e.to_string()))
+        }) {
+            Ok(result) => QueryReply::Ok { result },
+            Err(e) => QueryReply::Error {
+                code: e.code().to_string(),
+                message: e.to_string(),
+            },
+        }
+    }
+}
+
+/// Serves read-model queries on `infrastructure.query.>`
+pub struct QueryResponder<R> {
+    client: NatsClient,
+    read_model: ScopedReadModel<R>,
+    authenticator: Option<Arc<dyn Authenticator>>,
+}
+
+impl<R: ReadModel> QueryResponder<R> {
+    /// Create a responder backed by `read_model`, scoped to the organization
+    /// of each request
+    pub fn new(client: NatsClient, read_model: Arc<R>) -> Self {
+        Self {
+            client,
+            read_model: ScopedReadModel::new(read_model),
+            authenticator: None,
+        }
+    }
+
+    /// Scope queries by `tenancy`
+    pub fn with_tenancy(mut self, tenancy: TenancyConfig) -> Self {
+        self.read_model = self.read_model.with_tenancy(tenancy);
+        self
+    }
+
+    /// Answer each query for the sender `authenticator` identifies from the
+    /// request's `Authorization` header, refusing requests it cannot identify
+    ///
+    /// Without one, queries are answered for the responder's own envelope.
+    pub fn with_authenticator(mut self, authenticator: Arc<dyn Authenticator>) -> Self {
+        self.authenticator = Some(authenticator);
+        self
+    }
+
+    /// Answer one query
+    pub async fn handle(&self, subject: &str, payload: &[u8]) -> QueryReply {
+        let compute_get = subjects::query("compute", "get");
+        let topology_view = subjects::query("topology", "view");
+        let scorecard_get = subjects::query("scorecard", "get");
+        let compute_enriched = subjects::query("compute", "enriched");
+        let archival_candidates = subjects::query("archival", "candidates");
+        let compute_list = subjects::query("compute", "list");
+
+        if subject == compute_get {
+            match decode::<GetComputeResource>(payload) {
+                Ok(request) => QueryReply::from_result(
+                    self.read_model.compute_resource(request.aggregate_id).await,
+                ),
+                Err(e) => QueryReply::from_result::<()>(Err(e)),
+            }
+        } else if subject == topology_view {
+            match decode::<TopologyQuery>(payload) {
+                Ok(query) => QueryReply::from_result(self.read_model.topology(&query).await),
+                Err(e) => QueryReply::from_result::<()>(Err(e)),
+            }
+        } else if subject == scorecard_get {
+            match decode::<GetScorecard>(payload) {
+                Ok(query) => QueryReply::from_result(self.read_model.scorecard(&query).await),
+                Err(e) => QueryReply::from_result::<()>(Err(e)),
+            }
+        } else if subject == compute_enriched {
+            match decode::<GetEnrichedResources>(payload) {
+                Ok(query) => {
+                    QueryReply::from_result(self.read_model.enriched_resources(&query).await)
+                }
+                Err(e) => QueryReply::from_result::<()>(Err(e)),
+            }
+        } else if subject == archival_candidates {
+            match decode::<GetArchivalCandidates>(payload) {
+                Ok(query) => {
+                    QueryReply::from_result(self.read_model.archival_candidates(&query).await)
+                }
+                Err(e) => QueryReply::from_result::<()>(Err(e)),
+            }
+        } else if subject == compute_list {
+            match decode::<ListResources>(payload) {
+                Ok(query) => QueryReply::from_result(self.read_model.list_resources(&query).await),
+                Err(e) => QueryReply::from_result::<()>(Err(e)),
+            }
+        } else {
+            QueryReply::from_result::<()>(Err(QueryError::Unsupported(subject.to_string())))
+        }
+    }
+
+    /// Answer queries until the subscription ends
+    pub async fn run(&self) -> InfrastructureResult<()> {
+        use futures::StreamExt;
+
+        let filter = subjects::all_queries();
+        let mut subscriber = self.client.subscribe(&filter).await?;
+        info!("Query responder listening on {}", filter);
+
+        while let Some(message) = subscriber.next().await {
+            let Some(reply_to) = message.reply.clone() else {
+                debug!("Query on {} had no reply subject", message.subject);
+                continue;
+            };
+
+            let reply = match self.sender(&message).await {
+                Ok(Some(envelope)) => {
+                    envelope
+                        .scope(self.handle(message.subject.as_ref(), &message.payload))
+                        .await
+                }
+                Ok(None) => {
+                    self.handle(message.subject.as_ref(), &message.payload)
+                        .await
+                }
+                Err(e) => QueryReply::from_result::<()>(Err(e)),
+            };
+            if let QueryReply::Error {
+                code,
+                message: detail,
+            } = &reply
+            {
+                warn!("Query on {} failed ({}): {}", message.subject, code, detail);
+            }
+
+            let payload = serde_json::to_vec(&reply)
+                .map_err(|e| InfrastructureError::Serialization(e.to_string()))?;
+            self.client
+                .inner()
+                .publish(reply_to, payload.into())
+                .await
+                .map_err(|e| InfrastructureError::NatsPublish(e.to_string()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Envelope of the sender of `message`, if queries are authenticated
+    async fn sender(
+        &self,
+        message: &async_nats::Message,
+    ) -> Result<Option<EventEnvelopeMetadata>, QueryError> {
+        let Some(authenticator) = &self.authenticator else {
+            return Ok(None);
+        };
+        let token = message
+            .headers
+            .as_ref()
+            .and_then(|headers| headers.get(AUTHORIZATION_HEADER))
+            .and_then(|value| bearer_token(value.as_str()));
+
+        authenticator
+            .authenticate(token)
+            .await
+            .map(Some)
+            .map_err(|e| QueryError::from_authentication_error(&e))
+    }
+}
+
+fn decode<T: for<'de> Deserialize<'de>>(payload: &[u8]) -> Result<T, QueryError> {
+    serde_json::from_slice(payload).map_err(|e| QueryError::Malformed(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::Hostname;
+    use crate::events::connection::ConnectionEndpoint;
+    use chrono::Utc;
+    use cim_domain::EntityId;
+
+    /// Resources of two organizations, `acme` and `globex`, all cabled together
+    struct TwoTenants {
+        acme: ComputeResourceState,
+        globex: ComputeResourceState,
+    }
+
+    impl TwoTenants {
+        fn new() -> Self {
+            let resource = |hostname: &str| {
+                let mut state = ComputeResourceState::default_for(Uuid::now_v7());
+                state.hostname = Hostname::new(hostname).unwrap();
+                state.organization_id = Some(EntityId::new());
+                state
+            };
+            Self {
+                acme: resource("web01"),
+                globex: resource("db01"),
+            }
+        }
+
+        fn tenant(state: &ComputeResourceState) -> EventEnvelopeMetadata {
+            EventEnvelopeMetadata::new()
+                .with_tenant(state.organization_id.as_ref().unwrap().to_string())
+        }
+    }
+
+    #[async_trait]
+    impl ReadModel for TwoTenants {
+        async fn compute_resource(
+            &self,
+            aggregate_id: Uuid,
+        ) -> Result<ComputeResourceState, QueryError> {
+            [&self.acme, &self.globex]
+                .into_iter()
+                .find(|state| state.id == aggregate_id)
+                .cloned()
+                .ok_or_else(|| QueryError::NotFound(aggregate_id.to_string()))
+        }
+
+        async fn topology(&self, query: &TopologyQuery) -> Result<TopologyView, QueryError> {
+            let root = self.compute_resource(query.root).await?;
+            let mut view = TopologyView::from_resource(&root);
+            for other in [&self.acme, &self.globex] {
+                if other.id != root.id {
+                    view.link(
+                        other.id.to_string(),
+                        TopologyNodeKind::ComputeResource,
+                        CABLED_TO,
+                    );
+                    view.link(
+                        other.organization_id.as_ref().unwrap().to_string(),
+                        TopologyNodeKind::Organization,
+                        "OWNED_BY",
+                    );
+                }
+            }
+            Ok(view)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_scoped_reads_hide_other_organizations() {
+        // Arrange
+        let tenants = Arc::new(TwoTenants::new());
+        let (acme, globex) = (tenants.acme.clone(), tenants.globex.clone());
+        let read_model = ScopedReadModel::new(tenants);
+        let query = TopologyQuery {
+            root: acme.id,
+            depth: 1,
+        };
+
+        // Act
+        let (own, foreign, view) = TwoTenants::tenant(&acme)
+            .scope(async {
+                (
+                    read_model.compute_resource(acme.id).await,
+                    read_model.compute_resource(globex.id).await,
+                    read_model.topology(&query).await.unwrap(),
+                )
+            })
+            .await;
+        let unscoped = read_model.compute_resource(globex.id).await;
+
+        // Assert
+        assert_eq!(own, Ok(acme.clone()));
+        assert_eq!(foreign, Err(QueryError::NotFound(globex.id.to_string())));
+        assert!(unscoped.is_ok());
+        let globex_ids = [
+            globex.id.to_string(),
+            globex.organization_id.as_ref().unwrap().to_string(),
+        ];
+        let ids: Vec<&str> = view.nodes.iter().map(|node| node.id.as_str()).collect();
+        assert_eq!(
+            ids,
+            [
+                acme.id.to_string(),
+                acme.organization_id.as_ref().unwrap().to_string()
+            ]
+        );
+        assert!(view.edges.iter().all(|edge| !globex_ids.contains(&edge.to)));
+    }
+
+    #[tokio::test]
+    async fn test_scoped_listings_are_limited_to_the_acting_organization() {
+        // Arrange
+        let tenants = Arc::new(TwoTenants::new());
+        let acting = TwoTenants::tenant(&tenants.acme);
+        let acme_org = acting.tenant.clone().unwrap();
+        let read_model = ScopedReadModel::new(tenants);
+
+        // Act
+        let (unfiltered, foreign) = acting
+            .scope(async {
+                (
+                    read_model
+                        .archival_candidates(&GetArchivalCandidates {
+                            organization_id: None,
+                        })
+                        .await,
+                    read_model
+                        .archival_candidates(&GetArchivalCandidates {
+                            organization_id: Some("globex".to_string()),
+                        })
+                        .await,
+                )
+            })
+            .await;
+
+        // Assert: the inner model was asked for the acting organization only
+        assert_eq!(
+            unfiltered,
+            Err(QueryError::Unsupported(format!(
+                "archival candidates ({acme_org})"
+            )))
+        );
+        assert!(matches!(foreign, Err(QueryError::Forbidden(_))));
+    }
+
+    #[test]
+    fn test_topology_view_from_resource() {
+        let mut state = ComputeResourceState::default_for(Uuid::now_v7());
+        state.hostname = Hostname::new("web-01").unwrap();
+
+        let view = TopologyView::from_resource(&state);
+
+        assert_eq!(view.root, state.id.to_string());
+        assert_eq!(view.nodes.len(), 1);
+        assert_eq!(view.nodes[0].label, "web-01");
+        assert!(view.edges.is_empty());
+    }
+
+    #[test]
+    fn test_connectivity_paths_and_isolation() {
+        // Arrange: db01 — sw01 — web01 cabled, a removed cable to spare01
+        let resources: Vec<ComputeResourceState> = ["db01", "sw01", "web01", "spare01"]
+            .into_iter()
+            .map(|hostname| {
+                let mut state = ComputeResourceState::default_for(Uuid::now_v7());
+                state.hostname = Hostname::new(hostname).unwrap();
+                state
+            })
+            .collect();
+        let [db, switch, web, spare] = [0, 1, 2, 3].map(|i| &resources[i]);
+        let cable = |a: &ComputeResourceState, b: &ComputeResourceState, removed: bool| {
+            let end = |state: &ComputeResourceState| ConnectionEndpoint {
+                interface_id: Uuid::now_v7(),
+                resource_id: state.id,
+                device: state.hostname.clone(),
+                port: "eth0".to_string(),
+            };
+            ConnectionState {
+                a_end: Some(end(a)),
+                b_end: Some(end(b)),
+                removed,
+                created_at: Some(Utc::now()),
+                ..ConnectionState::default_for(Uuid::now_v7())
+            }
+        };
+        let cables = [
+            cable(db, switch, false),
+            cable(switch, web, false),
+            cable(web, spare, true),
+        ];
+
+        // Act
+        let view = TopologyView::connectivity(&resources, &cables);
+
+        // Assert
+        let labels = |nodes: Vec<&TopologyNode>| -> Vec<String> {
+            nodes.into_iter().map(|n| n.label.clone()).collect()
+        };
+        assert_eq!(view.edges.len(), 2);
+        assert_eq!(
+            labels(view.path_between(db.id, web.id).unwrap()),
+            ["db01", "sw01", "web01"]
+        );
+        assert!(view.path_between(db.id, spare.id).is_none());
+        assert_eq!(labels(view.reachable_from(web.id)), ["sw01", "db01"]);
+        assert_eq!(labels(view.isolated_resources()), ["spare01"]);
+    }
+
+    #[test]
+    fn test_reply_encoding() {
+        let ok = QueryReply::from_result(Ok(GetComputeResource {
+            aggregate_id: Uuid::nil(),
+        }));
+        let err = QueryReply::from_result::<()>(Err(QueryError::NotFound("x".to_string())));
+
+        let ok_json = serde_json::to_value(&ok).unwrap();
+        let err_json = serde_json::to_value(&err).unwrap();
+
+        assert_eq!(ok_json["status"], "ok");
+        assert_eq!(ok_json["result"]["aggregate_id"], Uuid::nil().to_string());
+        assert_eq!(err_json["status"], "error");
+        assert_eq!(err_json["code"], "not_found");
+    }
+
+    #[test]
+    fn test_topology_query_default_depth() {
+        let query: TopologyQuery =
+            serde_json::from_str(&format!(r#"{{"root": "{}"}}"#, Uuid::nil())).unwrap();
+
+        assert_eq!(query.depth, 1);
+    }
+
+    #[test]
+    fn test_list_resources_defaults_to_the_first_page() {
+        let query: ListResources =
+            serde_json::from_str(r#"{"filter": {"status": "active"}}"#).unwrap();
+
+        assert_eq!(query.page, 1);
+        assert_eq!(query.page_size, 50);
+        assert_eq!(
+            query.filter.status,
+            Some(crate::events::ResourceStatus::Active)
+        );
+        assert!(query.filter.metadata.is_empty());
+    }
+}
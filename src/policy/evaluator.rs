@@ -0,0 +1,937 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Policy Condition Evaluation
+//!
+//! Compiles [`PolicyRule`] conditions into predicates and evaluates them
+//! against resources. Compilation checks syntax, field names and regular
+//! expressions up front, so a bad rule is rejected when it is loaded rather
+//! than silently never matching.
+//!
+//! # Condition Language
+//!
+//! ```text
+//! condition  := or
+//! or         := and ("or" and)*
+//! and        := unary ("and" unary)*
+//! unary      := "not" unary | "(" or ")" | comparison
+//! comparison := field ("==" | "!=" | "<" | "<=" | ">" | ">=") value
+//!             | field "is" ["not"] "set"
+//!             | field ["not"] "in" "[" value ("," value)* "]"
+//!             | field "matches" value
+//! value      := 'quoted' | "quoted" | bare-word | number
+//! ```
+//!
+//! Keywords are case-insensitive. Fields:
+//!
+//! | Field | Value |
+//! |-------|-------|
+//! | `hostname`, `resource_type`, `status` | text |
+//! | `organization`, `location`, `owner`, `account_concept` | ID |
+//! | `manufacturer`, `model`, `serial_number`, `asset_tag` | text |
+//! | `metadata.<key>` | text of the metadata entry |
+//! | `policies` | number of applied policies |
+//! | `topology.<RELATIONSHIP>` | number of edges of that type (e.g. `topology.LOCATED_AT`) |
+//!
+//! A field that is not set only satisfies `!=`, `not in` and `is not set`.
+//! Ordering comparisons are numeric and fail when either side is not a
+//! number. `topology.*` fields are only set when a [`TopologyView`] is
+//! supplied.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::policy::{PolicyEvaluator, PolicyRule};
+//!
+//! let evaluator = PolicyEvaluator::compile([
+//!     PolicyRule::new(
+//!         "servers-need-asset-tags",
+//!         "resource_type == physical_server and asset_tag is not set",
+//!         "alert",
+//!     ),
+//!     PolicyRule::new("racked", "metadata.rack is not set and status == active", "warn"),
+//! ])?;
+//!
+//! for violation in evaluator.evaluate(&resource) {
+//!     println!("{}", violation);
+//! }
+//! ```
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use uuid::Uuid;
+
+use super::{PolicyAction, PolicyRule};
+use crate::aggregate::ComputeResourceState;
+use crate::events::ResourceStatus;
+#[cfg(feature = "runtime")]
+use crate::nats::query::TopologyView;
+
+/// Policy rule compilation errors
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PolicyError {
+    /// Condition is not well-formed
+    #[error("Rule '{rule}': {message}")]
+    Syntax {
+        /// Rule name
+        rule: String,
+        /// What is wrong
+        message: String,
+    },
+
+    /// Condition refers to a field that does not exist
+    #[error("Rule '{rule}': unknown field '{field}'")]
+    UnknownField {
+        /// Rule name
+        rule: String,
+        /// The unknown field
+        field: String,
+    },
+
+    /// `matches` pattern is not a valid regular expression
+    #[error("Rule '{rule}': invalid pattern '{pattern}': {message}")]
+    InvalidPattern {
+        /// Rule name
+        rule: String,
+        /// The rejected pattern
+        pattern: String,
+        /// Regex parser message
+        message: String,
+    },
+
+    /// Action is not `warn` or `alert`
+    #[error("Rule '{rule}': unknown action '{action}'")]
+    UnknownAction {
+        /// Rule name
+        rule: String,
+        /// The rejected action
+        action: String,
+    },
+
+    /// Two rules share a name
+    #[error("Duplicate rule name '{0}'")]
+    DuplicateRule(String),
+}
+
+/// One rule broken by one resource
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct PolicyViolation {
+    /// Offending resource
+    pub resource_id: Uuid,
+
+    /// Hostname of the resource, for readability
+    pub hostname: String,
+
+    /// Name of the broken rule
+    pub rule: String,
+
+    /// Action configured on the rule
+    pub action: PolicyAction,
+
+    /// Rule description, or the condition that held
+    pub message: String,
+}
+
+impl fmt::Display for PolicyViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({}, {}): {}",
+            self.hostname, self.rule, self.action, self.message
+        )
+    }
+}
+
+/// Change in a resource's compliance with one rule
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComplianceChange {
+    /// Rule is newly broken
+    Violated(PolicyViolation),
+
+    /// Previously broken rule holds again
+    Restored(PolicyViolation),
+}
+
+/// Compare a resource's violations before and after a change
+///
+/// Violations are matched by rule name. Restored entries carry the
+/// violation as it was last reported.
+pub fn diff_violations(
+    previous: &[PolicyViolation],
+    current: &[PolicyViolation],
+) -> Vec<ComplianceChange> {
+    let broken_before = |rule: &str| previous.iter().any(|v| v.rule == rule);
+    let broken_now = |rule: &str| current.iter().any(|v| v.rule == rule);
+
+    current
+        .iter()
+        .filter(|v| !broken_before(&v.rule))
+        .cloned()
+        .map(ComplianceChange::Violated)
+        .chain(
+            previous
+                .iter()
+                .filter(|v| !broken_now(&v.rule))
+                .cloned()
+                .map(ComplianceChange::Restored),
+        )
+        .collect()
+}
+
+/// A compiled condition
+///
+/// Rules use conditions to select violating resources; other read-side
+/// tooling (exports, reports) uses them to select resources in general.
+#[derive(Debug, Clone)]
+pub struct Condition {
+    source: String,
+    predicate: Predicate,
+}
+
+impl Condition {
+    /// Compile a condition; `rule` names it in errors
+    pub fn compile(rule: &str, condition: &str) -> Result<Self, PolicyError> {
+        let tokens = tokenize(condition).map_err(|message| PolicyError::Syntax {
+            rule: rule.to_string(),
+            message,
+        })?;
+        let predicate = Parser {
+            rule,
+            tokens,
+            position: 0,
+        }
+        .parse()?;
+
+        Ok(Self {
+            source: condition.to_string(),
+            predicate,
+        })
+    }
+
+    /// The condition as written
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// Whether the condition holds for `resource`
+    pub fn matches(&self, resource: &ComputeResourceState) -> bool {
+        self.predicate.holds(&Facts::of(resource))
+    }
+}
+
+/// A rule with its condition compiled
+#[derive(Debug, Clone)]
+pub struct CompiledRule {
+    rule: PolicyRule,
+    action: PolicyAction,
+    condition: Condition,
+}
+
+impl CompiledRule {
+    /// Compile one rule
+    pub fn compile(rule: PolicyRule) -> Result<Self, PolicyError> {
+        let action =
+            PolicyAction::parse(&rule.action).ok_or_else(|| PolicyError::UnknownAction {
+                rule: rule.name.clone(),
+                action: rule.action.clone(),
+            })?;
+
+        let condition = Condition::compile(&rule.name, &rule.condition)?;
+
+        Ok(Self {
+            rule,
+            action,
+            condition,
+        })
+    }
+
+    /// Rule name
+    pub fn name(&self) -> &str {
+        &self.rule.name
+    }
+
+    /// Parsed action
+    pub fn action(&self) -> PolicyAction {
+        self.action
+    }
+
+    /// The rule as written
+    pub fn rule(&self) -> &PolicyRule {
+        &self.rule
+    }
+
+    /// Whether `resource` breaks this rule
+    pub fn is_violated_by(&self, resource: &ComputeResourceState) -> bool {
+        self.condition.matches(resource)
+    }
+
+    fn violation(&self, resource: &ComputeResourceState) -> PolicyViolation {
+        PolicyViolation {
+            resource_id: resource.id,
+            hostname: resource.hostname.to_string(),
+            rule: self.rule.name.clone(),
+            action: self.action,
+            message: self
+                .rule
+                .description
+                .clone()
+                .unwrap_or_else(|| format!("condition '{}' holds", self.rule.condition)),
+        }
+    }
+}
+
+/// Compiled rule set
+#[derive(Debug, Clone, Default)]
+pub struct PolicyEvaluator {
+    rules: Vec<CompiledRule>,
+}
+
+impl PolicyEvaluator {
+    /// Evaluator with no rules
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Compile a rule set; fails on the first invalid rule
+    pub fn compile<I>(rules: I) -> Result<Self, PolicyError>
+    where
+        I: IntoIterator<Item = PolicyRule>,
+    {
+        let mut evaluator = Self::new();
+        for rule in rules {
+            evaluator.register(rule)?;
+        }
+        Ok(evaluator)
+    }
+
+    /// Add a rule
+    pub fn with_rule(mut self, rule: PolicyRule) -> Result<Self, PolicyError> {
+        self.register(rule)?;
+        Ok(self)
+    }
+
+    /// Add a rule to an existing evaluator
+    pub fn register(&mut self, rule: PolicyRule) -> Result<(), PolicyError> {
+        if self.rules.iter().any(|r| r.name() == rule.name) {
+            return Err(PolicyError::DuplicateRule(rule.name));
+        }
+        self.rules.push(CompiledRule::compile(rule)?);
+        Ok(())
+    }
+
+    /// Names of registered rules, in registration order
+    pub fn rule_names(&self) -> Vec<&str> {
+        self.rules.iter().map(CompiledRule::name).collect()
+    }
+
+    /// Rules broken by a resource
+    pub fn evaluate(&self, resource: &ComputeResourceState) -> Vec<PolicyViolation> {
+        self.evaluate_facts(resource, &Facts::of(resource))
+    }
+
+    /// Rules broken by a resource, with `topology.*` fields taken from its
+    /// topology view
+    #[cfg(feature = "runtime")]
+    pub fn evaluate_with_topology(
+        &self,
+        resource: &ComputeResourceState,
+        topology: &TopologyView,
+    ) -> Vec<PolicyViolation> {
+        let mut relationships: HashMap<String, usize> = HashMap::new();
+        for edge in &topology.edges {
+            *relationships
+                .entry(edge.relationship.to_ascii_uppercase())
+                .or_insert(0) += 1;
+        }
+
+        let facts = Facts {
+            relationships: Some(relationships),
+            ..Facts::of(resource)
+        };
+        self.evaluate_facts(resource, &facts)
+    }
+
+    fn evaluate_facts(
+        &self,
+        resource: &ComputeResourceState,
+        facts: &Facts<'_>,
+    ) -> Vec<PolicyViolation> {
+        self.rules
+            .iter()
+            .filter(|rule| rule.condition.predicate.holds(facts))
+            .map(|rule| rule.violation(resource))
+            .collect()
+    }
+}
+
+/// Resource data a predicate is evaluated against
+struct Facts<'a> {
+    resource: &'a ComputeResourceState,
+    relationships: Option<HashMap<String, usize>>,
+}
+
+impl<'a> Facts<'a> {
+    fn of(resource: &'a ComputeResourceState) -> Self {
+        Self {
+            resource,
+            relationships: None,
+        }
+    }
+
+    fn value(&self, field: &Field) -> Option<Value> {
+        let resource = self.resource;
+        let text = |s: &str| Some(Value::Text(s.to_string()));
+
+        match field {
+            Field::Hostname => text(resource.hostname.as_str()),
+            Field::ResourceType => text(resource.resource_type.as_str()),
+            Field::Status => text(match resource.status {
+                ResourceStatus::Provisioning => "provisioning",
+                ResourceStatus::Active => "active",
+                ResourceStatus::Maintenance => "maintenance",
+                ResourceStatus::Decommissioned => "decommissioned",
+            }),
+            Field::Organization => resource
+                .organization_id
+                .as_ref()
+                .map(|id| Value::Text(id.to_string())),
+            Field::Location => resource
+                .location_id
+                .as_ref()
+                .map(|id| Value::Text(id.to_string())),
+            Field::Owner => resource
+                .owner_id
+                .as_ref()
+                .map(|id| Value::Text(id.to_string())),
+            Field::AccountConcept => resource
+                .account_concept_id
+                .as_ref()
+                .map(|id| Value::Text(id.to_string())),
+            Field::Manufacturer => resource.manufacturer.as_deref().and_then(text),
+            Field::Model => resource.model.as_deref().and_then(text),
+            Field::SerialNumber => resource.serial_number.as_deref().and_then(text),
+            Field::AssetTag => resource.asset_tag.as_deref().and_then(text),
+            Field::Policies => Some(Value::Number(resource.policy_ids.len() as f64)),
+            Field::Metadata(key) => resource
+                .metadata
+                .iter()
+                .find(|(k, _)| k == key)
+                .and_then(|(_, v)| text(v)),
+            Field::Relationship(relationship) => self
+                .relationships
+                .as_ref()
+                .map(|counts| Value::Number(*counts.get(relationship).unwrap_or(&0) as f64)),
+        }
+    }
+}
+
+/// Field a condition refers to
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Field {
+    Hostname,
+    ResourceType,
+    Status,
+    Organization,
+    Location,
+    Owner,
+    AccountConcept,
+    Manufacturer,
+    Model,
+    SerialNumber,
+    AssetTag,
+    Policies,
+    Metadata(String),
+    Relationship(String),
+}
+
+impl Field {
+    fn parse(name: &str) -> Option<Self> {
+        if let Some(key) = name.strip_prefix("metadata.") {
+            return (!key.is_empty()).then(|| Field::Metadata(key.to_string()));
+        }
+        if let Some(relationship) = name.strip_prefix("topology.") {
+            return (!relationship.is_empty())
+                .then(|| Field::Relationship(relationship.to_ascii_uppercase()));
+        }
+
+        Some(match name {
+            "hostname" => Field::Hostname,
+            "resource_type" => Field::ResourceType,
+            "status" => Field::Status,
+            "organization" => Field::Organization,
+            "location" => Field::Location,
+            "owner" => Field::Owner,
+            "account_concept" => Field::AccountConcept,
+            "manufacturer" => Field::Manufacturer,
+            "model" => Field::Model,
+            "serial_number" => Field::SerialNumber,
+            "asset_tag" => Field::AssetTag,
+            "policies" => Field::Policies,
+            _ => return None,
+        })
+    }
+}
+
+/// Value of a field on a resource
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Text(String),
+    Number(f64),
+}
+
+/// Value written in a condition
+#[derive(Debug, Clone, PartialEq)]
+struct Literal {
+    text: String,
+    number: Option<f64>,
+}
+
+impl Literal {
+    fn equals(&self, value: &Value) -> bool {
+        match (value, self.number) {
+            (Value::Number(n), Some(m)) => *n == m,
+            (Value::Number(n), None) => n.to_string() == self.text,
+            (Value::Text(s), _) => *s == self.text,
+        }
+    }
+
+    fn compare(&self, value: &Value) -> Option<std::cmp::Ordering> {
+        let left = match value {
+            Value::Number(n) => *n,
+            Value::Text(s) => s.parse().ok()?,
+        };
+        left.partial_cmp(&self.number?)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+/// Compiled condition
+#[derive(Debug, Clone)]
+enum Predicate {
+    And(Box<Predicate>, Box<Predicate>),
+    Or(Box<Predicate>, Box<Predicate>),
+    Not(Box<Predicate>),
+    Compare(Field, CompareOp, Literal),
+    IsSet(Field),
+    In(Field, Vec<Literal>),
+    Matches(Field, Regex),
+}
+
+impl Predicate {
+    fn holds(&self, facts: &Facts<'_>) -> bool {
+        use std::cmp::Ordering;
+
+        match self {
+            Predicate::And(a, b) => a.holds(facts) && b.holds(facts),
+            Predicate::Or(a, b) => a.holds(facts) || b.holds(facts),
+            Predicate::Not(p) => !p.holds(facts),
+            Predicate::IsSet(field) => facts.value(field).is_some(),
+            Predicate::Compare(field, op, literal) => {
+                let Some(value) = facts.value(field) else {
+                    return *op == CompareOp::Ne;
+                };
+                match op {
+                    CompareOp::Eq => literal.equals(&value),
+                    CompareOp::Ne => !literal.equals(&value),
+                    CompareOp::Lt => literal.compare(&value) == Some(Ordering::Less),
+                    CompareOp::Le => matches!(
+                        literal.compare(&value),
+                        Some(Ordering::Less | Ordering::Equal)
+                    ),
+                    CompareOp::Gt => literal.compare(&value) == Some(Ordering::Greater),
+                    CompareOp::Ge => matches!(
+                        literal.compare(&value),
+                        Some(Ordering::Greater | Ordering::Equal)
+                    ),
+                }
+            }
+            Predicate::In(field, literals) => facts
+                .value(field)
+                .is_some_and(|value| literals.iter().any(|l| l.equals(&value))),
+            Predicate::Matches(field, pattern) => match facts.value(field) {
+                Some(Value::Text(s)) => pattern.is_match(&s),
+                Some(Value::Number(n)) => pattern.is_match(&n.to_string()),
+                None => false,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Quoted(String),
+    Op(CompareOp),
+    LParen,
+    RParen,
+    LBracket,
+    RBracket,
+    Comma,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Word(w) => write!(f, "'{}'", w),
+            Token::Quoted(s) => write!(f, "\"{}\"", s),
+            Token::Op(op) => write!(
+                f,
+                "'{}'",
+                match op {
+                    CompareOp::Eq => "==",
+                    CompareOp::Ne => "!=",
+                    CompareOp::Lt => "<",
+                    CompareOp::Le => "<=",
+                    CompareOp::Gt => ">",
+                    CompareOp::Ge => ">=",
+                }
+            ),
+            Token::LParen => write!(f, "'('"),
+            Token::RParen => write!(f, "')'"),
+            Token::LBracket => write!(f, "'['"),
+            Token::RBracket => write!(f, "']'"),
+            Token::Comma => write!(f, "','"),
+        }
+    }
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' | ')' | '[' | ']' | ',' => {
+                chars.next();
+                tokens.push(match c {
+                    '(' => Token::LParen,
+                    ')' => Token::RParen,
+                    '[' => Token::LBracket,
+                    ']' => Token::RBracket,
+                    _ => Token::Comma,
+                });
+            }
+            '\'' | '"' => {
+                chars.next();
+                let mut text = String::new();
+                loop {
+                    match chars.next() {
+                        Some(ch) if ch == c => break,
+                        Some(ch) => text.push(ch),
+                        None => return Err(format!("unterminated string {}{}", c, text)),
+                    }
+                }
+                tokens.push(Token::Quoted(text));
+            }
+            '=' | '!' | '<' | '>' => {
+                chars.next();
+                let followed_by_eq = chars.next_if_eq(&'=').is_some();
+                tokens.push(Token::Op(match (c, followed_by_eq) {
+                    ('=', true) => CompareOp::Eq,
+                    ('!', true) => CompareOp::Ne,
+                    ('<', false) => CompareOp::Lt,
+                    ('<', true) => CompareOp::Le,
+                    ('>', false) => CompareOp::Gt,
+                    ('>', true) => CompareOp::Ge,
+                    _ => return Err(format!("unexpected '{}'", c)),
+                }));
+            }
+            _ => {
+                let mut word = String::new();
+                while let Some(ch) =
+                    chars.next_if(|ch| !ch.is_whitespace() && !"()[],'\"=!<>".contains(*ch))
+                {
+                    word.push(ch);
+                }
+                tokens.push(Token::Word(word));
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    rule: &'a str,
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn parse(mut self) -> Result<Predicate, PolicyError> {
+        if self.tokens.is_empty() {
+            return Err(self.syntax("empty condition"));
+        }
+
+        let predicate = self.parse_or()?;
+        match self.peek() {
+            None => Ok(predicate),
+            Some(token) => Err(self.syntax(format!("unexpected {}", token))),
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<Predicate, PolicyError> {
+        let mut left = self.parse_and()?;
+        while self.eat_keyword("or") {
+            let right = self.parse_and()?;
+            left = Predicate::Or(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_and(&mut self) -> Result<Predicate, PolicyError> {
+        let mut left = self.parse_unary()?;
+        while self.eat_keyword("and") {
+            let right = self.parse_unary()?;
+            left = Predicate::And(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_unary(&mut self) -> Result<Predicate, PolicyError> {
+        if self.eat_keyword("not") {
+            return Ok(Predicate::Not(Box::new(self.parse_unary()?)));
+        }
+        if self.peek() == Some(&Token::LParen) {
+            self.position += 1;
+            let inner = self.parse_or()?;
+            self.expect(&Token::RParen)?;
+            return Ok(inner);
+        }
+        self.parse_comparison()
+    }
+
+    fn parse_comparison(&mut self) -> Result<Predicate, PolicyError> {
+        let name = match self.next() {
+            Some(Token::Word(word)) if !is_keyword(&word) => word,
+            Some(token) => return Err(self.syntax(format!("expected a field, found {}", token))),
+            None => return Err(self.syntax("expected a field")),
+        };
+        let field = Field::parse(&name).ok_or_else(|| PolicyError::UnknownField {
+            rule: self.rule.to_string(),
+            field: name.clone(),
+        })?;
+
+        if let Some(Token::Op(op)) = self.peek().cloned() {
+            self.position += 1;
+            return Ok(Predicate::Compare(field, op, self.literal()?));
+        }
+
+        if self.eat_keyword("is") {
+            let negated = self.eat_keyword("not");
+            if !self.eat_keyword("set") {
+                return Err(self.syntax(format!("expected 'set' after '{} is'", name)));
+            }
+            let is_set = Predicate::IsSet(field);
+            return Ok(if negated {
+                Predicate::Not(Box::new(is_set))
+            } else {
+                is_set
+            });
+        }
+
+        let negated = self.eat_keyword("not");
+        if self.eat_keyword("in") {
+            let list = Predicate::In(field, self.list()?);
+            return Ok(if negated {
+                Predicate::Not(Box::new(list))
+            } else {
+                list
+            });
+        }
+        if negated {
+            return Err(self.syntax(format!("expected 'in' after '{} not'", name)));
+        }
+
+        if self.eat_keyword("matches") {
+            let pattern = self.literal()?.text;
+            let regex = Regex::new(&pattern).map_err(|e| PolicyError::InvalidPattern {
+                rule: self.rule.to_string(),
+                pattern: pattern.clone(),
+                message: e.to_string(),
+            })?;
+            return Ok(Predicate::Matches(field, regex));
+        }
+
+        Err(self.syntax(format!("expected an operator after '{}'", name)))
+    }
+
+    fn list(&mut self) -> Result<Vec<Literal>, PolicyError> {
+        self.expect(&Token::LBracket)?;
+        let mut literals = vec![self.literal()?];
+        while self.peek() == Some(&Token::Comma) {
+            self.position += 1;
+            literals.push(self.literal()?);
+        }
+        self.expect(&Token::RBracket)?;
+        Ok(literals)
+    }
+
+    fn literal(&mut self) -> Result<Literal, PolicyError> {
+        match self.next() {
+            Some(Token::Quoted(text)) => Ok(Literal { text, number: None }),
+            Some(Token::Word(text)) if !is_keyword(&text) => Ok(Literal {
+                number: text.parse().ok(),
+                text,
+            }),
+            Some(token) => Err(self.syntax(format!("expected a value, found {}", token))),
+            None => Err(self.syntax("expected a value")),
+        }
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        match self.peek() {
+            Some(Token::Word(word)) if word.eq_ignore_ascii_case(keyword) => {
+                self.position += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn expect(&mut self, expected: &Token) -> Result<(), PolicyError> {
+        match self.next() {
+            Some(token) if &token == expected => Ok(()),
+            Some(token) => Err(self.syntax(format!("expected {}, found {}", expected, token))),
+            None => Err(self.syntax(format!("expected {}", expected))),
+        }
+    }
+
+    fn syntax(&self, message: impl Into<String>) -> PolicyError {
+        PolicyError::Syntax {
+            rule: self.rule.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+fn is_keyword(word: &str) -> bool {
+    ["and", "or", "not", "is", "set", "in", "matches"]
+        .iter()
+        .any(|keyword| word.eq_ignore_ascii_case(keyword))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Hostname, ResourceType};
+
+    fn server(hostname: &str) -> ComputeResourceState {
+        let mut state = ComputeResourceState::default_for(Uuid::now_v7());
+        state.hostname = Hostname::new(hostname).unwrap();
+        state.resource_type = ResourceType::PhysicalServer;
+        state.status = ResourceStatus::Active;
+        state
+    }
+
+    fn evaluator() -> PolicyEvaluator {
+        PolicyEvaluator::compile([
+            PolicyRule::new(
+                "asset-tagged",
+                "resource_type == physical_server and asset_tag is not set",
+                "alert",
+            )
+            .with_description("physical servers need an asset tag"),
+            PolicyRule::new(
+                "racked",
+                "status in [active, maintenance] and not (metadata.rack matches '^R[0-9]+$')",
+                "warn",
+            ),
+            PolicyRule::new("governed", "policies < 1 AND hostname != 'lab-01'", "warn"),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_conditions_select_violating_resources() {
+        // Arrange
+        let untagged = server("web-01");
+        let compliant = {
+            let mut state = server("web-02");
+            state.asset_tag = Some("A-1001".to_string());
+            state.metadata.push(("rack".to_string(), "R12".to_string()));
+            state.policy_ids.push(cim_domain_policy::PolicyId::new());
+            state
+        };
+
+        // Act
+        let violations = evaluator().evaluate(&untagged);
+
+        // Assert
+        let rules: Vec<_> = violations.iter().map(|v| v.rule.as_str()).collect();
+        assert_eq!(rules, ["asset-tagged", "racked", "governed"]);
+        assert_eq!(violations[0].action, PolicyAction::Alert);
+        assert_eq!(violations[0].message, "physical servers need an asset tag");
+        assert!(evaluator().evaluate(&compliant).is_empty());
+    }
+
+    #[test]
+    fn test_invalid_rules_are_rejected_at_compile_time() {
+        let compile = |condition: &str, action: &str| {
+            CompiledRule::compile(PolicyRule::new("r", condition, action)).unwrap_err()
+        };
+
+        assert!(matches!(
+            compile("colour == red", "warn"),
+            PolicyError::UnknownField { field, .. } if field == "colour"
+        ));
+        assert!(matches!(
+            compile("hostname matches '(unclosed'", "warn"),
+            PolicyError::InvalidPattern { .. }
+        ));
+        assert!(matches!(
+            compile("asset_tag is not", "warn"),
+            PolicyError::Syntax { .. }
+        ));
+        assert!(matches!(
+            compile("(status == active", "warn"),
+            PolicyError::Syntax { .. }
+        ));
+        assert!(matches!(
+            compile("status == active", "page"),
+            PolicyError::UnknownAction { .. }
+        ));
+    }
+
+    #[test]
+    fn test_diff_reports_new_and_restored_rules() {
+        let mut resource = server("web-01");
+        let evaluator = evaluator();
+        let before = evaluator.evaluate(&resource);
+
+        resource.asset_tag = Some("A-1001".to_string());
+        resource.status = ResourceStatus::Provisioning;
+        let after = evaluator.evaluate(&resource);
+
+        let changes = diff_violations(&before, &after);
+        assert_eq!(changes.len(), 2);
+        assert!(changes
+            .iter()
+            .all(|change| matches!(change, ComplianceChange::Restored(_))));
+        assert!(diff_violations(&after, &after).is_empty());
+    }
+}
@@ -0,0 +1,115 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Infrastructure Policies
+//!
+//! A [`PolicyRule`] pairs a condition over a resource with an action to take
+//! when the condition holds. Rules are written as strings so they can be
+//! stored and edited outside the code:
+//!
+//! ```text
+//! name:      physical-servers-need-asset-tags
+//! condition: resource_type == physical_server and asset_tag is not set
+//! action:    alert
+//! ```
+//!
+//! The condition describes the *violation*: a resource for which it holds
+//! breaks the rule. The [`evaluator`] compiles conditions into predicates
+//! over [`ComputeResourceState`](crate::aggregate::ComputeResourceState)
+//! and its topology; with the runtime, the [`monitor`] evaluates them on
+//! every event and publishes
+//! [`PolicyViolationDetected`](crate::events::policy::PolicyViolationDetected)
+//! and
+//! [`PolicyComplianceRestored`](crate::events::policy::PolicyComplianceRestored)
+//! on `infrastructure.policy.*`.
+//!
+//! # Modules
+//!
+//! - [`evaluator`] - Condition language, compilation and evaluation
+//! - [`monitor`] - Event-driven evaluation and publishing (runtime)
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+pub mod evaluator;
+#[cfg(feature = "runtime")]
+pub mod monitor;
+
+pub use evaluator::{
+    diff_violations, CompiledRule, ComplianceChange, Condition, PolicyError, PolicyEvaluator,
+    PolicyViolation,
+};
+#[cfg(feature = "runtime")]
+pub use monitor::PolicyMonitor;
+
+/// What happens when a rule's condition holds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum PolicyAction {
+    /// Record the violation for reports and dashboards
+    Warn,
+
+    /// Record the violation and notify operators
+    Alert,
+}
+
+impl PolicyAction {
+    /// Parse an action string (`warn` or `alert`, case-insensitive)
+    pub fn parse(action: &str) -> Option<Self> {
+        match action.trim().to_ascii_lowercase().as_str() {
+            "warn" => Some(PolicyAction::Warn),
+            "alert" => Some(PolicyAction::Alert),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for PolicyAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PolicyAction::Warn => write!(f, "warn"),
+            PolicyAction::Alert => write!(f, "alert"),
+        }
+    }
+}
+
+/// A stored policy rule
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct PolicyRule {
+    /// Unique rule name, used in events and reports
+    pub name: String,
+
+    /// What the rule is for, shown with its violations
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// Condition that holds for violating resources
+    pub condition: String,
+
+    /// Action taken on violation (`warn` or `alert`)
+    pub action: String,
+}
+
+impl PolicyRule {
+    /// Create a rule
+    pub fn new(
+        name: impl Into<String>,
+        condition: impl Into<String>,
+        action: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            condition: condition.into(),
+            action: action.into(),
+        }
+    }
+
+    /// Describe what the rule is for
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
@@ -0,0 +1,287 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Event-Driven Policy Evaluation
+//!
+//! [`PolicyMonitor`] is a projection: it folds compute resource events into
+//! current state, re-evaluates every rule after each event, and publishes a
+//! [`PolicyEvent`] whenever a resource starts or stops breaking a rule.
+//!
+//! ```text
+//! StoredEvent ──> PolicyMonitor ──fold──> ComputeResourceState + TopologyView
+//!                      │                         │ evaluate
+//!                      │                  violations (per resource)
+//!                      └── diff ──publish──> infrastructure.policy.violation_detected
+//!                                            infrastructure.policy.compliance_restored
+//! ```
+//!
+//! Violations are tracked in memory. Rebuilding the monitor (see
+//! [`ProjectionManager`](crate::projection::manager::ProjectionManager))
+//! replays history and announces the transitions again.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::policy::{PolicyEvaluator, PolicyMonitor};
+//!
+//! let evaluator = Arc::new(PolicyEvaluator::compile(rules)?);
+//! manager.register(PolicyMonitor::new(evaluator).with_client(nats_client));
+//! ```
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use super::evaluator::{diff_violations, ComplianceChange, PolicyEvaluator, PolicyViolation};
+use crate::aggregate::{apply_event, ComputeResourceState};
+use crate::events::policy::{PolicyComplianceRestored, PolicyEvent, PolicyViolationDetected};
+use crate::events::InfrastructureEvent;
+use crate::jetstream::StoredEvent;
+use crate::nats::query::TopologyView;
+use crate::nats::NatsClient;
+use crate::projection::{ProjectionAdapter, ProjectionError};
+
+/// Resource state, the last sequence applied to it and its violations
+struct Tracked {
+    state: ComputeResourceState,
+    sequence: u64,
+    violations: Vec<PolicyViolation>,
+}
+
+/// Projection evaluating policies on every compute resource event
+pub struct PolicyMonitor {
+    evaluator: Arc<PolicyEvaluator>,
+    client: Option<NatsClient>,
+    resources: HashMap<Uuid, Tracked>,
+}
+
+impl PolicyMonitor {
+    /// Evaluate the rules of `evaluator`
+    ///
+    /// Without a client (see [`PolicyMonitor::with_client`]) transitions
+    /// are logged but not published.
+    pub fn new(evaluator: Arc<PolicyEvaluator>) -> Self {
+        Self {
+            evaluator,
+            client: None,
+            resources: HashMap::new(),
+        }
+    }
+
+    /// Publish policy events through `client`
+    pub fn with_client(mut self, client: NatsClient) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Rules a resource currently breaks
+    pub fn violations(&self, resource_id: Uuid) -> &[PolicyViolation] {
+        self.resources
+            .get(&resource_id)
+            .map(|tracked| tracked.violations.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Apply one event and return the policy events it causes
+    ///
+    /// Redelivered events and events of other aggregate types cause none.
+    pub fn observe(&mut self, event: &StoredEvent<InfrastructureEvent>) -> Vec<PolicyEvent> {
+        let InfrastructureEvent::ComputeResource(resource_event) = &event.data else {
+            return Vec::new();
+        };
+
+        let tracked = self
+            .resources
+            .entry(event.aggregate_id)
+            .or_insert_with(|| Tracked {
+                state: ComputeResourceState::default_for(event.aggregate_id),
+                sequence: 0,
+                violations: Vec::new(),
+            });
+
+        if event.sequence <= tracked.sequence {
+            return Vec::new();
+        }
+
+        tracked.state = apply_event(tracked.state.clone(), resource_event);
+        tracked.sequence = event.sequence;
+        if !tracked.state.is_initialized() {
+            return Vec::new();
+        }
+
+        let topology = TopologyView::from_resource(&tracked.state);
+        let current = self
+            .evaluator
+            .evaluate_with_topology(&tracked.state, &topology);
+        let changes = diff_violations(&tracked.violations, &current);
+        tracked.violations = current;
+
+        changes
+            .into_iter()
+            .map(|change| match change {
+                ComplianceChange::Violated(violation) => {
+                    PolicyEvent::PolicyViolationDetected(PolicyViolationDetected {
+                        event_id: Uuid::now_v7(),
+                        detected_at: event.data.timestamp(),
+                        correlation_id: event.correlation_id,
+                        causation_id: event.data.event_id(),
+                        resource_id: violation.resource_id,
+                        hostname: violation.hostname,
+                        rule: violation.rule,
+                        action: violation.action,
+                        message: violation.message,
+                    })
+                }
+                ComplianceChange::Restored(violation) => {
+                    PolicyEvent::PolicyComplianceRestored(PolicyComplianceRestored {
+                        event_id: Uuid::now_v7(),
+                        detected_at: event.data.timestamp(),
+                        correlation_id: event.correlation_id,
+                        causation_id: event.data.event_id(),
+                        resource_id: violation.resource_id,
+                        hostname: tracked.state.hostname.to_string(),
+                        rule: violation.rule,
+                    })
+                }
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl ProjectionAdapter for PolicyMonitor {
+    type Event = StoredEvent<InfrastructureEvent>;
+    type Error = ProjectionError;
+
+    async fn project(&mut self, event: Self::Event) -> Result<(), Self::Error> {
+        for policy_event in self.observe(&event) {
+            match &policy_event {
+                PolicyEvent::PolicyViolationDetected(e) => {
+                    warn!(
+                        "Policy violation: {} ({}): {}",
+                        e.hostname, e.rule, e.message
+                    )
+                }
+                PolicyEvent::PolicyComplianceRestored(e) => {
+                    info!("Policy compliance restored: {} ({})", e.hostname, e.rule)
+                }
+            }
+
+            if let Some(client) = &self.client {
+                client
+                    .publish(&policy_event.subject(), &policy_event)
+                    .await
+                    .map_err(|e| ProjectionError::TargetUnavailable(e.to_string()))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn initialize(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    async fn health_check(&self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    async fn reset(&mut self) -> Result<(), Self::Error> {
+        self.resources.clear();
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "policy_monitor"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Hostname, ResourceType};
+    use crate::events::compute_resource::{
+        AssetTagAssigned, ComputeResourceEvent, ResourceRegistered,
+    };
+    use crate::policy::PolicyRule;
+    use chrono::{DateTime, Utc};
+
+    fn test_timestamp() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-19T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn stored(sequence: u64, event: ComputeResourceEvent) -> StoredEvent<InfrastructureEvent> {
+        StoredEvent::new(
+            Uuid::now_v7(),
+            event.aggregate_id(),
+            sequence,
+            Uuid::now_v7(),
+            Uuid::now_v7(),
+            "test",
+            InfrastructureEvent::ComputeResource(event),
+        )
+    }
+
+    #[test]
+    fn test_transitions_are_announced_once() {
+        // Arrange
+        let resource_id = Uuid::now_v7();
+        let evaluator = PolicyEvaluator::compile([
+            PolicyRule::new(
+                "asset-tagged",
+                "resource_type == physical_server and asset_tag is not set",
+                "alert",
+            ),
+            PolicyRule::new("located", "topology.LOCATED_AT < 1", "warn"),
+        ])
+        .unwrap();
+        let mut monitor = PolicyMonitor::new(Arc::new(evaluator));
+
+        let registered = stored(
+            1,
+            ComputeResourceEvent::ResourceRegistered(ResourceRegistered {
+                event_version: 1,
+                event_id: Uuid::now_v7(),
+                aggregate_id: resource_id,
+                timestamp: test_timestamp(),
+                correlation_id: Uuid::now_v7(),
+                causation_id: None,
+                hostname: Hostname::new("web-01").unwrap(),
+                resource_type: ResourceType::PhysicalServer,
+            }),
+        );
+        let tagged = stored(
+            2,
+            ComputeResourceEvent::AssetTagAssigned(AssetTagAssigned {
+                event_version: 1,
+                event_id: Uuid::now_v7(),
+                aggregate_id: resource_id,
+                timestamp: test_timestamp(),
+                correlation_id: Uuid::now_v7(),
+                causation_id: None,
+                asset_tag: "A-1001".to_string(),
+            }),
+        );
+
+        // Act
+        let on_register = monitor.observe(&registered);
+        let redelivered = monitor.observe(&registered);
+        let on_tag = monitor.observe(&tagged);
+
+        // Assert
+        assert_eq!(on_register.len(), 2);
+        assert!(on_register
+            .iter()
+            .all(|e| matches!(e, PolicyEvent::PolicyViolationDetected(_))));
+        assert!(redelivered.is_empty());
+        assert_eq!(on_tag.len(), 1);
+        assert_eq!(on_tag[0].rule(), "asset-tagged");
+        assert_eq!(
+            on_tag[0].subject(),
+            "infrastructure.policy.compliance_restored"
+        );
+        assert_eq!(monitor.violations(resource_id).len(), 1);
+    }
+}
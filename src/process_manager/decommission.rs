@@ -0,0 +1,394 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Decommission Saga
+//!
+//! Cleans up after a resource is decommissioned.
+//!
+//! # States
+//!
+//! ```text
+//! Requested ──StatusChanged(→ Decommissioned)──> Detaching
+//!                                                  │ RemovePolicy × n
+//!                                                  │ DetachGuest × m
+//!                                                  │ PolicyRemoved / GuestDetached (each)
+//!                                                  ▼
+//!                                              Completed
+//! ```
+//!
+//! Policies are detached and guests (VMs, containers) are unplaced from the
+//! host; the guests themselves keep running until they are moved or
+//! decommissioned. Removing interfaces and releasing IP assignments become
+//! further steps once those aggregates accept commands.
+
+use cim_domain_policy::PolicyId;
+use uuid::Uuid;
+
+use super::{CommandContext, Saga};
+use crate::aggregate::{
+    ComputeResourceCommand, ComputeResourceState, DetachGuestCommand, RemovePolicyCommand,
+};
+use crate::events::{ComputeResourceEvent, InfrastructureEvent, ResourceStatus};
+use crate::jetstream::StoredEvent;
+use crate::service::InfrastructureCommand;
+use crate::state_machine::{StateMachine, TransitionError, TransitionResult};
+
+/// Workflow state of one decommissioning
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecommissionSaga {
+    /// Decommissioning observed, nothing sent yet
+    Requested {
+        /// Resource being decommissioned
+        resource_id: Uuid,
+
+        /// Policies attached when it was decommissioned
+        policy_ids: Vec<PolicyId>,
+
+        /// Guests placed on it when it was decommissioned
+        guest_ids: Vec<Uuid>,
+    },
+
+    /// `RemovePolicy` and `DetachGuest` sent, waiting for the results
+    Detaching {
+        /// Resource being decommissioned
+        resource_id: Uuid,
+
+        /// Policies not yet removed
+        policies: Vec<PolicyId>,
+
+        /// Guests not yet detached
+        guests: Vec<Uuid>,
+    },
+
+    /// Every step finished
+    Completed {
+        /// Resource that was decommissioned
+        resource_id: Uuid,
+    },
+}
+
+impl DecommissionSaga {
+    fn state_name(&self) -> &'static str {
+        match self {
+            DecommissionSaga::Requested { .. } => "Requested",
+            DecommissionSaga::Detaching { .. } => "Detaching",
+            DecommissionSaga::Completed { .. } => "Completed",
+        }
+    }
+}
+
+fn is_decommissioning(event: &InfrastructureEvent) -> bool {
+    matches!(
+        event,
+        InfrastructureEvent::ComputeResource(ComputeResourceEvent::StatusChanged(e))
+            if e.to_status == ResourceStatus::Decommissioned
+    )
+}
+
+impl StateMachine for DecommissionSaga {
+    type Input = StoredEvent<InfrastructureEvent>;
+    type Output = Vec<InfrastructureCommand>;
+
+    fn transition(&self, input: &Self::Input) -> TransitionResult<(Self, Self::Output)> {
+        match self {
+            DecommissionSaga::Requested {
+                resource_id,
+                policy_ids,
+                guest_ids,
+            } => {
+                if !is_decommissioning(&input.data) {
+                    return Err(TransitionError::InvalidTransition {
+                        from: self.state_name().to_string(),
+                        to: input.event_type.clone(),
+                    });
+                }
+
+                let context = CommandContext::caused_by(input);
+                let removals = policy_ids.iter().map(|policy_id| {
+                    ComputeResourceCommand::RemovePolicy(RemovePolicyCommand {
+                        policy_id: policy_id.clone(),
+                        timestamp: context.timestamp,
+                        correlation_id: context.correlation_id,
+                        causation_id: context.causation_id,
+                        change_ref: None,
+                    })
+                });
+                let detachments = guest_ids.iter().map(|guest_id| {
+                    ComputeResourceCommand::DetachGuest(DetachGuestCommand {
+                        guest_id: *guest_id,
+                        timestamp: context.timestamp,
+                        correlation_id: context.correlation_id,
+                        causation_id: context.causation_id,
+                        change_ref: None,
+                    })
+                });
+                let commands = removals
+                    .chain(detachments)
+                    .map(|command| InfrastructureCommand::ComputeResource {
+                        aggregate_id: Some(*resource_id),
+                        command,
+                    })
+                    .collect();
+
+                Ok((
+                    DecommissionSaga::Detaching {
+                        resource_id: *resource_id,
+                        policies: policy_ids.clone(),
+                        guests: guest_ids.clone(),
+                    },
+                    commands,
+                ))
+            }
+
+            DecommissionSaga::Detaching {
+                resource_id,
+                policies,
+                guests,
+            } => {
+                let mut policies = policies.clone();
+                let mut guests = guests.clone();
+                match &input.data {
+                    InfrastructureEvent::ComputeResource(ComputeResourceEvent::PolicyRemoved(
+                        removed,
+                    )) => policies.retain(|policy_id| *policy_id != removed.policy_id),
+                    InfrastructureEvent::ComputeResource(ComputeResourceEvent::GuestDetached(
+                        detached,
+                    )) => guests.retain(|guest_id| *guest_id != detached.guest_id),
+                    _ => return Ok((self.clone(), Vec::new())),
+                }
+
+                let next = if policies.is_empty() && guests.is_empty() {
+                    DecommissionSaga::Completed {
+                        resource_id: *resource_id,
+                    }
+                } else {
+                    DecommissionSaga::Detaching {
+                        resource_id: *resource_id,
+                        policies,
+                        guests,
+                    }
+                };
+                Ok((next, Vec::new()))
+            }
+
+            DecommissionSaga::Completed { .. } => Ok((self.clone(), Vec::new())),
+        }
+    }
+}
+
+impl Saga for DecommissionSaga {
+    fn name() -> &'static str {
+        "decommission_saga"
+    }
+
+    fn starts(event: &InfrastructureEvent) -> bool {
+        is_decommissioning(event)
+    }
+
+    fn start(
+        event: &StoredEvent<InfrastructureEvent>,
+        resource: &ComputeResourceState,
+    ) -> Option<Self> {
+        if resource.policy_ids.is_empty() && resource.guest_ids.is_empty() {
+            return None;
+        }
+
+        Some(DecommissionSaga::Requested {
+            resource_id: event.aggregate_id,
+            policy_ids: resource.policy_ids.clone(),
+            guest_ids: resource.guest_ids.clone(),
+        })
+    }
+
+    fn is_complete(&self) -> bool {
+        matches!(self, DecommissionSaga::Completed { .. })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{GuestDetached, PolicyRemoved, StatusChanged};
+    use crate::process_manager::ProcessManager;
+    use chrono::{DateTime, Utc};
+
+    fn test_timestamp() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-19T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn stored(
+        event: ComputeResourceEvent,
+        correlation_id: Uuid,
+    ) -> StoredEvent<InfrastructureEvent> {
+        let event_id = Uuid::now_v7();
+        let event_type = event.event_type_name().to_string();
+        StoredEvent::new(
+            event_id,
+            event.aggregate_id(),
+            1,
+            correlation_id,
+            event_id,
+            event_type,
+            InfrastructureEvent::ComputeResource(event),
+        )
+    }
+
+    fn decommissioned(
+        aggregate_id: Uuid,
+        correlation_id: Uuid,
+    ) -> StoredEvent<InfrastructureEvent> {
+        stored(
+            ComputeResourceEvent::StatusChanged(StatusChanged {
+                event_version: 1,
+                event_id: Uuid::now_v7(),
+                aggregate_id,
+                timestamp: test_timestamp(),
+                correlation_id,
+                causation_id: None,
+                from_status: ResourceStatus::Active,
+                to_status: ResourceStatus::Decommissioned,
+            }),
+            correlation_id,
+        )
+    }
+
+    fn policy_removed(aggregate_id: Uuid, policy_id: PolicyId) -> StoredEvent<InfrastructureEvent> {
+        let correlation_id = Uuid::now_v7();
+        stored(
+            ComputeResourceEvent::PolicyRemoved(PolicyRemoved {
+                event_version: 1,
+                event_id: Uuid::now_v7(),
+                aggregate_id,
+                timestamp: test_timestamp(),
+                correlation_id,
+                causation_id: None,
+                policy_id,
+            }),
+            correlation_id,
+        )
+    }
+
+    #[test]
+    fn test_decommission_detaches_policies() {
+        // Arrange
+        let aggregate_id = Uuid::now_v7();
+        let correlation_id = Uuid::now_v7();
+        let policies = vec![PolicyId::new(), PolicyId::new()];
+        let mut resource = ComputeResourceState::default_for(aggregate_id);
+        resource.policy_ids = policies.clone();
+        let mut manager = ProcessManager::<DecommissionSaga>::new();
+        let trigger = decommissioned(aggregate_id, correlation_id);
+
+        // Act
+        assert!(manager.would_start(&trigger));
+        let commands = manager.handle(&trigger, Some(&resource)).unwrap();
+
+        // Assert: one RemovePolicy per policy, continuing the trace
+        assert_eq!(commands.len(), 2);
+        for command in &commands {
+            let InfrastructureCommand::ComputeResource {
+                aggregate_id: target,
+                command: ComputeResourceCommand::RemovePolicy(remove),
+            } = command
+            else {
+                panic!("expected RemovePolicy, got {:?}", command);
+            };
+            assert_eq!(*target, Some(aggregate_id));
+            assert_eq!(remove.correlation_id, correlation_id);
+            assert_eq!(remove.causation_id, Some(trigger.data.event_id()));
+        }
+        assert_eq!(manager.active(), 1);
+
+        // Act: the removals arrive
+        for policy_id in policies {
+            let follow_up = manager
+                .handle(&policy_removed(aggregate_id, policy_id), None)
+                .unwrap();
+            assert!(follow_up.is_empty());
+        }
+
+        // Assert
+        assert_eq!(manager.active(), 0);
+        assert_eq!(manager.completed(), 1);
+    }
+
+    #[test]
+    fn test_decommission_detaches_guests() {
+        // Arrange: a hypervisor with one VM and no policies
+        let aggregate_id = Uuid::now_v7();
+        let guest_id = Uuid::now_v7();
+        let mut resource = ComputeResourceState::default_for(aggregate_id);
+        resource.guest_ids = vec![guest_id];
+        let mut manager = ProcessManager::<DecommissionSaga>::new();
+
+        // Act
+        let commands = manager
+            .handle(
+                &decommissioned(aggregate_id, Uuid::now_v7()),
+                Some(&resource),
+            )
+            .unwrap();
+
+        // Assert
+        assert!(matches!(
+            commands.as_slice(),
+            [InfrastructureCommand::ComputeResource {
+                command: ComputeResourceCommand::DetachGuest(detach),
+                ..
+            }] if detach.guest_id == guest_id
+        ));
+
+        // Act: the detachment arrives
+        let correlation_id = Uuid::now_v7();
+        let detached = stored(
+            ComputeResourceEvent::GuestDetached(GuestDetached {
+                event_version: 1,
+                event_id: Uuid::now_v7(),
+                aggregate_id,
+                timestamp: test_timestamp(),
+                correlation_id,
+                causation_id: None,
+                guest_id,
+            }),
+            correlation_id,
+        );
+        manager.handle(&detached, None).unwrap();
+
+        // Assert
+        assert_eq!(manager.completed(), 1);
+    }
+
+    #[test]
+    fn test_resource_without_policies_starts_nothing() {
+        let aggregate_id = Uuid::now_v7();
+        let resource = ComputeResourceState::default_for(aggregate_id);
+        let mut manager = ProcessManager::<DecommissionSaga>::new();
+
+        let commands = manager
+            .handle(
+                &decommissioned(aggregate_id, Uuid::now_v7()),
+                Some(&resource),
+            )
+            .unwrap();
+
+        assert!(commands.is_empty());
+        assert_eq!(manager.active(), 0);
+    }
+
+    #[test]
+    fn test_requested_rejects_other_events() {
+        let aggregate_id = Uuid::now_v7();
+        let saga = DecommissionSaga::Requested {
+            resource_id: aggregate_id,
+            policy_ids: vec![PolicyId::new()],
+            guest_ids: Vec::new(),
+        };
+
+        let result = saga.transition(&policy_removed(aggregate_id, PolicyId::new()));
+
+        assert!(matches!(
+            result,
+            Err(TransitionError::InvalidTransition { .. })
+        ));
+    }
+}
@@ -0,0 +1,290 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Process Managers (Sagas)
+//!
+//! Cross-aggregate workflows that react to events and issue follow-up
+//! commands. Each workflow is a [`Saga`]: a [`StateMachine`] whose input is
+//! a stored event and whose output is the commands to send next.
+//!
+//! # Architecture
+//!
+//! ```text
+//! StoredEvent ──> ProcessManager<S>
+//!                   │ no instance for key && S::starts(event)
+//!                   │     └── S::start(event, resource) ──> new instance
+//!                   │ instance.transition_with_history(event)
+//!                   ▼
+//!           Vec<InfrastructureCommand>
+//!                   │ SagaProjection: service.execute(...)
+//!                   ▼
+//!             new events ──> back into the ProcessManager
+//! ```
+//!
+//! Instances are keyed by [`Saga::instance_key`] (the aggregate ID by
+//! default) and dropped once [`Saga::is_complete`] holds. Transition history
+//! is kept per instance via [`StateMachineWithHistory`].
+//!
+//! # Correlation
+//!
+//! Follow-up commands continue the workflow's trace: they carry the
+//! triggering event's correlation ID and use the triggering event ID as
+//! their causation ID (see [`CommandContext`]).
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::process_manager::{DecommissionSaga, SagaProjection};
+//!
+//! let saga = SagaProjection::<DecommissionSaga, _>::new(Arc::new(service));
+//! manager.register(saga);
+//! ```
+
+pub mod decommission;
+
+pub use decommission::DecommissionSaga;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+use crate::aggregate::ComputeResourceState;
+use crate::events::InfrastructureEvent;
+use crate::jetstream::StoredEvent;
+use crate::projection::{ProjectionAdapter, ProjectionError};
+use crate::service::{ComputeResourceService, InfrastructureCommand, ServiceError};
+use crate::state_machine::{StateMachine, StateMachineWithHistory, TransitionResult};
+
+/// A cross-aggregate workflow
+///
+/// Implementors are the workflow state. Transitions must be pure: they
+/// decide which commands to send, the runner sends them.
+pub trait Saga:
+    StateMachine<Input = StoredEvent<InfrastructureEvent>, Output = Vec<InfrastructureCommand>>
+    + Send
+    + Sync
+{
+    /// Workflow name used in logs
+    fn name() -> &'static str;
+
+    /// Whether `event` begins a new workflow instance
+    fn starts(event: &InfrastructureEvent) -> bool;
+
+    /// Initial state for a workflow started by `event`
+    ///
+    /// `resource` is the current state of the event's aggregate. The
+    /// triggering event is then fed through [`StateMachine::transition`].
+    /// Returns `None` when there is nothing to do.
+    fn start(
+        event: &StoredEvent<InfrastructureEvent>,
+        resource: &ComputeResourceState,
+    ) -> Option<Self>;
+
+    /// Instance an event belongs to
+    fn instance_key(event: &StoredEvent<InfrastructureEvent>) -> Uuid {
+        event.aggregate_id
+    }
+
+    /// Whether the workflow has finished
+    fn is_complete(&self) -> bool;
+}
+
+/// Correlation data for commands issued in response to an event
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommandContext {
+    /// Correlation ID of the workflow
+    pub correlation_id: Uuid,
+
+    /// Event that caused the command
+    pub causation_id: Option<Uuid>,
+
+    /// Command timestamp (the event's time, keeping transitions pure)
+    pub timestamp: DateTime<Utc>,
+}
+
+impl CommandContext {
+    /// Context for commands caused by `event`
+    pub fn caused_by(event: &StoredEvent<InfrastructureEvent>) -> Self {
+        Self {
+            correlation_id: event.correlation_id,
+            causation_id: Some(event.data.event_id()),
+            timestamp: event.data.timestamp(),
+        }
+    }
+}
+
+/// Tracks running instances of one saga
+pub struct ProcessManager<S: Saga> {
+    instances: HashMap<Uuid, StateMachineWithHistory<S>>,
+    completed: u64,
+}
+
+impl<S: Saga> Default for ProcessManager<S> {
+    fn default() -> Self {
+        Self {
+            instances: HashMap::new(),
+            completed: 0,
+        }
+    }
+}
+
+impl<S: Saga> ProcessManager<S> {
+    /// Manager with no running instances
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether handling `event` would start an instance
+    ///
+    /// The runner uses this to load the aggregate state only when needed.
+    pub fn would_start(&self, event: &StoredEvent<InfrastructureEvent>) -> bool {
+        !self.instances.contains_key(&S::instance_key(event)) && S::starts(&event.data)
+    }
+
+    /// Feed an event to its instance, starting one if the event triggers it
+    ///
+    /// `resource` is required to start an instance (see
+    /// [`ProcessManager::would_start`]) and ignored otherwise.
+    pub fn handle(
+        &mut self,
+        event: &StoredEvent<InfrastructureEvent>,
+        resource: Option<&ComputeResourceState>,
+    ) -> TransitionResult<Vec<InfrastructureCommand>> {
+        let key = S::instance_key(event);
+
+        if !self.instances.contains_key(&key) {
+            let started = match resource {
+                Some(resource) if S::starts(&event.data) => S::start(event, resource),
+                _ => None,
+            };
+            let Some(initial) = started else {
+                return Ok(Vec::new());
+            };
+            debug!("{} started for {}", S::name(), key);
+            self.instances
+                .insert(key, StateMachineWithHistory::new(initial));
+        }
+
+        let instance = self
+            .instances
+            .get_mut(&key)
+            .expect("instance inserted above");
+        let commands = instance.transition_with_history(event.clone(), event.data.timestamp())?;
+
+        if instance.current_state().is_complete() {
+            debug!("{} completed for {}", S::name(), key);
+            self.instances.remove(&key);
+            self.completed += 1;
+        }
+
+        Ok(commands)
+    }
+
+    /// Running instance for a key
+    pub fn instance(&self, key: Uuid) -> Option<&StateMachineWithHistory<S>> {
+        self.instances.get(&key)
+    }
+
+    /// Number of running instances
+    pub fn active(&self) -> usize {
+        self.instances.len()
+    }
+
+    /// Number of instances that ran to completion
+    pub fn completed(&self) -> u64 {
+        self.completed
+    }
+}
+
+/// Runs a saga as a projection, sending its commands through the service
+///
+/// Commands that fail are logged and skipped; the instance keeps waiting
+/// for the events it expects. Sagas issue commands, so rebuilding this
+/// projection from the start of the stream re-runs old workflows — start it
+/// from the live position instead.
+pub struct SagaProjection<S: Saga, Svc> {
+    manager: ProcessManager<S>,
+    service: Arc<Svc>,
+    _saga: PhantomData<fn() -> S>,
+}
+
+impl<S: Saga, Svc: ComputeResourceService> SagaProjection<S, Svc> {
+    /// Run saga `S` against `service`
+    pub fn new(service: Arc<Svc>) -> Self {
+        Self {
+            manager: ProcessManager::new(),
+            service,
+            _saga: PhantomData,
+        }
+    }
+
+    /// Running instances
+    pub fn manager(&self) -> &ProcessManager<S> {
+        &self.manager
+    }
+
+    async fn dispatch(&self, command: InfrastructureCommand) {
+        let name = command.command_name();
+        let result = match command {
+            InfrastructureCommand::ComputeResource {
+                aggregate_id,
+                command,
+            } => self.service.execute(aggregate_id, command).await,
+        };
+        if let Err(e) = result {
+            warn!("{} could not send {}: {}", S::name(), name, e);
+        }
+    }
+}
+
+#[async_trait]
+impl<S, Svc> ProjectionAdapter for SagaProjection<S, Svc>
+where
+    S: Saga + 'static,
+    Svc: ComputeResourceService + 'static,
+{
+    type Event = StoredEvent<InfrastructureEvent>;
+    type Error = ProjectionError;
+
+    async fn project(&mut self, event: Self::Event) -> Result<(), Self::Error> {
+        let resource = if self.manager.would_start(&event) {
+            match self.service.get_resource(event.aggregate_id).await {
+                Ok(state) => Some(state),
+                Err(ServiceError::NotFound(_)) => None,
+                Err(e) => return Err(ProjectionError::TargetUnavailable(e.to_string())),
+            }
+        } else {
+            None
+        };
+
+        let commands = self
+            .manager
+            .handle(&event, resource.as_ref())
+            .map_err(|e| ProjectionError::InvalidEvent(format!("{}: {}", S::name(), e)))?;
+
+        for command in commands {
+            self.dispatch(command).await;
+        }
+
+        Ok(())
+    }
+
+    async fn initialize(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    async fn health_check(&self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    async fn reset(&mut self) -> Result<(), Self::Error> {
+        self.manager = ProcessManager::new();
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        S::name()
+    }
+}
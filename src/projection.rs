@@ -64,8 +64,18 @@
 //! }
 //! ```
 
+pub mod change_feed;
+pub mod decrypting;
 pub mod executor;
+pub mod fanout;
+pub mod ip_pool;
+pub mod manager;
 pub mod pure;
+pub mod read_model;
+pub mod registry;
+pub mod resource_kv;
+pub mod retry;
+pub mod timeline;
 
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
@@ -175,12 +185,6 @@ impl fmt::Display for ProjectionError {
 
 impl std::error::Error for ProjectionError {}
 
-/// Projection manager for coordinating multiple projections
-///
-/// Note: For simplicity, projection coordination is typically done at the
-/// application level. If you need to fan-out events to multiple projections,
-/// consider using NATS subscriptions with different consumer groups.
-
 #[cfg(test)]
 mod tests {
     use super::*;
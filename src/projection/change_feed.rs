@@ -0,0 +1,571 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Organization Change Feed Projection
+//!
+//! Derives a per-organization activity feed from infrastructure events.
+//! Each feed entry is a short, human-readable summary ("web01 moved to
+//! maintenance") suitable for end-user activity streams, published to
+//! `infrastructure.feed.{organization}`.
+//!
+//! # Architecture
+//!
+//! ```text
+//! StoredEvent ──> ChangeFeed (pure) ──> [ChangeFeedEntry] ──> infrastructure.feed.{org}
+//!                   │
+//!                   ├─ resource → organization routing
+//!                   ├─ buffering until an organization is known
+//!                   └─ redelivery suppression
+//! ```
+//!
+//! # Deduplication
+//!
+//! Entries are deduplicated twice:
+//! 1. The pure [`ChangeFeed`] drops redelivered events, recognised by the
+//!    ID of the domain event among the last [`SEEN_EVENTS_CAPACITY`] it
+//!    applied. Distinct events with the same summary (a resource moved
+//!    twice) are both kept.
+//! 2. Published entries carry a `Nats-Msg-Id` so JetStream discards
+//!    duplicates within its deduplication window.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::projection::change_feed::ChangeFeedProjection;
+//!
+//! let mut projection = ChangeFeedProjection::new(jetstream, "INFRASTRUCTURE_EVENTS");
+//! projection.project(stored_event).await?;
+//! ```
+
+use async_nats::jetstream;
+use async_nats::HeaderMap;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use uuid::Uuid;
+
+use crate::events::{ComputeResourceEvent, InfrastructureEvent, ResourceStatus};
+use crate::jetstream::StoredEvent;
+use crate::projection::{ProjectionAdapter, ProjectionError};
+use crate::subjects::subjects;
+
+/// Number of recent event IDs remembered to drop redeliveries
+pub const SEEN_EVENTS_CAPACITY: usize = 10_000;
+
+/// Category of a change feed entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    /// Resource was registered
+    Registered,
+
+    /// Resource joined this organization
+    TransferredIn,
+
+    /// Resource left this organization
+    TransferredOut,
+
+    /// Resource moved to another location
+    Moved,
+
+    /// Responsible person changed
+    OwnerChanged,
+
+    /// Lifecycle status changed
+    StatusChanged,
+
+    /// Policy attached or detached
+    PoliciesChanged,
+
+    /// Hardware details or asset tag changed
+    HardwareUpdated,
+
+    /// Descriptive metadata changed
+    MetadataUpdated,
+}
+
+/// A summarized change suitable for activity feeds
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ChangeFeedEntry {
+    /// Event that produced this entry
+    pub source_event_id: Uuid,
+
+    /// Organization whose feed carries this entry
+    pub organization_id: String,
+
+    /// Resource the change applies to
+    pub resource_id: Uuid,
+
+    /// Resource hostname, when known
+    pub hostname: Option<String>,
+
+    /// Change category
+    pub kind: ChangeKind,
+
+    /// Human-readable summary
+    pub summary: String,
+
+    /// When the change happened
+    pub occurred_at: DateTime<Utc>,
+
+    /// Correlation ID of the originating request
+    pub correlation_id: Uuid,
+}
+
+impl ChangeFeedEntry {
+    /// Deduplication key used as `Nats-Msg-Id`
+    pub fn dedup_key(&self) -> String {
+        format!("feed-{}-{}", self.organization_id, self.source_event_id)
+    }
+}
+
+/// Pure change feed state machine
+///
+/// Tracks which organization each resource belongs to and turns events into
+/// feed entries. Changes observed before a resource has an organization are
+/// buffered and released once one is assigned.
+#[derive(Debug, Default, Clone)]
+pub struct ChangeFeed {
+    organizations: HashMap<Uuid, String>,
+    hostnames: HashMap<Uuid, String>,
+    pending: HashMap<Uuid, Vec<ChangeFeedEntry>>,
+    seen_events: HashSet<Uuid>,
+    seen_order: VecDeque<Uuid>,
+}
+
+impl ChangeFeed {
+    /// Create an empty change feed
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Organization a resource currently belongs to
+    pub fn organization_of(&self, resource_id: Uuid) -> Option<&str> {
+        self.organizations.get(&resource_id).map(String::as_str)
+    }
+
+    /// Apply an event, returning the feed entries ready to publish
+    pub fn apply(&mut self, event: &InfrastructureEvent) -> Vec<ChangeFeedEntry> {
+        let event_id = event.event_id();
+        if !self.first_delivery(event_id) {
+            return Vec::new();
+        }
+
+        let InfrastructureEvent::ComputeResource(compute) = event else {
+            return Vec::new();
+        };
+        let resource_id = compute.aggregate_id();
+
+        if let ComputeResourceEvent::ResourceRegistered(e) = compute {
+            self.hostnames
+                .insert(resource_id, e.hostname.as_str().to_string());
+        }
+
+        if let ComputeResourceEvent::OrganizationAssigned(e) = compute {
+            return self.reassign(event_id, event, e.organization_id.to_string());
+        }
+
+        let Some((kind, summary)) = self.summarize(compute) else {
+            return Vec::new();
+        };
+
+        let entry = self.entry(event_id, event, String::new(), kind, summary);
+
+        match self.organizations.get(&resource_id) {
+            Some(org) => vec![ChangeFeedEntry {
+                organization_id: org.clone(),
+                ..entry
+            }],
+            None => {
+                self.pending.entry(resource_id).or_default().push(entry);
+                Vec::new()
+            }
+        }
+    }
+
+    /// Remember `event_id`; false if it was applied recently
+    fn first_delivery(&mut self, event_id: Uuid) -> bool {
+        if !self.seen_events.insert(event_id) {
+            return false;
+        }
+        self.seen_order.push_back(event_id);
+        while self.seen_order.len() > SEEN_EVENTS_CAPACITY {
+            if let Some(oldest) = self.seen_order.pop_front() {
+                self.seen_events.remove(&oldest);
+            }
+        }
+        true
+    }
+
+    fn reassign(
+        &mut self,
+        event_id: Uuid,
+        event: &InfrastructureEvent,
+        organization_id: String,
+    ) -> Vec<ChangeFeedEntry> {
+        let resource_id = event.aggregate_id();
+        let previous = self
+            .organizations
+            .insert(resource_id, organization_id.clone());
+
+        if previous.as_deref() == Some(organization_id.as_str()) {
+            return Vec::new();
+        }
+
+        let mut entries = Vec::new();
+        let name = self.display_name(resource_id);
+
+        if let Some(previous) = previous {
+            entries.push(self.entry(
+                event_id,
+                event,
+                previous,
+                ChangeKind::TransferredOut,
+                format!("{} transferred to another organization", name),
+            ));
+        }
+
+        // Release changes buffered before the organization was known
+        for pending in self.pending.remove(&resource_id).unwrap_or_default() {
+            entries.push(ChangeFeedEntry {
+                organization_id: organization_id.clone(),
+                ..pending
+            });
+        }
+
+        entries.push(self.entry(
+            event_id,
+            event,
+            organization_id,
+            ChangeKind::TransferredIn,
+            format!("{} joined the organization", name),
+        ));
+
+        entries
+    }
+
+    fn summarize(&self, event: &ComputeResourceEvent) -> Option<(ChangeKind, String)> {
+        let name = self.display_name(event.aggregate_id());
+
+        let change = match event {
+            ComputeResourceEvent::ResourceRegistered(e) => (
+                ChangeKind::Registered,
+                format!("{} registered as {}", name, e.resource_type.display_name()),
+            ),
+            ComputeResourceEvent::StatusChanged(e) if e.from_status != e.to_status => (
+                ChangeKind::StatusChanged,
+                match e.to_status {
+                    ResourceStatus::Provisioning => format!("{} is being provisioned", name),
+                    ResourceStatus::Active => format!("{} is now active", name),
+                    ResourceStatus::Maintenance => format!("{} moved to maintenance", name),
+                    ResourceStatus::Decommissioned => format!("{} was decommissioned", name),
+                },
+            ),
+            ComputeResourceEvent::LocationAssigned(_) => {
+                (ChangeKind::Moved, format!("{} moved to a new location", name))
+            }
+            ComputeResourceEvent::OwnerAssigned(_) => {
+                (ChangeKind::OwnerChanged, format!("{} has a new owner", name))
+            }
+            ComputeResourceEvent::PolicyAdded(_) => (
+                ChangeKind::PoliciesChanged,
+                format!("Policy applied to {}", name),
+            ),
+            ComputeResourceEvent::PolicyRemoved(_) => (
+                ChangeKind::PoliciesChanged,
+                format!("Policy removed from {}", name),
+            ),
+            ComputeResourceEvent::HardwareDetailsSet(_)
+            | ComputeResourceEvent::AssetTagAssigned(_) => (
+                ChangeKind::HardwareUpdated,
+                format!("{} hardware details updated", name),
+            ),
+            ComputeResourceEvent::MetadataUpdated(e) => (
+                ChangeKind::MetadataUpdated,
+                format!("{} {} updated", name, e.key),
+            ),
+            _ => return None,
+        };
+
+        Some(change)
+    }
+
+    fn entry(
+        &self,
+        event_id: Uuid,
+        event: &InfrastructureEvent,
+        organization_id: String,
+        kind: ChangeKind,
+        summary: String,
+    ) -> ChangeFeedEntry {
+        let resource_id = event.aggregate_id();
+
+        ChangeFeedEntry {
+            source_event_id: event_id,
+            organization_id,
+            resource_id,
+            hostname: self.hostnames.get(&resource_id).cloned(),
+            kind,
+            summary,
+            occurred_at: event.timestamp(),
+            correlation_id: event.correlation_id(),
+        }
+    }
+
+    fn display_name(&self, resource_id: Uuid) -> String {
+        self.hostnames
+            .get(&resource_id)
+            .cloned()
+            .unwrap_or_else(|| format!("Resource {}", resource_id))
+    }
+}
+
+/// Projection publishing the change feed to JetStream
+pub struct ChangeFeedProjection {
+    /// JetStream context used for publishing and purging
+    jetstream: jetstream::Context,
+
+    /// Stream that captures `infrastructure.feed.>`
+    stream_name: String,
+
+    /// Pure feed state
+    feed: ChangeFeed,
+}
+
+impl ChangeFeedProjection {
+    /// Create a change feed projection
+    pub fn new(jetstream: jetstream::Context, stream_name: impl Into<String>) -> Self {
+        Self {
+            jetstream,
+            stream_name: stream_name.into(),
+            feed: ChangeFeed::new(),
+        }
+    }
+
+    async fn publish(&self, entry: &ChangeFeedEntry) -> Result<(), ProjectionError> {
+        let payload = serde_json::to_vec(entry)
+            .map_err(|e| ProjectionError::InvalidEvent(e.to_string()))?;
+
+        let mut headers = HeaderMap::new();
+        headers.insert(async_nats::header::NATS_MESSAGE_ID, entry.dedup_key().as_str());
+
+        self.jetstream
+            .publish_with_headers(
+                subjects::organization_feed(&entry.organization_id),
+                headers,
+                payload.into(),
+            )
+            .await
+            .map_err(|e| ProjectionError::TargetUnavailable(e.to_string()))?
+            .await
+            .map_err(|e| ProjectionError::TargetUnavailable(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProjectionAdapter for ChangeFeedProjection {
+    type Event = StoredEvent<InfrastructureEvent>;
+    type Error = ProjectionError;
+
+    async fn project(&mut self, event: Self::Event) -> Result<(), Self::Error> {
+        let entries = self.feed.apply(&event.data);
+
+        for entry in &entries {
+            self.publish(entry).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn initialize(&mut self) -> Result<(), Self::Error> {
+        self.health_check().await
+    }
+
+    async fn health_check(&self) -> Result<(), Self::Error> {
+        self.jetstream
+            .get_stream(&self.stream_name)
+            .await
+            .map_err(|e| ProjectionError::TargetUnavailable(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn reset(&mut self) -> Result<(), Self::Error> {
+        let stream = self
+            .jetstream
+            .get_stream(&self.stream_name)
+            .await
+            .map_err(|e| ProjectionError::TargetUnavailable(e.to_string()))?;
+
+        stream
+            .purge()
+            .filter(subjects::all_organization_feeds())
+            .await
+            .map_err(|e| ProjectionError::DatabaseError(e.to_string()))?;
+
+        self.feed = ChangeFeed::new();
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "change-feed"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Hostname, ResourceType};
+    use crate::events::compute_resource::{
+        LocationAssigned, OrganizationAssigned, ResourceRegistered, StatusChanged,
+    };
+    use cim_domain::EntityId;
+
+    fn test_timestamp() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-19T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn test_aggregate_id() -> Uuid {
+        Uuid::parse_str("01934f4a-1000-7000-8000-000000001000").unwrap()
+    }
+
+    fn registered() -> InfrastructureEvent {
+        InfrastructureEvent::ComputeResource(ComputeResourceEvent::ResourceRegistered(
+            ResourceRegistered {
+                event_version: 1,
+                event_id: Uuid::now_v7(),
+                aggregate_id: test_aggregate_id(),
+                timestamp: test_timestamp(),
+                correlation_id: Uuid::now_v7(),
+                causation_id: None,
+                hostname: Hostname::new("web01").unwrap(),
+                resource_type: ResourceType::PhysicalServer,
+            },
+        ))
+    }
+
+    fn org_assigned() -> InfrastructureEvent {
+        InfrastructureEvent::ComputeResource(ComputeResourceEvent::OrganizationAssigned(
+            OrganizationAssigned {
+                event_version: 1,
+                event_id: Uuid::now_v7(),
+                aggregate_id: test_aggregate_id(),
+                timestamp: test_timestamp(),
+                correlation_id: Uuid::now_v7(),
+                causation_id: None,
+                organization_id: EntityId::new(),
+            },
+        ))
+    }
+
+    fn to_maintenance() -> InfrastructureEvent {
+        InfrastructureEvent::ComputeResource(ComputeResourceEvent::StatusChanged(StatusChanged {
+            event_version: 1,
+            event_id: Uuid::now_v7(),
+            aggregate_id: test_aggregate_id(),
+            timestamp: test_timestamp(),
+            correlation_id: Uuid::now_v7(),
+            causation_id: None,
+            from_status: ResourceStatus::Active,
+            to_status: ResourceStatus::Maintenance,
+        }))
+    }
+
+    fn location_assigned() -> InfrastructureEvent {
+        InfrastructureEvent::ComputeResource(ComputeResourceEvent::LocationAssigned(
+            LocationAssigned {
+                event_version: 1,
+                event_id: Uuid::now_v7(),
+                aggregate_id: test_aggregate_id(),
+                timestamp: test_timestamp(),
+                correlation_id: Uuid::now_v7(),
+                causation_id: None,
+                location_id: EntityId::new(),
+            },
+        ))
+    }
+
+    #[test]
+    fn test_entries_buffered_until_organization_known() {
+        // Arrange
+        let mut feed = ChangeFeed::new();
+
+        // Act
+        let before = feed.apply(&registered());
+        let released = feed.apply(&org_assigned());
+
+        // Assert
+        assert!(before.is_empty());
+        assert_eq!(released.len(), 2);
+        assert_eq!(released[0].kind, ChangeKind::Registered);
+        assert_eq!(released[0].summary, "web01 registered as Physical Server");
+        assert_eq!(released[1].kind, ChangeKind::TransferredIn);
+        assert_eq!(released[0].organization_id, released[1].organization_id);
+    }
+
+    #[test]
+    fn test_redelivered_event_is_dropped() {
+        let mut feed = ChangeFeed::new();
+        feed.apply(&org_assigned());
+
+        let change = to_maintenance();
+        let first = feed.apply(&change);
+        let redelivered = feed.apply(&change);
+
+        assert_eq!(first.len(), 1);
+        assert!(redelivered.is_empty());
+    }
+
+    #[test]
+    fn test_only_recent_event_ids_are_remembered() {
+        // Arrange
+        let mut feed = ChangeFeed::new();
+        let oldest = Uuid::now_v7();
+
+        // Act
+        feed.first_delivery(oldest);
+        for _ in 0..SEEN_EVENTS_CAPACITY {
+            feed.first_delivery(Uuid::now_v7());
+        }
+
+        // Assert
+        assert_eq!(feed.seen_events.len(), SEEN_EVENTS_CAPACITY);
+        assert!(feed.first_delivery(oldest));
+    }
+
+    #[test]
+    fn test_distinct_changes_with_the_same_summary_are_kept() {
+        // Arrange
+        let mut feed = ChangeFeed::new();
+        feed.apply(&org_assigned());
+
+        // Act
+        let first = feed.apply(&location_assigned());
+        let second = feed.apply(&location_assigned());
+
+        // Assert
+        assert_eq!(first.len(), 1);
+        assert_eq!(second.len(), 1);
+        assert_eq!(first[0].summary, second[0].summary);
+        assert_eq!(second[0].kind, ChangeKind::Moved);
+        assert_ne!(first[0].source_event_id, second[0].source_event_id);
+    }
+
+    #[test]
+    fn test_reassignment_notifies_both_organizations() {
+        let mut feed = ChangeFeed::new();
+        let first = feed.apply(&org_assigned());
+        let second = feed.apply(&org_assigned());
+
+        assert_eq!(second.len(), 2);
+        assert_eq!(second[0].kind, ChangeKind::TransferredOut);
+        assert_eq!(second[0].organization_id, first[0].organization_id);
+        assert_eq!(second[1].kind, ChangeKind::TransferredIn);
+        assert_ne!(second[1].organization_id, first[0].organization_id);
+    }
+}
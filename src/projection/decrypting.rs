@@ -0,0 +1,89 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Authorized Decryption for Projections
+//!
+//! Projections normally see encrypted fields only as opaque envelopes. A
+//! projection that is authorized to read confidential references (e.g. an
+//! internal CMDB, but not a public dashboard) is wrapped in a
+//! [`DecryptingProjection`], which reveals encrypted fields before handing
+//! the event to the inner adapter.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::projection::decrypting::DecryptingProjection;
+//!
+//! let projection = DecryptingProjection::new(neo4j_adapter, policy, Arc::new(cipher));
+//! projection.project(raw_stored_event_json).await?;
+//! ```
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::sync::Arc;
+
+use crate::events::serialization::{FieldCipher, SerializationPolicy};
+use crate::projection::{ProjectionAdapter, ProjectionError};
+
+/// Projection wrapper that decrypts protected fields for its inner adapter
+///
+/// Accepts raw stored-event JSON, reveals encrypted fields with the
+/// configured cipher and deserializes into the inner adapter's event type.
+pub struct DecryptingProjection<P> {
+    inner: P,
+    policy: SerializationPolicy,
+    cipher: Arc<dyn FieldCipher>,
+}
+
+impl<P> DecryptingProjection<P> {
+    /// Wrap a projection with decryption rights
+    pub fn new(inner: P, policy: SerializationPolicy, cipher: Arc<dyn FieldCipher>) -> Self {
+        Self {
+            inner,
+            policy,
+            cipher,
+        }
+    }
+
+    /// Access the wrapped projection
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+}
+
+#[async_trait]
+impl<P> ProjectionAdapter for DecryptingProjection<P>
+where
+    P: ProjectionAdapter,
+    P::Event: DeserializeOwned,
+    P::Error: From<ProjectionError>,
+{
+    type Event = Value;
+    type Error = P::Error;
+
+    async fn project(&mut self, mut event: Self::Event) -> Result<(), Self::Error> {
+        self.policy
+            .reveal(&mut event, self.cipher.as_ref())
+            .map_err(|e| ProjectionError::InvalidEvent(e.to_string()))?;
+
+        let decoded: P::Event = serde_json::from_value(event)
+            .map_err(|e| ProjectionError::InvalidEvent(e.to_string()))?;
+
+        self.inner.project(decoded).await
+    }
+
+    async fn initialize(&mut self) -> Result<(), Self::Error> {
+        self.inner.initialize().await
+    }
+
+    async fn health_check(&self) -> Result<(), Self::Error> {
+        self.inner.health_check().await
+    }
+
+    async fn reset(&mut self) -> Result<(), Self::Error> {
+        self.inner.reset().await
+    }
+
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+}
@@ -0,0 +1,291 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Notification Fan-Out
+//!
+//! Re-publishes stored events on per-type core NATS subjects, for
+//! subscribers that want live notifications without a JetStream consumer
+//! (dashboards, chat bots). Delivery is best effort: nothing is persisted,
+//! and subscribers that are offline miss what was published meanwhile.
+//!
+//! ```text
+//! INFRASTRUCTURE_EVENTS ──> NotificationFanout ──rules──> infrastructure.notify.compute.status_changed
+//!                                                         infrastructure.notify.network.defined
+//!                                                         …
+//! subscribers: infrastructure.notify.compute.>   (every compute event)
+//!              infrastructure.notify.*.registered (registrations of any aggregate)
+//! ```
+//!
+//! Each [`FanoutRule`] matches an aggregate and event type token (or `*`
+//! for any) and names a subject template:
+//!
+//! | Placeholder      | Replaced with                          |
+//! |------------------|----------------------------------------|
+//! | `{aggregate}`    | aggregate token, e.g. `compute`        |
+//! | `{event_type}`   | operation token, e.g. `status_changed` |
+//! | `{aggregate_id}` | aggregate UUID                         |
+//!
+//! An event is published once per distinct subject of the rules it
+//! matches. A rule's `sample_rate` publishes only that fraction of its
+//! events, chosen by a hash of the event ID so every instance and every
+//! redelivery picks the same ones. Subjects outside
+//! `infrastructure.notify.>` must be added to the
+//! [`SubjectRegistry`](crate::subjects::registry::SubjectRegistry) of a
+//! validating client.
+//!
+//! The fan-out runs as a projection under a
+//! [`ProjectionManager`](crate::projection::ProjectionManager). Rebuilding
+//! it republishes the whole history, so start it, never rebuild it.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::projection::fanout::{FanoutConfig, FanoutRule, NotificationFanout};
+//!
+//! let config = FanoutConfig::default().with_rule(
+//!     FanoutRule::new("compute", "metadata_updated", "dashboards.metadata").with_sample_rate(0.1),
+//! );
+//! manager.register(NotificationFanout::new(nats_client, config));
+//! manager.start("notification-fanout").await?;
+//! ```
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::events::InfrastructureEvent;
+use crate::jetstream::StoredEvent;
+use crate::nats::NatsClient;
+use crate::projection::{ProjectionAdapter, ProjectionError};
+use crate::subjects::subjects;
+
+/// Token matching any aggregate or event type
+pub const ANY: &str = "*";
+
+/// Where one kind of event is re-published
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FanoutRule {
+    /// Aggregate token, or `*`
+    pub aggregate: String,
+
+    /// Event type (operation) token, or `*`
+    pub event_type: String,
+
+    /// Subject template
+    pub subject: String,
+
+    /// Fraction of matching events published, from 0.0 to 1.0
+    #[serde(default = "full_rate")]
+    pub sample_rate: f64,
+}
+
+fn full_rate() -> f64 {
+    1.0
+}
+
+impl FanoutRule {
+    /// Publish every event of `aggregate` and `event_type` on `subject`
+    pub fn new(
+        aggregate: impl Into<String>,
+        event_type: impl Into<String>,
+        subject: impl Into<String>,
+    ) -> Self {
+        Self {
+            aggregate: aggregate.into(),
+            event_type: event_type.into(),
+            subject: subject.into(),
+            sample_rate: full_rate(),
+        }
+    }
+
+    /// Publish only `rate` of the matching events
+    pub fn with_sample_rate(mut self, rate: f64) -> Self {
+        self.sample_rate = rate.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Whether the rule publishes `event`
+    pub fn matches(&self, event: &StoredEvent<InfrastructureEvent>) -> bool {
+        let token = |pattern: &str, value: &str| pattern == ANY || pattern == value;
+        token(&self.aggregate, event.data.aggregate_type().as_str())
+            && token(&self.event_type, event.data.operation().as_str())
+            && sampled(event.data.event_id(), self.sample_rate)
+    }
+
+    /// Subject `event` is published on
+    pub fn subject_for(&self, event: &StoredEvent<InfrastructureEvent>) -> String {
+        self.subject
+            .replace("{aggregate}", event.data.aggregate_type().as_str())
+            .replace("{event_type}", event.data.operation().as_str())
+            .replace("{aggregate_id}", &event.aggregate_id.to_string())
+    }
+}
+
+/// Fan-out rules
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FanoutConfig {
+    /// Rules, all of which are applied to every event
+    pub rules: Vec<FanoutRule>,
+}
+
+impl Default for FanoutConfig {
+    /// Every event on `infrastructure.notify.{aggregate}.{event_type}`
+    fn default() -> Self {
+        Self {
+            rules: vec![FanoutRule::new(
+                ANY,
+                ANY,
+                subjects::notification("{aggregate}", "{event_type}"),
+            )],
+        }
+    }
+}
+
+impl FanoutConfig {
+    /// No rules yet
+    pub fn new() -> Self {
+        Self { rules: Vec::new() }
+    }
+
+    /// Add a rule
+    pub fn with_rule(mut self, rule: FanoutRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Distinct subjects `event` is published on
+    pub fn route(&self, event: &StoredEvent<InfrastructureEvent>) -> Vec<String> {
+        let mut routed = Vec::new();
+        for rule in self.rules.iter().filter(|rule| rule.matches(event)) {
+            let subject = rule.subject_for(event);
+            if !routed.contains(&subject) {
+                routed.push(subject);
+            }
+        }
+        routed
+    }
+}
+
+/// Whether an event falls within a sample of `rate`
+///
+/// Hashes the event ID, since the random bits of a v7 UUID do not span the
+/// whole range.
+fn sampled(event_id: Uuid, rate: f64) -> bool {
+    if rate >= 1.0 {
+        return true;
+    }
+    let digest = Uuid::new_v5(&Uuid::NAMESPACE_OID, event_id.as_bytes());
+    let bytes = digest.as_bytes();
+    let position = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    f64::from(position) / f64::from(u32::MAX) < rate
+}
+
+/// Projection re-publishing events on core NATS subjects
+pub struct NotificationFanout {
+    client: NatsClient,
+    config: FanoutConfig,
+}
+
+impl NotificationFanout {
+    /// Fan events out through `client` by `config`
+    pub fn new(client: NatsClient, config: FanoutConfig) -> Self {
+        Self { client, config }
+    }
+}
+
+#[async_trait]
+impl ProjectionAdapter for NotificationFanout {
+    type Event = StoredEvent<InfrastructureEvent>;
+    type Error = ProjectionError;
+
+    async fn project(&mut self, event: Self::Event) -> Result<(), Self::Error> {
+        // Best effort: a failed notification is not worth redelivering
+        for subject in self.config.route(&event) {
+            if let Err(e) = self.client.publish(&subject, &event).await {
+                warn!("Failed to publish notification on {}: {}", subject, e);
+            }
+        }
+        Ok(())
+    }
+
+    async fn initialize(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    async fn health_check(&self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    async fn reset(&mut self) -> Result<(), Self::Error> {
+        // Nothing is kept
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "notification-fanout"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_harness::{metadata_updated, registered, stored};
+
+    #[test]
+    fn test_events_are_routed_to_distinct_type_subjects() {
+        // Arrange
+        let aggregate_id = Uuid::now_v7();
+        let config = FanoutConfig::default()
+            .with_rule(FanoutRule::new(
+                "compute",
+                "registered",
+                "bots.{aggregate_id}",
+            ))
+            .with_rule(FanoutRule::new(
+                ANY,
+                ANY,
+                "infrastructure.notify.{aggregate}.{event_type}",
+            ));
+        let registration = stored(1, registered(aggregate_id, "web-01"));
+        let update = stored(2, metadata_updated(aggregate_id, "rack", "R12"));
+
+        // Act
+        let registration_subjects = config.route(&registration);
+        let update_subjects = config.route(&update);
+
+        // Assert
+        assert_eq!(
+            registration_subjects,
+            [
+                "infrastructure.notify.compute.registered".to_string(),
+                format!("bots.{}", aggregate_id),
+            ]
+        );
+        assert_eq!(
+            update_subjects,
+            ["infrastructure.notify.compute.metadata_updated"]
+        );
+    }
+
+    #[test]
+    fn test_sampling_is_stable_and_close_to_the_rate() {
+        // Arrange
+        let rule = FanoutRule::new(ANY, ANY, "sampled").with_sample_rate(0.25);
+        let events: Vec<_> = (0..2000)
+            .map(|sequence| stored(sequence, registered(Uuid::now_v7(), "web-01")))
+            .collect();
+
+        // Act
+        let published = events.iter().filter(|event| rule.matches(event)).count();
+        let again = events.iter().filter(|event| rule.matches(event)).count();
+        let mut redelivered = events[0].clone();
+        redelivered.event_id = Uuid::now_v7();
+
+        // Assert
+        assert_eq!(published, again);
+        assert_eq!(rule.matches(&redelivered), rule.matches(&events[0]));
+        assert!((400..600).contains(&published), "{}", published);
+        assert!(!FanoutRule::new(ANY, ANY, "none")
+            .with_sample_rate(0.0)
+            .matches(&events[0]));
+    }
+}
@@ -0,0 +1,331 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! IP Pool Availability Projection
+//!
+//! Keeps the current [`IpPoolState`] of every pool in memory and answers
+//! availability questions (next free address, free count, which pool serves
+//! a network) without replaying the event store.
+//!
+//! ```text
+//! StoredEvent ──> IpPoolProjection ──publish──> ArcSwap<IpPoolSnapshot>
+//!                                                     │ load (lock-free)
+//!                       IpPoolHandle (clone) ─────────┘
+//!                          next_free(pool_id)
+//!                          availability(pool_id)
+//! ```
+//!
+//! Answers reflect the events projected so far. A free address reported
+//! here may be taken by the time an allocation command runs; the IpPool
+//! aggregate remains the authority and rejects double allocations.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::projection::ip_pool::IpPoolProjection;
+//!
+//! let projection = IpPoolProjection::new();
+//! let pools = projection.handle();
+//! manager.register(projection);
+//!
+//! let address = pools.next_free(pool_id)?;
+//! ```
+
+use arc_swap::ArcSwap;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::aggregate::ip_pool::{apply_ip_pool_event, IpPoolState};
+use crate::domain::IpAddressWithCidr;
+use crate::events::InfrastructureEvent;
+use crate::jetstream::StoredEvent;
+use crate::nats::query::QueryError;
+use crate::projection::{ProjectionAdapter, ProjectionError};
+
+/// Availability summary of one pool
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct PoolAvailability {
+    /// Pool aggregate ID
+    pub pool_id: Uuid,
+
+    /// Pool name
+    pub name: String,
+
+    /// Prefix the pool allocates from
+    pub cidr: IpAddressWithCidr,
+
+    /// Network aggregate the pool serves, if any
+    pub network_id: Option<Uuid>,
+
+    /// Addresses currently allocated
+    pub allocated: usize,
+
+    /// Addresses still available
+    #[cfg_attr(feature = "typescript", ts(type = "number"))]
+    pub free: u128,
+
+    /// Lowest available address
+    pub next_free: Option<IpAddr>,
+}
+
+impl PoolAvailability {
+    /// Summarize a defined pool
+    pub fn of(state: &IpPoolState) -> Option<Self> {
+        Some(Self {
+            pool_id: state.id,
+            name: state.name.clone(),
+            cidr: state.cidr.clone()?,
+            network_id: state.network_id,
+            allocated: state.allocations.len(),
+            free: state.free_count(),
+            next_free: state.next_free(),
+        })
+    }
+}
+
+/// Pool state and the last aggregate sequence applied to it
+#[derive(Debug)]
+struct Entry {
+    state: IpPoolState,
+    sequence: u64,
+}
+
+/// Immutable view of all pools at one point in the event stream
+#[derive(Debug, Default)]
+pub struct IpPoolSnapshot {
+    pools: HashMap<Uuid, Arc<Entry>>,
+}
+
+impl IpPoolSnapshot {
+    /// Current state of a pool
+    pub fn get(&self, pool_id: Uuid) -> Option<&IpPoolState> {
+        self.pools.get(&pool_id).map(|entry| &entry.state)
+    }
+
+    /// Every pool in the snapshot, in no particular order
+    pub fn pools(&self) -> impl Iterator<Item = &IpPoolState> {
+        self.pools.values().map(|entry| &entry.state)
+    }
+}
+
+/// Cloneable query-side handle onto the projection
+#[derive(Clone)]
+pub struct IpPoolHandle {
+    snapshot: Arc<ArcSwap<IpPoolSnapshot>>,
+}
+
+impl IpPoolHandle {
+    /// Current snapshot, for several reads against one consistent view
+    pub fn snapshot(&self) -> Arc<IpPoolSnapshot> {
+        self.snapshot.load_full()
+    }
+
+    /// Current state of a pool
+    pub fn get(&self, pool_id: Uuid) -> Option<IpPoolState> {
+        self.snapshot.load().get(pool_id).cloned()
+    }
+
+    /// Availability summary of a pool
+    pub fn availability(&self, pool_id: Uuid) -> Result<PoolAvailability, QueryError> {
+        self.snapshot
+            .load()
+            .get(pool_id)
+            .and_then(PoolAvailability::of)
+            .ok_or_else(|| QueryError::NotFound(pool_id.to_string()))
+    }
+
+    /// Lowest address a pool can still allocate
+    pub fn next_free(&self, pool_id: Uuid) -> Result<IpAddr, QueryError> {
+        let availability = self.availability(pool_id)?;
+        availability.next_free.ok_or_else(|| {
+            QueryError::NotFound(format!("free address in pool {}", availability.cidr))
+        })
+    }
+
+    /// Pools serving a network aggregate
+    pub fn pools_for_network(&self, network_id: Uuid) -> Vec<PoolAvailability> {
+        self.snapshot
+            .load()
+            .pools()
+            .filter(|state| state.network_id == Some(network_id))
+            .filter_map(PoolAvailability::of)
+            .collect()
+    }
+}
+
+/// Projection maintaining pool availability
+pub struct IpPoolProjection {
+    handle: IpPoolHandle,
+    working: HashMap<Uuid, Arc<Entry>>,
+}
+
+impl IpPoolProjection {
+    /// Create an empty projection
+    pub fn new() -> Self {
+        Self {
+            handle: IpPoolHandle {
+                snapshot: Arc::new(ArcSwap::from_pointee(IpPoolSnapshot::default())),
+            },
+            working: HashMap::new(),
+        }
+    }
+
+    /// Query-side handle sharing this projection's data
+    pub fn handle(&self) -> IpPoolHandle {
+        self.handle.clone()
+    }
+
+    fn publish(&self) {
+        self.handle.snapshot.store(Arc::new(IpPoolSnapshot {
+            pools: self.working.clone(),
+        }));
+    }
+}
+
+impl Default for IpPoolProjection {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ProjectionAdapter for IpPoolProjection {
+    type Event = StoredEvent<InfrastructureEvent>;
+    type Error = ProjectionError;
+
+    async fn project(&mut self, event: Self::Event) -> Result<(), Self::Error> {
+        let InfrastructureEvent::IpPool(pool_event) = &event.data else {
+            return Ok(());
+        };
+
+        let current = self.working.get(&event.aggregate_id);
+
+        // Redelivered events are already reflected in the state
+        if current.is_some_and(|entry| event.sequence <= entry.sequence) {
+            return Ok(());
+        }
+
+        let state = current
+            .map(|entry| entry.state.clone())
+            .unwrap_or_else(|| IpPoolState::default_for(event.aggregate_id));
+
+        self.working.insert(
+            event.aggregate_id,
+            Arc::new(Entry {
+                state: apply_ip_pool_event(state, pool_event),
+                sequence: event.sequence,
+            }),
+        );
+        self.publish();
+
+        Ok(())
+    }
+
+    async fn initialize(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    async fn health_check(&self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    async fn reset(&mut self) -> Result<(), Self::Error> {
+        self.working.clear();
+        self.publish();
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "ip_pool_availability"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::ip_pool::{AddressAllocated, IpPoolEvent, PoolDefined};
+    use chrono::{DateTime, Utc};
+
+    fn test_timestamp() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-19T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn stored(sequence: u64, event: IpPoolEvent) -> StoredEvent<InfrastructureEvent> {
+        StoredEvent::new(
+            Uuid::now_v7(),
+            event.aggregate_id(),
+            sequence,
+            Uuid::now_v7(),
+            Uuid::now_v7(),
+            "test",
+            InfrastructureEvent::IpPool(event),
+        )
+    }
+
+    fn history(pool_id: Uuid, network_id: Uuid) -> Vec<StoredEvent<InfrastructureEvent>> {
+        vec![
+            stored(
+                1,
+                IpPoolEvent::PoolDefined(PoolDefined {
+                    event_version: 1,
+                    event_id: Uuid::now_v7(),
+                    aggregate_id: pool_id,
+                    timestamp: test_timestamp(),
+                    correlation_id: Uuid::now_v7(),
+                    causation_id: None,
+                    name: "servers".to_string(),
+                    cidr: IpAddressWithCidr::new("10.0.1.0/29").unwrap(),
+                    vrf: None,
+                    network_id: Some(network_id),
+                    reserved: Vec::new(),
+                }),
+            ),
+            stored(
+                2,
+                IpPoolEvent::AddressAllocated(AddressAllocated {
+                    event_version: 1,
+                    event_id: Uuid::now_v7(),
+                    aggregate_id: pool_id,
+                    timestamp: test_timestamp(),
+                    correlation_id: Uuid::now_v7(),
+                    causation_id: None,
+                    address: IpAddressWithCidr::new("10.0.1.1/29").unwrap(),
+                    interface_id: Uuid::now_v7(),
+                    resource_id: Uuid::now_v7(),
+                }),
+            ),
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_next_free_follows_allocations() {
+        // Arrange
+        let pool_id = Uuid::now_v7();
+        let network_id = Uuid::now_v7();
+        let mut projection = IpPoolProjection::new();
+        let handle = projection.handle();
+
+        // Act - the allocation is delivered twice
+        let events = history(pool_id, network_id);
+        for event in events.iter().chain(events.last()).cloned() {
+            projection.project(event).await.unwrap();
+        }
+
+        // Assert - /29 has six host addresses, one allocated
+        let availability = handle.availability(pool_id).unwrap();
+        assert_eq!(availability.allocated, 1);
+        assert_eq!(availability.free, 5);
+        assert_eq!(handle.next_free(pool_id).unwrap().to_string(), "10.0.1.2");
+        assert_eq!(handle.pools_for_network(network_id).len(), 1);
+        assert!(matches!(
+            handle.next_free(Uuid::now_v7()),
+            Err(QueryError::NotFound(_))
+        ));
+    }
+}
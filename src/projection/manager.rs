@@ -0,0 +1,795 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Projection Manager
+//!
+//! Runs registered projections against the JetStream event stream and
+//! rebuilds them on demand.
+//!
+//! # Rebuild
+//!
+//! ```text
+//! rebuild("neo4j")
+//!   1. stop live tail
+//!   2. ProjectionAdapter::reset()  + initialize()
+//!   3. replay stream sequences 1..=N   (N = last sequence when rebuild began)
+//!   4. resume live tail from N + 1
+//! ```
+//!
+//! Events appended while the replay runs have sequences greater than N and
+//! are picked up by the live tail, so nothing is skipped or applied twice.
+//!
+//! With [`ProjectionManager::with_progress`] a rebuild publishes its
+//! progress (operation `projection_rebuild`, scope = projection name) on
+//! `infrastructure.progress.<operation_id>`. [`ProjectionManager::rebuild_with`]
+//! takes the operation ID, so a caller can watch from the start, and a
+//! cancellation token checked between replayed events.
+//!
+//! # Unknown Fields
+//!
+//! Events carrying fields this version does not know (written by a newer
+//! writer) are delivered by default, and the dropped fields are counted on
+//! [`ProjectionManager::ignored_fields`]. With
+//! [`ProjectionManager::with_unknown_fields`]`(UnknownFields::Deny)` such
+//! events fail to decode instead: a rebuild stops, a live tail skips them.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::projection::manager::{EventDataProjection, ProjectionManager};
+//!
+//! let mut manager = ProjectionManager::new(jetstream, "INFRASTRUCTURE_EVENTS");
+//! manager.register(EventDataProjection::new(neo4j_adapter));
+//! manager.start_all().await?;
+//!
+//! // Later, after a schema change in the read model:
+//! let report = manager.rebuild("neo4j").await?;
+//! println!("replayed {} events", report.events_replayed);
+//! ```
+
+use async_nats::jetstream::{self, consumer};
+use async_nats::HeaderMap;
+use async_trait::async_trait;
+use futures::StreamExt;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::errors::InfrastructureResult;
+use crate::event_store::cloudevents::unwrap_structured;
+use crate::event_store::codec::{codec_for_content_type, message_content_type};
+use crate::event_store::offload::{resolve_payload, PayloadOffload};
+use crate::events::serialization::{decode_payload, IgnoredFieldReport, UnknownFields};
+use crate::events::InfrastructureEvent;
+use crate::jetstream::{JetStreamConfig, StoredEvent};
+use crate::nats::progress::{CancellationToken, ProgressReporter};
+use crate::nats::NatsClient;
+use crate::projection::{ProjectionAdapter, ProjectionError};
+use crate::subjects::subjects;
+
+/// Projection over stored events, as managed by [`ProjectionManager`]
+pub type ManagedAdapter = Box<
+    dyn ProjectionAdapter<Event = StoredEvent<InfrastructureEvent>, Error = ProjectionError>,
+>;
+
+/// Errors raised by the projection manager
+#[derive(Debug, thiserror::Error)]
+pub enum ManagerError {
+    /// No projection registered under this name
+    #[error("Unknown projection: {0}")]
+    UnknownProjection(String),
+
+    /// The projection adapter failed
+    #[error("Projection '{name}' failed: {source}")]
+    Projection {
+        /// Projection name
+        name: String,
+        /// Underlying adapter error
+        source: ProjectionError,
+    },
+
+    /// Stream or consumer operation failed
+    #[error("JetStream error: {0}")]
+    JetStream(String),
+
+    /// The rebuild was cancelled before the replay finished
+    #[error("Rebuild of projection '{0}' cancelled")]
+    Cancelled(String),
+
+    /// A stream message could not be decoded as a stored event
+    #[error("Cannot decode stream sequence {sequence}: {message}")]
+    Decode {
+        /// Stream sequence of the message
+        sequence: u64,
+        /// Decoder error
+        message: String,
+    },
+}
+
+/// Outcome of a projection rebuild
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RebuildReport {
+    /// Projection that was rebuilt
+    pub projection: String,
+
+    /// Operation ID the rebuild's progress was reported under
+    pub operation_id: Uuid,
+
+    /// Number of events replayed into the projection
+    pub events_replayed: u64,
+
+    /// Last stream sequence included in the replay
+    pub last_sequence: u64,
+
+    /// Wall-clock time the replay took
+    pub duration: Duration,
+}
+
+/// A registered projection and its live-tail state
+struct ManagedProjection {
+    adapter: Arc<Mutex<ManagedAdapter>>,
+
+    /// Last stream sequence applied to the projection
+    position: Arc<AtomicU64>,
+
+    /// Live tail task, if running
+    tail: Option<JoinHandle<()>>,
+}
+
+/// Positions of the projections registered when it was taken
+///
+/// Cloning is cheap and the positions stay current as the projections
+/// advance, so a health check can hold one while the manager runs.
+#[derive(Debug, Clone, Default)]
+pub struct ProjectionPositions {
+    positions: Vec<(String, Arc<AtomicU64>)>,
+}
+
+impl ProjectionPositions {
+    /// Last stream sequence applied to each projection, by name
+    pub fn current(&self) -> Vec<(String, u64)> {
+        self.positions
+            .iter()
+            .map(|(name, position)| (name.clone(), position.load(Ordering::SeqCst)))
+            .collect()
+    }
+}
+
+/// Coordinates projections: live tailing and rebuild from sequence 1
+pub struct ProjectionManager {
+    jetstream: jetstream::Context,
+    stream_name: String,
+    filter_subject: String,
+    projections: HashMap<String, ManagedProjection>,
+    progress: Option<NatsClient>,
+    decoder: EventDecoder,
+}
+
+impl ProjectionManager {
+    /// Create a manager for a JetStream stream
+    ///
+    /// Only aggregate events (`infrastructure.compute.>`) are delivered to
+    /// projections by default; derived subjects such as feeds and indexes
+    /// are skipped.
+    pub fn new(jetstream: jetstream::Context, stream_name: impl Into<String>) -> Self {
+        Self {
+            jetstream,
+            stream_name: stream_name.into(),
+            filter_subject: subjects::all_compute_events(),
+            projections: HashMap::new(),
+            progress: None,
+            decoder: EventDecoder::default(),
+        }
+    }
+
+    /// Create a manager for one organization's stream
+    ///
+    /// Reads the stream of [`JetStreamConfig::for_tenant`] and delivers
+    /// only that organization's aggregate events
+    /// (`infrastructure.<org_id>.compute.>`). Fails like
+    /// [`JetStreamConfig::for_tenant`] for an invalid `org_id`.
+    pub fn for_tenant(jetstream: jetstream::Context, org_id: &str) -> InfrastructureResult<Self> {
+        let config = JetStreamConfig::for_tenant(org_id)?;
+        Ok(Self::new(jetstream, config.stream_name)
+            .with_filter_subject(subjects::tenant_compute_events(org_id)))
+    }
+
+    /// Deliver a different subject filter to projections
+    pub fn with_filter_subject(mut self, filter_subject: impl Into<String>) -> Self {
+        self.filter_subject = filter_subject.into();
+        self
+    }
+
+    /// Publish rebuild progress through `client`
+    pub fn with_progress(mut self, client: NatsClient) -> Self {
+        self.progress = Some(client);
+        self
+    }
+
+    /// Treatment of event fields projections do not know (default
+    /// [`UnknownFields::Allow`])
+    pub fn with_unknown_fields(mut self, unknown_fields: UnknownFields) -> Self {
+        self.decoder.unknown_fields = unknown_fields;
+        self
+    }
+
+    /// Resolve payloads the event store offloaded to `offload`'s store
+    pub fn with_payload_offload(mut self, offload: PayloadOffload) -> Self {
+        self.decoder.payloads = Some(offload);
+        self
+    }
+
+    /// Fields dropped while decoding events for projections
+    pub fn ignored_fields(&self) -> IgnoredFieldReport {
+        self.decoder.ignored.clone()
+    }
+
+    /// Register a projection under its [`ProjectionAdapter::name`]
+    ///
+    /// Registering a second projection with the same name replaces the
+    /// first (stopping its live tail).
+    pub fn register<P>(&mut self, projection: P)
+    where
+        P: ProjectionAdapter<Event = StoredEvent<InfrastructureEvent>, Error = ProjectionError>
+            + 'static,
+    {
+        let name = projection.name().to_string();
+        if let Some(mut previous) = self.projections.remove(&name) {
+            stop_tail(&mut previous);
+        }
+
+        self.projections.insert(
+            name,
+            ManagedProjection {
+                adapter: Arc::new(Mutex::new(Box::new(projection))),
+                position: Arc::new(AtomicU64::new(0)),
+                tail: None,
+            },
+        );
+    }
+
+    /// Names of all registered projections
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.projections.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Last stream sequence applied to a projection
+    pub fn position(&self, name: &str) -> Option<u64> {
+        self.projections
+            .get(name)
+            .map(|p| p.position.load(Ordering::SeqCst))
+    }
+
+    /// Shared view of every registered projection's position
+    pub fn positions(&self) -> ProjectionPositions {
+        let mut positions: Vec<(String, Arc<AtomicU64>)> = self
+            .projections
+            .iter()
+            .map(|(name, p)| (name.clone(), p.position.clone()))
+            .collect();
+        positions.sort_by(|a, b| a.0.cmp(&b.0));
+        ProjectionPositions { positions }
+    }
+
+    /// Whether a projection's live tail is running
+    pub fn is_tailing(&self, name: &str) -> bool {
+        self.projections
+            .get(name)
+            .and_then(|p| p.tail.as_ref())
+            .map(|tail| !tail.is_finished())
+            .unwrap_or(false)
+    }
+
+    /// Initialize a projection and start tailing after its current position
+    pub async fn start(&mut self, name: &str) -> Result<(), ManagerError> {
+        let managed = self
+            .projections
+            .get(name)
+            .ok_or_else(|| ManagerError::UnknownProjection(name.to_string()))?;
+
+        managed
+            .adapter
+            .lock()
+            .await
+            .initialize()
+            .await
+            .map_err(|source| ManagerError::Projection {
+                name: name.to_string(),
+                source,
+            })?;
+
+        let from = managed.position.load(Ordering::SeqCst) + 1;
+        self.start_tail(name, from).await
+    }
+
+    /// Start every registered projection
+    pub async fn start_all(&mut self) -> Result<(), ManagerError> {
+        let names: Vec<String> = self.projections.keys().cloned().collect();
+        for name in names {
+            self.start(&name).await?;
+        }
+        Ok(())
+    }
+
+    /// Stop a projection's live tail
+    pub fn stop(&mut self, name: &str) -> Result<(), ManagerError> {
+        let managed = self
+            .projections
+            .get_mut(name)
+            .ok_or_else(|| ManagerError::UnknownProjection(name.to_string()))?;
+
+        stop_tail(managed);
+        Ok(())
+    }
+
+    /// Reset a projection, replay the stream from sequence 1, then resume
+    /// live tailing
+    ///
+    /// If the replay fails the projection is left stopped at the last
+    /// sequence it applied; call [`ProjectionManager::start`] to resume from
+    /// there or rebuild again.
+    pub async fn rebuild(&mut self, name: &str) -> Result<RebuildReport, ManagerError> {
+        self.rebuild_with(name, Uuid::now_v7(), &CancellationToken::new())
+            .await
+    }
+
+    /// Rebuild a projection, reporting progress under `operation_id`
+    ///
+    /// Cancelling `cancel` stops the replay before the next event and
+    /// returns [`ManagerError::Cancelled`]. As with a failed replay the
+    /// projection is left stopped and partially rebuilt.
+    pub async fn rebuild_with(
+        &mut self,
+        name: &str,
+        operation_id: Uuid,
+        cancel: &CancellationToken,
+    ) -> Result<RebuildReport, ManagerError> {
+        let mut progress =
+            ProgressReporter::with_id(operation_id, "projection_rebuild").with_scope(name);
+        if let Some(client) = &self.progress {
+            progress = progress.with_client(client.clone());
+        }
+
+        match self.run_rebuild(name, &mut progress, cancel).await {
+            Ok(report) => {
+                progress.complete().await;
+                Ok(report)
+            }
+            Err(e @ ManagerError::Cancelled(_)) => {
+                warn!("{}", e);
+                progress.cancel().await;
+                Err(e)
+            }
+            Err(e) => {
+                progress.fail(e.to_string()).await;
+                Err(e)
+            }
+        }
+    }
+
+    async fn run_rebuild(
+        &mut self,
+        name: &str,
+        progress: &mut ProgressReporter,
+        cancel: &CancellationToken,
+    ) -> Result<RebuildReport, ManagerError> {
+        let started = Instant::now();
+
+        let managed = self
+            .projections
+            .get_mut(name)
+            .ok_or_else(|| ManagerError::UnknownProjection(name.to_string()))?;
+        stop_tail(managed);
+
+        let adapter = managed.adapter.clone();
+        let position = managed.position.clone();
+        let projection_error = |source| ManagerError::Projection {
+            name: name.to_string(),
+            source,
+        };
+
+        info!("Rebuilding projection '{}'", name);
+        {
+            let mut adapter = adapter.lock().await;
+            adapter.reset().await.map_err(projection_error)?;
+            adapter.initialize().await.map_err(projection_error)?;
+        }
+        position.store(0, Ordering::SeqCst);
+
+        let last_sequence = self.last_stream_sequence().await?;
+        let events_replayed = self
+            .replay(&adapter, &position, last_sequence, name, progress, cancel)
+            .await?;
+
+        // Anything replayed was at or below last_sequence; resume right after
+        self.start_tail(name, last_sequence + 1).await?;
+
+        let report = RebuildReport {
+            projection: name.to_string(),
+            operation_id: progress.operation_id(),
+            events_replayed,
+            last_sequence,
+            duration: started.elapsed(),
+        };
+        info!(
+            "Rebuilt projection '{}': {} events up to sequence {} in {:?}",
+            name, report.events_replayed, report.last_sequence, report.duration
+        );
+
+        Ok(report)
+    }
+
+    /// Current last sequence of the stream (0 when empty)
+    async fn last_stream_sequence(&self) -> Result<u64, ManagerError> {
+        let mut stream = self
+            .jetstream
+            .get_stream(&self.stream_name)
+            .await
+            .map_err(|e| ManagerError::JetStream(e.to_string()))?;
+
+        let info = stream
+            .info()
+            .await
+            .map_err(|e| ManagerError::JetStream(e.to_string()))?;
+
+        Ok(info.state.last_sequence)
+    }
+
+    /// Ephemeral consumer over the filtered stream starting at a sequence
+    async fn consumer_from(
+        &self,
+        start_sequence: u64,
+    ) -> Result<consumer::Consumer<consumer::pull::Config>, ManagerError> {
+        let stream = self
+            .jetstream
+            .get_stream(&self.stream_name)
+            .await
+            .map_err(|e| ManagerError::JetStream(e.to_string()))?;
+
+        stream
+            .create_consumer(consumer::pull::Config {
+                filter_subject: self.filter_subject.clone(),
+                deliver_policy: consumer::DeliverPolicy::ByStartSequence { start_sequence },
+                ack_policy: consumer::AckPolicy::None,
+                ..Default::default()
+            })
+            .await
+            .map_err(|e| ManagerError::JetStream(e.to_string()))
+    }
+
+    /// Apply stream sequences 1..=last_sequence to a projection
+    async fn replay(
+        &self,
+        adapter: &Arc<Mutex<ManagedAdapter>>,
+        position: &Arc<AtomicU64>,
+        last_sequence: u64,
+        name: &str,
+        progress: &mut ProgressReporter,
+        cancel: &CancellationToken,
+    ) -> Result<u64, ManagerError> {
+        if last_sequence == 0 {
+            progress.start(Some(0)).await;
+            return Ok(0);
+        }
+
+        let consumer = self.consumer_from(1).await?;
+
+        // Messages matching the filter when the consumer was created; the
+        // last one may sit below last_sequence if later sequences belong to
+        // other subjects, so this bounds the replay as well
+        let mut remaining = consumer.cached_info().num_pending;
+        progress.start(Some(remaining)).await;
+        let mut messages = consumer
+            .messages()
+            .await
+            .map_err(|e| ManagerError::JetStream(e.to_string()))?;
+
+        let mut replayed = 0;
+        let mut adapter = adapter.lock().await;
+
+        while remaining > 0 {
+            let message = tokio::select! {
+                biased;
+                _ = cancel.cancelled() => {
+                    return Err(ManagerError::Cancelled(name.to_string()));
+                }
+                message = messages.next() => match message {
+                    Some(message) => message,
+                    None => break,
+                },
+            };
+            remaining -= 1;
+
+            let message = message.map_err(|e| ManagerError::JetStream(e.to_string()))?;
+            let sequence = message
+                .info()
+                .map_err(|e| ManagerError::JetStream(e.to_string()))?
+                .stream_sequence;
+
+            if sequence > last_sequence {
+                break;
+            }
+
+            let event = self
+                .decoder
+                .decode(sequence, &message.payload, message.headers.as_ref())
+                .await?;
+            adapter
+                .project(event)
+                .await
+                .map_err(|source| ManagerError::Projection {
+                    name: name.to_string(),
+                    source,
+                })?;
+
+            position.store(sequence, Ordering::SeqCst);
+            replayed += 1;
+            progress.advance(1).await;
+        }
+
+        Ok(replayed)
+    }
+
+    /// Spawn the live tail task for a projection
+    async fn start_tail(&mut self, name: &str, from: u64) -> Result<(), ManagerError> {
+        let consumer = self.consumer_from(from).await?;
+        let mut messages = consumer
+            .messages()
+            .await
+            .map_err(|e| ManagerError::JetStream(e.to_string()))?;
+
+        let managed = self
+            .projections
+            .get_mut(name)
+            .ok_or_else(|| ManagerError::UnknownProjection(name.to_string()))?;
+        stop_tail(managed);
+
+        let adapter = managed.adapter.clone();
+        let position = managed.position.clone();
+        let decoder = self.decoder.clone();
+        let name = name.to_string();
+
+        managed.tail = Some(tokio::spawn(async move {
+            info!("Projection '{}' tailing from sequence {}", name, from);
+
+            while let Some(message) = messages.next().await {
+                let message = match message {
+                    Ok(message) => message,
+                    Err(e) => {
+                        error!("Projection '{}' tail failed: {}", name, e);
+                        return;
+                    }
+                };
+
+                let sequence = match message.info() {
+                    Ok(info) => info.stream_sequence,
+                    Err(e) => {
+                        warn!("Projection '{}' skipped message without info: {}", name, e);
+                        continue;
+                    }
+                };
+
+                let event = match decoder
+                    .decode(sequence, &message.payload, message.headers.as_ref())
+                    .await
+                {
+                    Ok(event) => event,
+                    Err(e) => {
+                        warn!("Projection '{}' skipped event: {}", name, e);
+                        position.store(sequence, Ordering::SeqCst);
+                        continue;
+                    }
+                };
+
+                if let Err(e) = adapter.lock().await.project(event).await {
+                    // Stop at the failed event so a restart retries it
+                    error!(
+                        "Projection '{}' failed at sequence {}: {}",
+                        name, sequence, e
+                    );
+                    return;
+                }
+
+                position.store(sequence, Ordering::SeqCst);
+            }
+        }));
+
+        Ok(())
+    }
+}
+
+impl Drop for ProjectionManager {
+    fn drop(&mut self) {
+        for managed in self.projections.values_mut() {
+            stop_tail(managed);
+        }
+    }
+}
+
+fn stop_tail(managed: &mut ManagedProjection) {
+    if let Some(tail) = managed.tail.take() {
+        tail.abort();
+    }
+}
+
+/// Decodes stream messages, recording fields the typed events drop
+#[derive(Clone, Default)]
+struct EventDecoder {
+    unknown_fields: UnknownFields,
+    ignored: IgnoredFieldReport,
+    payloads: Option<PayloadOffload>,
+}
+
+impl EventDecoder {
+    async fn decode(
+        &self,
+        sequence: u64,
+        payload: &[u8],
+        headers: Option<&HeaderMap>,
+    ) -> Result<StoredEvent<InfrastructureEvent>, ManagerError> {
+        let decode_error = |message: String| ManagerError::Decode { sequence, message };
+
+        let payload = resolve_payload(self.payloads.as_ref(), headers, payload)
+            .await
+            .map_err(|e| decode_error(e.to_string()))?;
+        let raw = codec_for_content_type(message_content_type(headers))
+            .and_then(|codec| codec.decode(&payload))
+            .map_err(|e| decode_error(e.to_string()))?;
+        let raw = unwrap_structured(raw);
+        let (event, ignored) =
+            decode_payload(&raw, self.unknown_fields).map_err(|e| decode_error(e.to_string()))?;
+
+        if let Some(ignored) = ignored {
+            self.ignored.record(&ignored);
+        }
+        Ok(event)
+    }
+}
+
+/// Adapts a projection over bare domain events (Neo4j, NetBox) to the
+/// stored-event input the manager delivers
+pub struct EventDataProjection<P> {
+    inner: P,
+}
+
+impl<P> EventDataProjection<P> {
+    /// Wrap a projection that consumes [`InfrastructureEvent`]
+    pub fn new(inner: P) -> Self {
+        Self { inner }
+    }
+
+    /// Access the wrapped projection
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+}
+
+#[async_trait]
+impl<P> ProjectionAdapter for EventDataProjection<P>
+where
+    P: ProjectionAdapter<Event = InfrastructureEvent, Error = ProjectionError>,
+{
+    type Event = StoredEvent<InfrastructureEvent>;
+    type Error = ProjectionError;
+
+    async fn project(&mut self, event: Self::Event) -> Result<(), Self::Error> {
+        self.inner.project(event.data).await
+    }
+
+    async fn initialize(&mut self) -> Result<(), Self::Error> {
+        self.inner.initialize().await
+    }
+
+    async fn health_check(&self) -> Result<(), Self::Error> {
+        self.inner.health_check().await
+    }
+
+    async fn reset(&mut self) -> Result<(), Self::Error> {
+        self.inner.reset().await
+    }
+
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_harness::{registered, stored, TestNats};
+
+    struct NoopProjection;
+
+    #[async_trait]
+    impl ProjectionAdapter for NoopProjection {
+        type Event = StoredEvent<InfrastructureEvent>;
+        type Error = ProjectionError;
+
+        async fn project(&mut self, _event: Self::Event) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        async fn initialize(&mut self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        async fn health_check(&self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        async fn reset(&mut self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn name(&self) -> &str {
+            "noop"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_decode_reports_sequence() {
+        let err = EventDecoder::default()
+            .decode(42, b"not json", None)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, ManagerError::Decode { sequence: 42, .. }));
+    }
+
+    #[tokio::test]
+    async fn test_decode_unknown_fields_by_policy() {
+        // Arrange - an event from a writer that knows one more field
+        let event = stored(7, registered(Uuid::now_v7(), "web-01"));
+        let mut raw = serde_json::to_value(&event).unwrap();
+        raw["data"]["event"]["rack_unit"] = serde_json::json!(12);
+        let payload = serde_json::to_vec(&raw).unwrap();
+        let lenient = EventDecoder::default();
+        let strict = EventDecoder {
+            unknown_fields: UnknownFields::Deny,
+            ..EventDecoder::default()
+        };
+        let mut headers = HeaderMap::new();
+        headers.insert("Content-Type", "application/json");
+
+        // Act
+        let decoded = lenient.decode(7, &payload, None).await.unwrap();
+        let rejected = strict.decode(7, &payload, Some(&headers)).await;
+
+        // Assert
+        assert_eq!(decoded.event_id, event.event_id);
+        let counts = lenient.ignored.snapshot();
+        assert_eq!(counts.len(), 1);
+        assert_eq!(counts[0].path, "data.event.rack_unit");
+        assert!(matches!(
+            rejected,
+            Err(ManagerError::Decode { sequence: 7, .. })
+        ));
+    }
+
+    #[tokio::test]
+    #[ignore] // Requires NATS server
+    async fn test_rebuild_replays_stream() -> Result<(), Box<dyn std::error::Error>> {
+        let nats = TestNats::start().await?;
+        nats.event_store().await?;
+        let jetstream = jetstream::new(nats.client().await?);
+
+        let mut manager = ProjectionManager::new(jetstream, "INFRASTRUCTURE_EVENTS");
+        manager.register(NoopProjection);
+
+        let report = manager.rebuild("noop").await?;
+
+        assert_eq!(report.projection, "noop");
+        assert!(manager.is_tailing("noop"));
+        assert!(matches!(
+            manager.rebuild("missing").await,
+            Err(ManagerError::UnknownProjection(_))
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,879 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! In-Memory Read Model
+//!
+//! Keeps the current [`ComputeResourceState`] of every resource in memory
+//! so queries (NATS query API, topology views) are answered without
+//! replaying the event store.
+//!
+//! # Memory Footprint
+//!
+//! Large inventories do not fit in memory with full detail. With
+//! [`RetainedDetail::KeyFields`] only identifiers and the fields needed for
+//! listings and topology (hostname, type, status, ownership, location,
+//! policies) are kept; metadata, hardware details, asset tags and backup
+//! references are dropped. When an event store is attached, single-resource
+//! queries hydrate the full state from the store on demand.
+//!
+//! ```text
+//! StoredEvent ──> InMemoryReadModel ──retain()──> working index
+//!                                                     │ publish()
+//!                                                     ▼
+//!                                      ArcSwap<ReadModelSnapshot>
+//!                                                     │ load (lock-free)
+//!                       ReadModelHandle (clone) ──────┤
+//!                          compute_resource(id) ──────┴──> hydrate from EventStore
+//!                          topology(query)      ──────────> retained fields only
+//! ```
+//!
+//! # Snapshots
+//!
+//! The projection task is the only writer. After every `publish_every`
+//! events it swaps in a new immutable [`ReadModelSnapshot`]; readers load
+//! the current snapshot without taking a lock, so high query rates never
+//! contend with the writer. Per-resource state is shared between snapshots
+//! through `Arc`, so publishing copies the index, not the states.
+//!
+//! # Host Rollups
+//!
+//! The read model also keeps the [`HostRollup`] of every host with guests,
+//! recalculated as guests change. With
+//! [`InMemoryReadModel::with_rollup_advisories`] every change of a rollup
+//! status is published as a `host_rollup_changed` advisory.
+//!
+//! # Connectivity
+//!
+//! Live physical connections are kept alongside the resources, so
+//! [`ReadModelHandle::connectivity`] answers path and reachability
+//! questions (see [`TopologyView::path_between`]) without a graph database.
+//! Networks and network interfaces are kept as well, so resources can be
+//! traversed to their ports and the networks those ports attach to.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::projection::read_model::{InMemoryReadModel, ReadModelConfig, RetainedDetail};
+//!
+//! let projection = InMemoryReadModel::new(
+//!     ReadModelConfig::default().with_detail(RetainedDetail::KeyFields),
+//! )
+//! .with_event_store(Arc::new(event_store));
+//! let handle = projection.handle();
+//!
+//! manager.register(projection);
+//! QueryResponder::new(nats_client, Arc::new(handle)).run().await?;
+//! ```
+
+use arc_swap::ArcSwap;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::aggregate::compute_resource::apply_infrastructure_event;
+use crate::aggregate::{
+    apply_connection_event, apply_network_event, apply_network_interface_event,
+    ComputeResourceState, ConnectionState, NetworkInterfaceState, NetworkState,
+};
+use crate::archival::{ArchivalHandle, StaleAggregate};
+use crate::enrichment::{DisplayNameCache, EnrichedResource};
+use crate::event_store::EventStore;
+use crate::events::InfrastructureEvent;
+use crate::jetstream::StoredEvent;
+use crate::nats::query::{
+    GetArchivalCandidates, GetEnrichedResources, GetScorecard, QueryError, ReadModel,
+    TopologyQuery, TopologyView,
+};
+use crate::nats::NatsClient;
+use crate::projection::{ProjectionAdapter, ProjectionError};
+use crate::rollup::{HostRollup, RollupConfig, StatusRollups};
+use crate::scorecard::{Scorecard, ScorecardBoard};
+
+/// How much of each resource the read model keeps in memory
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RetainedDetail {
+    /// Complete state
+    #[default]
+    Full,
+
+    /// Identifiers and key fields only
+    KeyFields,
+}
+
+impl RetainedDetail {
+    /// Reduce a state to the retained detail
+    pub fn retain(&self, state: ComputeResourceState) -> ComputeResourceState {
+        match self {
+            RetainedDetail::Full => state,
+            RetainedDetail::KeyFields => ComputeResourceState {
+                manufacturer: None,
+                model: None,
+                serial_number: None,
+                asset_tag: None,
+                metadata: Vec::new(),
+                last_configuration_backup: None,
+                ..state
+            },
+        }
+    }
+}
+
+/// Read model configuration
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReadModelConfig {
+    /// Detail retained per resource
+    #[serde(default)]
+    pub detail: RetainedDetail,
+
+    /// Events applied between published snapshots (1 = after every event)
+    #[serde(default = "default_publish_every")]
+    pub publish_every: usize,
+}
+
+fn default_publish_every() -> usize {
+    1
+}
+
+impl Default for ReadModelConfig {
+    fn default() -> Self {
+        Self {
+            detail: RetainedDetail::default(),
+            publish_every: default_publish_every(),
+        }
+    }
+}
+
+impl ReadModelConfig {
+    /// Set the retained detail
+    pub fn with_detail(mut self, detail: RetainedDetail) -> Self {
+        self.detail = detail;
+        self
+    }
+
+    /// Publish a snapshot every `events` events (minimum 1)
+    ///
+    /// Publishing copies the resource index (one pointer per resource), so
+    /// large inventories replaying history should publish less often.
+    pub fn with_publish_every(mut self, events: usize) -> Self {
+        self.publish_every = events.max(1);
+        self
+    }
+}
+
+/// Retained state and the last aggregate sequence applied to it
+#[derive(Debug)]
+struct Entry<S = ComputeResourceState> {
+    state: S,
+    sequence: u64,
+}
+
+/// Immutable view of the read model at one point in the event stream
+///
+/// A snapshot never changes once published. Readers holding one keep a
+/// consistent view while the projection moves on; unchanged resources are
+/// shared between consecutive snapshots.
+#[derive(Debug, Default)]
+pub struct ReadModelSnapshot {
+    resources: HashMap<Uuid, Arc<Entry>>,
+    connections: HashMap<Uuid, Arc<Entry<ConnectionState>>>,
+    networks: HashMap<Uuid, Arc<Entry<NetworkState>>>,
+    interfaces: HashMap<Uuid, Arc<Entry<NetworkInterfaceState>>>,
+    rollups: HashMap<Uuid, HostRollup>,
+    events_applied: u64,
+}
+
+impl ReadModelSnapshot {
+    /// Retained state of a resource
+    pub fn get(&self, aggregate_id: Uuid) -> Option<&ComputeResourceState> {
+        self.resources.get(&aggregate_id).map(|entry| &entry.state)
+    }
+
+    /// Every resource in the snapshot, in no particular order
+    pub fn resources(&self) -> impl Iterator<Item = &ComputeResourceState> {
+        self.resources.values().map(|entry| &entry.state)
+    }
+
+    /// Live physical connections, in no particular order
+    pub fn connections(&self) -> impl Iterator<Item = &ConnectionState> {
+        self.connections
+            .values()
+            .map(|entry| &entry.state)
+            .filter(|state| state.is_live())
+    }
+
+    /// Defined network
+    pub fn network(&self, network_id: Uuid) -> Option<&NetworkState> {
+        self.networks.get(&network_id).map(|entry| &entry.state)
+    }
+
+    /// Every defined network, in no particular order
+    pub fn networks(&self) -> impl Iterator<Item = &NetworkState> {
+        self.networks.values().map(|entry| &entry.state)
+    }
+
+    /// Attached network interface
+    pub fn interface(&self, interface_id: Uuid) -> Option<&NetworkInterfaceState> {
+        self.interfaces.get(&interface_id).map(|entry| &entry.state)
+    }
+
+    /// Every attached network interface, in no particular order
+    pub fn interfaces(&self) -> impl Iterator<Item = &NetworkInterfaceState> {
+        self.interfaces.values().map(|entry| &entry.state)
+    }
+
+    /// Rollup of a host with guests
+    pub fn rollup(&self, host_id: Uuid) -> Option<&HostRollup> {
+        self.rollups.get(&host_id)
+    }
+
+    /// Rollup of every host with guests, in no particular order
+    pub fn rollups(&self) -> impl Iterator<Item = &HostRollup> {
+        self.rollups.values()
+    }
+
+    /// Number of resources held
+    pub fn len(&self) -> usize {
+        self.resources.len()
+    }
+
+    /// Whether no resources are held
+    pub fn is_empty(&self) -> bool {
+        self.resources.is_empty()
+    }
+
+    /// Events reflected in this snapshot since the last reset
+    pub fn events_applied(&self) -> u64 {
+        self.events_applied
+    }
+}
+
+/// Cloneable query-side handle onto the read model
+///
+/// Reads load the current snapshot without locking, so queries never wait
+/// for the projection task.
+#[derive(Clone)]
+pub struct ReadModelHandle {
+    snapshot: Arc<ArcSwap<ReadModelSnapshot>>,
+    detail: RetainedDetail,
+    event_store: Option<Arc<dyn EventStore>>,
+    scorecards: Option<ScorecardBoard>,
+    display_names: Option<DisplayNameCache>,
+    archival: Option<ArchivalHandle>,
+}
+
+impl ReadModelHandle {
+    /// Current snapshot, for several reads against one consistent view
+    pub fn snapshot(&self) -> Arc<ReadModelSnapshot> {
+        self.snapshot.load_full()
+    }
+
+    /// Retained state of a resource
+    pub fn get(&self, aggregate_id: Uuid) -> Option<ComputeResourceState> {
+        self.snapshot.load().get(aggregate_id).cloned()
+    }
+
+    /// Number of resources held
+    pub fn len(&self) -> usize {
+        self.snapshot.load().len()
+    }
+
+    /// Every resource and the live cables between them
+    ///
+    /// Built from the current snapshot; see [`TopologyView::connectivity`].
+    pub fn connectivity(&self) -> TopologyView {
+        let snapshot = self.snapshot.load();
+        TopologyView::connectivity(snapshot.resources(), snapshot.connections())
+    }
+
+    /// Rollups of hosts that are degraded or failed, worst first
+    pub fn hosts_needing_attention(&self) -> Vec<HostRollup> {
+        let mut rollups: Vec<HostRollup> = self
+            .snapshot
+            .load()
+            .rollups()
+            .filter(|rollup| rollup.needs_attention())
+            .cloned()
+            .collect();
+        rollups.sort_by(|a, b| b.status.cmp(&a.status).then(a.hostname.cmp(&b.hostname)));
+        rollups
+    }
+
+    /// Whether no resources are held
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Detail retained per resource
+    pub fn detail(&self) -> RetainedDetail {
+        self.detail
+    }
+
+    /// Answer scorecard queries from `board`
+    pub fn with_scorecards(mut self, board: ScorecardBoard) -> Self {
+        self.scorecards = Some(board);
+        self
+    }
+
+    /// Answer enriched resource queries with names from `names`
+    pub fn with_display_names(mut self, names: DisplayNameCache) -> Self {
+        self.display_names = Some(names);
+        self
+    }
+
+    /// Answer archival candidate queries from `candidates`
+    pub fn with_archival(mut self, candidates: ArchivalHandle) -> Self {
+        self.archival = Some(candidates);
+        self
+    }
+
+    /// Full state of a resource, read from the event store
+    async fn hydrate(
+        &self,
+        event_store: &dyn EventStore,
+        aggregate_id: Uuid,
+    ) -> Result<ComputeResourceState, QueryError> {
+        let events = event_store
+            .read_events(aggregate_id)
+            .await
+            .map_err(|e| QueryError::Unavailable(e.to_string()))?;
+
+        let state = events.iter().fold(
+            ComputeResourceState::default_for(aggregate_id),
+            |state, stored| apply_infrastructure_event(state, &stored.data),
+        );
+
+        if state.is_initialized() {
+            Ok(state)
+        } else {
+            Err(QueryError::NotFound(aggregate_id.to_string()))
+        }
+    }
+}
+
+#[async_trait]
+impl ReadModel for ReadModelHandle {
+    async fn compute_resource(
+        &self,
+        aggregate_id: Uuid,
+    ) -> Result<ComputeResourceState, QueryError> {
+        let retained = self
+            .get(aggregate_id)
+            .ok_or_else(|| QueryError::NotFound(aggregate_id.to_string()))?;
+
+        match (&self.event_store, self.detail) {
+            (Some(event_store), RetainedDetail::KeyFields) => {
+                self.hydrate(event_store.as_ref(), aggregate_id).await
+            }
+            _ => Ok(retained),
+        }
+    }
+
+    async fn topology(&self, query: &TopologyQuery) -> Result<TopologyView, QueryError> {
+        // Topology only needs key fields, so it never hydrates
+        let snapshot = self.snapshot.load();
+        let state = snapshot
+            .get(query.root)
+            .ok_or_else(|| QueryError::NotFound(query.root.to_string()))?;
+        Ok(TopologyView::from_resource(state))
+    }
+
+    async fn scorecard(&self, query: &GetScorecard) -> Result<Scorecard, QueryError> {
+        let organization_id = query.organization_id.as_deref();
+        let board = self
+            .scorecards
+            .as_ref()
+            .ok_or_else(|| QueryError::Unsupported("scorecards".to_string()))?;
+        board.get(organization_id).ok_or_else(|| {
+            QueryError::NotFound(organization_id.unwrap_or("unassigned").to_string())
+        })
+    }
+
+    async fn enriched_resources(
+        &self,
+        query: &GetEnrichedResources,
+    ) -> Result<Vec<EnrichedResource>, QueryError> {
+        // Names only need key fields, so this never hydrates either
+        let names = self
+            .display_names
+            .as_ref()
+            .ok_or_else(|| QueryError::Unsupported("enriched resources".to_string()))?;
+        let snapshot = self.snapshot.load_full();
+
+        let mut enriched = Vec::with_capacity(query.aggregate_ids.len());
+        for aggregate_id in &query.aggregate_ids {
+            if let Some(state) = snapshot.get(*aggregate_id) {
+                enriched.push(names.enrich(state.clone()).await);
+            }
+        }
+        Ok(enriched)
+    }
+
+    async fn archival_candidates(
+        &self,
+        query: &GetArchivalCandidates,
+    ) -> Result<Vec<StaleAggregate>, QueryError> {
+        let candidates = self
+            .archival
+            .as_ref()
+            .ok_or_else(|| QueryError::Unsupported("archival candidates".to_string()))?;
+        Ok(match query.organization_id.as_deref() {
+            Some(organization_id) => candidates.for_organization(organization_id),
+            None => candidates.current(),
+        })
+    }
+}
+
+/// Projection maintaining the in-memory read model
+///
+/// The projection task is the only writer. It updates a private working
+/// index and publishes immutable [`ReadModelSnapshot`]s for readers.
+pub struct InMemoryReadModel {
+    handle: ReadModelHandle,
+    working: HashMap<Uuid, Arc<Entry>>,
+    connections: HashMap<Uuid, Arc<Entry<ConnectionState>>>,
+    networks: HashMap<Uuid, Arc<Entry<NetworkState>>>,
+    interfaces: HashMap<Uuid, Arc<Entry<NetworkInterfaceState>>>,
+    rollups: StatusRollups,
+    advisories: Option<NatsClient>,
+    events_applied: u64,
+    unpublished: usize,
+    publish_every: usize,
+}
+
+impl InMemoryReadModel {
+    /// Create an empty read model
+    pub fn new(config: ReadModelConfig) -> Self {
+        Self {
+            handle: ReadModelHandle {
+                snapshot: Arc::new(ArcSwap::from_pointee(ReadModelSnapshot::default())),
+                detail: config.detail,
+                event_store: None,
+                scorecards: None,
+                display_names: None,
+                archival: None,
+            },
+            working: HashMap::new(),
+            connections: HashMap::new(),
+            networks: HashMap::new(),
+            interfaces: HashMap::new(),
+            rollups: StatusRollups::default(),
+            advisories: None,
+            events_applied: 0,
+            unpublished: 0,
+            publish_every: config.publish_every.max(1),
+        }
+    }
+
+    /// Hydrate dropped detail from `event_store` on single-resource queries
+    pub fn with_event_store(mut self, event_store: Arc<dyn EventStore>) -> Self {
+        self.handle.event_store = Some(event_store);
+        self
+    }
+
+    /// Find guests by a custom host metadata key
+    ///
+    /// Rollups computed so far are discarded; set this before projecting.
+    pub fn with_rollup_config(mut self, config: RollupConfig) -> Self {
+        self.rollups = StatusRollups::new(config);
+        self
+    }
+
+    /// Publish an advisory whenever a host's rollup status changes
+    pub fn with_rollup_advisories(mut self, client: NatsClient) -> Self {
+        self.advisories = Some(client);
+        self
+    }
+
+    /// Query-side handle sharing this read model's data
+    pub fn handle(&self) -> ReadModelHandle {
+        self.handle.clone()
+    }
+
+    /// Publish the working state as a new snapshot
+    ///
+    /// Called automatically every `publish_every` events; call it after a
+    /// replay to expose events that have not been published yet.
+    pub fn publish(&mut self) {
+        self.handle.snapshot.store(Arc::new(ReadModelSnapshot {
+            resources: self.working.clone(),
+            connections: self.connections.clone(),
+            networks: self.networks.clone(),
+            interfaces: self.interfaces.clone(),
+            rollups: self
+                .rollups
+                .rollups()
+                .map(|rollup| (rollup.host_id, rollup.clone()))
+                .collect(),
+            events_applied: self.events_applied,
+        }));
+        self.unpublished = 0;
+    }
+
+    /// Count an event applied to a connection, network or interface
+    /// entry; redeliveries are not counted
+    fn applied_if(&mut self, applied: bool) {
+        if applied {
+            self.applied();
+        }
+    }
+
+    /// Count an applied event, publishing when a batch is complete
+    fn applied(&mut self) {
+        self.events_applied += 1;
+        self.unpublished += 1;
+
+        if self.unpublished >= self.publish_every {
+            self.publish();
+        }
+    }
+}
+
+/// Apply an event to the entry of its aggregate
+///
+/// Returns false for redeliveries, which are already reflected. Removed
+/// connections keep their entry so that redeliveries of their earlier
+/// events are recognised.
+fn apply_entry<S: Clone>(
+    entries: &mut HashMap<Uuid, Arc<Entry<S>>>,
+    event: &StoredEvent<InfrastructureEvent>,
+    initial: impl FnOnce(Uuid) -> S,
+    apply: impl FnOnce(S) -> S,
+) -> bool {
+    let current = entries.get(&event.aggregate_id);
+    if current.is_some_and(|entry| event.sequence <= entry.sequence) {
+        return false;
+    }
+
+    let state = current
+        .map(|entry| entry.state.clone())
+        .unwrap_or_else(|| initial(event.aggregate_id));
+    entries.insert(
+        event.aggregate_id,
+        Arc::new(Entry {
+            state: apply(state),
+            sequence: event.sequence,
+        }),
+    );
+    true
+}
+
+#[async_trait]
+impl ProjectionAdapter for InMemoryReadModel {
+    type Event = StoredEvent<InfrastructureEvent>;
+    type Error = ProjectionError;
+
+    async fn project(&mut self, event: Self::Event) -> Result<(), Self::Error> {
+        match &event.data {
+            InfrastructureEvent::ComputeResource(_) => {}
+            InfrastructureEvent::Connection(connection) => {
+                let applied = apply_entry(
+                    &mut self.connections,
+                    &event,
+                    ConnectionState::default_for,
+                    |state| apply_connection_event(state, connection),
+                );
+                self.applied_if(applied);
+                return Ok(());
+            }
+            InfrastructureEvent::Network(network) => {
+                let applied = apply_entry(
+                    &mut self.networks,
+                    &event,
+                    NetworkState::default_for,
+                    |state| apply_network_event(state, network),
+                );
+                self.applied_if(applied);
+                return Ok(());
+            }
+            InfrastructureEvent::NetworkInterface(interface) => {
+                let applied = apply_entry(
+                    &mut self.interfaces,
+                    &event,
+                    NetworkInterfaceState::default_for,
+                    |state| apply_network_interface_event(state, interface),
+                );
+                self.applied_if(applied);
+                return Ok(());
+            }
+            // Other aggregates are not kept in this read model
+            _ => return Ok(()),
+        }
+
+        let current = self.working.get(&event.aggregate_id);
+
+        // Redelivered events are already reflected in the state
+        if current.is_some_and(|entry| event.sequence <= entry.sequence) {
+            return Ok(());
+        }
+
+        let state = current
+            .map(|entry| entry.state.clone())
+            .unwrap_or_else(|| ComputeResourceState::default_for(event.aggregate_id));
+        let state = apply_infrastructure_event(state, &event.data);
+
+        self.working.insert(
+            event.aggregate_id,
+            Arc::new(Entry {
+                state: self.handle.detail.retain(state.clone()),
+                sequence: event.sequence,
+            }),
+        );
+
+        // Rollups read the host from the state before detail is dropped
+        let working = &self.working;
+        let changes = self
+            .rollups
+            .update(&state, |id| working.get(&id).map(|entry| &entry.state));
+        if let Some(client) = &self.advisories {
+            for change in changes {
+                let advisory = change.to_advisory(event.correlation_id);
+                if let Err(e) = client.publish(&advisory.subject(), &advisory).await {
+                    warn!("Failed to publish host rollup advisory: {}", e);
+                }
+            }
+        }
+
+        self.applied();
+        Ok(())
+    }
+
+    async fn initialize(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    async fn health_check(&self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    async fn reset(&mut self) -> Result<(), Self::Error> {
+        self.working.clear();
+        self.connections.clear();
+        self.networks.clear();
+        self.interfaces.clear();
+        self.rollups.clear();
+        self.events_applied = 0;
+        self.publish();
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "in_memory_read_model"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Hostname, IpAddressWithCidr, ResourceType};
+    use crate::events::compute_resource::{
+        ComputeResourceEvent, MetadataUpdated, ResourceRegistered, StatusChanged,
+    };
+    use crate::events::network::{NetworkDefined, NetworkEvent};
+    use crate::events::ResourceStatus;
+    use crate::rollup::RollupStatus;
+    use chrono::{DateTime, Utc};
+
+    fn test_timestamp() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-19T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn stored(sequence: u64, event: ComputeResourceEvent) -> StoredEvent<InfrastructureEvent> {
+        StoredEvent::new(
+            Uuid::now_v7(),
+            event.aggregate_id(),
+            sequence,
+            Uuid::now_v7(),
+            Uuid::now_v7(),
+            "test",
+            InfrastructureEvent::ComputeResource(event),
+        )
+    }
+
+    fn history(aggregate_id: Uuid) -> Vec<StoredEvent<InfrastructureEvent>> {
+        vec![
+            stored(
+                1,
+                ComputeResourceEvent::ResourceRegistered(ResourceRegistered {
+                    event_version: 1,
+                    event_id: Uuid::now_v7(),
+                    aggregate_id,
+                    timestamp: test_timestamp(),
+                    correlation_id: Uuid::now_v7(),
+                    causation_id: None,
+                    hostname: Hostname::new("web-01").unwrap(),
+                    resource_type: ResourceType::PhysicalServer,
+                }),
+            ),
+            stored(
+                2,
+                ComputeResourceEvent::MetadataUpdated(MetadataUpdated {
+                    event_version: 1,
+                    event_id: Uuid::now_v7(),
+                    aggregate_id,
+                    timestamp: test_timestamp(),
+                    correlation_id: Uuid::now_v7(),
+                    causation_id: None,
+                    key: "rack".to_string(),
+                    value: "R12".to_string(),
+                }),
+            ),
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_key_fields_drop_detail() {
+        // Arrange
+        let aggregate_id = Uuid::now_v7();
+        let mut full = InMemoryReadModel::new(ReadModelConfig::default());
+        let mut lean = InMemoryReadModel::new(
+            ReadModelConfig::default().with_detail(RetainedDetail::KeyFields),
+        );
+
+        // Act
+        for event in history(aggregate_id) {
+            full.project(event.clone()).await.unwrap();
+            lean.project(event).await.unwrap();
+        }
+
+        // Assert
+        let full_state = full.handle().compute_resource(aggregate_id).await.unwrap();
+        let lean_state = lean.handle().compute_resource(aggregate_id).await.unwrap();
+        assert_eq!(full_state.metadata.len(), 1);
+        assert!(lean_state.metadata.is_empty());
+        assert_eq!(lean_state.hostname.as_str(), "web-01");
+    }
+
+    #[tokio::test]
+    async fn test_redelivered_events_are_skipped() {
+        let aggregate_id = Uuid::now_v7();
+        let mut model = InMemoryReadModel::new(ReadModelConfig::default());
+        let events = history(aggregate_id);
+
+        model.project(events[0].clone()).await.unwrap();
+        model.project(events[1].clone()).await.unwrap();
+        model.project(events[0].clone()).await.unwrap();
+
+        let state = model.handle().get(aggregate_id).unwrap();
+        assert_eq!(state.metadata.len(), 1);
+        assert_eq!(model.handle().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_snapshots_are_immutable_and_batched() {
+        // Arrange
+        let aggregate_id = Uuid::now_v7();
+        let mut model = InMemoryReadModel::new(ReadModelConfig::default().with_publish_every(2));
+        let handle = model.handle();
+        let events = history(aggregate_id);
+
+        // Act
+        let before = handle.snapshot();
+        model.project(events[0].clone()).await.unwrap();
+        let unpublished = handle.len();
+        model.project(events[1].clone()).await.unwrap();
+
+        // Assert
+        assert!(before.is_empty());
+        assert_eq!(unpublished, 0);
+        assert_eq!(handle.snapshot().events_applied(), 2);
+        assert_eq!(handle.get(aggregate_id).unwrap().metadata.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_guest_maintenance_rolls_up_to_host() {
+        // Arrange: a host and a guest placed on it, keeping key fields only
+        let host_id = Uuid::now_v7();
+        let guest_id = Uuid::now_v7();
+        let mut model = InMemoryReadModel::new(
+            ReadModelConfig::default().with_detail(RetainedDetail::KeyFields),
+        );
+        model.project(history(host_id).remove(0)).await.unwrap();
+        model.project(history(guest_id).remove(0)).await.unwrap();
+        model
+            .project(stored(
+                2,
+                ComputeResourceEvent::MetadataUpdated(MetadataUpdated {
+                    event_version: 1,
+                    event_id: Uuid::now_v7(),
+                    aggregate_id: guest_id,
+                    timestamp: test_timestamp(),
+                    correlation_id: Uuid::now_v7(),
+                    causation_id: None,
+                    key: "host_id".to_string(),
+                    value: host_id.to_string(),
+                }),
+            ))
+            .await
+            .unwrap();
+        let handle = model.handle();
+        assert!(handle.hosts_needing_attention().is_empty());
+
+        // Act
+        model
+            .project(stored(
+                3,
+                ComputeResourceEvent::StatusChanged(StatusChanged {
+                    event_version: 1,
+                    event_id: Uuid::now_v7(),
+                    aggregate_id: guest_id,
+                    timestamp: test_timestamp(),
+                    correlation_id: Uuid::now_v7(),
+                    causation_id: None,
+                    from_status: ResourceStatus::Provisioning,
+                    to_status: ResourceStatus::Maintenance,
+                }),
+            ))
+            .await
+            .unwrap();
+
+        // Assert
+        let attention = handle.hosts_needing_attention();
+        assert_eq!(attention.len(), 1);
+        assert_eq!(attention[0].host_id, host_id);
+        assert_eq!(attention[0].status, RollupStatus::Degraded);
+        assert_eq!(attention[0].degraded_guests, vec![guest_id]);
+        assert_eq!(handle.snapshot().rollup(host_id).unwrap().guest_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_networks_are_kept_and_redeliveries_skipped() {
+        // Arrange
+        let network_id = Uuid::now_v7();
+        let mut model = InMemoryReadModel::new(ReadModelConfig::default());
+        let defined = StoredEvent::new(
+            Uuid::now_v7(),
+            network_id,
+            1,
+            Uuid::now_v7(),
+            Uuid::now_v7(),
+            "test",
+            InfrastructureEvent::Network(NetworkEvent::NetworkDefined(NetworkDefined {
+                event_version: 1,
+                event_id: Uuid::now_v7(),
+                aggregate_id: network_id,
+                timestamp: test_timestamp(),
+                correlation_id: Uuid::now_v7(),
+                causation_id: None,
+                name: "servers".to_string(),
+                cidr: IpAddressWithCidr::new("10.20.0.0/24").unwrap(),
+                vlan_id: None,
+                segment: None,
+            })),
+        );
+
+        // Act
+        model.project(defined.clone()).await.unwrap();
+        model.project(defined).await.unwrap();
+
+        // Assert
+        let snapshot = model.handle().snapshot();
+        assert_eq!(snapshot.network(network_id).unwrap().name, "servers");
+        assert_eq!(snapshot.networks().count(), 1);
+        assert_eq!(snapshot.events_applied(), 1);
+        assert!(snapshot.is_empty());
+    }
+}
@@ -0,0 +1,492 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Resource Registry Projection
+//!
+//! Keeps a [`ResourceSummary`] of every compute resource in memory so
+//! listings can be filtered and paged without replaying the event store.
+//!
+//! ```text
+//! StoredEvent ──> ResourceRegistry ──publish──> ArcSwap<ResourceRegistrySnapshot>
+//!                                                     │ load (lock-free)
+//!                ResourceRegistryHandle (clone) ──────┘
+//!                   list_resources(filter, page, page_size)
+//! ```
+//!
+//! | Filter            | Matches resources                                   |
+//! |-------------------|-----------------------------------------------------|
+//! | `resource_type`   | of that type                                        |
+//! | `organization_id` | assigned to that organization                       |
+//! | `status`          | in that status; without it, all but decommissioned  |
+//! | `metadata`        | carrying every key (and value, when one is given)   |
+//!
+//! Pages are numbered from 1 and ordered by hostname, then aggregate ID, so
+//! paging through a registry that is not changing visits every resource
+//! once. Page sizes are capped at [`MAX_PAGE_SIZE`].
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::projection::registry::{ResourceFilter, ResourceRegistry};
+//!
+//! let registry = ResourceRegistry::new();
+//! let resources = registry.handle();
+//! manager.register(registry);
+//!
+//! let filter = ResourceFilter::default().with_metadata("rack", Some("R12"));
+//! let page = resources.list_resources(&filter, 1, 50);
+//! ```
+
+use arc_swap::ArcSwap;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::aggregate::{apply_event, ComputeResourceState};
+use crate::domain::ResourceType;
+use crate::events::{InfrastructureEvent, ResourceStatus};
+use crate::jetstream::StoredEvent;
+use crate::projection::{ProjectionAdapter, ProjectionError};
+
+/// Largest page a listing returns
+pub const MAX_PAGE_SIZE: u32 = 500;
+
+/// What a listing shows of one resource
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ResourceSummary {
+    /// Resource aggregate ID
+    pub aggregate_id: Uuid,
+
+    /// Hostname
+    pub hostname: String,
+
+    /// Kind of resource
+    pub resource_type: ResourceType,
+
+    /// Owning organization, if assigned
+    pub organization_id: Option<String>,
+
+    /// Current status
+    pub status: ResourceStatus,
+
+    /// Custom metadata
+    pub metadata: BTreeMap<String, String>,
+
+    /// When the resource was registered
+    pub created_at: Option<DateTime<Utc>>,
+
+    /// When the resource last changed
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl ResourceSummary {
+    /// Summarize a registered resource
+    pub fn of(state: &ComputeResourceState) -> Option<Self> {
+        state.is_initialized().then(|| Self {
+            aggregate_id: state.id,
+            hostname: state.hostname.to_string(),
+            resource_type: state.resource_type,
+            organization_id: state.organization_id.as_ref().map(ToString::to_string),
+            status: state.status,
+            metadata: state.metadata.iter().cloned().collect(),
+            created_at: state.created_at,
+            updated_at: state.updated_at,
+        })
+    }
+}
+
+/// Which resources a listing includes
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ResourceFilter {
+    /// Only resources of this type
+    #[serde(default)]
+    pub resource_type: Option<ResourceType>,
+
+    /// Only resources of this organization
+    #[serde(default)]
+    pub organization_id: Option<String>,
+
+    /// Only resources in this status; omit to leave out decommissioned ones
+    #[serde(default)]
+    pub status: Option<ResourceStatus>,
+
+    /// Metadata keys resources must carry, with the value to match or null
+    /// for any value
+    #[serde(default)]
+    pub metadata: BTreeMap<String, Option<String>>,
+}
+
+impl ResourceFilter {
+    /// Only resources of `resource_type`
+    pub fn with_resource_type(mut self, resource_type: ResourceType) -> Self {
+        self.resource_type = Some(resource_type);
+        self
+    }
+
+    /// Only resources of `organization_id`
+    pub fn with_organization(mut self, organization_id: impl Into<String>) -> Self {
+        self.organization_id = Some(organization_id.into());
+        self
+    }
+
+    /// Only resources in `status`
+    pub fn with_status(mut self, status: ResourceStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Only resources carrying `key`, set to `value` when one is given
+    pub fn with_metadata(mut self, key: impl Into<String>, value: Option<&str>) -> Self {
+        self.metadata.insert(key.into(), value.map(str::to_string));
+        self
+    }
+
+    /// Whether `summary` is included
+    pub fn matches(&self, summary: &ResourceSummary) -> bool {
+        let status = match self.status {
+            Some(status) => summary.status == status,
+            None => summary.status != ResourceStatus::Decommissioned,
+        };
+        status
+            && self
+                .resource_type
+                .map_or(true, |resource_type| summary.resource_type == resource_type)
+            && self.organization_id.as_ref().map_or(true, |organization| {
+                summary.organization_id.as_ref() == Some(organization)
+            })
+            && self
+                .metadata
+                .iter()
+                .all(|(key, value)| match (summary.metadata.get(key), value) {
+                    (Some(actual), Some(expected)) => actual == expected,
+                    (Some(_), None) => true,
+                    (None, _) => false,
+                })
+    }
+}
+
+/// One page of a resource listing
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ResourcePage {
+    /// Resources on this page
+    pub items: Vec<ResourceSummary>,
+
+    /// Page number, from 1
+    pub page: u32,
+
+    /// Resources per page
+    pub page_size: u32,
+
+    /// Resources matching the filter, on all pages
+    pub total_items: u64,
+
+    /// Pages the matching resources fill
+    pub total_pages: u32,
+}
+
+/// Filter, order and page `summaries`
+///
+/// `page` 0 is read as 1, and `page_size` is clamped to
+/// 1..=[`MAX_PAGE_SIZE`]. Pages past the end are empty.
+pub fn paginate<'a>(
+    summaries: impl IntoIterator<Item = &'a ResourceSummary>,
+    filter: &ResourceFilter,
+    page: u32,
+    page_size: u32,
+) -> ResourcePage {
+    let page = page.max(1);
+    let page_size = page_size.clamp(1, MAX_PAGE_SIZE);
+
+    let mut matching: Vec<&ResourceSummary> = summaries
+        .into_iter()
+        .filter(|summary| filter.matches(summary))
+        .collect();
+    matching.sort_by(|a, b| {
+        (a.hostname.as_str(), a.aggregate_id).cmp(&(b.hostname.as_str(), b.aggregate_id))
+    });
+
+    let total_items = matching.len() as u64;
+    let items = matching
+        .into_iter()
+        .skip((page as usize - 1).saturating_mul(page_size as usize))
+        .take(page_size as usize)
+        .cloned()
+        .collect();
+
+    ResourcePage {
+        items,
+        page,
+        page_size,
+        total_items,
+        total_pages: total_items.div_ceil(page_size as u64) as u32,
+    }
+}
+
+/// Resource state, its summary and the last aggregate sequence applied
+#[derive(Debug)]
+struct Entry {
+    state: ComputeResourceState,
+    summary: Option<ResourceSummary>,
+    sequence: u64,
+}
+
+/// Immutable view of all resources at one point in the event stream
+#[derive(Debug, Default)]
+pub struct ResourceRegistrySnapshot {
+    resources: HashMap<Uuid, Arc<Entry>>,
+}
+
+impl ResourceRegistrySnapshot {
+    /// Summary of a registered resource
+    pub fn get(&self, aggregate_id: Uuid) -> Option<&ResourceSummary> {
+        self.resources.get(&aggregate_id)?.summary.as_ref()
+    }
+
+    /// Every registered resource, in no particular order
+    pub fn resources(&self) -> impl Iterator<Item = &ResourceSummary> {
+        self.resources
+            .values()
+            .filter_map(|entry| entry.summary.as_ref())
+    }
+
+    /// One page of the resources matching `filter`
+    pub fn list(&self, filter: &ResourceFilter, page: u32, page_size: u32) -> ResourcePage {
+        paginate(self.resources(), filter, page, page_size)
+    }
+
+    /// Snapshot of `events`, for listings without a running projection
+    pub fn from_events<'a>(
+        events: impl IntoIterator<Item = &'a StoredEvent<InfrastructureEvent>>,
+    ) -> Self {
+        let mut resources = HashMap::new();
+        for event in events {
+            apply(&mut resources, event);
+        }
+        Self { resources }
+    }
+}
+
+/// Fold `event` into `resources`, returning whether anything changed
+fn apply(
+    resources: &mut HashMap<Uuid, Arc<Entry>>,
+    event: &StoredEvent<InfrastructureEvent>,
+) -> bool {
+    let InfrastructureEvent::ComputeResource(compute_event) = &event.data else {
+        return false;
+    };
+
+    let current = resources.get(&event.aggregate_id);
+
+    // Redelivered events are already reflected in the state
+    if current.is_some_and(|entry| event.sequence <= entry.sequence) {
+        return false;
+    }
+
+    let state = current
+        .map(|entry| entry.state.clone())
+        .unwrap_or_else(|| ComputeResourceState::default_for(event.aggregate_id));
+    let state = apply_event(state, compute_event);
+
+    resources.insert(
+        event.aggregate_id,
+        Arc::new(Entry {
+            summary: ResourceSummary::of(&state),
+            state,
+            sequence: event.sequence,
+        }),
+    );
+    true
+}
+
+/// Cloneable query-side handle onto the registry
+#[derive(Clone)]
+pub struct ResourceRegistryHandle {
+    snapshot: Arc<ArcSwap<ResourceRegistrySnapshot>>,
+}
+
+impl ResourceRegistryHandle {
+    /// Current snapshot, for several reads against one consistent view
+    pub fn snapshot(&self) -> Arc<ResourceRegistrySnapshot> {
+        self.snapshot.load_full()
+    }
+
+    /// Summary of a registered resource
+    pub fn get(&self, aggregate_id: Uuid) -> Option<ResourceSummary> {
+        self.snapshot.load().get(aggregate_id).cloned()
+    }
+
+    /// One page of the resources matching `filter`
+    pub fn list_resources(
+        &self,
+        filter: &ResourceFilter,
+        page: u32,
+        page_size: u32,
+    ) -> ResourcePage {
+        self.snapshot.load().list(filter, page, page_size)
+    }
+}
+
+/// Projection maintaining the resource registry
+pub struct ResourceRegistry {
+    handle: ResourceRegistryHandle,
+    working: HashMap<Uuid, Arc<Entry>>,
+}
+
+impl ResourceRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self {
+            handle: ResourceRegistryHandle {
+                snapshot: Arc::new(ArcSwap::from_pointee(ResourceRegistrySnapshot::default())),
+            },
+            working: HashMap::new(),
+        }
+    }
+
+    /// Query-side handle sharing this registry's data
+    pub fn handle(&self) -> ResourceRegistryHandle {
+        self.handle.clone()
+    }
+
+    fn publish(&self) {
+        self.handle
+            .snapshot
+            .store(Arc::new(ResourceRegistrySnapshot {
+                resources: self.working.clone(),
+            }));
+    }
+}
+
+impl Default for ResourceRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ProjectionAdapter for ResourceRegistry {
+    type Event = StoredEvent<InfrastructureEvent>;
+    type Error = ProjectionError;
+
+    async fn project(&mut self, event: Self::Event) -> Result<(), Self::Error> {
+        if apply(&mut self.working, &event) {
+            self.publish();
+        }
+        Ok(())
+    }
+
+    async fn initialize(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    async fn health_check(&self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    async fn reset(&mut self) -> Result<(), Self::Error> {
+        self.working.clear();
+        self.publish();
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "resource_registry"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{ComputeResourceEvent, StatusChanged};
+    use crate::test_harness;
+
+    fn decommissioned(aggregate_id: Uuid) -> InfrastructureEvent {
+        InfrastructureEvent::ComputeResource(ComputeResourceEvent::StatusChanged(StatusChanged {
+            event_version: 1,
+            event_id: Uuid::now_v7(),
+            aggregate_id,
+            timestamp: test_harness::test_timestamp(),
+            correlation_id: Uuid::now_v7(),
+            causation_id: None,
+            from_status: ResourceStatus::Provisioning,
+            to_status: ResourceStatus::Decommissioned,
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_registry_filters_by_metadata_and_hides_decommissioned() {
+        // Arrange
+        let (web, db, old) = (Uuid::now_v7(), Uuid::now_v7(), Uuid::now_v7());
+        let mut registry = ResourceRegistry::new();
+        let resources = registry.handle();
+        let mut events = test_harness::resource_history(web, "web-01");
+        events.push(test_harness::stored(
+            1,
+            test_harness::registered(db, "db-01"),
+        ));
+        events.push(test_harness::stored(
+            1,
+            test_harness::registered(old, "old-01"),
+        ));
+        events.push(test_harness::stored(2, decommissioned(old)));
+
+        // Act - the metadata update is delivered twice
+        events.push(events[1].clone());
+        test_harness::project_all(&mut registry, events)
+            .await
+            .unwrap();
+        let in_rack = ResourceFilter::default().with_metadata("rack", Some("R12"));
+        let with_rack = ResourceFilter::default().with_metadata("rack", None);
+        let retired = ResourceFilter::default().with_status(ResourceStatus::Decommissioned);
+
+        // Assert
+        let active = resources.list_resources(&ResourceFilter::default(), 1, 50);
+        assert_eq!(active.total_items, 2);
+        assert_eq!(active.items[0].hostname, "db-01");
+        assert_eq!(
+            resources.list_resources(&in_rack, 1, 50).items[0].aggregate_id,
+            web
+        );
+        assert_eq!(resources.list_resources(&with_rack, 1, 50).total_items, 1);
+        assert_eq!(
+            resources.list_resources(&retired, 1, 50).items[0].aggregate_id,
+            old
+        );
+        assert_eq!(resources.get(web).unwrap().metadata["rack"], "R12");
+    }
+
+    #[test]
+    fn test_pages_are_ordered_by_hostname_and_clamped() {
+        // Arrange
+        let events: Vec<_> = (1..=5)
+            .map(|n| {
+                test_harness::stored(
+                    1,
+                    test_harness::registered(Uuid::now_v7(), &format!("host-{}", n)),
+                )
+            })
+            .collect();
+        let snapshot = ResourceRegistrySnapshot::from_events(&events);
+        let all = ResourceFilter::default();
+
+        // Act
+        let second = snapshot.list(&all, 2, 2);
+        let past_end = snapshot.list(&all, 4, 2);
+        let unbounded = snapshot.list(&all, 0, u32::MAX);
+
+        // Assert
+        let hostnames: Vec<_> = second.items.iter().map(|s| s.hostname.as_str()).collect();
+        assert_eq!(hostnames, ["host-3", "host-4"]);
+        assert_eq!((second.total_items, second.total_pages), (5, 3));
+        assert!(past_end.items.is_empty());
+        assert_eq!((unbounded.page, unbounded.page_size), (1, MAX_PAGE_SIZE));
+        assert_eq!(unbounded.items.len(), 5);
+    }
+}
@@ -0,0 +1,325 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Resource State in NATS KV
+//!
+//! Writes the latest [`ComputeResourceState`] of every compute resource to
+//! a JetStream KV bucket, so other services get the current state of a
+//! resource with one key lookup, and react to changes with a KV watch,
+//! without replaying the event store or running a read model of their own:
+//!
+//! ```text
+//! StoredEvent ──> ResourceKvProjection ──fold──> infra_resources
+//!                                                  0193…  ──> {"id": "0193…", "hostname": …, "sequence": 7}
+//!                                                  │
+//!                 ResourceStateStore::get(id) ─────┤
+//!                 ResourceStateStore::watch() ─────┘──> ResourceChange
+//! ```
+//!
+//! Keys are aggregate IDs. Values are the state as JSON plus the aggregate
+//! `sequence` it reflects, so they decode as a plain `ComputeResourceState`
+//! in any language. Redelivered events are recognised by that sequence and
+//! skipped. The projection must be the only writer of the bucket; run one
+//! instance under a [`ProjectionManager`](crate::projection::ProjectionManager).
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::projection::resource_kv::{ResourceKvProjection, ResourceStateStore};
+//!
+//! let store = ResourceStateStore::open(jetstream.clone()).await?;
+//! manager.register(ResourceKvProjection::new(store.clone()));
+//!
+//! let state = store.get(aggregate_id).await?;
+//! let mut changes = store.watch().await?;
+//! while let Some(change) = changes.next().await {
+//!     println!("{} changed", change?.aggregate_id);
+//! }
+//! ```
+
+use async_nats::jetstream::{self, kv};
+use async_trait::async_trait;
+use futures::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::aggregate::compute_resource::apply_infrastructure_event;
+use crate::aggregate::ComputeResourceState;
+use crate::errors::{InfrastructureError, InfrastructureResult};
+use crate::events::InfrastructureEvent;
+use crate::jetstream::StoredEvent;
+use crate::projection::{ProjectionAdapter, ProjectionError};
+
+/// KV bucket holding the latest state of every resource
+pub const RESOURCES_BUCKET: &str = "infra_resources";
+
+/// Value stored per resource
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceRecord {
+    /// Aggregate sequence of the last event applied
+    pub sequence: u64,
+
+    /// State after that event
+    #[serde(flatten)]
+    pub state: ComputeResourceState,
+}
+
+impl ResourceRecord {
+    /// Record after applying `event` to `current`
+    ///
+    /// Returns `None` for events of other aggregates and for redeliveries
+    /// already reflected in `current`.
+    pub fn next(
+        current: Option<&ResourceRecord>,
+        event: &StoredEvent<InfrastructureEvent>,
+    ) -> Option<Self> {
+        if !matches!(event.data, InfrastructureEvent::ComputeResource(_)) {
+            return None;
+        }
+        if current.is_some_and(|record| event.sequence <= record.sequence) {
+            return None;
+        }
+        let state = current
+            .map(|record| record.state.clone())
+            .unwrap_or_else(|| ComputeResourceState::default_for(event.aggregate_id));
+        Some(Self {
+            sequence: event.sequence,
+            state: apply_infrastructure_event(state, &event.data),
+        })
+    }
+}
+
+/// A resource's entry in the bucket changed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceChange {
+    /// Changed resource
+    pub aggregate_id: Uuid,
+
+    /// New state, or `None` if the entry was deleted
+    pub state: Option<ComputeResourceState>,
+
+    /// KV revision of the change
+    pub revision: u64,
+}
+
+/// Latest resource states in a JetStream KV bucket
+///
+/// Cloning is cheap; all clones share the bucket.
+#[derive(Clone)]
+pub struct ResourceStateStore {
+    store: kv::Store,
+}
+
+impl ResourceStateStore {
+    /// Open the resource bucket, creating it if needed
+    pub async fn open(jetstream: jetstream::Context) -> InfrastructureResult<Self> {
+        let store = match jetstream.get_key_value(RESOURCES_BUCKET).await {
+            Ok(store) => store,
+            Err(_) => jetstream
+                .create_key_value(kv::Config {
+                    bucket: RESOURCES_BUCKET.to_string(),
+                    description: "Latest state of every compute resource".to_string(),
+                    history: 1,
+                    ..Default::default()
+                })
+                .await
+                .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?,
+        };
+        Ok(Self { store })
+    }
+
+    /// Latest state of a resource
+    pub async fn get(
+        &self,
+        aggregate_id: Uuid,
+    ) -> InfrastructureResult<Option<ComputeResourceState>> {
+        Ok(self.record(aggregate_id).await?.map(|record| record.state))
+    }
+
+    /// Latest record of a resource
+    pub async fn record(&self, aggregate_id: Uuid) -> InfrastructureResult<Option<ResourceRecord>> {
+        let value = self
+            .store
+            .get(aggregate_id.to_string())
+            .await
+            .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?;
+        value
+            .map(|bytes| {
+                serde_json::from_slice(&bytes)
+                    .map_err(|e| InfrastructureError::Deserialization(e.to_string()))
+            })
+            .transpose()
+    }
+
+    /// Changes of every resource from now on
+    ///
+    /// Entries that cannot be decoded end up as `Deserialization` errors;
+    /// the stream continues after them.
+    pub async fn watch(
+        &self,
+    ) -> InfrastructureResult<impl Stream<Item = InfrastructureResult<ResourceChange>> + '_> {
+        let watch = self
+            .store
+            .watch_all()
+            .await
+            .map_err(|e| InfrastructureError::NatsSubscribe(e.to_string()))?;
+        Ok(watch.map(|entry| {
+            let entry = entry.map_err(|e| InfrastructureError::NatsSubscribe(e.to_string()))?;
+            change_of(&entry)
+        }))
+    }
+
+    async fn put(&self, record: &ResourceRecord) -> Result<(), ProjectionError> {
+        let value =
+            serde_json::to_vec(record).map_err(|e| ProjectionError::Other(e.to_string()))?;
+        self.store
+            .put(record.state.id.to_string(), value.into())
+            .await
+            .map_err(|e| ProjectionError::TargetUnavailable(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn clear(&self) -> Result<(), ProjectionError> {
+        let mut keys = self
+            .store
+            .keys()
+            .await
+            .map_err(|e| ProjectionError::TargetUnavailable(e.to_string()))?;
+
+        while let Some(key) = keys.next().await {
+            let key = key.map_err(|e| ProjectionError::TargetUnavailable(e.to_string()))?;
+            self.store
+                .purge(&key)
+                .await
+                .map_err(|e| ProjectionError::TargetUnavailable(e.to_string()))?;
+        }
+        Ok(())
+    }
+}
+
+/// Change described by a watched entry
+fn change_of(entry: &kv::Entry) -> InfrastructureResult<ResourceChange> {
+    let aggregate_id = entry
+        .key
+        .parse()
+        .map_err(|_| InfrastructureError::Deserialization(format!("Invalid key {}", entry.key)))?;
+    let state = match entry.operation {
+        kv::Operation::Put => Some(
+            serde_json::from_slice::<ResourceRecord>(&entry.value)
+                .map_err(|e| InfrastructureError::Deserialization(e.to_string()))?
+                .state,
+        ),
+        kv::Operation::Delete | kv::Operation::Purge => None,
+    };
+    Ok(ResourceChange {
+        aggregate_id,
+        state,
+        revision: entry.revision,
+    })
+}
+
+/// Projection writing resource states to [`RESOURCES_BUCKET`]
+pub struct ResourceKvProjection {
+    store: ResourceStateStore,
+}
+
+impl ResourceKvProjection {
+    /// Write states to `store`
+    pub fn new(store: ResourceStateStore) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl ProjectionAdapter for ResourceKvProjection {
+    type Event = StoredEvent<InfrastructureEvent>;
+    type Error = ProjectionError;
+
+    async fn project(&mut self, event: Self::Event) -> Result<(), Self::Error> {
+        if !matches!(event.data, InfrastructureEvent::ComputeResource(_)) {
+            return Ok(());
+        }
+        let current = self
+            .store
+            .record(event.aggregate_id)
+            .await
+            .map_err(|e| ProjectionError::TargetUnavailable(e.to_string()))?;
+        match ResourceRecord::next(current.as_ref(), &event) {
+            Some(record) => self.store.put(&record).await,
+            None => Ok(()),
+        }
+    }
+
+    async fn initialize(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    async fn health_check(&self) -> Result<(), Self::Error> {
+        self.store
+            .store
+            .status()
+            .await
+            .map(|_| ())
+            .map_err(|e| ProjectionError::TargetUnavailable(e.to_string()))
+    }
+
+    async fn reset(&mut self) -> Result<(), Self::Error> {
+        self.store.clear().await
+    }
+
+    fn name(&self) -> &str {
+        "resource-kv"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_harness::{metadata_updated, registered, stored};
+
+    #[test]
+    fn test_records_fold_events_and_skip_redeliveries() {
+        // Arrange
+        let aggregate_id = Uuid::now_v7();
+        let first = ResourceRecord::next(None, &stored(1, registered(aggregate_id, "web-01")))
+            .expect("registration is recorded");
+
+        // Act
+        let second = ResourceRecord::next(
+            Some(&first),
+            &stored(2, metadata_updated(aggregate_id, "rack", "R12")),
+        )
+        .expect("update is recorded");
+        let redelivered = ResourceRecord::next(
+            Some(&second),
+            &stored(2, metadata_updated(aggregate_id, "rack", "R12")),
+        );
+
+        // Assert
+        assert_eq!(first.state.hostname.as_str(), "web-01");
+        assert_eq!(second.sequence, 2);
+        assert_eq!(
+            second.state.metadata,
+            [("rack".to_string(), "R12".to_string())]
+        );
+        assert_eq!(redelivered, None);
+    }
+
+    #[test]
+    fn test_stored_value_decodes_as_plain_state() {
+        // Arrange
+        let aggregate_id = Uuid::now_v7();
+        let record = ResourceRecord::next(None, &stored(3, registered(aggregate_id, "web-01")))
+            .expect("registration is recorded");
+
+        // Act
+        let value = serde_json::to_value(&record).unwrap();
+        let state: ComputeResourceState = serde_json::from_value(value.clone()).unwrap();
+
+        // Assert
+        assert_eq!(value["sequence"], 3);
+        assert_eq!(state, record.state);
+        assert_eq!(
+            serde_json::from_value::<ResourceRecord>(value).unwrap(),
+            record
+        );
+    }
+}
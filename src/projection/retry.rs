@@ -0,0 +1,326 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Retrying Projections
+//!
+//! Neo4j, NetBox and NATS targets fail transiently (restarts, timeouts,
+//! rate limits). [`RetryingProjection`] wraps any adapter and retries
+//! `project` with exponential backoff and jitter before surfacing the error.
+//! Permanent failures (malformed events, duplicates) are returned at once.
+//!
+//! # Backoff
+//!
+//! ```text
+//! delay(n) = min(initial_backoff × multiplier^(n-1), max_backoff) × (1 ± jitter)
+//! ```
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::projection::retry::{RetryPolicy, RetryingProjection};
+//!
+//! let policy = RetryPolicy::default()
+//!     .with_max_attempts(8)
+//!     .with_max_backoff(Duration::from_secs(30));
+//! let projection = RetryingProjection::new(neo4j_adapter, policy);
+//! ```
+
+use async_trait::async_trait;
+use std::time::Duration;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::projection::{ProjectionAdapter, ProjectionError};
+
+/// Classifies errors as worth retrying or not
+pub trait RetryableError {
+    /// Whether the operation may succeed if attempted again
+    fn is_transient(&self) -> bool;
+}
+
+impl RetryableError for ProjectionError {
+    fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            ProjectionError::TargetUnavailable(_) | ProjectionError::DatabaseError(_)
+        )
+    }
+}
+
+/// Retry limits and backoff shape
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Total attempts including the first (1 = no retries)
+    pub max_attempts: u32,
+
+    /// Delay before the first retry
+    pub initial_backoff: Duration,
+
+    /// Upper bound for any single delay
+    pub max_backoff: Duration,
+
+    /// Growth factor between consecutive delays
+    pub multiplier: f64,
+
+    /// Random spread applied to each delay, as a fraction (0.0 - 1.0)
+    pub jitter: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(10),
+            multiplier: 2.0,
+            jitter: 0.2,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Policy that never retries
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Set the total number of attempts
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Set the delay before the first retry
+    pub fn with_initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Set the maximum delay
+    pub fn with_max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Set the growth factor between delays
+    pub fn with_multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier.max(1.0);
+        self
+    }
+
+    /// Set the jitter fraction (clamped to 0.0 - 1.0)
+    pub fn with_jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Delay before retry number `retry` (1-based), without jitter
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let exponent = retry.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let secs = self.initial_backoff.as_secs_f64() * self.multiplier.powi(exponent);
+
+        if !secs.is_finite() || secs >= self.max_backoff.as_secs_f64() {
+            self.max_backoff
+        } else {
+            Duration::from_secs_f64(secs)
+        }
+    }
+
+    /// Apply jitter to a delay; `random` is uniform in 0.0 - 1.0
+    pub fn jittered(&self, delay: Duration, random: f64) -> Duration {
+        let factor = 1.0 + self.jitter * (2.0 * random.clamp(0.0, 1.0) - 1.0);
+        delay.mul_f64(factor.max(0.0))
+    }
+}
+
+/// Uniform value in 0.0 - 1.0 from the random bits of a v7 UUID
+pub(crate) fn random_unit() -> f64 {
+    let bits = Uuid::now_v7().as_u128() as u64 >> 11;
+    bits as f64 / (1u64 << 53) as f64
+}
+
+/// Projection wrapper that retries transient failures
+pub struct RetryingProjection<P> {
+    inner: P,
+    policy: RetryPolicy,
+}
+
+impl<P> RetryingProjection<P> {
+    /// Wrap a projection with a retry policy
+    pub fn new(inner: P, policy: RetryPolicy) -> Self {
+        Self { inner, policy }
+    }
+
+    /// Access the wrapped projection
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// Active retry policy
+    pub fn policy(&self) -> &RetryPolicy {
+        &self.policy
+    }
+}
+
+#[async_trait]
+impl<P> ProjectionAdapter for RetryingProjection<P>
+where
+    P: ProjectionAdapter,
+    P::Event: Clone,
+    P::Error: RetryableError,
+{
+    type Event = P::Event;
+    type Error = P::Error;
+
+    async fn project(&mut self, event: Self::Event) -> Result<(), Self::Error> {
+        let mut attempt = 1;
+
+        loop {
+            match self.inner.project(event.clone()).await {
+                Ok(()) => return Ok(()),
+                Err(e) if e.is_transient() && attempt < self.policy.max_attempts => {
+                    let delay = self
+                        .policy
+                        .jittered(self.policy.backoff(attempt), random_unit());
+                    warn!(
+                        "Projection '{}' attempt {}/{} failed: {}; retrying in {:?}",
+                        self.inner.name(),
+                        attempt,
+                        self.policy.max_attempts,
+                        e,
+                        delay
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    async fn initialize(&mut self) -> Result<(), Self::Error> {
+        self.inner.initialize().await
+    }
+
+    async fn health_check(&self) -> Result<(), Self::Error> {
+        self.inner.health_check().await
+    }
+
+    async fn reset(&mut self) -> Result<(), Self::Error> {
+        self.inner.reset().await
+    }
+
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Fails with the given error until `failures` attempts have been made
+    struct FlakyProjection {
+        failures: u32,
+        error: ProjectionError,
+        attempts: u32,
+    }
+
+    #[async_trait]
+    impl ProjectionAdapter for FlakyProjection {
+        type Event = String;
+        type Error = ProjectionError;
+
+        async fn project(&mut self, _event: Self::Event) -> Result<(), Self::Error> {
+            self.attempts += 1;
+            if self.attempts <= self.failures {
+                Err(self.error.clone())
+            } else {
+                Ok(())
+            }
+        }
+
+        async fn initialize(&mut self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        async fn health_check(&self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        async fn reset(&mut self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn name(&self) -> &str {
+            "flaky"
+        }
+    }
+
+    fn fast_policy(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy::default()
+            .with_max_attempts(max_attempts)
+            .with_initial_backoff(Duration::from_millis(1))
+            .with_jitter(0.0)
+    }
+
+    #[test]
+    fn test_backoff_grows_and_caps() {
+        let policy = RetryPolicy::default()
+            .with_initial_backoff(Duration::from_millis(100))
+            .with_max_backoff(Duration::from_millis(500));
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(3), Duration::from_millis(400));
+        assert_eq!(policy.backoff(10), Duration::from_millis(500));
+
+        let high = policy.jittered(Duration::from_millis(100), 1.0);
+        assert!(high > Duration::from_millis(119) && high <= Duration::from_millis(120));
+        assert_eq!(
+            policy.jittered(Duration::from_millis(100), 0.5),
+            Duration::from_millis(100)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_transient_errors_are_retried() {
+        let mut projection = RetryingProjection::new(
+            FlakyProjection {
+                failures: 2,
+                error: ProjectionError::TargetUnavailable("neo4j restarting".to_string()),
+                attempts: 0,
+            },
+            fast_policy(3),
+        );
+
+        projection.project("event".to_string()).await.unwrap();
+
+        assert_eq!(projection.inner().attempts, 3);
+    }
+
+    #[tokio::test]
+    async fn test_permanent_errors_and_exhaustion_surface() {
+        let mut invalid = RetryingProjection::new(
+            FlakyProjection {
+                failures: 1,
+                error: ProjectionError::InvalidEvent("bad payload".to_string()),
+                attempts: 0,
+            },
+            fast_policy(5),
+        );
+        assert!(invalid.project("event".to_string()).await.is_err());
+        assert_eq!(invalid.inner().attempts, 1);
+
+        let mut exhausted = RetryingProjection::new(
+            FlakyProjection {
+                failures: 10,
+                error: ProjectionError::DatabaseError("timeout".to_string()),
+                attempts: 0,
+            },
+            fast_policy(2),
+        );
+        assert!(exhausted.project("event".to_string()).await.is_err());
+        assert_eq!(exhausted.inner().attempts, 2);
+    }
+}
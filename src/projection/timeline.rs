@@ -0,0 +1,404 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Resource Timeline Projection
+//!
+//! Builds a per-resource timeline from the aggregate's event history. Events
+//! are grouped into day or week buckets with per-type counts, and notable
+//! transitions (registration, status changes, ownership/location moves) are
+//! surfaced as markers that timeline widgets can render directly.
+//!
+//! # Architecture
+//!
+//! ```text
+//! [e1, e2, e3, ...]  ──build_timeline──>  ResourceTimeline
+//!                                           ├─ bucket (2026-01-19)
+//!                                           │    ├─ counts {StatusChanged: 2}
+//!                                           │    └─ markers [active → maintenance]
+//!                                           └─ bucket (2026-01-20) ...
+//! ```
+//!
+//! The projection is a pure function of the event history, so UIs get the
+//! same shape whether the timeline is computed on demand or cached.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::projection::timeline::{build_timeline, TimelineGranularity};
+//!
+//! let timeline = build_timeline(aggregate_id, &events, TimelineGranularity::Week);
+//! let json = serde_json::to_string(&timeline)?;
+//! ```
+
+use chrono::{DateTime, Datelike, Duration, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+use crate::events::{ComputeResourceEvent, InfrastructureEvent, ResourceStatus};
+
+/// Size of the buckets events are grouped into
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum TimelineGranularity {
+    /// One bucket per UTC calendar day
+    Day,
+
+    /// One bucket per ISO week (starting Monday 00:00 UTC)
+    Week,
+}
+
+impl TimelineGranularity {
+    /// Start of the bucket containing `timestamp`
+    pub fn bucket_start(&self, timestamp: DateTime<Utc>) -> DateTime<Utc> {
+        let midnight = timestamp
+            .date_naive()
+            .and_time(NaiveTime::MIN)
+            .and_utc();
+
+        match self {
+            TimelineGranularity::Day => midnight,
+            TimelineGranularity::Week => {
+                let days_from_monday = timestamp.weekday().num_days_from_monday() as i64;
+                midnight - Duration::days(days_from_monday)
+            }
+        }
+    }
+
+    /// Length of a single bucket
+    pub fn bucket_length(&self) -> Duration {
+        match self {
+            TimelineGranularity::Day => Duration::days(1),
+            TimelineGranularity::Week => Duration::weeks(1),
+        }
+    }
+}
+
+/// Kind of notable transition shown as a marker on the timeline
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TransitionKind {
+    /// Resource entered the system
+    Registered,
+
+    /// Lifecycle status changed
+    StatusChanged {
+        /// Previous status
+        from: ResourceStatus,
+        /// New status
+        to: ResourceStatus,
+    },
+
+    /// Owning organization was (re)assigned
+    OrganizationChanged,
+
+    /// Physical location was (re)assigned
+    LocationChanged,
+
+    /// Responsible person was (re)assigned
+    OwnerChanged,
+}
+
+/// A notable transition rendered as a point marker
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct TimelineMarker {
+    /// Event that produced the marker
+    pub event_id: Uuid,
+
+    /// When the transition happened
+    pub timestamp: DateTime<Utc>,
+
+    /// Event type name (e.g. "StatusChanged")
+    pub event_type: String,
+
+    /// Short human-readable label
+    pub label: String,
+
+    /// Structured transition details
+    pub transition: TransitionKind,
+}
+
+/// Events that fall within one day or week
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct TimelineBucket {
+    /// Inclusive bucket start
+    pub start: DateTime<Utc>,
+
+    /// Exclusive bucket end
+    pub end: DateTime<Utc>,
+
+    /// Number of events in the bucket
+    pub total_events: usize,
+
+    /// Event counts keyed by event type name
+    pub event_type_counts: BTreeMap<String, usize>,
+
+    /// Notable transitions in chronological order
+    pub markers: Vec<TimelineMarker>,
+}
+
+/// Timeline for a single resource, ready for front-end timeline components
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ResourceTimeline {
+    /// Resource aggregate ID
+    pub aggregate_id: Uuid,
+
+    /// Bucket size used
+    pub granularity: TimelineGranularity,
+
+    /// Timestamp of the earliest event
+    pub first_event_at: Option<DateTime<Utc>>,
+
+    /// Timestamp of the latest event
+    pub last_event_at: Option<DateTime<Utc>>,
+
+    /// Total number of events across all buckets
+    pub total_events: usize,
+
+    /// Non-empty buckets in chronological order
+    pub buckets: Vec<TimelineBucket>,
+}
+
+/// Build a timeline from a resource's event history
+///
+/// Events belonging to other aggregates are ignored. Only buckets that
+/// contain at least one event are emitted; gaps are left to the renderer.
+pub fn build_timeline(
+    aggregate_id: Uuid,
+    events: &[InfrastructureEvent],
+    granularity: TimelineGranularity,
+) -> ResourceTimeline {
+    let mut relevant: Vec<&InfrastructureEvent> = events
+        .iter()
+        .filter(|event| event.aggregate_id() == aggregate_id)
+        .collect();
+    relevant.sort_by_key(|event| event.timestamp());
+
+    let mut buckets: BTreeMap<DateTime<Utc>, TimelineBucket> = BTreeMap::new();
+
+    for event in &relevant {
+        let start = granularity.bucket_start(event.timestamp());
+        let bucket = buckets.entry(start).or_insert_with(|| TimelineBucket {
+            start,
+            end: start + granularity.bucket_length(),
+            total_events: 0,
+            event_type_counts: BTreeMap::new(),
+            markers: Vec::new(),
+        });
+
+        bucket.total_events += 1;
+        *bucket
+            .event_type_counts
+            .entry(event.event_type_name().to_string())
+            .or_insert(0) += 1;
+
+        if let Some(marker) = notable_transition(event) {
+            bucket.markers.push(marker);
+        }
+    }
+
+    ResourceTimeline {
+        aggregate_id,
+        granularity,
+        first_event_at: relevant.first().map(|event| event.timestamp()),
+        last_event_at: relevant.last().map(|event| event.timestamp()),
+        total_events: relevant.len(),
+        buckets: buckets.into_values().collect(),
+    }
+}
+
+/// Extract a timeline marker if the event is a notable transition
+fn notable_transition(event: &InfrastructureEvent) -> Option<TimelineMarker> {
+    let InfrastructureEvent::ComputeResource(compute) = event else {
+        return None;
+    };
+
+    let (event_id, label, transition) = match compute {
+        ComputeResourceEvent::ResourceRegistered(e) => (
+            e.event_id,
+            format!("Registered as {}", e.hostname.as_str()),
+            TransitionKind::Registered,
+        ),
+        ComputeResourceEvent::StatusChanged(e) if e.from_status != e.to_status => (
+            e.event_id,
+            format!(
+                "Status changed from {} to {}",
+                status_label(e.from_status),
+                status_label(e.to_status)
+            ),
+            TransitionKind::StatusChanged {
+                from: e.from_status,
+                to: e.to_status,
+            },
+        ),
+        ComputeResourceEvent::OrganizationAssigned(e) => (
+            e.event_id,
+            "Organization assigned".to_string(),
+            TransitionKind::OrganizationChanged,
+        ),
+        ComputeResourceEvent::LocationAssigned(e) => (
+            e.event_id,
+            "Moved to new location".to_string(),
+            TransitionKind::LocationChanged,
+        ),
+        ComputeResourceEvent::OwnerAssigned(e) => (
+            e.event_id,
+            "Owner assigned".to_string(),
+            TransitionKind::OwnerChanged,
+        ),
+        _ => return None,
+    };
+
+    Some(TimelineMarker {
+        event_id,
+        timestamp: event.timestamp(),
+        event_type: event.event_type_name().to_string(),
+        label,
+        transition,
+    })
+}
+
+fn status_label(status: ResourceStatus) -> &'static str {
+    match status {
+        ResourceStatus::Provisioning => "provisioning",
+        ResourceStatus::Active => "active",
+        ResourceStatus::Maintenance => "maintenance",
+        ResourceStatus::Decommissioned => "decommissioned",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Hostname, ResourceType};
+    use crate::events::compute_resource::{MetadataUpdated, ResourceRegistered, StatusChanged};
+
+    fn test_timestamp() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-19T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn test_aggregate_id() -> Uuid {
+        Uuid::parse_str("01934f4a-1000-7000-8000-000000001000").unwrap()
+    }
+
+    fn registered(at: DateTime<Utc>) -> InfrastructureEvent {
+        InfrastructureEvent::ComputeResource(ComputeResourceEvent::ResourceRegistered(
+            ResourceRegistered {
+                event_version: 1,
+                event_id: Uuid::now_v7(),
+                aggregate_id: test_aggregate_id(),
+                timestamp: at,
+                correlation_id: Uuid::now_v7(),
+                causation_id: None,
+                hostname: Hostname::new("web01.example.com").unwrap(),
+                resource_type: ResourceType::PhysicalServer,
+            },
+        ))
+    }
+
+    fn metadata(at: DateTime<Utc>) -> InfrastructureEvent {
+        InfrastructureEvent::ComputeResource(ComputeResourceEvent::MetadataUpdated(
+            MetadataUpdated {
+                event_version: 1,
+                event_id: Uuid::now_v7(),
+                aggregate_id: test_aggregate_id(),
+                timestamp: at,
+                correlation_id: Uuid::now_v7(),
+                causation_id: None,
+                key: "rack".to_string(),
+                value: "R12".to_string(),
+            },
+        ))
+    }
+
+    fn status(at: DateTime<Utc>, from: ResourceStatus, to: ResourceStatus) -> InfrastructureEvent {
+        InfrastructureEvent::ComputeResource(ComputeResourceEvent::StatusChanged(StatusChanged {
+            event_version: 1,
+            event_id: Uuid::now_v7(),
+            aggregate_id: test_aggregate_id(),
+            timestamp: at,
+            correlation_id: Uuid::now_v7(),
+            causation_id: None,
+            from_status: from,
+            to_status: to,
+        }))
+    }
+
+    #[test]
+    fn test_week_bucket_starts_on_monday() {
+        // 2026-01-22 is a Thursday; its ISO week starts Monday 2026-01-19
+        let thursday = test_timestamp() + Duration::days(3);
+        let start = TimelineGranularity::Week.bucket_start(thursday);
+
+        assert_eq!(start.to_rfc3339(), "2026-01-19T00:00:00+00:00");
+    }
+
+    #[test]
+    fn test_daily_buckets_with_counts() {
+        // Arrange
+        let t0 = test_timestamp();
+        let events = vec![
+            registered(t0),
+            metadata(t0 + Duration::hours(1)),
+            metadata(t0 + Duration::days(1)),
+        ];
+
+        // Act
+        let timeline = build_timeline(test_aggregate_id(), &events, TimelineGranularity::Day);
+
+        // Assert
+        assert_eq!(timeline.total_events, 3);
+        assert_eq!(timeline.buckets.len(), 2);
+        assert_eq!(timeline.buckets[0].event_type_counts["ResourceRegistered"], 1);
+        assert_eq!(timeline.buckets[0].event_type_counts["MetadataUpdated"], 1);
+        assert_eq!(timeline.buckets[1].total_events, 1);
+        assert_eq!(timeline.first_event_at, Some(t0));
+    }
+
+    #[test]
+    fn test_notable_transitions_become_markers() {
+        // Arrange
+        let t0 = test_timestamp();
+        let events = vec![
+            registered(t0),
+            status(t0 + Duration::hours(1), ResourceStatus::Provisioning, ResourceStatus::Active),
+            status(t0 + Duration::hours(2), ResourceStatus::Active, ResourceStatus::Active),
+            metadata(t0 + Duration::hours(3)),
+        ];
+
+        // Act
+        let timeline = build_timeline(test_aggregate_id(), &events, TimelineGranularity::Week);
+
+        // Assert - no-op status change and metadata are not markers
+        let markers = &timeline.buckets[0].markers;
+        assert_eq!(markers.len(), 2);
+        assert_eq!(markers[0].transition, TransitionKind::Registered);
+        assert_eq!(markers[1].label, "Status changed from provisioning to active");
+    }
+
+    #[test]
+    fn test_ignores_other_aggregates() {
+        let mut other = registered(test_timestamp());
+        let InfrastructureEvent::ComputeResource(ComputeResourceEvent::ResourceRegistered(e)) =
+            &mut other
+        else {
+            unreachable!()
+        };
+        e.aggregate_id = Uuid::now_v7();
+
+        let timeline = build_timeline(test_aggregate_id(), &[other], TimelineGranularity::Day);
+
+        assert_eq!(timeline.total_events, 0);
+        assert!(timeline.buckets.is_empty());
+    }
+}
@@ -0,0 +1,187 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Python Bindings
+//!
+//! PyO3 module exposing the pure domain core to Python: value-object
+//! validation, command parsing and handling, event deserialization and
+//! state folding. Commands, events and state cross the boundary as plain
+//! dicts in the same JSON shape used on NATS, so scripts never maintain a
+//! copy of the schemas.
+//!
+//! # Building
+//!
+//! ```text
+//! maturin develop --features python
+//! ```
+//!
+//! # Example
+//!
+//! ```text
+//! import cim_infrastructure as cim
+//!
+//! command = cim.parse_command({"command": "assign_asset_tag", "asset_tag": "A-1001", ...})
+//! event = cim.handle_command(state, command, aggregate_id)   # raises CommandRejected
+//! state = cim.fold_events([registered, event])
+//! ```
+
+use pyo3::create_exception;
+use pyo3::exceptions::{PyException, PyValueError};
+use pyo3::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::aggregate::compute_resource::apply_infrastructure_event;
+use crate::aggregate::{handle_command as handle, ComputeResourceCommand, ComputeResourceState};
+use crate::domain::{Hostname, IpAddressWithCidr, MacAddress, VlanId};
+use crate::events::InfrastructureEvent;
+
+create_exception!(
+    cim_infrastructure,
+    ValidationError,
+    PyValueError,
+    "Value, command or event does not satisfy the domain schema"
+);
+create_exception!(
+    cim_infrastructure,
+    CommandRejected,
+    PyException,
+    "Command is invalid for the current aggregate state"
+);
+
+/// Convert a Python object (dict/list/str/...) into a domain type
+fn from_py<T: DeserializeOwned>(value: &Bound<'_, PyAny>) -> PyResult<T> {
+    let json = value.py().import_bound("json")?;
+    let text: String = json.call_method1("dumps", (value,))?.extract()?;
+    serde_json::from_str(&text).map_err(|e| ValidationError::new_err(e.to_string()))
+}
+
+/// Convert a domain type into Python dicts/lists
+fn to_py<T: Serialize>(py: Python<'_>, value: &T) -> PyResult<PyObject> {
+    let text = serde_json::to_string(value).map_err(|e| ValidationError::new_err(e.to_string()))?;
+    let json = py.import_bound("json")?;
+    Ok(json.call_method1("loads", (text,))?.unbind())
+}
+
+fn parse_uuid(value: &str) -> PyResult<Uuid> {
+    Uuid::parse_str(value).map_err(|e| ValidationError::new_err(e.to_string()))
+}
+
+/// Validate a hostname, returning its normalized form
+#[pyfunction]
+fn validate_hostname(hostname: &str) -> PyResult<String> {
+    Hostname::new(hostname)
+        .map(|h| h.to_string())
+        .map_err(|e| ValidationError::new_err(e.to_string()))
+}
+
+/// Validate an IP address with CIDR prefix (`10.0.0.5/24`)
+#[pyfunction]
+fn validate_ip_cidr(value: &str) -> PyResult<String> {
+    IpAddressWithCidr::new(value)
+        .map(|ip| ip.to_string())
+        .map_err(|e| ValidationError::new_err(e.to_string()))
+}
+
+/// Validate a MAC address, returning its normalized form
+#[pyfunction]
+fn validate_mac_address(value: &str) -> PyResult<String> {
+    MacAddress::new(value)
+        .map(|mac| mac.to_string())
+        .map_err(|e| ValidationError::new_err(e.to_string()))
+}
+
+/// Validate an 802.1Q VLAN ID
+#[pyfunction]
+fn validate_vlan_id(value: u16) -> PyResult<u16> {
+    VlanId::new(value)
+        .map(|_| value)
+        .map_err(|e| ValidationError::new_err(e.to_string()))
+}
+
+/// Parse a command dict (`{"command": "...", ...}`), returning it normalized
+#[pyfunction]
+fn parse_command(py: Python<'_>, command: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+    let command: ComputeResourceCommand = from_py(command)?;
+    to_py(py, &command)
+}
+
+/// Handle a command against a state, returning the resulting event
+///
+/// `state` is `None` for a resource that does not exist yet.
+#[pyfunction]
+#[pyo3(signature = (state, command, aggregate_id))]
+fn handle_command(
+    py: Python<'_>,
+    state: Option<&Bound<'_, PyAny>>,
+    command: &Bound<'_, PyAny>,
+    aggregate_id: &str,
+) -> PyResult<PyObject> {
+    let aggregate_id = parse_uuid(aggregate_id)?;
+    let state = match state {
+        Some(state) => from_py(state)?,
+        None => ComputeResourceState::default_for(aggregate_id),
+    };
+    let command: ComputeResourceCommand = from_py(command)?;
+
+    let event = handle(&state, command, aggregate_id)
+        .map_err(|e| CommandRejected::new_err(e.to_string()))?;
+    to_py(py, &InfrastructureEvent::ComputeResource(event))
+}
+
+/// Deserialize an event payload as published on NATS
+#[pyfunction]
+fn deserialize_event(py: Python<'_>, payload: &[u8]) -> PyResult<PyObject> {
+    let event: InfrastructureEvent =
+        serde_json::from_slice(payload).map_err(|e| ValidationError::new_err(e.to_string()))?;
+    to_py(py, &event)
+}
+
+/// Apply one event to a state
+#[pyfunction]
+fn apply_event(
+    py: Python<'_>,
+    state: &Bound<'_, PyAny>,
+    event: &Bound<'_, PyAny>,
+) -> PyResult<PyObject> {
+    let state: ComputeResourceState = from_py(state)?;
+    let event: InfrastructureEvent = from_py(event)?;
+    to_py(py, &apply_infrastructure_event(state, &event))
+}
+
+/// Fold a sequence of events into the resource state
+#[pyfunction]
+fn fold_events(py: Python<'_>, events: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+    let events: Vec<InfrastructureEvent> = from_py(events)?;
+    let Some(first) = events.first() else {
+        return Err(ValidationError::new_err("no events to fold"));
+    };
+
+    let initial = ComputeResourceState::default_for(first.aggregate_id());
+    let state = events.iter().fold(initial, apply_infrastructure_event);
+    to_py(py, &state)
+}
+
+/// The `cim_infrastructure` Python module
+#[pymodule]
+fn cim_infrastructure(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add(
+        "ValidationError",
+        m.py().get_type_bound::<ValidationError>(),
+    )?;
+    m.add(
+        "CommandRejected",
+        m.py().get_type_bound::<CommandRejected>(),
+    )?;
+    m.add("__version__", crate::VERSION)?;
+
+    m.add_function(wrap_pyfunction!(validate_hostname, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_ip_cidr, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_mac_address, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_vlan_id, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_command, m)?)?;
+    m.add_function(wrap_pyfunction!(handle_command, m)?)?;
+    m.add_function(wrap_pyfunction!(deserialize_event, m)?)?;
+    m.add_function(wrap_pyfunction!(apply_event, m)?)?;
+    m.add_function(wrap_pyfunction!(fold_events, m)?)?;
+    Ok(())
+}
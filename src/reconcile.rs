@@ -0,0 +1,757 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Drift Detection
+//!
+//! Compares what agents and scans *observe* with the event-sourced desired
+//! state and reports every difference as [`Drift`]: declared hosts that
+//! were not seen, hosts nobody declared, interfaces whose addresses changed
+//! and interfaces that exist on only one side.
+//!
+//! ```text
+//! agent / scan ──Observation──> infrastructure.observation.<source>
+//!                                        │
+//!       DesiredStateSource ──> DriftMonitor ──detect_drift()──> Vec<Drift>
+//!   (resources + interfaces)             │
+//!                                        ├──publish──> infrastructure.advisory.drift_detected
+//!                                        └──> DriftHandle (current drift, e.g. for scorecards)
+//! ```
+//!
+//! Detection is pure ([`detect_drift`]); [`DriftMonitor`] loads the desired
+//! state, runs it for each observation and publishes a
+//! [`DriftDetected`] advisory for each newly found difference. Drift is
+//! reported, never corrected: whether the observation or the declaration
+//! is wrong is for an operator to decide.
+//!
+//! # Partial Observations
+//!
+//! Most scans see part of the estate. Declared hosts are reported missing
+//! only when the observation is [exhaustive](Observation::exhaustive), and
+//! interfaces are compared only for hosts whose observation lists
+//! interfaces (a ping sweep proves a host exists, not what it is wired to).
+//! Only `active` resources are expected to be seen.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::reconcile::{detect_drift, DesiredState, Observation, ObservedHost};
+//!
+//! let observation = Observation::new("lldp-agent", observed_at)
+//!     .with_host(ObservedHost::new(Hostname::new("web-01")?).with_interface(eth0));
+//!
+//! for drift in detect_drift(&DesiredState::new(resources, interfaces), &observation) {
+//!     println!("{}", drift);
+//! }
+//! ```
+
+#[cfg(feature = "runtime")]
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+#[cfg(feature = "runtime")]
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "runtime")]
+use std::collections::{BTreeMap, BTreeSet};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+#[cfg(feature = "runtime")]
+use std::sync::{Arc, Mutex};
+#[cfg(feature = "runtime")]
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::aggregate::network_interface::NetworkInterfaceState;
+use crate::aggregate::ComputeResourceState;
+use crate::domain::{Hostname, IpAddressWithCidr, MacAddress};
+#[cfg(feature = "runtime")]
+use crate::errors::InfrastructureResult;
+#[cfg(feature = "clock")]
+use crate::events::advisory::{AdvisoryEvent, DriftDetected};
+use crate::events::ResourceStatus;
+#[cfg(feature = "runtime")]
+use crate::nats::NatsClient;
+#[cfg(feature = "runtime")]
+use crate::subjects::subjects;
+
+/// Facts reported by one agent or scan
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct Observation {
+    /// Name of the reporting agent or scan (e.g. `lldp-agent`)
+    pub source: String,
+
+    /// When the facts were collected
+    pub observed_at: DateTime<Utc>,
+
+    /// Whether every host in scope was observed
+    ///
+    /// Only exhaustive observations report declared hosts as missing.
+    #[serde(default)]
+    pub exhaustive: bool,
+
+    /// Hosts seen
+    pub hosts: Vec<ObservedHost>,
+}
+
+impl Observation {
+    /// Start an empty, non-exhaustive observation
+    pub fn new(source: impl Into<String>, observed_at: DateTime<Utc>) -> Self {
+        Self {
+            source: source.into(),
+            observed_at,
+            exhaustive: false,
+            hosts: Vec::new(),
+        }
+    }
+
+    /// Add a host
+    pub fn with_host(mut self, host: ObservedHost) -> Self {
+        self.hosts.push(host);
+        self
+    }
+
+    /// Mark the observation as covering every host
+    pub fn exhaustive(mut self) -> Self {
+        self.exhaustive = true;
+        self
+    }
+}
+
+/// A host as observed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ObservedHost {
+    /// Hostname the host reports or resolves to
+    pub hostname: Hostname,
+
+    /// Interfaces seen; empty when the source does not report interfaces
+    #[serde(default)]
+    pub interfaces: Vec<ObservedInterface>,
+}
+
+impl ObservedHost {
+    /// Host without interface details
+    pub fn new(hostname: Hostname) -> Self {
+        Self {
+            hostname,
+            interfaces: Vec::new(),
+        }
+    }
+
+    /// Add an interface
+    pub fn with_interface(mut self, interface: ObservedInterface) -> Self {
+        self.interfaces.push(interface);
+        self
+    }
+}
+
+/// An interface as observed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ObservedInterface {
+    /// Interface name on the host (e.g. `eth0`)
+    pub name: String,
+
+    /// Hardware address, if reported
+    #[serde(default)]
+    pub mac_address: Option<MacAddress>,
+
+    /// Configured addresses
+    #[serde(default)]
+    pub addresses: Vec<IpAddressWithCidr>,
+}
+
+impl ObservedInterface {
+    /// Interface with addresses
+    pub fn new(name: impl Into<String>, addresses: Vec<IpAddressWithCidr>) -> Self {
+        Self {
+            name: name.into(),
+            mac_address: None,
+            addresses,
+        }
+    }
+
+    /// Set the hardware address
+    pub fn with_mac_address(mut self, mac_address: MacAddress) -> Self {
+        self.mac_address = Some(mac_address);
+        self
+    }
+}
+
+/// One difference between declared and observed infrastructure
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Drift {
+    /// Active resource was not observed by an exhaustive observation
+    MissingHost {
+        /// Declared resource
+        resource_id: Uuid,
+        /// Declared hostname
+        hostname: String,
+    },
+
+    /// Observed host matches no declared resource
+    UnknownHost {
+        /// Observed hostname
+        hostname: String,
+        /// Addresses seen on the host
+        addresses: Vec<IpAddressWithCidr>,
+    },
+
+    /// Interface addresses differ from the declared ones
+    AddressesChanged {
+        /// Declared resource
+        resource_id: Uuid,
+        /// Declared hostname
+        hostname: String,
+        /// Declared interface
+        interface_id: Uuid,
+        /// Interface name as observed
+        interface: String,
+        /// Declared addresses
+        declared: Vec<IpAddressWithCidr>,
+        /// Observed addresses
+        observed: Vec<IpAddressWithCidr>,
+    },
+
+    /// Observed interface matches no declared interface of the resource
+    UnknownInterface {
+        /// Declared resource
+        resource_id: Uuid,
+        /// Declared hostname
+        hostname: String,
+        /// Observed interface name
+        interface: String,
+        /// Observed hardware address
+        mac_address: Option<MacAddress>,
+        /// Observed addresses
+        addresses: Vec<IpAddressWithCidr>,
+    },
+
+    /// Declared interface was not observed on its resource
+    MissingInterface {
+        /// Declared resource
+        resource_id: Uuid,
+        /// Declared hostname
+        hostname: String,
+        /// Declared interface
+        interface_id: Uuid,
+        /// Declared interface name
+        interface: String,
+    },
+}
+
+impl Drift {
+    /// Kind token (`missing_host`, `addresses_changed`, ...)
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Drift::MissingHost { .. } => "missing_host",
+            Drift::UnknownHost { .. } => "unknown_host",
+            Drift::AddressesChanged { .. } => "addresses_changed",
+            Drift::UnknownInterface { .. } => "unknown_interface",
+            Drift::MissingInterface { .. } => "missing_interface",
+        }
+    }
+
+    /// Declared resource involved; None for unknown hosts
+    pub fn resource_id(&self) -> Option<Uuid> {
+        match self {
+            Drift::MissingHost { resource_id, .. }
+            | Drift::AddressesChanged { resource_id, .. }
+            | Drift::UnknownInterface { resource_id, .. }
+            | Drift::MissingInterface { resource_id, .. } => Some(*resource_id),
+            Drift::UnknownHost { .. } => None,
+        }
+    }
+
+    /// Hostname involved
+    pub fn hostname(&self) -> &str {
+        match self {
+            Drift::MissingHost { hostname, .. }
+            | Drift::UnknownHost { hostname, .. }
+            | Drift::AddressesChanged { hostname, .. }
+            | Drift::UnknownInterface { hostname, .. }
+            | Drift::MissingInterface { hostname, .. } => hostname,
+        }
+    }
+
+    /// Build the advisory event for this drift
+    #[cfg(feature = "clock")]
+    pub fn to_advisory(&self, source: &str, correlation_id: Uuid) -> AdvisoryEvent {
+        AdvisoryEvent::DriftDetected(DriftDetected {
+            event_id: Uuid::now_v7(),
+            detected_at: Utc::now(),
+            correlation_id,
+            source: source.to_string(),
+            drift: self.clone(),
+        })
+    }
+}
+
+impl fmt::Display for Drift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let list = |addresses: &[IpAddressWithCidr]| {
+            addresses
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        match self {
+            Drift::MissingHost { hostname, .. } => {
+                write!(f, "{} is declared active but was not observed", hostname)
+            }
+            Drift::UnknownHost {
+                hostname,
+                addresses,
+            } => write!(f, "{} is not declared [{}]", hostname, list(addresses)),
+            Drift::AddressesChanged {
+                hostname,
+                interface,
+                declared,
+                observed,
+                ..
+            } => write!(
+                f,
+                "{} {} has [{}], declared [{}]",
+                hostname,
+                interface,
+                list(observed),
+                list(declared)
+            ),
+            Drift::UnknownInterface {
+                hostname,
+                interface,
+                addresses,
+                ..
+            } => write!(
+                f,
+                "{} {} is not declared [{}]",
+                hostname,
+                interface,
+                list(addresses)
+            ),
+            Drift::MissingInterface {
+                hostname,
+                interface,
+                ..
+            } => write!(
+                f,
+                "{} {} is declared but was not observed",
+                hostname, interface
+            ),
+        }
+    }
+}
+
+/// Declared infrastructure to reconcile against
+#[derive(Debug, Clone, Default)]
+pub struct DesiredState {
+    resources: Vec<ComputeResourceState>,
+    interfaces: Vec<NetworkInterfaceState>,
+}
+
+impl DesiredState {
+    /// Desired state from resource and interface read models
+    ///
+    /// Uninitialized states are ignored.
+    pub fn new(
+        resources: impl IntoIterator<Item = ComputeResourceState>,
+        interfaces: impl IntoIterator<Item = NetworkInterfaceState>,
+    ) -> Self {
+        Self {
+            resources: resources
+                .into_iter()
+                .filter(ComputeResourceState::is_initialized)
+                .collect(),
+            interfaces: interfaces
+                .into_iter()
+                .filter(NetworkInterfaceState::is_initialized)
+                .collect(),
+        }
+    }
+
+    /// Declared resources
+    pub fn resources(&self) -> &[ComputeResourceState] {
+        &self.resources
+    }
+
+    /// Declared interfaces
+    pub fn interfaces(&self) -> &[NetworkInterfaceState] {
+        &self.interfaces
+    }
+}
+
+/// Addresses as a set, ignoring order and duplicates
+fn address_set(addresses: &[IpAddressWithCidr]) -> HashSet<&IpAddressWithCidr> {
+    addresses.iter().collect()
+}
+
+/// Addresses in a stable order for reporting
+fn sorted(addresses: &[IpAddressWithCidr]) -> Vec<IpAddressWithCidr> {
+    let mut addresses = addresses.to_vec();
+    addresses.sort_by_key(|a| (a.address(), a.prefix_length()));
+    addresses.dedup();
+    addresses
+}
+
+/// Compare an observation with the desired state
+///
+/// Observed interfaces are matched to declared ones by name, then by
+/// hardware address, so a renamed interface (`eth0` → `ens3`) is compared
+/// rather than reported twice. Decommissioned resources are ignored
+/// entirely. Results are ordered by hostname, then kind.
+pub fn detect_drift(desired: &DesiredState, observation: &Observation) -> Vec<Drift> {
+    let mut drift = Vec::new();
+
+    let declared: HashMap<&Hostname, &ComputeResourceState> = desired
+        .resources
+        .iter()
+        .filter(|r| r.status != ResourceStatus::Decommissioned)
+        .map(|r| (&r.hostname, r))
+        .collect();
+    let observed: HashMap<&Hostname, &ObservedHost> =
+        observation.hosts.iter().map(|h| (&h.hostname, h)).collect();
+
+    if observation.exhaustive {
+        for resource in declared.values() {
+            if resource.status == ResourceStatus::Active
+                && !observed.contains_key(&resource.hostname)
+            {
+                drift.push(Drift::MissingHost {
+                    resource_id: resource.id,
+                    hostname: resource.hostname.to_string(),
+                });
+            }
+        }
+    }
+
+    for host in observed.values() {
+        let Some(resource) = declared.get(&host.hostname) else {
+            let addresses: Vec<_> = host
+                .interfaces
+                .iter()
+                .flat_map(|i| i.addresses.iter().cloned())
+                .collect();
+            drift.push(Drift::UnknownHost {
+                hostname: host.hostname.to_string(),
+                addresses: sorted(&addresses),
+            });
+            continue;
+        };
+
+        if host.interfaces.is_empty() {
+            continue;
+        }
+
+        let mut unmatched: Vec<&NetworkInterfaceState> = desired
+            .interfaces
+            .iter()
+            .filter(|i| i.resource_id == Some(resource.id))
+            .collect();
+
+        for interface in &host.interfaces {
+            let position = unmatched
+                .iter()
+                .position(|d| d.name == interface.name)
+                .or_else(|| {
+                    interface.mac_address.as_ref().and_then(|mac| {
+                        unmatched
+                            .iter()
+                            .position(|d| d.mac_address.as_ref() == Some(mac))
+                    })
+                });
+
+            let Some(position) = position else {
+                drift.push(Drift::UnknownInterface {
+                    resource_id: resource.id,
+                    hostname: resource.hostname.to_string(),
+                    interface: interface.name.clone(),
+                    mac_address: interface.mac_address.clone(),
+                    addresses: sorted(&interface.addresses),
+                });
+                continue;
+            };
+
+            let declared_interface = unmatched.swap_remove(position);
+            if address_set(&declared_interface.addresses) != address_set(&interface.addresses) {
+                drift.push(Drift::AddressesChanged {
+                    resource_id: resource.id,
+                    hostname: resource.hostname.to_string(),
+                    interface_id: declared_interface.id,
+                    interface: interface.name.clone(),
+                    declared: sorted(&declared_interface.addresses),
+                    observed: sorted(&interface.addresses),
+                });
+            }
+        }
+
+        for missing in unmatched {
+            drift.push(Drift::MissingInterface {
+                resource_id: resource.id,
+                hostname: resource.hostname.to_string(),
+                interface_id: missing.id,
+                interface: missing.name.clone(),
+            });
+        }
+    }
+
+    drift.sort_by(|a, b| {
+        (a.hostname(), a.kind(), a.to_string()).cmp(&(b.hostname(), b.kind(), b.to_string()))
+    });
+    drift
+}
+
+/// Supplies the desired state to the monitor
+#[cfg(feature = "runtime")]
+#[async_trait]
+pub trait DesiredStateSource: Send + Sync {
+    /// Load the current desired state
+    async fn desired_state(&self) -> InfrastructureResult<DesiredState>;
+}
+
+/// Reconciles observations and publishes advisories for new drift
+///
+/// Drift is tracked per observation source. Each difference is announced
+/// once; if a later observation from the same source no longer shows it
+/// and it reappears afterwards, it is announced again.
+#[cfg(feature = "runtime")]
+pub struct DriftMonitor<S> {
+    client: NatsClient,
+    source: S,
+    reported: HashMap<String, BTreeSet<String>>,
+    current: DriftHandle,
+}
+
+/// Drift found by the latest observation of each source
+///
+/// Cloning is cheap; all clones see the drift of the same monitor.
+#[cfg(feature = "runtime")]
+#[derive(Clone, Default)]
+pub struct DriftHandle {
+    by_source: Arc<Mutex<HashMap<String, Vec<Drift>>>>,
+}
+
+#[cfg(feature = "runtime")]
+impl DriftHandle {
+    /// Current drift across all sources, without duplicates
+    pub fn current(&self) -> Vec<Drift> {
+        let by_source = self.by_source.lock().expect("drift lock poisoned");
+        let unique: BTreeMap<String, &Drift> = by_source
+            .values()
+            .flatten()
+            .map(|drift| (drift.to_string(), drift))
+            .collect();
+        unique.into_values().cloned().collect()
+    }
+
+    fn replace(&self, source: &str, drift: Vec<Drift>) {
+        self.by_source
+            .lock()
+            .expect("drift lock poisoned")
+            .insert(source.to_string(), drift);
+    }
+}
+
+#[cfg(feature = "runtime")]
+impl<S: DesiredStateSource> DriftMonitor<S> {
+    /// Create a monitor over a desired state source
+    pub fn new(client: NatsClient, source: S) -> Self {
+        Self {
+            client,
+            source,
+            reported: HashMap::new(),
+            current: DriftHandle::default(),
+        }
+    }
+
+    /// Handle onto the drift currently present
+    pub fn handle(&self) -> DriftHandle {
+        self.current.clone()
+    }
+
+    /// Reconcile one observation, publishing advisories for new drift
+    ///
+    /// Returns all drift currently present, new or not.
+    pub async fn reconcile(
+        &mut self,
+        observation: &Observation,
+    ) -> InfrastructureResult<Vec<Drift>> {
+        let desired = self.source.desired_state().await?;
+        let drift = detect_drift(&desired, observation);
+        let correlation_id = Uuid::now_v7();
+
+        let current: BTreeSet<String> = drift.iter().map(ToString::to_string).collect();
+        let reported = self.reported.entry(observation.source.clone()).or_default();
+        reported.retain(|key| current.contains(key));
+
+        for found in &drift {
+            let key = found.to_string();
+            if reported.contains(&key) {
+                continue;
+            }
+
+            warn!("Drift detected by {}: {}", observation.source, found);
+            let advisory = found.to_advisory(&observation.source, correlation_id);
+            self.client.publish(&advisory.subject(), &advisory).await?;
+            reported.insert(key);
+        }
+
+        self.current.replace(&observation.source, drift.clone());
+        Ok(drift)
+    }
+
+    /// Reconcile observations published on `infrastructure.observation.>`
+    ///
+    /// Malformed observations are logged and skipped. Returns when the
+    /// subscription closes or publishing fails.
+    pub async fn run(&mut self) -> InfrastructureResult<()> {
+        let subject = subjects::all_observations();
+        info!("Starting drift monitor on {}", subject);
+        let mut subscriber = self.client.subscribe(&subject).await?;
+
+        while let Some(message) = subscriber.next().await {
+            match serde_json::from_slice::<Observation>(&message.payload) {
+                Ok(observation) => {
+                    self.reconcile(&observation).await?;
+                }
+                Err(e) => warn!(
+                    "Ignoring malformed observation on {}: {}",
+                    message.subject, e
+                ),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::ResourceType;
+
+    fn test_timestamp() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-19T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn resource(hostname: &str, status: ResourceStatus) -> ComputeResourceState {
+        let mut state = ComputeResourceState::default_for(Uuid::now_v7());
+        state.hostname = Hostname::new(hostname).unwrap();
+        state.resource_type = ResourceType::PhysicalServer;
+        state.status = status;
+        state.created_at = Some(test_timestamp());
+        state
+    }
+
+    fn interface(resource: &ComputeResourceState, name: &str, cidr: &str) -> NetworkInterfaceState {
+        let mut state = NetworkInterfaceState::default_for(Uuid::now_v7());
+        state.resource_id = Some(resource.id);
+        state.name = name.to_string();
+        state.addresses = vec![IpAddressWithCidr::new(cidr).unwrap()];
+        state.created_at = Some(test_timestamp());
+        state
+    }
+
+    fn observed(name: &str, cidr: &str) -> ObservedInterface {
+        ObservedInterface::new(name, vec![IpAddressWithCidr::new(cidr).unwrap()])
+    }
+
+    #[test]
+    fn test_detects_changed_unknown_and_missing_interfaces() {
+        // Arrange
+        let web = resource("web-01", ResourceStatus::Active);
+        let desired = DesiredState::new(
+            [web.clone()],
+            [
+                interface(&web, "eth0", "10.0.0.5/24"),
+                interface(&web, "eth1", "10.1.0.5/24"),
+            ],
+        );
+        let observation = Observation::new("agent", test_timestamp()).with_host(
+            ObservedHost::new(web.hostname.clone())
+                .with_interface(observed("eth0", "10.0.0.99/24"))
+                .with_interface(observed("docker0", "172.17.0.1/16")),
+        );
+
+        // Act
+        let drift = detect_drift(&desired, &observation);
+
+        // Assert
+        let kinds: Vec<_> = drift.iter().map(Drift::kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                "addresses_changed",
+                "missing_interface",
+                "unknown_interface"
+            ]
+        );
+        assert!(drift.iter().all(|d| d.resource_id() == Some(web.id)));
+        assert_eq!(
+            drift[0].to_string(),
+            "web-01 eth0 has [10.0.0.99/24], declared [10.0.0.5/24]"
+        );
+    }
+
+    #[test]
+    fn test_missing_hosts_need_an_exhaustive_observation() {
+        // Arrange
+        let desired = DesiredState::new(
+            [
+                resource("web-01", ResourceStatus::Active),
+                resource("db-01", ResourceStatus::Active),
+                resource("old-01", ResourceStatus::Decommissioned),
+                resource("new-01", ResourceStatus::Provisioning),
+            ],
+            [],
+        );
+        let partial = Observation::new("ping-sweep", test_timestamp())
+            .with_host(ObservedHost::new(Hostname::new("web-01").unwrap()))
+            .with_host(ObservedHost::new(Hostname::new("rogue-01").unwrap()));
+
+        // Act
+        let from_partial = detect_drift(&desired, &partial);
+        let from_exhaustive = detect_drift(&desired, &partial.clone().exhaustive());
+
+        // Assert
+        assert_eq!(
+            from_partial,
+            [Drift::UnknownHost {
+                hostname: "rogue-01".to_string(),
+                addresses: vec![],
+            }]
+        );
+        let kinds: Vec<_> = from_exhaustive
+            .iter()
+            .map(|d| (d.hostname(), d.kind()))
+            .collect();
+        assert_eq!(
+            kinds,
+            [("db-01", "missing_host"), ("rogue-01", "unknown_host")]
+        );
+    }
+
+    #[test]
+    fn test_renamed_interface_is_matched_by_mac_address() {
+        // Arrange
+        let web = resource("web-01", ResourceStatus::Active);
+        let mac = MacAddress::new("00:11:22:33:44:55").unwrap();
+        let mut eth0 = interface(&web, "eth0", "10.0.0.5/24");
+        eth0.mac_address = Some(mac.clone());
+        let desired = DesiredState::new([web.clone()], [eth0]);
+        let observation = Observation::new("agent", test_timestamp()).with_host(
+            ObservedHost::new(web.hostname.clone())
+                .with_interface(observed("ens3", "10.0.0.5/24").with_mac_address(mac)),
+        );
+
+        // Act / Assert
+        assert!(detect_drift(&desired, &observation).is_empty());
+    }
+}
@@ -0,0 +1,405 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Host Status Rollups
+//!
+//! A host's own lifecycle status says nothing about the virtual machines
+//! and containers running on it. A [`HostRollup`] combines both: the host
+//! is only [`RollupStatus::Healthy`] if it and every guest are in service,
+//! so operators see which physical boxes need attention because of what
+//! runs on them.
+//!
+//! ```text
+//! guest (metadata host_id=<host>) ──┐
+//! guest                           ──┼──> StatusRollups ──> HostRollup{status, degraded_guests, failed_guests}
+//! host                            ──┘          │
+//!                                              └──changed──> infrastructure.advisory.host_rollup_changed
+//! ```
+//!
+//! # Status
+//!
+//! | Resource status              | Condition  |
+//! |------------------------------|------------|
+//! | `provisioning`, `active`     | healthy    |
+//! | `maintenance`                | degraded   |
+//! | `decommissioned`             | failed     |
+//!
+//! The rollup is the host's own condition, raised to `degraded` when any
+//! guest is degraded or failed. Only the host itself can make its rollup
+//! `failed`; a decommissioned guest still placed on a host counts as a
+//! failed guest.
+//!
+//! Guests name their host by aggregate ID in the `host_id` metadata key
+//! (configurable with [`RollupConfig`]) and leave it by setting the key to
+//! an empty value, since metadata keys are never removed. Resources without
+//! guests have no rollup.
+//!
+//! The in-memory read model keeps rollups current as events arrive; see
+//! [`InMemoryReadModel::with_rollup_advisories`](crate::projection::read_model::InMemoryReadModel::with_rollup_advisories).
+
+#[cfg(feature = "clock")]
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use uuid::Uuid;
+
+use crate::aggregate::ComputeResourceState;
+#[cfg(feature = "clock")]
+use crate::events::advisory::{AdvisoryEvent, HostRollupChanged};
+use crate::events::ResourceStatus;
+
+/// Condition of a resource, or of a host together with its guests
+///
+/// Ordered from best to worst.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum RollupStatus {
+    /// In service
+    Healthy,
+
+    /// Running with reduced service
+    Degraded,
+
+    /// Out of service
+    Failed,
+}
+
+impl RollupStatus {
+    /// Condition of a single resource
+    pub fn of(status: ResourceStatus) -> Self {
+        match status {
+            ResourceStatus::Provisioning | ResourceStatus::Active => RollupStatus::Healthy,
+            ResourceStatus::Maintenance => RollupStatus::Degraded,
+            ResourceStatus::Decommissioned => RollupStatus::Failed,
+        }
+    }
+}
+
+/// Rollup settings
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RollupConfig {
+    /// Metadata key in which a guest names its host's aggregate ID
+    #[serde(default = "default_host_key")]
+    pub host_key: String,
+}
+
+fn default_host_key() -> String {
+    "host_id".to_string()
+}
+
+impl Default for RollupConfig {
+    fn default() -> Self {
+        Self {
+            host_key: default_host_key(),
+        }
+    }
+}
+
+impl RollupConfig {
+    /// Host a resource runs on, if it names a valid one
+    pub fn host_of(&self, state: &ComputeResourceState) -> Option<Uuid> {
+        self.placement(state).flatten()
+    }
+
+    /// Placement recorded in a state: None when the key is absent, Some(None)
+    /// when it does not name another resource
+    fn placement(&self, state: &ComputeResourceState) -> Option<Option<Uuid>> {
+        state
+            .metadata
+            .iter()
+            .find(|(key, _)| key == &self.host_key)
+            .map(|(_, value)| {
+                value
+                    .parse()
+                    .ok()
+                    .filter(|host_id: &Uuid| *host_id != state.id)
+            })
+    }
+}
+
+/// Status of a host combined with its guests
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct HostRollup {
+    /// The host
+    pub host_id: Uuid,
+
+    /// Hostname of the host
+    pub hostname: String,
+
+    /// The host's own lifecycle status
+    pub own_status: ResourceStatus,
+
+    /// Combined condition of the host and its guests
+    pub status: RollupStatus,
+
+    /// Number of guests placed on the host
+    pub guest_count: usize,
+
+    /// Guests in maintenance, in ID order
+    pub degraded_guests: Vec<Uuid>,
+
+    /// Decommissioned guests still placed on the host, in ID order
+    pub failed_guests: Vec<Uuid>,
+}
+
+impl HostRollup {
+    /// Roll up a host and its guests
+    pub fn compute<'a>(
+        host: &ComputeResourceState,
+        guests: impl IntoIterator<Item = &'a ComputeResourceState>,
+    ) -> Self {
+        let mut guest_count = 0;
+        let mut degraded_guests = Vec::new();
+        let mut failed_guests = Vec::new();
+        for guest in guests {
+            guest_count += 1;
+            match RollupStatus::of(guest.status) {
+                RollupStatus::Healthy => {}
+                RollupStatus::Degraded => degraded_guests.push(guest.id),
+                RollupStatus::Failed => failed_guests.push(guest.id),
+            }
+        }
+        degraded_guests.sort();
+        failed_guests.sort();
+
+        let guests_impaired = !degraded_guests.is_empty() || !failed_guests.is_empty();
+        let mut status = RollupStatus::of(host.status);
+        if guests_impaired {
+            status = status.max(RollupStatus::Degraded);
+        }
+
+        Self {
+            host_id: host.id,
+            hostname: host.hostname.to_string(),
+            own_status: host.status,
+            status,
+            guest_count,
+            degraded_guests,
+            failed_guests,
+        }
+    }
+
+    /// Whether the host or any of its guests needs attention
+    pub fn needs_attention(&self) -> bool {
+        self.status != RollupStatus::Healthy
+    }
+}
+
+/// Change of a host's rollup status
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RollupChange {
+    /// Status before the change; None for a host's first rollup
+    pub previous: Option<RollupStatus>,
+
+    /// Rollup after the change
+    pub rollup: HostRollup,
+}
+
+impl RollupChange {
+    /// Build the advisory event for this change
+    #[cfg(feature = "clock")]
+    pub fn to_advisory(&self, correlation_id: Uuid) -> AdvisoryEvent {
+        AdvisoryEvent::HostRollupChanged(HostRollupChanged {
+            event_id: Uuid::now_v7(),
+            detected_at: Utc::now(),
+            correlation_id,
+            previous: self.previous,
+            rollup: self.rollup.clone(),
+        })
+    }
+}
+
+/// Incrementally maintained rollups of every host with guests
+///
+/// Fed the full state of each resource after it changes; recomputes only
+/// the hosts that resource affects.
+#[derive(Debug, Clone, Default)]
+pub struct StatusRollups {
+    config: RollupConfig,
+    host_of: HashMap<Uuid, Uuid>,
+    guests: HashMap<Uuid, BTreeSet<Uuid>>,
+    rollups: HashMap<Uuid, HostRollup>,
+}
+
+impl StatusRollups {
+    /// Empty rollups
+    pub fn new(config: RollupConfig) -> Self {
+        Self {
+            config,
+            ..Self::default()
+        }
+    }
+
+    /// Current rollup of a host
+    pub fn get(&self, host_id: Uuid) -> Option<&HostRollup> {
+        self.rollups.get(&host_id)
+    }
+
+    /// Every current rollup, in no particular order
+    pub fn rollups(&self) -> impl Iterator<Item = &HostRollup> {
+        self.rollups.values()
+    }
+
+    /// Forget every resource, keeping the configuration
+    pub fn clear(&mut self) {
+        self.host_of.clear();
+        self.guests.clear();
+        self.rollups.clear();
+    }
+
+    /// Record the new state of a resource
+    ///
+    /// A `state` without the host key keeps its placement, so states reduced
+    /// to key fields may be passed for events that did not set it. `lookup`
+    /// resolves the current state of every resource, `state`
+    /// included, and only their lifecycle status is read. Returns the
+    /// rollups whose status changed, including hosts rolled up for the
+    /// first time.
+    pub fn update<'a>(
+        &mut self,
+        state: &ComputeResourceState,
+        lookup: impl Fn(Uuid) -> Option<&'a ComputeResourceState>,
+    ) -> Vec<RollupChange> {
+        let host = match self.config.placement(state) {
+            Some(host) => host,
+            None => self.host_of.get(&state.id).copied(),
+        };
+        let previous_host = match host {
+            Some(host_id) => self.host_of.insert(state.id, host_id),
+            None => self.host_of.remove(&state.id),
+        };
+
+        if previous_host != host {
+            if let Some(previous) = previous_host {
+                if let Some(guests) = self.guests.get_mut(&previous) {
+                    guests.remove(&state.id);
+                }
+            }
+            if let Some(host_id) = host {
+                self.guests.entry(host_id).or_default().insert(state.id);
+            }
+        }
+
+        let mut affected: BTreeSet<Uuid> = [previous_host, host].into_iter().flatten().collect();
+        affected.insert(state.id);
+
+        affected
+            .into_iter()
+            .filter_map(|host_id| {
+                let host_state = if host_id == state.id {
+                    Some(state)
+                } else {
+                    lookup(host_id)
+                };
+                self.recompute(host_id, host_state, &lookup)
+            })
+            .collect()
+    }
+
+    /// Recompute one host, returning the change if its status moved
+    ///
+    /// A host losing its last guest drops its rollup; the change back to
+    /// the host's own condition is still reported.
+    fn recompute<'a>(
+        &mut self,
+        host_id: Uuid,
+        host: Option<&ComputeResourceState>,
+        lookup: &impl Fn(Uuid) -> Option<&'a ComputeResourceState>,
+    ) -> Option<RollupChange> {
+        let guest_ids = self.guests.get(&host_id).cloned().unwrap_or_default();
+        if guest_ids.is_empty() {
+            self.guests.remove(&host_id);
+        }
+        let previous = self.rollups.remove(&host_id).map(|r| r.status);
+
+        // Guests may name a host that has not been registered (yet)
+        let host = host?;
+        if guest_ids.is_empty() && previous.is_none() {
+            return None;
+        }
+
+        let rollup = HostRollup::compute(host, guest_ids.iter().filter_map(|id| lookup(*id)));
+        if !guest_ids.is_empty() {
+            self.rollups.insert(host_id, rollup.clone());
+        }
+
+        (previous != Some(rollup.status)).then_some(RollupChange { previous, rollup })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Hostname, ResourceType};
+
+    fn resource(
+        hostname: &str,
+        status: ResourceStatus,
+        host: Option<Uuid>,
+    ) -> ComputeResourceState {
+        let mut state = ComputeResourceState::default_for(Uuid::now_v7());
+        state.hostname = Hostname::new(hostname).unwrap();
+        state.resource_type = ResourceType::VirtualMachine;
+        state.status = status;
+        if let Some(host) = host {
+            state.metadata = vec![("host_id".to_string(), host.to_string())];
+        }
+        state
+    }
+
+    #[test]
+    fn test_guest_degrades_host_rollup() {
+        // Arrange
+        let host = resource("hv01", ResourceStatus::Active, None);
+        let healthy = resource("vm01", ResourceStatus::Active, Some(host.id));
+        let failed = resource("vm02", ResourceStatus::Decommissioned, Some(host.id));
+
+        // Act
+        let rollup = HostRollup::compute(&host, [&healthy, &failed]);
+
+        // Assert
+        assert_eq!(rollup.status, RollupStatus::Degraded);
+        assert_eq!(rollup.guest_count, 2);
+        assert_eq!(rollup.failed_guests, vec![failed.id]);
+        assert!(rollup.needs_attention());
+    }
+
+    #[test]
+    fn test_updates_report_only_status_changes() {
+        // Arrange
+        let host = resource("hv01", ResourceStatus::Active, None);
+        let mut guest = resource("vm01", ResourceStatus::Active, Some(host.id));
+        let mut rollups = StatusRollups::new(RollupConfig::default());
+        let mut states = HashMap::from([(host.id, host.clone())]);
+
+        // Act / Assert: first rollup is reported
+        states.insert(guest.id, guest.clone());
+        let changes = rollups.update(&guest, |id| states.get(&id));
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].previous, None);
+        assert_eq!(changes[0].rollup.status, RollupStatus::Healthy);
+
+        // Guest into maintenance degrades the host
+        guest.status = ResourceStatus::Maintenance;
+        states.insert(guest.id, guest.clone());
+        let changes = rollups.update(&guest, |id| states.get(&id));
+        assert_eq!(changes[0].previous, Some(RollupStatus::Healthy));
+        assert_eq!(changes[0].rollup.degraded_guests, vec![guest.id]);
+
+        // An unrelated host change keeps the status: nothing reported
+        let mut host = host;
+        host.asset_tag = Some("A-1".to_string());
+        states.insert(host.id, host.clone());
+        assert!(rollups.update(&host, |id| states.get(&id)).is_empty());
+
+        // Moving the guest off the host restores its own condition
+        guest.metadata = vec![("host_id".to_string(), String::new())];
+        states.insert(guest.id, guest.clone());
+        let changes = rollups.update(&guest, |id| states.get(&id));
+        assert_eq!(changes[0].rollup.status, RollupStatus::Healthy);
+        assert_eq!(changes[0].rollup.guest_count, 0);
+        assert!(rollups.get(host.id).is_none());
+    }
+}
@@ -0,0 +1,201 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! JSON Schemas
+//!
+//! Exports draft-07 JSON Schemas for every event and command, derived from
+//! the Rust types (including their serde tags and renames). Publishers
+//! written in other languages validate what they send against them, and
+//! contract tests compare them between releases.
+//!
+//! Schemas are versioned by crate version, one file per type:
+//!
+//! ```text
+//! <out_dir>/
+//! └── v0.1.0/
+//!     ├── events/
+//!     │   ├── InfrastructureEvent.schema.json
+//!     │   └── AdvisoryEvent.schema.json …
+//!     └── commands/
+//!         ├── ComputeResourceCommand.schema.json
+//!         └── InfrastructureCommand.schema.json …
+//! ```
+//!
+//! Each schema carries its relative path as `$id` and embeds the types it
+//! references under `definitions`, so files can be used on their own.
+//!
+//! # Generating
+//!
+//! ```text
+//! cargo run --bin export-schemas --features json-schema -- schemas
+//! ```
+
+use schemars::{schema_for, JsonSchema};
+use serde_json::{json, Value};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::aggregate::connection::ConnectionCommand;
+use crate::aggregate::ip_pool::IpPoolCommand;
+use crate::aggregate::network::NetworkCommand;
+use crate::aggregate::network_interface::{
+    AttachInterfaceCommand, DissolveBondCommand, FormBondCommand,
+};
+use crate::aggregate::ComputeResourceCommand;
+use crate::events::{
+    AdvisoryEvent, AlertRaised, ComputeResourceEvent, InfrastructureEvent, OperationProgress,
+    PolicyEvent, ScorecardComputed,
+};
+
+/// Version the exported schemas are filed under
+pub const SCHEMA_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Category directory of event schemas
+pub const EVENTS: &str = "events";
+
+/// Category directory of command schemas
+pub const COMMANDS: &str = "commands";
+
+/// JSON Schema of one exported type
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeSchema {
+    /// [`EVENTS`] or [`COMMANDS`]
+    pub category: &'static str,
+
+    /// Type name, also the file name
+    pub name: &'static str,
+
+    /// The schema document
+    pub schema: Value,
+}
+
+impl TypeSchema {
+    /// Schema of `T`
+    pub fn of<T: JsonSchema>(category: &'static str, name: &'static str) -> Self {
+        let mut type_schema = Self {
+            category,
+            name,
+            schema: serde_json::to_value(schema_for!(T)).expect("JSON schema serializes"),
+        };
+        type_schema.schema["$id"] = json!(type_schema.id());
+        type_schema.schema["title"] = json!(name);
+        type_schema
+    }
+
+    /// `$id` of the schema: its path below the output directory
+    pub fn id(&self) -> String {
+        format!(
+            "v{}/{}/{}.schema.json",
+            SCHEMA_VERSION, self.category, self.name
+        )
+    }
+
+    /// Path of the schema file below the output directory
+    pub fn relative_path(&self) -> PathBuf {
+        PathBuf::from(self.id())
+    }
+}
+
+/// Schemas of every event and command
+pub fn schemas() -> Vec<TypeSchema> {
+    let schemas = vec![
+        // Events
+        TypeSchema::of::<InfrastructureEvent>(EVENTS, "InfrastructureEvent"),
+        TypeSchema::of::<ComputeResourceEvent>(EVENTS, "ComputeResourceEvent"),
+        TypeSchema::of::<AdvisoryEvent>(EVENTS, "AdvisoryEvent"),
+        TypeSchema::of::<PolicyEvent>(EVENTS, "PolicyEvent"),
+        TypeSchema::of::<AlertRaised>(EVENTS, "AlertRaised"),
+        TypeSchema::of::<ScorecardComputed>(EVENTS, "ScorecardComputed"),
+        TypeSchema::of::<OperationProgress>(EVENTS, "OperationProgress"),
+        // Commands
+        TypeSchema::of::<ComputeResourceCommand>(COMMANDS, "ComputeResourceCommand"),
+        TypeSchema::of::<NetworkCommand>(COMMANDS, "NetworkCommand"),
+        TypeSchema::of::<AttachInterfaceCommand>(COMMANDS, "AttachInterfaceCommand"),
+        TypeSchema::of::<FormBondCommand>(COMMANDS, "FormBondCommand"),
+        TypeSchema::of::<DissolveBondCommand>(COMMANDS, "DissolveBondCommand"),
+        TypeSchema::of::<ConnectionCommand>(COMMANDS, "ConnectionCommand"),
+        TypeSchema::of::<IpPoolCommand>(COMMANDS, "IpPoolCommand"),
+    ];
+
+    #[cfg(feature = "runtime")]
+    let schemas = [schemas, runtime_schemas()].concat();
+
+    schemas
+}
+
+/// Stored event envelope and command bus messages (only built with the
+/// runtime)
+#[cfg(feature = "runtime")]
+fn runtime_schemas() -> Vec<TypeSchema> {
+    use crate::jetstream::StoredEvent;
+    use crate::service::{CommandReply, InfrastructureCommand};
+
+    vec![
+        TypeSchema::of::<StoredEvent<InfrastructureEvent>>(EVENTS, "StoredEvent"),
+        TypeSchema::of::<InfrastructureCommand>(COMMANDS, "InfrastructureCommand"),
+        TypeSchema::of::<CommandReply>(COMMANDS, "CommandReply"),
+    ]
+}
+
+/// Write every schema below `out_dir`, returning the files written
+pub fn export_all(out_dir: impl AsRef<Path>) -> io::Result<Vec<PathBuf>> {
+    let out_dir = out_dir.as_ref();
+    let mut written = Vec::new();
+
+    for type_schema in schemas() {
+        let path = out_dir.join(type_schema.relative_path());
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(&type_schema.schema)?;
+        std::fs::write(&path, json + "\n")?;
+        written.push(path);
+    }
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schemas_follow_serde_tags() {
+        // Arrange
+        let schemas = schemas();
+
+        // Act
+        let infrastructure = schemas
+            .iter()
+            .find(|schema| schema.name == "InfrastructureEvent")
+            .unwrap();
+        let text = infrastructure.schema.to_string();
+
+        // Assert
+        assert_eq!(
+            infrastructure.schema["$id"],
+            format!("v{}/events/InfrastructureEvent.schema.json", SCHEMA_VERSION)
+        );
+        assert!(text.contains("aggregate_type"));
+        assert!(text.contains("compute_resource"));
+        assert!(infrastructure.schema["definitions"]["ResourceRegistered"].is_object());
+    }
+
+    #[test]
+    fn test_export_writes_versioned_files() {
+        // Arrange
+        let out_dir = std::env::temp_dir().join(format!("cim-schemas-{}", uuid::Uuid::now_v7()));
+
+        // Act
+        let written = export_all(&out_dir).unwrap();
+
+        // Assert
+        assert_eq!(written.len(), schemas().len());
+        let version_dir = out_dir.join(format!("v{}", SCHEMA_VERSION));
+        for file in [
+            "events/InfrastructureEvent.schema.json",
+            "commands/ComputeResourceCommand.schema.json",
+        ] {
+            assert!(version_dir.join(file).exists(), "{} not exported", file);
+        }
+        std::fs::remove_dir_all(&out_dir).unwrap();
+    }
+}
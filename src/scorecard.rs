@@ -0,0 +1,440 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Inventory Hygiene Scorecards
+//!
+//! A [`Scorecard`] quantifies how well one organization's inventory is
+//! governed: how many of its resources have an owner, a location and
+//! policies, how many devices that need configuration backups have a recent
+//! one, and how much [drift](crate::reconcile) the latest observations
+//! show.
+//!
+//! ```text
+//! ReadModelHandle ──resources──┐
+//!                              ├──> ScorecardGenerator ──> infrastructure.scorecard.<organization>
+//! DriftHandle ──current drift──┘            │
+//!                                           └──> ScorecardBoard ──> infrastructure.query.scorecard.get
+//! ```
+//!
+//! Computation is pure ([`compute_scorecards`]). With the runtime,
+//! [`ScorecardGenerator`] computes scorecards on an interval, publishes a
+//! [`ScorecardComputed`] event per organization (captured by the event
+//! stream, so past scorecards form a history) and keeps the latest ones on
+//! a [`ScorecardBoard`] for queries.
+//!
+//! Decommissioned resources are not scored. Resources without an
+//! organization are scored together under `organization_id: None`.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::scorecard::{ScorecardConfig, ScorecardGenerator};
+//!
+//! let generator = ScorecardGenerator::new(nats_client, read_model.clone(), ScorecardConfig::default())
+//!     .with_drift(drift_monitor.handle());
+//! let read_model = read_model.with_scorecards(generator.board());
+//!
+//! tokio::spawn(async move { generator.run(Duration::from_secs(3600)).await });
+//! QueryResponder::new(nats_client, Arc::new(read_model)).run().await?;
+//! ```
+
+#[cfg(feature = "runtime")]
+use arc_swap::ArcSwap;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+#[cfg(feature = "runtime")]
+use std::sync::Arc;
+#[cfg(feature = "runtime")]
+use tracing::info;
+use uuid::Uuid;
+
+use crate::aggregate::queries::requires_configuration_backup;
+use crate::aggregate::ComputeResourceState;
+#[cfg(feature = "runtime")]
+use crate::errors::InfrastructureResult;
+#[cfg(feature = "runtime")]
+use crate::events::scorecard::ScorecardComputed;
+use crate::events::ResourceStatus;
+#[cfg(feature = "runtime")]
+use crate::nats::NatsClient;
+#[cfg(feature = "runtime")]
+use crate::projection::read_model::ReadModelHandle;
+use crate::reconcile::Drift;
+#[cfg(feature = "runtime")]
+use crate::reconcile::DriftHandle;
+
+/// Inventory hygiene of one organization at one point in time
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct Scorecard {
+    /// Organization scored; None for resources without an organization
+    pub organization_id: Option<String>,
+
+    /// When the scorecard was computed
+    pub computed_at: DateTime<Utc>,
+
+    /// Resources scored (decommissioned resources excluded)
+    #[cfg_attr(feature = "typescript", ts(type = "number"))]
+    pub resources: u64,
+
+    /// Resources with an owner
+    #[cfg_attr(feature = "typescript", ts(type = "number"))]
+    pub with_owner: u64,
+
+    /// Resources with a location
+    #[cfg_attr(feature = "typescript", ts(type = "number"))]
+    pub with_location: u64,
+
+    /// Resources with at least one policy
+    #[cfg_attr(feature = "typescript", ts(type = "number"))]
+    pub with_policies: u64,
+
+    /// Devices expected to have configuration backups
+    #[cfg_attr(feature = "typescript", ts(type = "number"))]
+    pub backup_required: u64,
+
+    /// Of those, devices whose latest backup is recent enough
+    #[cfg_attr(feature = "typescript", ts(type = "number"))]
+    pub backup_current: u64,
+
+    /// Open drift findings on the organization's resources
+    #[cfg_attr(feature = "typescript", ts(type = "number"))]
+    pub drift: u64,
+}
+
+/// `part` as a percentage of `whole`; an empty whole is fully covered
+fn percent(part: u64, whole: u64) -> f64 {
+    if whole == 0 {
+        100.0
+    } else {
+        part as f64 * 100.0 / whole as f64
+    }
+}
+
+impl Scorecard {
+    fn empty(organization_id: Option<String>, computed_at: DateTime<Utc>) -> Self {
+        Self {
+            organization_id,
+            computed_at,
+            resources: 0,
+            with_owner: 0,
+            with_location: 0,
+            with_policies: 0,
+            backup_required: 0,
+            backup_current: 0,
+            drift: 0,
+        }
+    }
+
+    /// Percentage of resources with an owner
+    pub fn owner_coverage(&self) -> f64 {
+        percent(self.with_owner, self.resources)
+    }
+
+    /// Percentage of resources with a location
+    pub fn location_coverage(&self) -> f64 {
+        percent(self.with_location, self.resources)
+    }
+
+    /// Percentage of resources with at least one policy
+    pub fn policy_coverage(&self) -> f64 {
+        percent(self.with_policies, self.resources)
+    }
+
+    /// Percentage of backup-requiring devices with a recent backup
+    pub fn backup_coverage(&self) -> f64 {
+        percent(self.backup_current, self.backup_required)
+    }
+
+    /// Mean of owner, location and policy coverage
+    pub fn governance_completeness(&self) -> f64 {
+        (self.owner_coverage() + self.location_coverage() + self.policy_coverage()) / 3.0
+    }
+}
+
+impl fmt::Display for Scorecard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} resources, governance {:.1}% (owner {:.1}%, location {:.1}%, policies {:.1}%), backups {:.1}%, {} drift",
+            self.organization_id.as_deref().unwrap_or("unassigned"),
+            self.resources,
+            self.governance_completeness(),
+            self.owner_coverage(),
+            self.location_coverage(),
+            self.policy_coverage(),
+            self.backup_coverage(),
+            self.drift
+        )
+    }
+}
+
+/// Scorecard thresholds
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScorecardConfig {
+    /// Oldest configuration backup still counted as current
+    pub backup_max_age: Duration,
+}
+
+impl Default for ScorecardConfig {
+    fn default() -> Self {
+        Self {
+            backup_max_age: Duration::days(7),
+        }
+    }
+}
+
+impl ScorecardConfig {
+    /// Count backups up to `max_age` old as current
+    pub fn with_backup_max_age(mut self, max_age: Duration) -> Self {
+        self.backup_max_age = max_age;
+        self
+    }
+}
+
+/// Score every organization's resources
+///
+/// Drift is attributed through its resource; findings without one (unknown
+/// hosts) belong to no organization and are not counted. Results are
+/// ordered by organization, with unassigned resources first.
+pub fn compute_scorecards<'a>(
+    resources: impl IntoIterator<Item = &'a ComputeResourceState>,
+    drift: &[Drift],
+    config: &ScorecardConfig,
+    computed_at: DateTime<Utc>,
+) -> Vec<Scorecard> {
+    let mut cards: BTreeMap<Option<String>, Scorecard> = BTreeMap::new();
+    let mut organization_of: HashMap<Uuid, Option<String>> = HashMap::new();
+
+    for resource in resources {
+        if !resource.is_initialized() || resource.status == ResourceStatus::Decommissioned {
+            continue;
+        }
+
+        let organization_id = resource.organization_id.as_ref().map(ToString::to_string);
+        organization_of.insert(resource.id, organization_id.clone());
+        let card = cards
+            .entry(organization_id.clone())
+            .or_insert_with(|| Scorecard::empty(organization_id, computed_at));
+
+        card.resources += 1;
+        card.with_owner += u64::from(resource.owner_id.is_some());
+        card.with_location += u64::from(resource.location_id.is_some());
+        card.with_policies += u64::from(!resource.policy_ids.is_empty());
+        if requires_configuration_backup(resource) {
+            card.backup_required += 1;
+            card.backup_current +=
+                u64::from(!resource.lacks_recent_backup(computed_at, config.backup_max_age));
+        }
+    }
+
+    for finding in drift {
+        let Some(organization_id) = finding
+            .resource_id()
+            .and_then(|id| organization_of.get(&id))
+        else {
+            continue;
+        };
+        if let Some(card) = cards.get_mut(organization_id) {
+            card.drift += 1;
+        }
+    }
+
+    cards.into_values().collect()
+}
+
+/// Latest scorecards, shared between the generator and query handlers
+///
+/// Cloning is cheap; all clones see the same scorecards. Reads never wait
+/// for the generator.
+#[cfg(feature = "runtime")]
+#[derive(Clone, Default)]
+pub struct ScorecardBoard {
+    cards: Arc<ArcSwap<HashMap<Option<String>, Scorecard>>>,
+}
+
+#[cfg(feature = "runtime")]
+impl ScorecardBoard {
+    /// Latest scorecard of an organization (None for unassigned resources)
+    pub fn get(&self, organization_id: Option<&str>) -> Option<Scorecard> {
+        self.cards
+            .load()
+            .get(&organization_id.map(str::to_string))
+            .cloned()
+    }
+
+    /// Latest scorecards of every organization
+    pub fn all(&self) -> Vec<Scorecard> {
+        let mut cards: Vec<Scorecard> = self.cards.load().values().cloned().collect();
+        cards.sort_by(|a, b| a.organization_id.cmp(&b.organization_id));
+        cards
+    }
+
+    fn replace(&self, cards: &[Scorecard]) {
+        self.cards.store(Arc::new(
+            cards
+                .iter()
+                .map(|card| (card.organization_id.clone(), card.clone()))
+                .collect(),
+        ));
+    }
+}
+
+/// Periodically scores the read model and publishes the results
+#[cfg(feature = "runtime")]
+pub struct ScorecardGenerator {
+    client: NatsClient,
+    read_model: ReadModelHandle,
+    drift: Option<DriftHandle>,
+    config: ScorecardConfig,
+    board: ScorecardBoard,
+}
+
+#[cfg(feature = "runtime")]
+impl ScorecardGenerator {
+    /// Score the resources of `read_model`
+    ///
+    /// Backup coverage needs backup references, which a read model
+    /// retaining only key fields drops; such a read model scores every
+    /// device as lacking a backup.
+    pub fn new(client: NatsClient, read_model: ReadModelHandle, config: ScorecardConfig) -> Self {
+        Self {
+            client,
+            read_model,
+            drift: None,
+            config,
+            board: ScorecardBoard::default(),
+        }
+    }
+
+    /// Count the current drift of a [`DriftMonitor`](crate::reconcile::DriftMonitor)
+    pub fn with_drift(mut self, drift: DriftHandle) -> Self {
+        self.drift = Some(drift);
+        self
+    }
+
+    /// Board holding the latest scorecards
+    pub fn board(&self) -> ScorecardBoard {
+        self.board.clone()
+    }
+
+    /// Compute and publish scorecards once
+    pub async fn generate_once(&self) -> InfrastructureResult<Vec<Scorecard>> {
+        let snapshot = self.read_model.snapshot();
+        let drift = self
+            .drift
+            .as_ref()
+            .map(DriftHandle::current)
+            .unwrap_or_default();
+        let cards = compute_scorecards(snapshot.resources(), &drift, &self.config, Utc::now());
+        let correlation_id = Uuid::now_v7();
+
+        for card in &cards {
+            info!("Scorecard {}", card);
+            let event = ScorecardComputed {
+                event_id: Uuid::now_v7(),
+                correlation_id,
+                scorecard: card.clone(),
+            };
+            self.client.publish(&event.subject(), &event).await?;
+        }
+
+        self.board.replace(&cards);
+        Ok(cards)
+    }
+
+    /// Generate scorecards on a fixed interval until an error occurs
+    pub async fn run(&self, interval: std::time::Duration) -> InfrastructureResult<()> {
+        info!("Starting scorecard generator (interval: {:?})", interval);
+        let mut ticker = tokio::time::interval(interval);
+
+        loop {
+            ticker.tick().await;
+            self.generate_once().await?;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Hostname, ResourceType};
+    use crate::events::ConfigurationBackupRef;
+    use cim_domain::EntityId;
+    use cim_domain_person::PersonId;
+
+    fn test_timestamp() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-19T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn resource(hostname: &str, resource_type: ResourceType) -> ComputeResourceState {
+        let mut state = ComputeResourceState::default_for(Uuid::now_v7());
+        state.hostname = Hostname::new(hostname).unwrap();
+        state.resource_type = resource_type;
+        state.status = ResourceStatus::Active;
+        state.created_at = Some(test_timestamp());
+        state
+    }
+
+    #[test]
+    fn test_scores_each_organization() {
+        // Arrange
+        let organization = EntityId::new();
+        let mut web = resource("web-01", ResourceType::PhysicalServer);
+        web.organization_id = Some(organization.clone());
+        web.owner_id = Some(PersonId::new());
+        let mut switch = resource("sw-01", ResourceType::Switch);
+        switch.organization_id = Some(organization.clone());
+        switch.last_configuration_backup = Some(ConfigurationBackupRef {
+            object_store: "device-configs".to_string(),
+            object_key: "sw-01.cfg".to_string(),
+            content_hash: "sha256:00".to_string(),
+            tool: "oxidized".to_string(),
+            taken_at: test_timestamp() - Duration::days(1),
+            size_bytes: None,
+        });
+        let mut retired = resource("old-01", ResourceType::PhysicalServer);
+        retired.organization_id = Some(organization.clone());
+        retired.status = ResourceStatus::Decommissioned;
+        let orphan = resource("lab-01", ResourceType::PhysicalServer);
+
+        let drift = vec![Drift::MissingInterface {
+            resource_id: web.id,
+            hostname: "web-01".to_string(),
+            interface_id: Uuid::now_v7(),
+            interface: "eth1".to_string(),
+        }];
+
+        // Act
+        let cards = compute_scorecards(
+            [&web, &switch, &retired, &orphan],
+            &drift,
+            &ScorecardConfig::default(),
+            test_timestamp(),
+        );
+
+        // Assert
+        assert_eq!(cards.len(), 2);
+        assert_eq!(cards[0].organization_id, None);
+        assert_eq!(cards[0].resources, 1);
+
+        let card = &cards[1];
+        assert_eq!(card.organization_id, Some(organization.to_string()));
+        assert_eq!(card.resources, 2);
+        assert_eq!(card.owner_coverage(), 50.0);
+        assert_eq!(card.location_coverage(), 0.0);
+        assert_eq!((card.backup_required, card.backup_current), (1, 1));
+        assert_eq!(card.drift, 1);
+    }
+
+    #[test]
+    fn test_empty_totals_count_as_covered() {
+        let card = Scorecard::empty(None, test_timestamp());
+
+        assert_eq!(card.backup_coverage(), 100.0);
+        assert_eq!(card.governance_completeness(), 100.0);
+    }
+}
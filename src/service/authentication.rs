@@ -0,0 +1,155 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Request Authentication
+//!
+//! The API edges establish who sent a request before the service sees it.
+//! An [`Authenticator`] turns the request's bearer token into the
+//! [`EventEnvelopeMetadata`] the request is handled under, so the actor
+//! and tenant that authorization, tenancy and rate limits act on are the
+//! authenticated ones, never a header the client chose:
+//!
+//! ```text
+//! Authorization: Bearer <token> ──> Authenticator ──> EventEnvelopeMetadata { actor, tenant }
+//!                                        │                     │ scope(...)
+//!                                        │                     ▼
+//!                                        │            rate limit ──> service ──> events
+//!                                        └── Err ──> 401 / NackReason::Unauthenticated
+//! ```
+//!
+//! | Authenticator          | Identity                                          |
+//! |------------------------|---------------------------------------------------|
+//! | [`TokenAuthenticator`] | actor and organization registered for each token  |
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::service::authentication::TokenAuthenticator;
+//!
+//! let authenticator = TokenAuthenticator::new()
+//!     .with_token(ci_token, Actor::service("ci-pipeline"), Some(acme_org_id.to_string()))
+//!     .with_token(admin_token, Actor::user("alice@example.com"), None);
+//!
+//! let app = rest::authenticated(rest::router(service, read_model), Arc::new(authenticator));
+//! ```
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+use crate::event_store::provenance::{Actor, EventEnvelopeMetadata};
+
+/// Header carrying the credentials of a request
+pub const AUTHORIZATION_HEADER: &str = "Authorization";
+
+/// A request could not be authenticated
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum AuthenticationError {
+    /// The request carries no credentials
+    #[error("Missing credentials")]
+    Missing,
+
+    /// The credentials are not recognised
+    #[error("Invalid credentials")]
+    Invalid,
+
+    /// The authenticator could not decide
+    #[error("Authenticator unavailable: {0}")]
+    Unavailable(String),
+}
+
+/// Establishes who sent a request
+#[async_trait]
+pub trait Authenticator: Send + Sync {
+    /// Envelope of the sender of `token`, or why there is none
+    async fn authenticate(
+        &self,
+        token: Option<&str>,
+    ) -> Result<EventEnvelopeMetadata, AuthenticationError>;
+}
+
+/// Token of an `Authorization` header value, if it is a bearer token
+pub fn bearer_token(value: &str) -> Option<&str> {
+    let (scheme, token) = value.trim().split_once(' ')?;
+    let token = token.trim();
+    (scheme.eq_ignore_ascii_case("bearer") && !token.is_empty()).then_some(token)
+}
+
+/// Authenticates requests by static bearer tokens
+#[derive(Debug, Clone, Default)]
+pub struct TokenAuthenticator {
+    tokens: HashMap<String, EventEnvelopeMetadata>,
+}
+
+impl TokenAuthenticator {
+    /// Authenticator knowing no tokens yet
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests with `token` act as `actor`, for `tenant` if given
+    pub fn with_token(
+        mut self,
+        token: impl Into<String>,
+        actor: Actor,
+        tenant: Option<String>,
+    ) -> Self {
+        let mut envelope = EventEnvelopeMetadata::new().with_actor(actor);
+        envelope.tenant = tenant;
+        self.tokens.insert(token.into(), envelope);
+        self
+    }
+}
+
+#[async_trait]
+impl Authenticator for TokenAuthenticator {
+    async fn authenticate(
+        &self,
+        token: Option<&str>,
+    ) -> Result<EventEnvelopeMetadata, AuthenticationError> {
+        let token = token.ok_or(AuthenticationError::Missing)?;
+        self.tokens
+            .get(token)
+            .cloned()
+            .ok_or(AuthenticationError::Invalid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bearer_tokens_are_taken_from_header_values() {
+        assert_eq!(bearer_token("Bearer s3cret"), Some("s3cret"));
+        assert_eq!(bearer_token("bearer  s3cret "), Some("s3cret"));
+        assert_eq!(bearer_token("Basic dXNlcjpwdw=="), None);
+        assert_eq!(bearer_token("Bearer"), None);
+    }
+
+    #[tokio::test]
+    async fn test_tokens_resolve_to_their_actor_and_tenant() {
+        // Arrange
+        let authenticator = TokenAuthenticator::new()
+            .with_token(
+                "ci",
+                Actor::service("ci-pipeline"),
+                Some("acme".to_string()),
+            )
+            .with_token("admin", Actor::user("alice@example.com"), None);
+
+        // Act
+        let ci = authenticator.authenticate(Some("ci")).await.unwrap();
+        let admin = authenticator.authenticate(Some("admin")).await.unwrap();
+
+        // Assert
+        assert_eq!(ci.actor, Some(Actor::service("ci-pipeline")));
+        assert_eq!(ci.tenant.as_deref(), Some("acme"));
+        assert_eq!(admin.tenant, None);
+        assert_eq!(
+            authenticator.authenticate(Some("guess")).await,
+            Err(AuthenticationError::Invalid)
+        );
+        assert_eq!(
+            authenticator.authenticate(None).await,
+            Err(AuthenticationError::Missing)
+        );
+    }
+}
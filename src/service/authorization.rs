@@ -0,0 +1,406 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Command Authorization
+//!
+//! The service asks an [`Authorizer`] before handling every command:
+//!
+//! ```text
+//! command ──> load state ──> Authorizer::authorize(actor, command, aggregate) ──> handle ──> append
+//!                                   │
+//!                                   └── Denied ──> ServiceError::Unauthorized
+//! ```
+//!
+//! The actor is the one of the request's
+//! [`EventEnvelopeMetadata`](crate::event_store::provenance::EventEnvelopeMetadata),
+//! as set by [authentication](crate::service::authentication) at the API
+//! edge (or the service's), so it is the same identity that ends up on the
+//! events. Compute resource, network, interface and connection commands
+//! are all authorized this way.
+//!
+//! | Authorizer           | Decision                                                          |
+//! |----------------------|-------------------------------------------------------------------|
+//! | [`AllowAll`]         | every command (the service default)                               |
+//! | [`PolicyAuthorizer`] | commands the governing policies grant to the actor's roles        |
+//!
+//! # Domain Policies
+//!
+//! Access is decided by the policies governing the target: the policies
+//! attached to a compute resource (its `policy_ids`) plus the baseline
+//! policies that govern every command. Policies are named by their
+//! cim-domain-policy [`PolicyId`], but their [`AccessRule`]s are not read
+//! from cim-domain-policy: they come from a [`PolicySource`], such as the
+//! in-memory [`DomainPolicies`] the application fills, or its own
+//! implementation. Policies the source has no access rules for
+//! (compliance only) do not take part in the decision.
+//!
+//! ```text
+//! governing = baseline ∪ resource.policy_ids
+//!     ──> PolicySource::access_rules(policy_id) for each
+//!     ──> every policy with access rules grants the command to one of the actor's roles?
+//!           yes ──> allow          no (or no such policy) ──> Denied
+//! ```
+//!
+//! So a resource under a PCI policy can only be changed by roles the PCI
+//! policy grants, whatever the baseline allows.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::service::authorization::{AccessRule, DomainPolicies, PolicyAuthorizer};
+//!
+//! let policies = DomainPolicies::new()
+//!     .with_policy(baseline_policy_id, [AccessRule::grant("operator")])
+//!     .with_policy(pci_policy_id, [AccessRule::grant("pci-admin")]);
+//!
+//! let authorizer = PolicyAuthorizer::new(Arc::new(policies))
+//!     .with_baseline(baseline_policy_id)
+//!     .with_role(&Actor::user("alice@example.com"), "operator")
+//!     .with_role(&Actor::user("bob@example.com"), "pci-admin");
+//!
+//! let service = EventSourcedComputeResourceService::new(event_store, nats_client)
+//!     .with_authorizer(Arc::new(authorizer));
+//! ```
+
+use async_trait::async_trait;
+use cim_domain_policy::PolicyId;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::event_store::provenance::Actor;
+use crate::subjects::AggregateType;
+
+/// What is being asked
+#[derive(Debug, Clone, Copy)]
+pub struct AuthorizationRequest<'a> {
+    /// Who is acting, if known
+    pub actor: Option<&'a Actor>,
+
+    /// Kind of the target aggregate
+    pub aggregate_type: AggregateType,
+
+    /// Command name, as in
+    /// [`ComputeResourceCommand::command_name`](crate::aggregate::commands::ComputeResourceCommand::command_name)
+    pub command: &'a str,
+
+    /// Target aggregate
+    pub aggregate_id: Uuid,
+
+    /// Domain policies attached to the target (a compute resource's
+    /// `policy_ids`; none for other aggregates and registrations)
+    pub policy_ids: &'a [PolicyId],
+}
+
+/// A command was not authorized
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum AuthorizationError {
+    /// The actor may not run the command against the aggregate
+    #[error("{actor} may not {command} aggregate {aggregate_id}")]
+    Denied {
+        actor: String,
+        command: String,
+        aggregate_id: Uuid,
+    },
+
+    /// The authorizer could not decide
+    #[error("Authorizer unavailable: {0}")]
+    Unavailable(String),
+}
+
+impl AuthorizationError {
+    /// Denial of `request`
+    pub fn denied(request: &AuthorizationRequest<'_>) -> Self {
+        AuthorizationError::Denied {
+            actor: request
+                .actor
+                .map_or_else(|| "anonymous".to_string(), ToString::to_string),
+            command: request.command.to_string(),
+            aggregate_id: request.aggregate_id,
+        }
+    }
+}
+
+/// Decides whether a command may run
+#[async_trait]
+pub trait Authorizer: Send + Sync {
+    /// Allow the command, or say why not
+    async fn authorize(&self, request: &AuthorizationRequest<'_>)
+        -> Result<(), AuthorizationError>;
+}
+
+/// Allows every command
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AllowAll;
+
+#[async_trait]
+impl Authorizer for AllowAll {
+    async fn authorize(
+        &self,
+        _request: &AuthorizationRequest<'_>,
+    ) -> Result<(), AuthorizationError> {
+        Ok(())
+    }
+}
+
+/// Commands a domain policy grants to a role
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccessRule {
+    /// Role granted the commands
+    pub role: String,
+
+    /// Command names granted; empty for every command
+    #[serde(default)]
+    pub commands: Vec<String>,
+}
+
+impl AccessRule {
+    /// Every command, to `role`
+    pub fn grant(role: impl Into<String>) -> Self {
+        Self {
+            role: role.into(),
+            commands: Vec::new(),
+        }
+    }
+
+    /// Only these commands
+    pub fn for_commands<I, C>(mut self, commands: I) -> Self
+    where
+        I: IntoIterator<Item = C>,
+        C: Into<String>,
+    {
+        self.commands = commands.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Whether the rule grants `command` to a holder of one of `roles`
+    pub fn grants(&self, roles: &HashSet<String>, command: &str) -> bool {
+        roles.contains(&self.role)
+            && (self.commands.is_empty() || self.commands.iter().any(|name| name == command))
+    }
+}
+
+/// Where the access rules of domain policies are looked up
+#[async_trait]
+pub trait PolicySource: Send + Sync {
+    /// Access rules of the policy, or `None` if it does not govern access
+    async fn access_rules(
+        &self,
+        policy_id: &PolicyId,
+    ) -> Result<Option<Vec<AccessRule>>, AuthorizationError>;
+}
+
+/// Access rules of policies, registered by ID and held in memory
+#[derive(Debug, Clone, Default)]
+pub struct DomainPolicies {
+    policies: Vec<(PolicyId, Vec<AccessRule>)>,
+}
+
+impl DomainPolicies {
+    /// No policies yet
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Give policy `policy_id` access rules
+    pub fn with_policy<I>(mut self, policy_id: PolicyId, rules: I) -> Self
+    where
+        I: IntoIterator<Item = AccessRule>,
+    {
+        match self.policies.iter_mut().find(|(id, _)| *id == policy_id) {
+            Some((_, existing)) => existing.extend(rules),
+            None => self.policies.push((policy_id, rules.into_iter().collect())),
+        }
+        self
+    }
+}
+
+#[async_trait]
+impl PolicySource for DomainPolicies {
+    async fn access_rules(
+        &self,
+        policy_id: &PolicyId,
+    ) -> Result<Option<Vec<AccessRule>>, AuthorizationError> {
+        Ok(self
+            .policies
+            .iter()
+            .find(|(id, _)| id == policy_id)
+            .map(|(_, rules)| rules.clone()))
+    }
+}
+
+/// Role-based authorization by the access rules of the policies governing
+/// the target
+///
+/// Denies anything the governing policies do not all grant, including
+/// commands without an actor and commands no policy governs.
+#[derive(Clone)]
+pub struct PolicyAuthorizer {
+    policies: Arc<dyn PolicySource>,
+    baseline: Vec<PolicyId>,
+    roles: HashMap<String, HashSet<String>>,
+}
+
+impl PolicyAuthorizer {
+    /// Authorizer consulting `policies`, granting nothing yet
+    pub fn new(policies: Arc<dyn PolicySource>) -> Self {
+        Self {
+            policies,
+            baseline: Vec::new(),
+            roles: HashMap::new(),
+        }
+    }
+
+    /// Let `policy_id` govern every command
+    pub fn with_baseline(mut self, policy_id: PolicyId) -> Self {
+        self.baseline.push(policy_id);
+        self
+    }
+
+    /// Give `actor` a role
+    pub fn with_role(mut self, actor: &Actor, role: impl Into<String>) -> Self {
+        self.roles
+            .entry(actor.to_string())
+            .or_default()
+            .insert(role.into());
+        self
+    }
+
+    /// Whether every governing policy grants `request` to one of the
+    /// actor's roles
+    pub async fn allows(
+        &self,
+        request: &AuthorizationRequest<'_>,
+    ) -> Result<bool, AuthorizationError> {
+        let Some(roles) = request
+            .actor
+            .and_then(|actor| self.roles.get(&actor.to_string()))
+        else {
+            return Ok(false);
+        };
+
+        let mut governed = false;
+        for policy_id in self.baseline.iter().chain(request.policy_ids) {
+            let Some(rules) = self.policies.access_rules(policy_id).await? else {
+                continue;
+            };
+            if !rules.iter().any(|rule| rule.grants(roles, request.command)) {
+                return Ok(false);
+            }
+            governed = true;
+        }
+        Ok(governed)
+    }
+}
+
+#[async_trait]
+impl Authorizer for PolicyAuthorizer {
+    async fn authorize(
+        &self,
+        request: &AuthorizationRequest<'_>,
+    ) -> Result<(), AuthorizationError> {
+        if self.allows(request).await? {
+            Ok(())
+        } else {
+            Err(AuthorizationError::denied(request))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request<'a>(
+        actor: Option<&'a Actor>,
+        command: &'a str,
+        policy_ids: &'a [PolicyId],
+    ) -> AuthorizationRequest<'a> {
+        AuthorizationRequest {
+            actor,
+            aggregate_type: AggregateType::Compute,
+            command,
+            aggregate_id: Uuid::nil(),
+            policy_ids,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_allow_all_allows_anonymous_commands() {
+        // Act
+        let result = AllowAll
+            .authorize(&request(None, "register_resource", &[]))
+            .await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_every_governing_policy_must_grant_the_command() {
+        // Arrange
+        let baseline = PolicyId::new();
+        let pci = PolicyId::new();
+        let compliance_only = PolicyId::new();
+        let alice = Actor::user("alice@example.com");
+        let bob = Actor::user("bob@example.com");
+        let policies = DomainPolicies::new()
+            .with_policy(
+                baseline.clone(),
+                [
+                    AccessRule::grant("operator").for_commands(["change_status"]),
+                    AccessRule::grant("pci-admin"),
+                ],
+            )
+            .with_policy(pci.clone(), [AccessRule::grant("pci-admin")]);
+        let authorizer = PolicyAuthorizer::new(Arc::new(policies))
+            .with_baseline(baseline)
+            .with_role(&alice, "operator")
+            .with_role(&bob, "pci-admin");
+        let plain = [compliance_only];
+        let regulated = [pci];
+
+        // Act / Assert
+        assert!(authorizer
+            .allows(&request(Some(&alice), "change_status", &plain))
+            .await
+            .unwrap());
+        assert!(!authorizer
+            .allows(&request(Some(&alice), "assign_owner", &plain))
+            .await
+            .unwrap());
+        assert!(!authorizer
+            .allows(&request(Some(&alice), "change_status", &regulated))
+            .await
+            .unwrap());
+        assert!(authorizer
+            .allows(&request(Some(&bob), "assign_owner", &regulated))
+            .await
+            .unwrap());
+        assert_eq!(
+            authorizer
+                .authorize(&request(None, "change_status", &plain))
+                .await,
+            Err(AuthorizationError::Denied {
+                actor: "anonymous".to_string(),
+                command: "change_status".to_string(),
+                aggregate_id: Uuid::nil(),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_commands_no_policy_governs_are_denied() {
+        // Arrange
+        let alice = Actor::user("alice@example.com");
+        let authorizer =
+            PolicyAuthorizer::new(Arc::new(DomainPolicies::new())).with_role(&alice, "operator");
+
+        // Act
+        let allowed = authorizer
+            .allows(&request(Some(&alice), "define_network", &[]))
+            .await;
+
+        // Assert
+        assert_eq!(allowed, Ok(false));
+    }
+}
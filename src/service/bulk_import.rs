@@ -0,0 +1,298 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Bulk Import Service
+//!
+//! Executes a validated [`BulkImportPlan`] through the compute, network and
+//! connection services:
+//!
+//! ```text
+//! DefineNetwork × n ──> RegisterResource + UpdateMetadata × n
+//!                   ──> AttachInterface × n ──> EstablishConnection × n
+//! ```
+//!
+//! Every command carries the plan's correlation ID. The run stops at the
+//! first rejected command and reports it in [`BulkImportReport::failure`];
+//! what was stored before stays stored. Resources already registered by an
+//! earlier import are left alone, along with their metadata, interfaces and
+//! cables.
+//!
+//! # Progress
+//!
+//! Runs publish [`ImportProgress`] reports (operation `bulk_import`, scoped
+//! to the document name) on `infrastructure.progress.<operation_id>`, one
+//! work item per command. Pass your own operation ID to
+//! [`BulkImportService::import_with`] to subscribe before the run starts.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::service::bulk_import::BulkImportService;
+//!
+//! let importer = BulkImportService::new(compute, network, connections)
+//!     .with_progress(nats_client.clone());
+//!
+//! let operation_id = Uuid::now_v7();
+//! let reports = watch_progress(&nats_client, operation_id).await?;
+//! let report = importer
+//!     .import_with(operation_id, &plan, &CancellationToken::new())
+//!     .await?;
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::aggregate::CommandError;
+use crate::errors::{InfrastructureError, InfrastructureResult};
+use crate::events::progress::OperationProgress;
+use crate::import::BulkImportPlan;
+use crate::nats::progress::{CancellationToken, ProgressReporter};
+use crate::nats::NatsClient;
+use crate::service::{
+    ComputeResourceService, ConnectionService, NetworkService, ServiceError, ServiceResult,
+};
+
+/// Operation name of bulk import progress reports
+pub const BULK_IMPORT_OPERATION: &str = "bulk_import";
+
+/// Progress report of a bulk import run
+pub type ImportProgress = OperationProgress;
+
+/// Outcome of one bulk import run
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BulkImportReport {
+    /// Progress operation ID of the run
+    pub operation_id: Uuid,
+
+    /// Defined networks by name
+    pub networks: HashMap<String, Uuid>,
+
+    /// Resources registered by this run
+    pub registered: usize,
+
+    /// Resources registered by an earlier import
+    pub already_registered: usize,
+
+    /// Interfaces attached
+    pub interfaces: usize,
+
+    /// Connections established
+    pub connections: usize,
+
+    /// Rejected command that stopped the run, if any
+    pub failure: Option<String>,
+}
+
+impl BulkImportReport {
+    /// Whether every planned command ran or was already applied
+    pub fn is_complete(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+/// Why a run stopped early
+enum Stop {
+    Cancelled,
+    Rejected(String),
+}
+
+/// Imports bulk import plans through the service layer
+pub struct BulkImportService {
+    compute: Arc<dyn ComputeResourceService>,
+    network: Arc<dyn NetworkService>,
+    connections: Arc<dyn ConnectionService>,
+    progress: Option<NatsClient>,
+}
+
+impl BulkImportService {
+    /// Import through the given services
+    pub fn new(
+        compute: Arc<dyn ComputeResourceService>,
+        network: Arc<dyn NetworkService>,
+        connections: Arc<dyn ConnectionService>,
+    ) -> Self {
+        Self {
+            compute,
+            network,
+            connections,
+            progress: None,
+        }
+    }
+
+    /// Publish progress of runs through `client`
+    pub fn with_progress(mut self, client: NatsClient) -> Self {
+        self.progress = Some(client);
+        self
+    }
+
+    /// Import a plan under a fresh operation ID
+    pub async fn import(&self, plan: &BulkImportPlan) -> InfrastructureResult<BulkImportReport> {
+        self.import_with(Uuid::now_v7(), plan, &CancellationToken::new())
+            .await
+    }
+
+    /// Import a plan, reporting progress as `operation_id`
+    ///
+    /// A cancelled run returns [`InfrastructureError::Cancelled`] after the
+    /// command in flight; importing the document again continues with the
+    /// resources not yet registered.
+    pub async fn import_with(
+        &self,
+        operation_id: Uuid,
+        plan: &BulkImportPlan,
+        cancel: &CancellationToken,
+    ) -> InfrastructureResult<BulkImportReport> {
+        let mut progress =
+            ProgressReporter::with_id(operation_id, BULK_IMPORT_OPERATION).with_scope(&plan.name);
+        if let Some(client) = &self.progress {
+            progress = progress.with_client(client.clone());
+        }
+        progress.start(Some(plan.command_count() as u64)).await;
+        info!(
+            "Importing {} ({} commands, correlation {})",
+            plan.name,
+            plan.command_count(),
+            plan.correlation_id
+        );
+
+        let mut report = BulkImportReport {
+            operation_id,
+            ..BulkImportReport::default()
+        };
+        match self.run(plan, &mut report, &mut progress, cancel).await {
+            Ok(()) => {
+                progress.complete().await;
+                Ok(report)
+            }
+            Err(Stop::Cancelled) => {
+                progress.cancel().await;
+                Err(InfrastructureError::Cancelled(format!(
+                    "bulk import {}",
+                    plan.name
+                )))
+            }
+            Err(Stop::Rejected(message)) => {
+                warn!("Bulk import {} stopped: {}", plan.name, message);
+                progress.fail(message.clone()).await;
+                report.failure = Some(message);
+                Ok(report)
+            }
+        }
+    }
+
+    async fn run(
+        &self,
+        plan: &BulkImportPlan,
+        report: &mut BulkImportReport,
+        progress: &mut ProgressReporter,
+        cancel: &CancellationToken,
+    ) -> Result<(), Stop> {
+        for command in &plan.networks {
+            checkpoint(cancel)?;
+            let network_id = step(
+                format!("define network {}", command.name),
+                self.network.define_network(command.clone()).await,
+            )?;
+            report.networks.insert(command.name.clone(), network_id);
+            progress.advance(1).await;
+        }
+
+        let mut registered = HashSet::new();
+        for planned in &plan.resources {
+            checkpoint(cancel)?;
+            let what = format!("register {}", planned.command.hostname);
+            match self
+                .compute
+                .register_resource_as(planned.aggregate_id, planned.command.clone())
+                .await
+            {
+                Ok(()) => {}
+                Err(ServiceError::CommandError(CommandError::AlreadyInitialized)) => {
+                    report.already_registered += 1;
+                    progress.advance(1 + planned.metadata.len() as u64).await;
+                    continue;
+                }
+                Err(e) => return Err(Stop::Rejected(format!("{}: {}", what, e))),
+            }
+            progress.advance(1).await;
+
+            for command in &planned.metadata {
+                checkpoint(cancel)?;
+                step(
+                    format!("{} metadata {}", what, command.key),
+                    self.compute
+                        .update_metadata(planned.aggregate_id, command.clone())
+                        .await,
+                )?;
+                progress.advance(1).await;
+            }
+            registered.insert(planned.aggregate_id);
+            report.registered += 1;
+        }
+
+        let mut interface_ids: HashMap<(Uuid, &str), Uuid> = HashMap::new();
+        for interface in &plan.interfaces {
+            checkpoint(cancel)?;
+            if let (true, Some(network_id)) = (
+                registered.contains(&interface.resource_id),
+                report.networks.get(&interface.network),
+            ) {
+                let interface_id = step(
+                    format!("attach interface {}", interface.name),
+                    self.network
+                        .attach_interface(None, plan.attach_command(interface, *network_id))
+                        .await,
+                )?;
+                interface_ids.insert(
+                    (interface.resource_id, interface.name.as_str()),
+                    interface_id,
+                );
+                report.interfaces += 1;
+            }
+            progress.advance(1).await;
+        }
+
+        for connection in &plan.connections {
+            checkpoint(cancel)?;
+            let a_end = interface_ids.get(&(
+                connection.a_end.resource_id,
+                connection.a_end.interface.as_str(),
+            ));
+            let b_end = interface_ids.get(&(
+                connection.b_end.resource_id,
+                connection.b_end.interface.as_str(),
+            ));
+            if let (Some(a_end), Some(b_end)) = (a_end, b_end) {
+                step(
+                    format!(
+                        "connect {} {} to {} {}",
+                        connection.a_end.device,
+                        connection.a_end.interface,
+                        connection.b_end.device,
+                        connection.b_end.interface
+                    ),
+                    self.connections
+                        .establish_connection(plan.establish_command(connection, *a_end, *b_end))
+                        .await,
+                )?;
+                report.connections += 1;
+            }
+            progress.advance(1).await;
+        }
+
+        Ok(())
+    }
+}
+
+fn checkpoint(cancel: &CancellationToken) -> Result<(), Stop> {
+    if cancel.is_cancelled() {
+        Err(Stop::Cancelled)
+    } else {
+        Ok(())
+    }
+}
+
+/// Result of one command, with what it did prefixed to a rejection
+fn step<T>(what: String, result: ServiceResult<T>) -> Result<T, Stop> {
+    result.map_err(|e| Stop::Rejected(format!("{}: {}", what, e)))
+}
@@ -0,0 +1,506 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! NATS Command Bus
+//!
+//! Accepts commands over NATS request/reply and routes them to the service
+//! layer, so clients that are not linked against this crate can issue
+//! commands.
+//!
+//! # Subjects
+//!
+//! ```text
+//! infrastructure.cmd.<aggregate>.<command>
+//! infrastructure.cmd.compute.assign_owner
+//! ```
+//!
+//! The subject must agree with the payload: a `compute` subject carries a
+//! `compute_resource` command whose `command` tag equals the last token.
+//!
+//! # Request / Reply
+//!
+//! ```text
+//! request: {"aggregate_type": "compute_resource",
+//!           "aggregate_id": "0193…",
+//!           "command": {"command": "assign_asset_tag", "asset_tag": "A-1001", …}}
+//!
+//! reply:   {"status": "ack",  "aggregate_id": "0193…", "correlation_id": "…"}
+//!          {"status": "nack", "reason": "rejected", "message": "…", "correlation_id": "…"}
+//! ```
+//!
+//! With an [`Authenticator`], the `Authorization: Bearer` header of each
+//! request identifies its sender, and the command runs as that actor, for
+//! that actor's organization (see [`tenancy`](crate::tenancy)); requests
+//! it cannot identify are refused as `unauthenticated`. With a
+//! [`RateLimiter`], commands of an organization over its rate are refused
+//! as `rate_limited`.
+//!
+//! Command subjects must not be captured by the event stream; see
+//! [`subjects::stream_subjects`].
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::service::command_bus::CommandSubscriber;
+//!
+//! let subscriber = CommandSubscriber::new(nats_client, Arc::new(service))
+//!     .with_authenticator(Arc::new(authenticator));
+//! subscriber.run().await?;
+//! ```
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+use crate::aggregate::commands::ComputeResourceCommand;
+use crate::errors::{InfrastructureError, InfrastructureResult};
+use crate::event_store::provenance::EventEnvelopeMetadata;
+use crate::nats::NatsClient;
+use crate::service::authentication::{
+    bearer_token, AuthenticationError, Authenticator, AUTHORIZATION_HEADER,
+};
+use crate::service::authorization::AuthorizationError;
+use crate::service::lookup::LookupError;
+use crate::service::rate_limit::{RateLimitError, RateLimiter};
+use crate::service::{ComputeResourceService, ServiceError};
+use crate::subjects::{subjects, AggregateType};
+
+/// Command request envelope received on the command bus
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(tag = "aggregate_type", rename_all = "snake_case")]
+pub enum InfrastructureCommand {
+    /// Command for a compute resource
+    ComputeResource {
+        /// Target aggregate; for `register_resource`, the ID of the new
+        /// resource (generated when omitted)
+        #[serde(default)]
+        aggregate_id: Option<Uuid>,
+
+        /// The command itself
+        command: ComputeResourceCommand,
+    },
+}
+
+impl InfrastructureCommand {
+    /// Aggregate type token used in the subject
+    pub fn aggregate_type(&self) -> AggregateType {
+        match self {
+            InfrastructureCommand::ComputeResource { .. } => AggregateType::Compute,
+        }
+    }
+
+    /// Command name token used in the subject
+    pub fn command_name(&self) -> &'static str {
+        match self {
+            InfrastructureCommand::ComputeResource { command, .. } => command.command_name(),
+        }
+    }
+
+    /// Correlation ID carried by the command
+    pub fn correlation_id(&self) -> Uuid {
+        match self {
+            InfrastructureCommand::ComputeResource { command, .. } => command.correlation_id(),
+        }
+    }
+
+    /// Timestamp carried by the command
+    pub fn timestamp(&self) -> DateTime<Utc> {
+        match self {
+            InfrastructureCommand::ComputeResource { command, .. } => command.timestamp(),
+        }
+    }
+
+    /// Subject this command is sent on
+    pub fn subject(&self) -> String {
+        subjects::command(self.aggregate_type(), self.command_name())
+    }
+}
+
+/// Why a command was not accepted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum NackReason {
+    /// Subject is not a command subject or disagrees with the payload
+    InvalidSubject,
+
+    /// Payload could not be decoded
+    Malformed,
+
+    /// Business rules rejected the command
+    Rejected,
+
+    /// The sender could not be authenticated
+    Unauthenticated,
+
+    /// The actor may not run the command
+    Forbidden,
+
+    /// Target aggregate does not exist
+    NotFound,
+
+    /// Concurrent modification; retry with fresh state
+    Conflict,
+
+    /// The organization is over its command rate; retry later
+    RateLimited,
+
+    /// Event store or NATS failure; retry later
+    Unavailable,
+}
+
+/// Reply sent on the request's reply subject
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum CommandReply {
+    /// Command accepted and its event persisted
+    Ack {
+        /// Aggregate the command was applied to
+        aggregate_id: Uuid,
+
+        /// Correlation ID of the command
+        correlation_id: Uuid,
+    },
+
+    /// Command not accepted
+    Nack {
+        /// Failure category
+        reason: NackReason,
+
+        /// Human-readable detail
+        message: String,
+
+        /// Correlation ID, when the payload could be decoded
+        correlation_id: Option<Uuid>,
+    },
+}
+
+impl CommandReply {
+    /// Whether the command was accepted
+    pub fn is_ack(&self) -> bool {
+        matches!(self, CommandReply::Ack { .. })
+    }
+
+    pub(crate) fn nack(
+        reason: NackReason,
+        message: impl Into<String>,
+        correlation_id: Option<Uuid>,
+    ) -> Self {
+        CommandReply::Nack {
+            reason,
+            message: message.into(),
+            correlation_id,
+        }
+    }
+
+    pub(crate) fn from_service_error(error: &ServiceError, correlation_id: Uuid) -> Self {
+        let reason = match error {
+            ServiceError::CommandError(_)
+            | ServiceError::BusinessRuleViolation(_)
+            | ServiceError::Lookup(LookupError::Taken { .. }) => NackReason::Rejected,
+            ServiceError::NotFound(_) => NackReason::NotFound,
+            ServiceError::Unauthorized(AuthorizationError::Denied { .. }) => NackReason::Forbidden,
+            ServiceError::ConcurrencyConflict { .. } | ServiceError::PartiallyCommitted { .. } => {
+                NackReason::Conflict
+            }
+            ServiceError::EventStoreError(_)
+            | ServiceError::NatsError(_)
+            | ServiceError::WriteQueue(_)
+            | ServiceError::Lease(_)
+            | ServiceError::Lookup(LookupError::Store(_))
+            | ServiceError::Unauthorized(AuthorizationError::Unavailable(_)) => {
+                NackReason::Unavailable
+            }
+        };
+        Self::nack(reason, error.to_string(), Some(correlation_id))
+    }
+
+    pub(crate) fn from_authentication_error(
+        error: &AuthenticationError,
+        correlation_id: Option<Uuid>,
+    ) -> Self {
+        let reason = match error {
+            AuthenticationError::Missing | AuthenticationError::Invalid => {
+                NackReason::Unauthenticated
+            }
+            AuthenticationError::Unavailable(_) => NackReason::Unavailable,
+        };
+        Self::nack(reason, error.to_string(), correlation_id)
+    }
+
+    pub(crate) fn from_rate_limit_error(error: &RateLimitError, correlation_id: Uuid) -> Self {
+        let reason = match error {
+            RateLimitError::Exhausted(_) => NackReason::RateLimited,
+            RateLimitError::Unavailable(_) => NackReason::Unavailable,
+        };
+        Self::nack(reason, error.to_string(), Some(correlation_id))
+    }
+}
+
+/// Decode a command and check it against its subject
+pub fn decode_command(subject: &str, payload: &[u8]) -> Result<InfrastructureCommand, CommandReply> {
+    let tokens: Vec<&str> = subject.split('.').collect();
+    let [root, "cmd", aggregate, command_name] = tokens.as_slice() else {
+        return Err(CommandReply::nack(
+            NackReason::InvalidSubject,
+            format!("{} is not a command subject", subject),
+            None,
+        ));
+    };
+    if *root != crate::subjects::INFRASTRUCTURE_ROOT {
+        return Err(CommandReply::nack(
+            NackReason::InvalidSubject,
+            format!("{} is outside the infrastructure namespace", subject),
+            None,
+        ));
+    }
+
+    let command: InfrastructureCommand = serde_json::from_slice(payload)
+        .map_err(|e| CommandReply::nack(NackReason::Malformed, e.to_string(), None))?;
+
+    if command.aggregate_type().to_string() != *aggregate || command.command_name() != *command_name
+    {
+        return Err(CommandReply::nack(
+            NackReason::InvalidSubject,
+            format!(
+                "payload is {} but subject is {}",
+                command.subject(),
+                subject
+            ),
+            Some(command.correlation_id()),
+        ));
+    }
+
+    Ok(command)
+}
+
+/// Subscribes to command subjects and routes commands to the service layer
+pub struct CommandSubscriber<S> {
+    client: NatsClient,
+    service: Arc<S>,
+    authenticator: Option<Arc<dyn Authenticator>>,
+    limiter: Option<RateLimiter>,
+}
+
+impl<S: ComputeResourceService> CommandSubscriber<S> {
+    /// Create a subscriber routing to `service`
+    pub fn new(client: NatsClient, service: Arc<S>) -> Self {
+        Self {
+            client,
+            service,
+            authenticator: None,
+            limiter: None,
+        }
+    }
+
+    /// Run each command as the sender `authenticator` identifies from the
+    /// request's `Authorization` header, refusing requests it cannot identify
+    ///
+    /// Without one, commands run as the service's own envelope.
+    pub fn with_authenticator(mut self, authenticator: Arc<dyn Authenticator>) -> Self {
+        self.authenticator = Some(authenticator);
+        self
+    }
+
+    /// Refuse commands of organizations over their rate
+    pub fn with_rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.limiter = Some(limiter);
+        self
+    }
+
+    /// Handle one command message and produce its reply
+    pub async fn handle(&self, subject: &str, payload: &[u8]) -> CommandReply {
+        let command = match decode_command(subject, payload) {
+            Ok(command) => command,
+            Err(nack) => return nack,
+        };
+
+        let correlation_id = command.correlation_id();
+        if let Some(limiter) = &self.limiter {
+            let tenant = EventEnvelopeMetadata::current().and_then(|envelope| envelope.tenant);
+            if let Err(e) = limiter.check(tenant.as_deref(), correlation_id).await {
+                warn!("Command {} throttled: {}", correlation_id, e);
+                return CommandReply::from_rate_limit_error(&e, correlation_id);
+            }
+        }
+        debug!("Executing {} ({})", command.command_name(), correlation_id);
+
+        let result = match command {
+            InfrastructureCommand::ComputeResource {
+                aggregate_id,
+                command,
+            } => self.service.execute(aggregate_id, command).await,
+        };
+
+        match result {
+            Ok(aggregate_id) => CommandReply::Ack {
+                aggregate_id,
+                correlation_id,
+            },
+            Err(e) => {
+                warn!("Command {} rejected: {}", correlation_id, e);
+                CommandReply::from_service_error(&e, correlation_id)
+            }
+        }
+    }
+
+    /// Process commands until the subscription ends
+    ///
+    /// Commands are handled one at a time, in arrival order.
+    pub async fn run(&self) -> InfrastructureResult<()> {
+        use futures::StreamExt;
+
+        let filter = subjects::all_commands();
+        let mut subscriber = self.client.subscribe(&filter).await?;
+        info!("Command bus listening on {}", filter);
+
+        while let Some(message) = subscriber.next().await {
+            let reply = match self.sender(&message).await {
+                Ok(Some(envelope)) => {
+                    envelope
+                        .scope(self.handle(message.subject.as_ref(), &message.payload))
+                        .await
+                }
+                Ok(None) => {
+                    self.handle(message.subject.as_ref(), &message.payload)
+                        .await
+                }
+                Err(nack) => nack,
+            };
+
+            let Some(reply_to) = message.reply else {
+                debug!("Command on {} had no reply subject", message.subject);
+                continue;
+            };
+
+            let payload = serde_json::to_vec(&reply)
+                .map_err(|e| InfrastructureError::Serialization(e.to_string()))?;
+            self.client
+                .inner()
+                .publish(reply_to, payload.into())
+                .await
+                .map_err(|e| InfrastructureError::NatsPublish(e.to_string()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Envelope of the sender of `message`, if commands are authenticated
+    async fn sender(
+        &self,
+        message: &async_nats::Message,
+    ) -> Result<Option<EventEnvelopeMetadata>, CommandReply> {
+        let Some(authenticator) = &self.authenticator else {
+            return Ok(None);
+        };
+        let token = message
+            .headers
+            .as_ref()
+            .and_then(|headers| headers.get(AUTHORIZATION_HEADER))
+            .and_then(|value| bearer_token(value.as_str()));
+
+        match authenticator.authenticate(token).await {
+            Ok(envelope) => Ok(Some(envelope)),
+            Err(e) => {
+                warn!("Command on {} not authenticated: {}", message.subject, e);
+                Err(CommandReply::from_authentication_error(&e, None))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aggregate::commands::AssignAssetTagCommand;
+    use chrono::{DateTime, Utc};
+
+    fn test_timestamp() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-19T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn asset_tag_command() -> InfrastructureCommand {
+        InfrastructureCommand::ComputeResource {
+            aggregate_id: Some(Uuid::now_v7()),
+            command: ComputeResourceCommand::AssignAssetTag(AssignAssetTagCommand {
+                asset_tag: "A-1001".to_string(),
+                timestamp: test_timestamp(),
+                correlation_id: Uuid::now_v7(),
+                causation_id: None,
+                change_ref: None,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_decode_matching_subject() {
+        let command = asset_tag_command();
+        let payload = serde_json::to_vec(&command).unwrap();
+
+        let decoded = decode_command("infrastructure.cmd.compute.assign_asset_tag", &payload);
+
+        assert_eq!(command.subject(), "infrastructure.cmd.compute.assign_asset_tag");
+        assert_eq!(decoded.unwrap(), command);
+    }
+
+    #[test]
+    fn test_decode_rejects_mismatch_and_garbage() {
+        let payload = serde_json::to_vec(&asset_tag_command()).unwrap();
+
+        let mismatch = decode_command("infrastructure.cmd.compute.assign_owner", &payload);
+        let garbage = decode_command("infrastructure.cmd.compute.assign_owner", b"{}");
+        let not_command = decode_command("infrastructure.compute.x.registered", &payload);
+
+        assert!(matches!(
+            mismatch,
+            Err(CommandReply::Nack { reason: NackReason::InvalidSubject, correlation_id: Some(_), .. })
+        ));
+        assert!(matches!(
+            garbage,
+            Err(CommandReply::Nack { reason: NackReason::Malformed, .. })
+        ));
+        assert!(matches!(
+            not_command,
+            Err(CommandReply::Nack { reason: NackReason::InvalidSubject, .. })
+        ));
+    }
+
+    #[test]
+    fn test_service_errors_map_to_reasons() {
+        let reply = CommandReply::from_service_error(
+            &ServiceError::NotFound(Uuid::now_v7()),
+            Uuid::now_v7(),
+        );
+
+        let json = serde_json::to_value(&reply).unwrap();
+        assert_eq!(json["status"], "nack");
+        assert_eq!(json["reason"], "not_found");
+        assert!(!reply.is_ack());
+    }
+
+    #[test]
+    fn test_authentication_failures_map_to_reasons() {
+        let invalid = CommandReply::from_authentication_error(&AuthenticationError::Invalid, None);
+        let unavailable = CommandReply::from_authentication_error(
+            &AuthenticationError::Unavailable("directory down".to_string()),
+            None,
+        );
+
+        let json = serde_json::to_value(&invalid).unwrap();
+        assert_eq!(json["reason"], "unauthenticated");
+        assert!(matches!(
+            unavailable,
+            CommandReply::Nack {
+                reason: NackReason::Unavailable,
+                ..
+            }
+        ));
+    }
+}
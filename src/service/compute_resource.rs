@@ -28,16 +28,71 @@
 //! 5. Publish event to NATS
 //!
 //! If any step fails, the entire transaction fails.
+//!
+//! [`ComputeResourceService::execute_batch`] runs several commands as one
+//! transaction: all events are appended with a single concurrency check
+//! and published together. [`ComputeResourceService::commit`] does the same
+//! for a [`UnitOfWork`] spanning several resources.
+//!
+//! With [`EventSourcedComputeResourceService::with_write_queue`], commands
+//! to the same resource wait for each other instead of failing with a
+//! concurrency conflict; see [`WriteQueue`]. Across instances,
+//! [`EventSourcedComputeResourceService::with_leases`] serializes writers of
+//! one resource through per-aggregate leases; see [`LeaseManager`].
+//!
+//! Events are written with the service's
+//! [`EventEnvelopeMetadata`](crate::event_store::provenance::EventEnvelopeMetadata)
+//! (see [`EventSourcedComputeResourceService::with_envelope`]), completed
+//! by the envelope of the request being served.
+//!
+//! [`ComputeResourceService::list_resources`] pages through resources
+//! filtered by type, organization, status and metadata, served from a
+//! [registry projection](crate::projection::registry) when one is given
+//! with [`EventSourcedComputeResourceService::with_registry`].
+//!
+//! With [`EventSourcedComputeResourceService::with_lookup`], hostnames and
+//! asset tags stay unique across resources; see [`LookupIndex`].
+//! [`EventSourcedComputeResourceService::with_tenancy`] limits commands and
+//! reads to the organization the request acts for; see
+//! [`tenancy`](crate::tenancy). Every command is first put to the service's
+//! [`Authorizer`] (see
+//! [`EventSourcedComputeResourceService::with_authorizer`]).
 
 use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::warn;
 use uuid::Uuid;
 
 use crate::aggregate::commands::*;
+use crate::aggregate::compute_resource::apply_infrastructure_event;
 use crate::aggregate::handlers::*;
-use crate::aggregate::ComputeResourceState;
+use crate::aggregate::{apply_event, ComputeResourceState};
+use crate::change_control::{shared_change_ref, ChangeControlConfig, ChangeRef};
+use crate::conventions::{ConventionLinter, Severity};
+use crate::errors::InfrastructureError;
+use crate::event_store::provenance::EventEnvelopeMetadata;
+use crate::event_store::snapshot::{rebuild, SnapshotStore};
 use crate::event_store::{EventStore, NatsEventStore};
 use crate::events::{ComputeResourceEvent, InfrastructureEvent};
+use crate::jetstream::StoredEvent;
 use crate::nats::NatsClient;
+use crate::projection::registry::{
+    ResourceFilter, ResourcePage, ResourceRegistryHandle, ResourceRegistrySnapshot,
+};
+use crate::projection::timeline::{build_timeline, ResourceTimeline, TimelineGranularity};
+use crate::service::authorization::{
+    AllowAll, AuthorizationError, AuthorizationRequest, Authorizer,
+};
+use crate::service::lease::{Lease, LeaseError, LeaseManager};
+use crate::service::lookup::{
+    find_in_events, LookupChanges, LookupError, LookupField, LookupIndex,
+};
+use crate::service::retry::RetryOnConflict;
+use crate::service::unit_of_work::{CommittedUnit, UnitOfWork};
+use crate::service::write_queue::{WriteQueue, WriteQueueError, WriteTurn};
+use crate::subjects::{AggregateType, SubjectBuilder};
+use crate::tenancy::{TenancyConfig, TenancyError};
 
 /// Service layer result type
 pub type ServiceResult<T> = Result<T, ServiceError>;
@@ -68,6 +123,31 @@ pub enum ServiceError {
     /// Business rule violation
     #[error("Business rule violation: {0}")]
     BusinessRuleViolation(String),
+
+    /// The command did not get its turn in the write queue
+    #[error("Write queue: {0}")]
+    WriteQueue(#[from] WriteQueueError),
+
+    /// The command did not get the aggregate's lease
+    #[error("Lease: {0}")]
+    Lease(#[from] LeaseError),
+
+    /// The authorizer did not allow the command
+    #[error("Unauthorized: {0}")]
+    Unauthorized(#[from] AuthorizationError),
+
+    /// A hostname or asset tag is taken, or the lookup index failed
+    #[error("Lookup: {0}")]
+    Lookup(#[from] LookupError),
+
+    /// A unit of work stored some aggregates before failing on another
+    #[error("Unit of work partially committed ({} aggregates stored): {message}", committed.len())]
+    PartiallyCommitted {
+        /// Aggregates whose events were stored
+        committed: Vec<Uuid>,
+        /// Why the next aggregate failed
+        message: String,
+    },
 }
 
 /// ComputeResource service trait
@@ -84,6 +164,16 @@ pub trait ComputeResourceService: Send + Sync {
     /// - Aggregate ID of the new resource
     async fn register_resource(&self, command: RegisterResourceCommand) -> ServiceResult<Uuid>;
 
+    /// Register a new compute resource under an ID chosen by the caller
+    ///
+    /// Fails with [`CommandError::AlreadyInitialized`] if the ID is taken,
+    /// so retrying a registration never creates a second resource.
+    async fn register_resource_as(
+        &self,
+        aggregate_id: Uuid,
+        command: RegisterResourceCommand,
+    ) -> ServiceResult<()>;
+
     /// Assign organization to a resource
     async fn assign_organization(
         &self,
@@ -161,6 +251,27 @@ pub trait ComputeResourceService: Send + Sync {
         command: ChangeStatusCommand,
     ) -> ServiceResult<()>;
 
+    /// Record that a configuration backup of the device was taken
+    async fn record_configuration_backup(
+        &self,
+        aggregate_id: Uuid,
+        command: RecordConfigurationBackupCommand,
+    ) -> ServiceResult<()>;
+
+    /// Place a virtual machine or container host on this host
+    async fn attach_guest(
+        &self,
+        aggregate_id: Uuid,
+        command: AttachGuestCommand,
+    ) -> ServiceResult<()>;
+
+    /// Remove a guest from this host
+    async fn detach_guest(
+        &self,
+        aggregate_id: Uuid,
+        command: DetachGuestCommand,
+    ) -> ServiceResult<()>;
+
     /// Get current state of a resource
     ///
     /// # Parameters
@@ -172,6 +283,152 @@ pub trait ComputeResourceService: Send + Sync {
 
     /// Check if resource exists
     async fn exists(&self, aggregate_id: Uuid) -> ServiceResult<bool>;
+
+    /// Get the bucketed event timeline of a resource
+    ///
+    /// # Parameters
+    /// - `aggregate_id`: ID of the resource
+    /// - `granularity`: Day or week buckets
+    ///
+    /// # Returns
+    /// - Timeline with per-bucket event type counts and notable transitions
+    async fn get_timeline(
+        &self,
+        aggregate_id: Uuid,
+        granularity: TimelineGranularity,
+    ) -> ServiceResult<ResourceTimeline>;
+
+    /// List resources matching a filter, one page at a time
+    ///
+    /// # Parameters
+    /// - `filter`: Resource type, organization, status and metadata to match
+    /// - `page`: Page number, from 1
+    /// - `page_size`: Resources per page, capped at
+    ///   [`MAX_PAGE_SIZE`](crate::projection::registry::MAX_PAGE_SIZE)
+    ///
+    /// # Returns
+    /// - Matching resources ordered by hostname, with totals across pages
+    async fn list_resources(
+        &self,
+        filter: &ResourceFilter,
+        page: u32,
+        page_size: u32,
+    ) -> ServiceResult<ResourcePage>;
+
+    /// List the resources of one organization, one page at a time
+    async fn list_resources_for_org(
+        &self,
+        organization_id: &str,
+        page: u32,
+        page_size: u32,
+    ) -> ServiceResult<ResourcePage> {
+        let filter = ResourceFilter::default().with_organization(organization_id);
+        self.list_resources(&filter, page, page_size).await
+    }
+
+    /// Find the resource registered under a hostname
+    ///
+    /// Hostnames are compared case-insensitively; decommissioned resources
+    /// are not found.
+    async fn find_by_hostname(&self, hostname: &str) -> ServiceResult<Option<Uuid>>;
+
+    /// Find the resource carrying an asset tag
+    ///
+    /// Decommissioned resources are not found.
+    async fn find_by_asset_tag(&self, asset_tag: &str) -> ServiceResult<Option<Uuid>>;
+
+    /// Execute any compute resource command
+    ///
+    /// Routes to the matching service method. For
+    /// [`ComputeResourceCommand::RegisterResource`] `aggregate_id` is the ID
+    /// of the new resource (generated when None); other commands require it.
+    ///
+    /// # Returns
+    /// - Aggregate ID the command was applied to
+    async fn execute(
+        &self,
+        aggregate_id: Option<Uuid>,
+        command: ComputeResourceCommand,
+    ) -> ServiceResult<Uuid> {
+        if let ComputeResourceCommand::RegisterResource(command) = command {
+            return match aggregate_id {
+                Some(aggregate_id) => {
+                    self.register_resource_as(aggregate_id, command).await?;
+                    Ok(aggregate_id)
+                }
+                None => self.register_resource(command).await,
+            };
+        }
+
+        let aggregate_id = aggregate_id.ok_or_else(|| {
+            ServiceError::BusinessRuleViolation(format!(
+                "{} requires an aggregate_id",
+                command.command_name()
+            ))
+        })?;
+
+        match command {
+            ComputeResourceCommand::RegisterResource(_) => unreachable!("handled above"),
+            ComputeResourceCommand::AssignOrganization(c) => {
+                self.assign_organization(aggregate_id, c).await?
+            }
+            ComputeResourceCommand::AssignLocation(c) => self.assign_location(aggregate_id, c).await?,
+            ComputeResourceCommand::AssignOwner(c) => self.assign_owner(aggregate_id, c).await?,
+            ComputeResourceCommand::AddPolicy(c) => self.add_policy(aggregate_id, c).await?,
+            ComputeResourceCommand::RemovePolicy(c) => self.remove_policy(aggregate_id, c).await?,
+            ComputeResourceCommand::AssignAccountConcept(c) => {
+                self.assign_account_concept(aggregate_id, c).await?
+            }
+            ComputeResourceCommand::ClearAccountConcept(c) => {
+                self.clear_account_concept(aggregate_id, c).await?
+            }
+            ComputeResourceCommand::SetHardwareDetails(c) => {
+                self.set_hardware_details(aggregate_id, c).await?
+            }
+            ComputeResourceCommand::AssignAssetTag(c) => self.assign_asset_tag(aggregate_id, c).await?,
+            ComputeResourceCommand::UpdateMetadata(c) => self.update_metadata(aggregate_id, c).await?,
+            ComputeResourceCommand::ChangeStatus(c) => self.change_status(aggregate_id, c).await?,
+            ComputeResourceCommand::RecordConfigurationBackup(c) => {
+                self.record_configuration_backup(aggregate_id, c).await?
+            }
+            ComputeResourceCommand::AttachGuest(c) => self.attach_guest(aggregate_id, c).await?,
+            ComputeResourceCommand::DetachGuest(c) => self.detach_guest(aggregate_id, c).await?,
+        }
+
+        Ok(aggregate_id)
+    }
+
+    /// Execute several commands against one aggregate as a single transaction
+    ///
+    /// Commands are handled in order, each against the state produced by
+    /// the commands before it. The resulting events are appended with one
+    /// optimistic concurrency check and published together; if any command
+    /// is rejected nothing is stored.
+    ///
+    /// With `aggregate_id` None the batch must start with
+    /// [`ComputeResourceCommand::RegisterResource`] and creates a resource.
+    ///
+    /// # Returns
+    /// - Aggregate ID the commands were applied to
+    async fn execute_batch(
+        &self,
+        aggregate_id: Option<Uuid>,
+        commands: Vec<ComputeResourceCommand>,
+    ) -> ServiceResult<Uuid>;
+
+    /// Commit a unit of work spanning one or more resources
+    ///
+    /// Every command is validated against one snapshot of its target before
+    /// anything is written; see [`UnitOfWork`] for the commit sequence.
+    async fn commit(&self, unit: UnitOfWork) -> ServiceResult<CommittedUnit>;
+}
+
+/// Right to write one aggregate, held until dropped
+///
+/// The lease is released before the local turn is handed on.
+struct Turn {
+    _lease: Option<Lease>,
+    _queued: Option<WriteTurn>,
 }
 
 /// Event-sourced implementation of ComputeResourceService
@@ -183,6 +440,36 @@ pub struct EventSourcedComputeResourceService {
 
     /// NATS client for publishing
     nats_client: NatsClient,
+
+    /// Naming and metadata conventions checked on every command
+    conventions: Option<Arc<ConventionLinter>>,
+
+    /// Change request requirements checked on every command
+    change_control: Option<ChangeControlConfig>,
+
+    /// Serializes local commands to the same aggregate
+    write_queue: Option<WriteQueue>,
+
+    /// Serializes commands to the same aggregate across instances
+    leases: Option<LeaseManager>,
+
+    /// Snapshots that state is rebuilt from
+    snapshots: Option<SnapshotStore>,
+
+    /// Provenance recorded on every event written
+    envelope: Option<EventEnvelopeMetadata>,
+
+    /// Registry projection that listings are served from
+    registry: Option<ResourceRegistryHandle>,
+
+    /// Unique hostname and asset tag index
+    lookup: Option<LookupIndex>,
+
+    /// Organization checks on commands and reads
+    tenancy: Option<TenancyConfig>,
+
+    /// Asked before every command
+    authorizer: Arc<dyn Authorizer>,
 }
 
 impl EventSourcedComputeResourceService {
@@ -191,66 +478,419 @@ impl EventSourcedComputeResourceService {
         Self {
             event_store,
             nats_client,
+            conventions: None,
+            change_control: None,
+            write_queue: None,
+            leases: None,
+            snapshots: None,
+            envelope: None,
+            registry: None,
+            lookup: None,
+            tenancy: None,
+            authorizer: Arc::new(AllowAll),
+        }
+    }
+
+    /// Check naming and metadata conventions on every command
+    ///
+    /// Commands that introduce error-severity violations are rejected with
+    /// [`ServiceError::BusinessRuleViolation`]; warnings are logged.
+    pub fn with_conventions(mut self, linter: Arc<ConventionLinter>) -> Self {
+        self.conventions = Some(linter);
+        self
+    }
+
+    /// Require change references as configured
+    ///
+    /// Commands against production resources without a `change_ref` are
+    /// rejected with [`ServiceError::BusinessRuleViolation`]. Change
+    /// references are recorded on events with or without this setting.
+    pub fn with_change_control(mut self, config: ChangeControlConfig) -> Self {
+        self.change_control = Some(config);
+        self
+    }
+
+    /// Make concurrent commands to one aggregate take turns
+    ///
+    /// Commands wait for earlier commands to the same aggregate to finish
+    /// instead of failing with [`ServiceError::ConcurrencyConflict`]; a
+    /// command that cannot get its turn fails with
+    /// [`ServiceError::WriteQueue`].
+    pub fn with_write_queue(mut self, queue: WriteQueue) -> Self {
+        self.write_queue = Some(queue);
+        self
+    }
+
+    /// Hold a per-aggregate lease while handling each command
+    ///
+    /// Instances sharing the lease bucket take turns on an aggregate
+    /// instead of failing with [`ServiceError::ConcurrencyConflict`]; a
+    /// command that cannot get the lease fails with [`ServiceError::Lease`].
+    /// Combined with a write queue, local commands queue first so only one
+    /// of them waits for the lease.
+    pub fn with_leases(mut self, leases: LeaseManager) -> Self {
+        self.leases = Some(leases);
+        self
+    }
+
+    /// Rebuild state from snapshots and the events after them
+    ///
+    /// Required once compute resources are
+    /// [compacted](crate::event_store::compaction), since the events a
+    /// snapshot covers may be gone.
+    pub fn with_snapshots(mut self, snapshots: SnapshotStore) -> Self {
+        self.snapshots = Some(snapshots);
+        self
+    }
+
+    /// Record provenance (source system, schema, tenant) on every event
+    ///
+    /// Fields set by the request's envelope
+    /// ([`EventEnvelopeMetadata::scope`]) take precedence.
+    pub fn with_envelope(mut self, envelope: EventEnvelopeMetadata) -> Self {
+        self.envelope = Some(envelope);
+        self
+    }
+
+    /// Serve listings from a running [registry](crate::projection::registry)
+    ///
+    /// Without one, every listing replays all compute resource events.
+    pub fn with_registry(mut self, registry: ResourceRegistryHandle) -> Self {
+        self.registry = Some(registry);
+        self
+    }
+
+    /// Keep hostnames and asset tags unique through a lookup index
+    ///
+    /// Commands that would give a resource a hostname or asset tag held by
+    /// another fail with [`ServiceError::Lookup`]; lookups are answered
+    /// from the index instead of replaying events.
+    pub fn with_lookup(mut self, lookup: LookupIndex) -> Self {
+        self.lookup = Some(lookup);
+        self
+    }
+
+    /// Limit commands and reads to the acting organization
+    ///
+    /// The acting organization is the `tenant` of the request's envelope
+    /// ([`EventEnvelopeMetadata::scope`]), or of the service's. Commands and
+    /// reads against other organizations' resources fail with
+    /// [`ServiceError::NotFound`], before the command is authorized or
+    /// handled; commands handing a resource to another organization fail
+    /// with [`ServiceError::BusinessRuleViolation`].
+    pub fn with_tenancy(mut self, config: TenancyConfig) -> Self {
+        self.tenancy = Some(config);
+        self
+    }
+
+    /// Ask `authorizer` before every command
+    ///
+    /// The actor is taken from the request's envelope, or the service's.
+    /// Commands it does not allow fail with [`ServiceError::Unauthorized`].
+    /// Without one, every command is allowed.
+    pub fn with_authorizer(mut self, authorizer: Arc<dyn Authorizer>) -> Self {
+        self.authorizer = authorizer;
+        self
+    }
+
+    /// Retry commands up to `retries` times after a concurrency conflict
+    ///
+    /// Each retry reloads the resource and handles the command again; see
+    /// [`RetryOnConflict`]. Configure the service before wrapping it.
+    pub fn with_retry_on_conflict(self, retries: u32) -> RetryOnConflict<Self> {
+        RetryOnConflict::new(self, retries)
+    }
+
+    /// Wait for the turn to write an aggregate when queueing or leases are
+    /// enabled
+    async fn wait_turn(&self, aggregate_id: Uuid) -> ServiceResult<Turn> {
+        let queued = match &self.write_queue {
+            Some(queue) => Some(queue.acquire(aggregate_id).await?),
+            None => None,
+        };
+        let lease = match &self.leases {
+            Some(leases) => Some(leases.acquire(aggregate_id).await?),
+            None => None,
+        };
+        Ok(Turn {
+            _lease: lease,
+            _queued: queued,
+        })
+    }
+
+    /// Reject a transition that introduces error-severity convention violations
+    fn check_conventions(
+        &self,
+        before: &ComputeResourceState,
+        after: &ComputeResourceState,
+    ) -> ServiceResult<()> {
+        let Some(linter) = &self.conventions else {
+            return Ok(());
+        };
+
+        let (errors, warnings): (Vec<_>, Vec<_>) = linter
+            .check_transition(before, after)
+            .into_iter()
+            .partition(|v| v.severity == Severity::Error);
+
+        for violation in &warnings {
+            warn!("Convention warning: {}", violation);
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ServiceError::BusinessRuleViolation(
+                errors
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join("; "),
+            ))
         }
     }
 
-    /// Load current state from event store
-    async fn load_state(&self, aggregate_id: Uuid) -> ServiceResult<ComputeResourceState> {
+    /// Load current state from event store, with the version it was folded
+    /// up to (0 if the aggregate has no events)
+    ///
+    /// Commands append with that version as their expected version, so a
+    /// write that lands after the state was read is a conflict.
+    async fn load_state(&self, aggregate_id: Uuid) -> ServiceResult<(ComputeResourceState, u64)> {
+        if let Some(snapshots) = &self.snapshots {
+            let snapshot = snapshots
+                .load::<ComputeResourceState>(aggregate_id)
+                .await
+                .map_err(|e| ServiceError::EventStoreError(e.to_string()))?;
+            let from_version = snapshot.as_ref().map_or(1, |s| s.version + 1);
+            let stored_events = self
+                .event_store
+                .read_events_from(aggregate_id, from_version)
+                .await
+                .map_err(|e| ServiceError::EventStoreError(e.to_string()))?;
+            let version = last_sequence(&stored_events).max(from_version - 1);
+            return Ok((rebuild(aggregate_id, snapshot, &stored_events), version));
+        }
+
         let stored_events = self
             .event_store
             .read_events(aggregate_id)
             .await
             .map_err(|e| ServiceError::EventStoreError(e.to_string()))?;
+        let version = last_sequence(&stored_events);
 
         // Extract ComputeResourceEvent from StoredEvent<InfrastructureEvent>
         let events: Vec<ComputeResourceEvent> = stored_events
             .into_iter()
-            .map(|stored| {
-                // Currently only ComputeResource events exist
-                let InfrastructureEvent::ComputeResource(event) = stored.data;
-                event
+            .filter_map(|stored| match stored.data {
+                InfrastructureEvent::ComputeResource(event) => Some(event),
+                _ => None,
             })
             .collect();
 
-        Ok(ComputeResourceState::from_events(&events))
+        Ok((ComputeResourceState::from_events(&events), version))
     }
 
-    /// Append event and publish to NATS
+    /// Current version of an aggregate, 0 if it has no events
+    async fn current_version(&self, aggregate_id: Uuid) -> ServiceResult<u64> {
+        Ok(self
+            .event_store
+            .get_version(aggregate_id)
+            .await
+            .map_err(|e| ServiceError::EventStoreError(e.to_string()))?
+            .unwrap_or(0))
+    }
+
+    /// Ask the authorizer whether the request's actor may run `command`
+    /// against `state`
+    async fn authorize(&self, command: &str, state: &ComputeResourceState) -> ServiceResult<()> {
+        let envelope = EventEnvelopeMetadata::resolve(self.envelope.as_ref());
+        let request = AuthorizationRequest {
+            actor: envelope
+                .as_ref()
+                .and_then(|envelope| envelope.actor.as_ref()),
+            aggregate_type: AggregateType::Compute,
+            command,
+            aggregate_id: state.id,
+            policy_ids: &state.policy_ids,
+        };
+        Ok(self.authorizer.authorize(&request).await?)
+    }
+
+    /// Organization the current request acts for
+    fn acting_organization(&self) -> Option<String> {
+        EventEnvelopeMetadata::resolve(self.envelope.as_ref()).and_then(|envelope| envelope.tenant)
+    }
+
+    /// Hide another organization's resource from the current request
+    fn check_read(&self, state: &ComputeResourceState) -> ServiceResult<()> {
+        match &self.tenancy {
+            Some(config) => config
+                .check_read(self.acting_organization().as_deref(), state)
+                .map_err(|e| match e {
+                    TenancyError::ForeignResource { .. } => ServiceError::NotFound(state.id),
+                    other => ServiceError::BusinessRuleViolation(other.to_string()),
+                }),
+            None => Ok(()),
+        }
+    }
+
+    /// `aggregate_id`, unless the current request may not see the resource
+    async fn visible(&self, aggregate_id: Option<Uuid>) -> ServiceResult<Option<Uuid>> {
+        let Some(aggregate_id) = aggregate_id else {
+            return Ok(None);
+        };
+        if self.tenancy.is_none() {
+            return Ok(Some(aggregate_id));
+        }
+        let (state, _) = self.load_state(aggregate_id).await?;
+        match self.check_read(&state) {
+            Ok(()) => Ok(Some(aggregate_id)),
+            Err(ServiceError::NotFound(_)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Reject a transition handing a resource to another organization
+    ///
+    /// `before` has passed [`Self::check_read`] when it was loaded, so only
+    /// the assignment of `after` is left to reject.
+    fn check_tenancy(
+        &self,
+        before: &ComputeResourceState,
+        after: &ComputeResourceState,
+    ) -> ServiceResult<()> {
+        match &self.tenancy {
+            Some(config) => config
+                .check(self.acting_organization().as_deref(), before, after)
+                .map_err(|e| match e {
+                    TenancyError::ForeignResource { .. } => ServiceError::NotFound(before.id),
+                    other => ServiceError::BusinessRuleViolation(other.to_string()),
+                }),
+            None => Ok(()),
+        }
+    }
+
+    /// Reject a production change without a change reference
+    fn check_change_control(
+        &self,
+        before: &ComputeResourceState,
+        after: &ComputeResourceState,
+        change_ref: Option<&ChangeRef>,
+    ) -> ServiceResult<()> {
+        match &self.change_control {
+            Some(config) => config
+                .check(before, after, change_ref)
+                .map_err(|e| ServiceError::BusinessRuleViolation(e.to_string())),
+            None => Ok(()),
+        }
+    }
+
+    /// Check conventions, append event and publish to NATS
     async fn append_and_publish(
         &self,
+        state: &ComputeResourceState,
         aggregate_id: Uuid,
         event: ComputeResourceEvent,
         expected_version: Option<u64>,
+        change_ref: Option<&ChangeRef>,
     ) -> ServiceResult<()> {
-        // Append to event store
-        self.event_store
-            .append(
+        self.append_all_and_publish(
+            state,
+            aggregate_id,
+            vec![event],
+            expected_version,
+            change_ref,
+        )
+        .await
+    }
+
+    /// Check conventions, append events atomically and publish them to NATS
+    ///
+    /// Conventions are checked against the state after the whole batch, so
+    /// intermediate states may be incomplete (e.g. registered but not yet
+    /// located). Events are only published once the batch is stored.
+    async fn append_all_and_publish(
+        &self,
+        state: &ComputeResourceState,
+        aggregate_id: Uuid,
+        events: Vec<ComputeResourceEvent>,
+        expected_version: Option<u64>,
+        change_ref: Option<&ChangeRef>,
+    ) -> ServiceResult<()> {
+        let mut lookup = None;
+        if self.conventions.is_some()
+            || self.change_control.is_some()
+            || self.lookup.is_some()
+            || self.tenancy.is_some()
+        {
+            let after = events
+                .iter()
+                .fold(state.clone(), |current, event| apply_event(current, event));
+            self.check_tenancy(state, &after)?;
+            self.check_conventions(state, &after)?;
+            self.check_change_control(state, &after, change_ref)?;
+            lookup = self
+                .lookup
+                .as_ref()
+                .map(|index| (index, LookupChanges::between(state, &after)));
+        }
+
+        // Claim new hostnames and asset tags before anything is stored
+        if let Some((index, changes)) = &lookup {
+            index.claim_all(aggregate_id, &changes.claimed).await?;
+        }
+
+        // Append to event store (one concurrency check for the batch)
+        let envelope = EventEnvelopeMetadata::resolve(self.envelope.as_ref());
+        let appended = self
+            .event_store
+            .append_with_context(
                 aggregate_id,
-                vec![InfrastructureEvent::ComputeResource(event.clone())],
+                events
+                    .iter()
+                    .cloned()
+                    .map(InfrastructureEvent::ComputeResource)
+                    .collect(),
                 expected_version,
+                change_ref,
+                envelope.as_ref(),
             )
-            .await
-            .map_err(|e| ServiceError::EventStoreError(e.to_string()))?;
+            .await;
+        let appended = match appended {
+            Err(InfrastructureError::ConcurrencyError(_)) => {
+                let expected = expected_version.unwrap_or(0);
+                let actual = self.current_version(aggregate_id).await.unwrap_or(expected);
+                Err(ServiceError::ConcurrencyConflict { expected, actual })
+            }
+            appended => appended.map_err(|e| ServiceError::EventStoreError(e.to_string())),
+        };
+
+        if let Some((index, changes)) = &lookup {
+            match &appended {
+                Ok(_) => index.release_all(aggregate_id, &changes.released).await,
+                Err(_) => index.release_all(aggregate_id, &changes.claimed).await,
+            }
+        }
+        appended?;
 
         // Publish to NATS for projections
-        self.publish_event(&event)
-            .await
-            .map_err(|e| ServiceError::NatsError(e))?;
+        for event in &events {
+            self.publish_event(event)
+                .await
+                .map_err(|e| ServiceError::NatsError(e))?;
+        }
 
         Ok(())
     }
 
     /// Publish event to NATS
     async fn publish_event(&self, event: &ComputeResourceEvent) -> Result<(), String> {
-        // Serialize event
-        let payload = serde_json::to_vec(event).map_err(|e| format!("Serialization error: {}", e))?;
-
         // Determine subject based on event type
         let subject = self.event_subject(event);
 
-        // Publish to NATS
+        // Publish to NATS (the client serializes the event)
         self.nats_client
-            .publish(&subject, &payload)
+            .publish(&subject, event)
             .await
             .map_err(|e| format!("NATS publish error: {}", e))?;
 
@@ -259,24 +899,26 @@ impl EventSourcedComputeResourceService {
 
     /// Get NATS subject for event
     fn event_subject(&self, event: &ComputeResourceEvent) -> String {
-        use crate::events::compute_resource::ComputeResourceEvent::*;
-
-        let event_type = match event {
-            ResourceRegistered(_) => "registered",
-            OrganizationAssigned(_) => "organization_assigned",
-            LocationAssigned(_) => "location_assigned",
-            OwnerAssigned(_) => "owner_assigned",
-            PolicyAdded(_) => "policy_added",
-            PolicyRemoved(_) => "policy_removed",
-            AccountConceptAssigned(_) => "account_concept_assigned",
-            AccountConceptCleared(_) => "account_concept_cleared",
-            HardwareDetailsSet(_) => "hardware_details_set",
-            AssetTagAssigned(_) => "asset_tag_assigned",
-            MetadataUpdated(_) => "metadata_updated",
-            StatusChanged(_) => "status_changed",
-        };
+        SubjectBuilder::new()
+            .aggregate(AggregateType::Compute)
+            .aggregate_id(event.aggregate_id())
+            .operation(event.operation())
+            .build()
+    }
+
+    /// Resource holding a hostname or asset tag, from the lookup index when
+    /// one is configured
+    async fn find(&self, field: LookupField, value: &str) -> ServiceResult<Option<Uuid>> {
+        if let Some(lookup) = &self.lookup {
+            return Ok(lookup.find(field, value).await?);
+        }
 
-        format!("infrastructure.compute.{}.{}", event.aggregate_id(), event_type)
+        let events = self
+            .event_store
+            .read_aggregate_type(AggregateType::Compute)
+            .await
+            .map_err(|e| ServiceError::EventStoreError(e.to_string()))?;
+        Ok(find_in_events(&events, field, value))
     }
 }
 
@@ -288,44 +930,79 @@ impl ComputeResourceService for EventSourcedComputeResourceService {
 
         // Handle command (pure function)
         let initial_state = ComputeResourceState::default_for(aggregate_id);
+        self.authorize("register_resource", &initial_state).await?;
+
+        let change_ref = command.change_ref.clone();
         let event = handle_register_resource(&initial_state, command, aggregate_id)?;
 
         // Append and publish
-        self.append_and_publish(aggregate_id, ComputeResourceEvent::ResourceRegistered(event), None)
-            .await?;
+        self.append_and_publish(
+            &initial_state,
+            aggregate_id,
+            ComputeResourceEvent::ResourceRegistered(event),
+            None,
+            change_ref.as_ref(),
+        )
+        .await?;
 
         Ok(aggregate_id)
     }
 
+    async fn register_resource_as(
+        &self,
+        aggregate_id: Uuid,
+        command: RegisterResourceCommand,
+    ) -> ServiceResult<()> {
+        let _turn = self.wait_turn(aggregate_id).await?;
+
+        let (state, _) = self.load_state(aggregate_id).await?;
+        self.check_read(&state)?;
+        self.authorize("register_resource", &state).await?;
+
+        let change_ref = command.change_ref.clone();
+        let event = handle_register_resource(&state, command, aggregate_id)?;
+
+        // Expecting an empty stream closes the race with a concurrent
+        // registration under the same ID
+        self.append_and_publish(
+            &state,
+            aggregate_id,
+            ComputeResourceEvent::ResourceRegistered(event),
+            Some(0),
+            change_ref.as_ref(),
+        )
+        .await
+    }
+
     async fn assign_organization(
         &self,
         aggregate_id: Uuid,
         command: AssignOrganizationCommand,
     ) -> ServiceResult<()> {
+        let _turn = self.wait_turn(aggregate_id).await?;
+
         // Load current state
-        let state = self.load_state(aggregate_id).await?;
+        let (state, version) = self.load_state(aggregate_id).await?;
+        self.check_read(&state)?;
 
         // Check if initialized
         if !state.is_initialized() {
             return Err(ServiceError::NotFound(aggregate_id));
         }
 
+        self.authorize("assign_organization", &state).await?;
+
         // Handle command
+        let change_ref = command.change_ref.clone();
         let event = handle_assign_organization(&state, command)?;
 
-        // Get current version
-        let version = self
-            .event_store
-            .get_version(aggregate_id)
-            .await
-            .map_err(|e| ServiceError::EventStoreError(e.to_string()))?
-            .unwrap_or(0);
-
         // Append and publish
         self.append_and_publish(
+            &state,
             aggregate_id,
             ComputeResourceEvent::OrganizationAssigned(event),
             Some(version),
+            change_ref.as_ref(),
         )
         .await?;
 
@@ -337,23 +1014,26 @@ impl ComputeResourceService for EventSourcedComputeResourceService {
         aggregate_id: Uuid,
         command: AssignLocationCommand,
     ) -> ServiceResult<()> {
-        let state = self.load_state(aggregate_id).await?;
+        let _turn = self.wait_turn(aggregate_id).await?;
+
+        let (state, version) = self.load_state(aggregate_id).await?;
+        self.check_read(&state)?;
         if !state.is_initialized() {
             return Err(ServiceError::NotFound(aggregate_id));
         }
 
+        self.authorize("assign_location", &state).await?;
+
+        let change_ref = command.change_ref.clone();
+
         let event = handle_assign_location(&state, command)?;
-        let version = self
-            .event_store
-            .get_version(aggregate_id)
-            .await
-            .map_err(|e| ServiceError::EventStoreError(e.to_string()))?
-            .unwrap_or(0);
 
         self.append_and_publish(
+            &state,
             aggregate_id,
             ComputeResourceEvent::LocationAssigned(event),
             Some(version),
+            change_ref.as_ref(),
         )
         .await?;
 
@@ -365,21 +1045,28 @@ impl ComputeResourceService for EventSourcedComputeResourceService {
         aggregate_id: Uuid,
         command: AssignOwnerCommand,
     ) -> ServiceResult<()> {
-        let state = self.load_state(aggregate_id).await?;
+        let _turn = self.wait_turn(aggregate_id).await?;
+
+        let (state, version) = self.load_state(aggregate_id).await?;
+        self.check_read(&state)?;
         if !state.is_initialized() {
             return Err(ServiceError::NotFound(aggregate_id));
         }
 
+        self.authorize("assign_owner", &state).await?;
+
+        let change_ref = command.change_ref.clone();
+
         let event = handle_assign_owner(&state, command)?;
-        let version = self
-            .event_store
-            .get_version(aggregate_id)
-            .await
-            .map_err(|e| ServiceError::EventStoreError(e.to_string()))?
-            .unwrap_or(0);
 
-        self.append_and_publish(aggregate_id, ComputeResourceEvent::OwnerAssigned(event), Some(version))
-            .await?;
+        self.append_and_publish(
+            &state,
+            aggregate_id,
+            ComputeResourceEvent::OwnerAssigned(event),
+            Some(version),
+            change_ref.as_ref(),
+        )
+        .await?;
 
         Ok(())
     }
@@ -389,21 +1076,28 @@ impl ComputeResourceService for EventSourcedComputeResourceService {
         aggregate_id: Uuid,
         command: AddPolicyCommand,
     ) -> ServiceResult<()> {
-        let state = self.load_state(aggregate_id).await?;
+        let _turn = self.wait_turn(aggregate_id).await?;
+
+        let (state, version) = self.load_state(aggregate_id).await?;
+        self.check_read(&state)?;
         if !state.is_initialized() {
             return Err(ServiceError::NotFound(aggregate_id));
         }
 
+        self.authorize("add_policy", &state).await?;
+
+        let change_ref = command.change_ref.clone();
+
         let event = handle_add_policy(&state, command)?;
-        let version = self
-            .event_store
-            .get_version(aggregate_id)
-            .await
-            .map_err(|e| ServiceError::EventStoreError(e.to_string()))?
-            .unwrap_or(0);
 
-        self.append_and_publish(aggregate_id, ComputeResourceEvent::PolicyAdded(event), Some(version))
-            .await?;
+        self.append_and_publish(
+            &state,
+            aggregate_id,
+            ComputeResourceEvent::PolicyAdded(event),
+            Some(version),
+            change_ref.as_ref(),
+        )
+        .await?;
 
         Ok(())
     }
@@ -413,21 +1107,28 @@ impl ComputeResourceService for EventSourcedComputeResourceService {
         aggregate_id: Uuid,
         command: RemovePolicyCommand,
     ) -> ServiceResult<()> {
-        let state = self.load_state(aggregate_id).await?;
+        let _turn = self.wait_turn(aggregate_id).await?;
+
+        let (state, version) = self.load_state(aggregate_id).await?;
+        self.check_read(&state)?;
         if !state.is_initialized() {
             return Err(ServiceError::NotFound(aggregate_id));
         }
 
+        self.authorize("remove_policy", &state).await?;
+
+        let change_ref = command.change_ref.clone();
+
         let event = handle_remove_policy(&state, command)?;
-        let version = self
-            .event_store
-            .get_version(aggregate_id)
-            .await
-            .map_err(|e| ServiceError::EventStoreError(e.to_string()))?
-            .unwrap_or(0);
 
-        self.append_and_publish(aggregate_id, ComputeResourceEvent::PolicyRemoved(event), Some(version))
-            .await?;
+        self.append_and_publish(
+            &state,
+            aggregate_id,
+            ComputeResourceEvent::PolicyRemoved(event),
+            Some(version),
+            change_ref.as_ref(),
+        )
+        .await?;
 
         Ok(())
     }
@@ -437,23 +1138,26 @@ impl ComputeResourceService for EventSourcedComputeResourceService {
         aggregate_id: Uuid,
         command: AssignAccountConceptCommand,
     ) -> ServiceResult<()> {
-        let state = self.load_state(aggregate_id).await?;
+        let _turn = self.wait_turn(aggregate_id).await?;
+
+        let (state, version) = self.load_state(aggregate_id).await?;
+        self.check_read(&state)?;
         if !state.is_initialized() {
             return Err(ServiceError::NotFound(aggregate_id));
         }
 
+        self.authorize("assign_account_concept", &state).await?;
+
+        let change_ref = command.change_ref.clone();
+
         let event = handle_assign_account_concept(&state, command)?;
-        let version = self
-            .event_store
-            .get_version(aggregate_id)
-            .await
-            .map_err(|e| ServiceError::EventStoreError(e.to_string()))?
-            .unwrap_or(0);
 
         self.append_and_publish(
+            &state,
             aggregate_id,
             ComputeResourceEvent::AccountConceptAssigned(event),
             Some(version),
+            change_ref.as_ref(),
         )
         .await?;
 
@@ -465,23 +1169,26 @@ impl ComputeResourceService for EventSourcedComputeResourceService {
         aggregate_id: Uuid,
         command: ClearAccountConceptCommand,
     ) -> ServiceResult<()> {
-        let state = self.load_state(aggregate_id).await?;
+        let _turn = self.wait_turn(aggregate_id).await?;
+
+        let (state, version) = self.load_state(aggregate_id).await?;
+        self.check_read(&state)?;
         if !state.is_initialized() {
             return Err(ServiceError::NotFound(aggregate_id));
         }
 
+        self.authorize("clear_account_concept", &state).await?;
+
+        let change_ref = command.change_ref.clone();
+
         let event = handle_clear_account_concept(&state, command)?;
-        let version = self
-            .event_store
-            .get_version(aggregate_id)
-            .await
-            .map_err(|e| ServiceError::EventStoreError(e.to_string()))?
-            .unwrap_or(0);
 
         self.append_and_publish(
+            &state,
             aggregate_id,
             ComputeResourceEvent::AccountConceptCleared(event),
             Some(version),
+            change_ref.as_ref(),
         )
         .await?;
 
@@ -493,23 +1200,26 @@ impl ComputeResourceService for EventSourcedComputeResourceService {
         aggregate_id: Uuid,
         command: SetHardwareDetailsCommand,
     ) -> ServiceResult<()> {
-        let state = self.load_state(aggregate_id).await?;
+        let _turn = self.wait_turn(aggregate_id).await?;
+
+        let (state, version) = self.load_state(aggregate_id).await?;
+        self.check_read(&state)?;
         if !state.is_initialized() {
             return Err(ServiceError::NotFound(aggregate_id));
         }
 
+        self.authorize("set_hardware_details", &state).await?;
+
+        let change_ref = command.change_ref.clone();
+
         let event = handle_set_hardware_details(&state, command)?;
-        let version = self
-            .event_store
-            .get_version(aggregate_id)
-            .await
-            .map_err(|e| ServiceError::EventStoreError(e.to_string()))?
-            .unwrap_or(0);
 
         self.append_and_publish(
+            &state,
             aggregate_id,
             ComputeResourceEvent::HardwareDetailsSet(event),
             Some(version),
+            change_ref.as_ref(),
         )
         .await?;
 
@@ -521,23 +1231,26 @@ impl ComputeResourceService for EventSourcedComputeResourceService {
         aggregate_id: Uuid,
         command: AssignAssetTagCommand,
     ) -> ServiceResult<()> {
-        let state = self.load_state(aggregate_id).await?;
+        let _turn = self.wait_turn(aggregate_id).await?;
+
+        let (state, version) = self.load_state(aggregate_id).await?;
+        self.check_read(&state)?;
         if !state.is_initialized() {
             return Err(ServiceError::NotFound(aggregate_id));
         }
 
+        self.authorize("assign_asset_tag", &state).await?;
+
+        let change_ref = command.change_ref.clone();
+
         let event = handle_assign_asset_tag(&state, command)?;
-        let version = self
-            .event_store
-            .get_version(aggregate_id)
-            .await
-            .map_err(|e| ServiceError::EventStoreError(e.to_string()))?
-            .unwrap_or(0);
 
         self.append_and_publish(
+            &state,
             aggregate_id,
             ComputeResourceEvent::AssetTagAssigned(event),
             Some(version),
+            change_ref.as_ref(),
         )
         .await?;
 
@@ -549,23 +1262,26 @@ impl ComputeResourceService for EventSourcedComputeResourceService {
         aggregate_id: Uuid,
         command: UpdateMetadataCommand,
     ) -> ServiceResult<()> {
-        let state = self.load_state(aggregate_id).await?;
+        let _turn = self.wait_turn(aggregate_id).await?;
+
+        let (state, version) = self.load_state(aggregate_id).await?;
+        self.check_read(&state)?;
         if !state.is_initialized() {
             return Err(ServiceError::NotFound(aggregate_id));
         }
 
+        self.authorize("update_metadata", &state).await?;
+
+        let change_ref = command.change_ref.clone();
+
         let event = handle_update_metadata(&state, command)?;
-        let version = self
-            .event_store
-            .get_version(aggregate_id)
-            .await
-            .map_err(|e| ServiceError::EventStoreError(e.to_string()))?
-            .unwrap_or(0);
 
         self.append_and_publish(
+            &state,
             aggregate_id,
             ComputeResourceEvent::MetadataUpdated(event),
             Some(version),
+            change_ref.as_ref(),
         )
         .await?;
 
@@ -577,31 +1293,278 @@ impl ComputeResourceService for EventSourcedComputeResourceService {
         aggregate_id: Uuid,
         command: ChangeStatusCommand,
     ) -> ServiceResult<()> {
-        let state = self.load_state(aggregate_id).await?;
+        let _turn = self.wait_turn(aggregate_id).await?;
+
+        let (state, version) = self.load_state(aggregate_id).await?;
+        self.check_read(&state)?;
         if !state.is_initialized() {
             return Err(ServiceError::NotFound(aggregate_id));
         }
 
+        self.authorize("change_status", &state).await?;
+
+        let change_ref = command.change_ref.clone();
+
         let event = handle_change_status(&state, command)?;
-        let version = self
-            .event_store
-            .get_version(aggregate_id)
-            .await
-            .map_err(|e| ServiceError::EventStoreError(e.to_string()))?
-            .unwrap_or(0);
 
-        self.append_and_publish(aggregate_id, ComputeResourceEvent::StatusChanged(event), Some(version))
+        self.append_and_publish(
+            &state,
+            aggregate_id,
+            ComputeResourceEvent::StatusChanged(event),
+            Some(version),
+            change_ref.as_ref(),
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn record_configuration_backup(
+        &self,
+        aggregate_id: Uuid,
+        command: RecordConfigurationBackupCommand,
+    ) -> ServiceResult<()> {
+        let _turn = self.wait_turn(aggregate_id).await?;
+
+        let (state, version) = self.load_state(aggregate_id).await?;
+        self.check_read(&state)?;
+        if !state.is_initialized() {
+            return Err(ServiceError::NotFound(aggregate_id));
+        }
+
+        self.authorize("record_configuration_backup", &state)
             .await?;
 
+        let change_ref = command.change_ref.clone();
+
+        let event = handle_record_configuration_backup(&state, command)?;
+
+        self.append_and_publish(
+            &state,
+            aggregate_id,
+            ComputeResourceEvent::ConfigurationBackupRecorded(event),
+            Some(version),
+            change_ref.as_ref(),
+        )
+        .await?;
+
         Ok(())
     }
 
+    async fn attach_guest(
+        &self,
+        aggregate_id: Uuid,
+        command: AttachGuestCommand,
+    ) -> ServiceResult<()> {
+        let _turn = self.wait_turn(aggregate_id).await?;
+
+        let (state, version) = self.load_state(aggregate_id).await?;
+        self.check_read(&state)?;
+        if !state.is_initialized() {
+            return Err(ServiceError::NotFound(aggregate_id));
+        }
+
+        self.authorize("attach_guest", &state).await?;
+
+        let change_ref = command.change_ref.clone();
+
+        let event = handle_attach_guest(&state, command)?;
+
+        self.append_and_publish(
+            &state,
+            aggregate_id,
+            ComputeResourceEvent::GuestAttached(event),
+            Some(version),
+            change_ref.as_ref(),
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn detach_guest(
+        &self,
+        aggregate_id: Uuid,
+        command: DetachGuestCommand,
+    ) -> ServiceResult<()> {
+        let _turn = self.wait_turn(aggregate_id).await?;
+
+        let (state, version) = self.load_state(aggregate_id).await?;
+        self.check_read(&state)?;
+        if !state.is_initialized() {
+            return Err(ServiceError::NotFound(aggregate_id));
+        }
+
+        self.authorize("detach_guest", &state).await?;
+
+        let change_ref = command.change_ref.clone();
+
+        let event = handle_detach_guest(&state, command)?;
+
+        self.append_and_publish(
+            &state,
+            aggregate_id,
+            ComputeResourceEvent::GuestDetached(event),
+            Some(version),
+            change_ref.as_ref(),
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn execute_batch(
+        &self,
+        aggregate_id: Option<Uuid>,
+        commands: Vec<ComputeResourceCommand>,
+    ) -> ServiceResult<Uuid> {
+        let creates = commands
+            .first()
+            .is_some_and(ComputeResourceCommand::is_creation);
+
+        // Held until the batch is stored
+        let mut _turn = None;
+        let (aggregate_id, state, version) = match (aggregate_id, creates) {
+            (None, true) => {
+                let aggregate_id = Uuid::now_v7();
+                (aggregate_id, ComputeResourceState::default_for(aggregate_id), None)
+            }
+            (Some(aggregate_id), false) => {
+                _turn = Some(self.wait_turn(aggregate_id).await?);
+                let (state, version) = self.load_state(aggregate_id).await?;
+                self.check_read(&state)?;
+                if !state.is_initialized() {
+                    return Err(ServiceError::NotFound(aggregate_id));
+                }
+
+                (aggregate_id, state, Some(version))
+            }
+            (None, false) => {
+                return Err(ServiceError::BusinessRuleViolation(
+                    "A batch without aggregate_id must start with register_resource".to_string(),
+                ))
+            }
+            (Some(aggregate_id), true) => {
+                return Err(ServiceError::BusinessRuleViolation(format!(
+                    "register_resource cannot target existing aggregate {}",
+                    aggregate_id
+                )))
+            }
+        };
+
+        // One append carries one change request
+        let change_ref = shared_change_ref(commands.iter().map(ComputeResourceCommand::change_ref))
+            .map_err(|e| ServiceError::BusinessRuleViolation(e.to_string()))?;
+
+        for command in &commands {
+            self.authorize(command.command_name(), &state).await?;
+        }
+
+        // Handle every command (pure) before touching the event store
+        let (events, _) = handle_commands(&state, commands, aggregate_id)?;
+        if events.is_empty() {
+            return Ok(aggregate_id);
+        }
+
+        self.append_all_and_publish(&state, aggregate_id, events, version, change_ref.as_ref())
+            .await?;
+
+        Ok(aggregate_id)
+    }
+
+    async fn commit(&self, unit: UnitOfWork) -> ServiceResult<CommittedUnit> {
+        // Turns are taken in ID order so two units never wait on each other
+        let mut aggregate_ids = unit.aggregate_ids();
+        aggregate_ids.sort();
+        let mut _turns = Vec::with_capacity(aggregate_ids.len());
+        for aggregate_id in aggregate_ids {
+            _turns.push(self.wait_turn(aggregate_id).await?);
+        }
+
+        let mut snapshot = HashMap::new();
+        let mut versions = HashMap::new();
+        for aggregate_id in unit.aggregate_ids() {
+            let (state, version) = self.load_state(aggregate_id).await?;
+            self.check_read(&state)?;
+            snapshot.insert(aggregate_id, state);
+            versions.insert(aggregate_id, version);
+        }
+
+        for (aggregate_id, command) in unit.commands() {
+            self.authorize(command.command_name(), &snapshot[aggregate_id])
+                .await?;
+        }
+
+        // Handle every command (pure) and check conventions and change
+        // control before writing
+        let planned = unit.plan(&snapshot)?;
+        let mut change_refs = HashMap::new();
+        for (aggregate_id, events) in &planned {
+            let change_ref = unit.change_ref_for(*aggregate_id)?;
+            let before = &snapshot[aggregate_id];
+            let after = events
+                .iter()
+                .fold(before.clone(), |current, event| apply_event(current, event));
+            self.check_tenancy(before, &after)?;
+            self.check_conventions(before, &after)?;
+            self.check_change_control(before, &after, change_ref.as_ref())?;
+            change_refs.insert(*aggregate_id, change_ref);
+        }
+
+        // Cheap early exit if another writer got in since the snapshot;
+        // each append re-checks its own version
+        for (aggregate_id, expected) in &versions {
+            let actual = self.current_version(*aggregate_id).await?;
+            if actual != *expected {
+                return Err(ServiceError::ConcurrencyConflict {
+                    expected: *expected,
+                    actual,
+                });
+            }
+        }
+
+        let mut committed = CommittedUnit {
+            correlation_id: unit.correlation_id(),
+            aggregate_ids: Vec::new(),
+            event_ids: Vec::new(),
+        };
+        for (aggregate_id, events) in planned {
+            let event_ids: Vec<Uuid> = events.iter().map(ComputeResourceEvent::event_id).collect();
+            let result = self
+                .append_all_and_publish(
+                    &snapshot[&aggregate_id],
+                    aggregate_id,
+                    events,
+                    Some(versions[&aggregate_id]),
+                    change_refs[&aggregate_id].as_ref(),
+                )
+                .await;
+
+            match result {
+                Ok(()) => {
+                    committed.aggregate_ids.push(aggregate_id);
+                    committed.event_ids.extend(event_ids);
+                }
+                Err(e) if committed.aggregate_ids.is_empty() => return Err(e),
+                Err(e) => {
+                    return Err(ServiceError::PartiallyCommitted {
+                        committed: committed.aggregate_ids,
+                        message: e.to_string(),
+                    })
+                }
+            }
+        }
+
+        Ok(committed)
+    }
+
     async fn get_resource(&self, aggregate_id: Uuid) -> ServiceResult<ComputeResourceState> {
-        let state = self.load_state(aggregate_id).await?;
+        let (state, _) = self.load_state(aggregate_id).await?;
 
         if !state.is_initialized() {
             return Err(ServiceError::NotFound(aggregate_id));
         }
+        self.check_read(&state)?;
 
         Ok(state)
     }
@@ -614,13 +1577,97 @@ impl ComputeResourceService for EventSourcedComputeResourceService {
             .map_err(|e| ServiceError::EventStoreError(e.to_string()))?
             .unwrap_or(0);
 
-        Ok(version > 0)
+        if version == 0 {
+            return Ok(false);
+        }
+        Ok(self.visible(Some(aggregate_id)).await?.is_some())
+    }
+
+    async fn get_timeline(
+        &self,
+        aggregate_id: Uuid,
+        granularity: TimelineGranularity,
+    ) -> ServiceResult<ResourceTimeline> {
+        let events: Vec<InfrastructureEvent> = self
+            .event_store
+            .read_events(aggregate_id)
+            .await
+            .map_err(|e| ServiceError::EventStoreError(e.to_string()))?
+            .into_iter()
+            .map(|stored| stored.data)
+            .collect();
+
+        if events.is_empty() {
+            return Err(ServiceError::NotFound(aggregate_id));
+        }
+        let state = events.iter().fold(
+            ComputeResourceState::default_for(aggregate_id),
+            apply_infrastructure_event,
+        );
+        self.check_read(&state)?;
+
+        Ok(build_timeline(aggregate_id, &events, granularity))
+    }
+
+    async fn list_resources(
+        &self,
+        filter: &ResourceFilter,
+        page: u32,
+        page_size: u32,
+    ) -> ServiceResult<ResourcePage> {
+        let scoped;
+        let filter = match &self.tenancy {
+            Some(config) => {
+                let organization_id = config
+                    .scope_listing(
+                        self.acting_organization().as_deref(),
+                        filter.organization_id.as_deref(),
+                    )
+                    .map_err(|e| ServiceError::BusinessRuleViolation(e.to_string()))?;
+                scoped = ResourceFilter {
+                    organization_id,
+                    ..filter.clone()
+                };
+                &scoped
+            }
+            None => filter,
+        };
+
+        if let Some(registry) = &self.registry {
+            return Ok(registry.list_resources(filter, page, page_size));
+        }
+
+        let events = self
+            .event_store
+            .read_aggregate_type(AggregateType::Compute)
+            .await
+            .map_err(|e| ServiceError::EventStoreError(e.to_string()))?;
+
+        Ok(ResourceRegistrySnapshot::from_events(&events).list(filter, page, page_size))
+    }
+
+    async fn find_by_hostname(&self, hostname: &str) -> ServiceResult<Option<Uuid>> {
+        let found = self.find(LookupField::Hostname, hostname).await?;
+        self.visible(found).await
     }
+
+    async fn find_by_asset_tag(&self, asset_tag: &str) -> ServiceResult<Option<Uuid>> {
+        let found = self.find(LookupField::AssetTag, asset_tag).await?;
+        self.visible(found).await
+    }
+}
+
+/// Highest sequence among `events`, 0 if there are none
+fn last_sequence(events: &[StoredEvent<InfrastructureEvent>]) -> u64 {
+    events.iter().map(|event| event.sequence).max().unwrap_or(0)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::errors::InfrastructureResult;
+    use crate::nats::NatsConfig;
+    use crate::test_harness::{Fixtures, TestNats};
 
     // Note: Full integration tests require a running NATS server
     // These are basic unit tests for the service structure
@@ -637,4 +1684,49 @@ mod tests {
         let svc_err: ServiceError = cmd_err.into();
         assert!(matches!(svc_err, ServiceError::CommandError(_)));
     }
+
+    #[tokio::test]
+    #[ignore] // Requires NATS server
+    async fn test_write_behind_an_interleaved_writer_conflicts() -> InfrastructureResult<()> {
+        // Arrange: a registered resource, loaded before another writer changes it
+        let nats = TestNats::start().await?;
+        let client = NatsClient::new(NatsConfig {
+            servers: vec![nats.url().to_string()],
+            ..NatsConfig::default()
+        })
+        .await?;
+        let service = EventSourcedComputeResourceService::new(nats.event_store().await?, client);
+        let aggregate_id = service
+            .execute(None, Fixtures::new().register_resource("web01"))
+            .await
+            .unwrap();
+        let fixtures = Fixtures::new().with_aggregate_id(aggregate_id);
+        let (stale, version) = service.load_state(aggregate_id).await.unwrap();
+
+        // Act
+        service
+            .execute(Some(aggregate_id), fixtures.assign_asset_tag("A-1001"))
+            .await
+            .unwrap();
+        let behind = service
+            .append_and_publish(
+                &stale,
+                aggregate_id,
+                fixtures.asset_tag_assigned("A-1002"),
+                Some(version),
+                None,
+            )
+            .await;
+
+        // Assert
+        assert_eq!(version, 1);
+        assert!(matches!(
+            behind,
+            Err(ServiceError::ConcurrencyConflict {
+                expected: 1,
+                actual: 2
+            })
+        ));
+        Ok(())
+    }
 }
@@ -0,0 +1,305 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Connection Service Layer
+//!
+//! Application service for PhysicalConnection aggregates. Same transaction
+//! semantics as the network service: load, handle (pure), append with
+//! optimistic concurrency, publish.
+//!
+//! Patching a cable reads every connection to build the [`PortOccupancy`]
+//! the handler checks; ports are not locked, so two cables patched into
+//! the same port concurrently are not detected.
+//!
+//! Every command is put to the service's
+//! [`Authorizer`](crate::service::authorization::Authorizer) first.
+//!
+//! # Subjects
+//!
+//! ```text
+//! infrastructure.connection.<aggregate_id>.<event_type>
+//! ```
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::aggregate::connection::*;
+use crate::event_store::provenance::EventEnvelopeMetadata;
+use crate::event_store::{EventStore, NatsEventStore};
+use crate::events::{ConnectionEvent, InfrastructureEvent};
+use crate::nats::NatsClient;
+use crate::service::authorization::{AllowAll, AuthorizationRequest, Authorizer};
+use crate::service::{ServiceError, ServiceResult};
+use crate::subjects::AggregateType;
+
+/// Connection service trait
+#[async_trait]
+pub trait ConnectionService: Send + Sync {
+    /// Patch a cable between two interfaces
+    ///
+    /// # Returns
+    /// - Aggregate ID of the new connection
+    async fn establish_connection(
+        &self,
+        command: EstablishConnectionCommand,
+    ) -> ServiceResult<Uuid>;
+
+    /// Set or change a cable label
+    async fn label_connection(
+        &self,
+        connection_id: Uuid,
+        command: LabelConnectionCommand,
+    ) -> ServiceResult<()>;
+
+    /// Remove a cable, freeing both ports
+    async fn remove_connection(
+        &self,
+        connection_id: Uuid,
+        command: RemoveConnectionCommand,
+    ) -> ServiceResult<()>;
+
+    /// Get current connection state
+    async fn get_connection(&self, connection_id: Uuid) -> ServiceResult<ConnectionState>;
+
+    /// Get every live connection
+    async fn list_connections(&self) -> ServiceResult<Vec<ConnectionState>>;
+}
+
+/// Event-sourced implementation of ConnectionService
+pub struct EventSourcedConnectionService {
+    /// Event store for persistence
+    event_store: NatsEventStore,
+
+    /// NATS client for publishing
+    nats_client: NatsClient,
+
+    /// Provenance recorded on every event written
+    envelope: Option<EventEnvelopeMetadata>,
+
+    /// Asked before every command
+    authorizer: Arc<dyn Authorizer>,
+}
+
+impl EventSourcedConnectionService {
+    /// Create a new event-sourced service
+    pub fn new(event_store: NatsEventStore, nats_client: NatsClient) -> Self {
+        Self {
+            event_store,
+            nats_client,
+            envelope: None,
+            authorizer: Arc::new(AllowAll),
+        }
+    }
+
+    /// Record provenance (source system, schema, tenant) on every event
+    ///
+    /// Fields set by the request's envelope
+    /// ([`EventEnvelopeMetadata::scope`]) take precedence.
+    pub fn with_envelope(mut self, envelope: EventEnvelopeMetadata) -> Self {
+        self.envelope = Some(envelope);
+        self
+    }
+
+    /// Ask `authorizer` before every command
+    ///
+    /// Commands it does not allow fail with [`ServiceError::Unauthorized`].
+    /// Without one, every command is allowed.
+    pub fn with_authorizer(mut self, authorizer: Arc<dyn Authorizer>) -> Self {
+        self.authorizer = authorizer;
+        self
+    }
+
+    /// Ask the authorizer whether the request's actor may run `command`
+    /// against a connection
+    async fn authorize(
+        &self,
+        aggregate_type: AggregateType,
+        command: &str,
+        aggregate_id: Uuid,
+    ) -> ServiceResult<()> {
+        let envelope = EventEnvelopeMetadata::resolve(self.envelope.as_ref());
+        let request = AuthorizationRequest {
+            actor: envelope
+                .as_ref()
+                .and_then(|envelope| envelope.actor.as_ref()),
+            aggregate_type,
+            command,
+            aggregate_id,
+            policy_ids: &[],
+        };
+        Ok(self.authorizer.authorize(&request).await?)
+    }
+
+    /// Load current connection state with its version
+    async fn load_connection(&self, connection_id: Uuid) -> ServiceResult<(ConnectionState, u64)> {
+        let stored_events = self
+            .event_store
+            .read_events(connection_id)
+            .await
+            .map_err(|e| ServiceError::EventStoreError(e.to_string()))?;
+
+        let version = stored_events.last().map(|e| e.sequence).unwrap_or(0);
+        let events: Vec<ConnectionEvent> = stored_events
+            .into_iter()
+            .filter_map(|stored| match stored.data {
+                InfrastructureEvent::Connection(event) => Some(event),
+                _ => None,
+            })
+            .collect();
+
+        let state = if events.is_empty() {
+            ConnectionState::default_for(connection_id)
+        } else {
+            ConnectionState::from_events(&events)
+        };
+        Ok((state, version))
+    }
+
+    /// Load every connection's events, in stream order
+    async fn load_connection_events(&self) -> ServiceResult<Vec<ConnectionEvent>> {
+        let stored_events = self
+            .event_store
+            .read_aggregate_type(AggregateType::Connection)
+            .await
+            .map_err(|e| ServiceError::EventStoreError(e.to_string()))?;
+
+        Ok(stored_events
+            .into_iter()
+            .filter_map(|stored| match stored.data {
+                InfrastructureEvent::Connection(event) => Some(event),
+                _ => None,
+            })
+            .collect())
+    }
+
+    /// Append event to the store and publish it to NATS
+    async fn append_and_publish(
+        &self,
+        aggregate_id: Uuid,
+        event: ConnectionEvent,
+        expected_version: Option<u64>,
+    ) -> ServiceResult<()> {
+        let event = InfrastructureEvent::Connection(event);
+
+        let envelope = EventEnvelopeMetadata::resolve(self.envelope.as_ref());
+        self.event_store
+            .append_with_context(
+                aggregate_id,
+                vec![event.clone()],
+                expected_version,
+                None,
+                envelope.as_ref(),
+            )
+            .await
+            .map_err(|e| ServiceError::EventStoreError(e.to_string()))?;
+
+        self.nats_client
+            .publish(&event.subject(), &event)
+            .await
+            .map_err(|e| ServiceError::NatsError(format!("NATS publish error: {}", e)))?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ConnectionService for EventSourcedConnectionService {
+    async fn establish_connection(
+        &self,
+        command: EstablishConnectionCommand,
+    ) -> ServiceResult<Uuid> {
+        let aggregate_id = Uuid::now_v7();
+        self.authorize(
+            AggregateType::Connection,
+            "establish_connection",
+            aggregate_id,
+        )
+        .await?;
+        let state = ConnectionState::default_for(aggregate_id);
+
+        let mut occupancy = PortOccupancy::new();
+        for event in self.load_connection_events().await? {
+            occupancy.apply(&event);
+        }
+
+        let event = handle_establish_connection(&state, command, aggregate_id, &occupancy)?;
+        self.append_and_publish(
+            aggregate_id,
+            ConnectionEvent::ConnectionEstablished(event),
+            Some(0),
+        )
+        .await?;
+
+        Ok(aggregate_id)
+    }
+
+    async fn label_connection(
+        &self,
+        connection_id: Uuid,
+        command: LabelConnectionCommand,
+    ) -> ServiceResult<()> {
+        let (state, version) = self.load_connection(connection_id).await?;
+        if !state.is_initialized() {
+            return Err(ServiceError::NotFound(connection_id));
+        }
+        self.authorize(AggregateType::Connection, "label_connection", connection_id)
+            .await?;
+
+        let event = handle_label_connection(&state, command)?;
+        self.append_and_publish(
+            connection_id,
+            ConnectionEvent::ConnectionLabeled(event),
+            Some(version),
+        )
+        .await
+    }
+
+    async fn remove_connection(
+        &self,
+        connection_id: Uuid,
+        command: RemoveConnectionCommand,
+    ) -> ServiceResult<()> {
+        let (state, version) = self.load_connection(connection_id).await?;
+        if !state.is_initialized() {
+            return Err(ServiceError::NotFound(connection_id));
+        }
+        self.authorize(
+            AggregateType::Connection,
+            "remove_connection",
+            connection_id,
+        )
+        .await?;
+
+        let event = handle_remove_connection(&state, command)?;
+        self.append_and_publish(
+            connection_id,
+            ConnectionEvent::ConnectionRemoved(event),
+            Some(version),
+        )
+        .await
+    }
+
+    async fn get_connection(&self, connection_id: Uuid) -> ServiceResult<ConnectionState> {
+        let (state, _) = self.load_connection(connection_id).await?;
+        if !state.is_initialized() {
+            return Err(ServiceError::NotFound(connection_id));
+        }
+        Ok(state)
+    }
+
+    async fn list_connections(&self) -> ServiceResult<Vec<ConnectionState>> {
+        let mut by_connection: HashMap<Uuid, Vec<ConnectionEvent>> = HashMap::new();
+        for event in self.load_connection_events().await? {
+            by_connection
+                .entry(event.aggregate_id())
+                .or_default()
+                .push(event);
+        }
+
+        Ok(by_connection
+            .into_values()
+            .map(|events| ConnectionState::from_events(&events))
+            .filter(ConnectionState::is_live)
+            .collect())
+    }
+}
@@ -0,0 +1,291 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Per-Aggregate Leases Across Instances
+//!
+//! A [`WriteQueue`](super::write_queue::WriteQueue) orders writers in one
+//! process. When several service instances handle commands for the same
+//! hot aggregate, they still collide on the optimistic concurrency check
+//! and retry. A [`LeaseManager`] serializes them through a JetStream KV
+//! bucket instead:
+//!
+//! ```text
+//! instance A ──create(lease.<id>)──> ok ───> load → handle → append → clear
+//! instance B ──create(lease.<id>)──> exists ─wait─> create ──> ok ──> ...
+//! ```
+//!
+//! A lease is a key in the `infrastructure-leases` bucket, created only if
+//! absent or cleared and holding the holder's name. The bucket's max age
+//! is the lease TTL, so a lease left by a crashed instance expires on its
+//! own; keep the TTL well above the time a command takes. Leases are
+//! released when the [`Lease`] is dropped, by clearing the key only if it
+//! still holds the revision the lease was acquired at, so a lease that
+//! expired and was taken by another instance is left alone.
+//!
+//! Leases make contention cheaper, not correctness possible: a lease that
+//! expires mid-command lets another writer in, and the concurrency check
+//! still decides.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::service::lease::{LeaseConfig, LeaseManager};
+//!
+//! let leases = LeaseManager::open(
+//!     jetstream.clone(),
+//!     LeaseConfig::new("inventory-api-2").with_ttl(Duration::from_secs(10)),
+//! )
+//! .await?;
+//! let service = EventSourcedComputeResourceService::new(event_store, nats_client)
+//!     .with_write_queue(queue)
+//!     .with_leases(leases);
+//! ```
+
+use async_nats::jetstream::{self, kv};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+use crate::errors::{InfrastructureError, InfrastructureResult};
+use crate::nats::kv::{clear_entry, create_entry};
+
+/// KV bucket holding the leases
+pub const LEASE_BUCKET: &str = "infrastructure-leases";
+
+/// Lease timing and identity
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeaseConfig {
+    /// Name recorded in leases this instance holds
+    pub holder: String,
+
+    /// How long an unreleased lease lives
+    pub ttl: Duration,
+
+    /// Longest a command waits for a lease
+    pub timeout: Duration,
+
+    /// Pause between attempts while another instance holds the lease
+    pub retry_interval: Duration,
+}
+
+impl LeaseConfig {
+    /// Leases held under `holder`, with default timing
+    pub fn new(holder: impl Into<String>) -> Self {
+        Self {
+            holder: holder.into(),
+            ttl: Duration::from_secs(30),
+            timeout: Duration::from_secs(5),
+            retry_interval: Duration::from_millis(25),
+        }
+    }
+
+    /// Set the lease lifetime
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Set the longest wait for a lease
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set the pause between attempts
+    pub fn with_retry_interval(mut self, retry_interval: Duration) -> Self {
+        self.retry_interval = retry_interval;
+        self
+    }
+}
+
+/// A command did not get the lease
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum LeaseError {
+    /// Another instance held the lease for longer than the timeout
+    #[error("Timed out after {waited:?} waiting for the lease on aggregate {aggregate_id} (held by {holder})")]
+    Timeout {
+        aggregate_id: Uuid,
+        holder: String,
+        waited: Duration,
+    },
+
+    /// The lease bucket could not be read or written
+    #[error("Lease store error: {0}")]
+    Store(String),
+}
+
+#[derive(Debug, Default)]
+struct Counters {
+    acquired: AtomicU64,
+    contended: AtomicU64,
+    timed_out: AtomicU64,
+}
+
+/// Lease counters at one point in time
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LeaseMetrics {
+    /// Leases acquired
+    pub acquired: u64,
+
+    /// Acquisitions that had to wait for another holder
+    pub contended: u64,
+
+    /// Commands that gave up waiting
+    pub timed_out: u64,
+}
+
+/// Acquires per-aggregate leases from the lease bucket
+///
+/// Cloning is cheap; all clones share the bucket and metrics.
+#[derive(Clone)]
+pub struct LeaseManager {
+    store: kv::Store,
+    config: LeaseConfig,
+    counters: Arc<Counters>,
+}
+
+impl LeaseManager {
+    /// Open the lease bucket, creating it with the configured TTL if needed
+    ///
+    /// An existing bucket keeps the max age it was created with.
+    pub async fn open(
+        jetstream: jetstream::Context,
+        config: LeaseConfig,
+    ) -> InfrastructureResult<Self> {
+        let store = match jetstream.get_key_value(LEASE_BUCKET).await {
+            Ok(store) => store,
+            Err(_) => jetstream
+                .create_key_value(kv::Config {
+                    bucket: LEASE_BUCKET.to_string(),
+                    description: "Single-writer leases on hot aggregates".to_string(),
+                    history: 1,
+                    max_age: config.ttl,
+                    ..Default::default()
+                })
+                .await
+                .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?,
+        };
+
+        Ok(Self {
+            store,
+            config,
+            counters: Arc::default(),
+        })
+    }
+
+    /// Wait for the lease on `aggregate_id`
+    ///
+    /// The lease lasts until the returned [`Lease`] is dropped or expires.
+    pub async fn acquire(&self, aggregate_id: Uuid) -> Result<Lease, LeaseError> {
+        let key = lease_key(aggregate_id);
+        let started = Instant::now();
+        let mut contended = false;
+
+        loop {
+            match create_entry(&self.store, &key, self.config.holder.clone().into_bytes()).await {
+                Ok(Some(revision)) => {
+                    self.counters.acquired.fetch_add(1, Ordering::Relaxed);
+                    if contended {
+                        self.counters.contended.fetch_add(1, Ordering::Relaxed);
+                    }
+                    return Ok(Lease {
+                        store: self.store.clone(),
+                        key,
+                        revision,
+                        released: false,
+                    });
+                }
+                Ok(None) => {
+                    contended = true;
+                    if started.elapsed() >= self.config.timeout {
+                        self.counters.timed_out.fetch_add(1, Ordering::Relaxed);
+                        return Err(LeaseError::Timeout {
+                            aggregate_id,
+                            holder: self.holder_of(&key).await,
+                            waited: started.elapsed(),
+                        });
+                    }
+                    tokio::time::sleep(self.config.retry_interval).await;
+                }
+                Err(e) => return Err(LeaseError::Store(e.to_string())),
+            }
+        }
+    }
+
+    /// Current values
+    pub fn metrics(&self) -> LeaseMetrics {
+        LeaseMetrics {
+            acquired: self.counters.acquired.load(Ordering::Relaxed),
+            contended: self.counters.contended.load(Ordering::Relaxed),
+            timed_out: self.counters.timed_out.load(Ordering::Relaxed),
+        }
+    }
+
+    async fn holder_of(&self, key: &str) -> String {
+        match self.store.get(key).await {
+            Ok(Some(holder)) if !holder.is_empty() => String::from_utf8_lossy(&holder).into_owned(),
+            _ => "unknown".to_string(),
+        }
+    }
+}
+
+/// Exclusive right, across instances, to write one aggregate
+pub struct Lease {
+    store: kv::Store,
+    key: String,
+    revision: u64,
+    released: bool,
+}
+
+impl Lease {
+    /// Release the lease now rather than when dropped
+    pub async fn release(mut self) {
+        release(&self.store, &self.key, self.revision).await;
+        self.released = true;
+    }
+}
+
+impl Drop for Lease {
+    fn drop(&mut self) {
+        if self.released {
+            return;
+        }
+        let (store, key, revision) = (self.store.clone(), self.key.clone(), self.revision);
+        if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+            runtime.spawn(async move { release(&store, &key, revision).await });
+        }
+    }
+}
+
+/// Clear the lease unless it expired and was taken by someone else
+async fn release(store: &kv::Store, key: &str, revision: u64) {
+    match clear_entry(store, key, revision).await {
+        Ok(true) => {}
+        Ok(false) => debug!("Lease {} expired before release", key),
+        Err(e) => warn!("Failed to release lease {}: {}", key, e),
+    }
+}
+
+/// KV key of an aggregate's lease
+pub fn lease_key(aggregate_id: Uuid) -> String {
+    format!("lease.{}", aggregate_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_builders() {
+        let config = LeaseConfig::new("api-1")
+            .with_ttl(Duration::from_secs(10))
+            .with_timeout(Duration::from_secs(1))
+            .with_retry_interval(Duration::from_millis(5));
+
+        assert_eq!(config.holder, "api-1");
+        assert_eq!(config.ttl, Duration::from_secs(10));
+        assert_eq!(config.timeout, Duration::from_secs(1));
+        assert_eq!(config.retry_interval, Duration::from_millis(5));
+        assert!(lease_key(Uuid::nil()).starts_with("lease.0000"));
+    }
+}
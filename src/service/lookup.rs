@@ -0,0 +1,379 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Unique Lookups by Hostname and Asset Tag
+//!
+//! A [`LookupIndex`] maps hostnames and asset tags to the resource holding
+//! them, in a JetStream KV bucket maintained as events are appended:
+//!
+//! ```text
+//! infrastructure-lookup
+//!   hostname.<base64url(web-01.example.com)>  ──> 0193…
+//!   asset_tag.<base64url(AT-00042)>           ──> 0193…
+//! ```
+//!
+//! With [`EventSourcedComputeResourceService::with_lookup`](super::EventSourcedComputeResourceService::with_lookup),
+//! uniqueness is a command-time invariant. Values a command introduces are
+//! claimed before the append (creating the key only if absent, so two
+//! instances cannot both win), and released again if the append fails:
+//!
+//! ```text
+//! before ──command──> after
+//!   claim   values in after but not before    ──> Taken? reject the command
+//!   append
+//!   release values in before but not after
+//! ```
+//!
+//! Hostnames are compared case-insensitively. Decommissioned resources
+//! release their hostname and asset tag for reuse, so lookups only find
+//! resources still in service. Resources registered before the index was
+//! enabled are added with [`LookupIndex::index_resource`].
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::service::lookup::LookupIndex;
+//!
+//! let lookup = LookupIndex::open(jetstream.clone()).await?;
+//! let service = EventSourcedComputeResourceService::new(event_store, nats_client)
+//!     .with_lookup(lookup);
+//!
+//! let resource = service.find_by_hostname("web-01.example.com").await?;
+//! ```
+
+use async_nats::jetstream::{self, kv};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use std::collections::HashMap;
+use std::fmt;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::aggregate::compute_resource::apply_infrastructure_event;
+use crate::aggregate::ComputeResourceState;
+use crate::errors::{InfrastructureError, InfrastructureResult};
+use crate::events::{InfrastructureEvent, ResourceStatus};
+use crate::jetstream::StoredEvent;
+use crate::nats::kv::create_entry;
+
+/// KV bucket holding the lookup index
+pub const LOOKUP_BUCKET: &str = "infrastructure-lookup";
+
+/// Times a claim is tried while the value keeps being released under it
+const CLAIM_ATTEMPTS: usize = 3;
+
+/// Field a resource can be looked up by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LookupField {
+    /// Fully qualified hostname
+    Hostname,
+
+    /// Asset tag
+    AssetTag,
+}
+
+impl fmt::Display for LookupField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LookupField::Hostname => write!(f, "hostname"),
+            LookupField::AssetTag => write!(f, "asset_tag"),
+        }
+    }
+}
+
+/// A value could not be claimed or looked up
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum LookupError {
+    /// Another resource already holds the value
+    #[error("{field} {value} is already used by resource {owner}")]
+    Taken {
+        field: LookupField,
+        value: String,
+        owner: Uuid,
+    },
+
+    /// The lookup bucket could not be read or written
+    #[error("Lookup store error: {0}")]
+    Store(String),
+}
+
+/// Values a resource holds in the index
+pub fn lookup_values(state: &ComputeResourceState) -> Vec<(LookupField, String)> {
+    if !state.is_initialized() || state.status == ResourceStatus::Decommissioned {
+        return Vec::new();
+    }
+    let mut values = vec![(
+        LookupField::Hostname,
+        normalize(LookupField::Hostname, state.hostname.as_str()),
+    )];
+    if let Some(tag) = &state.asset_tag {
+        values.push((LookupField::AssetTag, normalize(LookupField::AssetTag, tag)));
+    }
+    values
+}
+
+/// Index updates a change from `before` to `after` needs
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LookupChanges {
+    /// Values the resource takes
+    pub claimed: Vec<(LookupField, String)>,
+
+    /// Values the resource gives up
+    pub released: Vec<(LookupField, String)>,
+}
+
+impl LookupChanges {
+    /// Differences between the values held before and after
+    pub fn between(before: &ComputeResourceState, after: &ComputeResourceState) -> Self {
+        let before = lookup_values(before);
+        let after = lookup_values(after);
+        Self {
+            claimed: after
+                .iter()
+                .filter(|value| !before.contains(value))
+                .cloned()
+                .collect(),
+            released: before
+                .iter()
+                .filter(|value| !after.contains(value))
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+/// Resource holding `value`, found by replaying compute resource events
+///
+/// For services running without a [`LookupIndex`].
+pub fn find_in_events(
+    events: &[StoredEvent<InfrastructureEvent>],
+    field: LookupField,
+    value: &str,
+) -> Option<Uuid> {
+    let mut states: HashMap<Uuid, ComputeResourceState> = HashMap::new();
+    for event in events {
+        let state = states
+            .remove(&event.aggregate_id)
+            .unwrap_or_else(|| ComputeResourceState::default_for(event.aggregate_id));
+        states.insert(
+            event.aggregate_id,
+            apply_infrastructure_event(state, &event.data),
+        );
+    }
+
+    let wanted = (field, normalize(field, value));
+    states
+        .into_values()
+        .find(|state| lookup_values(state).contains(&wanted))
+        .map(|state| state.id)
+}
+
+/// Hostname and asset tag index in a JetStream KV bucket
+///
+/// Cloning is cheap; all clones share the bucket.
+#[derive(Clone)]
+pub struct LookupIndex {
+    store: kv::Store,
+}
+
+impl LookupIndex {
+    /// Open the lookup bucket, creating it if needed
+    pub async fn open(jetstream: jetstream::Context) -> InfrastructureResult<Self> {
+        let store = match jetstream.get_key_value(LOOKUP_BUCKET).await {
+            Ok(store) => store,
+            Err(_) => jetstream
+                .create_key_value(kv::Config {
+                    bucket: LOOKUP_BUCKET.to_string(),
+                    description: "Unique hostname and asset tag lookups".to_string(),
+                    history: 1,
+                    ..Default::default()
+                })
+                .await
+                .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?,
+        };
+        Ok(Self { store })
+    }
+
+    /// Resource holding `value`
+    pub async fn find(&self, field: LookupField, value: &str) -> Result<Option<Uuid>, LookupError> {
+        self.owner(&lookup_key(field, value)).await
+    }
+
+    /// Resource registered under `hostname`
+    pub async fn find_by_hostname(&self, hostname: &str) -> Result<Option<Uuid>, LookupError> {
+        self.find(LookupField::Hostname, hostname).await
+    }
+
+    /// Resource tagged `asset_tag`
+    pub async fn find_by_asset_tag(&self, asset_tag: &str) -> Result<Option<Uuid>, LookupError> {
+        self.find(LookupField::AssetTag, asset_tag).await
+    }
+
+    /// Claim every value for `aggregate_id`, or none of them
+    ///
+    /// Values the resource already holds are claimed again without error.
+    pub async fn claim_all(
+        &self,
+        aggregate_id: Uuid,
+        values: &[(LookupField, String)],
+    ) -> Result<(), LookupError> {
+        for (claimed, (field, value)) in values.iter().enumerate() {
+            if let Err(e) = self.claim(aggregate_id, *field, value).await {
+                self.release_all(aggregate_id, &values[..claimed]).await;
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
+    /// Release the values `aggregate_id` holds, logging failures
+    pub async fn release_all(&self, aggregate_id: Uuid, values: &[(LookupField, String)]) {
+        for (field, value) in values {
+            let key = lookup_key(*field, value);
+            let released = match self.owner(&key).await {
+                Ok(Some(owner)) if owner == aggregate_id => self
+                    .store
+                    .delete(&key)
+                    .await
+                    .map_err(|e| LookupError::Store(e.to_string())),
+                Ok(_) => Ok(()),
+                Err(e) => Err(e),
+            };
+            if let Err(e) = released {
+                warn!("Failed to release {} {}: {}", field, value, e);
+            }
+        }
+    }
+
+    /// Add the values of a resource registered before the index existed
+    pub async fn index_resource(&self, state: &ComputeResourceState) -> Result<(), LookupError> {
+        self.claim_all(state.id, &lookup_values(state)).await
+    }
+
+    async fn claim(
+        &self,
+        aggregate_id: Uuid,
+        field: LookupField,
+        value: &str,
+    ) -> Result<(), LookupError> {
+        let key = lookup_key(field, value);
+        for _ in 0..CLAIM_ATTEMPTS {
+            let created = create_entry(&self.store, &key, aggregate_id.to_string().into_bytes())
+                .await
+                .map_err(|e| LookupError::Store(e.to_string()))?;
+            if created.is_some() {
+                return Ok(());
+            }
+            match self.owner(&key).await? {
+                Some(owner) if owner == aggregate_id => return Ok(()),
+                Some(owner) => {
+                    return Err(LookupError::Taken {
+                        field,
+                        value: value.to_string(),
+                        owner,
+                    })
+                }
+                // Released in the meantime; try to create it again
+                None => {}
+            }
+        }
+        Err(LookupError::Store(format!(
+            "{} {} kept changing hands while being claimed",
+            field, value
+        )))
+    }
+
+    async fn owner(&self, key: &str) -> Result<Option<Uuid>, LookupError> {
+        let value = self
+            .store
+            .get(key)
+            .await
+            .map_err(|e| LookupError::Store(e.to_string()))?;
+        Ok(value.and_then(|owner| Uuid::try_parse_ascii(&owner).ok()))
+    }
+}
+
+/// Compared form of a value
+fn normalize(field: LookupField, value: &str) -> String {
+    match field {
+        LookupField::Hostname => value.trim().to_ascii_lowercase(),
+        LookupField::AssetTag => value.trim().to_string(),
+    }
+}
+
+/// KV key of a value; values are encoded since tags may hold any character
+pub fn lookup_key(field: LookupField, value: &str) -> String {
+    format!(
+        "{}.{}",
+        field,
+        URL_SAFE_NO_PAD.encode(normalize(field, value))
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_harness;
+
+    #[test]
+    fn test_changes_claim_new_values_and_release_old_ones() {
+        // Arrange
+        let aggregate_id = Uuid::now_v7();
+        let empty = ComputeResourceState::default_for(aggregate_id);
+        let registered = apply_infrastructure_event(
+            empty.clone(),
+            &test_harness::registered(aggregate_id, "web-01"),
+        );
+        let tagged = ComputeResourceState {
+            asset_tag: Some("AT-1".to_string()),
+            ..registered.clone()
+        };
+        let retagged = ComputeResourceState {
+            asset_tag: Some("AT-2".to_string()),
+            ..registered.clone()
+        };
+        let decommissioned = ComputeResourceState {
+            status: ResourceStatus::Decommissioned,
+            ..retagged.clone()
+        };
+
+        // Act
+        let registration = LookupChanges::between(&empty, &registered);
+        let retagging = LookupChanges::between(&tagged, &retagged);
+        let retirement = LookupChanges::between(&retagged, &decommissioned);
+
+        // Assert
+        assert_eq!(
+            registration.claimed,
+            [(LookupField::Hostname, "web-01".to_string())]
+        );
+        assert_eq!(
+            retagging.claimed,
+            [(LookupField::AssetTag, "AT-2".to_string())]
+        );
+        assert_eq!(
+            retagging.released,
+            [(LookupField::AssetTag, "AT-1".to_string())]
+        );
+        assert!(retirement.claimed.is_empty());
+        assert_eq!(retirement.released.len(), 2);
+    }
+
+    #[test]
+    fn test_events_are_searched_case_insensitively_by_hostname() {
+        // Arrange
+        let aggregate_id = Uuid::now_v7();
+        let events = test_harness::resource_history(aggregate_id, "web-01");
+
+        // Act
+        let found = find_in_events(&events, LookupField::Hostname, "WEB-01");
+        let missing = find_in_events(&events, LookupField::AssetTag, "web-01");
+
+        // Assert
+        assert_eq!(found, Some(aggregate_id));
+        assert_eq!(missing, None);
+        assert_eq!(
+            lookup_key(LookupField::Hostname, "Web-01"),
+            lookup_key(LookupField::Hostname, "web-01")
+        );
+        assert!(lookup_key(LookupField::AssetTag, "AT 1/2").starts_with("asset_tag."));
+    }
+}
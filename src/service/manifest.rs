@@ -0,0 +1,630 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Resumable Manifest Apply
+//!
+//! A [`Manifest`] is a named, ordered list of commands applied as one bulk
+//! operation. [`ManifestApplier`] records the status of every item in an
+//! [`ApplyJournal`], so re-running the same manifest after a crash or a
+//! failed item picks up where the last run stopped.
+//!
+//! # Item Identity
+//!
+//! Items are keyed by a deterministic hash of their content
+//! ([`item_id`]): a UUIDv5 over the command's canonical JSON (object keys
+//! sorted). Reordering a manifest, or inserting items, does not change the
+//! key of an unchanged item. Registration commands without an explicit
+//! `aggregate_id` register the resource under the item ID, so a retried
+//! registration hits the same aggregate instead of creating a duplicate.
+//!
+//! # Journal Semantics
+//!
+//! ```text
+//! (none) ──record──> started ──execute ok──> applied   (skipped on re-run)
+//!                       │
+//!                       └──execute err──> failed      (retried on re-run)
+//! ```
+//!
+//! An item left `started` by a crash may or may not have been stored. On
+//! the next run the applier looks for an event on the target aggregate with
+//! the item's correlation ID and timestamp; if it finds one the item is
+//! marked applied without executing it again.
+//!
+//! The run stops at the first failed item. Cancellation is checked between
+//! items. With [`ManifestApplier::with_progress`], runs publish progress
+//! (operation `manifest_apply`, scoped to the manifest name).
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::service::manifest::{KvApplyJournal, ManifestApplier};
+//!
+//! let journal = KvApplyJournal::open(jetstream).await?;
+//! let applier = ManifestApplier::new(service, event_store, Arc::new(journal));
+//!
+//! let report = applier.apply(&manifest).await?;
+//! if let Some(failure) = report.failure {
+//!     eprintln!("item {} failed: {}", failure.index, failure.message);
+//! }
+//! ```
+
+use async_nats::jetstream::{self, kv};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+use crate::aggregate::commands::ComputeResourceCommand;
+use crate::errors::{InfrastructureError, InfrastructureResult};
+use crate::event_store::EventStore;
+use crate::nats::progress::{CancellationToken, ProgressReporter};
+use crate::nats::NatsClient;
+use crate::service::command_bus::InfrastructureCommand;
+use crate::service::ComputeResourceService;
+
+/// JetStream KV bucket holding apply journals
+pub const APPLY_JOURNAL_BUCKET: &str = "INFRASTRUCTURE_APPLY_JOURNAL";
+
+/// Namespace for manifest and item UUIDv5s
+pub const MANIFEST_NAMESPACE: Uuid = Uuid::from_u128(0x6f1d_2b0e_4c3a_5e8f_9a71_d04b_3c2e_8a15);
+
+/// Named, ordered list of commands applied as one operation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct Manifest {
+    /// Manifest name; runs of the same name share a journal
+    pub name: String,
+
+    /// Commands in apply order
+    pub items: Vec<InfrastructureCommand>,
+}
+
+impl Manifest {
+    /// Create a manifest
+    pub fn new(name: impl Into<String>, items: Vec<InfrastructureCommand>) -> Self {
+        Self {
+            name: name.into(),
+            items,
+        }
+    }
+
+    /// Journal key of this manifest, derived from its name
+    pub fn id(&self) -> Uuid {
+        Uuid::new_v5(&MANIFEST_NAMESPACE, self.name.as_bytes())
+    }
+}
+
+/// Deterministic ID of a manifest item, derived from its content
+pub fn item_id(command: &InfrastructureCommand) -> Uuid {
+    let value = serde_json::to_value(command).expect("commands serialize to JSON");
+    let canonical = serde_json::to_vec(&canonicalize(value)).expect("JSON values serialize");
+    Uuid::new_v5(&MANIFEST_NAMESPACE, &canonical)
+}
+
+/// Sort object keys recursively so equal commands hash equally
+fn canonicalize(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut entries: Vec<_> = map.into_iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            serde_json::Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, canonicalize(value)))
+                    .collect(),
+            )
+        }
+        serde_json::Value::Array(items) => {
+            serde_json::Value::Array(items.into_iter().map(canonicalize).collect())
+        }
+        other => other,
+    }
+}
+
+/// Aggregate an item is applied to
+///
+/// Registrations without an explicit ID use the item ID, so every run
+/// targets the same aggregate.
+fn target_aggregate(item_id: Uuid, command: &InfrastructureCommand) -> Option<Uuid> {
+    match command {
+        InfrastructureCommand::ComputeResource {
+            aggregate_id,
+            command,
+        } => match command {
+            ComputeResourceCommand::RegisterResource(_) => Some(aggregate_id.unwrap_or(item_id)),
+            _ => *aggregate_id,
+        },
+    }
+}
+
+/// Status of one manifest item
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum ItemStatus {
+    /// Execution began; the outcome was not recorded
+    Started,
+
+    /// Command was stored
+    Applied {
+        /// Aggregate the command was applied to
+        aggregate_id: Uuid,
+    },
+
+    /// Command was rejected
+    Failed {
+        /// Rejection reason
+        message: String,
+    },
+}
+
+/// Journal record of one manifest item
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct JournalEntry {
+    /// Content hash of the item ([`item_id`])
+    pub item_id: Uuid,
+
+    /// Position in the manifest during the last run
+    pub index: usize,
+
+    /// Command name, for operators reading the journal
+    pub command: String,
+
+    /// Current status
+    pub status: ItemStatus,
+
+    /// Times execution was started
+    pub attempts: u32,
+
+    /// When the status last changed
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Durable per-item status of manifest runs
+#[async_trait]
+pub trait ApplyJournal: Send + Sync {
+    /// Recorded entry of an item, if any
+    async fn load(
+        &self,
+        manifest_id: Uuid,
+        item_id: Uuid,
+    ) -> InfrastructureResult<Option<JournalEntry>>;
+
+    /// Record an item's entry, replacing the previous one
+    async fn record(&self, manifest_id: Uuid, entry: &JournalEntry) -> InfrastructureResult<()>;
+}
+
+/// In-memory journal, for tests and one-shot runs
+#[derive(Debug, Default)]
+pub struct MemoryApplyJournal {
+    entries: Mutex<HashMap<(Uuid, Uuid), JournalEntry>>,
+}
+
+impl MemoryApplyJournal {
+    /// Create an empty journal
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl ApplyJournal for MemoryApplyJournal {
+    async fn load(
+        &self,
+        manifest_id: Uuid,
+        item_id: Uuid,
+    ) -> InfrastructureResult<Option<JournalEntry>> {
+        let entries = self.entries.lock().expect("journal lock poisoned");
+        Ok(entries.get(&(manifest_id, item_id)).cloned())
+    }
+
+    async fn record(&self, manifest_id: Uuid, entry: &JournalEntry) -> InfrastructureResult<()> {
+        let mut entries = self.entries.lock().expect("journal lock poisoned");
+        entries.insert((manifest_id, entry.item_id), entry.clone());
+        Ok(())
+    }
+}
+
+/// Journal stored in a JetStream KV bucket, keyed `<manifest_id>.<item_id>`
+pub struct KvApplyJournal {
+    store: kv::Store,
+}
+
+impl KvApplyJournal {
+    /// Open the journal bucket, creating it if needed
+    pub async fn open(jetstream: jetstream::Context) -> InfrastructureResult<Self> {
+        let store = match jetstream.get_key_value(APPLY_JOURNAL_BUCKET).await {
+            Ok(store) => store,
+            Err(_) => jetstream
+                .create_key_value(kv::Config {
+                    bucket: APPLY_JOURNAL_BUCKET.to_string(),
+                    description: "Per-item status of manifest apply runs".to_string(),
+                    history: 5,
+                    ..Default::default()
+                })
+                .await
+                .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?,
+        };
+
+        Ok(Self { store })
+    }
+
+    fn key(manifest_id: Uuid, item_id: Uuid) -> String {
+        format!("{}.{}", manifest_id, item_id)
+    }
+}
+
+#[async_trait]
+impl ApplyJournal for KvApplyJournal {
+    async fn load(
+        &self,
+        manifest_id: Uuid,
+        item_id: Uuid,
+    ) -> InfrastructureResult<Option<JournalEntry>> {
+        let value = self
+            .store
+            .get(Self::key(manifest_id, item_id))
+            .await
+            .map_err(|e| InfrastructureError::NatsSubscribe(e.to_string()))?;
+
+        value
+            .map(|bytes| {
+                serde_json::from_slice(&bytes)
+                    .map_err(|e| InfrastructureError::Deserialization(e.to_string()))
+            })
+            .transpose()
+    }
+
+    async fn record(&self, manifest_id: Uuid, entry: &JournalEntry) -> InfrastructureResult<()> {
+        let payload = serde_json::to_vec(entry)
+            .map_err(|e| InfrastructureError::Serialization(e.to_string()))?;
+
+        self.store
+            .put(Self::key(manifest_id, entry.item_id), payload.into())
+            .await
+            .map_err(|e| InfrastructureError::NatsPublish(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+/// Item that stopped a run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemFailure {
+    /// Position in the manifest
+    pub index: usize,
+
+    /// Content hash of the item
+    pub item_id: Uuid,
+
+    /// Rejection reason
+    pub message: String,
+}
+
+/// Outcome of one manifest run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApplyReport {
+    /// Journal key of the manifest
+    pub manifest_id: Uuid,
+
+    /// Items executed in this run
+    pub applied: usize,
+
+    /// Items already applied by an earlier run
+    pub skipped: usize,
+
+    /// Items an interrupted run had stored without recording it
+    pub recovered: usize,
+
+    /// Item that stopped the run, if any
+    pub failure: Option<ItemFailure>,
+}
+
+impl ApplyReport {
+    /// Whether every item is applied
+    pub fn is_complete(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+/// What happened to one item
+enum ItemOutcome {
+    Applied,
+    Skipped,
+    Recovered,
+    Failed(String),
+}
+
+/// Applies manifests through the service layer, journalling every item
+pub struct ManifestApplier<S> {
+    service: Arc<S>,
+    event_store: Arc<dyn EventStore>,
+    journal: Arc<dyn ApplyJournal>,
+    progress: Option<NatsClient>,
+}
+
+impl<S: ComputeResourceService> ManifestApplier<S> {
+    /// Apply through `service`, checking interrupted items in `event_store`
+    pub fn new(
+        service: Arc<S>,
+        event_store: Arc<dyn EventStore>,
+        journal: Arc<dyn ApplyJournal>,
+    ) -> Self {
+        Self {
+            service,
+            event_store,
+            journal,
+            progress: None,
+        }
+    }
+
+    /// Publish progress of runs through `client`
+    pub fn with_progress(mut self, client: NatsClient) -> Self {
+        self.progress = Some(client);
+        self
+    }
+
+    /// Apply every item not yet applied
+    ///
+    /// A rejected item stops the run and is reported in
+    /// [`ApplyReport::failure`]; journal and event store errors are
+    /// returned as errors. Either way, items applied so far stay recorded.
+    pub async fn apply(&self, manifest: &Manifest) -> InfrastructureResult<ApplyReport> {
+        self.apply_with(manifest, &CancellationToken::new()).await
+    }
+
+    /// Apply every item not yet applied, stopping early if `cancel` fires
+    ///
+    /// A cancelled run returns [`InfrastructureError::Cancelled`]; re-running
+    /// the manifest continues after the last applied item.
+    pub async fn apply_with(
+        &self,
+        manifest: &Manifest,
+        cancel: &CancellationToken,
+    ) -> InfrastructureResult<ApplyReport> {
+        let manifest_id = manifest.id();
+        let mut report = ApplyReport {
+            manifest_id,
+            applied: 0,
+            skipped: 0,
+            recovered: 0,
+            failure: None,
+        };
+
+        let mut progress = ProgressReporter::new("manifest_apply").with_scope(&manifest.name);
+        if let Some(client) = &self.progress {
+            progress = progress.with_client(client.clone());
+        }
+        progress.start(Some(manifest.items.len() as u64)).await;
+
+        info!(
+            "Applying manifest {} ({} items)",
+            manifest.name,
+            manifest.items.len()
+        );
+
+        for (index, command) in manifest.items.iter().enumerate() {
+            if cancel.is_cancelled() {
+                progress.cancel().await;
+                return Err(InfrastructureError::Cancelled(format!(
+                    "manifest {}",
+                    manifest.name
+                )));
+            }
+
+            let item_id = item_id(command);
+            let outcome = match self.apply_item(manifest_id, index, item_id, command).await {
+                Ok(outcome) => outcome,
+                Err(e) => {
+                    progress.fail(e.to_string()).await;
+                    return Err(e);
+                }
+            };
+
+            match outcome {
+                ItemOutcome::Applied => report.applied += 1,
+                ItemOutcome::Skipped => report.skipped += 1,
+                ItemOutcome::Recovered => report.recovered += 1,
+                ItemOutcome::Failed(message) => {
+                    warn!(
+                        "Manifest {} stopped at item {}: {}",
+                        manifest.name, index, message
+                    );
+                    progress.fail(message.clone()).await;
+                    report.failure = Some(ItemFailure {
+                        index,
+                        item_id,
+                        message,
+                    });
+                    return Ok(report);
+                }
+            }
+            progress.advance(1).await;
+        }
+
+        progress.complete().await;
+        Ok(report)
+    }
+
+    async fn apply_item(
+        &self,
+        manifest_id: Uuid,
+        index: usize,
+        item_id: Uuid,
+        command: &InfrastructureCommand,
+    ) -> InfrastructureResult<ItemOutcome> {
+        let previous = self.journal.load(manifest_id, item_id).await?;
+        let target = target_aggregate(item_id, command);
+        let attempts = previous.as_ref().map_or(0, |entry| entry.attempts);
+
+        let mut entry = JournalEntry {
+            item_id,
+            index,
+            command: command.command_name().to_string(),
+            status: ItemStatus::Started,
+            attempts,
+            updated_at: Utc::now(),
+        };
+
+        match previous.map(|entry| entry.status) {
+            Some(ItemStatus::Applied { .. }) => {
+                debug!("Item {} ({}) already applied", index, item_id);
+                return Ok(ItemOutcome::Skipped);
+            }
+            Some(ItemStatus::Started) => {
+                if let Some(aggregate_id) = self.landed(target, command).await? {
+                    entry.status = ItemStatus::Applied { aggregate_id };
+                    self.journal.record(manifest_id, &entry).await?;
+                    return Ok(ItemOutcome::Recovered);
+                }
+            }
+            Some(ItemStatus::Failed { .. }) | None => {}
+        }
+
+        entry.attempts += 1;
+        self.journal.record(manifest_id, &entry).await?;
+
+        let result = match command.clone() {
+            InfrastructureCommand::ComputeResource { command, .. } => {
+                self.service.execute(target, command).await
+            }
+        };
+
+        let outcome = match result {
+            Ok(aggregate_id) => {
+                entry.status = ItemStatus::Applied { aggregate_id };
+                ItemOutcome::Applied
+            }
+            Err(e) => {
+                entry.status = ItemStatus::Failed {
+                    message: e.to_string(),
+                };
+                ItemOutcome::Failed(e.to_string())
+            }
+        };
+
+        entry.updated_at = Utc::now();
+        self.journal.record(manifest_id, &entry).await?;
+
+        Ok(outcome)
+    }
+
+    /// Aggregate holding the item's event, if an earlier run stored it
+    async fn landed(
+        &self,
+        target: Option<Uuid>,
+        command: &InfrastructureCommand,
+    ) -> InfrastructureResult<Option<Uuid>> {
+        let Some(aggregate_id) = target else {
+            return Ok(None);
+        };
+
+        let events = self.event_store.read_events(aggregate_id).await?;
+        let found = events.iter().any(|stored| {
+            stored.data.correlation_id() == command.correlation_id()
+                && stored.data.timestamp() == command.timestamp()
+        });
+
+        Ok(found.then_some(aggregate_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aggregate::commands::{AssignAssetTagCommand, RegisterResourceCommand};
+    use crate::domain::{Hostname, ResourceType};
+
+    fn test_timestamp() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-19T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn register_command(correlation_id: Uuid) -> InfrastructureCommand {
+        InfrastructureCommand::ComputeResource {
+            aggregate_id: None,
+            command: ComputeResourceCommand::RegisterResource(RegisterResourceCommand {
+                hostname: Hostname::new("web01.example.com").unwrap(),
+                resource_type: ResourceType::PhysicalServer,
+                timestamp: test_timestamp(),
+                correlation_id,
+                change_ref: None,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_item_id_is_stable_and_content_derived() {
+        // Arrange
+        let correlation_id = Uuid::now_v7();
+        let command = register_command(correlation_id);
+
+        // Act - the same command written with its keys in another order
+        let json = format!(
+            r#"{{"command": {}, "aggregate_type": "compute_resource"}}"#,
+            serde_json::to_string(&serde_json::to_value(&command).unwrap()["command"]).unwrap()
+        );
+        let decoded: InfrastructureCommand = serde_json::from_str(&json).unwrap();
+
+        // Assert
+        assert_eq!(item_id(&command), item_id(&decoded));
+        assert_ne!(
+            item_id(&command),
+            item_id(&register_command(Uuid::now_v7()))
+        );
+        assert_eq!(
+            target_aggregate(item_id(&command), &command),
+            Some(item_id(&command))
+        );
+    }
+
+    #[test]
+    fn test_updates_target_their_aggregate() {
+        let aggregate_id = Uuid::now_v7();
+        let command = InfrastructureCommand::ComputeResource {
+            aggregate_id: Some(aggregate_id),
+            command: ComputeResourceCommand::AssignAssetTag(AssignAssetTagCommand {
+                asset_tag: "A-1001".to_string(),
+                timestamp: test_timestamp(),
+                correlation_id: Uuid::now_v7(),
+                causation_id: None,
+                change_ref: None,
+            }),
+        };
+
+        assert_eq!(
+            target_aggregate(item_id(&command), &command),
+            Some(aggregate_id)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_memory_journal_keeps_latest_entry() {
+        let journal = MemoryApplyJournal::new();
+        let manifest = Manifest::new("rack-a", vec![register_command(Uuid::now_v7())]);
+        let item = item_id(&manifest.items[0]);
+        let mut entry = JournalEntry {
+            item_id: item,
+            index: 0,
+            command: "register_resource".to_string(),
+            status: ItemStatus::Started,
+            attempts: 1,
+            updated_at: test_timestamp(),
+        };
+
+        journal.record(manifest.id(), &entry).await.unwrap();
+        entry.status = ItemStatus::Applied { aggregate_id: item };
+        journal.record(manifest.id(), &entry).await.unwrap();
+
+        let loaded = journal.load(manifest.id(), item).await.unwrap().unwrap();
+        assert_eq!(loaded.status, ItemStatus::Applied { aggregate_id: item });
+        assert_eq!(manifest.id(), Manifest::new("rack-a", Vec::new()).id());
+        assert!(journal.load(Uuid::now_v7(), item).await.unwrap().is_none());
+    }
+}
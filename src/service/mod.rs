@@ -28,6 +28,52 @@
 //! - **Event Bus**: NATS publishing for projections
 //! - **Query Side**: Read models and projections
 //!
+//! # Command Bus
+//!
+//! Besides the Rust API, commands can be sent over NATS request/reply on
+//! `infrastructure.cmd.{aggregate}.{command}`; see [`command_bus`].
+//! Commands and events due at a later time are kept by a
+//! [`scheduler::Scheduler`] until then.
+//!
+//! # Units of Work
+//!
+//! Commands against several resources that must see one consistent state
+//! are staged in a [`unit_of_work::UnitOfWork`] and committed together.
+//!
+//! # Manifests
+//!
+//! Bulk changes are applied as [`manifest::Manifest`]s, whose per-item
+//! journal makes re-running a partially applied manifest safe.
+//!
+//! Inventories described in YAML or JSON are brought in by
+//! [`bulk_import::BulkImportService`], which reports progress while it runs.
+//!
+//! # Write Queue
+//!
+//! Concurrent local commands to one resource can be made to take turns
+//! instead of failing with concurrency conflicts; see [`write_queue`].
+//! Instances sharing a NATS cluster serialize writers of hot resources with
+//! leases; see [`lease`]. Commands that do hit a conflict can be retried
+//! against the reloaded resource with [`retry::RetryOnConflict`].
+//!
+//! # Authorization
+//!
+//! The API edges identify the sender of each request with an
+//! [`authentication::Authenticator`] and handle it under that actor and
+//! tenant. Every command is put to an [`authorization::Authorizer`] before
+//! it is handled; [`authorization::PolicyAuthorizer`] grants commands by
+//! role, as the domain policies governing the target allow.
+//!
+//! # Lookups
+//!
+//! Hostnames and asset tags are kept unique, and resources found by them,
+//! through a KV index maintained on append; see [`lookup`].
+//!
+//! # Rate Limits
+//!
+//! The command bus and REST API refuse commands of organizations over
+//! their rate, from token buckets shared through KV; see [`rate_limit`].
+//!
 //! # Design Principles
 //!
 //! 1. **Transaction Boundaries**: Services define transaction scope
@@ -57,8 +103,38 @@
 //! }
 //! ```
 
+pub mod authentication;
+pub mod authorization;
+pub mod bulk_import;
+pub mod command_bus;
 pub mod compute_resource;
+pub mod connection;
+pub mod lease;
+pub mod lookup;
+pub mod manifest;
+pub mod network;
+pub mod rate_limit;
+pub mod retry;
+pub mod scheduler;
+pub mod unit_of_work;
+pub mod write_queue;
 
+pub use authentication::{AuthenticationError, Authenticator, TokenAuthenticator};
+pub use authorization::{AllowAll, Authorizer, DomainPolicies, PolicyAuthorizer};
+pub use bulk_import::{BulkImportReport, BulkImportService, ImportProgress};
+pub use command_bus::{CommandReply, CommandSubscriber, InfrastructureCommand, NackReason};
 pub use compute_resource::{
     ComputeResourceService, EventSourcedComputeResourceService, ServiceError, ServiceResult,
 };
+pub use connection::{ConnectionService, EventSourcedConnectionService};
+pub use lease::{LeaseConfig, LeaseManager, LeaseMetrics};
+pub use lookup::{LookupError, LookupIndex};
+pub use manifest::{
+    ApplyJournal, ApplyReport, KvApplyJournal, Manifest, ManifestApplier, MemoryApplyJournal,
+};
+pub use network::{EventSourcedNetworkService, NetworkService};
+pub use rate_limit::{RateLimit, RateLimitConfig, RateLimitError, RateLimiter};
+pub use retry::RetryOnConflict;
+pub use scheduler::{Schedule, ScheduledAction, Scheduler, SchedulerConfig};
+pub use unit_of_work::{CommittedUnit, UnitOfWork};
+pub use write_queue::{WriteQueue, WriteQueueConfig, WriteQueueMetrics};
@@ -0,0 +1,459 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Network Service Layer
+//!
+//! Application service for Network and NetworkInterface aggregates. Same
+//! transaction semantics as the compute resource service: load, handle
+//! (pure), append with optimistic concurrency, publish.
+//!
+//! Attaching an interface reads the target network's current state so the
+//! handler can check the addresses against its prefix. The network itself
+//! is not locked, so a renumbering racing the attachment is not detected.
+//!
+//! Likewise, defining, renumbering or re-tagging a network reads every
+//! other network to reject overlapping prefixes and VLAN reuse; two such
+//! commands racing each other are not detected.
+//!
+//! Forming a bond reads its member interfaces and every other interface, so
+//! a member cannot join two bonds; members are not locked either, so two
+//! bonds formed concurrently over the same interface are not detected.
+//!
+//! Every command is put to the service's
+//! [`Authorizer`](crate::service::authorization::Authorizer) first.
+//!
+//! # Subjects
+//!
+//! ```text
+//! infrastructure.network.<aggregate_id>.<operation>
+//! infrastructure.interface.<aggregate_id>.<operation>
+//! ```
+//!
+//! Interfaces attached by earlier releases stay under `network`; reads
+//! consult both.
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::aggregate::network::*;
+use crate::aggregate::network_interface::*;
+use crate::event_store::provenance::EventEnvelopeMetadata;
+use crate::event_store::{EventStore, NatsEventStore};
+use crate::events::{InfrastructureEvent, NetworkEvent, NetworkInterfaceEvent};
+use crate::nats::NatsClient;
+use crate::service::authorization::{AllowAll, AuthorizationRequest, Authorizer};
+use crate::service::{ServiceError, ServiceResult};
+use crate::subjects::AggregateType;
+
+/// Network service trait
+#[async_trait]
+pub trait NetworkService: Send + Sync {
+    /// Define a new network
+    ///
+    /// # Returns
+    /// - Aggregate ID of the new network
+    async fn define_network(&self, command: DefineNetworkCommand) -> ServiceResult<Uuid>;
+
+    /// Renumber a network
+    async fn change_cidr(&self, network_id: Uuid, command: ChangeCidrCommand) -> ServiceResult<()>;
+
+    /// Bind a network to a VLAN
+    async fn assign_vlan(&self, network_id: Uuid, command: AssignVlanCommand) -> ServiceResult<()>;
+
+    /// Attach an interface to a network
+    ///
+    /// With `interface_id` None a new interface is created; otherwise the
+    /// existing interface is moved.
+    ///
+    /// # Returns
+    /// - Aggregate ID of the interface
+    async fn attach_interface(
+        &self,
+        interface_id: Option<Uuid>,
+        command: AttachInterfaceCommand,
+    ) -> ServiceResult<Uuid>;
+
+    /// Form a bond from member interfaces of one resource
+    ///
+    /// # Returns
+    /// - Aggregate ID of the bond interface
+    async fn form_bond(&self, command: FormBondCommand) -> ServiceResult<Uuid>;
+
+    /// Dissolve a bond, releasing its members
+    async fn dissolve_bond(&self, bond_id: Uuid, command: DissolveBondCommand)
+        -> ServiceResult<()>;
+
+    /// Get current state of a network
+    async fn get_network(&self, network_id: Uuid) -> ServiceResult<NetworkState>;
+
+    /// Get current state of an interface
+    async fn get_interface(&self, interface_id: Uuid) -> ServiceResult<NetworkInterfaceState>;
+}
+
+/// Event-sourced implementation of NetworkService
+pub struct EventSourcedNetworkService {
+    /// Event store for persistence
+    event_store: NatsEventStore,
+
+    /// NATS client for publishing
+    nats_client: NatsClient,
+
+    /// Provenance recorded on every event written
+    envelope: Option<EventEnvelopeMetadata>,
+
+    /// Asked before every command
+    authorizer: Arc<dyn Authorizer>,
+}
+
+impl EventSourcedNetworkService {
+    /// Create a new event-sourced service
+    pub fn new(event_store: NatsEventStore, nats_client: NatsClient) -> Self {
+        Self {
+            event_store,
+            nats_client,
+            envelope: None,
+            authorizer: Arc::new(AllowAll),
+        }
+    }
+
+    /// Record provenance (source system, schema, tenant) on every event
+    ///
+    /// Fields set by the request's envelope
+    /// ([`EventEnvelopeMetadata::scope`]) take precedence.
+    pub fn with_envelope(mut self, envelope: EventEnvelopeMetadata) -> Self {
+        self.envelope = Some(envelope);
+        self
+    }
+
+    /// Ask `authorizer` before every command
+    ///
+    /// Commands it does not allow fail with [`ServiceError::Unauthorized`].
+    /// Without one, every command is allowed.
+    pub fn with_authorizer(mut self, authorizer: Arc<dyn Authorizer>) -> Self {
+        self.authorizer = authorizer;
+        self
+    }
+
+    /// Ask the authorizer whether the request's actor may run `command`
+    /// against a network or interface
+    async fn authorize(
+        &self,
+        aggregate_type: AggregateType,
+        command: &str,
+        aggregate_id: Uuid,
+    ) -> ServiceResult<()> {
+        let envelope = EventEnvelopeMetadata::resolve(self.envelope.as_ref());
+        let request = AuthorizationRequest {
+            actor: envelope
+                .as_ref()
+                .and_then(|envelope| envelope.actor.as_ref()),
+            aggregate_type,
+            command,
+            aggregate_id,
+            policy_ids: &[],
+        };
+        Ok(self.authorizer.authorize(&request).await?)
+    }
+
+    /// Load an aggregate's events with their current version
+    async fn load_events(
+        &self,
+        aggregate_id: Uuid,
+    ) -> ServiceResult<(Vec<InfrastructureEvent>, u64)> {
+        let stored_events = self
+            .event_store
+            .read_events(aggregate_id)
+            .await
+            .map_err(|e| ServiceError::EventStoreError(e.to_string()))?;
+
+        let version = stored_events.last().map(|e| e.sequence).unwrap_or(0);
+        let events = stored_events
+            .into_iter()
+            .map(|stored| stored.data)
+            .collect();
+        Ok((events, version))
+    }
+
+    /// Load current network state with its version
+    async fn load_network(&self, network_id: Uuid) -> ServiceResult<(NetworkState, u64)> {
+        let (events, version) = self.load_events(network_id).await?;
+        let events: Vec<NetworkEvent> = events
+            .into_iter()
+            .filter_map(|event| match event {
+                InfrastructureEvent::Network(event) => Some(event),
+                _ => None,
+            })
+            .collect();
+
+        let state = if events.is_empty() {
+            NetworkState::default_for(network_id)
+        } else {
+            NetworkState::from_events(&events)
+        };
+        Ok((state, version))
+    }
+
+    /// Load the current state of every defined network
+    async fn load_networks(&self) -> ServiceResult<Vec<NetworkState>> {
+        let stored_events = self
+            .event_store
+            .read_aggregate_type(AggregateType::Network)
+            .await
+            .map_err(|e| ServiceError::EventStoreError(e.to_string()))?;
+
+        let mut by_network: HashMap<Uuid, Vec<NetworkEvent>> = HashMap::new();
+        for stored in stored_events {
+            if let InfrastructureEvent::Network(event) = stored.data {
+                by_network
+                    .entry(stored.aggregate_id)
+                    .or_default()
+                    .push(event);
+            }
+        }
+
+        Ok(by_network
+            .into_values()
+            .map(|events| NetworkState::from_events(&events))
+            .collect())
+    }
+
+    /// Load current interface state with its version
+    async fn load_interface(
+        &self,
+        interface_id: Uuid,
+    ) -> ServiceResult<(NetworkInterfaceState, u64)> {
+        let (events, version) = self.load_events(interface_id).await?;
+        let events: Vec<NetworkInterfaceEvent> = events
+            .into_iter()
+            .filter_map(|event| match event {
+                InfrastructureEvent::NetworkInterface(event) => Some(event),
+                _ => None,
+            })
+            .collect();
+
+        let state = if events.is_empty() {
+            NetworkInterfaceState::default_for(interface_id)
+        } else {
+            NetworkInterfaceState::from_events(&events)
+        };
+        Ok((state, version))
+    }
+
+    /// Load the current state of every interface of a resource
+    async fn load_resource_interfaces(
+        &self,
+        resource_id: Uuid,
+    ) -> ServiceResult<Vec<NetworkInterfaceState>> {
+        // Interfaces attached before they had their own subjects live under
+        // the network aggregate
+        let mut stored_events = Vec::new();
+        for aggregate_type in [AggregateType::Network, AggregateType::Interface] {
+            stored_events.extend(
+                self.event_store
+                    .read_aggregate_type(aggregate_type)
+                    .await
+                    .map_err(|e| ServiceError::EventStoreError(e.to_string()))?,
+            );
+        }
+
+        let mut by_interface: HashMap<Uuid, Vec<NetworkInterfaceEvent>> = HashMap::new();
+        for stored in stored_events {
+            if let InfrastructureEvent::NetworkInterface(event) = stored.data {
+                by_interface
+                    .entry(stored.aggregate_id)
+                    .or_default()
+                    .push(event);
+            }
+        }
+
+        Ok(by_interface
+            .into_values()
+            .map(|events| NetworkInterfaceState::from_events(&events))
+            .filter(|state| state.resource_id == Some(resource_id))
+            .collect())
+    }
+
+    /// Append event to the store and publish it to NATS
+    async fn append_and_publish(
+        &self,
+        aggregate_id: Uuid,
+        event: InfrastructureEvent,
+        expected_version: Option<u64>,
+    ) -> ServiceResult<()> {
+        let envelope = EventEnvelopeMetadata::resolve(self.envelope.as_ref());
+        self.event_store
+            .append_with_context(
+                aggregate_id,
+                vec![event.clone()],
+                expected_version,
+                None,
+                envelope.as_ref(),
+            )
+            .await
+            .map_err(|e| ServiceError::EventStoreError(e.to_string()))?;
+
+        self.nats_client
+            .publish(&event.subject(), &event)
+            .await
+            .map_err(|e| ServiceError::NatsError(format!("NATS publish error: {}", e)))?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl NetworkService for EventSourcedNetworkService {
+    async fn define_network(&self, command: DefineNetworkCommand) -> ServiceResult<Uuid> {
+        let aggregate_id = Uuid::now_v7();
+        self.authorize(AggregateType::Network, "define_network", aggregate_id)
+            .await?;
+        let state = NetworkState::default_for(aggregate_id);
+        let existing = self.load_networks().await?;
+        let event = handle_define_network(&state, command, aggregate_id, &existing)?;
+
+        self.append_and_publish(
+            aggregate_id,
+            InfrastructureEvent::Network(NetworkEvent::NetworkDefined(event)),
+            Some(0),
+        )
+        .await?;
+
+        Ok(aggregate_id)
+    }
+
+    async fn change_cidr(&self, network_id: Uuid, command: ChangeCidrCommand) -> ServiceResult<()> {
+        let (state, version) = self.load_network(network_id).await?;
+        if !state.is_initialized() {
+            return Err(ServiceError::NotFound(network_id));
+        }
+        self.authorize(AggregateType::Network, "change_cidr", network_id)
+            .await?;
+
+        let existing = self.load_networks().await?;
+        let event = handle_change_cidr(&state, command, &existing)?;
+        self.append_and_publish(
+            network_id,
+            InfrastructureEvent::Network(NetworkEvent::CidrChanged(event)),
+            Some(version),
+        )
+        .await
+    }
+
+    async fn assign_vlan(&self, network_id: Uuid, command: AssignVlanCommand) -> ServiceResult<()> {
+        let (state, version) = self.load_network(network_id).await?;
+        if !state.is_initialized() {
+            return Err(ServiceError::NotFound(network_id));
+        }
+        self.authorize(AggregateType::Network, "assign_vlan", network_id)
+            .await?;
+
+        let existing = self.load_networks().await?;
+        let event = handle_assign_vlan(&state, command, &existing)?;
+        self.append_and_publish(
+            network_id,
+            InfrastructureEvent::Network(NetworkEvent::VlanAssigned(event)),
+            Some(version),
+        )
+        .await
+    }
+
+    async fn attach_interface(
+        &self,
+        interface_id: Option<Uuid>,
+        command: AttachInterfaceCommand,
+    ) -> ServiceResult<Uuid> {
+        let (network, _) = self.load_network(command.network_id).await?;
+        if !network.is_initialized() {
+            return Err(ServiceError::NotFound(command.network_id));
+        }
+
+        let (state, version) = match interface_id {
+            Some(id) => {
+                let (state, version) = self.load_interface(id).await?;
+                if !state.is_initialized() {
+                    return Err(ServiceError::NotFound(id));
+                }
+                (state, version)
+            }
+            None => (NetworkInterfaceState::default_for(Uuid::now_v7()), 0),
+        };
+
+        let aggregate_id = state.id;
+        self.authorize(AggregateType::Interface, "attach_interface", aggregate_id)
+            .await?;
+        let event = handle_attach_interface(&state, command, aggregate_id, &network)?;
+        self.append_and_publish(
+            aggregate_id,
+            InfrastructureEvent::NetworkInterface(NetworkInterfaceEvent::InterfaceAttached(event)),
+            Some(version),
+        )
+        .await?;
+
+        Ok(aggregate_id)
+    }
+
+    async fn form_bond(&self, command: FormBondCommand) -> ServiceResult<Uuid> {
+        let aggregate_id = Uuid::now_v7();
+        self.authorize(AggregateType::Interface, "form_bond", aggregate_id)
+            .await?;
+        let state = NetworkInterfaceState::default_for(aggregate_id);
+
+        // The resource's interfaces hold both the members and any bonds
+        // they might already belong to
+        let interfaces = self.load_resource_interfaces(command.resource_id).await?;
+        if let Some(missing) = command
+            .members
+            .iter()
+            .find(|id| !interfaces.iter().any(|i| i.id == **id))
+        {
+            return Err(ServiceError::NotFound(*missing));
+        }
+
+        let event = handle_form_bond(&state, command, aggregate_id, &interfaces, &interfaces)?;
+        self.append_and_publish(
+            aggregate_id,
+            InfrastructureEvent::NetworkInterface(NetworkInterfaceEvent::BondFormed(event)),
+            Some(0),
+        )
+        .await?;
+
+        Ok(aggregate_id)
+    }
+
+    async fn dissolve_bond(
+        &self,
+        bond_id: Uuid,
+        command: DissolveBondCommand,
+    ) -> ServiceResult<()> {
+        let (state, version) = self.load_interface(bond_id).await?;
+        if !state.is_initialized() {
+            return Err(ServiceError::NotFound(bond_id));
+        }
+        self.authorize(AggregateType::Interface, "dissolve_bond", bond_id)
+            .await?;
+
+        let event = handle_dissolve_bond(&state, command)?;
+        self.append_and_publish(
+            bond_id,
+            InfrastructureEvent::NetworkInterface(NetworkInterfaceEvent::BondDissolved(event)),
+            Some(version),
+        )
+        .await
+    }
+
+    async fn get_network(&self, network_id: Uuid) -> ServiceResult<NetworkState> {
+        let (state, _) = self.load_network(network_id).await?;
+        if !state.is_initialized() {
+            return Err(ServiceError::NotFound(network_id));
+        }
+        Ok(state)
+    }
+
+    async fn get_interface(&self, interface_id: Uuid) -> ServiceResult<NetworkInterfaceState> {
+        let (state, _) = self.load_interface(interface_id).await?;
+        if !state.is_initialized() {
+            return Err(ServiceError::NotFound(interface_id));
+        }
+        Ok(state)
+    }
+}
@@ -0,0 +1,430 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Per-Organization Rate Limiting
+//!
+//! A [`RateLimiter`] keeps one token bucket per organization in a
+//! JetStream KV bucket, so every instance of the command bus and REST API
+//! draws from the same budget:
+//!
+//! ```text
+//! infrastructure-rate-limits
+//!   org.<base64url(org id)>  ──> {"tokens": 3.5, "refilled_at": "…", "exhausted": false}
+//!   anonymous                ──> commands naming no organization
+//!
+//! command ──> refill by elapsed × per_second (up to burst) ──> take one token
+//!               └── less than one left ──> refuse (NackReason::RateLimited / 429)
+//! ```
+//!
+//! Buckets are updated with a compare-and-set on the entry's revision
+//! (see [`nats::kv`](crate::nats::kv)), so concurrent instances never hand
+//! out the same token twice. When a bucket first runs dry an
+//! [`AdvisoryEvent::QuotaExceeded`] is published; it is not repeated for
+//! the commands refused until the bucket refills.
+//!
+//! The organization is the tenant of the request's authenticated
+//! [`EventEnvelopeMetadata`](crate::event_store::provenance::EventEnvelopeMetadata),
+//! never a header the sender chose.
+//!
+//! The limiter fails closed: if the bucket cannot be read or written, or
+//! stays contended, the command is refused as unavailable
+//! ([`RateLimitError::Unavailable`]). Deployments that prefer admitting
+//! commands to refusing them while KV is down opt in with
+//! [`RateLimitConfig::with_fail_open`].
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::service::rate_limit::{RateLimit, RateLimitConfig, RateLimiter};
+//!
+//! let config = RateLimitConfig::new(RateLimit::per_second(20.0).with_burst(50))
+//!     .with_organization(big_customer_id.to_string(), RateLimit::per_second(200.0));
+//! let limiter = RateLimiter::open(jetstream.clone(), config)
+//!     .await?
+//!     .with_advisories(nats_client.clone());
+//!
+//! let subscriber = CommandSubscriber::new(nats_client, service)
+//!     .with_authenticator(authenticator.clone())
+//!     .with_rate_limiter(limiter.clone());
+//! let app = rest::authenticated(
+//!     rest::rate_limited(rest::router(service, read_model), limiter),
+//!     authenticator,
+//! );
+//! ```
+
+use async_nats::jetstream::{self, kv};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::errors::{InfrastructureError, InfrastructureResult};
+use crate::events::advisory::{AdvisoryEvent, QuotaExceeded};
+use crate::nats::kv::{create_entry, is_vacant, update_entry};
+use crate::nats::NatsClient;
+
+/// KV bucket holding the token buckets
+pub const RATE_LIMIT_BUCKET: &str = "infrastructure-rate-limits";
+
+/// Compare-and-set attempts before a contended bucket is given up on
+const MAX_ATTEMPTS: usize = 5;
+
+/// Size and refill rate of a token bucket
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RateLimit {
+    /// Commands that may be sent at once after an idle period
+    pub burst: u32,
+
+    /// Commands per second over time
+    pub per_second: f64,
+}
+
+impl RateLimit {
+    /// `per_second` commands per second, with a burst of one second's worth
+    pub fn per_second(per_second: f64) -> Self {
+        Self {
+            burst: per_second.ceil().max(1.0) as u32,
+            per_second,
+        }
+    }
+
+    /// `per_minute` commands per minute, with a burst of one second's worth
+    pub fn per_minute(per_minute: u32) -> Self {
+        Self::per_second(f64::from(per_minute) / 60.0)
+    }
+
+    /// Set the burst size
+    pub fn with_burst(mut self, burst: u32) -> Self {
+        self.burst = burst;
+        self
+    }
+}
+
+/// Rate limits by organization
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RateLimitConfig {
+    /// Limit of organizations without one of their own, and of commands
+    /// naming no organization
+    pub default: RateLimit,
+
+    /// Limits of individual organizations
+    #[serde(default)]
+    pub organizations: HashMap<String, RateLimit>,
+
+    /// Admit commands when the buckets cannot be used, instead of
+    /// refusing them
+    #[serde(default)]
+    pub fail_open: bool,
+}
+
+impl RateLimitConfig {
+    /// Every organization limited to `default`
+    pub fn new(default: RateLimit) -> Self {
+        Self {
+            default,
+            organizations: HashMap::new(),
+            fail_open: false,
+        }
+    }
+
+    /// Give `organization_id` a limit of its own
+    pub fn with_organization(
+        mut self,
+        organization_id: impl Into<String>,
+        limit: RateLimit,
+    ) -> Self {
+        self.organizations.insert(organization_id.into(), limit);
+        self
+    }
+
+    /// Admit commands while the buckets cannot be read or written
+    pub fn with_fail_open(mut self, fail_open: bool) -> Self {
+        self.fail_open = fail_open;
+        self
+    }
+
+    /// Limit applying to `organization_id`
+    pub fn limit_for(&self, organization_id: Option<&str>) -> RateLimit {
+        organization_id
+            .and_then(|organization_id| self.organizations.get(organization_id))
+            .copied()
+            .unwrap_or(self.default)
+    }
+}
+
+/// Token bucket of one organization, as stored
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TokenBucket {
+    /// Tokens left
+    pub tokens: f64,
+
+    /// When `tokens` was last brought up to date
+    pub refilled_at: DateTime<Utc>,
+
+    /// Whether a command has been refused since the bucket last had a token
+    #[serde(default)]
+    pub exhausted: bool,
+}
+
+impl TokenBucket {
+    /// Bucket holding a full burst
+    pub fn full(limit: &RateLimit, now: DateTime<Utc>) -> Self {
+        Self {
+            tokens: f64::from(limit.burst),
+            refilled_at: now,
+            exhausted: false,
+        }
+    }
+
+    /// Take one token at `now`
+    ///
+    /// Returns the updated bucket, and on refusal how long until a token is
+    /// available.
+    pub fn take(&self, limit: &RateLimit, now: DateTime<Utc>) -> (Self, Result<(), Duration>) {
+        let elapsed = (now - self.refilled_at)
+            .to_std()
+            .unwrap_or_default()
+            .as_secs_f64();
+        let tokens = (self.tokens + elapsed * limit.per_second).min(f64::from(limit.burst));
+
+        if tokens >= 1.0 {
+            let bucket = Self {
+                tokens: tokens - 1.0,
+                refilled_at: now,
+                exhausted: false,
+            };
+            return (bucket, Ok(()));
+        }
+
+        let retry_after =
+            Duration::try_from_secs_f64((1.0 - tokens) / limit.per_second).unwrap_or(Duration::MAX);
+        let bucket = Self {
+            tokens,
+            refilled_at: now,
+            exhausted: true,
+        };
+        (bucket, Err(retry_after))
+    }
+}
+
+/// A command was refused because its organization is over its rate
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Organization {organization} is over its command rate; retry in {retry_after:?}")]
+pub struct QuotaExhausted {
+    /// Throttled organization, or `anonymous`
+    pub organization: String,
+
+    /// How long until the next command is admitted
+    pub retry_after: Duration,
+}
+
+/// A command was refused by the rate limiter
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum RateLimitError {
+    /// The organization is over its rate
+    #[error(transparent)]
+    Exhausted(#[from] QuotaExhausted),
+
+    /// The bucket could not be used, and the limiter fails closed
+    #[error("Rate limit unavailable: {0}")]
+    Unavailable(String),
+}
+
+/// Per-organization token buckets in a JetStream KV bucket
+///
+/// Cloning is cheap; all clones share the bucket.
+#[derive(Clone)]
+pub struct RateLimiter {
+    store: kv::Store,
+    config: Arc<RateLimitConfig>,
+    advisories: Option<NatsClient>,
+}
+
+impl RateLimiter {
+    /// Open the rate limit bucket, creating it if needed
+    pub async fn open(
+        jetstream: jetstream::Context,
+        config: RateLimitConfig,
+    ) -> InfrastructureResult<Self> {
+        let store = match jetstream.get_key_value(RATE_LIMIT_BUCKET).await {
+            Ok(store) => store,
+            Err(_) => jetstream
+                .create_key_value(kv::Config {
+                    bucket: RATE_LIMIT_BUCKET.to_string(),
+                    description: "Per-organization command rate limits".to_string(),
+                    history: 1,
+                    ..Default::default()
+                })
+                .await
+                .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?,
+        };
+
+        Ok(Self {
+            store,
+            config: Arc::new(config),
+            advisories: None,
+        })
+    }
+
+    /// Publish a `QuotaExceeded` advisory when an organization runs dry
+    pub fn with_advisories(mut self, client: NatsClient) -> Self {
+        self.advisories = Some(client);
+        self
+    }
+
+    /// Admit one command of `organization_id`, or refuse it
+    ///
+    /// `correlation_id` is the command's, recorded on the advisory.
+    pub async fn check(
+        &self,
+        organization_id: Option<&str>,
+        correlation_id: Uuid,
+    ) -> Result<(), RateLimitError> {
+        let limit = self.config.limit_for(organization_id);
+        let (admitted, newly_exhausted) = match self.take(organization_id, &limit).await {
+            Ok(taken) => taken,
+            Err(e) if self.config.fail_open => {
+                warn!("Rate limit not applied: {}", e);
+                return Ok(());
+            }
+            Err(e) => return Err(RateLimitError::Unavailable(e.to_string())),
+        };
+        let Err(retry_after) = admitted else {
+            return Ok(());
+        };
+
+        if newly_exhausted {
+            self.advise(organization_id, &limit, retry_after, correlation_id)
+                .await;
+        }
+        Err(RateLimitError::Exhausted(QuotaExhausted {
+            organization: organization_id.unwrap_or("anonymous").to_string(),
+            retry_after,
+        }))
+    }
+
+    /// Take a token with compare-and-set, retrying on contention
+    ///
+    /// Returns the outcome and whether this take emptied the bucket.
+    async fn take(
+        &self,
+        organization_id: Option<&str>,
+        limit: &RateLimit,
+    ) -> InfrastructureResult<(Result<(), Duration>, bool)> {
+        let key = rate_limit_key(organization_id);
+        for _ in 0..MAX_ATTEMPTS {
+            let now = Utc::now();
+            let entry = self
+                .store
+                .entry(&key)
+                .await
+                .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?;
+            let current = entry
+                .as_ref()
+                .filter(|entry| !is_vacant(&entry.operation, &entry.value))
+                .and_then(|entry| serde_json::from_slice::<TokenBucket>(&entry.value).ok());
+
+            let before = current.unwrap_or_else(|| TokenBucket::full(limit, now));
+            let (after, admitted) = before.take(limit, now);
+            let newly_exhausted = after.exhausted && !before.exhausted;
+            let value = serde_json::to_vec(&after)
+                .map_err(|e| InfrastructureError::Serialization(e.to_string()))?;
+
+            let written = match &entry {
+                Some(entry) => update_entry(&self.store, &key, value, entry.revision).await?,
+                None => create_entry(&self.store, &key, value).await?,
+            };
+            if written.is_some() {
+                return Ok((admitted, newly_exhausted));
+            }
+        }
+        Err(InfrastructureError::NatsPublish(format!(
+            "bucket {} stayed contended",
+            key
+        )))
+    }
+
+    async fn advise(
+        &self,
+        organization_id: Option<&str>,
+        limit: &RateLimit,
+        retry_after: Duration,
+        correlation_id: Uuid,
+    ) {
+        let Some(client) = &self.advisories else {
+            return;
+        };
+        let advisory = AdvisoryEvent::QuotaExceeded(QuotaExceeded {
+            event_id: Uuid::now_v7(),
+            detected_at: Utc::now(),
+            correlation_id,
+            organization_id: organization_id.map(str::to_string),
+            burst: limit.burst,
+            per_second: limit.per_second,
+            retry_after_ms: u64::try_from(retry_after.as_millis()).unwrap_or(u64::MAX),
+        });
+        if let Err(e) = client.publish(&advisory.subject(), &advisory).await {
+            warn!("Failed to publish quota advisory: {}", e);
+        }
+    }
+}
+
+/// KV key of an organization's bucket; IDs are encoded since they may hold
+/// any character
+pub fn rate_limit_key(organization_id: Option<&str>) -> String {
+    match organization_id {
+        Some(organization_id) => format!("org.{}", URL_SAFE_NO_PAD.encode(organization_id)),
+        None => "anonymous".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_bucket_refuses_when_dry_and_refills_over_time() {
+        // Arrange
+        let limit = RateLimit::per_second(2.0).with_burst(2);
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let bucket = TokenBucket::full(&limit, start);
+
+        // Act
+        let (bucket, first) = bucket.take(&limit, start);
+        let (bucket, second) = bucket.take(&limit, start);
+        let (dry, third) = bucket.take(&limit, start);
+        let (refilled, later) = dry.take(&limit, start + chrono::Duration::milliseconds(500));
+
+        // Assert
+        assert!(first.is_ok());
+        assert!(second.is_ok());
+        assert_eq!(third, Err(Duration::from_millis(500)));
+        assert!(dry.exhausted);
+        assert!(later.is_ok());
+        assert!(!refilled.exhausted);
+    }
+
+    #[test]
+    fn test_organizations_get_their_own_limits_and_keys() {
+        // Arrange
+        let config = RateLimitConfig::new(RateLimit::per_minute(600))
+            .with_organization("acme", RateLimit::per_second(100.0));
+
+        // Act / Assert
+        assert_eq!(config.limit_for(Some("acme")).burst, 100);
+        assert_eq!(
+            config.limit_for(Some("globex")),
+            RateLimit::per_second(10.0)
+        );
+        assert_eq!(config.limit_for(None), config.default);
+        assert!(!config.fail_open);
+        assert!(config.with_fail_open(true).fail_open);
+        assert_eq!(rate_limit_key(None), "anonymous");
+        assert_ne!(rate_limit_key(Some("acme")), rate_limit_key(Some("globex")));
+        assert!(rate_limit_key(Some("acme corp/eu")).starts_with("org."));
+    }
+}
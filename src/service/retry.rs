@@ -0,0 +1,400 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Retrying Concurrency Conflicts
+//!
+//! Commands are appended with the version their state was loaded at, so a
+//! command racing another writer of the same resource fails with
+//! [`ServiceError::ConcurrencyConflict`]. Most single-field commands (add a
+//! policy, update metadata) are still valid against the newer state;
+//! [`RetryOnConflict`] runs them again, which reloads the resource and
+//! re-runs the pure command handler:
+//!
+//! ```text
+//! command ──> load state ──> handle ──> append(expected version)
+//!                 ▲                          │
+//!                 └──── ConcurrencyConflict ─┘  (up to n times, with backoff)
+//! ```
+//!
+//! Other errors, including rejections by the command handler against the
+//! newer state, are returned at once. Registrations are not retried: a new
+//! resource cannot conflict, and a taken ID stays taken. A unit of work is
+//! only retried when nothing of it was stored.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::service::EventSourcedComputeResourceService;
+//!
+//! let service = EventSourcedComputeResourceService::new(event_store, nats_client)
+//!     .with_lookup(lookup)
+//!     .with_retry_on_conflict(3);
+//!
+//! service.add_policy(aggregate_id, command).await?;
+//! ```
+
+use async_trait::async_trait;
+use std::future::Future;
+use std::time::Duration;
+use tracing::debug;
+use uuid::Uuid;
+
+use crate::aggregate::commands::*;
+use crate::aggregate::ComputeResourceState;
+use crate::projection::registry::{ResourceFilter, ResourcePage};
+use crate::projection::retry::{random_unit, RetryPolicy};
+use crate::projection::timeline::{ResourceTimeline, TimelineGranularity};
+use crate::service::compute_resource::{ComputeResourceService, ServiceError, ServiceResult};
+use crate::service::unit_of_work::{CommittedUnit, UnitOfWork};
+
+/// Backoff between attempts: conflicts clear as soon as the other writer
+/// is done, so start short
+pub fn conflict_policy(retries: u32) -> RetryPolicy {
+    RetryPolicy::default()
+        .with_max_attempts(retries.saturating_add(1))
+        .with_initial_backoff(Duration::from_millis(10))
+        .with_max_backoff(Duration::from_millis(500))
+}
+
+/// Run `attempt` again while it fails with a concurrency conflict
+///
+/// `attempt` must reload whatever it decides on, so each run sees the
+/// latest state.
+pub async fn retry_on_conflict<T, F, Fut>(policy: &RetryPolicy, mut attempt: F) -> ServiceResult<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = ServiceResult<T>>,
+{
+    let mut attempts = 1;
+    loop {
+        match attempt().await {
+            Err(ServiceError::ConcurrencyConflict { expected, actual })
+                if attempts < policy.max_attempts =>
+            {
+                let delay = policy.jittered(policy.backoff(attempts), random_unit());
+                debug!(
+                    "Concurrency conflict (expected version {}, got {}); retrying in {:?}",
+                    expected, actual, delay
+                );
+                tokio::time::sleep(delay).await;
+                attempts += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Service wrapper retrying commands that hit a concurrency conflict
+pub struct RetryOnConflict<S> {
+    inner: S,
+    policy: RetryPolicy,
+}
+
+impl<S> RetryOnConflict<S> {
+    /// Retry commands of `inner` up to `retries` times
+    pub fn new(inner: S, retries: u32) -> Self {
+        Self {
+            inner,
+            policy: conflict_policy(retries),
+        }
+    }
+
+    /// Use `policy` for attempts and backoff instead
+    pub fn with_policy(mut self, policy: RetryPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Access the wrapped service
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// Active retry policy
+    pub fn policy(&self) -> &RetryPolicy {
+        &self.policy
+    }
+}
+
+#[async_trait]
+impl<S: ComputeResourceService> ComputeResourceService for RetryOnConflict<S> {
+    async fn register_resource(&self, command: RegisterResourceCommand) -> ServiceResult<Uuid> {
+        self.inner.register_resource(command).await
+    }
+
+    async fn register_resource_as(
+        &self,
+        aggregate_id: Uuid,
+        command: RegisterResourceCommand,
+    ) -> ServiceResult<()> {
+        self.inner.register_resource_as(aggregate_id, command).await
+    }
+
+    async fn assign_organization(
+        &self,
+        aggregate_id: Uuid,
+        command: AssignOrganizationCommand,
+    ) -> ServiceResult<()> {
+        retry_on_conflict(&self.policy, || {
+            self.inner
+                .assign_organization(aggregate_id, command.clone())
+        })
+        .await
+    }
+
+    async fn assign_location(
+        &self,
+        aggregate_id: Uuid,
+        command: AssignLocationCommand,
+    ) -> ServiceResult<()> {
+        retry_on_conflict(&self.policy, || {
+            self.inner.assign_location(aggregate_id, command.clone())
+        })
+        .await
+    }
+
+    async fn assign_owner(
+        &self,
+        aggregate_id: Uuid,
+        command: AssignOwnerCommand,
+    ) -> ServiceResult<()> {
+        retry_on_conflict(&self.policy, || {
+            self.inner.assign_owner(aggregate_id, command.clone())
+        })
+        .await
+    }
+
+    async fn add_policy(&self, aggregate_id: Uuid, command: AddPolicyCommand) -> ServiceResult<()> {
+        retry_on_conflict(&self.policy, || {
+            self.inner.add_policy(aggregate_id, command.clone())
+        })
+        .await
+    }
+
+    async fn remove_policy(
+        &self,
+        aggregate_id: Uuid,
+        command: RemovePolicyCommand,
+    ) -> ServiceResult<()> {
+        retry_on_conflict(&self.policy, || {
+            self.inner.remove_policy(aggregate_id, command.clone())
+        })
+        .await
+    }
+
+    async fn assign_account_concept(
+        &self,
+        aggregate_id: Uuid,
+        command: AssignAccountConceptCommand,
+    ) -> ServiceResult<()> {
+        retry_on_conflict(&self.policy, || {
+            self.inner
+                .assign_account_concept(aggregate_id, command.clone())
+        })
+        .await
+    }
+
+    async fn clear_account_concept(
+        &self,
+        aggregate_id: Uuid,
+        command: ClearAccountConceptCommand,
+    ) -> ServiceResult<()> {
+        retry_on_conflict(&self.policy, || {
+            self.inner
+                .clear_account_concept(aggregate_id, command.clone())
+        })
+        .await
+    }
+
+    async fn set_hardware_details(
+        &self,
+        aggregate_id: Uuid,
+        command: SetHardwareDetailsCommand,
+    ) -> ServiceResult<()> {
+        retry_on_conflict(&self.policy, || {
+            self.inner
+                .set_hardware_details(aggregate_id, command.clone())
+        })
+        .await
+    }
+
+    async fn assign_asset_tag(
+        &self,
+        aggregate_id: Uuid,
+        command: AssignAssetTagCommand,
+    ) -> ServiceResult<()> {
+        retry_on_conflict(&self.policy, || {
+            self.inner.assign_asset_tag(aggregate_id, command.clone())
+        })
+        .await
+    }
+
+    async fn update_metadata(
+        &self,
+        aggregate_id: Uuid,
+        command: UpdateMetadataCommand,
+    ) -> ServiceResult<()> {
+        retry_on_conflict(&self.policy, || {
+            self.inner.update_metadata(aggregate_id, command.clone())
+        })
+        .await
+    }
+
+    async fn change_status(
+        &self,
+        aggregate_id: Uuid,
+        command: ChangeStatusCommand,
+    ) -> ServiceResult<()> {
+        retry_on_conflict(&self.policy, || {
+            self.inner.change_status(aggregate_id, command.clone())
+        })
+        .await
+    }
+
+    async fn record_configuration_backup(
+        &self,
+        aggregate_id: Uuid,
+        command: RecordConfigurationBackupCommand,
+    ) -> ServiceResult<()> {
+        retry_on_conflict(&self.policy, || {
+            self.inner
+                .record_configuration_backup(aggregate_id, command.clone())
+        })
+        .await
+    }
+
+    async fn attach_guest(
+        &self,
+        aggregate_id: Uuid,
+        command: AttachGuestCommand,
+    ) -> ServiceResult<()> {
+        retry_on_conflict(&self.policy, || {
+            self.inner.attach_guest(aggregate_id, command.clone())
+        })
+        .await
+    }
+
+    async fn detach_guest(
+        &self,
+        aggregate_id: Uuid,
+        command: DetachGuestCommand,
+    ) -> ServiceResult<()> {
+        retry_on_conflict(&self.policy, || {
+            self.inner.detach_guest(aggregate_id, command.clone())
+        })
+        .await
+    }
+
+    async fn get_resource(&self, aggregate_id: Uuid) -> ServiceResult<ComputeResourceState> {
+        self.inner.get_resource(aggregate_id).await
+    }
+
+    async fn exists(&self, aggregate_id: Uuid) -> ServiceResult<bool> {
+        self.inner.exists(aggregate_id).await
+    }
+
+    async fn get_timeline(
+        &self,
+        aggregate_id: Uuid,
+        granularity: TimelineGranularity,
+    ) -> ServiceResult<ResourceTimeline> {
+        self.inner.get_timeline(aggregate_id, granularity).await
+    }
+
+    async fn list_resources(
+        &self,
+        filter: &ResourceFilter,
+        page: u32,
+        page_size: u32,
+    ) -> ServiceResult<ResourcePage> {
+        self.inner.list_resources(filter, page, page_size).await
+    }
+
+    async fn find_by_hostname(&self, hostname: &str) -> ServiceResult<Option<Uuid>> {
+        self.inner.find_by_hostname(hostname).await
+    }
+
+    async fn find_by_asset_tag(&self, asset_tag: &str) -> ServiceResult<Option<Uuid>> {
+        self.inner.find_by_asset_tag(asset_tag).await
+    }
+
+    async fn execute_batch(
+        &self,
+        aggregate_id: Option<Uuid>,
+        commands: Vec<ComputeResourceCommand>,
+    ) -> ServiceResult<Uuid> {
+        // A batch creating a resource is a registration
+        if aggregate_id.is_none() {
+            return self.inner.execute_batch(aggregate_id, commands).await;
+        }
+        retry_on_conflict(&self.policy, || {
+            self.inner.execute_batch(aggregate_id, commands.clone())
+        })
+        .await
+    }
+
+    async fn commit(&self, unit: UnitOfWork) -> ServiceResult<CommittedUnit> {
+        // A partially committed unit fails with PartiallyCommitted instead
+        retry_on_conflict(&self.policy, || self.inner.commit(unit.clone())).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn conflict() -> ServiceError {
+        ServiceError::ConcurrencyConflict {
+            expected: 3,
+            actual: 4,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_conflicts_are_retried_until_the_command_goes_through() {
+        // Arrange
+        let policy = conflict_policy(3).with_initial_backoff(Duration::ZERO);
+        let calls = &AtomicU32::new(0);
+
+        // Act
+        let result = retry_on_conflict(&policy, || async move {
+            match calls.fetch_add(1, Ordering::SeqCst) {
+                0 | 1 => Err(conflict()),
+                _ => Ok("stored"),
+            }
+        })
+        .await;
+
+        // Assert
+        assert_eq!(result.unwrap(), "stored");
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_other_errors_and_exhausted_retries_are_returned() {
+        // Arrange
+        let policy = conflict_policy(2).with_initial_backoff(Duration::ZERO);
+        let conflicts = &AtomicU32::new(0);
+        let rejections = &AtomicU32::new(0);
+
+        // Act
+        let exhausted = retry_on_conflict(&policy, || async move {
+            conflicts.fetch_add(1, Ordering::SeqCst);
+            Err::<(), _>(conflict())
+        })
+        .await;
+        let rejected = retry_on_conflict(&policy, || async move {
+            rejections.fetch_add(1, Ordering::SeqCst);
+            Err::<(), _>(ServiceError::NotFound(Uuid::nil()))
+        })
+        .await;
+
+        // Assert
+        assert!(matches!(
+            exhausted,
+            Err(ServiceError::ConcurrencyConflict { .. })
+        ));
+        assert_eq!(conflicts.load(Ordering::SeqCst), 3);
+        assert!(matches!(rejected, Err(ServiceError::NotFound(_))));
+        assert_eq!(rejections.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,651 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Scheduled Commands and Events
+//!
+//! A [`Scheduler`] sends a command, or publishes an event, at a future
+//! time ("decommission web-01 on 2026-03-01"). Schedules are kept in the
+//! `infrastructure-schedules` JetStream KV bucket, so they survive restarts
+//! and every instance sees the same list:
+//!
+//! ```text
+//! schedule() ──put(schedule.<id>)──> [bucket] <──poll── run()
+//!                                                  │ due?
+//!                                                  ├─ claim (compare revision) ─ lost? skip
+//!                                                  ├─ command → request on infrastructure.cmd.…
+//!                                                  └─ event   → publish on its subject
+//!                                                       ok → delete
+//!                                                       failed → retry later, or mark failed
+//! ```
+//!
+//! Several instances may run the scheduler; claiming a schedule updates it
+//! against the revision that was read, so only one of them fires it. A
+//! claim left by a crashed instance is taken over after the claim timeout,
+//! so a schedule fires at least once, rarely twice.
+//!
+//! Commands are sent with the scheduler's service token as their
+//! `Authorization: Bearer` header (see [`SchedulerConfig::with_service_token`]),
+//! so a command bus that authenticates its senders runs them as that
+//! service. Whoever schedules a command must be allowed to have the service
+//! run it; the token itself is never written to the bucket.
+//!
+//! Commands are retried when the reply is a `conflict`, `rate_limited` or
+//! `unavailable` nack or no reply arrives; other nacks mark the schedule
+//! failed at once. Failed schedules stay listed until they are cancelled.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::service::scheduler::{ScheduledAction, Scheduler, SchedulerConfig};
+//!
+//! let config = SchedulerConfig::new("api-1").with_service_token(scheduler_token);
+//! let scheduler = Scheduler::open(jetstream, nats_client, config).await?;
+//! let schedule = scheduler
+//!     .schedule(
+//!         "2026-03-01T00:00:00Z".parse()?,
+//!         ScheduledAction::command(decommission),
+//!         Some("Decommission web-01"),
+//!     )
+//!     .await?;
+//! tokio::spawn(async move { scheduler.run().await });
+//!
+//! for pending in scheduler.list().await? {
+//!     println!("{} at {}", pending.id, pending.due_at);
+//! }
+//! scheduler.cancel(schedule.id).await?;
+//! ```
+
+use async_nats::jetstream::{self, kv};
+use async_nats::HeaderMap;
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::errors::{InfrastructureError, InfrastructureResult};
+use crate::nats::kv::{create_entry, update_entry};
+use crate::nats::NatsClient;
+use crate::service::authentication::AUTHORIZATION_HEADER;
+use crate::service::command_bus::{CommandReply, InfrastructureCommand, NackReason};
+
+/// KV bucket holding the schedules
+pub const SCHEDULE_BUCKET: &str = "infrastructure-schedules";
+
+/// What a schedule does when it is due
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ScheduledAction {
+    /// Send a command on the command bus and wait for its reply
+    Command {
+        /// The command
+        command: InfrastructureCommand,
+    },
+
+    /// Publish a message, usually an event, on a subject
+    Publish {
+        /// Subject to publish on
+        subject: String,
+
+        /// Message body
+        payload: serde_json::Value,
+    },
+}
+
+impl ScheduledAction {
+    /// Send `command` when due
+    pub fn command(command: InfrastructureCommand) -> Self {
+        ScheduledAction::Command { command }
+    }
+
+    /// Publish `message` on `subject` when due
+    pub fn publish<T: Serialize>(
+        subject: impl Into<String>,
+        message: &T,
+    ) -> InfrastructureResult<Self> {
+        Ok(ScheduledAction::Publish {
+            subject: subject.into(),
+            payload: serde_json::to_value(message)?,
+        })
+    }
+
+    /// Subject the action is sent on
+    pub fn subject(&self) -> String {
+        match self {
+            ScheduledAction::Command { command } => command.subject(),
+            ScheduledAction::Publish { subject, .. } => subject.clone(),
+        }
+    }
+}
+
+/// Where a schedule stands
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "state", rename_all = "snake_case")]
+pub enum ScheduleState {
+    /// Waiting for its time
+    Pending,
+
+    /// Claimed by an instance that is firing it
+    Firing {
+        /// Instance holding the claim
+        holder: String,
+
+        /// When the claim was taken
+        since: DateTime<Utc>,
+    },
+
+    /// Gave up; kept until cancelled
+    Failed {
+        /// Last failure
+        reason: String,
+    },
+}
+
+/// A command or event waiting for its time
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Schedule {
+    /// Schedule ID, also its key in the bucket
+    pub id: Uuid,
+
+    /// When the action is due
+    pub due_at: DateTime<Utc>,
+
+    /// What to do
+    pub action: ScheduledAction,
+
+    /// Why it was scheduled
+    pub description: Option<String>,
+
+    /// When it was scheduled
+    pub created_at: DateTime<Utc>,
+
+    /// Failed attempts so far
+    pub attempts: u32,
+
+    /// Where it stands
+    pub state: ScheduleState,
+}
+
+impl Schedule {
+    /// Pending schedule of `action` at `due_at`
+    pub fn new(due_at: DateTime<Utc>, action: ScheduledAction, now: DateTime<Utc>) -> Self {
+        Self {
+            id: Uuid::now_v7(),
+            due_at,
+            action,
+            description: None,
+            created_at: now,
+            attempts: 0,
+            state: ScheduleState::Pending,
+        }
+    }
+
+    /// Set the description
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Whether the schedule should be fired now: pending and due, or
+    /// claimed longer than `claim_timeout` ago
+    pub fn is_due(&self, now: DateTime<Utc>, claim_timeout: Duration) -> bool {
+        match &self.state {
+            ScheduleState::Pending => self.due_at <= now,
+            ScheduleState::Firing { since, .. } => *since + chrono_duration(claim_timeout) <= now,
+            ScheduleState::Failed { .. } => false,
+        }
+    }
+
+    /// State after a failed attempt: retried after `retry_delay`, or failed
+    /// for good once `max_attempts` is reached or the failure is permanent
+    fn after_failure(
+        mut self,
+        reason: String,
+        retryable: bool,
+        now: DateTime<Utc>,
+        config: &SchedulerConfig,
+    ) -> Self {
+        self.attempts += 1;
+        self.state = if retryable && self.attempts < config.max_attempts {
+            self.due_at = now + chrono_duration(config.retry_delay);
+            ScheduleState::Pending
+        } else {
+            ScheduleState::Failed { reason }
+        };
+        self
+    }
+}
+
+/// Scheduler timing and identity
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchedulerConfig {
+    /// Name recorded in claims this instance holds
+    pub holder: String,
+
+    /// Pause between checks for due schedules
+    pub poll_interval: Duration,
+
+    /// How long a claim lasts before another instance takes over
+    pub claim_timeout: Duration,
+
+    /// Pause before a failed attempt is retried
+    pub retry_delay: Duration,
+
+    /// Attempts before a schedule is marked failed
+    pub max_attempts: u32,
+
+    /// Bearer token commands are sent with (None = no credentials)
+    pub service_token: Option<String>,
+}
+
+impl SchedulerConfig {
+    /// Scheduler running as `holder`, with default timing
+    pub fn new(holder: impl Into<String>) -> Self {
+        Self {
+            holder: holder.into(),
+            poll_interval: Duration::from_secs(1),
+            claim_timeout: Duration::from_secs(60),
+            retry_delay: Duration::from_secs(30),
+            max_attempts: 5,
+            service_token: None,
+        }
+    }
+
+    /// Set the pause between checks
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Set how long a claim lasts
+    pub fn with_claim_timeout(mut self, claim_timeout: Duration) -> Self {
+        self.claim_timeout = claim_timeout;
+        self
+    }
+
+    /// Set the pause before retries
+    pub fn with_retry_delay(mut self, retry_delay: Duration) -> Self {
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    /// Set the attempts before a schedule fails (at least one)
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Send commands with `token` as their bearer token
+    pub fn with_service_token(mut self, token: impl Into<String>) -> Self {
+        self.service_token = Some(token.into());
+        self
+    }
+
+    /// Headers commands are sent with
+    fn command_headers(&self) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        if let Some(token) = &self.service_token {
+            headers.insert(AUTHORIZATION_HEADER, format!("Bearer {}", token).as_str());
+        }
+        headers
+    }
+}
+
+/// Keeps schedules in the schedule bucket and fires them when due
+///
+/// Cloning is cheap; all clones share the bucket and client.
+#[derive(Clone)]
+pub struct Scheduler {
+    store: kv::Store,
+    client: NatsClient,
+    config: SchedulerConfig,
+}
+
+impl Scheduler {
+    /// Open the schedule bucket, creating it if needed
+    pub async fn open(
+        jetstream: jetstream::Context,
+        client: NatsClient,
+        config: SchedulerConfig,
+    ) -> InfrastructureResult<Self> {
+        let store = match jetstream.get_key_value(SCHEDULE_BUCKET).await {
+            Ok(store) => store,
+            Err(_) => jetstream
+                .create_key_value(kv::Config {
+                    bucket: SCHEDULE_BUCKET.to_string(),
+                    description: "Commands and events scheduled for later".to_string(),
+                    history: 1,
+                    ..Default::default()
+                })
+                .await
+                .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))?,
+        };
+
+        Ok(Self {
+            store,
+            client,
+            config,
+        })
+    }
+
+    /// Schedule `action` at `due_at`
+    pub async fn schedule(
+        &self,
+        due_at: DateTime<Utc>,
+        action: ScheduledAction,
+        description: Option<&str>,
+    ) -> InfrastructureResult<Schedule> {
+        let mut schedule = Schedule::new(due_at, action, Utc::now());
+        if let Some(description) = description {
+            schedule = schedule.with_description(description);
+        }
+        let key = schedule_key(schedule.id);
+        if create_entry(&self.store, &key, encode(&schedule)?)
+            .await?
+            .is_none()
+        {
+            return Err(InfrastructureError::ConcurrencyError(format!(
+                "Schedule {} already exists",
+                schedule.id
+            )));
+        }
+        info!("Scheduled {} at {}", schedule.action.subject(), due_at);
+        Ok(schedule)
+    }
+
+    /// Cancel a schedule; false when there is none with this ID
+    ///
+    /// A schedule already being fired may still fire.
+    pub async fn cancel(&self, id: Uuid) -> InfrastructureResult<bool> {
+        if self.get(id).await?.is_none() {
+            return Ok(false);
+        }
+        self.store
+            .delete(schedule_key(id))
+            .await
+            .map_err(|e| InfrastructureError::NatsPublish(e.to_string()))?;
+        info!("Cancelled schedule {}", id);
+        Ok(true)
+    }
+
+    /// One schedule
+    pub async fn get(&self, id: Uuid) -> InfrastructureResult<Option<Schedule>> {
+        Ok(self
+            .entry(&schedule_key(id))
+            .await?
+            .map(|(schedule, _)| schedule))
+    }
+
+    /// Every schedule, earliest first
+    pub async fn list(&self) -> InfrastructureResult<Vec<Schedule>> {
+        let mut schedules: Vec<Schedule> = self
+            .entries()
+            .await?
+            .into_iter()
+            .map(|(schedule, _)| schedule)
+            .collect();
+        schedules.sort_by_key(|schedule| (schedule.due_at, schedule.id));
+        Ok(schedules)
+    }
+
+    /// Fire due schedules forever, checking every poll interval
+    pub async fn run(&self) -> InfrastructureResult<()> {
+        info!(
+            "Scheduler {} polling every {:?}",
+            self.config.holder, self.config.poll_interval
+        );
+        loop {
+            if let Err(e) = self.fire_due(Utc::now()).await {
+                warn!("Scheduler poll failed: {}", e);
+            }
+            tokio::time::sleep(self.config.poll_interval).await;
+        }
+    }
+
+    /// Fire every schedule due at `now`, returning how many this instance
+    /// fired
+    pub async fn fire_due(&self, now: DateTime<Utc>) -> InfrastructureResult<usize> {
+        let mut fired = 0;
+        for (schedule, revision) in self.entries().await? {
+            if !schedule.is_due(now, self.config.claim_timeout) {
+                continue;
+            }
+            if let Some(claimed) = self.claim(schedule, revision, now).await? {
+                self.fire(claimed, now).await?;
+                fired += 1;
+            }
+        }
+        Ok(fired)
+    }
+
+    /// Mark a schedule as firing; None when another instance was first
+    async fn claim(
+        &self,
+        schedule: Schedule,
+        revision: u64,
+        now: DateTime<Utc>,
+    ) -> InfrastructureResult<Option<(Schedule, u64)>> {
+        let claimed = Schedule {
+            state: ScheduleState::Firing {
+                holder: self.config.holder.clone(),
+                since: now,
+            },
+            ..schedule
+        };
+        let key = schedule_key(claimed.id);
+        Ok(update_entry(&self.store, &key, encode(&claimed)?, revision)
+            .await?
+            .map(|revision| (claimed, revision)))
+    }
+
+    /// Carry out a claimed schedule and record the result
+    async fn fire(
+        &self,
+        (schedule, revision): (Schedule, u64),
+        now: DateTime<Utc>,
+    ) -> InfrastructureResult<()> {
+        let key = schedule_key(schedule.id);
+        let failure = match &schedule.action {
+            ScheduledAction::Command { command } => {
+                match self
+                    .client
+                    .request_with_headers::<_, CommandReply>(
+                        &command.subject(),
+                        self.config.command_headers(),
+                        command,
+                    )
+                    .await
+                {
+                    Ok(CommandReply::Ack { .. }) => None,
+                    Ok(CommandReply::Nack {
+                        reason, message, ..
+                    }) => Some((format!("{:?}: {}", reason, message), is_retryable(reason))),
+                    Err(e) => Some((e.to_string(), true)),
+                }
+            }
+            ScheduledAction::Publish { subject, payload } => self
+                .client
+                .publish(subject, payload)
+                .await
+                .err()
+                .map(|e| (e.to_string(), true)),
+        };
+
+        match failure {
+            None => {
+                info!(
+                    "Fired schedule {} on {}",
+                    schedule.id,
+                    schedule.action.subject()
+                );
+                self.store
+                    .delete(&key)
+                    .await
+                    .map_err(|e| InfrastructureError::NatsPublish(e.to_string()))?;
+            }
+            Some((reason, retryable)) => {
+                warn!("Schedule {} failed: {}", schedule.id, reason);
+                let next = schedule.after_failure(reason, retryable, now, &self.config);
+                if let Err(e) = self
+                    .store
+                    .update(&key, encode(&next)?.into(), revision)
+                    .await
+                {
+                    // Cancelled or taken over meanwhile
+                    warn!("Could not record result of schedule {}: {}", next.id, e);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn entry(&self, key: &str) -> InfrastructureResult<Option<(Schedule, u64)>> {
+        let entry = self
+            .store
+            .entry(key)
+            .await
+            .map_err(|e| InfrastructureError::NatsSubscribe(e.to_string()))?;
+        match entry {
+            Some(entry) if entry.operation == kv::Operation::Put => {
+                let schedule = serde_json::from_slice(&entry.value)
+                    .map_err(|e| InfrastructureError::Deserialization(e.to_string()))?;
+                Ok(Some((schedule, entry.revision)))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    async fn entries(&self) -> InfrastructureResult<Vec<(Schedule, u64)>> {
+        let mut keys = self
+            .store
+            .keys()
+            .await
+            .map_err(|e| InfrastructureError::NatsSubscribe(e.to_string()))?;
+        let mut entries = Vec::new();
+        while let Some(key) = keys.next().await {
+            let key = key.map_err(|e| InfrastructureError::NatsSubscribe(e.to_string()))?;
+            match self.entry(&key).await {
+                Ok(Some(entry)) => entries.push(entry),
+                Ok(None) => {}
+                Err(e) => warn!("Skipping unreadable schedule {}: {}", key, e),
+            }
+        }
+        Ok(entries)
+    }
+}
+
+/// KV key of a schedule
+pub fn schedule_key(id: Uuid) -> String {
+    format!("schedule.{}", id)
+}
+
+/// Whether a command nacked for `reason` may succeed later
+fn is_retryable(reason: NackReason) -> bool {
+    matches!(
+        reason,
+        NackReason::Conflict | NackReason::RateLimited | NackReason::Unavailable
+    )
+}
+
+fn encode(schedule: &Schedule) -> InfrastructureResult<Vec<u8>> {
+    Ok(serde_json::to_vec(schedule)?)
+}
+
+fn chrono_duration(duration: Duration) -> ChronoDuration {
+    ChronoDuration::from_std(duration).unwrap_or(ChronoDuration::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::authentication::bearer_token;
+
+    fn schedule(now: DateTime<Utc>) -> Schedule {
+        let action = ScheduledAction::publish(
+            "infrastructure.alert.maintenance",
+            &serde_json::json!({"window": "opened"}),
+        )
+        .unwrap();
+        Schedule::new(now + ChronoDuration::hours(1), action, now)
+            .with_description("Open the maintenance window")
+    }
+
+    #[test]
+    fn test_schedule_is_due_at_its_time_or_after_a_stale_claim() {
+        // Arrange
+        let now = Utc::now();
+        let timeout = Duration::from_secs(60);
+        let pending = schedule(now);
+        let claimed = Schedule {
+            state: ScheduleState::Firing {
+                holder: "api-1".to_string(),
+                since: now,
+            },
+            ..pending.clone()
+        };
+
+        // Act / Assert
+        assert!(!pending.is_due(now, timeout));
+        assert!(pending.is_due(now + ChronoDuration::hours(1), timeout));
+        assert!(!claimed.is_due(now + ChronoDuration::seconds(30), timeout));
+        assert!(claimed.is_due(now + ChronoDuration::seconds(60), timeout));
+        assert_eq!(pending.action.subject(), "infrastructure.alert.maintenance");
+        assert!(schedule_key(pending.id).starts_with("schedule."));
+
+        let json = serde_json::to_value(&claimed).unwrap();
+        assert_eq!(json["state"]["state"], "firing");
+        assert_eq!(json["action"]["kind"], "publish");
+    }
+
+    #[test]
+    fn test_failures_are_retried_until_max_attempts() {
+        // Arrange
+        let now = Utc::now();
+        let config = SchedulerConfig::new("api-1")
+            .with_retry_delay(Duration::from_secs(30))
+            .with_max_attempts(2);
+
+        // Act
+        let retried = schedule(now).after_failure("no responders".into(), true, now, &config);
+        let exhausted = retried
+            .clone()
+            .after_failure("no responders".into(), true, now, &config);
+        let rejected = schedule(now).after_failure("Rejected".into(), false, now, &config);
+
+        // Assert
+        assert_eq!(retried.state, ScheduleState::Pending);
+        assert_eq!(retried.due_at, now + ChronoDuration::seconds(30));
+        assert_eq!(
+            exhausted.state,
+            ScheduleState::Failed {
+                reason: "no responders".to_string()
+            }
+        );
+        assert_eq!(exhausted.attempts, 2);
+        assert!(!exhausted.is_due(now + ChronoDuration::days(1), config.claim_timeout));
+        assert!(matches!(rejected.state, ScheduleState::Failed { .. }));
+    }
+
+    #[test]
+    fn test_commands_carry_the_service_token() {
+        // Arrange
+        let anonymous = SchedulerConfig::new("api-1");
+        let authenticated = SchedulerConfig::new("api-1").with_service_token("s3cret");
+
+        // Act
+        let headers = authenticated.command_headers();
+
+        // Assert
+        assert!(anonymous
+            .command_headers()
+            .get(AUTHORIZATION_HEADER)
+            .is_none());
+        assert_eq!(
+            headers
+                .get(AUTHORIZATION_HEADER)
+                .and_then(|value| bearer_token(value.as_str())),
+            Some("s3cret")
+        );
+        assert!(is_retryable(NackReason::RateLimited));
+        assert!(!is_retryable(NackReason::Unauthenticated));
+    }
+}
@@ -0,0 +1,339 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Units of Work
+//!
+//! A [`UnitOfWork`] stages commands against one or more compute resources
+//! and commits them together with
+//! [`ComputeResourceService::commit`](super::ComputeResourceService::commit),
+//! instead of issuing one service call after another with other writers
+//! free to interleave:
+//!
+//! ```text
+//! stage, stage, ... ──commit──> 1. load every target once (state + version)
+//!                               2. handle all commands in stage order (pure);
+//!                                  a rejection aborts before anything is written
+//!                               3. check conventions for every target
+//!                               4. re-check versions, then append per aggregate
+//!                                  with the snapshot version and publish
+//! ```
+//!
+//! Every command is given the unit's correlation ID, and its causation ID
+//! is the event of the command staged before it (the first command takes
+//! [`UnitOfWork::caused_by`], if set), so the unit reads as one chain in the
+//! event store. Registration commands carry no causation ID.
+//!
+//! A unit started [`UnitOfWork::under_change`] records its change request
+//! on every event; commands may name their own, but the commands of one
+//! aggregate must agree on it (see [`change_control`](crate::change_control)).
+//!
+//! Events of one aggregate are appended atomically, but JetStream has no
+//! transactions across aggregates: if appending to a later aggregate fails
+//! after earlier ones were stored, the error is
+//! [`ServiceError::PartiallyCommitted`] and names the stored aggregates.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::service::UnitOfWork;
+//!
+//! let mut unit = UnitOfWork::new(correlation_id);
+//! let replacement = unit.register(register_command);
+//! unit.stage(replacement, assign_location)
+//!     .stage(old_server, decommission);
+//!
+//! let committed = service.commit(unit).await?;
+//! ```
+
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use super::{ServiceError, ServiceResult};
+use crate::aggregate::commands::{ComputeResourceCommand, RegisterResourceCommand};
+use crate::aggregate::handlers::handle_command;
+use crate::aggregate::{apply_event, ComputeResourceState};
+use crate::change_control::{shared_change_ref, ChangeRef};
+use crate::events::ComputeResourceEvent;
+
+/// Commands staged for one commit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnitOfWork {
+    correlation_id: Uuid,
+    causation_id: Option<Uuid>,
+    change_ref: Option<ChangeRef>,
+    staged: Vec<(Uuid, ComputeResourceCommand)>,
+}
+
+/// Events of a unit, grouped by aggregate in the order targets were staged
+pub type PlannedEvents = Vec<(Uuid, Vec<ComputeResourceEvent>)>;
+
+impl UnitOfWork {
+    /// Empty unit whose commands share `correlation_id`
+    pub fn new(correlation_id: Uuid) -> Self {
+        Self {
+            correlation_id,
+            causation_id: None,
+            change_ref: None,
+            staged: Vec::new(),
+        }
+    }
+
+    /// Record `causation_id` as the cause of the first command
+    pub fn caused_by(mut self, causation_id: Uuid) -> Self {
+        self.causation_id = Some(causation_id);
+        self
+    }
+
+    /// Record `change_ref` on commands that do not name a change request
+    pub fn under_change(mut self, change_ref: ChangeRef) -> Self {
+        self.change_ref = Some(change_ref);
+        self
+    }
+
+    /// Change request the events of `aggregate_id` are written under
+    ///
+    /// Fails if the aggregate's commands name different change requests.
+    pub fn change_ref_for(&self, aggregate_id: Uuid) -> ServiceResult<Option<ChangeRef>> {
+        let named = self
+            .staged
+            .iter()
+            .filter(|(id, _)| *id == aggregate_id)
+            .map(|(_, command)| command.change_ref().or(self.change_ref.as_ref()));
+        shared_change_ref(named).map_err(|e| ServiceError::BusinessRuleViolation(e.to_string()))
+    }
+
+    /// Correlation ID of every command in the unit
+    pub fn correlation_id(&self) -> Uuid {
+        self.correlation_id
+    }
+
+    /// Stage the registration of a new resource and return its ID
+    pub fn register(&mut self, command: RegisterResourceCommand) -> Uuid {
+        let aggregate_id = Uuid::now_v7();
+        self.register_as(aggregate_id, command);
+        aggregate_id
+    }
+
+    /// Stage the registration of a new resource under `aggregate_id`
+    pub fn register_as(
+        &mut self,
+        aggregate_id: Uuid,
+        command: RegisterResourceCommand,
+    ) -> &mut Self {
+        self.stage(
+            aggregate_id,
+            ComputeResourceCommand::RegisterResource(command),
+        )
+    }
+
+    /// Stage a command against `aggregate_id`
+    pub fn stage(&mut self, aggregate_id: Uuid, command: ComputeResourceCommand) -> &mut Self {
+        self.staged.push((aggregate_id, command));
+        self
+    }
+
+    /// Staged commands with their targets, in stage order
+    pub fn commands(&self) -> &[(Uuid, ComputeResourceCommand)] {
+        &self.staged
+    }
+
+    /// Number of staged commands
+    pub fn len(&self) -> usize {
+        self.staged.len()
+    }
+
+    /// Whether nothing is staged
+    pub fn is_empty(&self) -> bool {
+        self.staged.is_empty()
+    }
+
+    /// Targeted aggregates, in the order they were first staged
+    pub fn aggregate_ids(&self) -> Vec<Uuid> {
+        let mut ids: Vec<Uuid> = Vec::new();
+        for (aggregate_id, _) in &self.staged {
+            if !ids.contains(aggregate_id) {
+                ids.push(*aggregate_id);
+            }
+        }
+        ids
+    }
+
+    /// Handle every staged command against `snapshot`
+    ///
+    /// `snapshot` holds the current state of the targeted aggregates;
+    /// missing entries are treated as not yet registered. Fails on the first
+    /// rejected command, or a command other than a registration against an
+    /// unregistered aggregate.
+    pub fn plan(
+        &self,
+        snapshot: &HashMap<Uuid, ComputeResourceState>,
+    ) -> ServiceResult<PlannedEvents> {
+        let mut states: HashMap<Uuid, ComputeResourceState> = HashMap::new();
+        let mut planned: PlannedEvents = self
+            .aggregate_ids()
+            .into_iter()
+            .map(|aggregate_id| (aggregate_id, Vec::new()))
+            .collect();
+        let mut causation_id = self.causation_id;
+
+        for (aggregate_id, command) in &self.staged {
+            let state = states.entry(*aggregate_id).or_insert_with(|| {
+                snapshot
+                    .get(aggregate_id)
+                    .cloned()
+                    .unwrap_or_else(|| ComputeResourceState::default_for(*aggregate_id))
+            });
+            if !state.is_initialized() && !command.is_creation() {
+                return Err(ServiceError::NotFound(*aggregate_id));
+            }
+
+            let command = command
+                .clone()
+                .with_trace(self.correlation_id, causation_id);
+            let event = handle_command(state, command, *aggregate_id)?;
+            causation_id = Some(event.event_id());
+            *state = apply_event(state.clone(), &event);
+
+            planned
+                .iter_mut()
+                .find(|(id, _)| id == aggregate_id)
+                .expect("every target is planned")
+                .1
+                .push(event);
+        }
+
+        Ok(planned)
+    }
+}
+
+/// Result of a committed unit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommittedUnit {
+    /// Correlation ID shared by the unit's events
+    pub correlation_id: Uuid,
+
+    /// Aggregates written, in the order they were first staged
+    pub aggregate_ids: Vec<Uuid>,
+
+    /// IDs of the stored events, grouped by aggregate
+    pub event_ids: Vec<Uuid>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aggregate::commands::UpdateMetadataCommand;
+    use crate::aggregate::CommandError;
+    use crate::domain::{Hostname, ResourceType};
+    use chrono::{DateTime, Utc};
+
+    fn test_timestamp() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-19T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn register(hostname: &str) -> RegisterResourceCommand {
+        RegisterResourceCommand {
+            hostname: Hostname::new(hostname).unwrap(),
+            resource_type: ResourceType::PhysicalServer,
+            timestamp: test_timestamp(),
+            correlation_id: Uuid::now_v7(),
+            change_ref: None,
+        }
+    }
+
+    fn metadata(key: &str, value: &str) -> ComputeResourceCommand {
+        ComputeResourceCommand::UpdateMetadata(UpdateMetadataCommand {
+            key: key.to_string(),
+            value: value.to_string(),
+            timestamp: test_timestamp(),
+            correlation_id: Uuid::now_v7(),
+            causation_id: None,
+            change_ref: None,
+        })
+    }
+
+    fn registered(aggregate_id: Uuid, hostname: &str) -> ComputeResourceState {
+        let state = ComputeResourceState::default_for(aggregate_id);
+        let event = handle_command(
+            &state,
+            ComputeResourceCommand::RegisterResource(register(hostname)),
+            aggregate_id,
+        )
+        .unwrap();
+        apply_event(state, &event)
+    }
+
+    #[test]
+    fn test_plan_chains_correlation_and_causation() {
+        // Arrange
+        let existing = Uuid::now_v7();
+        let snapshot = HashMap::from([(existing, registered(existing, "web-01"))]);
+        let correlation_id = Uuid::now_v7();
+        let trigger = Uuid::now_v7();
+        let mut unit = UnitOfWork::new(correlation_id).caused_by(trigger);
+        unit.stage(existing, metadata("role", "retiring"));
+        let replacement = unit.register(register("web-02"));
+        unit.stage(replacement, metadata("role", "web"));
+
+        // Act
+        let planned = unit.plan(&snapshot).unwrap();
+
+        // Assert
+        assert_eq!(unit.aggregate_ids(), vec![existing, replacement]);
+        let events: Vec<&ComputeResourceEvent> =
+            planned.iter().flat_map(|(_, events)| events).collect();
+        assert_eq!(events.len(), 3);
+        assert!(events.iter().all(|e| e.correlation_id() == correlation_id));
+
+        let (old, new) = (&planned[0].1, &planned[1].1);
+        assert_eq!(old[0].causation_id(), Some(trigger));
+        assert_eq!(new[0].causation_id(), None);
+        assert_eq!(new[1].causation_id(), Some(new[0].event_id()));
+    }
+
+    #[test]
+    fn test_plan_rejects_whole_unit() {
+        let existing = Uuid::now_v7();
+        let snapshot = HashMap::from([(existing, registered(existing, "web-01"))]);
+
+        let mut duplicate = UnitOfWork::new(Uuid::now_v7());
+        duplicate
+            .stage(existing, metadata("role", "web"))
+            .register_as(existing, register("web-01"));
+        let mut unknown = UnitOfWork::new(Uuid::now_v7());
+        let missing = Uuid::now_v7();
+        unknown.stage(missing, metadata("role", "web"));
+
+        assert!(matches!(
+            duplicate.plan(&snapshot),
+            Err(ServiceError::CommandError(CommandError::AlreadyInitialized))
+        ));
+        assert!(matches!(
+            unknown.plan(&snapshot),
+            Err(ServiceError::NotFound(id)) if id == missing
+        ));
+    }
+
+    #[test]
+    fn test_change_ref_per_aggregate() {
+        let change = ChangeRef::new("CHG0012345").unwrap();
+        let other = ChangeRef::new("CHG0012346").unwrap();
+        let (web, db) = (Uuid::now_v7(), Uuid::now_v7());
+        let mut unit = UnitOfWork::new(Uuid::now_v7()).under_change(change.clone());
+        unit.stage(web, metadata("role", "web"));
+        let mut named = metadata("role", "db");
+        if let ComputeResourceCommand::UpdateMetadata(c) = &mut named {
+            c.change_ref = Some(other.clone());
+        }
+        unit.stage(db, named.clone()).stage(db, named);
+
+        assert_eq!(unit.change_ref_for(web).unwrap(), Some(change));
+        assert_eq!(unit.change_ref_for(db).unwrap(), Some(other));
+
+        unit.stage(db, metadata("rack", "r1"));
+        assert!(matches!(
+            unit.change_ref_for(db),
+            Err(ServiceError::BusinessRuleViolation(_))
+        ));
+    }
+}
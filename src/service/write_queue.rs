@@ -0,0 +1,291 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Per-Aggregate Write Queue
+//!
+//! Two commands to the same aggregate that load state at the same time both
+//! expect the same version; the event store accepts the first append and
+//! fails the second with a concurrency error, and its caller has to retry.
+//! With a [`WriteQueue`] attached, the service makes commands to one
+//! aggregate take turns locally instead:
+//!
+//! ```text
+//! cmd A ──┐                        ┌──> load → handle → append (v3)
+//! cmd B ──┼──> lane(aggregate) ────┼──> load → handle → append (v4)
+//! cmd C ──┘   (FIFO, max_depth)    └──> load → handle → append (v5)
+//! ```
+//!
+//! Turns are granted in arrival order. A command that waits longer than
+//! [`WriteQueueConfig::timeout`], or arrives while `max_depth` commands are
+//! already waiting, fails with a [`WriteQueueError`]. Commands to different
+//! aggregates never wait for each other.
+//!
+//! The queue only orders writers in this process; writers elsewhere are
+//! still caught by the optimistic concurrency check.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::service::write_queue::{WriteQueue, WriteQueueConfig};
+//!
+//! let queue = WriteQueue::new(WriteQueueConfig::default().with_timeout(Duration::from_secs(2)));
+//! let service = EventSourcedComputeResourceService::new(event_store, nats_client)
+//!     .with_write_queue(queue.clone());
+//!
+//! let metrics = queue.metrics();
+//! info!("{} commands queued, deepest queue {}", metrics.queued, metrics.max_depth);
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::OwnedMutexGuard;
+use uuid::Uuid;
+
+/// Write queue limits
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WriteQueueConfig {
+    /// Longest a command waits for its turn
+    pub timeout: Duration,
+
+    /// Commands allowed to wait on one aggregate, besides the one writing
+    pub max_depth: usize,
+}
+
+impl Default for WriteQueueConfig {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(5),
+            max_depth: 64,
+        }
+    }
+}
+
+impl WriteQueueConfig {
+    /// Set the longest wait for a turn
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set the number of commands allowed to wait on one aggregate
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+}
+
+/// A command did not get its turn
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum WriteQueueError {
+    /// The command waited longer than the configured timeout
+    #[error("Timed out after {waited:?} waiting to write aggregate {aggregate_id}")]
+    Timeout {
+        aggregate_id: Uuid,
+        waited: Duration,
+    },
+
+    /// Too many commands were already waiting on the aggregate
+    #[error("Write queue for aggregate {aggregate_id} is full ({waiting} waiting)")]
+    Full { aggregate_id: Uuid, waiting: usize },
+}
+
+/// Commands writing or waiting to write one aggregate
+struct Lane {
+    turn: Arc<tokio::sync::Mutex<()>>,
+    depth: usize,
+}
+
+type Lanes = Arc<Mutex<HashMap<Uuid, Lane>>>;
+
+#[derive(Debug, Default)]
+struct Counters {
+    acquired: AtomicU64,
+    timed_out: AtomicU64,
+    rejected: AtomicU64,
+    max_depth: AtomicU64,
+}
+
+/// Per-aggregate FIFO of local writers
+///
+/// Cloning is cheap; all clones share the same lanes and metrics.
+#[derive(Clone)]
+pub struct WriteQueue {
+    config: WriteQueueConfig,
+    lanes: Lanes,
+    counters: Arc<Counters>,
+}
+
+impl WriteQueue {
+    /// Create an empty queue
+    pub fn new(config: WriteQueueConfig) -> Self {
+        Self {
+            config,
+            lanes: Arc::default(),
+            counters: Arc::default(),
+        }
+    }
+
+    /// Wait for the turn to write `aggregate_id`
+    ///
+    /// The turn lasts until the returned [`WriteTurn`] is dropped. Dropping
+    /// the future while it waits gives up its place in the queue.
+    pub async fn acquire(&self, aggregate_id: Uuid) -> Result<WriteTurn, WriteQueueError> {
+        let turn = {
+            let mut lanes = self.lanes.lock().expect("write queue lock poisoned");
+            let lane = lanes.entry(aggregate_id).or_insert_with(|| Lane {
+                turn: Arc::default(),
+                depth: 0,
+            });
+
+            let waiting = lane.depth.saturating_sub(1);
+            if lane.depth > 0 && waiting >= self.config.max_depth {
+                self.counters.rejected.fetch_add(1, Ordering::Relaxed);
+                return Err(WriteQueueError::Full {
+                    aggregate_id,
+                    waiting,
+                });
+            }
+
+            lane.depth += 1;
+            self.counters
+                .max_depth
+                .fetch_max(lane.depth as u64, Ordering::Relaxed);
+            lane.turn.clone()
+        };
+        let place = Place {
+            lanes: self.lanes.clone(),
+            aggregate_id,
+        };
+
+        match tokio::time::timeout(self.config.timeout, turn.lock_owned()).await {
+            Ok(guard) => {
+                self.counters.acquired.fetch_add(1, Ordering::Relaxed);
+                Ok(WriteTurn {
+                    _guard: guard,
+                    _place: place,
+                })
+            }
+            Err(_) => {
+                self.counters.timed_out.fetch_add(1, Ordering::Relaxed);
+                Err(WriteQueueError::Timeout {
+                    aggregate_id,
+                    waited: self.config.timeout,
+                })
+            }
+        }
+    }
+
+    /// Commands writing or waiting to write `aggregate_id`
+    pub fn depth(&self, aggregate_id: Uuid) -> usize {
+        self.lanes
+            .lock()
+            .expect("write queue lock poisoned")
+            .get(&aggregate_id)
+            .map_or(0, |lane| lane.depth)
+    }
+
+    /// Current values
+    pub fn metrics(&self) -> WriteQueueMetrics {
+        let lanes = self.lanes.lock().expect("write queue lock poisoned");
+        WriteQueueMetrics {
+            queued: lanes.values().map(|lane| lane.depth).sum(),
+            busy_aggregates: lanes.len(),
+            max_depth: self.counters.max_depth.load(Ordering::Relaxed),
+            acquired: self.counters.acquired.load(Ordering::Relaxed),
+            timed_out: self.counters.timed_out.load(Ordering::Relaxed),
+            rejected: self.counters.rejected.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// A command's place in a lane, given up when dropped
+struct Place {
+    lanes: Lanes,
+    aggregate_id: Uuid,
+}
+
+impl Drop for Place {
+    fn drop(&mut self) {
+        let mut lanes = self.lanes.lock().expect("write queue lock poisoned");
+        if let Some(lane) = lanes.get_mut(&self.aggregate_id) {
+            lane.depth -= 1;
+            if lane.depth == 0 {
+                lanes.remove(&self.aggregate_id);
+            }
+        }
+    }
+}
+
+/// Exclusive local right to write one aggregate
+pub struct WriteTurn {
+    _guard: OwnedMutexGuard<()>,
+    _place: Place,
+}
+
+/// Write queue counters at one point in time
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WriteQueueMetrics {
+    /// Commands writing or waiting, across all aggregates
+    pub queued: usize,
+
+    /// Aggregates with a command writing or waiting
+    pub busy_aggregates: usize,
+
+    /// Deepest queue seen on one aggregate, the writer included
+    pub max_depth: u64,
+
+    /// Turns granted
+    pub acquired: u64,
+
+    /// Commands that gave up waiting
+    pub timed_out: u64,
+
+    /// Commands turned away from a full queue
+    pub rejected: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_turns_are_exclusive_per_aggregate() {
+        // Arrange
+        let queue = WriteQueue::new(
+            WriteQueueConfig::default()
+                .with_timeout(Duration::from_millis(20))
+                .with_max_depth(1),
+        );
+        let aggregate_id = Uuid::now_v7();
+
+        // Act
+        let first = queue.acquire(aggregate_id).await.unwrap();
+        let other = queue.acquire(Uuid::now_v7()).await;
+        let waiting = {
+            let queue = queue.clone();
+            tokio::spawn(async move { queue.acquire(aggregate_id).await.map(|_| ()) })
+        };
+        tokio::task::yield_now().await;
+        let rejected = queue.acquire(aggregate_id).await;
+        let timed_out = waiting.await.unwrap();
+        drop(first);
+        let next = queue.acquire(aggregate_id).await;
+
+        // Assert
+        assert!(other.is_ok());
+        assert!(matches!(
+            rejected,
+            Err(WriteQueueError::Full { waiting: 1, .. })
+        ));
+        assert!(matches!(timed_out, Err(WriteQueueError::Timeout { .. })));
+        assert!(next.is_ok());
+
+        let metrics = queue.metrics();
+        assert_eq!(metrics.max_depth, 2);
+        assert_eq!(metrics.timed_out, 1);
+        assert_eq!(metrics.rejected, 1);
+        drop((other, next));
+        assert_eq!(queue.depth(aggregate_id), 0);
+    }
+}
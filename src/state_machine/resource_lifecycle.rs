@@ -53,6 +53,22 @@ pub enum LifecycleCommand {
     Update,
 }
 
+impl LifecycleCommand {
+    /// Command moving a resource from `from` to `to`, if any does
+    pub fn between(from: ResourceStatus, to: ResourceStatus) -> Option<Self> {
+        use ResourceStatus::*;
+
+        match (from, to) {
+            _ if from == to => Some(LifecycleCommand::Update),
+            (Maintenance, Active) => Some(LifecycleCommand::EndMaintenance),
+            (_, Active) => Some(LifecycleCommand::Activate),
+            (_, Maintenance) => Some(LifecycleCommand::BeginMaintenance),
+            (_, Decommissioned) => Some(LifecycleCommand::Decommission),
+            (_, Provisioning) => None,
+        }
+    }
+}
+
 /// Transition output with metadata
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TransitionOutput {
@@ -187,6 +203,38 @@ impl StateMachine for ResourceStatus {
     }
 }
 
+/// Move a resource from one status to another through the FSM
+///
+/// Fails when no lifecycle command leads from `from` to `to`.
+pub fn transition_to(
+    from: ResourceStatus,
+    to: ResourceStatus,
+) -> TransitionResult<TransitionOutput> {
+    let invalid = || TransitionError::InvalidTransition {
+        from: format!("{:?}", from),
+        to: format!("{:?}", to),
+    };
+
+    let command = LifecycleCommand::between(from, to).ok_or_else(invalid)?;
+    match from.transition(&command)? {
+        (reached, output) if reached == to => Ok(output),
+        _ => Err(invalid()),
+    }
+}
+
+/// Statuses a resource can move to from `status`, besides staying put
+pub fn allowed_next_states(status: ResourceStatus) -> Vec<ResourceStatus> {
+    let mut allowed = Vec::new();
+    for command in status.valid_inputs() {
+        if let Ok((next, _)) = status.transition(&command) {
+            if next != status && !allowed.contains(&next) {
+                allowed.push(next);
+            }
+        }
+    }
+    allowed
+}
+
 /// Helper to check if transition is allowed
 pub fn is_valid_lifecycle_transition(
     from: ResourceStatus,
@@ -324,4 +372,21 @@ mod tests {
             assert_eq!(new_state, ResourceStatus::Decommissioned);
         }
     }
+
+    #[test]
+    fn test_transition_to_follows_lifecycle_commands() {
+        // Maintenance returns to Active through EndMaintenance
+        assert!(transition_to(ResourceStatus::Maintenance, ResourceStatus::Active).is_ok());
+
+        // Nothing leads back to Provisioning
+        assert!(matches!(
+            transition_to(ResourceStatus::Decommissioned, ResourceStatus::Provisioning),
+            Err(TransitionError::InvalidTransition { .. })
+        ));
+        assert_eq!(
+            allowed_next_states(ResourceStatus::Provisioning),
+            vec![ResourceStatus::Active, ResourceStatus::Decommissioned]
+        );
+        assert!(allowed_next_states(ResourceStatus::Decommissioned).is_empty());
+    }
 }
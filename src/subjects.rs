@@ -17,6 +17,42 @@
 //! - Aggregate-level wildcards (`infrastructure.compute.>`)
 //! - Global subscriptions (`infrastructure.>`)
 //!
+//! Stored events carry their aggregate ID between the two, which
+//! [`EventSubject`] parses back:
+//!
+//! ```text
+//! infrastructure.{aggregate}.{aggregate_id}.{operation}
+//! ```
+//!
+//! Every aggregate has its own segment (`compute`, `network`, `interface`,
+//! `ip_pool`, `connection`, `software`, `policy`) and
+//! [`AggregateType::operations`] lists the operations it publishes.
+//! Events stored before interfaces and IP pools had their own segments
+//! stay under `network`, with their event type name lower-cased as the
+//! last token.
+//!
+//! # Tenants
+//!
+//! Organizations sharing a NATS cluster can be isolated by placing their
+//! subjects under an organization token:
+//!
+//! ```text
+//! infrastructure.{org_id}.{aggregate}.{operation}
+//! ```
+//!
+//! Tenant subjects never overlap the untenanted ones (organization IDs may
+//! not be [reserved tokens](is_reserved_token) such as aggregate names or
+//! `cmd`), so each tenant can have its own stream
+//! ([`JetStreamConfig::for_tenant`](crate::jetstream::JetStreamConfig::for_tenant))
+//! and subscribers filter on [`subjects::tenant_events`] to see only one
+//! organization. Request/reply subjects (commands, queries) stay shared.
+//!
+//! # Registry
+//!
+//! [`registry::SubjectRegistry`] lists every subject the crate publishes
+//! on with its payload types, validates publishes against that list and
+//! documents it for other teams.
+//!
 //! # Examples
 //!
 //! ```rust
@@ -34,9 +70,20 @@
 //!     .aggregate(AggregateType::Network)
 //!     .build_wildcard();
 //! assert_eq!(wildcard, "infrastructure.network.>");
+//!
+//! // Scope a subject to one organization
+//! let tenant = SubjectBuilder::new()
+//!     .tenant("acme")
+//!     .aggregate(AggregateType::Compute)
+//!     .operation(Operation::Registered)
+//!     .build();
+//! assert_eq!(tenant, "infrastructure.acme.compute.registered");
 //! ```
 
+pub mod registry;
+
 use std::fmt;
+use uuid::Uuid;
 
 /// Root namespace for all infrastructure subjects
 pub const INFRASTRUCTURE_ROOT: &str = "infrastructure";
@@ -44,12 +91,17 @@ pub const INFRASTRUCTURE_ROOT: &str = "infrastructure";
 /// Infrastructure aggregate types
 ///
 /// These represent the bounded contexts within the infrastructure domain.
+/// Each is the second token of its event subjects.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum AggregateType {
     /// Compute resources (servers, VMs, containers)
     Compute,
     /// Network topology and configuration
     Network,
+    /// Network interfaces and bonds of compute resources
+    Interface,
+    /// IP address pools
+    IpPool,
     /// Physical and logical connections between resources
     Connection,
     /// Software artifacts and configurations
@@ -58,40 +110,150 @@ pub enum AggregateType {
     Policy,
 }
 
-impl fmt::Display for AggregateType {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl AggregateType {
+    /// Every aggregate type
+    pub const ALL: [AggregateType; 7] = [
+        AggregateType::Compute,
+        AggregateType::Network,
+        AggregateType::Interface,
+        AggregateType::IpPool,
+        AggregateType::Connection,
+        AggregateType::Software,
+        AggregateType::Policy,
+    ];
+
+    /// Subject token
+    pub fn as_str(&self) -> &'static str {
         match self {
-            AggregateType::Compute => write!(f, "compute"),
-            AggregateType::Network => write!(f, "network"),
-            AggregateType::Connection => write!(f, "connection"),
-            AggregateType::Software => write!(f, "software"),
-            AggregateType::Policy => write!(f, "policy"),
+            AggregateType::Compute => "compute",
+            AggregateType::Network => "network",
+            AggregateType::Interface => "interface",
+            AggregateType::IpPool => "ip_pool",
+            AggregateType::Connection => "connection",
+            AggregateType::Software => "software",
+            AggregateType::Policy => "policy",
         }
     }
+
+    /// Aggregate type of a subject token
+    pub fn parse(token: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|aggregate| aggregate.as_str() == token)
+    }
+
+    /// Operations (event subjects) of the aggregate
+    pub fn operations(&self) -> &'static [Operation] {
+        use Operation::*;
+
+        match self {
+            AggregateType::Compute => &[
+                Registered,
+                OrganizationAssigned,
+                LocationAssigned,
+                OwnerAssigned,
+                PolicyAdded,
+                PolicyRemoved,
+                AccountConceptAssigned,
+                AccountConceptCleared,
+                HardwareDetailsSet,
+                AssetTagAssigned,
+                MetadataUpdated,
+                StatusChanged,
+                ConfigurationBackupRecorded,
+                GuestAttached,
+                GuestDetached,
+                Decommissioned,
+                Updated,
+            ],
+            AggregateType::Network => &[Defined, CidrChanged, VlanAssigned, Removed],
+            AggregateType::Interface => &[Attached, BondFormed, BondDissolved, Added],
+            AggregateType::IpPool => &[Defined, AddressAllocated, AddressReleased],
+            AggregateType::Connection => &[Established, Labeled, Removed, Severed],
+            AggregateType::Software => &[Configured, Deployed],
+            AggregateType::Policy => &[ViolationDetected, ComplianceRestored, Set],
+        }
+    }
+}
+
+impl fmt::Display for AggregateType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
 }
 
 /// Infrastructure operations (event types)
 ///
-/// These represent domain events that can occur within each aggregate.
+/// These represent domain events that can occur within each aggregate;
+/// [`AggregateType::operations`] lists which belong to which. Each is the
+/// last token of its event subjects.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Operation {
     // Compute operations
     /// A compute resource was registered in the system
     Registered,
+    /// A compute resource was assigned to an organization
+    OrganizationAssigned,
+    /// A compute resource was assigned to a location
+    LocationAssigned,
+    /// A compute resource was assigned an owner
+    OwnerAssigned,
+    /// A policy was attached to a compute resource
+    PolicyAdded,
+    /// A policy was detached from a compute resource
+    PolicyRemoved,
+    /// A compute resource was assigned an account concept
+    AccountConceptAssigned,
+    /// A compute resource's account concept was cleared
+    AccountConceptCleared,
+    /// Hardware details of a compute resource were set
+    HardwareDetailsSet,
+    /// A compute resource was tagged with an asset tag
+    AssetTagAssigned,
+    /// Metadata of a compute resource was updated
+    MetadataUpdated,
+    /// A compute resource changed status
+    StatusChanged,
+    /// A configuration backup of a compute resource was recorded
+    ConfigurationBackupRecorded,
+    /// A guest was placed on a compute resource
+    GuestAttached,
+    /// A guest was removed from a compute resource
+    GuestDetached,
     /// A compute resource was decommissioned
     Decommissioned,
     /// A compute resource was updated
     Updated,
 
-    // Network operations
-    /// A network was defined
+    // Network and IP pool operations
+    /// A network (or IP pool) was defined
     Defined,
-    /// A network was removed
+    /// A network's CIDR was changed
+    CidrChanged,
+    /// A network was assigned a VLAN
+    VlanAssigned,
+    /// A network (or connection) was removed
     Removed,
+    /// An address was allocated from an IP pool
+    AddressAllocated,
+    /// An address was returned to an IP pool
+    AddressReleased,
+
+    // Interface operations
+    /// An interface was attached to a compute resource
+    Attached,
+    /// Interfaces were bonded
+    BondFormed,
+    /// A bond was dissolved
+    BondDissolved,
+    /// An interface was added
+    Added,
 
     // Connection operations
     /// A connection was established
     Established,
+    /// A connection was labeled
+    Labeled,
     /// A connection was severed
     Severed,
 
@@ -101,30 +263,166 @@ pub enum Operation {
     /// Software was deployed
     Deployed,
 
-    // Interface operations
-    /// An interface was added
-    Added,
-
     // Policy operations
+    /// A resource started breaking a policy rule
+    ViolationDetected,
+    /// A resource stopped breaking a policy rule
+    ComplianceRestored,
     /// A policy was set or updated
     Set,
 }
 
+impl Operation {
+    /// Every operation
+    pub const ALL: [Operation; 35] = [
+        Operation::Registered,
+        Operation::OrganizationAssigned,
+        Operation::LocationAssigned,
+        Operation::OwnerAssigned,
+        Operation::PolicyAdded,
+        Operation::PolicyRemoved,
+        Operation::AccountConceptAssigned,
+        Operation::AccountConceptCleared,
+        Operation::HardwareDetailsSet,
+        Operation::AssetTagAssigned,
+        Operation::MetadataUpdated,
+        Operation::StatusChanged,
+        Operation::ConfigurationBackupRecorded,
+        Operation::GuestAttached,
+        Operation::GuestDetached,
+        Operation::Decommissioned,
+        Operation::Updated,
+        Operation::Defined,
+        Operation::CidrChanged,
+        Operation::VlanAssigned,
+        Operation::Removed,
+        Operation::AddressAllocated,
+        Operation::AddressReleased,
+        Operation::Attached,
+        Operation::BondFormed,
+        Operation::BondDissolved,
+        Operation::Added,
+        Operation::Established,
+        Operation::Labeled,
+        Operation::Severed,
+        Operation::Configured,
+        Operation::Deployed,
+        Operation::ViolationDetected,
+        Operation::ComplianceRestored,
+        Operation::Set,
+    ];
+
+    /// Subject token
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Operation::Registered => "registered",
+            Operation::OrganizationAssigned => "organization_assigned",
+            Operation::LocationAssigned => "location_assigned",
+            Operation::OwnerAssigned => "owner_assigned",
+            Operation::PolicyAdded => "policy_added",
+            Operation::PolicyRemoved => "policy_removed",
+            Operation::AccountConceptAssigned => "account_concept_assigned",
+            Operation::AccountConceptCleared => "account_concept_cleared",
+            Operation::HardwareDetailsSet => "hardware_details_set",
+            Operation::AssetTagAssigned => "asset_tag_assigned",
+            Operation::MetadataUpdated => "metadata_updated",
+            Operation::StatusChanged => "status_changed",
+            Operation::ConfigurationBackupRecorded => "configuration_backup_recorded",
+            Operation::GuestAttached => "guest_attached",
+            Operation::GuestDetached => "guest_detached",
+            Operation::Decommissioned => "decommissioned",
+            Operation::Updated => "updated",
+            Operation::Defined => "defined",
+            Operation::CidrChanged => "cidr_changed",
+            Operation::VlanAssigned => "vlan_assigned",
+            Operation::Removed => "removed",
+            Operation::AddressAllocated => "address_allocated",
+            Operation::AddressReleased => "address_released",
+            Operation::Attached => "attached",
+            Operation::BondFormed => "bond_formed",
+            Operation::BondDissolved => "bond_dissolved",
+            Operation::Added => "added",
+            Operation::Established => "established",
+            Operation::Labeled => "labeled",
+            Operation::Severed => "severed",
+            Operation::Configured => "configured",
+            Operation::Deployed => "deployed",
+            Operation::ViolationDetected => "violation_detected",
+            Operation::ComplianceRestored => "compliance_restored",
+            Operation::Set => "set",
+        }
+    }
+
+    /// Operation of a subject token
+    pub fn parse(token: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|operation| operation.as_str() == token)
+    }
+}
+
 impl fmt::Display for Operation {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Operation::Registered => write!(f, "registered"),
-            Operation::Decommissioned => write!(f, "decommissioned"),
-            Operation::Updated => write!(f, "updated"),
-            Operation::Defined => write!(f, "defined"),
-            Operation::Removed => write!(f, "removed"),
-            Operation::Established => write!(f, "established"),
-            Operation::Severed => write!(f, "severed"),
-            Operation::Configured => write!(f, "configured"),
-            Operation::Deployed => write!(f, "deployed"),
-            Operation::Added => write!(f, "added"),
-            Operation::Set => write!(f, "set"),
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Parsed aggregate event subject
+///
+/// `infrastructure[.{org_id}].{aggregate}.{aggregate_id}.{operation}`, as
+/// written by the event store and the services.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventSubject {
+    /// Organization the subject is scoped to
+    pub tenant: Option<String>,
+
+    /// Aggregate type token
+    pub aggregate: AggregateType,
+
+    /// Aggregate the event belongs to
+    pub aggregate_id: Uuid,
+
+    /// Operation token
+    pub operation: Operation,
+}
+
+impl EventSubject {
+    /// Parse an event subject; None for any other subject
+    pub fn parse(subject: &str) -> Option<Self> {
+        let tokens: Vec<&str> = subject.split('.').collect();
+        let (tenant, rest) = match tokens.as_slice() {
+            [root, rest @ ..] if *root == INFRASTRUCTURE_ROOT && rest.len() == 3 => (None, rest),
+            [root, org_id, rest @ ..] if *root == INFRASTRUCTURE_ROOT && rest.len() == 3 => {
+                (Some(org_id.to_string()), rest)
+            }
+            _ => return None,
+        };
+        let aggregate = AggregateType::parse(rest[0])?;
+        let operation = Operation::parse(rest[2])?;
+        if !aggregate.operations().contains(&operation) {
+            return None;
         }
+        Some(Self {
+            tenant,
+            aggregate,
+            aggregate_id: Uuid::parse_str(rest[1]).ok()?,
+            operation,
+        })
+    }
+}
+
+impl fmt::Display for EventSubject {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(org_id) = &self.tenant {
+            write!(f, "{}.{}.", INFRASTRUCTURE_ROOT, org_id)?;
+        } else {
+            write!(f, "{}.", INFRASTRUCTURE_ROOT)?;
+        }
+        write!(
+            f,
+            "{}.{}.{}",
+            self.aggregate, self.aggregate_id, self.operation
+        )
     }
 }
 
@@ -133,7 +431,9 @@ impl fmt::Display for Operation {
 /// Provides a type-safe way to construct NATS subject patterns.
 #[derive(Debug, Clone)]
 pub struct SubjectBuilder {
+    tenant: Option<String>,
     aggregate: Option<AggregateType>,
+    aggregate_id: Option<Uuid>,
     operation: Option<Operation>,
 }
 
@@ -141,17 +441,42 @@ impl SubjectBuilder {
     /// Create a new subject builder
     pub fn new() -> Self {
         Self {
+            tenant: None,
             aggregate: None,
+            aggregate_id: None,
             operation: None,
         }
     }
 
+    /// Scope the subject to an organization
+    ///
+    /// # Panics
+    ///
+    /// Panics if `org_id` is not a valid subject token (empty, or
+    /// containing `.`, `*`, `>` or whitespace)
+    pub fn tenant(mut self, org_id: impl Into<String>) -> Self {
+        let org_id = org_id.into();
+        assert!(
+            is_valid_token(&org_id),
+            "tenant must be a single subject token: {:?}",
+            org_id
+        );
+        self.tenant = Some(org_id);
+        self
+    }
+
     /// Set the aggregate type
     pub fn aggregate(mut self, aggregate: AggregateType) -> Self {
         self.aggregate = Some(aggregate);
         self
     }
 
+    /// Set the aggregate the event belongs to
+    pub fn aggregate_id(mut self, aggregate_id: Uuid) -> Self {
+        self.aggregate_id = Some(aggregate_id);
+        self
+    }
+
     /// Set the operation
     pub fn operation(mut self, operation: Operation) -> Self {
         self.operation = Some(operation);
@@ -160,25 +485,47 @@ impl SubjectBuilder {
 
     /// Build the complete subject string
     ///
+    /// Returns `infrastructure.{aggregate}.{operation}`, or the event
+    /// subject `infrastructure.{aggregate}.{aggregate_id}.{operation}` when
+    /// an aggregate ID is set.
+    ///
     /// # Panics
     ///
     /// Panics if aggregate or operation is not set
     pub fn build(self) -> String {
         let aggregate = self.aggregate.expect("aggregate must be set");
         let operation = self.operation.expect("operation must be set");
-        format!("{}.{}.{}", INFRASTRUCTURE_ROOT, aggregate, operation)
+        match self.aggregate_id {
+            Some(aggregate_id) => format!(
+                "{}.{}.{}.{}",
+                self.root(),
+                aggregate,
+                aggregate_id,
+                operation
+            ),
+            None => format!("{}.{}.{}", self.root(), aggregate, operation),
+        }
     }
 
     /// Build a wildcard subscription for all operations on this aggregate
     ///
-    /// Returns: `infrastructure.{aggregate}.>`
+    /// Returns: `infrastructure.{aggregate}.>` (or
+    /// `infrastructure.{org_id}.{aggregate}.>` with a tenant)
     ///
     /// # Panics
     ///
     /// Panics if aggregate is not set
     pub fn build_wildcard(self) -> String {
         let aggregate = self.aggregate.expect("aggregate must be set");
-        format!("{}.{}.>", INFRASTRUCTURE_ROOT, aggregate)
+        format!("{}.{}.>", self.root(), aggregate)
+    }
+
+    /// Root of the subject: `infrastructure` or `infrastructure.{org_id}`
+    fn root(&self) -> String {
+        match &self.tenant {
+            Some(org_id) => subjects::tenant_root(org_id),
+            None => INFRASTRUCTURE_ROOT.to_string(),
+        }
     }
 
     /// Build a subscription for all infrastructure events
@@ -195,6 +542,68 @@ impl Default for SubjectBuilder {
     }
 }
 
+/// Whether `token` can stand as one subject token
+pub fn is_valid_token(token: &str) -> bool {
+    !token.is_empty()
+        && !token
+            .chars()
+            .any(|c| matches!(c, '.' | '*' | '>') || c.is_whitespace())
+}
+
+/// Tokens of derived records stored next to the events of a root
+const DERIVED_TOKENS: [&str; 7] = [
+    "correlation",
+    "change",
+    "cid",
+    "feed",
+    "advisory",
+    "scorecard",
+    "alert",
+];
+
+/// Tokens of request/reply and transient subjects under the root
+const TRANSIENT_TOKENS: [&str; 7] = [
+    "cmd",
+    "query",
+    "notify",
+    "progress",
+    "observation",
+    "health",
+    "agent",
+];
+
+/// Whether `token` has a meaning of its own right under the root
+///
+/// Aggregate types, derived records and request/reply subjects: a tenant
+/// named like one would share subjects with the untenanted tree.
+pub fn is_reserved_token(token: &str) -> bool {
+    AggregateType::parse(token).is_some()
+        || DERIVED_TOKENS.contains(&token)
+        || TRANSIENT_TOKENS.contains(&token)
+}
+
+/// Whether `org_id` can name a tenant: one subject token, not reserved
+pub fn is_valid_tenant(org_id: &str) -> bool {
+    is_valid_token(org_id) && !is_reserved_token(org_id)
+}
+
+/// Whether some subject matches both filters `a` and `b`
+///
+/// `*` stands for any one token and `>` for one or more trailing tokens,
+/// as in NATS subscriptions and stream subjects.
+pub fn subjects_overlap(a: &str, b: &str) -> bool {
+    let mut a = a.split('.');
+    let mut b = b.split('.');
+    loop {
+        match (a.next(), b.next()) {
+            (Some(">"), Some(_)) | (Some(_), Some(">")) => return true,
+            (Some(x), Some(y)) if x == y || x == "*" || y == "*" => {}
+            (None, None) => return true,
+            _ => return false,
+        }
+    }
+}
+
 /// Convenience functions for common subject patterns
 pub mod subjects {
     use super::*;
@@ -308,6 +717,123 @@ pub mod subjects {
     pub fn all_infrastructure_events() -> String {
         SubjectBuilder::build_all()
     }
+
+    // Derived feeds
+    pub fn organization_feed(organization_id: &str) -> String {
+        format!("{}.feed.{}", INFRASTRUCTURE_ROOT, organization_id)
+    }
+
+    pub fn all_organization_feeds() -> String {
+        format!("{}.feed.>", INFRASTRUCTURE_ROOT)
+    }
+
+    // Commands (request/reply, never persisted)
+    pub fn command(aggregate: AggregateType, command: &str) -> String {
+        format!("{}.cmd.{}.{}", INFRASTRUCTURE_ROOT, aggregate, command)
+    }
+
+    pub fn all_commands() -> String {
+        format!("{}.cmd.>", INFRASTRUCTURE_ROOT)
+    }
+
+    // Read-model queries (request/reply, never persisted)
+    pub fn query(model: &str, query: &str) -> String {
+        format!("{}.query.{}.{}", INFRASTRUCTURE_ROOT, model, query)
+    }
+
+    pub fn all_queries() -> String {
+        format!("{}.query.>", INFRASTRUCTURE_ROOT)
+    }
+
+    // Event notifications fanned out per type (core NATS, never persisted)
+    pub fn notification(aggregate: &str, event_type: &str) -> String {
+        format!("{}.notify.{}.{}", INFRASTRUCTURE_ROOT, aggregate, event_type)
+    }
+
+    pub fn all_notifications() -> String {
+        format!("{}.notify.>", INFRASTRUCTURE_ROOT)
+    }
+
+    // Long-running operation progress (core NATS, never persisted)
+    pub fn progress(operation_id: &str) -> String {
+        format!("{}.progress.{}", INFRASTRUCTURE_ROOT, operation_id)
+    }
+
+    pub fn all_progress() -> String {
+        format!("{}.progress.>", INFRASTRUCTURE_ROOT)
+    }
+
+    // Observed state from agents and scans (core NATS, never persisted)
+    pub fn observation(source: &str) -> String {
+        format!("{}.observation.{}", INFRASTRUCTURE_ROOT, source)
+    }
+
+    pub fn all_observations() -> String {
+        format!("{}.observation.>", INFRASTRUCTURE_ROOT)
+    }
+
+    // Component health reports (core NATS, never persisted)
+    pub fn health_report() -> String {
+        format!("{}.health.report", INFRASTRUCTURE_ROOT)
+    }
+
+    // Discovery agent fact requests (request/reply, never persisted)
+    pub fn agent_facts(agent: &str) -> String {
+        format!("{}.agent.{}.facts", INFRASTRUCTURE_ROOT, agent)
+    }
+
+    /// Subjects captured by the event stream
+    ///
+    /// Everything except request/reply subjects: a stream bound to
+    /// `infrastructure.>` would also capture commands and queries and
+    /// answer each request with a JetStream publish ack. Progress reports,
+    /// observations and notifications are transient and left out as well.
+    pub fn stream_subjects() -> Vec<String> {
+        stream_subjects_under(INFRASTRUCTURE_ROOT)
+    }
+
+    /// Request/reply and transient subjects, none of which a stream may capture
+    pub fn transient_subjects() -> Vec<String> {
+        TRANSIENT_TOKENS
+            .iter()
+            .map(|token| format!("{}.{}.>", INFRASTRUCTURE_ROOT, token))
+            .collect()
+    }
+
+    // Tenants (one organization on a shared cluster)
+    pub fn tenant_root(org_id: &str) -> String {
+        format!("{}.{}", INFRASTRUCTURE_ROOT, org_id)
+    }
+
+    pub fn tenant_events(org_id: &str) -> String {
+        format!("{}.>", tenant_root(org_id))
+    }
+
+    pub fn tenant_compute_events(org_id: &str) -> String {
+        SubjectBuilder::new()
+            .tenant(org_id)
+            .aggregate(AggregateType::Compute)
+            .build_wildcard()
+    }
+
+    /// Subjects captured by a tenant's event stream
+    ///
+    /// The same subjects as [`stream_subjects`], under the tenant's root.
+    pub fn tenant_stream_subjects(org_id: &str) -> Vec<String> {
+        stream_subjects_under(&tenant_root(org_id))
+    }
+
+    fn stream_subjects_under(root: &str) -> Vec<String> {
+        AggregateType::ALL
+            .iter()
+            .map(|aggregate| format!("{}.{}.>", root, aggregate))
+            .chain(
+                DERIVED_TOKENS
+                    .iter()
+                    .map(|derived| format!("{}.{}.>", root, derived)),
+            )
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -354,6 +880,101 @@ mod tests {
         assert_eq!(subjects::all_infrastructure_events(), "infrastructure.>");
     }
 
+    #[test]
+    fn test_organization_feed_subjects() {
+        assert_eq!(subjects::organization_feed("acme"), "infrastructure.feed.acme");
+        assert_eq!(subjects::all_organization_feeds(), "infrastructure.feed.>");
+        assert_eq!(
+            subjects::notification("compute", "registered"),
+            "infrastructure.notify.compute.registered"
+        );
+        assert_eq!(subjects::all_notifications(), "infrastructure.notify.>");
+    }
+
+    #[test]
+    fn test_command_subjects() {
+        assert_eq!(
+            subjects::command(AggregateType::Compute, "assign_owner"),
+            "infrastructure.cmd.compute.assign_owner"
+        );
+        assert_eq!(subjects::all_commands(), "infrastructure.cmd.>");
+        assert!(!subjects::stream_subjects()
+            .iter()
+            .any(|s| s.starts_with("infrastructure.cmd")));
+    }
+
+    #[test]
+    fn test_query_subjects() {
+        assert_eq!(
+            subjects::query("topology", "view"),
+            "infrastructure.query.topology.view"
+        );
+        assert_eq!(subjects::all_queries(), "infrastructure.query.>");
+        assert!(!subjects::stream_subjects()
+            .iter()
+            .any(|s| s.starts_with("infrastructure.query")));
+    }
+
+    #[test]
+    fn test_progress_subjects() {
+        assert_eq!(
+            subjects::progress("0193abcd"),
+            "infrastructure.progress.0193abcd"
+        );
+        assert_eq!(subjects::all_progress(), "infrastructure.progress.>");
+        assert!(!subjects::stream_subjects()
+            .iter()
+            .any(|s| s.starts_with("infrastructure.progress")));
+    }
+
+    #[test]
+    fn test_observation_subjects() {
+        assert_eq!(
+            subjects::observation("lldp-agent"),
+            "infrastructure.observation.lldp-agent"
+        );
+        assert_eq!(subjects::all_observations(), "infrastructure.observation.>");
+    }
+
+    #[test]
+    fn test_health_report_subject() {
+        assert_eq!(subjects::health_report(), "infrastructure.health.report");
+        assert!(!subjects::stream_subjects()
+            .iter()
+            .any(|s| s.starts_with("infrastructure.health")));
+    }
+
+    #[test]
+    fn test_agent_facts_subject() {
+        assert_eq!(
+            subjects::agent_facts("web-01"),
+            "infrastructure.agent.web-01.facts"
+        );
+        assert!(!subjects::stream_subjects()
+            .iter()
+            .any(|s| s.starts_with("infrastructure.agent")));
+    }
+
+    #[test]
+    fn test_tenant_subjects() {
+        let subject = SubjectBuilder::new()
+            .tenant("acme")
+            .aggregate(AggregateType::Network)
+            .build_wildcard();
+
+        assert_eq!(subject, "infrastructure.acme.network.>");
+        assert_eq!(subjects::tenant_events("acme"), "infrastructure.acme.>");
+        assert_eq!(
+            subjects::tenant_compute_events("acme"),
+            "infrastructure.acme.compute.>"
+        );
+        assert!(subjects::tenant_stream_subjects("acme")
+            .iter()
+            .all(|s| s.starts_with("infrastructure.acme.")));
+        assert!(!is_valid_token("acme.eu"));
+        assert!(!is_valid_token(""));
+    }
+
     #[test]
     fn test_aggregate_display() {
         assert_eq!(AggregateType::Compute.to_string(), "compute");
@@ -367,4 +988,104 @@ mod tests {
         assert_eq!(Operation::Defined.to_string(), "defined");
         assert_eq!(Operation::Established.to_string(), "established");
     }
+
+    #[test]
+    fn test_every_aggregate_and_operation_round_trips() {
+        let aggregate_id = Uuid::now_v7();
+
+        for aggregate in AggregateType::ALL {
+            assert_eq!(AggregateType::parse(aggregate.as_str()), Some(aggregate));
+            assert!(is_valid_token(aggregate.as_str()));
+            assert!(
+                subjects::stream_subjects().contains(&format!("infrastructure.{}.>", aggregate))
+            );
+
+            for &operation in aggregate.operations() {
+                let subject = SubjectBuilder::new()
+                    .aggregate(aggregate)
+                    .aggregate_id(aggregate_id)
+                    .operation(operation)
+                    .build();
+                let tenant_subject = SubjectBuilder::new()
+                    .tenant("acme")
+                    .aggregate(aggregate)
+                    .aggregate_id(aggregate_id)
+                    .operation(operation)
+                    .build();
+
+                let parsed = EventSubject::parse(&subject).unwrap();
+                let parsed_tenant = EventSubject::parse(&tenant_subject).unwrap();
+
+                assert_eq!(
+                    parsed,
+                    EventSubject {
+                        tenant: None,
+                        aggregate,
+                        aggregate_id,
+                        operation,
+                    }
+                );
+                assert_eq!(parsed.to_string(), subject);
+                assert_eq!(parsed_tenant.tenant.as_deref(), Some("acme"));
+                assert_eq!(parsed_tenant.to_string(), tenant_subject);
+            }
+        }
+
+        for operation in Operation::ALL {
+            assert_eq!(Operation::parse(operation.as_str()), Some(operation));
+            assert!(AggregateType::ALL
+                .iter()
+                .any(|aggregate| aggregate.operations().contains(&operation)));
+        }
+    }
+
+    #[test]
+    fn test_overlapping_subjects() {
+        assert!(subjects_overlap("infrastructure.>", "infrastructure.cmd.>"));
+        assert!(subjects_overlap(">", "infrastructure.query.>"));
+        assert!(subjects_overlap(
+            "infrastructure.*.compute.assign_owner",
+            "infrastructure.cmd.>"
+        ));
+        assert!(subjects_overlap("infrastructure.cmd", "infrastructure.*"));
+        assert!(!subjects_overlap("infrastructure.compute.>", "infrastructure.cmd.>"));
+        assert!(!subjects_overlap("infrastructure.cmd", "infrastructure.cmd.>"));
+        assert!(!subjects_overlap("infrastructure.*", "infrastructure.cmd.x"));
+        for stream_subject in subjects::stream_subjects() {
+            for transient in subjects::transient_subjects() {
+                assert!(!subjects_overlap(&stream_subject, &transient));
+            }
+        }
+    }
+
+    #[test]
+    fn test_event_subject_rejects_foreign_subjects() {
+        let aggregate_id = Uuid::now_v7();
+
+        assert_eq!(
+            EventSubject::parse("infrastructure.compute.registered"),
+            None
+        );
+        assert_eq!(
+            EventSubject::parse(&format!(
+                "infrastructure.network.{}.established",
+                aggregate_id
+            )),
+            None,
+            "operation of another aggregate"
+        );
+        assert_eq!(
+            EventSubject::parse(&format!(
+                "infrastructure.network.{}.networkdefined",
+                aggregate_id
+            )),
+            None,
+            "legacy event type token"
+        );
+        assert_eq!(
+            EventSubject::parse("infrastructure.cmd.compute.assign_owner"),
+            None
+        );
+        assert_eq!(EventSubject::parse("metrics.compute.x.registered"), None);
+    }
 }
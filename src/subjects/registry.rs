@@ -0,0 +1,802 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Subject Registry
+//!
+//! Declares every subject this crate publishes on, with the payloads it
+//! may carry, so publishes can be checked against the contract and the
+//! contract can be handed to other teams:
+//!
+//! | Pattern                                                | Kind      | Payload                       |
+//! |--------------------------------------------------------|-----------|-------------------------------|
+//! | `infrastructure.{aggregate}.{aggregateId}.{eventType}` | event     | aggregate events              |
+//! | `infrastructure.{orgId}.{aggregate}.{aggregateId}.…`   | event     | aggregate events of a tenant  |
+//! | `infrastructure.correlation.{correlationId}`           | event     | `StoredEvent`                 |
+//! | `infrastructure.advisory.{advisoryType}`               | event     | `AdvisoryEvent`               |
+//! | `infrastructure.policy.{policyEventType}`              | event     | `PolicyEvent`                 |
+//! | `infrastructure.cmd.compute.{command}`                 | request   | `InfrastructureCommand`       |
+//! | `infrastructure.progress.{operationId}`                | transient | `OperationProgress`           |
+//!
+//! and so on; [`SubjectRegistry::standard`] holds the full list and
+//! [`SubjectRegistry::to_markdown`] prints it.
+//!
+//! Patterns are dot-separated tokens where `{name}` stands for exactly one
+//! token, optionally limited to a set of values. A subject matching several
+//! patterns resolves to the one with the most literal tokens, so
+//! `infrastructure.cmd.compute.assign_owner` is a command rather than an
+//! event of aggregate `cmd`.
+//!
+//! # Validating publishes
+//!
+//! A [`NatsClient`](crate::nats::NatsClient) given a registry refuses to
+//! publish on unregistered subjects or with payloads that do not decode as
+//! one of the subject's payload types. Tests can check subjects and
+//! payloads directly:
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::subjects::registry::SubjectRegistry;
+//!
+//! let registry = SubjectRegistry::standard();
+//! let client = NatsClient::new(config).await?.with_subject_registry(registry.clone());
+//!
+//! let payload = serde_json::to_vec(&advisory)?;
+//! registry.validate(&advisory.subject(), &payload)?;
+//!
+//! std::fs::write("subjects.json", registry.to_asyncapi("Infrastructure", "1.0.0").to_string())?;
+//! ```
+
+use serde::de::DeserializeOwned;
+use serde_json::{json, Map, Value};
+use std::fmt;
+
+use super::{subjects, AggregateType, INFRASTRUCTURE_ROOT};
+use crate::events::advisory::{advisory_subject, AdvisoryEvent};
+use crate::events::alert::{alert_subject, AlertRaised};
+use crate::events::policy::{policy_subject, PolicyEvent};
+use crate::events::progress::OperationProgress;
+use crate::events::scorecard::{scorecard_subject, ScorecardComputed};
+use crate::events::{ComputeResourceEvent, InfrastructureEvent};
+use crate::health::HealthReport;
+use crate::reconcile::Observation;
+
+/// How a subject is used
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SubjectKind {
+    /// Captured by the event stream
+    Event,
+
+    /// Request/reply; never captured by the event stream
+    Request,
+
+    /// Core NATS only; never captured by the event stream
+    Transient,
+}
+
+impl fmt::Display for SubjectKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            SubjectKind::Event => "event",
+            SubjectKind::Request => "request",
+            SubjectKind::Transient => "transient",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// A subject was published outside the registered contract
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum RegistryError {
+    /// No pattern matches the subject
+    #[error("Subject {0} is not registered")]
+    Unregistered(String),
+
+    /// The payload decodes as none of the subject's payload types
+    #[error("Payload on {subject} is not a valid {expected}: {reason}")]
+    InvalidPayload {
+        subject: String,
+        expected: String,
+        reason: String,
+    },
+}
+
+/// Named payload type with a check that bytes decode as it
+#[derive(Clone)]
+pub struct PayloadType {
+    /// Rust type name, also the message name in generated documents
+    pub name: &'static str,
+    check: fn(&[u8]) -> Result<(), String>,
+}
+
+impl PayloadType {
+    /// JSON encoding of `T`
+    pub fn of<T: DeserializeOwned>(name: &'static str) -> Self {
+        Self {
+            name,
+            check: |payload| {
+                serde_json::from_slice::<T>(payload)
+                    .map(|_| ())
+                    .map_err(|e| e.to_string())
+            },
+        }
+    }
+
+    /// No payload
+    pub fn empty() -> Self {
+        Self {
+            name: "Empty",
+            check: |payload| {
+                if payload.is_empty() {
+                    Ok(())
+                } else {
+                    Err(format!("expected no payload, got {} bytes", payload.len()))
+                }
+            },
+        }
+    }
+
+    /// Whether `payload` decodes as this type
+    pub fn check(&self, payload: &[u8]) -> Result<(), String> {
+        (self.check)(payload)
+    }
+}
+
+impl fmt::Debug for PayloadType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PayloadType({})", self.name)
+    }
+}
+
+/// One `{name}` token of a pattern
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubjectParameter {
+    /// Placeholder name
+    pub name: String,
+
+    /// What the token holds
+    pub description: String,
+
+    /// Allowed values (any token when empty)
+    pub values: Vec<String>,
+}
+
+/// A registered subject pattern
+#[derive(Debug, Clone)]
+pub struct SubjectEntry {
+    /// Channel name in generated documents
+    pub name: String,
+
+    /// Dot-separated tokens, `{name}` for a parameter
+    pub pattern: String,
+
+    /// How the subject is used
+    pub kind: SubjectKind,
+
+    /// One-line description
+    pub description: String,
+
+    /// Payload types accepted on the subject
+    pub payloads: Vec<PayloadType>,
+
+    /// Reply payload of a request
+    pub reply: Option<PayloadType>,
+
+    /// Parameters, in pattern order
+    pub parameters: Vec<SubjectParameter>,
+}
+
+impl SubjectEntry {
+    /// Entry for `pattern` carrying `payload`
+    pub fn new(
+        name: impl Into<String>,
+        pattern: impl Into<String>,
+        kind: SubjectKind,
+        payload: PayloadType,
+    ) -> Self {
+        let pattern = pattern.into();
+        let parameters = pattern
+            .split('.')
+            .filter_map(placeholder)
+            .map(|name| SubjectParameter {
+                name: name.to_string(),
+                description: String::new(),
+                values: Vec::new(),
+            })
+            .collect();
+        Self {
+            name: name.into(),
+            pattern,
+            kind,
+            description: String::new(),
+            payloads: vec![payload],
+            reply: None,
+            parameters,
+        }
+    }
+
+    /// Set the description
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// Accept another payload type
+    pub fn with_payload(mut self, payload: PayloadType) -> Self {
+        self.payloads.push(payload);
+        self
+    }
+
+    /// Set the reply payload of a request
+    pub fn with_reply(mut self, reply: PayloadType) -> Self {
+        self.reply = Some(reply);
+        self
+    }
+
+    /// Describe a parameter
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pattern has no `{name}` token
+    pub fn with_parameter(mut self, name: &str, description: impl Into<String>) -> Self {
+        self.parameter_mut(name).description = description.into();
+        self
+    }
+
+    /// Limit a parameter to `values`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pattern has no `{name}` token
+    pub fn with_values<S: ToString>(
+        mut self,
+        name: &str,
+        values: impl IntoIterator<Item = S>,
+    ) -> Self {
+        self.parameter_mut(name).values = values.into_iter().map(|v| v.to_string()).collect();
+        self
+    }
+
+    fn parameter_mut(&mut self, name: &str) -> &mut SubjectParameter {
+        let pattern = &self.pattern;
+        self.parameters
+            .iter_mut()
+            .find(|parameter| parameter.name == name)
+            .unwrap_or_else(|| panic!("{} has no parameter {}", pattern, name))
+    }
+
+    /// Whether `subject` matches the pattern
+    pub fn matches(&self, subject: &str) -> bool {
+        let mut tokens = subject.split('.');
+        for expected in self.pattern.split('.') {
+            let Some(token) = tokens.next() else {
+                return false;
+            };
+            let fits = match placeholder(expected) {
+                Some(name) => {
+                    super::is_valid_token(token)
+                        && self
+                            .parameters
+                            .iter()
+                            .find(|parameter| parameter.name == name)
+                            .map_or(true, |parameter| {
+                                parameter.values.is_empty()
+                                    || parameter.values.iter().any(|value| value == token)
+                            })
+                }
+                None => expected == token,
+            };
+            if !fits {
+                return false;
+            }
+        }
+        tokens.next().is_none()
+    }
+
+    /// Literal tokens in the pattern; the most specific match wins
+    fn specificity(&self) -> usize {
+        self.pattern
+            .split('.')
+            .filter(|token| placeholder(token).is_none())
+            .count()
+    }
+}
+
+/// Name inside a `{name}` token
+fn placeholder(token: &str) -> Option<&str> {
+    token.strip_prefix('{')?.strip_suffix('}')
+}
+
+/// Every permissible subject pattern and its payloads
+#[derive(Debug, Clone, Default)]
+pub struct SubjectRegistry {
+    entries: Vec<SubjectEntry>,
+}
+
+impl SubjectRegistry {
+    /// Empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Subjects published by this crate
+    pub fn standard() -> Self {
+        let aggregates = [
+            AggregateType::Compute,
+            AggregateType::Network,
+            AggregateType::Interface,
+            AggregateType::IpPool,
+            AggregateType::Connection,
+        ];
+        let events = |name: &str, pattern: String| {
+            SubjectEntry::new(
+                name,
+                pattern,
+                SubjectKind::Event,
+                PayloadType::of::<InfrastructureEvent>("InfrastructureEvent"),
+            )
+            .with_payload(PayloadType::of::<ComputeResourceEvent>(
+                "ComputeResourceEvent",
+            ))
+            .with_values("aggregate", aggregates)
+            .with_parameter("aggregate", "Aggregate type token")
+            .with_parameter("aggregateId", "Aggregate UUID")
+            .with_parameter("eventType", "Operation token of the aggregate")
+        };
+
+        let registry = Self::new()
+            .with_entry(
+                events(
+                    "events",
+                    format!(
+                        "{}.{{aggregate}}.{{aggregateId}}.{{eventType}}",
+                        INFRASTRUCTURE_ROOT
+                    ),
+                )
+                .with_description(
+                    "Aggregate events: the stored envelope and the live notification",
+                ),
+            )
+            .with_entry(
+                events(
+                    "tenantEvents",
+                    format!(
+                        "{}.{{orgId}}.{{aggregate}}.{{aggregateId}}.{{eventType}}",
+                        INFRASTRUCTURE_ROOT
+                    ),
+                )
+                .with_description("Aggregate events of one organization on a shared cluster")
+                .with_parameter("orgId", "Organization ID"),
+            )
+            .with_entry(
+                SubjectEntry::new(
+                    "advisories",
+                    advisory_subject("{advisoryType}"),
+                    SubjectKind::Event,
+                    PayloadType::of::<AdvisoryEvent>("AdvisoryEvent"),
+                )
+                .with_description("Findings from background checks")
+                .with_parameter("advisoryType", "Advisory type token"),
+            )
+            .with_entry(
+                SubjectEntry::new(
+                    "policy",
+                    policy_subject("{policyEventType}"),
+                    SubjectKind::Event,
+                    PayloadType::of::<PolicyEvent>("PolicyEvent"),
+                )
+                .with_description("Policy compliance changes")
+                .with_values(
+                    "policyEventType",
+                    ["violation_detected", "compliance_restored"],
+                ),
+            )
+            .with_entry(
+                SubjectEntry::new(
+                    "alerts",
+                    alert_subject("{rule}"),
+                    SubjectKind::Event,
+                    PayloadType::of::<AlertRaised>("AlertRaised"),
+                )
+                .with_description("Alert rules reaching their threshold")
+                .with_parameter("rule", "Name of the alert rule"),
+            )
+            .with_entry(
+                SubjectEntry::new(
+                    "scorecards",
+                    scorecard_subject(Some("{organizationId}")),
+                    SubjectKind::Event,
+                    PayloadType::of::<ScorecardComputed>("ScorecardComputed"),
+                )
+                .with_description("Inventory hygiene per organization")
+                .with_parameter(
+                    "organizationId",
+                    "Organization ID, or `unassigned` for resources without one",
+                ),
+            )
+            .with_entry(
+                SubjectEntry::new(
+                    "progress",
+                    subjects::progress("{operationId}"),
+                    SubjectKind::Transient,
+                    PayloadType::of::<OperationProgress>("OperationProgress"),
+                )
+                .with_description("Progress of long-running operations")
+                .with_parameter("operationId", "Operation UUID shared by one run"),
+            )
+            .with_entry(
+                SubjectEntry::new(
+                    "observations",
+                    subjects::observation("{source}"),
+                    SubjectKind::Transient,
+                    PayloadType::of::<Observation>("Observation"),
+                )
+                .with_description("Observed state from agents and scans")
+                .with_parameter("source", "Reporting agent or scan"),
+            )
+            .with_entry(
+                SubjectEntry::new(
+                    "healthReports",
+                    subjects::health_report(),
+                    SubjectKind::Transient,
+                    PayloadType::of::<HealthReport>("HealthReport"),
+                )
+                .with_description("Component health reports"),
+            )
+            .with_entry(
+                SubjectEntry::new(
+                    "agentFacts",
+                    subjects::agent_facts("{agent}"),
+                    SubjectKind::Request,
+                    PayloadType::empty(),
+                )
+                .with_reply(PayloadType::of::<crate::discovery::facts::HostFacts>(
+                    "HostFacts",
+                ))
+                .with_description("Facts requested from a discovery agent")
+                .with_parameter("agent", "Agent name"),
+            );
+
+        #[cfg(feature = "runtime")]
+        let registry = registry.with_runtime_entries();
+
+        registry
+    }
+
+    /// Subjects whose payloads are defined by the runtime modules
+    #[cfg(feature = "runtime")]
+    fn with_runtime_entries(self) -> Self {
+        use crate::jetstream::StoredEvent;
+        use crate::nats::query::{
+            GetArchivalCandidates, GetComputeResource, GetEnrichedResources, GetScorecard,
+            ListResources, QueryReply, TopologyQuery,
+        };
+        use crate::projection::change_feed::ChangeFeedEntry;
+        use crate::service::{CommandReply, InfrastructureCommand};
+
+        let stored = || PayloadType::of::<StoredEvent<InfrastructureEvent>>("StoredEvent");
+        let query = |name: &str, model: &str, query: &str, request: PayloadType| {
+            SubjectEntry::new(
+                name,
+                subjects::query(model, query),
+                SubjectKind::Request,
+                request,
+            )
+            .with_reply(PayloadType::of::<QueryReply>("QueryReply"))
+            .with_description("Read-model query")
+        };
+
+        self.with_entry(
+            SubjectEntry::new(
+                "correlation",
+                format!("{}.correlation.{{correlationId}}", INFRASTRUCTURE_ROOT),
+                SubjectKind::Event,
+                stored(),
+            )
+            .with_description("Copy of every stored event keyed by correlation ID")
+            .with_parameter("correlationId", "Correlation UUID"),
+        )
+        .with_entry(
+            SubjectEntry::new(
+                "change",
+                format!("{}.change.{{changeKey}}", INFRASTRUCTURE_ROOT),
+                SubjectKind::Event,
+                stored(),
+            )
+            .with_description("Copy of every event written under a change request")
+            .with_parameter("changeKey", "ChangeRef::index_key of the change request"),
+        )
+        .with_entry(
+            SubjectEntry::new(
+                "cid",
+                format!("{}.cid.{{cid}}", INFRASTRUCTURE_ROOT),
+                SubjectKind::Event,
+                stored(),
+            )
+            .with_description("Copy of every content-addressed event keyed by its CID")
+            .with_parameter("cid", "CIDv1 of the event content"),
+        )
+        .with_entry(
+            SubjectEntry::new(
+                "notifications",
+                subjects::notification("{aggregate}", "{eventType}"),
+                SubjectKind::Transient,
+                stored(),
+            )
+            .with_description("Stored events fanned out per type for live subscribers")
+            .with_parameter("aggregate", "Aggregate type token")
+            .with_parameter("eventType", "Operation token of the aggregate"),
+        )
+        .with_entry(
+            SubjectEntry::new(
+                "organizationFeed",
+                subjects::organization_feed("{organizationId}"),
+                SubjectKind::Event,
+                PayloadType::of::<ChangeFeedEntry>("ChangeFeedEntry"),
+            )
+            .with_description("Summarized changes per organization")
+            .with_parameter("organizationId", "Organization ID"),
+        )
+        .with_entry(
+            SubjectEntry::new(
+                "computeCommands",
+                subjects::command(AggregateType::Compute, "{command}"),
+                SubjectKind::Request,
+                PayloadType::of::<InfrastructureCommand>("InfrastructureCommand"),
+            )
+            .with_reply(PayloadType::of::<CommandReply>("CommandReply"))
+            .with_description("Compute resource commands")
+            .with_parameter("command", "Command tag, e.g. `assign_owner`"),
+        )
+        .with_entry(query(
+            "getComputeResource",
+            "compute",
+            "get",
+            PayloadType::of::<GetComputeResource>("GetComputeResource"),
+        ))
+        .with_entry(query(
+            "viewTopology",
+            "topology",
+            "view",
+            PayloadType::of::<TopologyQuery>("TopologyQuery"),
+        ))
+        .with_entry(query(
+            "getScorecard",
+            "scorecard",
+            "get",
+            PayloadType::of::<GetScorecard>("GetScorecard"),
+        ))
+        .with_entry(query(
+            "getEnrichedResources",
+            "compute",
+            "enriched",
+            PayloadType::of::<GetEnrichedResources>("GetEnrichedResources"),
+        ))
+        .with_entry(query(
+            "getArchivalCandidates",
+            "archival",
+            "candidates",
+            PayloadType::of::<GetArchivalCandidates>("GetArchivalCandidates"),
+        ))
+        .with_entry(query(
+            "listResources",
+            "compute",
+            "list",
+            PayloadType::of::<ListResources>("ListResources"),
+        ))
+    }
+
+    /// Register another subject pattern
+    pub fn with_entry(mut self, entry: SubjectEntry) -> Self {
+        self.entries.push(entry);
+        self
+    }
+
+    /// Registered patterns, in registration order
+    pub fn entries(&self) -> &[SubjectEntry] {
+        &self.entries
+    }
+
+    /// Most specific pattern matching `subject`
+    pub fn resolve(&self, subject: &str) -> Option<&SubjectEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.matches(subject))
+            .max_by_key(|entry| entry.specificity())
+    }
+
+    /// Check a publish against the registry
+    pub fn validate(&self, subject: &str, payload: &[u8]) -> Result<&SubjectEntry, RegistryError> {
+        let entry = self
+            .resolve(subject)
+            .ok_or_else(|| RegistryError::Unregistered(subject.to_string()))?;
+
+        let mut reasons = Vec::new();
+        for payload_type in &entry.payloads {
+            match payload_type.check(payload) {
+                Ok(()) => return Ok(entry),
+                Err(reason) => reasons.push(reason),
+            }
+        }
+        Err(RegistryError::InvalidPayload {
+            subject: subject.to_string(),
+            expected: entry
+                .payloads
+                .iter()
+                .map(|payload_type| payload_type.name)
+                .collect::<Vec<_>>()
+                .join(" or "),
+            reason: reasons.join("; "),
+        })
+    }
+
+    /// Markdown table of the subject hierarchy
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::from(
+            "| Subject | Kind | Payload | Reply | Description |\n|---|---|---|---|---|\n",
+        );
+        for entry in &self.entries {
+            out.push_str(&format!(
+                "| `{}` | {} | {} | {} | {} |\n",
+                entry.pattern,
+                entry.kind,
+                entry
+                    .payloads
+                    .iter()
+                    .map(|payload_type| payload_type.name)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                entry.reply.as_ref().map_or("", |reply| reply.name),
+                entry.description
+            ));
+        }
+        out
+    }
+
+    /// AsyncAPI 3.0 document of the subject hierarchy
+    ///
+    /// Messages are named after their payload types; the full document
+    /// with JSON schemas comes from `asyncapi::generate` (feature
+    /// `asyncapi`).
+    pub fn to_asyncapi(&self, title: &str, version: &str) -> Value {
+        let mut channels = Map::new();
+        let mut operations = Map::new();
+        let mut messages = Map::new();
+        let mut message_ref = |payload: &PayloadType| {
+            messages.insert(
+                payload.name.to_string(),
+                json!({ "name": payload.name, "contentType": "application/json" }),
+            );
+            (
+                payload.name.to_string(),
+                json!({ "$ref": format!("#/components/messages/{}", payload.name) }),
+            )
+        };
+
+        for entry in &self.entries {
+            let parameters: Map<String, Value> = entry
+                .parameters
+                .iter()
+                .map(|parameter| {
+                    let mut value = json!({ "description": parameter.description });
+                    if !parameter.values.is_empty() {
+                        value["enum"] = json!(parameter.values);
+                    }
+                    (parameter.name.clone(), value)
+                })
+                .collect();
+            let channel_messages: Map<String, Value> =
+                entry.payloads.iter().map(&mut message_ref).collect();
+            channels.insert(
+                entry.name.clone(),
+                json!({
+                    "address": entry.pattern,
+                    "description": entry.description,
+                    "parameters": parameters,
+                    "messages": channel_messages,
+                }),
+            );
+
+            let channel = json!({ "$ref": format!("#/channels/{}", entry.name) });
+            let mut operation = match entry.kind {
+                SubjectKind::Request => json!({ "action": "send", "channel": channel }),
+                _ => json!({ "action": "receive", "channel": channel }),
+            };
+            if let Some(reply) = &entry.reply {
+                let reply_channel = format!("{}Reply", entry.name);
+                let (name, reference) = message_ref(reply);
+                channels.insert(
+                    reply_channel.clone(),
+                    json!({
+                        "address": null,
+                        "description": "Requester's reply inbox",
+                        "messages": { name: reference },
+                    }),
+                );
+                operation["reply"] =
+                    json!({ "channel": { "$ref": format!("#/channels/{}", reply_channel) } });
+            }
+            operations.insert(entry.name.clone(), operation);
+        }
+
+        json!({
+            "asyncapi": "3.0.0",
+            "info": { "title": title, "version": version },
+            "defaultContentType": "application/json",
+            "channels": channels,
+            "operations": operations,
+            "components": { "messages": messages },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::progress::ProgressTracker;
+
+    #[test]
+    fn test_standard_subjects_resolve_to_the_most_specific_pattern() {
+        // Arrange
+        let registry = SubjectRegistry::standard();
+
+        // Act
+        let resolved = |subject: &str| registry.resolve(subject).map(|entry| entry.name.as_str());
+
+        // Assert
+        assert_eq!(
+            resolved("infrastructure.compute.0193abcd.registered"),
+            Some("events")
+        );
+        assert_eq!(
+            resolved("infrastructure.acme.network.0193abcd.defined"),
+            Some("tenantEvents")
+        );
+        assert_eq!(
+            resolved(&policy_subject("violation_detected")),
+            Some("policy")
+        );
+        assert_eq!(resolved(&subjects::progress("0193abcd")), Some("progress"));
+        assert_eq!(resolved(&subjects::health_report()), Some("healthReports"));
+        assert_eq!(
+            resolved(&subjects::agent_facts("web-01")),
+            Some("agentFacts")
+        );
+        assert_eq!(resolved("infrastructure.policy.set"), None);
+        assert_eq!(resolved("infrastructure.software.0193abcd.deployed"), None);
+        assert_eq!(
+            resolved("infrastructure.compute.0193abcd.registered.extra"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_validate_checks_payloads() {
+        // Arrange
+        let registry = SubjectRegistry::standard();
+        let report = ProgressTracker::new(uuid::Uuid::nil(), "bulk_import", chrono::Utc::now())
+            .running(chrono::Utc::now());
+        let payload = serde_json::to_vec(&report).unwrap();
+
+        // Act
+        let valid = registry.validate(&report.subject(), &payload);
+        let wrong = registry.validate(&report.subject(), br#"{"hostname":"web-01"}"#);
+        let unregistered = registry.validate("metrics.cpu", &payload);
+
+        // Assert
+        assert_eq!(valid.unwrap().name, "progress");
+        assert!(matches!(
+            wrong,
+            Err(RegistryError::InvalidPayload { expected, .. }) if expected == "OperationProgress"
+        ));
+        assert_eq!(
+            unregistered.unwrap_err(),
+            RegistryError::Unregistered("metrics.cpu".to_string())
+        );
+
+        let document = registry.to_asyncapi("Infrastructure", "1.0.0");
+        assert_eq!(
+            document["channels"]["progress"]["address"],
+            "infrastructure.progress.{operationId}"
+        );
+        assert!(registry
+            .to_markdown()
+            .contains("| `infrastructure.health.report` | transient | HealthReport |"));
+    }
+}
@@ -0,0 +1,206 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Organization Tenancy
+//!
+//! Resources assigned to an organization (see
+//! [`ComputeResource::is_multi_tenant`](crate::domain::ComputeResource::is_multi_tenant))
+//! belong to that tenant. Commands carry the organization they act for as
+//! the `tenant` of the request's `EventEnvelopeMetadata`, and the service
+//! checks every transition against it:
+//!
+//! | Command acting for `acme`                  | Outcome                                   |
+//! |--------------------------------------------|-------------------------------------------|
+//! | against a resource of `acme`               | allowed                                   |
+//! | against a resource without an organization | allowed                                   |
+//! | against a resource of `globex`             | rejected                                  |
+//! | assigning a resource to `globex`           | rejected                                  |
+//! | with no acting organization                | allowed, unless [`TenancyConfig::strict`] |
+//!
+//! Reads are scoped the same way: resources of other organizations are
+//! not found, and listings only show the acting organization's resources.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::tenancy::TenancyConfig;
+//!
+//! let service = EventSourcedComputeResourceService::new(event_store, nats_client)
+//!     .with_tenancy(TenancyConfig::strict());
+//!
+//! let request = EventEnvelopeMetadata::new().with_tenant(org_id.to_string());
+//! request.scope(service.change_status(aggregate_id, command)).await?;
+//! ```
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::aggregate::ComputeResourceState;
+
+/// Tenancy settings
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TenancyConfig {
+    /// Reject commands and reads that name no acting organization
+    #[serde(default)]
+    pub require_acting_organization: bool,
+}
+
+/// Command or read rejected by tenancy
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TenancyError {
+    /// No acting organization was given
+    #[error("An acting organization is required")]
+    OrganizationRequired,
+
+    /// The resource belongs to another organization
+    #[error("Resource {aggregate_id} belongs to organization {owner}, not {acting}")]
+    ForeignResource {
+        aggregate_id: Uuid,
+        owner: String,
+        acting: String,
+    },
+
+    /// A listing asked for another organization's resources
+    #[error("Organization {acting} cannot list resources of organization {requested}")]
+    ForeignListing { requested: String, acting: String },
+
+    /// The command would hand the resource to another organization
+    #[error("Organization {acting} cannot assign resource {aggregate_id} to organization {to}")]
+    ForeignAssignment {
+        aggregate_id: Uuid,
+        to: String,
+        acting: String,
+    },
+}
+
+impl TenancyConfig {
+    /// Every command and read must name an acting organization
+    pub fn strict() -> Self {
+        Self {
+            require_acting_organization: true,
+        }
+    }
+
+    /// Check that `acting` may see `state`
+    pub fn check_read(
+        &self,
+        acting: Option<&str>,
+        state: &ComputeResourceState,
+    ) -> Result<(), TenancyError> {
+        let Some(acting) = self.acting(acting)? else {
+            return Ok(());
+        };
+        match organization_of(state) {
+            Some(owner) if owner != acting => Err(TenancyError::ForeignResource {
+                aggregate_id: state.id,
+                owner,
+                acting: acting.to_string(),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Check the transition of a resource from `before` to `after`
+    pub fn check(
+        &self,
+        acting: Option<&str>,
+        before: &ComputeResourceState,
+        after: &ComputeResourceState,
+    ) -> Result<(), TenancyError> {
+        self.check_read(acting, before)?;
+        let Some(acting) = self.acting(acting)? else {
+            return Ok(());
+        };
+        match organization_of(after) {
+            Some(to) if to != acting => Err(TenancyError::ForeignAssignment {
+                aggregate_id: after.id,
+                to,
+                acting: acting.to_string(),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Organization a listing is limited to
+    ///
+    /// `requested` is the organization a caller filtered on; it must be the
+    /// acting one, if there is one.
+    pub fn scope_listing(
+        &self,
+        acting: Option<&str>,
+        requested: Option<&str>,
+    ) -> Result<Option<String>, TenancyError> {
+        match (self.acting(acting)?, requested) {
+            (Some(acting), Some(requested)) if requested != acting => {
+                Err(TenancyError::ForeignListing {
+                    requested: requested.to_string(),
+                    acting: acting.to_string(),
+                })
+            }
+            (Some(acting), _) => Ok(Some(acting.to_string())),
+            (None, requested) => Ok(requested.map(str::to_string)),
+        }
+    }
+
+    fn acting<'a>(&self, acting: Option<&'a str>) -> Result<Option<&'a str>, TenancyError> {
+        match acting {
+            None if self.require_acting_organization => Err(TenancyError::OrganizationRequired),
+            acting => Ok(acting),
+        }
+    }
+}
+
+/// Organization a resource belongs to, as text
+fn organization_of(state: &ComputeResourceState) -> Option<String> {
+    state.organization_id.as_ref().map(ToString::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cim_domain::EntityId;
+
+    #[test]
+    fn test_commands_are_limited_to_the_acting_organization() {
+        // Arrange
+        let config = TenancyConfig::default();
+        let unassigned = ComputeResourceState::default_for(Uuid::now_v7());
+        let acme = ComputeResourceState {
+            organization_id: Some(EntityId::new()),
+            ..unassigned.clone()
+        };
+        let globex = ComputeResourceState {
+            organization_id: Some(EntityId::new()),
+            ..unassigned.clone()
+        };
+        let acting = organization_of(&acme).unwrap();
+
+        // Act / Assert
+        assert!(config.check(Some(&acting), &acme, &acme).is_ok());
+        assert!(config.check(Some(&acting), &unassigned, &acme).is_ok());
+        assert!(config.check(None, &globex, &globex).is_ok());
+        assert!(matches!(
+            config.check(Some(&acting), &globex, &globex),
+            Err(TenancyError::ForeignResource { .. })
+        ));
+        assert!(matches!(
+            config.check(Some(&acting), &unassigned, &globex),
+            Err(TenancyError::ForeignAssignment { .. })
+        ));
+    }
+
+    #[test]
+    fn test_strict_tenancy_scopes_listings_and_requires_an_organization() {
+        // Arrange
+        let config = TenancyConfig::strict();
+
+        // Act / Assert
+        assert_eq!(
+            config.scope_listing(Some("acme"), None),
+            Ok(Some("acme".to_string()))
+        );
+        assert!(config.scope_listing(Some("acme"), Some("globex")).is_err());
+        assert_eq!(
+            config.scope_listing(None, Some("acme")),
+            Err(TenancyError::OrganizationRequired)
+        );
+    }
+}
@@ -0,0 +1,224 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Command and Event Fixtures
+//!
+//! [`Fixtures`] builds ComputeResource commands and the events they are
+//! expected to produce for one aggregate, with a fixed correlation ID and
+//! clock. Event IDs are fresh (handlers mint them), which is why
+//! [`then_events`](crate::test_harness::ScenarioOutcome::then_events)
+//! ignores them.
+//!
+//! Projections consume stored events; [`stored`] envelopes an event as the
+//! event store would, and [`resource_history`] is a short history to
+//! project.
+
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+use crate::aggregate::{
+    AssignAssetTagCommand, ChangeStatusCommand, ComputeResourceCommand, RegisterResourceCommand,
+};
+use crate::domain::{Hostname, ResourceType};
+use crate::events::{
+    AssetTagAssigned, ComputeResourceEvent, InfrastructureEvent, MetadataUpdated,
+    ResourceRegistered, ResourceStatus, StatusChanged,
+};
+use crate::jetstream::StoredEvent;
+
+/// Aggregate used unless [`Fixtures::with_aggregate_id`] says otherwise
+pub const AGGREGATE_ID: Uuid = Uuid::from_u128(0x01934f4a_1000_7000_8000_000000001000);
+
+/// Correlation ID of every fixture
+pub const CORRELATION_ID: Uuid = Uuid::from_u128(0x01934f4a_c001_7000_8000_00000000c001);
+
+/// Builds commands and matching events for one aggregate
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fixtures {
+    /// Aggregate the fixtures belong to
+    pub aggregate_id: Uuid,
+
+    /// Correlation ID of commands and events
+    pub correlation_id: Uuid,
+
+    /// Timestamp of commands and events
+    pub timestamp: DateTime<Utc>,
+}
+
+impl Default for Fixtures {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Fixtures {
+    /// Fixtures for [`AGGREGATE_ID`] at 2026-01-19T12:00:00Z
+    pub fn new() -> Self {
+        Self {
+            aggregate_id: AGGREGATE_ID,
+            correlation_id: CORRELATION_ID,
+            timestamp: test_timestamp(),
+        }
+    }
+
+    /// Fixtures for another aggregate
+    pub fn with_aggregate_id(mut self, aggregate_id: Uuid) -> Self {
+        self.aggregate_id = aggregate_id;
+        self
+    }
+
+    /// Fixtures at another time
+    pub fn with_timestamp(mut self, timestamp: DateTime<Utc>) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    /// Fixtures `duration` later, for sequences of steps
+    pub fn later(&self, duration: Duration) -> Self {
+        self.clone().with_timestamp(self.timestamp + duration)
+    }
+
+    /// Register a physical server
+    pub fn register_resource(&self, hostname: &str) -> ComputeResourceCommand {
+        ComputeResourceCommand::RegisterResource(RegisterResourceCommand {
+            hostname: hostname_of(hostname),
+            resource_type: ResourceType::PhysicalServer,
+            timestamp: self.timestamp,
+            correlation_id: self.correlation_id,
+            change_ref: None,
+        })
+    }
+
+    /// A physical server was registered
+    pub fn resource_registered(&self, hostname: &str) -> ComputeResourceEvent {
+        ComputeResourceEvent::ResourceRegistered(ResourceRegistered {
+            event_version: 1,
+            event_id: Uuid::now_v7(),
+            aggregate_id: self.aggregate_id,
+            timestamp: self.timestamp,
+            correlation_id: self.correlation_id,
+            causation_id: None,
+            hostname: hostname_of(hostname),
+            resource_type: ResourceType::PhysicalServer,
+        })
+    }
+
+    /// Change the lifecycle status
+    pub fn change_status(&self, to_status: ResourceStatus) -> ComputeResourceCommand {
+        ComputeResourceCommand::ChangeStatus(ChangeStatusCommand {
+            to_status,
+            timestamp: self.timestamp,
+            correlation_id: self.correlation_id,
+            causation_id: None,
+            change_ref: None,
+        })
+    }
+
+    /// The lifecycle status changed
+    pub fn status_changed(
+        &self,
+        from_status: ResourceStatus,
+        to_status: ResourceStatus,
+    ) -> ComputeResourceEvent {
+        ComputeResourceEvent::StatusChanged(StatusChanged {
+            event_version: 1,
+            event_id: Uuid::now_v7(),
+            aggregate_id: self.aggregate_id,
+            timestamp: self.timestamp,
+            correlation_id: self.correlation_id,
+            causation_id: None,
+            from_status,
+            to_status,
+        })
+    }
+
+    /// Assign an asset tag
+    pub fn assign_asset_tag(&self, asset_tag: &str) -> ComputeResourceCommand {
+        ComputeResourceCommand::AssignAssetTag(AssignAssetTagCommand {
+            asset_tag: asset_tag.to_string(),
+            timestamp: self.timestamp,
+            correlation_id: self.correlation_id,
+            causation_id: None,
+            change_ref: None,
+        })
+    }
+
+    /// An asset tag was assigned
+    pub fn asset_tag_assigned(&self, asset_tag: &str) -> ComputeResourceEvent {
+        ComputeResourceEvent::AssetTagAssigned(AssetTagAssigned {
+            event_version: 1,
+            event_id: Uuid::now_v7(),
+            aggregate_id: self.aggregate_id,
+            timestamp: self.timestamp,
+            correlation_id: self.correlation_id,
+            causation_id: None,
+            asset_tag: asset_tag.to_string(),
+        })
+    }
+}
+
+/// Fixture hostnames are literals; an invalid one is a bug in the test
+fn hostname_of(hostname: &str) -> Hostname {
+    Hostname::new(hostname).expect("fixture hostname must be valid")
+}
+
+/// Fixed timestamp used by the builders
+pub fn test_timestamp() -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339("2026-01-19T12:00:00Z")
+        .unwrap()
+        .with_timezone(&Utc)
+}
+
+/// Envelope `event` as the event store would, at `sequence` of its aggregate
+pub fn stored(sequence: u64, event: InfrastructureEvent) -> StoredEvent<InfrastructureEvent> {
+    let mut stored = StoredEvent::new(
+        event.event_id(),
+        event.aggregate_id(),
+        sequence,
+        Uuid::now_v7(),
+        Uuid::now_v7(),
+        event.event_type_name().to_string(),
+        event,
+    );
+    stored.timestamp = stored.data.timestamp();
+    stored
+}
+
+/// A physical server was registered
+pub fn registered(aggregate_id: Uuid, hostname: &str) -> InfrastructureEvent {
+    InfrastructureEvent::ComputeResource(ComputeResourceEvent::ResourceRegistered(
+        ResourceRegistered {
+            event_version: 1,
+            event_id: Uuid::now_v7(),
+            aggregate_id,
+            timestamp: test_timestamp(),
+            correlation_id: Uuid::now_v7(),
+            causation_id: None,
+            hostname: hostname_of(hostname),
+            resource_type: ResourceType::PhysicalServer,
+        },
+    ))
+}
+
+/// A metadata entry was set on a resource
+pub fn metadata_updated(aggregate_id: Uuid, key: &str, value: &str) -> InfrastructureEvent {
+    InfrastructureEvent::ComputeResource(ComputeResourceEvent::MetadataUpdated(MetadataUpdated {
+        event_version: 1,
+        event_id: Uuid::now_v7(),
+        aggregate_id,
+        timestamp: test_timestamp(),
+        correlation_id: Uuid::now_v7(),
+        causation_id: None,
+        key: key.to_string(),
+        value: value.to_string(),
+    }))
+}
+
+/// Registration of `hostname` followed by a metadata update, sequenced 1..
+pub fn resource_history(
+    aggregate_id: Uuid,
+    hostname: &str,
+) -> Vec<StoredEvent<InfrastructureEvent>> {
+    vec![
+        stored(1, registered(aggregate_id, hostname)),
+        stored(2, metadata_updated(aggregate_id, "rack", "R12")),
+    ]
+}
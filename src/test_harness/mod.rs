@@ -0,0 +1,60 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Test Harness
+//!
+//! Test fixtures for this crate and downstream tests, enabled by the
+//! `test-harness` feature. The `test-support` feature enables only the
+//! [`strategies`] and [`given_events`] scenarios, which need no runtime:
+//!
+//! | Helper                                      | Purpose                                        |
+//! |---------------------------------------------|------------------------------------------------|
+//! | [`TestNats`]                                | throwaway JetStream-enabled `nats-server`      |
+//! | [`Fixtures`]                                | commands and events with fixed IDs and time    |
+//! | [`stored`], [`resource_history`]            | stored events to feed projections              |
+//! | [`given_events`]                            | given/when/then checks of the command handlers |
+//! | [`assert_idempotent`], [`RecordingAdapter`] | checks of projections and adapters             |
+//! | [`strategies`]                              | proptest strategies for aggregate commands     |
+//!
+//! [`TestNats::start`] honours `NATS_URL` so CI can point tests at a shared
+//! server; otherwise it runs `nats-server` from `PATH` on a free port with
+//! its own storage directory, so tests never depend on lab addresses.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::test_harness::{given_events, Fixtures, TestNats};
+//!
+//! #[test]
+//! fn activating_a_provisioned_resource() {
+//!     let f = Fixtures::new();
+//!     given_events([f.resource_registered("web-01")])
+//!         .when_command(f.change_status(ResourceStatus::Active))
+//!         .then_events([f.status_changed(ResourceStatus::Provisioning, ResourceStatus::Active)]);
+//! }
+//!
+//! #[tokio::test]
+//! async fn events_reach_the_store() -> InfrastructureResult<()> {
+//!     let nats = TestNats::start().await?;
+//!     let store = nats.event_store().await?;
+//!     // ...
+//! }
+//! ```
+
+#[cfg(feature = "runtime")]
+pub mod fixtures;
+#[cfg(feature = "runtime")]
+pub mod nats;
+#[cfg(feature = "runtime")]
+pub mod projection;
+pub mod scenario;
+#[cfg(any(feature = "test-support", test))]
+pub mod strategies;
+
+#[cfg(feature = "runtime")]
+pub use fixtures::{
+    metadata_updated, registered, resource_history, stored, test_timestamp, Fixtures,
+};
+#[cfg(feature = "runtime")]
+pub use nats::TestNats;
+#[cfg(feature = "runtime")]
+pub use projection::{assert_idempotent, project_all, RecordingAdapter};
+pub use scenario::{given_events, Scenario, ScenarioOutcome};
@@ -0,0 +1,168 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Throwaway NATS Server
+//!
+//! ```text
+//! NATS_URL set?  ──yes──> connect to it (shared CI server)
+//!      │
+//!      no
+//!      ▼
+//! nats-server -js -a 127.0.0.1 -p <free port> -sd <temp dir>
+//!      │  killed and its storage removed on drop
+//! ```
+//!
+//! Every [`TestNats`] gets its own server, so tests run in parallel
+//! without seeing each other's streams. With a shared server use
+//! [`JetStreamConfig::for_tenant`](crate::jetstream::JetStreamConfig) or
+//! fresh aggregate IDs to keep tests apart.
+
+use async_nats::Client;
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::process::{Child, Command};
+use tracing::{debug, info};
+use uuid::Uuid;
+
+use crate::errors::{InfrastructureError, InfrastructureResult};
+use crate::event_store::NatsEventStore;
+
+/// How long to wait for a spawned server to accept connections
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A NATS server for one test
+#[derive(Debug)]
+pub struct TestNats {
+    url: String,
+    server: Option<Child>,
+    storage: Option<PathBuf>,
+}
+
+impl TestNats {
+    /// Server from `NATS_URL`, or a fresh `nats-server` from `PATH`
+    pub async fn start() -> InfrastructureResult<Self> {
+        match std::env::var("NATS_URL") {
+            Ok(url) if !url.trim().is_empty() => {
+                info!("Using NATS server from NATS_URL: {}", url);
+                Ok(Self {
+                    url,
+                    server: None,
+                    storage: None,
+                })
+            }
+            _ => Self::spawn("nats-server").await,
+        }
+    }
+
+    /// Run the given `nats-server` binary on a free local port
+    pub async fn spawn(program: impl Into<PathBuf>) -> InfrastructureResult<Self> {
+        let program = program.into();
+        let port = free_port()?;
+        let storage = std::env::temp_dir().join(format!("cim-test-nats-{}", Uuid::now_v7()));
+
+        let server = Command::new(&program)
+            .args(["-js", "-a", "127.0.0.1", "-p", &port.to_string(), "-sd"])
+            .arg(&storage)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| {
+                InfrastructureError::Configuration(format!(
+                    "Failed to start {} (is it on PATH, or NATS_URL set?): {}",
+                    program.display(),
+                    e
+                ))
+            })?;
+
+        let nats = Self {
+            url: format!("nats://127.0.0.1:{}", port),
+            server: Some(server),
+            storage: Some(storage),
+        };
+        nats.wait_until_ready().await?;
+        info!("Started test NATS server at {}", nats.url);
+        Ok(nats)
+    }
+
+    /// Server URL
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Whether this harness owns the server process
+    pub fn is_spawned(&self) -> bool {
+        self.server.is_some()
+    }
+
+    /// Plain client connection
+    pub async fn client(&self) -> InfrastructureResult<Client> {
+        async_nats::connect(&self.url)
+            .await
+            .map_err(|e| InfrastructureError::NatsConnection(e.to_string()))
+    }
+
+    /// Event store on this server, creating the stream
+    pub async fn event_store(&self) -> InfrastructureResult<NatsEventStore> {
+        NatsEventStore::connect(&self.url).await
+    }
+
+    /// Poll until the server accepts connections
+    async fn wait_until_ready(&self) -> InfrastructureResult<()> {
+        let deadline = tokio::time::Instant::now() + STARTUP_TIMEOUT;
+        loop {
+            match async_nats::connect(&self.url).await {
+                Ok(_) => return Ok(()),
+                Err(e) if tokio::time::Instant::now() < deadline => {
+                    debug!("Test NATS server not ready yet: {}", e);
+                    tokio::time::sleep(Duration::from_millis(50)).await;
+                }
+                Err(e) => {
+                    return Err(InfrastructureError::Timeout(format!(
+                        "Test NATS server at {} did not start: {}",
+                        self.url, e
+                    )))
+                }
+            }
+        }
+    }
+}
+
+impl Drop for TestNats {
+    fn drop(&mut self) {
+        if let Some(server) = self.server.as_mut() {
+            let _ = server.start_kill();
+        }
+        if let Some(storage) = self.storage.take() {
+            let _ = std::fs::remove_dir_all(storage);
+        }
+    }
+}
+
+/// A local port nothing is listening on
+fn free_port() -> InfrastructureResult<u16> {
+    TcpListener::bind("127.0.0.1:0")
+        .and_then(|listener| listener.local_addr())
+        .map(|addr| addr.port())
+        .map_err(|e| InfrastructureError::Configuration(format!("No free port: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_missing_binary_is_a_configuration_error() {
+        // Arrange
+        let program = "/nonexistent/nats-server";
+
+        // Act
+        let result = TestNats::spawn(program).await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(InfrastructureError::Configuration(message)) if message.contains("NATS_URL")
+        ));
+    }
+}
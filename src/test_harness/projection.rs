@@ -0,0 +1,123 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Projection Test Helpers
+//!
+//! Helpers for the properties every projection must have, so an
+//! integration's tests read like the ones in this crate:
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::test_harness::{assert_idempotent, resource_history};
+//!
+//! #[tokio::test]
+//! async fn test_replay_is_idempotent() {
+//!     let history = resource_history(uuid::Uuid::now_v7(), "web-01");
+//!     let mut adapter = ZabbixProjection::new(mock_api());
+//!
+//!     assert_idempotent(&mut adapter, history, |a| a.hosts().clone()).await;
+//! }
+//! ```
+
+use async_trait::async_trait;
+use std::fmt::Debug;
+use uuid::Uuid;
+
+use crate::events::InfrastructureEvent;
+use crate::jetstream::StoredEvent;
+use crate::projection::{ProjectionAdapter, ProjectionError};
+
+/// Project `events` in order, stopping at the first error
+pub async fn project_all<A>(adapter: &mut A, events: Vec<A::Event>) -> Result<(), A::Error>
+where
+    A: ProjectionAdapter,
+{
+    for event in events {
+        adapter.project(event).await?;
+    }
+    Ok(())
+}
+
+/// Assert that projecting `events` twice leaves the state `observe` sees
+/// unchanged after the first pass
+pub async fn assert_idempotent<A, T, F>(adapter: &mut A, events: Vec<A::Event>, observe: F)
+where
+    A: ProjectionAdapter,
+    A::Event: Clone,
+    A::Error: Debug,
+    T: PartialEq + Debug,
+    F: Fn(&A) -> T,
+{
+    project_all(adapter, events.clone())
+        .await
+        .expect("first pass failed");
+    let once = observe(adapter);
+
+    project_all(adapter, events).await.expect("replay failed");
+    assert_eq!(
+        observe(adapter),
+        once,
+        "replaying events changed the projection"
+    );
+}
+
+/// Adapter recording the IDs of the events it receives
+#[derive(Debug, Clone)]
+pub struct RecordingAdapter {
+    name: String,
+    events: Vec<Uuid>,
+    failing: bool,
+}
+
+impl RecordingAdapter {
+    /// Recorder registered under `name`
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            events: Vec::new(),
+            failing: false,
+        }
+    }
+
+    /// Reject every event and health check, as an unreachable target would
+    pub fn failing(mut self) -> Self {
+        self.failing = true;
+        self
+    }
+
+    /// IDs of the events projected so far
+    pub fn events(&self) -> &[Uuid] {
+        &self.events
+    }
+}
+
+#[async_trait]
+impl ProjectionAdapter for RecordingAdapter {
+    type Event = StoredEvent<InfrastructureEvent>;
+    type Error = ProjectionError;
+
+    async fn project(&mut self, event: Self::Event) -> Result<(), Self::Error> {
+        if self.failing {
+            return Err(ProjectionError::TargetUnavailable(self.name.clone()));
+        }
+        self.events.push(event.event_id);
+        Ok(())
+    }
+
+    async fn initialize(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    async fn health_check(&self) -> Result<(), Self::Error> {
+        if self.failing {
+            return Err(ProjectionError::TargetUnavailable(self.name.clone()));
+        }
+        Ok(())
+    }
+
+    async fn reset(&mut self) -> Result<(), Self::Error> {
+        self.events.clear();
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
@@ -0,0 +1,172 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Given / When / Then Scenarios
+//!
+//! ```text
+//! given_events([...])      fold history into state
+//!   .when_command(cmd)     run the command handlers
+//!   .then_events([...])    compare emitted events, ignoring event IDs
+//!   .then_error(|e| ...)   or expect a rejection
+//! ```
+//!
+//! Scenarios run the pure ComputeResource handlers, so they need no NATS
+//! server. Failed expectations panic with both sides pretty-printed.
+
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::aggregate::{
+    apply_event, handle_commands, CommandError, ComputeResourceCommand, ComputeResourceState,
+};
+use crate::events::ComputeResourceEvent;
+
+/// Start a scenario from an aggregate's history
+///
+/// The aggregate ID is taken from the first event; with no history, use
+/// [`Scenario::with_aggregate_id`] so registrations get a known ID.
+pub fn given_events(events: impl IntoIterator<Item = ComputeResourceEvent>) -> Scenario {
+    let history: Vec<ComputeResourceEvent> = events.into_iter().collect();
+    let aggregate_id = history
+        .first()
+        .map(|event| event.aggregate_id())
+        .unwrap_or_else(Uuid::now_v7);
+    Scenario {
+        aggregate_id,
+        history,
+    }
+}
+
+/// History of one aggregate, waiting for a command
+#[derive(Debug, Clone)]
+pub struct Scenario {
+    aggregate_id: Uuid,
+    history: Vec<ComputeResourceEvent>,
+}
+
+impl Scenario {
+    /// Run against another aggregate ID
+    pub fn with_aggregate_id(mut self, aggregate_id: Uuid) -> Self {
+        self.aggregate_id = aggregate_id;
+        self
+    }
+
+    /// State after the history
+    pub fn state(&self) -> ComputeResourceState {
+        self.history.iter().fold(
+            ComputeResourceState::default_for(self.aggregate_id),
+            apply_event,
+        )
+    }
+
+    /// Handle one command
+    pub fn when_command(self, command: ComputeResourceCommand) -> ScenarioOutcome {
+        self.when_commands([command])
+    }
+
+    /// Handle commands as one transaction
+    pub fn when_commands(
+        self,
+        commands: impl IntoIterator<Item = ComputeResourceCommand>,
+    ) -> ScenarioOutcome {
+        let result = handle_commands(
+            &self.state(),
+            commands.into_iter().collect(),
+            self.aggregate_id,
+        );
+        ScenarioOutcome {
+            result: result.map(|(events, _)| events),
+        }
+    }
+}
+
+/// What the handlers did with the commands
+#[derive(Debug, Clone)]
+pub struct ScenarioOutcome {
+    result: Result<Vec<ComputeResourceEvent>, CommandError>,
+}
+
+impl ScenarioOutcome {
+    /// Expect exactly these events, in order, ignoring event IDs
+    ///
+    /// Returns the emitted events for further checks.
+    #[track_caller]
+    pub fn then_events(
+        self,
+        expected: impl IntoIterator<Item = ComputeResourceEvent>,
+    ) -> Vec<ComputeResourceEvent> {
+        let events = match self.result {
+            Ok(events) => events,
+            Err(e) => panic!("expected events, command was rejected: {}", e),
+        };
+        let actual: Vec<Value> = events.iter().map(comparable).collect();
+        let expected: Vec<Value> = expected.into_iter().map(|e| comparable(&e)).collect();
+        if actual != expected {
+            panic!(
+                "emitted events differ\nexpected: {}\n  actual: {}",
+                pretty(&expected),
+                pretty(&actual)
+            );
+        }
+        events
+    }
+
+    /// Expect a rejection the predicate accepts
+    ///
+    /// Returns the error for further checks.
+    #[track_caller]
+    pub fn then_error(self, predicate: impl FnOnce(&CommandError) -> bool) -> CommandError {
+        match self.result {
+            Ok(events) => panic!(
+                "expected a rejection, command emitted {}",
+                pretty(&events.iter().map(comparable).collect::<Vec<_>>())
+            ),
+            Err(e) if predicate(&e) => e,
+            Err(e) => panic!("command was rejected with an unexpected error: {:?}", e),
+        }
+    }
+
+    /// Emitted events or the rejection, unchecked
+    pub fn into_result(self) -> Result<Vec<ComputeResourceEvent>, CommandError> {
+        self.result
+    }
+}
+
+/// Event as JSON without its event ID
+fn comparable(event: &ComputeResourceEvent) -> Value {
+    let mut value = serde_json::to_value(event).expect("events serialize");
+    if let Some(fields) = value.as_object_mut() {
+        fields.remove("event_id");
+    }
+    value
+}
+
+fn pretty(events: &[Value]) -> String {
+    serde_json::to_string_pretty(events).unwrap_or_default()
+}
+
+#[cfg(all(test, feature = "runtime"))]
+mod tests {
+    use super::*;
+    use crate::events::ResourceStatus;
+    use crate::test_harness::Fixtures;
+
+    #[test]
+    fn test_given_when_then() {
+        // Arrange
+        let f = Fixtures::new();
+
+        // Act
+        let registered = given_events([])
+            .with_aggregate_id(f.aggregate_id)
+            .when_command(f.register_resource("web-01"))
+            .then_events([f.resource_registered("web-01")]);
+        let activated = given_events(registered)
+            .when_command(f.change_status(ResourceStatus::Active))
+            .then_events([f.status_changed(ResourceStatus::Provisioning, ResourceStatus::Active)]);
+
+        // Assert
+        assert_eq!(activated.len(), 1);
+        given_events([])
+            .when_command(f.assign_asset_tag("A-1001"))
+            .then_error(|e| *e == CommandError::NotInitialized);
+    }
+}
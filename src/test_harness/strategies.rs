@@ -0,0 +1,384 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Proptest Strategies
+//!
+//! Strategies for property tests over the ComputeResource aggregate,
+//! shared with downstream crates through the `test-support` feature (also
+//! enabled by `test-harness`).
+//!
+//! | Strategy             | Generates                                     |
+//! |----------------------|-----------------------------------------------|
+//! | [`hostname`]         | valid DNS hostnames                           |
+//! | [`resource_type`]    | any [`ResourceType`]                          |
+//! | [`resource_status`]  | any [`ResourceStatus`]                        |
+//! | [`timestamp`]        | whole seconds between 2024 and 2030           |
+//! | [`register_command`] | a registration                                |
+//! | [`compute_command`]  | any other command                             |
+//! | [`command_sequence`] | a registration followed by arbitrary commands |
+//!
+//! Generated commands are well-formed but not necessarily accepted: a
+//! `RemovePolicy` may name a policy that was never added, a status change
+//! may be an invalid transition. [`accepted_events`] runs commands through
+//! the handlers and keeps the events of the accepted ones, which is what
+//! the aggregate would have stored.
+//!
+//! Entity IDs without a UUID constructor (policies, owners, ...) are fresh
+//! on every run, so shrunk cases reproduce everything but those IDs.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cim_infrastructure::test_harness::strategies::{accepted_events, command_sequence};
+//!
+//! proptest! {
+//!     #[test]
+//!     fn prop_projection_sees_every_event(commands in command_sequence(30)) {
+//!         let (events, state) = accepted_events(Uuid::now_v7(), commands);
+//!         // ...
+//!     }
+//! }
+//! ```
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use cim_domain::EntityId;
+use cim_domain_location::LocationMarker;
+use cim_domain_person::PersonId;
+use cim_domain_policy::PolicyId;
+use cim_domain_spaces::ConceptId;
+use proptest::prelude::*;
+use proptest::strategy::LazyJust;
+use uuid::Uuid;
+
+use crate::aggregate::{
+    apply_event, handle_command, AddPolicyCommand, AssignAccountConceptCommand,
+    AssignAssetTagCommand, AssignLocationCommand, AssignOrganizationCommand, AssignOwnerCommand,
+    AttachGuestCommand, ChangeStatusCommand, ClearAccountConceptCommand, ComputeResourceCommand,
+    ComputeResourceState, DetachGuestCommand, RecordConfigurationBackupCommand,
+    RegisterResourceCommand, RemovePolicyCommand, SetHardwareDetailsCommand, UpdateMetadataCommand,
+};
+use crate::domain::{Hostname, ResourceType};
+use crate::events::{ComputeResourceEvent, ConfigurationBackupRef, ResourceStatus};
+
+/// Every resource type
+const RESOURCE_TYPES: [ResourceType; 33] = [
+    ResourceType::PhysicalServer,
+    ResourceType::VirtualMachine,
+    ResourceType::ContainerHost,
+    ResourceType::Hypervisor,
+    ResourceType::Router,
+    ResourceType::Switch,
+    ResourceType::Layer3Switch,
+    ResourceType::AccessPoint,
+    ResourceType::LoadBalancer,
+    ResourceType::Firewall,
+    ResourceType::IDS,
+    ResourceType::VPNGateway,
+    ResourceType::WAF,
+    ResourceType::Camera,
+    ResourceType::StorageArray,
+    ResourceType::NAS,
+    ResourceType::SANSwitch,
+    ResourceType::Appliance,
+    ResourceType::BackupAppliance,
+    ResourceType::MonitoringAppliance,
+    ResourceType::AuthServer,
+    ResourceType::KVM,
+    ResourceType::Monitor,
+    ResourceType::EdgeDevice,
+    ResourceType::IoTGateway,
+    ResourceType::Sensor,
+    ResourceType::PDU,
+    ResourceType::UPS,
+    ResourceType::EnvironmentalMonitor,
+    ResourceType::PBX,
+    ResourceType::VideoConference,
+    ResourceType::Other,
+    ResourceType::Unknown,
+];
+
+/// Policies a sequence adds and removes, so removals can succeed
+const POLICY_POOL: usize = 3;
+
+/// Guests a sequence attaches and detaches, so detaches can succeed
+const GUEST_POOL: [Uuid; 3] = [
+    Uuid::from_u128(0x01934f4a_6000_7000_8000_000000006001),
+    Uuid::from_u128(0x01934f4a_6000_7000_8000_000000006002),
+    Uuid::from_u128(0x01934f4a_6000_7000_8000_000000006003),
+];
+
+/// Any UUID
+pub fn uuid() -> impl Strategy<Value = Uuid> {
+    any::<u128>().prop_map(Uuid::from_u128)
+}
+
+/// Valid DNS hostnames, optionally qualified
+pub fn hostname() -> impl Strategy<Value = Hostname> {
+    "[a-z]([a-z0-9-]{0,14}[a-z0-9])?(\\.example\\.com)?"
+        .prop_filter_map("valid hostname", |name| Hostname::new(name).ok())
+}
+
+/// Any resource type
+pub fn resource_type() -> impl Strategy<Value = ResourceType> {
+    prop::sample::select(RESOURCE_TYPES.to_vec())
+}
+
+/// Any lifecycle status
+pub fn resource_status() -> impl Strategy<Value = ResourceStatus> {
+    prop_oneof![
+        Just(ResourceStatus::Provisioning),
+        Just(ResourceStatus::Active),
+        Just(ResourceStatus::Maintenance),
+        Just(ResourceStatus::Decommissioned),
+    ]
+}
+
+/// Whole-second timestamps between 2024 and 2030
+pub fn timestamp() -> impl Strategy<Value = DateTime<Utc>> {
+    (1_704_067_200i64..1_893_456_000).prop_map(|secs| Utc.timestamp_opt(secs, 0).unwrap())
+}
+
+/// Backup taken up to a day before `timestamp`
+fn configuration_backup(timestamp: DateTime<Utc>) -> impl Strategy<Value = ConfigurationBackupRef> {
+    (
+        "[a-z0-9]{1,8}/[a-z0-9-]{1,16}",
+        "sha256:[0-9a-f]{64}",
+        prop::sample::select(vec!["oxidized", "rancid", "ansible"]),
+        0i64..86_400,
+        prop::option::of(any::<u64>()),
+    )
+        .prop_map(move |(object_key, content_hash, tool, age, size_bytes)| {
+            ConfigurationBackupRef {
+                object_store: "config-backups".to_string(),
+                object_key,
+                content_hash,
+                tool: tool.to_string(),
+                taken_at: timestamp - Duration::seconds(age),
+                size_bytes,
+            }
+        })
+}
+
+/// Registration of a new resource
+pub fn register_command() -> impl Strategy<Value = ComputeResourceCommand> {
+    (hostname(), resource_type(), timestamp(), uuid()).prop_map(
+        |(hostname, resource_type, timestamp, correlation_id)| {
+            ComputeResourceCommand::RegisterResource(RegisterResourceCommand {
+                hostname,
+                resource_type,
+                timestamp,
+                correlation_id,
+                change_ref: None,
+            })
+        },
+    )
+}
+
+/// Any command but registration; policies are picked from `policies`
+pub fn compute_command(policies: Vec<PolicyId>) -> impl Strategy<Value = ComputeResourceCommand> {
+    use ComputeResourceCommand as C;
+
+    let trace = || (timestamp(), uuid(), prop::option::of(uuid()));
+    let policy = prop::sample::select(policies);
+    let text = || prop::option::of("[A-Za-z0-9 -]{1,16}");
+
+    let guest = || prop::sample::select(GUEST_POOL.to_vec());
+
+    // Groups of at most six keep each prop_oneof! within its unboxed arity
+    let ownership = prop_oneof![
+        trace().prop_map(|(timestamp, correlation_id, causation_id)| {
+            C::AssignOrganization(AssignOrganizationCommand {
+                organization_id: EntityId::new(),
+                timestamp,
+                correlation_id,
+                causation_id,
+                change_ref: None,
+            })
+        }),
+        trace().prop_map(|(timestamp, correlation_id, causation_id)| {
+            C::AssignLocation(AssignLocationCommand {
+                location_id: EntityId::<LocationMarker>::new(),
+                timestamp,
+                correlation_id,
+                causation_id,
+                change_ref: None,
+            })
+        }),
+        (trace(), LazyJust::new(PersonId::new)).prop_map(
+            |((timestamp, correlation_id, causation_id), owner_id)| {
+                C::AssignOwner(AssignOwnerCommand {
+                    owner_id,
+                    timestamp,
+                    correlation_id,
+                    causation_id,
+                    change_ref: None,
+                })
+            }
+        ),
+        (trace(), policy.clone()).prop_map(
+            |((timestamp, correlation_id, causation_id), policy_id)| {
+                C::AddPolicy(AddPolicyCommand {
+                    policy_id,
+                    timestamp,
+                    correlation_id,
+                    causation_id,
+                    change_ref: None,
+                })
+            }
+        ),
+        (trace(), policy).prop_map(|((timestamp, correlation_id, causation_id), policy_id)| {
+            C::RemovePolicy(RemovePolicyCommand {
+                policy_id,
+                timestamp,
+                correlation_id,
+                causation_id,
+                change_ref: None,
+            })
+        }),
+        (trace(), LazyJust::new(ConceptId::new)).prop_map(
+            |((timestamp, correlation_id, causation_id), concept_id)| {
+                C::AssignAccountConcept(AssignAccountConceptCommand {
+                    concept_id,
+                    timestamp,
+                    correlation_id,
+                    causation_id,
+                    change_ref: None,
+                })
+            }
+        ),
+    ];
+    let details = prop_oneof![
+        trace().prop_map(|(timestamp, correlation_id, causation_id)| {
+            C::ClearAccountConcept(ClearAccountConceptCommand {
+                timestamp,
+                correlation_id,
+                causation_id,
+                change_ref: None,
+            })
+        }),
+        (trace(), text(), text(), text()).prop_map(
+            |((timestamp, correlation_id, causation_id), manufacturer, model, serial_number)| {
+                C::SetHardwareDetails(SetHardwareDetailsCommand {
+                    manufacturer,
+                    model,
+                    serial_number,
+                    timestamp,
+                    correlation_id,
+                    causation_id,
+                    change_ref: None,
+                })
+            }
+        ),
+        (trace(), "A-[0-9]{4}").prop_map(
+            |((timestamp, correlation_id, causation_id), asset_tag)| {
+                C::AssignAssetTag(AssignAssetTagCommand {
+                    asset_tag,
+                    timestamp,
+                    correlation_id,
+                    causation_id,
+                    change_ref: None,
+                })
+            }
+        ),
+        (trace(), "[a-z_]{1,12}", "[A-Za-z0-9 .-]{0,24}").prop_map(
+            |((timestamp, correlation_id, causation_id), key, value)| {
+                C::UpdateMetadata(UpdateMetadataCommand {
+                    key,
+                    value,
+                    timestamp,
+                    correlation_id,
+                    causation_id,
+                    change_ref: None,
+                })
+            }
+        ),
+        (trace(), resource_status()).prop_map(
+            |((timestamp, correlation_id, causation_id), to_status)| {
+                C::ChangeStatus(ChangeStatusCommand {
+                    to_status,
+                    timestamp,
+                    correlation_id,
+                    causation_id,
+                    change_ref: None,
+                })
+            }
+        ),
+        trace()
+            .prop_flat_map(|trace| (Just(trace), configuration_backup(trace.0)))
+            .prop_map(|((timestamp, correlation_id, causation_id), backup)| {
+                C::RecordConfigurationBackup(RecordConfigurationBackupCommand {
+                    backup,
+                    timestamp,
+                    correlation_id,
+                    causation_id,
+                    change_ref: None,
+                })
+            }),
+    ];
+
+    let hosting = prop_oneof![
+        (
+            trace(),
+            guest(),
+            prop::sample::select(vec![
+                ResourceType::VirtualMachine,
+                ResourceType::ContainerHost
+            ]),
+        )
+            .prop_map(
+                |((timestamp, correlation_id, causation_id), guest_id, guest_type)| {
+                    C::AttachGuest(AttachGuestCommand {
+                        guest_id,
+                        guest_type,
+                        host_ancestors: Vec::new(),
+                        timestamp,
+                        correlation_id,
+                        causation_id,
+                        change_ref: None,
+                    })
+                }
+            ),
+        (trace(), guest()).prop_map(|((timestamp, correlation_id, causation_id), guest_id)| {
+            C::DetachGuest(DetachGuestCommand {
+                guest_id,
+                timestamp,
+                correlation_id,
+                causation_id,
+                change_ref: None,
+            })
+        }),
+    ];
+
+    prop_oneof![6 => ownership, 6 => details, 2 => hosting]
+}
+
+/// A registration followed by up to `max_len` other commands
+pub fn command_sequence(max_len: usize) -> impl Strategy<Value = Vec<ComputeResourceCommand>> {
+    prop::collection::vec(LazyJust::new(PolicyId::new), POLICY_POOL)
+        .prop_flat_map(move |policies| {
+            (
+                register_command(),
+                prop::collection::vec(compute_command(policies), 0..=max_len),
+            )
+        })
+        .prop_map(|(register, rest)| std::iter::once(register).chain(rest).collect())
+}
+
+/// Events of the accepted commands, and the state after them
+///
+/// Each command is handled against the state left by the accepted commands
+/// before it; rejected commands are skipped.
+pub fn accepted_events(
+    aggregate_id: Uuid,
+    commands: impl IntoIterator<Item = ComputeResourceCommand>,
+) -> (Vec<ComputeResourceEvent>, ComputeResourceState) {
+    let mut state = ComputeResourceState::default_for(aggregate_id);
+    let mut events = Vec::new();
+
+    for command in commands {
+        if let Ok(event) = handle_command(&state, command, aggregate_id) {
+            state = apply_event(state, &event);
+            events.push(event);
+        }
+    }
+
+    (events, state)
+}
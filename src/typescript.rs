@@ -0,0 +1,149 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! TypeScript Definitions
+//!
+//! Exports `.ts` declarations for the JSON shapes web UIs exchange with the
+//! system: events, commands, command/query replies and read-model DTOs.
+//! The declarations are derived from the Rust types (including their serde
+//! tags and renames), so regenerating them after a schema change is enough
+//! to surface breakage in the front end at compile time.
+//!
+//! Identifiers from the `cim-domain-*` crates are declared as `string`;
+//! 64/128-bit counters as `number`, matching what `JSON.parse` produces.
+//!
+//! # Generating
+//!
+//! ```text
+//! cargo run --bin export-ts --features typescript -- web/src/generated
+//! ```
+//!
+//! One file is written per type; referenced types are exported alongside.
+
+use std::path::Path;
+use ts_rs::{ExportError, TS};
+
+use crate::aggregate::connection::{ConnectionCommand, ConnectionState};
+use crate::aggregate::ip_pool::{IpPoolCommand, IpPoolState};
+use crate::aggregate::network::{NetworkCommand, NetworkState};
+use crate::aggregate::network_interface::{
+    AttachInterfaceCommand, DissolveBondCommand, FormBondCommand, NetworkInterfaceState,
+};
+use crate::aggregate::{ComputeResourceCommand, ComputeResourceState};
+use crate::alert::AlertRule;
+use crate::events::{
+    AdvisoryEvent, AlertRaised, InfrastructureEvent, OperationProgress, PolicyEvent,
+    ScorecardComputed,
+};
+use crate::policy::PolicyRule;
+use crate::reconcile::Observation;
+
+/// Write declarations for every exported type into `out_dir`
+pub fn export_all(out_dir: impl AsRef<Path>) -> Result<(), ExportError> {
+    let out_dir = out_dir.as_ref();
+
+    // Events
+    InfrastructureEvent::export_all_to(out_dir)?;
+    AdvisoryEvent::export_all_to(out_dir)?;
+    PolicyEvent::export_all_to(out_dir)?;
+    OperationProgress::export_all_to(out_dir)?;
+    ScorecardComputed::export_all_to(out_dir)?;
+    AlertRaised::export_all_to(out_dir)?;
+
+    // Commands
+    ComputeResourceCommand::export_all_to(out_dir)?;
+    NetworkCommand::export_all_to(out_dir)?;
+    AttachInterfaceCommand::export_all_to(out_dir)?;
+    FormBondCommand::export_all_to(out_dir)?;
+    DissolveBondCommand::export_all_to(out_dir)?;
+    ConnectionCommand::export_all_to(out_dir)?;
+    IpPoolCommand::export_all_to(out_dir)?;
+
+    // Aggregate state
+    ComputeResourceState::export_all_to(out_dir)?;
+    NetworkState::export_all_to(out_dir)?;
+    NetworkInterfaceState::export_all_to(out_dir)?;
+    ConnectionState::export_all_to(out_dir)?;
+    IpPoolState::export_all_to(out_dir)?;
+
+    // Policies
+    PolicyRule::export_all_to(out_dir)?;
+    AlertRule::export_all_to(out_dir)?;
+
+    // Drift detection
+    Observation::export_all_to(out_dir)?;
+
+    #[cfg(feature = "runtime")]
+    export_runtime(out_dir)?;
+
+    Ok(())
+}
+
+/// Command bus and query DTOs (only built with the runtime)
+#[cfg(feature = "runtime")]
+fn export_runtime(out_dir: &Path) -> Result<(), ExportError> {
+    use crate::archival::StaleAggregate;
+    use crate::enrichment::EnrichedResource;
+    use crate::nats::query::{
+        GetArchivalCandidates, GetComputeResource, GetEnrichedResources, GetScorecard,
+        ListResources, QueryReply, TopologyQuery, TopologyView,
+    };
+    use crate::projection::ip_pool::PoolAvailability;
+    use crate::projection::registry::ResourcePage;
+    use crate::projection::timeline::ResourceTimeline;
+    use crate::service::manifest::JournalEntry;
+    use crate::service::{CommandReply, InfrastructureCommand, Manifest};
+
+    InfrastructureCommand::export_all_to(out_dir)?;
+    CommandReply::export_all_to(out_dir)?;
+    Manifest::export_all_to(out_dir)?;
+    JournalEntry::export_all_to(out_dir)?;
+    GetComputeResource::export_all_to(out_dir)?;
+    TopologyQuery::export_all_to(out_dir)?;
+    TopologyView::export_all_to(out_dir)?;
+    GetScorecard::export_all_to(out_dir)?;
+    GetEnrichedResources::export_all_to(out_dir)?;
+    EnrichedResource::export_all_to(out_dir)?;
+    GetArchivalCandidates::export_all_to(out_dir)?;
+    StaleAggregate::export_all_to(out_dir)?;
+    ListResources::export_all_to(out_dir)?;
+    ResourcePage::export_all_to(out_dir)?;
+    QueryReply::export_all_to(out_dir)?;
+    ResourceTimeline::export_all_to(out_dir)?;
+    PoolAvailability::export_all_to(out_dir)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::ComputeResourceEvent;
+
+    #[test]
+    fn test_declarations_follow_serde_tags() {
+        let infrastructure = InfrastructureEvent::decl();
+        let compute = ComputeResourceEvent::decl();
+
+        assert!(infrastructure.contains("aggregate_type"));
+        assert!(infrastructure.contains("compute_resource"));
+        assert!(compute.contains("resource_registered"));
+    }
+
+    #[test]
+    fn test_export_writes_referenced_types() {
+        // Arrange
+        let out_dir = std::env::temp_dir().join(format!("cim-ts-{}", uuid::Uuid::now_v7()));
+
+        // Act
+        export_all(&out_dir).unwrap();
+
+        // Assert
+        for file in [
+            "InfrastructureEvent.ts",
+            "ResourceRegistered.ts",
+            "Hostname.ts",
+        ] {
+            assert!(out_dir.join(file).exists(), "{} not exported", file);
+        }
+        std::fs::remove_dir_all(&out_dir).unwrap();
+    }
+}
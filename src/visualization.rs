@@ -0,0 +1,299 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Live Topology for Bevy
+//!
+//! Mirrors the read model's connectivity view into a Bevy ECS resource, so
+//! graphical front-ends render the live infrastructure graph without glue
+//! code of their own:
+//!
+//! ```text
+//! StoredEvent ──> InMemoryReadModel ──publish()──> ReadModelSnapshot
+//!                                                        │ TopologySource (polled every frame)
+//!                                                        ▼
+//!                          Res<TopologyGraph> + Events<TopologyChanged> ──> render systems
+//! ```
+//!
+//! The graph is only rebuilt when the read model publishes a new snapshot,
+//! and systems are told what changed:
+//!
+//! | Event                               | Sent when                             |
+//! |-------------------------------------|---------------------------------------|
+//! | [`TopologyChanged::NodeAdded`]      | a resource appears                    |
+//! | [`TopologyChanged::NodeUpdated`]    | a node's kind or label changes        |
+//! | [`TopologyChanged::NodeRemoved`]    | a resource is gone from the view      |
+//! | [`TopologyChanged::EdgeAdded`]      | a cable goes live                     |
+//! | [`TopologyChanged::EdgeRemoved`]    | a cable is removed                    |
+//!
+//! Node events come before edge events, so a system spawning entities per
+//! node finds both ends of every new edge. Views from elsewhere (a NATS
+//! topology query, a file) can be fed with [`TopologyGraph::apply`].
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use bevy::prelude::*;
+//! use cim_infrastructure::visualization::{TopologyChanged, TopologyGraph, TopologyPlugin};
+//!
+//! App::new()
+//!     .add_plugins(DefaultPlugins)
+//!     .add_plugins(TopologyPlugin::new(read_model.handle()))
+//!     .add_systems(Update, spawn_nodes)
+//!     .run();
+//!
+//! fn spawn_nodes(mut changes: EventReader<TopologyChanged>, mut commands: Commands) {
+//!     for change in changes.read() {
+//!         if let TopologyChanged::NodeAdded(node) = change {
+//!             commands.spawn((Name::new(node.label.clone()), SpatialBundle::default()));
+//!         }
+//!     }
+//! }
+//! ```
+
+use bevy_app::{App, Plugin, Update};
+use bevy_ecs::prelude::{Event, EventWriter, ResMut, Resource};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use crate::nats::query::{TopologyEdge, TopologyNode, TopologyView};
+use crate::projection::read_model::{ReadModelHandle, ReadModelSnapshot};
+
+/// A change of the live topology
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub enum TopologyChanged {
+    /// A node appeared
+    NodeAdded(TopologyNode),
+
+    /// A node's kind or label changed
+    NodeUpdated(TopologyNode),
+
+    /// The node with this ID is gone
+    NodeRemoved(String),
+
+    /// An edge appeared
+    EdgeAdded(TopologyEdge),
+
+    /// An edge is gone
+    EdgeRemoved(TopologyEdge),
+}
+
+/// Current nodes and edges of the topology
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct TopologyGraph {
+    nodes: HashMap<String, TopologyNode>,
+    edges: HashSet<TopologyEdge>,
+}
+
+impl TopologyGraph {
+    /// Node by ID
+    pub fn node(&self, id: &str) -> Option<&TopologyNode> {
+        self.nodes.get(id)
+    }
+
+    /// Every node, in no particular order
+    pub fn nodes(&self) -> impl Iterator<Item = &TopologyNode> {
+        self.nodes.values()
+    }
+
+    /// Every edge, in no particular order
+    pub fn edges(&self) -> impl Iterator<Item = &TopologyEdge> {
+        self.edges.iter()
+    }
+
+    /// Number of nodes
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Whether there are no nodes
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Replace the graph with `view`, returning what changed
+    ///
+    /// Additions and updates follow the view's order; removals are sorted.
+    pub fn apply(&mut self, view: &TopologyView) -> Vec<TopologyChanged> {
+        let mut changes = Vec::new();
+
+        let mut nodes = HashMap::with_capacity(view.nodes.len());
+        for node in &view.nodes {
+            match self.nodes.remove(&node.id) {
+                None => changes.push(TopologyChanged::NodeAdded(node.clone())),
+                Some(previous) if previous != *node => {
+                    changes.push(TopologyChanged::NodeUpdated(node.clone()))
+                }
+                Some(_) => {}
+            }
+            nodes.insert(node.id.clone(), node.clone());
+        }
+        let mut removed: Vec<String> = self.nodes.drain().map(|(id, _)| id).collect();
+        removed.sort();
+        changes.extend(removed.into_iter().map(TopologyChanged::NodeRemoved));
+
+        let mut edges = HashSet::with_capacity(view.edges.len());
+        for edge in &view.edges {
+            if !self.edges.remove(edge) && !edges.contains(edge) {
+                changes.push(TopologyChanged::EdgeAdded(edge.clone()));
+            }
+            edges.insert(edge.clone());
+        }
+        let mut removed: Vec<TopologyEdge> = self.edges.drain().collect();
+        removed.sort_by(|a, b| {
+            (&a.from, &a.to, &a.relationship).cmp(&(&b.from, &b.to, &b.relationship))
+        });
+        changes.extend(removed.into_iter().map(TopologyChanged::EdgeRemoved));
+
+        self.nodes = nodes;
+        self.edges = edges;
+        changes
+    }
+}
+
+/// Read model the topology is taken from
+#[derive(Resource, Clone)]
+pub struct TopologySource {
+    read_model: ReadModelHandle,
+    seen: Option<Arc<ReadModelSnapshot>>,
+}
+
+impl TopologySource {
+    /// Follow `read_model`
+    pub fn new(read_model: ReadModelHandle) -> Self {
+        Self {
+            read_model,
+            seen: None,
+        }
+    }
+
+    /// Connectivity view of the latest snapshot, if not seen yet
+    pub fn poll(&mut self) -> Option<TopologyView> {
+        let snapshot = self.read_model.snapshot();
+        if self
+            .seen
+            .as_ref()
+            .is_some_and(|seen| Arc::ptr_eq(seen, &snapshot))
+        {
+            return None;
+        }
+        let view = TopologyView::connectivity(snapshot.resources(), snapshot.connections());
+        self.seen = Some(snapshot);
+        Some(view)
+    }
+}
+
+/// Keeps [`TopologyGraph`] in sync with a read model
+pub struct TopologyPlugin {
+    source: TopologySource,
+}
+
+impl TopologyPlugin {
+    /// Follow `read_model`
+    pub fn new(read_model: ReadModelHandle) -> Self {
+        Self {
+            source: TopologySource::new(read_model),
+        }
+    }
+}
+
+impl Plugin for TopologyPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<TopologyChanged>()
+            .init_resource::<TopologyGraph>()
+            .insert_resource(self.source.clone())
+            .add_systems(Update, sync_topology);
+    }
+}
+
+/// Apply the latest snapshot to [`TopologyGraph`] and announce the changes
+pub fn sync_topology(
+    mut source: ResMut<TopologySource>,
+    mut graph: ResMut<TopologyGraph>,
+    mut changes: EventWriter<TopologyChanged>,
+) {
+    if let Some(view) = source.poll() {
+        changes.send_batch(graph.apply(&view));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nats::query::{TopologyNodeKind, CABLED_TO};
+
+    fn node(id: &str, label: &str) -> TopologyNode {
+        TopologyNode {
+            id: id.to_string(),
+            kind: TopologyNodeKind::ComputeResource,
+            label: label.to_string(),
+        }
+    }
+
+    fn cable(from: &str, to: &str) -> TopologyEdge {
+        TopologyEdge {
+            from: from.to_string(),
+            to: to.to_string(),
+            relationship: CABLED_TO.to_string(),
+        }
+    }
+
+    fn view(nodes: Vec<TopologyNode>, edges: Vec<TopologyEdge>) -> TopologyView {
+        TopologyView {
+            root: String::new(),
+            nodes,
+            edges,
+        }
+    }
+
+    #[test]
+    fn test_first_view_adds_nodes_before_edges() {
+        // Arrange
+        let mut graph = TopologyGraph::default();
+        let first = view(
+            vec![node("a", "web-01"), node("b", "sw-01")],
+            vec![cable("a", "b")],
+        );
+
+        // Act
+        let changes = graph.apply(&first);
+
+        // Assert
+        assert_eq!(
+            changes,
+            [
+                TopologyChanged::NodeAdded(node("a", "web-01")),
+                TopologyChanged::NodeAdded(node("b", "sw-01")),
+                TopologyChanged::EdgeAdded(cable("a", "b")),
+            ]
+        );
+        assert_eq!(graph.len(), 2);
+        assert!(graph.apply(&first).is_empty());
+    }
+
+    #[test]
+    fn test_later_views_report_updates_and_removals() {
+        // Arrange
+        let mut graph = TopologyGraph::default();
+        graph.apply(&view(
+            vec![node("a", "web-01"), node("b", "sw-01"), node("c", "db-01")],
+            vec![cable("a", "b"), cable("c", "b")],
+        ));
+        let next = view(
+            vec![node("a", "web-01"), node("b", "sw-01a")],
+            vec![cable("a", "b")],
+        );
+
+        // Act
+        let changes = graph.apply(&next);
+
+        // Assert
+        assert_eq!(
+            changes,
+            [
+                TopologyChanged::NodeUpdated(node("b", "sw-01a")),
+                TopologyChanged::NodeRemoved("c".to_string()),
+                TopologyChanged::EdgeRemoved(cable("c", "b")),
+            ]
+        );
+        assert_eq!(graph.node("b").map(|b| b.label.as_str()), Some("sw-01a"));
+        assert_eq!(graph.edges().count(), 1);
+    }
+}
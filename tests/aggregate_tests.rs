@@ -46,6 +46,7 @@ fn test_complete_resource_lifecycle() {
         resource_type: ResourceType::PhysicalServer,
         timestamp: test_timestamp(),
         correlation_id: Uuid::now_v7(),
+        change_ref: None,
     };
 
     let register_event = handle_register_resource(&state, register_cmd, aggregate_id)
@@ -66,6 +67,7 @@ fn test_complete_resource_lifecycle() {
         timestamp: test_timestamp(),
         correlation_id: Uuid::now_v7(),
         causation_id: Some(register_event_id),
+        change_ref: None,
     };
 
     let org_event = handle_assign_organization(&state, org_cmd)
@@ -84,6 +86,7 @@ fn test_complete_resource_lifecycle() {
         timestamp: test_timestamp(),
         correlation_id: Uuid::now_v7(),
         causation_id: Some(org_event_id),
+        change_ref: None,
     };
 
     let policy1_event = handle_add_policy(&state, policy1_cmd)
@@ -102,6 +105,7 @@ fn test_complete_resource_lifecycle() {
         timestamp: test_timestamp(),
         correlation_id: Uuid::now_v7(),
         causation_id: Some(policy1_event_id),
+        change_ref: None,
     };
 
     let status_event = handle_change_status(&state, status_cmd)
@@ -136,6 +140,7 @@ fn test_command_validation() {
         timestamp: test_timestamp(),
         correlation_id: Uuid::now_v7(),
         causation_id: None,
+        change_ref: None,
     };
 
     let result = handle_assign_organization(&state, org_cmd);
@@ -155,6 +160,7 @@ fn test_cannot_register_twice() {
         resource_type: ResourceType::PhysicalServer,
         timestamp: test_timestamp(),
         correlation_id: Uuid::now_v7(),
+        change_ref: None,
     };
 
     let event1 = handle_register_resource(&state, register_cmd1, aggregate_id)
@@ -167,6 +173,7 @@ fn test_cannot_register_twice() {
         resource_type: ResourceType::VirtualMachine,
         timestamp: test_timestamp(),
         correlation_id: Uuid::now_v7(),
+        change_ref: None,
     };
 
     let result = handle_register_resource(&state, register_cmd2, aggregate_id);
@@ -186,6 +193,7 @@ fn test_cannot_add_policy_twice() {
         resource_type: ResourceType::PhysicalServer,
         timestamp: test_timestamp(),
         correlation_id: Uuid::now_v7(),
+        change_ref: None,
     };
 
     let register_event = handle_register_resource(&state, register_cmd, aggregate_id).unwrap();
@@ -198,6 +206,7 @@ fn test_cannot_add_policy_twice() {
         timestamp: test_timestamp(),
         correlation_id: Uuid::now_v7(),
         causation_id: None,
+        change_ref: None,
     };
 
     let policy_event = handle_add_policy(&state, add_cmd1).unwrap();
@@ -209,6 +218,7 @@ fn test_cannot_add_policy_twice() {
         timestamp: test_timestamp(),
         correlation_id: Uuid::now_v7(),
         causation_id: None,
+        change_ref: None,
     };
 
     let result = handle_add_policy(&state, add_cmd2);
@@ -231,6 +241,7 @@ fn test_invalid_status_transition() {
         resource_type: ResourceType::PhysicalServer,
         timestamp: test_timestamp(),
         correlation_id: Uuid::now_v7(),
+        change_ref: None,
     };
 
     let register_event = handle_register_resource(&state, register_cmd, aggregate_id).unwrap();
@@ -242,6 +253,7 @@ fn test_invalid_status_transition() {
         timestamp: test_timestamp(),
         correlation_id: Uuid::now_v7(),
         causation_id: None,
+        change_ref: None,
     };
 
     let activate_event = handle_change_status(&state, activate_cmd).unwrap();
@@ -253,6 +265,7 @@ fn test_invalid_status_transition() {
         timestamp: test_timestamp(),
         correlation_id: Uuid::now_v7(),
         causation_id: None,
+        change_ref: None,
     };
 
     let result = handle_change_status(&state, invalid_cmd);
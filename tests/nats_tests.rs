@@ -41,6 +41,7 @@ async fn test_nats_config_creation() {
         name: "test-client".to_string(),
         connect_timeout: Duration::from_secs(5),
         request_timeout: Duration::from_secs(30),
+        outage_buffer: 0,
     };
 
     // Then configuration is properly set
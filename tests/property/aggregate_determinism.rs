@@ -0,0 +1,85 @@
+// Copyright (c) 2025 - Cowboy AI, Inc.
+//! Property-Based Tests for the ComputeResource Aggregate
+//!
+//! Generates command sequences with the `test_harness` strategies, keeps
+//! the events of the accepted commands and checks that:
+//!
+//! - folding the emitted events reproduces the handlers' state
+//! - folding a single aggregate's events is stable however it is chunked
+//! - every emitted ComputeResource event survives a serialization round-trip
+
+use cim_infrastructure::aggregate::{apply_event, ComputeResourceState};
+use cim_infrastructure::events::{ComputeResourceEvent, InfrastructureEvent};
+use cim_infrastructure::test_harness::strategies::{accepted_events, command_sequence};
+use proptest::prelude::*;
+use proptest::sample::Index;
+use uuid::Uuid;
+
+/// Fold events onto a starting state
+fn fold(state: ComputeResourceState, events: &[ComputeResourceEvent]) -> ComputeResourceState {
+    events.iter().fold(state, apply_event)
+}
+
+proptest! {
+    /// Property: Folding emitted events reproduces the command state
+    ///
+    /// The state the handlers built while accepting commands must equal the
+    /// state rebuilt from the stored events alone.
+    #[test]
+    fn prop_fold_reproduces_command_state(commands in command_sequence(40)) {
+        let aggregate_id = Uuid::now_v7();
+        let (events, state) = accepted_events(aggregate_id, commands);
+
+        prop_assert!(
+            matches!(events.first(), Some(ComputeResourceEvent::ResourceRegistered(_))),
+            "Sequences must start with a registration"
+        );
+        prop_assert_eq!(
+            ComputeResourceState::from_events(&events),
+            state,
+            "Rebuilt state must match the handlers' state"
+        );
+    }
+
+    /// Property: Event application is order-stable for one aggregate
+    ///
+    /// Replaying the same stream always yields the same state, and resuming
+    /// from a snapshot at any point yields the state of a full replay.
+    #[test]
+    fn prop_fold_is_order_stable(commands in command_sequence(40), split in any::<Index>()) {
+        let aggregate_id = Uuid::now_v7();
+        let (events, _) = accepted_events(aggregate_id, commands);
+        let initial = ComputeResourceState::default_for(aggregate_id);
+        let split = split.index(events.len() + 1);
+
+        let full = fold(initial.clone(), &events);
+        let replayed = fold(initial.clone(), &events);
+        let snapshot = fold(initial, &events[..split]);
+        let resumed = fold(snapshot, &events[split..]);
+
+        prop_assert_eq!(&full, &replayed, "Replays must be deterministic");
+        prop_assert_eq!(full, resumed, "Resuming from a snapshot must match a full replay");
+    }
+
+    /// Property: ComputeResource events round-trip through JSON
+    ///
+    /// Checked both bare and inside the InfrastructureEvent envelope, as
+    /// events are published both ways. Only events the command strategies
+    /// lead to are generated; events of the network, interface, connection
+    /// and IP pool aggregates are not covered here.
+    #[test]
+    fn prop_events_round_trip(commands in command_sequence(40)) {
+        let (events, _) = accepted_events(Uuid::now_v7(), commands);
+
+        for event in events {
+            let json = serde_json::to_string(&event).unwrap();
+            let decoded: ComputeResourceEvent = serde_json::from_str(&json).unwrap();
+            prop_assert_eq!(&decoded, &event);
+
+            let wrapped = InfrastructureEvent::ComputeResource(event);
+            let json = serde_json::to_string(&wrapped).unwrap();
+            let decoded: InfrastructureEvent = serde_json::from_str(&json).unwrap();
+            prop_assert_eq!(decoded, wrapped);
+        }
+    }
+}
@@ -4,4 +4,7 @@
 //! This module contains property-based tests using proptest to verify
 //! fundamental mathematical properties of the event sourcing system.
 
+// Needs the strategies: `cargo test --features test-support`
+#[cfg(feature = "test-support")]
+mod aggregate_determinism;
 mod event_application;